@@ -0,0 +1,31 @@
+use crate::ports::inbound::{SbomGenerationEvent, SbomGenerationObserver};
+use std::sync::Mutex;
+
+/// CollectingObserver adapter that records every phase event
+///
+/// For tests and embedding applications that inspect the events after the
+/// run. Share it with the use case through an `Arc` to keep access to it.
+// Used only through the library; the binary does not observe phases
+#[allow(dead_code)]
+#[derive(Debug, Default)]
+pub struct CollectingObserver {
+    events: Mutex<Vec<SbomGenerationEvent>>,
+}
+
+#[allow(dead_code)]
+impl CollectingObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the events received so far, in order
+    pub fn events(&self) -> Vec<SbomGenerationEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+impl SbomGenerationObserver for CollectingObserver {
+    fn on_event(&self, event: &SbomGenerationEvent) {
+        self.events.lock().unwrap().push(event.clone());
+    }
+}
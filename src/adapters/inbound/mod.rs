@@ -1,2 +1,11 @@
-// Inbound adapters - Entry points for the application
-// CLI adapter will be implemented in Phase 4.5
+/// Inbound adapters - Implementations of the inbound ports
+///
+/// The CLI drives the use cases directly; these adapters follow a run
+/// through the [`crate::ports::inbound::SbomGenerationObserver`] port.
+mod collecting_observer;
+mod progress_reporter_observer;
+
+#[allow(unused_imports)]
+pub use collecting_observer::CollectingObserver;
+#[allow(unused_imports)]
+pub use progress_reporter_observer::ProgressReporterObserver;
@@ -0,0 +1,118 @@
+use crate::i18n::{Locale, Messages};
+use crate::ports::inbound::{SbomGenerationEvent, SbomGenerationObserver};
+use crate::ports::outbound::ProgressReporter;
+
+/// ProgressReporterObserver adapter that turns phase events into progress messages
+///
+/// Every event becomes a [`ProgressReporter::report_detail`] line, so with a
+/// reporter that only shows details in verbose mode, the normal output of the
+/// CLI stays as it is. Failures are reported as details too: the error itself
+/// reaches the caller through the use case's result.
+// Used only through the library; the binary does not observe phases
+#[allow(dead_code)]
+pub struct ProgressReporterObserver<PR> {
+    progress_reporter: PR,
+    locale: Locale,
+}
+
+#[allow(dead_code)]
+impl<PR: ProgressReporter> ProgressReporterObserver<PR> {
+    /// Creates an observer that reports to `progress_reporter` in `locale`
+    pub fn new(progress_reporter: PR, locale: Locale) -> Self {
+        Self {
+            progress_reporter,
+            locale,
+        }
+    }
+
+    /// Formats an event as the message reported for it
+    fn message(&self, event: &SbomGenerationEvent) -> String {
+        let msgs = Messages::for_locale(self.locale);
+        let phase = event.phase().id();
+        let millis = event
+            .duration()
+            .map(|duration| duration.as_millis().to_string())
+            .unwrap_or_default();
+        match event {
+            SbomGenerationEvent::PhaseStarted { .. } => {
+                Messages::format(msgs.progress_phase_started, &[phase])
+            }
+            SbomGenerationEvent::PhaseFailed { error, .. } => {
+                Messages::format(msgs.progress_phase_failed, &[phase, &millis, error])
+            }
+            _ => {
+                let counts: Vec<String> = event
+                    .counts()
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value))
+                    .collect();
+                let counts = if counts.is_empty() {
+                    String::new()
+                } else {
+                    format!(" ({})", counts.join(", "))
+                };
+                Messages::format(msgs.progress_phase_finished, &[phase, &millis, &counts])
+            }
+        }
+    }
+}
+
+impl<PR: ProgressReporter + Send + Sync> SbomGenerationObserver for ProgressReporterObserver<PR> {
+    fn on_event(&self, event: &SbomGenerationEvent) {
+        self.progress_reporter.report_detail(&self.message(event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::inbound::SbomPhase;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Clone, Default)]
+    struct RecordingReporter {
+        details: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ProgressReporter for RecordingReporter {
+        fn report(&self, _message: &str) {}
+        fn report_error(&self, _message: &str) {}
+        fn report_completion(&self, _message: &str) {}
+        fn report_detail(&self, message: &str) {
+            self.details.lock().unwrap().push(message.to_string());
+        }
+    }
+
+    #[test]
+    fn test_events_are_reported_as_details() {
+        let reporter = RecordingReporter::default();
+        let observer = ProgressReporterObserver::new(reporter.clone(), Locale::En);
+
+        observer.on_event(&SbomGenerationEvent::PhaseStarted {
+            phase: SbomPhase::ReadLockfile,
+        });
+        observer.on_event(&SbomGenerationEvent::LockfileRead {
+            package_count: 312,
+            duration: Duration::from_millis(25),
+        });
+        observer.on_event(&SbomGenerationEvent::PoliciesEvaluated {
+            duration: Duration::from_millis(1),
+        });
+        observer.on_event(&SbomGenerationEvent::PhaseFailed {
+            phase: SbomPhase::EnrichLicenses,
+            error: "timed out".to_string(),
+            duration: Duration::from_millis(3000),
+        });
+
+        assert_eq!(
+            *reporter.details.lock().unwrap(),
+            [
+                "▶️  Phase read_lockfile started",
+                "⏱️  Phase read_lockfile finished in 25 ms (package_count: 312)",
+                "⏱️  Phase evaluate_policies finished in 1 ms",
+                "❌ Phase enrich_licenses failed after 3000 ms: timed out",
+            ]
+        );
+    }
+}
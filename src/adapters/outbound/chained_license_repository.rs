@@ -0,0 +1,229 @@
+use crate::ports::outbound::{EndpointProbe, LicenseRepository, PyPiMetadata};
+use crate::sbom_generation::domain::LicenseInfo;
+use crate::shared::error::UvSbomError;
+use crate::shared::Result;
+use async_trait::async_trait;
+
+/// ChainedLicenseRepository tries a primary LicenseRepository and falls back
+/// to a second one when the primary fails.
+///
+/// Either side can be left out, so a single concrete type covers "primary
+/// only", "fallback only" and "primary, then fallback". This lets the
+/// composition root pick the license source at runtime while the use case
+/// stays generic over one repository type.
+#[derive(Clone)]
+pub struct ChainedLicenseRepository<A: LicenseRepository, B: LicenseRepository> {
+    primary: Option<A>,
+    fallback: Option<B>,
+}
+
+impl<A: LicenseRepository, B: LicenseRepository> ChainedLicenseRepository<A, B> {
+    /// Consults `primary` first and `fallback` for packages it cannot answer
+    pub fn new(primary: A, fallback: B) -> Self {
+        Self {
+            primary: Some(primary),
+            fallback: Some(fallback),
+        }
+    }
+
+    /// Consults `primary` only
+    pub fn primary_only(primary: A) -> Self {
+        Self {
+            primary: Some(primary),
+            fallback: None,
+        }
+    }
+
+    /// Consults `fallback` only
+    pub fn fallback_only(fallback: B) -> Self {
+        Self {
+            primary: None,
+            fallback: Some(fallback),
+        }
+    }
+}
+
+#[async_trait]
+impl<A: LicenseRepository, B: LicenseRepository> LicenseRepository
+    for ChainedLicenseRepository<A, B>
+{
+    async fn fetch_license_info(&self, package_name: &str, version: &str) -> Result<PyPiMetadata> {
+        let primary_error = match &self.primary {
+            Some(primary) => match primary.fetch_license_info(package_name, version).await {
+                Ok(metadata) => return Ok(metadata),
+                Err(e) => e,
+            },
+            None => UvSbomError::other("No license source configured"),
+        };
+
+        match &self.fallback {
+            Some(fallback) => fallback.fetch_license_info(package_name, version).await,
+            None => Err(primary_error),
+        }
+    }
+
+    /// Delegates enrichment as a whole so each side records its own license source
+    async fn enrich_with_license(&self, package_name: &str, version: &str) -> Result<LicenseInfo> {
+        let primary_error = match &self.primary {
+            Some(primary) => match primary.enrich_with_license(package_name, version).await {
+                Ok(info) => return Ok(info),
+                Err(e) => e,
+            },
+            None => UvSbomError::other("No license source configured"),
+        };
+
+        match &self.fallback {
+            Some(fallback) => fallback.enrich_with_license(package_name, version).await,
+            None => Err(primary_error),
+        }
+    }
+
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        let mut probes = Vec::new();
+        if let Some(primary) = &self.primary {
+            probes.extend(primary.probe_endpoints().await);
+        }
+        if let Some(fallback) = &self.fallback {
+            probes.extend(fallback.probe_endpoints().await);
+        }
+        probes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::LicenseSource;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Answers only for the listed package names and counts every call
+    #[derive(Clone)]
+    struct MockLicenseRepository {
+        license: &'static str,
+        known: &'static [&'static str],
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockLicenseRepository {
+        fn new(license: &'static str, known: &'static [&'static str]) -> Self {
+            Self {
+                license,
+                known,
+                calls: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LicenseRepository for MockLicenseRepository {
+        async fn fetch_license_info(
+            &self,
+            package_name: &str,
+            _version: &str,
+        ) -> Result<PyPiMetadata> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if !self.known.contains(&package_name) {
+                return Err(UvSbomError::other(format!("{} not found", package_name)));
+            }
+            Ok(PyPiMetadata {
+                license: Some(self.license.to_string()),
+                ..Default::default()
+            })
+        }
+
+        async fn enrich_with_license(
+            &self,
+            package_name: &str,
+            version: &str,
+        ) -> Result<LicenseInfo> {
+            let license = self
+                .fetch_license_info(package_name, version)
+                .await?
+                .license;
+            let source = match self.license {
+                "local" => LicenseSource::LocalMetadata,
+                _ => LicenseSource::PyPiLicenseField,
+            };
+            Ok(LicenseInfo::new(license, None).with_source(source))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_primary_hit_skips_fallback() {
+        let local = MockLicenseRepository::new("local", &["requests"]);
+        let pypi = MockLicenseRepository::new("pypi", &["requests"]);
+        let repo = ChainedLicenseRepository::new(local, pypi.clone());
+
+        let license = repo
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .license;
+
+        assert_eq!(license.as_deref(), Some("local"));
+        assert_eq!(pypi.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_primary_miss_uses_fallback() {
+        let local = MockLicenseRepository::new("local", &[]);
+        let pypi = MockLicenseRepository::new("pypi", &["requests"]);
+        let repo = ChainedLicenseRepository::new(local.clone(), pypi);
+
+        let license = repo
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .license;
+
+        assert_eq!(license.as_deref(), Some("pypi"));
+        assert_eq!(local.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_primary_only_returns_primary_error() {
+        let repo = ChainedLicenseRepository::<_, MockLicenseRepository>::primary_only(
+            MockLicenseRepository::new("local", &[]),
+        );
+
+        let err = repo
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("requests not found"));
+    }
+
+    #[tokio::test]
+    async fn test_fallback_only_skips_primary() {
+        let repo = ChainedLicenseRepository::<MockLicenseRepository, _>::fallback_only(
+            MockLicenseRepository::new("pypi", &["requests"]),
+        );
+
+        let license = repo
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .license;
+
+        assert_eq!(license.as_deref(), Some("pypi"));
+    }
+
+    #[tokio::test]
+    async fn test_enrich_keeps_source_of_answering_side() {
+        let repo = ChainedLicenseRepository::new(
+            MockLicenseRepository::new("local", &["requests"]),
+            MockLicenseRepository::new("pypi", &["requests", "urllib3"]),
+        );
+
+        let local = repo
+            .enrich_with_license("requests", "2.31.0")
+            .await
+            .unwrap();
+        let pypi = repo.enrich_with_license("urllib3", "2.0.0").await.unwrap();
+
+        assert_eq!(local.source(), LicenseSource::LocalMetadata);
+        assert_eq!(pypi.source(), LicenseSource::PyPiLicenseField);
+    }
+}
@@ -0,0 +1,300 @@
+use crate::ports::outbound::{
+    EndpointProbe, IncompleteAdvisory, ProgressCallback, UncheckedPackage,
+    VulnerabilityFetchOutcome, VulnerabilityRepository,
+};
+use crate::sbom_generation::domain::services::AliasDeduplicator;
+use crate::sbom_generation::domain::vulnerability::{PackageVulnerabilities, Vulnerability};
+use crate::sbom_generation::domain::{Package, PackageName};
+use crate::shared::Result;
+use async_trait::async_trait;
+
+/// CompositeVulnerabilityRepository queries two VulnerabilityRepositories and
+/// merges what they report.
+///
+/// Either side can be left out, so a single concrete type covers "primary
+/// only", "secondary only" and "both", and the composition root can pick the
+/// vulnerability sources at runtime. When both report an advisory with the
+/// same ID the primary's copy is kept; advisories that are aliases of one
+/// another are then collapsed as usual.
+#[derive(Clone)]
+pub struct CompositeVulnerabilityRepository<A: VulnerabilityRepository, B: VulnerabilityRepository>
+{
+    primary: Option<A>,
+    secondary: Option<B>,
+}
+
+impl<A: VulnerabilityRepository, B: VulnerabilityRepository>
+    CompositeVulnerabilityRepository<A, B>
+{
+    /// Queries both `primary` and `secondary`
+    pub fn new(primary: A, secondary: B) -> Self {
+        Self {
+            primary: Some(primary),
+            secondary: Some(secondary),
+        }
+    }
+
+    /// Queries `primary` only
+    pub fn primary_only(primary: A) -> Self {
+        Self {
+            primary: Some(primary),
+            secondary: None,
+        }
+    }
+
+    /// Queries `secondary` only
+    pub fn secondary_only(secondary: B) -> Self {
+        Self {
+            primary: None,
+            secondary: Some(secondary),
+        }
+    }
+}
+
+#[async_trait]
+impl<A: VulnerabilityRepository, B: VulnerabilityRepository> VulnerabilityRepository
+    for CompositeVulnerabilityRepository<A, B>
+{
+    async fn fetch_vulnerabilities(
+        &self,
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
+        self.fetch_vulnerabilities_with_progress(packages, Box::new(|_, _| {}))
+            .await
+    }
+
+    /// Progress is reported by the primary source, or by the secondary one
+    /// when it is the only source
+    async fn fetch_vulnerabilities_with_progress(
+        &self,
+        packages: &[Package],
+        progress_callback: ProgressCallback<'static>,
+    ) -> Result<VulnerabilityFetchOutcome> {
+        let mut progress_callback = Some(progress_callback);
+        let mut outcomes = Vec::new();
+        if let Some(primary) = &self.primary {
+            let callback = progress_callback
+                .take()
+                .unwrap_or_else(|| Box::new(|_, _| {}));
+            outcomes.push(
+                primary
+                    .fetch_vulnerabilities_with_progress(packages, callback)
+                    .await?,
+            );
+        }
+        if let Some(secondary) = &self.secondary {
+            let callback = progress_callback
+                .take()
+                .unwrap_or_else(|| Box::new(|_, _| {}));
+            outcomes.push(
+                secondary
+                    .fetch_vulnerabilities_with_progress(packages, callback)
+                    .await?,
+            );
+        }
+        Ok(merge_outcomes(outcomes))
+    }
+
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        let mut probes = Vec::new();
+        if let Some(primary) = &self.primary {
+            probes.extend(primary.probe_endpoints().await);
+        }
+        if let Some(secondary) = &self.secondary {
+            probes.extend(secondary.probe_endpoints().await);
+        }
+        probes
+    }
+}
+
+/// Merges the outcomes per package, in the order the packages were first reported
+///
+/// A package that any source could not check stays listed as unchecked, even
+/// when another source checked it.
+fn merge_outcomes(outcomes: Vec<VulnerabilityFetchOutcome>) -> VulnerabilityFetchOutcome {
+    let mut merged: Vec<(String, String, String, Vec<Vulnerability>)> = Vec::new();
+    let mut failed_packages: Vec<UncheckedPackage> = Vec::new();
+    let mut incomplete_advisories: Vec<IncompleteAdvisory> = Vec::new();
+    let mut cached_packages = 0;
+
+    for outcome in outcomes {
+        cached_packages += outcome.cached_packages;
+        for result in outcome.results {
+            let key = PackageName::normalize(result.package_name());
+            let version = result.current_version().to_string();
+            let index = match merged
+                .iter()
+                .position(|(k, _, v, _)| *k == key && *v == version)
+            {
+                Some(index) => index,
+                None => {
+                    merged.push((key, result.package_name().to_string(), version, Vec::new()));
+                    merged.len() - 1
+                }
+            };
+            let vulnerabilities = &mut merged[index].3;
+            for vulnerability in result.vulnerabilities() {
+                if !vulnerabilities.iter().any(|v| v.id() == vulnerability.id()) {
+                    vulnerabilities.push(vulnerability.clone());
+                }
+            }
+        }
+        for unchecked in outcome.failed_packages {
+            let already_listed = failed_packages.iter().any(|f| {
+                f.package.name() == unchecked.package.name()
+                    && f.package.version() == unchecked.package.version()
+            });
+            if !already_listed {
+                failed_packages.push(unchecked);
+            }
+        }
+        for advisory in outcome.incomplete_advisories {
+            if !incomplete_advisories.iter().any(|a| a.id == advisory.id) {
+                incomplete_advisories.push(advisory);
+            }
+        }
+    }
+
+    let results = merged
+        .into_iter()
+        .map(|(_, name, version, mut vulnerabilities)| {
+            vulnerabilities.sort_by(|a, b| a.id().cmp(b.id()));
+            PackageVulnerabilities::new(name, version, AliasDeduplicator::apply(vulnerabilities))
+        })
+        .collect();
+
+    VulnerabilityFetchOutcome {
+        results,
+        failed_packages,
+        incomplete_advisories,
+        cached_packages,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::use_cases::test_doubles::MockVulnerabilityRepository;
+    use crate::sbom_generation::domain::Severity;
+
+    fn vulnerability(id: &str, aliases: &[&str], severity: Severity) -> Vulnerability {
+        Vulnerability::new(id.to_string(), None, severity, None, None)
+            .unwrap()
+            .with_aliases(aliases.iter().map(|a| a.to_string()).collect())
+    }
+
+    fn repository(
+        package: &str,
+        vulnerabilities: Vec<Vulnerability>,
+    ) -> MockVulnerabilityRepository {
+        MockVulnerabilityRepository {
+            vulnerabilities: vec![PackageVulnerabilities::new(
+                package.to_string(),
+                "2.30.0".to_string(),
+                vulnerabilities,
+            )],
+            ..Default::default()
+        }
+    }
+
+    fn packages() -> Vec<Package> {
+        ["requests", "urllib3"]
+            .iter()
+            .map(|name| Package::new(name.to_string(), "2.30.0".to_string()).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_merges_and_deduplicates_by_advisory_id() {
+        let osv = repository(
+            "requests",
+            vec![vulnerability("GHSA-j8r2-6x86-q33q", &[], Severity::Medium)],
+        );
+        let advisory_db = repository(
+            "requests",
+            vec![
+                vulnerability("GHSA-j8r2-6x86-q33q", &[], Severity::None),
+                vulnerability("PYSEC-2024-1", &[], Severity::None),
+            ],
+        );
+
+        let outcome = CompositeVulnerabilityRepository::new(osv, advisory_db)
+            .fetch_vulnerabilities(&packages())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        let vulnerabilities = outcome.results[0].vulnerabilities();
+        let ids: Vec<&str> = vulnerabilities.iter().map(|v| v.id()).collect();
+        assert_eq!(ids, ["GHSA-j8r2-6x86-q33q", "PYSEC-2024-1"]);
+        // The primary's copy is kept
+        assert_eq!(vulnerabilities[0].severity(), Severity::Medium);
+    }
+
+    #[tokio::test]
+    async fn test_collapses_aliases_across_sources() {
+        let osv = repository(
+            "requests",
+            vec![vulnerability(
+                "GHSA-j8r2-6x86-q33q",
+                &["CVE-2023-32681"],
+                Severity::Medium,
+            )],
+        );
+        let advisory_db = repository(
+            "requests",
+            vec![vulnerability(
+                "PYSEC-2023-74",
+                &["CVE-2023-32681"],
+                Severity::None,
+            )],
+        );
+
+        let outcome = CompositeVulnerabilityRepository::new(osv, advisory_db)
+            .fetch_vulnerabilities(&packages())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.results[0].vulnerabilities().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unchecked_packages_are_kept() {
+        let osv = MockVulnerabilityRepository {
+            failing_packages: vec!["urllib3".to_string()],
+            ..Default::default()
+        };
+        let advisory_db = repository(
+            "urllib3",
+            vec![vulnerability("PYSEC-2024-2", &[], Severity::Low)],
+        );
+
+        let outcome = CompositeVulnerabilityRepository::new(osv, advisory_db)
+            .fetch_vulnerabilities(&packages())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.results[0].package_name(), "urllib3");
+        assert_eq!(outcome.failed_packages.len(), 1);
+        assert_eq!(outcome.failed_packages[0].package.name(), "urllib3");
+    }
+
+    #[tokio::test]
+    async fn test_single_source() {
+        let advisory_db = repository(
+            "requests",
+            vec![vulnerability("PYSEC-2024-1", &[], Severity::Low)],
+        );
+
+        let outcome =
+            CompositeVulnerabilityRepository::<MockVulnerabilityRepository, _>::secondary_only(
+                advisory_db,
+            )
+            .fetch_vulnerabilities(&packages())
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert!(outcome.failed_packages.is_empty());
+    }
+}
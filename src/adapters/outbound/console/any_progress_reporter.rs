@@ -0,0 +1,82 @@
+use super::{JsonProgressReporter, StderrProgressReporter};
+use crate::ports::outbound::{ProgressPhase, ProgressReporter, TaskHandle};
+use std::sync::Arc;
+
+/// AnyProgressReporter dispatches to the reporter for the progress format in use
+///
+/// Human-readable progress is written by `StderrProgressReporter` and
+/// newline-delimited JSON events by `JsonProgressReporter`; the format is
+/// chosen at runtime from the command line.
+#[derive(Clone)]
+pub enum AnyProgressReporter {
+    Text(StderrProgressReporter),
+    Json(JsonProgressReporter),
+}
+
+impl AnyProgressReporter {
+    /// Holds the JSON `done` event back until [`Self::finish`]
+    pub fn with_deferred_done(self) -> Self {
+        match self {
+            Self::Json(reporter) => Self::Json(reporter.with_deferred_done()),
+            text => text,
+        }
+    }
+
+    /// Emits the JSON `done` event held back by [`Self::with_deferred_done`]
+    pub fn finish(&self) {
+        if let Self::Json(reporter) = self {
+            reporter.finish();
+        }
+    }
+}
+
+impl ProgressReporter for AnyProgressReporter {
+    fn report(&self, message: &str) {
+        match self {
+            Self::Text(reporter) => reporter.report(message),
+            Self::Json(reporter) => reporter.report(message),
+        }
+    }
+
+    fn report_error(&self, message: &str) {
+        match self {
+            Self::Text(reporter) => reporter.report_error(message),
+            Self::Json(reporter) => reporter.report_error(message),
+        }
+    }
+
+    fn report_completion(&self, message: &str) {
+        match self {
+            Self::Text(reporter) => reporter.report_completion(message),
+            Self::Json(reporter) => reporter.report_completion(message),
+        }
+    }
+
+    fn report_detail(&self, message: &str) {
+        match self {
+            Self::Text(reporter) => reporter.report_detail(message),
+            Self::Json(reporter) => reporter.report_detail(message),
+        }
+    }
+
+    fn shows_progress(&self) -> bool {
+        match self {
+            Self::Text(reporter) => reporter.shows_progress(),
+            Self::Json(reporter) => reporter.shows_progress(),
+        }
+    }
+
+    fn start_task(&self, phase: ProgressPhase, total: usize) -> Arc<dyn TaskHandle> {
+        match self {
+            Self::Text(reporter) => reporter.start_task(phase, total),
+            Self::Json(reporter) => reporter.start_task(phase, total),
+        }
+    }
+
+    fn report_done(&self, packages: usize, warnings: usize) {
+        match self {
+            Self::Text(reporter) => reporter.report_done(packages, warnings),
+            Self::Json(reporter) => reporter.report_done(packages, warnings),
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use crate::ports::outbound::{ProgressPhase, ProgressReporter, TaskHandle};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+type SharedWriter = Arc<Mutex<Box<dyn Write + Send>>>;
+/// Package and warning counts of a `done` event that has not been emitted yet
+type PendingDone = Arc<Mutex<Option<(usize, usize)>>>;
+
+/// JsonProgressReporter adapter emitting progress as newline-delimited JSON
+///
+/// For tools that wrap uv-sbom (TUIs, CI dashboards) and want structured
+/// progress instead of parsing the human-readable stderr output. Each event is
+/// one JSON object on its own line, written to stderr unless another writer is
+/// given with [`JsonProgressReporter::with_writer`].
+///
+/// # Event Schema
+/// Every object has an `event` field naming the event:
+///
+/// | `event`       | Other fields                                   | Emitted when                        |
+/// |---------------|------------------------------------------------|-------------------------------------|
+/// | `phase_start` | `phase` (string), `total` (number)             | A long-running phase begins         |
+/// | `progress`    | `phase` (string), `current`, `total` (numbers) | An item of the phase is done        |
+/// | `phase_end`   | `phase` (string)                               | The phase is done                   |
+/// | `message`     | `message` (string)                             | A step or result message            |
+/// | `detail`      | `message` (string)                             | A per-package detail                |
+/// | `error`       | `message` (string)                             | An error or warning                 |
+/// | `done`        | `packages`, `warnings` (numbers)               | SBOM generation finished            |
+///
+/// `phase` is one of `license_fetch`, `vulnerability_fetch` and
+/// `maintenance_fetch`. A `total` of 0 means the amount of work is not known
+/// yet; `vulnerability_fetch` learns its total from a later `progress` event.
+/// Messages are localized text meant for display, not for matching.
+///
+/// Fatal errors that end the run are not events: they are printed as text
+/// and reported through the exit code, so consumers should skip lines that
+/// are not JSON objects.
+///
+/// # Example
+/// ```text
+/// {"event":"phase_start","phase":"license_fetch","total":2}
+/// {"event":"progress","phase":"license_fetch","current":1,"total":2}
+/// {"event":"progress","phase":"license_fetch","current":2,"total":2}
+/// {"event":"phase_end","phase":"license_fetch"}
+/// {"event":"done","packages":2,"warnings":0}
+/// ```
+///
+/// Clones share the writer, so a binary can hand one clone to the use case and
+/// keep another for the messages it reports once generation is done.
+#[derive(Clone)]
+pub struct JsonProgressReporter {
+    writer: SharedWriter,
+    /// Counts of a `done` event held back until [`JsonProgressReporter::finish`];
+    /// `None` when `done` is emitted as soon as it is reported
+    deferred_done: Option<PendingDone>,
+}
+
+impl JsonProgressReporter {
+    /// Creates a reporter writing to stderr
+    pub fn new() -> Self {
+        Self::with_writer(std::io::stderr())
+    }
+
+    /// Creates a reporter writing to `writer`
+    pub fn with_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(Box::new(writer))),
+            deferred_done: None,
+        }
+    }
+
+    /// Holds the `done` event back until [`Self::finish`], so messages reported
+    /// after generation, such as output digests, still come before it
+    pub fn with_deferred_done(mut self) -> Self {
+        self.deferred_done = Some(Arc::default());
+        self
+    }
+
+    /// Emits the `done` event held back by [`Self::with_deferred_done`], if any
+    pub fn finish(&self) {
+        let pending = self
+            .deferred_done
+            .as_ref()
+            .and_then(|deferred| deferred.lock().ok()?.take());
+        if let Some((packages, warnings)) = pending {
+            emit_done(&self.writer, packages, warnings);
+        }
+    }
+
+    fn emit_message(&self, event: &str, message: &str) {
+        emit(&self.writer, json!({ "event": event, "message": message }));
+    }
+}
+
+impl Default for JsonProgressReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes one event line; a closed stream is ignored, as progress is best-effort
+fn emit(writer: &SharedWriter, event: Value) {
+    if let Ok(mut writer) = writer.lock() {
+        let _ = writeln!(writer, "{}", event);
+        let _ = writer.flush();
+    }
+}
+
+impl ProgressReporter for JsonProgressReporter {
+    fn report(&self, message: &str) {
+        self.emit_message("message", message);
+    }
+
+    fn report_error(&self, message: &str) {
+        self.emit_message("error", message);
+    }
+
+    fn report_completion(&self, message: &str) {
+        self.emit_message("message", message);
+    }
+
+    fn report_detail(&self, message: &str) {
+        self.emit_message("detail", message);
+    }
+
+    fn shows_progress(&self) -> bool {
+        false
+    }
+
+    fn start_task(&self, phase: ProgressPhase, total: usize) -> Arc<dyn TaskHandle> {
+        emit(
+            &self.writer,
+            json!({ "event": "phase_start", "phase": phase.id(), "total": total }),
+        );
+        Arc::new(JsonTask {
+            writer: self.writer.clone(),
+            phase,
+            finished: AtomicBool::new(false),
+        })
+    }
+
+    fn report_done(&self, packages: usize, warnings: usize) {
+        match &self.deferred_done {
+            Some(deferred) => {
+                if let Ok(mut pending) = deferred.lock() {
+                    *pending = Some((packages, warnings));
+                }
+            }
+            None => emit_done(&self.writer, packages, warnings),
+        }
+    }
+}
+
+fn emit_done(writer: &SharedWriter, packages: usize, warnings: usize) {
+    emit(
+        writer,
+        json!({ "event": "done", "packages": packages, "warnings": warnings }),
+    );
+}
+
+/// Emits the `progress` and `phase_end` events of one phase
+struct JsonTask {
+    writer: SharedWriter,
+    phase: ProgressPhase,
+    finished: AtomicBool,
+}
+
+impl TaskHandle for JsonTask {
+    fn update(&self, current: usize, total: usize) {
+        emit(
+            &self.writer,
+            json!({
+                "event": "progress",
+                "phase": self.phase.id(),
+                "current": current,
+                "total": total,
+            }),
+        );
+    }
+
+    fn finish(&self) {
+        if !self.finished.swap(true, Ordering::Relaxed) {
+            emit(
+                &self.writer,
+                json!({ "event": "phase_end", "phase": self.phase.id() }),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writer collecting the output in a buffer shared with the test
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn events(buffer: &Buffer) -> Vec<Value> {
+        String::from_utf8(buffer.0.lock().unwrap().clone())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_task_emits_phase_events() {
+        let buffer = Buffer::default();
+        let reporter = JsonProgressReporter::with_writer(buffer.clone());
+
+        let task = reporter.start_task(ProgressPhase::LicenseEnrichment, 2);
+        task.update(1, 2);
+        task.finish();
+        // A second finish is a no-op
+        task.finish();
+
+        assert_eq!(
+            events(&buffer),
+            vec![
+                json!({"event": "phase_start", "phase": "license_fetch", "total": 2}),
+                json!({"event": "progress", "phase": "license_fetch", "current": 1, "total": 2}),
+                json!({"event": "phase_end", "phase": "license_fetch"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_messages_are_escaped_json() {
+        let buffer = Buffer::default();
+        let reporter = JsonProgressReporter::with_writer(buffer.clone());
+
+        reporter.report_error("⚠️  Warning: \"requests\"\nnot found");
+        reporter.report_done(3, 1);
+
+        assert_eq!(
+            events(&buffer),
+            vec![
+                json!({"event": "error", "message": "⚠️  Warning: \"requests\"\nnot found"}),
+                json!({"event": "done", "packages": 3, "warnings": 1}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deferred_done_follows_later_messages() {
+        let buffer = Buffer::default();
+        let reporter = JsonProgressReporter::with_writer(buffer.clone()).with_deferred_done();
+        let kept = reporter.clone();
+
+        reporter.report_done(3, 0);
+        kept.report_completion("sbom.json digest");
+        kept.finish();
+        // A second finish emits nothing
+        kept.finish();
+
+        assert_eq!(
+            events(&buffer),
+            vec![
+                json!({"event": "message", "message": "sbom.json digest"}),
+                json!({"event": "done", "packages": 3, "warnings": 0}),
+            ]
+        );
+    }
+}
@@ -1,4 +1,13 @@
 /// Console adapters for stderr/stdout output
+mod any_progress_reporter;
+mod json_progress_reporter;
 mod progress_reporter;
+mod silent_progress_reporter;
+mod summary_presenter;
 
+pub use any_progress_reporter::AnyProgressReporter;
+pub use json_progress_reporter::JsonProgressReporter;
 pub use progress_reporter::StderrProgressReporter;
+#[allow(unused_imports)]
+pub use silent_progress_reporter::SilentProgressReporter;
+pub use summary_presenter::{ConsoleSummary, ConsoleSummaryPresenter};
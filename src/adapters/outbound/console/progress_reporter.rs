@@ -1,17 +1,57 @@
 use crate::i18n::Locale;
-use crate::ports::outbound::ProgressReporter;
+use crate::ports::outbound::{ProgressPhase, ProgressReporter, TaskHandle, Verbosity};
+use crate::shared::terminal::{self, ProgressDisplay, TerminalProgress};
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Interval at which the progress bar thread picks up task updates
+const PROGRESS_POLL_INTERVAL_MS: u64 = 50;
 
 /// StderrProgressReporter adapter for reporting progress to stderr
 ///
 /// This adapter implements the ProgressReporter port, writing progress
 /// information to stderr so it doesn't interfere with stdout output.
 /// The reporter emits messages as-is; locale-aware string selection is
-/// handled by the caller.
-pub struct StderrProgressReporter;
+/// handled by the caller. Errors and warnings are always written; what else
+/// is written depends on the [`Verbosity`]. When `NO_COLOR` is set, emoji are
+/// stripped from every message.
+#[derive(Clone)]
+pub struct StderrProgressReporter {
+    verbosity: Verbosity,
+    display: ProgressDisplay,
+    plain: bool,
+}
 
 impl StderrProgressReporter {
     pub fn new(_locale: Locale) -> Self {
-        Self
+        Self {
+            verbosity: Verbosity::Normal,
+            display: ProgressDisplay::Auto,
+            plain: terminal::no_color(),
+        }
+    }
+
+    /// Sets how much progress output is written
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Sets when progress bars are drawn instead of plain lines
+    pub fn with_progress_display(mut self, display: ProgressDisplay) -> Self {
+        self.display = display;
+        self
+    }
+
+    fn text<'a>(&self, message: &'a str) -> Cow<'a, str> {
+        if self.plain {
+            Cow::Owned(terminal::strip_emoji(message))
+        } else {
+            Cow::Borrowed(message)
+        }
     }
 }
 
@@ -23,16 +63,118 @@ impl Default for StderrProgressReporter {
 
 impl ProgressReporter for StderrProgressReporter {
     fn report(&self, message: &str) {
-        eprintln!("{}", message);
+        if self.verbosity != Verbosity::Quiet {
+            eprintln!("{}", self.text(message));
+        }
     }
 
     fn report_error(&self, message: &str) {
-        eprintln!("{}", message);
+        eprintln!("{}", self.text(message));
     }
 
     fn report_completion(&self, message: &str) {
-        eprintln!();
-        eprintln!("{}", message);
+        if self.verbosity != Verbosity::Quiet {
+            eprintln!();
+            eprintln!("{}", self.text(message));
+        }
+    }
+
+    fn report_detail(&self, message: &str) {
+        if self.verbosity == Verbosity::Verbose {
+            eprintln!("{}", self.text(message));
+        }
+    }
+
+    fn shows_progress(&self) -> bool {
+        self.verbosity != Verbosity::Quiet
+    }
+
+    fn start_task(&self, phase: ProgressPhase, total: usize) -> Arc<dyn TaskHandle> {
+        Arc::new(StderrTask::start(
+            phase.label(),
+            total,
+            self.shows_progress(),
+            self.display,
+        ))
+    }
+}
+
+/// Progress bar for one task, drawn by a background thread
+///
+/// The bar is redrawn from a thread that polls the latest counts, so that
+/// updates from async code never block on terminal output.
+struct StderrTask {
+    current: Arc<AtomicUsize>,
+    total: Arc<AtomicUsize>,
+    done: Arc<AtomicBool>,
+    visible: bool,
+    thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl StderrTask {
+    fn start(label: &str, total: usize, visible: bool, display: ProgressDisplay) -> Self {
+        let current = Arc::new(AtomicUsize::new(0));
+        let total = Arc::new(AtomicUsize::new(total));
+        let done = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let current = current.clone();
+            let total = total.clone();
+            let done = done.clone();
+            let mut pb = TerminalProgress::new_if(
+                visible,
+                display,
+                total.load(Ordering::Relaxed) as u64,
+                label,
+            );
+            thread::spawn(move || {
+                while !done.load(Ordering::Relaxed) {
+                    let total = total.load(Ordering::Relaxed);
+                    if total > 0 {
+                        pb.set_length(total as u64);
+                        pb.set_position(current.load(Ordering::Relaxed) as u64);
+                    } else {
+                        // Amount of work not known yet - show a spinner
+                        pb.tick();
+                    }
+                    thread::sleep(Duration::from_millis(PROGRESS_POLL_INTERVAL_MS));
+                }
+                pb.finish();
+            })
+        };
+
+        Self {
+            current,
+            total,
+            done,
+            visible,
+            thread: Mutex::new(Some(thread)),
+        }
+    }
+}
+
+impl TaskHandle for StderrTask {
+    fn update(&self, current: usize, total: usize) {
+        self.current.store(current, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    fn finish(&self) {
+        self.done.store(true, Ordering::Relaxed);
+        let thread = self.thread.lock().ok().and_then(|mut t| t.take());
+        if let Some(thread) = thread {
+            let _ = thread.join();
+            if self.visible {
+                eprintln!(); // newline after progress bar
+            }
+        }
+    }
+}
+
+impl Drop for StderrTask {
+    fn drop(&mut self) {
+        // Stop the thread if the task was abandoned, e.g. on an early error return
+        self.done.store(true, Ordering::Relaxed);
     }
 }
 
@@ -56,6 +198,41 @@ mod tests {
         reporter.report("テストメッセージ");
     }
 
+    #[test]
+    fn test_progress_reporter_verbosity() {
+        let reporter = StderrProgressReporter::new(Locale::En);
+        assert!(reporter.shows_progress());
+
+        let quiet = StderrProgressReporter::new(Locale::En).with_verbosity(Verbosity::Quiet);
+        assert!(!quiet.shows_progress());
+        quiet.report("suppressed");
+        quiet.report_error("still written");
+
+        let verbose = StderrProgressReporter::new(Locale::En).with_verbosity(Verbosity::Verbose);
+        assert!(verbose.shows_progress());
+        verbose.report_detail("requests 2.31.0: Apache-2.0");
+    }
+
+    #[test]
+    fn test_start_task_finishes_once() {
+        let quiet = StderrProgressReporter::new(Locale::En).with_verbosity(Verbosity::Quiet);
+        let task = quiet.start_task(ProgressPhase::LicenseEnrichment, 3);
+        task.update(1, 3);
+        task.update(3, 3);
+        task.finish();
+        // A second finish is a no-op
+        task.finish();
+    }
+
+    #[test]
+    fn test_plain_reporter_strips_emoji() {
+        let mut reporter = StderrProgressReporter::new(Locale::En);
+        assert_eq!(reporter.text("🔍 Checking"), "🔍 Checking");
+
+        reporter.plain = true;
+        assert_eq!(reporter.text("🔍 Checking"), "Checking");
+    }
+
     #[test]
     fn test_progress_reporter_default() {
         let reporter: StderrProgressReporter = Default::default();
@@ -0,0 +1,23 @@
+use crate::ports::outbound::ProgressReporter;
+
+/// SilentProgressReporter adapter that discards all progress output
+///
+/// For library callers that render their own UI or run in the background:
+/// nothing is written to stderr, not even warnings. Warnings remain
+/// available on the `SbomResponse`.
+// Used only through the library; the binary always reports to stderr
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SilentProgressReporter;
+
+impl ProgressReporter for SilentProgressReporter {
+    fn report(&self, _message: &str) {}
+
+    fn report_error(&self, _message: &str) {}
+
+    fn report_completion(&self, _message: &str) {}
+
+    fn shows_progress(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,354 @@
+use crate::application::dto::SbomResponse;
+use crate::application::read_models::{
+    DependencyCounts, LicenseCounts, VulnerabilityCountsBySeverity,
+};
+use crate::i18n::{Locale, Messages};
+use crate::shared::terminal;
+use owo_colors::OwoColorize;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Spaces between the label and value columns
+const COLUMN_GAP: usize = 2;
+
+/// Counts shown in the end-of-run summary table
+#[derive(Debug, Clone)]
+pub struct ConsoleSummary {
+    pub total_packages: usize,
+    /// `None` when no dependency graph was built (JSON output)
+    pub dependencies: Option<DependencyCounts>,
+    pub licenses: LicenseCounts,
+    /// `None` when the vulnerability check did not run
+    pub vulnerabilities: Option<VulnerabilityCountsBySeverity>,
+    /// File or directory written; `None` for stdout
+    pub output: Option<PathBuf>,
+    pub elapsed: Duration,
+}
+
+impl ConsoleSummary {
+    /// Collects the counts of a generation run; output and elapsed time are
+    /// known only to the caller and set with [`Self::with_output`] and
+    /// [`Self::with_elapsed`]
+    pub fn from_response(response: &SbomResponse) -> Self {
+        Self {
+            total_packages: response.enriched_packages.len(),
+            dependencies: response
+                .dependency_graph
+                .as_ref()
+                .map(DependencyCounts::of_graph),
+            licenses: LicenseCounts::of_packages(&response.enriched_packages),
+            vulnerabilities: response
+                .vulnerability_check_result
+                .as_ref()
+                .map(VulnerabilityCountsBySeverity::of_check_result),
+            output: None,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn with_output(mut self, output: Option<PathBuf>) -> Self {
+        self.output = output;
+        self
+    }
+
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+}
+
+/// ConsoleSummaryPresenter adapter for the summary table printed to stderr
+/// after the SBOM is written
+///
+/// Labels are padded by their display width, so the value column stays aligned
+/// when translated labels contain double-width (CJK) characters. Severity
+/// counts are coloured only when colours are enabled, which by default follows
+/// whether stderr is an ANSI-capable terminal and `NO_COLOR` is unset.
+pub struct ConsoleSummaryPresenter {
+    messages: &'static Messages,
+    colors: bool,
+}
+
+/// A table value, with its text as measured and as printed
+struct Cell {
+    plain: String,
+    styled: String,
+}
+
+impl Cell {
+    fn plain(text: String) -> Self {
+        Self {
+            styled: text.clone(),
+            plain: text,
+        }
+    }
+}
+
+impl ConsoleSummaryPresenter {
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            messages: Messages::for_locale(locale),
+            colors: terminal::stderr_colors_enabled(),
+        }
+    }
+
+    /// Overrides whether severity counts are coloured
+    #[allow(dead_code)] // Library API
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Writes the summary table to stderr
+    pub fn present(&self, summary: &ConsoleSummary) {
+        eprint!("{}", self.render(summary));
+    }
+
+    /// Renders the summary table, one line per row under a title and a rule
+    pub fn render(&self, summary: &ConsoleSummary) -> String {
+        let msgs = self.messages;
+        let skipped = || Cell::plain(msgs.stats_skipped.to_string());
+        let rows = [
+            (
+                msgs.label_stats_total_packages,
+                Cell::plain(summary.total_packages.to_string()),
+            ),
+            (
+                msgs.label_stats_dependencies,
+                summary.dependencies.map_or_else(skipped, |d| {
+                    Cell::plain(Messages::format(
+                        msgs.stats_dependencies_value,
+                        &[&d.direct.to_string(), &d.transitive.to_string()],
+                    ))
+                }),
+            ),
+            (
+                msgs.label_stats_licenses,
+                Cell::plain(Messages::format(
+                    msgs.stats_licenses_value,
+                    &[
+                        &summary.licenses.resolved.to_string(),
+                        &summary.licenses.missing.to_string(),
+                    ],
+                )),
+            ),
+            (
+                msgs.label_stats_vulnerabilities,
+                summary
+                    .vulnerabilities
+                    .as_ref()
+                    .map_or_else(skipped, |v| self.severity_cell(v)),
+            ),
+            (
+                msgs.label_summary_output,
+                Cell::plain(summary.output.as_ref().map_or_else(
+                    || msgs.summary_output_stdout.to_string(),
+                    |path| path.display().to_string(),
+                )),
+            ),
+            (
+                msgs.label_summary_elapsed,
+                Cell::plain(Messages::format(
+                    msgs.summary_elapsed_value,
+                    &[&format!("{:.1}", summary.elapsed.as_secs_f64())],
+                )),
+            ),
+        ];
+
+        let label_width = rows
+            .iter()
+            .map(|(label, _)| display_width(label))
+            .max()
+            .unwrap_or(0);
+        let value_width = rows
+            .iter()
+            .map(|(_, cell)| display_width(&cell.plain))
+            .max()
+            .unwrap_or(0);
+        let rule = "-".repeat(label_width + COLUMN_GAP + value_width);
+
+        let title = if self.colors {
+            msgs.label_summary_title.bold().to_string()
+        } else {
+            msgs.label_summary_title.to_string()
+        };
+        let mut table = format!("\n{}\n{}\n", title, rule);
+        for (label, cell) in &rows {
+            let padding = label_width - display_width(label) + COLUMN_GAP;
+            table.push_str(&format!(
+                "{}{}{}\n",
+                label,
+                " ".repeat(padding),
+                cell.styled
+            ));
+        }
+        table.push_str(&rule);
+        table.push('\n');
+        table
+    }
+
+    /// Formats the severity counts, colouring the non-zero ones
+    fn severity_cell(&self, counts: &VulnerabilityCountsBySeverity) -> Cell {
+        let values = [counts.critical, counts.high, counts.medium, counts.low];
+        let plain: Vec<String> = values.iter().map(usize::to_string).collect();
+        let styled: Vec<String> = values
+            .iter()
+            .zip(&plain)
+            .enumerate()
+            .map(|(level, (&count, text))| match level {
+                _ if !self.colors || count == 0 => text.clone(),
+                0 => text.bright_red().bold().to_string(),
+                1 => text.red().to_string(),
+                2 => text.yellow().to_string(),
+                _ => text.blue().to_string(),
+            })
+            .collect();
+        let template = self.messages.stats_vulnerabilities_value;
+        Cell {
+            plain: Messages::format(
+                template,
+                &plain.iter().map(String::as_str).collect::<Vec<_>>(),
+            ),
+            styled: Messages::format(
+                template,
+                &styled.iter().map(String::as_str).collect::<Vec<_>>(),
+            ),
+        }
+    }
+}
+
+/// Returns the number of terminal columns `text` takes up, counting East
+/// Asian wide and fullwidth characters as two
+fn display_width(text: &str) -> usize {
+    text.chars().map(|c| if is_wide(c) { 2 } else { 1 }).sum()
+}
+
+fn is_wide(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA000..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+            | 0x20000..=0x3FFFD
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary() -> ConsoleSummary {
+        ConsoleSummary {
+            total_packages: 1_204_311,
+            dependencies: Some(DependencyCounts {
+                direct: 4,
+                transitive: 1_204_306,
+            }),
+            licenses: LicenseCounts {
+                resolved: 1_204_300,
+                missing: 11,
+            },
+            vulnerabilities: Some(VulnerabilityCountsBySeverity {
+                critical: 1,
+                high: 0,
+                medium: 12,
+                low: 3,
+            }),
+            output: Some(PathBuf::from("sbom.json")),
+            elapsed: Duration::from_millis(2345),
+        }
+    }
+
+    fn presenter(locale: Locale, colors: bool) -> ConsoleSummaryPresenter {
+        ConsoleSummaryPresenter::new(locale).with_colors(colors)
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let rendered = presenter(Locale::En, false).render(&summary());
+
+        assert_eq!(
+            rendered,
+            "
+Summary
+-----------------------------------------------------
+Total packages   1204311
+Dependencies     4 direct, 1204306 transitive
+Licenses         1204300 resolved, 11 missing
+Vulnerabilities  1 critical, 0 high, 12 medium, 3 low
+Output           sbom.json
+Elapsed          2.3 s
+-----------------------------------------------------
+"
+        );
+    }
+
+    #[test]
+    fn test_render_colors_only_nonzero_severity_counts() {
+        let rendered = presenter(Locale::En, true).render(&summary());
+
+        let line = rendered
+            .lines()
+            .find(|line| line.starts_with("Vulnerabilities"))
+            .unwrap();
+        assert_eq!(
+            line,
+            format!(
+                "Vulnerabilities  {} critical, 0 high, {} medium, {} low",
+                "1".bright_red().bold(),
+                "12".yellow(),
+                "3".blue()
+            )
+        );
+        // Colour codes do not count towards the rule width
+        assert!(rendered.contains(&format!("\n{}\n", "-".repeat(53))));
+    }
+
+    #[test]
+    fn test_render_skipped_steps_and_stdout() {
+        let summary = ConsoleSummary {
+            dependencies: None,
+            vulnerabilities: None,
+            output: None,
+            ..summary()
+        };
+
+        let rendered = presenter(Locale::En, true).render(&summary);
+
+        assert!(rendered.contains("Dependencies     skipped\n"));
+        assert!(rendered.contains("Vulnerabilities  skipped\n"));
+        assert!(rendered.contains("Output           stdout\n"));
+    }
+
+    #[test]
+    fn test_render_pads_wide_labels_by_display_width() {
+        let rendered = presenter(Locale::Ja, false).render(&summary());
+
+        let value_columns: Vec<usize> = rendered
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('-'))
+            .skip(1)
+            .map(|line| {
+                let value_start = line.trim_end().rfind("  ").unwrap() + 2;
+                display_width(&line[..value_start])
+            })
+            .collect();
+        assert_eq!(value_columns.len(), 6);
+        assert!(value_columns.iter().all(|&c| c == value_columns[0]));
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width("Licenses"), 8);
+        assert_eq!(display_width("ライセンス"), 10);
+        assert_eq!(display_width("ＣＶＥ 1"), 8);
+    }
+}
@@ -0,0 +1,179 @@
+use super::pylock_reader::{PylockReader, PYLOCK_FILENAME};
+use super::requirements_txt_reader::RequirementsTxtReader;
+use super::{FileSystemReader, StdinLockfileReader};
+use crate::ports::outbound::{LockfileParseResult, LockfileReader};
+use crate::shared::error::UvSbomError;
+use crate::shared::Result;
+use std::path::{Path, PathBuf};
+
+/// AnyLockfileReader dispatches to the reader for the lockfile format in use
+///
+/// `uv.lock` is read by `FileSystemReader`, PEP 751 `pylock.toml` by
+/// `PylockReader` and a pinned `requirements.txt` by `RequirementsTxtReader`.
+/// The format is chosen at runtime, either from the files present in the
+/// project directory or from an explicit lockfile path. A `uv.lock` piped
+/// through stdin is read by `StdinLockfileReader`.
+pub enum AnyLockfileReader {
+    UvLock(FileSystemReader),
+    Pylock(PylockReader),
+    /// Pinned requirements, e.g. from `uv export --format requirements-txt`;
+    /// only used for an explicit path
+    RequirementsTxt(RequirementsTxtReader),
+    /// A uv.lock already read from stdin
+    Stdin(StdinLockfileReader),
+}
+
+impl AnyLockfileReader {
+    /// Picks the reader from the lockfiles in `project_path`.
+    ///
+    /// `uv.lock` wins when both it and `pylock.toml` exist; the returned flag
+    /// is true in that case so the caller can mention the ignored file. When
+    /// neither exists the `uv.lock` reader is returned, so the usual "uv.lock
+    /// not found" error is reported.
+    pub fn detect(project_path: &Path) -> (Self, bool) {
+        let has_uv_lock = project_path.join("uv.lock").exists();
+        let has_pylock = project_path.join(PYLOCK_FILENAME).exists();
+
+        if has_pylock && !has_uv_lock {
+            (Self::Pylock(PylockReader::new()), false)
+        } else {
+            (
+                Self::UvLock(FileSystemReader::new()),
+                has_uv_lock && has_pylock,
+            )
+        }
+    }
+
+    /// Picks the reader from the file name of an explicit lockfile path:
+    /// `uv.lock`, `pylock.toml` / `pylock.<name>.toml` as named by PEP 751,
+    /// or any `.txt` file as a pinned requirements file.
+    pub fn for_path(path: PathBuf) -> Result<Self> {
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+
+        if file_name == "uv.lock" {
+            Ok(Self::UvLock(
+                FileSystemReader::new().with_lockfile_path(path),
+            ))
+        } else if file_name.starts_with("pylock.") && file_name.ends_with(".toml") {
+            Ok(Self::Pylock(PylockReader::new().with_lockfile_path(path)))
+        } else if file_name.ends_with(".txt") {
+            Ok(Self::RequirementsTxt(RequirementsTxtReader::new(path)))
+        } else {
+            Err(UvSbomError::other(format!("Unrecognized lockfile name: {}\n\n💡 Hint: --lockfile accepts uv.lock, pylock.toml, pylock.<name>.toml or a requirements .txt file",
+                path.display())))
+        }
+    }
+
+    /// Rejects lockfiles larger than `bytes` instead of the default 50 MB
+    ///
+    /// Has no effect on stdin, which is checked against its limit while it is read.
+    pub fn with_max_lockfile_size(self, bytes: u64) -> Self {
+        match self {
+            Self::UvLock(reader) => Self::UvLock(reader.with_max_lockfile_size(bytes)),
+            Self::Pylock(reader) => Self::Pylock(reader.with_max_lockfile_size(bytes)),
+            Self::RequirementsTxt(reader) => {
+                Self::RequirementsTxt(reader.with_max_lockfile_size(bytes))
+            }
+            Self::Stdin(reader) => Self::Stdin(reader),
+        }
+    }
+
+    fn inner(&self) -> &dyn LockfileReader {
+        match self {
+            Self::UvLock(reader) => reader,
+            Self::Pylock(reader) => reader,
+            Self::RequirementsTxt(reader) => reader,
+            Self::Stdin(reader) => reader,
+        }
+    }
+}
+
+impl LockfileReader for AnyLockfileReader {
+    fn read_lockfile(&self, project_path: &Path) -> Result<String> {
+        self.inner().read_lockfile(project_path)
+    }
+
+    fn read_and_parse_lockfile(&self, project_path: &Path) -> Result<LockfileParseResult> {
+        self.inner().read_and_parse_lockfile(project_path)
+    }
+
+    fn has_dependency_graph(&self) -> bool {
+        self.inner().has_dependency_graph()
+    }
+
+    fn read_and_parse_lockfile_for_member(
+        &self,
+        project_path: &Path,
+        member_name: &str,
+    ) -> Result<LockfileParseResult> {
+        self.inner()
+            .read_and_parse_lockfile_for_member(project_path, member_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_prefers_uv_lock() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("uv.lock"), "").unwrap();
+        fs::write(temp_dir.path().join(PYLOCK_FILENAME), "").unwrap();
+
+        let (reader, both_present) = AnyLockfileReader::detect(temp_dir.path());
+
+        assert!(matches!(reader, AnyLockfileReader::UvLock(_)));
+        assert!(both_present);
+    }
+
+    #[test]
+    fn test_detect_uses_pylock_when_alone() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join(PYLOCK_FILENAME), "").unwrap();
+
+        let (reader, both_present) = AnyLockfileReader::detect(temp_dir.path());
+
+        assert!(matches!(reader, AnyLockfileReader::Pylock(_)));
+        assert!(!both_present);
+    }
+
+    #[test]
+    fn test_detect_defaults_to_uv_lock() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let (reader, both_present) = AnyLockfileReader::detect(temp_dir.path());
+
+        assert!(matches!(reader, AnyLockfileReader::UvLock(_)));
+        assert!(!both_present);
+    }
+
+    #[test]
+    fn test_for_path_picks_parser_by_file_name() {
+        let reader = |name: &str| AnyLockfileReader::for_path(PathBuf::from("locks").join(name));
+
+        assert!(matches!(
+            reader("uv.lock"),
+            Ok(AnyLockfileReader::UvLock(_))
+        ));
+        assert!(matches!(
+            reader("pylock.toml"),
+            Ok(AnyLockfileReader::Pylock(_))
+        ));
+        assert!(matches!(
+            reader("pylock.prod.toml"),
+            Ok(AnyLockfileReader::Pylock(_))
+        ));
+        assert!(matches!(
+            reader("requirements.txt"),
+            Ok(AnyLockfileReader::RequirementsTxt(_))
+        ));
+        let err = reader("Pipfile.lock").err().unwrap();
+        assert!(err.to_string().contains("Unrecognized lockfile name"));
+    }
+}
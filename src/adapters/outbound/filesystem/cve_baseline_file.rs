@@ -0,0 +1,149 @@
+use crate::sbom_generation::domain::VulnerabilityBaseline;
+use crate::shared::error::UvSbomError;
+use crate::shared::security::{read_file_with_security, MAX_FILE_SIZE};
+use crate::shared::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// Version of the baseline file layout; bumped on any breaking change
+const BASELINE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BaselineDocument {
+    version: u32,
+    /// Accepted vulnerability IDs per PEP 503 normalized package name
+    #[serde(default)]
+    packages: BTreeMap<String, BTreeSet<String>>,
+}
+
+/// CveBaselineFile adapter for the `--cve-baseline` JSON file
+///
+/// The file lists the accepted vulnerability IDs per package:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "packages": {
+///     "jinja2": ["GHSA-h5c8-rqwp-cp95"]
+///   }
+/// }
+/// ```
+///
+/// Packages and IDs are written sorted and one per line, so that changes to
+/// the baseline show up as small diffs in review.
+pub struct CveBaselineFile;
+
+impl CveBaselineFile {
+    /// Reads the baseline at `path`
+    ///
+    /// Returns `CveBaselineParseError` when the file is not a baseline of a
+    /// supported version.
+    pub fn read(path: &Path) -> Result<VulnerabilityBaseline> {
+        let content = read_file_with_security(path, "CVE baseline", MAX_FILE_SIZE)?;
+        Self::parse(&content, path)
+    }
+
+    fn parse(content: &str, path: &Path) -> Result<VulnerabilityBaseline> {
+        let parse_error = |details: String| UvSbomError::CveBaselineParseError {
+            path: path.to_path_buf(),
+            details,
+        };
+
+        let document: BaselineDocument =
+            serde_json::from_str(content).map_err(|e| parse_error(e.to_string()))?;
+        if document.version != BASELINE_VERSION {
+            return Err(parse_error(format!(
+                "unsupported version {} (expected {})",
+                document.version, BASELINE_VERSION
+            )));
+        }
+
+        let mut baseline = VulnerabilityBaseline::new();
+        for (package, ids) in &document.packages {
+            for id in ids {
+                baseline.insert(package, id);
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Renders `baseline` as the contents of a baseline file
+    pub fn render(baseline: &VulnerabilityBaseline) -> Result<String> {
+        let document = BaselineDocument {
+            version: BASELINE_VERSION,
+            packages: baseline.packages().clone(),
+        };
+        let mut content = serde_json::to_string_pretty(&document)?;
+        content.push('\n');
+        Ok(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_render_is_sorted_and_versioned() {
+        let mut baseline = VulnerabilityBaseline::new();
+        baseline.insert("urllib3", "GHSA-b");
+        baseline.insert("Jinja2", "GHSA-z");
+        baseline.insert("urllib3", "GHSA-a");
+
+        let content = CveBaselineFile::render(&baseline).unwrap();
+
+        assert_eq!(
+            content,
+            r#"{
+  "version": 1,
+  "packages": {
+    "jinja2": [
+      "GHSA-z"
+    ],
+    "urllib3": [
+      "GHSA-a",
+      "GHSA-b"
+    ]
+  }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_read_round_trips_rendered_baseline() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("cve-baseline.json");
+        let mut baseline = VulnerabilityBaseline::new();
+        baseline.insert("jinja2", "GHSA-h5c8-rqwp-cp95");
+        baseline.insert("requests", "PYSEC-2023-74");
+        fs::write(&path, CveBaselineFile::render(&baseline).unwrap()).unwrap();
+
+        assert_eq!(CveBaselineFile::read(&path).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_parse_rejects_unsupported_version() {
+        let path = Path::new("cve-baseline.json");
+
+        let err = CveBaselineFile::parse(r#"{"version": 2, "packages": {}}"#, path).unwrap_err();
+
+        assert!(matches!(
+            &err,
+            UvSbomError::CveBaselineParseError { details, .. } if details.contains("unsupported version 2")
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_version() {
+        let path = Path::new("cve-baseline.json");
+
+        let err = CveBaselineFile::parse(r#"{"packages": {}}"#, path).unwrap_err();
+
+        assert!(matches!(err, UvSbomError::CveBaselineParseError { .. }));
+    }
+}
@@ -0,0 +1,270 @@
+use crate::ports::outbound::SbomReader;
+use crate::sbom_generation::domain::PackageEntry;
+use crate::shared::error::UvSbomError;
+use crate::shared::security::{read_file_with_security, MAX_FILE_SIZE};
+use crate::shared::Result;
+use serde::Deserialize;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct Bom {
+    #[serde(rename = "bomFormat")]
+    bom_format: String,
+    metadata: Option<Metadata>,
+    #[serde(default)]
+    components: Vec<Component>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Metadata {
+    component: Option<Component>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Component {
+    name: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    licenses: Vec<LicenseChoice>,
+    purl: Option<String>,
+}
+
+/// A `licenses[]` entry: either a license object or an SPDX expression
+#[derive(Debug, Deserialize)]
+struct LicenseChoice {
+    license: Option<LicenseContent>,
+    expression: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LicenseContent {
+    id: Option<String>,
+    name: Option<String>,
+}
+
+impl LicenseChoice {
+    fn text(self) -> Option<String> {
+        self.expression
+            .or_else(|| self.license.and_then(|l| l.id.or(l.name)))
+    }
+}
+
+/// CycloneDxReader adapter for reading components from CycloneDX JSON files
+///
+/// Only the fields needed to compare and scan SBOMs are read: component name,
+/// version, purl and license. Multiple license entries on one component are
+/// joined with ", ". `read_packages` reads the project described by
+/// `metadata.component` as a package too, as it is part of the lockfile the
+/// SBOM was generated from.
+pub struct CycloneDxReader;
+
+impl CycloneDxReader {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn parse(&self, content: &str, path: &Path) -> Result<Vec<PackageEntry>> {
+        let bom = Self::parse_bom(content, path)?;
+
+        // Older uv-sbom versions also listed the project under components
+        let project = bom.metadata.and_then(|m| m.component).filter(|project| {
+            !bom.components
+                .iter()
+                .any(|c| c.name == project.name && c.version == project.version)
+        });
+
+        Ok(project
+            .into_iter()
+            .chain(bom.components)
+            .map(Self::entry)
+            .collect())
+    }
+
+    fn parse_components(&self, content: &str, path: &Path) -> Result<Vec<PackageEntry>> {
+        let bom = Self::parse_bom(content, path)?;
+        Ok(bom.components.into_iter().map(Self::entry).collect())
+    }
+
+    fn parse_bom(content: &str, path: &Path) -> Result<Bom> {
+        let parse_error = |details: String| UvSbomError::SbomParseError {
+            path: path.to_path_buf(),
+            details,
+        };
+
+        let bom: Bom = serde_json::from_str(content).map_err(|e| parse_error(e.to_string()))?;
+        if bom.bom_format != "CycloneDX" {
+            return Err(parse_error(format!(
+                "unsupported bomFormat \"{}\" (expected CycloneDX)",
+                bom.bom_format
+            )));
+        }
+        Ok(bom)
+    }
+
+    fn entry(component: Component) -> PackageEntry {
+        let licenses: Vec<String> = component
+            .licenses
+            .into_iter()
+            .filter_map(LicenseChoice::text)
+            .collect();
+        PackageEntry::new(component.name, component.version)
+            .with_license((!licenses.is_empty()).then(|| licenses.join(", ")))
+            .with_purl(component.purl)
+    }
+
+    fn read(path: &Path) -> Result<String> {
+        read_file_with_security(path, "SBOM file", MAX_FILE_SIZE).map_err(|e| {
+            UvSbomError::FileReadError {
+                path: path.to_path_buf(),
+                details: e.to_string(),
+            }
+        })
+    }
+}
+
+impl Default for CycloneDxReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SbomReader for CycloneDxReader {
+    fn read_packages(&self, path: &Path) -> Result<Vec<PackageEntry>> {
+        self.parse(&Self::read(path)?, path)
+    }
+
+    fn read_components(&self, path: &Path) -> Result<Vec<PackageEntry>> {
+        self.parse_components(&Self::read(path)?, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::formatters::CycloneDxFormatter;
+    use crate::ports::outbound::SbomFormatter;
+    use crate::test_fixtures::SbomReadModelFixture;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_packages_from_generated_sbom() {
+        let model = SbomReadModelFixture::small_clean().build();
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("sbom.json");
+        fs::write(&path, json).unwrap();
+
+        let packages = CycloneDxReader::new().read_packages(&path).unwrap();
+
+        let expected: Vec<PackageEntry> = model
+            .components
+            .iter()
+            .map(|c| {
+                PackageEntry::new(c.name.clone(), c.version.clone())
+                    .with_license(
+                        c.license
+                            .as_ref()
+                            .map(|l| l.spdx_id.clone().unwrap_or_else(|| l.name.clone())),
+                    )
+                    .with_purl(Some(c.purl.clone()))
+            })
+            .collect();
+        assert_eq!(packages, expected);
+    }
+
+    #[test]
+    fn test_read_packages_license_expression_and_missing_license() {
+        let json = r#"{
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.6",
+            "components": [
+                {"name": "pyyaml", "version": "6.0.1", "licenses": [{"expression": "MIT OR Apache-2.0"}]},
+                {"name": "left-pad", "version": "1.0.0"}
+            ]
+        }"#;
+
+        let packages = CycloneDxReader::new()
+            .parse(json, Path::new("sbom.json"))
+            .unwrap();
+
+        assert_eq!(
+            packages,
+            vec![
+                PackageEntry::new("pyyaml", "6.0.1")
+                    .with_license(Some("MIT OR Apache-2.0".to_string())),
+                PackageEntry::new("left-pad", "1.0.0"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_packages_includes_metadata_component_once() {
+        let json = r#"{
+            "bomFormat": "CycloneDX",
+            "metadata": {"component": {"name": "my-app", "version": "1.0.0", "licenses": [{"license": {"id": "MIT"}}]}},
+            "components": [{"name": "requests", "version": "2.31.0"}]
+        }"#;
+        let legacy = r#"{
+            "bomFormat": "CycloneDX",
+            "metadata": {"component": {"name": "my-app", "version": "1.0.0"}},
+            "components": [{"name": "my-app", "version": "1.0.0"}, {"name": "requests", "version": "2.31.0"}]
+        }"#;
+        let reader = CycloneDxReader::new();
+
+        assert_eq!(
+            reader.parse(json, Path::new("sbom.json")).unwrap(),
+            vec![
+                PackageEntry::new("my-app", "1.0.0").with_license(Some("MIT".to_string())),
+                PackageEntry::new("requests", "2.31.0"),
+            ]
+        );
+        assert_eq!(
+            reader.parse(legacy, Path::new("sbom.json")).unwrap(),
+            vec![
+                PackageEntry::new("my-app", "1.0.0"),
+                PackageEntry::new("requests", "2.31.0"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_components_skips_metadata_component() {
+        let json = r#"{
+            "bomFormat": "CycloneDX",
+            "metadata": {"component": {"name": "my-app", "version": "1.0.0", "purl": "pkg:pypi/my-app@1.0.0"}},
+            "components": [
+                {"name": "requests", "version": "2.31.0", "purl": "pkg:pypi/requests@2.31.0"},
+                {"name": "left-pad", "version": "1.3.0", "purl": "pkg:npm/left-pad@1.3.0"}
+            ]
+        }"#;
+
+        let components = CycloneDxReader::new()
+            .parse_components(json, Path::new("sbom.json"))
+            .unwrap();
+
+        assert_eq!(
+            components,
+            vec![
+                PackageEntry::new("requests", "2.31.0")
+                    .with_purl(Some("pkg:pypi/requests@2.31.0".to_string())),
+                PackageEntry::new("left-pad", "1.3.0")
+                    .with_purl(Some("pkg:npm/left-pad@1.3.0".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_packages_rejects_non_cyclonedx() {
+        let reader = CycloneDxReader::new();
+        for content in ["not json", r#"{"bomFormat": "SPDX", "components": []}"#] {
+            let err = reader.parse(content, Path::new("sbom.json")).unwrap_err();
+            assert!(matches!(err, UvSbomError::SbomParseError { .. }));
+            let err = reader
+                .parse_components(content, Path::new("sbom.json"))
+                .unwrap_err();
+            assert!(matches!(err, UvSbomError::SbomParseError { .. }));
+        }
+    }
+}
@@ -1,20 +1,337 @@
-use crate::ports::outbound::{LockfileParseResult, LockfileReader, ProjectConfigReader};
-use crate::sbom_generation::domain::Package;
-use crate::shared::error::SbomError;
-use crate::shared::security::{read_file_with_security, MAX_FILE_SIZE};
+use crate::adapters::outbound::uv::UvWorkspaceReader;
+use crate::ports::outbound::{
+    DependencyGroups, EdgeExtras, EdgeMarkers, LockfileParseResult, LockfileReader,
+    LockfileVersion, ProjectConfigReader, WorkspaceReader,
+};
+use crate::sbom_generation::domain::{Package, PackageAnnotation, PackageName, PackageSource};
+use crate::shared::error::UvSbomError;
+use crate::shared::security::{
+    check_lockfile_size, read_file_with_security, DEFAULT_MAX_LOCKFILE_SIZE, MAX_FILE_SIZE,
+};
 use crate::shared::Result;
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::path::Path;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+/// Newest uv.lock `version` this reader understands
+const SUPPORTED_LOCK_VERSION: u32 = 1;
+
+/// Top-level fields of uv.lock describing the file itself
+///
+/// Read separately from the packages so they are known even when a newer
+/// format changed the package tables in a way the parser rejects.
+#[derive(Debug, Default, Deserialize)]
+struct UvLockHeader {
+    version: Option<u32>,
+    #[serde(rename = "requires-python")]
+    requires_python: Option<String>,
+}
+
+impl UvLockHeader {
+    fn read(content: &str) -> Self {
+        toml::from_str(content).unwrap_or_default()
+    }
+
+    fn version(&self) -> Option<LockfileVersion> {
+        self.version
+            .map(|v| LockfileVersion::new(v.to_string(), SUPPORTED_LOCK_VERSION))
+    }
+
+    /// Describes a parse failure, naming the lockfile version when it is
+    /// newer than supported since that is the likely cause
+    fn parse_error_details(&self, error: toml::de::Error) -> String {
+        match self
+            .version()
+            .filter(LockfileVersion::is_newer_than_supported)
+        {
+            Some(version) => format!(
+                "{} (lockfile version {} is newer than supported {})",
+                error, version.version, SUPPORTED_LOCK_VERSION
+            ),
+            None => error.to_string(),
+        }
+    }
+}
+
+/// A distribution file (`sdist` or `wheels[]` entry) of a `[[package]]` in uv.lock
+#[derive(Debug, Deserialize)]
+struct UvArtifact {
+    hash: Option<String>,
+    url: Option<String>,
+    path: Option<String>,
+    filename: Option<String>,
+}
+
+impl UvArtifact {
+    /// The `sys_platform` a wheel is built for, from the platform tag of its file
+    /// name. `None` for pure-Python wheels (`any`) and unrecognized platforms.
+    fn wheel_sys_platform(&self) -> Option<&'static str> {
+        let location = self
+            .filename
+            .as_ref()
+            .or(self.url.as_ref())
+            .or(self.path.as_ref())?;
+        let file_name = location.rsplit('/').next()?.strip_suffix(".whl")?;
+        let platform_tag = file_name.rsplit('-').next()?;
+        let platforms: HashSet<&str> = platform_tag
+            .split('.')
+            .map(|tag| match tag {
+                t if t.starts_with("win") => Some("win32"),
+                t if t.starts_with("macosx") => Some("darwin"),
+                t if t.contains("linux") => Some("linux"),
+                _ => None,
+            })
+            .collect::<Option<_>>()?;
+        // A compressed tag set spanning several platforms constrains nothing
+        if platforms.len() == 1 {
+            platforms.into_iter().next()
+        } else {
+            None
+        }
+    }
+}
+
+/// Picks the SHA-256 of a package's primary artifact: the sdist when present,
+/// otherwise the first wheel. Returns `None` for packages without hashes
+/// (virtual, editable and other local sources).
+fn primary_sha256(sdist: Option<&UvArtifact>, wheels: &[UvArtifact]) -> Option<String> {
+    sdist
+        .into_iter()
+        .chain(wheels.first())
+        .find_map(|artifact| artifact.hash.as_deref()?.strip_prefix("sha256:"))
+        .map(str::to_string)
+}
+
+/// Builds the environment marker a package is limited to, if any
+///
+/// `resolution-markers` lists the forks of the resolution that use this
+/// package version. A package with only platform-specific wheels and no sdist
+/// can only be installed on those platforms.
+fn package_marker(
+    resolution_markers: &[String],
+    sdist: Option<&UvArtifact>,
+    wheels: &[UvArtifact],
+) -> Option<String> {
+    let wheel_platforms: Option<BTreeSet<&str>> = if sdist.is_none() && !wheels.is_empty() {
+        wheels.iter().map(UvArtifact::wheel_sys_platform).collect()
+    } else {
+        None
+    };
+    let wheel_markers: Vec<String> = wheel_platforms
+        .into_iter()
+        .flatten()
+        .map(|platform| format!("sys_platform == '{}'", platform))
+        .collect();
+
+    let mut clauses: Vec<String> = [resolution_markers, &wheel_markers]
+        .into_iter()
+        .filter(|markers| !markers.is_empty())
+        .map(any_of)
+        .collect();
+    // Resolution markers often repeat what the wheels already say
+    clauses.dedup();
+    match clauses.len() {
+        0 => None,
+        1 => clauses.into_iter().next(),
+        _ => Some(
+            clauses
+                .iter()
+                .map(|clause| format!("({})", clause))
+                .collect::<Vec<_>>()
+                .join(" and "),
+        ),
+    }
+}
+
+/// Joins markers with `or`, parenthesizing each when there is more than one
+fn any_of(markers: &[String]) -> String {
+    if let [marker] = markers {
+        return marker.clone();
+    }
+    markers
+        .iter()
+        .map(|marker| format!("({})", marker))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+/// The `source` of a `[[package]]` in uv.lock, e.g. `{ registry = "https://pypi.org/simple" }`
+#[derive(Debug, Deserialize)]
+struct UvSource {
+    registry: Option<String>,
+    git: Option<String>,
+    path: Option<String>,
+    directory: Option<String>,
+    editable: Option<String>,
+    #[serde(rename = "virtual")]
+    virtual_path: Option<String>,
+}
+
+impl UvSource {
+    /// Returns true for workspace members and the project itself
+    /// (`editable` for uv < 0.5, `virtual` for uv >= 0.5)
+    fn is_local_project(&self) -> bool {
+        self.editable.is_some() || self.virtual_path.is_some()
+    }
+
+    /// Returns true for the project the lockfile was created for, which uv
+    /// locks at `.` (`editable` or `virtual` like other local projects)
+    fn is_project_root(&self) -> bool {
+        self.editable
+            .as_ref()
+            .or(self.virtual_path.as_ref())
+            .is_some_and(|path| path == ".")
+    }
+
+    /// Converts to the domain source; `None` for direct URL sources
+    fn to_package_source(&self) -> Option<PackageSource> {
+        if let Some(url) = &self.registry {
+            return Some(PackageSource::Registry { url: url.clone() });
+        }
+        if let Some(git) = &self.git {
+            // `<repository>?<rev|tag|branch|subdirectory>=...#<commit>`
+            let (location, revision) = match git.split_once('#') {
+                Some((location, commit)) => (location, Some(commit.to_string())),
+                None => (git.as_str(), None),
+            };
+            let url = location.split('?').next().unwrap_or(location).to_string();
+            return Some(PackageSource::Git {
+                url,
+                revision: revision.filter(|commit| !commit.is_empty()),
+            });
+        }
+        if let Some(path) = &self.virtual_path {
+            return Some(PackageSource::Virtual { path: path.clone() });
+        }
+        self.editable
+            .as_ref()
+            .or(self.directory.as_ref())
+            .or(self.path.as_ref())
+            .map(|path| PackageSource::Path { path: path.clone() })
+    }
+}
+
+/// A dependency edge of a `[[package]]` in uv.lock, e.g. `{ name = "requests", extra = ["socks"] }`
+#[derive(Debug, Deserialize)]
+struct UvDependency {
+    name: String,
+    #[serde(default)]
+    extra: Vec<String>,
+    marker: Option<String>,
+}
+
+/// Records the extras requested on `parent`'s edges to `deps`.
+///
+/// A dependency listed more than once (e.g. under different markers or in both
+/// runtime and group tables) keeps the union of its extras.
+fn record_edge_extras(edge_extras: &mut EdgeExtras, parent: &str, deps: &[UvDependency]) {
+    for dep in deps.iter().filter(|dep| !dep.extra.is_empty()) {
+        let extras = edge_extras
+            .entry(parent.to_string())
+            .or_default()
+            .entry(dep.name.clone())
+            .or_default();
+        for extra in &dep.extra {
+            if !extras.contains(extra) {
+                extras.push(extra.clone());
+            }
+        }
+    }
+}
+
+/// Records the markers of `parent`'s conditional edges to `deps`.
+///
+/// A dependency listed more than once keeps the disjunction of its markers, and
+/// is not recorded at all when one of its edges is unconditional.
+fn record_edge_markers<'a>(
+    edge_markers: &mut EdgeMarkers,
+    parent: &str,
+    deps: impl IntoIterator<Item = &'a UvDependency>,
+) {
+    // dependency name -> markers of its edges, `None` once an edge is unconditional
+    let mut markers: BTreeMap<&str, Option<Vec<String>>> = BTreeMap::new();
+    for dep in deps {
+        let entry = markers
+            .entry(dep.name.as_str())
+            .or_insert_with(|| Some(Vec::new()));
+        match &dep.marker {
+            Some(marker) => {
+                if let Some(list) = entry.as_mut().filter(|list| !list.contains(marker)) {
+                    list.push(marker.clone());
+                }
+            }
+            None => *entry = None,
+        }
+    }
+    for (dep, markers) in markers {
+        if let Some(markers) = markers {
+            edge_markers
+                .entry(parent.to_string())
+                .or_default()
+                .insert(dep.to_string(), any_of(&markers));
+        }
+    }
+}
+
+/// Appends the requirement strings of a pyproject.toml dependency list.
+///
+/// Non-string entries, such as `{include-group = "..."}` in `[dependency-groups]`,
+/// are skipped.
+fn push_requirements(requirements: &mut Vec<String>, list: Option<&toml::Value>) {
+    let entries = list.and_then(|l| l.as_array()).into_iter().flatten();
+    requirements.extend(entries.filter_map(|r| r.as_str()).map(str::to_string));
+}
 
 /// FileSystemReader adapter for reading files from the file system
 ///
 /// This adapter implements both LockfileReader and ProjectConfigReader ports,
 /// providing file system access for reading lockfiles and project configuration.
-pub struct FileSystemReader;
+pub struct FileSystemReader {
+    /// Explicit lockfile location; `None` means `uv.lock` in the project directory
+    lockfile_path: Option<PathBuf>,
+    /// Explicit pyproject.toml location; `None` means `pyproject.toml` in the project directory
+    pyproject_path: Option<PathBuf>,
+    /// Largest lockfile, in bytes, that is read
+    max_lockfile_size: u64,
+}
 
 impl FileSystemReader {
     pub fn new() -> Self {
-        Self
+        Self {
+            lockfile_path: None,
+            pyproject_path: None,
+            max_lockfile_size: DEFAULT_MAX_LOCKFILE_SIZE,
+        }
+    }
+
+    /// Reads the lockfile from `path` instead of `uv.lock` in the project directory
+    pub fn with_lockfile_path(mut self, path: PathBuf) -> Self {
+        self.lockfile_path = Some(path);
+        self
+    }
+
+    /// Reads project configuration from `path` instead of `pyproject.toml` in the project directory
+    pub fn with_pyproject_path(mut self, path: PathBuf) -> Self {
+        self.pyproject_path = Some(path);
+        self
+    }
+
+    /// Rejects lockfiles larger than `bytes` instead of the default 50 MB
+    pub fn with_max_lockfile_size(mut self, bytes: u64) -> Self {
+        self.max_lockfile_size = bytes;
+        self
+    }
+
+    fn lockfile_path(&self, project_path: &Path) -> PathBuf {
+        self.lockfile_path
+            .clone()
+            .unwrap_or_else(|| project_path.join("uv.lock"))
+    }
+
+    fn pyproject_path(&self, project_path: &Path) -> PathBuf {
+        self.pyproject_path
+            .clone()
+            .unwrap_or_else(|| project_path.join("pyproject.toml"))
     }
 }
 
@@ -49,28 +366,37 @@ impl LockfileReader for FileSystemReader {
     }
 
     fn read_lockfile(&self, project_path: &Path) -> Result<String> {
-        let lockfile_path = project_path.join("uv.lock");
+        let lockfile_path = self.lockfile_path(project_path);
 
         // Check if uv.lock file exists
         if !lockfile_path.exists() {
-            return Err(SbomError::LockfileNotFound {
-                path: lockfile_path.clone(),
-                suggestion: format!(
+            let suggestion = if self.lockfile_path.is_some() {
+                format!(
+                    "{} does not exist.\n   \
+                     Please check the path given with the --lockfile option; relative paths are resolved against the current directory.",
+                    lockfile_path.display()
+                )
+            } else {
+                format!(
                     "uv.lock file does not exist in project directory \"{}\".\n   \
                      Please run in the root directory of a uv project, or specify the correct path with the --path option.",
                     project_path.display()
-                ),
-            }
-            .into());
+                )
+            };
+            return Err(UvSbomError::LockfileNotFound {
+                path: lockfile_path.clone(),
+                suggestion,
+            });
         }
 
+        check_lockfile_size(&lockfile_path, self.max_lockfile_size)?;
+
         // Read lockfile content with security checks
-        self.safe_read_file(&lockfile_path, "uv.lock").map_err(|e| {
-            SbomError::LockfileParseError {
+        read_file_with_security(&lockfile_path, "uv.lock", self.max_lockfile_size).map_err(|e| {
+            UvSbomError::LockfileParseError {
                 path: lockfile_path,
                 details: e.to_string(),
             }
-            .into()
         })
     }
 
@@ -84,19 +410,25 @@ impl LockfileReader for FileSystemReader {
 }
 
 impl FileSystemReader {
-    /// Parses lockfile content to extract packages and dependency map
+    /// Parses lockfile content to extract packages, runtime dependency map and
+    /// dependency group edges
     ///
     /// This method handles the TOML parsing logic which is an infrastructure concern.
     /// It was moved from the application layer to properly separate concerns.
-    fn parse_lockfile_content(
+    ///
+    /// `dependencies` are runtime edges. `dev-dependencies` tables (the `dev` group
+    /// and any other `[dependency-groups]`) become group edges. `optional-dependencies`
+    /// of the project and workspace members are opt-in extras and become group edges
+    /// named after the extra; for every other package they are runtime edges, since
+    /// uv only locks the extras that something in the resolution requests.
+    pub(super) fn parse_lockfile_content(
         &self,
         content: &str,
         project_path: &Path,
     ) -> Result<LockfileParseResult> {
-        use serde::Deserialize;
-
         #[derive(Debug, Deserialize)]
         struct UvLock {
+            #[serde(default)]
             package: Vec<UvPackage>,
         }
 
@@ -104,49 +436,111 @@ impl FileSystemReader {
         struct UvPackage {
             name: String,
             version: String,
+            source: Option<UvSource>,
+            #[serde(default, rename = "resolution-markers")]
+            resolution_markers: Vec<String>,
+            #[serde(rename = "requires-python")]
+            requires_python: Option<String>,
             #[serde(default)]
             dependencies: Vec<UvDependency>,
+            #[serde(default, rename = "optional-dependencies")]
+            optional_dependencies: BTreeMap<String, Vec<UvDependency>>,
             #[serde(default, rename = "dev-dependencies")]
-            dev_dependencies: Option<DevDependencies>,
-        }
-
-        #[derive(Debug, Deserialize)]
-        struct UvDependency {
-            name: String,
-        }
-
-        #[derive(Debug, Deserialize)]
-        struct DevDependencies {
+            dev_dependencies: BTreeMap<String, Vec<UvDependency>>,
+            sdist: Option<UvArtifact>,
             #[serde(default)]
-            dev: Vec<UvDependency>,
+            wheels: Vec<UvArtifact>,
         }
 
+        let names = |deps: &[UvDependency]| -> Vec<String> {
+            deps.iter().map(|dep| dep.name.clone()).collect()
+        };
+
+        let header = UvLockHeader::read(content);
         let lockfile: UvLock =
-            toml::from_str(content).map_err(|e| SbomError::LockfileParseError {
-                path: project_path.join("uv.lock"),
-                details: e.to_string(),
+            toml::from_str(content).map_err(|e| UvSbomError::LockfileParseError {
+                path: self.lockfile_path(project_path),
+                details: header.parse_error_details(e),
             })?;
 
         let mut packages = Vec::new();
         let mut dependency_map = HashMap::new();
+        let mut dependency_groups: DependencyGroups = HashMap::new();
+        let mut edge_extras: EdgeExtras = HashMap::new();
+        let mut edge_markers: EdgeMarkers = HashMap::new();
 
         for pkg in lockfile.package {
-            packages.push(Package::new(pkg.name.clone(), pkg.version.clone())?);
-
-            // Build dependency map
-            let mut deps = Vec::new();
-            for dep in &pkg.dependencies {
-                deps.push(dep.name.clone());
+            packages.push(
+                Package::new(pkg.name.clone(), pkg.version.clone())?
+                    .with_sha256_hash(primary_sha256(pkg.sdist.as_ref(), &pkg.wheels))
+                    .with_source(pkg.source.as_ref().and_then(UvSource::to_package_source))
+                    .with_marker(package_marker(
+                        &pkg.resolution_markers,
+                        pkg.sdist.as_ref(),
+                        &pkg.wheels,
+                    ))
+                    .with_requires_python(pkg.requires_python.clone())
+                    .with_project_root(pkg.source.as_ref().is_some_and(UvSource::is_project_root)),
+            );
+
+            let is_local = pkg.source.as_ref().is_some_and(UvSource::is_local_project);
+
+            record_edge_extras(&mut edge_extras, &pkg.name, &pkg.dependencies);
+            for extra_deps in pkg
+                .optional_dependencies
+                .values()
+                .chain(pkg.dev_dependencies.values())
+            {
+                record_edge_extras(&mut edge_extras, &pkg.name, extra_deps);
             }
-            if let Some(dev_deps) = &pkg.dev_dependencies {
-                for dep in &dev_deps.dev {
-                    deps.push(dep.name.clone());
+            record_edge_markers(
+                &mut edge_markers,
+                &pkg.name,
+                pkg.dependencies.iter().chain(
+                    pkg.optional_dependencies
+                        .values()
+                        .chain(pkg.dev_dependencies.values())
+                        .flatten(),
+                ),
+            );
+
+            let mut deps = names(&pkg.dependencies);
+            for (extra, extra_deps) in &pkg.optional_dependencies {
+                if is_local {
+                    dependency_groups
+                        .entry(extra.clone())
+                        .or_default()
+                        .insert(pkg.name.clone(), names(extra_deps));
+                } else {
+                    deps.extend(names(extra_deps));
+                }
+            }
+            for (group, group_deps) in &pkg.dev_dependencies {
+                dependency_groups
+                    .entry(group.clone())
+                    .or_default()
+                    .insert(pkg.name.clone(), names(group_deps));
+            }
+            // A package locked at several versions (a resolution fork) keeps the
+            // edges of all of them, since the map is keyed by name
+            let edges: &mut Vec<String> = dependency_map.entry(pkg.name).or_default();
+            for dep in deps {
+                if !edges.contains(&dep) {
+                    edges.push(dep);
                 }
             }
-            dependency_map.insert(pkg.name, deps);
         }
 
-        Ok((packages, dependency_map))
+        Ok(LockfileParseResult {
+            packages,
+            dependency_map,
+            dependency_groups,
+            edge_extras,
+            edge_markers,
+            version: header.version(),
+            requires_python: header.requires_python,
+            ..Default::default()
+        })
     }
 
     /// Parse lockfile content and return only packages reachable from the given member.
@@ -156,27 +550,12 @@ impl FileSystemReader {
     /// uses `virtual` for packages without a build system), then performs BFS over the
     /// dependency graph to collect all transitively reachable packages. The member root
     /// itself is excluded.
-    fn parse_lockfile_content_for_member(
+    pub(super) fn parse_lockfile_content_for_member(
         &self,
         content: &str,
         project_path: &Path,
         member_name: &str,
     ) -> Result<LockfileParseResult> {
-        use serde::Deserialize;
-
-        #[derive(Debug, Deserialize)]
-        struct PackageSource {
-            editable: Option<String>,
-            #[serde(rename = "virtual")]
-            virtual_path: Option<String>,
-        }
-
-        impl PackageSource {
-            fn is_local(&self) -> bool {
-                self.editable.is_some() || self.virtual_path.is_some()
-            }
-        }
-
         #[derive(Debug, Deserialize)]
         struct UvPackage {
             name: String,
@@ -185,12 +564,14 @@ impl FileSystemReader {
             dependencies: Vec<UvDependency>,
             #[serde(default, rename = "dev-dependencies")]
             dev_dependencies: Option<DevDependencies>,
-            source: Option<PackageSource>,
-        }
-
-        #[derive(Debug, Deserialize)]
-        struct UvDependency {
-            name: String,
+            source: Option<UvSource>,
+            #[serde(default, rename = "resolution-markers")]
+            resolution_markers: Vec<String>,
+            #[serde(rename = "requires-python")]
+            requires_python: Option<String>,
+            sdist: Option<UvArtifact>,
+            #[serde(default)]
+            wheels: Vec<UvArtifact>,
         }
 
         #[derive(Debug, Deserialize)]
@@ -201,21 +582,37 @@ impl FileSystemReader {
 
         #[derive(Debug, Deserialize)]
         struct UvLock {
+            #[serde(default)]
             package: Vec<UvPackage>,
         }
 
+        let header = UvLockHeader::read(content);
         let lockfile: UvLock =
-            toml::from_str(content).map_err(|e| SbomError::LockfileParseError {
-                path: project_path.join("uv.lock"),
-                details: e.to_string(),
+            toml::from_str(content).map_err(|e| UvSbomError::LockfileParseError {
+                path: self.lockfile_path(project_path),
+                details: header.parse_error_details(e),
             })?;
 
         // Build dependency map (name -> list of dependency names) and package lookup
         let mut full_dep_map: HashMap<String, Vec<String>> = HashMap::new();
-        let mut pkg_lookup: HashMap<String, (String, String)> = HashMap::new(); // name -> (name, version)
+        let mut pkg_lookup: HashMap<String, Package> = HashMap::new();
         let mut member_direct_deps: Option<Vec<String>> = None;
+        let mut full_edge_extras: EdgeExtras = HashMap::new();
+        let mut full_edge_markers: EdgeMarkers = HashMap::new();
 
         for pkg in &lockfile.package {
+            record_edge_extras(&mut full_edge_extras, &pkg.name, &pkg.dependencies);
+            if let Some(dev_deps) = &pkg.dev_dependencies {
+                record_edge_extras(&mut full_edge_extras, &pkg.name, &dev_deps.dev);
+            }
+            record_edge_markers(
+                &mut full_edge_markers,
+                &pkg.name,
+                pkg.dependencies
+                    .iter()
+                    .chain(pkg.dev_dependencies.iter().flat_map(|dev| &dev.dev)),
+            );
+
             let mut deps: Vec<String> = pkg.dependencies.iter().map(|d| d.name.clone()).collect();
             if let Some(dev_deps) = &pkg.dev_dependencies {
                 for dep in &dev_deps.dev {
@@ -226,21 +623,30 @@ impl FileSystemReader {
             // Detect member root: name matches AND source is a local path
             // (editable for uv < 0.5, virtual for uv >= 0.5)
             let is_member_root = pkg.name == member_name
-                && pkg.source.as_ref().map(|s| s.is_local()).unwrap_or(false);
+                && pkg.source.as_ref().is_some_and(UvSource::is_local_project);
 
             if is_member_root {
                 member_direct_deps = Some(deps.clone());
             }
 
             full_dep_map.insert(pkg.name.clone(), deps);
-            pkg_lookup.insert(pkg.name.clone(), (pkg.name.clone(), pkg.version.clone()));
+            pkg_lookup.insert(
+                pkg.name.clone(),
+                Package::new(pkg.name.clone(), pkg.version.clone())?
+                    .with_sha256_hash(primary_sha256(pkg.sdist.as_ref(), &pkg.wheels))
+                    .with_source(pkg.source.as_ref().and_then(UvSource::to_package_source))
+                    .with_marker(package_marker(
+                        &pkg.resolution_markers,
+                        pkg.sdist.as_ref(),
+                        &pkg.wheels,
+                    ))
+                    .with_requires_python(pkg.requires_python.clone()),
+            );
         }
 
         let direct_deps = member_direct_deps.ok_or_else(|| {
-            anyhow::anyhow!(
-                "Workspace member '{}' not found in uv.lock (no package with source.editable or source.virtual set)",
-                member_name
-            )
+            UvSbomError::other(format!("Workspace member '{}' not found in uv.lock (no package with source.editable or source.virtual set)",
+                member_name))
         })?;
 
         // BFS traversal from direct dependencies of the member root
@@ -270,40 +676,278 @@ impl FileSystemReader {
         let mut dependency_map = HashMap::new();
 
         for name in &visited {
-            if let Some((pkg_name, pkg_version)) = pkg_lookup.get(name) {
-                packages.push(Package::new(pkg_name.clone(), pkg_version.clone())?);
+            if let Some(package) = pkg_lookup.remove(name) {
                 if let Some(deps) = full_dep_map.get(name) {
-                    dependency_map.insert(pkg_name.clone(), deps.clone());
+                    dependency_map.insert(package.name().to_string(), deps.clone());
                 }
+                packages.push(package);
             }
         }
 
-        Ok((packages, dependency_map))
+        // Keep the extras of the member root's edges and of the reachable packages' edges
+        full_edge_extras.retain(|parent, _| parent == member_name || visited.contains(parent));
+        full_edge_markers.retain(|parent, _| parent == member_name || visited.contains(parent));
+
+        Ok(LockfileParseResult {
+            packages,
+            dependency_map,
+            dependency_groups: HashMap::new(),
+            edge_extras: full_edge_extras,
+            edge_markers: full_edge_markers,
+            version: header.version(),
+            requires_python: header.requires_python,
+            ..Default::default()
+        })
     }
 }
 
 impl ProjectConfigReader for FileSystemReader {
     fn read_project_name(&self, project_path: &Path) -> Result<String> {
-        let pyproject_path = project_path.join("pyproject.toml");
+        let pyproject_path = self.pyproject_path(project_path);
 
         if !pyproject_path.exists() {
-            anyhow::bail!("pyproject.toml not found in project directory");
+            return Err(UvSbomError::ProjectConfigNotFound {
+                path: pyproject_path,
+            });
         }
 
         // Read with security checks
         let pyproject_content = self.safe_read_file(&pyproject_path, "pyproject.toml")?;
 
         let pyproject: toml::Value = toml::from_str(&pyproject_content)
-            .map_err(|e| anyhow::anyhow!("Failed to parse pyproject.toml: {}", e))?;
+            .map_err(|e| UvSbomError::other(format!("Failed to parse pyproject.toml: {}", e)))?;
 
         let project_name = pyproject
             .get("project")
             .and_then(|p| p.get("name"))
             .and_then(|n| n.as_str())
-            .ok_or_else(|| anyhow::anyhow!("Project name not found in pyproject.toml"))?;
+            .ok_or(UvSbomError::ProjectNameMissing {
+                path: pyproject_path.clone(),
+            })?;
 
         Ok(project_name.to_string())
     }
+
+    fn read_project_version(&self, project_path: &Path) -> Result<Option<String>> {
+        let pyproject_path = self.pyproject_path(project_path);
+
+        if !pyproject_path.exists() {
+            return Ok(None);
+        }
+
+        let pyproject_content = self.safe_read_file(&pyproject_path, "pyproject.toml")?;
+
+        let pyproject: toml::Value = toml::from_str(&pyproject_content)
+            .map_err(|e| UvSbomError::other(format!("Failed to parse pyproject.toml: {}", e)))?;
+
+        Ok(pyproject
+            .get("project")
+            .and_then(|p| p.get("version"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string))
+    }
+
+    fn read_requires_python(&self, project_path: &Path) -> Result<Option<String>> {
+        let pyproject_path = self.pyproject_path(project_path);
+
+        if !pyproject_path.exists() {
+            return Ok(None);
+        }
+
+        let pyproject_content = self.safe_read_file(&pyproject_path, "pyproject.toml")?;
+
+        let pyproject: toml::Value = toml::from_str(&pyproject_content)
+            .map_err(|e| UvSbomError::other(format!("Failed to parse pyproject.toml: {}", e)))?;
+
+        Ok(pyproject
+            .get("project")
+            .and_then(|p| p.get("requires-python"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string))
+    }
+
+    fn read_project_license(&self, project_path: &Path) -> Result<Option<String>> {
+        let pyproject_path = self.pyproject_path(project_path);
+
+        if !pyproject_path.exists() {
+            return Ok(None);
+        }
+
+        let pyproject_content = self.safe_read_file(&pyproject_path, "pyproject.toml")?;
+
+        let pyproject: toml::Value = toml::from_str(&pyproject_content)
+            .map_err(|e| UvSbomError::other(format!("Failed to parse pyproject.toml: {}", e)))?;
+
+        let Some(project) = pyproject.get("project") else {
+            return Ok(None);
+        };
+
+        let declared = match project.get("license") {
+            Some(toml::Value::String(expression)) => Some(expression.as_str()),
+            Some(table) => table.get("text").and_then(|t| t.as_str()),
+            None => None,
+        };
+        if let Some(license) = declared.map(str::trim).filter(|l| !l.is_empty()) {
+            return Ok(Some(license.to_string()));
+        }
+
+        // Classifiers look like "License :: OSI Approved :: MIT License"
+        Ok(project
+            .get("classifiers")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c.as_str())
+            .filter(|c| c.starts_with("License ::"))
+            .filter_map(|c| c.rsplit(" :: ").next())
+            .find(|name| *name != "OSI Approved")
+            .map(str::to_string))
+    }
+
+    fn read_declared_requirements(&self, project_path: &Path) -> Result<Option<Vec<String>>> {
+        let pyproject_path = self.pyproject_path(project_path);
+
+        if !pyproject_path.exists() {
+            return Ok(None);
+        }
+
+        let pyproject_content = self.safe_read_file(&pyproject_path, "pyproject.toml")?;
+
+        let pyproject: toml::Value = toml::from_str(&pyproject_content)
+            .map_err(|e| UvSbomError::other(format!("Failed to parse pyproject.toml: {}", e)))?;
+
+        let Some(project) = pyproject.get("project") else {
+            return Ok(None);
+        };
+        // Dependencies computed by the build backend cannot be read from the file
+        let dynamic = project.get("dynamic").and_then(|d| d.as_array());
+        if dynamic.is_some_and(|d| d.iter().any(|f| f.as_str() == Some("dependencies"))) {
+            return Ok(None);
+        }
+
+        let mut requirements = Vec::new();
+        push_requirements(&mut requirements, project.get("dependencies"));
+        let lists = [
+            project.get("optional-dependencies"),
+            pyproject.get("dependency-groups"),
+        ];
+        for table in lists.into_iter().flatten().filter_map(|t| t.as_table()) {
+            for list in table.values() {
+                push_requirements(&mut requirements, Some(list));
+            }
+        }
+        push_requirements(
+            &mut requirements,
+            pyproject
+                .get("tool")
+                .and_then(|t| t.get("uv"))
+                .and_then(|u| u.get("dev-dependencies")),
+        );
+        Ok(Some(requirements))
+    }
+
+    fn read_local_package_licenses(&self, project_path: &Path) -> Result<HashMap<String, String>> {
+        #[derive(Debug, Deserialize)]
+        struct UvLock {
+            #[serde(default)]
+            package: Vec<UvPackage>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct UvPackage {
+            name: String,
+            source: Option<PackageSource>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct PackageSource {
+            editable: Option<String>,
+            #[serde(rename = "virtual")]
+            virtual_path: Option<String>,
+            directory: Option<String>,
+        }
+
+        let content = self.read_lockfile(project_path)?;
+        let lockfile: UvLock =
+            toml::from_str(&content).map_err(|e| UvSbomError::LockfileParseError {
+                path: self.lockfile_path(project_path),
+                details: e.to_string(),
+            })?;
+
+        let mut licenses = HashMap::new();
+        for pkg in lockfile.package {
+            let Some(source_path) = pkg
+                .source
+                .and_then(|s| s.editable.or(s.virtual_path).or(s.directory))
+            else {
+                continue;
+            };
+            // An unreadable local pyproject.toml only means no license for that package
+            if let Ok(Some(license)) = self.read_project_license(&project_path.join(source_path)) {
+                licenses.insert(PackageName::normalize(&pkg.name), license);
+            }
+        }
+        Ok(licenses)
+    }
+
+    fn read_package_annotations(&self, project_path: &Path) -> Result<Vec<PackageAnnotation>> {
+        let pyproject_path = self.pyproject_path(project_path);
+
+        if !pyproject_path.exists() {
+            return Ok(vec![]);
+        }
+
+        let pyproject_content = self.safe_read_file(&pyproject_path, "pyproject.toml")?;
+
+        let pyproject: toml::Value = toml::from_str(&pyproject_content)
+            .map_err(|e| UvSbomError::other(format!("Failed to parse pyproject.toml: {}", e)))?;
+
+        let Some(annotations) = pyproject
+            .get("tool")
+            .and_then(|t| t.get("uv-sbom"))
+            .and_then(|t| t.get("annotations"))
+        else {
+            return Ok(vec![]);
+        };
+
+        let table = annotations.as_table().ok_or_else(|| {
+            UvSbomError::other("[tool.uv-sbom.annotations] in pyproject.toml must be a table")
+        })?;
+
+        table
+            .iter()
+            .map(|(package, entry)| {
+                let entry = entry.as_table().ok_or_else(|| {
+                    UvSbomError::other(format!("[tool.uv-sbom.annotations.\"{}\"] in pyproject.toml must be a table",
+                        package))
+                })?;
+                let fields = entry
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = value.as_str().ok_or_else(|| {
+                            UvSbomError::other(format!("Annotation '{}' for package '{}' in pyproject.toml must be a string",
+                                key,
+                                package))
+                        })?;
+                        Ok((key.clone(), value.to_string()))
+                    })
+                    .collect::<Result<BTreeMap<_, _>>>()?;
+                Ok(PackageAnnotation::new(package.clone(), fields))
+            })
+            .collect()
+    }
+
+    fn read_workspace_member_names(&self, project_path: &Path) -> Result<Vec<String>> {
+        if !project_path.join("uv.lock").exists() {
+            return Ok(vec![]);
+        }
+
+        Ok(UvWorkspaceReader::new()
+            .read_workspace_members(project_path)?
+            .into_iter()
+            .map(|member| member.name)
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -326,6 +970,20 @@ mod tests {
         assert_eq!(content, "test content");
     }
 
+    #[test]
+    fn test_read_lockfile_over_size_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("uv.lock"), "test content").unwrap();
+
+        let reader = FileSystemReader::new().with_max_lockfile_size(4);
+        let err = reader.read_lockfile(temp_dir.path()).unwrap_err();
+
+        assert!(matches!(
+            err,
+            UvSbomError::LockfileTooLarge { size: 12, .. }
+        ));
+    }
+
     #[test]
     fn test_read_lockfile_not_found() {
         let temp_dir = TempDir::new().unwrap();
@@ -339,45 +997,112 @@ mod tests {
     }
 
     #[test]
-    fn test_read_project_name_success() {
+    fn test_read_lockfile_from_explicit_path() {
         let temp_dir = TempDir::new().unwrap();
-        let pyproject_path = temp_dir.path().join("pyproject.toml");
+        let lockfile_path = temp_dir.path().join("locks").join("uv.lock");
+        fs::create_dir(temp_dir.path().join("locks")).unwrap();
+        fs::write(&lockfile_path, "test content").unwrap();
+
+        let reader = FileSystemReader::new().with_lockfile_path(lockfile_path);
+        let content = reader.read_lockfile(temp_dir.path()).unwrap();
+
+        assert_eq!(content, "test content");
+    }
+
+    #[test]
+    fn test_read_lockfile_explicit_path_not_found_names_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let lockfile_path = temp_dir.path().join("services").join("uv.lock");
+
+        let reader = FileSystemReader::new().with_lockfile_path(lockfile_path.clone());
+        let err_string = reader
+            .read_lockfile(temp_dir.path())
+            .unwrap_err()
+            .to_string();
+
+        assert!(err_string.contains(&format!("{} does not exist", lockfile_path.display())));
+        assert!(err_string.contains("--lockfile"));
+    }
+
+    #[test]
+    fn test_read_project_config_from_explicit_pyproject_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_path = temp_dir.path().join("services").join("pyproject.toml");
+        fs::create_dir(temp_dir.path().join("services")).unwrap();
         fs::write(
             &pyproject_path,
             r#"
 [project]
-name = "test-project"
-version = "1.0.0"
+name = "api"
+version = "0.3.0"
 "#,
         )
         .unwrap();
 
-        let reader = FileSystemReader::new();
-        let project_name = reader.read_project_name(temp_dir.path()).unwrap();
+        let reader = FileSystemReader::new().with_pyproject_path(pyproject_path);
 
-        assert_eq!(project_name, "test-project");
+        assert_eq!(reader.read_project_name(temp_dir.path()).unwrap(), "api");
+        assert_eq!(
+            reader.read_project_version(temp_dir.path()).unwrap(),
+            Some("0.3.0".to_string())
+        );
     }
 
     #[test]
-    fn test_read_project_name_file_not_found() {
+    fn test_read_project_name_explicit_path_not_found_names_path() {
         let temp_dir = TempDir::new().unwrap();
+        let pyproject_path = temp_dir.path().join("missing").join("pyproject.toml");
 
-        let reader = FileSystemReader::new();
-        let result = reader.read_project_name(temp_dir.path());
+        let reader = FileSystemReader::new().with_pyproject_path(pyproject_path.clone());
+        let err_string = reader
+            .read_project_name(temp_dir.path())
+            .unwrap_err()
+            .to_string();
 
-        assert!(result.is_err());
-        let err_string = format!("{}", result.unwrap_err());
-        assert!(err_string.contains("pyproject.toml not found"));
+        assert!(err_string.contains(&pyproject_path.display().to_string()));
     }
 
     #[test]
-    fn test_read_project_name_invalid_toml() {
+    fn test_read_project_name_success() {
         let temp_dir = TempDir::new().unwrap();
         let pyproject_path = temp_dir.path().join("pyproject.toml");
-        fs::write(&pyproject_path, "invalid toml [[[").unwrap();
-
-        let reader = FileSystemReader::new();
-        let result = reader.read_project_name(temp_dir.path());
+        fs::write(
+            &pyproject_path,
+            r#"
+[project]
+name = "test-project"
+version = "1.0.0"
+"#,
+        )
+        .unwrap();
+
+        let reader = FileSystemReader::new();
+        let project_name = reader.read_project_name(temp_dir.path()).unwrap();
+
+        assert_eq!(project_name, "test-project");
+    }
+
+    #[test]
+    fn test_read_project_name_file_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let reader = FileSystemReader::new();
+        let result = reader.read_project_name(temp_dir.path());
+
+        assert!(matches!(
+            result,
+            Err(UvSbomError::ProjectConfigNotFound { path }) if path == temp_dir.path().join("pyproject.toml")
+        ));
+    }
+
+    #[test]
+    fn test_read_project_name_invalid_toml() {
+        let temp_dir = TempDir::new().unwrap();
+        let pyproject_path = temp_dir.path().join("pyproject.toml");
+        fs::write(&pyproject_path, "invalid toml [[[").unwrap();
+
+        let reader = FileSystemReader::new();
+        let result = reader.read_project_name(temp_dir.path());
 
         assert!(result.is_err());
         let err_string = format!("{}", result.unwrap_err());
@@ -405,6 +1130,699 @@ version = "1.0.0"
         assert!(err_string.contains("Project name not found"));
     }
 
+    #[test]
+    fn test_read_project_license() {
+        let temp_dir = TempDir::new().unwrap();
+        let reader = FileSystemReader::new();
+        let cases = [
+            ("license = \"Apache-2.0\"", Some("Apache-2.0")),
+            ("license = { text = \"MIT\" }", Some("MIT")),
+            ("license = { file = \"LICENSE\" }", None),
+            (
+                "classifiers = [\"Programming Language :: Python\", \"License :: OSI Approved :: BSD License\"]",
+                Some("BSD License"),
+            ),
+            ("", None),
+        ];
+
+        for (project_table, expected) in cases {
+            fs::write(
+                temp_dir.path().join("pyproject.toml"),
+                format!("[project]\nname = \"test-project\"\n{}\n", project_table),
+            )
+            .unwrap();
+            assert_eq!(
+                reader
+                    .read_project_license(temp_dir.path())
+                    .unwrap()
+                    .as_deref(),
+                expected,
+                "{}",
+                project_table
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_declared_requirements() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"
+[project]
+name = "test-project"
+dependencies = ["requests>=2.31"]
+
+[project.optional-dependencies]
+http2 = ["httpx[http2]"]
+
+[dependency-groups]
+dev = ["pytest", { include-group = "lint" }]
+lint = ["ruff"]
+
+[tool.uv]
+dev-dependencies = ["mypy"]
+"#,
+        )
+        .unwrap();
+
+        let mut requirements = FileSystemReader::new()
+            .read_declared_requirements(temp_dir.path())
+            .unwrap()
+            .unwrap();
+        requirements.sort();
+
+        assert_eq!(
+            requirements,
+            vec!["httpx[http2]", "mypy", "pytest", "requests>=2.31", "ruff"]
+        );
+    }
+
+    #[test]
+    fn test_read_declared_requirements_without_project_table() {
+        let temp_dir = TempDir::new().unwrap();
+        let reader = FileSystemReader::new();
+        assert_eq!(
+            reader.read_declared_requirements(temp_dir.path()).unwrap(),
+            None
+        );
+
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[tool.uv.workspace]\nmembers = [\"packages/*\"]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            reader.read_declared_requirements(temp_dir.path()).unwrap(),
+            None
+        );
+
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"test-project\"\ndynamic = [\"dependencies\"]\n",
+        )
+        .unwrap();
+        assert_eq!(
+            reader.read_declared_requirements(temp_dir.path()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_read_package_annotations() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"
+[project]
+name = "test-project"
+
+[tool.uv-sbom.annotations."requests"]
+owner = "team-web"
+
+[tool.uv-sbom.annotations."Pandas"]
+owner = "team-data"
+justification = "needed for ETL"
+"#,
+        )
+        .unwrap();
+
+        let reader = FileSystemReader::new();
+        let annotations = reader.read_package_annotations(temp_dir.path()).unwrap();
+
+        assert_eq!(annotations.len(), 2);
+        let pandas = annotations
+            .iter()
+            .find(|a| a.package() == "Pandas")
+            .unwrap();
+        assert_eq!(pandas.fields()["owner"], "team-data");
+        assert_eq!(pandas.fields()["justification"], "needed for ETL");
+    }
+
+    #[test]
+    fn test_read_package_annotations_absent() {
+        let temp_dir = TempDir::new().unwrap();
+        let reader = FileSystemReader::new();
+        assert!(reader
+            .read_package_annotations(temp_dir.path())
+            .unwrap()
+            .is_empty());
+
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"test-project\"\n",
+        )
+        .unwrap();
+        assert!(reader
+            .read_package_annotations(temp_dir.path())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_read_package_annotations_rejects_non_string_value() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"
+[tool.uv-sbom.annotations."requests"]
+reviewed = true
+"#,
+        )
+        .unwrap();
+
+        let reader = FileSystemReader::new();
+        let err = reader
+            .read_package_annotations(temp_dir.path())
+            .unwrap_err();
+        assert!(err.to_string().contains("'reviewed'"));
+        assert!(err.to_string().contains("must be a string"));
+    }
+
+    const HASHED_LOCK: &str = r#"
+version = 1
+requires-python = ">=3.11"
+
+[[package]]
+name = "myproject"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+  { name = "certifi" },
+  { name = "idna" },
+]
+
+[[package]]
+name = "certifi"
+version = "2024.1.1"
+source = { registry = "https://pypi.org/simple" }
+sdist = { url = "https://example.com/certifi-2024.1.1.tar.gz", hash = "sha256:5eed", size = 1 }
+wheels = [
+    { url = "https://example.com/certifi-2024.1.1-py3-none-any.whl", hash = "sha256:aaaa", size = 1 },
+]
+
+[[package]]
+name = "idna"
+version = "3.6"
+source = { registry = "https://pypi.org/simple" }
+wheels = [
+    { url = "https://example.com/idna-3.6-py3-none-any.whl", hash = "sha256:1111", size = 1 },
+    { url = "https://example.com/idna-3.6-py3-none-win32.whl", hash = "sha256:2222", size = 1 },
+]
+"#;
+
+    #[test]
+    fn test_parse_lockfile_extracts_primary_sha256() {
+        let reader = FileSystemReader::new();
+        let LockfileParseResult { packages, .. } = reader
+            .parse_lockfile_content(HASHED_LOCK, Path::new("/project"))
+            .unwrap();
+
+        let hash_of = |name: &str| {
+            packages
+                .iter()
+                .find(|p| p.name() == name)
+                .unwrap()
+                .sha256_hash()
+                .map(str::to_string)
+        };
+        // sdist is preferred over wheels
+        assert_eq!(hash_of("certifi").as_deref(), Some("5eed"));
+        // without an sdist, the first wheel is used
+        assert_eq!(hash_of("idna").as_deref(), Some("1111"));
+        // local sources have no artifacts
+        assert_eq!(hash_of("myproject"), None);
+    }
+
+    #[test]
+    fn test_parse_lockfile_records_requires_python() {
+        let content = r#"
+version = 1
+
+[[package]]
+name = "numpy"
+version = "1.26.4"
+source = { registry = "https://pypi.org/simple" }
+requires-python = ">=3.9,<3.13"
+
+[[package]]
+name = "six"
+version = "1.16.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let reader = FileSystemReader::new();
+        let LockfileParseResult { packages, .. } = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+
+        let requires_python_of = |name: &str| {
+            packages
+                .iter()
+                .find(|p| p.name() == name)
+                .unwrap()
+                .requires_python()
+                .map(str::to_string)
+        };
+        assert_eq!(requires_python_of("numpy").as_deref(), Some(">=3.9,<3.13"));
+        assert_eq!(requires_python_of("six"), None);
+    }
+
+    #[test]
+    fn test_parse_lockfile_records_version_and_requires_python() {
+        let content = r#"
+version = 1
+requires-python = ">=3.12"
+
+[[package]]
+name = "six"
+version = "1.16.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let reader = FileSystemReader::new();
+        let result = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+
+        let version = result.version.unwrap();
+        assert_eq!(version.version, "1");
+        assert!(!version.is_newer_than_supported());
+        assert_eq!(result.requires_python.as_deref(), Some(">=3.12"));
+    }
+
+    #[test]
+    fn test_parse_lockfile_of_newer_version_is_best_effort() {
+        let content = r#"
+version = 2
+unknown-table = { added = "later" }
+
+[[package]]
+name = "six"
+version = "1.16.0"
+source = { registry = "https://pypi.org/simple" }
+new-field = true
+"#;
+        let reader = FileSystemReader::new();
+        let result = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+
+        assert_eq!(result.packages.len(), 1);
+        assert!(result.version.unwrap().is_newer_than_supported());
+    }
+
+    #[test]
+    fn test_parse_error_of_newer_version_names_the_version() {
+        let content = r#"
+version = 3
+
+[[package]]
+name = "six"
+version = { major = 1 }
+"#;
+        let reader = FileSystemReader::new();
+        let err = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("lockfile version 3 is newer than supported 1"));
+    }
+
+    #[test]
+    fn test_parse_lockfile_keeps_forked_package_versions() {
+        let content = r#"
+version = 1
+
+[[package]]
+name = "numpy"
+version = "1.26.4"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = ["python_full_version < '3.10'"]
+dependencies = [{ name = "packaging" }]
+
+[[package]]
+name = "numpy"
+version = "2.0.0"
+source = { registry = "https://pypi.org/simple" }
+resolution-markers = ["python_full_version >= '3.10'"]
+
+[[package]]
+name = "packaging"
+version = "24.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let reader = FileSystemReader::new();
+        let LockfileParseResult {
+            packages,
+            dependency_map,
+            ..
+        } = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+
+        let numpy_versions: Vec<&str> = packages
+            .iter()
+            .filter(|p| p.name() == "numpy")
+            .map(|p| p.version())
+            .collect();
+        assert_eq!(numpy_versions, ["1.26.4", "2.0.0"]);
+        assert_eq!(dependency_map["numpy"], ["packaging"]);
+    }
+
+    #[test]
+    fn test_read_requires_python() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"app\"\nrequires-python = \">=3.9\"\n",
+        )
+        .unwrap();
+
+        let reader = FileSystemReader::new();
+
+        assert_eq!(
+            reader
+                .read_requires_python(temp_dir.path())
+                .unwrap()
+                .as_deref(),
+            Some(">=3.9")
+        );
+        let empty_dir = TempDir::new().unwrap();
+        assert_eq!(reader.read_requires_python(empty_dir.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_lockfile_records_package_sources() {
+        let content = r#"
+version = 1
+
+[[package]]
+name = "myproject"
+version = "0.1.0"
+source = { virtual = "." }
+
+[[package]]
+name = "certifi"
+version = "2024.1.1"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "httpx"
+version = "0.28.0.dev0"
+source = { git = "https://github.com/encode/httpx?rev=master#b4b27ff6777c8906c2b31dd879bd4cc1d9e4f6ca" }
+
+[[package]]
+name = "internal-lib"
+version = "1.2.0"
+source = { directory = "../internal-lib" }
+
+[[package]]
+name = "vendored"
+version = "0.3.0"
+source = { url = "https://example.com/vendored-0.3.0.tar.gz" }
+"#;
+        let reader = FileSystemReader::new();
+        let LockfileParseResult { packages, .. } = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+
+        let source_of = |name: &str| {
+            packages
+                .iter()
+                .find(|p| p.name() == name)
+                .unwrap()
+                .source()
+                .cloned()
+        };
+        assert_eq!(
+            source_of("myproject"),
+            Some(PackageSource::Virtual {
+                path: ".".to_string()
+            })
+        );
+        assert_eq!(
+            source_of("certifi"),
+            Some(PackageSource::Registry {
+                url: "https://pypi.org/simple".to_string()
+            })
+        );
+        assert_eq!(
+            source_of("httpx"),
+            Some(PackageSource::Git {
+                url: "https://github.com/encode/httpx".to_string(),
+                revision: Some("b4b27ff6777c8906c2b31dd879bd4cc1d9e4f6ca".to_string()),
+            })
+        );
+        assert_eq!(
+            source_of("internal-lib"),
+            Some(PackageSource::Path {
+                path: "../internal-lib".to_string()
+            })
+        );
+        assert_eq!(source_of("vendored"), None);
+
+        let roots: Vec<&str> = packages
+            .iter()
+            .filter(|p| p.is_project_root())
+            .map(|p| p.name())
+            .collect();
+        assert_eq!(roots, vec!["myproject"]);
+    }
+
+    const GROUPED_LOCK: &str = r#"
+version = 1
+requires-python = ">=3.11"
+
+[[package]]
+name = "myproject"
+version = "0.1.0"
+source = { editable = "." }
+dependencies = [
+  { name = "requests", extra = ["socks"] },
+]
+
+[package.optional-dependencies]
+cli = [
+  { name = "click" },
+]
+
+[package.dev-dependencies]
+dev = [
+  { name = "pytest" },
+]
+lint = [
+  { name = "ruff" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+
+[package.optional-dependencies]
+socks = [
+  { name = "pysocks" },
+]
+
+[[package]]
+name = "pysocks"
+version = "1.7.1"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "click"
+version = "8.1.7"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "pytest"
+version = "8.0.0"
+source = { registry = "https://pypi.org/simple" }
+
+[[package]]
+name = "ruff"
+version = "0.3.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+    #[test]
+    fn test_parse_lockfile_separates_dependency_groups() {
+        let reader = FileSystemReader::new();
+        let LockfileParseResult {
+            packages,
+            dependency_map: dep_map,
+            dependency_groups: groups,
+            ..
+        } = reader
+            .parse_lockfile_content(GROUPED_LOCK, Path::new("/project"))
+            .unwrap();
+
+        assert_eq!(packages.len(), 6);
+        // Runtime edges only; extras of a dependency are runtime edges too
+        assert_eq!(dep_map["myproject"], ["requests"]);
+        assert_eq!(dep_map["requests"], ["pysocks"]);
+        // Dependency groups and the project's own extras are separate groups
+        let mut group_names: Vec<&str> = groups.keys().map(String::as_str).collect();
+        group_names.sort();
+        assert_eq!(group_names, ["cli", "dev", "lint"]);
+        assert_eq!(groups["dev"]["myproject"], ["pytest"]);
+        assert_eq!(groups["lint"]["myproject"], ["ruff"]);
+        assert_eq!(groups["cli"]["myproject"], ["click"]);
+    }
+
+    #[test]
+    fn test_parse_lockfile_records_edge_extras() {
+        let reader = FileSystemReader::new();
+        let LockfileParseResult { edge_extras, .. } = reader
+            .parse_lockfile_content(GROUPED_LOCK, Path::new("/project"))
+            .unwrap();
+
+        assert_eq!(edge_extras["myproject"]["requests"], ["socks"]);
+        // Edges without extras are not recorded
+        assert_eq!(edge_extras.len(), 1);
+        assert_eq!(edge_extras["myproject"].len(), 1);
+    }
+
+    #[test]
+    fn test_parse_lockfile_merges_multiple_extras_per_edge() {
+        let content = r#"
+version = 1
+
+[[package]]
+name = "myproject"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+  { name = "requests", extra = ["socks", "security"], marker = "sys_platform == 'linux'" },
+  { name = "requests", extra = ["socks", "use-chardet-on-py3"], marker = "sys_platform != 'linux'" },
+]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+        let reader = FileSystemReader::new();
+        let LockfileParseResult { edge_extras, .. } = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+        let LockfileParseResult {
+            edge_extras: member_extras,
+            ..
+        } = reader
+            .parse_lockfile_content_for_member(content, Path::new("/project"), "myproject")
+            .unwrap();
+
+        assert_eq!(
+            edge_extras["myproject"]["requests"],
+            ["socks", "security", "use-chardet-on-py3"]
+        );
+        assert_eq!(member_extras, edge_extras);
+    }
+
+    #[test]
+    fn test_parse_lockfile_records_environment_markers() {
+        let content = include_str!("../../../../tests/fixtures/platform-project/uv.lock");
+        let reader = FileSystemReader::new();
+        let LockfileParseResult {
+            packages,
+            edge_markers,
+            ..
+        } = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+
+        let root = &edge_markers["platform-project"];
+        assert_eq!(root["pywin32"], "sys_platform == 'win32'");
+        assert_eq!(root["tomli"], "python_full_version < '3.11'");
+        // Unconditional edges are not recorded
+        assert!(!root.contains_key("click"));
+        assert_eq!(
+            edge_markers["click"]["colorama"],
+            "platform_system == 'Windows'"
+        );
+
+        let marker_of = |name: &str| {
+            packages
+                .iter()
+                .find(|p| p.name() == name)
+                .unwrap()
+                .marker()
+                .map(str::to_string)
+        };
+        // Resolution markers combined with the platforms of wheel-only packages
+        assert_eq!(
+            marker_of("pywin32").as_deref(),
+            Some("sys_platform == 'win32'")
+        );
+        assert_eq!(
+            marker_of("uvloop").as_deref(),
+            Some("(sys_platform != 'win32') and ((sys_platform == 'darwin') or (sys_platform == 'linux'))")
+        );
+        // Packages with an sdist or pure-Python wheels run anywhere
+        assert_eq!(marker_of("click"), None);
+        assert_eq!(marker_of("colorama"), None);
+    }
+
+    #[test]
+    fn test_record_edge_markers_unconditional_edge_wins() {
+        let content = r#"
+version = 1
+
+[[package]]
+name = "myproject"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [
+  { name = "numpy", marker = "python_full_version < '3.11'" },
+  { name = "numpy", marker = "python_full_version >= '3.12'" },
+  { name = "requests", marker = "sys_platform == 'linux'" },
+  { name = "requests" },
+]
+"#;
+        let reader = FileSystemReader::new();
+        let LockfileParseResult { edge_markers, .. } = reader
+            .parse_lockfile_content(content, Path::new("/project"))
+            .unwrap();
+        let LockfileParseResult {
+            edge_markers: member_markers,
+            ..
+        } = reader
+            .parse_lockfile_content_for_member(content, Path::new("/project"), "myproject")
+            .unwrap();
+
+        assert_eq!(
+            edge_markers["myproject"]["numpy"],
+            "(python_full_version < '3.11') or (python_full_version >= '3.12')"
+        );
+        assert!(!edge_markers["myproject"].contains_key("requests"));
+        assert_eq!(member_markers, edge_markers);
+    }
+
+    #[test]
+    fn test_parse_lockfile_for_member_extracts_primary_sha256() {
+        let reader = FileSystemReader::new();
+        let LockfileParseResult { packages, .. } = reader
+            .parse_lockfile_content_for_member(HASHED_LOCK, Path::new("/project"), "myproject")
+            .unwrap();
+
+        let certifi = packages.iter().find(|p| p.name() == "certifi").unwrap();
+        assert_eq!(certifi.sha256_hash(), Some("5eed"));
+    }
+
+    #[test]
+    fn test_primary_sha256_ignores_other_algorithms() {
+        let wheels = vec![UvArtifact {
+            hash: Some("md5:abcd".to_string()),
+            url: None,
+            path: None,
+            filename: None,
+        }];
+        assert_eq!(primary_sha256(None, &wheels), None);
+        assert_eq!(primary_sha256(None, &[]), None);
+    }
+
     // Workspace lock fixture used by member-scoped filtering tests.
     //
     // Dependency graph:
@@ -471,7 +1889,11 @@ source = { registry = "https://pypi.org/simple" }
     #[test]
     fn test_parse_lockfile_for_member_returns_correct_subtree_for_alpha() {
         let reader = FileSystemReader::new();
-        let (packages, dep_map) = reader
+        let LockfileParseResult {
+            packages,
+            dependency_map: dep_map,
+            ..
+        } = reader
             .parse_lockfile_content_for_member(
                 WORKSPACE_LOCK_FOR_MEMBER,
                 Path::new("/workspace"),
@@ -505,7 +1927,7 @@ source = { registry = "https://pypi.org/simple" }
     #[test]
     fn test_parse_lockfile_for_member_returns_correct_subtree_for_beta() {
         let reader = FileSystemReader::new();
-        let (packages, _dep_map) = reader
+        let LockfileParseResult { packages, .. } = reader
             .parse_lockfile_content_for_member(
                 WORKSPACE_LOCK_FOR_MEMBER,
                 Path::new("/workspace"),
@@ -527,7 +1949,7 @@ source = { registry = "https://pypi.org/simple" }
     #[test]
     fn test_parse_lockfile_for_member_member_root_excluded() {
         let reader = FileSystemReader::new();
-        let (packages, _) = reader
+        let LockfileParseResult { packages, .. } = reader
             .parse_lockfile_content_for_member(
                 WORKSPACE_LOCK_FOR_MEMBER,
                 Path::new("/workspace"),
@@ -565,7 +1987,7 @@ source = { registry = "https://pypi.org/simple" }
         fs::write(temp_dir.path().join("uv.lock"), WORKSPACE_LOCK_FOR_MEMBER).unwrap();
 
         let reader = FileSystemReader::new();
-        let (packages, _) = reader
+        let LockfileParseResult { packages, .. } = reader
             .read_and_parse_lockfile_for_member(temp_dir.path(), "alpha")
             .unwrap();
 
@@ -576,6 +1998,44 @@ source = { registry = "https://pypi.org/simple" }
         assert!(!names.contains("alpha"));
     }
 
+    #[test]
+    fn test_read_workspace_member_names() {
+        let reader = FileSystemReader::new();
+        let temp_dir = TempDir::new().unwrap();
+
+        // No uv.lock: not a workspace root
+        assert!(reader
+            .read_workspace_member_names(temp_dir.path())
+            .unwrap()
+            .is_empty());
+
+        fs::write(temp_dir.path().join("uv.lock"), WORKSPACE_LOCK_FOR_MEMBER).unwrap();
+        assert_eq!(
+            reader.read_workspace_member_names(temp_dir.path()).unwrap(),
+            vec!["alpha".to_string(), "beta".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_read_local_package_licenses() {
+        let reader = FileSystemReader::new();
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("uv.lock"), WORKSPACE_LOCK_FOR_MEMBER).unwrap();
+
+        let alpha = temp_dir.path().join("packages/alpha");
+        fs::create_dir_all(&alpha).unwrap();
+        fs::write(
+            alpha.join("pyproject.toml"),
+            "[project]\nname = \"alpha\"\nlicense = \"MIT\"\n",
+        )
+        .unwrap();
+        // beta has no pyproject.toml; registry packages are never looked up
+
+        let licenses = reader.read_local_package_licenses(temp_dir.path()).unwrap();
+        assert_eq!(licenses.len(), 1);
+        assert_eq!(licenses.get("alpha").map(String::as_str), Some("MIT"));
+    }
+
     // uv >= 0.5 workspace lock fixture using `source.virtual` instead of `source.editable`.
     //
     // Dependency graph:
@@ -628,7 +2088,7 @@ dependencies = [
     #[test]
     fn test_parse_lockfile_for_member_handles_virtual_source_for_api() {
         let reader = FileSystemReader::new();
-        let (packages, _) = reader
+        let LockfileParseResult { packages, .. } = reader
             .parse_lockfile_content_for_member(
                 WORKSPACE_LOCK_VIRTUAL_FORMAT,
                 Path::new("/workspace"),
@@ -648,7 +2108,7 @@ dependencies = [
     #[test]
     fn test_parse_lockfile_for_member_handles_virtual_source_for_worker() {
         let reader = FileSystemReader::new();
-        let (packages, _) = reader
+        let LockfileParseResult { packages, .. } = reader
             .parse_lockfile_content_for_member(
                 WORKSPACE_LOCK_VIRTUAL_FORMAT,
                 Path::new("/workspace"),
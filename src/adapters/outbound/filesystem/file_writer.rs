@@ -1,6 +1,6 @@
 use crate::i18n::{Locale, Messages};
-use crate::ports::outbound::OutputPresenter;
-use crate::shared::error::SbomError;
+use crate::ports::outbound::{OutputPresenter, Verbosity};
+use crate::shared::error::UvSbomError;
 use crate::shared::security::validate_not_symlink;
 use crate::shared::Result;
 use std::fs;
@@ -9,10 +9,14 @@ use std::path::{Path, PathBuf};
 
 /// FileSystemWriter adapter for writing output to files
 ///
-/// This adapter implements the OutputPresenter port for file output.
+/// This adapter implements the OutputPresenter port for file output. Files are
+/// written to a temporary file in the target directory and renamed into place,
+/// so a failed write never leaves a truncated file behind.
 pub struct FileSystemWriter {
     output_path: PathBuf,
     locale: Locale,
+    create_dirs: bool,
+    verbosity: Verbosity,
 }
 
 impl FileSystemWriter {
@@ -20,18 +24,54 @@ impl FileSystemWriter {
         Self {
             output_path,
             locale,
+            create_dirs: false,
+            verbosity: Verbosity::Normal,
         }
     }
 
-    /// Validates that the parent directory exists before writing
-    fn validate_parent_directory(&self) -> Result<()> {
+    /// Creates missing parent directories of the output path instead of failing
+    pub fn with_create_dirs(mut self, create_dirs: bool) -> Self {
+        self.create_dirs = create_dirs;
+        self
+    }
+
+    /// Sets the verbosity; written files are not announced in quiet mode
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Returns the output path made absolute against the current directory,
+    /// used in error messages so relative paths can be traced
+    fn absolute_path(&self) -> PathBuf {
+        std::path::absolute(&self.output_path).unwrap_or_else(|_| self.output_path.clone())
+    }
+
+    fn write_error(&self, details: impl ToString) -> UvSbomError {
+        UvSbomError::FileWriteError {
+            path: self.absolute_path(),
+            details: details.to_string(),
+        }
+    }
+
+    /// Ensures the parent directory exists, creating it when `create_dirs` is set
+    fn prepare_parent_directory(&self) -> Result<()> {
         if let Some(parent) = self.output_path.parent() {
             if !parent.exists() && parent != Path::new("") {
-                return Err(SbomError::FileWriteError {
-                    path: self.output_path.clone(),
-                    details: format!("Parent directory does not exist: {}", parent.display()),
+                if !self.create_dirs {
+                    let path = self.absolute_path();
+                    return Err(UvSbomError::OutputDirectoryMissing {
+                        parent: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+                        path,
+                    });
                 }
-                .into());
+                fs::create_dir_all(parent).map_err(|e| {
+                    self.write_error(format!(
+                        "Failed to create directory {}: {}",
+                        parent.display(),
+                        e
+                    ))
+                })?;
             }
         }
         Ok(())
@@ -43,12 +83,7 @@ impl FileSystemWriter {
     fn validate_output_security(&self) -> Result<()> {
         // If the file already exists, check it's not a symlink
         if self.output_path.exists() {
-            validate_not_symlink(&self.output_path, "write").map_err(|e| {
-                SbomError::FileWriteError {
-                    path: self.output_path.clone(),
-                    details: e.to_string(),
-                }
-            })?;
+            validate_not_symlink(&self.output_path, "write").map_err(|e| self.write_error(e))?;
         }
 
         // Validate parent directory chain for symlinks
@@ -64,11 +99,9 @@ impl FileSystemWriter {
                         }
                     }
                     Err(e) => {
-                        return Err(SbomError::FileWriteError {
-                            path: self.output_path.clone(),
-                            details: format!("Failed to validate parent directory: {}", e),
-                        }
-                        .into());
+                        return Err(
+                            self.write_error(format!("Failed to validate parent directory: {}", e))
+                        );
                     }
                 }
             }
@@ -81,27 +114,92 @@ impl FileSystemWriter {
 impl OutputPresenter for FileSystemWriter {
     fn present(&self, content: &str) -> Result<()> {
         // Security validations
-        self.validate_parent_directory()?;
+        self.prepare_parent_directory()?;
         self.validate_output_security()?;
 
         // Safe to write now
-        fs::write(&self.output_path, content).map_err(|e| SbomError::FileWriteError {
-            path: self.output_path.clone(),
-            details: e.to_string(),
-        })?;
-
-        let msgs = Messages::for_locale(self.locale);
-        eprintln!(
-            "{}",
-            Messages::format(
-                msgs.output_complete,
-                &[&self.output_path.display().to_string()]
-            )
-        );
+        write_atomically(&self.output_path, content).map_err(|e| self.write_error(e))?;
+
+        if self.verbosity != Verbosity::Quiet {
+            let msgs = Messages::for_locale(self.locale);
+            eprintln!(
+                "{}",
+                Messages::format(
+                    msgs.output_complete,
+                    &[&self.output_path.display().to_string()]
+                )
+            );
+        }
+        Ok(())
+    }
+
+    /// Writes each file into the output directory, creating the directory if needed.
+    /// Missing directories above it are only created when `create_dirs` is set.
+    ///
+    /// A file that cannot be written does not stop the others; the failures are
+    /// reported together in one error once every file has been attempted.
+    fn present_many(&self, files: &[(String, String)]) -> Result<()> {
+        self.prepare_parent_directory()?;
+        self.validate_output_security()?;
+
+        if self.output_path.exists() {
+            if !self.output_path.is_dir() {
+                return Err(
+                    self.write_error("Output path must be a directory for multi-file output")
+                );
+            }
+        } else {
+            fs::create_dir(&self.output_path).map_err(|e| self.write_error(e))?;
+        }
+
+        let mut failures = Vec::new();
+        for (name, content) in files {
+            let writer = FileSystemWriter::new(self.output_path.join(name), self.locale)
+                .with_verbosity(self.verbosity);
+            match writer.present(content) {
+                Ok(()) => {}
+                Err(UvSbomError::FileWriteError { path, details }) => {
+                    failures.push(format!("  {}: {}", path.display(), details));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        if !failures.is_empty() {
+            return Err(self.write_error(format!(
+                "{} of {} files could not be written:\n{}",
+                failures.len(),
+                files.len(),
+                failures.join("\n")
+            )));
+        }
         Ok(())
     }
 }
 
+/// Writes `content` to a temporary file next to `path` and renames it over `path`.
+///
+/// The rename is atomic within a directory, so readers see either the previous
+/// file or the complete new one. The temporary file is removed if any step fails.
+fn write_atomically(path: &Path, content: &str) -> io::Result<()> {
+    let dir = match path.parent() {
+        Some(parent) if parent != Path::new("") => parent,
+        _ => Path::new("."),
+    };
+    let mut builder = tempfile::Builder::new();
+    builder.prefix(".uv-sbom-").suffix(".tmp");
+    // Same mode as `fs::write` (before the umask) rather than tempfile's 0600
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(fs::Permissions::from_mode(0o666));
+    }
+    let mut temp = builder.tempfile_in(dir)?;
+    temp.write_all(content.as_bytes())?;
+    temp.as_file().sync_all()?;
+    temp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
+
 /// StdoutPresenter adapter for writing output to stdout
 ///
 /// This adapter implements the OutputPresenter port for stdout output.
@@ -120,14 +218,31 @@ impl Default for StdoutPresenter {
 }
 
 impl OutputPresenter for StdoutPresenter {
+    /// Writes `content` to stdout.
+    ///
+    /// # Errors
+    /// Returns [`UvSbomError::OutputClosed`] when the reading end of a pipe has
+    /// been closed, so callers can tell an early-exiting consumer apart from a
+    /// real write failure.
     fn present(&self, content: &str) -> Result<()> {
-        io::stdout()
-            .write_all(content.as_bytes())
-            .map_err(|e| anyhow::anyhow!("Failed to write to stdout: {}", e))?;
-        Ok(())
+        write_output(&mut io::stdout().lock(), content)
     }
 }
 
+fn write_output(out: &mut impl Write, content: &str) -> Result<()> {
+    out.write_all(content.as_bytes())
+        .and_then(|()| out.flush())
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::BrokenPipe => UvSbomError::OutputClosed,
+            _ => UvSbomError::other(format!("Failed to write to stdout: {}", e)),
+        })
+}
+
+/// Returns true when `error` means the consumer of stdout stopped reading
+pub fn is_output_closed(error: &UvSbomError) -> bool {
+    matches!(error, UvSbomError::OutputClosed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,6 +274,160 @@ mod tests {
         assert!(err_string.contains("Parent directory does not exist"));
     }
 
+    #[test]
+    fn test_file_writer_missing_parent_error_has_absolute_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("reports/nested/sbom.json");
+
+        let writer = FileSystemWriter::new(output_path.clone(), Locale::En);
+        match writer.present("{}").unwrap_err() {
+            UvSbomError::OutputDirectoryMissing { path, parent } => {
+                assert_eq!(path, output_path);
+                assert_eq!(parent, temp_dir.path().join("reports/nested"));
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+        assert!(!temp_dir.path().join("reports").exists());
+    }
+
+    #[test]
+    fn test_file_writer_create_dirs_creates_nested_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("reports/nested/sbom.json");
+
+        let writer = FileSystemWriter::new(output_path.clone(), Locale::En).with_create_dirs(true);
+        writer.present("test content").unwrap();
+
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), "test content");
+    }
+
+    #[test]
+    fn test_file_writer_replaces_existing_file_without_leftovers() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("sbom.json");
+        fs::write(&output_path, "old content that is longer").unwrap();
+
+        let writer = FileSystemWriter::new(output_path.clone(), Locale::En);
+        writer.present("new").unwrap();
+
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), "new");
+        let entries: Vec<_> = fs::read_dir(temp_dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_file_writer_failed_rename_removes_temporary_file() {
+        let temp_dir = TempDir::new().unwrap();
+        // A non-empty directory in place of the file makes the final rename fail
+        let output_path = temp_dir.path().join("sbom.json");
+        fs::create_dir(&output_path).unwrap();
+        fs::write(output_path.join("keep"), "").unwrap();
+
+        let writer = FileSystemWriter::new(output_path.clone(), Locale::En);
+        let err = writer.present("{}").unwrap_err();
+
+        assert!(matches!(err, UvSbomError::FileWriteError { path, .. } if path == output_path));
+        let entries: Vec<_> = fs::read_dir(temp_dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_file_writer_read_only_parent_keeps_previous_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().join("readonly");
+        fs::create_dir(&dir).unwrap();
+        let output_path = dir.join("sbom.json");
+        fs::write(&output_path, "previous").unwrap();
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o555)).unwrap();
+
+        // Permission bits do not apply to root; nothing to check then
+        let probe = dir.join("probe");
+        if fs::write(&probe, "").is_ok() {
+            fs::remove_file(&probe).unwrap();
+            fs::set_permissions(&dir, fs::Permissions::from_mode(0o755)).unwrap();
+            return;
+        }
+
+        let writer = FileSystemWriter::new(output_path.clone(), Locale::En).with_create_dirs(true);
+        let result = writer.present("new");
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o755)).unwrap();
+
+        assert!(matches!(result, Err(UvSbomError::FileWriteError { .. })));
+        assert_eq!(fs::read_to_string(&output_path).unwrap(), "previous");
+    }
+
+    #[test]
+    fn test_file_writer_present_many_creates_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_dir = temp_dir.path().join("report");
+
+        let writer = FileSystemWriter::new(output_dir.clone(), Locale::En);
+        let files = vec![
+            ("index.md".to_string(), "index".to_string()),
+            ("components.md".to_string(), "components".to_string()),
+        ];
+        writer.present_many(&files).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(output_dir.join("index.md")).unwrap(),
+            "index"
+        );
+        assert_eq!(
+            fs::read_to_string(output_dir.join("components.md")).unwrap(),
+            "components"
+        );
+    }
+
+    #[test]
+    fn test_file_writer_present_many_reports_every_failure() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_dir = temp_dir.path().join("artifacts");
+        // Directories in place of two of the files make those writes fail
+        fs::create_dir_all(output_dir.join("sbom.json")).unwrap();
+        fs::create_dir_all(output_dir.join("cve-report.json")).unwrap();
+
+        let writer = FileSystemWriter::new(output_dir.clone(), Locale::En);
+        let files = vec![
+            ("sbom.json".to_string(), "{}".to_string()),
+            ("sbom.md".to_string(), "# SBOM".to_string()),
+            ("cve-report.json".to_string(), "{}".to_string()),
+        ];
+        let result = writer.present_many(&files);
+
+        // The file after the first failure is still written
+        assert_eq!(
+            fs::read_to_string(output_dir.join("sbom.md")).unwrap(),
+            "# SBOM"
+        );
+        let err_string = format!("{}", result.unwrap_err());
+        assert!(err_string.contains("2 of 3 files could not be written"));
+        assert!(err_string.contains("sbom.json"));
+        assert!(err_string.contains("cve-report.json"));
+    }
+
+    #[test]
+    fn test_file_writer_present_many_rejects_file_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("sbom.md");
+        fs::write(&output_path, "existing").unwrap();
+
+        let writer = FileSystemWriter::new(output_path, Locale::En);
+        let result = writer.present_many(&[("index.md".to_string(), String::new())]);
+
+        assert!(result.is_err());
+        let err_string = format!("{}", result.unwrap_err());
+        assert!(err_string.contains("must be a directory"));
+    }
+
+    #[test]
+    fn test_stdout_presenter_rejects_present_many() {
+        let presenter = StdoutPresenter::new();
+        assert!(presenter.present_many(&[]).is_err());
+    }
+
     #[test]
     fn test_stdout_presenter_success() {
         let presenter = StdoutPresenter::new();
@@ -167,4 +436,31 @@ mod tests {
         let result = presenter.present("test output\n");
         assert!(result.is_ok());
     }
+
+    /// Writer that fails every write with the given error kind
+    struct FailingWriter(io::ErrorKind);
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::from(self.0))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_output_broken_pipe_is_output_closed() {
+        let err = write_output(&mut FailingWriter(io::ErrorKind::BrokenPipe), "{}").unwrap_err();
+        assert!(is_output_closed(&err));
+    }
+
+    #[test]
+    fn test_write_output_other_errors_are_not_output_closed() {
+        let err =
+            write_output(&mut FailingWriter(io::ErrorKind::PermissionDenied), "{}").unwrap_err();
+        assert!(!is_output_closed(&err));
+        assert!(err.to_string().contains("Failed to write to stdout"));
+    }
 }
@@ -0,0 +1,383 @@
+use crate::ports::outbound::{LicenseRepository, PyPiMetadata};
+use crate::sbom_generation::domain::{
+    LicenseInfo, LicenseSource, PackageAuthorship, PackageLinks, PackageName,
+};
+use crate::sbom_generation::policies::LicensePriority;
+use crate::shared::error::UvSbomError;
+use crate::shared::security::{read_file_with_security, MAX_FILE_SIZE};
+use crate::shared::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// LocalMetadataLicenseRepository adapter for reading license information from
+/// installed distributions
+///
+/// Packages installed into the project's `.venv` carry their core metadata in
+/// `site-packages/<name>-<version>.dist-info/METADATA`, which holds the same
+/// `License`, `License-Expression` and `Classifier` fields that PyPI serves.
+/// Reading it avoids a network round trip per package.
+///
+/// Packages that are not installed (or installed at a different version)
+/// return an error, so this adapter is meant to be chained in front of
+/// `PyPiLicenseRepository`.
+#[derive(Clone, Default)]
+pub struct LocalMetadataLicenseRepository {
+    /// `(normalized name, version)` -> path of the `.dist-info` directory
+    dist_infos: Arc<HashMap<(String, String), PathBuf>>,
+    priority: LicensePriority,
+}
+
+impl LocalMetadataLicenseRepository {
+    /// Indexes the `.dist-info` directories of the virtual environment at
+    /// `project_path/.venv`. A missing virtual environment yields an empty index.
+    pub fn new(project_path: &Path) -> Self {
+        let venv = project_path.join(".venv");
+        let mut dist_infos = HashMap::new();
+        for site_packages in Self::site_packages_dirs(&venv) {
+            let Ok(entries) = std::fs::read_dir(&site_packages) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let file_name = entry.file_name();
+                let Some(stem) = file_name
+                    .to_str()
+                    .and_then(|n| n.strip_suffix(".dist-info"))
+                else {
+                    continue;
+                };
+                // Directory names are `{name}-{version}`; names never contain `-`
+                // there because installers escape it to `_`
+                if let Some((name, version)) = stem.rsplit_once('-') {
+                    dist_infos.insert(
+                        (PackageName::normalize(name), version.to_string()),
+                        entry.path(),
+                    );
+                }
+            }
+        }
+        Self {
+            dist_infos: Arc::new(dist_infos),
+            priority: LicensePriority::default(),
+        }
+    }
+
+    /// Sets which of the License, License-Expression and Classifier fields wins
+    pub fn with_license_priority(mut self, priority: LicensePriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the candidate site-packages directories of a virtual environment:
+    /// `lib/python3.X/site-packages` on Unix and `Lib/site-packages` on Windows.
+    fn site_packages_dirs(venv: &Path) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = std::fs::read_dir(venv.join("lib"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path().join("site-packages"))
+            .filter(|path| path.is_dir())
+            .collect();
+        let windows = venv.join("Lib").join("site-packages");
+        if windows.is_dir() && !dirs.contains(&windows) {
+            dirs.push(windows);
+        }
+        dirs
+    }
+
+    /// Parses the header section of a core metadata file.
+    ///
+    /// Headers end at the first blank line (the long description follows).
+    /// Continuation lines start with whitespace and are appended to the
+    /// previous header's value.
+    fn parse_metadata(content: &str) -> std::result::Result<PyPiMetadata, String> {
+        let mut headers: Vec<(String, String)> = Vec::new();
+        for line in content.lines() {
+            if line.is_empty() {
+                break;
+            }
+            if line.starts_with([' ', '\t']) {
+                let Some((_, value)) = headers.last_mut() else {
+                    return Err("continuation line before the first header".to_string());
+                };
+                value.push('\n');
+                value.push_str(line.trim());
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(format!("invalid header line {:?}", line));
+            };
+            headers.push((key.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+
+        if !headers.iter().any(|(key, _)| key == "name") {
+            return Err("missing Name header".to_string());
+        }
+
+        let field = |name: &str| {
+            headers
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .filter(|value| !value.is_empty() && value != "UNKNOWN")
+        };
+        let classifiers = headers
+            .iter()
+            .filter(|(key, _)| key == "classifier")
+            .map(|(_, value)| value.clone())
+            .collect();
+        let home_page = field("home-page");
+        let links = PackageLinks::new(
+            home_page.as_deref(),
+            headers
+                .iter()
+                .filter(|(key, _)| key == "project-url")
+                .filter_map(|(_, value)| value.split_once(','))
+                .map(|(label, url)| (label.trim(), url.trim())),
+        );
+
+        Ok(PyPiMetadata {
+            license: field("license"),
+            license_expression: field("license-expression"),
+            classifiers,
+            description: field("summary"),
+            authorship: PackageAuthorship::new(
+                field("author"),
+                field("author-email"),
+                field("maintainer"),
+            ),
+            links,
+            ..Default::default()
+        })
+    }
+}
+
+#[async_trait]
+impl LicenseRepository for LocalMetadataLicenseRepository {
+    async fn fetch_license_info(&self, package_name: &str, version: &str) -> Result<PyPiMetadata> {
+        let key = (PackageName::normalize(package_name), version.to_string());
+        let Some(dist_info) = self.dist_infos.get(&key) else {
+            return Err(UvSbomError::other(format!(
+                "{} {} is not installed in the project's virtual environment",
+                package_name, version
+            )));
+        };
+
+        let metadata_path = dist_info.join("METADATA");
+        let content = read_file_with_security(&metadata_path, "METADATA", MAX_FILE_SIZE)?;
+        Self::parse_metadata(&content).map_err(|reason| {
+            UvSbomError::other(format!(
+                "Malformed METADATA for {} {} ({}): {}",
+                package_name,
+                version,
+                metadata_path.display(),
+                reason
+            ))
+        })
+    }
+
+    fn license_priority(&self) -> LicensePriority {
+        self.priority
+    }
+
+    async fn enrich_with_license(&self, package_name: &str, version: &str) -> Result<LicenseInfo> {
+        let metadata = self.fetch_license_info(package_name, version).await?;
+
+        let info = self
+            .priority
+            .license_info(
+                metadata.license,
+                metadata.license_expression,
+                &metadata.classifiers,
+                metadata.description,
+            )
+            .with_authorship(metadata.authorship)
+            .with_links(metadata.links);
+        Ok(match info.license_text() {
+            Some(_) => info.with_source(LicenseSource::LocalMetadata),
+            None => info,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn install(project: &Path, dist_info: &str, metadata: &str) {
+        let dir = project
+            .join(".venv/lib/python3.12/site-packages")
+            .join(dist_info);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("METADATA"), metadata).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_from_installed_package() {
+        let temp_dir = TempDir::new().unwrap();
+        install(
+            temp_dir.path(),
+            "typing_extensions-4.8.0.dist-info",
+            "Metadata-Version: 2.1\n\
+             Name: typing_extensions\n\
+             Version: 4.8.0\n\
+             Summary: Backported type hints\n\
+             License-Expression: PSF-2.0\n\
+             Classifier: Programming Language :: Python :: 3\n\
+             Classifier: License :: OSI Approved :: Python Software Foundation License\n\
+             \n\
+             License: this line is part of the description\n",
+        );
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+        let PyPiMetadata {
+            license,
+            license_expression: expression,
+            classifiers,
+            description: summary,
+            sha256_hash: sha256,
+            yanked,
+            ..
+        } = repo
+            .fetch_license_info("typing-extensions", "4.8.0")
+            .await
+            .unwrap();
+
+        assert_eq!(license, None);
+        assert_eq!(expression.as_deref(), Some("PSF-2.0"));
+        assert_eq!(classifiers.len(), 2);
+        assert_eq!(summary.as_deref(), Some("Backported type hints"));
+        assert_eq!(sha256, None);
+        assert_eq!(yanked, None);
+    }
+
+    #[tokio::test]
+    async fn test_enrich_with_license_records_local_source() {
+        let temp_dir = TempDir::new().unwrap();
+        install(
+            temp_dir.path(),
+            "foo-1.0.dist-info",
+            "Metadata-Version: 2.1\nName: foo\nVersion: 1.0\nLicense: MIT\n",
+        );
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+        let info = repo.enrich_with_license("foo", "1.0").await.unwrap();
+
+        assert_eq!(info.license_text(), Some("MIT"));
+        assert_eq!(info.source(), LicenseSource::LocalMetadata);
+    }
+
+    #[tokio::test]
+    async fn test_enrich_with_license_reads_author_and_maintainer() {
+        let temp_dir = TempDir::new().unwrap();
+        install(
+            temp_dir.path(),
+            "foo-1.0.dist-info",
+            "Metadata-Version: 2.1\nName: foo\nVersion: 1.0\nAuthor: UNKNOWN\n\
+             Author-email: Jane Doe <jane@example.com>\nMaintainer: Foo Team\n",
+        );
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+        let info = repo.enrich_with_license("foo", "1.0").await.unwrap();
+
+        assert_eq!(
+            info.authorship().author(),
+            Some("Jane Doe <jane@example.com>")
+        );
+        assert_eq!(info.authorship().maintainer(), Some("Foo Team"));
+    }
+
+    #[tokio::test]
+    async fn test_enrich_with_license_reads_project_urls() {
+        let temp_dir = TempDir::new().unwrap();
+        install(
+            temp_dir.path(),
+            "foo-1.0.dist-info",
+            "Metadata-Version: 2.1\nName: foo\nVersion: 1.0\n\
+             Project-URL: Source Code, https://github.com/example/foo\n\
+             Project-URL: Homepage, https://foo.example.com\n",
+        );
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+        let info = repo.enrich_with_license("foo", "1.0").await.unwrap();
+
+        let refs: Vec<(&str, &str)> = info
+            .links()
+            .refs()
+            .iter()
+            .map(|(ref_type, url)| (ref_type.as_str(), url.as_str()))
+            .collect();
+        assert_eq!(
+            refs,
+            [
+                ("website", "https://foo.example.com"),
+                ("vcs", "https://github.com/example/foo"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_joins_continuation_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        install(
+            temp_dir.path(),
+            "foo-1.0.dist-info",
+            "Metadata-Version: 2.1\nName: foo\nVersion: 1.0\nLicense: Copyright (c) Foo\n        All rights reserved.\n",
+        );
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+        let license = repo.fetch_license_info("foo", "1.0").await.unwrap().license;
+
+        assert_eq!(
+            license.as_deref(),
+            Some("Copyright (c) Foo\nAll rights reserved.")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_missing_package_is_error() {
+        let temp_dir = TempDir::new().unwrap();
+        install(
+            temp_dir.path(),
+            "requests-2.31.0.dist-info",
+            "Metadata-Version: 2.1\nName: requests\nVersion: 2.31.0\nLicense: Apache 2.0\n",
+        );
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+
+        // Different version installed
+        let err = repo
+            .fetch_license_info("requests", "2.32.0")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not installed"));
+        // Not installed at all
+        assert!(repo.fetch_license_info("urllib3", "2.0.0").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_without_venv_is_error() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+
+        assert!(repo.fetch_license_info("requests", "2.31.0").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_malformed_metadata_is_error() {
+        let temp_dir = TempDir::new().unwrap();
+        install(
+            temp_dir.path(),
+            "broken-1.0.dist-info",
+            "this is not a metadata file\n",
+        );
+
+        let repo = LocalMetadataLicenseRepository::new(temp_dir.path());
+        let err = repo.fetch_license_info("broken", "1.0").await.unwrap_err();
+
+        assert!(err.to_string().contains("Malformed METADATA"));
+    }
+}
@@ -1,6 +1,24 @@
 /// Filesystem adapters for file I/O operations
+mod any_lockfile_reader;
+mod cve_baseline_file;
+mod cyclonedx_reader;
 mod file_reader;
 mod file_writer;
+mod local_metadata_repository;
+mod pylock_reader;
+mod pypa_advisory_db_repository;
+mod requirements_txt_reader;
+mod stdin_lockfile_reader;
 
+pub use any_lockfile_reader::AnyLockfileReader;
+pub use cve_baseline_file::CveBaselineFile;
+pub use cyclonedx_reader::CycloneDxReader;
 pub use file_reader::FileSystemReader;
-pub use file_writer::{FileSystemWriter, StdoutPresenter};
+pub use file_writer::{is_output_closed, FileSystemWriter, StdoutPresenter};
+pub use local_metadata_repository::LocalMetadataLicenseRepository;
+#[allow(unused_imports)]
+pub use pylock_reader::PylockReader;
+pub use pypa_advisory_db_repository::PypaAdvisoryDbRepository;
+#[allow(unused_imports)]
+pub use requirements_txt_reader::RequirementsTxtReader;
+pub use stdin_lockfile_reader::{StdinLockfileReader, STDIN_LOCKFILE};
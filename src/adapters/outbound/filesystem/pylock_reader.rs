@@ -0,0 +1,356 @@
+use crate::ports::outbound::{LockfileParseResult, LockfileReader, LockfileVersion};
+use crate::sbom_generation::domain::Package;
+use crate::shared::error::UvSbomError;
+use crate::shared::security::{
+    check_lockfile_size, read_file_with_security, DEFAULT_MAX_LOCKFILE_SIZE,
+};
+use crate::shared::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Default file name of a PEP 751 lockfile
+pub const PYLOCK_FILENAME: &str = "pylock.toml";
+
+/// Newest major `lock-version` this reader understands
+const SUPPORTED_LOCK_VERSION: u32 = 1;
+
+#[derive(Debug, Deserialize)]
+struct Pylock {
+    #[serde(rename = "lock-version")]
+    lock_version: String,
+    #[serde(rename = "requires-python")]
+    requires_python: Option<String>,
+    packages: Vec<PylockPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PylockPackage {
+    name: String,
+    /// Optional in PEP 751 for directory and VCS sources
+    version: Option<String>,
+    /// Environment marker limiting where the package is installed
+    marker: Option<String>,
+    #[serde(rename = "requires-python")]
+    requires_python: Option<String>,
+    #[serde(default)]
+    dependencies: Vec<PylockDependency>,
+    sdist: Option<PylockArtifact>,
+    #[serde(default)]
+    wheels: Vec<PylockArtifact>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PylockDependency {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PylockArtifact {
+    #[serde(default)]
+    hashes: HashMap<String, String>,
+}
+
+/// PylockReader adapter for reading PEP 751 `pylock.toml` lockfiles
+///
+/// Produces the same `LockfileParseResult` as the `uv.lock` reader:
+/// `[[packages]]` entries become packages, their `dependencies` references
+/// form the dependency map, and the sdist (or first wheel) SHA-256 becomes
+/// the package hash. A package's `marker` is kept on the package. Packages
+/// without a `version` (local directories, VCS checkouts) only contribute
+/// their dependencies.
+pub struct PylockReader {
+    /// Explicit lockfile location; `None` means `pylock.toml` in the project directory
+    lockfile_path: Option<PathBuf>,
+    /// Largest lockfile, in bytes, that is read
+    max_lockfile_size: u64,
+}
+
+impl PylockReader {
+    pub fn new() -> Self {
+        Self {
+            lockfile_path: None,
+            max_lockfile_size: DEFAULT_MAX_LOCKFILE_SIZE,
+        }
+    }
+
+    /// Reads the lockfile from `path` instead of `pylock.toml` in the project directory
+    pub fn with_lockfile_path(mut self, path: PathBuf) -> Self {
+        self.lockfile_path = Some(path);
+        self
+    }
+
+    /// Rejects lockfiles larger than `bytes` instead of the default 50 MB
+    pub fn with_max_lockfile_size(mut self, bytes: u64) -> Self {
+        self.max_lockfile_size = bytes;
+        self
+    }
+
+    fn lockfile_path(&self, project_path: &Path) -> PathBuf {
+        self.lockfile_path
+            .clone()
+            .unwrap_or_else(|| project_path.join(PYLOCK_FILENAME))
+    }
+
+    fn parse_error(&self, project_path: &Path, details: String) -> UvSbomError {
+        UvSbomError::LockfileParseError {
+            path: self.lockfile_path(project_path),
+            details,
+        }
+    }
+
+    fn parse_lockfile_content(
+        &self,
+        content: &str,
+        project_path: &Path,
+    ) -> Result<LockfileParseResult> {
+        let lockfile: Pylock =
+            toml::from_str(content).map_err(|e| self.parse_error(project_path, e.to_string()))?;
+
+        // Newer majors are parsed as far as possible; the caller decides
+        // whether to accept them
+        let version = LockfileVersion::new(lockfile.lock_version, SUPPORTED_LOCK_VERSION);
+        if version
+            .major()
+            .is_none_or(|major| major < SUPPORTED_LOCK_VERSION)
+        {
+            return Err(self.parse_error(
+                project_path,
+                format!(
+                    "unsupported lock-version \"{}\" (expected {}.x)",
+                    version.version, SUPPORTED_LOCK_VERSION
+                ),
+            ));
+        }
+
+        let mut packages = Vec::new();
+        let mut dependency_map = HashMap::new();
+
+        for pkg in lockfile.packages {
+            if let Some(version) = &pkg.version {
+                let sha256_hash = pkg
+                    .sdist
+                    .iter()
+                    .chain(pkg.wheels.first())
+                    .find_map(|artifact| artifact.hashes.get("sha256").cloned());
+                packages.push(
+                    Package::new(pkg.name.clone(), version.clone())?
+                        .with_sha256_hash(sha256_hash)
+                        .with_marker(pkg.marker.clone())
+                        .with_requires_python(pkg.requires_python.clone()),
+                );
+            }
+
+            // Entries for the same name at several versions share their edges
+            let edges: &mut Vec<String> = dependency_map.entry(pkg.name).or_default();
+            for dep in pkg.dependencies.into_iter().map(|d| d.name) {
+                if !edges.contains(&dep) {
+                    edges.push(dep);
+                }
+            }
+        }
+
+        Ok(LockfileParseResult {
+            packages,
+            dependency_map,
+            version: Some(version),
+            requires_python: lockfile.requires_python,
+            ..Default::default()
+        })
+    }
+}
+
+impl Default for PylockReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LockfileReader for PylockReader {
+    fn read_lockfile(&self, project_path: &Path) -> Result<String> {
+        let lockfile_path = self.lockfile_path(project_path);
+
+        if !lockfile_path.exists() {
+            return Err(UvSbomError::LockfileNotFound {
+                path: lockfile_path.clone(),
+                suggestion: format!(
+                    "{} does not exist. Export one with `uv export --format pylock.toml -o {}`, or point --lockfile at an existing file.",
+                    lockfile_path.display(),
+                    PYLOCK_FILENAME
+                ),
+            });
+        }
+
+        check_lockfile_size(&lockfile_path, self.max_lockfile_size)?;
+
+        read_file_with_security(&lockfile_path, PYLOCK_FILENAME, self.max_lockfile_size).map_err(
+            |e| UvSbomError::LockfileParseError {
+                path: lockfile_path,
+                details: e.to_string(),
+            },
+        )
+    }
+
+    fn read_and_parse_lockfile(&self, project_path: &Path) -> Result<LockfileParseResult> {
+        let content = self.read_lockfile(project_path)?;
+        self.parse_lockfile_content(&content, project_path)
+    }
+
+    fn read_and_parse_lockfile_for_member(
+        &self,
+        _project_path: &Path,
+        member_name: &str,
+    ) -> Result<LockfileParseResult> {
+        Err(UvSbomError::other(format!(
+            "Cannot scope {} to workspace member '{}': workspace mode requires uv.lock",
+            PYLOCK_FILENAME, member_name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const PYLOCK: &str = r#"
+lock-version = "1.0"
+created-by = "uv"
+requires-python = ">=3.12"
+
+[[packages]]
+name = "my-project"
+directory = { path = ".", editable = true }
+dependencies = [{ name = "requests" }]
+
+[[packages]]
+name = "requests"
+version = "2.31.0"
+index = "https://pypi.org/simple"
+dependencies = [{ name = "idna" }, { name = "urllib3" }]
+sdist = { url = "https://files.pythonhosted.org/requests-2.31.0.tar.gz", hashes = { sha256 = "aaaa" } }
+wheels = [{ url = "https://files.pythonhosted.org/requests-2.31.0-py3-none-any.whl", hashes = { sha256 = "bbbb" } }]
+
+[[packages]]
+name = "idna"
+version = "3.6"
+wheels = [{ url = "https://files.pythonhosted.org/idna-3.6-py3-none-any.whl", hashes = { sha256 = "cccc" } }]
+
+[[packages]]
+name = "urllib3"
+version = "2.1.0"
+marker = "python_version >= '3.8'"
+"#;
+
+    fn write_pylock(dir: &Path, content: &str) {
+        fs::write(dir.join(PYLOCK_FILENAME), content).unwrap();
+    }
+
+    #[test]
+    fn test_read_and_parse_lockfile() {
+        let temp_dir = TempDir::new().unwrap();
+        write_pylock(temp_dir.path(), PYLOCK);
+
+        let result = PylockReader::new()
+            .read_and_parse_lockfile(temp_dir.path())
+            .unwrap();
+        let (packages, dependency_map) = (&result.packages, &result.dependency_map);
+
+        let summary: Vec<(&str, &str, Option<&str>)> = packages
+            .iter()
+            .map(|p| (p.name(), p.version(), p.sha256_hash()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("requests", "2.31.0", Some("aaaa")),
+                ("idna", "3.6", Some("cccc")),
+                ("urllib3", "2.1.0", None),
+            ]
+        );
+        assert_eq!(packages[2].marker(), Some("python_version >= '3.8'"));
+        assert_eq!(packages[0].marker(), None);
+        assert_eq!(dependency_map["my-project"], ["requests"]);
+        assert_eq!(dependency_map["requests"], ["idna", "urllib3"]);
+        assert!(dependency_map["urllib3"].is_empty());
+        assert_eq!(result.version, Some(LockfileVersion::new("1.0", 1)));
+        assert_eq!(result.requires_python.as_deref(), Some(">=3.12"));
+    }
+
+    #[test]
+    fn test_read_from_explicit_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pylock.prod.toml");
+        fs::write(&path, PYLOCK).unwrap();
+
+        let result = PylockReader::new()
+            .with_lockfile_path(path)
+            .read_and_parse_lockfile(temp_dir.path())
+            .unwrap();
+
+        assert_eq!(result.packages.len(), 3);
+    }
+
+    #[test]
+    fn test_missing_lockfile_is_not_found_error() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let err = PylockReader::new()
+            .read_and_parse_lockfile(temp_dir.path())
+            .unwrap_err();
+
+        assert!(matches!(err, UvSbomError::LockfileNotFound { .. }));
+    }
+
+    #[test]
+    fn test_malformed_lockfile_is_parse_error() {
+        for content in [
+            "this is = = not toml",
+            "lock-version = \"1.0\"\n[[packages]]\nversion = \"1.0\"\n",
+            "lock-version = \"0.9\"\npackages = []\n",
+        ] {
+            let temp_dir = TempDir::new().unwrap();
+            write_pylock(temp_dir.path(), content);
+
+            let err = PylockReader::new()
+                .read_and_parse_lockfile(temp_dir.path())
+                .unwrap_err();
+
+            match err {
+                UvSbomError::LockfileParseError { path, .. } => {
+                    assert_eq!(path, temp_dir.path().join(PYLOCK_FILENAME));
+                }
+                other => panic!("expected LockfileParseError, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_newer_lock_version_is_parsed_best_effort() {
+        let temp_dir = TempDir::new().unwrap();
+        write_pylock(
+            temp_dir.path(),
+            &PYLOCK.replace("lock-version = \"1.0\"", "lock-version = \"2.0\""),
+        );
+
+        let result = PylockReader::new()
+            .read_and_parse_lockfile(temp_dir.path())
+            .unwrap();
+
+        assert_eq!(result.packages.len(), 3);
+        assert!(result.version.unwrap().is_newer_than_supported());
+    }
+
+    #[test]
+    fn test_member_scoping_is_unsupported() {
+        let temp_dir = TempDir::new().unwrap();
+        write_pylock(temp_dir.path(), PYLOCK);
+
+        let err = PylockReader::new()
+            .read_and_parse_lockfile_for_member(temp_dir.path(), "my-project")
+            .unwrap_err();
+
+        assert!(err.to_string().contains("requires uv.lock"));
+    }
+}
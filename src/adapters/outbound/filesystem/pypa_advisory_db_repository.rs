@@ -0,0 +1,311 @@
+use crate::adapters::outbound::network::OsvVulnerability;
+use crate::ports::outbound::{VulnerabilityFetchOutcome, VulnerabilityRepository};
+use crate::sbom_generation::domain::services::AliasDeduplicator;
+use crate::sbom_generation::domain::vulnerability::{CvssPreference, PackageVulnerabilities};
+use crate::sbom_generation::domain::{Package, PackageName};
+use crate::shared::error::UvSbomError;
+use crate::shared::security::read_file_with_security;
+use crate::shared::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Largest advisory file read; advisories are a few kilobytes
+const MAX_ADVISORY_SIZE: u64 = 1024 * 1024;
+
+/// PypaAdvisoryDbRepository adapter for a local copy of the PyPA advisory
+/// database (https://github.com/pypa/advisory-database)
+///
+/// Every `.yaml`, `.yml` and `.json` file under the directory is read once,
+/// when the repository is opened, as an advisory in the OSV schema. Packages
+/// are matched by PEP 503 normalized name and their version is checked
+/// against each advisory's affected versions and `ECOSYSTEM` ranges, so no
+/// network access is needed. Withdrawn advisories are ignored, and hidden
+/// directories such as `.git` are skipped.
+#[derive(Clone)]
+pub struct PypaAdvisoryDbRepository {
+    /// Advisories keyed by the normalized name of each package they affect
+    advisories: Arc<HashMap<String, Vec<Arc<OsvVulnerability>>>>,
+    cvss_preference: CvssPreference,
+}
+
+impl PypaAdvisoryDbRepository {
+    /// Reads every advisory under `dir`
+    ///
+    /// # Errors
+    /// Returns `AdvisoryDbError` when `dir` is not a directory or an advisory
+    /// cannot be read or parsed.
+    pub fn open(dir: &Path) -> Result<Self> {
+        if !dir.is_dir() {
+            return Err(UvSbomError::AdvisoryDbError {
+                path: dir.to_path_buf(),
+                details: "not a directory".to_string(),
+            });
+        }
+
+        let mut files = Vec::new();
+        collect_advisory_files(dir, &mut files)?;
+        files.sort();
+
+        let mut advisories: HashMap<String, Vec<Arc<OsvVulnerability>>> = HashMap::new();
+        for path in files {
+            let advisory = Arc::new(parse_advisory(&path)?);
+            if advisory.is_withdrawn() {
+                continue;
+            }
+            for name in advisory.pypi_package_names() {
+                let entries = advisories.entry(name).or_default();
+                if !entries.iter().any(|a| a.id() == advisory.id()) {
+                    entries.push(Arc::clone(&advisory));
+                }
+            }
+        }
+
+        Ok(Self {
+            advisories: Arc::new(advisories),
+            cvss_preference: CvssPreference::default(),
+        })
+    }
+
+    /// Sets which CVSS version is scored when an advisory carries both v3 and v4
+    pub fn with_cvss_preference(mut self, preference: CvssPreference) -> Self {
+        self.cvss_preference = preference;
+        self
+    }
+}
+
+#[async_trait]
+impl VulnerabilityRepository for PypaAdvisoryDbRepository {
+    async fn fetch_vulnerabilities(
+        &self,
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
+        let results = packages
+            .iter()
+            .filter_map(|package| {
+                let advisories = self
+                    .advisories
+                    .get(&PackageName::normalize(package.name()))?;
+                let mut vulnerabilities: Vec<_> = advisories
+                    .iter()
+                    .filter(|a| a.affects(package.name(), package.version()))
+                    .filter_map(|a| a.to_vulnerability(self.cvss_preference).ok())
+                    .collect();
+                if vulnerabilities.is_empty() {
+                    return None;
+                }
+                vulnerabilities.sort_by(|a, b| a.id().cmp(b.id()));
+                Some(PackageVulnerabilities::new(
+                    package.name().to_string(),
+                    package.version().to_string(),
+                    AliasDeduplicator::apply(vulnerabilities),
+                ))
+            })
+            .collect();
+
+        Ok(VulnerabilityFetchOutcome {
+            results,
+            failed_packages: Vec::new(),
+            incomplete_advisories: Vec::new(),
+            cached_packages: 0,
+        })
+    }
+}
+
+/// Collects the advisory files under `dir`, skipping hidden directories
+fn collect_advisory_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let read_error = |e: std::io::Error| UvSbomError::AdvisoryDbError {
+        path: dir.to_path_buf(),
+        details: e.to_string(),
+    };
+    for entry in fs::read_dir(dir).map_err(read_error)? {
+        let entry = entry.map_err(read_error)?;
+        let path = entry.path();
+        if entry.file_type().map_err(read_error)?.is_dir() {
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                collect_advisory_files(&path, files)?;
+            }
+        } else if matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yaml" | "yml" | "json")
+        ) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Parses one advisory file; JSON is valid YAML, so both go through the YAML parser
+fn parse_advisory(path: &Path) -> Result<OsvVulnerability> {
+    let content = read_file_with_security(path, "advisory", MAX_ADVISORY_SIZE)?;
+    serde_yaml_ng::from_str(&content).map_err(|e| UvSbomError::AdvisoryDbError {
+        path: path.to_path_buf(),
+        details: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::Severity;
+    use tempfile::TempDir;
+
+    /// PYSEC-2023-74: requests >=2.3.0, <2.31.0
+    const REQUESTS_ADVISORY: &str = r#"
+id: PYSEC-2023-74
+details: Unintended leak of Proxy-Authorization header in requests
+aliases:
+- CVE-2023-32681
+- GHSA-j8r2-6x86-q33q
+affected:
+- package:
+    name: requests
+    ecosystem: PyPI
+    purl: pkg:pypi/requests
+  ranges:
+  - type: ECOSYSTEM
+    events:
+    - introduced: 2.3.0
+    - fixed: 2.31.0
+  versions:
+  - 2.3.0
+  - 2.30.0
+"#;
+
+    /// Affects every Jinja2 version up to and including 3.1.2, and 2.10 listed explicitly
+    const JINJA_ADVISORY: &str = r#"{
+  "id": "GHSA-h5c8-rqwp-cp95",
+  "summary": "Jinja vulnerable to HTML attribute injection",
+  "affected": [{
+    "package": {"name": "Jinja2", "ecosystem": "PyPI"},
+    "ranges": [{"type": "ECOSYSTEM", "events": [{"introduced": "0"}, {"last_affected": "3.1.2"}]}],
+    "versions": ["2.10"]
+  }],
+  "database_specific": {"severity": "MODERATE"}
+}"#;
+
+    const WITHDRAWN_ADVISORY: &str = r#"
+id: PYSEC-2099-1
+withdrawn: "2024-01-01T00:00:00Z"
+affected:
+- package:
+    name: requests
+    ecosystem: PyPI
+  ranges:
+  - type: ECOSYSTEM
+    events:
+    - introduced: "0"
+"#;
+
+    fn advisory_db() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let requests = dir.path().join("vulns").join("requests");
+        fs::create_dir_all(&requests).unwrap();
+        fs::write(requests.join("PYSEC-2023-74.yaml"), REQUESTS_ADVISORY).unwrap();
+        fs::write(requests.join("PYSEC-2099-1.yaml"), WITHDRAWN_ADVISORY).unwrap();
+        let jinja = dir.path().join("vulns").join("jinja2");
+        fs::create_dir_all(&jinja).unwrap();
+        fs::write(jinja.join("GHSA-h5c8-rqwp-cp95.json"), JINJA_ADVISORY).unwrap();
+        let git = dir.path().join(".git");
+        fs::create_dir_all(&git).unwrap();
+        fs::write(git.join("broken.yaml"), "not: [an advisory").unwrap();
+        dir
+    }
+
+    fn package(name: &str, version: &str) -> Package {
+        Package::new(name.to_string(), version.to_string()).unwrap()
+    }
+
+    async fn vulnerable(
+        repository: &PypaAdvisoryDbRepository,
+        name: &str,
+        version: &str,
+    ) -> Vec<String> {
+        let outcome = repository
+            .fetch_vulnerabilities(&[package(name, version)])
+            .await
+            .unwrap();
+        outcome
+            .results
+            .iter()
+            .flat_map(|r| r.vulnerabilities().iter().map(|v| v.id().to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_range_boundaries() {
+        let dir = advisory_db();
+        let repository = PypaAdvisoryDbRepository::open(dir.path()).unwrap();
+
+        // introduced is inclusive, fixed is exclusive; aliases collapse to the CVE ID
+        assert_eq!(
+            vulnerable(&repository, "requests", "2.3.0").await,
+            ["CVE-2023-32681"]
+        );
+        assert_eq!(
+            vulnerable(&repository, "requests", "2.30.0").await,
+            ["CVE-2023-32681"]
+        );
+        assert!(vulnerable(&repository, "requests", "2.31.0")
+            .await
+            .is_empty());
+        assert!(vulnerable(&repository, "requests", "2.2.1")
+            .await
+            .is_empty());
+        // last_affected is inclusive
+        assert_eq!(
+            vulnerable(&repository, "jinja2", "3.1.2").await,
+            ["GHSA-h5c8-rqwp-cp95"]
+        );
+        assert!(vulnerable(&repository, "jinja2", "3.1.3").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_matches_normalized_names_and_converts_advisory() {
+        let dir = advisory_db();
+        let repository = PypaAdvisoryDbRepository::open(dir.path()).unwrap();
+
+        let outcome = repository
+            .fetch_vulnerabilities(&[package("Jinja2", "2.10"), package("urllib3", "2.0.0")])
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].package_name(), "Jinja2");
+        let vulnerability = &outcome.results[0].vulnerabilities()[0];
+        assert_eq!(vulnerability.severity(), Severity::Medium);
+        assert!(outcome.failed_packages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_withdrawn_advisory_is_ignored() {
+        let dir = advisory_db();
+        let repository = PypaAdvisoryDbRepository::open(dir.path()).unwrap();
+
+        assert!(vulnerable(&repository, "requests", "1.0.0")
+            .await
+            .is_empty());
+    }
+
+    #[test]
+    fn test_malformed_advisory_names_file() {
+        let dir = advisory_db();
+        let path = dir.path().join("vulns").join("bad.yml");
+        fs::write(&path, "id: [unterminated").unwrap();
+
+        let err = PypaAdvisoryDbRepository::open(dir.path()).err().unwrap();
+
+        assert!(matches!(err, UvSbomError::AdvisoryDbError { path: p, .. } if p == path));
+    }
+
+    #[test]
+    fn test_missing_directory() {
+        let err = PypaAdvisoryDbRepository::open(Path::new("/does/not/exist"))
+            .err()
+            .unwrap();
+
+        assert!(err.to_string().contains("Failed to read advisory database"));
+    }
+}
@@ -0,0 +1,369 @@
+use crate::ports::outbound::{LockfileParseResult, LockfileReader, SkippedLockfileLine};
+use crate::sbom_generation::domain::Package;
+use crate::shared::error::UvSbomError;
+use crate::shared::security::{
+    check_lockfile_size, read_file_with_security, DEFAULT_MAX_LOCKFILE_SIZE,
+};
+use crate::shared::Result;
+use std::path::{Path, PathBuf};
+
+/// Name used for requirements files in messages
+const REQUIREMENTS_FILE_KIND: &str = "requirements.txt";
+
+/// RequirementsTxtReader adapter for pinned requirements files, as written by
+/// `uv export --format requirements-txt`
+///
+/// Every requirement must pin one version with `==`; the first `--hash=sha256:`
+/// option becomes the package hash and the environment marker after `;` is kept
+/// on the package. A requirements file records no dependency edges, so the
+/// dependency map is empty. Editable requirements (`-e .`), direct references
+/// (`name @ git+https://...`), which pin no version, and option lines such as
+/// `--index-url` are skipped and reported in `skipped_lines`.
+pub struct RequirementsTxtReader {
+    lockfile_path: PathBuf,
+    /// Largest file, in bytes, that is read
+    max_lockfile_size: u64,
+}
+
+impl RequirementsTxtReader {
+    pub fn new(lockfile_path: PathBuf) -> Self {
+        Self {
+            lockfile_path,
+            max_lockfile_size: DEFAULT_MAX_LOCKFILE_SIZE,
+        }
+    }
+
+    /// Rejects files larger than `bytes` instead of the default 50 MB
+    pub fn with_max_lockfile_size(mut self, bytes: u64) -> Self {
+        self.max_lockfile_size = bytes;
+        self
+    }
+
+    fn parse_content(&self, content: &str) -> Result<LockfileParseResult> {
+        let mut result = LockfileParseResult::default();
+
+        for (line, text) in logical_lines(content) {
+            if text.is_empty() {
+                continue;
+            }
+            if text.starts_with('-') || is_direct_reference(&text) {
+                result.skipped_lines.push(SkippedLockfileLine {
+                    line,
+                    content: text,
+                });
+                continue;
+            }
+
+            let requirement =
+                parse_requirement(&text).ok_or_else(|| UvSbomError::UnpinnedRequirement {
+                    path: self.lockfile_path.clone(),
+                    line,
+                    requirement: text.clone(),
+                })?;
+            result.packages.push(
+                Package::new(requirement.name, requirement.version)?
+                    .with_sha256_hash(requirement.sha256_hash)
+                    .with_marker(requirement.marker),
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+/// A pinned requirement line
+#[derive(Debug, PartialEq, Eq)]
+struct PinnedRequirement {
+    name: String,
+    version: String,
+    marker: Option<String>,
+    sha256_hash: Option<String>,
+}
+
+/// Joins `\` continuations and strips comments, returning each logical line
+/// with the number of its first physical line
+fn logical_lines(content: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for (index, raw) in content.lines().enumerate() {
+        let text = strip_comment(raw);
+        let (continues, text) = match text.trim_end().strip_suffix('\\') {
+            Some(text) => (true, text),
+            None => (false, text),
+        };
+        let (start, mut joined) = current.take().unwrap_or((index + 1, String::new()));
+        if !joined.is_empty() {
+            joined.push(' ');
+        }
+        joined.push_str(text.trim());
+        if continues {
+            current = Some((start, joined));
+        } else {
+            lines.push((start, joined.trim().to_string()));
+        }
+    }
+    if let Some((start, joined)) = current {
+        lines.push((start, joined.trim().to_string()));
+    }
+    lines
+}
+
+/// Removes a comment, which starts with `#` at the beginning of the line or
+/// after whitespace; a `#` inside a URL fragment is kept
+fn strip_comment(line: &str) -> &str {
+    let mut previous = None;
+    for (index, c) in line.char_indices() {
+        if c == '#' && previous.is_none_or(char::is_whitespace) {
+            return &line[..index];
+        }
+        previous = Some(c);
+    }
+    line
+}
+
+/// Splits `name[extras] rest` into the name and the rest after the extras;
+/// `None` when the extras are not closed
+fn split_name(spec: &str) -> Option<(&str, &str)> {
+    let name_end = spec
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(spec.len());
+    let (name, rest) = spec.split_at(name_end);
+    let rest = rest.trim_start();
+    let rest = match rest.strip_prefix('[') {
+        Some(extras) => extras.split_once(']')?.1.trim_start(),
+        None => rest,
+    };
+    Some((name, rest))
+}
+
+/// Whether the line is a direct reference, `name @ url`
+fn is_direct_reference(text: &str) -> bool {
+    split_name(text).is_some_and(|(name, rest)| !name.is_empty() && rest.starts_with('@'))
+}
+
+/// Parses `name[extras]==version ; marker --hash=sha256:...`; `None` when the
+/// line pins no single version
+fn parse_requirement(text: &str) -> Option<PinnedRequirement> {
+    // Per-requirement options such as --hash follow the specifier and marker
+    let (spec, options) = match text.find(" --") {
+        Some(index) => (&text[..index], &text[index..]),
+        None => (text, ""),
+    };
+    let (spec, marker) = match spec.split_once(';') {
+        Some((spec, marker)) => (spec.trim(), Some(marker.trim()).filter(|m| !m.is_empty())),
+        None => (spec.trim(), None),
+    };
+
+    let (name, rest) = split_name(spec)?;
+    let version = rest
+        .strip_prefix("===")
+        .or_else(|| rest.strip_prefix("=="))?
+        .trim();
+    let pinned = !version.is_empty()
+        && !version
+            .contains(|c: char| c.is_whitespace() || matches!(c, ',' | '*' | '<' | '>' | '!'));
+    if name.is_empty() || !pinned {
+        return None;
+    }
+
+    let mut tokens = options.split_whitespace();
+    let mut sha256_hash = None;
+    while let Some(token) = tokens.next() {
+        let hash = match token.strip_prefix("--hash") {
+            Some("") => tokens.next(),
+            Some(value) => value.strip_prefix('='),
+            None => None,
+        };
+        if let Some(digest) = hash.and_then(|h| h.strip_prefix("sha256:")) {
+            sha256_hash.get_or_insert_with(|| digest.to_string());
+        }
+    }
+
+    Some(PinnedRequirement {
+        name: name.to_string(),
+        version: version.to_string(),
+        marker: marker.map(str::to_string),
+        sha256_hash,
+    })
+}
+
+impl LockfileReader for RequirementsTxtReader {
+    fn read_lockfile(&self, _project_path: &Path) -> Result<String> {
+        let path = &self.lockfile_path;
+
+        if !path.exists() {
+            return Err(UvSbomError::LockfileNotFound {
+                path: path.clone(),
+                suggestion: format!(
+                    "{} does not exist. Export one with `uv export --format requirements-txt -o {}`, or point --lockfile at an existing file.",
+                    path.display(),
+                    REQUIREMENTS_FILE_KIND
+                ),
+            });
+        }
+
+        check_lockfile_size(path, self.max_lockfile_size)?;
+
+        read_file_with_security(path, REQUIREMENTS_FILE_KIND, self.max_lockfile_size).map_err(|e| {
+            UvSbomError::LockfileParseError {
+                path: path.clone(),
+                details: e.to_string(),
+            }
+        })
+    }
+
+    fn read_and_parse_lockfile(&self, project_path: &Path) -> Result<LockfileParseResult> {
+        let content = self.read_lockfile(project_path)?;
+        self.parse_content(&content)
+    }
+
+    fn has_dependency_graph(&self) -> bool {
+        false
+    }
+
+    fn read_and_parse_lockfile_for_member(
+        &self,
+        _project_path: &Path,
+        member_name: &str,
+    ) -> Result<LockfileParseResult> {
+        Err(UvSbomError::other(format!(
+            "Cannot scope {} to workspace member '{}': workspace mode requires uv.lock",
+            REQUIREMENTS_FILE_KIND, member_name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const REQUIREMENTS: &str = r#"# This file was autogenerated by uv via the following command:
+#    uv export --format requirements-txt
+-e .
+--index-url https://pypi.org/simple
+certifi==2024.2.2 \
+    --hash=sha256:aaaa \
+    --hash=sha256:bbbb
+    # via requests
+colorama==0.4.6 ; sys_platform == 'win32' \
+    --hash=sha256:cccc
+requests[socks]==2.31.0
+"#;
+
+    fn read(content: &str) -> Result<LockfileParseResult> {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("requirements.txt");
+        fs::write(&path, content).unwrap();
+        RequirementsTxtReader::new(path).read_and_parse_lockfile(temp_dir.path())
+    }
+
+    #[test]
+    fn test_read_and_parse_lockfile() {
+        let result = read(REQUIREMENTS).unwrap();
+
+        let summary: Vec<(&str, &str, Option<&str>, Option<&str>)> = result
+            .packages
+            .iter()
+            .map(|p| (p.name(), p.version(), p.sha256_hash(), p.marker()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("certifi", "2024.2.2", Some("aaaa"), None),
+                (
+                    "colorama",
+                    "0.4.6",
+                    Some("cccc"),
+                    Some("sys_platform == 'win32'")
+                ),
+                ("requests", "2.31.0", None, None),
+            ]
+        );
+        assert!(result.dependency_map.is_empty());
+        assert!(result.version.is_none());
+        assert_eq!(
+            result.skipped_lines,
+            [
+                SkippedLockfileLine {
+                    line: 3,
+                    content: "-e .".to_string(),
+                },
+                SkippedLockfileLine {
+                    line: 4,
+                    content: "--index-url https://pypi.org/simple".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unpinned_requirements_are_errors() {
+        for (line, expected) in [
+            ("requests>=2.0", "requests>=2.0"),
+            ("requests", "requests"),
+            ("requests==2.*", "requests==2.*"),
+            ("requests==2.31.0,<3", "requests==2.31.0,<3"),
+        ] {
+            let err = read(&format!("certifi==2024.2.2\n{}\n", line)).unwrap_err();
+
+            match err {
+                UvSbomError::UnpinnedRequirement {
+                    line, requirement, ..
+                } => {
+                    assert_eq!(line, 2);
+                    assert_eq!(requirement, expected);
+                }
+                other => panic!("expected UnpinnedRequirement, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_direct_references_are_skipped() {
+        let result = read(
+            "certifi==2024.2.2\n\
+             mylib @ https://example.com/mylib-1.0.tar.gz\n\
+             tool[cli] @ git+https://github.com/example/tool@v1.0 ; python_version >= '3.9'\n",
+        )
+        .unwrap();
+
+        let names: Vec<&str> = result.packages.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["certifi"]);
+        assert_eq!(
+            result.skipped_lines,
+            [
+                SkippedLockfileLine {
+                    line: 2,
+                    content: "mylib @ https://example.com/mylib-1.0.tar.gz".to_string(),
+                },
+                SkippedLockfileLine {
+                    line: 3,
+                    content: "tool[cli] @ git+https://github.com/example/tool@v1.0 ; \
+                              python_version >= '3.9'"
+                        .to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reports_no_dependency_graph() {
+        let reader = RequirementsTxtReader::new(PathBuf::from("requirements.txt"));
+
+        assert!(!reader.has_dependency_graph());
+    }
+
+    #[test]
+    fn test_missing_file_is_not_found_error() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let err = RequirementsTxtReader::new(temp_dir.path().join("requirements.txt"))
+            .read_and_parse_lockfile(temp_dir.path())
+            .unwrap_err();
+
+        assert!(matches!(err, UvSbomError::LockfileNotFound { .. }));
+    }
+}
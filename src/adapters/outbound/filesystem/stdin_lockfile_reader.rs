@@ -0,0 +1,145 @@
+use super::file_reader::FileSystemReader;
+use crate::ports::outbound::{LockfileParseResult, LockfileReader};
+use crate::shared::error::UvSbomError;
+use crate::shared::Result;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Value of `--lockfile` that reads the lockfile from stdin
+pub const STDIN_LOCKFILE: &str = "-";
+
+/// Name used for the lockfile in error messages when it was read from stdin
+const STDIN_DISPLAY_NAME: &str = "<stdin>";
+
+/// StdinLockfileReader adapter for a uv.lock piped through stdin (`--lockfile -`)
+///
+/// The whole input is read once, up front, since stdin cannot be read twice
+/// and the lockfile is needed both for its digest and for parsing. The content
+/// is parsed like a `uv.lock` file; the project directory is only used to
+/// resolve the sources of local packages.
+pub struct StdinLockfileReader {
+    content: String,
+    parser: FileSystemReader,
+}
+
+impl StdinLockfileReader {
+    /// Reads the lockfile from stdin, rejecting input larger than `max_size` bytes
+    pub fn from_stdin(max_size: u64) -> Result<Self> {
+        Self::read_from(io::stdin().lock(), max_size)
+    }
+
+    /// Reads the lockfile from `input`, rejecting input larger than `max_size` bytes
+    pub fn read_from(mut input: impl Read, max_size: u64) -> Result<Self> {
+        let read_error = |e: io::Error| UvSbomError::LockfileParseError {
+            path: PathBuf::from(STDIN_DISPLAY_NAME),
+            details: e.to_string(),
+        };
+
+        let mut bytes = Vec::new();
+        input
+            .by_ref()
+            .take(max_size.saturating_add(1))
+            .read_to_end(&mut bytes)
+            .map_err(read_error)?;
+        if bytes.len() as u64 > max_size {
+            // Count the rest without keeping it, so the error reports the real size
+            let rest = io::copy(&mut input, &mut io::sink()).map_err(read_error)?;
+            return Err(UvSbomError::LockfileTooLarge {
+                path: PathBuf::from(STDIN_DISPLAY_NAME),
+                size: bytes.len() as u64 + rest,
+                limit: max_size,
+            });
+        }
+
+        let content = String::from_utf8(bytes).map_err(|e| UvSbomError::LockfileParseError {
+            path: PathBuf::from(STDIN_DISPLAY_NAME),
+            details: e.to_string(),
+        })?;
+        Ok(Self {
+            content,
+            parser: FileSystemReader::new().with_lockfile_path(PathBuf::from(STDIN_DISPLAY_NAME)),
+        })
+    }
+}
+
+impl LockfileReader for StdinLockfileReader {
+    fn read_lockfile(&self, _project_path: &Path) -> Result<String> {
+        Ok(self.content.clone())
+    }
+
+    fn read_and_parse_lockfile(&self, project_path: &Path) -> Result<LockfileParseResult> {
+        self.parser
+            .parse_lockfile_content(&self.content, project_path)
+    }
+
+    fn read_and_parse_lockfile_for_member(
+        &self,
+        project_path: &Path,
+        member_name: &str,
+    ) -> Result<LockfileParseResult> {
+        self.parser
+            .parse_lockfile_content_for_member(&self.content, project_path, member_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCKFILE: &str = r#"
+version = 1
+
+[[package]]
+name = "my-service"
+version = "0.1.0"
+source = { virtual = "." }
+dependencies = [{ name = "requests" }]
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+    #[test]
+    fn test_read_and_parse_lockfile_from_input() {
+        let reader = StdinLockfileReader::read_from(LOCKFILE.as_bytes(), 1024).unwrap();
+
+        let LockfileParseResult {
+            packages,
+            dependency_map,
+            ..
+        } = reader
+            .read_and_parse_lockfile(Path::new("does-not-exist"))
+            .unwrap();
+
+        let names: Vec<&str> = packages.iter().map(|p| p.name()).collect();
+        assert!(names.contains(&"requests"));
+        assert_eq!(dependency_map["my-service"], ["requests"]);
+        assert_eq!(
+            reader.read_lockfile(Path::new(".")).unwrap(),
+            LOCKFILE.to_string()
+        );
+    }
+
+    #[test]
+    fn test_input_over_size_limit() {
+        let err = StdinLockfileReader::read_from(LOCKFILE.as_bytes(), 16)
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            err,
+            UvSbomError::LockfileTooLarge { size, limit: 16, .. } if size == LOCKFILE.len() as u64
+        ));
+    }
+
+    #[test]
+    fn test_invalid_toml_names_stdin() {
+        let reader = StdinLockfileReader::read_from("[[package".as_bytes(), 1024).unwrap();
+
+        let err = reader.read_and_parse_lockfile(Path::new(".")).unwrap_err();
+
+        assert!(err.to_string().contains("<stdin>"));
+    }
+}
@@ -1,5 +1,9 @@
-use super::super::schema::{Component, Hash, License, LicenseContent};
+use super::super::schema::{
+    Component, ExternalReference, Hash, License, LicenseContent, OrganizationalEntity, Property,
+};
 use crate::application::read_models::{ComponentView, LicenseView};
+use crate::sbom_generation::domain::{LicenseSource, PackageSource};
+use std::collections::HashMap;
 
 /// Build a list of CycloneDX [`Component`] entries from a [`ComponentView`] slice.
 pub(in super::super) fn build_all(components: &[ComponentView]) -> Vec<Component> {
@@ -7,15 +11,12 @@ pub(in super::super) fn build_all(components: &[ComponentView]) -> Vec<Component
         .iter()
         .map(|c| {
             let licenses = c.license.as_ref().map(build_license);
-            let hashes = c.sha256_hash.as_ref().map(|hash| {
-                vec![Hash {
-                    alg: "SHA-256".to_string(),
-                    content: hash.clone(),
-                }]
-            });
+            let hashes = build_hashes(c);
             Component {
                 component_type: "library".to_string(),
                 bom_ref: c.bom_ref.clone(),
+                supplier: build_supplier(c),
+                author: c.author.clone(),
                 group: "pypi".to_string(),
                 name: c.name.clone(),
                 version: c.version.clone(),
@@ -23,16 +24,153 @@ pub(in super::super) fn build_all(components: &[ComponentView]) -> Vec<Component
                 hashes,
                 licenses,
                 purl: c.purl.clone(),
+                external_references: build_external_references(c),
+                properties: build_properties(c),
             }
         })
         .collect()
 }
 
+/// Build the supplier entity; `None` when the metadata names no author or maintainer.
+pub(in super::super) fn build_supplier(component: &ComponentView) -> Option<OrganizationalEntity> {
+    component
+        .supplier
+        .as_ref()
+        .map(|name| OrganizationalEntity { name: name.clone() })
+}
+
+/// Build the SHA-256 hash list; `None` when the component has no hash.
+pub(in super::super) fn build_hashes(component: &ComponentView) -> Option<Vec<Hash>> {
+    component.sha256_hash.as_ref().map(|hash| {
+        vec![Hash {
+            alg: "SHA-256".to_string(),
+            content: hash.clone(),
+        }]
+    })
+}
+
+/// Build the external references: a `vcs` reference for a package installed from
+/// a Git repository, with the locked commit in its comment, then the homepage,
+/// repository and documentation links from the package metadata. A repository
+/// link repeating the Git source is left out. `None` when there are none.
+fn build_external_references(component: &ComponentView) -> Option<Vec<ExternalReference>> {
+    let mut references = Vec::new();
+    if let Some(PackageSource::Git { url, revision }) = &component.source {
+        references.push(ExternalReference {
+            reference_type: "vcs".to_string(),
+            url: url.clone(),
+            comment: revision
+                .as_ref()
+                .map(|revision| format!("revision: {}", revision)),
+        });
+    }
+    for (ref_type, url) in &component.external_refs {
+        if references.iter().any(|r| r.url == *url) {
+            continue;
+        }
+        references.push(ExternalReference {
+            reference_type: ref_type.as_str().to_string(),
+            url: url.clone(),
+            comment: None,
+        });
+    }
+    (!references.is_empty()).then_some(references)
+}
+
+/// Build the component's properties: `uv-sbom:license-override` (and its reason)
+/// when the license was set in the config file, then `uv-sbom:annotation:<key>`
+/// for each annotation, then `uv-sbom:yanked-reason` when the release was yanked
+/// (empty if no reason was given), then `uv-sbom:license-source` when the origin
+/// of the license is known (`not-found` for a package missing from the index), then
+/// `uv-sbom:license-fetch-error` with the error when the license lookup failed,
+/// then `uv-sbom:source-path` for a package from a local
+/// path or virtual source, then `uv-sbom:declared-constraint` for a direct
+/// dependency with a requirement in pyproject.toml.
+///
+/// Returns `None` when the component has none of these.
+fn build_properties(component: &ComponentView) -> Option<Vec<Property>> {
+    let mut properties = Vec::new();
+    if let Some(license_override) = &component.license_override {
+        properties.push(Property {
+            name: "uv-sbom:license-override".to_string(),
+            value: "true".to_string(),
+        });
+        if let Some(reason) = &license_override.reason {
+            properties.push(Property {
+                name: "uv-sbom:license-override:reason".to_string(),
+                value: reason.clone(),
+            });
+        }
+    }
+    properties.extend(component.annotations.iter().map(|(key, value)| Property {
+        name: format!("uv-sbom:annotation:{}", key),
+        value: value.clone(),
+    }));
+    if let Some(reason) = &component.yanked {
+        properties.push(Property {
+            name: "uv-sbom:yanked-reason".to_string(),
+            value: reason.clone(),
+        });
+    }
+    let license_source = match component.license.as_ref() {
+        Some(license) => license.source.clone(),
+        None => component
+            .license_not_found
+            .then(|| LicenseSource::NotFound.as_str().to_string()),
+    };
+    if let Some(source) = license_source {
+        properties.push(Property {
+            name: "uv-sbom:license-source".to_string(),
+            value: source,
+        });
+    }
+    if let Some(error) = &component.license_fetch_error {
+        properties.push(Property {
+            name: "uv-sbom:license-fetch-error".to_string(),
+            value: error.clone(),
+        });
+    }
+    if let Some(PackageSource::Path { path } | PackageSource::Virtual { path }) = &component.source
+    {
+        properties.push(Property {
+            name: "uv-sbom:source-path".to_string(),
+            value: path.clone(),
+        });
+    }
+    if let Some(constraint) = &component.declared_constraint {
+        properties.push(Property {
+            name: "uv-sbom:declared-constraint".to_string(),
+            value: constraint.clone(),
+        });
+    }
+    (!properties.is_empty()).then_some(properties)
+}
+
+/// Add a `uv-sbom:extras` property, listing the requested extras comma-separated,
+/// to each component that is requested with extras (`extras` is keyed by bom-ref).
+pub(in super::super) fn add_extras(
+    components: &mut [Component],
+    extras: &HashMap<String, Vec<String>>,
+) {
+    for component in components {
+        let Some(extras) = extras.get(&component.bom_ref).filter(|e| !e.is_empty()) else {
+            continue;
+        };
+        component
+            .properties
+            .get_or_insert_with(Vec::new)
+            .push(Property {
+                name: "uv-sbom:extras".to_string(),
+                value: extras.join(","),
+            });
+    }
+}
+
 /// Build license from LicenseView.
 ///
 /// When a SPDX license ID is available, outputs `id` only (CycloneDX spec preference).
 /// Falls back to `name` when no SPDX mapping exists.
-fn build_license(license: &LicenseView) -> Vec<License> {
+pub(in super::super) fn build_license(license: &LicenseView) -> Vec<License> {
     vec![License {
         license: if license.spdx_id.is_some() {
             LicenseContent {
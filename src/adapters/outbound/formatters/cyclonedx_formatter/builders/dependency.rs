@@ -6,7 +6,8 @@ use super::super::schema::Dependency;
 ///
 /// Direct dependencies are listed first, each with their transitive children as
 /// `depends_on`. Packages that appear only as transitives (not in
-/// `dep_view.direct`) are appended afterward with their own `depends_on` list.
+/// `dep_view.direct`) are appended afterward, sorted by bom-ref, with their own
+/// `depends_on` list.
 pub(in super::super) fn build(dep_view: &DependencyView) -> Vec<Dependency> {
     let mut dependencies = Vec::new();
 
@@ -23,19 +24,40 @@ pub(in super::super) fn build(dep_view: &DependencyView) -> Vec<Dependency> {
         });
     }
 
-    // Add transitive dependencies that are not direct
-    for (parent_ref, children) in &dep_view.transitive {
-        if !dep_view.direct.contains(parent_ref) {
-            dependencies.push(Dependency {
-                bom_ref: parent_ref.clone(),
-                depends_on: children.clone(),
-            });
-        }
+    // Add transitive dependencies that are not direct; the map has no stable order
+    let mut transitive_only: Vec<(&String, &Vec<String>)> = dep_view
+        .transitive
+        .iter()
+        .filter(|(parent_ref, _)| !dep_view.direct.contains(parent_ref))
+        .collect();
+    transitive_only.sort_by_key(|(parent_ref, _)| *parent_ref);
+    for (parent_ref, children) in transitive_only {
+        dependencies.push(Dependency {
+            bom_ref: parent_ref.clone(),
+            depends_on: children.clone(),
+        });
     }
 
     dependencies
 }
 
+/// Puts the entry of the root application first, depending on every direct
+/// dependency, replacing any entry the project had as a package of its own.
+pub(in super::super) fn add_root(
+    dependencies: &mut Vec<Dependency>,
+    root_ref: &str,
+    dep_view: &DependencyView,
+) {
+    dependencies.retain(|d| d.bom_ref != root_ref);
+    dependencies.insert(
+        0,
+        Dependency {
+            bom_ref: root_ref.to_string(),
+            depends_on: dep_view.direct.clone(),
+        },
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,6 +74,9 @@ mod tests {
         let dep_view = DependencyView {
             direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
             transitive,
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
         };
 
         let result = build(&dep_view);
@@ -76,6 +101,9 @@ mod tests {
         let dep_view = DependencyView {
             direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
             transitive,
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
         };
 
         let result = build(&dep_view);
@@ -91,6 +119,9 @@ mod tests {
         let dep_view = DependencyView {
             direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
             transitive: HashMap::new(),
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
         };
 
         let result = build(&dep_view);
@@ -99,4 +130,32 @@ mod tests {
         assert_eq!(result[0].bom_ref, "pkg:pypi/requests@2.31.0");
         assert!(result[0].depends_on.is_empty());
     }
+
+    #[test]
+    fn test_add_root_depends_on_all_direct_dependencies() {
+        let dep_view = DependencyView {
+            direct: vec!["requests-2.31.0".to_string(), "numpy-1.26.0".to_string()],
+            transitive: HashMap::new(),
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
+        };
+        let mut dependencies = build(&dep_view);
+        dependencies.push(Dependency {
+            bom_ref: "my-project-1.0.0".to_string(),
+            depends_on: Vec::new(),
+        });
+
+        add_root(&mut dependencies, "my-project-1.0.0", &dep_view);
+
+        let refs: Vec<&str> = dependencies.iter().map(|d| d.bom_ref.as_str()).collect();
+        assert_eq!(
+            refs,
+            ["my-project-1.0.0", "requests-2.31.0", "numpy-1.26.0"]
+        );
+        assert_eq!(
+            dependencies[0].depends_on,
+            ["requests-2.31.0", "numpy-1.26.0"]
+        );
+    }
 }
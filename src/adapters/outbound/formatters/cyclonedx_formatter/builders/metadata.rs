@@ -1,16 +1,50 @@
-use crate::application::read_models::SbomMetadataView;
+use crate::application::read_models::{ComponentView, SbomMetadataView};
+use crate::sbom_generation::domain::PackageName;
 
 use super::super::schema::{Metadata, MetadataComponent, Tool};
+use super::{component, property};
 
 /// Build a CycloneDX `Metadata` from an [`SbomMetadataView`].
-pub(in super::super) fn build(metadata: &SbomMetadataView) -> Metadata {
-    let component = metadata.component.as_ref().map(|c| MetadataComponent {
-        component_type: "application".to_string(),
-        bom_ref: format!("{}-{}", c.name, c.version),
-        name: c.name.clone(),
-        version: c.version.clone(),
+///
+/// When the project itself is among `components`, its purl, description, hashes
+/// and license are carried over to the metadata component. A project with a
+/// dynamic version gets a bom-ref of its normalized name alone. The lockfile's
+/// format version and `requires-python` become `uv-sbom:lockfile-*` properties,
+/// followed by the recorded generation options as `uv-sbom:opt:<name>`.
+pub(in super::super) fn build(
+    metadata: &SbomMetadataView,
+    components: &[ComponentView],
+) -> Metadata {
+    let component = metadata.component.as_ref().map(|c| {
+        let normalized = PackageName::normalize(&c.name);
+        let bom_ref = match &c.version {
+            Some(version) => format!("{}-{}", normalized, version),
+            None => normalized,
+        };
+        let project = components.iter().find(|view| view.bom_ref == bom_ref);
+        MetadataComponent {
+            component_type: "application".to_string(),
+            bom_ref,
+            name: c.name.clone(),
+            version: c.version.clone(),
+            description: project.and_then(|p| p.description.clone()),
+            hashes: project.and_then(component::build_hashes),
+            licenses: project
+                .and_then(|p| p.license.as_ref())
+                .map(component::build_license),
+            purl: project.map(|p| p.purl.clone()),
+        }
     });
 
+    let mut properties = property::from_lockfile_format(metadata);
+    properties.extend(
+        metadata
+            .invocation
+            .as_ref()
+            .map(property::from_invocation)
+            .unwrap_or_default(),
+    );
+
     Metadata {
         timestamp: metadata.timestamp.clone(),
         tools: vec![Tool {
@@ -18,5 +52,6 @@ pub(in super::super) fn build(metadata: &SbomMetadataView) -> Metadata {
             version: metadata.tool_version.clone(),
         }],
         component,
+        properties: (!properties.is_empty()).then_some(properties),
     }
 }
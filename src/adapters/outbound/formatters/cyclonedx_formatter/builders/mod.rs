@@ -1,5 +1,6 @@
 pub(super) mod component;
 pub(super) mod dependency;
 pub(super) mod metadata;
+pub(super) mod project;
 pub(super) mod property;
 pub(super) mod vulnerability;
@@ -0,0 +1,169 @@
+use crate::application::read_models::{ComponentView, ProjectView};
+use crate::sbom_generation::domain::PackageName;
+
+use super::super::schema::{Component, Dependency};
+use super::component;
+
+/// Build a top-level `application` component for each source project of an
+/// aggregated SBOM.
+///
+/// As with the metadata component, a project that is among `components` carries
+/// over its purl, description, hashes and license.
+pub(in super::super) fn build_components(
+    projects: &[ProjectView],
+    components: &[ComponentView],
+) -> Vec<Component> {
+    projects
+        .iter()
+        .map(|p| {
+            let bom_ref = p.bom_ref();
+            let package = components.iter().find(|c| c.bom_ref == bom_ref);
+            let purl = package.map(|c| c.purl.clone()).unwrap_or_else(|| {
+                let name = PackageName::normalize(&p.name);
+                match &p.version {
+                    Some(version) => format!("pkg:pypi/{}@{}", name, version),
+                    None => format!("pkg:pypi/{}", name),
+                }
+            });
+            Component {
+                component_type: "application".to_string(),
+                bom_ref,
+                supplier: package.and_then(component::build_supplier),
+                author: package.and_then(|c| c.author.clone()),
+                group: "pypi".to_string(),
+                name: p.name.clone(),
+                version: p.version.clone().unwrap_or_default(),
+                description: package.and_then(|c| c.description.clone()),
+                hashes: package.and_then(component::build_hashes),
+                licenses: package
+                    .and_then(|c| c.license.as_ref())
+                    .map(component::build_license),
+                purl,
+                external_references: None,
+                properties: None,
+            }
+        })
+        .collect()
+}
+
+/// Prepend one dependency entry per project, listing its direct dependencies,
+/// to the entries of the combined dependency graph.
+///
+/// A project can also be a package in another project's graph; its graph entry
+/// is folded into the project entry so that each ref has a single entry.
+pub(in super::super) fn build_dependencies(
+    projects: &[ProjectView],
+    graph: Vec<Dependency>,
+) -> Vec<Dependency> {
+    let mut dependencies: Vec<Dependency> = projects
+        .iter()
+        .map(|p| Dependency {
+            bom_ref: p.bom_ref(),
+            depends_on: p.direct_dependencies.clone(),
+        })
+        .collect();
+    let project_count = dependencies.len();
+
+    for entry in graph {
+        match dependencies[..project_count]
+            .iter_mut()
+            .find(|d| d.bom_ref == entry.bom_ref)
+        {
+            Some(project) => {
+                for child in entry.depends_on {
+                    if !project.depends_on.contains(&child) {
+                        project.depends_on.push(child);
+                    }
+                }
+            }
+            None => dependencies.push(entry),
+        }
+    }
+    dependencies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(name: &str, direct: &[&str]) -> ProjectView {
+        ProjectView {
+            name: name.to_string(),
+            version: Some("1.0.0".to_string()),
+            direct_dependencies: direct.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_build_components_marks_projects_as_applications() {
+        let projects = [project("API", &[]), project("worker", &[])];
+        let components = [ComponentView {
+            bom_ref: "worker-1.0.0".to_string(),
+            name: "worker".to_string(),
+            version: "1.0.0".to_string(),
+            purl: "pkg:pypi/worker@1.0.0".to_string(),
+            license: None,
+            description: Some("Background jobs".to_string()),
+            sha256_hash: None,
+            is_direct_dependency: false,
+            annotations: Default::default(),
+            license_override: None,
+            yanked: None,
+            source: None,
+            declared_constraint: None,
+            license_not_found: false,
+            license_fetch_error: None,
+            python_compat: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
+        }];
+
+        let result = build_components(&projects, &components);
+
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|c| c.component_type == "application"));
+        assert_eq!(result[0].bom_ref, "api-1.0.0");
+        assert_eq!(result[0].purl, "pkg:pypi/api@1.0.0");
+        assert_eq!(result[0].description, None);
+        assert_eq!(result[1].description.as_deref(), Some("Background jobs"));
+    }
+
+    #[test]
+    fn test_build_dependencies_folds_project_entries_from_the_graph() {
+        let projects = [
+            project("api", &["requests-2.31.0", "shared-1.0.0"]),
+            project("shared", &["idna-3.7"]),
+        ];
+        let graph = vec![
+            Dependency {
+                bom_ref: "requests-2.31.0".to_string(),
+                depends_on: vec!["idna-3.7".to_string()],
+            },
+            Dependency {
+                bom_ref: "shared-1.0.0".to_string(),
+                depends_on: vec!["idna-3.7".to_string(), "six-1.16.0".to_string()],
+            },
+        ];
+
+        let result = build_dependencies(&projects, graph);
+
+        let entries: Vec<(&str, Vec<&str>)> = result
+            .iter()
+            .map(|d| {
+                (
+                    d.bom_ref.as_str(),
+                    d.depends_on.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("api-1.0.0", vec!["requests-2.31.0", "shared-1.0.0"]),
+                ("shared-1.0.0", vec!["idna-3.7", "six-1.16.0"]),
+                ("requests-2.31.0", vec!["idna-3.7"]),
+            ]
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use super::super::schema::Property;
 use crate::application::read_models::{
-    LicenseComplianceView, ResolutionGuideView, UpgradeEntryView, UpgradeRecommendationView,
-    VulnerabilityView,
+    InvocationView, LicenseComplianceView, ResolutionGuideView, SbomMetadataView, SbomWarning,
+    UpgradeEntryView, UpgradeRecommendationView, VulnerabilityView,
 };
+use crate::i18n::{Locale, Messages};
 
 /// Build vulnerability [`Property`] entries from a resolution guide and upgrade recommendations.
 ///
@@ -74,6 +75,46 @@ pub(in super::super) fn from_resolution_guide(
     }
 }
 
+/// Build the BOM-level digest of the lockfile the SBOM was generated from.
+pub(in super::super) fn from_lockfile_sha256(digest: &str) -> Property {
+    Property {
+        name: "uv-sbom:lockfile-sha256".to_string(),
+        value: digest.to_string(),
+    }
+}
+
+/// Build metadata [`Property`] entries for the format version and
+/// `requires-python` of the lockfile, leaving out the ones it does not record.
+pub(in super::super) fn from_lockfile_format(metadata: &SbomMetadataView) -> Vec<Property> {
+    [
+        ("uv-sbom:lockfile-version", &metadata.lockfile_version),
+        (
+            "uv-sbom:lockfile-requires-python",
+            &metadata.lockfile_requires_python,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| {
+        value.as_ref().map(|value| Property {
+            name: name.to_string(),
+            value: value.clone(),
+        })
+    })
+    .collect()
+}
+
+/// Build metadata [`Property`] entries for the options the SBOM was generated with.
+pub(in super::super) fn from_invocation(invocation: &InvocationView) -> Vec<Property> {
+    invocation
+        .parameters
+        .iter()
+        .map(|parameter| Property {
+            name: format!("uv-sbom:opt:{}", parameter.name),
+            value: parameter.value.clone(),
+        })
+        .collect()
+}
+
 /// Build BOM-level [`Property`] entries for license compliance information.
 pub(in super::super) fn from_license_compliance(
     compliance: &LicenseComplianceView,
@@ -113,3 +154,28 @@ pub(in super::super) fn from_license_compliance(
 
     props
 }
+
+/// Build the BOM-level count of packages the vulnerability check could not query.
+///
+/// Returns `None` when every package was checked, so a complete check adds nothing.
+pub(in super::super) fn from_unchecked_count(count: usize) -> Option<Property> {
+    (count > 0).then(|| Property {
+        name: "uv-sbom:vulnerability-check:unchecked-count".to_string(),
+        value: count.to_string(),
+    })
+}
+
+/// Build BOM-level [`Property`] entries for warnings raised during generation.
+///
+/// Each warning becomes a `uv-sbom:warning:<kind>` property whose value is the
+/// English message, so the output does not depend on the `--lang` setting.
+pub(in super::super) fn from_warnings(warnings: &[SbomWarning]) -> Vec<Property> {
+    let messages = Messages::for_locale(Locale::En);
+    warnings
+        .iter()
+        .map(|w| Property {
+            name: format!("uv-sbom:warning:{}", w.kind()),
+            value: w.message(messages),
+        })
+        .collect()
+}
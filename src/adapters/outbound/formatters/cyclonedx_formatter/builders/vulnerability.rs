@@ -1,59 +1,152 @@
-use super::super::schema::{Affect, Rating, Vulnerability, VulnerabilitySource};
+use super::super::schema::{
+    Affect, Analysis, Property, Rating, Vulnerability, VulnerabilityReference, VulnerabilitySource,
+};
+use super::super::SpecVersion;
 use super::property;
 use crate::application::read_models::{
-    ResolutionGuideView, UpgradeRecommendationView, VulnerabilityReportView, VulnerabilityView,
+    CvssVersionView, ResolutionGuideView, UpgradeRecommendationView, VulnerabilityReportView,
+    VulnerabilityView,
 };
 
 /// Build a list of CycloneDX [`Vulnerability`] entries from a [`VulnerabilityReportView`].
 ///
 /// Actionable vulnerabilities are listed first, followed by informational ones.
+/// Ignored vulnerabilities come last, each with a VEX `analysis` block.
 pub(in super::super) fn build_all(
     report: &VulnerabilityReportView,
     resolution_guide: Option<&ResolutionGuideView>,
     upgrade_recommendations: Option<&UpgradeRecommendationView>,
+    spec_version: SpecVersion,
 ) -> Vec<Vulnerability> {
     let mut vulnerabilities = Vec::new();
 
     for vuln in &report.actionable {
-        vulnerabilities.push(build(vuln, resolution_guide, upgrade_recommendations));
+        vulnerabilities.push(build(
+            vuln,
+            resolution_guide,
+            upgrade_recommendations,
+            spec_version,
+        ));
     }
 
     for vuln in &report.informational {
-        vulnerabilities.push(build(vuln, resolution_guide, upgrade_recommendations));
+        vulnerabilities.push(build(
+            vuln,
+            resolution_guide,
+            upgrade_recommendations,
+            spec_version,
+        ));
+    }
+
+    for ignored in &report.ignored {
+        vulnerabilities.push(Vulnerability {
+            analysis: Some(Analysis {
+                state: ignored.state.as_str().to_string(),
+                detail: ignored.detail.clone(),
+            }),
+            ..build(&ignored.vulnerability, None, None, spec_version)
+        });
     }
 
     vulnerabilities
 }
 
+/// Build CycloneDX [`Vulnerability`] entries for the project's own published version.
+///
+/// Each entry affects the metadata component and is tagged with a
+/// `uv-sbom:project-self-check` property so consumers can tell it apart
+/// from dependency findings.
+pub(in super::super) fn build_project_self_check(
+    report: &VulnerabilityReportView,
+    spec_version: SpecVersion,
+) -> Vec<Vulnerability> {
+    report
+        .actionable
+        .iter()
+        .chain(report.informational.iter())
+        .map(|vuln| Vulnerability {
+            properties: Some(vec![Property {
+                name: "uv-sbom:project-self-check".to_string(),
+                value: "true".to_string(),
+            }]),
+            ..build(vuln, None, None, spec_version)
+        })
+        .collect()
+}
+
+/// Maps a CVSS version to the CycloneDX `ratings[].method` value.
+///
+/// "CVSSv4" only exists from spec 1.6; older specs get "other" instead.
+fn rating_method(version: CvssVersionView, spec_version: SpecVersion) -> &'static str {
+    match version {
+        CvssVersionView::V2_0 => "CVSSv2",
+        CvssVersionView::V3_0 => "CVSSv3",
+        CvssVersionView::V3_1 => "CVSSv31",
+        CvssVersionView::V4_0 if spec_version.supports_cvss_v4_method() => "CVSSv4",
+        CvssVersionView::V4_0 => "other",
+    }
+}
+
 /// Build a single CycloneDX [`Vulnerability`] entry from a [`VulnerabilityView`].
 fn build(
     vuln: &VulnerabilityView,
     resolution_guide: Option<&ResolutionGuideView>,
     upgrade_recommendations: Option<&UpgradeRecommendationView>,
+    spec_version: SpecVersion,
 ) -> Vulnerability {
     let source = vuln
         .source_url
         .as_ref()
         .map(|url| VulnerabilitySource { url: url.clone() });
 
+    // OSV resolves every alias, whichever database published it
+    let references = (!vuln.aliases.is_empty()).then(|| {
+        vuln.aliases
+            .iter()
+            .map(|alias| VulnerabilityReference {
+                id: alias.clone(),
+                source: VulnerabilitySource {
+                    url: format!("https://osv.dev/vulnerability/{}", alias),
+                },
+            })
+            .collect()
+    });
+
     let ratings = Some(vec![Rating {
         score: vuln.cvss_score,
         severity: vuln.severity.as_str().to_string(),
+        method: vuln
+            .cvss_version
+            .map(|v| rating_method(v, spec_version).to_string()),
         vector: vuln.cvss_vector.clone(),
     }]);
 
-    let properties =
+    let mut properties =
         property::from_resolution_guide(vuln, resolution_guide, upgrade_recommendations);
 
+    // A package excluded from the component list has no bom-ref to point at,
+    // so it is named in a property instead
+    let affects = if vuln.excluded_from_inventory {
+        properties.get_or_insert_with(Vec::new).push(Property {
+            name: "uv-sbom:excluded-from-inventory".to_string(),
+            value: format!("{}@{}", vuln.affected_component_name, vuln.affected_version),
+        });
+        Vec::new()
+    } else {
+        vec![Affect {
+            bom_ref: vuln.affected_component.clone(),
+        }]
+    };
+
     Vulnerability {
         bom_ref: vuln.bom_ref.clone(),
         id: vuln.id.clone(),
         description: vuln.description.clone(),
         source,
+        references,
         ratings,
-        affects: vec![Affect {
-            bom_ref: vuln.affected_component.clone(),
-        }],
+        affects,
+        analysis: None,
         properties,
     }
 }
@@ -1,19 +1,32 @@
 mod builders;
 mod schema;
+mod spec_version;
 use schema::*;
+pub use spec_version::SpecVersion;
 
 use crate::application::read_models::SbomReadModel;
 use crate::ports::outbound::SbomFormatter;
 use crate::shared::Result;
 
-/// CycloneDxFormatter adapter for generating CycloneDX 1.6 JSON format
+/// CycloneDxFormatter adapter for generating CycloneDX JSON format
 ///
 /// This adapter implements the SbomFormatter port for CycloneDX format.
-pub struct CycloneDxFormatter;
+/// Output targets spec 1.6 unless an older [`SpecVersion`] is selected.
+pub struct CycloneDxFormatter {
+    spec_version: SpecVersion,
+}
 
 impl CycloneDxFormatter {
     pub fn new() -> Self {
-        Self
+        Self {
+            spec_version: SpecVersion::default(),
+        }
+    }
+
+    /// Sets the CycloneDX spec version the output conforms to
+    pub fn with_spec_version(mut self, spec_version: SpecVersion) -> Self {
+        self.spec_version = spec_version;
+        self
     }
 }
 
@@ -25,27 +38,80 @@ impl Default for CycloneDxFormatter {
 
 impl SbomFormatter for CycloneDxFormatter {
     fn format(&self, model: &SbomReadModel) -> Result<String> {
-        let properties = model
-            .license_compliance
-            .as_ref()
-            .map(builders::property::from_license_compliance);
+        let mut properties: Vec<Property> = model
+            .metadata
+            .lockfile_sha256
+            .as_deref()
+            .map(builders::property::from_lockfile_sha256)
+            .into_iter()
+            .collect();
+        properties.extend(
+            model
+                .license_compliance
+                .as_ref()
+                .map(builders::property::from_license_compliance)
+                .unwrap_or_default(),
+        );
+        properties.extend(builders::property::from_unchecked_count(
+            model.unchecked_package_count(),
+        ));
+        properties.extend(builders::property::from_warnings(&model.warnings));
+
+        let mut vulnerabilities = model.vulnerabilities.as_ref().map(|v| {
+            builders::vulnerability::build_all(
+                v,
+                model.resolution_guide.as_ref(),
+                model.upgrade_recommendations.as_ref(),
+                self.spec_version,
+            )
+        });
+        if let Some(report) = &model.project_self_check {
+            vulnerabilities.get_or_insert_with(Vec::new).extend(
+                builders::vulnerability::build_project_self_check(report, self.spec_version),
+            );
+        }
+
+        // The project is described by the metadata component; listing it under
+        // components as well would give two elements the same bom-ref
+        let metadata = builders::metadata::build(&model.metadata, &model.components);
+        let mut components = builders::component::build_all(&model.components);
+        if let Some(deps) = &model.dependencies {
+            builders::component::add_extras(&mut components, &deps.extras);
+        }
+        if let Some(project) = &metadata.component {
+            components.retain(|c| c.bom_ref != project.bom_ref);
+        }
+        let mut dependencies = model.dependencies.as_ref().map(|deps| {
+            let mut dependencies = builders::dependency::build(deps);
+            if let Some(project) = &metadata.component {
+                builders::dependency::add_root(&mut dependencies, &project.bom_ref, deps);
+            }
+            dependencies
+        });
+
+        // An aggregated SBOM lists each source project as an application, with
+        // its direct dependencies nested under it
+        if !model.projects.is_empty() {
+            let applications =
+                builders::project::build_components(&model.projects, &model.components);
+            components.retain(|c| !applications.iter().any(|a| a.bom_ref == c.bom_ref));
+            components.splice(0..0, applications);
+            dependencies = Some(builders::project::build_dependencies(
+                &model.projects,
+                dependencies.unwrap_or_default(),
+            ));
+        }
 
         let bom = Bom {
             bom_format: "CycloneDX".to_string(),
-            spec_version: "1.6".to_string(),
+            spec_version: self.spec_version.as_str().to_string(),
             version: 1,
             serial_number: model.metadata.serial_number.clone(),
-            metadata: builders::metadata::build(&model.metadata),
-            components: builders::component::build_all(&model.components),
-            dependencies: model.dependencies.as_ref().map(builders::dependency::build),
-            vulnerabilities: model.vulnerabilities.as_ref().map(|v| {
-                builders::vulnerability::build_all(
-                    v,
-                    model.resolution_guide.as_ref(),
-                    model.upgrade_recommendations.as_ref(),
-                )
-            }),
-            properties,
+            metadata,
+            components,
+            dependencies,
+            vulnerabilities,
+            properties: (!properties.is_empty()).then_some(properties),
         };
 
         serde_json::to_string_pretty(&bom).map_err(Into::into)
@@ -56,9 +122,11 @@ impl SbomFormatter for CycloneDxFormatter {
 mod tests {
     use super::*;
     use crate::application::read_models::{
-        ComponentView, DependencyView, LicenseView, SbomMetadataView, SeverityView,
+        AnalysisStateView, ComponentView, CvssVersionView, DependencyView,
+        IgnoredVulnerabilityView, LicenseOverrideView, LicenseView, SbomMetadataView, SeverityView,
         VulnerabilityReportView, VulnerabilitySummary, VulnerabilityView,
     };
+    use crate::sbom_generation::domain::{ExternalRefType, PackageSource};
     use std::collections::HashMap;
 
     fn create_test_read_model() -> SbomReadModel {
@@ -69,6 +137,11 @@ mod tests {
                 tool_version: "1.0.0".to_string(),
                 serial_number: "urn:uuid:test-123".to_string(),
                 component: None,
+                lockfile_sha256: None,
+                target_environment: None,
+                invocation: None,
+                lockfile_version: None,
+                lockfile_requires_python: None,
             },
             components: vec![
                 ComponentView {
@@ -79,10 +152,22 @@ mod tests {
                     license: Some(LicenseView {
                         spdx_id: Some("Apache-2.0".to_string()),
                         name: "Apache License 2.0".to_string(),
+                        source: None,
                     }),
                     description: Some("HTTP library".to_string()),
                     sha256_hash: None,
                     is_direct_dependency: true,
+                    annotations: Default::default(),
+                    license_override: None,
+                    yanked: None,
+                    source: None,
+                    declared_constraint: None,
+                    license_not_found: false,
+                    license_fetch_error: None,
+                    python_compat: None,
+                    author: None,
+                    supplier: None,
+                    external_refs: Vec::new(),
                 },
                 ComponentView {
                     bom_ref: "pkg:pypi/numpy@1.24.0".to_string(),
@@ -93,13 +178,34 @@ mod tests {
                     description: Some("Array library".to_string()),
                     sha256_hash: None,
                     is_direct_dependency: false,
+                    annotations: Default::default(),
+                    license_override: None,
+                    yanked: None,
+                    source: None,
+                    declared_constraint: None,
+                    license_not_found: false,
+                    license_fetch_error: None,
+                    python_compat: None,
+                    author: None,
+                    supplier: None,
+                    external_refs: Vec::new(),
                 },
             ],
             dependencies: None,
             vulnerabilities: None,
             license_compliance: None,
+            license_compatibility: None,
+            license_risk: None,
+            python_compatibility: None,
+            typosquats: Vec::new(),
             resolution_guide: None,
             upgrade_recommendations: None,
+            fix_suggestions: None,
+            project_self_check: None,
+            warnings: Vec::new(),
+            stats: None,
+            projects: Vec::new(),
+            version_conflicts: Vec::new(),
         }
     }
 
@@ -132,6 +238,9 @@ mod tests {
         model.dependencies = Some(DependencyView {
             direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
             transitive,
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
         });
 
         let formatter = CycloneDxFormatter::new();
@@ -144,6 +253,64 @@ mod tests {
         assert!(json.contains("urllib3"));
     }
 
+    #[test]
+    fn test_format_with_dependencies_adds_project_root_entry() {
+        use crate::application::read_models::MetadataComponentView;
+
+        let mut model = create_test_read_model();
+        model.metadata.component = Some(MetadataComponentView {
+            name: "my-project".to_string(),
+            version: Some("1.0.0".to_string()),
+        });
+        model.dependencies = Some(DependencyView {
+            direct: vec!["requests-2.31.0".to_string(), "numpy-1.24.0".to_string()],
+            transitive: HashMap::new(),
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
+        });
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let bom: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            bom["dependencies"][0],
+            serde_json::json!({
+                "ref": "my-project-1.0.0",
+                "dependsOn": ["requests-2.31.0", "numpy-1.24.0"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_format_with_extras_adds_component_property() {
+        let mut model = create_test_read_model();
+        model.dependencies = Some(DependencyView {
+            direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
+            transitive: HashMap::new(),
+            children: HashMap::new(),
+            extras: HashMap::from([(
+                "pkg:pypi/requests@2.31.0".to_string(),
+                vec!["security".to_string(), "socks".to_string()],
+            )]),
+            unreferenced: Vec::new(),
+        });
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let bom: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let requests = bom["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["name"] == "requests")
+            .unwrap();
+        assert_eq!(
+            requests["properties"],
+            serde_json::json!([{ "name": "uv-sbom:extras", "value": "security,socks" }])
+        );
+    }
+
     #[test]
     fn test_format_with_vulnerabilities() {
         let mut model = create_test_read_model();
@@ -151,21 +318,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: Some("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:N/I:N/A:H".to_string()),
+                cvss_version: Some(CvssVersionView::V3_1),
                 severity: SeverityView::High,
                 fixed_version: Some("2.32.0".to_string()),
                 description: Some("Test vulnerability".to_string()),
                 source_url: Some("https://nvd.nist.gov/vuln/detail/CVE-2024-1234".to_string()),
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
 
         let formatter = CycloneDxFormatter::new();
@@ -177,6 +351,82 @@ mod tests {
         assert!(json.contains("CVE-2024-1234"));
         assert!(json.contains("\"severity\": \"HIGH\""));
         assert!(json.contains("\"score\": 7.5"));
+        assert!(json.contains("\"method\": \"CVSSv31\""));
+        assert!(json.contains("\"url\": \"https://nvd.nist.gov/vuln/detail/CVE-2024-1234\""));
+    }
+
+    #[test]
+    fn test_format_lists_aliases_as_references() {
+        use crate::test_fixtures::SbomReadModelFixture;
+
+        let mut fixture = SbomReadModelFixture::small_clean();
+        fixture
+            .add_vulnerability("CVE-2024-1234")
+            .severity(SeverityView::High)
+            .aliases(&["GHSA-aaaa-bbbb-cccc"])
+            .on("requests", "2.31.0");
+        fixture
+            .add_vulnerability("CVE-2024-5678")
+            .on("urllib3", "1.26.0");
+
+        let json = CycloneDxFormatter::new().format(&fixture.build()).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let vulnerabilities = parsed["vulnerabilities"].as_array().unwrap();
+        assert_eq!(vulnerabilities[0]["id"], "CVE-2024-1234");
+        assert_eq!(
+            vulnerabilities[0]["references"],
+            serde_json::json!([{
+                "id": "GHSA-aaaa-bbbb-cccc",
+                "source": {"url": "https://osv.dev/vulnerability/GHSA-aaaa-bbbb-cccc"}
+            }])
+        );
+        assert!(vulnerabilities[1].get("references").is_none());
+    }
+
+    #[test]
+    fn test_format_with_ignored_vulnerability_adds_analysis() {
+        let mut model = create_test_read_model();
+        model.vulnerabilities = Some(VulnerabilityReportView {
+            actionable: vec![],
+            informational: vec![],
+            ignored: vec![IgnoredVulnerabilityView {
+                vulnerability: VulnerabilityView {
+                    bom_ref: "vuln-002".to_string(),
+                    id: "CVE-2024-5678".to_string(),
+                    aliases: Vec::new(),
+                    affected_component: "pkg:pypi/requests@2.31.0".to_string(),
+                    affected_component_name: "requests".to_string(),
+                    affected_version: "2.31.0".to_string(),
+                    cvss_score: None,
+                    cvss_vector: None,
+                    cvss_version: None,
+                    severity: SeverityView::Medium,
+                    fixed_version: None,
+                    description: None,
+                    source_url: None,
+                    introduced_by: vec![],
+                    excluded_from_inventory: false,
+                    in_baseline: false,
+                },
+                state: AnalysisStateView::FalsePositive,
+                detail: Some("Code path not reachable".to_string()),
+            }],
+            summary: VulnerabilitySummary {
+                total_count: 0,
+                affected_package_count: 0,
+            },
+            ..Default::default()
+        });
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let bom: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let vulns = bom["vulnerabilities"].as_array().unwrap();
+        assert_eq!(vulns.len(), 1);
+        assert_eq!(vulns[0]["id"], "CVE-2024-5678");
+        assert_eq!(vulns[0]["analysis"]["state"], "false_positive");
+        assert_eq!(vulns[0]["analysis"]["detail"], "Code path not reachable");
     }
 
     #[test]
@@ -200,6 +450,7 @@ mod tests {
         model.components[0].license = Some(LicenseView {
             spdx_id: None,
             name: "Some Proprietary License".to_string(),
+            source: None,
         });
         let formatter = CycloneDxFormatter::new();
 
@@ -250,21 +501,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
 
         model.resolution_guide = Some(ResolutionGuideView {
@@ -300,21 +558,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
 
         model.resolution_guide = Some(ResolutionGuideView {
@@ -386,7 +651,7 @@ mod tests {
         let mut model = create_test_read_model();
         model.metadata.component = Some(MetadataComponentView {
             name: "my-project".to_string(),
-            version: "1.0.0".to_string(),
+            version: Some("1.0.0".to_string()),
         });
 
         let formatter = CycloneDxFormatter::new();
@@ -398,6 +663,52 @@ mod tests {
         assert!(json.contains("\"version\": \"1.0.0\""));
     }
 
+    #[test]
+    fn test_format_metadata_component_with_dynamic_version() {
+        use crate::application::read_models::MetadataComponentView;
+
+        let mut model = create_test_read_model();
+        model.metadata.component = Some(MetadataComponentView {
+            name: "My_Project".to_string(),
+            version: None,
+        });
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let project = &parsed["metadata"]["component"];
+        assert_eq!(project["bom-ref"], "my-project");
+        assert_eq!(project["name"], "My_Project");
+        assert!(project.get("version").is_none());
+    }
+
+    #[test]
+    fn test_format_moves_project_component_to_metadata() {
+        use crate::application::read_models::MetadataComponentView;
+
+        let mut model = create_test_read_model();
+        model.metadata.component = Some(MetadataComponentView {
+            name: "requests".to_string(),
+            version: Some("2.31.0".to_string()),
+        });
+        model.components[0].bom_ref = "requests-2.31.0".to_string();
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let project = &parsed["metadata"]["component"];
+        assert_eq!(project["bom-ref"], "requests-2.31.0");
+        assert_eq!(project["purl"], "pkg:pypi/requests@2.31.0");
+        assert_eq!(project["licenses"][0]["license"]["id"], "Apache-2.0");
+        let names: Vec<&str> = parsed["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["numpy"]);
+    }
+
     #[test]
     fn test_format_without_metadata_component() {
         let model = create_test_read_model();
@@ -409,6 +720,61 @@ mod tests {
         assert!(parsed["metadata"]["component"].is_null());
     }
 
+    #[test]
+    fn test_format_lists_aggregated_projects_as_applications() {
+        use crate::application::read_models::ProjectView;
+        use crate::test_fixtures::SbomReadModelFixture;
+
+        let mut fixture = SbomReadModelFixture::small_clean();
+        fixture
+            .add_component("api", "0.3.0")
+            .description("Public API");
+        let mut model = fixture
+            .with_dependencies(&[("requests", &["urllib3"])])
+            .build();
+        model.projects = vec![
+            ProjectView {
+                name: "api".to_string(),
+                version: Some("0.3.0".to_string()),
+                direct_dependencies: vec!["requests-2.31.0".to_string()],
+            },
+            ProjectView {
+                name: "worker".to_string(),
+                version: Some("1.0.0".to_string()),
+                direct_dependencies: vec!["urllib3-1.26.0".to_string()],
+            },
+        ];
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(parsed["metadata"]["component"].is_null());
+        let components: Vec<(&str, &str)> = parsed["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| (c["bom-ref"].as_str().unwrap(), c["type"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            components,
+            [
+                ("api-0.3.0", "application"),
+                ("worker-1.0.0", "application"),
+                ("requests-2.31.0", "library"),
+                ("urllib3-1.26.0", "library"),
+            ]
+        );
+        assert_eq!(parsed["components"][0]["description"], "Public API");
+        assert_eq!(
+            parsed["dependencies"],
+            serde_json::json!([
+                {"ref": "api-0.3.0", "dependsOn": ["requests-2.31.0"]},
+                {"ref": "worker-1.0.0", "dependsOn": ["urllib3-1.26.0"]},
+                {"ref": "requests-2.31.0", "dependsOn": ["urllib3-1.26.0"]},
+            ])
+        );
+    }
+
     #[test]
     fn test_format_vulnerability_no_resolution_guide() {
         let mut model = create_test_read_model();
@@ -416,21 +782,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
 
         let formatter = CycloneDxFormatter::new();
@@ -438,6 +811,8 @@ mod tests {
 
         // No properties field when no resolution guide
         assert!(!json.contains("\"uv-sbom:introduced-by\""));
+        // No rating method when the CVSS version is unknown
+        assert!(!json.contains("\"method\""));
     }
 
     // ============================================================
@@ -453,21 +828,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
         model.upgrade_recommendations = Some(UpgradeRecommendationView {
             entries: vec![UpgradeEntryView::Upgradable {
@@ -497,21 +879,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
         model.upgrade_recommendations = Some(UpgradeRecommendationView {
             entries: vec![UpgradeEntryView::Unresolvable {
@@ -535,21 +924,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
         // upgrade_recommendations is None
 
@@ -559,4 +955,525 @@ mod tests {
         assert!(!json.contains("\"uv-sbom:recommended-action\""));
         assert!(!json.contains("\"uv-sbom:resolved-version\""));
     }
+
+    #[test]
+    fn test_format_with_warnings_adds_bom_properties() {
+        use crate::application::read_models::SbomWarning;
+
+        let mut model = create_test_read_model();
+        model.warnings = vec![SbomWarning::UnmatchedExcludePattern {
+            pattern: "legacy-*".to_string(),
+        }];
+
+        let formatter = CycloneDxFormatter::new();
+        let json = formatter.format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(
+            properties[0]["name"],
+            "uv-sbom:warning:unmatched-exclude-pattern"
+        );
+        assert_eq!(
+            properties[0]["value"],
+            "Exclude pattern 'legacy-*' did not match any dependencies"
+        );
+    }
+
+    #[test]
+    fn test_format_with_unchecked_packages_adds_count_property() {
+        use crate::application::read_models::SbomWarning;
+
+        let mut model = create_test_read_model();
+        model.warnings = vec![SbomWarning::VulnerabilityCheckFailed {
+            package: "requests".to_string(),
+            version: "2.31.0".to_string(),
+            error: "OSV API returned status code 503".to_string(),
+        }];
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(
+            properties[0]["name"],
+            "uv-sbom:vulnerability-check:unchecked-count"
+        );
+        assert_eq!(properties[0]["value"], "1");
+        assert_eq!(
+            properties[1]["name"],
+            "uv-sbom:warning:vulnerability-check-failed"
+        );
+        assert_eq!(
+            properties[1]["value"],
+            "Failed to check requests 2.31.0 for vulnerabilities: OSV API returned status code 503"
+        );
+    }
+
+    #[test]
+    fn test_format_without_warnings_omits_bom_properties() {
+        let model = create_test_read_model();
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(parsed.get("properties").is_none());
+    }
+
+    #[test]
+    fn test_format_with_lockfile_digest_adds_bom_property() {
+        let model = create_test_read_model().with_lockfile_sha256(Some("ab".repeat(32)));
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0]["name"], "uv-sbom:lockfile-sha256");
+        assert_eq!(properties[0]["value"], "ab".repeat(32));
+    }
+
+    #[test]
+    fn test_format_with_lockfile_format_adds_metadata_properties() {
+        let mut model = create_test_read_model();
+        model.metadata.lockfile_version = Some("1".to_string());
+        model.metadata.lockfile_requires_python = Some(">=3.12".to_string());
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["metadata"]["properties"].as_array().unwrap();
+        let pairs: Vec<(&str, &str)> = properties
+            .iter()
+            .map(|p| (p["name"].as_str().unwrap(), p["value"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("uv-sbom:lockfile-version", "1"),
+                ("uv-sbom:lockfile-requires-python", ">=3.12"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_with_annotations_adds_component_properties() {
+        let mut model = create_test_read_model();
+        model.components[0].annotations =
+            [("owner", "team-data"), ("justification", "needed for ETL")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["components"][0]["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0]["name"], "uv-sbom:annotation:justification");
+        assert_eq!(properties[0]["value"], "needed for ETL");
+        assert_eq!(properties[1]["name"], "uv-sbom:annotation:owner");
+        assert_eq!(properties[1]["value"], "team-data");
+    }
+
+    #[test]
+    fn test_format_with_license_override_marks_component() {
+        let mut model = create_test_read_model();
+        model.components[0].license_override = Some(LicenseOverrideView {
+            reason: Some("vendored fork".to_string()),
+        });
+        model.components[0].annotations = [("owner".to_string(), "team-data".to_string())].into();
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["components"][0]["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 3);
+        assert_eq!(properties[0]["name"], "uv-sbom:license-override");
+        assert_eq!(properties[0]["value"], "true");
+        assert_eq!(properties[1]["name"], "uv-sbom:license-override:reason");
+        assert_eq!(properties[1]["value"], "vendored fork");
+        assert_eq!(properties[2]["name"], "uv-sbom:annotation:owner");
+        assert!(parsed["components"][1].get("properties").is_none());
+    }
+
+    #[test]
+    fn test_format_with_license_source_adds_component_property() {
+        let mut model = create_test_read_model();
+        if let Some(license) = model.components[0].license.as_mut() {
+            license.source = Some("pypi-classifier".to_string());
+        }
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["components"][0]["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0]["name"], "uv-sbom:license-source");
+        assert_eq!(properties[0]["value"], "pypi-classifier");
+    }
+
+    #[test]
+    fn test_format_with_package_not_on_index_adds_not_found_source() {
+        let mut model = create_test_read_model();
+        model.components[0].license = None;
+        model.components[0].license_not_found = true;
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["components"][0]["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0]["name"], "uv-sbom:license-source");
+        assert_eq!(properties[0]["value"], "not-found");
+    }
+
+    #[test]
+    fn test_format_with_license_fetch_error_adds_component_property() {
+        let mut model = create_test_read_model();
+        model.components[0].license = None;
+        model.components[0].license_fetch_error = Some("connection reset".to_string());
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["components"][0]["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 1);
+        assert_eq!(properties[0]["name"], "uv-sbom:license-fetch-error");
+        assert_eq!(properties[0]["value"], "connection reset");
+    }
+
+    #[test]
+    fn test_format_with_yanked_release_adds_component_property() {
+        let mut model = create_test_read_model();
+        model.components[0].yanked = Some("Broken wheel".to_string());
+        if let Some(license) = model.components[0].license.as_mut() {
+            license.source = Some("pypi-license-field".to_string());
+        }
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let properties = parsed["components"][0]["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0]["name"], "uv-sbom:yanked-reason");
+        assert_eq!(properties[0]["value"], "Broken wheel");
+        assert_eq!(properties[1]["name"], "uv-sbom:license-source");
+    }
+
+    #[test]
+    fn test_format_with_supplier_adds_author_and_supplier() {
+        let mut model = create_test_read_model();
+        model.components[0].author = Some("Kenneth Reitz".to_string());
+        model.components[0].supplier = Some("Kenneth Reitz".to_string());
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed["components"][0]["author"], "Kenneth Reitz");
+        assert_eq!(parsed["components"][0]["supplier"]["name"], "Kenneth Reitz");
+        let second = parsed["components"][1].as_object().unwrap();
+        assert!(!second.contains_key("author"));
+        assert!(!second.contains_key("supplier"));
+    }
+
+    #[test]
+    fn test_format_with_package_links_adds_external_references() {
+        let mut model = create_test_read_model();
+        model.components[0].external_refs = vec![
+            (
+                ExternalRefType::Website,
+                "https://requests.readthedocs.io".to_string(),
+            ),
+            (
+                ExternalRefType::Vcs,
+                "https://github.com/psf/requests".to_string(),
+            ),
+            (
+                ExternalRefType::Documentation,
+                "https://requests.readthedocs.io/en/latest/".to_string(),
+            ),
+        ];
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            parsed["components"][0]["externalReferences"],
+            serde_json::json!([
+                {"type": "website", "url": "https://requests.readthedocs.io"},
+                {"type": "vcs", "url": "https://github.com/psf/requests"},
+                {"type": "documentation", "url": "https://requests.readthedocs.io/en/latest/"},
+            ])
+        );
+        assert!(parsed["components"][1].get("externalReferences").is_none());
+    }
+
+    #[test]
+    fn test_format_with_git_source_skips_repeated_repository_link() {
+        let mut model = create_test_read_model();
+        model.components[0].source = Some(PackageSource::Git {
+            url: "https://github.com/psf/requests".to_string(),
+            revision: None,
+        });
+        model.components[0].external_refs = vec![(
+            ExternalRefType::Vcs,
+            "https://github.com/psf/requests".to_string(),
+        )];
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            parsed["components"][0]["externalReferences"],
+            serde_json::json!([{"type": "vcs", "url": "https://github.com/psf/requests"}])
+        );
+    }
+
+    #[test]
+    fn test_format_with_package_sources() {
+        let mut model = create_test_read_model();
+        model.components[0].source = Some(PackageSource::Git {
+            url: "https://github.com/psf/requests".to_string(),
+            revision: Some("0e322af87745eff34caffe4df68456ebc20d9068".to_string()),
+        });
+        model.components[1].source = Some(PackageSource::Path {
+            path: "vendor/numpy".to_string(),
+        });
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let git = &parsed["components"][0];
+        assert_eq!(
+            git["externalReferences"],
+            serde_json::json!([{
+                "type": "vcs",
+                "url": "https://github.com/psf/requests",
+                "comment": "revision: 0e322af87745eff34caffe4df68456ebc20d9068",
+            }])
+        );
+        assert!(git.get("properties").is_none());
+
+        let path = &parsed["components"][1];
+        assert!(path.get("externalReferences").is_none());
+        assert_eq!(
+            path["properties"],
+            serde_json::json!([{"name": "uv-sbom:source-path", "value": "vendor/numpy"}])
+        );
+    }
+
+    #[test]
+    fn test_format_with_declared_constraint_adds_component_property() {
+        let mut model = create_test_read_model();
+        model.components[0].declared_constraint =
+            Some("requests[socks]>=2.28,<3; python_version >= \"3.9\"".to_string());
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            parsed["components"][0]["properties"],
+            serde_json::json!([{
+                "name": "uv-sbom:declared-constraint",
+                "value": "requests[socks]>=2.28,<3; python_version >= \"3.9\"",
+            }])
+        );
+        assert!(parsed["components"][1].get("properties").is_none());
+    }
+
+    #[test]
+    fn test_format_without_annotations_omits_component_properties() {
+        let model = create_test_read_model();
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(parsed["components"][0].get("properties").is_none());
+    }
+
+    #[test]
+    fn test_format_with_project_self_check() {
+        let mut model = create_test_read_model();
+        model.project_self_check = Some(VulnerabilityReportView {
+            actionable: vec![VulnerabilityView {
+                bom_ref: "GHSA-self-0001-myproject-1.0.0".to_string(),
+                id: "GHSA-self-0001".to_string(),
+                aliases: Vec::new(),
+                affected_component: "myproject-1.0.0".to_string(),
+                affected_component_name: "myproject".to_string(),
+                affected_version: "1.0.0".to_string(),
+                cvss_score: Some(9.1),
+                cvss_vector: None,
+                cvss_version: None,
+                severity: SeverityView::Critical,
+                fixed_version: Some("1.0.1".to_string()),
+                description: None,
+                source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
+            }],
+            informational: vec![],
+            ignored: vec![],
+            summary: VulnerabilitySummary {
+                total_count: 1,
+                affected_package_count: 1,
+            },
+            ..Default::default()
+        });
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let vulns = parsed["vulnerabilities"].as_array().unwrap();
+        assert_eq!(vulns.len(), 1);
+        assert_eq!(vulns[0]["affects"][0]["ref"], "myproject-1.0.0");
+        assert_eq!(
+            vulns[0]["properties"][0]["name"],
+            "uv-sbom:project-self-check"
+        );
+    }
+
+    #[test]
+    fn test_format_vulnerability_of_excluded_package_names_it_in_a_property() {
+        let mut model = create_cvss_v4_read_model();
+        let report = model.vulnerabilities.as_mut().unwrap();
+        report.ignored.clear();
+        let vuln = &mut report.actionable[0];
+        vuln.affected_component = "internal-lib-1.0.0".to_string();
+        vuln.affected_component_name = "internal-lib".to_string();
+        vuln.affected_version = "1.0.0".to_string();
+        vuln.excluded_from_inventory = true;
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let vulns = parsed["vulnerabilities"].as_array().unwrap();
+        assert!(vulns[0].get("affects").is_none());
+        assert_eq!(
+            vulns[0]["properties"][0]["name"],
+            "uv-sbom:excluded-from-inventory"
+        );
+        assert_eq!(vulns[0]["properties"][0]["value"], "internal-lib@1.0.0");
+        // The fixture's serial number is not a UUID; only the vulnerability refs matter here
+        let violations =
+            crate::adapters::outbound::formatters::CycloneDxValidator::new().validate(&json);
+        assert!(
+            !violations
+                .iter()
+                .any(|v| v.path.starts_with("vulnerabilities")),
+            "{:?}",
+            violations
+        );
+    }
+
+    fn create_cvss_v4_read_model() -> SbomReadModel {
+        let vuln = VulnerabilityView {
+            bom_ref: "vuln-004".to_string(),
+            id: "CVE-2024-9999".to_string(),
+            aliases: Vec::new(),
+            affected_component: "pkg:pypi/requests@2.31.0".to_string(),
+            affected_component_name: "requests".to_string(),
+            affected_version: "2.31.0".to_string(),
+            cvss_score: Some(8.7),
+            cvss_vector: Some("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N".to_string()),
+            cvss_version: Some(CvssVersionView::V4_0),
+            severity: SeverityView::High,
+            fixed_version: None,
+            description: None,
+            source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
+        };
+        let mut model = create_test_read_model();
+        model.vulnerabilities = Some(VulnerabilityReportView {
+            actionable: vec![vuln.clone()],
+            informational: vec![],
+            ignored: vec![IgnoredVulnerabilityView {
+                vulnerability: VulnerabilityView {
+                    bom_ref: "vuln-005".to_string(),
+                    ..vuln
+                },
+                state: AnalysisStateView::NotAffected,
+                detail: None,
+            }],
+            summary: VulnerabilitySummary {
+                total_count: 2,
+                affected_package_count: 1,
+            },
+            ..Default::default()
+        });
+        model
+    }
+
+    #[test]
+    fn test_format_cvss_v2_rating_method() {
+        let mut model = create_cvss_v4_read_model();
+        let report = model.vulnerabilities.as_mut().unwrap();
+        report.ignored.clear();
+        for vuln in &mut report.actionable {
+            vuln.cvss_version = Some(CvssVersionView::V2_0);
+        }
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+
+        assert!(json.contains("\"method\": \"CVSSv2\""));
+        assert!(!json.contains("\"method\": \"CVSSv4\""));
+    }
+
+    #[test]
+    fn test_format_field_sets_per_spec_version() {
+        let model = create_cvss_v4_read_model();
+        let cases = [
+            (SpecVersion::V1_4, "1.4", "other"),
+            (SpecVersion::V1_5, "1.5", "other"),
+            (SpecVersion::V1_6, "1.6", "CVSSv4"),
+        ];
+
+        for (spec_version, expected_version, expected_method) in cases {
+            let json = CycloneDxFormatter::new()
+                .with_spec_version(spec_version)
+                .format(&model)
+                .unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(parsed["specVersion"], expected_version);
+            let mut keys: Vec<&str> = parsed
+                .as_object()
+                .unwrap()
+                .keys()
+                .map(String::as_str)
+                .collect();
+            keys.sort_unstable();
+            assert_eq!(
+                keys,
+                [
+                    "bomFormat",
+                    "components",
+                    "metadata",
+                    "serialNumber",
+                    "specVersion",
+                    "version",
+                    "vulnerabilities"
+                ]
+            );
+
+            let vulns = parsed["vulnerabilities"].as_array().unwrap();
+            assert_eq!(vulns[0]["ratings"][0]["method"], expected_method);
+            assert_eq!(
+                vulns[0]["ratings"][0]["vector"],
+                "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N"
+            );
+            // VEX analysis is part of the schema since 1.4
+            assert_eq!(vulns[1]["analysis"]["state"], "not_affected");
+        }
+    }
+
+    #[test]
+    fn test_format_defaults_to_spec_version_1_6() {
+        let model = create_test_read_model();
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["specVersion"], "1.6");
+    }
 }
@@ -42,24 +42,45 @@ pub(super) struct Vulnerability {
     pub(super) description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) source: Option<VulnerabilitySource>,
+    /// Other IDs the same advisory is published under
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) references: Option<Vec<VulnerabilityReference>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) ratings: Option<Vec<Rating>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub(super) affects: Vec<Affect>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) analysis: Option<Analysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) properties: Option<Vec<Property>>,
 }
 
+#[derive(Debug, Serialize)]
+pub(super) struct Analysis {
+    pub(super) state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) detail: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub(super) struct VulnerabilitySource {
     pub(super) url: String,
 }
 
+#[derive(Debug, Serialize)]
+pub(super) struct VulnerabilityReference {
+    pub(super) id: String,
+    pub(super) source: VulnerabilitySource,
+}
+
 #[derive(Debug, Serialize)]
 pub(super) struct Rating {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) score: Option<f32>,
     pub(super) severity: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) vector: Option<String>,
 }
 
@@ -75,6 +96,8 @@ pub(super) struct Metadata {
     pub(super) tools: Vec<Tool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) component: Option<MetadataComponent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) properties: Option<Vec<Property>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -84,7 +107,16 @@ pub(super) struct MetadataComponent {
     #[serde(rename = "bom-ref")]
     pub(super) bom_ref: String,
     pub(super) name: String,
-    pub(super) version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) hashes: Option<Vec<Hash>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) licenses: Option<Vec<License>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) purl: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -99,6 +131,10 @@ pub(super) struct Component {
     pub(super) component_type: String,
     #[serde(rename = "bom-ref")]
     pub(super) bom_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) supplier: Option<OrganizationalEntity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) author: Option<String>,
     pub(super) group: String,
     pub(super) name: String,
     pub(super) version: String,
@@ -109,6 +145,25 @@ pub(super) struct Component {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(super) licenses: Option<Vec<License>>,
     pub(super) purl: String,
+    #[serde(rename = "externalReferences", skip_serializing_if = "Option::is_none")]
+    pub(super) external_references: Option<Vec<ExternalReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) properties: Option<Vec<Property>>,
+}
+
+/// A named organization or individual, e.g. a component supplier
+#[derive(Debug, Serialize)]
+pub(super) struct OrganizationalEntity {
+    pub(super) name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct ExternalReference {
+    #[serde(rename = "type")]
+    pub(super) reference_type: String,
+    pub(super) url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) comment: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
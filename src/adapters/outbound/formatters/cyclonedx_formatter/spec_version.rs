@@ -0,0 +1,59 @@
+/// CycloneDX specification version the JSON output conforms to
+///
+/// Fields and enum values introduced after the selected version are left out
+/// or mapped to their closest older equivalent, so consumers that validate
+/// against an older schema (e.g. Dependency-Track with 1.5) accept the BOM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum SpecVersion {
+    V1_4,
+    V1_5,
+    #[default]
+    V1_6,
+}
+
+impl SpecVersion {
+    /// Parses a version string such as "1.5"
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "1.4" => Some(SpecVersion::V1_4),
+            "1.5" => Some(SpecVersion::V1_5),
+            "1.6" => Some(SpecVersion::V1_6),
+            _ => None,
+        }
+    }
+
+    /// Returns the `specVersion` value (e.g. "1.6")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SpecVersion::V1_4 => "1.4",
+            SpecVersion::V1_5 => "1.5",
+            SpecVersion::V1_6 => "1.6",
+        }
+    }
+
+    /// Whether `ratings[].method` accepts "CVSSv4" (added in 1.6)
+    pub(super) fn supports_cvss_v4_method(&self) -> bool {
+        *self >= SpecVersion::V1_6
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_round_trips_as_str() {
+        for version in [SpecVersion::V1_4, SpecVersion::V1_5, SpecVersion::V1_6] {
+            assert_eq!(SpecVersion::parse(version.as_str()), Some(version));
+        }
+        assert_eq!(SpecVersion::parse("1.3"), None);
+        assert_eq!(SpecVersion::parse("v1.6"), None);
+    }
+
+    #[test]
+    fn test_supports_cvss_v4_method() {
+        assert!(!SpecVersion::V1_4.supports_cvss_v4_method());
+        assert!(!SpecVersion::V1_5.supports_cvss_v4_method());
+        assert!(SpecVersion::V1_6.supports_cvss_v4_method());
+    }
+}
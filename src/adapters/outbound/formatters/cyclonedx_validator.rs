@@ -0,0 +1,381 @@
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// A structural problem found in a CycloneDX document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Location in the document, e.g. `dependencies[0].dependsOn[1]`
+    pub path: String,
+    pub message: String,
+}
+
+impl Violation {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// CycloneDxValidator checks structural invariants of a CycloneDX JSON document
+///
+/// These are the rules the JSON schema alone does not catch, or that are easy
+/// to break while building the BOM:
+/// - `serialNumber` is a `urn:uuid:` URN with a hyphenated UUID
+/// - every `bom-ref` is unique across the metadata component, components and vulnerabilities
+/// - every `dependencies[].ref`, `dependsOn` entry and `affects[].ref` resolves to
+///   a component or the metadata component, and each component has at most one dependency entry
+/// - every `purl` is syntactically valid
+pub struct CycloneDxValidator;
+
+impl CycloneDxValidator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns the violations found in `json`, in document order; empty when valid.
+    pub fn validate(&self, json: &str) -> Vec<Violation> {
+        let bom: Value = match serde_json::from_str(json) {
+            Ok(bom) => bom,
+            Err(e) => return vec![Violation::new("$", format!("not valid JSON: {}", e))],
+        };
+
+        let mut violations = Vec::new();
+        if bom["bomFormat"] != "CycloneDX" {
+            violations.push(Violation::new("bomFormat", "must be \"CycloneDX\""));
+        }
+        if let Some(serial) = bom.get("serialNumber") {
+            if !serial.as_str().is_some_and(is_uuid_urn) {
+                violations.push(Violation::new(
+                    "serialNumber",
+                    format!("{} is not a urn:uuid: URN", serial),
+                ));
+            }
+        }
+
+        let components = Self::components(&bom);
+        for (path, component) in &components {
+            if let Some(purl) = component.get("purl") {
+                let result = purl.as_str().ok_or("must be a string").and_then(check_purl);
+                if let Err(reason) = result {
+                    violations.push(Violation::new(
+                        format!("{}.purl", path),
+                        format!("invalid purl {}: {}", purl, reason),
+                    ));
+                }
+            }
+        }
+
+        let vulnerabilities = Self::items(&bom, "vulnerabilities");
+        let mut first_use: HashMap<&str, &str> = HashMap::new();
+        let ref_owners = components.iter().chain(vulnerabilities.iter());
+        for (path, item) in ref_owners {
+            let Some(bom_ref) = item.get("bom-ref").and_then(Value::as_str) else {
+                continue;
+            };
+            if let Some(first) = first_use.get(bom_ref) {
+                violations.push(Violation::new(
+                    format!("{}.bom-ref", path),
+                    format!("duplicate bom-ref \"{}\" (also used by {})", bom_ref, first),
+                ));
+            } else {
+                first_use.insert(bom_ref, path);
+            }
+        }
+
+        let component_refs: HashSet<&str> = components
+            .iter()
+            .filter_map(|(_, c)| c.get("bom-ref").and_then(Value::as_str))
+            .collect();
+        let mut check_ref = |path: String, value: &Value| match value.as_str() {
+            Some(r) if component_refs.contains(r) => {}
+            _ => violations.push(Violation::new(
+                path,
+                format!("{} does not resolve to a component", value),
+            )),
+        };
+
+        let mut dependency_entries: HashMap<String, usize> = HashMap::new();
+        for (i, dependency) in Self::array(&bom, "dependencies").iter().enumerate() {
+            let path = format!("dependencies[{}]", i);
+            check_ref(format!("{}.ref", path), &dependency["ref"]);
+            *dependency_entries
+                .entry(dependency["ref"].to_string())
+                .or_default() += 1;
+            for (j, child) in Self::array(dependency, "dependsOn").iter().enumerate() {
+                check_ref(format!("{}.dependsOn[{}]", path, j), child);
+            }
+        }
+        for (path, vulnerability) in &vulnerabilities {
+            for (j, affect) in Self::array(vulnerability, "affects").iter().enumerate() {
+                check_ref(format!("{}.affects[{}].ref", path, j), &affect["ref"]);
+            }
+        }
+
+        let mut duplicated: Vec<(String, usize)> = dependency_entries
+            .into_iter()
+            .filter(|(_, count)| *count > 1)
+            .collect();
+        duplicated.sort();
+        for (bom_ref, count) in duplicated {
+            violations.push(Violation::new(
+                "dependencies",
+                format!(
+                    "{} has {} entries; each component may have only one",
+                    bom_ref, count
+                ),
+            ));
+        }
+
+        violations
+    }
+
+    /// The metadata component (if any) followed by the top-level components, with their paths
+    fn components(bom: &Value) -> Vec<(String, &Value)> {
+        let metadata_component = bom
+            .get("metadata")
+            .and_then(|m| m.get("component"))
+            .map(|c| ("metadata.component".to_string(), c));
+        metadata_component
+            .into_iter()
+            .chain(Self::items(bom, "components"))
+            .collect()
+    }
+
+    fn items<'a>(bom: &'a Value, key: &str) -> Vec<(String, &'a Value)> {
+        Self::array(bom, key)
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (format!("{}[{}]", key, i), item))
+            .collect()
+    }
+
+    fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+        value
+            .get(key)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+impl Default for CycloneDxValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks the `urn:uuid:` form required by the CycloneDX schema (RFC 4122, hyphenated)
+fn is_uuid_urn(serial: &str) -> bool {
+    serial
+        .strip_prefix("urn:uuid:")
+        .is_some_and(|uuid| uuid.len() == 36 && uuid::Uuid::try_parse(uuid).is_ok())
+}
+
+/// Checks `pkg:type/namespace/name@version?qualifiers#subpath` syntax
+fn check_purl(purl: &str) -> std::result::Result<(), &'static str> {
+    if purl.chars().any(char::is_whitespace) {
+        return Err("whitespace must be percent-encoded");
+    }
+    let rest = purl
+        .strip_prefix("pkg:")
+        .ok_or("must start with \"pkg:\"")?;
+    let rest = rest.split(['?', '#']).next().unwrap_or_default();
+    let (purl_type, path) = rest.split_once('/').ok_or("missing package name")?;
+
+    let mut type_chars = purl_type.chars();
+    let type_is_valid = type_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && type_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'));
+    if !type_is_valid {
+        return Err(
+            "type must start with a letter and contain only letters, digits, '.', '+' or '-'",
+        );
+    }
+
+    let name_and_version = path.rsplit('/').next().unwrap_or_default();
+    let name = match name_and_version.split_once('@') {
+        Some((_, "")) => return Err("empty version after '@'"),
+        Some((name, _)) => name,
+        None => name_and_version,
+    };
+    if name.is_empty() {
+        return Err("missing package name");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::formatters::CycloneDxFormatter;
+    use crate::application::read_models::{MetadataComponentView, ProjectView, SeverityView};
+    use crate::ports::outbound::SbomFormatter;
+    use crate::test_fixtures::SbomReadModelFixture;
+
+    const SERIAL: &str = "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79";
+
+    fn messages(json: serde_json::Value) -> Vec<String> {
+        CycloneDxValidator::new()
+            .validate(&json.to_string())
+            .iter()
+            .map(Violation::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_formatter_output_with_dependencies_and_vulnerabilities_is_valid() {
+        let mut fixture = SbomReadModelFixture::with_vulns();
+        fixture.add_component("idna", "3.7").license("BSD-3-Clause");
+        fixture
+            .add_vulnerability("CVE-2024-9999")
+            .severity(SeverityView::Medium)
+            .on("idna", "3.7");
+        let mut model = fixture
+            .with_dependencies(&[("requests", &["urllib3", "idna"])])
+            .build();
+        model.metadata.component = Some(MetadataComponentView {
+            name: "my-project".to_string(),
+            version: Some("1.0.0".to_string()),
+        });
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+
+        assert_eq!(CycloneDxValidator::new().validate(&json), vec![]);
+    }
+
+    #[test]
+    fn test_formatter_output_for_clean_fixture_is_valid() {
+        let model = SbomReadModelFixture::small_clean().build();
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+        assert_eq!(CycloneDxValidator::new().validate(&json), vec![]);
+    }
+
+    #[test]
+    fn test_formatter_output_for_aggregated_sbom_is_valid() {
+        let mut fixture = SbomReadModelFixture::with_vulns();
+        fixture.add_component("shared", "1.0.0");
+        let mut model = fixture
+            .with_dependencies(&[("requests", &["urllib3"]), ("shared", &["urllib3"])])
+            .build();
+        model.projects = ["api", "shared"]
+            .into_iter()
+            .map(|name| ProjectView {
+                name: name.to_string(),
+                version: Some("1.0.0".to_string()),
+                direct_dependencies: vec!["requests-2.31.0".to_string()],
+            })
+            .collect();
+
+        let json = CycloneDxFormatter::new().format(&model).unwrap();
+
+        assert_eq!(CycloneDxValidator::new().validate(&json), vec![]);
+    }
+
+    #[test]
+    fn test_validate_reports_duplicate_bom_refs() {
+        let violations = messages(serde_json::json!({
+            "bomFormat": "CycloneDX",
+            "serialNumber": SERIAL,
+            "metadata": {"component": {"bom-ref": "app-1.0.0", "name": "app"}},
+            "components": [
+                {"bom-ref": "app-1.0.0", "name": "app", "purl": "pkg:pypi/app@1.0.0"},
+                {"bom-ref": "idna-3.7", "name": "idna", "purl": "pkg:pypi/idna@3.7"}
+            ],
+            "vulnerabilities": [{"bom-ref": "idna-3.7", "affects": [{"ref": "idna-3.7"}]}]
+        }));
+
+        assert_eq!(
+            violations,
+            vec![
+                "components[0].bom-ref: duplicate bom-ref \"app-1.0.0\" (also used by metadata.component)",
+                "vulnerabilities[0].bom-ref: duplicate bom-ref \"idna-3.7\" (also used by components[1])",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_unresolved_references() {
+        let violations = messages(serde_json::json!({
+            "bomFormat": "CycloneDX",
+            "serialNumber": SERIAL,
+            "metadata": {"component": {"bom-ref": "app-1.0.0", "name": "app"}},
+            "components": [{"bom-ref": "requests-2.31.0", "name": "requests"}],
+            "dependencies": [
+                {"ref": "app-1.0.0", "dependsOn": ["requests-2.31.0"]},
+                {"ref": "requests-2.31.0", "dependsOn": ["urllib3-1.26.0"]},
+                {"ref": "requests-2.31.0"}
+            ],
+            "vulnerabilities": [{"bom-ref": "CVE-1", "affects": [{"ref": "gone-1.0"}]}]
+        }));
+
+        assert_eq!(
+            violations,
+            vec![
+                "dependencies[1].dependsOn[0]: \"urllib3-1.26.0\" does not resolve to a component",
+                "vulnerabilities[0].affects[0].ref: \"gone-1.0\" does not resolve to a component",
+                "dependencies: \"requests-2.31.0\" has 2 entries; each component may have only one",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_bad_serial_number_and_format() {
+        let violations = messages(serde_json::json!({
+            "bomFormat": "SPDX",
+            "serialNumber": "urn:uuid:test-123",
+        }));
+
+        assert_eq!(
+            violations,
+            vec![
+                "bomFormat: must be \"CycloneDX\"",
+                "serialNumber: \"urn:uuid:test-123\" is not a urn:uuid: URN",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_non_json() {
+        let violations = CycloneDxValidator::new().validate("not json");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "$");
+    }
+
+    #[test]
+    fn test_is_uuid_urn() {
+        assert!(is_uuid_urn(SERIAL));
+        assert!(!is_uuid_urn("3e671687-395b-41f5-a30f-a58921a69b79"));
+        assert!(!is_uuid_urn("urn:uuid:3e671687395b41f5a30fa58921a69b79"));
+        assert!(!is_uuid_urn("urn:uuid:not-a-uuid"));
+    }
+
+    #[test]
+    fn test_check_purl() {
+        for valid in [
+            "pkg:pypi/requests@2.31.0",
+            "pkg:pypi/zope-interface",
+            "pkg:github/package-url/purl-spec@244fd47e07d1004",
+            "pkg:pypi/django@1.11.1?repository_url=https://example.com#src",
+        ] {
+            assert_eq!(check_purl(valid), Ok(()), "{}", valid);
+        }
+        for invalid in [
+            "pypi/requests@2.31.0",
+            "pkg:pypi",
+            "pkg:pypi/@2.31.0",
+            "pkg:pypi/requests@",
+            "pkg:1pypi/requests@2.31.0",
+            "pkg:pypi/my package@1.0",
+        ] {
+            assert!(check_purl(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+mod schema;
+use schema::*;
+
+use crate::application::read_models::SbomDiffView;
+use crate::ports::outbound::SbomDiffFormatter;
+use crate::shared::Result;
+
+/// DiffJsonFormatter adapter for the machine-readable SBOM comparison
+///
+/// Renders a `schema_version`, summary counts, and the added, removed,
+/// updated and license-only changed packages, each sorted by name.
+pub struct DiffJsonFormatter;
+
+impl DiffJsonFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for DiffJsonFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SbomDiffFormatter for DiffJsonFormatter {
+    fn format_diff(&self, diff: &SbomDiffView) -> Result<String> {
+        serde_json::to_string_pretty(&DiffReport::from(diff)).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::{DiffPackageView, VersionChangeView};
+
+    #[test]
+    fn test_format_diff_schema() {
+        let diff = SbomDiffView {
+            added: vec![DiffPackageView {
+                name: "idna".to_string(),
+                version: "3.7".to_string(),
+                license: Some("BSD-3-Clause".to_string()),
+            }],
+            updated: vec![VersionChangeView {
+                name: "requests".to_string(),
+                old_version: "2.31.0".to_string(),
+                new_version: "2.32.3".to_string(),
+                old_license: None,
+                new_license: None,
+            }],
+            ..Default::default()
+        };
+
+        let output = DiffJsonFormatter::new().format_diff(&diff).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "schema_version": "1",
+                "summary": {"added": 1, "removed": 0, "updated": 1, "license_changed": 0},
+                "added": [{"name": "idna", "version": "3.7", "license": "BSD-3-Clause"}],
+                "removed": [],
+                "updated": [{
+                    "name": "requests",
+                    "old_version": "2.31.0",
+                    "new_version": "2.32.3",
+                    "old_license": null,
+                    "new_license": null,
+                }],
+                "license_changed": [],
+            })
+        );
+    }
+}
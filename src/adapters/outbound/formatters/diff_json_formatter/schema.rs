@@ -0,0 +1,104 @@
+use crate::application::read_models::{
+    DiffPackageView, LicenseChangeView, SbomDiffView, VersionChangeView,
+};
+use serde::Serialize;
+
+/// Version of the diff layout; bumped on any breaking change to the fields below
+pub(super) const SCHEMA_VERSION: &str = "1";
+
+#[derive(Debug, Serialize)]
+pub(super) struct DiffReport {
+    pub(super) schema_version: &'static str,
+    pub(super) summary: Summary,
+    pub(super) added: Vec<PackageEntry>,
+    pub(super) removed: Vec<PackageEntry>,
+    pub(super) updated: Vec<VersionChange>,
+    pub(super) license_changed: Vec<LicenseChange>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct Summary {
+    pub(super) added: usize,
+    pub(super) removed: usize,
+    pub(super) updated: usize,
+    pub(super) license_changed: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct PackageEntry {
+    pub(super) name: String,
+    pub(super) version: String,
+    pub(super) license: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct VersionChange {
+    pub(super) name: String,
+    pub(super) old_version: String,
+    pub(super) new_version: String,
+    pub(super) old_license: Option<String>,
+    pub(super) new_license: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct LicenseChange {
+    pub(super) name: String,
+    pub(super) version: String,
+    pub(super) old_license: Option<String>,
+    pub(super) new_license: Option<String>,
+}
+
+impl From<&SbomDiffView> for DiffReport {
+    fn from(diff: &SbomDiffView) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            summary: Summary {
+                added: diff.added.len(),
+                removed: diff.removed.len(),
+                updated: diff.updated.len(),
+                license_changed: diff.license_changed.len(),
+            },
+            added: diff.added.iter().map(PackageEntry::from).collect(),
+            removed: diff.removed.iter().map(PackageEntry::from).collect(),
+            updated: diff.updated.iter().map(VersionChange::from).collect(),
+            license_changed: diff
+                .license_changed
+                .iter()
+                .map(LicenseChange::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&DiffPackageView> for PackageEntry {
+    fn from(package: &DiffPackageView) -> Self {
+        Self {
+            name: package.name.clone(),
+            version: package.version.clone(),
+            license: package.license.clone(),
+        }
+    }
+}
+
+impl From<&VersionChangeView> for VersionChange {
+    fn from(change: &VersionChangeView) -> Self {
+        Self {
+            name: change.name.clone(),
+            old_version: change.old_version.clone(),
+            new_version: change.new_version.clone(),
+            old_license: change.old_license.clone(),
+            new_license: change.new_license.clone(),
+        }
+    }
+}
+
+impl From<&LicenseChangeView> for LicenseChange {
+    fn from(change: &LicenseChangeView) -> Self {
+        Self {
+            name: change.name.clone(),
+            version: change.version.clone(),
+            old_license: change.old_license.clone(),
+            new_license: change.new_license.clone(),
+        }
+    }
+}
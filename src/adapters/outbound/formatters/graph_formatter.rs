@@ -0,0 +1,420 @@
+use crate::application::read_models::{ComponentView, SbomReadModel};
+use crate::ports::outbound::SbomFormatter;
+use crate::sbom_generation::domain::PackageName;
+use crate::shared::Result;
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Write;
+
+/// Syntax of the dependency graph export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid `graph TD` flowchart
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Parses a format name such as "dot"
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "dot" => Some(GraphFormat::Dot),
+            "mermaid" => Some(GraphFormat::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+/// How a node is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Root,
+    Direct,
+    Transitive,
+}
+
+/// A node of the exported graph
+struct Node {
+    id: String,
+    label: String,
+    kind: NodeKind,
+    vulnerable: bool,
+}
+
+/// GraphFormatter adapter for the dependency graph export
+///
+/// Renders the project, its direct dependencies and every dependency edge as a
+/// Graphviz DOT or Mermaid graph. Nodes are labeled `name@version`; direct
+/// dependencies are drawn apart from transitive ones, and packages with a
+/// known vulnerability are highlighted. A model without dependency
+/// information renders the packages without edges.
+pub struct GraphFormatter {
+    format: GraphFormat,
+}
+
+impl GraphFormatter {
+    pub fn new(format: GraphFormat) -> Self {
+        Self { format }
+    }
+
+    /// Collects the nodes, root first and the packages sorted by name and version,
+    /// and the edges between their ids
+    fn collect(model: &SbomReadModel) -> (Vec<Node>, Vec<(String, String)>) {
+        let root = model.metadata.component.as_ref().map(|project| {
+            // Same bom-ref as the CycloneDX metadata component
+            let name = PackageName::normalize(&project.name);
+            let bom_ref = match &project.version {
+                Some(version) => format!("{}-{}", name, version),
+                None => name,
+            };
+            let label = match &project.version {
+                Some(version) => format!("{}@{}", project.name, version),
+                None => project.name.clone(),
+            };
+            (bom_ref, label)
+        });
+        let root_ref = root.as_ref().map(|(bom_ref, _)| bom_ref.as_str());
+
+        let direct: HashSet<&str> = model
+            .dependencies
+            .as_ref()
+            .map(|deps| deps.direct.iter().map(String::as_str).collect())
+            .unwrap_or_default();
+        let vulnerable: HashSet<(&str, &str)> = model
+            .vulnerabilities
+            .iter()
+            .flat_map(|report| report.actionable.iter().chain(report.informational.iter()))
+            .map(|v| {
+                (
+                    v.affected_component_name.as_str(),
+                    v.affected_version.as_str(),
+                )
+            })
+            .collect();
+
+        let mut components: Vec<&ComponentView> = model
+            .components
+            .iter()
+            .filter(|c| Some(c.bom_ref.as_str()) != root_ref)
+            .collect();
+        components.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+        let known: HashSet<&str> = components.iter().map(|c| c.bom_ref.as_str()).collect();
+
+        let mut nodes: Vec<Node> = Vec::new();
+        if let Some((bom_ref, label)) = &root {
+            nodes.push(Node {
+                id: node_id(bom_ref),
+                label: label.clone(),
+                kind: NodeKind::Root,
+                vulnerable: false,
+            });
+        }
+        nodes.extend(components.iter().map(|c| Node {
+            id: node_id(&c.bom_ref),
+            label: format!("{}@{}", c.name, c.version),
+            kind: if direct.contains(c.bom_ref.as_str()) {
+                NodeKind::Direct
+            } else {
+                NodeKind::Transitive
+            },
+            vulnerable: vulnerable.contains(&(c.name.as_str(), c.version.as_str())),
+        }));
+
+        let mut edges: Vec<(String, String)> = Vec::new();
+        let mut seen: BTreeSet<(String, String)> = BTreeSet::new();
+        let mut push_edge = |from: &str, to: &str| {
+            let edge = (node_id(from), node_id(to));
+            if seen.insert(edge.clone()) {
+                edges.push(edge);
+            }
+        };
+        if let Some(deps) = &model.dependencies {
+            if let Some(root_ref) = root_ref {
+                for child in deps.direct.iter().filter(|c| known.contains(c.as_str())) {
+                    push_edge(root_ref, child);
+                }
+            }
+            for component in &components {
+                let children = deps.children.get(&component.bom_ref).into_iter().flatten();
+                for child in children.filter(|c| known.contains(c.as_str())) {
+                    push_edge(&component.bom_ref, child);
+                }
+            }
+        }
+        (nodes, edges)
+    }
+
+    fn format_dot(nodes: &[Node], edges: &[(String, String)]) -> String {
+        let mut output = String::from("digraph dependencies {\n");
+        output.push_str("    rankdir=LR;\n");
+        output.push_str("    node [shape=box, style=\"rounded,filled\", fillcolor=\"#ffffff\"];\n");
+        for node in nodes {
+            let mut attributes = vec![format!("label=\"{}\"", escape_dot_label(&node.label))];
+            match node.kind {
+                NodeKind::Root => attributes.push("shape=doubleoctagon".to_string()),
+                NodeKind::Direct => {
+                    attributes.push("penwidth=2".to_string());
+                    if !node.vulnerable {
+                        attributes.push("fillcolor=\"#cce5ff\"".to_string());
+                    }
+                }
+                NodeKind::Transitive => {}
+            }
+            if node.vulnerable {
+                attributes.push("fillcolor=\"#f8d7da\"".to_string());
+                attributes.push("color=\"#d62728\"".to_string());
+            }
+            let _ = writeln!(output, "    {} [{}];", node.id, attributes.join(", "));
+        }
+        for (from, to) in edges {
+            let _ = writeln!(output, "    {} -> {};", from, to);
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    fn format_mermaid(nodes: &[Node], edges: &[(String, String)]) -> String {
+        let mut output = String::from("graph TD\n");
+        for node in nodes {
+            let _ = writeln!(
+                output,
+                "    {}[\"{}\"]",
+                node.id,
+                escape_mermaid_label(&node.label)
+            );
+        }
+        for (from, to) in edges {
+            let _ = writeln!(output, "    {} --> {}", from, to);
+        }
+
+        output.push_str("    classDef root fill:#e2e3e5,stroke:#383d41,stroke-width:2px;\n");
+        output.push_str("    classDef direct fill:#cce5ff,stroke:#004085,stroke-width:2px;\n");
+        output.push_str("    classDef transitive fill:#ffffff,stroke:#6c757d;\n");
+        output.push_str("    classDef vulnerable fill:#f8d7da,stroke:#d62728;\n");
+        let classes = [
+            (
+                "root",
+                nodes
+                    .iter()
+                    .filter(|n| n.kind == NodeKind::Root)
+                    .collect::<Vec<_>>(),
+            ),
+            (
+                "direct",
+                nodes
+                    .iter()
+                    .filter(|n| n.kind == NodeKind::Direct)
+                    .collect(),
+            ),
+            (
+                "transitive",
+                nodes
+                    .iter()
+                    .filter(|n| n.kind == NodeKind::Transitive)
+                    .collect(),
+            ),
+            (
+                "vulnerable",
+                nodes.iter().filter(|n| n.vulnerable).collect(),
+            ),
+        ];
+        for (class, members) in classes {
+            if !members.is_empty() {
+                let ids: Vec<&str> = members.iter().map(|n| n.id.as_str()).collect();
+                let _ = writeln!(output, "    class {} {};", ids.join(","), class);
+            }
+        }
+        output
+    }
+}
+
+impl SbomFormatter for GraphFormatter {
+    fn format(&self, model: &SbomReadModel) -> Result<String> {
+        let (nodes, edges) = Self::collect(model);
+        Ok(match self.format {
+            GraphFormat::Dot => Self::format_dot(&nodes, &edges),
+            GraphFormat::Mermaid => Self::format_mermaid(&nodes, &edges),
+        })
+    }
+}
+
+/// Turns a bom-ref into an identifier both syntaxes accept unquoted
+///
+/// ASCII letters and digits are kept; every other character, `_` included,
+/// becomes `_` followed by its hex code point, so distinct bom-refs such as
+/// `a-b` and `a.b` never share an identifier.
+fn node_id(bom_ref: &str) -> String {
+    let mut id = String::from("n_");
+    for c in bom_ref.chars() {
+        if c.is_ascii_alphanumeric() {
+            id.push(c);
+        } else {
+            let _ = write!(id, "_{:x}", c as u32);
+        }
+    }
+    id
+}
+
+/// Escapes a label for a double-quoted DOT string
+fn escape_dot_label(label: &str) -> String {
+    label
+        .chars()
+        .filter(|c| !c.is_control())
+        .flat_map(|c| match c {
+            '"' | '\\' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
+/// Escapes a label for a double-quoted Mermaid node text using entity codes
+fn escape_mermaid_label(label: &str) -> String {
+    let mut escaped = String::with_capacity(label.len());
+    for c in label.chars().filter(|c| !c.is_control()) {
+        match c {
+            '"' => escaped.push_str("#quot;"),
+            '#' => escaped.push_str("#35;"),
+            '<' => escaped.push_str("#lt;"),
+            '>' => escaped.push_str("#gt;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::{MetadataComponentView, SeverityView};
+    use crate::test_fixtures::SbomReadModelFixture;
+
+    /// app → zope.interface and typing-extensions, which both depend on my_base
+    fn diamond() -> SbomReadModel {
+        let mut fixture = SbomReadModelFixture::builder();
+        fixture.add_component("zope.interface", "6.0").direct();
+        fixture.add_component("typing-extensions", "4.9.0").direct();
+        fixture.add_component("my_base", "1.0");
+        fixture
+            .add_vulnerability("CVE-2024-1234")
+            .severity(SeverityView::High)
+            .on("my_base", "1.0");
+        let mut model = fixture
+            .with_dependencies(&[
+                ("zope.interface", &["my_base"]),
+                ("typing-extensions", &["my_base"]),
+            ])
+            .build();
+        model.metadata.component = Some(MetadataComponentView {
+            name: "app".to_string(),
+            version: Some("0.1.0".to_string()),
+        });
+        model
+    }
+
+    const APP: &str = "n_app_2d0_2e1_2e0";
+    const ZOPE: &str = "n_zope_2dinterface_2d6_2e0";
+    const TYPING: &str = "n_typing_2dextensions_2d4_2e9_2e0";
+    const BASE: &str = "n_my_2dbase_2d1_2e0";
+
+    #[test]
+    fn test_node_id_keeps_distinct_bom_refs_apart() {
+        assert_eq!(node_id("zope-interface-6.0"), ZOPE);
+        assert_ne!(node_id("a-b"), node_id("a.b"));
+        assert_ne!(node_id("a_2d"), node_id("a-"));
+        assert!(node_id("x y\"z")
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    }
+
+    #[test]
+    fn test_graph_format_parse() {
+        assert_eq!(GraphFormat::parse("DOT"), Some(GraphFormat::Dot));
+        assert_eq!(GraphFormat::parse("mermaid"), Some(GraphFormat::Mermaid));
+        assert_eq!(GraphFormat::parse("svg"), None);
+    }
+
+    #[test]
+    fn test_format_dot_diamond() {
+        let dot = GraphFormatter::new(GraphFormat::Dot)
+            .format(&diamond())
+            .unwrap();
+
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert_eq!(dot.matches('{').count(), dot.matches('}').count());
+        assert!(dot.contains(&format!(
+            "    {} [label=\"app@0.1.0\", shape=doubleoctagon];",
+            APP
+        )));
+        assert!(dot.contains(&format!(
+            "    {} [label=\"zope.interface@6.0\", penwidth=2, fillcolor=\"#cce5ff\"];",
+            ZOPE
+        )));
+        assert!(dot.contains(&format!(
+            "    {} [label=\"my_base@1.0\", fillcolor=\"#f8d7da\", color=\"#d62728\"];",
+            BASE
+        )));
+
+        let edges: Vec<&str> = dot.lines().filter(|l| l.contains(" -> ")).collect();
+        assert_eq!(
+            edges,
+            [
+                format!("    {} -> {};", APP, ZOPE),
+                format!("    {} -> {};", APP, TYPING),
+                format!("    {} -> {};", TYPING, BASE),
+                format!("    {} -> {};", ZOPE, BASE),
+            ]
+        );
+        // The shared dependency is a single node
+        assert_eq!(dot.matches(&format!("    {} [", BASE)).count(), 1);
+    }
+
+    #[test]
+    fn test_format_mermaid_diamond() {
+        let mermaid = GraphFormatter::new(GraphFormat::Mermaid)
+            .format(&diamond())
+            .unwrap();
+
+        let lines: Vec<&str> = mermaid.lines().collect();
+        assert_eq!(lines[0], "graph TD");
+        assert_eq!(lines[1], format!("    {}[\"app@0.1.0\"]", APP));
+
+        // Every edge endpoint is a declared node
+        let declared: HashSet<&str> = lines
+            .iter()
+            .filter_map(|l| l.trim().split_once("[\"").map(|(id, _)| id))
+            .collect();
+        let edges: Vec<(&str, &str)> = lines
+            .iter()
+            .filter_map(|l| l.trim().split_once(" --> "))
+            .collect();
+        assert_eq!(edges.len(), 4);
+        for (from, to) in &edges {
+            assert!(declared.contains(from) && declared.contains(to));
+        }
+        assert_eq!(edges.iter().filter(|(_, to)| *to == BASE).count(), 2);
+
+        assert!(mermaid.contains(&format!("    class {} root;", APP)));
+        assert!(mermaid.contains(&format!("    class {},{} direct;", TYPING, ZOPE)));
+        assert!(mermaid.contains(&format!("    class {} transitive;", BASE)));
+        assert!(mermaid.contains(&format!("    class {} vulnerable;", BASE)));
+    }
+
+    #[test]
+    fn test_labels_are_escaped() {
+        assert_eq!(escape_dot_label("a\"b\\c\n"), "a\\\"b\\\\c");
+        assert_eq!(escape_mermaid_label("a\"b<c>#"), "a#quot;b#lt;c#gt;#35;");
+    }
+
+    #[test]
+    fn test_format_without_dependency_information() {
+        let model = SbomReadModelFixture::small_clean().build();
+
+        let dot = GraphFormatter::new(GraphFormat::Dot)
+            .format(&model)
+            .unwrap();
+
+        assert!(dot.contains("label=\"requests@2.31.0\""));
+        assert!(!dot.contains(" -> "));
+    }
+}
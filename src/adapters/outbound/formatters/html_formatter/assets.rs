@@ -0,0 +1,57 @@
+//! Stylesheet and script embedded in every HTML report
+//!
+//! Both are inlined so the report is a single file that renders offline and
+//! loads nothing from a CDN.
+
+/// Embedded stylesheet; severity cells are colored by their `severity-*` class
+pub(super) const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; margin: 2rem; color: #1f2328; line-height: 1.5; }
+header { border-bottom: 1px solid #d0d7de; margin-bottom: 1.5rem; }
+dl.metadata { display: grid; grid-template-columns: max-content auto; gap: 0.25rem 1rem; }
+dl.metadata dt { font-weight: 600; }
+dl.metadata dd { margin: 0; font-family: ui-monospace, monospace; }
+table { border-collapse: collapse; margin-bottom: 1.5rem; width: 100%; }
+th, td { border: 1px solid #d0d7de; padding: 0.35rem 0.6rem; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; }
+table.sortable th { cursor: pointer; user-select: none; }
+table.sortable th[aria-sort="ascending"]::after { content: " \25B2"; }
+table.sortable th[aria-sort="descending"]::after { content: " \25BC"; }
+.extras { color: #57606a; }
+.note { color: #57606a; font-style: italic; }
+.severity { font-weight: 600; text-align: center; }
+.severity-critical { background: #8b0000; color: #ffffff; }
+.severity-high { background: #d1242f; color: #ffffff; }
+.severity-medium { background: #f0b400; color: #1f2328; }
+.severity-low { background: #ddf4ff; color: #1f2328; }
+.severity-none { background: #eaeef2; color: #1f2328; }
+"#;
+
+/// Sorts a `table.sortable` by the clicked column, toggling the direction on
+/// each click. Cells sort by their `data-sort` attribute when present (e.g.
+/// severity rank), otherwise by their text with numeric-aware comparison.
+pub(super) const SORT_SCRIPT: &str = r#"
+document.querySelectorAll("table.sortable th").forEach(function (th) {
+  th.addEventListener("click", function () {
+    var row = th.parentNode;
+    var body = th.closest("table").tBodies[0];
+    var index = Array.prototype.indexOf.call(row.children, th);
+    var ascending = th.getAttribute("aria-sort") !== "ascending";
+    Array.prototype.forEach.call(row.children, function (cell) {
+      cell.removeAttribute("aria-sort");
+    });
+    th.setAttribute("aria-sort", ascending ? "ascending" : "descending");
+    var key = function (tr) {
+      var cell = tr.cells[index];
+      return cell.getAttribute("data-sort") || cell.textContent.trim();
+    };
+    Array.prototype.slice.call(body.rows)
+      .sort(function (a, b) {
+        var order = key(a).localeCompare(key(b), undefined, { numeric: true });
+        return ascending ? order : -order;
+      })
+      .forEach(function (tr) {
+        body.appendChild(tr);
+      });
+  });
+});
+"#;
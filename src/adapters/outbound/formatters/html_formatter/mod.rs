@@ -0,0 +1,634 @@
+mod assets;
+
+use crate::application::read_models::{
+    ComponentView, DependencyView, SbomMetadataView, SbomReadModel, SeverityView,
+    VulnerabilityReportView, VulnerabilityView,
+};
+use crate::i18n::{Locale, Messages};
+use crate::ports::outbound::SbomFormatter;
+use crate::shared::Result;
+use std::collections::HashMap;
+
+/// HtmlFormatter adapter for generating a self-contained HTML SBOM report
+///
+/// Renders the component inventory, the dependency sections and the vulnerability
+/// report as sortable tables. Styles and the sorting script are embedded, so the
+/// report is a single static file that needs no network access to display.
+pub struct HtmlFormatter {
+    messages: &'static Messages,
+    lang: &'static str,
+}
+
+impl HtmlFormatter {
+    pub fn new(locale: Locale) -> Self {
+        let lang = match locale {
+            Locale::En => "en",
+            Locale::Ja => "ja",
+        };
+        Self {
+            messages: Messages::for_locale(locale),
+            lang,
+        }
+    }
+}
+
+impl Default for HtmlFormatter {
+    fn default() -> Self {
+        Self::new(Locale::En)
+    }
+}
+
+impl SbomFormatter for HtmlFormatter {
+    fn format(&self, model: &SbomReadModel) -> Result<String> {
+        let title = plain(self.messages.section_sbom_title);
+        let mut output = String::new();
+        output.push_str("<!DOCTYPE html>\n");
+        output.push_str(&format!("<html lang=\"{}\">\n<head>\n", self.lang));
+        output.push_str("<meta charset=\"utf-8\">\n");
+        output.push_str(&format!("<title>{}</title>\n", escape_html(title)));
+        output.push_str(&format!("<style>{}</style>\n", assets::STYLE));
+        output.push_str("</head>\n<body>\n");
+
+        self.render_header(&mut output, title, &model.metadata);
+        self.render_components(&mut output, &model.components);
+        if let Some(deps) = &model.dependencies {
+            self.render_dependencies(&mut output, deps, &model.components);
+        }
+        if let Some(vulns) = &model.vulnerabilities {
+            self.render_vulnerabilities(
+                &mut output,
+                vulns,
+                model.project_self_check.as_ref(),
+                model.unchecked_package_count(),
+            );
+        }
+
+        output.push_str(&format!("<script>{}</script>\n", assets::SORT_SCRIPT));
+        output.push_str("</body>\n</html>\n");
+        Ok(output)
+    }
+}
+
+impl HtmlFormatter {
+    /// Renders the page title and the metadata block (tool, timestamp, serial number)
+    fn render_header(&self, output: &mut String, title: &str, metadata: &SbomMetadataView) {
+        let msgs = self.messages;
+        output.push_str("<header>\n");
+        output.push_str(&format!("<h1>{}</h1>\n", escape_html(title)));
+        output.push_str("<dl class=\"metadata\">\n");
+        if let Some(component) = &metadata.component {
+            let project = match &component.version {
+                Some(version) => format!("{} {}", component.name, version),
+                None => component.name.clone(),
+            };
+            push_metadata_entry(output, msgs.col_project, &project);
+        }
+        push_metadata_entry(
+            output,
+            msgs.label_html_tool,
+            &format!("{} {}", metadata.tool_name, metadata.tool_version),
+        );
+        push_metadata_entry(output, msgs.label_html_timestamp, &metadata.timestamp);
+        push_metadata_entry(
+            output,
+            msgs.label_html_serial_number,
+            &metadata.serial_number,
+        );
+        output.push_str("</dl>\n");
+        output.push_str(&format!(
+            "<p class=\"note\">{}</p>\n",
+            escape_html(msgs.label_html_sort_hint)
+        ));
+        output.push_str("</header>\n");
+    }
+
+    /// Renders the component inventory section
+    fn render_components(&self, output: &mut String, components: &[ComponentView]) {
+        let msgs = self.messages;
+        push_heading(output, "h2", msgs.section_component_inventory);
+        push_paragraph(output, msgs.desc_sbom_report);
+        self.render_package_table(output, components.iter().map(|c| (c, None)));
+    }
+
+    /// Renders the direct and transitive dependency sections, and the packages
+    /// no `--roots` package reaches
+    fn render_dependencies(
+        &self,
+        output: &mut String,
+        deps: &DependencyView,
+        components: &[ComponentView],
+    ) {
+        let msgs = self.messages;
+        let component_map: HashMap<&str, &ComponentView> =
+            components.iter().map(|c| (c.bom_ref.as_str(), c)).collect();
+        let rows = |refs: &[String]| -> Vec<(&ComponentView, Option<&Vec<String>>)> {
+            refs.iter()
+                .filter_map(|bom_ref| {
+                    component_map
+                        .get(bom_ref.as_str())
+                        .map(|component| (*component, deps.extras.get(bom_ref)))
+                })
+                .collect()
+        };
+
+        push_heading(output, "h2", msgs.section_direct_deps);
+        push_paragraph(output, msgs.desc_direct_deps);
+        if deps.direct.is_empty() {
+            push_paragraph(output, msgs.label_no_direct_deps);
+        } else {
+            self.render_package_table(output, rows(&deps.direct).into_iter());
+        }
+
+        push_heading(output, "h2", msgs.section_transitive_deps);
+        push_paragraph(output, msgs.desc_transitive_deps);
+        if deps.transitive.is_empty() {
+            push_paragraph(output, msgs.label_no_transitive_deps);
+        }
+        for direct_ref in &deps.direct {
+            let Some(trans_deps) = deps.transitive.get(direct_ref) else {
+                continue;
+            };
+            if trans_deps.is_empty() {
+                continue;
+            }
+            let parent_name = component_map
+                .get(direct_ref.as_str())
+                .map(|c| c.name.as_str())
+                .unwrap_or(direct_ref);
+            push_heading(
+                output,
+                "h3",
+                &Messages::format(msgs.deps_for_header, &[parent_name]),
+            );
+            self.render_package_table(output, rows(trans_deps).into_iter());
+        }
+
+        if !deps.unreferenced.is_empty() {
+            push_heading(output, "h2", msgs.section_unreferenced_packages);
+            push_paragraph(output, msgs.desc_unreferenced_packages);
+            self.render_package_table(output, rows(&deps.unreferenced).into_iter());
+        }
+    }
+
+    /// Renders a sortable package table; each row may carry the extras it was requested with
+    fn render_package_table<'a>(
+        &self,
+        output: &mut String,
+        rows: impl Iterator<Item = (&'a ComponentView, Option<&'a Vec<String>>)>,
+    ) {
+        let msgs = self.messages;
+        push_table_head(
+            output,
+            &[
+                msgs.col_package,
+                msgs.col_version,
+                msgs.col_license,
+                msgs.col_description,
+            ],
+        );
+        for (component, extras) in rows {
+            let license = component
+                .license
+                .as_ref()
+                .map(|l| l.spdx_id.as_deref().unwrap_or(l.name.as_str()))
+                .unwrap_or("N/A");
+            let extras = match extras {
+                Some(extras) if !extras.is_empty() => format!(
+                    " <span class=\"extras\">[{}]</span>",
+                    escape_html(&extras.join(","))
+                ),
+                _ => String::new(),
+            };
+            output.push_str(&format!(
+                "<tr><td>{}{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&component.name),
+                extras,
+                escape_html(&component.version),
+                escape_html(license),
+                escape_html(component.description.as_deref().unwrap_or("")),
+            ));
+        }
+        output.push_str("</tbody>\n</table>\n");
+    }
+
+    /// Renders the vulnerability report section
+    ///
+    /// `unchecked_count` packages could not be queried; a note says the report is
+    /// incomplete so that an empty table is not read as "no vulnerabilities".
+    fn render_vulnerabilities(
+        &self,
+        output: &mut String,
+        vulns: &VulnerabilityReportView,
+        project_self_check: Option<&VulnerabilityReportView>,
+        unchecked_count: usize,
+    ) {
+        let msgs = self.messages;
+        push_heading(output, "h2", msgs.section_vuln_report);
+        if unchecked_count > 0 {
+            push_paragraph(
+                output,
+                &Messages::format(
+                    msgs.warn_vuln_check_incomplete,
+                    &[&unchecked_count.to_string()],
+                ),
+            );
+        }
+        push_paragraph(
+            output,
+            &self.count_message(
+                msgs.summary_vuln_found,
+                vulns.summary.total_count,
+                vulns.summary.affected_package_count,
+            ),
+        );
+
+        if vulns.actionable.is_empty() {
+            push_heading(output, "h3", msgs.warn_no_vuln_above_threshold);
+        } else {
+            let packages = count_unique_packages(&vulns.actionable);
+            push_heading(
+                output,
+                "h3",
+                &self.count_message(msgs.warn_vuln_found, vulns.actionable.len(), packages),
+            );
+            self.render_vulnerability_table(output, vulns.actionable.iter());
+        }
+        if let Some((listed, omitted)) = vulns.listed_informational() {
+            let packages = count_unique_packages(&vulns.informational);
+            push_heading(
+                output,
+                "h3",
+                &self.count_message(msgs.info_vuln_found, vulns.informational.len(), packages),
+            );
+            if !listed.is_empty() {
+                self.render_vulnerability_table(output, listed.into_iter());
+            }
+            if omitted > 0 {
+                push_paragraph(
+                    output,
+                    &Messages::format(msgs.label_informational_omitted, &[&omitted.to_string()]),
+                );
+            }
+        }
+
+        if let Some(report) = project_self_check {
+            push_heading(output, "h3", msgs.section_project_self_check);
+            push_paragraph(output, msgs.desc_project_self_check);
+            if report.actionable.is_empty() && report.informational.is_empty() {
+                push_paragraph(output, msgs.label_project_self_check_none);
+            } else {
+                self.render_vulnerability_table(
+                    output,
+                    report.actionable.iter().chain(report.informational.iter()),
+                );
+            }
+        }
+
+        push_paragraph(output, msgs.label_html_osv_attribution);
+    }
+
+    /// Fills a "Found {} {} in {} {}" template with singular or plural unit words
+    fn count_message(&self, template: &str, vulns: usize, packages: usize) -> String {
+        let msgs = self.messages;
+        let vuln_word = msgs.unit(
+            vulns,
+            msgs.label_vulnerability_singular,
+            msgs.label_vulnerability_plural,
+        );
+        let pkg_word = msgs.unit(
+            packages,
+            msgs.label_package_singular,
+            msgs.label_package_plural,
+        );
+        Messages::format(
+            template,
+            &[
+                &vulns.to_string(),
+                vuln_word,
+                &packages.to_string(),
+                pkg_word,
+            ],
+        )
+    }
+
+    /// Renders a sortable vulnerability table, most severe first, with color-coded
+    /// severity cells; the "Via" column is only shown when it is known
+    fn render_vulnerability_table<'a>(
+        &self,
+        output: &mut String,
+        vulns: impl Iterator<Item = &'a VulnerabilityView>,
+    ) {
+        let msgs = self.messages;
+        let mut sorted: Vec<&VulnerabilityView> = vulns.collect();
+        sorted.sort_by_key(|v| v.severity);
+        let show_aliases = sorted.iter().any(|v| !v.aliases.is_empty());
+        let show_via = sorted.iter().any(|v| !v.introduced_by.is_empty());
+
+        let mut columns = vec![
+            msgs.col_package,
+            msgs.col_current_version,
+            msgs.col_fixed_version,
+            msgs.col_cvss,
+            msgs.col_severity,
+            msgs.col_vuln_id,
+        ];
+        if show_aliases {
+            columns.push(msgs.col_aliases);
+        }
+        if show_via {
+            columns.push(msgs.col_via);
+        }
+        push_table_head(output, &columns);
+
+        for vuln in sorted {
+            let cvss = match (vuln.cvss_score, vuln.cvss_version) {
+                (Some(score), Some(version)) => format!("{:.1} (v{})", score, version.as_str()),
+                (Some(score), None) => format!("{:.1}", score),
+                (None, _) => "N/A".to_string(),
+            };
+            let id = match &vuln.source_url {
+                Some(url) => format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape_html(url),
+                    escape_html(&vuln.id)
+                ),
+                None => escape_html(&vuln.id),
+            };
+            let mut package = escape_html(&vuln.affected_component_name);
+            if vuln.excluded_from_inventory {
+                package.push(' ');
+                package.push_str(&escape_html(msgs.label_excluded_from_inventory));
+            }
+            output.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td>{}<td>{}</td>",
+                package,
+                escape_html(&vuln.affected_version),
+                escape_html(vuln.fixed_version.as_deref().unwrap_or("N/A")),
+                escape_html(&cvss),
+                severity_cell(vuln.severity),
+                id,
+            ));
+            if show_aliases {
+                let aliases = if vuln.aliases.is_empty() {
+                    "-".to_string()
+                } else {
+                    vuln.aliases.join(", ")
+                };
+                output.push_str(&format!("<td>{}</td>", escape_html(&aliases)));
+            }
+            if show_via {
+                output.push_str(&format!("<td>{}</td>", escape_html(&self.via(vuln))));
+            }
+            output.push_str("</tr>\n");
+        }
+        output.push_str("</tbody>\n</table>\n");
+    }
+
+    /// Formats the "Via" cell: the introducing direct dependencies, with the
+    /// package itself shown as "(direct)"
+    fn via(&self, vuln: &VulnerabilityView) -> String {
+        if vuln.introduced_by.is_empty() {
+            return "-".to_string();
+        }
+        vuln.introduced_by
+            .iter()
+            .map(|name| {
+                if *name == vuln.affected_component_name {
+                    self.messages.label_via_direct
+                } else {
+                    name.as_str()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Escapes text for use in HTML element content and quoted attribute values
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Strips the Markdown heading and emphasis markers the shared messages carry
+fn plain(text: &str) -> &str {
+    text.trim_start_matches('#')
+        .trim()
+        .trim_matches(|c| c == '*' || c == '_')
+}
+
+fn push_heading(output: &mut String, tag: &str, text: &str) {
+    output.push_str(&format!("<{tag}>{}</{tag}>\n", escape_html(plain(text))));
+}
+
+fn push_paragraph(output: &mut String, text: &str) {
+    output.push_str(&format!("<p>{}</p>\n", escape_html(plain(text))));
+}
+
+fn push_metadata_entry(output: &mut String, label: &str, value: &str) {
+    output.push_str(&format!(
+        "<dt>{}</dt><dd>{}</dd>\n",
+        escape_html(label),
+        escape_html(value)
+    ));
+}
+
+/// Opens a sortable table with the given column headers and its body
+fn push_table_head(output: &mut String, columns: &[&str]) {
+    output.push_str("<table class=\"sortable\">\n<thead><tr>");
+    for column in columns {
+        output.push_str(&format!("<th>{}</th>", escape_html(column)));
+    }
+    output.push_str("</tr></thead>\n<tbody>\n");
+}
+
+/// Severity cell colored by its `severity-*` class; `data-sort` keeps the most
+/// severe first when the column is sorted ascending
+fn severity_cell(severity: SeverityView) -> String {
+    let rank = match severity {
+        SeverityView::Critical => 0,
+        SeverityView::High => 1,
+        SeverityView::Medium => 2,
+        SeverityView::Low => 3,
+        SeverityView::None => 4,
+    };
+    format!(
+        "<td class=\"severity severity-{}\" data-sort=\"{}\">{}</td>",
+        severity.as_str().to_lowercase(),
+        rank,
+        severity.as_str()
+    )
+}
+
+fn count_unique_packages(vulns: &[VulnerabilityView]) -> usize {
+    let mut packages: Vec<&str> = vulns
+        .iter()
+        .map(|v| v.affected_component_name.as_str())
+        .collect();
+    packages.sort_unstable();
+    packages.dedup();
+    packages.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::{InformationalDisplay, SeverityView};
+    use crate::test_fixtures::SbomReadModelFixture;
+
+    fn format(model: &SbomReadModel) -> String {
+        HtmlFormatter::new(Locale::En).format(model).unwrap()
+    }
+
+    #[test]
+    fn test_format_is_a_self_contained_document() {
+        let output = format(&SbomReadModelFixture::small_clean().build());
+
+        assert!(output.starts_with("<!DOCTYPE html>\n<html lang=\"en\">"));
+        assert!(output.contains("<meta charset=\"utf-8\">"));
+        assert!(output.contains("<style>"));
+        assert!(output.contains("<script>"));
+        assert!(output.trim_end().ends_with("</html>"));
+        assert!(!output.contains("<link"));
+        assert!(!output.contains("src="));
+    }
+
+    #[test]
+    fn test_format_renders_metadata_header() {
+        let output = format(&SbomReadModelFixture::small_clean().build());
+
+        assert!(output.contains("<h1>Software Bill of Materials (SBOM)</h1>"));
+        assert!(output.contains("<dt>Tool</dt><dd>uv-sbom 1.0.0</dd>"));
+        assert!(output.contains("<dt>Generated</dt><dd>2024-01-01T00:00:00Z</dd>"));
+        assert!(output.contains(
+            "<dt>Serial number</dt><dd>urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79</dd>"
+        ));
+    }
+
+    #[test]
+    fn test_format_renders_component_inventory() {
+        let output = format(&SbomReadModelFixture::small_clean().build());
+
+        assert!(output.contains("<h2>Component Inventory</h2>"));
+        assert!(output.contains("<table class=\"sortable\">"));
+        assert!(output.contains(
+            "<tr><td>requests</td><td>2.31.0</td><td>Apache-2.0</td><td>HTTP library</td></tr>"
+        ));
+        assert!(!output.contains("<h2>Direct Dependencies</h2>"));
+        assert!(!output.contains("<h2>Vulnerability Report</h2>"));
+    }
+
+    #[test]
+    fn test_format_renders_dependency_sections() {
+        let mut fixture = SbomReadModelFixture::small_clean();
+        let model = fixture
+            .with_dependencies(&[("requests", &["urllib3"])])
+            .build();
+
+        let output = format(&model);
+
+        assert!(output.contains("<h2>Direct Dependencies</h2>"));
+        assert!(output.contains("<h2>Transitive Dependencies</h2>"));
+        assert!(output.contains("<h3>Dependencies for requests</h3>"));
+        assert!(output.contains("<tr><td>urllib3</td><td>1.26.0</td><td>MIT</td>"));
+    }
+
+    #[test]
+    fn test_format_renders_unreferenced_packages() {
+        let mut model = SbomReadModelFixture::small_clean()
+            .with_dependencies(&[])
+            .build();
+        let urllib3 = model
+            .components
+            .iter()
+            .find(|c| c.name == "urllib3")
+            .map(|c| c.bom_ref.clone())
+            .unwrap();
+        if let Some(deps) = model.dependencies.as_mut() {
+            deps.unreferenced.push(urllib3);
+        }
+
+        let output = format(&model);
+
+        let section = output
+            .split("<h2>Unreferenced Packages</h2>")
+            .nth(1)
+            .expect("unreferenced section");
+        assert!(section.contains("<tr><td>urllib3</td><td>1.26.0</td><td>MIT</td>"));
+    }
+
+    #[test]
+    fn test_format_color_codes_severity_cells() {
+        let output = format(&SbomReadModelFixture::with_vulns().build());
+
+        assert!(output.contains("<h2>Vulnerability Report</h2>"));
+        assert!(output
+            .contains("<td class=\"severity severity-critical\" data-sort=\"0\">CRITICAL</td>"));
+        assert!(output.contains("<td class=\"severity severity-low\" data-sort=\"3\">LOW</td>"));
+        assert!(output.contains(".severity-critical {"));
+        assert!(output.contains("<td>CVE-2024-1234</td>"));
+    }
+
+    #[test]
+    fn test_format_limits_informational_table() {
+        let model = SbomReadModelFixture::with_vulns()
+            .build()
+            .with_informational_display(InformationalDisplay::Limit(0));
+
+        let output = format(&model);
+
+        assert!(output.contains("Info Found 1 vulnerability in 1 package."));
+        assert!(!output.contains("<td>CVE-2024-5678</td>"));
+        assert!(output.contains("<p>… and 1 more</p>"));
+        assert!(output.contains("<td>CVE-2024-1234</td>"));
+
+        let model = SbomReadModelFixture::with_vulns()
+            .build()
+            .with_informational_display(InformationalDisplay::Hidden);
+        let output = format(&model);
+        assert!(!output.contains("Info Found"));
+        assert!(!output.contains("<td>CVE-2024-5678</td>"));
+    }
+
+    #[test]
+    fn test_format_escapes_names_and_descriptions() {
+        let mut fixture = SbomReadModelFixture::builder();
+        fixture
+            .add_component("evil<script>", "1.0&2")
+            .description("Says \"hi\" & 'bye' <b>loudly</b>");
+        let output = format(&fixture.build());
+
+        assert!(output.contains("<td>evil&lt;script&gt;</td><td>1.0&amp;2</td>"));
+        assert!(output.contains(
+            "<td>Says &quot;hi&quot; &amp; &#39;bye&#39; &lt;b&gt;loudly&lt;/b&gt;</td>"
+        ));
+        assert!(!output.contains("evil<script>"));
+        assert!(!output.contains("<b>loudly</b>"));
+    }
+
+    #[test]
+    fn test_format_uses_locale() {
+        let output = HtmlFormatter::new(Locale::Ja)
+            .format(&SbomReadModelFixture::small_clean().build())
+            .unwrap();
+
+        assert!(output.contains("<html lang=\"ja\">"));
+        assert!(output.contains("<h2>コンポーネント一覧</h2>"));
+    }
+
+    #[test]
+    fn test_severity_cell_ranks_sort_most_severe_first() {
+        assert!(severity_cell(SeverityView::High).contains("data-sort=\"1\""));
+        assert!(severity_cell(SeverityView::None).contains("severity-none"));
+    }
+}
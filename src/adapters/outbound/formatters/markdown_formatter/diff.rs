@@ -0,0 +1,214 @@
+use super::table::{escape_markdown_table_cell, make_separator};
+use super::MarkdownFormatter;
+use crate::application::read_models::{DiffPackageView, SbomDiffView};
+use crate::i18n::Messages;
+use crate::ports::outbound::SbomDiffFormatter;
+use crate::shared::Result;
+
+/// Placeholder for a side without license data
+const NO_LICENSE: &str = "N/A";
+
+impl SbomDiffFormatter for MarkdownFormatter {
+    /// Renders the comparison as a summary line followed by one table per
+    /// non-empty group: added, removed, updated and license-only changes.
+    fn format_diff(&self, diff: &SbomDiffView) -> Result<String> {
+        let messages = self.messages;
+        let mut output = String::new();
+        output.push_str(messages.section_diff_title);
+        output.push_str("\n\n");
+
+        if diff.is_empty() {
+            output.push_str(messages.label_diff_no_changes);
+            output.push('\n');
+            return Ok(output);
+        }
+
+        output.push_str(&Messages::format(
+            messages.label_diff_summary,
+            &[
+                &diff.added.len().to_string(),
+                &diff.removed.len().to_string(),
+                &diff.updated.len().to_string(),
+                &diff.license_changed.len().to_string(),
+            ],
+        ));
+        output.push('\n');
+
+        render_packages(
+            messages,
+            &mut output,
+            messages.section_diff_added,
+            &diff.added,
+        );
+        render_packages(
+            messages,
+            &mut output,
+            messages.section_diff_removed,
+            &diff.removed,
+        );
+
+        if !diff.updated.is_empty() {
+            let cols = [
+                messages.col_package,
+                messages.col_old_version,
+                messages.col_new_version,
+            ];
+            push_table_start(&mut output, messages.section_diff_updated, &cols);
+            for change in &diff.updated {
+                output.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    escape_markdown_table_cell(&change.name),
+                    escape_markdown_table_cell(&change.old_version),
+                    escape_markdown_table_cell(&change.new_version),
+                ));
+            }
+        }
+
+        if !diff.license_changed.is_empty() {
+            let cols = [
+                messages.col_package,
+                messages.col_version,
+                messages.col_old_license,
+                messages.col_new_license,
+            ];
+            push_table_start(&mut output, messages.section_diff_license_changed, &cols);
+            for change in &diff.license_changed {
+                output.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    escape_markdown_table_cell(&change.name),
+                    escape_markdown_table_cell(&change.version),
+                    escape_markdown_table_cell(change.old_license.as_deref().unwrap_or(NO_LICENSE)),
+                    escape_markdown_table_cell(change.new_license.as_deref().unwrap_or(NO_LICENSE)),
+                ));
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+/// Renders the added or removed packages table, skipped when `packages` is empty
+fn render_packages(
+    messages: &'static Messages,
+    output: &mut String,
+    heading: &str,
+    packages: &[DiffPackageView],
+) {
+    if packages.is_empty() {
+        return;
+    }
+    let cols = [
+        messages.col_package,
+        messages.col_version,
+        messages.col_license,
+    ];
+    push_table_start(output, heading, &cols);
+    for package in packages {
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            escape_markdown_table_cell(&package.name),
+            escape_markdown_table_cell(&package.version),
+            escape_markdown_table_cell(package.license.as_deref().unwrap_or(NO_LICENSE)),
+        ));
+    }
+}
+
+fn push_table_start(output: &mut String, heading: &str, cols: &[&str]) {
+    output.push('\n');
+    output.push_str(heading);
+    output.push_str("\n\n");
+    output.push_str(&format!("| {} |\n", cols.join(" | ")));
+    output.push_str(&make_separator(cols));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::{LicenseChangeView, VersionChangeView};
+    use crate::i18n::Locale;
+
+    fn package(name: &str, version: &str, license: Option<&str>) -> DiffPackageView {
+        DiffPackageView {
+            name: name.to_string(),
+            version: version.to_string(),
+            license: license.map(str::to_string),
+        }
+    }
+
+    fn sample_diff() -> SbomDiffView {
+        SbomDiffView {
+            added: vec![package("idna", "3.7", Some("BSD-3-Clause"))],
+            removed: vec![package("chardet", "5.2.0", None)],
+            updated: vec![VersionChangeView {
+                name: "requests".to_string(),
+                old_version: "2.31.0".to_string(),
+                new_version: "2.32.3".to_string(),
+                old_license: None,
+                new_license: None,
+            }],
+            license_changed: vec![LicenseChangeView {
+                name: "pyyaml".to_string(),
+                version: "6.0.1".to_string(),
+                old_license: Some("MIT".to_string()),
+                new_license: Some("MIT OR Apache-2.0".to_string()),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_format_diff_sections() {
+        let output = MarkdownFormatter::new(Locale::En)
+            .format_diff(&sample_diff())
+            .unwrap();
+
+        assert_eq!(
+            output,
+            "# SBOM Diff\n\n\
+             **1 added, 1 removed, 1 updated, 1 license changes.**\n\
+             \n### Added\n\n\
+             | Package | Version | License |\n\
+             |---------|---------|---------|\n\
+             | idna | 3.7 | BSD-3-Clause |\n\
+             \n### Removed\n\n\
+             | Package | Version | License |\n\
+             |---------|---------|---------|\n\
+             | chardet | 5.2.0 | N/A |\n\
+             \n### Updated\n\n\
+             | Package | Old Version | New Version |\n\
+             |---------|-------------|-------------|\n\
+             | requests | 2.31.0 | 2.32.3 |\n\
+             \n### License Changes\n\n\
+             | Package | Version | Old License | New License |\n\
+             |---------|---------|-------------|-------------|\n\
+             | pyyaml | 6.0.1 | MIT | MIT OR Apache-2.0 |\n"
+        );
+    }
+
+    #[test]
+    fn test_format_diff_skips_empty_groups() {
+        let diff = SbomDiffView {
+            added: vec![package("idna", "3.7", None)],
+            ..Default::default()
+        };
+
+        let output = MarkdownFormatter::new(Locale::Ja)
+            .format_diff(&diff)
+            .unwrap();
+
+        assert!(output.starts_with("# SBOM 差分\n"));
+        assert!(output.contains("### 追加"));
+        assert!(!output.contains("### 削除"));
+        assert!(!output.contains("### 更新"));
+    }
+
+    #[test]
+    fn test_format_diff_without_changes() {
+        let output = MarkdownFormatter::new(Locale::En)
+            .format_diff(&SbomDiffView::default())
+            .unwrap();
+        assert_eq!(
+            output,
+            "# SBOM Diff\n\nNo package changes between the two SBOMs.\n"
+        );
+    }
+}
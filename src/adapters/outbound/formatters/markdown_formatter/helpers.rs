@@ -74,41 +74,56 @@ mod tests {
             VulnerabilityView {
                 bom_ref: "v1".to_string(),
                 id: "CVE-1".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/a@1.0".to_string(),
                 affected_component_name: "a".to_string(),
                 affected_version: "1.0".to_string(),
                 cvss_score: None,
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: None,
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             },
             VulnerabilityView {
                 bom_ref: "v2".to_string(),
                 id: "CVE-2".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/a@1.0".to_string(),
                 affected_component_name: "a".to_string(),
                 affected_version: "1.0".to_string(),
                 cvss_score: None,
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::Medium,
                 fixed_version: None,
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             },
             VulnerabilityView {
                 bom_ref: "v3".to_string(),
                 id: "CVE-3".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/b@2.0".to_string(),
                 affected_component_name: "b".to_string(),
                 affected_version: "2.0".to_string(),
                 cvss_score: None,
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::Low,
                 fixed_version: None,
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             },
         ];
 
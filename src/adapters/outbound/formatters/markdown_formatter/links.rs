@@ -13,10 +13,18 @@ pub(super) fn package_to_pypi_link(name: &str) -> String {
     format!(
         "[{}](https://pypi.org/project/{}/)",
         table::escape_markdown_table_cell(name),
-        normalized
+        escape_link_target(&normalized)
     )
 }
 
+/// Percent-encodes the characters that would end a link target or the table cell
+fn escape_link_target(url: &str) -> String {
+    url.replace(' ', "%20")
+        .replace('(', "%28")
+        .replace(')', "%29")
+        .replace('|', "%7C")
+}
+
 /// Generate a Markdown hyperlink for a vulnerability ID based on its prefix.
 ///
 /// - `CVE-*` → NVD (NIST)
@@ -30,7 +38,26 @@ pub(super) fn vulnerability_id_to_link(id: &str) -> String {
     } else {
         format!("https://osv.dev/vulnerability/{}", id)
     };
-    format!("[{}]({})", table::escape_markdown_table_cell(id), url)
+    format!(
+        "[{}]({})",
+        table::escape_markdown_table_cell(id),
+        escape_link_target(&url)
+    )
+}
+
+/// Generate a Markdown hyperlink for a vulnerability ID to its advisory URL,
+/// falling back to the ID-prefix based link when the URL is unknown.
+///
+/// Characters that would end the link target or the table cell are percent-encoded.
+pub(super) fn vulnerability_to_link(id: &str, source_url: Option<&str>) -> String {
+    match source_url {
+        Some(url) => format!(
+            "[{}]({})",
+            table::escape_markdown_table_cell(id),
+            escape_link_target(url)
+        ),
+        None => vulnerability_id_to_link(id),
+    }
 }
 
 /// Format a package name as a PyPI link or plain text based on verification results.
@@ -72,6 +99,11 @@ mod tests {
                 tool_version: "1.0.0".to_string(),
                 serial_number: "urn:uuid:test-123".to_string(),
                 component: None,
+                lockfile_sha256: None,
+                target_environment: None,
+                invocation: None,
+                lockfile_version: None,
+                lockfile_requires_python: None,
             },
             components: vec![
                 ComponentView {
@@ -82,10 +114,22 @@ mod tests {
                     license: Some(LicenseView {
                         spdx_id: Some("Apache-2.0".to_string()),
                         name: "Apache License 2.0".to_string(),
+                        source: None,
                     }),
                     description: Some("HTTP library".to_string()),
                     sha256_hash: None,
                     is_direct_dependency: true,
+                    annotations: Default::default(),
+                    license_override: None,
+                    yanked: None,
+                    source: None,
+                    declared_constraint: None,
+                    license_not_found: false,
+                    license_fetch_error: None,
+                    python_compat: None,
+                    author: None,
+                    supplier: None,
+                    external_refs: Vec::new(),
                 },
                 ComponentView {
                     bom_ref: "pkg:pypi/urllib3@1.26.0".to_string(),
@@ -95,17 +139,39 @@ mod tests {
                     license: Some(LicenseView {
                         spdx_id: Some("MIT".to_string()),
                         name: "MIT License".to_string(),
+                        source: None,
                     }),
                     description: None,
                     sha256_hash: None,
                     is_direct_dependency: false,
+                    annotations: Default::default(),
+                    license_override: None,
+                    yanked: None,
+                    source: None,
+                    declared_constraint: None,
+                    license_not_found: false,
+                    license_fetch_error: None,
+                    python_compat: None,
+                    author: None,
+                    supplier: None,
+                    external_refs: Vec::new(),
                 },
             ],
             dependencies: None,
             vulnerabilities: None,
             license_compliance: None,
+            license_compatibility: None,
+            license_risk: None,
+            python_compatibility: None,
+            typosquats: Vec::new(),
             resolution_guide: None,
             upgrade_recommendations: None,
+            fix_suggestions: None,
+            project_self_check: None,
+            warnings: Vec::new(),
+            stats: None,
+            projects: Vec::new(),
+            version_conflicts: Vec::new(),
         }
     }
 
@@ -174,6 +240,9 @@ mod tests {
         model.dependencies = Some(DependencyView {
             direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
             transitive,
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
         });
 
         let formatter = MarkdownFormatter::new(Locale::En);
@@ -192,21 +261,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(9.8),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::Critical,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
 
         let formatter = MarkdownFormatter::new(Locale::En);
@@ -268,21 +344,28 @@ mod tests {
             actionable: vec![VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1234".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(9.8),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::Critical,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             }],
             informational: vec![],
+            ignored: vec![],
             summary: VulnerabilitySummary {
                 total_count: 1,
                 affected_package_count: 1,
             },
+            ..Default::default()
         });
 
         // "requests" is NOT in verified set
@@ -351,4 +434,27 @@ mod tests {
             "[RUSTSEC-2023-0001](https://osv.dev/vulnerability/RUSTSEC-2023-0001)"
         );
     }
+
+    #[test]
+    fn test_vulnerability_to_link_uses_source_url() {
+        assert_eq!(
+            vulnerability_to_link(
+                "CVE-2024-1234",
+                Some("https://github.com/org/repo/security/advisories/GHSA-abcd")
+            ),
+            "[CVE-2024-1234](https://github.com/org/repo/security/advisories/GHSA-abcd)"
+        );
+        assert_eq!(
+            vulnerability_to_link("PYSEC-2024-1", Some("https://example.com/a (b)|c")),
+            "[PYSEC-2024-1](https://example.com/a%20%28b%29%7Cc)"
+        );
+    }
+
+    #[test]
+    fn test_vulnerability_to_link_without_source_url() {
+        assert_eq!(
+            vulnerability_to_link("CVE-2024-1234", None),
+            vulnerability_id_to_link("CVE-2024-1234")
+        );
+    }
 }
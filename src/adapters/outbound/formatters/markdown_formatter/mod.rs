@@ -1,6 +1,8 @@
+mod diff;
 mod helpers;
 mod links;
 mod sections;
+mod style;
 mod table;
 mod vuln_render;
 
@@ -10,6 +12,8 @@ use crate::ports::outbound::SbomFormatter;
 use crate::shared::Result;
 use std::collections::HashSet;
 
+pub use style::MarkdownStyle;
+
 /// MarkdownFormatter adapter for generating detailed Markdown SBOM with dependency information
 ///
 /// This adapter implements the SbomFormatter port for Markdown format,
@@ -19,6 +23,7 @@ pub struct MarkdownFormatter {
     /// When None, all packages get PyPI hyperlinks (default behavior).
     verified_packages: Option<HashSet<String>>,
     messages: &'static Messages,
+    style: MarkdownStyle,
 }
 
 impl MarkdownFormatter {
@@ -26,6 +31,7 @@ impl MarkdownFormatter {
         Self {
             verified_packages: None,
             messages: Messages::for_locale(locale),
+            style: MarkdownStyle::default(),
         }
     }
 
@@ -35,8 +41,15 @@ impl MarkdownFormatter {
         Self {
             verified_packages: Some(verified_packages),
             messages: Messages::for_locale(locale),
+            style: MarkdownStyle::default(),
         }
     }
+
+    /// Renders severities and status markers in the given style instead of the default emoji
+    pub fn with_style(mut self, style: MarkdownStyle) -> Self {
+        self.style = style;
+        self
+    }
 }
 
 impl Default for MarkdownFormatter {
@@ -45,66 +58,219 @@ impl Default for MarkdownFormatter {
     }
 }
 
+/// File names used by [`MarkdownFormatter::format_split`], in rendering order.
+const SPLIT_INDEX_FILE: &str = "index.md";
+const SPLIT_COMPONENTS_FILE: &str = "components.md";
+const SPLIT_DEPENDENCIES_FILE: &str = "dependencies.md";
+const SPLIT_VULNERABILITIES_FILE: &str = "vulnerabilities.md";
+
+/// Rendered bodies of the report, grouped the way `--markdown-split` writes them.
+///
+/// Concatenating the bodies in field order yields the single-file report.
+struct SectionBodies {
+    /// Summary and SBOM title
+    index: String,
+    /// Component inventory
+    components: String,
+    /// Direct and transitive dependencies
+    dependencies: String,
+    /// Vulnerability report, resolution guide, license compliance and compatibility,
+    /// warnings appendix, generation summary and generation parameters
+    vulnerabilities: String,
+}
+
 impl MarkdownFormatter {
-    /// Renders the three always-present sections in fixed order: summary, header, components.
-    fn render_required_sections(&self, output: &mut String, model: &SbomReadModel) {
+    /// Renders every section into its group, reusing the same section renderers
+    /// for both the single-file and the split output.
+    fn render_bodies(&self, model: &SbomReadModel) -> SectionBodies {
+        let mut index = String::new();
         sections::summary::render(
             self.messages,
-            output,
+            &mut index,
             &model.components,
             model.vulnerabilities.as_ref(),
+            model.unchecked_package_count(),
             model.license_compliance.as_ref(),
+            model.license_compatibility.as_ref(),
         );
-        sections::header::render(self.messages, output);
+        sections::header::render(
+            self.messages,
+            &mut index,
+            model.metadata.component.as_ref(),
+            model.metadata.target_environment.as_deref(),
+        );
+        if !model.projects.is_empty() {
+            sections::projects::render(self.messages, &mut index, &model.projects);
+        }
+
+        let mut components = String::new();
         sections::components::render(
             self.messages,
             self.verified_packages.as_ref(),
-            output,
+            &mut components,
             &model.components,
+            self.style,
         );
-    }
+        if !model.version_conflicts.is_empty() {
+            sections::version_conflicts::render(
+                self.messages,
+                &mut components,
+                &model.version_conflicts,
+            );
+        }
 
-    /// Renders the four conditional sections when present: dependencies, vulnerabilities,
-    /// resolution guide, and license compliance.
-    fn render_optional_sections(&self, output: &mut String, model: &SbomReadModel) {
+        let mut dependencies = String::new();
         if let Some(deps) = &model.dependencies {
             sections::dependencies::render(
                 self.messages,
                 self.verified_packages.as_ref(),
-                output,
+                &mut dependencies,
                 deps,
                 &model.components,
+                self.style,
             );
         }
+
+        let mut vulnerabilities = String::new();
         if let Some(vulns) = &model.vulnerabilities {
             vuln_render::render_vulnerabilities(
                 self.messages,
+                self.style,
                 self.verified_packages.as_ref(),
-                output,
+                &mut vulnerabilities,
                 vulns,
+                model.project_self_check.as_ref(),
+                model.unchecked_package_count(),
             );
         }
         if let Some(guide) = &model.resolution_guide {
             if !guide.entries.is_empty() {
                 sections::resolution_guide::render(
                     self.messages,
-                    output,
+                    self.style,
+                    &mut vulnerabilities,
                     guide,
                     model.upgrade_recommendations.as_ref(),
                 );
             }
         }
+        if let Some(suggestions) = &model.fix_suggestions {
+            sections::fix_suggestions::render(self.messages, &mut vulnerabilities, suggestions);
+        }
         if let Some(compliance) = &model.license_compliance {
-            sections::license_compliance::render(self.messages, output, compliance);
+            sections::license_compliance::render(self.messages, &mut vulnerabilities, compliance);
+        }
+        if let Some(compatibility) = &model.license_compatibility {
+            sections::license_compatibility::render(
+                self.messages,
+                &mut vulnerabilities,
+                compatibility,
+            );
+        }
+        if let Some(risk) = &model.license_risk {
+            sections::license_risk::render(self.messages, &mut vulnerabilities, risk);
+        }
+        if let Some(compatibility) = &model.python_compatibility {
+            sections::python_compatibility::render(
+                self.messages,
+                &mut vulnerabilities,
+                compatibility,
+                &model.components,
+            );
+        }
+        if !model.typosquats.is_empty() {
+            sections::supply_chain_warnings::render(
+                self.messages,
+                &mut vulnerabilities,
+                &model.typosquats,
+            );
+        }
+        if !model.warnings.is_empty() {
+            sections::warnings::render(self.messages, &mut vulnerabilities, &model.warnings);
+        }
+        if let Some(stats) = &model.stats {
+            sections::generation_summary::render(self.messages, &mut vulnerabilities, stats);
+        }
+        if let Some(invocation) = &model.metadata.invocation {
+            sections::generation_parameters::render(
+                self.messages,
+                &mut vulnerabilities,
+                invocation,
+            );
+        }
+
+        SectionBodies {
+            index: self.style.apply_markers(index),
+            components: self.style.apply_markers(components),
+            dependencies: self.style.apply_markers(dependencies),
+            vulnerabilities: self.style.apply_markers(vulnerabilities),
         }
     }
+
+    /// Renders the report as several linked files for `--markdown-split`.
+    ///
+    /// Returns `(file name, content)` pairs starting with [`SPLIT_INDEX_FILE`], which
+    /// holds the summary and a table of contents. Each other file starts with a link
+    /// back to the index followed by exactly the body it has in the single-file output.
+    /// Groups with an empty body (e.g. no vulnerability check) are omitted.
+    pub fn format_split(&self, model: &SbomReadModel) -> Vec<(String, String)> {
+        let bodies = self.render_bodies(model);
+        let parts = [
+            (
+                SPLIT_COMPONENTS_FILE,
+                self.messages.label_split_components,
+                bodies.components,
+            ),
+            (
+                SPLIT_DEPENDENCIES_FILE,
+                self.messages.label_split_dependencies,
+                bodies.dependencies,
+            ),
+            (
+                SPLIT_VULNERABILITIES_FILE,
+                self.messages.label_split_vulnerabilities,
+                bodies.vulnerabilities,
+            ),
+        ];
+
+        let mut index = bodies.index;
+        index.push_str(self.messages.label_split_contents);
+        index.push_str("\n\n");
+        for (file, label, body) in &parts {
+            if !body.is_empty() {
+                index.push_str(&format!("- [{}]({})\n", label, file));
+            }
+        }
+
+        let mut files = vec![(SPLIT_INDEX_FILE.to_string(), index)];
+        for (file, _, body) in parts {
+            if body.is_empty() {
+                continue;
+            }
+            let content = format!(
+                "[{}]({})\n\n{}",
+                self.messages.label_split_back_to_index, SPLIT_INDEX_FILE, body
+            );
+            files.push((file.to_string(), content));
+        }
+        files
+    }
+
+    /// Renders only the vulnerability report and what follows it, for `uv-sbom scan`.
+    ///
+    /// This is the body `--markdown-split` writes to [`SPLIT_VULNERABILITIES_FILE`].
+    pub fn format_vulnerability_report(&self, model: &SbomReadModel) -> String {
+        self.render_bodies(model).vulnerabilities
+    }
 }
 
 impl SbomFormatter for MarkdownFormatter {
     fn format(&self, model: &SbomReadModel) -> Result<String> {
-        let mut output = String::new();
-        self.render_required_sections(&mut output, model);
-        self.render_optional_sections(&mut output, model);
+        let bodies = self.render_bodies(model);
+        let mut output = bodies.index;
+        output.push_str(&bodies.components);
+        output.push_str(&bodies.dependencies);
+        output.push_str(&bodies.vulnerabilities);
         Ok(output)
     }
 }
@@ -112,204 +278,36 @@ impl SbomFormatter for MarkdownFormatter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::read_models::{
-        ComponentView, DependencyView, LicenseView, SeverityView,
-    };
+    use crate::application::read_models::{DependencyView, SeverityView};
     use crate::i18n::Locale;
+    use crate::test_fixtures::SbomReadModelFixture;
     use std::collections::HashMap;
 
-    mod test_fixtures {
-        use crate::application::read_models::{
-            ComponentView, LicenseView, SbomMetadataView, SbomReadModel, SeverityView,
-            VulnerabilityReportView, VulnerabilitySummary, VulnerabilityView,
+    /// Requests/urllib3 model with one actionable CVE-2024-1234 on requests.
+    fn with_vulnerability(severity: SeverityView) -> SbomReadModel {
+        let mut fixture = SbomReadModelFixture::small_clean();
+        let vuln = fixture
+            .add_vulnerability("CVE-2024-1234")
+            .severity(severity)
+            .on("requests", "2.31.0");
+        match severity {
+            SeverityView::Critical => vuln.cvss(9.8).fixed_in("2.32.0"),
+            SeverityView::High => vuln.cvss(7.5).fixed_in("2.32.0"),
+            SeverityView::Medium => vuln.cvss(5.0),
+            SeverityView::Low => vuln.cvss(3.1),
+            SeverityView::None => vuln,
         };
+        fixture.build()
+    }
 
-        pub(super) fn base_model() -> SbomReadModel {
-            SbomReadModel {
-                metadata: SbomMetadataView {
-                    timestamp: "2024-01-01T00:00:00Z".to_string(),
-                    tool_name: "uv-sbom".to_string(),
-                    tool_version: "1.0.0".to_string(),
-                    serial_number: "urn:uuid:test-123".to_string(),
-                    component: None,
-                },
-                components: vec![
-                    ComponentView {
-                        bom_ref: "pkg:pypi/requests@2.31.0".to_string(),
-                        name: "requests".to_string(),
-                        version: "2.31.0".to_string(),
-                        purl: "pkg:pypi/requests@2.31.0".to_string(),
-                        license: Some(LicenseView {
-                            spdx_id: Some("Apache-2.0".to_string()),
-                            name: "Apache License 2.0".to_string(),
-                        }),
-                        description: Some("HTTP library".to_string()),
-                        sha256_hash: None,
-                        is_direct_dependency: true,
-                    },
-                    ComponentView {
-                        bom_ref: "pkg:pypi/urllib3@1.26.0".to_string(),
-                        name: "urllib3".to_string(),
-                        version: "1.26.0".to_string(),
-                        purl: "pkg:pypi/urllib3@1.26.0".to_string(),
-                        license: Some(LicenseView {
-                            spdx_id: Some("MIT".to_string()),
-                            name: "MIT License".to_string(),
-                        }),
-                        description: None,
-                        sha256_hash: None,
-                        is_direct_dependency: false,
-                    },
-                ],
-                dependencies: None,
-                vulnerabilities: None,
-                license_compliance: None,
-                resolution_guide: None,
-                upgrade_recommendations: None,
-            }
-        }
-
-        pub(super) fn with_vulnerabilities(severity: SeverityView) -> SbomReadModel {
-            let mut model = base_model();
-            let cvss_score = match severity {
-                SeverityView::Critical => Some(9.8),
-                SeverityView::High => Some(7.5),
-                SeverityView::Medium => Some(5.0),
-                SeverityView::Low => Some(3.1),
-                SeverityView::None => None,
-            };
-            let fixed_version = match severity {
-                SeverityView::Critical | SeverityView::High => Some("2.32.0".to_string()),
-                _ => None,
-            };
-            model.vulnerabilities = Some(VulnerabilityReportView {
-                actionable: vec![VulnerabilityView {
-                    bom_ref: "vuln-001".to_string(),
-                    id: "CVE-2024-1234".to_string(),
-                    affected_component: "pkg:pypi/requests@2.31.0".to_string(),
-                    affected_component_name: "requests".to_string(),
-                    affected_version: "2.31.0".to_string(),
-                    cvss_score,
-                    cvss_vector: None,
-                    severity,
-                    fixed_version,
-                    description: None,
-                    source_url: None,
-                }],
-                informational: vec![],
-                summary: VulnerabilitySummary {
-                    total_count: 1,
-                    affected_package_count: 1,
-                },
-            });
-            model
-        }
-
-        /// Returns a model with one Critical-severity actionable vulnerability (CVE-2024-1234).
-        pub(super) fn with_critical_vuln() -> SbomReadModel {
-            with_vulnerabilities(SeverityView::Critical)
-        }
+    fn with_critical_vuln() -> SbomReadModel {
+        with_vulnerability(SeverityView::Critical)
+    }
 
-        /// Returns a model with an empty vulnerability report (no actionable or informational).
-        pub(super) fn with_empty_vuln_report() -> SbomReadModel {
-            let mut model = base_model();
-            model.vulnerabilities = Some(VulnerabilityReportView {
-                actionable: vec![],
-                informational: vec![],
-                summary: VulnerabilitySummary {
-                    total_count: 0,
-                    affected_package_count: 0,
-                },
-            });
-            model
-        }
-
-        /// Returns a model with two actionable vulnerabilities on the same package (Critical + High).
-        pub(super) fn with_two_actionable_vulns() -> SbomReadModel {
-            let mut model = base_model();
-            model.vulnerabilities = Some(VulnerabilityReportView {
-                actionable: vec![
-                    make_vuln(
-                        "CVE-2024-1234",
-                        "pkg:pypi/requests@2.31.0",
-                        "requests",
-                        "2.31.0",
-                        SeverityView::Critical,
-                        Some(9.8_f32),
-                        Some("2.32.0"),
-                    ),
-                    make_vuln(
-                        "CVE-2024-5678",
-                        "pkg:pypi/requests@2.31.0",
-                        "requests",
-                        "2.31.0",
-                        SeverityView::High,
-                        Some(7.5_f32),
-                        None,
-                    ),
-                ],
-                informational: vec![],
-                summary: VulnerabilitySummary {
-                    total_count: 2,
-                    affected_package_count: 1,
-                },
-            });
-            model
-        }
-
-        /// Returns a model with one Critical actionable and one Low informational vulnerability.
-        pub(super) fn with_actionable_and_informational_vulns() -> SbomReadModel {
-            let mut model = base_model();
-            model.vulnerabilities = Some(VulnerabilityReportView {
-                actionable: vec![make_vuln(
-                    "CVE-2024-1234",
-                    "pkg:pypi/requests@2.31.0",
-                    "requests",
-                    "2.31.0",
-                    SeverityView::Critical,
-                    Some(9.8_f32),
-                    Some("2.32.0"),
-                )],
-                informational: vec![make_vuln(
-                    "CVE-2024-5678",
-                    "pkg:pypi/urllib3@1.26.0",
-                    "urllib3",
-                    "1.26.0",
-                    SeverityView::Low,
-                    Some(2.0_f32),
-                    None,
-                )],
-                summary: VulnerabilitySummary {
-                    total_count: 2,
-                    affected_package_count: 2,
-                },
-            });
-            model
-        }
-
-        fn make_vuln(
-            id: &str,
-            affected_component: &str,
-            name: &str,
-            version: &str,
-            severity: SeverityView,
-            cvss_score: Option<f32>,
-            fixed_version: Option<&str>,
-        ) -> VulnerabilityView {
-            VulnerabilityView {
-                bom_ref: id.to_string(),
-                id: id.to_string(),
-                affected_component: affected_component.to_string(),
-                affected_component_name: name.to_string(),
-                affected_version: version.to_string(),
-                cvss_score,
-                cvss_vector: None,
-                severity,
-                fixed_version: fixed_version.map(str::to_string),
-                description: None,
-                source_url: None,
-            }
-        }
+    fn with_dependencies() -> SbomReadModel {
+        SbomReadModelFixture::small_clean()
+            .with_dependencies(&[("requests", &["urllib3"])])
+            .build()
     }
 
     fn assert_ja_output_contains(
@@ -357,9 +355,101 @@ mod tests {
         }
     }
 
+    /// Number of cells in a table row, not counting escaped pipes
+    fn table_cell_count(row: &str) -> usize {
+        let mut count: usize = 0;
+        let mut chars = row.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '|' => count += 1,
+                _ => {}
+            }
+        }
+        count.saturating_sub(1)
+    }
+
+    /// Asserts every row of each table has as many cells as its header
+    fn assert_tables_well_formed(markdown: &str) {
+        let mut header_cells = None;
+        for line in markdown.lines() {
+            if !line.starts_with('|') {
+                header_cells = None;
+                continue;
+            }
+            let cells = table_cell_count(line);
+            let expected = *header_cells.get_or_insert(cells);
+            assert_eq!(
+                cells, expected,
+                "malformed table row {line:?} in:\n{markdown}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_sanitizes_adversarial_package_metadata() {
+        let descriptions = [
+            "# Heading | injected\n> quote\n- item",
+            "[click me](https://evil.example) ![img](https://evil.example/x.png)",
+            "\u{202E}txt.exe\u{202C} and zero\u{200B}width\u{2066}isolate\u{2069}",
+            "`code | span` with \u{7}bell and \u{1B}[31mescape",
+            "<script>alert(1)</script> |||",
+        ];
+        let long = "long description ".repeat(40);
+        let mut fixture = SbomReadModelFixture::builder();
+        fixture
+            .add_component("evil|pkg", "1.0|0")
+            .license("MIT | GPL")
+            .description(&long)
+            .direct();
+        for (i, description) in descriptions.iter().enumerate() {
+            fixture
+                .add_component(&format!("pkg-{i}"), "1.0.0")
+                .description(description);
+        }
+        let children: Vec<String> = (0..descriptions.len())
+            .map(|i| format!("pkg-{i}"))
+            .collect();
+        let children: Vec<&str> = children.iter().map(String::as_str).collect();
+        fixture.with_dependencies(&[("evil|pkg", &children)]);
+        fixture
+            .add_vulnerability("GHSA-evil|id)")
+            .severity(SeverityView::High)
+            .fixed_in("2.0|0")
+            .on("evil|pkg", "1.0|0");
+        let model = fixture.build();
+
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+
+        assert_tables_well_formed(&markdown);
+        for invisible in ['\u{202E}', '\u{200B}', '\u{2066}', '\u{7}', '\u{1B}'] {
+            assert!(!markdown.contains(invisible), "{invisible:?} not stripped");
+        }
+        assert!(markdown.contains("| \\# Heading \\| injected > quote - item |"));
+        assert!(markdown.contains("\\[click me\\](https://evil.example)"));
+        assert!(markdown.contains("\\`code \\| span\\`"));
+        // 200 characters including the ellipsis
+        assert!(markdown.contains(" long descrip… |"));
+        assert!(!markdown.contains(long.trim_end()));
+
+        let tree = MarkdownFormatter::new(Locale::En)
+            .with_style(MarkdownStyle::default().with_tree_depth(Some(0)))
+            .format(&model)
+            .unwrap();
+        assert_tables_well_formed(&tree);
+
+        let untruncated = MarkdownFormatter::new(Locale::En)
+            .with_style(MarkdownStyle::default().with_description_max_length(0))
+            .format(&model)
+            .unwrap();
+        assert!(untruncated.contains(long.trim_end()));
+    }
+
     #[test]
     fn test_format_basic() {
-        let model = test_fixtures::base_model();
+        let model = SbomReadModelFixture::small_clean().build();
         let formatter = MarkdownFormatter::new(Locale::En);
 
         let result = formatter.format(&model);
@@ -376,17 +466,7 @@ mod tests {
 
     #[test]
     fn test_format_with_dependencies() {
-        let mut model = test_fixtures::base_model();
-        let mut transitive = HashMap::new();
-        transitive.insert(
-            "pkg:pypi/requests@2.31.0".to_string(),
-            vec!["pkg:pypi/urllib3@1.26.0".to_string()],
-        );
-
-        model.dependencies = Some(DependencyView {
-            direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
-            transitive,
-        });
+        let model = with_dependencies();
 
         let formatter = MarkdownFormatter::new(Locale::En);
         let result = formatter.format(&model);
@@ -405,7 +485,7 @@ mod tests {
         // threshold.") is covered by test_format_vulnerability_section_ordering, which uses
         // a model with both actionable and informational vulns.
         for severity in [SeverityView::Critical, SeverityView::Low] {
-            let model = test_fixtures::with_vulnerabilities(severity);
+            let model = with_vulnerability(severity);
             let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
 
             assert!(
@@ -441,16 +521,7 @@ mod tests {
 
     #[test]
     fn test_format_output_section_ordering() {
-        let mut model = test_fixtures::base_model();
-        let mut transitive = HashMap::new();
-        transitive.insert(
-            "pkg:pypi/requests@2.31.0".to_string(),
-            vec!["pkg:pypi/urllib3@1.26.0".to_string()],
-        );
-        model.dependencies = Some(DependencyView {
-            direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
-            transitive,
-        });
+        let model = with_dependencies();
 
         let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
 
@@ -468,7 +539,7 @@ mod tests {
 
     #[test]
     fn test_summary_vuln_skipped_note_when_no_network() {
-        let model = test_fixtures::base_model(); // vulnerabilities: None
+        let model = SbomReadModelFixture::small_clean().build(); // vulnerabilities: None
 
         let formatter = MarkdownFormatter::new(Locale::En);
         let markdown = formatter.format(&model).unwrap();
@@ -478,14 +549,14 @@ mod tests {
 
     #[test]
     fn test_summary_overall_action_required_when_critical_vuln() {
-        let model = test_fixtures::with_critical_vuln();
+        let model = with_critical_vuln();
         let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
         assert!(markdown.contains("**Overall: Action required**"));
     }
 
     #[test]
     fn test_summary_overall_no_issues_when_clean() {
-        let model = test_fixtures::base_model(); // no vulns, no license violations
+        let model = SbomReadModelFixture::small_clean().build(); // no vulns, no license violations
 
         let formatter = MarkdownFormatter::new(Locale::En);
         let markdown = formatter.format(&model).unwrap();
@@ -493,9 +564,54 @@ mod tests {
         assert!(markdown.contains("**Overall: No issues found** ✅"));
     }
 
+    #[test]
+    fn test_markdown_style_emoji_and_plain() {
+        let model = with_critical_vuln();
+        let row = "| [requests](https://pypi.org/project/requests/) | 2.31.0 | 2.32.0 | 9.8 | {} | [CVE-2024-1234](https://nvd.nist.gov/vuln/detail/CVE-2024-1234) |";
+
+        let emoji = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+        let plain = MarkdownFormatter::new(Locale::En)
+            .with_style(MarkdownStyle::plain())
+            .format(&model)
+            .unwrap();
+
+        for (markdown, expected) in [
+            (
+                &emoji,
+                [
+                    Messages::format(row, &["🔴 CRITICAL"]),
+                    "### ⚠️Warning Found 1 vulnerability in 1 package.".to_string(),
+                    "| Vulnerabilities (CRITICAL) | 1 | ❌ |".to_string(),
+                    "| Vulnerabilities (HIGH) | 0 | ✅ |".to_string(),
+                ],
+            ),
+            (
+                &plain,
+                [
+                    Messages::format(row, &["CRITICAL"]),
+                    "### [WARNING] Warning Found 1 vulnerability in 1 package.".to_string(),
+                    "| Vulnerabilities (CRITICAL) | 1 | [FAIL] |".to_string(),
+                    "| Vulnerabilities (HIGH) | 0 | [OK] |".to_string(),
+                ],
+            ),
+        ] {
+            for line in expected {
+                assert!(
+                    markdown.lines().any(|l| l == line),
+                    "missing line {:?} in:\n{}",
+                    line,
+                    markdown
+                );
+            }
+        }
+        for marker in ["🔴", "✅", "⚠️", "❌"] {
+            assert!(!plain.contains(marker), "plain output contains {}", marker);
+        }
+    }
+
     #[test]
     fn test_format_vulnerability_section_ordering() {
-        let model = test_fixtures::with_actionable_and_informational_vulns();
+        let model = SbomReadModelFixture::with_vulns().build();
         let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
 
         assert_section_order(
@@ -509,11 +625,208 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_notes_unchecked_packages_in_vulnerability_section() {
+        use crate::application::read_models::SbomWarning;
+
+        let model = SbomReadModelFixture::with_vulns()
+            .build()
+            .with_warnings(vec![SbomWarning::VulnerabilityCheckFailed {
+                package: "idna".to_string(),
+                version: "3.6".to_string(),
+                error: "OSV API returned status code 503".to_string(),
+            }]);
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+
+        assert_section_order(
+            &markdown,
+            &[
+                "| Packages not checked for vulnerabilities | 1 | ⚠️ |",
+                "**⚠️ 1 packages could not be checked for vulnerabilities",
+                "**Found 2 vulnerabilities in 2 packages.**",
+                "Failed to check idna 3.6 for vulnerabilities: OSV API returned status code 503",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_format_generation_summary_is_last_section() {
+        use crate::application::read_models::{GenerationStats, SbomWarning};
+        use std::time::Duration;
+
+        let model = SbomReadModelFixture::with_vulns()
+            .build()
+            .with_warnings(vec![SbomWarning::UnmatchedExcludePattern {
+                pattern: "foo-*".to_string(),
+            }])
+            .with_stats(Some(GenerationStats {
+                total_packages: 2,
+                parse_time: Duration::from_millis(4),
+                ..Default::default()
+            }));
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+
+        assert_section_order(
+            &markdown,
+            &[
+                "## Vulnerability Report",
+                "## Warnings",
+                "## Generation Summary",
+                "| Total packages | 2 |",
+                "| Lockfile parsing | 4 ms |",
+                "| Vulnerability check | skipped |",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_format_generation_parameters_are_collapsed_at_the_bottom() {
+        use crate::application::read_models::{InvocationParameterView, InvocationView};
+
+        let mut model = SbomReadModelFixture::with_vulns().build();
+        model.metadata.invocation = Some(InvocationView {
+            parameters: vec![
+                InvocationParameterView {
+                    name: "check-cve".to_string(),
+                    value: "true".to_string(),
+                },
+                InvocationParameterView {
+                    name: "exclude-patterns".to_string(),
+                    value: "a|b".to_string(),
+                },
+            ],
+        });
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+
+        assert_section_order(
+            &markdown,
+            &[
+                "## Vulnerability Report",
+                "## Generation Parameters",
+                "<details>",
+                "<summary>Options this SBOM was generated with</summary>",
+                "| Option | Value |",
+                "| `check-cve` | true |",
+                "| `exclude-patterns` | a\\|b |",
+                "</details>",
+            ],
+        );
+        assert!(markdown.trim_end().ends_with("</details>"));
+    }
+
+    #[test]
+    fn test_format_without_stats_omits_generation_summary() {
+        let model = SbomReadModelFixture::small_clean().build();
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+        assert!(!markdown.contains("## Generation Summary"));
+    }
+
+    #[test]
+    fn test_format_lists_source_projects_of_aggregated_sbom() {
+        use crate::application::read_models::ProjectView;
+
+        let mut model = SbomReadModelFixture::small_clean().build();
+        model.projects = vec![
+            ProjectView {
+                name: "api".to_string(),
+                version: Some("1.0.0".to_string()),
+                direct_dependencies: vec!["requests-2.31.0".to_string()],
+            },
+            ProjectView {
+                name: "worker".to_string(),
+                version: None,
+                direct_dependencies: vec![],
+            },
+        ];
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+
+        assert_section_order(
+            &markdown,
+            &[
+                "# Software Bill of Materials (SBOM)",
+                "## Source Projects",
+                "| Project | Version | Direct Dependencies |",
+                "| api | 1.0.0 | 1 |",
+                "| worker | - | 0 |",
+                "## Component Inventory",
+            ],
+        );
+        let single = SbomReadModelFixture::small_clean().build();
+        let markdown = MarkdownFormatter::new(Locale::En).format(&single).unwrap();
+        assert!(!markdown.contains("## Source Projects"));
+    }
+
+    #[test]
+    fn test_header_notes_target_platform() {
+        let model = SbomReadModelFixture::small_clean()
+            .build()
+            .with_target_environment(Some("linux, python_version=3.12".to_string()));
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+        assert!(markdown.contains(
+            "# Software Bill of Materials (SBOM)\n\n**Target platform:** linux, python_version=3.12\n\n"
+        ));
+
+        let unfiltered = SbomReadModelFixture::small_clean().build();
+        let markdown = MarkdownFormatter::new(Locale::En)
+            .format(&unfiltered)
+            .unwrap();
+        assert!(!markdown.contains("Target platform"));
+    }
+
+    #[test]
+    fn test_format_lists_every_version_of_a_package() {
+        let mut fixture = SbomReadModelFixture::builder();
+        fixture.add_component("numpy", "1.26.4").direct();
+        fixture.add_component("numpy", "2.0.0").direct();
+        fixture.add_component("scipy", "1.13.0").direct();
+        let model = fixture.with_dependencies(&[]).build();
+
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+
+        let (inventory, dependencies) = markdown.split_once("## Direct Dependencies").unwrap();
+        for section in [inventory, dependencies] {
+            assert!(section.contains("/numpy/) | 1.26.4 |"));
+            assert!(section.contains("/numpy/) | 2.0.0 |"));
+        }
+        assert!(inventory.contains(
+            "### Version Conflicts\n\nThe following packages are locked at more than one version"
+        ));
+        assert!(inventory.contains(
+            "| Package | Versions |\n|---------|----------|\n| numpy | 1.26.4, 2.0.0 |\n"
+        ));
+    }
+
+    #[test]
+    fn test_format_omits_version_conflicts_when_none() {
+        let markdown = MarkdownFormatter::new(Locale::En)
+            .format(&SbomReadModelFixture::small_clean().build())
+            .unwrap();
+        assert!(!markdown.contains("Version Conflicts"));
+    }
+
+    #[test]
+    fn test_header_names_project() {
+        use crate::application::read_models::MetadataComponentView;
+
+        let mut model = SbomReadModelFixture::small_clean().build();
+        model.metadata.component = Some(MetadataComponentView {
+            name: "my-project".to_string(),
+            version: Some("1.2.0".to_string()),
+        });
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+        assert!(markdown
+            .contains("# Software Bill of Materials (SBOM)\n\n**Project:** my-project v1.2.0\n\n"));
+
+        model.metadata.component.as_mut().unwrap().version = None;
+        let markdown = MarkdownFormatter::new(Locale::En).format(&model).unwrap();
+        assert!(markdown.contains("**Project:** my-project\n\n"));
+    }
+
     // ===== Tests for --lang option (i18n) =====
 
     #[test]
     fn test_lang_ja_markdown_output_contains_japanese_headers() {
-        let model = test_fixtures::base_model();
+        let model = SbomReadModelFixture::small_clean().build();
         assert_ja_output_contains(&model, "# ソフトウェア部品表 (SBOM)");
         assert_ja_output_contains(&model, "## コンポーネント一覧");
         assert_ja_output_excludes(&model, "# Software Bill of Materials (SBOM)");
@@ -522,7 +835,7 @@ mod tests {
 
     #[test]
     fn test_lang_ja_markdown_output_contains_japanese_table_column() {
-        let model = test_fixtures::base_model();
+        let model = SbomReadModelFixture::small_clean().build();
         assert_ja_output_contains(&model, "パッケージ");
         assert_ja_output_contains(&model, "バージョン");
         assert_ja_output_contains(&model, "ライセンス");
@@ -530,7 +843,7 @@ mod tests {
 
     #[test]
     fn test_lang_en_markdown_output_unchanged() {
-        let model = test_fixtures::base_model();
+        let model = SbomReadModelFixture::small_clean().build();
         let formatter = MarkdownFormatter::new(Locale::En);
 
         let markdown = formatter.format(&model).unwrap();
@@ -546,16 +859,7 @@ mod tests {
 
     #[test]
     fn test_lang_ja_with_dependencies_contains_japanese_dep_headers() {
-        let mut model = test_fixtures::base_model();
-        let mut transitive = HashMap::new();
-        transitive.insert(
-            "pkg:pypi/requests@2.31.0".to_string(),
-            vec!["pkg:pypi/urllib3@1.26.0".to_string()],
-        );
-        model.dependencies = Some(DependencyView {
-            direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
-            transitive,
-        });
+        let model = with_dependencies();
         assert_ja_output_contains(&model, "## 直接依存パッケージ");
         assert_ja_output_contains(&model, "## 間接依存パッケージ");
         assert_ja_output_excludes(&model, "## Direct Dependencies");
@@ -566,7 +870,7 @@ mod tests {
 
     #[test]
     fn test_lang_ja_section_descriptions_are_japanese() {
-        let model = test_fixtures::base_model();
+        let model = SbomReadModelFixture::small_clean().build();
         assert_ja_output_contains(
             &model,
             "このプロジェクトに含まれるすべてのソフトウェアコンポーネントとライブラリの一覧です。",
@@ -576,10 +880,13 @@ mod tests {
 
     #[test]
     fn test_lang_ja_no_direct_deps_label_is_japanese() {
-        let mut model = test_fixtures::base_model();
+        let mut model = SbomReadModelFixture::small_clean().build();
         model.dependencies = Some(DependencyView {
             direct: vec![],
             transitive: HashMap::new(),
+            children: HashMap::new(),
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
         });
         assert_ja_output_contains(&model, "*直接依存パッケージなし*");
         assert_ja_output_excludes(&model, "*No direct dependencies*");
@@ -587,18 +894,18 @@ mod tests {
 
     #[test]
     fn test_lang_ja_no_transitive_deps_label_is_japanese() {
-        let mut model = test_fixtures::base_model();
-        model.dependencies = Some(DependencyView {
-            direct: vec!["pkg:pypi/requests@2.31.0".to_string()],
-            transitive: HashMap::new(),
-        });
+        let model = SbomReadModelFixture::small_clean()
+            .with_dependencies(&[])
+            .build();
         assert_ja_output_contains(&model, "*間接依存パッケージなし*");
         assert_ja_output_excludes(&model, "*No transitive dependencies*");
     }
 
     #[test]
     fn test_lang_ja_vuln_above_threshold_warning_is_japanese() {
-        let model = test_fixtures::with_empty_vuln_report();
+        let model = SbomReadModelFixture::small_clean()
+            .with_vulnerability_check()
+            .build();
         assert_ja_output_contains(
             &model,
             "### ⚠️警告 閾値を超える脆弱性は見つかりませんでした。",
@@ -611,7 +918,7 @@ mod tests {
 
     #[test]
     fn test_lang_ja_actionable_vuln_count_is_japanese() {
-        let model = test_fixtures::with_critical_vuln();
+        let model = with_critical_vuln();
         assert_ja_output_contains(
             &model,
             "### ⚠️警告 1件の脆弱性が1個のパッケージで見つかりました。",
@@ -621,7 +928,9 @@ mod tests {
 
     #[test]
     fn test_lang_ja_osv_attribution_is_japanese() {
-        let model = test_fixtures::with_empty_vuln_report();
+        let model = SbomReadModelFixture::small_clean()
+            .with_vulnerability_check()
+            .build();
         assert_ja_output_contains(&model, "*脆弱性データは [OSV](https://osv.dev) より CC-BY 4.0 ライセンスの下で提供されています*");
         assert_ja_output_excludes(&model, "*Vulnerability data provided by");
     }
@@ -630,7 +939,7 @@ mod tests {
     fn test_lang_ja_no_license_violations_is_japanese() {
         use crate::application::read_models::{LicenseComplianceSummary, LicenseComplianceView};
 
-        let mut model = test_fixtures::base_model();
+        let mut model = SbomReadModelFixture::small_clean().build();
         model.license_compliance = Some(LicenseComplianceView {
             has_violations: false,
             violations: vec![],
@@ -650,7 +959,7 @@ mod tests {
             LicenseComplianceSummary, LicenseComplianceView, LicenseViolationView,
         };
 
-        let mut model = test_fixtures::base_model();
+        let mut model = SbomReadModelFixture::small_clean().build();
         model.license_compliance = Some(LicenseComplianceView {
             has_violations: true,
             violations: vec![
@@ -701,7 +1010,7 @@ mod tests {
             UpgradeRecommendationView,
         };
 
-        let mut model = test_fixtures::base_model();
+        let mut model = SbomReadModelFixture::small_clean().build();
         model.resolution_guide = Some(ResolutionGuideView {
             entries: vec![ResolutionEntryView {
                 vulnerable_package: "urllib3".to_string(),
@@ -736,7 +1045,7 @@ mod tests {
 
     #[test]
     fn test_lang_ja_with_vulnerabilities_contains_japanese_vuln_headers() {
-        let model = test_fixtures::with_critical_vuln();
+        let model = with_critical_vuln();
         // CVE ID remains in its original form regardless of locale
         assert_ja_output_contains(&model, "## 脆弱性レポート");
         assert_ja_output_excludes(&model, "## Vulnerability Report");
@@ -745,31 +1054,171 @@ mod tests {
 
     #[test]
     fn test_lang_ja_vuln_summary_is_japanese() {
-        let model = test_fixtures::with_two_actionable_vulns();
+        let mut fixture = SbomReadModelFixture::small_clean();
+        fixture
+            .add_vulnerability("CVE-2024-1234")
+            .severity(SeverityView::Critical)
+            .cvss(9.8)
+            .fixed_in("2.32.0")
+            .on("requests", "2.31.0");
+        fixture
+            .add_vulnerability("CVE-2024-5678")
+            .severity(SeverityView::High)
+            .cvss(7.5)
+            .on("requests", "2.31.0");
+        let model = fixture.build();
         assert_ja_output_contains(&model, "**2件の脆弱性が1個のパッケージで見つかりました。**");
         assert_ja_output_excludes(&model, "**Found");
     }
 
+    #[test]
+    fn test_vulnerability_counts_per_locale() {
+        let one = with_critical_vuln();
+        let mut fixture = SbomReadModelFixture::small_clean();
+        fixture
+            .add_vulnerability("CVE-2024-1234")
+            .severity(SeverityView::Critical)
+            .on("requests", "2.31.0");
+        fixture
+            .add_vulnerability("CVE-2024-5678")
+            .severity(SeverityView::High)
+            .on("urllib3", "1.26.0");
+        let many = fixture.build();
+
+        let cases = [
+            (
+                Locale::En,
+                &one,
+                "**Found 1 vulnerability in 1 package.**",
+                "### ⚠️Warning Found 1 vulnerability in 1 package.",
+            ),
+            (
+                Locale::En,
+                &many,
+                "**Found 2 vulnerabilities in 2 packages.**",
+                "### ⚠️Warning Found 2 vulnerabilities in 2 packages.",
+            ),
+            (
+                Locale::Ja,
+                &one,
+                "**1件の脆弱性が1個のパッケージで見つかりました。**",
+                "### ⚠️警告 1件の脆弱性が1個のパッケージで見つかりました。",
+            ),
+            (
+                Locale::Ja,
+                &many,
+                "**2件の脆弱性が2個のパッケージで見つかりました。**",
+                "### ⚠️警告 2件の脆弱性が2個のパッケージで見つかりました。",
+            ),
+        ];
+        for (locale, model, summary, warning) in cases {
+            let markdown = MarkdownFormatter::new(locale).format(model).unwrap();
+            assert!(
+                markdown.contains(summary),
+                "{locale:?}: {summary:?} missing"
+            );
+            assert!(
+                markdown.contains(warning),
+                "{locale:?}: {warning:?} missing"
+            );
+        }
+    }
+
     #[test]
     fn test_format_license_falls_back_to_name_when_spdx_id_is_none() {
-        let mut model = test_fixtures::base_model();
-        model.components.push(ComponentView {
-            bom_ref: "pkg:pypi/somelib@1.0.0".to_string(),
-            name: "somelib".to_string(),
-            version: "1.0.0".to_string(),
-            purl: "pkg:pypi/somelib@1.0.0".to_string(),
-            license: Some(LicenseView {
-                spdx_id: None,
-                name: "Some Custom License".to_string(),
-            }),
-            description: None,
-            sha256_hash: None,
-            is_direct_dependency: false,
-        });
+        let mut fixture = SbomReadModelFixture::small_clean();
+        fixture
+            .add_component("somelib", "1.0.0")
+            .license("Some Custom License");
+        let model = fixture.build();
+        assert_eq!(model.components[2].license.as_ref().unwrap().spdx_id, None);
 
         let formatter = MarkdownFormatter::new(Locale::En);
         let markdown = formatter.format(&model).unwrap();
 
         assert!(markdown.contains("Some Custom License"));
     }
+
+    // ============================================================
+    // Split output (--markdown-split)
+    // ============================================================
+
+    fn split_model() -> SbomReadModel {
+        let mut model = with_critical_vuln();
+        model.dependencies = with_dependencies().dependencies;
+        model
+    }
+
+    /// Strips the back-to-index link that precedes each non-index body.
+    fn split_body(content: &str) -> &str {
+        content.split_once("\n\n").map(|(_, body)| body).unwrap()
+    }
+
+    #[test]
+    fn test_format_split_writes_linked_files() {
+        use crate::adapters::outbound::filesystem::FileSystemWriter;
+        use crate::ports::outbound::OutputPresenter;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_dir = temp_dir.path().join("sbom");
+        let files = MarkdownFormatter::new(Locale::En).format_split(&split_model());
+        FileSystemWriter::new(output_dir.clone(), Locale::En)
+            .present_many(&files)
+            .unwrap();
+
+        for name in [
+            "index.md",
+            "components.md",
+            "dependencies.md",
+            "vulnerabilities.md",
+        ] {
+            assert!(output_dir.join(name).is_file(), "{} was not written", name);
+        }
+
+        // Every relative Markdown link points at a file that was written
+        for (_, content) in &files {
+            for target in content.split("](").skip(1) {
+                let target = &target[..target.find(')').unwrap()];
+                if target.ends_with(".md") {
+                    assert!(
+                        output_dir.join(target).is_file(),
+                        "dangling link {}",
+                        target
+                    );
+                }
+            }
+        }
+
+        let index = std::fs::read_to_string(output_dir.join("index.md")).unwrap();
+        assert!(index.contains("## Contents"));
+        assert!(index.contains("- [Component Inventory](components.md)"));
+        assert!(index.contains("- [Dependencies](dependencies.md)"));
+        assert!(index.contains("- [Vulnerabilities and Compliance](vulnerabilities.md)"));
+    }
+
+    #[test]
+    fn test_format_split_bodies_match_single_file() {
+        let model = split_model();
+        let formatter = MarkdownFormatter::new(Locale::En);
+        let single = formatter.format(&model).unwrap();
+        let files = formatter.format_split(&model);
+
+        let index = &files[0].1;
+        let mut concatenated = index[..index.find("## Contents").unwrap()].to_string();
+        for (_, content) in &files[1..] {
+            concatenated.push_str(split_body(content));
+        }
+
+        assert_eq!(concatenated, single);
+    }
+
+    #[test]
+    fn test_format_split_omits_empty_groups() {
+        let files = MarkdownFormatter::new(Locale::En)
+            .format_split(&SbomReadModelFixture::small_clean().build());
+        let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+
+        assert_eq!(names, vec!["index.md", "components.md"]);
+        assert!(!files[0].1.contains("dependencies.md"));
+    }
 }
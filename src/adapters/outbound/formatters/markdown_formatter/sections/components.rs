@@ -1,3 +1,4 @@
+use super::super::MarkdownStyle;
 use crate::application::read_models::ComponentView;
 use crate::i18n::Messages;
 use std::collections::HashSet;
@@ -6,43 +7,151 @@ use std::collections::HashSet;
 ///
 /// Lists all components in a Markdown table with package name, version, license,
 /// and description columns. Package names are hyperlinked when `verified_packages`
-/// is provided and the package is present in the set.
+/// is provided and the package is present in the set. Annotated packages get a
+/// footnote listing their `[tool.uv-sbom.annotations]` fields below the table.
+/// Licenses set by a `license_overrides` entry are marked with an asterisk and
+/// listed, with their reasons, in a note below the table. Yanked releases are
+/// marked next to their version and listed, with the yank reasons, in a note as
+/// well. With `style.show_license_source`, each license is followed by where it
+/// came from, and with `style.include_supplier` a "Supplier" column follows the
+/// license. Licenses whose lookup failed read "Unavailable (fetch error)"
+/// instead of "N/A". Descriptions are shortened to `style.description_max_length`.
+/// Without components, a "No third-party dependencies" note replaces the table.
 pub(in super::super) fn render(
     messages: &'static Messages,
     verified_packages: Option<&HashSet<String>>,
     output: &mut String,
     components: &[ComponentView],
+    style: MarkdownStyle,
 ) {
     output.push_str(messages.section_component_inventory);
     output.push_str("\n\n");
     output.push_str(messages.desc_sbom_report);
     output.push_str("\n\n");
-    output.push_str(&super::super::table::table_header(messages));
-    output.push_str(&super::super::table::table_separator(messages));
+    if components.is_empty() {
+        output.push_str(messages.label_no_third_party_deps);
+        output.push_str("\n\n");
+        return;
+    }
+    output.push_str(&super::super::table::component_table_header(
+        messages,
+        style.include_supplier,
+    ));
+    output.push_str(&super::super::table::component_table_separator(
+        messages,
+        style.include_supplier,
+    ));
 
+    let mut footnotes = Vec::new();
+    let mut overridden = Vec::new();
+    let mut yanked = Vec::new();
     for component in components {
-        let license = component
-            .license
-            .as_ref()
-            .map(|l| l.spdx_id.as_deref().unwrap_or(l.name.as_str()))
-            .unwrap_or("N/A");
+        let footnote_ref = if component.annotations.is_empty() {
+            String::new()
+        } else {
+            footnotes.push(annotation_footnote(footnotes.len() + 1, component));
+            format!("[^annotation-{}]", footnotes.len())
+        };
+        let license = match &component.license {
+            Some(l) => {
+                let id = l.spdx_id.as_deref().unwrap_or(l.name.as_str());
+                match l.source.as_deref().filter(|_| style.show_license_source) {
+                    Some(source) => format!("{} ({})", id, source),
+                    None => id.to_string(),
+                }
+            }
+            None if component.license_fetch_error.is_some() => {
+                messages.label_license_fetch_error.to_string()
+            }
+            None => "N/A".to_string(),
+        };
+        let override_marker = match &component.license_override {
+            Some(license_override) => {
+                overridden.push(match &license_override.reason {
+                    Some(reason) => format!("{} ({})", component.name, reason),
+                    None => component.name.clone(),
+                });
+                "\\*"
+            }
+            None => "",
+        };
+        let version = match &component.yanked {
+            Some(reason) => {
+                let release = format!("{} {}", component.name, component.version);
+                yanked.push(if reason.is_empty() {
+                    release
+                } else {
+                    format!("{} ({})", release, reason)
+                });
+                format!("{} {}", component.version, messages.label_yanked)
+            }
+            None => component.version.clone(),
+        };
         let description = component.description.as_deref().unwrap_or("");
+        let supplier = if style.include_supplier {
+            format!(
+                " {} |",
+                super::super::table::escape_markdown_table_cell(
+                    component.supplier.as_deref().unwrap_or("")
+                )
+            )
+        } else {
+            String::new()
+        };
 
         output.push_str(&format!(
-            "| {} | {} | {} | {} |\n",
+            "| {}{} | {} | {}{} |{} {} |\n",
             super::super::links::format_package_name(&component.name, verified_packages),
-            super::super::table::escape_markdown_table_cell(&component.version),
-            super::super::table::escape_markdown_table_cell(license),
-            super::super::table::escape_markdown_table_cell(description)
+            footnote_ref,
+            super::super::table::escape_markdown_table_cell(&version),
+            super::super::table::escape_markdown_table_cell(&license),
+            override_marker,
+            supplier,
+            super::super::table::description_cell(description, style.description_max_length)
         ));
     }
     output.push('\n');
+
+    if !overridden.is_empty() {
+        output.push_str(&Messages::format(
+            messages.desc_license_override,
+            &[&overridden.join(", ")],
+        ));
+        output.push_str("\n\n");
+    }
+
+    if !yanked.is_empty() {
+        output.push_str(&Messages::format(
+            messages.desc_yanked,
+            &[&super::super::table::escape_markdown_table_cell(
+                &yanked.join(", "),
+            )],
+        ));
+        output.push_str("\n\n");
+    }
+
+    if !footnotes.is_empty() {
+        for footnote in footnotes {
+            output.push_str(&footnote);
+        }
+        output.push('\n');
+    }
+}
+
+/// Formats the footnote definition listing a component's annotations.
+fn annotation_footnote(index: usize, component: &ComponentView) -> String {
+    let fields: Vec<String> = component
+        .annotations
+        .iter()
+        .map(|(key, value)| format!("{}: {}", key, value))
+        .collect();
+    format!("[^annotation-{}]: {}\n", index, fields.join("; "))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::read_models::LicenseView;
+    use crate::application::read_models::{LicenseOverrideView, LicenseView};
     use crate::i18n::{Locale, Messages};
 
     fn make_component(
@@ -60,10 +169,22 @@ mod tests {
             license: Some(LicenseView {
                 spdx_id: spdx_id.map(|s| s.to_string()),
                 name: license_name.to_string(),
+                source: None,
             }),
             description: description.map(|s| s.to_string()),
             sha256_hash: None,
             is_direct_dependency: true,
+            annotations: Default::default(),
+            license_override: None,
+            yanked: None,
+            source: None,
+            declared_constraint: None,
+            license_not_found: false,
+            license_fetch_error: None,
+            python_compat: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
         }
     }
 
@@ -82,7 +203,13 @@ mod tests {
             None,
         );
         let mut output = String::new();
-        render(msgs, None, &mut output, &[component]);
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(output.contains("Apache-2.0"));
         assert!(!output.contains("Apache License 2.0"));
     }
@@ -92,7 +219,13 @@ mod tests {
         let msgs = Messages::for_locale(Locale::En);
         let component = make_component("requests", "2.31.0", None, "Apache License 2.0", None);
         let mut output = String::new();
-        render(msgs, None, &mut output, &[component]);
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(output.contains("Apache License 2.0"));
     }
 
@@ -108,12 +241,240 @@ mod tests {
             description: None,
             sha256_hash: None,
             is_direct_dependency: true,
+            annotations: Default::default(),
+            license_override: None,
+            yanked: None,
+            source: None,
+            declared_constraint: None,
+            license_not_found: false,
+            license_fetch_error: None,
+            python_compat: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
         };
         let mut output = String::new();
-        render(msgs, None, &mut output, &[component]);
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(output.contains("N/A"));
     }
 
+    #[test]
+    fn test_license_fetch_error_is_not_shown_as_na() {
+        let msgs = Messages::for_locale(Locale::En);
+        let mut component = make_component("broken", "1.0.0", None, "", None);
+        component.license = None;
+        component.license_fetch_error = Some("connection reset".to_string());
+        let mut output = String::new();
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
+        assert!(
+            output.contains("| 1.0.0 | Unavailable (fetch error) |"),
+            "{}",
+            output
+        );
+        assert!(!output.contains("N/A"));
+    }
+
+    #[test]
+    fn test_license_source_shown_only_when_enabled() {
+        let msgs = Messages::for_locale(Locale::En);
+        let mut component = make_component("requests", "2.31.0", Some("MIT"), "MIT", None);
+        if let Some(license) = component.license.as_mut() {
+            license.source = Some("pypi-classifier".to_string());
+        }
+
+        let mut hidden = String::new();
+        render(
+            msgs,
+            None,
+            &mut hidden,
+            &[component.clone()],
+            MarkdownStyle::default(),
+        );
+        let mut shown = String::new();
+        render(
+            msgs,
+            None,
+            &mut shown,
+            &[component],
+            MarkdownStyle::default().with_license_source(true),
+        );
+
+        assert!(!hidden.contains("pypi-classifier"));
+        assert!(shown.contains("| MIT (pypi-classifier) |"));
+    }
+
+    #[test]
+    fn test_no_components_renders_note_instead_of_table() {
+        let msgs = Messages::for_locale(Locale::En);
+        let mut output = String::new();
+        render(msgs, None, &mut output, &[], MarkdownStyle::default());
+
+        assert!(output.contains("*No third-party dependencies*"));
+        assert!(!output.contains("| Package |"));
+    }
+
+    #[test]
+    fn test_supplier_column_shown_only_when_enabled() {
+        let msgs = Messages::for_locale(Locale::En);
+        let mut component = make_component("requests", "2.31.0", Some("MIT"), "MIT", Some("HTTP"));
+        component.supplier = Some("Kenneth Reitz".to_string());
+        let anonymous = make_component("six", "1.16.0", Some("MIT"), "MIT", Some("Py2/3"));
+
+        let mut hidden = String::new();
+        render(
+            msgs,
+            None,
+            &mut hidden,
+            &[component.clone(), anonymous.clone()],
+            MarkdownStyle::default(),
+        );
+        let mut shown = String::new();
+        render(
+            msgs,
+            None,
+            &mut shown,
+            &[component, anonymous],
+            MarkdownStyle::default().with_supplier(true),
+        );
+
+        assert!(!hidden.contains("Supplier"));
+        assert!(!hidden.contains("Kenneth Reitz"));
+        assert!(shown.contains("| Package | Version | License | Supplier | Description |"));
+        assert!(
+            shown.contains("| MIT | Kenneth Reitz | HTTP |"),
+            "{}",
+            shown
+        );
+        assert!(shown.contains("| MIT |  | Py2/3 |"), "{}", shown);
+    }
+
+    #[test]
+    fn test_yanked_release_is_marked_and_listed() {
+        let msgs = Messages::for_locale(Locale::En);
+        let mut yanked = make_component("urllib3", "2.0.0", Some("MIT"), "MIT", None);
+        yanked.yanked = Some("Broken on Python 3.7".to_string());
+        let mut no_reason = make_component("idna", "3.5", Some("BSD-3-Clause"), "BSD", None);
+        no_reason.yanked = Some(String::new());
+        let plain = make_component("requests", "2.31.0", Some("Apache-2.0"), "Apache", None);
+
+        let mut output = String::new();
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[plain, yanked, no_reason],
+            MarkdownStyle::default(),
+        );
+
+        assert!(output.contains("| 2.0.0 ⚠️ yanked |"));
+        assert!(output.contains("| 3.5 ⚠️ yanked |"));
+        assert!(output.contains("| 2.31.0 |"));
+        assert!(output.contains(
+            "⚠️ Release yanked from PyPI: urllib3 2.0.0 (Broken on Python 3.7), idna 3.5\n"
+        ));
+    }
+
+    // ============================================================
+    // Annotation footnotes
+    // ============================================================
+
+    #[test]
+    fn test_annotations_render_as_footnotes() {
+        let msgs = Messages::for_locale(Locale::En);
+        let mut annotated = make_component("pandas", "2.2.0", Some("BSD-3-Clause"), "BSD", None);
+        annotated.annotations = [("owner", "team-data"), ("justification", "needed for ETL")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        let plain = make_component("requests", "2.31.0", Some("Apache-2.0"), "Apache", None);
+
+        let mut output = String::new();
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[plain, annotated],
+            MarkdownStyle::default(),
+        );
+
+        assert!(output.contains("pandas/)[^annotation-1] |"));
+        assert!(!output.contains("requests/)[^"));
+        assert!(
+            output.contains("[^annotation-1]: justification: needed for ETL; owner: team-data\n")
+        );
+    }
+
+    #[test]
+    fn test_no_footnotes_without_annotations() {
+        let msgs = Messages::for_locale(Locale::En);
+        let component = make_component("requests", "2.31.0", Some("Apache-2.0"), "Apache", None);
+        let mut output = String::new();
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
+        assert!(!output.contains("[^annotation"));
+    }
+
+    // ============================================================
+    // License overrides
+    // ============================================================
+
+    #[test]
+    fn test_license_override_is_marked_and_listed() {
+        let msgs = Messages::for_locale(Locale::En);
+        let mut overridden = make_component("internal-utils", "1.0.0", Some("MIT"), "MIT", None);
+        overridden.license_override = Some(LicenseOverrideView {
+            reason: Some("vendored fork".to_string()),
+        });
+        let plain = make_component("requests", "2.31.0", Some("Apache-2.0"), "Apache", None);
+
+        let mut output = String::new();
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[overridden, plain],
+            MarkdownStyle::default(),
+        );
+
+        assert!(output.contains("| 1.0.0 | MIT\\* |"));
+        assert!(output.contains("| 2.31.0 | Apache-2.0 |"));
+        assert!(output.contains(
+            "\\* License set manually via `license_overrides` in the config file: internal-utils (vendored fork)\n"
+        ));
+    }
+
+    #[test]
+    fn test_no_override_note_without_overrides() {
+        let msgs = Messages::for_locale(Locale::En);
+        let component = make_component("requests", "2.31.0", Some("Apache-2.0"), "Apache", None);
+        let mut output = String::new();
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
+        assert!(!output.contains("license_overrides"));
+    }
+
     // ============================================================
     // i18n column headers
     // ============================================================
@@ -121,16 +482,30 @@ mod tests {
     #[test]
     fn test_en_locale_column_headers() {
         let msgs = Messages::for_locale(Locale::En);
+        let component = make_component("requests", "2.31.0", Some("MIT"), "MIT", None);
         let mut output = String::new();
-        render(msgs, None, &mut output, &[]);
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(output.contains("| Package | Version | License | Description |"));
     }
 
     #[test]
     fn test_ja_locale_column_headers() {
         let msgs = Messages::for_locale(Locale::Ja);
+        let component = make_component("requests", "2.31.0", Some("MIT"), "MIT", None);
         let mut output = String::new();
-        render(msgs, None, &mut output, &[]);
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(output.contains("| パッケージ | バージョン | ライセンス | 説明 |"));
     }
 
@@ -145,7 +520,13 @@ mod tests {
         let mut verified = HashSet::new();
         verified.insert("requests".to_string());
         let mut output = String::new();
-        render(msgs, Some(&verified), &mut output, &[component]);
+        render(
+            msgs,
+            Some(&verified),
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(output.contains("[requests](https://pypi.org/project/requests/)"));
     }
 
@@ -155,7 +536,13 @@ mod tests {
         let component = make_component("requests", "2.31.0", Some("MIT"), "MIT License", None);
         let verified = HashSet::new();
         let mut output = String::new();
-        render(msgs, Some(&verified), &mut output, &[component]);
+        render(
+            msgs,
+            Some(&verified),
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(!output.contains("[requests](https://pypi.org/project/requests/)"));
         assert!(output.contains("| requests |"));
     }
@@ -165,7 +552,13 @@ mod tests {
         let msgs = Messages::for_locale(Locale::En);
         let component = make_component("requests", "2.31.0", Some("MIT"), "MIT License", None);
         let mut output = String::new();
-        render(msgs, None, &mut output, &[component]);
+        render(
+            msgs,
+            None,
+            &mut output,
+            &[component],
+            MarkdownStyle::default(),
+        );
         assert!(output.contains("[requests](https://pypi.org/project/requests/)"));
     }
 }
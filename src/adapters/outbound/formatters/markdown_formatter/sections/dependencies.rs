@@ -1,11 +1,29 @@
+use super::super::MarkdownStyle;
 use crate::application::read_models::{ComponentView, DependencyView};
 use crate::i18n::Messages;
 use std::collections::{HashMap, HashSet};
 
+/// Renders requested extras as a suffix to the package name, e.g. ` \[socks\]`;
+/// empty when the package is requested without extras
+fn extras_suffix(extras: Option<&Vec<String>>) -> String {
+    match extras {
+        Some(extras) if !extras.is_empty() => format!(
+            " \\[{}\\]",
+            super::super::table::escape_markdown_table_cell(&extras.join(","))
+        ),
+        _ => String::new(),
+    }
+}
+
+/// Renders one package table row; `declared_constraint` adds the Declared
+/// Constraint column of the Direct Dependencies table
 fn render_component_row(
     output: &mut String,
     component: &ComponentView,
+    extras: Option<&Vec<String>>,
     verified_packages: Option<&HashSet<String>>,
+    declared_constraint: bool,
+    description_max_length: usize,
 ) {
     let license = component
         .license
@@ -14,22 +32,40 @@ fn render_component_row(
         .unwrap_or("N/A");
     let description = component.description.as_deref().unwrap_or("");
 
+    let constraint = if declared_constraint {
+        format!(
+            " {} |",
+            super::super::table::escape_markdown_table_cell(
+                component.declared_constraint.as_deref().unwrap_or("")
+            )
+        )
+    } else {
+        String::new()
+    };
+
     output.push_str(&format!(
-        "| {} | {} | {} | {} |\n",
+        "| {}{} | {} |{} {} | {} |\n",
         super::super::links::format_package_name(&component.name, verified_packages),
+        extras_suffix(extras),
         super::super::table::escape_markdown_table_cell(&component.version),
+        constraint,
         super::super::table::escape_markdown_table_cell(license),
-        super::super::table::escape_markdown_table_cell(description)
+        super::super::table::description_cell(description, description_max_length)
     ));
 }
 
 /// Renders the dependencies section
+///
+/// With `style.tree_depth` set, transitive dependencies are rendered as an indented
+/// tree under each direct dependency, `tree_depth` levels deep (0 = unlimited),
+/// instead of one flat table per direct dependency.
 pub(in super::super) fn render(
     messages: &'static Messages,
     verified_packages: Option<&HashSet<String>>,
     output: &mut String,
     deps: &DependencyView,
     components: &[ComponentView],
+    style: MarkdownStyle,
 ) {
     // Create component lookup map by bom_ref
     let component_map: HashMap<&str, &ComponentView> =
@@ -42,12 +78,31 @@ pub(in super::super) fn render(
     output.push_str("\n\n");
 
     if !deps.direct.is_empty() {
-        output.push_str(&super::super::table::table_header(messages));
-        output.push_str(&super::super::table::table_separator(messages));
+        // The Declared Constraint column is only shown when pyproject.toml provided one
+        let declared_constraint = deps.direct.iter().any(|bom_ref| {
+            component_map
+                .get(bom_ref.as_str())
+                .is_some_and(|c| c.declared_constraint.is_some())
+        });
+        output.push_str(&super::super::table::direct_table_header(
+            messages,
+            declared_constraint,
+        ));
+        output.push_str(&super::super::table::direct_table_separator(
+            messages,
+            declared_constraint,
+        ));
 
         for bom_ref in &deps.direct {
             if let Some(component) = component_map.get(bom_ref.as_str()) {
-                render_component_row(output, component, verified_packages);
+                render_component_row(
+                    output,
+                    component,
+                    deps.extras.get(bom_ref),
+                    verified_packages,
+                    declared_constraint,
+                    style.description_max_length,
+                );
             }
         }
         output.push('\n');
@@ -62,7 +117,19 @@ pub(in super::super) fn render(
     output.push_str(messages.desc_transitive_deps);
     output.push_str("\n\n");
 
-    if !deps.transitive.is_empty() {
+    if let Some(max_depth) = style.tree_depth {
+        let tree = DependencyTree {
+            messages,
+            verified_packages,
+            deps,
+            component_map: &component_map,
+            max_depth,
+        };
+        if !tree.render(output) {
+            output.push_str(messages.label_no_transitive_deps);
+            output.push_str("\n\n");
+        }
+    } else if !deps.transitive.is_empty() {
         for direct_ref in &deps.direct {
             if let Some(trans_deps) = deps.transitive.get(direct_ref) {
                 if trans_deps.is_empty() {
@@ -82,7 +149,14 @@ pub(in super::super) fn render(
 
                 for trans_ref in trans_deps {
                     if let Some(component) = component_map.get(trans_ref.as_str()) {
-                        render_component_row(output, component, verified_packages);
+                        render_component_row(
+                            output,
+                            component,
+                            deps.extras.get(trans_ref),
+                            verified_packages,
+                            false,
+                            style.description_max_length,
+                        );
                     }
                 }
                 output.push('\n');
@@ -92,6 +166,111 @@ pub(in super::super) fn render(
         output.push_str(messages.label_no_transitive_deps);
         output.push_str("\n\n");
     }
+
+    // Unreferenced Packages section, only for a graph rooted at --roots
+    if !deps.unreferenced.is_empty() {
+        output.push_str(messages.section_unreferenced_packages);
+        output.push_str("\n\n");
+        output.push_str(messages.desc_unreferenced_packages);
+        output.push_str("\n\n");
+        output.push_str(&super::super::table::table_header(messages));
+        output.push_str(&super::super::table::table_separator(messages));
+        for bom_ref in &deps.unreferenced {
+            if let Some(component) = component_map.get(bom_ref.as_str()) {
+                render_component_row(
+                    output,
+                    component,
+                    None,
+                    verified_packages,
+                    false,
+                    style.description_max_length,
+                );
+            }
+        }
+        output.push('\n');
+    }
+}
+
+/// Indented dependency tree, one top-level item per direct dependency
+struct DependencyTree<'a> {
+    messages: &'static Messages,
+    verified_packages: Option<&'a HashSet<String>>,
+    deps: &'a DependencyView,
+    component_map: &'a HashMap<&'a str, &'a ComponentView>,
+    /// Levels rendered below each direct dependency; 0 = unlimited
+    max_depth: usize,
+}
+
+impl DependencyTree<'_> {
+    /// Renders the direct dependencies that have dependencies of their own;
+    /// returns false when there are none
+    fn render(&self, output: &mut String) -> bool {
+        let mut rendered = false;
+        for direct_ref in &self.deps.direct {
+            if self.children(direct_ref).is_empty() {
+                continue;
+            }
+            let Some(component) = self.component_map.get(direct_ref.as_str()) else {
+                continue;
+            };
+            self.render_item(output, component, 0, false);
+            self.render_children(output, &mut vec![direct_ref.as_str()]);
+            rendered = true;
+        }
+        if rendered {
+            output.push('\n');
+        }
+        rendered
+    }
+
+    /// Renders the dependencies of the last package in `path`. A package that is
+    /// already in `path` closes a cycle: it is marked and not expanded again.
+    /// Shared dependencies are rendered under every parent.
+    fn render_children<'p>(&'p self, output: &mut String, path: &mut Vec<&'p str>) {
+        let depth = path.len();
+        let parent = path[depth - 1];
+        for child_ref in self.children(parent) {
+            let Some(component) = self.component_map.get(child_ref.as_str()) else {
+                continue;
+            };
+            let circular = path.contains(&child_ref.as_str());
+            self.render_item(output, component, depth, circular);
+            if !circular && (self.max_depth == 0 || depth < self.max_depth) {
+                path.push(child_ref);
+                self.render_children(output, path);
+                path.pop();
+            }
+        }
+    }
+
+    fn render_item(
+        &self,
+        output: &mut String,
+        component: &ComponentView,
+        depth: usize,
+        circular: bool,
+    ) {
+        output.push_str(&"  ".repeat(depth));
+        output.push_str(&format!(
+            "- {}{} {}",
+            super::super::links::format_package_name(&component.name, self.verified_packages),
+            extras_suffix(self.deps.extras.get(&component.bom_ref)),
+            super::super::table::escape_markdown_table_cell(&component.version)
+        ));
+        if circular {
+            output.push(' ');
+            output.push_str(self.messages.label_circular_dependency);
+        }
+        output.push('\n');
+    }
+
+    fn children(&self, bom_ref: &str) -> &[String] {
+        self.deps
+            .children
+            .get(bom_ref)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
 }
 
 #[cfg(test)]
@@ -109,20 +288,80 @@ mod tests {
             license: Some(LicenseView {
                 spdx_id: Some("MIT".to_string()),
                 name: "MIT License".to_string(),
+                source: None,
             }),
             description: Some(format!("{name} description")),
             sha256_hash: None,
             is_direct_dependency: true,
+            annotations: Default::default(),
+            license_override: None,
+            yanked: None,
+            source: None,
+            declared_constraint: None,
+            license_not_found: false,
+            license_fetch_error: None,
+            python_compat: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
         }
     }
 
     fn call_render(locale: Locale, deps: &DependencyView, components: &[ComponentView]) -> String {
         let messages = crate::i18n::Messages::for_locale(locale);
         let mut output = String::new();
-        render(messages, None, &mut output, deps, components);
+        render(
+            messages,
+            None,
+            &mut output,
+            deps,
+            components,
+            MarkdownStyle::default(),
+        );
         output
     }
 
+    fn call_render_tree(
+        deps: &DependencyView,
+        components: &[ComponentView],
+        depth: usize,
+    ) -> String {
+        let messages = crate::i18n::Messages::for_locale(Locale::En);
+        let mut output = String::new();
+        render(
+            messages,
+            Some(&HashSet::new()),
+            &mut output,
+            deps,
+            components,
+            MarkdownStyle::default().with_tree_depth(Some(depth)),
+        );
+        output
+    }
+
+    /// a -> b -> c -> d -> e, a -> shared, b -> shared, d -> b (cycle)
+    fn deep_tree() -> (DependencyView, Vec<ComponentView>) {
+        let components: Vec<ComponentView> = ["a", "b", "c", "d", "e", "shared"]
+            .iter()
+            .map(|name| make_component(name, name, "1.0"))
+            .collect();
+        let edges = [
+            ("a", vec!["b", "shared"]),
+            ("b", vec!["c", "shared"]),
+            ("c", vec!["d"]),
+            ("d", vec!["e", "b"]),
+        ];
+        let mut deps = DependencyView::default();
+        deps.direct.push("a".to_string());
+        for (parent, children) in edges {
+            deps.children.insert(
+                parent.to_string(),
+                children.iter().map(|c| c.to_string()).collect(),
+            );
+        }
+        (deps, components)
+    }
+
     // --- i18n header tests ---
 
     #[test]
@@ -170,6 +409,34 @@ mod tests {
         assert!(output.contains("*間接依存パッケージなし*"));
     }
 
+    #[test]
+    fn test_unreferenced_section_lists_packages_no_root_reaches() {
+        let components = vec![
+            make_component("boto3", "boto3", "1.34.0"),
+            make_component("flask", "flask", "3.0.0"),
+        ];
+        let mut deps = DependencyView::default();
+        deps.direct.push("boto3".to_string());
+        deps.unreferenced.push("flask".to_string());
+
+        let output = call_render(Locale::En, &deps, &components);
+
+        let section = output
+            .split("## Unreferenced Packages")
+            .nth(1)
+            .expect("unreferenced section");
+        assert!(section.contains("[flask](https://pypi.org/project/flask/) | 3.0.0 |"));
+        assert!(!section.contains("boto3"));
+    }
+
+    #[test]
+    fn test_unreferenced_section_hidden_without_unreferenced_packages() {
+        let deps = DependencyView::default();
+        let output = call_render(Locale::En, &deps, &[]);
+
+        assert!(!output.contains("## Unreferenced Packages"));
+    }
+
     // --- direct dependency row rendering ---
 
     #[test]
@@ -199,6 +466,25 @@ mod tests {
         assert!(output.contains("httpx"));
     }
 
+    #[test]
+    fn test_dep_row_shows_requested_extras() {
+        let requests = make_component("pkg-a", "requests", "2.31.0");
+        let urllib3 = make_component("pkg-b", "urllib3", "2.0.0");
+        let mut deps = DependencyView::default();
+        deps.direct.push("pkg-a".to_string());
+        deps.transitive
+            .insert("pkg-a".to_string(), vec!["pkg-b".to_string()]);
+        deps.extras.insert(
+            "pkg-a".to_string(),
+            vec!["security".to_string(), "socks".to_string()],
+        );
+
+        let output = call_render(Locale::En, &deps, &[requests, urllib3]);
+
+        assert!(output.contains("/requests/) \\[security,socks\\] | 2.31.0 |"));
+        assert!(output.contains("/urllib3/) | 2.0.0 |"));
+    }
+
     #[test]
     fn test_direct_dep_no_license_shows_na() {
         let mut component = make_component("pkg-a", "no-license-pkg", "1.0.0");
@@ -211,6 +497,44 @@ mod tests {
         assert!(output.contains("N/A"));
     }
 
+    #[test]
+    fn test_direct_deps_show_declared_constraint_column() {
+        let mut requests = make_component("pkg-a", "requests", "2.31.0");
+        requests.declared_constraint =
+            Some("requests[socks]>=2.28,<3; python_version >= '3.9'".to_string());
+        let httpx = make_component("pkg-b", "httpx", "0.25.0");
+        let urllib3 = make_component("pkg-c", "urllib3", "2.0.0");
+        let mut deps = DependencyView::default();
+        deps.direct.push("pkg-a".to_string());
+        deps.direct.push("pkg-b".to_string());
+        deps.transitive
+            .insert("pkg-a".to_string(), vec!["pkg-c".to_string()]);
+
+        let output = call_render(Locale::En, &deps, &[requests, httpx, urllib3]);
+
+        assert!(
+            output.contains("| Package | Version | Declared Constraint | License | Description |")
+        );
+        assert!(output
+            .contains("| 2.31.0 | requests\\[socks\\]>=2.28,<3; python_version >= '3.9' | MIT |"));
+        // A direct dependency declared nowhere gets an empty cell
+        assert!(output.contains("| 0.25.0 |  | MIT |"));
+        // Transitive tables keep the plain columns
+        assert!(output.contains("| 2.0.0 | MIT |"));
+    }
+
+    #[test]
+    fn test_declared_constraint_column_hidden_without_constraints() {
+        let component = make_component("pkg-a", "requests", "2.31.0");
+        let mut deps = DependencyView::default();
+        deps.direct.push("pkg-a".to_string());
+
+        let output = call_render(Locale::En, &deps, &[component]);
+
+        assert!(!output.contains("Declared Constraint"));
+        assert!(output.contains("| Package | Version | License | Description |"));
+    }
+
     // --- transitive dependency row rendering ---
 
     #[test]
@@ -268,4 +592,48 @@ mod tests {
         assert!(output.contains("requests"));
         assert!(output.contains("*No transitive dependencies*"));
     }
+
+    // --- dependency tree rendering ---
+
+    #[test]
+    fn test_tree_renders_every_level_and_marks_cycles() {
+        let (deps, components) = deep_tree();
+
+        let output = call_render_tree(&deps, &components, 0);
+
+        let expected = "\
+- a 1.0
+  - b 1.0
+    - c 1.0
+      - d 1.0
+        - e 1.0
+        - b 1.0 (circular)
+    - shared 1.0
+  - shared 1.0
+";
+        assert!(output.contains(expected), "{}", output);
+        assert!(!output.contains("### Dependencies for"));
+    }
+
+    #[test]
+    fn test_tree_stops_at_max_depth() {
+        let (deps, components) = deep_tree();
+
+        let output = call_render_tree(&deps, &components, 2);
+
+        assert!(
+            output.contains("- a 1.0\n  - b 1.0\n    - c 1.0\n    - shared 1.0\n  - shared 1.0\n")
+        );
+        assert!(!output.contains("- d 1.0"));
+    }
+
+    #[test]
+    fn test_tree_without_children_shows_no_transitive_label() {
+        let mut deps = DependencyView::default();
+        deps.direct.push("pkg-a".to_string());
+
+        let output = call_render_tree(&deps, &[make_component("pkg-a", "requests", "2.31.0")], 0);
+
+        assert!(output.contains("*No transitive dependencies*"));
+    }
 }
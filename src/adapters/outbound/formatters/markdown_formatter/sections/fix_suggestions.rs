@@ -0,0 +1,150 @@
+use crate::application::read_models::{FixSuggestionView, FixSuggestionsView};
+use crate::i18n::Messages;
+
+/// Renders the suggested fixes section: one upgrade per vulnerable package,
+/// followed by the advisories that have no fixed version yet.
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    suggestions: &FixSuggestionsView,
+) {
+    if !suggestions.fixable.is_empty() {
+        output.push('\n');
+        output.push_str(messages.section_fix_suggestions);
+        output.push_str("\n\n");
+        output.push_str(messages.desc_fix_suggestions);
+        output.push_str("\n\n");
+
+        let columns = [
+            messages.col_package,
+            messages.col_current_version,
+            messages.col_fixed_version,
+            messages.col_vuln_id,
+            messages.col_action,
+        ];
+        output.push_str(&format!("| {} |\n", columns.join(" | ")));
+        output.push_str(&super::super::table::make_separator(&columns));
+
+        for suggestion in &suggestions.fixable {
+            output.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                super::super::table::escape_markdown_table_cell(&suggestion.package_name),
+                super::super::table::escape_markdown_table_cell(&suggestion.current_version),
+                super::super::table::escape_markdown_table_cell(&suggestion.fixed_version),
+                id_links(&suggestion.vulnerability_ids),
+                action(messages, suggestion),
+            ));
+        }
+        output.push('\n');
+    }
+
+    if !suggestions.unfixed.is_empty() {
+        output.push('\n');
+        output.push_str(messages.section_no_fix_available);
+        output.push_str("\n\n");
+        output.push_str(messages.desc_no_fix_available);
+        output.push_str("\n\n");
+
+        let columns = [
+            messages.col_package,
+            messages.col_current_version,
+            messages.col_vuln_id,
+        ];
+        output.push_str(&format!("| {} |\n", columns.join(" | ")));
+        output.push_str(&super::super::table::make_separator(&columns));
+
+        for package in &suggestions.unfixed {
+            output.push_str(&format!(
+                "| {} | {} | {} |\n",
+                super::super::table::escape_markdown_table_cell(&package.package_name),
+                super::super::table::escape_markdown_table_cell(&package.current_version),
+                id_links(&package.vulnerability_ids),
+            ));
+        }
+        output.push('\n');
+    }
+}
+
+/// The `uv add` command for a direct dependency, or which direct dependencies
+/// to upgrade for a transitive one, escaped for the table cell
+fn action(messages: &'static Messages, suggestion: &FixSuggestionView) -> String {
+    if let Some(command) = &suggestion.command {
+        return format!("`{}`", super::super::table::escape_code_span(command));
+    }
+    if suggestion.introduced_by.is_empty() {
+        return "-".to_string();
+    }
+    let parents =
+        super::super::table::escape_markdown_table_cell(&suggestion.introduced_by.join(", "));
+    Messages::format(messages.action_fix_transitive, &[&parents, &parents])
+}
+
+fn id_links(ids: &[String]) -> String {
+    ids.iter()
+        .map(|id| super::super::links::vulnerability_id_to_link(id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::UnfixedPackageView;
+    use crate::i18n::Locale;
+
+    fn view() -> FixSuggestionsView {
+        FixSuggestionsView {
+            fixable: vec![
+                FixSuggestionView {
+                    package_name: "requests".to_string(),
+                    current_version: "2.31.0".to_string(),
+                    fixed_version: "2.32.0".to_string(),
+                    vulnerability_ids: vec!["CVE-2024-35195".to_string()],
+                    command: Some("uv add 'requests>=2.32.0'".to_string()),
+                    introduced_by: vec![],
+                },
+                FixSuggestionView {
+                    package_name: "urllib3".to_string(),
+                    current_version: "1.26.15".to_string(),
+                    fixed_version: "1.26.19".to_string(),
+                    vulnerability_ids: vec!["CVE-2024-37891".to_string()],
+                    command: None,
+                    introduced_by: vec!["requests".to_string()],
+                },
+            ],
+            unfixed: vec![UnfixedPackageView {
+                package_name: "ecdsa".to_string(),
+                current_version: "0.19.0".to_string(),
+                vulnerability_ids: vec!["GHSA-wj6h-64fc-37mp".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_render_fix_suggestions() {
+        let mut output = String::new();
+        render(Messages::for_locale(Locale::En), &mut output, &view());
+
+        assert!(output.contains("## Suggested Fixes"));
+        assert!(output.contains(
+            "| requests | 2.31.0 | 2.32.0 | [CVE-2024-35195](https://nvd.nist.gov/vuln/detail/CVE-2024-35195) | `uv add 'requests>=2.32.0'` |"
+        ));
+        assert!(output.contains(
+            "| urllib3 | 1.26.15 | 1.26.19 | [CVE-2024-37891](https://nvd.nist.gov/vuln/detail/CVE-2024-37891) | Transitive via requests — upgrade requests or add a constraint |"
+        ));
+        assert!(output.contains("### No fix available yet"));
+        assert!(output.contains("| ecdsa | 0.19.0 | [GHSA-wj6h-64fc-37mp]"));
+    }
+
+    #[test]
+    fn test_render_nothing_without_suggestions() {
+        let mut output = String::new();
+        render(
+            Messages::for_locale(Locale::En),
+            &mut output,
+            &FixSuggestionsView::default(),
+        );
+
+        assert!(output.is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+use super::super::table::{escape_markdown_table_cell, make_separator};
+use crate::application::read_models::InvocationView;
+use crate::i18n::Messages;
+
+/// Renders the options the SBOM was generated with, collapsed by default
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    invocation: &InvocationView,
+) {
+    output.push('\n');
+    output.push_str(messages.section_generation_parameters);
+    output.push_str("\n\n<details>\n<summary>");
+    output.push_str(messages.label_generation_parameters_summary);
+    output.push_str("</summary>\n\n");
+
+    let columns = [messages.col_option, messages.col_value];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&make_separator(&columns));
+    for parameter in &invocation.parameters {
+        output.push_str(&format!(
+            "| `{}` | {} |\n",
+            parameter.name,
+            escape_markdown_table_cell(&parameter.value)
+        ));
+    }
+    output.push_str("\n</details>\n");
+}
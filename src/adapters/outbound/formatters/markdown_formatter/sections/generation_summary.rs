@@ -0,0 +1,20 @@
+use crate::application::read_models::GenerationStats;
+use crate::i18n::Messages;
+
+/// Renders the generation summary table with counts and per-phase timings
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    stats: &GenerationStats,
+) {
+    output.push('\n');
+    output.push_str(messages.section_generation_summary);
+    output.push_str("\n\n");
+
+    let columns = [messages.col_metric, messages.col_value];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&super::super::table::make_separator(&columns));
+    for (label, value) in stats.rows(messages) {
+        output.push_str(&format!("| {} | {} |\n", label, value));
+    }
+}
@@ -1,7 +1,35 @@
+use crate::application::read_models::MetadataComponentView;
 use crate::i18n::Messages;
 
-/// Renders the SBOM header section into `output`.
-pub(in super::super) fn render(messages: &'static Messages, output: &mut String) {
+/// Renders the SBOM header section into `output`, naming the project when it
+/// is known and noting the target platform when the packages were filtered
+/// for one.
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    project: Option<&MetadataComponentView>,
+    target_environment: Option<&str>,
+) {
     output.push_str(messages.section_sbom_title);
     output.push_str("\n\n");
+    if let Some(project) = project {
+        let name = super::super::table::escape_markdown_table_cell(&project.name);
+        let label = match &project.version {
+            Some(version) => format!(
+                "{} v{}",
+                name,
+                super::super::table::escape_markdown_table_cell(version)
+            ),
+            None => name,
+        };
+        output.push_str(&Messages::format(messages.label_project, &[&label]));
+        output.push_str("\n\n");
+    }
+    if let Some(environment) = target_environment {
+        output.push_str(&Messages::format(
+            messages.label_target_platform,
+            &[environment],
+        ));
+        output.push_str("\n\n");
+    }
 }
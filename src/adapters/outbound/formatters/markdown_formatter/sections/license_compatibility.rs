@@ -0,0 +1,254 @@
+use crate::application::read_models::{CompatibilityVerdictView, LicenseCompatibilityView};
+use crate::i18n::Messages;
+
+/// Renders the license compatibility section
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    compatibility: &LicenseCompatibilityView,
+) {
+    output.push('\n');
+    output.push_str(messages.section_license_compatibility);
+    output.push_str("\n\n");
+
+    // Project license
+    let project_line = match (
+        &compatibility.project_license,
+        &compatibility.project_family,
+    ) {
+        (Some(license), Some(family)) => {
+            Messages::format(messages.label_project_license, &[license, family])
+        }
+        (Some(license), None) => {
+            Messages::format(messages.label_project_license_unclassified, &[license])
+        }
+        (None, _) => messages.label_project_license_missing.to_string(),
+    };
+    output.push_str(&project_line);
+    output.push_str("\n\n");
+
+    // Summary
+    let summary = &compatibility.summary;
+    output.push_str(&Messages::format(
+        messages.label_license_compatibility_counts,
+        &[
+            &summary.compatible_count.to_string(),
+            &summary.conditional_count.to_string(),
+            &summary.incompatible_count.to_string(),
+            &summary.needs_review_count.to_string(),
+        ],
+    ));
+    output.push_str("\n\n");
+
+    // Plainly compatible packages are only counted; a view built outside the
+    // read model builder may still list them as findings, and they are skipped
+    let findings: Vec<_> = compatibility
+        .findings
+        .iter()
+        .filter_map(|entry| {
+            let (verdict, note) = match entry.verdict {
+                CompatibilityVerdictView::Incompatible => (
+                    messages.verdict_incompatible,
+                    messages.note_license_incompatible,
+                ),
+                CompatibilityVerdictView::NeedsReview => (
+                    messages.verdict_needs_review,
+                    messages.note_license_needs_review,
+                ),
+                CompatibilityVerdictView::Conditional => (
+                    messages.verdict_conditional,
+                    messages.note_license_conditional,
+                ),
+                CompatibilityVerdictView::Compatible => return None,
+            };
+            Some((entry, verdict, note))
+        })
+        .collect();
+
+    if findings.is_empty() {
+        output.push_str(messages.label_all_licenses_compatible);
+        output.push_str("\n\n");
+        return;
+    }
+
+    // Findings table
+    let columns = [
+        messages.col_package,
+        messages.col_version,
+        messages.col_license,
+        messages.col_verdict,
+        messages.col_note,
+    ];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&super::super::table::make_separator(&columns));
+
+    for (entry, verdict, note) in findings {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            super::super::table::escape_markdown_table_cell(&entry.package_name),
+            super::super::table::escape_markdown_table_cell(&entry.package_version),
+            super::super::table::escape_markdown_table_cell(&entry.license),
+            verdict,
+            note,
+        ));
+    }
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::{
+        LicenseCompatibilityEntryView, LicenseCompatibilitySummary,
+    };
+    use crate::i18n::{Locale, Messages};
+
+    fn make_entry(
+        package: &str,
+        license: &str,
+        verdict: CompatibilityVerdictView,
+    ) -> LicenseCompatibilityEntryView {
+        LicenseCompatibilityEntryView {
+            package_name: package.to_string(),
+            package_version: "1.0.0".to_string(),
+            license: license.to_string(),
+            verdict,
+        }
+    }
+
+    fn make_view(
+        project_license: Option<&str>,
+        project_family: Option<&str>,
+        findings: Vec<LicenseCompatibilityEntryView>,
+    ) -> LicenseCompatibilityView {
+        let count = |v| findings.iter().filter(|e| e.verdict == v).count();
+        let summary = LicenseCompatibilitySummary {
+            compatible_count: 3,
+            conditional_count: count(CompatibilityVerdictView::Conditional),
+            incompatible_count: count(CompatibilityVerdictView::Incompatible),
+            needs_review_count: count(CompatibilityVerdictView::NeedsReview),
+        };
+        LicenseCompatibilityView {
+            project_license: project_license.map(str::to_string),
+            project_family: project_family.map(str::to_string),
+            findings,
+            summary,
+        }
+    }
+
+    fn render_view(locale: Locale, view: &LicenseCompatibilityView) -> String {
+        let mut output = String::new();
+        render(Messages::for_locale(locale), &mut output, view);
+        output
+    }
+
+    #[test]
+    fn test_render_findings_table() {
+        let view = make_view(
+            Some("Apache-2.0"),
+            Some("permissive"),
+            vec![
+                make_entry(
+                    "gpl-lib",
+                    "GPL-3.0-only",
+                    CompatibilityVerdictView::Incompatible,
+                ),
+                make_entry("mystery", "N/A", CompatibilityVerdictView::NeedsReview),
+                make_entry(
+                    "chardet",
+                    "LGPL-2.1-only",
+                    CompatibilityVerdictView::Conditional,
+                ),
+            ],
+        );
+        let output = render_view(Locale::En, &view);
+
+        assert!(output.contains("## License Compatibility"));
+        assert!(output.contains("Project license: **Apache-2.0** (permissive)"));
+        assert!(output.contains("**3 compatible, 1 conditional, 1 incompatible, 1 need review.**"));
+        assert!(output.contains("| Package | Version | License | Verdict | Note |"));
+        assert!(output.contains(
+            "| gpl-lib | 1.0.0 | GPL-3.0-only | ❌ Incompatible | Cannot be distributed under the project license |"
+        ));
+        assert!(output.contains("| mystery | 1.0.0 | N/A | ❓ Needs review |"));
+        assert!(output.contains("| chardet | 1.0.0 | LGPL-2.1-only | ⚠️ Conditional |"));
+    }
+
+    #[test]
+    fn test_render_all_compatible() {
+        let view = make_view(Some("MIT"), Some("permissive"), vec![]);
+        let output = render_view(Locale::En, &view);
+
+        assert!(output.contains("All dependency licenses are compatible with the project license."));
+        assert!(!output.contains("| Package |"));
+    }
+
+    #[test]
+    fn test_render_skips_compatible_findings() {
+        let view = make_view(
+            Some("MIT"),
+            Some("permissive"),
+            vec![
+                make_entry(
+                    "requests",
+                    "Apache-2.0",
+                    CompatibilityVerdictView::Compatible,
+                ),
+                make_entry(
+                    "chardet",
+                    "LGPL-2.1-only",
+                    CompatibilityVerdictView::Conditional,
+                ),
+            ],
+        );
+        let output = render_view(Locale::En, &view);
+
+        assert!(!output.contains("| requests |"));
+        assert!(output.contains("| chardet | 1.0.0 | LGPL-2.1-only | ⚠️ Conditional |"));
+
+        let only_compatible = make_view(
+            Some("MIT"),
+            Some("permissive"),
+            vec![make_entry(
+                "requests",
+                "Apache-2.0",
+                CompatibilityVerdictView::Compatible,
+            )],
+        );
+        let output = render_view(Locale::En, &only_compatible);
+        assert!(output.contains("All dependency licenses are compatible with the project license."));
+        assert!(!output.contains("| Package |"));
+    }
+
+    #[test]
+    fn test_render_unknown_project_license() {
+        let unclassified = render_view(
+            Locale::En,
+            &make_view(Some("LicenseRef-Proprietary"), None, vec![]),
+        );
+        assert!(unclassified.contains(
+            "Project license: **LicenseRef-Proprietary** (not classified; evaluated as permissive)"
+        ));
+
+        let missing = render_view(Locale::En, &make_view(None, None, vec![]));
+        assert!(missing.contains("Project license: not declared (evaluated as permissive)"));
+    }
+
+    #[test]
+    fn test_render_japanese() {
+        let view = make_view(
+            Some("Apache-2.0"),
+            Some("permissive"),
+            vec![make_entry(
+                "gpl-lib",
+                "GPL-3.0-only",
+                CompatibilityVerdictView::Incompatible,
+            )],
+        );
+        let output = render_view(Locale::Ja, &view);
+
+        assert!(output.contains("## ライセンス互換性"));
+        assert!(output.contains("| パッケージ |"));
+        assert!(output.contains("❌ 非互換"));
+    }
+}
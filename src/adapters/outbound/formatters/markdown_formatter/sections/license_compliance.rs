@@ -13,11 +13,11 @@ pub(in super::super) fn render(
 
     // Summary
     if compliance.has_violations {
-        let unit = if compliance.summary.violation_count == 1 {
-            messages.label_license_violation_singular
-        } else {
-            messages.label_license_violation_plural
-        };
+        let unit = messages.unit(
+            compliance.summary.violation_count,
+            messages.label_license_violation_singular,
+            messages.label_license_violation_plural,
+        );
         output.push_str(&format!(
             "**{} {}**\n\n",
             compliance.summary.violation_count, unit,
@@ -63,11 +63,11 @@ pub(in super::super) fn render(
     // Warnings table
     if !compliance.warnings.is_empty() {
         let warning_count = compliance.summary.warning_count;
-        let pkg_word = if warning_count == 1 {
-            messages.label_package_singular
-        } else {
-            messages.label_package_plural
-        };
+        let pkg_word = messages.unit(
+            warning_count,
+            messages.label_package_singular,
+            messages.label_package_plural,
+        );
         output.push_str(messages.section_warnings);
         output.push_str("\n\n");
         output.push_str(&Messages::format(
@@ -0,0 +1,116 @@
+use crate::application::read_models::{LicenseRiskEntryView, LicenseRiskView};
+use crate::i18n::Messages;
+
+/// Renders the license risk section: the reachable copyleft packages, followed
+/// by the reachable packages with unknown licenses when strict mode found any.
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    risk: &LicenseRiskView,
+) {
+    output.push('\n');
+    output.push_str(messages.section_license_risk);
+    output.push_str("\n\n");
+
+    if risk.copyleft.is_empty() {
+        output.push_str(messages.label_no_license_risk);
+        output.push_str("\n\n");
+    } else {
+        output.push_str(messages.desc_license_risk);
+        output.push_str("\n\n");
+        render_table(messages, output, &risk.copyleft);
+    }
+
+    if !risk.unknown.is_empty() {
+        output.push_str(messages.section_unknown_license_reachable);
+        output.push_str("\n\n");
+        render_table(messages, output, &risk.unknown);
+    }
+}
+
+fn render_table(
+    messages: &'static Messages,
+    output: &mut String,
+    entries: &[LicenseRiskEntryView],
+) {
+    let columns = [
+        messages.col_package,
+        messages.col_version,
+        messages.col_license,
+        messages.col_introduced_via,
+        messages.col_path_count,
+    ];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&super::super::table::make_separator(&columns));
+
+    for entry in entries {
+        output.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            super::super::table::escape_markdown_table_cell(&entry.package_name),
+            super::super::table::escape_markdown_table_cell(&entry.package_version),
+            super::super::table::escape_markdown_table_cell(&entry.license),
+            super::super::table::escape_markdown_table_cell(&entry.introduction_path.join(" → ")),
+            entry.path_count,
+        ));
+    }
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+
+    fn entry(
+        package: &str,
+        license: &str,
+        path: &[&str],
+        path_count: usize,
+    ) -> LicenseRiskEntryView {
+        LicenseRiskEntryView {
+            package_name: package.to_string(),
+            package_version: "1.0.0".to_string(),
+            license: license.to_string(),
+            introduction_path: path.iter().map(|p| p.to_string()).collect(),
+            path_count,
+        }
+    }
+
+    #[test]
+    fn test_render_license_risk() {
+        let view = LicenseRiskView {
+            copyleft: vec![
+                entry(
+                    "agpl-core",
+                    "AGPL-3.0-or-later",
+                    &["web", "helper", "agpl-core"],
+                    1,
+                ),
+                entry("gpl-lib", "GPL-3.0-only", &["gpl-lib"], 3),
+            ],
+            unknown: vec![entry("mystery", "N/A", &["cli", "mystery"], 1)],
+        };
+        let mut output = String::new();
+        render(Messages::for_locale(Locale::En), &mut output, &view);
+
+        assert!(output.contains("## License Risk"));
+        assert!(output
+            .contains("| agpl-core | 1.0.0 | AGPL-3.0-or-later | web → helper → agpl-core | 1 |"));
+        assert!(output.contains("| gpl-lib | 1.0.0 | GPL-3.0-only | gpl-lib | 3 |"));
+        assert!(output.contains("### Reachable packages with unknown license"));
+        assert!(output.contains("| mystery | 1.0.0 | N/A | cli → mystery | 1 |"));
+    }
+
+    #[test]
+    fn test_render_without_copyleft_packages() {
+        let mut output = String::new();
+        render(
+            Messages::for_locale(Locale::En),
+            &mut output,
+            &LicenseRiskView::default(),
+        );
+
+        assert!(output.contains("No copyleft package is reachable from the project."));
+        assert!(!output.contains("### Reachable packages with unknown license"));
+    }
+}
@@ -1,6 +1,16 @@
 pub(super) mod components;
 pub(super) mod dependencies;
+pub(super) mod fix_suggestions;
+pub(super) mod generation_parameters;
+pub(super) mod generation_summary;
 pub(super) mod header;
+pub(super) mod license_compatibility;
 pub(super) mod license_compliance;
+pub(super) mod license_risk;
+pub(super) mod projects;
+pub(super) mod python_compatibility;
 pub(super) mod resolution_guide;
 pub(super) mod summary;
+pub(super) mod supply_chain_warnings;
+pub(super) mod version_conflicts;
+pub(super) mod warnings;
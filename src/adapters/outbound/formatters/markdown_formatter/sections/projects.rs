@@ -0,0 +1,29 @@
+use crate::application::read_models::ProjectView;
+use crate::i18n::Messages;
+
+/// Renders the source projects of an aggregated SBOM with their direct dependency counts
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    projects: &[ProjectView],
+) {
+    output.push_str(messages.section_merged_projects);
+    output.push_str("\n\n");
+
+    let columns = [
+        messages.col_project,
+        messages.col_version,
+        messages.col_direct_dependencies,
+    ];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&super::super::table::make_separator(&columns));
+    for project in projects {
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            super::super::table::escape_markdown_table_cell(&project.name),
+            project.version.as_deref().unwrap_or("-"),
+            project.direct_dependencies.len()
+        ));
+    }
+    output.push('\n');
+}
@@ -0,0 +1,102 @@
+use crate::application::read_models::{ComponentView, PythonCompatibilityView};
+use crate::i18n::Messages;
+
+/// Renders the Python compatibility section: the components whose
+/// `requires-python` excludes Python versions the project supports.
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    compatibility: &PythonCompatibilityView,
+    components: &[ComponentView],
+) {
+    output.push('\n');
+    output.push_str(messages.section_python_compatibility);
+    output.push_str("\n\n");
+
+    let mut incompatible: Vec<(&ComponentView, &str)> = components
+        .iter()
+        .filter_map(|c| c.python_compat.as_deref().map(|range| (c, range)))
+        .collect();
+    let project_range =
+        super::super::table::escape_code_span(&compatibility.project_requires_python);
+    if incompatible.is_empty() {
+        output.push_str(&Messages::format(
+            messages.label_no_python_incompatibility,
+            &[&project_range],
+        ));
+        output.push_str("\n\n");
+        return;
+    }
+    incompatible.sort_by(|(a, _), (b, _)| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+
+    output.push_str(&Messages::format(
+        messages.desc_python_compatibility,
+        &[&project_range],
+    ));
+    output.push_str("\n\n");
+
+    let columns = [
+        messages.col_package,
+        messages.col_version,
+        messages.col_requires_python,
+    ];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&super::super::table::make_separator(&columns));
+    for (component, range) in incompatible {
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            super::super::table::escape_markdown_table_cell(&component.name),
+            super::super::table::escape_markdown_table_cell(&component.version),
+            super::super::table::escape_markdown_table_cell(range),
+        ));
+    }
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+    use crate::test_fixtures::SbomReadModelFixture;
+
+    fn view() -> PythonCompatibilityView {
+        PythonCompatibilityView {
+            project_requires_python: ">=3.9".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_incompatible_components() {
+        let mut components = SbomReadModelFixture::small_clean().build().components;
+        components[1].python_compat = Some(">=3.9,<3.13".to_string());
+
+        let mut output = String::new();
+        render(
+            Messages::for_locale(Locale::En),
+            &mut output,
+            &view(),
+            &components,
+        );
+
+        assert!(output.contains("## Python Compatibility"));
+        assert!(output.contains("project's `>=3.9`"));
+        assert!(output.contains("| urllib3 | 1.26.0 | \\>=3.9,<3.13 |"));
+        assert!(!output.contains("requests"));
+    }
+
+    #[test]
+    fn test_render_without_incompatible_components() {
+        let components = SbomReadModelFixture::small_clean().build().components;
+
+        let mut output = String::new();
+        render(
+            Messages::for_locale(Locale::En),
+            &mut output,
+            &view(),
+            &components,
+        );
+
+        assert!(output
+            .contains("Every package supports all Python versions the project allows (`>=3.9`)."));
+    }
+}
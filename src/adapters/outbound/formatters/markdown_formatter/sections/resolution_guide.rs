@@ -1,3 +1,4 @@
+use super::super::style::MarkdownStyle;
 use crate::application::read_models::{ResolutionGuideView, UpgradeRecommendationView};
 use crate::i18n::Messages;
 
@@ -8,6 +9,7 @@ use crate::i18n::Messages;
 /// direct dependency. When `None`, that column is omitted entirely.
 pub(in super::super) fn render(
     messages: &'static Messages,
+    style: MarkdownStyle,
     output: &mut String,
     guide: &ResolutionGuideView,
     upgrade_recommendations: Option<&UpgradeRecommendationView>,
@@ -37,13 +39,6 @@ pub(in super::super) fn render(
 
     for entry in &guide.entries {
         let fixed = entry.fixed_version.as_deref().unwrap_or("N/A");
-        let severity_emoji = match entry.severity {
-            crate::application::read_models::SeverityView::Critical => "🔴",
-            crate::application::read_models::SeverityView::High => "🟠",
-            crate::application::read_models::SeverityView::Medium => "🟡",
-            crate::application::read_models::SeverityView::Low => "🟢",
-            crate::application::read_models::SeverityView::None => "⚪",
-        };
 
         let introduced_by = entry
             .introduced_by
@@ -66,7 +61,7 @@ pub(in super::super) fn render(
             super::super::table::escape_markdown_table_cell(&entry.vulnerable_package),
             super::super::table::escape_markdown_table_cell(&entry.current_version),
             super::super::table::escape_markdown_table_cell(fixed),
-            format!("{} {}", severity_emoji, entry.severity.as_str()),
+            style.severity_cell(entry.severity),
             super::super::table::escape_markdown_table_cell(&introduced_by),
         ];
         if let Some(ref act) = action {
@@ -155,6 +150,11 @@ mod tests {
                 tool_version: "1.0.0".to_string(),
                 serial_number: "urn:uuid:test-123".to_string(),
                 component: None,
+                lockfile_sha256: None,
+                target_environment: None,
+                invocation: None,
+                lockfile_version: None,
+                lockfile_requires_python: None,
             },
             components: vec![
                 ComponentView {
@@ -165,10 +165,22 @@ mod tests {
                     license: Some(LicenseView {
                         spdx_id: Some("Apache-2.0".to_string()),
                         name: "Apache License 2.0".to_string(),
+                        source: None,
                     }),
                     description: Some("HTTP library".to_string()),
                     sha256_hash: None,
                     is_direct_dependency: true,
+                    annotations: Default::default(),
+                    license_override: None,
+                    yanked: None,
+                    source: None,
+                    declared_constraint: None,
+                    license_not_found: false,
+                    license_fetch_error: None,
+                    python_compat: None,
+                    author: None,
+                    supplier: None,
+                    external_refs: Vec::new(),
                 },
                 ComponentView {
                     bom_ref: "pkg:pypi/urllib3@1.26.0".to_string(),
@@ -178,17 +190,39 @@ mod tests {
                     license: Some(LicenseView {
                         spdx_id: Some("MIT".to_string()),
                         name: "MIT License".to_string(),
+                        source: None,
                     }),
                     description: None,
                     sha256_hash: None,
                     is_direct_dependency: false,
+                    annotations: Default::default(),
+                    license_override: None,
+                    yanked: None,
+                    source: None,
+                    declared_constraint: None,
+                    license_not_found: false,
+                    license_fetch_error: None,
+                    python_compat: None,
+                    author: None,
+                    supplier: None,
+                    external_refs: Vec::new(),
                 },
             ],
             dependencies: None,
             vulnerabilities: None,
             license_compliance: None,
+            license_compatibility: None,
+            license_risk: None,
+            python_compatibility: None,
+            typosquats: Vec::new(),
             resolution_guide: None,
             upgrade_recommendations: None,
+            fix_suggestions: None,
+            project_self_check: None,
+            warnings: Vec::new(),
+            stats: None,
+            projects: Vec::new(),
+            version_conflicts: Vec::new(),
         }
     }
 
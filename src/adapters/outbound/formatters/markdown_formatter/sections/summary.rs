@@ -1,5 +1,5 @@
 use crate::application::read_models::{
-    ComponentView, LicenseComplianceView, VulnerabilityReportView,
+    ComponentView, LicenseCompatibilityView, LicenseComplianceView, VulnerabilityReportView,
 };
 use crate::i18n::Messages;
 
@@ -9,7 +9,9 @@ pub(in super::super) fn render(
     output: &mut String,
     components: &[ComponentView],
     vulnerabilities: Option<&VulnerabilityReportView>,
+    unchecked_count: usize,
     license_compliance: Option<&LicenseComplianceView>,
+    license_compatibility: Option<&LicenseCompatibilityView>,
 ) {
     output.push_str(messages.section_summary);
     output.push_str("\n\n");
@@ -85,6 +87,13 @@ pub(in super::super) fn render(
             "| {} | {} | {} |\n",
             messages.label_vuln_low, counts.low, low_status
         ));
+        if unchecked_count > 0 {
+            has_warning = true;
+            output.push_str(&format!(
+                "| {} | {} | ⚠️ |\n",
+                messages.label_vuln_unchecked, unchecked_count
+            ));
+        }
     } else {
         output.push_str(&format!("\n{}\n", messages.label_vuln_check_skipped));
     }
@@ -104,6 +113,32 @@ pub(in super::super) fn render(
         messages.label_license_violations, violation_count, license_status
     ));
 
+    // License compatibility rows (only when the check ran)
+    if let Some(compatibility) = license_compatibility {
+        let incompatible_count = compatibility.summary.incompatible_count;
+        let incompatible_status = if incompatible_count > 0 {
+            has_critical = true;
+            "❌"
+        } else {
+            "✅"
+        };
+        let review_count = compatibility.summary.needs_review_count;
+        let review_status = if review_count > 0 {
+            has_warning = true;
+            "⚠️"
+        } else {
+            "✅"
+        };
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            messages.label_license_incompatibilities, incompatible_count, incompatible_status
+        ));
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            messages.label_licenses_needing_review, review_count, review_status
+        ));
+    }
+
     // Overall line
     output.push('\n');
     let overall = if has_critical {
@@ -121,8 +156,8 @@ pub(in super::super) fn render(
 mod tests {
     use super::*;
     use crate::application::read_models::{
-        LicenseComplianceSummary, LicenseComplianceView, SeverityView, VulnerabilityReportView,
-        VulnerabilityView,
+        LicenseCompatibilitySummary, LicenseComplianceSummary, LicenseComplianceView, SeverityView,
+        VulnerabilityReportView, VulnerabilityView,
     };
     use crate::i18n::{Locale, Messages};
 
@@ -136,6 +171,17 @@ mod tests {
             description: None,
             sha256_hash: None,
             is_direct_dependency: is_direct,
+            annotations: Default::default(),
+            license_override: None,
+            yanked: None,
+            source: None,
+            declared_constraint: None,
+            license_not_found: false,
+            license_fetch_error: None,
+            python_compat: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
         }
     }
 
@@ -143,15 +189,20 @@ mod tests {
         VulnerabilityView {
             bom_ref: String::new(),
             id: String::new(),
+            aliases: Vec::new(),
             affected_component: String::new(),
             affected_component_name: String::new(),
             affected_version: String::new(),
             cvss_score: None,
             cvss_vector: None,
+            cvss_version: None,
             severity,
             fixed_version: None,
             description: None,
             source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
         }
     }
 
@@ -180,7 +231,9 @@ mod tests {
             &mut output,
             components,
             vulnerabilities,
+            0,
             license_compliance,
+            None,
         );
         output
     }
@@ -246,6 +299,23 @@ mod tests {
         assert!(!output.contains("_Vulnerability check skipped._"));
     }
 
+    #[test]
+    fn test_unchecked_packages_row_is_warning() {
+        let report = VulnerabilityReportView::default();
+        let mut output = String::new();
+        render(
+            Messages::for_locale(Locale::En),
+            &mut output,
+            &[],
+            Some(&report),
+            3,
+            None,
+            None,
+        );
+        assert!(output.contains("| Packages not checked for vulnerabilities | 3 | ⚠️ |"));
+        assert!(output.contains("**Overall: Attention recommended**"));
+    }
+
     #[test]
     fn test_vuln_critical_status_is_error() {
         let report = VulnerabilityReportView {
@@ -341,6 +411,38 @@ mod tests {
         assert!(output.contains("**総合判定: 注意が必要です**"));
     }
 
+    #[test]
+    fn test_license_compatibility_rows() {
+        let compatibility = LicenseCompatibilityView {
+            project_license: Some("Apache-2.0".to_string()),
+            project_family: Some("permissive".to_string()),
+            findings: vec![],
+            summary: LicenseCompatibilitySummary {
+                compatible_count: 5,
+                conditional_count: 1,
+                incompatible_count: 2,
+                needs_review_count: 0,
+            },
+        };
+        let mut output = String::new();
+        render(
+            Messages::for_locale(Locale::En),
+            &mut output,
+            &[],
+            None,
+            0,
+            None,
+            Some(&compatibility),
+        );
+
+        assert!(output.contains("| License incompatibilities | 2 | ❌ |"));
+        assert!(output.contains("| Licenses needing review | 0 | ✅ |"));
+        assert!(output.contains("**Overall: Action required**"));
+
+        let without = render_summary(Locale::En, &[], None, None);
+        assert!(!without.contains("License incompatibilities"));
+    }
+
     #[test]
     fn test_license_compliance_none_shows_zero_violations() {
         let output = render_summary(Locale::En, &[], None, None);
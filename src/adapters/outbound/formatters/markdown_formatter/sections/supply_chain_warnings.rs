@@ -0,0 +1,55 @@
+use crate::application::read_models::TyposquatView;
+use crate::i18n::Messages;
+
+/// Renders the Supply Chain Warnings section: the packages whose names are
+/// near misses of popular package names.
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    typosquats: &[TyposquatView],
+) {
+    output.push('\n');
+    output.push_str(messages.section_supply_chain_warnings);
+    output.push_str("\n\n");
+    output.push_str(messages.desc_supply_chain_warnings);
+    output.push_str("\n\n");
+
+    let columns = [
+        messages.col_package,
+        messages.col_version,
+        messages.col_similar_to,
+    ];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&super::super::table::make_separator(&columns));
+    for typosquat in typosquats {
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            super::super::table::escape_markdown_table_cell(&typosquat.package_name),
+            super::super::table::escape_markdown_table_cell(&typosquat.package_version),
+            super::super::table::escape_markdown_table_cell(&typosquat.similar_to),
+        ));
+    }
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+
+    #[test]
+    fn test_render_typosquats() {
+        let typosquats = vec![TyposquatView {
+            package_name: "requets".to_string(),
+            package_version: "2.31.0".to_string(),
+            similar_to: "requests".to_string(),
+        }];
+
+        let mut output = String::new();
+        render(Messages::for_locale(Locale::En), &mut output, &typosquats);
+
+        assert!(output.contains("## Supply Chain Warnings"));
+        assert!(output.contains("| Package | Version | Similar To |"));
+        assert!(output.contains("| requets | 2.31.0 | requests |"));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::application::read_models::VersionConflictView;
+use crate::i18n::Messages;
+
+/// Renders the Version Conflicts subsection: the packages locked at more
+/// than one version, with every locked version.
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    conflicts: &[VersionConflictView],
+) {
+    output.push_str(messages.section_version_conflicts);
+    output.push_str("\n\n");
+    output.push_str(messages.desc_version_conflicts);
+    output.push_str("\n\n");
+
+    let columns = [messages.col_package, messages.col_versions];
+    output.push_str(&format!("| {} |\n", columns.join(" | ")));
+    output.push_str(&super::super::table::make_separator(&columns));
+    for conflict in conflicts {
+        output.push_str(&format!(
+            "| {} | {} |\n",
+            super::super::table::escape_markdown_table_cell(&conflict.name),
+            super::super::table::escape_markdown_table_cell(&conflict.versions.join(", ")),
+        ));
+    }
+    output.push('\n');
+}
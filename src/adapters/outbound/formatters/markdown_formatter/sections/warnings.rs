@@ -0,0 +1,19 @@
+use crate::application::read_models::SbomWarning;
+use crate::i18n::Messages;
+
+/// Renders the warnings appendix listing non-fatal issues raised during generation
+pub(in super::super) fn render(
+    messages: &'static Messages,
+    output: &mut String,
+    warnings: &[SbomWarning],
+) {
+    output.push('\n');
+    output.push_str(messages.section_run_warnings);
+    output.push_str("\n\n");
+    output.push_str(messages.desc_run_warnings);
+    output.push_str("\n\n");
+
+    for warning in warnings {
+        output.push_str(&format!("- {}\n", warning.message(messages)));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::application::read_models::SeverityView;
+
+/// Status markers and the text that replaces them in the plain style
+const PLAIN_MARKERS: &[(&str, &str)] = &[
+    ("✅", "[OK]"),
+    ("⚠️", "[WARNING]"),
+    ("❌", "[FAIL]"),
+    ("❓", "[REVIEW]"),
+    ("ℹ️", "[INFO]"),
+];
+
+/// Visual style of the Markdown report
+///
+/// The default style prefixes severities with a colored circle and marks
+/// status cells and section headings with emoji. The plain style renders both
+/// as text only, for systems that strip emoji when the report is ingested
+/// (issue trackers, some wikis) and would otherwise leave those cells empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkdownStyle {
+    /// Decorate severities and status markers with emoji
+    pub use_emoji: bool,
+    /// Follow each license in the component table with where it came from,
+    /// e.g. `MIT (pypi-classifier)`
+    pub show_license_source: bool,
+    /// Add a "Supplier" column, from the package author or maintainer, to the
+    /// component table
+    pub include_supplier: bool,
+    /// Render transitive dependencies as an indented tree this many levels deep
+    /// (0 = unlimited) instead of one flat table per direct dependency
+    pub tree_depth: Option<usize>,
+    /// Shorten package descriptions in the tables to this many characters
+    /// (0 = unlimited), ending them with an ellipsis
+    pub description_max_length: usize,
+}
+
+impl Default for MarkdownStyle {
+    fn default() -> Self {
+        Self {
+            use_emoji: true,
+            show_license_source: false,
+            include_supplier: false,
+            tree_depth: None,
+            description_max_length: super::table::DEFAULT_DESCRIPTION_MAX_LENGTH,
+        }
+    }
+}
+
+impl MarkdownStyle {
+    /// Text-only style: bare uppercase severities and `[OK]` / `[WARNING]` style markers
+    pub fn plain() -> Self {
+        Self {
+            use_emoji: false,
+            ..Self::default()
+        }
+    }
+
+    /// Sets whether licenses are followed by their source in the component table
+    pub fn with_license_source(mut self, show: bool) -> Self {
+        self.show_license_source = show;
+        self
+    }
+
+    /// Sets whether the component table has a "Supplier" column
+    pub fn with_supplier(mut self, include: bool) -> Self {
+        self.include_supplier = include;
+        self
+    }
+
+    /// Sets how many levels of the dependency tree are rendered; `None` keeps the flat tables
+    pub fn with_tree_depth(mut self, depth: Option<usize>) -> Self {
+        self.tree_depth = depth;
+        self
+    }
+
+    /// Sets the maximum length of description cells; 0 keeps descriptions whole
+    pub fn with_description_max_length(mut self, max_length: usize) -> Self {
+        self.description_max_length = max_length;
+        self
+    }
+
+    /// Renders a severity table cell, e.g. `🔴 CRITICAL` or `CRITICAL`
+    pub(super) fn severity_cell(&self, severity: SeverityView) -> String {
+        if !self.use_emoji {
+            return severity.as_str().to_string();
+        }
+        let emoji = match severity {
+            SeverityView::Critical => "🔴",
+            SeverityView::High => "🟠",
+            SeverityView::Medium => "🟡",
+            SeverityView::Low => "🟢",
+            SeverityView::None => "⚪",
+        };
+        format!("{} {}", emoji, severity.as_str())
+    }
+
+    /// Replaces the status emoji in rendered Markdown with bracketed labels
+    /// when emoji are disabled. A label directly followed by text (as in
+    /// `### ⚠️Warning`) is separated from it by a space.
+    pub(super) fn apply_markers(&self, text: String) -> String {
+        if self.use_emoji {
+            return text;
+        }
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        'scan: while let Some(c) = rest.chars().next() {
+            for (emoji, label) in PLAIN_MARKERS {
+                if let Some(after) = rest.strip_prefix(emoji) {
+                    output.push_str(label);
+                    if after.starts_with(char::is_alphanumeric) {
+                        output.push(' ');
+                    }
+                    rest = after;
+                    continue 'scan;
+                }
+            }
+            output.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_severity_cell() {
+        let emoji = MarkdownStyle::default();
+        let plain = MarkdownStyle::plain();
+
+        assert_eq!(emoji.severity_cell(SeverityView::Critical), "🔴 CRITICAL");
+        assert_eq!(emoji.severity_cell(SeverityView::None), "⚪ NONE");
+        assert_eq!(plain.severity_cell(SeverityView::Critical), "CRITICAL");
+        assert_eq!(plain.severity_cell(SeverityView::Low), "LOW");
+    }
+
+    #[test]
+    fn test_apply_markers() {
+        let text =
+            "### ⚠️Warning Found\n| Direct | 3 | ✅ |\n| GPL | ❌ Incompatible |\n### ℹ️情報\n";
+
+        assert_eq!(
+            MarkdownStyle::default().apply_markers(text.to_string()),
+            text
+        );
+        assert_eq!(
+            MarkdownStyle::plain().apply_markers(text.to_string()),
+            "### [WARNING] Warning Found\n| Direct | 3 | [OK] |\n| GPL | [FAIL] Incompatible |\n### [INFO] 情報\n"
+        );
+    }
+}
@@ -1,8 +1,82 @@
+use crate::application::read_models::VulnerabilityView;
 use crate::i18n::Messages;
 
-/// Escapes pipe characters and newlines for safe Markdown table rendering
+/// Default maximum length, in characters, of a description cell
+pub(super) const DEFAULT_DESCRIPTION_MAX_LENGTH: usize = 200;
+
+/// Returns true for zero-width and bidirectional formatting code points, which
+/// render invisibly but can reorder or hide the surrounding text
+fn is_invisible_format_char(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{061C}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// Replaces line breaks and tabs with spaces and drops other control characters
+/// and invisible formatting code points
+fn strip_unsafe_chars(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| match c {
+            '\n' | '\r' | '\t' => Some(' '),
+            c if c.is_control() || is_invisible_format_char(c) => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+/// Escapes text for safe rendering inside a Markdown table cell
+///
+/// Line breaks become spaces and control characters and zero-width or bidi
+/// override code points are dropped. Pipes, backticks and square brackets are
+/// escaped so the text cannot end the cell, open a code span or form a link,
+/// and a leading `#`, `>` or `-` is escaped so it is not read as a heading,
+/// quote or list marker.
 pub(super) fn escape_markdown_table_cell(text: &str) -> String {
-    text.replace('|', "\\|").replace('\n', " ")
+    let stripped = strip_unsafe_chars(text);
+    let trimmed = stripped.trim_start();
+    let mut escaped = String::with_capacity(stripped.len());
+    escaped.push_str(&stripped[..stripped.len() - trimmed.len()]);
+    if trimmed.starts_with(['#', '>', '-']) {
+        escaped.push('\\');
+    }
+    for c in trimmed.chars() {
+        if matches!(c, '|' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Escapes text rendered inside a code span in a table cell
+///
+/// Backslash escapes are literal inside code spans, so only the unsafe characters
+/// are dropped, pipes are escaped for the table, and backticks, which would close
+/// the span, become single quotes.
+pub(super) fn escape_code_span(text: &str) -> String {
+    strip_unsafe_chars(text)
+        .replace('|', "\\|")
+        .replace('`', "'")
+}
+
+/// Escapes a free-text description for a table cell, shortening it to at most
+/// `max_length` characters (0 = unlimited) with a trailing ellipsis
+pub(super) fn description_cell(text: &str, max_length: usize) -> String {
+    let stripped = strip_unsafe_chars(text);
+    let text = stripped.trim();
+    if max_length == 0 || text.chars().count() <= max_length {
+        return escape_markdown_table_cell(text);
+    }
+    let truncated: String = text.chars().take(max_length.saturating_sub(1)).collect();
+    format!("{}…", escape_markdown_table_cell(truncated.trim_end()))
 }
 
 /// Generates a Markdown table separator row from column header strings.
@@ -18,47 +92,126 @@ pub(super) fn make_separator(cols: &[&str]) -> String {
     sep
 }
 
+/// Package table column headers, with "Declared Constraint" after the version
+/// when `declared_constraint` is set
+fn package_table_columns(
+    messages: &'static Messages,
+    declared_constraint: bool,
+) -> Vec<&'static str> {
+    let mut cols = vec![messages.col_package, messages.col_version];
+    if declared_constraint {
+        cols.push(messages.col_declared_constraint);
+    }
+    cols.extend([messages.col_license, messages.col_description]);
+    cols
+}
+
 /// Locale-aware package table header line
 pub(super) fn table_header(messages: &'static Messages) -> String {
-    format!(
-        "| {} | {} | {} | {} |\n",
-        messages.col_package, messages.col_version, messages.col_license, messages.col_description,
-    )
+    direct_table_header(messages, false)
 }
 
 /// Locale-aware package table separator line
 pub(super) fn table_separator(messages: &'static Messages) -> String {
-    make_separator(&[
+    direct_table_separator(messages, false)
+}
+
+/// Locale-aware Direct Dependencies table header line
+pub(super) fn direct_table_header(
+    messages: &'static Messages,
+    declared_constraint: bool,
+) -> String {
+    format!(
+        "| {} |\n",
+        package_table_columns(messages, declared_constraint).join(" | ")
+    )
+}
+
+/// Locale-aware Direct Dependencies table separator line
+pub(super) fn direct_table_separator(
+    messages: &'static Messages,
+    declared_constraint: bool,
+) -> String {
+    make_separator(&package_table_columns(messages, declared_constraint))
+}
+
+/// Component inventory table column headers, with "Supplier" after the license
+/// when `supplier` is set
+fn component_table_columns(messages: &'static Messages, supplier: bool) -> Vec<&'static str> {
+    let mut cols = vec![
         messages.col_package,
         messages.col_version,
         messages.col_license,
-        messages.col_description,
-    ])
+    ];
+    if supplier {
+        cols.push(messages.col_supplier);
+    }
+    cols.push(messages.col_description);
+    cols
 }
 
-/// Locale-aware vulnerability table header line
-pub(super) fn vuln_table_header(messages: &'static Messages) -> String {
+/// Locale-aware component inventory table header line
+pub(super) fn component_table_header(messages: &'static Messages, supplier: bool) -> String {
     format!(
-        "| {} | {} | {} | {} | {} | {} |\n",
+        "| {} |\n",
+        component_table_columns(messages, supplier).join(" | ")
+    )
+}
+
+/// Locale-aware component inventory table separator line
+pub(super) fn component_table_separator(messages: &'static Messages, supplier: bool) -> String {
+    make_separator(&component_table_columns(messages, supplier))
+}
+
+/// Optional vulnerability table columns, shown only when some row has data for them
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct VulnColumns {
+    /// "Aliases": other IDs the advisory is published under
+    pub(super) aliases: bool,
+    /// "Via": the direct dependencies introducing each package
+    pub(super) via: bool,
+}
+
+impl VulnColumns {
+    /// Enables each optional column that at least one of `vulns` fills
+    pub(super) fn for_vulns<'a>(vulns: impl Iterator<Item = &'a VulnerabilityView>) -> Self {
+        vulns.fold(Self::default(), |columns, vuln| Self {
+            aliases: columns.aliases || !vuln.aliases.is_empty(),
+            via: columns.via || !vuln.introduced_by.is_empty(),
+        })
+    }
+}
+
+/// Vulnerability table column headers, including the enabled optional columns
+fn vuln_table_columns(messages: &'static Messages, columns: VulnColumns) -> Vec<&'static str> {
+    let mut cols = vec![
         messages.col_package,
         messages.col_current_version,
         messages.col_fixed_version,
         messages.col_cvss,
         messages.col_severity,
         messages.col_vuln_id,
+    ];
+    if columns.aliases {
+        cols.push(messages.col_aliases);
+    }
+    if columns.via {
+        cols.push(messages.col_via);
+    }
+    cols
+}
+
+/// Locale-aware vulnerability table header line
+pub(super) fn vuln_table_header(messages: &'static Messages, columns: VulnColumns) -> String {
+    format!(
+        "| {} |\n",
+        vuln_table_columns(messages, columns).join(" | ")
     )
 }
 
 /// Locale-aware vulnerability table separator line
-pub(super) fn vuln_table_separator(messages: &'static Messages) -> String {
-    make_separator(&[
-        messages.col_package,
-        messages.col_current_version,
-        messages.col_fixed_version,
-        messages.col_cvss,
-        messages.col_severity,
-        messages.col_vuln_id,
-    ])
+pub(super) fn vuln_table_separator(messages: &'static Messages, columns: VulnColumns) -> String {
+    make_separator(&vuln_table_columns(messages, columns))
 }
 
 #[cfg(test)]
@@ -71,4 +224,64 @@ mod tests {
         let escaped = escape_markdown_table_cell(input);
         assert_eq!(escaped, "Text with \\| pipe and newline");
     }
+
+    #[test]
+    fn test_escape_markdown_table_cell_escapes_markdown_syntax() {
+        assert_eq!(
+            escape_markdown_table_cell("see [docs](https://example.com) and `code`"),
+            "see \\[docs\\](https://example.com) and \\`code\\`"
+        );
+        assert_eq!(escape_markdown_table_cell("# Heading"), "\\# Heading");
+        assert_eq!(escape_markdown_table_cell("  > quote"), "  \\> quote");
+        assert_eq!(escape_markdown_table_cell("- item"), "\\- item");
+        // Only a leading marker is escaped
+        assert_eq!(escape_markdown_table_cell("a-b #1 > 0"), "a-b #1 > 0");
+    }
+
+    #[test]
+    fn test_escape_markdown_table_cell_strips_invisible_characters() {
+        assert_eq!(
+            escape_markdown_table_cell("\u{202E}gnp.exe\u{202C} zero\u{200B}width\u{FEFF}"),
+            "gnp.exe zerowidth"
+        );
+        assert_eq!(
+            escape_markdown_table_cell("tab\there\r\nbell\u{7}\u{1B}[31m"),
+            "tab here  bell\\[31m"
+        );
+        // Visible non-ASCII text is kept
+        assert_eq!(escape_markdown_table_cell("日本語 café"), "日本語 café");
+    }
+
+    #[test]
+    fn test_escape_code_span() {
+        assert_eq!(
+            escape_code_span(">=3.9,<4 | `x`\u{200B}"),
+            ">=3.9,<4 \\| 'x'"
+        );
+    }
+
+    #[test]
+    fn test_description_cell_truncates_with_ellipsis() {
+        let long = "a".repeat(250);
+        let cell = description_cell(&long, DEFAULT_DESCRIPTION_MAX_LENGTH);
+        assert_eq!(cell.chars().count(), DEFAULT_DESCRIPTION_MAX_LENGTH);
+        assert!(cell.ends_with("a…"));
+
+        assert_eq!(description_cell("short", 5), "short");
+        assert_eq!(
+            description_cell("multi byte 日本語テキスト", 13),
+            "multi byte 日…"
+        );
+        assert_eq!(description_cell(&long, 0), long);
+        // Invisible characters do not count towards the limit
+        assert_eq!(description_cell("ab\u{200B}\u{200B}c", 3), "abc");
+    }
+
+    #[test]
+    fn test_description_cell_does_not_split_escapes() {
+        // The limit applies to the text before escaping, so a cut never leaves a
+        // dangling backslash that would escape the closing pipe
+        assert_eq!(description_cell("abc|def", 5), "abc\\|…");
+        assert_eq!(description_cell("abcd |ef", 6), "abcd…");
+    }
 }
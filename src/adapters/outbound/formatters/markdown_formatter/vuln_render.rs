@@ -1,3 +1,5 @@
+use super::style::MarkdownStyle;
+use super::table::VulnColumns;
 use crate::application::read_models::{
     VulnerabilityReportView, VulnerabilitySummary, VulnerabilityView,
 };
@@ -5,59 +7,126 @@ use crate::i18n::Messages;
 use std::collections::HashSet;
 
 /// Renders the vulnerabilities section
+///
+/// `unchecked_count` packages could not be queried; a note says the report is
+/// incomplete so that an empty table is not read as "no vulnerabilities".
 pub(super) fn render_vulnerabilities(
     messages: &'static Messages,
+    style: MarkdownStyle,
     verified_packages: Option<&HashSet<String>>,
     output: &mut String,
     vulns: &VulnerabilityReportView,
+    project_self_check: Option<&VulnerabilityReportView>,
+    unchecked_count: usize,
 ) {
     output.push('\n');
     output.push_str(messages.section_vuln_report);
     output.push_str("\n\n");
 
+    if unchecked_count > 0 {
+        output.push_str(&Messages::format(
+            messages.warn_vuln_check_incomplete,
+            &[&unchecked_count.to_string()],
+        ));
+        output.push_str("\n\n");
+    }
+
     // Summary section
     render_vulnerability_summary(messages, output, &vulns.summary);
+    let baseline_count = vulns
+        .actionable
+        .iter()
+        .chain(&vulns.informational)
+        .filter(|v| v.in_baseline)
+        .count();
+    if baseline_count > 0 {
+        output.push_str(&Messages::format(
+            messages.summary_vuln_baseline,
+            &[&baseline_count.to_string()],
+        ));
+        output.push_str("\n\n");
+    }
 
     // Actionable vulnerabilities (warning section)
     if vulns.actionable.is_empty() {
         output.push_str(messages.warn_no_vuln_above_threshold);
         output.push_str("\n\n");
     } else {
-        render_actionable_vulnerabilities(messages, verified_packages, output, &vulns.actionable);
-    }
-
-    // Informational vulnerabilities
-    if !vulns.informational.is_empty() {
-        render_informational_vulnerabilities(
+        render_actionable_vulnerabilities(
             messages,
+            style,
             verified_packages,
             output,
-            &vulns.informational,
+            &vulns.actionable,
         );
     }
 
+    // Informational vulnerabilities
+    render_informational_vulnerabilities(messages, style, verified_packages, output, vulns);
+
+    if let Some(self_check) = project_self_check {
+        render_project_self_check(messages, style, verified_packages, output, self_check);
+    }
+
     // Attribution
     output.push_str("\n---\n\n");
     output.push_str(messages.label_osv_attribution);
     output.push('\n');
 }
 
+/// Renders the "Project Self-Check" subsection for the project's own published version
+pub(super) fn render_project_self_check(
+    messages: &'static Messages,
+    style: MarkdownStyle,
+    verified_packages: Option<&HashSet<String>>,
+    output: &mut String,
+    report: &VulnerabilityReportView,
+) {
+    output.push('\n');
+    output.push_str(messages.section_project_self_check);
+    output.push_str("\n\n");
+    output.push_str(messages.desc_project_self_check);
+    output.push_str("\n\n");
+
+    if report.actionable.is_empty() && report.informational.is_empty() {
+        output.push_str(messages.label_project_self_check_none);
+        output.push('\n');
+        return;
+    }
+
+    let columns =
+        VulnColumns::for_vulns(report.actionable.iter().chain(report.informational.iter()));
+    output.push_str(&super::table::vuln_table_header(messages, columns));
+    output.push_str(&super::table::vuln_table_separator(messages, columns));
+
+    let mut sorted_vulns: Vec<&VulnerabilityView> = report
+        .actionable
+        .iter()
+        .chain(report.informational.iter())
+        .collect();
+    sorted_vulns.sort_by_key(|v| &v.severity);
+
+    for vuln in sorted_vulns {
+        render_vulnerability_row(messages, style, verified_packages, output, vuln, columns);
+    }
+}
+
 /// Renders vulnerability summary statistics
 pub(super) fn render_vulnerability_summary(
     messages: &'static Messages,
     output: &mut String,
     summary: &VulnerabilitySummary,
 ) {
-    let vuln_word = if summary.total_count == 1 {
-        messages.label_vulnerability_singular
-    } else {
-        messages.label_vulnerability_plural
-    };
-    let pkg_word = if summary.affected_package_count == 1 {
-        messages.label_package_singular
-    } else {
-        messages.label_package_plural
-    };
+    let vuln_word = messages.unit(
+        summary.total_count,
+        messages.label_vulnerability_singular,
+        messages.label_vulnerability_plural,
+    );
+    let pkg_word = messages.unit(
+        summary.affected_package_count,
+        messages.label_package_singular,
+        messages.label_package_plural,
+    );
     output.push_str(&Messages::format(
         messages.summary_vuln_found,
         &[
@@ -73,22 +142,23 @@ pub(super) fn render_vulnerability_summary(
 /// Renders the warning section for actionable vulnerabilities
 pub(super) fn render_actionable_vulnerabilities(
     messages: &'static Messages,
+    style: MarkdownStyle,
     verified_packages: Option<&HashSet<String>>,
     output: &mut String,
     vulns: &[VulnerabilityView],
 ) {
     let total_vulns = vulns.len();
     let unique_packages = super::helpers::count_unique_packages(vulns);
-    let vuln_word = if total_vulns == 1 {
-        messages.label_vulnerability_singular
-    } else {
-        messages.label_vulnerability_plural
-    };
-    let pkg_word = if unique_packages == 1 {
-        messages.label_package_singular
-    } else {
-        messages.label_package_plural
-    };
+    let vuln_word = messages.unit(
+        total_vulns,
+        messages.label_vulnerability_singular,
+        messages.label_vulnerability_plural,
+    );
+    let pkg_word = messages.unit(
+        unique_packages,
+        messages.label_package_singular,
+        messages.label_package_plural,
+    );
 
     output.push_str(&Messages::format(
         messages.warn_vuln_found,
@@ -101,38 +171,48 @@ pub(super) fn render_actionable_vulnerabilities(
     ));
     output.push_str("\n\n");
 
-    output.push_str(&super::table::vuln_table_header(messages));
-    output.push_str(&super::table::vuln_table_separator(messages));
+    let columns = VulnColumns::for_vulns(vulns.iter());
+    output.push_str(&super::table::vuln_table_header(messages, columns));
+    output.push_str(&super::table::vuln_table_separator(messages, columns));
 
     // Sort by severity (Critical first)
     let mut sorted_vulns: Vec<&VulnerabilityView> = vulns.iter().collect();
     sorted_vulns.sort_by_key(|v| &v.severity);
 
     for vuln in sorted_vulns {
-        render_vulnerability_row(verified_packages, output, vuln);
+        render_vulnerability_row(messages, style, verified_packages, output, vuln, columns);
     }
     output.push('\n');
 }
 
 /// Renders the info section for informational vulnerabilities
+///
+/// The heading counts every informational vulnerability; the table lists the
+/// ones the report's `informational_display` allows, followed by a footer
+/// with the number left out.
 pub(super) fn render_informational_vulnerabilities(
     messages: &'static Messages,
+    style: MarkdownStyle,
     verified_packages: Option<&HashSet<String>>,
     output: &mut String,
-    vulns: &[VulnerabilityView],
+    report: &VulnerabilityReportView,
 ) {
+    let Some((listed, omitted)) = report.listed_informational() else {
+        return;
+    };
+    let vulns = &report.informational;
     let total_vulns = vulns.len();
     let unique_packages = super::helpers::count_unique_packages(vulns);
-    let vuln_word = if total_vulns == 1 {
-        messages.label_vulnerability_singular
-    } else {
-        messages.label_vulnerability_plural
-    };
-    let pkg_word = if unique_packages == 1 {
-        messages.label_package_singular
-    } else {
-        messages.label_package_plural
-    };
+    let vuln_word = messages.unit(
+        total_vulns,
+        messages.label_vulnerability_singular,
+        messages.label_vulnerability_plural,
+    );
+    let pkg_word = messages.unit(
+        unique_packages,
+        messages.label_package_singular,
+        messages.label_package_plural,
+    );
 
     output.push_str(&Messages::format(
         messages.info_vuln_found,
@@ -145,51 +225,110 @@ pub(super) fn render_informational_vulnerabilities(
     ));
     output.push_str("\n\n");
 
-    output.push_str(&super::table::vuln_table_header(messages));
-    output.push_str(&super::table::vuln_table_separator(messages));
+    if !listed.is_empty() {
+        let columns = VulnColumns::for_vulns(listed.iter().copied());
+        output.push_str(&super::table::vuln_table_header(messages, columns));
+        output.push_str(&super::table::vuln_table_separator(messages, columns));
+        for vuln in listed {
+            render_vulnerability_row(messages, style, verified_packages, output, vuln, columns);
+        }
+    }
+    if omitted > 0 {
+        if !output.ends_with("\n\n") {
+            output.push('\n');
+        }
+        output.push_str(&Messages::format(
+            messages.label_informational_omitted,
+            &[&omitted.to_string()],
+        ));
+        output.push('\n');
+    }
+}
 
-    let mut sorted_vulns: Vec<&VulnerabilityView> = vulns.iter().collect();
-    sorted_vulns.sort_by_key(|v| &v.severity);
+/// Formats the "Aliases" cell, linking each alias like the primary ID
+fn format_aliases(vuln: &VulnerabilityView) -> String {
+    if vuln.aliases.is_empty() {
+        return "-".to_string();
+    }
+    vuln.aliases
+        .iter()
+        .map(|alias| super::links::vulnerability_id_to_link(alias))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
 
-    for vuln in sorted_vulns {
-        render_vulnerability_row(verified_packages, output, vuln);
+/// Formats the "Via" cell: the introducing direct dependencies, with the
+/// package itself shown as "(direct)"
+fn format_via(messages: &'static Messages, vuln: &VulnerabilityView) -> String {
+    if vuln.introduced_by.is_empty() {
+        return "-".to_string();
     }
+    vuln.introduced_by
+        .iter()
+        .map(|name| {
+            if *name == vuln.affected_component_name {
+                messages.label_via_direct.to_string()
+            } else {
+                super::table::escape_markdown_table_cell(name)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// Renders a single vulnerability row
 pub(super) fn render_vulnerability_row(
+    messages: &'static Messages,
+    style: MarkdownStyle,
     verified_packages: Option<&HashSet<String>>,
     output: &mut String,
     vuln: &VulnerabilityView,
+    columns: VulnColumns,
 ) {
-    let cvss_display = vuln
-        .cvss_score
-        .map_or("N/A".to_string(), |s| format!("{:.1}", s));
-    let fixed_version = vuln.fixed_version.as_deref().unwrap_or("N/A");
-    let severity_emoji = match vuln.severity {
-        crate::application::read_models::SeverityView::Critical => "🔴",
-        crate::application::read_models::SeverityView::High => "🟠",
-        crate::application::read_models::SeverityView::Medium => "🟡",
-        crate::application::read_models::SeverityView::Low => "🟢",
-        crate::application::read_models::SeverityView::None => "⚪",
+    let cvss_display = match (vuln.cvss_score, vuln.cvss_version) {
+        (Some(score), Some(version)) => format!("{:.1} (v{})", score, version.as_str()),
+        (Some(score), None) => format!("{:.1}", score),
+        (None, _) => "N/A".to_string(),
     };
+    let fixed_version = vuln.fixed_version.as_deref().unwrap_or("N/A");
+    let mut package_cell =
+        super::links::format_package_name(&vuln.affected_component_name, verified_packages);
+    if vuln.excluded_from_inventory {
+        package_cell.push(' ');
+        package_cell.push_str(messages.label_excluded_from_inventory);
+    }
+
+    let mut id_cell = super::links::vulnerability_to_link(&vuln.id, vuln.source_url.as_deref());
+    if vuln.in_baseline {
+        id_cell.push(' ');
+        id_cell.push_str(messages.label_cve_baseline);
+    }
 
     output.push_str(&format!(
-        "| {} | {} | {} | {} | {} {} | {} |\n",
-        super::links::format_package_name(&vuln.affected_component_name, verified_packages),
+        "| {} | {} | {} | {} | {} | {} |",
+        package_cell,
         super::table::escape_markdown_table_cell(&vuln.affected_version),
         super::table::escape_markdown_table_cell(fixed_version),
         cvss_display,
-        severity_emoji,
-        vuln.severity.as_str(),
-        super::links::vulnerability_id_to_link(&vuln.id),
+        style.severity_cell(vuln.severity),
+        id_cell,
     ));
+    if columns.aliases {
+        output.push_str(&format!(" {} |", format_aliases(vuln)));
+    }
+    if columns.via {
+        output.push_str(&format!(" {} |", format_via(messages, vuln)));
+    }
+    output.push('\n');
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::read_models::{SeverityView, VulnerabilitySummary, VulnerabilityView};
+    use crate::application::read_models::{
+        CvssVersionView, InformationalDisplay, SeverityView, VulnerabilitySummary,
+        VulnerabilityView,
+    };
     use crate::i18n::{Locale, Messages};
 
     fn messages() -> &'static Messages {
@@ -228,33 +367,49 @@ mod tests {
             VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1111".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(9.8),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::Critical,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             },
             VulnerabilityView {
                 bom_ref: "vuln-002".to_string(),
                 id: "CVE-2024-2222".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(7.5),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: None,
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             },
         ];
 
         let mut output = String::new();
-        render_actionable_vulnerabilities(messages(), None, &mut output, &vulns);
+        render_actionable_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &vulns,
+        );
 
         assert!(output.contains("### ⚠️Warning Found 2 vulnerabilities in 1 package."));
         assert!(output.contains("[CVE-2024-1111](https://nvd.nist.gov/vuln/detail/CVE-2024-1111)"));
@@ -265,24 +420,230 @@ mod tests {
         assert!(output.contains("7.5"));
     }
 
+    #[test]
+    fn test_render_vulnerability_row_shows_cvss_version() {
+        let vuln = VulnerabilityView {
+            bom_ref: "vuln-004".to_string(),
+            id: "GHSA-aaaa-bbbb-cccc".to_string(),
+            aliases: Vec::new(),
+            affected_component: "pkg:pypi/jinja2@3.1.0".to_string(),
+            affected_component_name: "jinja2".to_string(),
+            affected_version: "3.1.0".to_string(),
+            cvss_score: Some(9.3),
+            cvss_vector: None,
+            cvss_version: Some(CvssVersionView::V4_0),
+            severity: SeverityView::Critical,
+            fixed_version: None,
+            description: None,
+            source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
+        };
+
+        let mut output = String::new();
+        render_vulnerability_row(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &vuln,
+            VulnColumns::default(),
+        );
+
+        assert!(output.contains("| 9.3 (v4.0) |"));
+    }
+
+    #[test]
+    fn test_render_vulnerability_row_links_source_url() {
+        let vuln = VulnerabilityView {
+            bom_ref: "vuln-005".to_string(),
+            id: "CVE-2024-1234".to_string(),
+            aliases: Vec::new(),
+            affected_component: "pkg:pypi/jinja2@3.1.0".to_string(),
+            affected_component_name: "jinja2".to_string(),
+            affected_version: "3.1.0".to_string(),
+            cvss_score: None,
+            cvss_vector: None,
+            cvss_version: None,
+            severity: SeverityView::High,
+            fixed_version: None,
+            description: None,
+            source_url: Some(
+                "https://github.com/pallets/jinja/security/advisories/GHSA-h5c8-rqwp-cp95"
+                    .to_string(),
+            ),
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
+        };
+
+        let mut output = String::new();
+        render_vulnerability_row(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &vuln,
+            VulnColumns::default(),
+        );
+
+        assert!(output.contains(
+            "[CVE-2024-1234](https://github.com/pallets/jinja/security/advisories/GHSA-h5c8-rqwp-cp95)"
+        ));
+    }
+
+    #[test]
+    fn test_render_vulnerability_row_notes_excluded_package() {
+        let vuln = VulnerabilityView {
+            bom_ref: "vuln-006".to_string(),
+            id: "CVE-2024-6666".to_string(),
+            aliases: Vec::new(),
+            affected_component: "internal-lib-1.0.0".to_string(),
+            affected_component_name: "internal-lib".to_string(),
+            affected_version: "1.0.0".to_string(),
+            cvss_score: None,
+            cvss_vector: None,
+            cvss_version: None,
+            severity: SeverityView::High,
+            fixed_version: None,
+            description: None,
+            source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: true,
+            in_baseline: false,
+        };
+
+        let mut output = String::new();
+        render_vulnerability_row(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &vuln,
+            VulnColumns::default(),
+        );
+
+        assert!(output.starts_with(
+            "| [internal-lib](https://pypi.org/project/internal-lib/) (excluded from inventory) | 1.0.0 |"
+        ));
+    }
+
+    #[test]
+    fn test_render_vulnerabilities_via_column() {
+        let vuln = |name: &str, introduced_by: Vec<&str>| VulnerabilityView {
+            bom_ref: format!("vuln-{}", name),
+            id: "CVE-2024-4444".to_string(),
+            aliases: Vec::new(),
+            affected_component: format!("pkg:pypi/{}@1.0.0", name),
+            affected_component_name: name.to_string(),
+            affected_version: "1.0.0".to_string(),
+            cvss_score: None,
+            cvss_vector: None,
+            cvss_version: None,
+            severity: SeverityView::High,
+            fixed_version: None,
+            description: None,
+            source_url: None,
+            introduced_by: introduced_by.into_iter().map(String::from).collect(),
+            excluded_from_inventory: false,
+            in_baseline: false,
+        };
+        let vulns = vec![
+            vuln("urllib3", vec!["httpx", "requests"]),
+            vuln("requests", vec!["requests"]),
+        ];
+
+        let mut output = String::new();
+        render_actionable_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &vulns,
+        );
+
+        assert!(output.contains("| Vulnerability ID | Via |"));
+        assert!(output.contains("| httpx, requests |\n"));
+        assert!(output.contains("| (direct) |\n"));
+    }
+
+    #[test]
+    fn test_render_vulnerabilities_aliases_column() {
+        let vuln = |name: &str, aliases: Vec<&str>| VulnerabilityView {
+            bom_ref: format!("vuln-{}", name),
+            id: "CVE-2024-5555".to_string(),
+            aliases: aliases.into_iter().map(String::from).collect(),
+            affected_component: format!("pkg:pypi/{}@1.0.0", name),
+            affected_component_name: name.to_string(),
+            affected_version: "1.0.0".to_string(),
+            cvss_score: None,
+            cvss_vector: None,
+            cvss_version: None,
+            severity: SeverityView::High,
+            fixed_version: None,
+            description: None,
+            source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
+        };
+        let vulns = vec![
+            vuln("urllib3", vec!["GHSA-aaaa-bbbb-cccc", "PYSEC-2024-1"]),
+            vuln("requests", vec![]),
+        ];
+
+        let mut output = String::new();
+        render_actionable_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &vulns,
+        );
+
+        assert!(output.contains("| Vulnerability ID | Aliases |\n"));
+        assert!(output.contains(
+            "| [GHSA-aaaa-bbbb-cccc](https://github.com/advisories/GHSA-aaaa-bbbb-cccc), \
+             [PYSEC-2024-1](https://osv.dev/vulnerability/PYSEC-2024-1) |\n"
+        ));
+        assert!(output.contains("| - |\n"));
+    }
+
     #[test]
     fn test_render_informational_vulnerabilities() {
-        let vulns = vec![VulnerabilityView {
+        let informational = vec![VulnerabilityView {
             bom_ref: "vuln-003".to_string(),
             id: "CVE-2024-3333".to_string(),
+            aliases: Vec::new(),
             affected_component: "pkg:pypi/urllib3@1.26.0".to_string(),
             affected_component_name: "urllib3".to_string(),
             affected_version: "1.26.0".to_string(),
             cvss_score: Some(2.5),
             cvss_vector: None,
+            cvss_version: None,
             severity: SeverityView::Low,
             fixed_version: Some("1.27.0".to_string()),
             description: None,
             source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
         }];
 
+        let report = VulnerabilityReportView {
+            informational,
+            ..Default::default()
+        };
+
         let mut output = String::new();
-        render_informational_vulnerabilities(messages(), None, &mut output, &vulns);
+        render_informational_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &report,
+        );
 
         assert!(output.contains("### ℹ️Info Found 1 vulnerability in 1 package."));
         assert!(output.contains("[CVE-2024-3333](https://nvd.nist.gov/vuln/detail/CVE-2024-3333)"));
@@ -297,34 +658,167 @@ mod tests {
             VulnerabilityView {
                 bom_ref: "vuln-001".to_string(),
                 id: "CVE-2024-1111".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/requests@2.31.0".to_string(),
                 affected_component_name: "requests".to_string(),
                 affected_version: "2.31.0".to_string(),
                 cvss_score: Some(9.8),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::Critical,
                 fixed_version: Some("2.32.0".to_string()),
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             },
             VulnerabilityView {
                 bom_ref: "vuln-002".to_string(),
                 id: "CVE-2024-4444".to_string(),
+                aliases: Vec::new(),
                 affected_component: "pkg:pypi/urllib3@1.26.0".to_string(),
                 affected_component_name: "urllib3".to_string(),
                 affected_version: "1.26.0".to_string(),
                 cvss_score: Some(8.0),
                 cvss_vector: None,
+                cvss_version: None,
                 severity: SeverityView::High,
                 fixed_version: None,
                 description: None,
                 source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
             },
         ];
 
         let mut output = String::new();
-        render_actionable_vulnerabilities(messages(), None, &mut output, &vulns);
+        render_actionable_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &vulns,
+        );
 
         assert!(output.contains("### ⚠️Warning Found 2 vulnerabilities in 2 packages."));
     }
+
+    fn informational_report(display: InformationalDisplay) -> VulnerabilityReportView {
+        let vuln = |id: &str, severity: SeverityView| VulnerabilityView {
+            bom_ref: id.to_string(),
+            id: id.to_string(),
+            aliases: Vec::new(),
+            affected_component: "pkg:pypi/urllib3@1.26.0".to_string(),
+            affected_component_name: "urllib3".to_string(),
+            affected_version: "1.26.0".to_string(),
+            cvss_score: None,
+            cvss_vector: None,
+            cvss_version: None,
+            severity,
+            fixed_version: None,
+            description: None,
+            source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
+        };
+        VulnerabilityReportView {
+            informational: vec![
+                vuln("CVE-2024-0001", SeverityView::None),
+                vuln("CVE-2024-0002", SeverityView::Low),
+                vuln("CVE-2024-0003", SeverityView::None),
+            ],
+            informational_display: display,
+            ..Default::default()
+        }
+    }
+
+    fn render_informational(display: InformationalDisplay) -> String {
+        let mut output = String::new();
+        render_informational_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &informational_report(display),
+        );
+        output
+    }
+
+    #[test]
+    fn test_render_informational_vulnerabilities_truncated() {
+        let output = render_informational(InformationalDisplay::Limit(1));
+
+        assert!(output.contains("### ℹ️Info Found 3 vulnerabilities in 1 package."));
+        assert!(output.contains("CVE-2024-0002"));
+        assert!(!output.contains("CVE-2024-0001"));
+        assert!(output.ends_with("|\n\n… and 2 more\n"));
+    }
+
+    #[test]
+    fn test_render_informational_vulnerabilities_limit_boundaries() {
+        let output = render_informational(InformationalDisplay::Limit(0));
+        assert!(output.contains("### ℹ️Info Found 3 vulnerabilities in 1 package."));
+        assert!(!output.contains("| Package |"));
+        assert!(output.contains("… and 3 more"));
+
+        for limit in [3, 10] {
+            let output = render_informational(InformationalDisplay::Limit(limit));
+            assert_eq!(output, render_informational(InformationalDisplay::All));
+            assert!(output.contains("CVE-2024-0001"));
+            assert!(!output.contains("more"));
+        }
+    }
+
+    #[test]
+    fn test_render_vulnerabilities_hidden_informational_keeps_summary() {
+        let mut report = informational_report(InformationalDisplay::Hidden);
+        report.summary.total_count = 3;
+        report.summary.affected_package_count = 1;
+
+        let mut output = String::new();
+        render_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &report,
+            None,
+            0,
+        );
+
+        assert!(output.contains("Found 3 vulnerabilities in 1 package."));
+        assert!(!output.contains("ℹ️Info"));
+        assert!(!output.contains("CVE-2024-0002"));
+    }
+
+    #[test]
+    fn test_render_vulnerabilities_labels_baseline_entries() {
+        let mut report = informational_report(InformationalDisplay::All);
+        report.informational[1].in_baseline = true;
+        report.summary.total_count = 3;
+        report.summary.affected_package_count = 1;
+
+        let mut output = String::new();
+        render_vulnerabilities(
+            messages(),
+            MarkdownStyle::default(),
+            None,
+            &mut output,
+            &report,
+            None,
+            0,
+        );
+
+        assert!(output.contains(
+            "1 of them are accepted in the CVE baseline and marked (baseline); \
+             only the others count toward the threshold."
+        ));
+        assert!(output.contains(
+            "[CVE-2024-0002](https://nvd.nist.gov/vuln/detail/CVE-2024-0002) (baseline) |"
+        ));
+        assert!(!output.contains("CVE-2024-0001) (baseline)"));
+    }
 }
@@ -1,6 +1,18 @@
 /// Formatter adapters for different SBOM output formats
 mod cyclonedx_formatter;
+mod cyclonedx_validator;
+mod diff_json_formatter;
+mod graph_formatter;
+mod html_formatter;
 mod markdown_formatter;
+mod vulnerability_json_formatter;
 
-pub use cyclonedx_formatter::CycloneDxFormatter;
-pub use markdown_formatter::MarkdownFormatter;
+pub use cyclonedx_formatter::{CycloneDxFormatter, SpecVersion};
+pub use cyclonedx_validator::CycloneDxValidator;
+#[allow(unused_imports)]
+pub use cyclonedx_validator::Violation;
+pub use diff_json_formatter::DiffJsonFormatter;
+pub use graph_formatter::{GraphFormat, GraphFormatter};
+pub use html_formatter::HtmlFormatter;
+pub use markdown_formatter::{MarkdownFormatter, MarkdownStyle};
+pub use vulnerability_json_formatter::VulnerabilityJsonFormatter;
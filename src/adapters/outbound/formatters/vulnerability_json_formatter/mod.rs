@@ -0,0 +1,127 @@
+mod schema;
+use schema::*;
+
+use crate::application::read_models::{SbomReadModel, VulnerabilityReportView};
+use crate::ports::outbound::SbomFormatter;
+use crate::shared::Result;
+
+/// VulnerabilityJsonFormatter adapter for the machine-readable CVE report
+///
+/// Renders only the vulnerability check result: a `schema_version`, summary
+/// counts, and the actionable and informational findings. Ignored findings are
+/// left out, as they are in the summary. A model without a vulnerability
+/// report renders as an empty report, so a gate can always parse the file.
+pub struct VulnerabilityJsonFormatter;
+
+impl VulnerabilityJsonFormatter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for VulnerabilityJsonFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SbomFormatter for VulnerabilityJsonFormatter {
+    fn format(&self, model: &SbomReadModel) -> Result<String> {
+        let empty = VulnerabilityReportView::default();
+        let view = model.vulnerabilities.as_ref().unwrap_or(&empty);
+        let counts = view.counts_by_severity();
+
+        let report = VulnerabilityReport {
+            schema_version: SCHEMA_VERSION.to_string(),
+            summary: Summary {
+                total: view.summary.total_count,
+                affected_packages: view.summary.affected_package_count,
+                critical: counts.critical,
+                high: counts.high,
+                medium: counts.medium,
+                low: counts.low,
+                unchecked_packages: model.unchecked_package_count(),
+            },
+            actionable: view.actionable.iter().map(Entry::from).collect(),
+            informational: view.informational.iter().map(Entry::from).collect(),
+        };
+
+        serde_json::to_string_pretty(&report).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::{CvssVersionView, VulnerabilitySummary};
+    use crate::test_fixtures::SbomReadModelFixture;
+
+    #[test]
+    fn test_format_schema() {
+        let model = SbomReadModelFixture::with_vulns().build();
+
+        let output = VulnerabilityJsonFormatter::new().format(&model).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(json["schema_version"], "1");
+        assert_eq!(
+            json["summary"],
+            serde_json::json!({
+                "total": 2,
+                "affected_packages": 2,
+                "critical": 1,
+                "high": 0,
+                "medium": 0,
+                "low": 1,
+                "unchecked_packages": 0,
+            })
+        );
+        let actionable = &json["actionable"][0];
+        assert_eq!(actionable["id"], "CVE-2024-1234");
+        assert_eq!(actionable["package"], "requests");
+        assert_eq!(actionable["current_version"], "2.31.0");
+        assert_eq!(actionable["fixed_version"], "2.32.0");
+        assert_eq!(actionable["severity"], "CRITICAL");
+        assert_eq!(json["informational"][0]["package"], "urllib3");
+        assert_eq!(json["informational"][0]["severity"], "LOW");
+    }
+
+    #[test]
+    fn test_round_trip_into_views() {
+        let mut model = SbomReadModelFixture::with_vulns().build();
+        let critical = &mut model.vulnerabilities.as_mut().unwrap().actionable[0];
+        critical.cvss_version = Some(CvssVersionView::V3_1);
+        critical.description = Some("Header leak on redirect".to_string());
+
+        let output = VulnerabilityJsonFormatter::new().format(&model).unwrap();
+        let report: VulnerabilityReport = serde_json::from_str(&output).unwrap();
+
+        let parsed = VulnerabilityReportView {
+            actionable: report.actionable.into_iter().map(Into::into).collect(),
+            informational: report.informational.into_iter().map(Into::into).collect(),
+            ignored: vec![],
+            summary: VulnerabilitySummary {
+                total_count: report.summary.total,
+                affected_package_count: report.summary.affected_packages,
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            format!("{:?}", parsed),
+            format!("{:?}", model.vulnerabilities.unwrap())
+        );
+    }
+
+    #[test]
+    fn test_format_without_vulnerability_report() {
+        let model = SbomReadModelFixture::small_clean().build();
+
+        let output = VulnerabilityJsonFormatter::new().format(&model).unwrap();
+        let report: VulnerabilityReport = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(report.schema_version, SCHEMA_VERSION);
+        assert_eq!(report.summary.total, 0);
+        assert!(report.actionable.is_empty());
+        assert!(report.informational.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::application::read_models::{SeverityView, VulnerabilityView};
+use serde::{Deserialize, Serialize};
+
+/// Version of the report layout; bumped on any breaking change to the fields below
+pub(super) const SCHEMA_VERSION: &str = "1";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct VulnerabilityReport {
+    pub(super) schema_version: String,
+    pub(super) summary: Summary,
+    pub(super) actionable: Vec<Entry>,
+    pub(super) informational: Vec<Entry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct Summary {
+    pub(super) total: usize,
+    pub(super) affected_packages: usize,
+    pub(super) critical: usize,
+    pub(super) high: usize,
+    pub(super) medium: usize,
+    pub(super) low: usize,
+    /// Packages the check could not query; their vulnerabilities are not in this report
+    #[serde(default)]
+    pub(super) unchecked_packages: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct Entry {
+    pub(super) id: String,
+    /// Other IDs the same advisory is published under
+    #[serde(default)]
+    pub(super) aliases: Vec<String>,
+    pub(super) bom_ref: String,
+    pub(super) component_ref: String,
+    pub(super) package: String,
+    pub(super) current_version: String,
+    pub(super) fixed_version: Option<String>,
+    pub(super) cvss_score: Option<f32>,
+    pub(super) cvss_version: Option<String>,
+    pub(super) cvss_vector: Option<String>,
+    pub(super) severity: Severity,
+    pub(super) description: Option<String>,
+    pub(super) source_url: Option<String>,
+    /// The package was checked but left out of the SBOM by `exclude_scope: output`;
+    /// `component_ref` then names no component
+    #[serde(default)]
+    pub(super) excluded_from_inventory: bool,
+    /// Accepted in the `--cve-baseline` file, so it does not fail the run
+    #[serde(default)]
+    pub(super) in_baseline: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub(super) enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    None,
+}
+
+impl From<SeverityView> for Severity {
+    fn from(severity: SeverityView) -> Self {
+        match severity {
+            SeverityView::Critical => Severity::Critical,
+            SeverityView::High => Severity::High,
+            SeverityView::Medium => Severity::Medium,
+            SeverityView::Low => Severity::Low,
+            SeverityView::None => Severity::None,
+        }
+    }
+}
+
+impl From<&VulnerabilityView> for Entry {
+    fn from(vuln: &VulnerabilityView) -> Self {
+        Self {
+            id: vuln.id.clone(),
+            aliases: vuln.aliases.clone(),
+            bom_ref: vuln.bom_ref.clone(),
+            component_ref: vuln.affected_component.clone(),
+            package: vuln.affected_component_name.clone(),
+            current_version: vuln.affected_version.clone(),
+            fixed_version: vuln.fixed_version.clone(),
+            cvss_score: vuln.cvss_score,
+            cvss_version: vuln.cvss_version.map(|v| v.as_str().to_string()),
+            cvss_vector: vuln.cvss_vector.clone(),
+            severity: vuln.severity.into(),
+            description: vuln.description.clone(),
+            source_url: vuln.source_url.clone(),
+            excluded_from_inventory: vuln.excluded_from_inventory,
+            in_baseline: vuln.in_baseline,
+        }
+    }
+}
+
+/// Reads an entry back into the view it was written from
+#[cfg(test)]
+impl From<Entry> for VulnerabilityView {
+    fn from(entry: Entry) -> Self {
+        use crate::application::read_models::CvssVersionView;
+
+        Self {
+            bom_ref: entry.bom_ref,
+            id: entry.id,
+            aliases: entry.aliases,
+            affected_component: entry.component_ref,
+            affected_component_name: entry.package,
+            affected_version: entry.current_version,
+            cvss_score: entry.cvss_score,
+            cvss_vector: entry.cvss_vector,
+            cvss_version: entry.cvss_version.as_deref().map(|v| match v {
+                "2.0" => CvssVersionView::V2_0,
+                "3.0" => CvssVersionView::V3_0,
+                "3.1" => CvssVersionView::V3_1,
+                "4.0" => CvssVersionView::V4_0,
+                other => panic!("unexpected CVSS version {other}"),
+            }),
+            severity: match entry.severity {
+                Severity::Critical => SeverityView::Critical,
+                Severity::High => SeverityView::High,
+                Severity::Medium => SeverityView::Medium,
+                Severity::Low => SeverityView::Low,
+                Severity::None => SeverityView::None,
+            },
+            fixed_version: entry.fixed_version,
+            description: entry.description,
+            source_url: entry.source_url,
+            introduced_by: vec![],
+            excluded_from_inventory: entry.excluded_from_inventory,
+            in_baseline: entry.in_baseline,
+        }
+    }
+}
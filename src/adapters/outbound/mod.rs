@@ -1,6 +1,11 @@
 /// Outbound adapters - Infrastructure implementations of outbound ports
+mod chained_license_repository;
+mod composite_vulnerability_repository;
 pub mod console;
 pub mod filesystem;
 pub mod formatters;
 pub mod network;
 pub mod uv;
+
+pub use chained_license_repository::ChainedLicenseRepository;
+pub use composite_vulnerability_repository::CompositeVulnerabilityRepository;
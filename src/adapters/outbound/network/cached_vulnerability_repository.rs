@@ -0,0 +1,586 @@
+use crate::ports::outbound::{
+    EndpointProbe, ProgressCallback, VulnerabilityFetchOutcome, VulnerabilityRepository,
+};
+use crate::sbom_generation::domain::vulnerability::{
+    CvssScore, CvssVersion, PackageVulnerabilities, Severity, Vulnerability,
+};
+use crate::sbom_generation::domain::{Package, PackageName};
+use crate::shared::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default time a cached result is served before the package is queried again
+pub const DEFAULT_CVE_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Directory the cache is kept in, inside `base_dir` (the data directory or
+/// the system temporary directory)
+pub fn cve_cache_dir(base_dir: &Path) -> PathBuf {
+    base_dir.join("uv-sbom-osv-cache")
+}
+
+/// Version of the cache file layout; files written with another version are ignored
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// One cache file: the vulnerabilities of a single package version
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    format_version: u32,
+    /// When the result was fetched, in seconds since the Unix epoch
+    fetched_at: u64,
+    vulnerabilities: Vec<CachedVulnerability>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedVulnerability {
+    id: String,
+    cvss_score: Option<f32>,
+    cvss_version: Option<String>,
+    severity: String,
+    fixed_version: Option<String>,
+    summary: Option<String>,
+    aliases: Vec<String>,
+    source_url: Option<String>,
+}
+
+impl CachedVulnerability {
+    fn from_vulnerability(vulnerability: &Vulnerability) -> Self {
+        Self {
+            id: vulnerability.id().to_string(),
+            cvss_score: vulnerability.cvss_score().map(|score| score.value()),
+            cvss_version: vulnerability
+                .cvss_version()
+                .map(|version| cvss_version_to_str(version).to_string()),
+            severity: severity_to_str(vulnerability.severity()).to_string(),
+            fixed_version: vulnerability.fixed_version().map(str::to_string),
+            summary: vulnerability.summary().map(str::to_string),
+            aliases: vulnerability.aliases().to_vec(),
+            source_url: vulnerability.source_url().map(str::to_string),
+        }
+    }
+
+    /// Rebuilds the vulnerability; `None` when a stored value is invalid
+    fn into_vulnerability(self) -> Option<Vulnerability> {
+        let cvss_score = match self.cvss_score {
+            Some(score) => Some(CvssScore::new(score).ok()?),
+            None => None,
+        };
+        let cvss_version = match self.cvss_version.as_deref() {
+            Some(version) => Some(cvss_version_from_str(version)?),
+            None => None,
+        };
+        let vulnerability = Vulnerability::new(
+            self.id,
+            cvss_score,
+            severity_from_str(&self.severity)?,
+            self.fixed_version,
+            self.summary,
+        )
+        .ok()?;
+        Some(
+            vulnerability
+                .with_cvss_version(cvss_version)
+                .with_aliases(self.aliases)
+                .with_source_url(self.source_url),
+        )
+    }
+}
+
+fn severity_to_str(severity: Severity) -> &'static str {
+    match severity {
+        Severity::None => "NONE",
+        Severity::Low => "LOW",
+        Severity::Medium => "MEDIUM",
+        Severity::High => "HIGH",
+        Severity::Critical => "CRITICAL",
+    }
+}
+
+fn severity_from_str(severity: &str) -> Option<Severity> {
+    match severity {
+        "NONE" => Some(Severity::None),
+        "LOW" => Some(Severity::Low),
+        "MEDIUM" => Some(Severity::Medium),
+        "HIGH" => Some(Severity::High),
+        "CRITICAL" => Some(Severity::Critical),
+        _ => None,
+    }
+}
+
+fn cvss_version_to_str(version: CvssVersion) -> &'static str {
+    match version {
+        CvssVersion::V2_0 => "2.0",
+        CvssVersion::V3_0 => "3.0",
+        CvssVersion::V3_1 => "3.1",
+        CvssVersion::V4_0 => "4.0",
+    }
+}
+
+fn cvss_version_from_str(version: &str) -> Option<CvssVersion> {
+    match version {
+        "2.0" => Some(CvssVersion::V2_0),
+        "3.0" => Some(CvssVersion::V3_0),
+        "3.1" => Some(CvssVersion::V3_1),
+        "4.0" => Some(CvssVersion::V4_0),
+        _ => None,
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// Normalized name and version, matching a package to its results regardless
+/// of name spelling
+type PackageKey = (String, String);
+
+fn package_key(name: &str, version: &str) -> PackageKey {
+    (PackageName::normalize(name), version.to_string())
+}
+
+/// CachedVulnerabilityRepository wraps a VulnerabilityRepository and keeps its
+/// per-package results on disk for a limited time.
+///
+/// Unlike PyPI metadata, advisories are published over time, so a cached
+/// result is only served while it is younger than the TTL; older entries are
+/// queried again and overwritten. Only packages that were checked successfully
+/// are cached, including those without vulnerabilities. Unreadable, corrupt or
+/// outdated cache files are treated as missing, and a cache directory that
+/// cannot be written only means results are not cached: the cache never fails
+/// a run. A zero TTL disables the cache.
+#[derive(Clone)]
+pub struct CachedVulnerabilityRepository<R: VulnerabilityRepository> {
+    inner: R,
+    cache_dir: PathBuf,
+    ttl: Duration,
+    refresh: bool,
+}
+
+impl<R: VulnerabilityRepository> CachedVulnerabilityRepository<R> {
+    /// Creates a cache in `cache_dir` serving results younger than `ttl`
+    pub fn new(inner: R, cache_dir: PathBuf, ttl: Duration) -> Self {
+        Self {
+            inner,
+            cache_dir,
+            ttl,
+            refresh: false,
+        }
+    }
+
+    /// Ignores cached results and queries every package again, still updating the cache
+    pub fn with_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    fn entry_path(&self, package: &Package) -> PathBuf {
+        let file_name: String = format!(
+            "{}@{}.json",
+            PackageName::normalize(package.name()),
+            package.version()
+        )
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '+' | '@') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+        self.cache_dir.join(file_name)
+    }
+
+    /// Reads the cached vulnerabilities of `package` if a fresh, valid entry exists
+    fn read_entry(&self, package: &Package) -> Option<Vec<Vulnerability>> {
+        let content = std::fs::read_to_string(self.entry_path(package)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        if entry.format_version != CACHE_FORMAT_VERSION {
+            return None;
+        }
+        let age = unix_now().saturating_sub(entry.fetched_at);
+        if age >= self.ttl.as_secs() {
+            return None;
+        }
+        entry
+            .vulnerabilities
+            .into_iter()
+            .map(CachedVulnerability::into_vulnerability)
+            .collect()
+    }
+
+    /// Writes the entry for `package` through a temporary file, so concurrent
+    /// runs never read a partially written entry
+    fn write_entry(
+        &self,
+        package: &Package,
+        vulnerabilities: &[Vulnerability],
+    ) -> std::io::Result<()> {
+        let entry = CacheEntry {
+            format_version: CACHE_FORMAT_VERSION,
+            fetched_at: unix_now(),
+            vulnerabilities: vulnerabilities
+                .iter()
+                .map(CachedVulnerability::from_vulnerability)
+                .collect(),
+        };
+        let json = serde_json::to_vec(&entry).map_err(std::io::Error::other)?;
+        std::fs::create_dir_all(&self.cache_dir)?;
+        let mut file = tempfile::NamedTempFile::new_in(&self.cache_dir)?;
+        file.write_all(&json)?;
+        file.persist(self.entry_path(package))
+            .map(drop)
+            .map_err(|e| e.error)
+    }
+
+    /// Splits `packages` into cached results and packages still to query
+    fn lookup(
+        &self,
+        packages: &[Package],
+    ) -> (HashMap<PackageKey, Vec<Vulnerability>>, Vec<Package>) {
+        let mut cached = HashMap::new();
+        let mut misses = Vec::new();
+        for package in packages {
+            let entry = if self.refresh {
+                None
+            } else {
+                self.read_entry(package)
+            };
+            match entry {
+                Some(vulnerabilities) => {
+                    cached.insert(
+                        package_key(package.name(), package.version()),
+                        vulnerabilities,
+                    );
+                }
+                None => misses.push(package.clone()),
+            }
+        }
+        (cached, misses)
+    }
+}
+
+#[async_trait]
+impl<R: VulnerabilityRepository> VulnerabilityRepository for CachedVulnerabilityRepository<R> {
+    async fn fetch_vulnerabilities(
+        &self,
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
+        self.fetch_vulnerabilities_with_progress(packages, Box::new(|_, _| {}))
+            .await
+    }
+
+    /// Cache hits are counted as done up front, so the progress total covers them
+    /// even when nothing is left to query
+    async fn fetch_vulnerabilities_with_progress(
+        &self,
+        packages: &[Package],
+        progress_callback: ProgressCallback<'static>,
+    ) -> Result<VulnerabilityFetchOutcome> {
+        if self.ttl.is_zero() {
+            return self
+                .inner
+                .fetch_vulnerabilities_with_progress(packages, progress_callback)
+                .await;
+        }
+
+        let (mut cached, misses) = self.lookup(packages);
+        let hits = cached.len();
+        progress_callback(hits, hits);
+
+        let mut fetched = VulnerabilityFetchOutcome::default();
+        if !misses.is_empty() {
+            fetched = self
+                .inner
+                .fetch_vulnerabilities_with_progress(
+                    &misses,
+                    Box::new(move |current, total| progress_callback(hits + current, hits + total)),
+                )
+                .await?;
+        }
+
+        let mut fetched_results: HashMap<PackageKey, PackageVulnerabilities> = fetched
+            .results
+            .into_iter()
+            .map(|result| {
+                (
+                    package_key(result.package_name(), result.current_version()),
+                    result,
+                )
+            })
+            .collect();
+        for package in &misses {
+            let key = package_key(package.name(), package.version());
+            let failed = fetched.failed_packages.iter().any(|unchecked| {
+                package_key(unchecked.package.name(), unchecked.package.version()) == key
+            });
+            if !failed {
+                let vulnerabilities = fetched_results
+                    .get(&key)
+                    .map(PackageVulnerabilities::vulnerabilities)
+                    .unwrap_or(&[]);
+                // Failing to cache only costs a query on the next run
+                let _ = self.write_entry(package, vulnerabilities);
+            }
+        }
+
+        // Results keep the order of `packages`, wherever they came from
+        let results = packages
+            .iter()
+            .filter_map(|package| {
+                let key = package_key(package.name(), package.version());
+                match cached.remove(&key) {
+                    Some(vulnerabilities) if vulnerabilities.is_empty() => None,
+                    Some(vulnerabilities) => Some(PackageVulnerabilities::new(
+                        package.name().to_string(),
+                        package.version().to_string(),
+                        vulnerabilities,
+                    )),
+                    None => fetched_results.remove(&key),
+                }
+            })
+            .collect();
+
+        Ok(VulnerabilityFetchOutcome {
+            results,
+            failed_packages: fetched.failed_packages,
+            incomplete_advisories: fetched.incomplete_advisories,
+            cached_packages: hits + fetched.cached_packages,
+        })
+    }
+
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        self.inner.probe_endpoints().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::outbound::UncheckedPackage;
+    use std::sync::{Arc, Mutex};
+
+    const HOUR: Duration = Duration::from_secs(60 * 60);
+
+    /// Inner repository recording which packages it was asked about; `requests`
+    /// has one vulnerability and packages named in `failing` cannot be checked
+    #[derive(Default)]
+    struct RecordingRepository {
+        queried: Mutex<Vec<String>>,
+        failing: Vec<String>,
+    }
+
+    impl RecordingRepository {
+        fn queried(&self) -> Vec<String> {
+            self.queried.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl VulnerabilityRepository for RecordingRepository {
+        async fn fetch_vulnerabilities(
+            &self,
+            packages: &[Package],
+        ) -> Result<VulnerabilityFetchOutcome> {
+            self.fetch_vulnerabilities_with_progress(packages, Box::new(|_, _| {}))
+                .await
+        }
+
+        async fn fetch_vulnerabilities_with_progress(
+            &self,
+            packages: &[Package],
+            progress_callback: ProgressCallback<'static>,
+        ) -> Result<VulnerabilityFetchOutcome> {
+            let mut outcome = VulnerabilityFetchOutcome::default();
+            for package in packages {
+                self.queried
+                    .lock()
+                    .unwrap()
+                    .push(package.name().to_string());
+                if self.failing.iter().any(|name| name == package.name()) {
+                    outcome.failed_packages.push(UncheckedPackage {
+                        package: package.clone(),
+                        error: "OSV API returned status code 503".to_string(),
+                    });
+                } else if package.name() == "requests" {
+                    let vulnerability = Vulnerability::new(
+                        "CVE-2024-35195".to_string(),
+                        Some(CvssScore::new(5.6).unwrap()),
+                        Severity::Medium,
+                        Some("2.32.0".to_string()),
+                        Some("Session verify=False persists".to_string()),
+                    )
+                    .unwrap()
+                    .with_cvss_version(Some(CvssVersion::V3_1))
+                    .with_aliases(vec!["GHSA-9wx4-h78v-vm56".to_string()]);
+                    outcome.results.push(PackageVulnerabilities::new(
+                        package.name().to_string(),
+                        package.version().to_string(),
+                        vec![vulnerability],
+                    ));
+                }
+            }
+            progress_callback(packages.len(), packages.len());
+            Ok(outcome)
+        }
+    }
+
+    fn packages() -> Vec<Package> {
+        vec![
+            Package::new("requests".to_string(), "2.31.0".to_string()).unwrap(),
+            Package::new("urllib3".to_string(), "2.2.1".to_string()).unwrap(),
+        ]
+    }
+
+    fn repository(dir: &Path, ttl: Duration) -> CachedVulnerabilityRepository<RecordingRepository> {
+        CachedVulnerabilityRepository::new(RecordingRepository::default(), dir.to_path_buf(), ttl)
+    }
+
+    /// Rewrites the timestamp of a cache entry to `age` ago
+    fn age_entry(path: &Path, age: Duration) {
+        let mut entry: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        entry["fetched_at"] = (unix_now() - age.as_secs()).into();
+        std::fs::write(path, entry.to_string()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fresh_entries_are_served_from_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = repository(dir.path(), 24 * HOUR);
+        let fetched = first.fetch_vulnerabilities(&packages()).await.unwrap();
+        assert_eq!(first.inner.queried(), ["requests", "urllib3"]);
+        assert_eq!(fetched.cached_packages, 0);
+
+        let second = repository(dir.path(), 24 * HOUR);
+        let cached = second.fetch_vulnerabilities(&packages()).await.unwrap();
+
+        assert!(second.inner.queried().is_empty());
+        assert_eq!(cached.cached_packages, 2);
+        assert_eq!(cached.results.len(), 1);
+        let (original, restored) = (
+            &fetched.results[0].vulnerabilities()[0],
+            &cached.results[0].vulnerabilities()[0],
+        );
+        assert_eq!(restored, original);
+        assert_eq!(restored.summary(), Some("Session verify=False persists"));
+    }
+
+    #[tokio::test]
+    async fn test_stale_entries_are_refetched() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = repository(dir.path(), HOUR);
+        repo.fetch_vulnerabilities(&packages()).await.unwrap();
+        age_entry(&dir.path().join("requests@2.31.0.json"), 2 * HOUR);
+
+        let stale = repository(dir.path(), HOUR);
+        let outcome = stale.fetch_vulnerabilities(&packages()).await.unwrap();
+
+        assert_eq!(stale.inner.queried(), ["requests"]);
+        assert_eq!(outcome.cached_packages, 1);
+        assert_eq!(outcome.results[0].package_name(), "requests");
+
+        // The refetched entry is fresh again
+        let again = repository(dir.path(), HOUR);
+        again.fetch_vulnerabilities(&packages()).await.unwrap();
+        assert!(again.inner.queried().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_and_outdated_entries_are_discarded() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("requests@2.31.0.json"), "{not json").unwrap();
+        std::fs::write(
+            dir.path().join("urllib3@2.2.1.json"),
+            format!(
+                r#"{{"format_version": 0, "fetched_at": {}, "vulnerabilities": []}}"#,
+                unix_now()
+            ),
+        )
+        .unwrap();
+
+        let repo = repository(dir.path(), 24 * HOUR);
+        let outcome = repo.fetch_vulnerabilities(&packages()).await.unwrap();
+
+        assert_eq!(repo.inner.queried(), ["requests", "urllib3"]);
+        assert_eq!(outcome.cached_packages, 0);
+        assert_eq!(outcome.results.len(), 1);
+        let rewritten = repository(dir.path(), 24 * HOUR);
+        rewritten.fetch_vulnerabilities(&packages()).await.unwrap();
+        assert!(rewritten.inner.queried().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_ignores_cached_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        repository(dir.path(), 24 * HOUR)
+            .fetch_vulnerabilities(&packages())
+            .await
+            .unwrap();
+
+        let refreshed = repository(dir.path(), 24 * HOUR).with_refresh(true);
+        let outcome = refreshed.fetch_vulnerabilities(&packages()).await.unwrap();
+
+        assert_eq!(refreshed.inner.queried(), ["requests", "urllib3"]);
+        assert_eq!(outcome.cached_packages, 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_packages_are_not_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let failing = CachedVulnerabilityRepository::new(
+            RecordingRepository {
+                failing: vec!["urllib3".to_string()],
+                ..Default::default()
+            },
+            dir.path().to_path_buf(),
+            24 * HOUR,
+        );
+        let outcome = failing.fetch_vulnerabilities(&packages()).await.unwrap();
+        assert_eq!(outcome.failed_packages.len(), 1);
+
+        let retry = repository(dir.path(), 24 * HOUR);
+        retry.fetch_vulnerabilities(&packages()).await.unwrap();
+        assert_eq!(retry.inner.queried(), ["urllib3"]);
+    }
+
+    #[tokio::test]
+    async fn test_cache_hits_count_towards_progress() {
+        let dir = tempfile::tempdir().unwrap();
+        repository(dir.path(), 24 * HOUR)
+            .fetch_vulnerabilities(&packages()[..1])
+            .await
+            .unwrap();
+
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let recorded = updates.clone();
+        repository(dir.path(), 24 * HOUR)
+            .fetch_vulnerabilities_with_progress(
+                &packages(),
+                Box::new(move |current, total| recorded.lock().unwrap().push((current, total))),
+            )
+            .await
+            .unwrap();
+
+        // One hit up front, then the inner repository's progress offset by it
+        assert_eq!(*updates.lock().unwrap(), [(1, 1), (2, 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_zero_ttl_disables_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = repository(&dir.path().join("cache"), Duration::ZERO);
+
+        repo.fetch_vulnerabilities(&packages()).await.unwrap();
+        repo.fetch_vulnerabilities(&packages()).await.unwrap();
+
+        assert_eq!(repo.inner.queried().len(), 4);
+        assert!(!dir.path().join("cache").exists());
+    }
+}
@@ -1,4 +1,5 @@
-use crate::ports::outbound::{LicenseRepository, PyPiMetadata};
+use crate::ports::outbound::{EndpointProbe, LicenseRepository, PyPiMetadata};
+use crate::sbom_generation::policies::LicensePriority;
 use crate::shared::Result;
 use async_trait::async_trait;
 use dashmap::DashMap;
@@ -70,11 +71,20 @@ impl<R: LicenseRepository> LicenseRepository for CachingPyPiLicenseRepository<R>
 
         Ok(metadata)
     }
+
+    fn license_priority(&self) -> LicensePriority {
+        self.inner.license_priority()
+    }
+
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        self.inner.probe_endpoints().await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sbom_generation::domain::{ExternalRefType, PackageAuthorship, PackageLinks};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     /// Mock repository for testing that tracks call counts
@@ -99,16 +109,29 @@ mod tests {
         async fn fetch_license_info(
             &self,
             package_name: &str,
-            _version: &str,
+            version: &str,
         ) -> Result<PyPiMetadata> {
             self.call_count.fetch_add(1, Ordering::SeqCst);
-            Ok((
-                Some(format!("{}-license", package_name)),
-                Some("MIT".to_string()),
-                vec!["License :: OSI Approved :: MIT License".to_string()],
-                Some(format!("{} description", package_name)),
-                None,
-            ))
+            Ok(PyPiMetadata {
+                license: Some(format!("{}-license", package_name)),
+                license_expression: Some("MIT".to_string()),
+                classifiers: vec!["License :: OSI Approved :: MIT License".to_string()],
+                description: Some(format!("{} description", package_name)),
+                // Pretend every 0.x release was yanked
+                yanked: version
+                    .starts_with("0.")
+                    .then(|| "Broken wheel".to_string()),
+                authorship: PackageAuthorship::new(
+                    Some(format!("{} author", package_name)),
+                    None,
+                    None,
+                ),
+                links: PackageLinks::new(
+                    Some(&format!("https://{}.example.com", package_name)),
+                    std::iter::empty(),
+                ),
+                ..Default::default()
+            })
         }
     }
 
@@ -122,7 +145,7 @@ mod tests {
             .fetch_license_info("requests", "2.31.0")
             .await
             .unwrap();
-        assert_eq!(result1.0, Some("requests-license".to_string()));
+        assert_eq!(result1.license, Some("requests-license".to_string()));
         assert_eq!(caching_repo.inner.get_call_count(), 1);
 
         // Second call - should return cached value
@@ -130,7 +153,7 @@ mod tests {
             .fetch_license_info("requests", "2.31.0")
             .await
             .unwrap();
-        assert_eq!(result2.0, Some("requests-license".to_string()));
+        assert_eq!(result2.license, Some("requests-license".to_string()));
         // Call count should still be 1 (cached)
         assert_eq!(caching_repo.inner.get_call_count(), 1);
 
@@ -138,6 +161,67 @@ mod tests {
         assert_eq!(caching_repo.cache_size(), 1);
     }
 
+    #[tokio::test]
+    async fn test_caching_repository_keeps_yank_reason() {
+        let mock = MockLicenseRepository::new();
+        let caching_repo = CachingPyPiLicenseRepository::new(mock);
+
+        let fetched = caching_repo
+            .fetch_license_info("urllib3", "0.9.0")
+            .await
+            .unwrap();
+        let cached = caching_repo
+            .enrich_with_license("urllib3", "0.9.0")
+            .await
+            .unwrap();
+
+        assert_eq!(fetched.yanked.as_deref(), Some("Broken wheel"));
+        assert_eq!(cached.yanked(), Some("Broken wheel"));
+        assert_eq!(caching_repo.inner.get_call_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_caching_repository_keeps_authorship() {
+        let mock = MockLicenseRepository::new();
+        let caching_repo = CachingPyPiLicenseRepository::new(mock);
+
+        caching_repo
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap();
+        let cached = caching_repo
+            .enrich_with_license("requests", "2.31.0")
+            .await
+            .unwrap();
+
+        assert_eq!(cached.authorship().supplier(), Some("requests author"));
+        assert_eq!(caching_repo.inner.get_call_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_caching_repository_keeps_links() {
+        let mock = MockLicenseRepository::new();
+        let caching_repo = CachingPyPiLicenseRepository::new(mock);
+
+        caching_repo
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap();
+        let cached = caching_repo
+            .enrich_with_license("requests", "2.31.0")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cached.links().refs(),
+            [(
+                ExternalRefType::Website,
+                "https://requests.example.com".to_string()
+            )]
+        );
+        assert_eq!(caching_repo.inner.get_call_count(), 1);
+    }
+
     #[tokio::test]
     async fn test_caching_repository_different_versions_cached_separately() {
         let mock = MockLicenseRepository::new();
@@ -171,14 +255,14 @@ mod tests {
             .fetch_license_info("requests", "2.31.0")
             .await
             .unwrap();
-        assert_eq!(result1.0, Some("requests-license".to_string()));
+        assert_eq!(result1.license, Some("requests-license".to_string()));
 
         // Fetch flask - should hit inner repository
         let result2 = caching_repo
             .fetch_license_info("flask", "2.3.0")
             .await
             .unwrap();
-        assert_eq!(result2.0, Some("flask-license".to_string()));
+        assert_eq!(result2.license, Some("flask-license".to_string()));
 
         assert_eq!(caching_repo.inner.get_call_count(), 2);
         assert_eq!(caching_repo.cache_size(), 2);
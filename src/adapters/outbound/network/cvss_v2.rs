@@ -0,0 +1,104 @@
+//! CVSS v2 base score calculation
+//!
+//! Older advisories in OSV only carry a `CVSS_V2` entry. Its base score follows
+//! the v2 equations: an impact sub-score from confidentiality, integrity and
+//! availability, and an exploitability sub-score from access vector, access
+//! complexity and authentication.
+
+use crate::sbom_generation::domain::vulnerability::CvssScore;
+use std::collections::HashMap;
+
+/// Extracts the numeric score from a CVSS v2 vector string
+///
+/// Example: "AV:N/AC:L/Au:N/C:P/I:P/A:P" -> Some(7.5)
+///
+/// The vector may be wrapped in parentheses or carry a "CVSS:2.0/" prefix, as
+/// some databases write it. Temporal and environmental metrics are ignored.
+pub(super) fn parse_cvss_v2_score(cvss_vector: &str) -> Option<CvssScore> {
+    let vector = cvss_vector
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')');
+    let vector = vector.strip_prefix("CVSS:2.0/").unwrap_or(vector);
+
+    let metrics: HashMap<&str, &str> = vector
+        .split('/')
+        .filter_map(|part| part.split_once(':'))
+        .collect();
+
+    let av = match *metrics.get("AV")? {
+        "L" => 0.395, // Local
+        "A" => 0.646, // Adjacent network
+        "N" => 1.0,   // Network
+        _ => return None,
+    };
+    let ac = match *metrics.get("AC")? {
+        "H" => 0.35, // High
+        "M" => 0.61, // Medium
+        "L" => 0.71, // Low
+        _ => return None,
+    };
+    let au = match *metrics.get("Au")? {
+        "M" => 0.45,  // Multiple
+        "S" => 0.56,  // Single
+        "N" => 0.704, // None
+        _ => return None,
+    };
+    let c = impact_value(metrics.get("C")?)?;
+    let i = impact_value(metrics.get("I")?)?;
+    let a = impact_value(metrics.get("A")?)?;
+
+    let impact = 10.41 * (1.0 - (1.0 - c) * (1.0 - i) * (1.0 - a));
+    let exploitability = 20.0 * av * ac * au;
+    let f_impact = if impact == 0.0 { 0.0 } else { 1.176 };
+    let base = ((0.6 * impact) + (0.4 * exploitability) - 1.5) * f_impact;
+
+    // Round to one decimal place; clamp the small negative result of all-None impact
+    let rounded = ((base * 10.0).round() / 10.0).max(0.0);
+    CvssScore::new(rounded as f32).ok()
+}
+
+/// Confidentiality, integrity and availability impact values
+fn impact_value(value: &str) -> Option<f64> {
+    match value {
+        "N" => Some(0.0),   // None
+        "P" => Some(0.275), // Partial
+        "C" => Some(0.660), // Complete
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(vector: &str) -> f32 {
+        parse_cvss_v2_score(vector).unwrap().value()
+    }
+
+    #[test]
+    fn test_base_scores_of_published_vectors() {
+        // CVE-2014-0160 (Heartbleed)
+        assert_eq!(score("AV:N/AC:L/Au:N/C:P/I:N/A:N"), 5.0);
+        // CVE-2014-6271 (Shellshock)
+        assert_eq!(score("AV:N/AC:L/Au:N/C:C/I:C/A:C"), 10.0);
+        assert_eq!(score("AV:N/AC:L/Au:N/C:P/I:P/A:P"), 7.5);
+        assert_eq!(score("AV:N/AC:M/Au:N/C:N/I:P/A:N"), 4.3);
+        assert_eq!(score("AV:L/AC:L/Au:N/C:P/I:N/A:N"), 2.1);
+        assert_eq!(score("AV:N/AC:L/Au:N/C:N/I:N/A:N"), 0.0);
+    }
+
+    #[test]
+    fn test_accepts_prefixed_and_parenthesized_vectors() {
+        assert_eq!(score("(AV:N/AC:L/Au:N/C:P/I:P/A:P)"), 7.5);
+        assert_eq!(score("CVSS:2.0/AV:N/AC:L/Au:N/C:P/I:P/A:P"), 7.5);
+        assert_eq!(score("AV:N/AC:L/Au:N/C:P/I:P/A:P/E:F/RL:OF/RC:C"), 7.5);
+    }
+
+    #[test]
+    fn test_rejects_incomplete_or_invalid_vectors() {
+        assert!(parse_cvss_v2_score("AV:N/AC:L/Au:N/C:P/I:P").is_none());
+        assert!(parse_cvss_v2_score("AV:X/AC:L/Au:N/C:P/I:P/A:P").is_none());
+        assert!(parse_cvss_v2_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").is_none());
+    }
+}
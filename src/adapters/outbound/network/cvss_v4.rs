@@ -0,0 +1,692 @@
+//! CVSS v4.0 base score calculation
+//!
+//! Implements the macrovector scoring from the CVSS v4.0 specification: the vector
+//! is mapped to one of 270 equivalence classes (EQ1-EQ6), whose score comes from
+//! the published lookup table, and is then lowered by the vector's distance from
+//! the highest-severity vector of its class.
+
+use crate::sbom_generation::domain::vulnerability::CvssScore;
+use std::collections::HashMap;
+
+/// Macrovector scores keyed by "EQ1 EQ2 EQ3 EQ4 EQ5 EQ6", from the specification
+const MACROVECTOR_SCORES: [(&str, f64); 270] = [
+    ("000000", 10.0),
+    ("000001", 9.9),
+    ("000010", 9.8),
+    ("000011", 9.5),
+    ("000020", 9.5),
+    ("000021", 9.2),
+    ("000100", 10.0),
+    ("000101", 9.6),
+    ("000110", 9.3),
+    ("000111", 8.7),
+    ("000120", 9.1),
+    ("000121", 8.1),
+    ("000200", 9.3),
+    ("000201", 9.0),
+    ("000210", 8.9),
+    ("000211", 8.0),
+    ("000220", 8.1),
+    ("000221", 6.8),
+    ("001000", 9.8),
+    ("001001", 9.5),
+    ("001010", 9.5),
+    ("001011", 9.2),
+    ("001020", 9.0),
+    ("001021", 8.4),
+    ("001100", 9.3),
+    ("001101", 9.2),
+    ("001110", 8.9),
+    ("001111", 8.1),
+    ("001120", 8.1),
+    ("001121", 6.5),
+    ("001200", 8.8),
+    ("001201", 8.0),
+    ("001210", 7.8),
+    ("001211", 7.0),
+    ("001220", 6.9),
+    ("001221", 4.8),
+    ("002001", 9.2),
+    ("002011", 8.2),
+    ("002021", 7.2),
+    ("002101", 7.9),
+    ("002111", 6.9),
+    ("002121", 5.0),
+    ("002201", 6.9),
+    ("002211", 5.5),
+    ("002221", 2.7),
+    ("010000", 9.9),
+    ("010001", 9.7),
+    ("010010", 9.5),
+    ("010011", 9.2),
+    ("010020", 9.2),
+    ("010021", 8.5),
+    ("010100", 9.5),
+    ("010101", 9.1),
+    ("010110", 9.0),
+    ("010111", 8.3),
+    ("010120", 8.4),
+    ("010121", 7.1),
+    ("010200", 9.2),
+    ("010201", 8.1),
+    ("010210", 8.2),
+    ("010211", 7.1),
+    ("010220", 7.2),
+    ("010221", 5.3),
+    ("011000", 9.5),
+    ("011001", 9.3),
+    ("011010", 9.2),
+    ("011011", 8.5),
+    ("011020", 8.5),
+    ("011021", 7.3),
+    ("011100", 9.2),
+    ("011101", 8.2),
+    ("011110", 8.0),
+    ("011111", 7.2),
+    ("011120", 7.0),
+    ("011121", 5.9),
+    ("011200", 8.4),
+    ("011201", 7.0),
+    ("011210", 7.1),
+    ("011211", 5.2),
+    ("011220", 5.0),
+    ("011221", 3.0),
+    ("012001", 8.6),
+    ("012011", 7.5),
+    ("012021", 5.2),
+    ("012101", 7.1),
+    ("012111", 5.2),
+    ("012121", 2.9),
+    ("012201", 6.3),
+    ("012211", 2.9),
+    ("012221", 1.7),
+    ("100000", 9.8),
+    ("100001", 9.5),
+    ("100010", 9.4),
+    ("100011", 8.7),
+    ("100020", 9.1),
+    ("100021", 8.1),
+    ("100100", 9.4),
+    ("100101", 8.9),
+    ("100110", 8.6),
+    ("100111", 7.4),
+    ("100120", 7.7),
+    ("100121", 6.4),
+    ("100200", 8.7),
+    ("100201", 7.5),
+    ("100210", 7.4),
+    ("100211", 6.3),
+    ("100220", 6.3),
+    ("100221", 4.9),
+    ("101000", 9.4),
+    ("101001", 8.9),
+    ("101010", 8.8),
+    ("101011", 7.7),
+    ("101020", 7.6),
+    ("101021", 6.7),
+    ("101100", 8.6),
+    ("101101", 7.6),
+    ("101110", 7.4),
+    ("101111", 5.8),
+    ("101120", 5.9),
+    ("101121", 5.0),
+    ("101200", 7.2),
+    ("101201", 5.7),
+    ("101210", 5.7),
+    ("101211", 5.2),
+    ("101220", 5.2),
+    ("101221", 2.5),
+    ("102001", 8.3),
+    ("102011", 7.0),
+    ("102021", 5.4),
+    ("102101", 6.5),
+    ("102111", 5.8),
+    ("102121", 2.6),
+    ("102201", 5.3),
+    ("102211", 2.1),
+    ("102221", 1.3),
+    ("110000", 9.5),
+    ("110001", 9.0),
+    ("110010", 8.8),
+    ("110011", 7.6),
+    ("110020", 7.6),
+    ("110021", 7.0),
+    ("110100", 9.0),
+    ("110101", 7.7),
+    ("110110", 7.5),
+    ("110111", 6.2),
+    ("110120", 6.1),
+    ("110121", 5.3),
+    ("110200", 7.7),
+    ("110201", 6.6),
+    ("110210", 6.8),
+    ("110211", 5.9),
+    ("110220", 5.2),
+    ("110221", 3.0),
+    ("111000", 8.9),
+    ("111001", 7.8),
+    ("111010", 7.6),
+    ("111011", 6.7),
+    ("111020", 6.2),
+    ("111021", 5.8),
+    ("111100", 7.4),
+    ("111101", 5.9),
+    ("111110", 5.7),
+    ("111111", 5.7),
+    ("111120", 4.7),
+    ("111121", 2.3),
+    ("111200", 6.1),
+    ("111201", 5.2),
+    ("111210", 5.7),
+    ("111211", 2.9),
+    ("111220", 2.4),
+    ("111221", 1.6),
+    ("112001", 7.1),
+    ("112011", 5.9),
+    ("112021", 3.0),
+    ("112101", 5.8),
+    ("112111", 2.6),
+    ("112121", 1.5),
+    ("112201", 2.3),
+    ("112211", 1.3),
+    ("112221", 0.6),
+    ("200000", 9.3),
+    ("200001", 8.7),
+    ("200010", 8.6),
+    ("200011", 7.2),
+    ("200020", 7.5),
+    ("200021", 5.8),
+    ("200100", 8.6),
+    ("200101", 7.4),
+    ("200110", 7.4),
+    ("200111", 6.1),
+    ("200120", 5.6),
+    ("200121", 3.4),
+    ("200200", 7.0),
+    ("200201", 5.4),
+    ("200210", 5.2),
+    ("200211", 4.0),
+    ("200220", 4.0),
+    ("200221", 2.2),
+    ("201000", 8.5),
+    ("201001", 7.5),
+    ("201010", 7.4),
+    ("201011", 5.5),
+    ("201020", 6.2),
+    ("201021", 5.1),
+    ("201100", 7.2),
+    ("201101", 5.7),
+    ("201110", 5.5),
+    ("201111", 4.1),
+    ("201120", 4.6),
+    ("201121", 1.9),
+    ("201200", 5.3),
+    ("201201", 3.6),
+    ("201210", 3.4),
+    ("201211", 1.9),
+    ("201220", 1.9),
+    ("201221", 0.8),
+    ("202001", 6.4),
+    ("202011", 5.1),
+    ("202021", 2.0),
+    ("202101", 4.7),
+    ("202111", 2.1),
+    ("202121", 1.1),
+    ("202201", 2.4),
+    ("202211", 0.9),
+    ("202221", 0.4),
+    ("210000", 8.8),
+    ("210001", 7.5),
+    ("210010", 7.3),
+    ("210011", 5.3),
+    ("210020", 6.0),
+    ("210021", 5.0),
+    ("210100", 7.3),
+    ("210101", 5.5),
+    ("210110", 5.9),
+    ("210111", 4.0),
+    ("210120", 4.1),
+    ("210121", 2.0),
+    ("210200", 5.4),
+    ("210201", 4.3),
+    ("210210", 4.5),
+    ("210211", 2.2),
+    ("210220", 2.0),
+    ("210221", 1.1),
+    ("211000", 7.5),
+    ("211001", 5.5),
+    ("211010", 5.8),
+    ("211011", 4.5),
+    ("211020", 4.0),
+    ("211021", 2.1),
+    ("211100", 6.1),
+    ("211101", 5.1),
+    ("211110", 4.8),
+    ("211111", 1.8),
+    ("211120", 2.0),
+    ("211121", 0.9),
+    ("211200", 4.6),
+    ("211201", 1.8),
+    ("211210", 1.7),
+    ("211211", 0.7),
+    ("211220", 0.8),
+    ("211221", 0.2),
+    ("212001", 5.3),
+    ("212011", 2.4),
+    ("212021", 1.4),
+    ("212101", 2.4),
+    ("212111", 1.2),
+    ("212121", 0.5),
+    ("212201", 1.0),
+    ("212211", 0.3),
+    ("212221", 0.1),
+];
+
+/// Metrics that contribute to the severity distance, grouped by equivalence set
+const EQ1_METRICS: [&str; 3] = ["AV", "PR", "UI"];
+const EQ2_METRICS: [&str; 2] = ["AC", "AT"];
+const EQ3_EQ6_METRICS: [&str; 6] = ["VC", "VI", "VA", "CR", "IR", "AR"];
+const EQ4_METRICS: [&str; 3] = ["SC", "SI", "SA"];
+
+/// Base metrics that must be present in every CVSS v4.0 vector
+const REQUIRED_METRICS: [&str; 11] = [
+    "AV", "AC", "AT", "PR", "UI", "VC", "VI", "VA", "SC", "SI", "SA",
+];
+
+/// Step between two adjacent metric levels
+const STEP: f64 = 0.1;
+
+/// Extracts the numeric score from a CVSS v4.0 vector string
+///
+/// Example: "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N" -> Some(9.3)
+///
+/// Threat and environmental metrics are honoured when present; supplemental
+/// metrics do not affect the score and are ignored.
+pub(super) fn parse_cvss_v4_score(cvss_vector: &str) -> Option<CvssScore> {
+    let mut parts = cvss_vector.split('/');
+    if parts.next()? != "CVSS:4.0" {
+        return None;
+    }
+
+    let metrics: HashMap<&str, &str> = parts.filter_map(|part| part.split_once(':')).collect();
+
+    if REQUIRED_METRICS.iter().any(|metric| {
+        metrics
+            .get(metric)
+            .is_none_or(|v| level(metric, v).is_none())
+    }) {
+        return None;
+    }
+
+    let vector = Vector { metrics };
+    CvssScore::new(vector.score()? as f32).ok()
+}
+
+/// A parsed vector, resolving modified and defaulted metric values
+struct Vector<'a> {
+    metrics: HashMap<&'a str, &'a str>,
+}
+
+impl<'a> Vector<'a> {
+    /// Returns the effective value of `metric`: the modified metric when set,
+    /// otherwise the base value, with the specification's defaults for `X`
+    fn get(&self, metric: &str) -> &'a str {
+        let modified = format!("M{}", metric);
+        if let Some(&value) = self.metrics.get(modified.as_str()) {
+            if value != "X" {
+                return value;
+            }
+        }
+        match (metric, self.metrics.get(metric).copied()) {
+            (_, Some(value)) if value != "X" => value,
+            ("E", _) => "A",
+            ("CR" | "IR" | "AR", _) => "H",
+            _ => "X",
+        }
+    }
+
+    fn is(&self, metric: &str, value: &str) -> bool {
+        self.get(metric) == value
+    }
+
+    /// Returns the macrovector digits EQ1 through EQ6
+    fn macrovector(&self) -> Option<[u8; 6]> {
+        let any_n = self.is("AV", "N") || self.is("PR", "N") || self.is("UI", "N");
+        let eq1 = if self.is("AV", "N") && self.is("PR", "N") && self.is("UI", "N") {
+            0
+        } else if any_n && !self.is("AV", "P") {
+            1
+        } else {
+            2
+        };
+
+        let eq2 = if self.is("AC", "L") && self.is("AT", "N") {
+            0
+        } else {
+            1
+        };
+
+        let eq3 = if self.is("VC", "H") && self.is("VI", "H") {
+            0
+        } else if self.is("VC", "H") || self.is("VI", "H") || self.is("VA", "H") {
+            1
+        } else {
+            2
+        };
+
+        let eq4 = if self.is("SI", "S") || self.is("SA", "S") {
+            0
+        } else if self.is("SC", "H") || self.is("SI", "H") || self.is("SA", "H") {
+            1
+        } else {
+            2
+        };
+
+        let eq5 = match self.get("E") {
+            "A" => 0,
+            "P" => 1,
+            "U" => 2,
+            _ => return None,
+        };
+
+        let eq6 = if (self.is("CR", "H") && self.is("VC", "H"))
+            || (self.is("IR", "H") && self.is("VI", "H"))
+            || (self.is("AR", "H") && self.is("VA", "H"))
+        {
+            0
+        } else {
+            1
+        };
+
+        Some([eq1, eq2, eq3, eq4, eq5, eq6])
+    }
+
+    fn score(&self) -> Option<f64> {
+        let no_impact = ["VC", "VI", "VA", "SC", "SI", "SA"]
+            .iter()
+            .all(|metric| self.is(metric, "N"));
+        if no_impact {
+            return Some(0.0);
+        }
+
+        let mv = self.macrovector()?;
+        let [eq1, eq2, eq3, eq4, eq5, eq6] = mv;
+        let value = lookup(mv)?;
+
+        let lower = |index: usize| {
+            let mut next = mv;
+            next[index] += 1;
+            lookup(next)
+        };
+        let lower_eq1 = lower(0);
+        let lower_eq2 = lower(1);
+        let lower_eq4 = lower(3);
+        let lower_eq5 = lower(4);
+        let lower_eq3_eq6 = match (eq3, eq6) {
+            (0, 0) => {
+                let left = lookup([eq1, eq2, eq3, eq4, eq5, eq6 + 1]);
+                let right = lookup([eq1, eq2, eq3 + 1, eq4, eq5, eq6]);
+                match (left, right) {
+                    (Some(l), Some(r)) => Some(l.max(r)),
+                    (l, r) => l.or(r),
+                }
+            }
+            (1, 0) => lookup([eq1, eq2, eq3, eq4, eq5, eq6 + 1]),
+            _ => lookup([eq1, eq2, eq3 + 1, eq4, eq5, eq6]),
+        };
+
+        let distances = self.severity_distances(mv)?;
+        let max_severity = [
+            max_severity_eq1(eq1),
+            max_severity_eq2(eq2),
+            max_severity_eq3_eq6(eq3, eq6),
+            max_severity_eq4(eq4),
+        ];
+
+        let mut existing_lower = 0;
+        let mut normalized_total = 0.0;
+        for ((lower, distance), max) in [lower_eq1, lower_eq2, lower_eq3_eq6, lower_eq4]
+            .into_iter()
+            .zip(distances)
+            .zip(max_severity)
+        {
+            if let Some(lower) = lower {
+                existing_lower += 1;
+                normalized_total += (value - lower) * (distance / (max * STEP));
+            }
+        }
+        // EQ5 has no intra-class distance, but a lower class still counts
+        if lower_eq5.is_some() {
+            existing_lower += 1;
+        }
+
+        let mean_distance = if existing_lower == 0 {
+            0.0
+        } else {
+            normalized_total / existing_lower as f64
+        };
+
+        let score = (value - mean_distance).clamp(0.0, 10.0);
+        Some(((score + 1e-6) * 10.0).round() / 10.0)
+    }
+
+    /// Returns the severity distances for EQ1, EQ2, EQ3+EQ6 and EQ4 from the first
+    /// highest-severity vector of the macrovector that this vector does not exceed
+    ///
+    /// EQ5 only selects the macrovector and never contributes a distance.
+    fn severity_distances(&self, mv: [u8; 6]) -> Option<[f64; 4]> {
+        let [eq1, eq2, eq3, eq4, _, eq6] = mv;
+        for a in max_vectors_eq1(eq1) {
+            for b in max_vectors_eq2(eq2) {
+                for c in max_vectors_eq3_eq6(eq3, eq6) {
+                    for d in max_vectors_eq4(eq4) {
+                        let max_vector = format!("{}{}{}{}", a, b, c, d);
+                        let max: HashMap<&str, &str> = max_vector
+                            .split('/')
+                            .filter_map(|part| part.split_once(':'))
+                            .collect();
+                        let distance = |metric: &str| -> Option<f64> {
+                            Some(level(metric, self.get(metric))? - level(metric, max[metric])?)
+                        };
+                        let sum = |metrics: &[&str]| -> Option<(f64, bool)> {
+                            let mut total = 0.0;
+                            let mut all_reachable = true;
+                            for metric in metrics {
+                                let d = distance(metric)?;
+                                all_reachable &= d >= 0.0;
+                                total += d;
+                            }
+                            Some((total, all_reachable))
+                        };
+
+                        let groups = [
+                            sum(&EQ1_METRICS)?,
+                            sum(&EQ2_METRICS)?,
+                            sum(&EQ3_EQ6_METRICS)?,
+                            sum(&EQ4_METRICS)?,
+                        ];
+                        if groups.iter().all(|(_, reachable)| *reachable) {
+                            return Some(groups.map(|(total, _)| total));
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+fn lookup(mv: [u8; 6]) -> Option<f64> {
+    let key: String = mv.iter().map(|d| char::from(b'0' + d)).collect();
+    MACROVECTOR_SCORES
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, score)| *score)
+}
+
+/// Returns the severity level of a metric value, lower meaning more severe
+fn level(metric: &str, value: &str) -> Option<f64> {
+    let level = match (metric, value) {
+        ("AV", "N") | ("PR", "N") | ("UI", "N") | ("AC", "L") | ("AT", "N") => 0.0,
+        ("AV", "A") | ("PR", "L") | ("UI", "P") | ("AC", "H") | ("AT", "P") => 0.1,
+        ("AV", "L") | ("PR", "H") | ("UI", "A") => 0.2,
+        ("AV", "P") => 0.3,
+        ("VC" | "VI" | "VA", "H") => 0.0,
+        ("VC" | "VI" | "VA", "L") => 0.1,
+        ("VC" | "VI" | "VA", "N") => 0.2,
+        ("SI" | "SA", "S") => 0.0,
+        ("SC" | "SI" | "SA", "H") => 0.1,
+        ("SC" | "SI" | "SA", "L") => 0.2,
+        ("SC" | "SI" | "SA", "N") => 0.3,
+        ("CR" | "IR" | "AR", "H") => 0.0,
+        ("CR" | "IR" | "AR", "M") => 0.1,
+        ("CR" | "IR" | "AR", "L") => 0.2,
+        _ => return None,
+    };
+    Some(level)
+}
+
+fn max_vectors_eq1(eq1: u8) -> &'static [&'static str] {
+    match eq1 {
+        0 => &["AV:N/PR:N/UI:N/"],
+        1 => &["AV:A/PR:N/UI:N/", "AV:N/PR:L/UI:N/", "AV:N/PR:N/UI:P/"],
+        _ => &["AV:P/PR:N/UI:N/", "AV:A/PR:L/UI:P/"],
+    }
+}
+
+fn max_vectors_eq2(eq2: u8) -> &'static [&'static str] {
+    match eq2 {
+        0 => &["AC:L/AT:N/"],
+        _ => &["AC:H/AT:N/", "AC:L/AT:P/"],
+    }
+}
+
+fn max_vectors_eq3_eq6(eq3: u8, eq6: u8) -> &'static [&'static str] {
+    match (eq3, eq6) {
+        (0, 0) => &["VC:H/VI:H/VA:H/CR:H/IR:H/AR:H/"],
+        (0, _) => &[
+            "VC:H/VI:H/VA:L/CR:M/IR:M/AR:H/",
+            "VC:H/VI:H/VA:H/CR:M/IR:M/AR:M/",
+        ],
+        (1, 0) => &[
+            "VC:L/VI:H/VA:H/CR:H/IR:H/AR:H/",
+            "VC:H/VI:L/VA:H/CR:H/IR:H/AR:H/",
+        ],
+        (1, _) => &[
+            "VC:L/VI:H/VA:L/CR:H/IR:M/AR:H/",
+            "VC:L/VI:H/VA:H/CR:H/IR:M/AR:M/",
+            "VC:H/VI:L/VA:H/CR:M/IR:H/AR:M/",
+            "VC:H/VI:L/VA:L/CR:M/IR:H/AR:H/",
+            "VC:L/VI:L/VA:H/CR:H/IR:H/AR:M/",
+        ],
+        _ => &["VC:L/VI:L/VA:L/CR:H/IR:H/AR:H/"],
+    }
+}
+
+fn max_vectors_eq4(eq4: u8) -> &'static [&'static str] {
+    match eq4 {
+        0 => &["SC:H/SI:S/SA:S"],
+        1 => &["SC:H/SI:H/SA:H"],
+        _ => &["SC:L/SI:L/SA:L"],
+    }
+}
+
+/// Number of steps between the highest and lowest severity vectors of each class
+fn max_severity_eq1(eq1: u8) -> f64 {
+    match eq1 {
+        0 => 1.0,
+        1 => 4.0,
+        _ => 5.0,
+    }
+}
+
+fn max_severity_eq2(eq2: u8) -> f64 {
+    match eq2 {
+        0 => 1.0,
+        _ => 2.0,
+    }
+}
+
+fn max_severity_eq3_eq6(eq3: u8, eq6: u8) -> f64 {
+    match (eq3, eq6) {
+        (0, 0) => 7.0,
+        (0, _) => 6.0,
+        (1, _) => 8.0,
+        _ => 10.0,
+    }
+}
+
+fn max_severity_eq4(eq4: u8) -> f64 {
+    match eq4 {
+        0 => 6.0,
+        1 => 5.0,
+        _ => 4.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(vector: &str) -> f32 {
+        parse_cvss_v4_score(vector).unwrap().value()
+    }
+
+    #[test]
+    fn test_lookup_table_covers_every_macrovector() {
+        let mut keys: Vec<&str> = MACROVECTOR_SCORES.iter().map(|(k, _)| *k).collect();
+        keys.sort_unstable();
+        keys.dedup();
+        assert_eq!(keys.len(), 270);
+    }
+
+    #[test]
+    fn test_highest_severity_vectors_score_their_macrovector() {
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:H/SI:H/SA:H"),
+            10.0
+        );
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"),
+            9.3
+        );
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:L/VI:L/VA:L/SC:N/SI:N/SA:N"),
+            6.9
+        );
+    }
+
+    #[test]
+    fn test_distance_from_highest_severity_vector_lowers_score() {
+        let local = score("CVSS:4.0/AV:L/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N");
+        let network = score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N");
+        assert!(local < network);
+        assert!((7.0..9.0).contains(&local));
+    }
+
+    #[test]
+    fn test_threat_metric_lowers_score() {
+        let unreported =
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N/E:U");
+        assert!(unreported < 9.3);
+    }
+
+    #[test]
+    fn test_no_impact_scores_zero() {
+        assert_eq!(
+            score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:N/VI:N/VA:N/SC:N/SI:N/SA:N"),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_invalid_vectors_are_rejected() {
+        assert!(parse_cvss_v4_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H").is_none());
+        assert!(parse_cvss_v4_score("CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N").is_none());
+        assert!(parse_cvss_v4_score(
+            "CVSS:4.0/AV:Q/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N"
+        )
+        .is_none());
+        assert!(parse_cvss_v4_score("invalid vector").is_none());
+    }
+}
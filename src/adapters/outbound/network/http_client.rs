@@ -0,0 +1,464 @@
+use crate::ports::outbound::EndpointProbe;
+use crate::shared::error::UvSbomError;
+use crate::shared::Result;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Timeout of a connectivity probe, well below the request timeouts
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Counts outbound HTTP requests per host and enforces an optional global ceiling
+///
+/// Clones share the same state, so a single counter handed to every network
+/// adapter accounts for the whole run.
+#[derive(Clone, Default)]
+pub struct RequestCounter {
+    state: Arc<Mutex<CounterState>>,
+    max_requests: Option<u64>,
+}
+
+#[derive(Default)]
+struct CounterState {
+    total: u64,
+    by_host: BTreeMap<String, u64>,
+    refused: u64,
+}
+
+impl RequestCounter {
+    /// Creates a counter; `max_requests` of `None` means unlimited
+    pub fn new(max_requests: Option<u64>) -> Self {
+        Self {
+            state: Arc::default(),
+            max_requests,
+        }
+    }
+
+    /// Records a request to `host`, or refuses it once the ceiling has been reached
+    fn try_acquire(&self, host: &str) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if self.max_requests.is_some_and(|max| state.total >= max) {
+            state.refused += 1;
+            return false;
+        }
+        state.total += 1;
+        *state.by_host.entry(host.to_string()).or_default() += 1;
+        true
+    }
+
+    /// Total number of requests sent so far
+    pub fn total(&self) -> u64 {
+        self.state.lock().unwrap().total
+    }
+
+    /// Requests sent so far per host, sorted by host name
+    pub fn by_host(&self) -> Vec<(String, u64)> {
+        let state = self.state.lock().unwrap();
+        state
+            .by_host
+            .iter()
+            .map(|(host, count)| (host.clone(), *count))
+            .collect()
+    }
+
+    /// Number of requests that were not sent because the ceiling was reached
+    pub fn refused(&self) -> u64 {
+        self.state.lock().unwrap().refused
+    }
+
+    /// The configured ceiling, if any
+    pub fn max_requests(&self) -> Option<u64> {
+        self.max_requests
+    }
+}
+
+/// Transport settings for the network adapters: proxy, extra CA roots, timeout
+/// and User-Agent
+///
+/// Without a `proxy_url`, the `HTTP_PROXY` / `HTTPS_PROXY` / `NO_PROXY`
+/// environment variables apply. Certificates from `ca_bundle_path` are trusted
+/// in addition to the built-in roots.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkConfig {
+    /// Proxy for all requests, overriding the proxy environment variables
+    pub proxy_url: Option<String>,
+    /// PEM file with additional root certificates
+    pub ca_bundle_path: Option<PathBuf>,
+    /// Request timeout; `None` keeps each adapter's default
+    pub timeout_secs: Option<u64>,
+    /// Contact details appended to the User-Agent, e.g. `(+mailto:secops@example.com)`
+    pub user_agent_suffix: Option<String>,
+}
+
+impl NetworkConfig {
+    /// The User-Agent sent with every request: `uv-sbom/{version}`, followed by
+    /// the suffix when one is set
+    pub fn user_agent(&self) -> String {
+        let base = format!("uv-sbom/{}", env!("CARGO_PKG_VERSION"));
+        match self.user_agent_suffix.as_deref().map(str::trim) {
+            Some(suffix) if !suffix.is_empty() => format!("{} {}", base, suffix),
+            _ => base,
+        }
+    }
+}
+
+/// HTTP client shared by the network adapters
+///
+/// Every request goes through [`CountingHttpClient::send`], which records it on the
+/// [`RequestCounter`] and refuses it with [`UvSbomError::RequestLimitReached`] once the
+/// ceiling is reached. This is the only place the ceiling is enforced.
+#[derive(Clone)]
+pub struct CountingHttpClient {
+    client: reqwest::Client,
+    counter: RequestCounter,
+    default_timeout: Duration,
+}
+
+impl CountingHttpClient {
+    /// Builds a client with the uv-sbom user agent and the given timeout
+    pub fn new(timeout: Duration) -> Result<Self> {
+        Ok(Self {
+            client: Self::build_client(timeout, &NetworkConfig::default())?,
+            counter: RequestCounter::default(),
+            default_timeout: timeout,
+        })
+    }
+
+    /// Rebuilds the client with the given proxy, CA bundle, timeout and User-Agent settings
+    ///
+    /// # Errors
+    /// Returns [`UvSbomError::CaBundleError`] when the CA bundle cannot be read or
+    /// holds no certificate, [`UvSbomError::InvalidProxyUrl`] for a malformed proxy and
+    /// [`UvSbomError::InvalidUserAgentSuffix`] for a suffix with control characters.
+    pub fn with_network_config(mut self, network: &NetworkConfig) -> Result<Self> {
+        let timeout = network
+            .timeout_secs
+            .map_or(self.default_timeout, Duration::from_secs);
+        self.client = Self::build_client(timeout, network)?;
+        Ok(self)
+    }
+
+    fn build_client(timeout: Duration, network: &NetworkConfig) -> Result<reqwest::Client> {
+        if let Some(suffix) = &network.user_agent_suffix {
+            if suffix.chars().any(char::is_control) {
+                return Err(UvSbomError::InvalidUserAgentSuffix {
+                    suffix: suffix.clone(),
+                });
+            }
+        }
+        let mut builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .user_agent(network.user_agent());
+
+        if let Some(url) = &network.proxy_url {
+            let proxy = reqwest::Proxy::all(url).map_err(|e| UvSbomError::InvalidProxyUrl {
+                url: url.clone(),
+                details: e.to_string(),
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(path) = &network.ca_bundle_path {
+            builder = builder.tls_certs_merge(load_ca_bundle(path)?);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Replaces the request counter, typically with one shared across adapters
+    pub fn with_counter(mut self, counter: RequestCounter) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    pub fn get(&self, url: impl reqwest::IntoUrl) -> reqwest::RequestBuilder {
+        self.client.get(url)
+    }
+
+    pub fn post(&self, url: impl reqwest::IntoUrl) -> reqwest::RequestBuilder {
+        self.client.post(url)
+    }
+
+    pub fn head(&self, url: impl reqwest::IntoUrl) -> reqwest::RequestBuilder {
+        self.client.head(url)
+    }
+
+    /// Sends a request built from this client, counting it against the ceiling
+    ///
+    /// # Errors
+    /// Returns [`UvSbomError::RequestLimitReached`] without touching the network when
+    /// the ceiling has been reached, or the underlying transport error.
+    pub async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let request = request.build()?;
+        let host = request.url().host_str().unwrap_or_default().to_string();
+        if !self.counter.try_acquire(&host) {
+            return Err(UvSbomError::RequestLimitReached {
+                limit: self.counter.max_requests.unwrap_or_default(),
+                host,
+            });
+        }
+        Ok(self.client.execute(request).await?)
+    }
+
+    /// Sends one HEAD request to `url` to check that it can be reached
+    ///
+    /// Any HTTP response counts as reachable, whatever its status.
+    pub async fn probe(&self, url: &str) -> EndpointProbe {
+        let error = self
+            .send(self.head(url).timeout(PROBE_TIMEOUT))
+            .await
+            .err()
+            .map(|e| e.to_string());
+        EndpointProbe {
+            endpoint: url.to_string(),
+            error,
+        }
+    }
+}
+
+/// Reads the PEM certificates from a CA bundle file
+fn load_ca_bundle(path: &Path) -> Result<Vec<reqwest::Certificate>> {
+    let ca_bundle_error = |details: String| UvSbomError::CaBundleError {
+        path: path.to_path_buf(),
+        details,
+    };
+    let pem = std::fs::read(path).map_err(|e| ca_bundle_error(e.to_string()))?;
+    let certs =
+        reqwest::Certificate::from_pem_bundle(&pem).map_err(|e| ca_bundle_error(e.to_string()))?;
+    if certs.is_empty() {
+        return Err(ca_bundle_error("no PEM certificate found".to_string()));
+    }
+    Ok(certs)
+}
+
+/// Returns true when `error` was caused by the global request ceiling
+///
+/// Errors wrapped by a license source or the vulnerability database are
+/// looked through.
+pub fn is_request_limit_error(error: &UvSbomError) -> bool {
+    match error {
+        UvSbomError::RequestLimitReached { .. } => true,
+        UvSbomError::NetworkLicense { error, .. } | UvSbomError::NetworkVulnerability(error) => {
+            is_request_limit_error(error)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Serves an empty 200 response to every connection.
+    fn spawn_ok_server() -> String {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok() && line.trim_end() != "" {
+                    line.clear();
+                }
+                let _ = stream.write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                );
+            }
+        });
+        base_url
+    }
+
+    #[test]
+    fn test_counter_unlimited_counts_per_host() {
+        let counter = RequestCounter::default();
+        assert!(counter.try_acquire("pypi.org"));
+        assert!(counter.try_acquire("api.osv.dev"));
+        assert!(counter.try_acquire("pypi.org"));
+
+        assert_eq!(counter.total(), 3);
+        assert_eq!(
+            counter.by_host(),
+            vec![("api.osv.dev".to_string(), 1), ("pypi.org".to_string(), 2)]
+        );
+        assert_eq!(counter.refused(), 0);
+    }
+
+    #[test]
+    fn test_counter_ceiling_is_exact_and_shared_across_clones() {
+        let counter = RequestCounter::new(Some(2));
+        let clone = counter.clone();
+
+        assert!(counter.try_acquire("pypi.org"));
+        assert!(clone.try_acquire("api.osv.dev"));
+        assert!(!counter.try_acquire("pypi.org"));
+        assert!(!clone.try_acquire("api.osv.dev"));
+
+        assert_eq!(counter.total(), 2);
+        assert_eq!(clone.refused(), 2);
+    }
+
+    #[test]
+    fn test_counter_zero_ceiling_refuses_everything() {
+        let counter = RequestCounter::new(Some(0));
+        assert!(!counter.try_acquire("pypi.org"));
+        assert_eq!(counter.total(), 0);
+        assert!(counter.by_host().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_stops_at_ceiling_without_contacting_server() {
+        let base_url = spawn_ok_server();
+        let counter = RequestCounter::new(Some(2));
+        let client = CountingHttpClient::new(Duration::from_secs(5))
+            .unwrap()
+            .with_counter(counter.clone());
+
+        for _ in 0..2 {
+            let response = client.send(client.get(&base_url)).await.unwrap();
+            assert!(response.status().is_success());
+        }
+        let err = client.send(client.get(&base_url)).await.unwrap_err();
+
+        assert!(is_request_limit_error(&err));
+        assert!(err.to_string().contains("127.0.0.1"));
+        assert_eq!(counter.total(), 2);
+        assert_eq!(counter.by_host(), vec![("127.0.0.1".to_string(), 2)]);
+        assert_eq!(counter.refused(), 1);
+    }
+
+    fn sbom_error(result: Result<CountingHttpClient>) -> UvSbomError {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(e) => e,
+        }
+    }
+
+    #[test]
+    fn test_network_config_invalid_ca_bundle() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let not_pem = dir.path().join("ca.pem");
+        std::fs::write(&not_pem, "this is not a certificate").unwrap();
+
+        for path in [not_pem, dir.path().join("missing.pem")] {
+            let network = NetworkConfig {
+                ca_bundle_path: Some(path.clone()),
+                ..Default::default()
+            };
+            let result = CountingHttpClient::new(Duration::from_secs(5))
+                .unwrap()
+                .with_network_config(&network);
+
+            match sbom_error(result) {
+                UvSbomError::CaBundleError {
+                    path: error_path, ..
+                } => assert_eq!(error_path, path),
+                other => panic!("expected CaBundleError, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_network_config_invalid_proxy_url() {
+        let network = NetworkConfig {
+            proxy_url: Some("not a url".to_string()),
+            ..Default::default()
+        };
+        let result = CountingHttpClient::new(Duration::from_secs(5))
+            .unwrap()
+            .with_network_config(&network);
+
+        assert!(matches!(
+            sbom_error(result),
+            UvSbomError::InvalidProxyUrl { url, .. } if url == "not a url"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_proxy_is_connect_error() {
+        // Bind and drop a listener to get a local port nothing listens on
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let network = NetworkConfig {
+            proxy_url: Some(format!("http://127.0.0.1:{}", port)),
+            ..Default::default()
+        };
+        let client = CountingHttpClient::new(Duration::from_secs(5))
+            .unwrap()
+            .with_network_config(&network)
+            .unwrap();
+
+        let err = client
+            .send(client.get("http://pypi.org/simple/"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, UvSbomError::Http(e) if e.is_connect()));
+    }
+
+    #[tokio::test]
+    async fn test_probe_reports_reachable_and_unreachable_endpoints() {
+        let base_url = spawn_ok_server();
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let closed_url = format!("http://127.0.0.1:{}", port);
+        let client = CountingHttpClient::new(Duration::from_secs(5)).unwrap();
+
+        let reachable = client.probe(&base_url).await;
+        let unreachable = client.probe(&closed_url).await;
+
+        assert_eq!(reachable.endpoint, base_url);
+        assert!(reachable.error.is_none());
+        assert_eq!(unreachable.endpoint, closed_url);
+        assert!(unreachable.error.is_some());
+    }
+
+    #[test]
+    fn test_user_agent_suffix() {
+        let version = env!("CARGO_PKG_VERSION");
+        let network = NetworkConfig {
+            user_agent_suffix: Some(" (+mailto:secops@example.com) ".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            network.user_agent(),
+            format!("uv-sbom/{} (+mailto:secops@example.com)", version)
+        );
+        assert_eq!(
+            NetworkConfig::default().user_agent(),
+            format!("uv-sbom/{}", version)
+        );
+    }
+
+    #[test]
+    fn test_user_agent_suffix_with_line_break_is_rejected() {
+        for suffix in ["(+mailto:a@example.com)\r\nX-Injected: 1", "tab\there"] {
+            let network = NetworkConfig {
+                user_agent_suffix: Some(suffix.to_string()),
+                ..Default::default()
+            };
+            let result = CountingHttpClient::new(Duration::from_secs(5))
+                .unwrap()
+                .with_network_config(&network);
+
+            assert!(matches!(
+                sbom_error(result),
+                UvSbomError::InvalidUserAgentSuffix { suffix: s } if s == suffix
+            ));
+        }
+    }
+
+    #[test]
+    fn test_is_request_limit_error_ignores_other_errors() {
+        assert!(!is_request_limit_error(&UvSbomError::other("timed out")));
+    }
+}
@@ -1,10 +1,23 @@
 /// Network adapters for external API calls
+mod cached_vulnerability_repository;
 mod caching_pypi_client;
+mod cvss_v2;
+mod cvss_v4;
+mod http_client;
 mod osv_client;
 mod pypi_client;
 mod pypi_maintenance_client;
+mod retry;
+#[cfg(test)]
+mod test_server;
 
+pub use cached_vulnerability_repository::{
+    cve_cache_dir, CachedVulnerabilityRepository, DEFAULT_CVE_CACHE_TTL,
+};
 pub use caching_pypi_client::CachingPyPiLicenseRepository;
+pub use http_client::{NetworkConfig, RequestCounter};
 pub use osv_client::OsvClient;
-pub use pypi_client::PyPiLicenseRepository;
+pub(crate) use osv_client::OsvVulnerability;
+pub use pypi_client::{IndexCredentials, PyPiLicenseRepository};
 pub use pypi_maintenance_client::PyPiMaintenanceRepository;
+pub use retry::RetryConfig;
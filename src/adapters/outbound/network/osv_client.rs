@@ -1,13 +1,28 @@
-use crate::ports::outbound::{ProgressCallback, VulnerabilityRepository};
+use super::cvss_v2::parse_cvss_v2_score;
+use super::cvss_v4::parse_cvss_v4_score;
+use super::http_client::{
+    is_request_limit_error, CountingHttpClient, NetworkConfig, RequestCounter,
+};
+use super::retry::RetryConfig;
+use crate::ports::outbound::{
+    EndpointProbe, IncompleteAdvisory, ProgressCallback, UncheckedPackage,
+    VulnerabilityFetchOutcome, VulnerabilityRepository,
+};
+use crate::sbom_generation::domain::pep440::compare_versions;
+use crate::sbom_generation::domain::services::AliasDeduplicator;
 use crate::sbom_generation::domain::vulnerability::{
-    CvssScore, PackageVulnerabilities, Severity, Vulnerability,
+    CvssPreference, CvssScore, CvssVersion, PackageVulnerabilities, Severity, Vulnerability,
 };
-use crate::sbom_generation::domain::Package;
+use crate::sbom_generation::domain::{Package, PackageName};
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 use async_trait::async_trait;
-use reqwest::Client;
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::sync::Mutex;
 use std::time::Duration;
+use tokio::time::Instant;
 
 /// OSV API client for fetching vulnerability data
 ///
@@ -16,65 +31,191 @@ use std::time::Duration;
 ///
 /// # Security
 /// - Implements rate limiting (10 req/sec) using tokio::time::sleep
+/// - Fetches vulnerability details with a bounded pool of workers (4 by default),
+///   all sharing the rate limit
 /// - Implements timeout (30 seconds)
-/// - Does not retry failed requests (fail fast for CVE checks)
+/// - Retries rate-limited (429), 5xx and connection failures with exponential backoff
 #[derive(Clone)]
 pub struct OsvClient {
-    client: Client,
-    api_url: String,
+    client: CountingHttpClient,
+    base_url: String,
+    cvss_preference: CvssPreference,
+    retry: RetryConfig,
+    detail_workers: usize,
 }
 
 impl OsvClient {
-    const API_ENDPOINT: &'static str = "https://api.osv.dev/v1/querybatch";
+    const BASE_URL: &'static str = "https://api.osv.dev/v1";
     const TIMEOUT_SECONDS: u64 = 30;
     const RATE_LIMIT_MS: u64 = 100; // 10 req/sec
     const MAX_BATCH_SIZE: usize = 100; // OSV API limit
+    /// Follow-up pages requested per batch before the remaining pages are given up on
+    const MAX_RESULT_PAGES: usize = 20;
+    /// Detail requests in flight at once unless set with [`Self::with_detail_workers`]
+    pub const DEFAULT_DETAIL_WORKERS: usize = 4;
 
     /// Creates a new OSV API client with default configuration
     pub fn new() -> Result<Self> {
-        let version = env!("CARGO_PKG_VERSION");
-        let user_agent = format!("uv-sbom/{}", version);
-        let client = Client::builder()
-            .timeout(Duration::from_secs(Self::TIMEOUT_SECONDS))
-            .user_agent(user_agent)
-            .build()?;
+        Self::with_base_url(Self::BASE_URL)
+    }
+
+    fn with_base_url(base_url: &str) -> Result<Self> {
+        let client = CountingHttpClient::new(Duration::from_secs(Self::TIMEOUT_SECONDS))?;
 
         Ok(Self {
             client,
-            api_url: Self::API_ENDPOINT.to_string(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cvss_preference: CvssPreference::default(),
+            retry: RetryConfig::default(),
+            detail_workers: Self::DEFAULT_DETAIL_WORKERS,
         })
     }
 
-    /// Fetches vulnerabilities for a batch of packages (async)
-    async fn fetch_batch(&self, packages: &[Package]) -> Result<Vec<OsvResult>> {
+    /// Sets which CVSS version is scored when an advisory carries both v3 and v4
+    pub fn with_cvss_preference(mut self, preference: CvssPreference) -> Self {
+        self.cvss_preference = preference;
+        self
+    }
+
+    /// Replaces the retry policy for transient failures
+    pub fn with_retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets how many vulnerability detail requests may be in flight at once (at least 1)
+    ///
+    /// Requests are still started at most one per 100 ms across all workers.
+    pub fn with_detail_workers(mut self, workers: usize) -> Self {
+        self.detail_workers = workers.max(1);
+        self
+    }
+
+    /// Counts requests against a counter shared with the other network adapters
+    pub fn with_request_counter(mut self, counter: RequestCounter) -> Self {
+        self.client = self.client.with_counter(counter);
+        self
+    }
+
+    /// Applies the proxy, CA bundle and timeout settings
+    pub fn with_network_config(mut self, network: &NetworkConfig) -> Result<Self> {
+        self.client = self.client.with_network_config(network)?;
+        Ok(self)
+    }
+
+    /// Fetches vulnerabilities for a batch of packages, retrying transient failures (async)
+    ///
+    /// OSV truncates the vulnerability list of a query with many advisories and
+    /// returns a `next_page_token` for it. The remaining pages of all truncated
+    /// queries are requested together, one rate-limited request per round, and
+    /// merged into the first page.
+    ///
+    /// Each result comes with the reason its pages are incomplete, if they are:
+    /// a follow-up page that failed to fetch, or more pages than
+    /// `MAX_RESULT_PAGES`. The pages collected until then are kept. Only a
+    /// failure of the first page fails the whole batch.
+    async fn fetch_batch(&self, packages: &[Package]) -> Result<Vec<(OsvResult, Option<String>)>> {
         // Build batch query
         let queries: Vec<OsvQuery> = packages
             .iter()
             .map(|pkg| OsvQuery {
                 package: OsvPackage {
-                    name: pkg.name().to_string(),
+                    name: PackageName::normalize(pkg.name()),
                     ecosystem: "PyPI".to_string(),
                 },
                 version: pkg.version().to_string(),
+                page_token: None,
             })
             .collect();
 
-        let batch_query = OsvBatchQuery { queries };
-
-        // Send async request
-        let response = self
-            .client
-            .post(&self.api_url)
-            .json(&batch_query)
-            .send()
+        let mut results = self
+            .post_batch(&OsvBatchQuery {
+                queries: queries.clone(),
+            })
             .await?;
+        let mut incomplete: Vec<Option<String>> = vec![None; results.len()];
+
+        for _ in 0..Self::MAX_RESULT_PAGES {
+            let truncated: Vec<usize> = results
+                .iter()
+                .enumerate()
+                .filter(|(_, result)| result.next_page_token.is_some())
+                .map(|(idx, _)| idx)
+                .collect();
+            if truncated.is_empty() {
+                break;
+            }
 
-        if !response.status().is_success() {
-            anyhow::bail!("OSV API returned status code {}", response.status());
+            tokio::time::sleep(Duration::from_millis(Self::RATE_LIMIT_MS)).await;
+            let page_query = OsvBatchQuery {
+                queries: truncated
+                    .iter()
+                    .map(|&idx| OsvQuery {
+                        page_token: results[idx].next_page_token.take(),
+                        ..queries[idx].clone()
+                    })
+                    .collect(),
+            };
+            let pages = match self.post_batch(&page_query).await {
+                Ok(pages) => pages,
+                Err(e) => {
+                    let error = e.to_string();
+                    for &idx in &truncated {
+                        incomplete[idx] = Some(error.clone());
+                    }
+                    break;
+                }
+            };
+
+            for (&idx, page) in truncated.iter().zip(pages) {
+                let result = &mut results[idx];
+                for vuln in page.vulns {
+                    if !result.vulns.iter().any(|known| known.id == vuln.id) {
+                        result.vulns.push(vuln);
+                    }
+                }
+                result.next_page_token = page.next_page_token;
+            }
         }
 
-        let batch_response: OsvBatchResponse = response.json().await?;
-        Ok(batch_response.results)
+        for (result, reason) in results.iter().zip(&mut incomplete) {
+            if result.next_page_token.is_some() {
+                *reason = Some(format!(
+                    "OSV returned more than {} result pages; the remaining pages were not fetched",
+                    Self::MAX_RESULT_PAGES + 1
+                ));
+            }
+        }
+
+        Ok(results.into_iter().zip(incomplete).collect())
+    }
+
+    /// Sends one querybatch request, retrying transient failures (async)
+    async fn post_batch(&self, batch_query: &OsvBatchQuery) -> Result<Vec<OsvResult>> {
+        self.retry
+            .run(|| async {
+                let response = self
+                    .client
+                    .send(
+                        self.client
+                            .post(format!("{}/querybatch", self.base_url))
+                            .json(batch_query),
+                    )
+                    .await?;
+
+                let status = response.status();
+                if !status.is_success() {
+                    return Err(UvSbomError::http_status(
+                        status,
+                        format!("OSV API returned status code {}", status),
+                    ));
+                }
+
+                let batch_response: OsvBatchResponse = response.json().await?;
+                Ok(batch_response.results)
+            })
+            .await
+            .map_err(|e| UvSbomError::NetworkVulnerability(Box::new(e)))
     }
 
     /// Fetches detailed vulnerability information by ID (async)
@@ -82,68 +223,32 @@ impl OsvClient {
     /// The batch API returns minimal information. To get severity and other details,
     /// we need to query each vulnerability individually.
     async fn fetch_vulnerability_details(&self, vuln_id: &str) -> Result<OsvVulnerability> {
-        let url = format!("https://api.osv.dev/v1/vulns/{}", vuln_id);
-        let response = self.client.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            anyhow::bail!(
-                "OSV API returned status code {} for vulnerability {}",
-                response.status(),
-                vuln_id
-            );
-        }
+        let url = format!("{}/vulns/{}", self.base_url, vuln_id);
+        self.retry
+            .run(|| async {
+                let response = self.client.send(self.client.get(&url)).await?;
+
+                let status = response.status();
+                if !status.is_success() {
+                    return Err(UvSbomError::http_status(
+                        status,
+                        format!(
+                            "OSV API returned status code {} for vulnerability {}",
+                            status, vuln_id
+                        ),
+                    ));
+                }
 
-        let vuln: OsvVulnerability = response.json().await?;
-        Ok(vuln)
+                let vuln: OsvVulnerability = response.json().await?;
+                Ok(vuln)
+            })
+            .await
+            .map_err(|e| UvSbomError::NetworkVulnerability(Box::new(e)))
     }
 
     /// Converts a single OSV vulnerability to domain model
     fn convert_to_vulnerability(&self, osv_vuln: &OsvVulnerability) -> Result<Vulnerability> {
-        // Extract CVSS score - try V3 first, then V4
-        let cvss_score = osv_vuln
-            .severity
-            .as_ref()
-            .and_then(|severities| {
-                severities
-                    .iter()
-                    .find(|s| s.severity_type == "CVSS_V3")
-                    .or_else(|| severities.iter().find(|s| s.severity_type == "CVSS_V4"))
-            })
-            .and_then(|s| parse_cvss_score(&s.score));
-
-        // Determine severity with fallback strategy:
-        // 1. First: use CVSS score if available
-        // 2. Second: fallback to database_specific.severity string
-        // 3. Third: default to Severity::None
-        let severity = if let Some(score) = cvss_score {
-            Severity::from_cvss_score(score)
-        } else if let Some(db_severity) = osv_vuln
-            .database_specific
-            .as_ref()
-            .and_then(|db| db.severity.as_deref())
-        {
-            parse_severity_string(db_severity)
-        } else {
-            Severity::None
-        };
-
-        // Extract fixed version
-        let fixed_version = osv_vuln.affected.as_ref().and_then(|affected| {
-            affected.iter().find_map(|a| {
-                a.ranges
-                    .as_ref()?
-                    .iter()
-                    .find_map(|r| r.events.iter().find_map(|e| e.fixed.clone()))
-            })
-        });
-
-        Vulnerability::new(
-            osv_vuln.id.clone(),
-            cvss_score,
-            severity,
-            fixed_version,
-            osv_vuln.summary.clone(),
-        )
+        osv_vuln.to_vulnerability(self.cvss_preference)
     }
 }
 
@@ -151,80 +256,160 @@ impl OsvClient {
 impl VulnerabilityRepository for OsvClient {
     async fn fetch_vulnerabilities(
         &self,
-        packages: Vec<Package>,
-    ) -> Result<Vec<PackageVulnerabilities>> {
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
         // Call the version with progress but with a no-op callback
         self.fetch_vulnerabilities_with_progress(packages, Box::new(|_, _| {}))
             .await
     }
 
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        vec![self.client.probe(&self.base_url).await]
+    }
+
     async fn fetch_vulnerabilities_with_progress(
         &self,
-        packages: Vec<Package>,
+        packages: &[Package],
         progress_callback: ProgressCallback<'static>,
-    ) -> Result<Vec<PackageVulnerabilities>> {
-        // Step 1: Fetch batch results and count total vulnerabilities
-        let mut batch_results: Vec<(Package, OsvResult)> = Vec::new();
+    ) -> Result<VulnerabilityFetchOutcome> {
+        // Step 1: Fetch batch results, every result page included, and count total
+        // vulnerabilities. A failed batch is recorded and the remaining batches are
+        // still queried; a package with missing result pages is recorded too, but
+        // keeps the advisories that were fetched.
+        let mut batch_results: Vec<(&Package, OsvResult)> = Vec::new();
+        let mut failed_packages: Vec<UncheckedPackage> = Vec::new();
         let mut total_vulns = 0;
 
-        for chunk in packages.chunks(Self::MAX_BATCH_SIZE) {
-            if !batch_results.is_empty() {
+        for (chunk_idx, chunk) in packages.chunks(Self::MAX_BATCH_SIZE).enumerate() {
+            if chunk_idx > 0 {
                 tokio::time::sleep(Duration::from_millis(Self::RATE_LIMIT_MS)).await;
             }
 
-            let osv_results = self.fetch_batch(chunk).await?;
-
-            for (package, osv_result) in chunk.iter().zip(osv_results) {
-                total_vulns += osv_result.vulns.len();
-                batch_results.push((package.clone(), osv_result));
+            match self.fetch_batch(chunk).await {
+                Ok(osv_results) => {
+                    for (package, (osv_result, incomplete)) in chunk.iter().zip(osv_results) {
+                        // Advisories from the pages that were fetched are still reported
+                        if let Some(error) = incomplete {
+                            failed_packages.push(UncheckedPackage {
+                                package: package.clone(),
+                                error,
+                            });
+                        }
+                        total_vulns += osv_result.vulns.len();
+                        batch_results.push((package, osv_result));
+                    }
+                }
+                Err(e) => {
+                    // Once the request ceiling is reached no later batch can be sent either
+                    let stop = is_request_limit_error(&e);
+                    let unchecked = if stop {
+                        &packages[chunk_idx * Self::MAX_BATCH_SIZE..]
+                    } else {
+                        chunk
+                    };
+                    let error = e.to_string();
+                    failed_packages.extend(unchecked.iter().map(|package| UncheckedPackage {
+                        package: package.clone(),
+                        error: error.clone(),
+                    }));
+                    if stop {
+                        break;
+                    }
+                }
             }
         }
 
-        // Step 2: Process vulnerabilities with progress reporting
-        let mut all_results = Vec::new();
-        let mut processed_vulns = 0;
-
-        for (package, osv_result) in batch_results {
-            if osv_result.vulns.is_empty() {
-                continue;
-            }
-
-            let mut vulnerabilities: Vec<Vulnerability> = Vec::new();
-
-            for osv_vuln in &osv_result.vulns {
-                // Report progress before fetching
-                processed_vulns += 1;
-                progress_callback(processed_vulns, total_vulns);
+        // Step 2: Fetch details with up to `detail_workers` requests in flight.
+        // Requests are still started at most one per RATE_LIMIT_MS, and progress
+        // is reported as each one completes, whatever order they finish in.
+        let detail_requests: Vec<(usize, String)> = batch_results
+            .iter()
+            .enumerate()
+            .flat_map(|(pkg_idx, (_, osv_result))| {
+                osv_result
+                    .vulns
+                    .iter()
+                    .map(move |osv_vuln| (pkg_idx, osv_vuln.id.clone()))
+            })
+            .collect();
 
-                // Fetch detailed vulnerability information (async)
-                match self.fetch_vulnerability_details(&osv_vuln.id).await {
-                    Ok(detailed_vuln) => {
-                        if let Ok(vuln) = self.convert_to_vulnerability(&detailed_vuln) {
-                            vulnerabilities.push(vuln);
+        let fetched = {
+            // Start times are handed out one RATE_LIMIT_MS apart across all in-flight fetches
+            let next_start = Mutex::new(Instant::now());
+            let next_start = &next_start;
+            let details = stream::iter(detail_requests)
+                .map(|(pkg_idx, vuln_id)| async move {
+                    let start = {
+                        let mut next = next_start.lock().unwrap_or_else(|e| e.into_inner());
+                        let start = (*next).max(Instant::now());
+                        *next = start + Duration::from_millis(Self::RATE_LIMIT_MS);
+                        start
+                    };
+                    tokio::time::sleep_until(start).await;
+
+                    let mut incomplete = None;
+                    let vulnerability = match self.fetch_vulnerability_details(&vuln_id).await {
+                        // A withdrawn advisory was published in error and no longer applies
+                        Ok(detailed_vuln) if detailed_vuln.withdrawn.is_some() => None,
+                        Ok(detailed_vuln) => Some(
+                            self.convert_to_vulnerability(&detailed_vuln)
+                                .or_else(|_| minimal_vulnerability(&vuln_id)),
+                        ),
+                        Err(e) => {
+                            let vulnerability = minimal_vulnerability(&vuln_id);
+                            incomplete = Some(IncompleteAdvisory {
+                                id: vuln_id,
+                                error: e.to_string(),
+                            });
+                            Some(vulnerability)
                         }
-                    }
-                    Err(e) => {
-                        eprintln!(
-                            "Warning: Failed to fetch details for {}: {}",
-                            osv_vuln.id, e
-                        );
-                    }
-                }
+                    };
+                    (pkg_idx, vulnerability.and_then(Result::ok), incomplete)
+                })
+                .buffer_unordered(self.detail_workers);
 
-                // Rate limiting: small delay between detail requests (async)
-                tokio::time::sleep(Duration::from_millis(Self::RATE_LIMIT_MS)).await;
+            let mut details = std::pin::pin!(details);
+
+            let mut fetched = Vec::with_capacity(total_vulns);
+            while let Some(result) = details.next().await {
+                fetched.push(result);
+                progress_callback(fetched.len(), total_vulns);
             }
+            fetched
+        };
 
-            if !vulnerabilities.is_empty() {
-                all_results.push(PackageVulnerabilities::new(
+        // Step 3: Group by package, sorted by ID so the result does not depend on
+        // completion order, collapsing advisories that are aliases of one another
+        let mut per_package: Vec<Vec<Vulnerability>> = vec![Vec::new(); batch_results.len()];
+        let mut incomplete_advisories = Vec::new();
+        for (pkg_idx, vulnerability, incomplete) in fetched {
+            per_package[pkg_idx].extend(vulnerability);
+            incomplete_advisories.extend(incomplete);
+        }
+        incomplete_advisories.sort_by(|a, b| a.id.cmp(&b.id));
+        for vulnerabilities in &mut per_package {
+            vulnerabilities.sort_by(|a, b| a.id().cmp(b.id()));
+        }
+
+        let all_results = batch_results
+            .into_iter()
+            .zip(per_package)
+            .filter(|(_, vulnerabilities)| !vulnerabilities.is_empty())
+            .map(|((package, _), vulnerabilities)| {
+                PackageVulnerabilities::new(
                     package.name().to_string(),
                     package.version().to_string(),
-                    vulnerabilities,
-                ));
-            }
-        }
+                    AliasDeduplicator::apply(vulnerabilities),
+                )
+            })
+            .collect();
 
-        Ok(all_results)
+        Ok(VulnerabilityFetchOutcome {
+            results: all_results,
+            failed_packages,
+            incomplete_advisories,
+            cached_packages: 0,
+        })
     }
 }
 
@@ -235,13 +420,16 @@ struct OsvBatchQuery {
     queries: Vec<OsvQuery>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct OsvQuery {
     package: OsvPackage,
     version: String,
+    /// Token of the result page to fetch, from the previous page's `next_page_token`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_token: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct OsvPackage {
     name: String,
     ecosystem: String, // "PyPI"
@@ -256,12 +444,22 @@ struct OsvBatchResponse {
 struct OsvResult {
     #[serde(default)]
     vulns: Vec<OsvVulnerability>,
+    /// Set when the vulnerability list was truncated and more pages are available
+    #[serde(default)]
+    next_page_token: Option<String>,
 }
 
+/// An advisory in the OSV schema, as served by the OSV API and stored in
+/// advisory databases such as the PyPA advisory database
 #[derive(Debug, Deserialize)]
-struct OsvVulnerability {
+pub(crate) struct OsvVulnerability {
     id: String,
     #[serde(default)]
+    aliases: Vec<String>,
+    /// Timestamp at which the advisory was withdrawn, if it was
+    #[serde(default)]
+    withdrawn: Option<String>,
+    #[serde(default)]
     summary: Option<String>,
     #[serde(default)]
     severity: Option<Vec<OsvSeverity>>,
@@ -269,6 +467,15 @@ struct OsvVulnerability {
     database_specific: Option<DatabaseSpecific>,
     #[serde(default)]
     affected: Option<Vec<OsvAffected>>,
+    #[serde(default)]
+    references: Vec<OsvReference>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvReference {
+    #[serde(rename = "type")]
+    reference_type: String, // "ADVISORY", "WEB", "FIX", "PACKAGE", ...
+    url: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -286,19 +493,234 @@ struct DatabaseSpecific {
 
 #[derive(Debug, Deserialize)]
 struct OsvAffected {
+    #[serde(default)]
+    package: Option<OsvAffectedPackage>,
     #[serde(default)]
     ranges: Option<Vec<OsvRange>>,
+    /// Affected versions listed one by one
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsvAffectedPackage {
+    name: String,
+    ecosystem: String, // "PyPI"
 }
 
 #[derive(Debug, Deserialize)]
 struct OsvRange {
+    #[serde(default, rename = "type")]
+    range_type: Option<String>, // "ECOSYSTEM", "SEMVER", "GIT"
     events: Vec<OsvEvent>,
 }
 
 #[derive(Debug, Deserialize)]
 struct OsvEvent {
+    #[serde(default)]
+    introduced: Option<String>,
     #[serde(default)]
     fixed: Option<String>,
+    #[serde(default)]
+    last_affected: Option<String>,
+}
+
+impl OsvVulnerability {
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn is_withdrawn(&self) -> bool {
+        self.withdrawn.is_some()
+    }
+
+    /// Normalized names of the PyPI packages the advisory affects
+    pub(crate) fn pypi_package_names(&self) -> impl Iterator<Item = String> + '_ {
+        self.affected
+            .iter()
+            .flatten()
+            .filter_map(|a| a.package.as_ref())
+            .filter(|p| p.ecosystem == "PyPI")
+            .map(|p| PackageName::normalize(&p.name))
+    }
+
+    /// Returns true when `version` of the PyPI package `name` is affected
+    ///
+    /// A version is affected when it is listed in `versions`, or falls in an
+    /// `ECOSYSTEM` range: at or after an `introduced` event (`0` means every
+    /// version) and before the following `fixed` event, or at or before the
+    /// following `last_affected` event. Versions are compared in PEP 440 order.
+    pub(crate) fn affects(&self, name: &str, version: &str) -> bool {
+        let name = PackageName::normalize(name);
+        self.affected
+            .iter()
+            .flatten()
+            .filter(|a| {
+                a.package.as_ref().is_some_and(|p| {
+                    p.ecosystem == "PyPI" && PackageName::normalize(&p.name) == name
+                })
+            })
+            .any(|a| {
+                a.versions
+                    .iter()
+                    .any(|v| compare_versions(v, version) == Ordering::Equal)
+                    || a.ranges.iter().flatten().any(|r| r.contains(version))
+            })
+    }
+
+    /// Converts the advisory to the domain model, scoring the preferred CVSS version
+    pub(crate) fn to_vulnerability(
+        &self,
+        cvss_preference: CvssPreference,
+    ) -> Result<Vulnerability> {
+        // Extract CVSS score - the preferred version first, then the other one, then v2
+        let (cvss_score, cvss_version) = self
+            .severity
+            .as_deref()
+            .and_then(|severities| select_cvss_score(severities, cvss_preference))
+            .unzip();
+
+        // Determine severity with fallback strategy:
+        // 1. First: use CVSS score if available
+        // 2. Second: fallback to database_specific.severity string
+        // 3. Third: default to Severity::None
+        let severity = if let Some(score) = cvss_score {
+            match (Severity::from_cvss_score(score), cvss_version) {
+                // CVSS v2 has no CRITICAL rating; 9.0 and above is HIGH
+                (Severity::Critical, Some(CvssVersion::V2_0)) => Severity::High,
+                (severity, _) => severity,
+            }
+        } else if let Some(db_severity) = self
+            .database_specific
+            .as_ref()
+            .and_then(|db| db.severity.as_deref())
+        {
+            parse_severity_string(db_severity)
+        } else {
+            Severity::None
+        };
+
+        // Extract fixed version
+        let fixed_version = self.affected.as_ref().and_then(|affected| {
+            affected.iter().find_map(|a| {
+                a.ranges
+                    .as_ref()?
+                    .iter()
+                    .find_map(|r| r.events.iter().find_map(|e| e.fixed.clone()))
+            })
+        });
+
+        Ok(Vulnerability::new(
+            self.id.clone(),
+            cvss_score,
+            severity,
+            fixed_version,
+            self.summary.clone(),
+        )?
+        .with_cvss_version(cvss_version)
+        .with_aliases(self.aliases.clone())
+        .with_source_url(Some(primary_reference_url(self))))
+    }
+}
+
+impl OsvRange {
+    /// Returns true when `version` falls in this `ECOSYSTEM` range
+    ///
+    /// The events are applied in version order; other range types never match.
+    fn contains(&self, version: &str) -> bool {
+        if self.range_type.as_deref().is_some_and(|t| t != "ECOSYSTEM") {
+            return false;
+        }
+        let mut events: Vec<(&str, &OsvEvent)> = self
+            .events
+            .iter()
+            .filter_map(|e| {
+                let bound = e
+                    .introduced
+                    .as_deref()
+                    .or(e.fixed.as_deref())
+                    .or(e.last_affected.as_deref())?;
+                Some((bound, e))
+            })
+            .collect();
+        events.sort_by(|(a, _), (b, _)| match (*a, *b) {
+            ("0", "0") => Ordering::Equal,
+            ("0", _) => Ordering::Less,
+            (_, "0") => Ordering::Greater,
+            (a, b) => compare_versions(a, b),
+        });
+
+        let mut affected = false;
+        for (bound, event) in events {
+            let ordering = compare_versions(version, bound);
+            if event.introduced.is_some() {
+                if bound == "0" || ordering != Ordering::Less {
+                    affected = true;
+                }
+            } else if event.fixed.is_some() {
+                if ordering != Ordering::Less {
+                    affected = false;
+                }
+            } else if ordering == Ordering::Greater {
+                // last_affected
+                affected = false;
+            }
+        }
+        affected
+    }
+}
+
+/// The batch-level information for an advisory whose details could not be read:
+/// its ID, with no score and `Severity::None`
+fn minimal_vulnerability(id: &str) -> Result<Vulnerability> {
+    Vulnerability::new(id.to_string(), None, Severity::None, None, None)
+}
+
+/// Picks the URL a reader should follow for an advisory
+///
+/// The first `ADVISORY` reference wins, then the first `WEB` reference; an
+/// advisory without either links to its OSV page.
+fn primary_reference_url(osv_vuln: &OsvVulnerability) -> String {
+    ["ADVISORY", "WEB"]
+        .iter()
+        .find_map(|reference_type| {
+            osv_vuln
+                .references
+                .iter()
+                .find(|r| r.reference_type == *reference_type)
+        })
+        .map(|r| r.url.clone())
+        .unwrap_or_else(|| format!("https://osv.dev/vulnerability/{}", osv_vuln.id))
+}
+
+/// Scores the first parseable CVSS entry, trying the preferred version first
+///
+/// Legacy `CVSS_V2` entries are the last resort for either preference.
+fn select_cvss_score(
+    severities: &[OsvSeverity],
+    preference: CvssPreference,
+) -> Option<(CvssScore, CvssVersion)> {
+    let order = match preference {
+        CvssPreference::V4 => ["CVSS_V4", "CVSS_V3", "CVSS_V2"],
+        CvssPreference::V3 => ["CVSS_V3", "CVSS_V4", "CVSS_V2"],
+    };
+    order.iter().find_map(|severity_type| {
+        severities
+            .iter()
+            .filter(|s| s.severity_type == *severity_type)
+            .find_map(|s| match s.severity_type.as_str() {
+                "CVSS_V4" => Some((parse_cvss_v4_score(&s.score)?, CvssVersion::V4_0)),
+                "CVSS_V2" => Some((parse_cvss_v2_score(&s.score)?, CvssVersion::V2_0)),
+                _ => {
+                    let version = if s.score.starts_with("CVSS:3.0/") {
+                        CvssVersion::V3_0
+                    } else {
+                        CvssVersion::V3_1
+                    };
+                    Some((parse_cvss_score(&s.score)?, version))
+                }
+            })
+    })
 }
 
 /// Extracts numeric CVSS score from CVSS vector string
@@ -432,6 +854,10 @@ fn parse_severity_string(severity: &str) -> Severity {
 
 #[cfg(test)]
 mod tests {
+    use super::super::test_server::{
+        spawn_http_server, spawn_recording_http_server, spawn_scripted_server,
+        spawn_slow_http_server,
+    };
     use super::*;
 
     #[test]
@@ -440,6 +866,408 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_fetch_vulnerabilities_groups_concurrent_details_by_package() {
+        use std::sync::{Arc, Mutex};
+
+        let vuln = |id: &str| {
+            (
+                format!("/vulns/{}", id),
+                "application/json".to_string(),
+                format!(r#"{{"id": "{}", "summary": "{} summary"}}"#, id, id).into_bytes(),
+            )
+        };
+        let base_url = spawn_http_server(|_| {
+            vec![
+                (
+                    "/querybatch".to_string(),
+                    "application/json".to_string(),
+                    br#"{"results": [
+                        {"vulns": [{"id": "GHSA-1"}, {"id": "GHSA-2"}]},
+                        {},
+                        {"vulns": [{"id": "GHSA-3"}, {"id": "GHSA-missing"}]}
+                    ]}"#
+                    .to_vec(),
+                ),
+                vuln("GHSA-1"),
+                vuln("GHSA-2"),
+                vuln("GHSA-3"),
+            ]
+        });
+        let client = OsvClient::with_base_url(&base_url).unwrap();
+        let packages = vec![
+            Package::new("requests".to_string(), "2.31.0".to_string()).unwrap(),
+            Package::new("idna".to_string(), "3.6".to_string()).unwrap(),
+            Package::new("urllib3".to_string(), "1.26.0".to_string()).unwrap(),
+        ];
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let recorded = progress.clone();
+        let outcome = client
+            .fetch_vulnerabilities_with_progress(
+                &packages,
+                Box::new(move |done, total| recorded.lock().unwrap().push((done, total))),
+            )
+            .await
+            .unwrap();
+        let results = outcome.results;
+        assert!(outcome.failed_packages.is_empty());
+
+        // Grouped per package in query order; a failed detail fetch keeps the
+        // advisory with the batch-level information only
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].package_name(), "requests");
+        let ids: Vec<&str> = results[0]
+            .vulnerabilities()
+            .iter()
+            .map(|v| v.id())
+            .collect();
+        assert_eq!(ids, ["GHSA-1", "GHSA-2"]);
+        assert_eq!(results[1].package_name(), "urllib3");
+        let missing = &results[1].vulnerabilities()[1];
+        assert_eq!(missing.id(), "GHSA-missing");
+        assert_eq!(missing.severity(), Severity::None);
+        assert_eq!(missing.cvss_score(), None);
+
+        // Progress counts every completed fetch, including the failed one
+        assert_eq!(*progress.lock().unwrap(), [(1, 4), (2, 4), (3, 4), (4, 4)]);
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_suffix_is_sent() {
+        let (base_url, requests) = spawn_recording_http_server(|_| {
+            vec![(
+                "/querybatch".to_string(),
+                "application/json".to_string(),
+                br#"{"results": [{}]}"#.to_vec(),
+            )]
+        });
+        let network = NetworkConfig {
+            user_agent_suffix: Some("(+mailto:secops@example.com)".to_string()),
+            ..Default::default()
+        };
+        let client = OsvClient::with_base_url(&base_url)
+            .unwrap()
+            .with_network_config(&network)
+            .unwrap();
+        let packages = vec![Package::new("requests".to_string(), "2.31.0".to_string()).unwrap()];
+
+        client.fetch_vulnerabilities(&packages).await.unwrap();
+
+        let expected = format!(
+            "user-agent: uv-sbom/{} (+mailto:secops@example.com)",
+            env!("CARGO_PKG_VERSION")
+        );
+        assert!(requests.lock().unwrap()[0].contains(&expected));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_vulnerabilities_follows_result_pages() {
+        use std::sync::{Arc, Mutex};
+
+        let vuln = |id: &str| {
+            (
+                format!("/vulns/{}", id),
+                "application/json".to_string(),
+                format!(r#"{{"id": "{}"}}"#, id).into_bytes(),
+            )
+        };
+        let (base_url, requests) = spawn_recording_http_server(|_| {
+            vec![
+                (
+                    "/querybatch".to_string(),
+                    "application/json".to_string(),
+                    br#"{"results": [
+                        {"vulns": [{"id": "GHSA-1"}, {"id": "GHSA-2"}], "next_page_token": "page-2"},
+                        {"vulns": [{"id": "GHSA-9"}]}
+                    ]}"#
+                    .to_vec(),
+                ),
+                // The follow-up only queries the truncated package; GHSA-2 is
+                // repeated on the second page and must not be fetched twice
+                (
+                    "/querybatch".to_string(),
+                    "application/json".to_string(),
+                    br#"{"results": [{"vulns": [{"id": "GHSA-2"}, {"id": "GHSA-3"}]}]}"#.to_vec(),
+                ),
+                vuln("GHSA-1"),
+                vuln("GHSA-2"),
+                vuln("GHSA-3"),
+                vuln("GHSA-9"),
+            ]
+        });
+        let client = OsvClient::with_base_url(&base_url).unwrap();
+        let packages = vec![
+            Package::new("requests".to_string(), "2.31.0".to_string()).unwrap(),
+            Package::new("idna".to_string(), "3.6".to_string()).unwrap(),
+        ];
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let recorded = progress.clone();
+        let results = client
+            .fetch_vulnerabilities_with_progress(
+                &packages,
+                Box::new(move |done, total| recorded.lock().unwrap().push((done, total))),
+            )
+            .await
+            .unwrap()
+            .results;
+
+        let ids: Vec<&str> = results[0]
+            .vulnerabilities()
+            .iter()
+            .map(|v| v.id())
+            .collect();
+        assert_eq!(ids, ["GHSA-1", "GHSA-2", "GHSA-3"]);
+        assert_eq!(results[1].vulnerabilities()[0].id(), "GHSA-9");
+
+        let request_lines: Vec<String> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|lines| lines[0].clone())
+            .collect();
+        let count = |line: &str| request_lines.iter().filter(|l| *l == line).count();
+        assert_eq!(count("POST /querybatch HTTP/1.1"), 2);
+        for id in ["GHSA-1", "GHSA-2", "GHSA-3", "GHSA-9"] {
+            assert_eq!(count(&format!("GET /vulns/{} HTTP/1.1", id)), 1, "{}", id);
+        }
+
+        // The total includes the vulnerabilities found on the second page
+        assert_eq!(progress.lock().unwrap().last(), Some(&(4, 4)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_vulnerabilities_caps_concurrent_details_and_sorts_by_id() {
+        let ids = ["GHSA-6", "GHSA-5", "GHSA-4", "GHSA-3", "GHSA-2", "GHSA-1"];
+        let (base_url, peak) = spawn_slow_http_server(Duration::from_millis(250), |_| {
+            let batch = ids
+                .iter()
+                .map(|id| format!(r#"{{"id": "{}"}}"#, id))
+                .collect::<Vec<_>>()
+                .join(", ");
+            std::iter::once((
+                "/querybatch".to_string(),
+                "application/json".to_string(),
+                format!(r#"{{"results": [{{"vulns": [{}]}}]}}"#, batch).into_bytes(),
+            ))
+            .chain(ids.iter().map(|id| {
+                (
+                    format!("/vulns/{}", id),
+                    "application/json".to_string(),
+                    format!(r#"{{"id": "{}"}}"#, id).into_bytes(),
+                )
+            }))
+            .collect()
+        });
+        let client = OsvClient::with_base_url(&base_url)
+            .unwrap()
+            .with_detail_workers(2);
+        let packages = vec![Package::new("requests".to_string(), "2.31.0".to_string()).unwrap()];
+
+        let started = Instant::now();
+        let results = client
+            .fetch_vulnerabilities(&packages)
+            .await
+            .unwrap()
+            .results;
+        let elapsed = started.elapsed();
+
+        // Six detail requests of 250 ms each, two at a time
+        assert_eq!(peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+        // The shared rate limit still spaces the six starts 100 ms apart
+        assert!(elapsed >= Duration::from_millis(250 + 500), "{:?}", elapsed);
+        let ids: Vec<&str> = results[0]
+            .vulnerabilities()
+            .iter()
+            .map(|v| v.id())
+            .collect();
+        assert_eq!(
+            ids,
+            ["GHSA-1", "GHSA-2", "GHSA-3", "GHSA-4", "GHSA-5", "GHSA-6"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_vulnerabilities_skips_withdrawn_and_collapses_aliases() {
+        let base_url = spawn_http_server(|_| {
+            vec![
+                (
+                    "/querybatch".to_string(),
+                    "application/json".to_string(),
+                    br#"{"results": [
+                        {"vulns": [{"id": "GHSA-aaaa"}, {"id": "PYSEC-2024-1"}, {"id": "GHSA-gone"}]}
+                    ]}"#
+                    .to_vec(),
+                ),
+                (
+                    "/vulns/GHSA-aaaa".to_string(),
+                    "application/json".to_string(),
+                    br#"{"id": "GHSA-aaaa", "aliases": ["CVE-2024-1234"]}"#.to_vec(),
+                ),
+                (
+                    "/vulns/PYSEC-2024-1".to_string(),
+                    "application/json".to_string(),
+                    br#"{"id": "PYSEC-2024-1", "aliases": ["CVE-2024-1234", "GHSA-aaaa"]}"#
+                        .to_vec(),
+                ),
+                (
+                    "/vulns/GHSA-gone".to_string(),
+                    "application/json".to_string(),
+                    br#"{"id": "GHSA-gone", "withdrawn": "2024-03-01T00:00:00Z"}"#.to_vec(),
+                ),
+            ]
+        });
+        let client = OsvClient::with_base_url(&base_url).unwrap();
+        let packages = vec![Package::new("requests".to_string(), "2.31.0".to_string()).unwrap()];
+
+        let results = client
+            .fetch_vulnerabilities(&packages)
+            .await
+            .unwrap()
+            .results;
+
+        assert_eq!(results.len(), 1);
+        let vulnerabilities = results[0].vulnerabilities();
+        assert_eq!(vulnerabilities.len(), 1);
+        assert_eq!(vulnerabilities[0].id(), "CVE-2024-1234");
+        assert_eq!(vulnerabilities[0].aliases(), ["GHSA-aaaa", "PYSEC-2024-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_detail_fetch_keeps_minimal_advisory() {
+        let base_url = spawn_http_server(|_| {
+            vec![(
+                "/querybatch".to_string(),
+                "application/json".to_string(),
+                br#"{"results": [{"vulns": [{"id": "GHSA-missing"}]}]}"#.to_vec(),
+            )]
+        });
+        let client = OsvClient::with_base_url(&base_url)
+            .unwrap()
+            .with_retry_config(RetryConfig {
+                max_attempts: 1,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(1),
+            });
+        let packages = vec![Package::new("requests".to_string(), "2.31.0".to_string()).unwrap()];
+
+        let outcome = client.fetch_vulnerabilities(&packages).await.unwrap();
+
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].vulnerabilities()[0].id(), "GHSA-missing");
+        assert_eq!(outcome.incomplete_advisories.len(), 1);
+        assert_eq!(outcome.incomplete_advisories[0].id, "GHSA-missing");
+        assert!(outcome.incomplete_advisories[0].error.contains("404"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_vulnerabilities_retries_rate_limited_batch() {
+        let (base_url, served) = spawn_scripted_server(vec![
+            ("429 Too Many Requests", Vec::new()),
+            ("200 OK", br#"{"results": [{}]}"#.to_vec()),
+        ]);
+        let client = OsvClient::with_base_url(&base_url)
+            .unwrap()
+            .with_retry_config(RetryConfig {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(5),
+            });
+        let packages = vec![Package::new("requests".to_string(), "2.31.0".to_string()).unwrap()];
+
+        let outcome = client.fetch_vulnerabilities(&packages).await.unwrap();
+
+        assert!(outcome.results.is_empty());
+        assert!(outcome.failed_packages.is_empty());
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_vulnerabilities_continues_after_failed_batch() {
+        let (base_url, served) = spawn_scripted_server(vec![
+            ("400 Bad Request", Vec::new()),
+            ("200 OK", br#"{"results": [{}, {}]}"#.to_vec()),
+        ]);
+        let client = OsvClient::with_base_url(&base_url).unwrap();
+        let packages: Vec<Package> = (0..OsvClient::MAX_BATCH_SIZE + 2)
+            .map(|i| Package::new(format!("pkg{}", i), "1.0.0".to_string()).unwrap())
+            .collect();
+
+        let outcome = client.fetch_vulnerabilities(&packages).await.unwrap();
+
+        // The first batch failed and is reported; the second one was still queried
+        assert!(outcome.results.is_empty());
+        assert_eq!(outcome.failed_packages.len(), OsvClient::MAX_BATCH_SIZE);
+        assert_eq!(outcome.failed_packages[0].package.name(), "pkg0");
+        assert!(outcome.failed_packages[0].error.contains("400"));
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_failed_result_page_keeps_first_page() {
+        let (base_url, _) = spawn_scripted_server(vec![
+            (
+                "200 OK",
+                br#"{"results": [{"vulns": [{"id": "GHSA-1"}], "next_page_token": "page-2"}, {}]}"#
+                    .to_vec(),
+            ),
+            ("400 Bad Request", Vec::new()),
+            ("200 OK", br#"{"id": "GHSA-1"}"#.to_vec()),
+        ]);
+        let client = OsvClient::with_base_url(&base_url).unwrap();
+        let packages = vec![
+            Package::new("requests".to_string(), "2.31.0".to_string()).unwrap(),
+            Package::new("idna".to_string(), "3.6".to_string()).unwrap(),
+        ];
+
+        let outcome = client.fetch_vulnerabilities(&packages).await.unwrap();
+
+        // The first page's advisory is kept; only the truncated package is unchecked
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.results[0].vulnerabilities()[0].id(), "GHSA-1");
+        assert_eq!(outcome.failed_packages.len(), 1);
+        assert_eq!(outcome.failed_packages[0].package.name(), "requests");
+        assert!(outcome.failed_packages[0].error.contains("400"));
+    }
+
+    #[tokio::test]
+    async fn test_too_many_result_pages_mark_package_unchecked() {
+        // Every page, the follow-ups included, points at yet another page
+        let (base_url, requests) = spawn_recording_http_server(|_| {
+            vec![
+                (
+                    "/querybatch".to_string(),
+                    "application/json".to_string(),
+                    br#"{"results": [{"vulns": [{"id": "GHSA-1"}], "next_page_token": "more"}]}"#
+                        .to_vec(),
+                ),
+                (
+                    "/vulns/GHSA-1".to_string(),
+                    "application/json".to_string(),
+                    br#"{"id": "GHSA-1"}"#.to_vec(),
+                ),
+            ]
+        });
+        let client = OsvClient::with_base_url(&base_url).unwrap();
+        let packages = vec![Package::new("requests".to_string(), "2.31.0".to_string()).unwrap()];
+
+        let outcome = client.fetch_vulnerabilities(&packages).await.unwrap();
+
+        assert_eq!(outcome.results[0].vulnerabilities()[0].id(), "GHSA-1");
+        assert_eq!(outcome.failed_packages.len(), 1);
+        assert!(outcome.failed_packages[0]
+            .error
+            .contains("more than 21 result pages"));
+        let batches = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|lines| lines[0] == "POST /querybatch HTTP/1.1")
+            .count();
+        assert_eq!(batches, OsvClient::MAX_RESULT_PAGES + 1);
+    }
+
     #[test]
     fn test_parse_cvss_score_critical() {
         // High severity example (network, low complexity, no privileges, no interaction)
@@ -554,6 +1382,7 @@ mod tests {
                     ecosystem: "PyPI".to_string(),
                 },
                 version: "2.31.0".to_string(),
+                page_token: None,
             }],
         };
 
@@ -561,6 +1390,7 @@ mod tests {
         assert!(json.contains("requests"));
         assert!(json.contains("PyPI"));
         assert!(json.contains("2.31.0"));
+        assert!(!json.contains("page_token"));
     }
 
     #[test]
@@ -629,6 +1459,188 @@ mod tests {
         assert!(vuln.database_specific.is_none());
     }
 
+    const V3_VECTOR: &str = "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+    const V4_VECTOR: &str = "CVSS:4.0/AV:N/AC:L/AT:N/PR:N/UI:N/VC:H/VI:H/VA:H/SC:N/SI:N/SA:N";
+    const V2_VECTOR: &str = "AV:N/AC:L/Au:N/C:P/I:P/A:P";
+
+    fn vuln_with_severities(severities: &[(&str, &str)]) -> OsvVulnerability {
+        OsvVulnerability {
+            id: "GHSA-test".to_string(),
+            aliases: Vec::new(),
+            withdrawn: None,
+            summary: None,
+            severity: Some(
+                severities
+                    .iter()
+                    .map(|(severity_type, score)| OsvSeverity {
+                        severity_type: severity_type.to_string(),
+                        score: score.to_string(),
+                    })
+                    .collect(),
+            ),
+            database_specific: None,
+            affected: None,
+            references: Vec::new(),
+        }
+    }
+
+    fn convert(
+        preference: CvssPreference,
+        severities: &[(&str, &str)],
+    ) -> (Option<f32>, Option<CvssVersion>) {
+        let client = OsvClient::new().unwrap().with_cvss_preference(preference);
+        let vuln = client
+            .convert_to_vulnerability(&vuln_with_severities(severities))
+            .unwrap();
+        (vuln.cvss_score().map(|s| s.value()), vuln.cvss_version())
+    }
+
+    #[test]
+    fn test_convert_only_v3_uses_v3_for_either_preference() {
+        for preference in [CvssPreference::V4, CvssPreference::V3] {
+            assert_eq!(
+                convert(preference, &[("CVSS_V3", V3_VECTOR)]),
+                (Some(9.8), Some(CvssVersion::V3_1))
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_only_v4_uses_v4_for_either_preference() {
+        for preference in [CvssPreference::V4, CvssPreference::V3] {
+            assert_eq!(
+                convert(preference, &[("CVSS_V4", V4_VECTOR)]),
+                (Some(9.3), Some(CvssVersion::V4_0))
+            );
+        }
+    }
+
+    fn source_url_of(json: &str) -> Option<String> {
+        let osv_vuln: OsvVulnerability = serde_json::from_str(json).unwrap();
+        let vuln = OsvClient::new()
+            .unwrap()
+            .convert_to_vulnerability(&osv_vuln)
+            .unwrap();
+        vuln.source_url().map(str::to_string)
+    }
+
+    #[test]
+    fn test_convert_prefers_advisory_reference() {
+        let url = source_url_of(
+            r#"{
+                "id": "GHSA-test",
+                "references": [
+                    {"type": "WEB", "url": "https://example.com/blog"},
+                    {"type": "FIX", "url": "https://github.com/org/repo/commit/abc"},
+                    {"type": "ADVISORY", "url": "https://nvd.nist.gov/vuln/detail/CVE-2024-1234"}
+                ]
+            }"#,
+        );
+        assert_eq!(
+            url.as_deref(),
+            Some("https://nvd.nist.gov/vuln/detail/CVE-2024-1234")
+        );
+    }
+
+    #[test]
+    fn test_convert_falls_back_to_web_reference() {
+        let url = source_url_of(
+            r#"{
+                "id": "GHSA-test",
+                "references": [
+                    {"type": "PACKAGE", "url": "https://pypi.org/project/requests/"},
+                    {"type": "WEB", "url": "https://example.com/blog"}
+                ]
+            }"#,
+        );
+        assert_eq!(url.as_deref(), Some("https://example.com/blog"));
+    }
+
+    #[test]
+    fn test_convert_without_references_links_to_osv() {
+        let url = source_url_of(r#"{"id": "PYSEC-2024-1"}"#);
+        assert_eq!(
+            url.as_deref(),
+            Some("https://osv.dev/vulnerability/PYSEC-2024-1")
+        );
+    }
+
+    #[test]
+    fn test_convert_both_follows_preference() {
+        let both = [("CVSS_V3", V3_VECTOR), ("CVSS_V4", V4_VECTOR)];
+        assert_eq!(
+            convert(CvssPreference::V4, &both),
+            (Some(9.3), Some(CvssVersion::V4_0))
+        );
+        assert_eq!(
+            convert(CvssPreference::V3, &both),
+            (Some(9.8), Some(CvssVersion::V3_1))
+        );
+    }
+
+    #[test]
+    fn test_convert_falls_back_when_preferred_vector_is_unparseable() {
+        let both = [("CVSS_V4", "CVSS:4.0/AV:N"), ("CVSS_V3", V3_VECTOR)];
+        assert_eq!(
+            convert(CvssPreference::V4, &both),
+            (Some(9.8), Some(CvssVersion::V3_1))
+        );
+    }
+
+    #[test]
+    fn test_convert_only_v2_uses_v2() {
+        for preference in [CvssPreference::V4, CvssPreference::V3] {
+            assert_eq!(
+                convert(preference, &[("CVSS_V2", V2_VECTOR)]),
+                (Some(7.5), Some(CvssVersion::V2_0))
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_v2_is_used_only_without_v3_or_v4() {
+        let all = [
+            ("CVSS_V2", V2_VECTOR),
+            ("CVSS_V3", V3_VECTOR),
+            ("CVSS_V4", V4_VECTOR),
+        ];
+        assert_eq!(
+            convert(CvssPreference::V4, &all),
+            (Some(9.3), Some(CvssVersion::V4_0))
+        );
+        assert_eq!(
+            convert(CvssPreference::V3, &all[..2]),
+            (Some(9.8), Some(CvssVersion::V3_1))
+        );
+    }
+
+    #[test]
+    fn test_convert_v2_score_is_never_critical() {
+        let client = OsvClient::new().unwrap();
+        let vuln = client
+            .convert_to_vulnerability(&vuln_with_severities(&[(
+                "CVSS_V2",
+                "AV:N/AC:L/Au:N/C:C/I:C/A:C",
+            )]))
+            .unwrap();
+        assert_eq!(vuln.cvss_score().map(|s| s.value()), Some(10.0));
+        assert_eq!(vuln.severity(), Severity::High);
+    }
+
+    #[test]
+    fn test_convert_records_cvss_3_0() {
+        let vector = "CVSS:3.0/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H";
+        assert_eq!(
+            convert(CvssPreference::V4, &[("CVSS_V3", vector)]).1,
+            Some(CvssVersion::V3_0)
+        );
+    }
+
+    #[test]
+    fn test_convert_without_cvss_records_no_version() {
+        assert_eq!(convert(CvssPreference::V4, &[]), (None, None));
+    }
+
     // Integration test - requires network access
     // Uncomment to run with real OSV API
     // #[test]
@@ -637,7 +1649,7 @@ mod tests {
     //     let packages = vec![
     //         Package::new("requests".to_string(), "2.3.0".to_string()).unwrap(),
     //     ];
-    //     let result = client.fetch_vulnerabilities(packages);
+    //     let result = client.fetch_vulnerabilities(&packages);
     //     assert!(result.is_ok());
     // }
 }
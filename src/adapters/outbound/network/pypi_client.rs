@@ -1,10 +1,27 @@
-use crate::ports::outbound::{LicenseRepository, PyPiMetadata};
+use super::http_client::{CountingHttpClient, NetworkConfig, RequestCounter};
+use super::retry::RetryConfig;
+use crate::ports::outbound::{EndpointProbe, LicenseFetchFields, LicenseRepository, PyPiMetadata};
+use crate::sbom_generation::domain::{PackageAuthorship, PackageLinks, PackageName};
+use crate::sbom_generation::policies::LicensePriority;
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 use async_trait::async_trait;
 use serde::Deserialize;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Default PyPI base URL
+const PYPI_BASE_URL: &str = "https://pypi.org";
+
+/// Maximum size of a per-version JSON response we are willing to buffer (2 MiB).
+///
+/// The JSON API embeds the full long_description, which exceeds a megabyte for
+/// some packages. Larger responses are abandoned in favour of the PEP 658 core
+/// metadata file, which carries the same license and summary fields.
+const MAX_JSON_RESPONSE_BYTES: u64 = 2 * 1024 * 1024;
+
 #[derive(Debug, Deserialize)]
 struct PyPiPackageInfo {
     info: PyPiInfo,
@@ -24,7 +41,7 @@ struct PyPiDigests {
     sha256: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 struct PyPiInfo {
     #[serde(default)]
     license: Option<String>,
@@ -34,6 +51,130 @@ struct PyPiInfo {
     summary: Option<String>,
     #[serde(default)]
     classifiers: Vec<String>,
+    #[serde(default)]
+    yanked: bool,
+    #[serde(default)]
+    yanked_reason: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    author_email: Option<String>,
+    #[serde(default)]
+    maintainer: Option<String>,
+    #[serde(default)]
+    home_page: Option<String>,
+    /// Labelled links, e.g. `"Source": "https://github.com/..."`
+    #[serde(default)]
+    project_urls: Option<BTreeMap<String, Option<String>>>,
+}
+
+impl PyPiInfo {
+    /// The yank reason (empty if none was given) if the release was yanked
+    fn yank(&self) -> Option<String> {
+        self.yanked
+            .then(|| self.yanked_reason.clone().unwrap_or_default())
+    }
+}
+
+/// Trimmed view of a per-version JSON response for [`LicenseFetchFields::LicenseOnly`]
+///
+/// Only the license and authorship fields and file digests are kept. Everything
+/// else, such as `summary`, `description` and any `releases` listing, is
+/// skipped by the deserializer without being allocated.
+#[derive(Debug, Deserialize)]
+struct PyPiLicenseOnlyPackageInfo {
+    info: PyPiLicenseOnlyInfo,
+    #[serde(default)]
+    urls: Vec<PyPiUrl>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PyPiLicenseOnlyInfo {
+    #[serde(default)]
+    license: Option<String>,
+    #[serde(default)]
+    license_expression: Option<String>,
+    #[serde(default)]
+    classifiers: Vec<String>,
+    #[serde(default)]
+    yanked: bool,
+    #[serde(default)]
+    yanked_reason: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    author_email: Option<String>,
+    #[serde(default)]
+    maintainer: Option<String>,
+    #[serde(default)]
+    home_page: Option<String>,
+    /// Labelled links, e.g. `"Source": "https://github.com/..."`
+    #[serde(default)]
+    project_urls: Option<BTreeMap<String, Option<String>>>,
+}
+
+impl From<PyPiLicenseOnlyPackageInfo> for PyPiPackageInfo {
+    fn from(trimmed: PyPiLicenseOnlyPackageInfo) -> Self {
+        Self {
+            info: PyPiInfo {
+                license: trimmed.info.license,
+                license_expression: trimmed.info.license_expression,
+                summary: None,
+                classifiers: trimmed.info.classifiers,
+                yanked: trimmed.info.yanked,
+                yanked_reason: trimmed.info.yanked_reason,
+                author: trimmed.info.author,
+                author_email: trimmed.info.author_email,
+                maintainer: trimmed.info.maintainer,
+                home_page: trimmed.info.home_page,
+                project_urls: trimmed.info.project_urls,
+            },
+            urls: trimmed.urls,
+        }
+    }
+}
+
+/// Basic-auth credentials for a private package index
+///
+/// Only sent to the configured index, never to the pypi.org fallback.
+#[derive(Clone, PartialEq, Eq)]
+pub struct IndexCredentials {
+    username: String,
+    password: Option<String>,
+}
+
+impl IndexCredentials {
+    /// Environment variable holding the index username
+    pub const USERNAME_VAR: &'static str = "UV_SBOM_INDEX_USERNAME";
+    /// Environment variable holding the index password or token
+    pub const PASSWORD_VAR: &'static str = "UV_SBOM_INDEX_PASSWORD";
+
+    /// Creates credentials from a username and an optional password
+    pub fn new(username: impl Into<String>, password: Option<String>) -> Self {
+        Self {
+            username: username.into(),
+            password,
+        }
+    }
+
+    /// Reads the credentials from `UV_SBOM_INDEX_USERNAME` and `UV_SBOM_INDEX_PASSWORD`
+    ///
+    /// Returns `None` when no username is set.
+    pub fn from_env() -> Option<Self> {
+        let username = std::env::var(Self::USERNAME_VAR)
+            .ok()
+            .filter(|u| !u.is_empty())?;
+        Some(Self::new(username, std::env::var(Self::PASSWORD_VAR).ok()))
+    }
+}
+
+impl std::fmt::Debug for IndexCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexCredentials")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
 }
 
 /// PyPiLicenseRepository adapter for fetching license information from PyPI API
@@ -44,100 +185,464 @@ struct PyPiInfo {
 /// # Async Support
 /// Uses async reqwest client for non-blocking HTTP requests, enabling parallel
 /// license fetching for improved performance.
+///
+/// # Response Size Limit
+/// Per-version JSON responses are read with a size cap. When a response
+/// exceeds the cap, the package's PEP 658 core metadata file is fetched via
+/// the PEP 691 simple API instead, so oversized descriptions are never buffered.
+///
+/// # Private Indexes
+/// [`with_index_url`](Self::with_index_url) points lookups at a mirror or private
+/// index serving the same JSON API, optionally with basic-auth credentials. With
+/// [`with_pypi_fallback`](Self::with_pypi_fallback), a package or version the
+/// index answers with 404 is looked up on pypi.org instead.
 #[derive(Clone)]
 pub struct PyPiLicenseRepository {
-    client: reqwest::Client,
-    max_retries: u32,
+    client: CountingHttpClient,
+    retry: RetryConfig,
+    base_url: String,
+    credentials: Option<IndexCredentials>,
+    fallback_url: Option<String>,
+    max_response_bytes: u64,
+    bytes_downloaded: Arc<AtomicU64>,
+    fields: LicenseFetchFields,
+    priority: LicensePriority,
 }
 
 impl PyPiLicenseRepository {
     /// Creates a new PyPI license repository with default configuration
     pub fn new() -> Result<Self> {
-        let version = env!("CARGO_PKG_VERSION");
-        let user_agent = format!("uv-sbom/{}", version);
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(10))
-            .user_agent(user_agent)
-            .build()?;
+        Self::with_base_url(PYPI_BASE_URL)
+    }
+
+    /// Creates a new PyPI license repository targeting a custom base URL
+    pub fn with_base_url(base_url: &str) -> Result<Self> {
+        let client = CountingHttpClient::new(Duration::from_secs(10))?;
 
         Ok(Self {
             client,
-            max_retries: 3,
+            retry: RetryConfig::default(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            credentials: None,
+            fallback_url: None,
+            max_response_bytes: MAX_JSON_RESPONSE_BYTES,
+            bytes_downloaded: Arc::new(AtomicU64::new(0)),
+            fields: LicenseFetchFields::Full,
+            priority: LicensePriority::default(),
         })
     }
 
-    /// Fetches package information from PyPI with retry logic (async)
-    async fn fetch_with_retry(&self, package_name: &str, version: &str) -> Result<PyPiPackageInfo> {
-        let mut last_error = None;
-
-        for attempt in 1..=self.max_retries {
-            match self.fetch_from_pypi(package_name, version).await {
-                Ok(result) => return Ok(result),
-                Err(e) => {
-                    last_error = Some(e);
-                    if attempt < self.max_retries {
-                        // Retry after a short wait (async)
-                        tokio::time::sleep(Duration::from_millis(100 * attempt as u64)).await;
-                    }
-                }
+    /// Looks packages up on a mirror or private index instead of pypi.org
+    ///
+    /// The index must serve PyPI's JSON API under `<url>/pypi/<name>/<version>/json`.
+    ///
+    /// # Errors
+    /// Returns a validation error when `url` is not an absolute http(s) URL.
+    pub fn with_index_url(mut self, url: &str) -> Result<Self> {
+        let parsed = reqwest::Url::parse(url).map_err(|e| {
+            UvSbomError::validation(format!("Invalid package index URL '{}': {}", url, e))
+        })?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(UvSbomError::validation(format!(
+                "Invalid package index URL '{}': only http and https are supported",
+                url
+            )));
+        }
+        self.base_url = url.trim_end_matches('/').to_string();
+        Ok(self)
+    }
+
+    /// Sends basic-auth credentials with every request to the index
+    pub fn with_credentials(mut self, credentials: Option<IndexCredentials>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Sets whether packages the index does not have (404) are looked up on pypi.org
+    pub fn with_pypi_fallback(mut self, enabled: bool) -> Self {
+        self.fallback_url =
+            (enabled && self.base_url != PYPI_BASE_URL).then(|| PYPI_BASE_URL.to_string());
+        self
+    }
+
+    /// Builds a GET request, attaching the credentials when `url` is on the index
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        let request = self.client.get(url);
+        let on_index = url
+            .strip_prefix(self.base_url.as_str())
+            .is_some_and(|rest| rest.starts_with('/'));
+        match &self.credentials {
+            Some(credentials) if on_index => {
+                request.basic_auth(&credentials.username, credentials.password.as_deref())
+            }
+            _ => request,
+        }
+    }
+
+    /// Selects the metadata fields to read; `LicenseOnly` returns no descriptions
+    pub fn with_fields(mut self, fields: LicenseFetchFields) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Sets which of the license field, license_expression and classifiers wins
+    pub fn with_license_priority(mut self, priority: LicensePriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Counts requests against a counter shared with the other network adapters
+    pub fn with_request_counter(mut self, counter: RequestCounter) -> Self {
+        self.client = self.client.with_counter(counter);
+        self
+    }
+
+    /// Applies the proxy, CA bundle and timeout settings
+    pub fn with_network_config(mut self, network: &NetworkConfig) -> Result<Self> {
+        self.client = self.client.with_network_config(network)?;
+        Ok(self)
+    }
+
+    /// Replaces the retry policy for transient failures
+    pub fn with_retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Returns the total number of response body bytes downloaded so far.
+    ///
+    /// Shared across clones, so the total covers every request made by this repository.
+    pub fn bytes_downloaded(&self) -> u64 {
+        self.bytes_downloaded.load(Ordering::Relaxed)
+    }
+
+    /// Reads a response body, giving up once it exceeds `max_response_bytes`.
+    ///
+    /// Returns `Ok(None)` when the declared `Content-Length` or the streamed
+    /// body is larger than the cap.
+    async fn read_capped_body(&self, mut response: reqwest::Response) -> Result<Option<Vec<u8>>> {
+        if response
+            .content_length()
+            .is_some_and(|len| len > self.max_response_bytes)
+        {
+            return Ok(None);
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            self.bytes_downloaded
+                .fetch_add(chunk.len() as u64, Ordering::Relaxed);
+            body.extend_from_slice(&chunk);
+            if body.len() as u64 > self.max_response_bytes {
+                return Ok(None);
             }
         }
+        Ok(Some(body))
+    }
 
-        Err(last_error.unwrap())
+    /// Reads a core metadata response only up to the end of its header section.
+    ///
+    /// The description follows the first blank line and can be as large as the
+    /// JSON response we gave up on, so the stream is dropped once it is reached.
+    async fn read_metadata_headers(&self, mut response: reqwest::Response) -> Result<Vec<u8>> {
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            self.bytes_downloaded
+                .fetch_add(chunk.len() as u64, Ordering::Relaxed);
+            body.extend_from_slice(&chunk);
+            if let Some(end) = find_header_end(&body) {
+                body.truncate(end);
+                return Ok(body);
+            }
+            if body.len() as u64 > self.max_response_bytes {
+                return Err(UvSbomError::other(
+                    "PyPI core metadata headers exceeded the size limit",
+                ));
+            }
+        }
+        Ok(body)
+    }
+
+    /// Fetches package information from the index, retrying transient failures (async)
+    ///
+    /// Falls back to pypi.org when enabled and the index answers with 404.
+    async fn fetch_with_retry(&self, package_name: &str, version: &str) -> Result<PyPiPackageInfo> {
+        let result = self
+            .retry
+            .run(|| self.fetch_from_pypi(&self.base_url, package_name, version))
+            .await;
+        match (&self.fallback_url, result) {
+            (
+                Some(fallback_url),
+                Err(UvSbomError::HttpStatus {
+                    status: reqwest::StatusCode::NOT_FOUND,
+                    ..
+                }),
+            ) => {
+                self.retry
+                    .run(|| self.fetch_from_pypi(fallback_url, package_name, version))
+                    .await
+            }
+            (_, result) => result,
+        }
     }
 
     /// Validates and sanitizes package name and version for URL safety
     fn validate_url_component(component: &str, component_type: &str) -> Result<()> {
         // Security: Prevent URL injection attacks
         if component.contains('/') || component.contains('\\') {
-            anyhow::bail!(
+            return Err(UvSbomError::other(format!(
                 "Security: {} contains path separators which are not allowed",
                 component_type
-            );
+            )));
         }
 
         if component.contains("..") {
-            anyhow::bail!(
+            return Err(UvSbomError::other(format!(
                 "Security: {} contains '..' which is not allowed",
                 component_type
-            );
+            )));
         }
 
         // Check for URL-unsafe characters that could cause issues
         if component.contains('#') || component.contains('?') || component.contains('@') {
-            anyhow::bail!(
+            return Err(UvSbomError::other(format!(
                 "Security: {} contains URL-unsafe characters",
                 component_type
-            );
+            )));
         }
 
         Ok(())
     }
 
-    /// Fetches package information from PyPI API (async)
-    async fn fetch_from_pypi(&self, package_name: &str, version: &str) -> Result<PyPiPackageInfo> {
+    /// Fetches package information from the PyPI JSON API at `base_url` (async)
+    async fn fetch_from_pypi(
+        &self,
+        base_url: &str,
+        package_name: &str,
+        version: &str,
+    ) -> Result<PyPiPackageInfo> {
         // Security: Validate URL components before using them
         Self::validate_url_component(package_name, "Package name")?;
         Self::validate_url_component(version, "Version")?;
 
-        // URL encode components to handle special characters safely
-        let encoded_package = urlencoding::encode(package_name);
+        // URL encode components to handle special characters safely; PyPI
+        // resolves projects by their PEP 503 normalized name
+        let normalized = PackageName::normalize(package_name);
+        let encoded_package = urlencoding::encode(&normalized);
         let encoded_version = urlencoding::encode(version);
 
         let url = format!(
-            "https://pypi.org/pypi/{}/{}/json",
-            encoded_package, encoded_version
+            "{}/pypi/{}/{}/json",
+            base_url, encoded_package, encoded_version
         );
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.client.send(self.get(&url)).await?;
 
-        if !response.status().is_success() {
-            anyhow::bail!("PyPI API returned status code {}", response.status());
+        let status = response.status();
+        if !status.is_success() {
+            return Err(UvSbomError::http_status(
+                status,
+                format!("PyPI API returned status code {}", status),
+            ));
         }
 
-        let package_info: PyPiPackageInfo = response.json().await?;
+        let package_info = match self.read_capped_body(response).await? {
+            Some(body) => match self.fields {
+                LicenseFetchFields::Full => serde_json::from_slice(&body)?,
+                LicenseFetchFields::LicenseOnly => {
+                    serde_json::from_slice::<PyPiLicenseOnlyPackageInfo>(&body)?.into()
+                }
+            },
+            None => {
+                self.fetch_from_core_metadata(base_url, package_name, version)
+                    .await?
+            }
+        };
         Ok(package_info)
     }
+
+    /// Fetches license fields from the PEP 658 core metadata of a release file.
+    ///
+    /// Used when the JSON API response is too large. Locates a file for the
+    /// version through the PEP 691 JSON simple API, then downloads its
+    /// `.metadata` sidecar, which contains only the distribution headers
+    /// followed by the description.
+    async fn fetch_from_core_metadata(
+        &self,
+        base_url: &str,
+        package_name: &str,
+        version: &str,
+    ) -> Result<PyPiPackageInfo> {
+        let normalized = PackageName::normalize(package_name);
+        let url = format!("{}/simple/{}/", base_url, urlencoding::encode(&normalized));
+
+        let response = self
+            .client
+            .send(self.get(&url).header(
+                reqwest::header::ACCEPT,
+                "application/vnd.pypi.simple.v1+json",
+            ))
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(UvSbomError::http_status(
+                status,
+                format!("PyPI simple API returned status code {}", status),
+            ));
+        }
+        let body = self.read_capped_body(response).await?.ok_or_else(|| {
+            UvSbomError::other("PyPI simple API response exceeded the size limit")
+        })?;
+        let index: SimpleIndex = serde_json::from_slice(&body)?;
+
+        let file = select_release_file(&index.files, &normalized, version).ok_or_else(|| {
+            UvSbomError::other(format!(
+                "No release file with core metadata found for {} {}",
+                package_name, version
+            ))
+        })?;
+
+        let response = self
+            .client
+            .send(self.get(&format!("{}.metadata", file.url)))
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(UvSbomError::http_status(
+                status,
+                format!("PyPI core metadata request returned status code {}", status),
+            ));
+        }
+        let metadata = self.read_metadata_headers(response).await?;
+        let mut info = parse_core_metadata(&String::from_utf8_lossy(&metadata));
+        if self.fields == LicenseFetchFields::LicenseOnly {
+            info.summary = None;
+        }
+        match &file.yanked {
+            Some(serde_json::Value::Bool(true)) => info.yanked = true,
+            Some(serde_json::Value::String(reason)) => {
+                info.yanked = true;
+                info.yanked_reason = Some(reason.clone());
+            }
+            _ => {}
+        }
+
+        Ok(PyPiPackageInfo {
+            info,
+            urls: vec![PyPiUrl {
+                digests: PyPiDigests {
+                    sha256: file.hashes.sha256.clone(),
+                },
+            }],
+        })
+    }
+}
+
+/// PEP 691 JSON simple API project page
+#[derive(Debug, Deserialize)]
+struct SimpleIndex {
+    #[serde(default)]
+    files: Vec<SimpleFile>,
+}
+
+/// A single release file entry in the PEP 691 simple API
+#[derive(Debug, Deserialize)]
+struct SimpleFile {
+    filename: String,
+    url: String,
+    #[serde(default)]
+    hashes: PyPiDigests,
+    /// PEP 714 name; `false` or absent when no metadata file is served
+    #[serde(default, rename = "core-metadata")]
+    core_metadata: Option<serde_json::Value>,
+    /// Legacy PEP 658 name for `core-metadata`
+    #[serde(default, rename = "data-dist-info-metadata")]
+    dist_info_metadata: Option<serde_json::Value>,
+    /// PEP 592 yank status: `false`, `true` or the yank reason
+    #[serde(default)]
+    yanked: Option<serde_json::Value>,
+}
+
+impl SimpleFile {
+    fn has_core_metadata(&self) -> bool {
+        let available = |v: &Option<serde_json::Value>| {
+            v.as_ref()
+                .is_some_and(|v| !matches!(v, serde_json::Value::Bool(false)))
+        };
+        available(&self.core_metadata) || available(&self.dist_info_metadata)
+    }
+}
+
+/// Picks a release file for `version` that has a core metadata sidecar.
+///
+/// Wheels are matched on `{name}-{version}-`, with `-`/`_`/`.` in the name
+/// treated as equivalent, as wheel filenames use underscores.
+fn select_release_file<'a>(
+    files: &'a [SimpleFile],
+    normalized_name: &str,
+    version: &str,
+) -> Option<&'a SimpleFile> {
+    let fold = |s: &str| s.to_lowercase().replace(['-', '.'], "_");
+    let expected = fold(&format!("{}-{}-", normalized_name, version));
+    files
+        .iter()
+        .find(|f| f.has_core_metadata() && fold(&f.filename).starts_with(&expected))
+}
+
+/// Returns the offset of the blank line that ends a metadata header section.
+fn find_header_end(body: &[u8]) -> Option<usize> {
+    body.windows(2)
+        .position(|w| w == b"\n\n")
+        .or_else(|| body.windows(4).position(|w| w == b"\r\n\r\n"))
+}
+
+/// Parses the header section of a core metadata (`METADATA`) file.
+///
+/// Reading stops at the first blank line, so the description body that
+/// follows the headers is never inspected.
+fn parse_core_metadata(text: &str) -> PyPiInfo {
+    let mut info = PyPiInfo::default();
+    let mut headers: Vec<(String, String)> = Vec::new();
+
+    for line in text.lines() {
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push('\n');
+                value.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            headers.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    for (key, value) in headers {
+        match key.to_ascii_lowercase().as_str() {
+            "license" if !value.is_empty() => info.license = Some(value),
+            "license-expression" if !value.is_empty() => info.license_expression = Some(value),
+            "summary" if !value.is_empty() => info.summary = Some(value),
+            "classifier" => info.classifiers.push(value),
+            "author" => info.author = Some(value),
+            "author-email" => info.author_email = Some(value),
+            "maintainer" => info.maintainer = Some(value),
+            "home-page" => info.home_page = Some(value),
+            "project-url" => {
+                if let Some((label, url)) = value.split_once(',') {
+                    info.project_urls
+                        .get_or_insert_with(BTreeMap::new)
+                        .insert(label.trim().to_string(), Some(url.trim().to_string()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    info
 }
 
 impl PyPiLicenseRepository {
@@ -146,13 +651,11 @@ impl PyPiLicenseRepository {
     /// non-existent packages (unlike the /project/ HTML endpoint which
     /// returns 200 for all requests).
     pub async fn verify_package_exists(&self, package_name: &str) -> bool {
-        let normalized = package_name.to_lowercase().replace('_', "-");
-        let url = format!("https://pypi.org/pypi/{}/json", normalized);
+        let normalized = PackageName::normalize(package_name);
+        let url = format!("{}/pypi/{}/json", self.base_url, normalized);
         match self
             .client
-            .head(&url)
-            .timeout(Duration::from_secs(5))
-            .send()
+            .send(self.client.head(&url).timeout(Duration::from_secs(5)))
             .await
         {
             Ok(response) => response.status().is_success(),
@@ -190,26 +693,60 @@ impl PyPiLicenseRepository {
 #[async_trait]
 impl LicenseRepository for PyPiLicenseRepository {
     async fn fetch_license_info(&self, package_name: &str, version: &str) -> Result<PyPiMetadata> {
-        let package_info = self.fetch_with_retry(package_name, version).await?;
+        let package_info = self
+            .fetch_with_retry(package_name, version)
+            .await
+            .map_err(|e| UvSbomError::license_fetch(package_name, e))?;
 
         let sha256_hash = package_info
             .urls
             .iter()
             .find_map(|url| url.digests.sha256.clone());
+        let yanked = package_info.info.yank();
+        let info = package_info.info;
+        let links = PackageLinks::new(
+            info.home_page.as_deref(),
+            info.project_urls
+                .iter()
+                .flatten()
+                .filter_map(|(label, url)| Some((label.as_str(), url.as_deref()?))),
+        );
+        let authorship = PackageAuthorship::new(info.author, info.author_email, info.maintainer);
 
-        Ok((
-            package_info.info.license,
-            package_info.info.license_expression,
-            package_info.info.classifiers,
-            package_info.info.summary,
+        Ok(PyPiMetadata {
+            license: info.license,
+            license_expression: info.license_expression,
+            classifiers: info.classifiers,
+            description: info.summary,
             sha256_hash,
-        ))
+            yanked,
+            authorship,
+            links,
+        })
+    }
+
+    fn license_priority(&self) -> LicensePriority {
+        self.priority
+    }
+
+    /// Probes the index and, when enabled, the pypi.org fallback
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        let mut probes = vec![self.client.probe(&self.base_url).await];
+        if let Some(fallback_url) = &self.fallback_url {
+            probes.push(self.client.probe(fallback_url).await);
+        }
+        probes
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::http_client::is_request_limit_error;
+    use super::super::test_server::{
+        spawn_http_server, spawn_recording_http_server, spawn_scripted_server,
+    };
     use super::*;
+    use crate::shared::error::LicenseFetchErrorKind;
 
     #[test]
     fn test_pypi_client_creation() {
@@ -279,6 +816,671 @@ mod tests {
         assert!(package_info.urls[0].digests.sha256.is_none());
     }
 
+    #[test]
+    fn test_parse_core_metadata_headers() {
+        let metadata = concat!(
+            "Metadata-Version: 2.1\n",
+            "Name: bigdoc\n",
+            "Version: 1.0.0\n",
+            "Summary: A package with a huge description\n",
+            "License: BSD 3-Clause License\n",
+            "        Copyright (c) Example\n",
+            "Classifier: License :: OSI Approved :: BSD License\n",
+            "Classifier: Programming Language :: Python :: 3\n",
+            "\n",
+            "License: Not-A-Header\n",
+        );
+
+        let info = parse_core_metadata(metadata);
+        assert_eq!(
+            info.license,
+            Some("BSD 3-Clause License\nCopyright (c) Example".to_string())
+        );
+        assert_eq!(
+            info.summary,
+            Some("A package with a huge description".to_string())
+        );
+        assert_eq!(info.license_expression, None);
+        assert_eq!(info.classifiers.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_core_metadata_license_expression() {
+        let info = parse_core_metadata("Name: pkg\nLicense-Expression: Apache-2.0\n");
+        assert_eq!(info.license_expression, Some("Apache-2.0".to_string()));
+        assert_eq!(info.license, None);
+    }
+
+    #[test]
+    fn test_select_release_file_matches_wheel_with_metadata() {
+        let json = r#"{
+            "files": [
+                {"filename": "big_doc-1.0.0.tar.gz", "url": "http://x/a", "hashes": {}},
+                {"filename": "big_doc-1.0.0-py3-none-any.whl", "url": "http://x/b",
+                 "hashes": {"sha256": "feed"}, "core-metadata": false},
+                {"filename": "big_doc-1.0.0-py3-none-any.whl", "url": "http://x/c",
+                 "hashes": {"sha256": "beef"}, "data-dist-info-metadata": {"sha256": "00"}},
+                {"filename": "big_doc-1.0.1-py3-none-any.whl", "url": "http://x/d",
+                 "hashes": {}, "core-metadata": true}
+            ]
+        }"#;
+        let index: SimpleIndex = serde_json::from_str(json).unwrap();
+        let file = select_release_file(&index.files, "big-doc", "1.0.0").unwrap();
+        assert_eq!(file.url, "http://x/c");
+        assert!(select_release_file(&index.files, "big-doc", "2.0.0").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_small_response_uses_json_api() {
+        let json = r#"{"info": {"license": "MIT", "summary": "Small"},
+            "urls": [{"digests": {"sha256": "abc"}}]}"#;
+        let base_url = spawn_http_server(|_| {
+            vec![(
+                "/pypi/small/1.0.0/json".to_string(),
+                "application/json".to_string(),
+                json.as_bytes().to_vec(),
+            )]
+        });
+
+        let client = PyPiLicenseRepository::with_base_url(&base_url).unwrap();
+        let PyPiMetadata {
+            license,
+            description: summary,
+            sha256_hash: sha256,
+            ..
+        } = client.fetch_license_info("small", "1.0.0").await.unwrap();
+
+        assert_eq!(license, Some("MIT".to_string()));
+        assert_eq!(summary, Some("Small".to_string()));
+        assert_eq!(sha256, Some("abc".to_string()));
+        assert_eq!(client.bytes_downloaded(), json.len() as u64);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_reads_author_and_maintainer() {
+        let base_url = spawn_http_server(|_| {
+            vec![
+                (
+                    "/pypi/requests/2.31.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"author": "Kenneth Reitz", "author_email": "me@kennethreitz.org",
+                        "maintainer": ""}, "urls": []}"#
+                        .to_vec(),
+                ),
+                (
+                    "/pypi/anon/1.0.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"author": "", "author_email": null, "maintainer": "UNKNOWN"},
+                        "urls": []}"#
+                        .to_vec(),
+                ),
+            ]
+        });
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_fields(LicenseFetchFields::LicenseOnly);
+
+        let authorship = client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .authorship;
+        assert_eq!(authorship.author(), Some("Kenneth Reitz"));
+        assert_eq!(authorship.supplier(), Some("Kenneth Reitz"));
+
+        let authorship = client
+            .fetch_license_info("anon", "1.0.0")
+            .await
+            .unwrap()
+            .authorship;
+        assert_eq!(authorship, PackageAuthorship::default());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_reads_project_urls() {
+        let base_url = spawn_http_server(|_| {
+            vec![
+                (
+                    "/pypi/requests/2.31.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"home_page": "https://requests.readthedocs.io",
+                        "project_urls": {"Documentation": "https://requests.readthedocs.io",
+                        "Source": "https://github.com/psf/requests", "Changelog": null}},
+                        "urls": []}"#
+                        .to_vec(),
+                ),
+                (
+                    "/pypi/bare/1.0.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"home_page": "", "project_urls": null}, "urls": []}"#.to_vec(),
+                ),
+                (
+                    "/pypi/shady/1.0.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"project_urls": {"Homepage": "javascript:alert(1)"}},
+                        "urls": []}"#
+                        .to_vec(),
+                ),
+            ]
+        });
+        let client = PyPiLicenseRepository::with_base_url(&base_url).unwrap();
+
+        let links = client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .links;
+        let types: Vec<&str> = links.refs().iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(types, ["website", "vcs", "documentation"]);
+        assert_eq!(links.refs()[1].1, "https://github.com/psf/requests");
+
+        let links = client
+            .fetch_license_info("bare", "1.0.0")
+            .await
+            .unwrap()
+            .links;
+        assert_eq!(links, PackageLinks::default());
+
+        let links = client
+            .fetch_license_info("shady", "1.0.0")
+            .await
+            .unwrap()
+            .links;
+        assert_eq!(links, PackageLinks::default());
+    }
+
+    #[tokio::test]
+    async fn test_enrich_with_license_tells_empty_license_from_fetch_error() {
+        let base_url = spawn_http_server(|_| {
+            vec![
+                (
+                    "/pypi/bare/1.0.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"license": "", "summary": "Bare"}, "urls": []}"#.to_vec(),
+                ),
+                (
+                    "/pypi/garbled/1.0.0/json".to_string(),
+                    "application/json".to_string(),
+                    b"{\"info\": ".to_vec(),
+                ),
+            ]
+        });
+        let client = PyPiLicenseRepository::with_base_url(&base_url).unwrap();
+
+        // A 200 response without license metadata succeeds with no license
+        let info = client.enrich_with_license("bare", "1.0.0").await.unwrap();
+        assert_eq!(info.license_text(), None);
+        assert_eq!(info.description(), Some("Bare"));
+
+        // A response that cannot be read is an error, not a missing license
+        assert!(client
+            .enrich_with_license("garbled", "1.0.0")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_enrich_with_license_applies_license_priority() {
+        use crate::adapters::outbound::network::CachingPyPiLicenseRepository;
+        use crate::sbom_generation::policies::LicenseResolution;
+
+        let base_url = spawn_http_server(|_| {
+            vec![(
+                "/pypi/dual/1.0.0/json".to_string(),
+                "application/json".to_string(),
+                br#"{"info": {"license": "BSD-3-Clause", "classifiers": [
+                    "License :: OSI Approved :: BSD License"]}, "urls": []}"#
+                    .to_vec(),
+            )]
+        });
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_license_priority(LicensePriority::from_policy(
+                LicenseResolution::ClassifierFirst,
+            ));
+
+        // The caching decorator keeps the policy of the repository it wraps
+        let info = CachingPyPiLicenseRepository::new(client)
+            .enrich_with_license("dual", "1.0.0")
+            .await
+            .unwrap();
+        assert_eq!(info.license_text(), Some("BSD License"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_reports_yanked_release() {
+        let base_url = spawn_http_server(|_| {
+            vec![
+                (
+                    "/pypi/urllib3/2.0.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"license": "MIT", "yanked": true,
+                        "yanked_reason": "Broken on Python 3.7"}, "urls": []}"#
+                        .to_vec(),
+                ),
+                (
+                    "/pypi/idna/3.5/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"license": "BSD", "yanked": true, "yanked_reason": null},
+                        "urls": []}"#
+                        .to_vec(),
+                ),
+                (
+                    "/pypi/requests/2.31.0/json".to_string(),
+                    "application/json".to_string(),
+                    br#"{"info": {"license": "Apache-2.0", "yanked": false}, "urls": []}"#.to_vec(),
+                ),
+            ]
+        });
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_fields(LicenseFetchFields::LicenseOnly);
+
+        let yanked = client
+            .fetch_license_info("urllib3", "2.0.0")
+            .await
+            .unwrap()
+            .yanked;
+        assert_eq!(yanked, Some("Broken on Python 3.7".to_string()));
+        let yanked = client
+            .fetch_license_info("idna", "3.5")
+            .await
+            .unwrap()
+            .yanked;
+        assert_eq!(yanked, Some(String::new()));
+        let yanked = client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .yanked;
+        assert_eq!(yanked, None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_requests_normalized_name() {
+        let base_url = spawn_http_server(|_| {
+            vec![(
+                "/pypi/ruamel-yaml/0.18.5/json".to_string(),
+                "application/json".to_string(),
+                br#"{"info": {"license": "MIT"}, "urls": []}"#.to_vec(),
+            )]
+        });
+
+        let client = PyPiLicenseRepository::with_base_url(&base_url).unwrap();
+        let license = client
+            .fetch_license_info("ruamel.yaml", "0.18.5")
+            .await
+            .unwrap()
+            .license;
+
+        assert_eq!(license, Some("MIT".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_license_only_skips_description() {
+        // A large response: long description plus a releases map with many files
+        let releases: Vec<String> = (0..2000)
+            .map(|i| {
+                format!(
+                    r#""1.0.{}": [{{"filename": "big-1.0.{}.tar.gz", "digests": {{"sha256": "{:064x}"}}}}]"#,
+                    i, i, i
+                )
+            })
+            .collect();
+        let json = format!(
+            r#"{{"info": {{"license": "", "classifiers": ["License :: OSI Approved :: MIT License"],
+                "summary": "Big", "description": "{}"}},
+                "releases": {{{}}},
+                "urls": [{{"digests": {{"sha256": "abc"}}}}]}}"#,
+            "x".repeat(512 * 1024),
+            releases.join(",")
+        );
+        assert!(json.len() > 512 * 1024);
+        let base_url = spawn_http_server(move |_| {
+            vec![(
+                "/pypi/big/1.0.0/json".to_string(),
+                "application/json".to_string(),
+                json.into_bytes(),
+            )]
+        });
+
+        let full = PyPiLicenseRepository::with_base_url(&base_url).unwrap();
+        let minimal = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_fields(LicenseFetchFields::LicenseOnly);
+        let PyPiMetadata {
+            classifiers,
+            description: full_summary,
+            ..
+        } = full.fetch_license_info("big", "1.0.0").await.unwrap();
+        let info = minimal.enrich_with_license("big", "1.0.0").await.unwrap();
+
+        assert_eq!(classifiers.len(), 1);
+        assert_eq!(full_summary.as_deref(), Some("Big"));
+        assert_eq!(info.license_text(), Some("MIT License"));
+        assert_eq!(info.description(), None);
+        assert_eq!(info.sha256_hash(), Some("abc"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_oversized_response_falls_back_to_core_metadata() {
+        let description = "x".repeat(5 * 1024 * 1024);
+        let big_json = format!(
+            r#"{{"info": {{"license": "MIT", "description": "{}"}}, "urls": []}}"#,
+            description
+        );
+        let metadata = "Metadata-Version: 2.1\nName: bigdoc\nVersion: 1.0.0\n\
+Summary: Huge docs\nLicense-Expression: MIT\n\
+Classifier: License :: OSI Approved :: MIT License\n\n"
+            .to_string()
+            + &description;
+
+        let base_url = spawn_http_server(|base| {
+            let simple_index = format!(
+                r#"{{"files": [{{"filename": "bigdoc-1.0.0-py3-none-any.whl",
+                    "url": "{}/files/bigdoc-1.0.0-py3-none-any.whl",
+                    "hashes": {{"sha256": "cafe"}}, "core-metadata": true,
+                    "yanked": "Broken docs"}}]}}"#,
+                base
+            );
+            vec![
+                (
+                    "/pypi/bigdoc/1.0.0/json".to_string(),
+                    "application/json".to_string(),
+                    big_json.into_bytes(),
+                ),
+                (
+                    "/simple/bigdoc/".to_string(),
+                    "application/vnd.pypi.simple.v1+json".to_string(),
+                    simple_index.into_bytes(),
+                ),
+                (
+                    "/files/bigdoc-1.0.0-py3-none-any.whl.metadata".to_string(),
+                    "text/plain".to_string(),
+                    metadata.into_bytes(),
+                ),
+            ]
+        });
+
+        let client = PyPiLicenseRepository::with_base_url(&base_url).unwrap();
+        let PyPiMetadata {
+            license,
+            license_expression,
+            classifiers,
+            description: summary,
+            sha256_hash: sha256,
+            yanked,
+            ..
+        } = client.fetch_license_info("bigdoc", "1.0.0").await.unwrap();
+
+        assert_eq!(license, None);
+        assert_eq!(license_expression, Some("MIT".to_string()));
+        assert_eq!(classifiers, vec!["License :: OSI Approved :: MIT License"]);
+        assert_eq!(summary, Some("Huge docs".to_string()));
+        assert_eq!(sha256, Some("cafe".to_string()));
+        assert_eq!(yanked, Some("Broken docs".to_string()));
+        // Neither the oversized JSON nor the metadata description is downloaded in full
+        assert!(client.bytes_downloaded() < 1024 * 1024);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_stops_at_request_ceiling_without_retrying() {
+        let json = r#"{"info": {"license": "MIT"}, "urls": []}"#;
+        let base_url = spawn_http_server(|_| {
+            ["first", "second"]
+                .iter()
+                .map(|name| {
+                    (
+                        format!("/pypi/{}/1.0.0/json", name),
+                        "application/json".to_string(),
+                        json.as_bytes().to_vec(),
+                    )
+                })
+                .collect()
+        });
+
+        let counter = RequestCounter::new(Some(1));
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_request_counter(counter.clone());
+
+        assert!(client.fetch_license_info("first", "1.0.0").await.is_ok());
+        let err = client
+            .fetch_license_info("second", "1.0.0")
+            .await
+            .unwrap_err();
+
+        assert!(is_request_limit_error(&err));
+        assert_eq!(counter.total(), 1);
+        // The refused request is not retried
+        assert_eq!(counter.refused(), 1);
+    }
+
+    fn fast_retry() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_retries_transient_failures() {
+        let (base_url, served) = spawn_scripted_server(vec![
+            ("503 Service Unavailable", Vec::new()),
+            ("429 Too Many Requests", Vec::new()),
+            (
+                "200 OK",
+                br#"{"info": {"license": "MIT"}, "urls": []}"#.to_vec(),
+            ),
+        ]);
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_retry_config(fast_retry());
+
+        let license = client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .license;
+
+        assert_eq!(license.as_deref(), Some("MIT"));
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_does_not_retry_not_found() {
+        let (base_url, served) = spawn_scripted_server(vec![("404 Not Found", Vec::new())]);
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_retry_config(fast_retry());
+
+        let err = client
+            .fetch_license_info("no-such-package", "1.0.0")
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("404"));
+        assert_eq!(
+            LicenseFetchErrorKind::of(&err),
+            LicenseFetchErrorKind::NotFound
+        );
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_reports_rate_limit_after_retries() {
+        let (base_url, served) =
+            spawn_scripted_server(vec![("429 Too Many Requests", Vec::new()); 3]);
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_retry_config(fast_retry());
+
+        let err = client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            LicenseFetchErrorKind::of(&err),
+            LicenseFetchErrorKind::RateLimited
+        );
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    fn license_route(path: &str, license: &str) -> Vec<(String, String, Vec<u8>)> {
+        vec![(
+            path.to_string(),
+            "application/json".to_string(),
+            format!(r#"{{"info": {{"license": "{}"}}, "urls": []}}"#, license).into_bytes(),
+        )]
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_from_private_index() {
+        let (index_url, requests) =
+            spawn_recording_http_server(|_| license_route("/pypi/internal-lib/1.0.0/json", "MIT"));
+        let client = PyPiLicenseRepository::new()
+            .unwrap()
+            .with_index_url(&format!("{}/", index_url))
+            .unwrap();
+
+        let license = client
+            .fetch_license_info("internal-lib", "1.0.0")
+            .await
+            .unwrap()
+            .license;
+
+        assert_eq!(license, Some("MIT".to_string()));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_suffix_is_sent() {
+        let (base_url, requests) =
+            spawn_recording_http_server(|_| license_route("/pypi/requests/2.31.0/json", "MIT"));
+        let network = NetworkConfig {
+            user_agent_suffix: Some("(+mailto:secops@example.com)".to_string()),
+            ..Default::default()
+        };
+        let client = PyPiLicenseRepository::with_base_url(&base_url)
+            .unwrap()
+            .with_network_config(&network)
+            .unwrap();
+
+        client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap();
+
+        let expected = format!(
+            "user-agent: uv-sbom/{} (+mailto:secops@example.com)",
+            env!("CARGO_PKG_VERSION")
+        );
+        assert!(requests.lock().unwrap()[0].contains(&expected));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_falls_back_to_pypi_on_not_found() {
+        let (index_url, index_requests) = spawn_recording_http_server(|_| Vec::new());
+        let (pypi_url, pypi_requests) = spawn_recording_http_server(|_| {
+            license_route("/pypi/requests/2.31.0/json", "Apache-2.0")
+        });
+        let mut client = PyPiLicenseRepository::with_base_url(&index_url)
+            .unwrap()
+            .with_credentials(Some(IndexCredentials::new(
+                "user",
+                Some("pass".to_string()),
+            )))
+            .with_retry_config(fast_retry());
+        client.fallback_url = Some(pypi_url);
+
+        let license = client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap()
+            .license;
+
+        assert_eq!(license, Some("Apache-2.0".to_string()));
+        assert_eq!(index_requests.lock().unwrap().len(), 1);
+        let pypi_requests = pypi_requests.lock().unwrap();
+        assert_eq!(pypi_requests.len(), 1);
+        // Index credentials are never sent to the fallback
+        assert!(!pypi_requests[0]
+            .iter()
+            .any(|line| line.starts_with("authorization:")));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_not_found_without_fallback() {
+        let (index_url, _) = spawn_recording_http_server(|_| Vec::new());
+        let client = PyPiLicenseRepository::with_base_url(&index_url)
+            .unwrap()
+            .with_retry_config(fast_retry())
+            .with_pypi_fallback(false);
+
+        let err = client
+            .fetch_license_info("requests", "2.31.0")
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("404"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_license_info_sends_index_credentials() {
+        let (index_url, requests) =
+            spawn_recording_http_server(|_| license_route("/pypi/internal-lib/1.0.0/json", "MIT"));
+        let client = PyPiLicenseRepository::new()
+            .unwrap()
+            .with_index_url(&index_url)
+            .unwrap()
+            .with_credentials(Some(IndexCredentials::new(
+                "user",
+                Some("pass".to_string()),
+            )));
+
+        client
+            .fetch_license_info("internal-lib", "1.0.0")
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert!(requests[0]
+            .iter()
+            .any(|line| line == "authorization: Basic dXNlcjpwYXNz"));
+    }
+
+    #[test]
+    fn test_with_index_url_rejects_unsupported_scheme() {
+        let client = PyPiLicenseRepository::new().unwrap();
+
+        assert!(client
+            .clone()
+            .with_index_url("ftp://mirror.example.com")
+            .is_err());
+        assert!(client.with_index_url("not a url").is_err());
+    }
+
+    #[test]
+    fn test_with_pypi_fallback_is_noop_for_pypi() {
+        let client = PyPiLicenseRepository::new()
+            .unwrap()
+            .with_pypi_fallback(true);
+
+        assert!(client.fallback_url.is_none());
+    }
+
+    #[test]
+    fn test_index_credentials_debug_redacts_password() {
+        let credentials = IndexCredentials::new("user", Some("s3cret".to_string()));
+
+        let debug = format!("{:?}", credentials);
+
+        assert!(debug.contains("user"));
+        assert!(!debug.contains("s3cret"));
+    }
+
     // Integration tests - require network access
     // Uncomment to run with real PyPI API
     // #[tokio::test]
@@ -1,4 +1,8 @@
-use crate::ports::outbound::{MaintenanceInfo, MaintenanceRepository};
+use super::http_client::{CountingHttpClient, NetworkConfig, RequestCounter};
+use super::retry::RetryConfig;
+use crate::ports::outbound::{EndpointProbe, MaintenanceInfo, MaintenanceRepository};
+use crate::sbom_generation::domain::PackageName;
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate};
@@ -23,89 +27,100 @@ struct PyPiUploadEntry {
 /// retrieve the latest release date, used for abandoned-package detection.
 #[derive(Clone)]
 pub struct PyPiMaintenanceRepository {
-    client: reqwest::Client,
+    client: CountingHttpClient,
+    retry: RetryConfig,
 }
 
 impl PyPiMaintenanceRepository {
-    const MAX_RETRIES: u32 = 3;
     // 10 MB — well above any realistic PyPI package metadata response
     const MAX_RESPONSE_BYTES: usize = 10 * 1024 * 1024;
 
     /// Creates a new PyPI maintenance repository with default configuration
     pub fn new() -> Result<Self> {
-        let version = env!("CARGO_PKG_VERSION");
-        let user_agent = format!("uv-sbom/{}", version);
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(10))
-            .user_agent(user_agent)
-            .build()?;
+        let client = CountingHttpClient::new(Duration::from_secs(10))?;
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            retry: RetryConfig::default(),
+        })
+    }
+
+    /// Replaces the retry policy for transient failures
+    pub fn with_retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Counts requests against a counter shared with the other network adapters
+    pub fn with_request_counter(mut self, counter: RequestCounter) -> Self {
+        self.client = self.client.with_counter(counter);
+        self
+    }
+
+    /// Applies the proxy, CA bundle and timeout settings
+    pub fn with_network_config(mut self, network: &NetworkConfig) -> Result<Self> {
+        self.client = self.client.with_network_config(network)?;
+        Ok(self)
     }
 
     /// Validates a URL component to prevent injection attacks
     fn validate_url_component(component: &str, component_type: &str) -> Result<()> {
         if component.contains('/') || component.contains('\\') {
-            anyhow::bail!(
+            return Err(UvSbomError::other(format!(
                 "Security: {} contains path separators which are not allowed",
                 component_type
-            );
+            )));
         }
         if component.contains("..") {
-            anyhow::bail!(
+            return Err(UvSbomError::other(format!(
                 "Security: {} contains '..' which is not allowed",
                 component_type
-            );
+            )));
         }
         if component.contains('#') || component.contains('?') || component.contains('@') {
-            anyhow::bail!(
+            return Err(UvSbomError::other(format!(
                 "Security: {} contains URL-unsafe characters",
                 component_type
-            );
+            )));
         }
         Ok(())
     }
 
     async fn fetch_from_pypi(&self, package_name: &str) -> Result<PyPiPackageResponse> {
         Self::validate_url_component(package_name, "Package name")?;
-        let encoded = urlencoding::encode(package_name);
+        let normalized = PackageName::normalize(package_name);
+        let encoded = urlencoding::encode(&normalized);
         let url = format!("https://pypi.org/pypi/{}/json", encoded);
 
-        let response = self.client.get(&url).send().await?;
-        if !response.status().is_success() {
-            anyhow::bail!("PyPI API returned status code {}", response.status());
+        let response = self.client.send(self.client.get(&url)).await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(UvSbomError::http_status(
+                status,
+                format!("PyPI API returned status code {}", status),
+            ));
         }
         // Reject oversized responses before allocating memory
         if let Some(len) = response.content_length() {
             if len as usize > Self::MAX_RESPONSE_BYTES {
-                anyhow::bail!("PyPI API response too large: {} bytes", len);
+                return Err(UvSbomError::other(format!(
+                    "PyPI API response too large: {} bytes",
+                    len
+                )));
             }
         }
         let bytes = response.bytes().await?;
         if bytes.len() > Self::MAX_RESPONSE_BYTES {
-            anyhow::bail!(
+            return Err(UvSbomError::other(format!(
                 "PyPI API response exceeded {} byte limit",
                 Self::MAX_RESPONSE_BYTES
-            );
+            )));
         }
         Ok(serde_json::from_slice::<PyPiPackageResponse>(&bytes)?)
     }
 
     async fn fetch_with_retry(&self, package_name: &str) -> Result<PyPiPackageResponse> {
-        let mut last_error = None;
-        for attempt in 1..=Self::MAX_RETRIES {
-            match self.fetch_from_pypi(package_name).await {
-                Ok(result) => return Ok(result),
-                Err(e) => {
-                    last_error = Some(e);
-                    if attempt < Self::MAX_RETRIES {
-                        // Linear back-off: 100 ms, 200 ms — matches PyPiLicenseRepository
-                        tokio::time::sleep(Duration::from_millis(100 * attempt as u64)).await;
-                    }
-                }
-            }
-        }
-        Err(last_error.unwrap())
+        self.retry.run(|| self.fetch_from_pypi(package_name)).await
     }
 
     /// Parses the latest release date from a PyPI package response.
@@ -132,6 +147,10 @@ impl MaintenanceRepository for PyPiMaintenanceRepository {
             last_release_date: Self::parse_last_release_date(&resp),
         })
     }
+
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        vec![self.client.probe("https://pypi.org").await]
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,205 @@
+use super::http_client::is_request_limit_error;
+use crate::shared::error::{LicenseFetchErrorKind, UvSbomError};
+use crate::shared::Result;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::BuildHasher;
+use std::time::Duration;
+
+/// Retry policy for transient network failures, shared by the network adapters
+///
+/// Rate limiting (429), server errors (5xx) and connection failures are
+/// retried with exponential backoff and jitter. Every other error, including
+/// 404 and other 4xx responses, fails on the first attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for each retry after that
+    pub base_delay: Duration,
+    /// Upper bound for a single delay
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: Self::DEFAULT_MAX_RETRIES + 1,
+            base_delay: Duration::from_millis(Self::DEFAULT_BASE_DELAY_MS),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryConfig {
+    pub const DEFAULT_MAX_RETRIES: u32 = 3;
+    pub const DEFAULT_BASE_DELAY_MS: u64 = 200;
+
+    /// Builds a policy from the `network` config file settings, falling back
+    /// to the defaults for missing values
+    pub fn from_settings(max_retries: Option<u32>, base_delay_ms: Option<u64>) -> Self {
+        let default = Self::default();
+        Self {
+            max_attempts: max_retries.map_or(default.max_attempts, |r| r.saturating_add(1)),
+            base_delay: base_delay_ms.map_or(default.base_delay, Duration::from_millis),
+            ..default
+        }
+    }
+
+    /// Runs `operation`, retrying transient failures. The error of the last
+    /// attempt is returned once the attempts are used up.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt >= self.max_attempts || !is_transient(&e) => return Err(e),
+                Err(_) => {
+                    tokio::time::sleep(self.delay(attempt)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Delay after failed attempt `attempt` (1-based): the exponential delay,
+    /// capped at `max_delay`, with up to half of it replaced by random jitter
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt - 1).unwrap_or(u32::MAX);
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        let half = delay / 2;
+        half + half.mul_f64(jitter())
+    }
+}
+
+/// Returns a pseudo-random value in `[0, 1)`.
+///
+/// Each `RandomState` is seeded differently, which is all the randomness
+/// spreading out retries needs.
+fn jitter() -> f64 {
+    let bits = RandomState::new().hash_one(0u8) >> 11;
+    bits as f64 / (1u64 << 53) as f64
+}
+
+/// Returns true for failures that may succeed when retried
+fn is_transient(error: &UvSbomError) -> bool {
+    match error {
+        // Retrying cannot succeed once the request ceiling is reached
+        _ if is_request_limit_error(error) => false,
+        // Rate limits and timeouts
+        _ if LicenseFetchErrorKind::of(error).is_retryable() => true,
+        UvSbomError::HttpStatus { status, .. } => status.is_server_error(),
+        UvSbomError::Http(e) => e.is_connect() || e.is_request() || e.is_body(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast_retry(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+        }
+    }
+
+    fn status_error(status: StatusCode) -> UvSbomError {
+        UvSbomError::http_status(status, format!("returned status code {}", status))
+    }
+
+    #[tokio::test]
+    async fn test_run_retries_transient_status_until_success() {
+        let calls = AtomicU32::new(0);
+
+        let result = fast_retry(4)
+            .run(|| async {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(status_error(StatusCode::SERVICE_UNAVAILABLE)),
+                    1 => Err(status_error(StatusCode::TOO_MANY_REQUESTS)),
+                    _ => Ok("ok"),
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "ok");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_returns_last_error_when_attempts_are_used_up() {
+        let calls = AtomicU32::new(0);
+
+        let err = fast_retry(3)
+            .run(|| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(status_error(StatusCode::BAD_GATEWAY))
+            })
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("502"));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_fails_fast_on_permanent_errors() {
+        let errors: [fn() -> UvSbomError; 4] = [
+            || status_error(StatusCode::NOT_FOUND),
+            || status_error(StatusCode::UNPROCESSABLE_ENTITY),
+            || UvSbomError::other("Security: Package name contains path separators"),
+            || UvSbomError::RequestLimitReached {
+                limit: 1,
+                host: "pypi.org".to_string(),
+            },
+        ];
+        for error in errors {
+            let calls = AtomicU32::new(0);
+
+            let result = fast_retry(4)
+                .run(|| async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err::<(), _>(error())
+                })
+                .await;
+
+            assert!(result.is_err());
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+        }
+    }
+
+    #[test]
+    fn test_delay_grows_exponentially_with_jitter_and_cap() {
+        let retry = RetryConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+
+        for (attempt, full) in [(1, 100), (2, 200), (3, 400), (4, 800), (5, 1000), (9, 1000)] {
+            let delay = retry.delay(attempt);
+            assert!(delay >= Duration::from_millis(full / 2), "{:?}", delay);
+            assert!(delay <= Duration::from_millis(full), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn test_from_settings() {
+        let retry = RetryConfig::from_settings(Some(0), Some(50));
+        assert_eq!(retry.max_attempts, 1);
+        assert_eq!(retry.base_delay, Duration::from_millis(50));
+
+        assert_eq!(
+            RetryConfig::from_settings(None, None),
+            RetryConfig::default()
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Local HTTP server for network adapter tests
+
+/// Minimal HTTP/1.1 server answering each request from a fixed route table.
+///
+/// `routes` receives the server's base URL so bodies can link back to it. A path
+/// listed more than once answers successive requests with its entries in order,
+/// repeating the last one.
+pub(super) fn spawn_http_server(
+    routes: impl FnOnce(&str) -> Vec<(String, String, Vec<u8>)>,
+) -> String {
+    spawn_recording_http_server(routes).0
+}
+
+/// Like [`spawn_http_server`], additionally recording the request line and
+/// headers of every request, e.g. `["GET /a HTTP/1.1", "authorization: Basic ..."]`
+///
+/// Header names are lowercased.
+pub(super) fn spawn_recording_http_server(
+    routes: impl FnOnce(&str) -> Vec<(String, String, Vec<u8>)>,
+) -> (String, std::sync::Arc<std::sync::Mutex<Vec<Vec<String>>>>) {
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::{Arc, Mutex};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let routes = routes(&base_url);
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { break };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).is_err() {
+                continue;
+            }
+            let mut lines = vec![request_line.trim_end().to_string()];
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok() && line.trim_end() != "" {
+                if let Some((name, value)) = line.trim_end().split_once(':') {
+                    lines.push(format!("{}:{}", name.to_ascii_lowercase(), value));
+                }
+                line.clear();
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or("");
+            let mut recorded = recorded.lock().unwrap();
+            let earlier = recorded
+                .iter()
+                .filter(|lines: &&Vec<String>| lines[0].split_whitespace().nth(1) == Some(path))
+                .count();
+            recorded.push(lines);
+            drop(recorded);
+
+            let matching: Vec<_> = routes.iter().filter(|(p, _, _)| p == path).collect();
+            let (status, content_type, body) = match matching.get(earlier).or(matching.last()) {
+                Some((_, content_type, body)) => ("200 OK", content_type.as_str(), body.as_slice()),
+                None => ("404 Not Found", "text/plain", &b""[..]),
+            };
+            let header = format!(
+                "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                content_type,
+                body.len()
+            );
+            let _ = stream.write_all(header.as_bytes());
+            let _ = stream.write_all(body);
+        }
+    });
+
+    (base_url, requests)
+}
+
+/// Like [`spawn_http_server`], but answering each request on its own thread
+/// after `delay`, so concurrent requests overlap
+///
+/// Returns the base URL and the highest number of requests in flight at once.
+pub(super) fn spawn_slow_http_server(
+    delay: std::time::Duration,
+    routes: impl FnOnce(&str) -> Vec<(String, String, Vec<u8>)>,
+) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let routes = Arc::new(routes(&base_url));
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let recorded_peak = peak.clone();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { break };
+            let (routes, in_flight, peak) = (routes.clone(), in_flight.clone(), peak.clone());
+            std::thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() {
+                    return;
+                }
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok() && line.trim_end() != "" {
+                    line.clear();
+                }
+
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(current, Ordering::SeqCst);
+                std::thread::sleep(delay);
+                // Released before answering, so the client cannot start the next
+                // request while this one is still counted
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                let path = request_line.split_whitespace().nth(1).unwrap_or("");
+                let (status, content_type, body) = match routes.iter().find(|(p, _, _)| p == path) {
+                    Some((_, content_type, body)) => {
+                        ("200 OK", content_type.as_str(), body.as_slice())
+                    }
+                    None => ("404 Not Found", "text/plain", &b""[..]),
+                };
+                let header = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    content_type,
+                    body.len()
+                );
+                let _ = stream.write_all(header.as_bytes());
+                let _ = stream.write_all(body);
+            });
+        }
+    });
+
+    (base_url, recorded_peak)
+}
+
+/// HTTP/1.1 server answering requests in arrival order from `responses`,
+/// regardless of path. The last response is repeated once the list runs out.
+///
+/// Returns the base URL and the number of requests served so far.
+pub(super) fn spawn_scripted_server(
+    responses: Vec<(&'static str, Vec<u8>)>,
+) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let served = Arc::new(AtomicUsize::new(0));
+    let counter = served.clone();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { break };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok() && line.trim_end() != "" {
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+                line.clear();
+            }
+            let mut body = vec![0; content_length];
+            let _ = reader.read_exact(&mut body);
+
+            let index = counter.fetch_add(1, Ordering::SeqCst);
+            let (status, body) = &responses[index.min(responses.len() - 1)];
+            let header = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                body.len()
+            );
+            let _ = stream.write_all(header.as_bytes());
+            let _ = stream.write_all(body);
+        }
+    });
+
+    (base_url, served)
+}
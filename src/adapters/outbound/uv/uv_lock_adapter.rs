@@ -1,22 +1,32 @@
-use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tokio::time::timeout;
 
 use crate::ports::outbound::uv_lock_simulator::{SimulationResult, UvLockSimulator};
+use crate::shared::error::UvSbomError;
+use crate::shared::Result;
 
 /// Adapter that implements [`UvLockSimulator`] by shelling out to the `uv` CLI.
 ///
 /// Uses a temporary directory strategy: copies `pyproject.toml` and `uv.lock`
 /// to a temp dir, runs `uv lock --upgrade-package <pkg>`, then parses the
 /// resulting lock file to determine resolved versions.
-pub struct UvLockAdapter;
+pub struct UvLockAdapter {
+    data_dir: Option<PathBuf>,
+}
 
 impl UvLockAdapter {
     pub fn new() -> Self {
-        Self
+        Self { data_dir: None }
+    }
+
+    /// Places the temp directory and uv's cache under `data_dir` instead of the
+    /// system temporary directory and the user's home. `None` keeps the defaults.
+    pub fn with_data_dir(mut self, data_dir: Option<PathBuf>) -> Self {
+        self.data_dir = data_dir;
+        self
     }
 
     /// Parse a uv.lock TOML content and return a map of package name → version.
@@ -34,8 +44,8 @@ impl UvLockAdapter {
             version: String,
         }
 
-        let lockfile: UvLock =
-            toml::from_str(content).map_err(|e| anyhow!("Failed to parse uv.lock: {}", e))?;
+        let lockfile: UvLock = toml::from_str(content)
+            .map_err(|e| UvSbomError::other(format!("Failed to parse uv.lock: {}", e)))?;
 
         Ok(lockfile
             .package
@@ -67,8 +77,8 @@ impl UvLockSimulator for UvLockAdapter {
             .unwrap_or(false);
 
         if !uv_available {
-            return Err(anyhow!(
-                "`uv` CLI not found. Install with: curl -LsSf https://astral.sh/uv/install.sh | sh"
+            return Err(UvSbomError::other(
+                "`uv` CLI not found. Install with: curl -LsSf https://astral.sh/uv/install.sh | sh",
             ));
         }
 
@@ -77,44 +87,56 @@ impl UvLockSimulator for UvLockAdapter {
         let lockfile_src = project_path.join("uv.lock");
 
         if !pyproject_src.exists() {
-            return Err(anyhow!(
+            return Err(UvSbomError::other(format!(
                 "pyproject.toml not found in: {}",
                 project_path.display()
-            ));
+            )));
         }
         if !lockfile_src.exists() {
-            return Err(anyhow!("uv.lock not found in: {}", project_path.display()));
+            return Err(UvSbomError::other(format!(
+                "uv.lock not found in: {}",
+                project_path.display()
+            )));
         }
 
         // 3. Create temp directory and copy files into it
-        let temp_dir = tempfile::TempDir::new()
-            .map_err(|e| anyhow!("Failed to create temp directory: {}", e))?;
+        let temp_dir = match &self.data_dir {
+            Some(dir) => tempfile::TempDir::new_in(dir),
+            None => tempfile::TempDir::new(),
+        }
+        .map_err(|e| UvSbomError::other(format!("Failed to create temp directory: {}", e)))?;
 
-        std::fs::copy(&pyproject_src, temp_dir.path().join("pyproject.toml"))
-            .map_err(|e| anyhow!("Failed to copy pyproject.toml to temp dir: {}", e))?;
-        std::fs::copy(&lockfile_src, temp_dir.path().join("uv.lock"))
-            .map_err(|e| anyhow!("Failed to copy uv.lock to temp dir: {}", e))?;
+        std::fs::copy(&pyproject_src, temp_dir.path().join("pyproject.toml")).map_err(|e| {
+            UvSbomError::other(format!("Failed to copy pyproject.toml to temp dir: {}", e))
+        })?;
+        std::fs::copy(&lockfile_src, temp_dir.path().join("uv.lock")).map_err(|e| {
+            UvSbomError::other(format!("Failed to copy uv.lock to temp dir: {}", e))
+        })?;
 
         // 4. Run: uv lock --upgrade-package <package_name> with 60-second timeout
-        let run_output = timeout(
-            Duration::from_secs(60),
-            tokio::process::Command::new("uv")
-                .args(["lock", "--upgrade-package", package_name])
-                .current_dir(temp_dir.path())
-                .output(),
-        )
-        .await
-        .map_err(|_| anyhow!("uv lock command timed out after 60 seconds"))?
-        .map_err(|e| anyhow!("Failed to run uv lock: {}", e))?;
+        let mut command = tokio::process::Command::new("uv");
+        command
+            .args(["lock", "--upgrade-package", package_name])
+            .current_dir(temp_dir.path());
+        if let Some(dir) = &self.data_dir {
+            command.env("UV_CACHE_DIR", dir.join("uv-cache"));
+        }
+        let run_output = timeout(Duration::from_secs(60), command.output())
+            .await
+            .map_err(|_| UvSbomError::other("uv lock command timed out after 60 seconds"))?
+            .map_err(|e| UvSbomError::other(format!("Failed to run uv lock: {}", e)))?;
 
         if !run_output.status.success() {
             let stderr = String::from_utf8_lossy(&run_output.stderr);
-            return Err(anyhow!("uv lock --upgrade-package failed: {}", stderr));
+            return Err(UvSbomError::other(format!(
+                "uv lock --upgrade-package failed: {}",
+                stderr
+            )));
         }
 
         // 5. Parse the resulting uv.lock
         let new_content = std::fs::read_to_string(temp_dir.path().join("uv.lock"))
-            .map_err(|e| anyhow!("Failed to read resulting uv.lock: {}", e))?;
+            .map_err(|e| UvSbomError::other(format!("Failed to read resulting uv.lock: {}", e)))?;
         let new_versions = Self::parse_versions(&new_content)?;
 
         // 6. Find the upgraded version (case-insensitive lookup for robustness)
@@ -123,7 +145,12 @@ impl UvLockSimulator for UvLockAdapter {
             .iter()
             .find(|(k, _)| k.to_lowercase() == package_name_lower)
             .map(|(_, v)| v.clone())
-            .ok_or_else(|| anyhow!("Package '{}' not found in resulting uv.lock", package_name))?;
+            .ok_or_else(|| {
+                UvSbomError::other(format!(
+                    "Package '{}' not found in resulting uv.lock",
+                    package_name
+                ))
+            })?;
 
         // 7. Return result (temp_dir is dropped here, cleaning up automatically)
         Ok(SimulationResult {
@@ -1,7 +1,7 @@
 use crate::ports::outbound::workspace_reader::{WorkspaceMember, WorkspaceReader};
+use crate::shared::error::UvSbomError;
 use crate::shared::security::{read_file_with_security, MAX_FILE_SIZE};
 use crate::shared::Result;
-use anyhow::Context;
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
 
@@ -65,7 +65,11 @@ impl UvWorkspaceReader {
             package: Vec<UvPackage>,
         }
 
-        let lock: UvLock = toml::from_str(content).context("Failed to parse uv.lock")?;
+        let lock: UvLock =
+            toml::from_str(content).map_err(|e| UvSbomError::LockfileParseError {
+                path: workspace_root.join("uv.lock"),
+                details: e.to_string(),
+            })?;
 
         let member_ids = match lock.manifest.and_then(|m| m.members) {
             Some(ids) if !ids.is_empty() => ids,
@@ -85,7 +89,7 @@ impl UvWorkspaceReader {
                         .map(|path| path == member_id)
                         .unwrap_or(false)
                 }) {
-                    let absolute_path = workspace_root.join(&member_id);
+                    let absolute_path = join_lock_path(workspace_root, &member_id);
                     return WorkspaceMember {
                         name: pkg.name.clone(),
                         absolute_path,
@@ -102,7 +106,7 @@ impl UvWorkspaceReader {
                         .and_then(|s| s.local_path())
                         .map(|p| p.to_owned())
                         .unwrap_or_else(|| member_id.clone());
-                    let absolute_path = workspace_root.join(&rel);
+                    let absolute_path = join_lock_path(workspace_root, &rel);
                     return WorkspaceMember {
                         name: pkg.name.clone(),
                         absolute_path,
@@ -115,7 +119,7 @@ impl UvWorkspaceReader {
                     .file_name()
                     .map(|n| n.to_string_lossy().into_owned())
                     .unwrap_or_else(|| member_id.clone());
-                let absolute_path = workspace_root.join(&member_id);
+                let absolute_path = join_lock_path(workspace_root, &member_id);
                 WorkspaceMember {
                     name,
                     absolute_path,
@@ -127,6 +131,17 @@ impl UvWorkspaceReader {
     }
 }
 
+/// Joins a `/`-separated path from `uv.lock` onto `root` one component at a time.
+///
+/// `uv.lock` always uses forward slashes, so joining the raw string would produce
+/// mixed separators such as `C:\ws\packages/alpha` on Windows.
+fn join_lock_path(root: &Path, relative: &str) -> PathBuf {
+    relative
+        .split('/')
+        .filter(|component| !component.is_empty())
+        .fold(root.to_path_buf(), |path, component| path.join(component))
+}
+
 impl Default for UvWorkspaceReader {
     fn default() -> Self {
         Self::new()
@@ -136,8 +151,7 @@ impl Default for UvWorkspaceReader {
 impl WorkspaceReader for UvWorkspaceReader {
     fn read_workspace_members(&self, workspace_root: &Path) -> Result<Vec<WorkspaceMember>> {
         let lock_path = workspace_root.join("uv.lock");
-        let content = read_file_with_security(&lock_path, "uv.lock", MAX_FILE_SIZE)
-            .with_context(|| format!("Failed to read uv.lock at: {}", lock_path.display()))?;
+        let content = read_file_with_security(&lock_path, "uv.lock", MAX_FILE_SIZE)?;
         Self::parse_members(&content, workspace_root)
     }
 }
@@ -341,4 +355,19 @@ source = { registry = "https://pypi.org/simple" }
             .unwrap();
         assert_eq!(alpha.name, "alpha");
     }
+
+    #[test]
+    fn test_join_lock_path_joins_each_component() {
+        let root = PathBuf::from(r"C:\ws");
+        assert_eq!(
+            join_lock_path(&root, "packages/alpha"),
+            root.join("packages").join("alpha")
+        );
+    }
+
+    #[test]
+    fn test_join_lock_path_current_directory() {
+        let root = PathBuf::from(r"\\server\share\ws");
+        assert_eq!(join_lock_path(&root, "."), root.join("."));
+    }
 }
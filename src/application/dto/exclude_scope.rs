@@ -0,0 +1,72 @@
+/// What exclude patterns remove packages from
+///
+/// With `All` (the default) an excluded package is dropped before license
+/// enrichment and the vulnerability check. With `Output` it is still checked
+/// for vulnerabilities and only left out of the component list and the
+/// dependency graph, so excluding an internal package does not hide its CVEs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExcludeScope {
+    /// Excluded packages are skipped entirely (default)
+    #[default]
+    All,
+    /// Excluded packages are hidden from the output but still checked for vulnerabilities
+    Output,
+}
+
+impl std::str::FromStr for ExcludeScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(ExcludeScope::All),
+            "output" => Ok(ExcludeScope::Output),
+            _ => Err(format!(
+                "Invalid exclude scope: {}. Please specify 'output' or 'all'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ExcludeScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExcludeScope::All => write!(f, "all"),
+            ExcludeScope::Output => write!(f, "output"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_exclude_scope_from_str() {
+        assert_eq!(ExcludeScope::from_str("all").unwrap(), ExcludeScope::All);
+        assert_eq!(
+            ExcludeScope::from_str("Output").unwrap(),
+            ExcludeScope::Output
+        );
+    }
+
+    #[test]
+    fn test_exclude_scope_from_str_invalid() {
+        let result = ExcludeScope::from_str("components");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid exclude scope"));
+    }
+
+    #[test]
+    fn test_exclude_scope_display_round_trips() {
+        for scope in [ExcludeScope::All, ExcludeScope::Output] {
+            assert_eq!(ExcludeScope::from_str(&scope.to_string()).unwrap(), scope);
+        }
+    }
+
+    #[test]
+    fn test_exclude_scope_default_is_all() {
+        assert_eq!(ExcludeScope::default(), ExcludeScope::All);
+    }
+}
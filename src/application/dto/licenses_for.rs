@@ -0,0 +1,67 @@
+/// Which packages have their license looked up
+///
+/// With `All` (the default) every package is looked up on the package index.
+/// With `DirectOnly` only the project's direct dependencies are; transitive
+/// packages are still listed as components, just without a license. This
+/// keeps license lookups cheap for very large dependency trees.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LicensesFor {
+    /// Every package is looked up (default)
+    #[default]
+    All,
+    /// Only direct dependencies are looked up
+    DirectOnly,
+}
+
+impl std::str::FromStr for LicensesFor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(LicensesFor::All),
+            "direct-only" => Ok(LicensesFor::DirectOnly),
+            _ => Err(format!(
+                "Invalid license lookup scope: {}. Please specify 'all' or 'direct-only'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for LicensesFor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LicensesFor::All => write!(f, "all"),
+            LicensesFor::DirectOnly => write!(f, "direct-only"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_licenses_for_from_str() {
+        assert_eq!(LicensesFor::from_str("all").unwrap(), LicensesFor::All);
+        assert_eq!(
+            LicensesFor::from_str("Direct-Only").unwrap(),
+            LicensesFor::DirectOnly
+        );
+    }
+
+    #[test]
+    fn test_licenses_for_from_str_invalid() {
+        let result = LicensesFor::from_str("direct");
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Invalid license lookup scope"));
+    }
+
+    #[test]
+    fn test_licenses_for_display_round_trips() {
+        for scope in [LicensesFor::All, LicensesFor::DirectOnly] {
+            assert_eq!(LicensesFor::from_str(&scope.to_string()).unwrap(), scope);
+        }
+    }
+}
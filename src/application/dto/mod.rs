@@ -2,10 +2,14 @@
 ///
 /// DTOs are used to transfer data between the application layer
 /// and adapters, keeping the domain layer isolated.
+mod exclude_scope;
+mod licenses_for;
 mod output_format;
 mod sbom_request;
 mod sbom_response;
 
+pub use exclude_scope::ExcludeScope;
+pub use licenses_for::LicensesFor;
 pub use output_format::OutputFormat;
 #[allow(unused_imports)]
 pub use sbom_request::{SbomRequest, SbomRequestBuilder};
@@ -10,6 +10,20 @@ pub enum OutputFormat {
     Json,
     /// Human-readable Markdown format
     Markdown,
+    /// Self-contained HTML report
+    Html,
+}
+
+impl OutputFormat {
+    /// File name used when the format is written into a directory, e.g. `sbom.md`
+    /// for `--output-dir` and for each member in workspace mode
+    pub fn file_name(self) -> &'static str {
+        match self {
+            OutputFormat::Json => "sbom.json",
+            OutputFormat::Markdown => "sbom.md",
+            OutputFormat::Html => "sbom.html",
+        }
+    }
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -19,8 +33,9 @@ impl std::str::FromStr for OutputFormat {
         match s.to_lowercase().as_str() {
             "json" => Ok(OutputFormat::Json),
             "markdown" | "md" => Ok(OutputFormat::Markdown),
+            "html" => Ok(OutputFormat::Html),
             _ => Err(format!(
-                "Invalid format: {}. Please specify 'json' or 'markdown'",
+                "Invalid format: {}. Please specify 'json', 'markdown' or 'html'",
                 s
             )),
         }
@@ -32,6 +47,7 @@ impl std::fmt::Display for OutputFormat {
         match self {
             OutputFormat::Json => write!(f, "json"),
             OutputFormat::Markdown => write!(f, "markdown"),
+            OutputFormat::Html => write!(f, "html"),
         }
     }
 }
@@ -77,6 +93,12 @@ mod tests {
         assert_eq!(format, OutputFormat::Markdown);
     }
 
+    #[test]
+    fn test_output_format_from_str_html() {
+        assert_eq!(OutputFormat::from_str("html").unwrap(), OutputFormat::Html);
+        assert_eq!(OutputFormat::from_str("HTML").unwrap(), OutputFormat::Html);
+    }
+
     #[test]
     fn test_output_format_from_str_invalid() {
         let result = OutputFormat::from_str("invalid");
@@ -98,6 +120,14 @@ mod tests {
     fn test_output_format_display() {
         assert_eq!(OutputFormat::Json.to_string(), "json");
         assert_eq!(OutputFormat::Markdown.to_string(), "markdown");
+        assert_eq!(OutputFormat::Html.to_string(), "html");
+    }
+
+    #[test]
+    fn test_output_format_file_name() {
+        assert_eq!(OutputFormat::Json.file_name(), "sbom.json");
+        assert_eq!(OutputFormat::Markdown.file_name(), "sbom.md");
+        assert_eq!(OutputFormat::Html.file_name(), "sbom.html");
     }
 
     #[test]
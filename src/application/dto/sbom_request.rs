@@ -1,10 +1,18 @@
+use super::{ExcludeScope, LicensesFor};
 use crate::config::IgnoreCve;
 use crate::i18n::Locale;
+use crate::ports::outbound::LicenseFetchFields;
 use crate::sbom_generation::domain::license_policy::LicensePolicy;
+use crate::sbom_generation::domain::services::TyposquatChecker;
 use crate::sbom_generation::domain::vulnerability::Severity;
-use crate::shared::error::SbomError;
+use crate::sbom_generation::domain::{
+    CopyleftLicenses, LicenseOverride, MarkerEnvironment, VulnerabilityBaseline,
+};
+use crate::sbom_generation::policies::{LicenseCompatibilityPolicy, LicenseResolution};
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// SbomRequest - Internal request DTO for SBOM generation use case
 ///
@@ -18,8 +26,20 @@ pub struct SbomRequest {
     pub include_dependency_info: bool,
     /// Patterns for excluding packages from the SBOM
     pub exclude_patterns: Vec<String>,
+    /// Whether excluded packages are still checked for vulnerabilities
+    pub exclude_scope: ExcludeScope,
+    /// Dependency groups to keep exclusively (`main` is the runtime dependencies).
+    /// Empty means every group that is not in `exclude_groups`.
+    pub only_groups: Vec<String>,
+    /// Dependency groups whose packages are pruned unless another group needs them
+    pub exclude_groups: Vec<String>,
+    /// Environment the SBOM is generated for; packages whose markers can never
+    /// be true in it are pruned. Empty means every platform.
+    pub marker_environment: MarkerEnvironment,
     /// Whether to perform dry-run validation only (skip network operations and output generation)
     pub dry_run: bool,
+    /// In dry-run mode, whether to also check that the network endpoints can be reached
+    pub probe_endpoints: bool,
     /// Whether to check for vulnerabilities using OSV API
     pub check_cve: bool,
     /// Severity threshold for vulnerability filtering
@@ -28,18 +48,114 @@ pub struct SbomRequest {
     pub cvss_threshold: Option<f32>,
     /// CVE IDs to ignore during vulnerability checks
     pub ignore_cves: Vec<IgnoreCve>,
+    /// Whether ignored CVEs are kept in the report as VEX entries
+    pub vex: bool,
+    /// Previously accepted vulnerabilities that do not count toward the threshold
+    pub cve_baseline: VulnerabilityBaseline,
     /// Whether to check license compliance
     pub check_license: bool,
     /// License compliance policy (only used when check_license is true)
     pub license_policy: Option<LicensePolicy>,
+    /// Whether to check dependency licenses against the project's own license
+    pub check_license_compatibility: bool,
+    /// Compatibility matrix (only used when check_license_compatibility is true)
+    pub license_compatibility_policy: LicenseCompatibilityPolicy,
+    /// Project license to evaluate against, overriding the one in pyproject.toml
+    pub project_license: Option<String>,
+    /// Project name whose dependencies are the direct dependencies, overriding the
+    /// one in pyproject.toml (needed when the lockfile is read from stdin)
+    pub project_name: Option<String>,
+    /// Packages (wildcards allowed) whose dependency graph is analyzed instead of the
+    /// project's; empty analyzes the project. Only meaningful with `include_dependency_info`.
+    pub roots: Vec<String>,
+    /// Whether incompatible dependency licenses fail the build
+    pub fail_on_license_incompatibility: bool,
+    /// Licenses set manually per package, applied after license enrichment
+    pub license_overrides: Vec<LicenseOverride>,
+    /// Whether to report copyleft packages reachable through the dependency graph
+    pub check_license_reachability: bool,
+    /// Whether the reachability check also reports packages without a known license
+    pub strict_license_reachability: bool,
+    /// Licenses reported by the reachability check
+    pub copyleft_licenses: CopyleftLicenses,
+    /// Whether to flag packages whose `requires-python` excludes Python versions
+    /// the project supports
+    pub check_python_compat: bool,
+    /// Whether to flag packages whose names are near misses of popular package names
+    pub check_typosquats: bool,
+    /// Largest Damerau-Levenshtein distance the typosquat check reports
+    pub typosquat_max_distance: usize,
+    /// Package names the typosquat check never reports
+    pub ignore_typosquats: Vec<String>,
+    /// Whether typosquat findings fail the build
+    pub fail_on_typosquat: bool,
     /// Whether to suggest direct dependency upgrade versions to fix transitive vulnerabilities.
     /// Only meaningful when `check_cve` is true.
     pub suggest_fix: bool,
+    /// Whether to list the upgrades fixing actionable vulnerabilities, taken from
+    /// the fixed versions reported by OSV. Only meaningful when `check_cve` is true.
+    pub suggest_fixes: bool,
     /// Whether to check for abandoned/unmaintained packages.
     pub check_abandoned: bool,
+    /// Whether to skip all network access. Licenses come from local project
+    /// metadata only; cannot be combined with `check_abandoned`, nor with
+    /// `check_cve` unless `advisory_db` is set.
+    pub offline: bool,
+    /// Local PyPA advisory database the vulnerabilities are (also) read from
+    pub advisory_db: Option<PathBuf>,
+    /// Whether to compare the lockfile with the requirements declared in pyproject.toml
+    pub check_lock: bool,
+    /// Whether a lockfile that is out of date with pyproject.toml fails generation
+    /// instead of producing warnings. Only meaningful when `check_lock` is true.
+    pub strict_lock: bool,
+    /// Whether a lockfile format newer than supported fails generation instead
+    /// of producing a warning and a best-effort parse
+    pub strict_lock_version: bool,
+    /// Whether dependency analysis fails when the project root can be determined
+    /// neither from pyproject.toml nor from the lockfile, instead of being skipped
+    pub strict: bool,
+    /// Metadata fields to resolve per package; `LicenseOnly` leaves descriptions empty.
+    /// Cannot be combined with a report that shows descriptions (Markdown or HTML).
+    pub license_fetch_fields: LicenseFetchFields,
+    /// Packages whose license is looked up; with `DirectOnly` transitive
+    /// packages are listed without a license
+    pub licenses_for: LicensesFor,
+    /// Most package lookups (license lookups and CVE queries, one per package)
+    /// made in the run; the packages beyond it are left unenriched.
+    /// `None` means every package is looked up.
+    pub max_network_requests: Option<usize>,
     /// Inactivity threshold in days for abandoned-package detection.
     /// Only meaningful when `check_abandoned` is true.
     pub abandoned_threshold_days: u64,
+    /// Whether to also check the project's own published name and version for vulnerabilities.
+    /// Only meaningful when `check_cve` is true.
+    pub check_self: bool,
+    /// Whether self-check findings above the threshold count towards a failing exit code.
+    pub self_check_fails_build: bool,
+    /// Base directory for cache, state and temporary files.
+    /// `None` means the system temporary directory is used.
+    pub data_dir: Option<PathBuf>,
+    /// Config file the options were read from, recorded in the SBOM metadata
+    pub config_path: Option<PathBuf>,
+    /// Package index licenses are fetched from, recorded in the SBOM metadata
+    /// without credentials. `None` means pypi.org.
+    pub index_url: Option<String>,
+    /// License resolution policy of the license repository, recorded in the
+    /// SBOM metadata
+    pub license_resolution: LicenseResolution,
+    /// Whether to report generation counts and per-phase timings.
+    pub stats: bool,
+    /// Whether to record the SHA-256 digest of the lockfile in the response.
+    pub lockfile_digest: bool,
+    /// Whether to derive the serial number from the lockfile so that identical
+    /// inputs produce an identical SBOM.
+    pub reproducible: bool,
+    /// Seconds since the Unix epoch to use as the SBOM timestamp in reproducible
+    /// mode, from `SOURCE_DATE_EPOCH`. `None` means the current time is used.
+    pub source_date_epoch: Option<i64>,
+    /// Overall deadline for the license enrichment and vulnerability check phases.
+    /// `None` means generation may run for as long as it takes.
+    pub timeout: Option<Duration>,
     /// Output locale for human-readable formats
     pub locale: Locale,
 }
@@ -93,17 +209,61 @@ impl SbomRequest {
 pub struct SbomRequestBuilder {
     project_path: Option<PathBuf>,
     include_dependency_info: bool,
+    show_descriptions: bool,
     exclude_patterns: Vec<String>,
+    exclude_scope: ExcludeScope,
+    only_groups: Vec<String>,
+    exclude_groups: Vec<String>,
+    marker_environment: MarkerEnvironment,
     dry_run: bool,
+    probe_endpoints: bool,
     check_cve: bool,
     severity_threshold: Option<Severity>,
     cvss_threshold: Option<f32>,
     ignore_cves: Vec<IgnoreCve>,
+    vex: bool,
+    cve_baseline: VulnerabilityBaseline,
     check_license: bool,
     license_policy: Option<LicensePolicy>,
+    check_license_compatibility: bool,
+    license_compatibility_policy: LicenseCompatibilityPolicy,
+    project_license: Option<String>,
+    project_name: Option<String>,
+    roots: Vec<String>,
+    fail_on_license_incompatibility: bool,
+    license_overrides: Vec<LicenseOverride>,
+    check_license_reachability: bool,
+    strict_license_reachability: bool,
+    copyleft_licenses: CopyleftLicenses,
+    check_python_compat: bool,
+    check_typosquats: bool,
+    typosquat_max_distance: usize,
+    ignore_typosquats: Vec<String>,
+    fail_on_typosquat: bool,
     suggest_fix: bool,
+    suggest_fixes: bool,
     check_abandoned: bool,
     abandoned_threshold_days: u64,
+    offline: bool,
+    advisory_db: Option<PathBuf>,
+    check_lock: bool,
+    strict_lock: bool,
+    strict_lock_version: bool,
+    strict: bool,
+    license_fetch_fields: LicenseFetchFields,
+    licenses_for: LicensesFor,
+    max_network_requests: Option<usize>,
+    check_self: bool,
+    self_check_fails_build: bool,
+    data_dir: Option<PathBuf>,
+    config_path: Option<PathBuf>,
+    index_url: Option<String>,
+    license_resolution: LicenseResolution,
+    stats: bool,
+    lockfile_digest: bool,
+    reproducible: bool,
+    source_date_epoch: Option<i64>,
+    timeout: Option<Duration>,
     locale: Locale,
 }
 
@@ -113,26 +273,75 @@ impl SbomRequestBuilder {
     /// Default values:
     /// - project_path: None (required)
     /// - include_dependency_info: false
+    /// - show_descriptions: false
     /// - exclude_patterns: empty Vec
+    /// - exclude_scope: All (excluded packages are not checked for vulnerabilities)
+    /// - only_groups / exclude_groups: empty Vec (all dependency groups)
+    /// - marker_environment: empty (all platforms)
     /// - dry_run: false
     /// - check_cve: false
     /// - severity_threshold: None
     /// - cvss_threshold: None
+    /// - check_lock: true (warn when uv.lock is out of date with pyproject.toml)
     pub fn new() -> Self {
         Self {
             project_path: None,
             include_dependency_info: false,
+            show_descriptions: false,
             exclude_patterns: Vec::new(),
+            exclude_scope: ExcludeScope::default(),
+            only_groups: Vec::new(),
+            exclude_groups: Vec::new(),
+            marker_environment: MarkerEnvironment::default(),
             dry_run: false,
+            probe_endpoints: false,
             check_cve: false,
             severity_threshold: None,
             cvss_threshold: None,
             ignore_cves: Vec::new(),
+            vex: false,
+            cve_baseline: VulnerabilityBaseline::new(),
             check_license: false,
             license_policy: None,
+            check_license_compatibility: false,
+            license_compatibility_policy: LicenseCompatibilityPolicy::default(),
+            project_license: None,
+            project_name: None,
+            roots: Vec::new(),
+            fail_on_license_incompatibility: false,
+            license_overrides: Vec::new(),
+            check_license_reachability: false,
+            strict_license_reachability: false,
+            copyleft_licenses: CopyleftLicenses::default(),
+            check_python_compat: false,
+            check_typosquats: false,
+            typosquat_max_distance: TyposquatChecker::DEFAULT_MAX_DISTANCE,
+            ignore_typosquats: Vec::new(),
+            fail_on_typosquat: false,
             suggest_fix: false,
+            suggest_fixes: false,
             check_abandoned: false,
             abandoned_threshold_days: 730,
+            offline: false,
+            advisory_db: None,
+            check_lock: true,
+            strict_lock: false,
+            strict_lock_version: false,
+            strict: false,
+            license_fetch_fields: LicenseFetchFields::Full,
+            licenses_for: LicensesFor::default(),
+            max_network_requests: None,
+            check_self: false,
+            self_check_fails_build: true,
+            data_dir: None,
+            config_path: None,
+            index_url: None,
+            license_resolution: LicenseResolution::default(),
+            stats: false,
+            lockfile_digest: false,
+            reproducible: false,
+            source_date_epoch: None,
+            timeout: None,
             locale: Locale::default(),
         }
     }
@@ -151,18 +360,56 @@ impl SbomRequestBuilder {
         self
     }
 
+    /// Sets whether the output shows package descriptions (Markdown and HTML reports).
+    ///
+    /// Only used to reject `LicenseFetchFields::LicenseOnly`, which leaves them empty.
+    pub fn show_descriptions(mut self, show: bool) -> Self {
+        self.show_descriptions = show;
+        self
+    }
+
     /// Sets the exclusion patterns for filtering packages.
     pub fn exclude_patterns(mut self, patterns: Vec<String>) -> Self {
         self.exclude_patterns = patterns;
         self
     }
 
+    /// Sets whether excluded packages are still checked for vulnerabilities.
+    pub fn exclude_scope(mut self, scope: ExcludeScope) -> Self {
+        self.exclude_scope = scope;
+        self
+    }
+
+    /// Keeps only the packages needed by these dependency groups.
+    pub fn only_groups(mut self, groups: Vec<String>) -> Self {
+        self.only_groups = groups;
+        self
+    }
+
+    /// Prunes the packages needed only by these dependency groups.
+    pub fn exclude_groups(mut self, groups: Vec<String>) -> Self {
+        self.exclude_groups = groups;
+        self
+    }
+
+    /// Prunes the packages whose environment markers can never be true in `environment`.
+    pub fn marker_environment(mut self, environment: MarkerEnvironment) -> Self {
+        self.marker_environment = environment;
+        self
+    }
+
     /// Sets whether to perform dry-run validation only.
     pub fn dry_run(mut self, dry_run: bool) -> Self {
         self.dry_run = dry_run;
         self
     }
 
+    /// Sets whether dry-run mode also probes the package index and vulnerability sources.
+    pub fn probe_endpoints(mut self, probe: bool) -> Self {
+        self.probe_endpoints = probe;
+        self
+    }
+
     /// Sets whether to check for vulnerabilities.
     pub fn check_cve(mut self, check: bool) -> Self {
         self.check_cve = check;
@@ -193,6 +440,19 @@ impl SbomRequestBuilder {
         self
     }
 
+    /// Sets whether ignored CVEs are kept in the report as VEX entries.
+    pub fn vex(mut self, vex: bool) -> Self {
+        self.vex = vex;
+        self
+    }
+
+    /// Sets the previously accepted vulnerabilities; only findings missing from
+    /// it count toward the threshold.
+    pub fn cve_baseline(mut self, baseline: VulnerabilityBaseline) -> Self {
+        self.cve_baseline = baseline;
+        self
+    }
+
     /// Sets whether to check license compliance.
     pub fn check_license(mut self, check: bool) -> Self {
         self.check_license = check;
@@ -205,12 +465,112 @@ impl SbomRequestBuilder {
         self
     }
 
+    /// Sets whether to check dependency licenses against the project license.
+    pub fn check_license_compatibility(mut self, check: bool) -> Self {
+        self.check_license_compatibility = check;
+        self
+    }
+
+    /// Sets the license compatibility matrix.
+    pub fn license_compatibility_policy(mut self, policy: LicenseCompatibilityPolicy) -> Self {
+        self.license_compatibility_policy = policy;
+        self
+    }
+
+    /// Sets the project license from an Option value.
+    ///
+    /// `None` reads the license from pyproject.toml.
+    pub fn project_license_opt(mut self, license: Option<String>) -> Self {
+        self.project_license = license;
+        self
+    }
+
+    /// Sets the project name from an Option value.
+    ///
+    /// `None` reads the name from pyproject.toml.
+    pub fn project_name_opt(mut self, name: Option<String>) -> Self {
+        self.project_name = name;
+        self
+    }
+
+    /// Sets the packages to root the dependency analysis at instead of the project
+    pub fn roots(mut self, roots: Vec<String>) -> Self {
+        self.roots = roots;
+        self
+    }
+
+    /// Sets whether incompatible dependency licenses fail the build.
+    pub fn fail_on_license_incompatibility(mut self, fail: bool) -> Self {
+        self.fail_on_license_incompatibility = fail;
+        self
+    }
+
+    /// Sets the per-package license overrides applied after license enrichment.
+    pub fn license_overrides(mut self, overrides: Vec<LicenseOverride>) -> Self {
+        self.license_overrides = overrides;
+        self
+    }
+
+    /// Sets whether to report copyleft packages reachable through the dependency graph.
+    pub fn check_license_reachability(mut self, check: bool) -> Self {
+        self.check_license_reachability = check;
+        self
+    }
+
+    /// Sets whether the reachability check also reports packages without a known license.
+    pub fn strict_license_reachability(mut self, strict: bool) -> Self {
+        self.strict_license_reachability = strict;
+        self
+    }
+
+    /// Sets the licenses reported by the reachability check.
+    pub fn copyleft_licenses(mut self, licenses: CopyleftLicenses) -> Self {
+        self.copyleft_licenses = licenses;
+        self
+    }
+
+    /// Sets whether to flag packages that do not support every Python version the project does.
+    pub fn check_python_compat(mut self, check: bool) -> Self {
+        self.check_python_compat = check;
+        self
+    }
+
+    /// Sets whether to flag packages whose names are near misses of popular package names.
+    pub fn check_typosquats(mut self, check: bool) -> Self {
+        self.check_typosquats = check;
+        self
+    }
+
+    /// Sets the largest edit distance the typosquat check reports.
+    pub fn typosquat_max_distance(mut self, distance: usize) -> Self {
+        self.typosquat_max_distance = distance;
+        self
+    }
+
+    /// Sets the package names the typosquat check never reports.
+    pub fn ignore_typosquats(mut self, names: Vec<String>) -> Self {
+        self.ignore_typosquats = names;
+        self
+    }
+
+    /// Sets whether typosquat findings fail the build.
+    pub fn fail_on_typosquat(mut self, fail: bool) -> Self {
+        self.fail_on_typosquat = fail;
+        self
+    }
+
     /// Sets whether to suggest upgrade paths for vulnerable transitive dependencies.
     pub fn suggest_fix(mut self, suggest: bool) -> Self {
         self.suggest_fix = suggest;
         self
     }
 
+    /// Sets whether to list upgrade commands for actionable vulnerabilities.
+    pub fn suggest_fixes(mut self, suggest: bool) -> Self {
+        self.suggest_fixes = suggest;
+        self
+    }
+
     /// Sets whether to check for abandoned/unmaintained packages.
     pub fn check_abandoned(mut self, check: bool) -> Self {
         self.check_abandoned = check;
@@ -223,6 +583,139 @@ impl SbomRequestBuilder {
         self
     }
 
+    /// Sets whether to skip all network access (PyPI and OSV).
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Sets the local PyPA advisory database vulnerabilities are read from.
+    pub fn advisory_db_opt(mut self, dir: Option<PathBuf>) -> Self {
+        self.advisory_db = dir;
+        self
+    }
+
+    /// Sets whether to compare the lockfile with the requirements in pyproject.toml.
+    pub fn check_lock(mut self, check: bool) -> Self {
+        self.check_lock = check;
+        self
+    }
+
+    /// Sets whether an out-of-date lockfile is an error instead of a warning.
+    pub fn strict_lock(mut self, strict: bool) -> Self {
+        self.strict_lock = strict;
+        self
+    }
+
+    /// Sets whether a lockfile format newer than supported is an error instead
+    /// of a warning.
+    pub fn strict_lock_version(mut self, strict: bool) -> Self {
+        self.strict_lock_version = strict;
+        self
+    }
+
+    /// Sets whether a project root that cannot be determined is an error instead
+    /// of skipping dependency analysis.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets the metadata fields to resolve per package.
+    pub fn license_fetch_fields(mut self, fields: LicenseFetchFields) -> Self {
+        self.license_fetch_fields = fields;
+        self
+    }
+
+    /// Sets which packages have their license looked up.
+    pub fn licenses_for(mut self, licenses_for: LicensesFor) -> Self {
+        self.licenses_for = licenses_for;
+        self
+    }
+
+    /// Sets the package lookup budget of the run from an Option value.
+    ///
+    /// `None` looks up every package.
+    pub fn max_network_requests_opt(mut self, max_network_requests: Option<usize>) -> Self {
+        self.max_network_requests = max_network_requests;
+        self
+    }
+
+    /// Sets whether to report generation counts and per-phase timings.
+    pub fn stats(mut self, stats: bool) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Sets whether to record the SHA-256 digest of the lockfile in the response.
+    pub fn lockfile_digest(mut self, lockfile_digest: bool) -> Self {
+        self.lockfile_digest = lockfile_digest;
+        self
+    }
+
+    /// Sets whether identical inputs must produce an identical SBOM.
+    pub fn reproducible(mut self, reproducible: bool) -> Self {
+        self.reproducible = reproducible;
+        self
+    }
+
+    /// Sets the reproducible-mode timestamp (seconds since the Unix epoch) from an Option value.
+    pub fn source_date_epoch_opt(mut self, source_date_epoch: Option<i64>) -> Self {
+        self.source_date_epoch = source_date_epoch;
+        self
+    }
+
+    /// Sets whether to check the project's own published version for vulnerabilities.
+    pub fn check_self(mut self, check: bool) -> Self {
+        self.check_self = check;
+        self
+    }
+
+    /// Sets whether self-check findings above the threshold fail the build.
+    pub fn self_check_fails_build(mut self, fails: bool) -> Self {
+        self.self_check_fails_build = fails;
+        self
+    }
+
+    /// Sets the data directory from an Option value.
+    ///
+    /// `None` keeps temporary files in the system temporary directory.
+    pub fn data_dir_opt(mut self, data_dir: Option<PathBuf>) -> Self {
+        self.data_dir = data_dir;
+        self
+    }
+
+    /// Sets the config file the options were read from, from an Option value.
+    pub fn config_path_opt(mut self, config_path: Option<PathBuf>) -> Self {
+        self.config_path = config_path;
+        self
+    }
+
+    /// Sets the package index URL from an Option value.
+    ///
+    /// Only recorded in the SBOM metadata; the index itself is configured on
+    /// the license repository.
+    pub fn index_url_opt(mut self, index_url: Option<String>) -> Self {
+        self.index_url = index_url;
+        self
+    }
+
+    /// Sets the license resolution policy to record in the SBOM metadata.
+    ///
+    /// The policy itself is applied by the license repository.
+    pub fn license_resolution(mut self, license_resolution: LicenseResolution) -> Self {
+        self.license_resolution = license_resolution;
+        self
+    }
+
+    /// Sets the overall deadline for generation from an Option value.
+    ///
+    /// `None` disables the deadline.
+    pub fn timeout_opt(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     /// Sets the output locale for human-readable formats.
     pub fn locale(mut self, locale: Locale) -> Self {
         self.locale = locale;
@@ -235,24 +728,111 @@ impl SbomRequestBuilder {
     ///
     /// Returns an error if project_path is not set.
     pub fn build(self) -> Result<SbomRequest> {
-        let project_path = self.project_path.ok_or_else(|| SbomError::Validation {
+        let project_path = self.project_path.ok_or_else(|| UvSbomError::Validation {
             message: "project_path is required".into(),
         })?;
 
+        if !self.only_groups.is_empty() && !self.exclude_groups.is_empty() {
+            return Err(UvSbomError::Validation {
+                message: "only_groups and exclude_groups cannot be combined".into(),
+            });
+        }
+
+        if self.offline && self.check_cve && self.advisory_db.is_none() {
+            return Err(UvSbomError::Validation {
+                message: "offline mode cannot be combined with CVE checking, which needs OSV. \
+                          Use --no-check-cve, remove check_cve: true from the config file, \
+                          or check against a local advisory database with --advisory-db"
+                    .into(),
+            });
+        }
+        if self.offline && self.probe_endpoints {
+            return Err(UvSbomError::Validation {
+                message: "offline mode cannot be combined with --dry-run=full, \
+                          which probes the network endpoints"
+                    .into(),
+            });
+        }
+        if self.offline && self.check_abandoned {
+            return Err(UvSbomError::Validation {
+                message: "offline mode cannot be combined with abandoned-package detection, \
+                          which needs PyPI"
+                    .into(),
+            });
+        }
+        if self.license_fetch_fields == LicenseFetchFields::LicenseOnly && self.show_descriptions {
+            return Err(UvSbomError::Validation {
+                message: "minimal metadata cannot be combined with Markdown or HTML output, \
+                          which show package descriptions"
+                    .into(),
+            });
+        }
+
+        if !self.roots.is_empty() && !self.include_dependency_info {
+            return Err(UvSbomError::Validation {
+                message: "roots only apply to the dependency analysis of Markdown, HTML \
+                          and graph output"
+                    .into(),
+            });
+        }
+
         Ok(SbomRequest {
             project_path,
             include_dependency_info: self.include_dependency_info,
             exclude_patterns: self.exclude_patterns,
+            exclude_scope: self.exclude_scope,
+            only_groups: self.only_groups,
+            exclude_groups: self.exclude_groups,
+            marker_environment: self.marker_environment,
             dry_run: self.dry_run,
+            probe_endpoints: self.probe_endpoints,
             check_cve: self.check_cve,
             severity_threshold: self.severity_threshold,
             cvss_threshold: self.cvss_threshold,
             ignore_cves: self.ignore_cves,
+            vex: self.vex,
+            cve_baseline: self.cve_baseline,
             check_license: self.check_license,
             license_policy: self.license_policy,
+            check_license_compatibility: self.check_license_compatibility,
+            license_compatibility_policy: self.license_compatibility_policy,
+            project_license: self.project_license,
+            project_name: self.project_name,
+            roots: self.roots,
+            fail_on_license_incompatibility: self.fail_on_license_incompatibility,
+            license_overrides: self.license_overrides,
+            check_license_reachability: self.check_license_reachability,
+            strict_license_reachability: self.strict_license_reachability,
+            copyleft_licenses: self.copyleft_licenses,
+            check_python_compat: self.check_python_compat,
+            check_typosquats: self.check_typosquats,
+            typosquat_max_distance: self.typosquat_max_distance,
+            ignore_typosquats: self.ignore_typosquats,
+            fail_on_typosquat: self.fail_on_typosquat,
             suggest_fix: self.suggest_fix,
+            suggest_fixes: self.suggest_fixes,
             check_abandoned: self.check_abandoned,
             abandoned_threshold_days: self.abandoned_threshold_days,
+            offline: self.offline,
+            advisory_db: self.advisory_db,
+            check_lock: self.check_lock,
+            strict_lock: self.strict_lock,
+            strict_lock_version: self.strict_lock_version,
+            strict: self.strict,
+            license_fetch_fields: self.license_fetch_fields,
+            licenses_for: self.licenses_for,
+            max_network_requests: self.max_network_requests,
+            check_self: self.check_self,
+            self_check_fails_build: self.self_check_fails_build,
+            data_dir: self.data_dir,
+            config_path: self.config_path,
+            index_url: self.index_url,
+            license_resolution: self.license_resolution,
+            stats: self.stats,
+            lockfile_digest: self.lockfile_digest,
+            reproducible: self.reproducible,
+            source_date_epoch: self.source_date_epoch,
+            timeout: self.timeout,
             locale: self.locale,
         })
     }
@@ -279,11 +859,16 @@ mod tests {
         assert_eq!(request.project_path, PathBuf::from("/test/project"));
         assert!(!request.include_dependency_info);
         assert!(request.exclude_patterns.is_empty());
+        assert_eq!(request.exclude_scope, ExcludeScope::All);
         assert!(!request.dry_run);
         assert!(!request.check_cve);
         assert!(request.severity_threshold.is_none());
         assert!(request.cvss_threshold.is_none());
         assert!(request.ignore_cves.is_empty());
+        assert!(request.check_lock);
+        assert!(!request.strict_lock);
+        assert!(!request.strict_lock_version);
+        assert!(!request.strict);
     }
 
     #[test]
@@ -301,6 +886,7 @@ mod tests {
             .project_path("/test/project")
             .include_dependency_info(true)
             .exclude_patterns(vec!["test-*".to_string()])
+            .exclude_scope(ExcludeScope::Output)
             .dry_run(true)
             .check_cve(true)
             .severity_threshold_opt(Some(Severity::High))
@@ -308,6 +894,7 @@ mod tests {
             .ignore_cves(vec![IgnoreCve {
                 id: "CVE-2024-1234".to_string(),
                 reason: Some("test".to_string()),
+                state: None,
             }])
             .build()
             .unwrap();
@@ -315,6 +902,7 @@ mod tests {
         assert_eq!(request.project_path, PathBuf::from("/test/project"));
         assert!(request.include_dependency_info);
         assert_eq!(request.exclude_patterns, vec!["test-*".to_string()]);
+        assert_eq!(request.exclude_scope, ExcludeScope::Output);
         assert!(request.dry_run);
         assert!(request.check_cve);
         assert_eq!(request.severity_threshold, Some(Severity::High));
@@ -323,6 +911,161 @@ mod tests {
         assert_eq!(request.ignore_cves[0].id, "CVE-2024-1234");
     }
 
+    #[test]
+    fn test_offline_without_network_checks() {
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .offline(true)
+            .build()
+            .unwrap();
+
+        assert!(request.offline);
+    }
+
+    #[test]
+    fn test_offline_with_check_cve_fails() {
+        let result = SbomRequest::builder()
+            .project_path("/test/project")
+            .offline(true)
+            .check_cve(true)
+            .build();
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("offline mode cannot be combined with CVE checking"));
+    }
+
+    #[test]
+    fn test_offline_with_check_cve_and_advisory_db() {
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .offline(true)
+            .check_cve(true)
+            .advisory_db_opt(Some(PathBuf::from("/srv/advisory-database")))
+            .build()
+            .unwrap();
+
+        assert!(request.check_cve);
+    }
+
+    #[test]
+    fn test_offline_with_check_abandoned_fails() {
+        let result = SbomRequest::builder()
+            .project_path("/test/project")
+            .offline(true)
+            .check_abandoned(true)
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_offline_with_probe_endpoints_fails() {
+        let result = SbomRequest::builder()
+            .project_path("/test/project")
+            .offline(true)
+            .dry_run(true)
+            .probe_endpoints(true)
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_strict_lock_version() {
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .strict_lock_version(true)
+            .build()
+            .unwrap();
+        assert!(request.strict_lock_version);
+    }
+
+    #[test]
+    fn test_roots() {
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .include_dependency_info(true)
+            .roots(vec!["boto3".to_string(), "flask-*".to_string()])
+            .build()
+            .unwrap();
+        assert_eq!(request.roots, ["boto3", "flask-*"]);
+    }
+
+    #[test]
+    fn test_roots_require_dependency_info() {
+        let result = SbomRequest::builder()
+            .project_path("/test/project")
+            .roots(vec!["boto3".to_string()])
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lookup_scope_and_budget() {
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .build()
+            .unwrap();
+        assert_eq!(request.licenses_for, LicensesFor::All);
+        assert_eq!(request.max_network_requests, None);
+
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .licenses_for(LicensesFor::DirectOnly)
+            .max_network_requests_opt(Some(100))
+            .build()
+            .unwrap();
+        assert_eq!(request.licenses_for, LicensesFor::DirectOnly);
+        assert_eq!(request.max_network_requests, Some(100));
+    }
+
+    #[test]
+    fn test_license_only_fields_require_cyclonedx_output() {
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .license_fetch_fields(LicenseFetchFields::LicenseOnly)
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.license_fetch_fields,
+            LicenseFetchFields::LicenseOnly
+        );
+
+        // Graph output needs the dependency analysis but not the descriptions
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .include_dependency_info(true)
+            .license_fetch_fields(LicenseFetchFields::LicenseOnly)
+            .build()
+            .unwrap();
+        assert!(request.include_dependency_info);
+
+        let result = SbomRequest::builder()
+            .project_path("/test/project")
+            .include_dependency_info(true)
+            .show_descriptions(true)
+            .license_fetch_fields(LicenseFetchFields::LicenseOnly)
+            .build();
+        let err = result.unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Validation error: minimal metadata cannot be combined with Markdown or HTML \
+             output, which show package descriptions"
+        );
+    }
+
+    #[test]
+    fn test_only_groups_with_exclude_groups_fails() {
+        let result = SbomRequest::builder()
+            .project_path("/test")
+            .only_groups(vec!["main".to_string()])
+            .exclude_groups(vec!["dev".to_string()])
+            .build();
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("only_groups and exclude_groups cannot be combined"));
+    }
+
     #[test]
     fn test_exclude_patterns_accumulates() {
         let request = SbomRequest::builder()
@@ -398,6 +1141,22 @@ mod tests {
         assert!(request.cvss_threshold.is_none());
     }
 
+    #[test]
+    fn test_timeout_opt() {
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .timeout_opt(Some(Duration::from_secs(120)))
+            .build()
+            .unwrap();
+        assert_eq!(request.timeout, Some(Duration::from_secs(120)));
+
+        let request = SbomRequest::builder()
+            .project_path("/test/project")
+            .build()
+            .unwrap();
+        assert!(request.timeout.is_none());
+    }
+
     #[test]
     fn test_builder_default_trait() {
         let builder = SbomRequestBuilder::default();
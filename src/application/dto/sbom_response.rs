@@ -1,9 +1,14 @@
 use crate::application::read_models::abandoned_package::AbandonedPackagesReport;
+use crate::application::read_models::{GenerationStats, SbomWarning};
 use crate::ports::outbound::EnrichedPackage;
 use crate::sbom_generation::domain::license_policy::LicenseComplianceResult;
-use crate::sbom_generation::domain::services::VulnerabilityCheckResult;
-use crate::sbom_generation::domain::{DependencyGraph, SbomMetadata, UpgradeRecommendation};
-use crate::shared::error::SbomError;
+use crate::sbom_generation::domain::services::{ValidationReport, VulnerabilityCheckResult};
+use crate::sbom_generation::domain::{
+    DependencyGraph, FixSuggestion, LicenseOverride, LicenseRiskReport, PackageAnnotation,
+    PythonCompatibilityReport, SbomMetadata, TyposquatFinding, UpgradeRecommendation,
+};
+use crate::sbom_generation::policies::LicenseCompatibilityResult;
+use crate::shared::error::UvSbomError;
 
 /// SbomResponse - Internal response DTO from SBOM generation use case
 ///
@@ -23,6 +28,9 @@ pub struct SbomResponse {
     /// Optional vulnerability check result with threshold evaluation
     /// Contains above/below threshold separation for formatting
     pub vulnerability_check_result: Option<VulnerabilityCheckResult>,
+    /// Whether some packages could not be checked for vulnerabilities.
+    /// The check result then covers only the packages that were queried.
+    pub vulnerability_check_incomplete: bool,
     /// Optional license compliance result (only present when license check is enabled)
     pub license_compliance_result: Option<LicenseComplianceResult>,
     /// Whether license violations were detected
@@ -30,9 +38,45 @@ pub struct SbomResponse {
     /// Upgrade recommendations for vulnerable transitive dependencies.
     /// Populated only when `suggest_fix` was true in the request.
     pub upgrade_recommendations: Option<Vec<UpgradeRecommendation>>,
+    /// Upgrade suggestions for packages with actionable vulnerabilities.
+    /// Populated only when `suggest_fixes` was true in the request.
+    pub fix_suggestions: Option<Vec<FixSuggestion>>,
     /// Abandoned packages report.
     /// Populated only when `check_abandoned` was true in the request.
     pub abandoned_packages_report: Option<AbandonedPackagesReport>,
+    /// Vulnerability check result for the project's own published version.
+    /// Populated only when `check_self` was true in the request.
+    pub project_self_check_result: Option<VulnerabilityCheckResult>,
+    /// License compatibility result (only present when the compatibility check is enabled)
+    pub license_compatibility_result: Option<LicenseCompatibilityResult>,
+    /// Whether incompatible licenses were found and
+    /// `fail_on_license_incompatibility` was set in the request
+    pub has_license_incompatibilities: bool,
+    /// Copyleft packages reachable through the dependency graph.
+    /// Populated only when `check_license_reachability` was true in the request.
+    pub license_risk_report: Option<LicenseRiskReport>,
+    /// Packages whose `requires-python` excludes Python versions the project supports.
+    /// Populated only when `check_python_compat` was true in the request and the
+    /// project declares a `requires-python` that could be parsed.
+    pub python_compatibility_report: Option<PythonCompatibilityReport>,
+    /// Packages whose names are near misses of popular package names.
+    /// Populated only when `check_typosquats` was true in the request.
+    pub typosquat_findings: Option<Vec<TyposquatFinding>>,
+    /// Whether typosquat findings were reported and `fail_on_typosquat` was set
+    /// in the request
+    pub has_typosquats: bool,
+    /// Per-package annotations declared in `[tool.uv-sbom.annotations]`
+    pub package_annotations: Vec<PackageAnnotation>,
+    /// License overrides from the config file that matched a package and were applied
+    pub license_overrides: Vec<LicenseOverride>,
+    /// Non-fatal warnings raised during generation, in the order they occurred
+    pub warnings: Vec<SbomWarning>,
+    /// Generation counts and timings, present only when `stats` was set in the request
+    pub stats: Option<GenerationStats>,
+    /// SHA-256 digest of the lockfile, present only when `lockfile_digest` was set in the request
+    pub lockfile_sha256: Option<String>,
+    /// Findings of the configuration checks, present only in dry-run mode
+    pub validation_report: Option<ValidationReport>,
 }
 
 impl SbomResponse {
@@ -51,6 +95,7 @@ pub struct SbomResponseBuilder {
     has_license_violations: bool,
     upgrade_recommendations: Option<Vec<UpgradeRecommendation>>,
     abandoned_packages_report: Option<AbandonedPackagesReport>,
+    warnings: Vec<SbomWarning>,
 }
 
 impl SbomResponseBuilder {
@@ -65,6 +110,7 @@ impl SbomResponseBuilder {
             has_license_violations: false,
             upgrade_recommendations: None,
             abandoned_packages_report: None,
+            warnings: Vec::new(),
         }
     }
 
@@ -119,8 +165,13 @@ impl SbomResponseBuilder {
         self
     }
 
-    pub fn build(self) -> Result<SbomResponse, SbomError> {
-        let metadata = self.metadata.ok_or_else(|| SbomError::Validation {
+    pub fn warnings(mut self, warnings: Vec<SbomWarning>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    pub fn build(self) -> Result<SbomResponse, UvSbomError> {
+        let metadata = self.metadata.ok_or_else(|| UvSbomError::Validation {
             message: "metadata is required".into(),
         })?;
 
@@ -130,10 +181,25 @@ impl SbomResponseBuilder {
             metadata,
             has_vulnerabilities_above_threshold: self.has_vulnerabilities_above_threshold,
             vulnerability_check_result: self.vulnerability_check_result,
+            vulnerability_check_incomplete: false,
             license_compliance_result: self.license_compliance_result,
             has_license_violations: self.has_license_violations,
             upgrade_recommendations: self.upgrade_recommendations,
+            fix_suggestions: None,
             abandoned_packages_report: self.abandoned_packages_report,
+            project_self_check_result: None,
+            license_compatibility_result: None,
+            has_license_incompatibilities: false,
+            license_risk_report: None,
+            python_compatibility_report: None,
+            typosquat_findings: None,
+            has_typosquats: false,
+            package_annotations: Vec::new(),
+            license_overrides: Vec::new(),
+            warnings: self.warnings,
+            stats: None,
+            lockfile_sha256: None,
+            validation_report: None,
         })
     }
 }
@@ -169,6 +235,7 @@ mod tests {
         assert!(response.vulnerability_check_result.is_none());
         assert!(response.license_compliance_result.is_none());
         assert!(!response.has_license_violations);
+        assert!(response.warnings.is_empty());
     }
 
     #[test]
@@ -1,7 +1,10 @@
-use crate::adapters::outbound::formatters::{CycloneDxFormatter, MarkdownFormatter};
+use crate::adapters::outbound::formatters::{
+    CycloneDxFormatter, DiffJsonFormatter, HtmlFormatter, MarkdownFormatter, MarkdownStyle,
+    SpecVersion,
+};
 use crate::application::dto::OutputFormat;
 use crate::i18n::{Locale, Messages};
-use crate::ports::outbound::SbomFormatter;
+use crate::ports::outbound::{SbomDiffFormatter, SbomFormatter};
 use std::collections::HashSet;
 
 /// Factory for creating SBOM formatters
@@ -16,19 +19,63 @@ impl FormatterFactory {
     ///
     /// When `verified_packages` is Some, only packages in the set get PyPI hyperlinks
     /// in Markdown output. When None, all packages get hyperlinks (default behavior).
+    /// `markdown_style` only applies to Markdown output, and `spec_version` to JSON.
     pub fn create(
         format: OutputFormat,
         verified_packages: Option<HashSet<String>>,
         locale: Locale,
+        markdown_style: MarkdownStyle,
+        spec_version: SpecVersion,
     ) -> Box<dyn SbomFormatter> {
         match format {
-            OutputFormat::Json => Box::new(CycloneDxFormatter::new()),
-            OutputFormat::Markdown => match verified_packages {
-                Some(packages) => {
-                    Box::new(MarkdownFormatter::with_verified_packages(packages, locale))
-                }
-                None => Box::new(MarkdownFormatter::new(locale)),
-            },
+            OutputFormat::Json => {
+                Box::new(CycloneDxFormatter::new().with_spec_version(spec_version))
+            }
+            OutputFormat::Markdown => {
+                let formatter = match verified_packages {
+                    Some(packages) => MarkdownFormatter::with_verified_packages(packages, locale),
+                    None => MarkdownFormatter::new(locale),
+                };
+                Box::new(formatter.with_style(markdown_style))
+            }
+            OutputFormat::Html => Box::new(HtmlFormatter::new(locale)),
+        }
+    }
+
+    /// Creates one formatter per format, for writing several outputs from the
+    /// same read model (`--output-dir`).
+    ///
+    /// The arguments are applied to each formatter as in [`FormatterFactory::create`].
+    pub fn create_all(
+        formats: &[OutputFormat],
+        verified_packages: Option<HashSet<String>>,
+        locale: Locale,
+        markdown_style: MarkdownStyle,
+        spec_version: SpecVersion,
+    ) -> Vec<(OutputFormat, Box<dyn SbomFormatter>)> {
+        formats
+            .iter()
+            .map(|&format| {
+                let formatter = Self::create(
+                    format,
+                    verified_packages.clone(),
+                    locale,
+                    markdown_style,
+                    spec_version,
+                );
+                (format, formatter)
+            })
+            .collect()
+    }
+
+    /// Creates a formatter for the output of `uv-sbom diff`.
+    ///
+    /// JSON output is locale-independent; Markdown headings follow `locale`.
+    /// There is no HTML diff report, so `Html` falls back to Markdown.
+    pub fn create_diff(format: OutputFormat, locale: Locale) -> Box<dyn SbomDiffFormatter> {
+        match format {
+            OutputFormat::Json => Box::new(DiffJsonFormatter::new()),
+            OutputFormat::Markdown | OutputFormat::Html => Box::new(MarkdownFormatter::new(locale)),
         }
     }
 
@@ -38,6 +85,7 @@ impl FormatterFactory {
         match format {
             OutputFormat::Json => msgs.progress_generating_json,
             OutputFormat::Markdown => msgs.progress_generating_markdown,
+            OutputFormat::Html => msgs.progress_generating_html,
         }
     }
 }
@@ -48,16 +96,88 @@ mod tests {
 
     #[test]
     fn test_create_json_formatter() {
-        let formatter = FormatterFactory::create(OutputFormat::Json, None, Locale::En);
+        let formatter = FormatterFactory::create(
+            OutputFormat::Json,
+            None,
+            Locale::En,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
         assert!(std::mem::size_of_val(&formatter) > 0);
     }
 
     #[test]
     fn test_create_markdown_formatter() {
-        let formatter = FormatterFactory::create(OutputFormat::Markdown, None, Locale::En);
+        let formatter = FormatterFactory::create(
+            OutputFormat::Markdown,
+            None,
+            Locale::En,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
         assert!(std::mem::size_of_val(&formatter) > 0);
     }
 
+    #[test]
+    fn test_create_html_formatter() {
+        use crate::test_fixtures::SbomReadModelFixture;
+
+        let formatter = FormatterFactory::create(
+            OutputFormat::Html,
+            None,
+            Locale::En,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
+        let output = formatter
+            .format(&SbomReadModelFixture::small_clean().build())
+            .unwrap();
+        assert!(output.starts_with("<!DOCTYPE html>"));
+        assert_eq!(
+            FormatterFactory::progress_message(OutputFormat::Html, Locale::En),
+            "📝 Generating HTML format output..."
+        );
+    }
+
+    #[test]
+    fn test_create_all_keeps_format_order() {
+        use crate::test_fixtures::SbomReadModelFixture;
+
+        let formatters = FormatterFactory::create_all(
+            &[OutputFormat::Markdown, OutputFormat::Json],
+            None,
+            Locale::En,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
+        let model = SbomReadModelFixture::small_clean().build();
+
+        assert_eq!(formatters.len(), 2);
+        assert_eq!(formatters[0].0, OutputFormat::Markdown);
+        assert!(formatters[0].1.format(&model).unwrap().starts_with('#'));
+        assert_eq!(formatters[1].0, OutputFormat::Json);
+        assert!(formatters[1]
+            .1
+            .format(&model)
+            .unwrap()
+            .contains("\"bomFormat\""));
+    }
+
+    #[test]
+    fn test_create_diff_formatters() {
+        use crate::application::read_models::SbomDiffView;
+
+        let diff = SbomDiffView::default();
+        let json = FormatterFactory::create_diff(OutputFormat::Json, Locale::En)
+            .format_diff(&diff)
+            .unwrap();
+        assert!(json.contains("\"schema_version\""));
+        let markdown = FormatterFactory::create_diff(OutputFormat::Markdown, Locale::En)
+            .format_diff(&diff)
+            .unwrap();
+        assert!(markdown.starts_with("# SBOM Diff"));
+    }
+
     #[test]
     fn test_progress_message_json_en() {
         let message = FormatterFactory::progress_message(OutputFormat::Json, Locale::En);
@@ -86,8 +206,13 @@ mod tests {
     fn test_create_with_verified_packages() {
         let mut verified = HashSet::new();
         verified.insert("requests".to_string());
-        let formatter =
-            FormatterFactory::create(OutputFormat::Markdown, Some(verified), Locale::En);
+        let formatter = FormatterFactory::create(
+            OutputFormat::Markdown,
+            Some(verified),
+            Locale::En,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
         assert!(std::mem::size_of_val(&formatter) > 0);
     }
 
@@ -95,15 +220,33 @@ mod tests {
     fn test_create_json_ignores_verified_packages() {
         let mut verified = HashSet::new();
         verified.insert("requests".to_string());
-        let formatter = FormatterFactory::create(OutputFormat::Json, Some(verified), Locale::En);
+        let formatter = FormatterFactory::create(
+            OutputFormat::Json,
+            Some(verified),
+            Locale::En,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
         assert!(std::mem::size_of_val(&formatter) > 0);
     }
 
     #[test]
     fn test_lang_does_not_affect_json_formatter_creation() {
         // JSON formatter is always CycloneDX regardless of locale
-        let formatter_en = FormatterFactory::create(OutputFormat::Json, None, Locale::En);
-        let formatter_ja = FormatterFactory::create(OutputFormat::Json, None, Locale::Ja);
+        let formatter_en = FormatterFactory::create(
+            OutputFormat::Json,
+            None,
+            Locale::En,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
+        let formatter_ja = FormatterFactory::create(
+            OutputFormat::Json,
+            None,
+            Locale::Ja,
+            MarkdownStyle::default(),
+            SpecVersion::default(),
+        );
         // Both should produce valid formatters (same type, locale-independent)
         assert!(std::mem::size_of_val(&formatter_en) > 0);
         assert!(std::mem::size_of_val(&formatter_ja) > 0);
@@ -1,6 +1,6 @@
 use crate::adapters::outbound::filesystem::{FileSystemWriter, StdoutPresenter};
 use crate::i18n::Locale;
-use crate::ports::outbound::OutputPresenter;
+use crate::ports::outbound::{OutputPresenter, Verbosity};
 use std::path::PathBuf;
 
 /// Presenter type enumeration for factory pattern
@@ -35,9 +35,34 @@ impl PresenterFactory {
     /// let presenter = PresenterFactory::create(PresenterType::Stdout, Locale::En);
     /// ```
     pub fn create(presenter_type: PresenterType, locale: Locale) -> Box<dyn OutputPresenter> {
+        Self::create_with_dirs(presenter_type, locale, false)
+    }
+
+    /// Creates a presenter whose file output creates missing parent directories
+    /// when `create_dirs` is set (`--create-dirs`)
+    pub fn create_with_dirs(
+        presenter_type: PresenterType,
+        locale: Locale,
+        create_dirs: bool,
+    ) -> Box<dyn OutputPresenter> {
+        Self::create_with_verbosity(presenter_type, locale, create_dirs, Verbosity::Normal)
+    }
+
+    /// Creates a presenter like [`Self::create_with_dirs`] whose file output is
+    /// not announced on stderr when `verbosity` is quiet
+    pub fn create_with_verbosity(
+        presenter_type: PresenterType,
+        locale: Locale,
+        create_dirs: bool,
+        verbosity: Verbosity,
+    ) -> Box<dyn OutputPresenter> {
         match presenter_type {
             PresenterType::Stdout => Box::new(StdoutPresenter::new()),
-            PresenterType::File(path) => Box::new(FileSystemWriter::new(path, locale)),
+            PresenterType::File(path) => Box::new(
+                FileSystemWriter::new(path, locale)
+                    .with_create_dirs(create_dirs)
+                    .with_verbosity(verbosity),
+            ),
         }
     }
 }
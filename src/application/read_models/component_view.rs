@@ -2,6 +2,9 @@
 //!
 //! These structs provide a flattened, query-optimized view of component data.
 
+use crate::sbom_generation::domain::{ExternalRefType, PackageSource};
+use std::collections::BTreeMap;
+
 /// View representation of a software component
 #[derive(Debug, Clone)]
 pub struct ComponentView {
@@ -21,6 +24,31 @@ pub struct ComponentView {
     pub sha256_hash: Option<String>,
     /// Whether this is a direct dependency
     pub is_direct_dependency: bool,
+    /// Project-declared annotations from `[tool.uv-sbom.annotations]`, sorted by key
+    pub annotations: BTreeMap<String, String>,
+    /// Set when the license comes from a `license_overrides` entry in the config file
+    pub license_override: Option<LicenseOverrideView>,
+    /// Yank reason (empty if none was given) when the release was yanked from PyPI
+    pub yanked: Option<String>,
+    /// Where the package comes from, as recorded in the lockfile
+    pub source: Option<PackageSource>,
+    /// Requirement declared in pyproject.toml for a direct dependency, as written
+    /// (e.g. `requests[socks]>=2.28,<3; python_version >= "3.9"`)
+    pub declared_constraint: Option<String>,
+    /// Whether the package was not found on the package index, so it has no license
+    pub license_not_found: bool,
+    /// Error message when the license lookup failed, so the missing license is
+    /// not mistaken for a package without license metadata
+    pub license_fetch_error: Option<String>,
+    /// The package's `requires-python` when it excludes Python versions the project
+    /// supports; only set by the Python compatibility check
+    pub python_compat: Option<String>,
+    /// Author named in the package metadata
+    pub author: Option<String>,
+    /// Supplier named in the package metadata: the author, else the maintainer
+    pub supplier: Option<String>,
+    /// Homepage, repository and documentation links from the package metadata
+    pub external_refs: Vec<(ExternalRefType, String)>,
 }
 
 /// View representation of license information
@@ -30,4 +58,13 @@ pub struct LicenseView {
     pub spdx_id: Option<String>,
     /// License name
     pub name: String,
+    /// Where the license came from (e.g. `pypi-classifier`), if known
+    pub source: Option<String>,
+}
+
+/// Marks a license that was set manually instead of found by enrichment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseOverrideView {
+    /// Why the license was overridden, if given
+    pub reason: Option<String>,
 }
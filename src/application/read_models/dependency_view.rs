@@ -14,4 +14,13 @@ pub struct DependencyView {
     pub direct: Vec<String>,
     /// Transitive dependencies mapped by their parent bom-ref
     pub transitive: HashMap<String, Vec<String>>,
+    /// Immediate dependencies of every package by its bom-ref, for rendering
+    /// the dependency tree; packages without dependencies are absent
+    pub children: HashMap<String, Vec<String>>,
+    /// Extras requested for a dependency (e.g. `socks` for `requests[socks]`), by
+    /// its bom-ref; dependencies requested without extras are absent
+    pub extras: HashMap<String, Vec<String>>,
+    /// BOM references of the packages no root reaches; only filled when the
+    /// graph is rooted at explicitly given packages
+    pub unreferenced: Vec<String>,
 }
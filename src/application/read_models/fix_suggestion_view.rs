@@ -0,0 +1,30 @@
+/// View representation of one package that can be upgraded to a fixed version.
+#[derive(Debug, Clone)]
+pub struct FixSuggestionView {
+    pub package_name: String,
+    pub current_version: String,
+    pub fixed_version: String,
+    /// Advisories resolved by the upgrade, sorted.
+    pub vulnerability_ids: Vec<String>,
+    /// Ready-to-run `uv add` command; `None` for transitive packages.
+    pub command: Option<String>,
+    /// Direct dependencies that pull in a transitive package, sorted.
+    pub introduced_by: Vec<String>,
+}
+
+/// View representation of one package with advisories that have no fixed version.
+#[derive(Debug, Clone)]
+pub struct UnfixedPackageView {
+    pub package_name: String,
+    pub current_version: String,
+    pub vulnerability_ids: Vec<String>,
+}
+
+/// Top-level view for the suggested fixes section of the report.
+#[derive(Debug, Clone, Default)]
+pub struct FixSuggestionsView {
+    /// Packages with a known fixed version, sorted by package name.
+    pub fixable: Vec<FixSuggestionView>,
+    /// Packages with advisories that are not fixed in any release yet.
+    pub unfixed: Vec<UnfixedPackageView>,
+}
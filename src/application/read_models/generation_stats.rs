@@ -0,0 +1,207 @@
+//! Counts and phase timings collected during SBOM generation
+//!
+//! Shown with `--stats` as a block on stderr and, for Markdown output, as a
+//! "Generation Summary" section. Values for steps that did not run are `None`
+//! and render as "skipped" rather than as zero.
+
+use super::vulnerability_view::VulnerabilityCountsBySeverity;
+use crate::i18n::Messages;
+use crate::ports::outbound::EnrichedPackage;
+use crate::sbom_generation::domain::services::VulnerabilityCheckResult;
+use crate::sbom_generation::domain::{DependencyGraph, Severity};
+use std::time::Duration;
+
+/// Direct and transitive dependency counts from the dependency graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DependencyCounts {
+    pub direct: usize,
+    pub transitive: usize,
+}
+
+impl DependencyCounts {
+    pub fn of_graph(graph: &DependencyGraph) -> Self {
+        Self {
+            direct: graph.direct_dependency_count(),
+            transitive: graph.transitive_dependency_count(),
+        }
+    }
+}
+
+/// Packages with and without license information
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LicenseCounts {
+    pub resolved: usize,
+    pub missing: usize,
+}
+
+impl LicenseCounts {
+    pub fn of_packages(enriched: &[EnrichedPackage]) -> Self {
+        let resolved = enriched.iter().filter(|p| p.license.is_some()).count();
+        Self {
+            resolved,
+            missing: enriched.len() - resolved,
+        }
+    }
+}
+
+impl VulnerabilityCountsBySeverity {
+    /// Counts reported vulnerabilities by severity; ignored ones are not included
+    pub fn of_check_result(result: &VulnerabilityCheckResult) -> Self {
+        let mut counts = Self::default();
+        let all = result.above_threshold.iter().chain(&result.below_threshold);
+        for vulnerability in all.flat_map(|p| p.vulnerabilities()) {
+            match vulnerability.severity() {
+                Severity::Critical => counts.critical += 1,
+                Severity::High => counts.high += 1,
+                Severity::Medium => counts.medium += 1,
+                Severity::Low => counts.low += 1,
+                Severity::None => {}
+            }
+        }
+        counts
+    }
+}
+
+/// Summary statistics of one SBOM generation run
+#[derive(Debug, Clone, Default)]
+pub struct GenerationStats {
+    /// Packages left after group selection and exclusion filters
+    pub total_packages: usize,
+    /// `None` when no dependency graph was built (non-Markdown output, dry-run)
+    pub dependencies: Option<DependencyCounts>,
+    /// `None` when license enrichment did not run (dry-run)
+    pub licenses: Option<LicenseCounts>,
+    /// Vulnerabilities above and below the threshold, excluding ignored ones.
+    /// `None` when the vulnerability check did not run.
+    pub vulnerabilities: Option<VulnerabilityCountsBySeverity>,
+    /// Time spent reading and parsing the lockfile
+    pub parse_time: Duration,
+    /// Time spent fetching licenses; `None` when skipped (dry-run, offline)
+    pub license_fetch_time: Option<Duration>,
+    /// Time spent querying vulnerabilities; `None` when skipped
+    pub cve_check_time: Option<Duration>,
+}
+
+impl GenerationStats {
+    /// Returns `(label, value)` pairs in display order, localized with `messages`.
+    pub fn rows(&self, messages: &Messages) -> Vec<(&'static str, String)> {
+        let skipped = || messages.stats_skipped.to_string();
+        vec![
+            (
+                messages.label_stats_total_packages,
+                self.total_packages.to_string(),
+            ),
+            (
+                messages.label_stats_dependencies,
+                self.dependencies.map_or_else(skipped, |d| {
+                    Messages::format(
+                        messages.stats_dependencies_value,
+                        &[&d.direct.to_string(), &d.transitive.to_string()],
+                    )
+                }),
+            ),
+            (
+                messages.label_stats_licenses,
+                self.licenses.map_or_else(skipped, |l| {
+                    Messages::format(
+                        messages.stats_licenses_value,
+                        &[&l.resolved.to_string(), &l.missing.to_string()],
+                    )
+                }),
+            ),
+            (
+                messages.label_stats_vulnerabilities,
+                self.vulnerabilities.as_ref().map_or_else(skipped, |v| {
+                    Messages::format(
+                        messages.stats_vulnerabilities_value,
+                        &[
+                            &v.critical.to_string(),
+                            &v.high.to_string(),
+                            &v.medium.to_string(),
+                            &v.low.to_string(),
+                        ],
+                    )
+                }),
+            ),
+            (
+                messages.label_stats_parse_time,
+                format_duration(self.parse_time),
+            ),
+            (
+                messages.label_stats_license_time,
+                self.license_fetch_time
+                    .map_or_else(skipped, format_duration),
+            ),
+            (
+                messages.label_stats_cve_time,
+                self.cve_check_time.map_or_else(skipped, format_duration),
+            ),
+        ]
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{} ms", duration.as_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+
+    #[test]
+    fn test_rows_render_skipped_phases() {
+        let stats = GenerationStats {
+            total_packages: 12,
+            parse_time: Duration::from_millis(8),
+            ..Default::default()
+        };
+
+        let rows = stats.rows(Messages::for_locale(Locale::En));
+
+        assert_eq!(
+            rows,
+            vec![
+                ("Total packages", "12".to_string()),
+                ("Dependencies", "skipped".to_string()),
+                ("Licenses", "skipped".to_string()),
+                ("Vulnerabilities", "skipped".to_string()),
+                ("Lockfile parsing", "8 ms".to_string()),
+                ("License fetch", "skipped".to_string()),
+                ("Vulnerability check", "skipped".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rows_render_counts_and_timings() {
+        let stats = GenerationStats {
+            total_packages: 3,
+            dependencies: Some(DependencyCounts {
+                direct: 1,
+                transitive: 2,
+            }),
+            licenses: Some(LicenseCounts {
+                resolved: 2,
+                missing: 1,
+            }),
+            vulnerabilities: Some(VulnerabilityCountsBySeverity {
+                critical: 1,
+                high: 0,
+                medium: 2,
+                low: 0,
+            }),
+            parse_time: Duration::from_millis(3),
+            license_fetch_time: Some(Duration::from_millis(1250)),
+            cve_check_time: Some(Duration::ZERO),
+        };
+
+        let rows = stats.rows(Messages::for_locale(Locale::Ja));
+
+        assert_eq!(rows[1].1, "直接 1 件、間接 2 件");
+        assert_eq!(rows[2].1, "判明 2 件、不明 1 件");
+        assert_eq!(rows[3].1, "CRITICAL 1 件、HIGH 0 件、MEDIUM 2 件、LOW 0 件");
+        assert_eq!(rows[5].1, "1250 ms");
+        assert_eq!(rows[6].1, "0 ms");
+    }
+}
@@ -0,0 +1,40 @@
+/// Verdict for one dependency license against the project license.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompatibilityVerdictView {
+    Compatible,
+    Conditional,
+    NeedsReview,
+    Incompatible,
+}
+
+/// View representation of one package's compatibility verdict.
+#[derive(Debug, Clone)]
+pub struct LicenseCompatibilityEntryView {
+    pub package_name: String,
+    pub package_version: String,
+    /// The license string, or "N/A" for unknown.
+    pub license: String,
+    pub verdict: CompatibilityVerdictView,
+}
+
+/// Number of packages per verdict.
+#[derive(Debug, Clone)]
+pub struct LicenseCompatibilitySummary {
+    pub compatible_count: usize,
+    pub conditional_count: usize,
+    pub incompatible_count: usize,
+    pub needs_review_count: usize,
+}
+
+/// Top-level view for the license compatibility section of the report.
+#[derive(Debug, Clone)]
+pub struct LicenseCompatibilityView {
+    /// The project license, or `None` when it is not declared.
+    pub project_license: Option<String>,
+    /// Family the project license was classified as (e.g. "permissive"),
+    /// or `None` when it is unknown or unclassified.
+    pub project_family: Option<String>,
+    /// Every package that is not plainly compatible, most severe first.
+    pub findings: Vec<LicenseCompatibilityEntryView>,
+    pub summary: LicenseCompatibilitySummary,
+}
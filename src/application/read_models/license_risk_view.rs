@@ -0,0 +1,21 @@
+/// View representation of one reachable package whose license needs review.
+#[derive(Debug, Clone)]
+pub struct LicenseRiskEntryView {
+    pub package_name: String,
+    pub package_version: String,
+    /// The license string, or "N/A" for unknown.
+    pub license: String,
+    /// Shortest path from a direct dependency, ending with the package itself.
+    pub introduction_path: Vec<String>,
+    /// Number of paths through which the project depends on the package.
+    pub path_count: usize,
+}
+
+/// Top-level view for the license risk section of the report.
+#[derive(Debug, Clone, Default)]
+pub struct LicenseRiskView {
+    /// Reachable copyleft packages, sorted by package name.
+    pub copyleft: Vec<LicenseRiskEntryView>,
+    /// Reachable packages without a known license; only filled in strict mode.
+    pub unknown: Vec<LicenseRiskEntryView>,
+}
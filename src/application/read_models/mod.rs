@@ -6,33 +6,69 @@
 pub mod abandoned_package;
 pub mod component_view;
 pub mod dependency_view;
+pub mod fix_suggestion_view;
+pub mod generation_stats;
+pub mod license_compatibility_view;
 pub mod license_compliance_view;
+pub mod license_risk_view;
+pub mod project_view;
+pub mod python_compatibility_view;
 pub mod resolution_guide_view;
+pub mod sbom_diff_view;
 pub mod sbom_read_model;
 pub mod sbom_read_model_builder;
+pub mod sbom_warning;
+pub mod typosquat_view;
 pub mod upgrade_recommendation_view;
+pub mod version_conflict_view;
 pub mod vulnerability_view;
 
 #[allow(unused_imports)]
 pub use abandoned_package::{AbandonedPackageView, AbandonedPackagesReport};
 #[allow(unused_imports)]
-pub use component_view::{ComponentView, LicenseView};
+pub use component_view::{ComponentView, LicenseOverrideView, LicenseView};
 #[allow(unused_imports)]
 pub use dependency_view::DependencyView;
 #[allow(unused_imports)]
+pub use fix_suggestion_view::{FixSuggestionView, FixSuggestionsView, UnfixedPackageView};
+#[allow(unused_imports)]
+pub use generation_stats::{DependencyCounts, GenerationStats, LicenseCounts};
+#[allow(unused_imports)]
+pub use license_compatibility_view::{
+    CompatibilityVerdictView, LicenseCompatibilityEntryView, LicenseCompatibilitySummary,
+    LicenseCompatibilityView,
+};
+#[allow(unused_imports)]
 pub use license_compliance_view::{
     LicenseComplianceSummary, LicenseComplianceView, LicenseViolationView, LicenseWarningView,
 };
 #[allow(unused_imports)]
+pub use license_risk_view::{LicenseRiskEntryView, LicenseRiskView};
+#[allow(unused_imports)]
+pub use project_view::ProjectView;
+#[allow(unused_imports)]
+pub use python_compatibility_view::PythonCompatibilityView;
+#[allow(unused_imports)]
 pub use resolution_guide_view::{IntroducedByView, ResolutionEntryView, ResolutionGuideView};
 #[allow(unused_imports)]
-pub use sbom_read_model::{MetadataComponentView, SbomMetadataView, SbomReadModel};
+pub use sbom_diff_view::{DiffPackageView, LicenseChangeView, SbomDiffView, VersionChangeView};
+#[allow(unused_imports)]
+pub use sbom_read_model::{
+    InvocationParameterView, InvocationView, MetadataComponentView, SbomMetadataView, SbomReadModel,
+};
 #[allow(unused_imports)]
 pub use sbom_read_model_builder::SbomReadModelBuilder;
 #[allow(unused_imports)]
+pub use sbom_warning::{FetchFailureCategory, SbomWarning};
+#[allow(unused_imports)]
+pub use typosquat_view::TyposquatView;
+#[allow(unused_imports)]
 pub use upgrade_recommendation_view::{UpgradeEntryView, UpgradeRecommendationView};
 #[allow(unused_imports)]
+pub use version_conflict_view::VersionConflictView;
+#[allow(unused_imports)]
 pub use vulnerability_view::{
+    AnalysisStateView, CvssVersionView, IgnoredVulnerabilityView, InformationalDisplay,
     SeverityView, VulnerabilityCountsBySeverity, VulnerabilityReportView, VulnerabilitySummary,
     VulnerabilityView,
 };
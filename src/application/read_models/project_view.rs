@@ -0,0 +1,49 @@
+//! Source project view for aggregated SBOMs
+//!
+//! An SBOM merged from several projects keeps one entry per project so that
+//! formatters can show which packages each project depends on directly.
+
+use crate::sbom_generation::domain::PackageName;
+
+/// View representation of one project in an aggregated SBOM
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectView {
+    /// Project name from `pyproject.toml`, or the directory name
+    pub name: String,
+    /// Project version; `None` when neither `pyproject.toml` nor the lockfile records one
+    pub version: Option<String>,
+    /// BOM references of the project's direct dependencies
+    pub direct_dependencies: Vec<String>,
+}
+
+impl ProjectView {
+    /// BOM reference of the project, `{normalized name}-{version}` like any other component
+    pub fn bom_ref(&self) -> String {
+        let name = PackageName::normalize(&self.name);
+        match &self.version {
+            Some(version) => format!("{}-{}", name, version),
+            None => name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bom_ref_matches_component_bom_refs() {
+        let project = ProjectView {
+            name: "My_Service".to_string(),
+            version: Some("1.2.0".to_string()),
+            direct_dependencies: vec![],
+        };
+        assert_eq!(project.bom_ref(), "my-service-1.2.0");
+
+        let unversioned = ProjectView {
+            version: None,
+            ..project
+        };
+        assert_eq!(unversioned.bom_ref(), "my-service");
+    }
+}
@@ -0,0 +1,8 @@
+/// Top-level view for the Python compatibility section of the report.
+///
+/// The incompatible packages are the components whose `python_compat` is set.
+#[derive(Debug, Clone)]
+pub struct PythonCompatibilityView {
+    /// The project's `requires-python`, as written in pyproject.toml.
+    pub project_requires_python: String,
+}
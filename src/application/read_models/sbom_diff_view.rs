@@ -0,0 +1,97 @@
+//! Read model for the difference between two SBOMs
+
+use crate::sbom_generation::domain::{LicenseChange, PackageEntry, SbomDiff, VersionChange};
+
+/// View of an SBOM comparison, as rendered by the diff formatters
+#[derive(Debug, Clone, Default)]
+pub struct SbomDiffView {
+    pub added: Vec<DiffPackageView>,
+    pub removed: Vec<DiffPackageView>,
+    pub updated: Vec<VersionChangeView>,
+    pub license_changed: Vec<LicenseChangeView>,
+}
+
+impl SbomDiffView {
+    /// Returns true when the two SBOMs have no package differences
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.updated.is_empty()
+            && self.license_changed.is_empty()
+    }
+}
+
+/// A package added to or removed from the head SBOM
+#[derive(Debug, Clone)]
+pub struct DiffPackageView {
+    pub name: String,
+    pub version: String,
+    pub license: Option<String>,
+}
+
+/// A package whose version changed (old → new)
+#[derive(Debug, Clone)]
+pub struct VersionChangeView {
+    pub name: String,
+    pub old_version: String,
+    pub new_version: String,
+    pub old_license: Option<String>,
+    pub new_license: Option<String>,
+}
+
+/// A package whose only change is its license
+#[derive(Debug, Clone)]
+pub struct LicenseChangeView {
+    pub name: String,
+    pub version: String,
+    pub old_license: Option<String>,
+    pub new_license: Option<String>,
+}
+
+impl From<&SbomDiff> for SbomDiffView {
+    fn from(diff: &SbomDiff) -> Self {
+        Self {
+            added: diff.added().iter().map(DiffPackageView::from).collect(),
+            removed: diff.removed().iter().map(DiffPackageView::from).collect(),
+            updated: diff.updated().iter().map(VersionChangeView::from).collect(),
+            license_changed: diff
+                .license_changed()
+                .iter()
+                .map(LicenseChangeView::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&PackageEntry> for DiffPackageView {
+    fn from(entry: &PackageEntry) -> Self {
+        Self {
+            name: entry.name.clone(),
+            version: entry.version.clone(),
+            license: entry.license.clone(),
+        }
+    }
+}
+
+impl From<&VersionChange> for VersionChangeView {
+    fn from(change: &VersionChange) -> Self {
+        Self {
+            name: change.name.clone(),
+            old_version: change.old_version.clone(),
+            new_version: change.new_version.clone(),
+            old_license: change.old_license.clone(),
+            new_license: change.new_license.clone(),
+        }
+    }
+}
+
+impl From<&LicenseChange> for LicenseChangeView {
+    fn from(change: &LicenseChange) -> Self {
+        Self {
+            name: change.name.clone(),
+            version: change.version.clone(),
+            old_license: change.old_license.clone(),
+            new_license: change.new_license.clone(),
+        }
+    }
+}
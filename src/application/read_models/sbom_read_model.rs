@@ -3,12 +3,24 @@
 //! This module provides the main read model struct that aggregates
 //! all SBOM data in a query-optimized format.
 
-use super::component_view::ComponentView;
+use super::component_view::{ComponentView, LicenseOverrideView};
 use super::dependency_view::DependencyView;
+use super::fix_suggestion_view::FixSuggestionsView;
+use super::generation_stats::GenerationStats;
+use super::license_compatibility_view::LicenseCompatibilityView;
 use super::license_compliance_view::LicenseComplianceView;
+use super::license_risk_view::LicenseRiskView;
+use super::project_view::ProjectView;
+use super::python_compatibility_view::PythonCompatibilityView;
 use super::resolution_guide_view::ResolutionGuideView;
+use super::sbom_warning::SbomWarning;
+use super::typosquat_view::TyposquatView;
 use super::upgrade_recommendation_view::UpgradeRecommendationView;
-use super::vulnerability_view::VulnerabilityReportView;
+use super::version_conflict_view::VersionConflictView;
+use super::vulnerability_view::{InformationalDisplay, VulnerabilityReportView, VulnerabilityView};
+use crate::sbom_generation::domain::{
+    LicenseOverride, PackageAnnotation, PythonCompatibilityReport, TyposquatFinding,
+};
 
 /// Main read model for SBOM data
 ///
@@ -26,11 +38,227 @@ pub struct SbomReadModel {
     pub vulnerabilities: Option<VulnerabilityReportView>,
     /// License compliance report
     pub license_compliance: Option<LicenseComplianceView>,
+    /// License compatibility with the project's own license
+    pub license_compatibility: Option<LicenseCompatibilityView>,
+    /// Copyleft packages reachable from the project.
+    /// Populated only when `check_license_reachability` was true in the request.
+    pub license_risk: Option<LicenseRiskView>,
+    /// The project's Python range, when packages were checked against it.
+    /// Populated only when `check_python_compat` was true in the request.
+    pub python_compatibility: Option<PythonCompatibilityView>,
+    /// Packages whose names are near misses of popular package names.
+    /// Empty unless `check_typosquats` was true in the request.
+    pub typosquats: Vec<TyposquatView>,
     /// Resolution guide for vulnerable transitive dependencies
     pub resolution_guide: Option<ResolutionGuideView>,
     /// Upgrade recommendations for vulnerable transitive dependencies.
     /// Populated only when `suggest_fix` was true in the request.
     pub upgrade_recommendations: Option<UpgradeRecommendationView>,
+    /// Upgrades fixing the actionable vulnerabilities.
+    /// Populated only when `suggest_fixes` was true in the request.
+    pub fix_suggestions: Option<FixSuggestionsView>,
+    /// Vulnerabilities affecting the project's own published version.
+    /// Populated only when `check_self` was true in the request.
+    pub project_self_check: Option<VulnerabilityReportView>,
+    /// Non-fatal warnings raised while generating the SBOM
+    pub warnings: Vec<SbomWarning>,
+    /// Generation counts and timings, present only when `--stats` was passed
+    pub stats: Option<GenerationStats>,
+    /// Source projects of an SBOM aggregated by `uv-sbom merge`; empty for a single project
+    pub projects: Vec<ProjectView>,
+    /// Packages present at more than one version, sorted by name
+    pub version_conflicts: Vec<VersionConflictView>,
+}
+
+impl SbomReadModel {
+    /// Attaches the warnings raised while generating the SBOM.
+    pub fn with_warnings(mut self, warnings: Vec<SbomWarning>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    /// Attaches the generation statistics.
+    pub fn with_stats(mut self, stats: Option<GenerationStats>) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Records the SHA-256 digest of the lockfile the SBOM was generated from.
+    pub fn with_lockfile_sha256(mut self, lockfile_sha256: Option<String>) -> Self {
+        self.metadata.lockfile_sha256 = lockfile_sha256;
+        self
+    }
+
+    /// Records the platform and marker variables the SBOM was restricted to.
+    pub fn with_target_environment(mut self, target_environment: Option<String>) -> Self {
+        self.metadata.target_environment = target_environment;
+        self
+    }
+
+    /// Sets how much of the informational vulnerabilities reports list.
+    ///
+    /// Only the rendering changes: the vulnerabilities stay in the model, so
+    /// the CycloneDX output and the summary counts are unaffected.
+    pub fn with_informational_display(mut self, display: InformationalDisplay) -> Self {
+        if let Some(vulnerabilities) = self.vulnerabilities.as_mut() {
+            vulnerabilities.informational_display = display;
+        }
+        self
+    }
+
+    /// Returns how many packages the vulnerability check could not query.
+    pub fn unchecked_package_count(&self) -> usize {
+        self.warnings
+            .iter()
+            .filter(|w| matches!(w, SbomWarning::VulnerabilityCheckFailed { .. }))
+            .count()
+    }
+
+    /// Attaches the vulnerability report for the project's own published version.
+    pub fn with_project_self_check(mut self, report: Option<VulnerabilityReportView>) -> Self {
+        self.project_self_check = report;
+        self
+    }
+
+    /// Attaches the license compatibility report.
+    pub fn with_license_compatibility(mut self, view: Option<LicenseCompatibilityView>) -> Self {
+        self.license_compatibility = view;
+        self
+    }
+
+    /// Attaches the license risk report.
+    pub fn with_license_risk(mut self, view: Option<LicenseRiskView>) -> Self {
+        self.license_risk = view;
+        self
+    }
+
+    /// Attaches the Python compatibility check and marks the components whose
+    /// `requires-python` excludes Python versions the project supports.
+    pub fn with_python_compatibility(mut self, report: Option<&PythonCompatibilityReport>) -> Self {
+        let Some(report) = report else {
+            return self;
+        };
+        for component in &mut self.components {
+            component.python_compat = report
+                .incompatible
+                .iter()
+                .find(|i| {
+                    i.package_name == component.name && i.package_version == component.version
+                })
+                .map(|i| i.requires_python.clone());
+        }
+        self.python_compatibility = Some(PythonCompatibilityView {
+            project_requires_python: report.project_requires_python.clone(),
+        });
+        self
+    }
+
+    /// Attaches the packages the typosquat check flagged.
+    pub fn with_typosquat_findings(mut self, findings: Option<&[TyposquatFinding]>) -> Self {
+        self.typosquats = findings
+            .unwrap_or_default()
+            .iter()
+            .map(|finding| TyposquatView {
+                package_name: finding.package_name.clone(),
+                package_version: finding.package_version.clone(),
+                similar_to: finding.similar_to.clone(),
+            })
+            .collect();
+        self
+    }
+
+    /// Attaches the suggested fixes for actionable vulnerabilities.
+    pub fn with_fix_suggestions(mut self, view: Option<FixSuggestionsView>) -> Self {
+        self.fix_suggestions = view;
+        self
+    }
+
+    /// Attaches project-declared annotations to the matching components.
+    ///
+    /// Package names are matched after PEP 503 normalization; annotations that
+    /// match no component are ignored.
+    pub fn with_annotations(mut self, annotations: &[PackageAnnotation]) -> Self {
+        for component in &mut self.components {
+            for annotation in annotations.iter().filter(|a| a.applies_to(&component.name)) {
+                component.annotations.extend(
+                    annotation
+                        .fields()
+                        .iter()
+                        .map(|(key, value)| (key.clone(), value.clone())),
+                );
+            }
+        }
+        self
+    }
+
+    /// Marks the components whose license was set by a `license_overrides` entry.
+    ///
+    /// Package names are matched after PEP 503 normalization.
+    pub fn with_license_overrides(mut self, overrides: &[LicenseOverride]) -> Self {
+        for component in &mut self.components {
+            if let Some(license_override) = overrides.iter().find(|o| o.applies_to(&component.name))
+            {
+                component.license_override = Some(LicenseOverrideView {
+                    reason: license_override.reason().map(str::to_string),
+                });
+            }
+        }
+        self
+    }
+
+    /// Sorts every list in the model so that formatting does not depend on the
+    /// order in which packages were read or vulnerabilities were returned.
+    ///
+    /// Components are ordered by name and version, dependency lists by bom-ref,
+    /// and vulnerabilities by affected package, version and ID. Used by
+    /// `--reproducible`.
+    pub fn with_stable_order(mut self) -> Self {
+        self.components
+            .sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+        if let Some(dependencies) = &mut self.dependencies {
+            dependencies.direct.sort();
+            for children in dependencies.transitive.values_mut() {
+                children.sort();
+            }
+        }
+        for project in &mut self.projects {
+            project.direct_dependencies.sort();
+        }
+        for report in self
+            .vulnerabilities
+            .iter_mut()
+            .chain(self.project_self_check.iter_mut())
+        {
+            report.actionable.sort_by(compare_vulnerabilities);
+            report.informational.sort_by(compare_vulnerabilities);
+            report
+                .ignored
+                .sort_by(|a, b| compare_vulnerabilities(&a.vulnerability, &b.vulnerability));
+        }
+        if let Some(guide) = &mut self.resolution_guide {
+            guide.entries.sort_by(|a, b| {
+                (&a.vulnerable_package, &a.vulnerability_id)
+                    .cmp(&(&b.vulnerable_package, &b.vulnerability_id))
+            });
+        }
+        if let Some(compliance) = &mut self.license_compliance {
+            compliance.violations.sort_by(|a, b| {
+                (&a.package_name, &a.package_version).cmp(&(&b.package_name, &b.package_version))
+            });
+            compliance.warnings.sort_by(|a, b| {
+                (&a.package_name, &a.package_version).cmp(&(&b.package_name, &b.package_version))
+            });
+        }
+        self
+    }
+}
+
+fn compare_vulnerabilities(a: &VulnerabilityView, b: &VulnerabilityView) -> std::cmp::Ordering {
+    (&a.affected_component_name, &a.affected_version, &a.id).cmp(&(
+        &b.affected_component_name,
+        &b.affected_version,
+        &b.id,
+    ))
 }
 
 /// View representation of SBOM metadata
@@ -46,6 +274,32 @@ pub struct SbomMetadataView {
     pub serial_number: String,
     /// The main project component being analyzed
     pub component: Option<MetadataComponentView>,
+    /// SHA-256 digest of the lockfile the SBOM was generated from, if recorded
+    pub lockfile_sha256: Option<String>,
+    /// Target environment the packages were filtered for, e.g. `linux`
+    pub target_environment: Option<String>,
+    /// Options the SBOM was generated with, if recorded
+    pub invocation: Option<InvocationView>,
+    /// Format version of the lockfile, as written in it
+    pub lockfile_version: Option<String>,
+    /// Python versions the lockfile's resolution supports
+    pub lockfile_requires_python: Option<String>,
+}
+
+/// View of the options an SBOM was generated with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvocationView {
+    /// Option name and value pairs in a fixed order; options that were not
+    /// set are left out
+    pub parameters: Vec<InvocationParameterView>,
+}
+
+/// A single generation option, e.g. `severity-threshold` = `high`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvocationParameterView {
+    /// Option name in kebab case
+    pub name: String,
+    pub value: String,
 }
 
 /// View representation of the main project component in metadata
@@ -53,6 +307,52 @@ pub struct SbomMetadataView {
 pub struct MetadataComponentView {
     /// Component name (project name)
     pub name: String,
-    /// Component version
-    pub version: String,
+    /// Component version; `None` when the project declares it dynamic
+    pub version: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::application::read_models::SeverityView;
+    use crate::test_fixtures::SbomReadModelFixture;
+
+    #[test]
+    fn test_with_stable_order_sorts_components_and_vulnerabilities() {
+        let mut fixture = SbomReadModelFixture::builder();
+        fixture.add_component("urllib3", "1.26.0");
+        fixture.add_component("requests", "2.31.0").direct();
+        fixture.add_component("certifi", "2024.2.2").direct();
+        fixture.with_dependencies(&[("requests", &["urllib3", "certifi"])]);
+        for (id, package, version) in [
+            ("CVE-2024-0002", "urllib3", "1.26.0"),
+            ("CVE-2024-0003", "requests", "2.31.0"),
+            ("CVE-2024-0001", "urllib3", "1.26.0"),
+        ] {
+            fixture
+                .add_vulnerability(id)
+                .severity(SeverityView::High)
+                .on(package, version);
+        }
+
+        let model = fixture.build().with_stable_order();
+
+        let names: Vec<&str> = model.components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["certifi", "requests", "urllib3"]);
+        let dependencies = model.dependencies.unwrap();
+        assert_eq!(
+            dependencies.direct,
+            vec!["certifi-2024.2.2", "requests-2.31.0"]
+        );
+        assert_eq!(
+            dependencies.transitive["requests-2.31.0"],
+            vec!["certifi-2024.2.2", "urllib3-1.26.0"]
+        );
+        let vulnerabilities = model.vulnerabilities.unwrap();
+        let ids: Vec<&str> = vulnerabilities
+            .actionable
+            .iter()
+            .map(|v| v.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["CVE-2024-0003", "CVE-2024-0001", "CVE-2024-0002"]);
+    }
 }
@@ -1,6 +1,7 @@
 use crate::ports::outbound::EnrichedPackage;
-use crate::sbom_generation::domain::DependencyGraph;
+use crate::sbom_generation::domain::{DependencyGraph, LicenseSource, PackageName};
 use crate::sbom_generation::policies::spdx_license_map;
+use std::collections::BTreeMap;
 
 use super::super::component_view::{ComponentView, LicenseView};
 
@@ -18,8 +19,10 @@ fn build_component(enriched: &EnrichedPackage, graph: Option<&DependencyGraph>)
     let name = enriched.package.name();
     let version = enriched.package.version();
 
-    let bom_ref = format!("{}-{}", name, version);
-    let purl = format!("pkg:pypi/{}@{}", name, version);
+    // PyPI purls require the PEP 503 normalized name; `name` keeps the lockfile spelling
+    let normalized = PackageName::normalize(name);
+    let bom_ref = format!("{}-{}", normalized, version);
+    let purl = format!("pkg:pypi/{}@{}", normalized, version);
 
     let is_direct = graph
         .map(|g| {
@@ -34,6 +37,10 @@ fn build_component(enriched: &EnrichedPackage, graph: Option<&DependencyGraph>)
         LicenseView {
             spdx_id,
             name: license_str.clone(),
+            source: match enriched.license_source {
+                LicenseSource::Unknown | LicenseSource::NotFound => None,
+                source => Some(source.as_str().to_string()),
+            },
         }
     });
 
@@ -46,6 +53,21 @@ fn build_component(enriched: &EnrichedPackage, graph: Option<&DependencyGraph>)
         description: enriched.description.clone(),
         sha256_hash: enriched.sha256_hash.clone(),
         is_direct_dependency: is_direct,
+        annotations: BTreeMap::new(),
+        license_override: None,
+        yanked: enriched.yanked.clone(),
+        source: enriched.package.source().cloned(),
+        declared_constraint: enriched.package.declared_constraint().map(str::to_string),
+        license_not_found: enriched.license.is_none()
+            && enriched.license_source == LicenseSource::NotFound,
+        license_fetch_error: enriched
+            .fetch_error()
+            .filter(|_| enriched.license.is_none())
+            .map(str::to_string),
+        python_compat: None,
+        author: enriched.author.clone(),
+        supplier: enriched.supplier.clone(),
+        external_refs: enriched.external_refs.clone(),
     }
 }
 
@@ -53,8 +75,8 @@ fn build_component(enriched: &EnrichedPackage, graph: Option<&DependencyGraph>)
 mod tests {
     use super::super::test_helpers as th;
     use super::*;
-    use crate::ports::outbound::EnrichedPackage;
-    use crate::sbom_generation::domain::Package;
+    use crate::ports::outbound::{EnrichedPackage, EnrichmentStatus};
+    use crate::sbom_generation::domain::{Package, PackageSource};
 
     #[test]
     fn test_build_components_generates_bom_ref() {
@@ -73,6 +95,43 @@ mod tests {
         assert_eq!(components[0].purl, "pkg:pypi/requests@2.31.0");
     }
 
+    #[test]
+    fn test_build_components_normalizes_purl_and_bom_ref() {
+        let packages = vec![
+            th::package("Django", "4.2.7"),
+            th::package("zope.interface", "6.1"),
+            th::package("ruamel.yaml", "0.18.5"),
+            th::package("typing_extensions", "4.9.0"),
+        ];
+        let components = build_components(&packages, None);
+
+        let refs: Vec<(&str, &str, &str)> = components
+            .iter()
+            .map(|c| (c.name.as_str(), c.purl.as_str(), c.bom_ref.as_str()))
+            .collect();
+        assert_eq!(
+            refs,
+            vec![
+                ("Django", "pkg:pypi/django@4.2.7", "django-4.2.7"),
+                (
+                    "zope.interface",
+                    "pkg:pypi/zope-interface@6.1",
+                    "zope-interface-6.1"
+                ),
+                (
+                    "ruamel.yaml",
+                    "pkg:pypi/ruamel-yaml@0.18.5",
+                    "ruamel-yaml-0.18.5"
+                ),
+                (
+                    "typing_extensions",
+                    "pkg:pypi/typing-extensions@4.9.0",
+                    "typing-extensions-4.9.0"
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_build_components_with_license() {
         let packages = vec![th::package("requests", "2.31.0")];
@@ -83,6 +142,111 @@ mod tests {
         assert_eq!(license.spdx_id, Some("MIT".to_string()));
     }
 
+    #[test]
+    fn test_build_components_records_known_license_source() {
+        let packages = vec![
+            th::package("requests", "2.31.0").with_license_source(LicenseSource::PyPiClassifier),
+            th::package("urllib3", "2.0.0"),
+        ];
+        let components = build_components(&packages, None);
+
+        let source = |i: usize| components[i].license.as_ref().unwrap().source.clone();
+        assert_eq!(source(0).as_deref(), Some("pypi-classifier"));
+        assert_eq!(source(1), None);
+    }
+
+    #[test]
+    fn test_build_components_marks_packages_not_on_index() {
+        let packages = vec![
+            EnrichedPackage::new(
+                Package::new("internal-lib".to_string(), "0.1.0".to_string()).unwrap(),
+                None,
+                None,
+            )
+            .with_license_source(LicenseSource::NotFound),
+            EnrichedPackage::new(
+                Package::new("urllib3".to_string(), "2.0.0".to_string()).unwrap(),
+                None,
+                None,
+            ),
+        ];
+        let components = build_components(&packages, None);
+
+        assert!(components[0].license.is_none());
+        assert!(components[0].license_not_found);
+        assert!(!components[1].license_not_found);
+    }
+
+    #[test]
+    fn test_build_components_carries_license_fetch_error() {
+        let packages = vec![
+            EnrichedPackage::new(
+                Package::new("broken".to_string(), "1.0.0".to_string()).unwrap(),
+                None,
+                None,
+            )
+            .with_enrichment_status(EnrichmentStatus::FetchFailed("timed out".to_string())),
+            th::package("requests", "2.31.0"),
+        ];
+        let components = build_components(&packages, None);
+
+        assert_eq!(
+            components[0].license_fetch_error.as_deref(),
+            Some("timed out")
+        );
+        assert!(!components[0].license_not_found);
+        assert_eq!(components[1].license_fetch_error, None);
+    }
+
+    #[test]
+    fn test_build_components_carries_yank_reason() {
+        let packages = vec![
+            th::package("urllib3", "2.0.0").with_yanked(Some("Broken wheel".to_string())),
+            th::package("requests", "2.31.0"),
+        ];
+        let components = build_components(&packages, None);
+
+        assert_eq!(components[0].yanked.as_deref(), Some("Broken wheel"));
+        assert_eq!(components[1].yanked, None);
+    }
+
+    #[test]
+    fn test_build_components_carries_package_source() {
+        let source = PackageSource::Git {
+            url: "https://github.com/encode/httpx".to_string(),
+            revision: None,
+        };
+        let package = Package::new("httpx".to_string(), "0.28.0".to_string())
+            .unwrap()
+            .with_source(Some(source.clone()));
+        let packages = vec![
+            EnrichedPackage::new(package, None, None),
+            th::package("requests", "2.31.0"),
+        ];
+        let components = build_components(&packages, None);
+
+        assert_eq!(components[0].source, Some(source));
+        assert_eq!(components[1].source, None);
+    }
+
+    #[test]
+    fn test_build_components_carries_declared_constraint() {
+        let package = Package::new("requests".to_string(), "2.31.0".to_string())
+            .unwrap()
+            .with_declared_constraint(Some("requests[socks]>=2.28,<3".to_string()));
+        let packages = vec![
+            EnrichedPackage::new(package, None, None),
+            th::package("urllib3", "2.0.0"),
+        ];
+        let components = build_components(&packages, None);
+
+        assert_eq!(
+            components[0].declared_constraint.as_deref(),
+            Some("requests[socks]>=2.28,<3")
+        );
+        assert_eq!(components[1].declared_constraint, None);
+    }
+
     #[test]
     fn test_build_components_without_license() {
         let package = EnrichedPackage::new(
@@ -1,44 +1,87 @@
 use super::super::component_view::ComponentView;
 use super::super::dependency_view::DependencyView;
-use crate::sbom_generation::domain::DependencyGraph;
+use crate::sbom_generation::domain::{DependencyGraph, PackageName};
 use std::collections::HashMap;
 
 pub(super) fn build_dependencies(
     graph: &DependencyGraph,
     components: &[ComponentView],
 ) -> DependencyView {
-    // Create a lookup map from package name to bom-ref
-    let name_to_bom_ref: HashMap<&str, &str> = components
-        .iter()
-        .map(|c| (c.name.as_str(), c.bom_ref.as_str()))
-        .collect();
+    // Create a lookup map from package name to bom-refs. The graph is keyed by
+    // name, so a package locked at several versions resolves to all of them,
+    // in component order.
+    let mut name_to_bom_refs: HashMap<&str, Vec<&str>> = HashMap::new();
+    for c in components {
+        name_to_bom_refs
+            .entry(c.name.as_str())
+            .or_default()
+            .push(c.bom_ref.as_str());
+    }
+    let bom_refs_of = |name: &PackageName| -> Vec<String> {
+        name_to_bom_refs
+            .get(name.as_str())
+            .into_iter()
+            .flatten()
+            .map(|s| s.to_string())
+            .collect()
+    };
 
     // Map direct dependencies to bom-refs
     let direct: Vec<String> = graph
         .direct_dependencies()
         .iter()
-        .filter_map(|dep| name_to_bom_ref.get(dep.as_str()).map(|s| s.to_string()))
+        .flat_map(bom_refs_of)
         .collect();
 
     // Build transitive dependency map
-    let transitive: HashMap<String, Vec<String>> = graph
-        .transitive_dependencies()
+    let mut transitive: HashMap<String, Vec<String>> = HashMap::new();
+    for (parent, children) in graph.transitive_dependencies() {
+        let child_bom_refs: Vec<String> = children.iter().flat_map(bom_refs_of).collect();
+        if child_bom_refs.is_empty() {
+            continue;
+        }
+        for parent_bom_ref in bom_refs_of(parent) {
+            transitive.insert(parent_bom_ref, child_bom_refs.clone());
+        }
+    }
+
+    // Immediate children of every component, for the dependency tree
+    let children: HashMap<String, Vec<String>> = components
         .iter()
-        .filter_map(|(parent, children)| {
-            let parent_bom_ref = name_to_bom_ref.get(parent.as_str())?;
-            let child_bom_refs: Vec<String> = children
+        .filter_map(|c| {
+            let name = PackageName::new(c.name.clone()).ok()?;
+            let child_bom_refs: Vec<String> = graph
+                .children_of(&name)
                 .iter()
-                .filter_map(|child| name_to_bom_ref.get(child.as_str()).map(|s| s.to_string()))
+                .flat_map(bom_refs_of)
                 .collect();
-            if child_bom_refs.is_empty() {
-                None
-            } else {
-                Some((parent_bom_ref.to_string(), child_bom_refs))
-            }
+            (!child_bom_refs.is_empty()).then(|| (c.bom_ref.clone(), child_bom_refs))
+        })
+        .collect();
+
+    // Extras each component is requested with, by any parent
+    let extras: HashMap<String, Vec<String>> = components
+        .iter()
+        .filter_map(|c| {
+            let name = PackageName::new(c.name.clone()).ok()?;
+            let extras = graph.requested_extras(&name);
+            (!extras.is_empty()).then(|| (c.bom_ref.clone(), extras))
         })
         .collect();
 
-    DependencyView { direct, transitive }
+    let unreferenced: Vec<String> = graph
+        .unreferenced_packages()
+        .iter()
+        .flat_map(bom_refs_of)
+        .collect();
+
+    DependencyView {
+        direct,
+        transitive,
+        children,
+        extras,
+        unreferenced,
+    }
 }
 
 #[cfg(test)]
@@ -70,6 +113,26 @@ mod tests {
         assert!(deps.direct.contains(&"urllib3-2.0.0".to_string()));
     }
 
+    #[test]
+    fn test_build_dependencies_maps_unreferenced_to_bom_refs() {
+        let packages = vec![
+            th::package("boto3", "1.34.0"),
+            th::package("flask", "3.0.0"),
+        ];
+        let components = component_builder::build_components(&packages, None);
+
+        let graph = DependencyGraph::new(
+            vec![PackageName::new("boto3".to_string()).unwrap()],
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .with_unreferenced_packages(vec![PackageName::new("flask".to_string()).unwrap()]);
+
+        let deps = build_dependencies(&graph, &components);
+
+        assert_eq!(deps.unreferenced, ["flask-3.0.0"]);
+    }
+
     #[test]
     fn test_build_dependencies_builds_transitive_map() {
         let packages = vec![
@@ -118,6 +181,35 @@ mod tests {
         assert!(deps.direct.contains(&"requests-2.31.0".to_string()));
     }
 
+    #[test]
+    fn test_build_dependencies_keeps_every_version_of_a_package() {
+        let packages = vec![
+            th::package("numpy", "1.26.4"),
+            th::package("scipy", "1.13.0"),
+            th::package("numpy", "2.0.0"),
+        ];
+        let components = component_builder::build_components(&packages, None);
+
+        let name = |n: &str| PackageName::new(n.to_string()).unwrap();
+        let graph = DependencyGraph::new(
+            vec![name("scipy")],
+            HashMap::from([(name("scipy"), vec![name("numpy")])]),
+            HashMap::from([(name("scipy"), vec![name("numpy")])]),
+        );
+
+        let deps = build_dependencies(&graph, &components);
+
+        assert_eq!(deps.direct, ["scipy-1.13.0"]);
+        assert_eq!(
+            deps.transitive["scipy-1.13.0"],
+            ["numpy-1.26.4", "numpy-2.0.0"]
+        );
+        assert_eq!(
+            deps.children["scipy-1.13.0"],
+            ["numpy-1.26.4", "numpy-2.0.0"]
+        );
+    }
+
     #[test]
     fn test_build_dependencies_empty_graph() {
         let packages = vec![th::package("requests", "2.31.0")];
@@ -129,4 +221,64 @@ mod tests {
         assert!(deps.direct.is_empty());
         assert!(deps.transitive.is_empty());
     }
+
+    #[test]
+    fn test_build_dependencies_maps_extras_to_bom_refs() {
+        let packages = vec![
+            th::package("requests", "2.31.0"),
+            th::package("pysocks", "1.7.1"),
+        ];
+        let components = component_builder::build_components(&packages, None);
+
+        let requests = PackageName::new("requests".to_string()).unwrap();
+        let graph = DependencyGraph::new(vec![requests.clone()], HashMap::new(), HashMap::new())
+            .with_edge_extras(HashMap::from([(
+                PackageName::new("my-project".to_string()).unwrap(),
+                HashMap::from([(requests, vec!["socks".to_string()])]),
+            )]));
+
+        let deps = build_dependencies(&graph, &components);
+
+        assert_eq!(
+            deps.extras,
+            HashMap::from([("requests-2.31.0".to_string(), vec!["socks".to_string()])])
+        );
+    }
+
+    #[test]
+    fn test_build_dependencies_maps_children_to_bom_refs() {
+        let packages = vec![
+            th::package("requests", "2.31.0"),
+            th::package("urllib3", "2.0.0"),
+            th::package("certifi", "2023.7.22"),
+        ];
+        let components = component_builder::build_components(&packages, None);
+
+        let name = |n: &str| PackageName::new(n.to_string()).unwrap();
+        let graph = DependencyGraph::new(
+            vec![name("requests")],
+            HashMap::new(),
+            HashMap::from([
+                (name("requests"), vec![name("urllib3"), name("unknown-pkg")]),
+                (name("urllib3"), vec![name("certifi")]),
+                (name("certifi"), vec![]),
+            ]),
+        );
+
+        let deps = build_dependencies(&graph, &components);
+
+        assert_eq!(
+            deps.children,
+            HashMap::from([
+                (
+                    "requests-2.31.0".to_string(),
+                    vec!["urllib3-2.0.0".to_string()]
+                ),
+                (
+                    "urllib3-2.0.0".to_string(),
+                    vec!["certifi-2023.7.22".to_string()]
+                ),
+            ])
+        );
+    }
 }
@@ -0,0 +1,33 @@
+use super::super::fix_suggestion_view::{
+    FixSuggestionView, FixSuggestionsView, UnfixedPackageView,
+};
+use crate::sbom_generation::domain::FixSuggestion;
+
+pub(super) fn build_fix_suggestions(suggestions: &[FixSuggestion]) -> FixSuggestionsView {
+    let fixable = suggestions
+        .iter()
+        .filter_map(|s| {
+            Some(FixSuggestionView {
+                package_name: s.package_name().to_string(),
+                current_version: s.current_version().to_string(),
+                fixed_version: s.fixed_version()?.to_string(),
+                vulnerability_ids: s.fixed_ids().to_vec(),
+                command: s.upgrade_command(),
+                introduced_by: s.introduced_by().to_vec(),
+            })
+        })
+        .collect();
+
+    // A package can appear in both lists when only some of its advisories are fixed
+    let unfixed = suggestions
+        .iter()
+        .filter(|s| !s.unfixed_ids().is_empty())
+        .map(|s| UnfixedPackageView {
+            package_name: s.package_name().to_string(),
+            current_version: s.current_version().to_string(),
+            vulnerability_ids: s.unfixed_ids().to_vec(),
+        })
+        .collect();
+
+    FixSuggestionsView { fixable, unfixed }
+}
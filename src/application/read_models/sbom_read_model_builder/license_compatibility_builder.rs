@@ -0,0 +1,47 @@
+use super::super::license_compatibility_view::{
+    CompatibilityVerdictView, LicenseCompatibilityEntryView, LicenseCompatibilitySummary,
+    LicenseCompatibilityView,
+};
+use crate::sbom_generation::policies::{Compatibility, LicenseCompatibilityResult};
+
+pub(super) fn build_license_compatibility(
+    result: &LicenseCompatibilityResult,
+) -> LicenseCompatibilityView {
+    let mut findings: Vec<LicenseCompatibilityEntryView> = result
+        .entries
+        .iter()
+        .filter(|e| e.verdict != Compatibility::Compatible)
+        .map(|e| LicenseCompatibilityEntryView {
+            package_name: e.package_name.clone(),
+            package_version: e.package_version.clone(),
+            license: e.license.clone().unwrap_or_else(|| "N/A".to_string()),
+            verdict: verdict_view(e.verdict),
+        })
+        .collect();
+    findings.sort_by(|a, b| {
+        b.verdict
+            .cmp(&a.verdict)
+            .then_with(|| a.package_name.cmp(&b.package_name))
+    });
+
+    LicenseCompatibilityView {
+        project_license: result.project_license.clone(),
+        project_family: result.project_family.map(|f| f.as_str().to_string()),
+        findings,
+        summary: LicenseCompatibilitySummary {
+            compatible_count: result.count(Compatibility::Compatible),
+            conditional_count: result.count(Compatibility::Conditional),
+            incompatible_count: result.count(Compatibility::Incompatible),
+            needs_review_count: result.count(Compatibility::NeedsReview),
+        },
+    }
+}
+
+fn verdict_view(verdict: Compatibility) -> CompatibilityVerdictView {
+    match verdict {
+        Compatibility::Compatible => CompatibilityVerdictView::Compatible,
+        Compatibility::Conditional => CompatibilityVerdictView::Conditional,
+        Compatibility::NeedsReview => CompatibilityVerdictView::NeedsReview,
+        Compatibility::Incompatible => CompatibilityVerdictView::Incompatible,
+    }
+}
@@ -0,0 +1,19 @@
+use super::super::license_risk_view::{LicenseRiskEntryView, LicenseRiskView};
+use crate::sbom_generation::domain::{LicenseRisk, LicenseRiskReport};
+
+pub(super) fn build_license_risk(report: &LicenseRiskReport) -> LicenseRiskView {
+    LicenseRiskView {
+        copyleft: report.copyleft.iter().map(build_entry).collect(),
+        unknown: report.unknown.iter().map(build_entry).collect(),
+    }
+}
+
+fn build_entry(risk: &LicenseRisk) -> LicenseRiskEntryView {
+    LicenseRiskEntryView {
+        package_name: risk.package_name().to_string(),
+        package_version: risk.package_version().to_string(),
+        license: risk.license().unwrap_or("N/A").to_string(),
+        introduction_path: risk.introduction_path().to_vec(),
+        path_count: risk.path_count(),
+    }
+}
@@ -1,10 +1,13 @@
-use crate::sbom_generation::domain::SbomMetadata;
+use crate::sbom_generation::domain::{InvocationParameters, SbomMetadata};
 
-use super::super::sbom_read_model::{MetadataComponentView, SbomMetadataView};
+use super::super::sbom_read_model::{
+    InvocationParameterView, InvocationView, MetadataComponentView, SbomMetadataView,
+};
+use super::vulnerability_builder::map_severity;
 
 pub(super) fn build_metadata(
     metadata: &SbomMetadata,
-    project_component: Option<(&str, &str)>,
+    project_component: Option<(&str, Option<&str>)>,
 ) -> SbomMetadataView {
     SbomMetadataView {
         timestamp: metadata.timestamp().to_string(),
@@ -13,11 +16,75 @@ pub(super) fn build_metadata(
         serial_number: metadata.serial_number().to_string(),
         component: project_component.map(|(name, version)| MetadataComponentView {
             name: name.to_string(),
-            version: version.to_string(),
+            version: version.map(str::to_string),
         }),
+        lockfile_sha256: None,
+        target_environment: None,
+        invocation: metadata.invocation().map(build_invocation),
+        lockfile_version: metadata.lockfile_version().map(str::to_string),
+        lockfile_requires_python: metadata.lockfile_requires_python().map(str::to_string),
     }
 }
 
+/// Lists the recorded options as name/value pairs, leaving out unset ones
+fn build_invocation(invocation: &InvocationParameters) -> InvocationView {
+    let optional = [
+        ("config-path", invocation.config_path.clone()),
+        (
+            "exclude-patterns",
+            (!invocation.exclude_patterns.is_empty())
+                .then(|| invocation.exclude_patterns.join(", ")),
+        ),
+        (
+            "severity-threshold",
+            invocation
+                .severity_threshold
+                .map(|s| map_severity(&s).as_str().to_lowercase()),
+        ),
+        (
+            "cvss-threshold",
+            invocation.cvss_threshold.map(|t| t.to_string()),
+        ),
+        ("index-url", invocation.index_url.clone()),
+        ("advisory-db", invocation.advisory_db.clone()),
+        (
+            "licenses-for",
+            invocation
+                .direct_licenses_only
+                .then(|| "direct-only".to_string()),
+        ),
+        (
+            "max-network-requests",
+            invocation.max_network_requests.map(|n| n.to_string()),
+        ),
+    ];
+    let parameters = [
+        ("project-path", Some(invocation.project_path.clone())),
+        ("check-cve", Some(invocation.check_cve.to_string())),
+        (
+            "ignored-cves",
+            Some(invocation.ignored_cve_count.to_string()),
+        ),
+        ("check-license", Some(invocation.check_license.to_string())),
+        ("offline", Some(invocation.offline.to_string())),
+        ("dry-run", Some(invocation.dry_run.to_string())),
+        (
+            "license-resolution",
+            Some(invocation.license_resolution.to_string()),
+        ),
+    ]
+    .into_iter()
+    .chain(optional)
+    .filter_map(|(name, value)| {
+        value.map(|value| InvocationParameterView {
+            name: name.to_string(),
+            value,
+        })
+    })
+    .collect();
+    InvocationView { parameters }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helpers as th;
@@ -41,12 +108,72 @@ mod tests {
     #[test]
     fn test_build_metadata_with_project_component() {
         let metadata = th::metadata();
-        let view = build_metadata(&metadata, Some(("my-project", "1.0.0")));
+        let view = build_metadata(&metadata, Some(("my-project", Some("1.0.0"))));
 
         assert!(view.component.is_some());
         let component = view.component.unwrap();
         assert_eq!(component.name, "my-project");
-        assert_eq!(component.version, "1.0.0");
+        assert_eq!(component.version.as_deref(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn test_build_metadata_with_dynamic_project_version() {
+        let view = build_metadata(&th::metadata(), Some(("my-project", None)));
+
+        let component = view.component.unwrap();
+        assert_eq!(component.name, "my-project");
+        assert!(component.version.is_none());
+    }
+
+    #[test]
+    fn test_build_metadata_with_lockfile_format() {
+        let metadata = th::metadata().with_lockfile_format(Some("1".to_string()), None);
+        let view = build_metadata(&metadata, None);
+
+        assert_eq!(view.lockfile_version.as_deref(), Some("1"));
+        assert!(view.lockfile_requires_python.is_none());
+    }
+
+    #[test]
+    fn test_build_metadata_with_invocation() {
+        let invocation = InvocationParameters {
+            project_path: "/work/app".to_string(),
+            exclude_patterns: vec!["internal-*".to_string(), "test-*".to_string()],
+            check_cve: true,
+            severity_threshold: Some(crate::sbom_generation::domain::Severity::High),
+            ignored_cve_count: 2,
+            direct_licenses_only: true,
+            max_network_requests: Some(200),
+            ..Default::default()
+        };
+        let metadata = th::metadata().with_invocation(Some(invocation));
+        let view = build_metadata(&metadata, None);
+
+        let parameters: Vec<(&str, &str)> = view
+            .invocation
+            .as_ref()
+            .unwrap()
+            .parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.value.as_str()))
+            .collect();
+        assert_eq!(
+            parameters,
+            [
+                ("project-path", "/work/app"),
+                ("check-cve", "true"),
+                ("ignored-cves", "2"),
+                ("check-license", "false"),
+                ("offline", "false"),
+                ("dry-run", "false"),
+                ("license-resolution", "license_field_first"),
+                ("exclude-patterns", "internal-*, test-*"),
+                ("severity-threshold", "high"),
+                ("licenses-for", "direct-only"),
+                ("max-network-requests", "200"),
+            ]
+        );
+        assert!(build_metadata(&th::metadata(), None).invocation.is_none());
     }
 
     #[test]
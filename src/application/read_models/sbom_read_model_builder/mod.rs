@@ -5,19 +5,31 @@
 
 mod component_builder;
 mod dependency_builder;
+mod fix_suggestion_builder;
+mod license_compatibility_builder;
 mod license_compliance_builder;
+mod license_risk_builder;
 mod metadata_builder;
 mod resolution_guide_builder;
 mod upgrade_recommendation_builder;
 mod vulnerability_builder;
 
+use super::fix_suggestion_view::FixSuggestionsView;
+use super::license_compatibility_view::LicenseCompatibilityView;
+use super::license_risk_view::LicenseRiskView;
 use super::resolution_guide_view::ResolutionGuideView;
 use super::sbom_read_model::SbomReadModel;
+use super::version_conflict_view::VersionConflictView;
+use super::vulnerability_view::VulnerabilityReportView;
 use crate::ports::outbound::EnrichedPackage;
 use crate::sbom_generation::domain::license_policy::LicenseComplianceResult;
 use crate::sbom_generation::domain::services::{ResolutionAnalyzer, VulnerabilityCheckResult};
 use crate::sbom_generation::domain::vulnerability::PackageVulnerabilities;
-use crate::sbom_generation::domain::{DependencyGraph, SbomMetadata, UpgradeRecommendation};
+use crate::sbom_generation::domain::{
+    DependencyGraph, FixSuggestion, LicenseRiskReport, SbomMetadata, UpgradeRecommendation,
+};
+use crate::sbom_generation::policies::LicenseCompatibilityResult;
+use crate::sbom_generation::services::DependencyAnalyzer;
 
 /// Builder for constructing SbomReadModel from domain objects
 ///
@@ -34,16 +46,18 @@ impl SbomReadModelBuilder {
         dependency_graph: Option<&DependencyGraph>,
         vulnerability_result: Option<&VulnerabilityCheckResult>,
         license_compliance_result: Option<&LicenseComplianceResult>,
-        project_component: Option<(&str, &str)>,
+        project_component: Option<(&str, Option<&str>)>,
         upgrade_recommendations: Option<&[UpgradeRecommendation]>,
     ) -> SbomReadModel {
         let metadata_view = metadata_builder::build_metadata(metadata, project_component);
         let components = component_builder::build_components(&packages, dependency_graph);
+        let version_conflicts = Self::build_version_conflicts(&packages);
 
         let dependencies = dependency_graph
             .map(|graph| dependency_builder::build_dependencies(graph, &components));
-        let vulnerabilities = vulnerability_result
-            .map(|result| vulnerability_builder::build_vulnerabilities(result, &components));
+        let vulnerabilities = vulnerability_result.map(|result| {
+            vulnerability_builder::build_vulnerabilities(result, &components, dependency_graph)
+        });
         let license_compliance =
             license_compliance_result.map(license_compliance_builder::build_license_compliance);
 
@@ -62,11 +76,72 @@ impl SbomReadModelBuilder {
             dependencies,
             vulnerabilities,
             license_compliance,
+            license_compatibility: None,
+            license_risk: None,
+            python_compatibility: None,
+            typosquats: Vec::new(),
             resolution_guide,
             upgrade_recommendations,
+            fix_suggestions: None,
+            project_self_check: None,
+            warnings: Vec::new(),
+            stats: None,
+            projects: Vec::new(),
+            version_conflicts,
         }
     }
 
+    /// Lists the packages locked at more than one version
+    fn build_version_conflicts(packages: &[EnrichedPackage]) -> Vec<VersionConflictView> {
+        DependencyAnalyzer::find_version_conflicts(packages.iter().map(|ep| &ep.package))
+            .into_iter()
+            .map(|conflict| VersionConflictView {
+                name: conflict.name,
+                versions: conflict.versions,
+            })
+            .collect()
+    }
+
+    /// Builds the license compatibility view
+    ///
+    /// Only packages that are not plainly compatible are listed, most severe first.
+    pub fn build_license_compatibility(
+        result: &LicenseCompatibilityResult,
+    ) -> LicenseCompatibilityView {
+        license_compatibility_builder::build_license_compatibility(result)
+    }
+
+    /// Builds the license risk view
+    ///
+    /// Copyleft packages and, in strict mode, packages with unknown licenses are
+    /// listed separately, each sorted by package name.
+    pub fn build_license_risk(report: &LicenseRiskReport) -> LicenseRiskView {
+        license_risk_builder::build_license_risk(report)
+    }
+
+    /// Builds the suggested fixes view
+    ///
+    /// Packages with a fixed version are listed with their upgrade; advisories
+    /// without one are listed separately as "no fix available yet".
+    pub fn build_fix_suggestions(suggestions: &[FixSuggestion]) -> FixSuggestionsView {
+        fix_suggestion_builder::build_fix_suggestions(suggestions)
+    }
+
+    /// Builds the vulnerability view for the project's own published version
+    ///
+    /// Findings reference the metadata component (`{name}-{version}`) rather than
+    /// any entry in the component list.
+    pub fn build_project_self_check(result: &VulnerabilityCheckResult) -> VulnerabilityReportView {
+        let mut report = vulnerability_builder::build_vulnerabilities(result, &[], None);
+        report
+            .actionable
+            .iter_mut()
+            .chain(report.informational.iter_mut())
+            .chain(report.ignored.iter_mut().map(|i| &mut i.vulnerability))
+            .for_each(|view| view.excluded_from_inventory = false);
+        report
+    }
+
     fn build_resolution_guide_if_applicable(
         dependency_graph: Option<&DependencyGraph>,
         vulnerability_result: Option<&VulnerabilityCheckResult>,
@@ -223,6 +298,87 @@ mod tests {
         assert!(read_model.components.is_empty());
     }
 
+    #[test]
+    fn test_with_annotations_matches_normalized_names() {
+        use crate::sbom_generation::domain::PackageAnnotation;
+        use std::collections::BTreeMap;
+
+        let packages = vec![
+            th::package("typing_extensions", "4.9.0"),
+            th::package("urllib3", "2.0.0"),
+        ];
+        let annotations = vec![
+            PackageAnnotation::new(
+                "Typing-Extensions".to_string(),
+                BTreeMap::from([("owner".to_string(), "team-core".to_string())]),
+            ),
+            PackageAnnotation::new(
+                "not-installed".to_string(),
+                BTreeMap::from([("owner".to_string(), "nobody".to_string())]),
+            ),
+        ];
+
+        let read_model = SbomReadModelBuilder::build_with_project(
+            packages,
+            &th::metadata(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .with_annotations(&annotations);
+
+        assert_eq!(
+            read_model.components[0].annotations.get("owner"),
+            Some(&"team-core".to_string())
+        );
+        assert!(read_model.components[1].annotations.is_empty());
+    }
+
+    #[test]
+    fn test_with_python_compatibility_marks_components() {
+        use crate::sbom_generation::domain::python_compatibility::PythonIncompatibility;
+        use crate::sbom_generation::domain::PythonCompatibilityReport;
+
+        let packages = vec![
+            th::package("numpy", "1.26.4"),
+            th::package("urllib3", "2.0.0"),
+        ];
+        let report = PythonCompatibilityReport {
+            project_requires_python: ">=3.9".to_string(),
+            incompatible: vec![PythonIncompatibility {
+                package_name: "numpy".to_string(),
+                package_version: "1.26.4".to_string(),
+                requires_python: ">=3.9,<3.13".to_string(),
+            }],
+        };
+
+        let read_model = SbomReadModelBuilder::build_with_project(
+            packages,
+            &th::metadata(),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .with_python_compatibility(Some(&report));
+
+        assert_eq!(
+            read_model.components[0].python_compat.as_deref(),
+            Some(">=3.9,<3.13")
+        );
+        assert_eq!(read_model.components[1].python_compat, None);
+        assert_eq!(
+            read_model
+                .python_compatibility
+                .map(|v| v.project_requires_python)
+                .as_deref(),
+            Some(">=3.9")
+        );
+    }
+
     #[test]
     fn test_build_full_read_model_with_vulnerabilities() {
         let packages = vec![th::package("requests", "2.31.0")];
@@ -235,6 +391,8 @@ mod tests {
             above_threshold: vec![pkg_vuln],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let read_model = SbomReadModelBuilder::build_with_project(
@@ -251,6 +409,166 @@ mod tests {
         let vulns = read_model.vulnerabilities.unwrap();
         assert_eq!(vulns.actionable.len(), 1);
         assert_eq!(vulns.actionable[0].id, "CVE-2024-1234");
+        assert!(!vulns.actionable[0].excluded_from_inventory);
+    }
+
+    #[test]
+    fn test_vulnerability_of_excluded_package_is_flagged() {
+        let packages = vec![th::package("requests", "2.31.0")];
+        let metadata = th::metadata();
+
+        let vuln = th::vulnerability("CVE-2024-1234", Some(9.8), Severity::Critical);
+        let vuln_result = VulnerabilityCheckResult {
+            above_threshold: vec![th::package_vulnerabilities(
+                "internal-lib",
+                "1.0.0",
+                vec![vuln],
+            )],
+            below_threshold: vec![],
+            threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
+        };
+
+        let read_model = SbomReadModelBuilder::build_with_project(
+            packages,
+            &metadata,
+            None,
+            Some(&vuln_result),
+            None,
+            None,
+            None,
+        );
+
+        assert_eq!(read_model.components.len(), 1);
+        let vulns = read_model.vulnerabilities.unwrap();
+        assert_eq!(vulns.actionable[0].affected_component_name, "internal-lib");
+        assert!(vulns.actionable[0].excluded_from_inventory);
+
+        // The project's own findings never reference the component list
+        let self_check = SbomReadModelBuilder::build_project_self_check(&vuln_result);
+        assert!(!self_check.actionable[0].excluded_from_inventory);
+    }
+
+    #[test]
+    fn test_fixture_matches_builder_output() {
+        use crate::application::read_models::SeverityView;
+        use crate::test_fixtures::SbomReadModelFixture;
+
+        let mut transitive = HashMap::new();
+        transitive.insert(
+            PackageName::new("requests".to_string()).unwrap(),
+            vec![PackageName::new("urllib3".to_string()).unwrap()],
+        );
+        let graph = DependencyGraph::new(
+            vec![PackageName::new("requests".to_string()).unwrap()],
+            transitive,
+            HashMap::new(),
+        );
+        let vuln_result = VulnerabilityCheckResult {
+            above_threshold: vec![th::package_vulnerabilities(
+                "requests",
+                "2.31.0",
+                vec![th::vulnerability_with_fix(
+                    "CVE-2024-1234",
+                    Some(9.8),
+                    Severity::Critical,
+                    "2.32.0",
+                )],
+            )],
+            below_threshold: vec![th::package_vulnerabilities(
+                "urllib3",
+                "1.26.0",
+                vec![th::vulnerability("CVE-2024-5678", Some(2.0), Severity::Low)],
+            )],
+            threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
+        };
+        let built = SbomReadModelBuilder::build_with_project(
+            vec![
+                th::package("requests", "2.31.0"),
+                th::package("urllib3", "1.26.0"),
+            ],
+            &th::metadata(),
+            Some(&graph),
+            Some(&vuln_result),
+            None,
+            None,
+            None,
+        );
+
+        let mut fixture = SbomReadModelFixture::builder();
+        for (name, version) in [("requests", "2.31.0"), ("urllib3", "1.26.0")] {
+            let component = fixture
+                .add_component(name, version)
+                .license("MIT")
+                .description("A test package");
+            if name == "requests" {
+                component.direct();
+            }
+        }
+        fixture
+            .add_vulnerability("CVE-2024-1234")
+            .severity(SeverityView::Critical)
+            .cvss(9.8)
+            .fixed_in("2.32.0")
+            .on("requests", "2.31.0");
+        fixture
+            .add_vulnerability("CVE-2024-5678")
+            .severity(SeverityView::Low)
+            .cvss(2.0)
+            .informational()
+            .on("urllib3", "1.26.0");
+        let expected = fixture
+            .with_dependencies(&[("requests", &["urllib3"])])
+            .build();
+
+        let summarize_components = |model: &SbomReadModel| -> Vec<String> {
+            model
+                .components
+                .iter()
+                .map(|c| {
+                    format!(
+                        "{} {} {:?} {:?} {}",
+                        c.bom_ref, c.purl, c.license, c.description, c.is_direct_dependency
+                    )
+                })
+                .collect()
+        };
+        assert_eq!(
+            summarize_components(&built),
+            summarize_components(&expected)
+        );
+
+        let (built_deps, expected_deps) =
+            (built.dependencies.unwrap(), expected.dependencies.unwrap());
+        assert_eq!(built_deps.direct, expected_deps.direct);
+        assert_eq!(built_deps.transitive, expected_deps.transitive);
+
+        let summarize_vulns = |report: &VulnerabilityReportView| -> Vec<String> {
+            report
+                .actionable
+                .iter()
+                .chain(&report.informational)
+                .map(|v| {
+                    format!(
+                        "{} {} {:?} {:?} {:?}",
+                        v.bom_ref, v.affected_component, v.severity, v.cvss_score, v.fixed_version
+                    )
+                })
+                .chain([format!(
+                    "{} actionable, {} total, {} packages",
+                    report.actionable.len(),
+                    report.summary.total_count,
+                    report.summary.affected_package_count
+                )])
+                .collect()
+        };
+        assert_eq!(
+            summarize_vulns(built.vulnerabilities.as_ref().unwrap()),
+            summarize_vulns(expected.vulnerabilities.as_ref().unwrap())
+        );
     }
 
     #[test]
@@ -278,6 +596,8 @@ mod tests {
             above_threshold: vec![pkg_vuln],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let read_model = SbomReadModelBuilder::build_with_project(
@@ -308,6 +628,8 @@ mod tests {
             above_threshold: vec![pkg_vuln],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let read_model = SbomReadModelBuilder::build_with_project(
@@ -354,6 +676,8 @@ mod tests {
             above_threshold: vec![pkg_vuln],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let read_model = SbomReadModelBuilder::build_with_project(
@@ -381,13 +705,13 @@ mod tests {
             None,
             None,
             None,
-            Some(("my-project", "1.0.0")),
+            Some(("my-project", Some("1.0.0"))),
             None,
         );
 
         assert!(read_model.metadata.component.is_some());
         let component = read_model.metadata.component.unwrap();
         assert_eq!(component.name, "my-project");
-        assert_eq!(component.version, "1.0.0");
+        assert_eq!(component.version.as_deref(), Some("1.0.0"));
     }
 }
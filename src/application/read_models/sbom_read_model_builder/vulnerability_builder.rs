@@ -1,35 +1,57 @@
 use super::super::component_view::ComponentView;
 use super::super::vulnerability_view::{
+    AnalysisStateView, CvssVersionView, IgnoredVulnerabilityView, InformationalDisplay,
     SeverityView, VulnerabilityReportView, VulnerabilitySummary, VulnerabilityView,
 };
-use crate::sbom_generation::domain::services::VulnerabilityCheckResult;
+use crate::config::IgnoreState;
+use crate::sbom_generation::domain::services::{IgnoredVulnerability, VulnerabilityCheckResult};
 use crate::sbom_generation::domain::vulnerability::{
-    PackageVulnerabilities, Severity, Vulnerability,
+    CvssVersion, PackageVulnerabilities, Severity, Vulnerability,
 };
-use std::collections::HashSet;
+use crate::sbom_generation::domain::{DependencyGraph, PackageName};
+use std::collections::{BTreeSet, HashSet};
 
 /// Builds vulnerability report view from vulnerability check result
 ///
 /// Converts above_threshold to actionable and below_threshold to informational.
-/// Uses existing VulnerabilityCheckResult semantic methods.
+/// Uses existing VulnerabilityCheckResult semantic methods. When a dependency
+/// graph is given, each view lists the direct dependencies that pull it in.
 pub(super) fn build_vulnerabilities(
     result: &VulnerabilityCheckResult,
     components: &[ComponentView],
+    dependency_graph: Option<&DependencyGraph>,
 ) -> VulnerabilityReportView {
     // Convert above_threshold to actionable vulnerabilities
-    let actionable: Vec<VulnerabilityView> = result
+    let mut actionable: Vec<VulnerabilityView> = result
         .above_threshold
         .iter()
-        .flat_map(|pkg| build_vulnerability_views_for_package(pkg, components))
+        .flat_map(|pkg| build_vulnerability_views_for_package(pkg, result, components))
         .collect();
 
     // Convert below_threshold to informational vulnerabilities
-    let informational: Vec<VulnerabilityView> = result
+    let mut informational: Vec<VulnerabilityView> = result
         .below_threshold
         .iter()
-        .flat_map(|pkg| build_vulnerability_views_for_package(pkg, components))
+        .flat_map(|pkg| build_vulnerability_views_for_package(pkg, result, components))
         .collect();
 
+    // Ignored vulnerabilities are kept for VEX output
+    let mut ignored: Vec<IgnoredVulnerabilityView> = result
+        .ignored
+        .iter()
+        .map(|ignored| build_ignored_vulnerability_view(ignored, components))
+        .collect();
+
+    if let Some(graph) = dependency_graph {
+        actionable
+            .iter_mut()
+            .chain(informational.iter_mut())
+            .chain(ignored.iter_mut().map(|i| &mut i.vulnerability))
+            .for_each(|view| {
+                view.introduced_by = introduced_by(graph, &view.affected_component_name)
+            });
+    }
+
     // Calculate unique affected packages
     let affected_packages: HashSet<&str> = result
         .above_threshold
@@ -46,19 +68,26 @@ pub(super) fn build_vulnerabilities(
     VulnerabilityReportView {
         actionable,
         informational,
+        ignored,
         summary,
+        informational_display: InformationalDisplay::default(),
     }
 }
 
-/// Builds vulnerability views for all vulnerabilities in a package
+/// Builds vulnerability views for all vulnerabilities in a package, marking
+/// the ones the result's baseline accepted
 fn build_vulnerability_views_for_package(
     package: &PackageVulnerabilities,
+    result: &VulnerabilityCheckResult,
     components: &[ComponentView],
 ) -> Vec<VulnerabilityView> {
     package
         .vulnerabilities()
         .iter()
-        .map(|vuln| build_vulnerability_view(vuln, package, components))
+        .map(|vuln| VulnerabilityView {
+            in_baseline: result.is_baseline(package.package_name(), vuln),
+            ..build_vulnerability_view(vuln, package, components)
+        })
         .collect()
 }
 
@@ -67,15 +96,53 @@ pub(super) fn build_vulnerability_view(
     vuln: &Vulnerability,
     package: &PackageVulnerabilities,
     components: &[ComponentView],
+) -> VulnerabilityView {
+    build_view(
+        vuln,
+        package.package_name(),
+        package.current_version(),
+        components,
+    )
+}
+
+/// Converts an ignored vulnerability to view, carrying its VEX analysis
+fn build_ignored_vulnerability_view(
+    ignored: &IgnoredVulnerability,
+    components: &[ComponentView],
+) -> IgnoredVulnerabilityView {
+    IgnoredVulnerabilityView {
+        vulnerability: build_view(
+            &ignored.vulnerability,
+            &ignored.package_name,
+            &ignored.package_version,
+            components,
+        ),
+        state: match ignored.state {
+            IgnoreState::NotAffected => AnalysisStateView::NotAffected,
+            IgnoreState::FalsePositive => AnalysisStateView::FalsePositive,
+        },
+        detail: ignored.reason.clone(),
+    }
+}
+
+fn build_view(
+    vuln: &Vulnerability,
+    package_name: &str,
+    package_version: &str,
+    components: &[ComponentView],
 ) -> VulnerabilityView {
     // Find the component bom-ref for this package
     let component = components
         .iter()
-        .find(|c| c.name == package.package_name() && c.version == package.current_version());
+        .find(|c| c.name == package_name && c.version == package_version);
 
-    let affected_component = component
-        .map(|c| c.bom_ref.clone())
-        .unwrap_or_else(|| format!("{}-{}", package.package_name(), package.current_version()));
+    let affected_component = component.map(|c| c.bom_ref.clone()).unwrap_or_else(|| {
+        format!(
+            "{}-{}",
+            PackageName::normalize(package_name),
+            package_version
+        )
+    });
 
     // Generate vulnerability bom-ref
     let bom_ref = format!("{}-{}", vuln.id(), affected_component);
@@ -83,15 +150,47 @@ pub(super) fn build_vulnerability_view(
     VulnerabilityView {
         bom_ref,
         id: vuln.id().to_string(),
+        aliases: vuln.aliases().to_vec(),
         affected_component,
-        affected_component_name: package.package_name().to_string(),
-        affected_version: package.current_version().to_string(),
+        affected_component_name: package_name.to_string(),
+        affected_version: package_version.to_string(),
         cvss_score: vuln.cvss_score().map(|s| s.value()),
         cvss_vector: None, // OSV API doesn't provide vector in our current implementation
+        cvss_version: vuln.cvss_version().map(map_cvss_version),
         severity: map_severity(&vuln.severity()),
         fixed_version: vuln.fixed_version().map(|s| s.to_string()),
         description: None, // Summary is not exposed in Vulnerability, could be added later
-        source_url: None,  // Not available in current domain model
+        source_url: vuln.source_url().map(|s| s.to_string()),
+        introduced_by: Vec::new(),
+        excluded_from_inventory: component.is_none(),
+        in_baseline: false,
+    }
+}
+
+/// Direct dependencies through which `package_name` enters the graph, sorted by name
+///
+/// A vulnerable direct dependency lists itself.
+fn introduced_by(graph: &DependencyGraph, package_name: &str) -> Vec<String> {
+    let Ok(target) = PackageName::new(package_name.to_string()) else {
+        return Vec::new();
+    };
+    graph
+        .dependency_paths_to(&target)
+        .iter()
+        .filter_map(|path| path.first())
+        .map(|direct| direct.as_str().to_string())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Converts domain CvssVersion to CvssVersionView
+fn map_cvss_version(version: CvssVersion) -> CvssVersionView {
+    match version {
+        CvssVersion::V2_0 => CvssVersionView::V2_0,
+        CvssVersion::V3_0 => CvssVersionView::V3_0,
+        CvssVersion::V3_1 => CvssVersionView::V3_1,
+        CvssVersion::V4_0 => CvssVersionView::V4_0,
     }
 }
 
@@ -113,6 +212,7 @@ mod tests {
     use crate::application::read_models::component_view::ComponentView;
     use crate::sbom_generation::domain::services::VulnerabilityCheckResult;
     use crate::sbom_generation::domain::vulnerability::Severity;
+    use std::collections::HashMap;
 
     #[test]
     fn test_map_severity_all_levels() {
@@ -136,17 +236,30 @@ mod tests {
             description: None,
             sha256_hash: None,
             is_direct_dependency: true,
+            annotations: Default::default(),
+            license_override: None,
+            yanked: None,
+            source: None,
+            declared_constraint: None,
+            license_not_found: false,
+            license_fetch_error: None,
+            python_compat: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
         }];
 
         let view = build_vulnerability_view(&vuln, &pkg, &components);
 
         assert_eq!(view.id, "CVE-2024-1234");
+        assert_eq!(view.source_url, None);
         assert_eq!(view.affected_component, "requests-2.31.0");
         assert_eq!(view.affected_component_name, "requests");
         assert_eq!(view.affected_version, "2.31.0");
         assert_eq!(view.cvss_score, Some(9.8));
         assert_eq!(view.severity, SeverityView::Critical);
         assert_eq!(view.bom_ref, "CVE-2024-1234-requests-2.31.0");
+        assert!(!view.excluded_from_inventory);
     }
 
     #[test]
@@ -160,6 +273,21 @@ mod tests {
         assert_eq!(view.fixed_version, Some("3.0.0".to_string()));
     }
 
+    #[test]
+    fn test_build_vulnerability_view_with_source_url() {
+        let vuln = th::vulnerability("GHSA-xxxx-yyyy-zzzz", None, Severity::High).with_source_url(
+            Some("https://github.com/advisories/GHSA-xxxx-yyyy-zzzz".to_string()),
+        );
+        let pkg = th::package_vulnerabilities("requests", "2.31.0", vec![vuln.clone()]);
+
+        let view = build_vulnerability_view(&vuln, &pkg, &[]);
+
+        assert_eq!(
+            view.source_url.as_deref(),
+            Some("https://github.com/advisories/GHSA-xxxx-yyyy-zzzz")
+        );
+    }
+
     #[test]
     fn test_build_vulnerability_view_without_cvss() {
         let vuln = th::vulnerability("GHSA-xxxx-yyyy-zzzz", None, Severity::High);
@@ -182,6 +310,7 @@ mod tests {
 
         assert_eq!(view.affected_component, "unknown-pkg-1.0.0");
         assert_eq!(view.bom_ref, "CVE-2024-1234-unknown-pkg-1.0.0");
+        assert!(view.excluded_from_inventory);
     }
 
     #[test]
@@ -196,10 +325,12 @@ mod tests {
             above_threshold: vec![above_pkg],
             below_threshold: vec![below_pkg],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let components = vec![];
-        let report = build_vulnerabilities(&result, &components);
+        let report = build_vulnerabilities(&result, &components, None);
 
         assert_eq!(report.actionable.len(), 1);
         assert_eq!(report.actionable[0].id, "CVE-2024-001");
@@ -207,6 +338,29 @@ mod tests {
         assert_eq!(report.informational[0].id, "CVE-2024-002");
     }
 
+    #[test]
+    fn test_build_vulnerabilities_marks_baseline_entries() {
+        let known = th::vulnerability("CVE-2024-001", Some(5.0), Severity::Medium);
+        let new = th::vulnerability("CVE-2024-002", Some(5.0), Severity::Medium);
+        let pkg = th::package_vulnerabilities("jinja2", "3.1.0", vec![known, new]);
+        let mut baseline = crate::sbom_generation::domain::VulnerabilityBaseline::new();
+        baseline.insert("jinja2", "CVE-2024-001");
+
+        let result = VulnerabilityCheckResult {
+            above_threshold: vec![pkg],
+            below_threshold: vec![],
+            threshold_exceeded: true,
+            ignored: vec![],
+            baseline,
+        };
+
+        let report = build_vulnerabilities(&result, &[], None);
+
+        assert!(report.actionable[0].in_baseline);
+        assert!(!report.actionable[1].in_baseline);
+        assert_eq!(report.summary.total_count, 2);
+    }
+
     #[test]
     fn test_build_vulnerabilities_summary_statistics() {
         let vuln1 = th::vulnerability("CVE-2024-001", Some(9.8), Severity::Critical);
@@ -220,10 +374,12 @@ mod tests {
             above_threshold: vec![above_pkg],
             below_threshold: vec![below_pkg],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let components = vec![];
-        let report = build_vulnerabilities(&result, &components);
+        let report = build_vulnerabilities(&result, &components, None);
 
         assert_eq!(report.summary.total_count, 3);
         assert_eq!(report.actionable.len(), 2);
@@ -231,16 +387,92 @@ mod tests {
         assert_eq!(report.summary.affected_package_count, 2);
     }
 
+    #[test]
+    fn test_build_vulnerabilities_maps_ignored_entries() {
+        let result = VulnerabilityCheckResult {
+            above_threshold: vec![],
+            below_threshold: vec![],
+            threshold_exceeded: false,
+            ignored: vec![IgnoredVulnerability {
+                package_name: "requests".to_string(),
+                package_version: "2.31.0".to_string(),
+                vulnerability: th::vulnerability("CVE-2024-001", Some(9.8), Severity::Critical),
+                state: IgnoreState::FalsePositive,
+                reason: Some("Not reachable".to_string()),
+            }],
+            baseline: Default::default(),
+        };
+
+        let components = vec![];
+        let report = build_vulnerabilities(&result, &components, None);
+
+        // Ignored entries are reported separately and do not count as findings
+        assert_eq!(report.summary.total_count, 0);
+        assert_eq!(report.ignored.len(), 1);
+        let ignored = &report.ignored[0];
+        assert_eq!(ignored.vulnerability.id, "CVE-2024-001");
+        assert_eq!(ignored.vulnerability.affected_component, "requests-2.31.0");
+        assert_eq!(ignored.state, AnalysisStateView::FalsePositive);
+        assert_eq!(ignored.detail.as_deref(), Some("Not reachable"));
+    }
+
+    #[test]
+    fn test_build_vulnerabilities_introduced_by_from_graph() {
+        let name = |n: &str| PackageName::new(n.to_string()).unwrap();
+        // requests -> urllib3, httpx -> httpcore -> urllib3
+        let edges = HashMap::from([
+            (name("requests"), vec![name("urllib3")]),
+            (name("httpx"), vec![name("httpcore")]),
+            (name("httpcore"), vec![name("urllib3")]),
+        ]);
+        let graph =
+            DependencyGraph::new(vec![name("requests"), name("httpx")], HashMap::new(), edges);
+        let result = VulnerabilityCheckResult {
+            above_threshold: vec![
+                th::package_vulnerabilities(
+                    "urllib3",
+                    "1.26.5",
+                    vec![th::vulnerability("CVE-2024-001", Some(7.5), Severity::High)],
+                ),
+                th::package_vulnerabilities(
+                    "requests",
+                    "2.31.0",
+                    vec![th::vulnerability(
+                        "CVE-2024-002",
+                        Some(5.0),
+                        Severity::Medium,
+                    )],
+                ),
+            ],
+            below_threshold: vec![],
+            threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
+        };
+
+        let report = build_vulnerabilities(&result, &[], Some(&graph));
+        assert_eq!(
+            report.actionable[0].introduced_by,
+            vec!["httpx", "requests"]
+        );
+        assert_eq!(report.actionable[1].introduced_by, vec!["requests"]);
+
+        let report = build_vulnerabilities(&result, &[], None);
+        assert!(report.actionable[0].introduced_by.is_empty());
+    }
+
     #[test]
     fn test_build_vulnerabilities_empty_result() {
         let result = VulnerabilityCheckResult {
             above_threshold: vec![],
             below_threshold: vec![],
             threshold_exceeded: false,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let components = vec![];
-        let report = build_vulnerabilities(&result, &components);
+        let report = build_vulnerabilities(&result, &components, None);
 
         assert!(report.actionable.is_empty());
         assert!(report.informational.is_empty());
@@ -260,10 +492,12 @@ mod tests {
             above_threshold: vec![pkg],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         let components = vec![];
-        let report = build_vulnerabilities(&result, &components);
+        let report = build_vulnerabilities(&result, &components, None);
 
         assert_eq!(report.actionable.len(), 3);
         for vuln_view in &report.actionable {
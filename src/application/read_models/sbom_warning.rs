@@ -0,0 +1,329 @@
+//! Warning records collected during SBOM generation
+//!
+//! Warnings are non-fatal events (unmatched filters, partial fetch failures)
+//! that may leave the generated SBOM incomplete. They are shown live through
+//! the progress reporter and also carried on the response so formatters and
+//! library consumers can include them in their output.
+
+use crate::i18n::Messages;
+use crate::shared::error::LicenseFetchErrorKind;
+
+/// Coarse classification of a license fetch failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchFailureCategory {
+    /// The package or version does not exist on the registry
+    NotFound,
+    /// The request could not be completed (connection, timeout)
+    Network,
+    /// The registry answered but the response could not be parsed
+    InvalidResponse,
+    /// The request was not sent because `--max-requests` or `--max-network-requests`
+    /// was reached
+    Skipped,
+    /// Any other failure
+    Other,
+}
+
+impl FetchFailureCategory {
+    /// Classifies a failure from its error message.
+    pub fn classify(error: &str) -> Self {
+        let lower = error.to_lowercase();
+        if lower.contains("request limit") {
+            Self::Skipped
+        } else if lower.contains("404") || lower.contains("not found") {
+            Self::NotFound
+        } else if lower.contains("error sending request")
+            || lower.contains("timed out")
+            || lower.contains("connect")
+            || lower.contains("dns")
+        {
+            Self::Network
+        } else if lower.contains("decoding")
+            || lower.contains("expected")
+            || lower.contains("missing field")
+            || lower.contains("eof while parsing")
+        {
+            Self::InvalidResponse
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Classifies a failure whose error class the license source reported,
+    /// falling back to the message for other failures.
+    pub fn from_kind(kind: LicenseFetchErrorKind, error: &str) -> Self {
+        match kind {
+            LicenseFetchErrorKind::NotFound => Self::NotFound,
+            LicenseFetchErrorKind::RateLimited | LicenseFetchErrorKind::Timeout => Self::Network,
+            LicenseFetchErrorKind::Skipped => Self::Skipped,
+            LicenseFetchErrorKind::Other => Self::classify(error),
+        }
+    }
+
+    /// Returns the stable identifier used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NotFound => "not-found",
+            Self::Network => "network",
+            Self::InvalidResponse => "invalid-response",
+            Self::Skipped => "skipped",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// A non-fatal event that occurred while generating the SBOM
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SbomWarning {
+    /// An `--exclude` pattern matched no package
+    UnmatchedExcludePattern { pattern: String },
+    /// A `deps-of:` exclude pattern names a package that is not in the lockfile
+    UnknownExcludeParent { package: String },
+    /// License information could not be fetched; the package has no license
+    LicenseFetchFailed {
+        package: String,
+        category: FetchFailureCategory,
+        error: String,
+    },
+    /// Maintenance information could not be fetched for the abandoned check
+    MaintenanceFetchFailed { package: String, error: String },
+    /// `uv lock` simulation for an upgrade recommendation failed
+    UpgradeSimulationFailed { package: String, error: String },
+    /// A `[tool.uv-sbom.annotations]` entry names a package that is not in the lockfile
+    OrphanedAnnotation { package: String },
+    /// A `license_overrides` entry in the config file names a package that is not in the lockfile
+    UnmatchedLicenseOverride { package: String },
+    /// A requirement declared in pyproject.toml has no package in the lockfile
+    UnlockedDependency { package: String },
+    /// The lockfile records a direct dependency that pyproject.toml no longer declares
+    StaleLockedDependency { package: String },
+    /// The vulnerability check could not query this package; it may have unreported vulnerabilities
+    VulnerabilityCheckFailed {
+        package: String,
+        version: String,
+        error: String,
+    },
+    /// The details of an advisory could not be fetched; it is reported with only its ID
+    AdvisoryDetailsFetchFailed { id: String, error: String },
+    /// Merged projects report different licenses for the same package version; the first is kept
+    ConflictingLicense {
+        package: String,
+        version: String,
+        kept: String,
+        ignored: String,
+    },
+    /// `uv-sbom scan` skipped a component whose purl is not a PyPI package URL
+    NonPypiComponent {
+        package: String,
+        version: String,
+        purl: String,
+    },
+    /// There is no pyproject.toml and the lockfile does not mark the project package,
+    /// so direct and transitive dependencies are not told apart
+    DependencyAnalysisSkipped { pyproject: String },
+    /// The lockfile format is newer than supported; structure it added may have been ignored
+    NewerLockfileVersion { version: String, supported: u32 },
+    /// A lockfile line was ignored, such as an editable requirement or an index
+    /// option in a requirements.txt
+    LockfileLineSkipped { line: usize, content: String },
+    /// Dependency information was requested, but the lockfile format records no
+    /// dependency edges
+    DependencyGraphUnavailable,
+}
+
+impl SbomWarning {
+    /// Returns the stable identifier of this warning kind used in serialized output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::UnmatchedExcludePattern { .. } => "unmatched-exclude-pattern",
+            Self::UnknownExcludeParent { .. } => "unknown-exclude-parent",
+            Self::LicenseFetchFailed { .. } => "license-fetch-failed",
+            Self::MaintenanceFetchFailed { .. } => "maintenance-fetch-failed",
+            Self::UpgradeSimulationFailed { .. } => "upgrade-simulation-failed",
+            Self::OrphanedAnnotation { .. } => "orphaned-annotation",
+            Self::UnmatchedLicenseOverride { .. } => "unmatched-license-override",
+            Self::UnlockedDependency { .. } => "unlocked-dependency",
+            Self::StaleLockedDependency { .. } => "stale-locked-dependency",
+            Self::VulnerabilityCheckFailed { .. } => "vulnerability-check-failed",
+            Self::AdvisoryDetailsFetchFailed { .. } => "advisory-details-fetch-failed",
+            Self::ConflictingLicense { .. } => "conflicting-license",
+            Self::NonPypiComponent { .. } => "non-pypi-component",
+            Self::DependencyAnalysisSkipped { .. } => "dependency-analysis-skipped",
+            Self::NewerLockfileVersion { .. } => "newer-lockfile-version",
+            Self::LockfileLineSkipped { .. } => "lockfile-line-skipped",
+            Self::DependencyGraphUnavailable => "dependency-graph-unavailable",
+        }
+    }
+
+    /// Returns the human-readable message in the given message table's locale.
+    pub fn message(&self, messages: &Messages) -> String {
+        match self {
+            Self::UnmatchedExcludePattern { pattern } => {
+                Messages::format(messages.run_warning_unmatched_exclude_pattern, &[pattern])
+            }
+            Self::UnknownExcludeParent { package } => {
+                Messages::format(messages.run_warning_unknown_exclude_parent, &[package])
+            }
+            Self::LicenseFetchFailed {
+                package,
+                category,
+                error,
+            } => Messages::format(
+                messages.run_warning_license_fetch_failed,
+                &[package, category.as_str(), error],
+            ),
+            Self::MaintenanceFetchFailed { package, error } => Messages::format(
+                messages.run_warning_maintenance_fetch_failed,
+                &[package, error],
+            ),
+            Self::UpgradeSimulationFailed { package, error } => Messages::format(
+                messages.run_warning_upgrade_simulation_failed,
+                &[package, error],
+            ),
+            Self::OrphanedAnnotation { package } => {
+                Messages::format(messages.run_warning_orphaned_annotation, &[package])
+            }
+            Self::UnmatchedLicenseOverride { package } => {
+                Messages::format(messages.run_warning_unmatched_license_override, &[package])
+            }
+            Self::UnlockedDependency { package } => {
+                Messages::format(messages.run_warning_unlocked_dependency, &[package])
+            }
+            Self::StaleLockedDependency { package } => {
+                Messages::format(messages.run_warning_stale_locked_dependency, &[package])
+            }
+            Self::VulnerabilityCheckFailed {
+                package,
+                version,
+                error,
+            } => Messages::format(
+                messages.run_warning_vulnerability_check_failed,
+                &[package, version, error],
+            ),
+            Self::AdvisoryDetailsFetchFailed { id, error } => Messages::format(
+                messages.run_warning_advisory_details_fetch_failed,
+                &[id, error],
+            ),
+            Self::ConflictingLicense {
+                package,
+                version,
+                kept,
+                ignored,
+            } => Messages::format(
+                messages.run_warning_conflicting_license,
+                &[package, version, kept, ignored],
+            ),
+            Self::NonPypiComponent {
+                package,
+                version,
+                purl,
+            } => Messages::format(
+                messages.run_warning_non_pypi_component,
+                &[package, version, purl],
+            ),
+            Self::DependencyAnalysisSkipped { pyproject } => Messages::format(
+                messages.run_warning_dependency_analysis_skipped,
+                &[pyproject],
+            ),
+            Self::NewerLockfileVersion { version, supported } => Messages::format(
+                messages.run_warning_newer_lockfile_version,
+                &[version, &supported.to_string()],
+            ),
+            Self::LockfileLineSkipped { line, content } => Messages::format(
+                messages.run_warning_lockfile_line_skipped,
+                &[&line.to_string(), content],
+            ),
+            Self::DependencyGraphUnavailable => messages
+                .run_warning_dependency_graph_unavailable
+                .to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+
+    #[test]
+    fn test_classify_not_found() {
+        assert_eq!(
+            FetchFailureCategory::classify("PyPI API returned status code 404 Not Found"),
+            FetchFailureCategory::NotFound
+        );
+    }
+
+    #[test]
+    fn test_classify_network() {
+        assert_eq!(
+            FetchFailureCategory::classify("error sending request for url (https://pypi.org/)"),
+            FetchFailureCategory::Network
+        );
+    }
+
+    #[test]
+    fn test_classify_invalid_response() {
+        assert_eq!(
+            FetchFailureCategory::classify("missing field `info` at line 1 column 2"),
+            FetchFailureCategory::InvalidResponse
+        );
+    }
+
+    #[test]
+    fn test_classify_request_limit_as_skipped() {
+        assert_eq!(
+            FetchFailureCategory::classify(
+                "Request limit of 10 reached; request to pypi.org was skipped"
+            ),
+            FetchFailureCategory::Skipped
+        );
+    }
+
+    #[test]
+    fn test_classify_other() {
+        assert_eq!(
+            FetchFailureCategory::classify("something odd"),
+            FetchFailureCategory::Other
+        );
+    }
+
+    #[test]
+    fn test_from_kind_prefers_the_reported_class() {
+        assert_eq!(
+            FetchFailureCategory::from_kind(LicenseFetchErrorKind::NotFound, "status code 404"),
+            FetchFailureCategory::NotFound
+        );
+        assert_eq!(
+            FetchFailureCategory::from_kind(LicenseFetchErrorKind::Timeout, "operation failed"),
+            FetchFailureCategory::Network
+        );
+        assert_eq!(
+            FetchFailureCategory::from_kind(LicenseFetchErrorKind::Other, "missing field `info`"),
+            FetchFailureCategory::InvalidResponse
+        );
+    }
+
+    #[test]
+    fn test_message_includes_details() {
+        let warning = SbomWarning::LicenseFetchFailed {
+            package: "requests".to_string(),
+            category: FetchFailureCategory::Network,
+            error: "timed out".to_string(),
+        };
+        let message = warning.message(Messages::for_locale(Locale::En));
+        assert!(message.contains("requests"));
+        assert!(message.contains("network"));
+        assert!(message.contains("timed out"));
+        assert_eq!(warning.kind(), "license-fetch-failed");
+    }
+
+    #[test]
+    fn test_message_ja() {
+        let warning = SbomWarning::UnmatchedExcludePattern {
+            pattern: "foo-*".to_string(),
+        };
+        let message = warning.message(Messages::for_locale(Locale::Ja));
+        assert!(message.contains("foo-*"));
+        assert!(message.contains("除外パターン"));
+    }
+}
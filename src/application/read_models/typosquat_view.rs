@@ -0,0 +1,9 @@
+/// View of a package whose name is a near miss of a popular package name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TyposquatView {
+    /// Package name as the lockfile spells it.
+    pub package_name: String,
+    pub package_version: String,
+    /// The popular package the name resembles.
+    pub similar_to: String,
+}
@@ -0,0 +1,8 @@
+/// View of a package the lockfile holds at more than one version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionConflictView {
+    /// Package name as the lockfile spells it.
+    pub name: String,
+    /// Locked versions, in lockfile order.
+    pub versions: Vec<String>,
+}
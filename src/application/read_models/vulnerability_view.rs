@@ -13,8 +13,25 @@ pub struct VulnerabilityReportView {
     pub actionable: Vec<VulnerabilityView>,
     /// Vulnerabilities for information only (LOW, NONE)
     pub informational: Vec<VulnerabilityView>,
+    /// Vulnerabilities suppressed through the ignore list (not counted in the summary).
+    /// Populated only when VEX output was requested.
+    pub ignored: Vec<IgnoredVulnerabilityView>,
     /// Summary statistics
     pub summary: VulnerabilitySummary,
+    /// How much of `informational` reports list; the summary always counts all of it
+    pub informational_display: InformationalDisplay,
+}
+
+/// How much of the informational (below-threshold) section reports list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InformationalDisplay {
+    /// List every informational vulnerability
+    #[default]
+    All,
+    /// List at most this many, most severe first
+    Limit(usize),
+    /// Leave the informational section out
+    Hidden,
 }
 
 impl VulnerabilityReportView {
@@ -35,6 +52,26 @@ impl VulnerabilityReportView {
         }
         counts
     }
+
+    /// Returns the informational vulnerabilities to list, most severe first,
+    /// and how many of them `informational_display` leaves out.
+    ///
+    /// `None` when there are none or the section is hidden.
+    pub fn listed_informational(&self) -> Option<(Vec<&VulnerabilityView>, usize)> {
+        let limit = match self.informational_display {
+            InformationalDisplay::All => usize::MAX,
+            InformationalDisplay::Limit(limit) => limit,
+            InformationalDisplay::Hidden => return None,
+        };
+        if self.informational.is_empty() {
+            return None;
+        }
+        let mut listed: Vec<&VulnerabilityView> = self.informational.iter().collect();
+        listed.sort_by_key(|v| v.severity);
+        let omitted = listed.len().saturating_sub(limit);
+        listed.truncate(limit);
+        Some((listed, omitted))
+    }
 }
 
 /// Vulnerability counts broken down by severity level
@@ -57,6 +94,8 @@ pub struct VulnerabilityView {
     pub bom_ref: String,
     /// Vulnerability ID (e.g., CVE-2024-1234)
     pub id: String,
+    /// Other IDs the same advisory is published under (e.g., GHSA or PYSEC IDs)
+    pub aliases: Vec<String>,
     /// BOM reference of the affected component
     pub affected_component: String,
     /// Name of the affected component
@@ -67,6 +106,8 @@ pub struct VulnerabilityView {
     pub cvss_score: Option<f32>,
     /// CVSS vector string
     pub cvss_vector: Option<String>,
+    /// CVSS version the score was calculated with
+    pub cvss_version: Option<CvssVersionView>,
     /// Severity level
     pub severity: SeverityView,
     /// Version that fixes the vulnerability
@@ -75,6 +116,45 @@ pub struct VulnerabilityView {
     pub description: Option<String>,
     /// URL to vulnerability source
     pub source_url: Option<String>,
+    /// Direct dependencies that pull the affected package in, sorted by name.
+    /// Empty when no dependency graph was built.
+    pub introduced_by: Vec<String>,
+    /// True when the affected package was checked but excluded from the component
+    /// list (`exclude_scope: output`), so `affected_component` resolves to no component
+    pub excluded_from_inventory: bool,
+    /// True when the vulnerability is accepted by the CVE baseline and therefore
+    /// does not count toward the threshold
+    pub in_baseline: bool,
+}
+
+/// View representation of a vulnerability suppressed through the ignore list
+#[derive(Debug, Clone)]
+pub struct IgnoredVulnerabilityView {
+    /// The suppressed vulnerability
+    pub vulnerability: VulnerabilityView,
+    /// VEX analysis state
+    pub state: AnalysisStateView,
+    /// Reason given in the ignore entry
+    pub detail: Option<String>,
+}
+
+/// VEX analysis state of an ignored vulnerability
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalysisStateView {
+    /// The component is not affected
+    NotAffected,
+    /// The vulnerability was reported in error
+    FalsePositive,
+}
+
+impl AnalysisStateView {
+    /// Returns the CycloneDX analysis state (e.g. "not_affected")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalysisStateView::NotAffected => "not_affected",
+            AnalysisStateView::FalsePositive => "false_positive",
+        }
+    }
 }
 
 /// Severity level for display purposes
@@ -106,6 +186,27 @@ impl SeverityView {
     }
 }
 
+/// CVSS version for display purposes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CvssVersionView {
+    V2_0,
+    V3_0,
+    V3_1,
+    V4_0,
+}
+
+impl CvssVersionView {
+    /// Returns the version number (e.g. "3.1")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CvssVersionView::V2_0 => "2.0",
+            CvssVersionView::V3_0 => "3.0",
+            CvssVersionView::V3_1 => "3.1",
+            CvssVersionView::V4_0 => "4.0",
+        }
+    }
+}
+
 /// Summary statistics for vulnerabilities
 #[derive(Debug, Clone, Default)]
 pub struct VulnerabilitySummary {
@@ -128,6 +229,14 @@ mod tests {
         assert_eq!(SeverityView::None.as_str(), "NONE");
     }
 
+    #[test]
+    fn test_cvss_version_view_as_str() {
+        assert_eq!(CvssVersionView::V2_0.as_str(), "2.0");
+        assert_eq!(CvssVersionView::V3_0.as_str(), "3.0");
+        assert_eq!(CvssVersionView::V3_1.as_str(), "3.1");
+        assert_eq!(CvssVersionView::V4_0.as_str(), "4.0");
+    }
+
     #[test]
     fn test_severity_view_ordering() {
         assert!(SeverityView::Critical < SeverityView::High);
@@ -145,15 +254,20 @@ mod tests {
         VulnerabilityView {
             bom_ref: String::new(),
             id: String::new(),
+            aliases: Vec::new(),
             affected_component: String::new(),
             affected_component_name: String::new(),
             affected_version: String::new(),
             cvss_score: None,
             cvss_vector: None,
+            cvss_version: None,
             severity,
             fixed_version: None,
             description: None,
             source_url: None,
+            introduced_by: vec![],
+            excluded_from_inventory: false,
+            in_baseline: false,
         }
     }
 
@@ -218,4 +332,50 @@ mod tests {
         assert_eq!(counts.medium, 1);
         assert_eq!(counts.low, 1);
     }
+
+    fn informational(display: InformationalDisplay) -> VulnerabilityReportView {
+        VulnerabilityReportView {
+            informational: vec![
+                make_vuln(SeverityView::None),
+                make_vuln(SeverityView::Low),
+                make_vuln(SeverityView::None),
+            ],
+            informational_display: display,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_listed_informational_limit() {
+        let report = informational(InformationalDisplay::Limit(1));
+        let (listed, omitted) = report.listed_informational().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].severity, SeverityView::Low);
+        assert_eq!(omitted, 2);
+
+        let report = informational(InformationalDisplay::Limit(0));
+        let (listed, omitted) = report.listed_informational().unwrap();
+        assert!(listed.is_empty());
+        assert_eq!(omitted, 3);
+    }
+
+    #[test]
+    fn test_listed_informational_limit_at_or_above_total() {
+        for limit in [3, 4] {
+            let report = informational(InformationalDisplay::Limit(limit));
+            let (listed, omitted) = report.listed_informational().unwrap();
+            assert_eq!(listed.len(), 3);
+            assert_eq!(omitted, 0);
+        }
+    }
+
+    #[test]
+    fn test_listed_informational_hidden_keeps_counts() {
+        let report = informational(InformationalDisplay::Hidden);
+        assert!(report.listed_informational().is_none());
+        assert_eq!(report.counts_by_severity().low, 1);
+        assert!(VulnerabilityReportView::default()
+            .listed_informational()
+            .is_none());
+    }
 }
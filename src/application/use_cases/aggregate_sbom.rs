@@ -0,0 +1,489 @@
+use crate::application::dto::SbomRequest;
+use crate::application::read_models::{
+    ComponentView, DependencyView, LicenseView, ProjectView, SbomReadModel, SbomReadModelBuilder,
+    SbomWarning, VersionConflictView, VulnerabilityReportView, VulnerabilityView,
+};
+use crate::application::use_cases::GenerateSbomUseCase;
+use crate::ports::outbound::{
+    LicenseRepository, LockfileReader, MaintenanceRepository, ProgressReporter,
+    ProjectConfigReader, VulnerabilityRepository,
+};
+use crate::sbom_generation::domain::PackageName;
+use crate::shared::error::UvSbomError;
+use crate::shared::Result;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// The read model of one source project, with the name and version it is listed under
+struct ProjectSbom {
+    name: String,
+    version: Option<String>,
+    model: SbomReadModel,
+}
+
+/// Use case for merging the SBOMs of several projects into one aggregated SBOM.
+///
+/// Each project goes through the regular `GenerateSbomUseCase` pipeline, sharing
+/// its adapters, and the resulting read models are merged:
+/// - components are deduplicated by purl and version; when projects disagree on a
+///   package's license, a known license wins over a missing one and a mismatch
+///   keeps the first and raises [`SbomWarning::ConflictingLicense`]
+/// - each project's direct dependencies are kept in [`SbomReadModel::projects`],
+///   while the transitive edges of all projects are combined
+/// - vulnerabilities are deduplicated by ID and affected package
+///
+/// License compliance, license compatibility, the resolution guide, upgrade
+/// recommendations and statistics describe a single project and are not carried over.
+///
+/// # Type Parameters
+/// Same as [`GenerateSbomUseCase`]
+pub struct AggregateSbomUseCase<LR, PCR, LREPO, PR, VREPO, MREPO> {
+    generate_sbom: GenerateSbomUseCase<LR, PCR, LREPO, PR, VREPO, MREPO>,
+    project_config_reader: PCR,
+}
+
+impl<LR, PCR, LREPO, PR, VREPO, MREPO> AggregateSbomUseCase<LR, PCR, LREPO, PR, VREPO, MREPO>
+where
+    LR: LockfileReader,
+    PCR: ProjectConfigReader,
+    LREPO: LicenseRepository + Clone,
+    PR: ProgressReporter,
+    VREPO: VulnerabilityRepository + Clone,
+    MREPO: MaintenanceRepository + Clone,
+{
+    /// Creates a new `AggregateSbomUseCase`.
+    ///
+    /// `project_config_reader` reads each project's name and version; the
+    /// generate use case keeps its own reader for annotations and licenses.
+    pub fn new(
+        generate_sbom: GenerateSbomUseCase<LR, PCR, LREPO, PR, VREPO, MREPO>,
+        project_config_reader: PCR,
+    ) -> Self {
+        Self {
+            generate_sbom,
+            project_config_reader,
+        }
+    }
+
+    /// Generates an SBOM for each request, in order, and returns the merged read model.
+    ///
+    /// The metadata (timestamp, serial number) is taken from the first project.
+    /// Requests should set `include_dependency_info` so that packages can be
+    /// attributed to the projects that depend on them.
+    pub async fn execute(&self, requests: Vec<SbomRequest>) -> Result<SbomReadModel> {
+        if requests.is_empty() {
+            return Err(UvSbomError::validation(
+                "at least one project is required to aggregate SBOMs",
+            ));
+        }
+
+        let mut projects = Vec::with_capacity(requests.len());
+        for request in requests {
+            let project_path = request.project_path.clone();
+            let response = self.generate_sbom.execute(request).await?;
+
+            let name = self.project_name(&project_path);
+            let version = self
+                .project_config_reader
+                .read_project_version(&project_path)
+                .ok()
+                .flatten()
+                .or_else(|| {
+                    response
+                        .enriched_packages
+                        .iter()
+                        .find(|ep| {
+                            PackageName::normalize(ep.package.name())
+                                == PackageName::normalize(&name)
+                        })
+                        .map(|ep| ep.package.version().to_string())
+                });
+
+            let model = SbomReadModelBuilder::build_with_project(
+                response.enriched_packages,
+                &response.metadata,
+                response.dependency_graph.as_ref(),
+                response.vulnerability_check_result.as_ref(),
+                None,
+                None,
+                None,
+            )
+            .with_annotations(&response.package_annotations)
+            .with_license_overrides(&response.license_overrides)
+            .with_warnings(response.warnings);
+
+            projects.push(ProjectSbom {
+                name,
+                version,
+                model,
+            });
+        }
+
+        Ok(merge(projects))
+    }
+
+    /// The `[project].name` from `pyproject.toml`, falling back to the directory name
+    fn project_name(&self, project_path: &Path) -> String {
+        self.project_config_reader
+            .read_project_name(project_path)
+            .unwrap_or_else(|_| {
+                project_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| project_path.display().to_string())
+            })
+    }
+}
+
+/// Merges per-project read models, in project order. `projects` must not be empty.
+fn merge(projects: Vec<ProjectSbom>) -> SbomReadModel {
+    let mut metadata = projects[0].model.metadata.clone();
+    metadata.component = None;
+    // Each project was generated with its own project path
+    metadata.invocation = None;
+
+    let mut views = Vec::with_capacity(projects.len());
+    let mut components: Vec<ComponentView> = Vec::new();
+    let mut component_index: HashMap<(String, String), usize> = HashMap::new();
+    let mut dependencies: Option<DependencyView> = None;
+    let mut vulnerabilities: Option<VulnerabilityReportView> = None;
+    let mut warnings: Vec<SbomWarning> = Vec::new();
+    let mut version_conflicts: Vec<VersionConflictView> = Vec::new();
+
+    for project in projects {
+        let model = project.model;
+        views.push(ProjectView {
+            name: project.name,
+            version: project.version,
+            direct_dependencies: model
+                .dependencies
+                .as_ref()
+                .map(|d| d.direct.clone())
+                .unwrap_or_default(),
+        });
+
+        for component in model.components {
+            let key = (component.purl.clone(), component.version.clone());
+            match component_index.get(&key) {
+                Some(&i) => merge_component(&mut components[i], component, &mut warnings),
+                None => {
+                    component_index.insert(key, components.len());
+                    components.push(component);
+                }
+            }
+        }
+
+        if let Some(project_dependencies) = model.dependencies {
+            merge_dependencies(
+                dependencies.get_or_insert_with(DependencyView::default),
+                project_dependencies,
+            );
+        }
+        if let Some(report) = model.vulnerabilities {
+            merge_vulnerabilities(
+                vulnerabilities.get_or_insert_with(VulnerabilityReportView::default),
+                report,
+            );
+        }
+        for warning in model.warnings {
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+        for conflict in model.version_conflicts {
+            match version_conflicts
+                .iter_mut()
+                .find(|c| c.name == conflict.name)
+            {
+                Some(existing) => {
+                    for version in conflict.versions {
+                        if !existing.versions.contains(&version) {
+                            existing.versions.push(version);
+                        }
+                    }
+                }
+                None => version_conflicts.push(conflict),
+            }
+        }
+    }
+    version_conflicts.sort_by(|a, b| a.name.cmp(&b.name));
+
+    if let Some(report) = &mut vulnerabilities {
+        let all = report.actionable.iter().chain(report.informational.iter());
+        let affected: HashSet<&str> = all
+            .clone()
+            .map(|v| v.affected_component_name.as_str())
+            .collect();
+        report.summary.total_count = all.count();
+        report.summary.affected_package_count = affected.len();
+    }
+
+    SbomReadModel {
+        metadata,
+        components,
+        dependencies,
+        vulnerabilities,
+        license_compliance: None,
+        license_compatibility: None,
+        license_risk: None,
+        python_compatibility: None,
+        typosquats: Vec::new(),
+        resolution_guide: None,
+        upgrade_recommendations: None,
+        fix_suggestions: None,
+        project_self_check: None,
+        warnings,
+        stats: None,
+        projects: views,
+        version_conflicts,
+    }
+}
+
+/// Folds a duplicate of `existing` from another project into it
+fn merge_component(
+    existing: &mut ComponentView,
+    other: ComponentView,
+    warnings: &mut Vec<SbomWarning>,
+) {
+    match (&existing.license, other.license) {
+        (None, Some(license)) => {
+            existing.license = Some(license);
+            existing.license_override = other.license_override;
+        }
+        (Some(kept), Some(ignored)) if license_label(kept) != license_label(&ignored) => {
+            warnings.push(SbomWarning::ConflictingLicense {
+                package: existing.name.clone(),
+                version: existing.version.clone(),
+                kept: license_label(kept).to_string(),
+                ignored: license_label(&ignored).to_string(),
+            });
+        }
+        _ => {}
+    }
+    existing.description = existing.description.take().or(other.description);
+    existing.sha256_hash = existing.sha256_hash.take().or(other.sha256_hash);
+    existing.is_direct_dependency |= other.is_direct_dependency;
+    for (key, value) in other.annotations {
+        existing.annotations.entry(key).or_insert(value);
+    }
+}
+
+fn license_label(license: &LicenseView) -> &str {
+    license.spdx_id.as_deref().unwrap_or(&license.name)
+}
+
+/// Adds a project's direct and transitive edges, keeping first-seen order, and
+/// the extras it requests
+fn merge_dependencies(merged: &mut DependencyView, project: DependencyView) {
+    extend_unique(&mut merged.direct, project.direct);
+    for (parent, children) in project.transitive {
+        extend_unique(merged.transitive.entry(parent).or_default(), children);
+    }
+    for (bom_ref, extras) in project.extras {
+        let merged_extras = merged.extras.entry(bom_ref).or_default();
+        extend_unique(merged_extras, extras);
+        merged_extras.sort();
+    }
+}
+
+fn extend_unique(target: &mut Vec<String>, items: Vec<String>) {
+    for item in items {
+        if !target.contains(&item) {
+            target.push(item);
+        }
+    }
+}
+
+/// Adds a project's findings, skipping those already reported for the same package.
+///
+/// A finding is identified by its vulnerability ID and affected component, i.e. its
+/// purl. The packages that introduce it are combined across projects.
+fn merge_vulnerabilities(merged: &mut VulnerabilityReportView, project: VulnerabilityReportView) {
+    let key = |v: &VulnerabilityView| (v.id.clone(), v.affected_component.clone());
+    let mut seen: HashMap<(String, String), (bool, usize)> = HashMap::new();
+    for (i, v) in merged.actionable.iter().enumerate() {
+        seen.insert(key(v), (true, i));
+    }
+    for (i, v) in merged.informational.iter().enumerate() {
+        seen.insert(key(v), (false, i));
+    }
+
+    let incoming = project
+        .actionable
+        .into_iter()
+        .map(|v| (true, v))
+        .chain(project.informational.into_iter().map(|v| (false, v)));
+    for (actionable, vulnerability) in incoming {
+        match seen.get(&key(&vulnerability)) {
+            Some(&(in_actionable, i)) => {
+                let existing = if in_actionable {
+                    &mut merged.actionable[i]
+                } else {
+                    &mut merged.informational[i]
+                };
+                extend_unique(&mut existing.introduced_by, vulnerability.introduced_by);
+                existing.introduced_by.sort();
+            }
+            None => {
+                let list = if actionable {
+                    &mut merged.actionable
+                } else {
+                    &mut merged.informational
+                };
+                seen.insert(key(&vulnerability), (actionable, list.len()));
+                list.push(vulnerability);
+            }
+        }
+    }
+
+    for ignored in project.ignored {
+        let is_new = !merged
+            .ignored
+            .iter()
+            .any(|i| key(&i.vulnerability) == key(&ignored.vulnerability));
+        if is_new {
+            merged.ignored.push(ignored);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::read_models::SeverityView;
+    use crate::test_fixtures::SbomReadModelFixture;
+
+    fn project(name: &str, model: SbomReadModel) -> ProjectSbom {
+        ProjectSbom {
+            name: name.to_string(),
+            version: Some("1.0.0".to_string()),
+            model,
+        }
+    }
+
+    #[test]
+    fn test_merge_deduplicates_components_and_prefers_known_licenses() {
+        let mut api = SbomReadModelFixture::builder();
+        api.add_component("requests", "2.31.0").direct();
+        api.add_component("urllib3", "1.26.0").license("MIT");
+        api.add_component("idna", "3.7").license("BSD-3-Clause");
+        let mut worker = SbomReadModelFixture::builder();
+        worker
+            .add_component("requests", "2.31.0")
+            .license("Apache-2.0");
+        worker
+            .add_component("urllib3", "1.26.0")
+            .license("Apache-2.0");
+        worker
+            .add_component("urllib3", "2.2.1")
+            .license("MIT")
+            .direct();
+
+        let merged = merge(vec![
+            project("api", api.build()),
+            project("worker", worker.build()),
+        ]);
+
+        let components: Vec<(&str, &str, Option<&str>, bool)> = merged
+            .components
+            .iter()
+            .map(|c| {
+                (
+                    c.name.as_str(),
+                    c.version.as_str(),
+                    c.license.as_ref().and_then(|l| l.spdx_id.as_deref()),
+                    c.is_direct_dependency,
+                )
+            })
+            .collect();
+        assert_eq!(
+            components,
+            vec![
+                ("requests", "2.31.0", Some("Apache-2.0"), true),
+                ("urllib3", "1.26.0", Some("MIT"), false),
+                ("idna", "3.7", Some("BSD-3-Clause"), false),
+                ("urllib3", "2.2.1", Some("MIT"), true),
+            ]
+        );
+        assert_eq!(
+            merged.warnings,
+            vec![SbomWarning::ConflictingLicense {
+                package: "urllib3".to_string(),
+                version: "1.26.0".to_string(),
+                kept: "MIT".to_string(),
+                ignored: "Apache-2.0".to_string(),
+            }]
+        );
+        assert!(merged.metadata.component.is_none());
+    }
+
+    #[test]
+    fn test_merge_keeps_direct_dependencies_per_project() {
+        let mut api = SbomReadModelFixture::builder();
+        api.add_component("requests", "2.31.0").direct();
+        api.add_component("urllib3", "1.26.0");
+        let api = api.with_dependencies(&[("requests", &["urllib3"])]).build();
+        let mut worker = SbomReadModelFixture::builder();
+        worker.add_component("celery", "5.4.0").direct();
+        worker.add_component("requests", "2.31.0").direct();
+        worker.add_component("urllib3", "1.26.0");
+        let worker = worker
+            .with_dependencies(&[("requests", &["urllib3"])])
+            .build();
+
+        let merged = merge(vec![project("api", api), project("worker", worker)]);
+
+        let direct: Vec<(&str, &[String])> = merged
+            .projects
+            .iter()
+            .map(|p| (p.name.as_str(), p.direct_dependencies.as_slice()))
+            .collect();
+        assert_eq!(
+            direct,
+            vec![
+                ("api", &["requests-2.31.0".to_string()][..]),
+                (
+                    "worker",
+                    &["celery-5.4.0".to_string(), "requests-2.31.0".to_string()][..]
+                ),
+            ]
+        );
+        let dependencies = merged.dependencies.unwrap();
+        assert_eq!(dependencies.direct, vec!["requests-2.31.0", "celery-5.4.0"]);
+        assert_eq!(
+            dependencies.transitive["requests-2.31.0"],
+            vec!["urllib3-1.26.0"]
+        );
+    }
+
+    #[test]
+    fn test_merge_deduplicates_vulnerabilities_by_id_and_package() {
+        let api = SbomReadModelFixture::with_vulns().build();
+        let mut worker = SbomReadModelFixture::with_vulns();
+        worker.add_component("urllib3", "2.2.1").license("MIT");
+        worker
+            .add_vulnerability("CVE-2024-5678")
+            .severity(SeverityView::Medium)
+            .on("urllib3", "2.2.1");
+
+        let merged = merge(vec![project("api", api), project("worker", worker.build())]);
+
+        let report = merged.vulnerabilities.unwrap();
+        let findings: Vec<(&str, &str)> = report
+            .actionable
+            .iter()
+            .chain(report.informational.iter())
+            .map(|v| (v.id.as_str(), v.affected_component.as_str()))
+            .collect();
+        assert_eq!(
+            findings,
+            vec![
+                ("CVE-2024-1234", "requests-2.31.0"),
+                ("CVE-2024-5678", "urllib3-2.2.1"),
+                ("CVE-2024-5678", "urllib3-1.26.0"),
+            ]
+        );
+        assert_eq!(report.summary.total_count, 3);
+        assert_eq!(report.summary.affected_package_count, 2);
+    }
+}
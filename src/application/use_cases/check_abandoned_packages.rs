@@ -1,10 +1,7 @@
-use crate::ports::outbound::{MaintenanceInfo, MaintenanceRepository};
+use crate::ports::outbound::{MaintenanceInfo, MaintenanceRepository, NoopTaskHandle, TaskHandle};
 use crate::sbom_generation::domain::Package;
 use crate::shared::Result;
-use indicatif::{ProgressBar, ProgressStyle};
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::thread;
 use std::time::Duration;
 
 /// Delay between maintenance info fetch requests (ms)
@@ -12,7 +9,7 @@ const MAINTENANCE_FETCH_DELAY_MS: u64 = 100;
 
 /// Use case for fetching package maintenance information for a list of packages.
 ///
-/// Handles progress bar display and sequential fetching, delegating the actual
+/// Handles progress updates and sequential fetching, delegating the actual
 /// retrieval to the injected `MaintenanceRepository`. Errors per package are
 /// collected and surfaced as warnings rather than aborting the whole check.
 ///
@@ -20,6 +17,7 @@ const MAINTENANCE_FETCH_DELAY_MS: u64 = 100;
 /// * `MR` - `MaintenanceRepository` implementation
 pub struct CheckAbandonedPackagesUseCase<MR: MaintenanceRepository> {
     maintenance_repository: MR,
+    progress: Arc<dyn TaskHandle>,
 }
 
 impl<MR: MaintenanceRepository> CheckAbandonedPackagesUseCase<MR> {
@@ -27,49 +25,34 @@ impl<MR: MaintenanceRepository> CheckAbandonedPackagesUseCase<MR> {
     pub fn new(maintenance_repository: MR) -> Self {
         Self {
             maintenance_repository,
+            progress: Arc::new(NoopTaskHandle),
         }
     }
 
-    /// Fetches maintenance information for all packages with a progress bar.
+    /// Reports progress to `progress`, a task started by the caller.
+    pub fn with_progress(mut self, progress: Arc<dyn TaskHandle>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Fetches maintenance information for all packages, reporting progress.
     ///
     /// Returns `(results, errors)` where:
-    /// - `results` is `(Package, MaintenanceInfo)` pairs for successful fetches
+    /// - `results` is `(package, MaintenanceInfo)` pairs for successful fetches,
+    ///   borrowing from `packages`
     /// - `errors` is `(package_name, error_message)` pairs for failed fetches
     ///
     /// Failed packages are omitted from `results` (no entry with `None` — callers
     /// skip packages with unknown release dates anyway).
-    pub async fn fetch_with_progress(
+    pub async fn fetch_with_progress<'a>(
         &self,
-        packages: Vec<Package>,
-    ) -> Result<(Vec<(Package, MaintenanceInfo)>, Vec<(String, String)>)> {
+        packages: &'a [Package],
+    ) -> Result<(Vec<(&'a Package, MaintenanceInfo)>, Vec<(String, String)>)> {
         let total = packages.len();
-        let progress_current = Arc::new(AtomicUsize::new(0));
-        let is_done = Arc::new(AtomicBool::new(false));
-
-        let progress_handle = {
-            let cur = progress_current.clone();
-            let done = is_done.clone();
-            thread::spawn(move || {
-                let pb = ProgressBar::new(total as u64);
-                pb.set_style(
-                    ProgressStyle::default_bar()
-                        .template("   {spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} - {msg}")
-                        .expect("Failed to set progress bar template")
-                        .progress_chars("=>-"),
-                );
-                pb.set_message("Fetching maintenance information..."); // i18n-ok: internal progress bar label
-                while !done.load(Ordering::Relaxed) {
-                    pb.set_position(cur.load(Ordering::Relaxed) as u64);
-                    thread::sleep(Duration::from_millis(50));
-                }
-                pb.finish_and_clear();
-            })
-        };
-
-        let mut results: Vec<(Package, MaintenanceInfo)> = Vec::new();
+        let mut results: Vec<(&Package, MaintenanceInfo)> = Vec::new();
         let mut errors: Vec<(String, String)> = Vec::new();
 
-        for (idx, package) in packages.into_iter().enumerate() {
+        for (idx, package) in packages.iter().enumerate() {
             let name = package.name().to_string();
             match self
                 .maintenance_repository
@@ -79,15 +62,12 @@ impl<MR: MaintenanceRepository> CheckAbandonedPackagesUseCase<MR> {
                 Ok(info) => results.push((package, info)),
                 Err(e) => errors.push((name, e.to_string())),
             }
-            progress_current.store(idx + 1, Ordering::Relaxed);
+            self.progress.update(idx + 1, total);
             if idx < total - 1 {
                 tokio::time::sleep(Duration::from_millis(MAINTENANCE_FETCH_DELAY_MS)).await;
             }
         }
 
-        is_done.store(true, Ordering::Relaxed);
-        let _ = progress_handle.join();
-
         Ok((results, errors))
     }
 }
@@ -114,11 +94,9 @@ mod tests {
             NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
         )))]);
         let use_case = CheckAbandonedPackagesUseCase::new(repo);
+        let packages = [pkg("requests", "2.31.0")];
 
-        let (results, errors) = use_case
-            .fetch_with_progress(vec![pkg("requests", "2.31.0")])
-            .await
-            .unwrap();
+        let (results, errors) = use_case.fetch_with_progress(&packages).await.unwrap();
 
         assert_eq!(results.len(), 1);
         assert!(errors.is_empty());
@@ -129,11 +107,9 @@ mod tests {
     async fn test_fetch_with_progress_error_collected() {
         let repo = MockMaintenanceRepository::with_responses([Err("network error".to_string())]);
         let use_case = CheckAbandonedPackagesUseCase::new(repo);
+        let packages = [pkg("requests", "2.31.0")];
 
-        let (results, errors) = use_case
-            .fetch_with_progress(vec![pkg("requests", "2.31.0")])
-            .await
-            .unwrap();
+        let (results, errors) = use_case.fetch_with_progress(&packages).await.unwrap();
 
         assert!(results.is_empty());
         assert_eq!(errors.len(), 1);
@@ -146,7 +122,7 @@ mod tests {
         let repo = MockMaintenanceRepository::new();
         let use_case = CheckAbandonedPackagesUseCase::new(repo);
 
-        let (results, errors) = use_case.fetch_with_progress(vec![]).await.unwrap();
+        let (results, errors) = use_case.fetch_with_progress(&[]).await.unwrap();
 
         assert!(results.is_empty());
         assert!(errors.is_empty());
@@ -167,7 +143,7 @@ mod tests {
             pkg("certifi", "2024.1.1"),
         ];
 
-        let (results, errors) = use_case.fetch_with_progress(packages).await.unwrap();
+        let (results, errors) = use_case.fetch_with_progress(&packages).await.unwrap();
 
         assert_eq!(results.len(), 2);
         assert_eq!(errors.len(), 1);
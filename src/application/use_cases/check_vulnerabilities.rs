@@ -1,22 +1,21 @@
-use crate::ports::outbound::VulnerabilityRepository;
+use crate::ports::outbound::{
+    NoopTaskHandle, TaskHandle, VulnerabilityFetchOutcome, VulnerabilityRepository,
+};
 use crate::sbom_generation::domain::{Package, PackageVulnerabilities};
 use crate::shared::Result;
-use indicatif::{ProgressBar, ProgressStyle};
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
-use std::thread;
-use std::time::Duration;
 
 /// CheckVulnerabilitiesUseCase - Use case for checking vulnerabilities
 ///
 /// This use case provides vulnerability fetching functionality with progress reporting.
-/// It encapsulates the progress bar display logic and delegates to the VulnerabilityRepository
-/// for the actual fetching.
+/// It forwards the repository's progress to a task handle and delegates to the
+/// VulnerabilityRepository for the actual fetching.
 ///
 /// # Type Parameters
 /// * `R` - VulnerabilityRepository implementation
 pub struct CheckVulnerabilitiesUseCase<R: VulnerabilityRepository> {
     vulnerability_repository: R,
+    progress: Arc<dyn TaskHandle>,
 }
 
 impl<R: VulnerabilityRepository> CheckVulnerabilitiesUseCase<R> {
@@ -27,83 +26,38 @@ impl<R: VulnerabilityRepository> CheckVulnerabilitiesUseCase<R> {
     pub fn new(vulnerability_repository: R) -> Self {
         Self {
             vulnerability_repository,
+            progress: Arc::new(NoopTaskHandle),
         }
     }
 
-    /// Fetches vulnerabilities for packages with progress bar display
+    /// Reports progress to `progress`, a task started by the caller.
+    pub fn with_progress(mut self, progress: Arc<dyn TaskHandle>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Fetches vulnerabilities for packages with progress reporting
     ///
-    /// This method handles the progress bar UI and delegates to the repository
-    /// for actual vulnerability fetching. The progress bar shows:
-    /// - A spinner during the batch query phase
-    /// - A progress bar during individual vulnerability detail fetching
+    /// The total stays 0 during the batch query phase and becomes the number of
+    /// vulnerability details to fetch once that is known.
     ///
     /// # Arguments
     /// * `packages` - Packages to check for vulnerabilities
     ///
     /// # Returns
-    /// Vector of PackageVulnerabilities for packages that have vulnerabilities
+    /// The packages that have vulnerabilities, and the packages that could not be checked
     pub async fn check_with_progress(
         &self,
-        packages: Vec<Package>,
-    ) -> Result<Vec<PackageVulnerabilities>> {
-        // Create atomic counters for thread-safe progress sharing
-        let progress_current = Arc::new(AtomicUsize::new(0));
-        let progress_total = Arc::new(AtomicUsize::new(0));
-        let is_done = Arc::new(AtomicBool::new(false));
-
-        // Clone references for the progress bar update thread
-        let current_clone = progress_current.clone();
-        let total_clone = progress_total.clone();
-        let done_clone = is_done.clone();
-
-        // Spawn a thread to update the progress bar
-        let progress_handle = thread::spawn(move || {
-            let pb = ProgressBar::new(0);
-            pb.set_style(
-                ProgressStyle::default_bar()
-                    .template("   {spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} - {msg}")
-                    .expect("Failed to set progress bar template")
-                    .progress_chars("=>-"),
-            );
-            pb.set_message("Fetching vulnerability details...");
-
-            // Poll for updates until done
-            while !done_clone.load(Ordering::Relaxed) {
-                let current = current_clone.load(Ordering::Relaxed);
-                let total = total_clone.load(Ordering::Relaxed);
-
-                if total > 0 {
-                    pb.set_length(total as u64);
-                    pb.set_position(current as u64);
-                } else {
-                    // Still in batch query phase - show spinner
-                    pb.tick();
-                }
-
-                thread::sleep(Duration::from_millis(50));
-            }
-
-            pb.finish_and_clear();
-        });
-
-        // Create progress callback that updates atomic counters
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
+        let progress = self.progress.clone();
         let progress_callback: Box<dyn Fn(usize, usize) + Send> =
-            Box::new(move |current: usize, total: usize| {
-                progress_current.store(current, Ordering::Relaxed);
-                progress_total.store(total, Ordering::Relaxed);
-            });
+            Box::new(move |current: usize, total: usize| progress.update(current, total));
 
         // Fetch vulnerabilities with progress reporting
-        let vulnerabilities = self
-            .vulnerability_repository
+        self.vulnerability_repository
             .fetch_vulnerabilities_with_progress(packages, progress_callback)
-            .await?;
-
-        // Signal completion and wait for progress bar thread
-        is_done.store(true, Ordering::Relaxed);
-        let _ = progress_handle.join();
-
-        Ok(vulnerabilities)
+            .await
     }
 
     /// Returns a summary of vulnerabilities found
@@ -206,13 +160,15 @@ mod tests {
 
     #[tokio::test]
     async fn test_check_with_progress_no_vulnerabilities() {
-        let repo = MockVulnerabilityRepository {
-            vulnerabilities: vec![],
-        };
+        let repo = MockVulnerabilityRepository::new();
         let use_case = CheckVulnerabilitiesUseCase::new(repo);
 
         let packages = vec![create_test_package("requests", "2.31.0")];
-        let result = use_case.check_with_progress(packages).await.unwrap();
+        let result = use_case
+            .check_with_progress(&packages)
+            .await
+            .unwrap()
+            .results;
 
         assert!(result.is_empty());
     }
@@ -224,11 +180,16 @@ mod tests {
 
         let repo = MockVulnerabilityRepository {
             vulnerabilities: vec![pkg_vulns],
+            ..Default::default()
         };
         let use_case = CheckVulnerabilitiesUseCase::new(repo);
 
         let packages = vec![create_test_package("requests", "2.31.0")];
-        let result = use_case.check_with_progress(packages).await.unwrap();
+        let result = use_case
+            .check_with_progress(&packages)
+            .await
+            .unwrap()
+            .results;
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].package_name(), "requests");
@@ -244,6 +205,7 @@ mod tests {
 
         let repo = MockVulnerabilityRepository {
             vulnerabilities: vec![pkg_vulns1, pkg_vulns2],
+            ..Default::default()
         };
         let use_case = CheckVulnerabilitiesUseCase::new(repo);
 
@@ -251,8 +213,31 @@ mod tests {
             create_test_package("requests", "2.31.0"),
             create_test_package("urllib3", "1.26.0"),
         ];
-        let result = use_case.check_with_progress(packages).await.unwrap();
+        let result = use_case
+            .check_with_progress(&packages)
+            .await
+            .unwrap()
+            .results;
 
         assert_eq!(result.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_check_with_progress_reports_failed_packages() {
+        let repo = MockVulnerabilityRepository {
+            failing_packages: vec!["urllib3".to_string()],
+            ..Default::default()
+        };
+        let use_case = CheckVulnerabilitiesUseCase::new(repo);
+
+        let packages = vec![
+            create_test_package("requests", "2.31.0"),
+            create_test_package("urllib3", "1.26.0"),
+        ];
+        let outcome = use_case.check_with_progress(&packages).await.unwrap();
+
+        assert!(outcome.results.is_empty());
+        assert_eq!(outcome.failed_packages.len(), 1);
+        assert_eq!(outcome.failed_packages[0].package.name(), "urllib3");
+    }
 }
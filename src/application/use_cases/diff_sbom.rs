@@ -0,0 +1,197 @@
+use crate::ports::outbound::{LockfileReader, SbomReader};
+use crate::sbom_generation::domain::{PackageEntry, SbomDiff};
+use crate::shared::Result;
+use std::path::{Path, PathBuf};
+
+/// One side of an SBOM comparison
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffSource {
+    /// A project directory; packages come from its lockfile
+    Project(PathBuf),
+    /// A previously generated CycloneDX JSON file
+    CycloneDx(PathBuf),
+}
+
+impl DiffSource {
+    /// Treats paths ending in `.json` as CycloneDX files and anything else as a project directory
+    pub fn from_path(path: PathBuf) -> Self {
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if is_json {
+            DiffSource::CycloneDx(path)
+        } else {
+            DiffSource::Project(path)
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        match self {
+            DiffSource::Project(path) | DiffSource::CycloneDx(path) => path,
+        }
+    }
+}
+
+/// Use case for comparing two SBOMs, e.g. the lockfiles of two releases.
+///
+/// Each side is either a project directory, read through a `LockfileReader`,
+/// or a saved CycloneDX file, read through an `SbomReader`. Lockfiles carry no
+/// license data, so license-only changes are found when both sides are SBOM files.
+///
+/// # Type Parameters
+/// * `LR` - `LockfileReader` implementation
+/// * `SR` - `SbomReader` implementation
+pub struct DiffSbomUseCase<LR: LockfileReader, SR: SbomReader> {
+    base_lockfile_reader: LR,
+    head_lockfile_reader: LR,
+    sbom_reader: SR,
+}
+
+impl<LR: LockfileReader, SR: SbomReader> DiffSbomUseCase<LR, SR> {
+    /// Creates a new `DiffSbomUseCase`.
+    ///
+    /// Each side gets its own lockfile reader, since the base and head projects
+    /// may use different lockfile formats.
+    pub fn new(base_lockfile_reader: LR, head_lockfile_reader: LR, sbom_reader: SR) -> Self {
+        Self {
+            base_lockfile_reader,
+            head_lockfile_reader,
+            sbom_reader,
+        }
+    }
+
+    /// Reads both sides and returns the packages added, removed and updated in `head`.
+    pub fn execute(&self, base: &DiffSource, head: &DiffSource) -> Result<SbomDiff> {
+        let base_packages = self.read_packages(base, &self.base_lockfile_reader)?;
+        let head_packages = self.read_packages(head, &self.head_lockfile_reader)?;
+        Ok(SbomDiff::compare(&base_packages, &head_packages))
+    }
+
+    fn read_packages(
+        &self,
+        source: &DiffSource,
+        lockfile_reader: &LR,
+    ) -> Result<Vec<PackageEntry>> {
+        match source {
+            DiffSource::Project(path) => {
+                let lockfile = lockfile_reader.read_and_parse_lockfile(path)?;
+                Ok(lockfile
+                    .packages
+                    .iter()
+                    .map(|p| PackageEntry::new(p.name(), p.version()))
+                    .collect())
+            }
+            DiffSource::CycloneDx(path) => self.sbom_reader.read_packages(path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::outbound::LockfileParseResult;
+    use crate::sbom_generation::domain::Package;
+
+    struct MockLockfileReader {
+        packages: Vec<(&'static str, &'static str)>,
+    }
+
+    impl LockfileReader for MockLockfileReader {
+        fn read_lockfile(&self, _path: &Path) -> Result<String> {
+            Ok(String::new())
+        }
+
+        fn read_and_parse_lockfile(&self, _path: &Path) -> Result<LockfileParseResult> {
+            let packages = self
+                .packages
+                .iter()
+                .map(|(name, version)| Package::new(name.to_string(), version.to_string()))
+                .collect::<Result<_>>()?;
+            Ok(LockfileParseResult {
+                packages,
+                ..Default::default()
+            })
+        }
+
+        fn read_and_parse_lockfile_for_member(
+            &self,
+            path: &Path,
+            _member_name: &str,
+        ) -> Result<LockfileParseResult> {
+            self.read_and_parse_lockfile(path)
+        }
+    }
+
+    struct MockSbomReader {
+        packages: Vec<PackageEntry>,
+    }
+
+    impl SbomReader for MockSbomReader {
+        fn read_packages(&self, _path: &Path) -> Result<Vec<PackageEntry>> {
+            Ok(self.packages.clone())
+        }
+
+        fn read_components(&self, _path: &Path) -> Result<Vec<PackageEntry>> {
+            Ok(self.packages.clone())
+        }
+    }
+
+    #[test]
+    fn test_from_path_detects_cyclonedx_files() {
+        assert_eq!(
+            DiffSource::from_path(PathBuf::from("release/sbom.JSON")),
+            DiffSource::CycloneDx(PathBuf::from("release/sbom.JSON"))
+        );
+        assert_eq!(
+            DiffSource::from_path(PathBuf::from("../project")),
+            DiffSource::Project(PathBuf::from("../project"))
+        );
+    }
+
+    #[test]
+    fn test_execute_compares_two_projects() {
+        let use_case = DiffSbomUseCase::new(
+            MockLockfileReader {
+                packages: vec![("requests", "2.31.0"), ("chardet", "5.2.0")],
+            },
+            MockLockfileReader {
+                packages: vec![("requests", "2.32.3"), ("idna", "3.7")],
+            },
+            MockSbomReader { packages: vec![] },
+        );
+
+        let diff = use_case
+            .execute(
+                &DiffSource::Project(PathBuf::from("base")),
+                &DiffSource::Project(PathBuf::from("head")),
+            )
+            .unwrap();
+
+        assert_eq!(diff.added(), &[PackageEntry::new("idna", "3.7")]);
+        assert_eq!(diff.removed(), &[PackageEntry::new("chardet", "5.2.0")]);
+        assert_eq!(diff.updated()[0].new_version, "2.32.3");
+    }
+
+    #[test]
+    fn test_execute_compares_sbom_file_with_project() {
+        let use_case = DiffSbomUseCase::new(
+            MockLockfileReader { packages: vec![] },
+            MockLockfileReader {
+                packages: vec![("requests", "2.31.0")],
+            },
+            MockSbomReader {
+                packages: vec![PackageEntry::new("requests", "2.31.0")
+                    .with_license(Some("Apache-2.0".to_string()))],
+            },
+        );
+
+        let diff = use_case
+            .execute(
+                &DiffSource::CycloneDx(PathBuf::from("base.json")),
+                &DiffSource::Project(PathBuf::from("head")),
+            )
+            .unwrap();
+
+        assert!(diff.is_empty());
+    }
+}
@@ -1,67 +1,67 @@
-use crate::ports::outbound::{EnrichedPackage, LicenseRepository};
-use crate::sbom_generation::domain::Package;
+use crate::ports::outbound::{
+    EnrichedPackage, EnrichmentStatus, LicenseRepository, NoopTaskHandle, TaskHandle,
+};
+use crate::sbom_generation::domain::{LicenseSource, Package};
+use crate::shared::error::LicenseFetchErrorKind;
 use crate::shared::Result;
-use indicatif::{ProgressBar, ProgressStyle};
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use std::thread;
 use std::time::Duration;
 
 /// Rate limiting: delay between license fetch requests (ms)
 const LICENSE_FETCH_DELAY_MS: u64 = 100;
 
+/// A package whose license lookup failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseFetchFailure {
+    pub package: String,
+    pub kind: LicenseFetchErrorKind,
+    pub error: String,
+}
+
 /// Use case for fetching license information for a list of packages.
 ///
-/// Handles progress bar display and rate limiting, delegating the actual
+/// Handles progress updates and rate limiting, delegating the actual
 /// license retrieval to the injected `LicenseRepository`.
 ///
 /// # Type Parameters
 /// * `LREPO` - `LicenseRepository` implementation
 pub struct FetchLicensesUseCase<LREPO: LicenseRepository> {
     license_repository: LREPO,
+    progress: Arc<dyn TaskHandle>,
 }
 
 impl<LREPO: LicenseRepository> FetchLicensesUseCase<LREPO> {
     /// Creates a new `FetchLicensesUseCase` with the given repository.
     pub fn new(license_repository: LREPO) -> Self {
-        Self { license_repository }
+        Self {
+            license_repository,
+            progress: Arc::new(NoopTaskHandle),
+        }
+    }
+
+    /// Reports progress to `progress`, a task started by the caller.
+    pub fn with_progress(mut self, progress: Arc<dyn TaskHandle>) -> Self {
+        self.progress = progress;
+        self
     }
 
-    /// Fetches license information for all packages with a progress bar.
+    /// Fetches license information for all packages, reporting progress.
     ///
-    /// Returns `(enriched_packages, errors)` where errors is a list of
-    /// `(package_name, error_message)` pairs for packages whose fetch failed.
-    /// Failed packages are included in `enriched_packages` with `license: None`.
-    pub async fn fetch_with_progress(
+    /// Returns `(enriched_packages, errors)` where errors lists the packages
+    /// whose fetch failed. Failed packages are included in `enriched_packages`
+    /// with `license: None`; those not on the index get the `NotFound` license
+    /// source and the `NoMetadata` status, all others the `FetchFailed` status.
+    ///
+    /// Packages are borrowed; each one is copied only into its enriched package.
+    pub async fn fetch_with_progress<'a>(
         &self,
-        packages: Vec<Package>,
-    ) -> Result<(Vec<EnrichedPackage>, Vec<(String, String)>)> {
+        packages: impl IntoIterator<Item = &'a Package>,
+    ) -> Result<(Vec<EnrichedPackage>, Vec<LicenseFetchFailure>)> {
+        let packages: Vec<&Package> = packages.into_iter().collect();
         let total = packages.len();
-        let progress_current = Arc::new(AtomicUsize::new(0));
-        let is_done = Arc::new(AtomicBool::new(false));
-
-        let progress_handle = {
-            let cur = progress_current.clone();
-            let done = is_done.clone();
-            thread::spawn(move || {
-                let pb = ProgressBar::new(total as u64);
-                pb.set_style(
-                    ProgressStyle::default_bar()
-                        .template("   {spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} - {msg}")
-                        .expect("Failed to set progress bar template")
-                        .progress_chars("=>-"),
-                );
-                pb.set_message("Fetching license information..."); // i18n-ok: internal progress bar label, consistent with CheckVulnerabilitiesUseCase
-                while !done.load(Ordering::Relaxed) {
-                    pb.set_position(cur.load(Ordering::Relaxed) as u64);
-                    thread::sleep(Duration::from_millis(50));
-                }
-                pb.finish_and_clear();
-            })
-        };
-
-        let mut enriched = Vec::new();
-        let mut errors: Vec<(String, String)> = Vec::new();
+        let mut enriched = Vec::with_capacity(total);
+        let mut errors: Vec<LicenseFetchFailure> = Vec::new();
 
         for (idx, package) in packages.into_iter().enumerate() {
             let name = package.name().to_string();
@@ -70,47 +70,81 @@ impl<LREPO: LicenseRepository> FetchLicensesUseCase<LREPO> {
                 .enrich_with_license(&name, package.version())
                 .await
             {
-                Ok(info) => enriched.push(
-                    EnrichedPackage::new(
-                        package,
+                Ok(info) => {
+                    let enriched_package = EnrichedPackage::new(
+                        package.clone(),
                         info.license_text().map(String::from),
                         info.description().map(String::from),
-                    )
-                    .with_sha256_hash(info.sha256_hash().map(String::from)),
-                ),
+                    );
+                    // The lockfile hash identifies the locked artifact; PyPI's is only a fallback
+                    let sha256_hash = enriched_package
+                        .sha256_hash
+                        .clone()
+                        .or_else(|| info.sha256_hash().map(String::from));
+                    enriched.push(
+                        enriched_package
+                            .with_sha256_hash(sha256_hash)
+                            .with_license_source(info.source())
+                            .with_yanked(info.yanked().map(String::from))
+                            .with_authorship(info.authorship())
+                            .with_links(info.links()),
+                    );
+                }
                 Err(e) => {
-                    errors.push((name, e.to_string()));
-                    enriched.push(EnrichedPackage::new(package, None, None));
+                    let kind = LicenseFetchErrorKind::of(&e);
+                    let mut enriched_package = EnrichedPackage::new(package.clone(), None, None);
+                    // The index answered for a package it does not have; that is no
+                    // metadata rather than a failed fetch
+                    enriched_package = if kind == LicenseFetchErrorKind::NotFound {
+                        enriched_package.with_license_source(LicenseSource::NotFound)
+                    } else {
+                        enriched_package
+                            .with_enrichment_status(EnrichmentStatus::FetchFailed(e.to_string()))
+                    };
+                    errors.push(LicenseFetchFailure {
+                        package: name,
+                        kind,
+                        error: e.to_string(),
+                    });
+                    enriched.push(enriched_package);
                 }
             }
-            progress_current.store(idx + 1, Ordering::Relaxed);
+            self.progress.update(idx + 1, total);
             if idx < total - 1 {
                 tokio::time::sleep(Duration::from_millis(LICENSE_FETCH_DELAY_MS)).await;
             }
         }
 
-        is_done.store(true, Ordering::Relaxed);
-        let _ = progress_handle.join();
-
         Ok((enriched, errors))
     }
 
     /// Returns a summary of the fetch results: (successful_count, total_count, failed_count)
     pub fn summarize(
         enriched: &[EnrichedPackage],
-        errors: &[(String, String)],
+        errors: &[LicenseFetchFailure],
     ) -> (usize, usize, usize) {
         let total = enriched.len();
         let failed = errors.len();
         let successful = total - failed;
         (successful, total, failed)
     }
+
+    /// Counts the failures of each kind; kinds without failures are left out
+    pub fn count_by_kind(errors: &[LicenseFetchFailure]) -> BTreeMap<LicenseFetchErrorKind, usize> {
+        let mut counts = BTreeMap::new();
+        for failure in errors {
+            *counts.entry(failure.kind).or_insert(0) += 1;
+        }
+        counts
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ports::outbound::PyPiMetadata;
+    use crate::sbom_generation::domain::{ExternalRefType, PackageAuthorship, PackageLinks};
+    use crate::shared::error::UvSbomError;
 
     struct MockLicenseRepository;
 
@@ -121,13 +155,13 @@ mod tests {
             _package_name: &str,
             _version: &str,
         ) -> Result<PyPiMetadata> {
-            Ok((
-                Some("MIT".to_string()),
-                None,
-                vec![],
-                Some("A test package".to_string()),
-                None,
-            ))
+            Ok(PyPiMetadata {
+                license: Some("MIT".to_string()),
+                description: Some("A test package".to_string()),
+                authorship: PackageAuthorship::new(None, None, Some("PSF".to_string())),
+                links: PackageLinks::new(None, [("Source", "https://github.com/psf/requests")]),
+                ..Default::default()
+            })
         }
     }
 
@@ -140,7 +174,54 @@ mod tests {
             _package_name: &str,
             _version: &str,
         ) -> Result<PyPiMetadata> {
-            Err(anyhow::anyhow!("network error"))
+            Err(UvSbomError::other("network error"))
+        }
+    }
+
+    /// Fails with the error class the package name asks for
+    struct ClassifyingLicenseRepository;
+
+    #[async_trait::async_trait]
+    impl LicenseRepository for ClassifyingLicenseRepository {
+        async fn fetch_license_info(
+            &self,
+            package_name: &str,
+            _version: &str,
+        ) -> Result<PyPiMetadata> {
+            let status = match package_name {
+                "internal-lib" => reqwest::StatusCode::NOT_FOUND,
+                "busy" => reqwest::StatusCode::TOO_MANY_REQUESTS,
+                _ => reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            Err(UvSbomError::license_fetch(
+                package_name,
+                UvSbomError::http_status(status, format!("status code {}", status)),
+            ))
+        }
+    }
+
+    /// Fails only for `broken`; `bare` has metadata without a license
+    struct SelectivelyFailingLicenseRepository;
+
+    #[async_trait::async_trait]
+    impl LicenseRepository for SelectivelyFailingLicenseRepository {
+        async fn fetch_license_info(
+            &self,
+            package_name: &str,
+            _version: &str,
+        ) -> Result<PyPiMetadata> {
+            match package_name {
+                "broken" => Err(UvSbomError::other("connection reset")),
+                "bare" => Ok(PyPiMetadata {
+                    license: Some(String::new()),
+                    ..Default::default()
+                }),
+                _ => {
+                    MockLicenseRepository
+                        .fetch_license_info(package_name, "")
+                        .await
+                }
+            }
         }
     }
 
@@ -148,6 +229,14 @@ mod tests {
         Package::new(name.to_string(), version.to_string()).unwrap()
     }
 
+    fn failure(package: &str, kind: LicenseFetchErrorKind) -> LicenseFetchFailure {
+        LicenseFetchFailure {
+            package: package.to_string(),
+            kind,
+            error: "error".to_string(),
+        }
+    }
+
     // ========== summarize() tests ==========
 
     #[test]
@@ -163,7 +252,7 @@ mod tests {
     fn test_summarize_all_successful() {
         let pkg = make_package("requests", "2.31.0");
         let enriched = vec![EnrichedPackage::new(pkg, Some("MIT".to_string()), None)];
-        let errors: Vec<(String, String)> = vec![];
+        let errors: Vec<LicenseFetchFailure> = vec![];
 
         let (successful, total, failed) =
             FetchLicensesUseCase::<MockLicenseRepository>::summarize(&enriched, &errors);
@@ -180,7 +269,7 @@ mod tests {
             EnrichedPackage::new(pkg1, Some("MIT".to_string()), None),
             EnrichedPackage::new(pkg2, None, None),
         ];
-        let errors = vec![("urllib3".to_string(), "network error".to_string())];
+        let errors = vec![failure("urllib3", LicenseFetchErrorKind::Other)];
 
         let (successful, total, failed) =
             FetchLicensesUseCase::<MockLicenseRepository>::summarize(&enriched, &errors);
@@ -196,12 +285,22 @@ mod tests {
         let use_case = FetchLicensesUseCase::new(MockLicenseRepository);
         let packages = vec![make_package("requests", "2.31.0")];
 
-        let (enriched, errors) = use_case.fetch_with_progress(packages).await.unwrap();
+        let (enriched, errors) = use_case.fetch_with_progress(&packages).await.unwrap();
 
         assert_eq!(enriched.len(), 1);
         assert!(errors.is_empty());
         assert_eq!(enriched[0].license.as_deref(), Some("MIT"));
         assert_eq!(enriched[0].description.as_deref(), Some("A test package"));
+        assert_eq!(enriched[0].license_source, LicenseSource::PyPiLicenseField);
+        assert_eq!(enriched[0].author, None);
+        assert_eq!(enriched[0].supplier.as_deref(), Some("PSF"));
+        assert_eq!(
+            enriched[0].external_refs,
+            [(
+                ExternalRefType::Vcs,
+                "https://github.com/psf/requests".to_string()
+            )]
+        );
     }
 
     #[tokio::test]
@@ -209,20 +308,126 @@ mod tests {
         let use_case = FetchLicensesUseCase::new(FailingLicenseRepository);
         let packages = vec![make_package("requests", "2.31.0")];
 
-        let (enriched, errors) = use_case.fetch_with_progress(packages).await.unwrap();
+        let (enriched, errors) = use_case.fetch_with_progress(&packages).await.unwrap();
 
         assert_eq!(enriched.len(), 1);
         assert_eq!(errors.len(), 1);
         assert!(enriched[0].license.is_none());
-        assert_eq!(errors[0].0, "requests");
-        assert!(errors[0].1.contains("network error"));
+        assert_eq!(errors[0].package, "requests");
+        assert_eq!(errors[0].kind, LicenseFetchErrorKind::Other);
+        assert!(errors[0].error.contains("network error"));
+        assert_eq!(enriched[0].license_source, LicenseSource::Unknown);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_progress_classifies_failures() {
+        let use_case = FetchLicensesUseCase::new(ClassifyingLicenseRepository);
+        let packages = vec![
+            make_package("internal-lib", "1.0.0"),
+            make_package("busy", "1.0.0"),
+            make_package("broken", "1.0.0"),
+        ];
+
+        let (enriched, errors) = use_case.fetch_with_progress(&packages).await.unwrap();
+
+        let kinds: Vec<_> = errors.iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                LicenseFetchErrorKind::NotFound,
+                LicenseFetchErrorKind::RateLimited,
+                LicenseFetchErrorKind::Other
+            ]
+        );
+        // Only the package missing from the index is marked as not found
+        let sources: Vec<_> = enriched.iter().map(|p| p.license_source).collect();
+        assert_eq!(
+            sources,
+            [
+                LicenseSource::NotFound,
+                LicenseSource::Unknown,
+                LicenseSource::Unknown
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_progress_records_enrichment_status() {
+        let use_case = FetchLicensesUseCase::new(SelectivelyFailingLicenseRepository);
+        let packages = vec![
+            make_package("requests", "2.31.0"),
+            make_package("broken", "1.0.0"),
+            make_package("bare", "1.0.0"),
+        ];
+
+        let (enriched, errors) = use_case.fetch_with_progress(&packages).await.unwrap();
+
+        assert_eq!(enriched[0].enrichment_status, EnrichmentStatus::Enriched);
+        assert!(matches!(
+            &enriched[1].enrichment_status,
+            EnrichmentStatus::FetchFailed(error) if error.contains("connection reset")
+        ));
+        assert!(enriched[1].license.is_none());
+        // A successful lookup without license metadata is not a failure
+        assert_eq!(enriched[2].enrichment_status, EnrichmentStatus::NoMetadata);
+        assert!(enriched[2].license.is_none());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].package, "broken");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_progress_not_found_is_no_metadata() {
+        let use_case = FetchLicensesUseCase::new(ClassifyingLicenseRepository);
+        let packages = vec![make_package("internal-lib", "1.0.0")];
+
+        let (enriched, _) = use_case.fetch_with_progress(&packages).await.unwrap();
+
+        assert_eq!(enriched[0].enrichment_status, EnrichmentStatus::NoMetadata);
+    }
+
+    #[test]
+    fn test_count_by_kind() {
+        let errors = vec![
+            failure("a", LicenseFetchErrorKind::NotFound),
+            failure("b", LicenseFetchErrorKind::Timeout),
+            failure("c", LicenseFetchErrorKind::NotFound),
+        ];
+
+        let counts = FetchLicensesUseCase::<MockLicenseRepository>::count_by_kind(&errors);
+
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            [
+                (LicenseFetchErrorKind::NotFound, 2),
+                (LicenseFetchErrorKind::Timeout, 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_with_progress_keeps_lockfile_hash() {
+        let packages =
+            vec![make_package("requests", "2.31.0").with_sha256_hash(Some("abc".into()))];
+
+        let (enriched, _) = FetchLicensesUseCase::new(MockLicenseRepository)
+            .fetch_with_progress(&packages)
+            .await
+            .unwrap();
+        assert_eq!(enriched[0].sha256_hash.as_deref(), Some("abc"));
+
+        // The hash does not depend on PyPI being reachable
+        let (enriched, _) = FetchLicensesUseCase::new(FailingLicenseRepository)
+            .fetch_with_progress(&packages)
+            .await
+            .unwrap();
+        assert_eq!(enriched[0].sha256_hash.as_deref(), Some("abc"));
     }
 
     #[tokio::test]
     async fn test_fetch_with_progress_empty() {
         let use_case = FetchLicensesUseCase::new(MockLicenseRepository);
 
-        let (enriched, errors) = use_case.fetch_with_progress(vec![]).await.unwrap();
+        let (enriched, errors) = use_case.fetch_with_progress(&[]).await.unwrap();
 
         assert!(enriched.is_empty());
         assert!(errors.is_empty());
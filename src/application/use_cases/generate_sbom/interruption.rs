@@ -0,0 +1,100 @@
+use crate::ports::outbound::TaskHandle;
+use crate::shared::error::{GenerationPhase, UvSbomError};
+use crate::shared::Result;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+/// Deadline and cancellation token shared by the interruptible phases of one run
+pub(super) struct Interruption {
+    deadline: Option<(Instant, Duration)>,
+    cancel: CancellationToken,
+}
+
+impl Interruption {
+    /// Starts the deadline clock; `timeout` of `None` means no deadline
+    pub(super) fn start(timeout: Option<Duration>, cancel: CancellationToken) -> Self {
+        Self {
+            deadline: timeout.map(|timeout| (Instant::now() + timeout, timeout)),
+            cancel,
+        }
+    }
+
+    /// Drives `future` until it completes, the deadline passes or the run is cancelled
+    ///
+    /// An interrupted future is dropped, and the error reports how far `progress` got.
+    pub(super) async fn run<T>(
+        &self,
+        phase: GenerationPhase,
+        progress: &CountingTask,
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let deadline = async {
+            match self.deadline {
+                Some((at, timeout)) => {
+                    tokio::time::sleep_until(at).await;
+                    timeout
+                }
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            // A phase that finishes right at the deadline still counts as finished
+            biased;
+            result = future => result,
+            _ = self.cancel.cancelled() => Err(UvSbomError::Cancelled {
+                phase,
+                processed: progress.processed(),
+                total: progress.total(),
+            }),
+            timeout = deadline => Err(UvSbomError::TimedOut {
+                phase,
+                processed: progress.processed(),
+                total: progress.total(),
+                timeout,
+            }),
+        }
+    }
+}
+
+/// TaskHandle that remembers the last progress update before forwarding it
+pub(super) struct CountingTask {
+    inner: Arc<dyn TaskHandle>,
+    processed: AtomicUsize,
+    total: AtomicUsize,
+}
+
+impl CountingTask {
+    /// Wraps `inner`, starting from 0 of `total` items
+    pub(super) fn new(inner: Arc<dyn TaskHandle>, total: usize) -> Self {
+        Self {
+            inner,
+            processed: AtomicUsize::new(0),
+            total: AtomicUsize::new(total),
+        }
+    }
+
+    fn processed(&self) -> usize {
+        self.processed.load(Ordering::Relaxed)
+    }
+
+    fn total(&self) -> usize {
+        self.total.load(Ordering::Relaxed)
+    }
+}
+
+impl TaskHandle for CountingTask {
+    fn update(&self, current: usize, total: usize) {
+        self.processed.store(current, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+        self.inner.update(current, total);
+    }
+
+    fn finish(&self) {
+        self.inner.finish();
+    }
+}
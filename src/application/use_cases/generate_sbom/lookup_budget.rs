@@ -0,0 +1,65 @@
+/// Package lookups left for the license and vulnerability phases of one run
+///
+/// Every package looked up on the index or queried on OSV takes one lookup,
+/// whether or not the answer comes from a cache. Once the budget is spent the
+/// remaining packages are not looked up, and their number is kept for the
+/// completion message.
+pub(super) struct LookupBudget {
+    limit: Option<usize>,
+    used: usize,
+    skipped: usize,
+}
+
+impl LookupBudget {
+    /// Creates a budget of `limit` lookups; `None` means no limit
+    pub(super) fn new(limit: Option<usize>) -> Self {
+        Self {
+            limit,
+            used: 0,
+            skipped: 0,
+        }
+    }
+
+    /// Takes up to `wanted` lookups and returns how many may be made
+    pub(super) fn take(&mut self, wanted: usize) -> usize {
+        let granted = match self.limit {
+            Some(limit) => wanted.min(limit.saturating_sub(self.used)),
+            None => wanted,
+        };
+        self.used += granted;
+        self.skipped += wanted - granted;
+        granted
+    }
+
+    pub(super) fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Returns the number of lookups refused so far
+    pub(super) fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_grants_until_the_limit_and_counts_the_rest() {
+        let mut budget = LookupBudget::new(Some(5));
+
+        assert_eq!(budget.take(3), 3);
+        assert_eq!(budget.take(4), 2);
+        assert_eq!(budget.take(2), 0);
+        assert_eq!(budget.skipped(), 4);
+    }
+
+    #[test]
+    fn test_unlimited_budget_grants_everything() {
+        let mut budget = LookupBudget::new(None);
+
+        assert_eq!(budget.take(1000), 1000);
+        assert_eq!(budget.skipped(), 0);
+    }
+}
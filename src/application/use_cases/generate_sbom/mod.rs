@@ -1,33 +1,88 @@
+mod interruption;
+mod lookup_budget;
+mod observation;
+
 use crate::adapters::outbound::uv::UvLockAdapter;
-use crate::application::dto::{SbomRequest, SbomResponse};
+use crate::application::dto::{ExcludeScope, LicensesFor, SbomRequest, SbomResponse};
 use crate::application::read_models::abandoned_package::{
     AbandonedPackageView, AbandonedPackagesReport,
 };
+use crate::application::read_models::{
+    DependencyCounts, FetchFailureCategory, GenerationStats, LicenseCounts, SbomWarning,
+    VulnerabilityCountsBySeverity,
+};
 use crate::application::use_cases::{
     CheckAbandonedPackagesUseCase, CheckVulnerabilitiesUseCase, FetchLicensesUseCase,
+    LicenseFetchFailure,
 };
 use crate::i18n::{Locale, Messages};
+use crate::ports::inbound::{
+    NoopSbomGenerationObserver, SbomGenerationEvent, SbomGenerationObserver, SbomPhase,
+};
 use crate::ports::outbound::{
-    EnrichedPackage, LicenseRepository, LockfileReader, MaintenanceRepository, ProgressReporter,
-    ProjectConfigReader, VulnerabilityRepository,
+    DependencyGroups, EdgeExtras, EdgeMarkers, EnrichedPackage, EnrichmentStatus,
+    IncompleteAdvisory, LicenseFetchFields, LicenseRepository, LockfileParseResult, LockfileReader,
+    LockfileVersion, MaintenanceRepository, ProgressPhase, ProgressReporter, ProjectConfigReader,
+    TaskHandle, UncheckedPackage, VulnerabilityRepository,
 };
 use crate::sbom_generation::domain::license_policy::LicenseComplianceResult;
 use crate::sbom_generation::domain::services::{
-    LicenseComplianceChecker, ResolutionAnalyzer, ThresholdConfig, UpgradeAdvisor,
-    VulnerabilityCheckResult, VulnerabilityChecker,
+    ConfigurationValidator, DeclaredConstraintMatcher, FixSuggester, IgnoredVulnerability,
+    LicenseComplianceChecker, LicenseReachabilityAnalyzer, LockfileConsistencyChecker,
+    PythonCompatibilityChecker, ResolutionAnalyzer, ThresholdConfig, TyposquatChecker,
+    UpgradeAdvisor, ValidationIssue, ValidationOutcome, ValidationReport, VulnerabilityCheckResult,
+    VulnerabilityChecker,
 };
 use crate::sbom_generation::domain::{
-    DependencyGraph, Package, PackageName, UpgradeRecommendation,
+    DependencyGraph, FixSuggestion, InvocationParameters, LicenseOverride, LicenseRiskReport,
+    LicenseSource, Package, PackageAnnotation, PackageName, PythonCompatibilityReport,
+    SbomMetadata, TyposquatFinding, UpgradeRecommendation,
+};
+use crate::sbom_generation::policies::{
+    Compatibility, LicenseCompatibilityChecker, LicenseCompatibilityResult,
 };
-use crate::sbom_generation::services::{DependencyAnalyzer, PackageFilter, SbomGenerator};
+use crate::sbom_generation::services::{
+    DependencyAnalyzer, DependencyGroupFilter, PackageFilter, PlatformFilter, SbomGenerator,
+};
+use crate::shared::error::{GenerationPhase, LicenseFetchErrorKind, UvSbomError};
 use crate::shared::Result;
 use chrono::Utc;
+use interruption::{CountingTask, Interruption};
+use lookup_budget::LookupBudget;
+use observation::PhaseTracker;
 use std::cmp::Reverse;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio_util::sync::CancellationToken;
 
 /// Type alias for package list with dependency map
 /// Used to simplify complex return types and satisfy clippy::type_complexity
-type PackagesWithDependencyMap = (Vec<Package>, std::collections::HashMap<String, Vec<String>>);
+type PackagesWithDependencyMap = (Vec<Package>, HashMap<String, Vec<String>>);
+
+/// Packages and dependency edges read from the lockfile, with its format details
+struct LockfileContents {
+    packages: Vec<Package>,
+    dependency_map: HashMap<String, Vec<String>>,
+    /// Extras requested on the dependency edges
+    edge_extras: EdgeExtras,
+    /// Format version as written in the lockfile
+    version: Option<String>,
+    requires_python: Option<String>,
+}
+
+/// How the license of a package is resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LicenseLookup {
+    /// Read from the package's own pyproject.toml (local path or virtual source)
+    Local,
+    /// Looked up on the package index
+    Index,
+    /// Not looked up: outside the packages `licenses_for` selects
+    NotRequested,
+    /// Not looked up: the lookup budget is spent
+    OverBudget,
+}
 
 /// GenerateSbomUseCase - Core use case for SBOM generation
 ///
@@ -49,6 +104,7 @@ pub struct GenerateSbomUseCase<LR, PCR, LREPO, PR, VREPO, MREPO> {
     vulnerability_repository: Option<VREPO>,
     maintenance_repository: Option<MREPO>,
     locale: Locale,
+    observer: Arc<dyn SbomGenerationObserver>,
 }
 
 impl<LR, PCR, LREPO, PR, VREPO, MREPO> GenerateSbomUseCase<LR, PCR, LREPO, PR, VREPO, MREPO>
@@ -78,9 +134,20 @@ where
             vulnerability_repository,
             maintenance_repository,
             locale,
+            observer: Arc::new(NoopSbomGenerationObserver),
         }
     }
 
+    /// Reports the phases of every run to `observer`
+    ///
+    /// Without an observer, phase events are discarded.
+    // Used only through the library; the binary does not observe phases
+    #[allow(dead_code)]
+    pub fn with_observer(mut self, observer: Arc<dyn SbomGenerationObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
     /// Executes the SBOM generation use case
     ///
     /// # Arguments
@@ -89,43 +156,306 @@ where
     /// # Returns
     /// SbomResponse containing enriched packages, optional dependency graph, and metadata
     pub async fn execute(&self, request: SbomRequest) -> Result<SbomResponse> {
-        // Step 1: Read and parse lockfile
-        let (packages, dependency_map) = self.read_and_report_lockfile(&request)?;
+        self.execute_with_cancel(request, CancellationToken::new())
+            .await
+    }
+
+    /// Executes the SBOM generation use case until it finishes or `cancel` is cancelled
+    ///
+    /// Cancellation and `request.timeout` interrupt the license enrichment and
+    /// vulnerability check phases, whose progress displays are removed before returning.
+    ///
+    /// # Errors
+    /// [`UvSbomError::Cancelled`] or [`UvSbomError::TimedOut`] naming the interrupted
+    /// phase and how far it got
+    pub async fn execute_with_cancel(
+        &self,
+        request: SbomRequest,
+        cancel: CancellationToken,
+    ) -> Result<SbomResponse> {
+        let tracker = PhaseTracker::new(self.observer.as_ref());
+        let result = self.run(request, cancel, &tracker).await;
+        if let Err(error) = &result {
+            tracker.fail(error);
+        }
+        result
+    }
+
+    /// Runs the generation steps, reporting each phase to `tracker`
+    ///
+    /// Every fallible step runs inside a phase, so an error always closes one.
+    async fn run(
+        &self,
+        request: SbomRequest,
+        cancel: CancellationToken,
+        tracker: &PhaseTracker<'_>,
+    ) -> Result<SbomResponse> {
+        let interruption = Interruption::start(request.timeout, cancel);
+        let mut lookup_budget = LookupBudget::new(request.max_network_requests);
+        let mut warnings = Vec::new();
+
+        // Step 1: Read and parse lockfile, pruning packages outside the selected dependency groups
+        tracker.start(SbomPhase::ReadLockfile);
+        let parse_started = Instant::now();
+        let LockfileContents {
+            packages,
+            dependency_map,
+            edge_extras,
+            version: lockfile_version,
+            requires_python: lockfile_requires_python,
+        } = self.read_and_report_lockfile(&request, &mut warnings)?;
+        let parse_time = parse_started.elapsed();
+        // The lockfile's own record of the project, used when pyproject.toml is missing
+        let lockfile_root = packages
+            .iter()
+            .find(|p| p.is_project_root())
+            .map(|p| p.name().to_string());
+        // Packages given with --roots, validated against the whole lockfile
+        let explicit_roots = self.resolve_explicit_roots(&request, &packages)?;
+
+        // Step 1b: Read package annotations from pyproject.toml
+        let package_annotations =
+            self.read_package_annotations(&request, &packages, &mut warnings)?;
+
+        // Step 1c: Warn about license overrides that match no package in the lockfile
+        self.warn_unmatched_license_overrides(&request, &packages, &mut warnings);
+
+        // Step 1d: Record the requirements pyproject.toml declares for the direct dependencies
+        let packages = self.attach_declared_constraints(&request, packages);
+        let package_count = packages.len();
+        tracker.finish(|duration| SbomGenerationEvent::LockfileRead {
+            package_count,
+            duration,
+        });
 
         // Step 2: Apply exclusion filters to packages only
         // Note: We pass dependency_map by reference to preserve it for dependency analysis.
         // The root project may be excluded from packages but we still need its entry
         // in dependency_map to correctly identify direct vs transitive dependencies.
-        let filtered_packages = self.apply_exclusion_filters(packages, &request)?;
+        // With exclude_scope: output the vulnerability check also covers the excluded
+        // packages, so its query is taken before filtering.
+        tracker.start(SbomPhase::FilterPackages);
+        let unfiltered_query = (request.exclude_scope == ExcludeScope::Output)
+            .then(|| self.build_vulnerability_query(&request, &packages));
+        let filtered_packages =
+            self.apply_exclusion_filters(packages, &dependency_map, &request, &mut warnings)?;
+
+        // Step 2b: Point out packages the lockfile holds at more than one version
+        self.report_version_conflicts(&filtered_packages);
+        tracker.finish(|duration| SbomGenerationEvent::PackagesFiltered {
+            package_count: filtered_packages.len(),
+            excluded_count: package_count - filtered_packages.len(),
+            duration,
+        });
 
         // Early return for dry-run mode (validation only)
         if request.dry_run {
-            return self.build_dry_run_response();
+            let stats = request.stats.then(|| GenerationStats {
+                total_packages: filtered_packages.len(),
+                parse_time,
+                ..Default::default()
+            });
+            tracker.start(SbomPhase::ValidateConfiguration);
+            let validation_report = self.validate_configuration(&request).await;
+            tracker.finish(|duration| SbomGenerationEvent::ConfigurationValidated {
+                issue_count: validation_report.issues.len(),
+                duration,
+            });
+            tracker.start(SbomPhase::BuildResponse);
+            let warning_count = warnings.len();
+            let response = self.build_dry_run_response(warnings, stats, validation_report)?;
+            tracker.finish(|duration| SbomGenerationEvent::ResponseBuilt {
+                package_count: filtered_packages.len(),
+                warning_count,
+                duration,
+            });
+            return Ok(response);
         }
 
+        // Step 2c: A lockfile holding nothing but the project has no dependencies to
+        // look up; the project itself is described by the metadata component
+        let has_third_party = has_third_party_packages(&filtered_packages);
+        let filtered_packages = if has_third_party {
+            filtered_packages
+        } else {
+            let msgs = Messages::for_locale(self.locale);
+            self.progress_reporter
+                .report(msgs.progress_no_third_party_packages);
+            Vec::new()
+        };
+
         // Step 3: Analyze dependencies if requested
         // Use original dependency_map to preserve dependency classification even when
         // root project is excluded from the package list (fixes #206)
-        let dependency_graph = self.analyze_dependencies_if_requested(&request, &dependency_map)?;
+        if request.include_dependency_info {
+            tracker.start(SbomPhase::AnalyzeDependencies);
+        }
+        let dependency_graph = match &explicit_roots {
+            _ if request.include_dependency_info
+                && !self.lockfile_reader.has_dependency_graph() =>
+            {
+                let msgs = Messages::for_locale(self.locale);
+                self.warn(
+                    &mut warnings,
+                    msgs.warn_dependency_graph_unavailable,
+                    SbomWarning::DependencyGraphUnavailable,
+                );
+                None
+            }
+            Some(roots) => Some(self.analyze_explicit_roots(
+                roots,
+                &filtered_packages,
+                &dependency_map,
+                &edge_extras,
+            )?),
+            None => self.analyze_dependencies_if_requested(
+                &request,
+                &dependency_map,
+                &edge_extras,
+                lockfile_root.as_deref(),
+                &mut warnings,
+            )?,
+        };
+        tracker.finish(|duration| SbomGenerationEvent::DependenciesAnalyzed {
+            direct_count: dependency_graph
+                .as_ref()
+                .map_or(0, |graph| graph.direct_dependency_count()),
+            transitive_count: dependency_graph
+                .as_ref()
+                .map_or(0, |graph| graph.transitive_dependency_count()),
+            duration,
+        });
 
         // Step 4: Enrich packages with license information
-        let enriched_packages = self.fetch_license_info(filtered_packages.clone()).await?;
+        if has_third_party {
+            tracker.start(SbomPhase::EnrichLicenses);
+        }
+        let license_started = Instant::now();
+        let (mut enriched_packages, license_fetch_time) = if !has_third_party {
+            (Vec::new(), None)
+        } else if request.offline {
+            let enriched = self.read_local_license_info(&request, &filtered_packages);
+            (enriched, None)
+        } else {
+            self.report_local_sources(&filtered_packages);
+            let license_targets = self.select_license_targets(
+                &request,
+                &filtered_packages,
+                dependency_graph.as_ref(),
+                &dependency_map,
+                lockfile_root.as_deref(),
+            );
+            let mut enriched = self
+                .fetch_license_info(
+                    &request,
+                    &filtered_packages,
+                    license_targets.as_ref(),
+                    &mut lookup_budget,
+                    &interruption,
+                    &mut warnings,
+                )
+                .await?;
+            if request.license_fetch_fields == LicenseFetchFields::LicenseOnly {
+                // Installed metadata still carries summaries; drop them so every package matches
+                for package in &mut enriched {
+                    package.description = None;
+                }
+            }
+            (enriched, Some(license_started.elapsed()))
+        };
+
+        // Step 4b: Replace enriched licenses with the overrides from the config file
+        let license_overrides = self.apply_license_overrides(&request, &mut enriched_packages);
+        tracker.finish(|duration| SbomGenerationEvent::LicensesEnriched {
+            package_count: enriched_packages.len(),
+            resolved_count: enriched_packages
+                .iter()
+                .filter(|p| p.license.is_some())
+                .count(),
+            duration,
+        });
 
-        // Step 5: CVE check if requested
-        let vulnerability_report = self
-            .check_vulnerabilities_if_requested(&request, &filtered_packages)
+        // Step 5: CVE check if requested; packages beyond the lookup budget are not queried
+        let (self_package, vulnerability_query) = unfiltered_query
+            .unwrap_or_else(|| self.build_vulnerability_query(&request, &filtered_packages));
+        let vulnerability_query = if has_third_party {
+            self.limit_vulnerability_query(vulnerability_query, &mut lookup_budget, &mut warnings)
+        } else {
+            vulnerability_query
+        };
+        if has_third_party && request.check_cve && self.vulnerability_repository.is_some() {
+            tracker.start(SbomPhase::CheckVulnerabilities);
+        }
+        let cve_started = Instant::now();
+        let vulnerability_report = if has_third_party {
+            self.check_vulnerabilities_if_requested(
+                &request,
+                &vulnerability_query,
+                &interruption,
+                &mut warnings,
+            )
+            .await?
+        } else {
+            None
+        };
+        let project_self_check_result = self
+            .check_project_self_if_requested(&request, self_package, &mut warnings)
             .await?;
+        let cve_check_time = vulnerability_report
+            .is_some()
+            .then(|| cve_started.elapsed());
+        self.report_lookup_budget(&lookup_budget);
+        tracker.finish(|duration| SbomGenerationEvent::VulnerabilitiesChecked {
+            package_count: vulnerability_query.len(),
+            vulnerable_package_count: vulnerability_report.as_ref().map_or(0, |report| {
+                report
+                    .iter()
+                    .filter(|p| !p.vulnerabilities().is_empty())
+                    .count()
+            }),
+            duration,
+        });
 
         // Step 6: Apply threshold evaluation if vulnerabilities were found
+        tracker.start(SbomPhase::EvaluatePolicies);
         let vulnerability_check_result = vulnerability_report.as_ref().map(|report| {
             let threshold_config = Self::build_threshold_config(&request);
-            VulnerabilityChecker::check(report.clone(), threshold_config, &request.ignore_cves)
+            let mut result = VulnerabilityChecker::check_against_baseline(
+                report.clone(),
+                threshold_config,
+                &request.ignore_cves,
+                &request.cve_baseline,
+            );
+            self.report_ignored_cves(&result.ignored);
+            // Ignored CVEs are only reported when VEX output was requested
+            if !request.vex {
+                result.ignored.clear();
+            }
+            result
         });
 
         // Step 7: License compliance check if requested
         let license_compliance_result =
             self.check_license_compliance_if_requested(&request, &enriched_packages);
 
+        // Step 7b: License compatibility check against the project license if requested
+        let license_compatibility_result =
+            self.check_license_compatibility_if_requested(&request, &enriched_packages)?;
+
+        // Step 7c: Copyleft packages reachable through the dependency graph if requested
+        let license_risk_report = self.check_license_reachability_if_requested(
+            &request,
+            &enriched_packages,
+            dependency_graph.as_ref(),
+        );
+
+        // Step 7d: Python version compatibility with the project if requested
+        let python_compatibility_report =
+            self.check_python_compat_if_requested(&request, &enriched_packages)?;
+
+        // Step 7e: Package names resembling popular packages if requested
+        let typosquat_findings = self.check_typosquats_if_requested(&request, &enriched_packages);
+
         // Step 8: Upgrade advisor if requested
         let upgrade_recommendations = self
             .advise_upgrades_if_requested(
@@ -133,23 +463,154 @@ where
                 dependency_graph.as_ref(),
                 vulnerability_report.as_deref(),
                 &enriched_packages,
+                &mut warnings,
             )
             .await;
 
+        // Step 8b: Fix suggestions from the OSV fixed versions if requested
+        let fix_suggestions = Self::suggest_fixes_if_requested(
+            &request,
+            vulnerability_check_result.as_ref(),
+            dependency_graph.as_ref(),
+        );
+
+        tracker.finish(|duration| SbomGenerationEvent::PoliciesEvaluated { duration });
+
         // Step 9: Abandoned package check if requested
-        let abandoned_packages_report = self
-            .check_abandoned_if_requested(&request, &filtered_packages, dependency_graph.as_ref())
-            .await?;
+        if has_third_party && request.check_abandoned && self.maintenance_repository.is_some() {
+            tracker.start(SbomPhase::CheckAbandoned);
+        }
+        let abandoned_packages_report = if has_third_party {
+            self.check_abandoned_if_requested(
+                &request,
+                &filtered_packages,
+                dependency_graph.as_ref(),
+                &mut warnings,
+            )
+            .await?
+        } else {
+            None
+        };
+        tracker.finish(|duration| SbomGenerationEvent::AbandonedChecked {
+            package_count: filtered_packages.len(),
+            abandoned_count: abandoned_packages_report
+                .as_ref()
+                .map_or(0, |report| report.packages.len()),
+            duration,
+        });
 
         // Step 10: Build and return response
-        Ok(self.build_response(
+        tracker.start(SbomPhase::BuildResponse);
+        let mut response = Self::build_response(
+            &request,
             enriched_packages,
             dependency_graph,
             vulnerability_check_result,
             license_compliance_result,
             upgrade_recommendations,
             abandoned_packages_report,
-        ))
+        );
+        if let Some(result) = project_self_check_result {
+            if request.self_check_fails_build && result.threshold_exceeded {
+                response.has_vulnerabilities_above_threshold = true;
+            }
+            response.project_self_check_result = Some(result);
+        }
+        response.fix_suggestions = fix_suggestions;
+        if let Some(result) = license_compatibility_result {
+            response.has_license_incompatibilities =
+                request.fail_on_license_incompatibility && result.has_incompatibilities();
+            response.license_compatibility_result = Some(result);
+        }
+        response.license_risk_report = license_risk_report;
+        response.python_compatibility_report = python_compatibility_report;
+        if let Some(findings) = typosquat_findings {
+            response.has_typosquats = request.fail_on_typosquat && !findings.is_empty();
+            response.typosquat_findings = Some(findings);
+        }
+        if request.reproducible {
+            let invocation = response.metadata.invocation().cloned();
+            response.metadata = self
+                .generate_reproducible_metadata(&request, &response.enriched_packages)?
+                .with_invocation(invocation);
+        }
+        response.metadata = response
+            .metadata
+            .with_lockfile_format(lockfile_version, lockfile_requires_python);
+        if request.lockfile_digest {
+            response.lockfile_sha256 = Some(
+                self.lockfile_reader
+                    .lockfile_sha256(&request.project_path)?,
+            );
+        }
+        response.package_annotations = package_annotations;
+        response.license_overrides = license_overrides;
+        response.vulnerability_check_incomplete = warnings
+            .iter()
+            .any(|w| matches!(w, SbomWarning::VulnerabilityCheckFailed { .. }));
+        response.warnings = warnings;
+        if request.stats {
+            let stats = GenerationStats {
+                total_packages: response.enriched_packages.len(),
+                dependencies: response
+                    .dependency_graph
+                    .as_ref()
+                    .map(DependencyCounts::of_graph),
+                licenses: Some(LicenseCounts::of_packages(&response.enriched_packages)),
+                vulnerabilities: response
+                    .vulnerability_check_result
+                    .as_ref()
+                    .map(VulnerabilityCountsBySeverity::of_check_result),
+                parse_time,
+                license_fetch_time,
+                cve_check_time,
+            };
+            self.report_stats(&stats);
+            response.stats = Some(stats);
+        }
+        tracker.finish(|duration| SbomGenerationEvent::ResponseBuilt {
+            package_count: response.enriched_packages.len(),
+            warning_count: response.warnings.len(),
+            duration,
+        });
+        self.progress_reporter
+            .report_done(response.enriched_packages.len(), response.warnings.len());
+        Ok(response)
+    }
+
+    /// Reports the generation statistics as a block on stderr
+    fn report_stats(&self, stats: &GenerationStats) {
+        let msgs = Messages::for_locale(self.locale);
+        let mut block = msgs.label_stats_title.to_string();
+        for (label, value) in stats.rows(msgs) {
+            block.push_str(&format!("\n  {}: {}", label, value));
+        }
+        self.progress_reporter.report_completion(&block);
+    }
+
+    /// Reports a warning for live display and records it on the response.
+    fn warn(&self, warnings: &mut Vec<SbomWarning>, live_message: &str, warning: SbomWarning) {
+        self.progress_reporter.report_error(live_message);
+        warnings.push(warning);
+    }
+
+    /// Reports each CVE dropped by the `ignore_cves` list, for transparency
+    fn report_ignored_cves(&self, ignored: &[IgnoredVulnerability]) {
+        let msgs = Messages::for_locale(self.locale);
+        for entry in ignored {
+            let id = entry.vulnerability.id();
+            let message = match &entry.reason {
+                Some(reason) => Messages::format(
+                    msgs.progress_cve_ignored,
+                    &[id, &entry.package_name, reason],
+                ),
+                None => Messages::format(
+                    msgs.progress_cve_ignored_no_reason,
+                    &[id, &entry.package_name],
+                ),
+            };
+            self.progress_reporter.report_error(&message);
+        }
     }
 
     /// Checks for abandoned packages if `check_abandoned` is enabled.
@@ -163,6 +624,7 @@ where
         request: &SbomRequest,
         packages: &[Package],
         dependency_graph: Option<&DependencyGraph>,
+        warnings: &mut Vec<SbomWarning>,
     ) -> Result<Option<AbandonedPackagesReport>> {
         if !request.check_abandoned {
             return Ok(None);
@@ -175,18 +637,24 @@ where
         self.progress_reporter
             .report(msgs.progress_fetching_abandoned);
 
-        let maint_use_case = CheckAbandonedPackagesUseCase::new(repo.clone());
-        let (results, errors) = maint_use_case
-            .fetch_with_progress(packages.to_vec())
-            .await?;
-
-        eprintln!(); // newline after progress bar
-
-        for (pkg_name, error_msg) in &errors {
-            self.progress_reporter.report_error(&Messages::format(
-                msgs.warn_abandoned_fetch_failed,
-                &[pkg_name, error_msg],
-            ));
+        let task = self
+            .progress_reporter
+            .start_task(ProgressPhase::AbandonedCheck, packages.len());
+        let maint_use_case =
+            CheckAbandonedPackagesUseCase::new(repo.clone()).with_progress(task.clone());
+        let fetched = maint_use_case.fetch_with_progress(packages).await;
+        task.finish();
+        let (results, errors) = fetched?;
+
+        for (pkg_name, error_msg) in errors {
+            self.warn(
+                warnings,
+                &Messages::format(msgs.warn_abandoned_fetch_failed, &[&pkg_name, &error_msg]),
+                SbomWarning::MaintenanceFetchFailed {
+                    package: pkg_name,
+                    error: error_msg,
+                },
+            );
         }
 
         let today = Utc::now().date_naive();
@@ -246,29 +714,388 @@ where
     /// Reads and parses the lockfile, reporting progress
     ///
     /// # Arguments
-    /// * `request` - The SBOM request containing project path
+    /// * `request` - The SBOM request containing project path and dependency group selection
     ///
     /// # Returns
-    /// Tuple of (packages, dependency_map)
-    fn read_and_report_lockfile(&self, request: &SbomRequest) -> Result<PackagesWithDependencyMap> {
+    /// The lockfile contents, with the edges of the selected dependency groups merged
+    /// into the dependency map and the packages outside the target environment pruned
+    fn read_and_report_lockfile(
+        &self,
+        request: &SbomRequest,
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Result<LockfileContents> {
         let msgs = Messages::for_locale(self.locale);
         self.progress_reporter.report(&Messages::format(
             msgs.progress_loading_lockfile,
             &[&request.project_path.display().to_string()],
         ));
 
-        let (packages, dependency_map) = self
+        let LockfileParseResult {
+            packages,
+            dependency_map,
+            dependency_groups,
+            edge_extras,
+            edge_markers,
+            version,
+            requires_python,
+            skipped_lines,
+        } = self
             .lockfile_reader
             .read_and_parse_lockfile(&request.project_path)?;
+        self.check_lockfile_version(request, version.as_ref(), warnings)?;
+        for skipped in skipped_lines {
+            let line = skipped.line.to_string();
+            self.warn(
+                warnings,
+                &Messages::format(msgs.warn_lockfile_line_skipped, &[&line, &skipped.content]),
+                SbomWarning::LockfileLineSkipped {
+                    line: skipped.line,
+                    content: skipped.content,
+                },
+            );
+        }
 
         self.progress_reporter.report(&Messages::format(
             msgs.progress_detected_packages,
             &[&packages.len().to_string()],
         ));
 
+        self.check_lockfile_consistency(
+            request,
+            &packages,
+            &dependency_map,
+            &dependency_groups,
+            warnings,
+        )?;
+
+        let (packages, dependency_map) =
+            self.select_dependency_groups(request, packages, dependency_map, &dependency_groups)?;
+        let (packages, dependency_map) =
+            self.select_target_platform(request, packages, dependency_map, &edge_markers)?;
+        Ok(LockfileContents {
+            packages,
+            dependency_map,
+            edge_extras,
+            version: version.map(|v| v.version),
+            requires_python,
+        })
+    }
+
+    /// Applies the lockfile version policy
+    ///
+    /// A format newer than the reader supports has been parsed as far as possible;
+    /// that is a warning, or an `UnsupportedLockfileVersion` error with
+    /// `strict_lock_version`.
+    fn check_lockfile_version(
+        &self,
+        request: &SbomRequest,
+        version: Option<&LockfileVersion>,
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Result<()> {
+        let Some(version) = version.filter(|v| v.is_newer_than_supported()) else {
+            return Ok(());
+        };
+        if request.strict_lock_version {
+            return Err(UvSbomError::UnsupportedLockfileVersion {
+                version: version.version.clone(),
+                supported: version.supported_major,
+            });
+        }
+        let msgs = Messages::for_locale(self.locale);
+        let supported = version.supported_major.to_string();
+        self.warn(
+            warnings,
+            &Messages::format(
+                msgs.warn_lockfile_version_newer,
+                &[&version.version, &supported],
+            ),
+            SbomWarning::NewerLockfileVersion {
+                version: version.version.clone(),
+                supported: version.supported_major,
+            },
+        );
+        Ok(())
+    }
+
+    /// Compares the lockfile with the requirements declared in pyproject.toml
+    ///
+    /// Each mismatch is a warning, or a `StaleLockfile` error with `strict_lock`.
+    /// Projects without a readable `[project]` table are not checked.
+    fn check_lockfile_consistency(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+        dependency_map: &HashMap<String, Vec<String>>,
+        dependency_groups: &DependencyGroups,
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Result<()> {
+        if !request.check_lock {
+            return Ok(());
+        }
+        let Some(requirements) = self
+            .project_config_reader
+            .read_declared_requirements(&request.project_path)?
+        else {
+            return Ok(());
+        };
+        let Ok(project_name) = self.read_project_name(request) else {
+            return Ok(());
+        };
+
+        let project = PackageName::normalize(&project_name);
+        let is_project = |name: &String| PackageName::normalize(name) == project;
+        let locked_direct: Vec<&str> = dependency_map
+            .iter()
+            .chain(dependency_groups.values().flatten())
+            .filter(|(name, _)| is_project(name))
+            .flat_map(|(_, deps)| deps.iter().map(String::as_str))
+            .collect();
+        let locked_packages: Vec<&str> = packages.iter().map(|p| p.name()).collect();
+
+        let consistency = LockfileConsistencyChecker::check(
+            &project_name,
+            &requirements,
+            &locked_packages,
+            &locked_direct,
+        );
+        if consistency.is_consistent() {
+            return Ok(());
+        }
+        if request.strict_lock {
+            return Err(UvSbomError::StaleLockfile {
+                unlocked: consistency.unlocked,
+                stale: consistency.stale,
+            });
+        }
+
+        let msgs = Messages::for_locale(self.locale);
+        for package in consistency.unlocked {
+            self.warn(
+                warnings,
+                &Messages::format(msgs.warn_unlocked_dependency, &[&package]),
+                SbomWarning::UnlockedDependency { package },
+            );
+        }
+        for package in consistency.stale {
+            self.warn(
+                warnings,
+                &Messages::format(msgs.warn_stale_locked_dependency, &[&package]),
+                SbomWarning::StaleLockedDependency { package },
+            );
+        }
+        Ok(())
+    }
+
+    /// Merges the selected dependency groups into the dependency map and prunes
+    /// packages that only deselected groups need
+    ///
+    /// Without a group selection every group is merged and nothing is pruned, so
+    /// the project roots are only resolved when a selection was requested.
+    fn select_dependency_groups(
+        &self,
+        request: &SbomRequest,
+        packages: Vec<Package>,
+        dependency_map: HashMap<String, Vec<String>>,
+        dependency_groups: &DependencyGroups,
+    ) -> Result<PackagesWithDependencyMap> {
+        let filter =
+            DependencyGroupFilter::new(request.only_groups.clone(), request.exclude_groups.clone());
+        if !filter.is_active() {
+            return Ok(filter.apply(&[], packages, dependency_map, dependency_groups));
+        }
+
+        let msgs = Messages::for_locale(self.locale);
+        for group in filter.unknown_groups(dependency_groups) {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_unknown_dependency_group,
+                &[group],
+            ));
+        }
+
+        let roots = self.resolve_dependency_roots(request)?;
+        let original_count = packages.len();
+        let (packages, dependency_map) =
+            filter.apply(&roots, packages, dependency_map, dependency_groups);
+
+        let pruned_count = original_count - packages.len();
+        if pruned_count > 0 {
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_groups_pruned,
+                &[&pruned_count.to_string()],
+            ));
+        }
+
+        Ok((packages, dependency_map))
+    }
+
+    /// Prunes the packages that cannot be installed in the requested environment
+    ///
+    /// Like the group selection, the project roots are only resolved when a
+    /// target environment was requested.
+    fn select_target_platform(
+        &self,
+        request: &SbomRequest,
+        packages: Vec<Package>,
+        dependency_map: HashMap<String, Vec<String>>,
+        edge_markers: &EdgeMarkers,
+    ) -> Result<PackagesWithDependencyMap> {
+        let filter = PlatformFilter::new(request.marker_environment.clone());
+        if !filter.is_active() {
+            return Ok((packages, dependency_map));
+        }
+
+        // Without dependency edges each package is judged by its own marker alone
+        let roots = if self.lockfile_reader.has_dependency_graph() {
+            self.resolve_dependency_roots(request)?
+        } else {
+            Vec::new()
+        };
+        let original_count = packages.len();
+        let (packages, dependency_map) =
+            filter.apply(&roots, packages, dependency_map, edge_markers);
+
+        let pruned_count = original_count - packages.len();
+        if pruned_count > 0 {
+            let msgs = Messages::for_locale(self.locale);
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_platform_pruned,
+                &[
+                    &pruned_count.to_string(),
+                    &request.marker_environment.to_string(),
+                ],
+            ));
+        }
+
         Ok((packages, dependency_map))
     }
 
+    /// Reads `[tool.uv-sbom.annotations]` and warns about entries for packages
+    /// that are not in the lockfile
+    ///
+    /// Package names are compared after PEP 503 normalization. Orphaned
+    /// annotations are still returned; they simply match no component.
+    fn read_package_annotations(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Result<Vec<PackageAnnotation>> {
+        let annotations = self
+            .project_config_reader
+            .read_package_annotations(&request.project_path)?;
+
+        let msgs = Messages::for_locale(self.locale);
+        for annotation in &annotations {
+            if !packages.iter().any(|p| annotation.applies_to(p.name())) {
+                self.warn(
+                    warnings,
+                    &Messages::format(msgs.warn_orphaned_annotation, &[annotation.package()]),
+                    SbomWarning::OrphanedAnnotation {
+                        package: annotation.package().to_string(),
+                    },
+                );
+            }
+        }
+
+        Ok(annotations)
+    }
+
+    /// Sets on each package the requirement pyproject.toml declares for it
+    ///
+    /// Only direct dependencies are declared, so transitive packages are left
+    /// without a constraint. A missing or unreadable pyproject.toml is not an
+    /// error: the packages are simply returned unchanged.
+    fn attach_declared_constraints(
+        &self,
+        request: &SbomRequest,
+        packages: Vec<Package>,
+    ) -> Vec<Package> {
+        let Ok(Some(requirements)) = self
+            .project_config_reader
+            .read_declared_requirements(&request.project_path)
+        else {
+            return packages;
+        };
+        let project_name = self.read_project_name(request).ok();
+
+        let constraints = DeclaredConstraintMatcher::index(project_name.as_deref(), &requirements);
+        packages
+            .into_iter()
+            .map(|package| {
+                let constraint = constraints
+                    .get(&PackageName::normalize(package.name()))
+                    .cloned();
+                package.with_declared_constraint(constraint)
+            })
+            .collect()
+    }
+
+    /// Warns about `license_overrides` entries for packages that are not in the lockfile
+    ///
+    /// Mirrors the unmatched exclude-pattern warning; names are compared after
+    /// PEP 503 normalization.
+    fn warn_unmatched_license_overrides(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+        warnings: &mut Vec<SbomWarning>,
+    ) {
+        let msgs = Messages::for_locale(self.locale);
+        for license_override in &request.license_overrides {
+            if !packages
+                .iter()
+                .any(|p| license_override.applies_to(p.name()))
+            {
+                self.warn(
+                    warnings,
+                    &Messages::format(
+                        msgs.warn_unmatched_license_override,
+                        &[license_override.package()],
+                    ),
+                    SbomWarning::UnmatchedLicenseOverride {
+                        package: license_override.package().to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Replaces the license of each package named in `license_overrides`
+    ///
+    /// Runs after enrichment, so the override wins over whatever the license
+    /// repository returned, including no license at all. Returns the overrides
+    /// that matched a package, for marking the components in the output.
+    fn apply_license_overrides(
+        &self,
+        request: &SbomRequest,
+        enriched_packages: &mut [EnrichedPackage],
+    ) -> Vec<LicenseOverride> {
+        let mut applied = Vec::new();
+        for license_override in &request.license_overrides {
+            let mut matched = false;
+            for enriched in enriched_packages
+                .iter_mut()
+                .filter(|p| license_override.applies_to(p.package.name()))
+            {
+                enriched.license = Some(license_override.license().to_string());
+                enriched.license_source = LicenseSource::ConfigOverride;
+                matched = true;
+            }
+            if matched {
+                applied.push(license_override.clone());
+            }
+        }
+
+        if !applied.is_empty() {
+            let msgs = Messages::for_locale(self.locale);
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_license_overrides_applied,
+                &[&applied.len().to_string()],
+            ));
+        }
+        applied
+    }
+
     /// Applies exclusion filters to packages
     ///
     /// Note: This method intentionally does NOT filter the dependency_map.
@@ -278,25 +1105,39 @@ where
     ///
     /// # Arguments
     /// * `packages` - Original packages from lockfile
+    /// * `dependency_map` - Map of package dependencies, used by `deps-of:` patterns
     /// * `request` - The SBOM request containing exclusion patterns
     ///
     /// # Returns
     /// Filtered packages list
     ///
     /// # Errors
-    /// Returns an error if all packages are excluded
+    /// Returns an error if all packages are excluded, unless the lockfile held
+    /// nothing but the project itself
     fn apply_exclusion_filters(
         &self,
         packages: Vec<Package>,
+        dependency_map: &HashMap<String, Vec<String>>,
         request: &SbomRequest,
+        warnings: &mut Vec<SbomWarning>,
     ) -> Result<Vec<Package>> {
         if request.exclude_patterns.is_empty() {
             return Ok(packages);
         }
 
         let filter = PackageFilter::new(request.exclude_patterns.clone())?;
+        let msgs = Messages::for_locale(self.locale);
+        for package in filter.get_unknown_parents(&packages) {
+            self.warn(
+                warnings,
+                &Messages::format(msgs.warn_unknown_exclude_parent, &[&package]),
+                SbomWarning::UnknownExcludeParent { package },
+            );
+        }
+
         let original_count = packages.len();
-        let filtered_pkgs = filter.filter_packages(packages);
+        let had_third_party = has_third_party_packages(&packages);
+        let filtered_pkgs = filter.filter_packages_with_dependencies(packages, dependency_map);
 
         let excluded_count = original_count - filtered_pkgs.len();
         if excluded_count > 0 {
@@ -306,36 +1147,146 @@ where
             ));
         }
 
-        // Check if all packages were excluded
-        if filtered_pkgs.is_empty() {
-            anyhow::bail!(
-                "All {} package(s) were excluded by the provided filters. \
-                     The SBOM would be empty. Please adjust your exclusion patterns.",
-                original_count
-            );
+        // Check if all packages were excluded; excluding the project from a lockfile
+        // that holds nothing else leaves an SBOM without dependencies, not an error
+        if filtered_pkgs.is_empty() && had_third_party {
+            return Err(UvSbomError::AllPackagesExcluded {
+                count: original_count,
+            });
         }
 
         // Warn about unmatched patterns
         let unmatched_patterns = filter.get_unmatched_patterns();
         for pattern in unmatched_patterns {
-            self.progress_reporter.report_error(&format!(
-                "⚠️  Warning: Exclude pattern '{}' did not match any dependencies.",
-                pattern
-            ));
+            self.warn(
+                warnings,
+                &format!(
+                    "⚠️  Warning: Exclude pattern '{}' did not match any dependencies.",
+                    pattern
+                ),
+                SbomWarning::UnmatchedExcludePattern {
+                    pattern: pattern.to_string(),
+                },
+            );
         }
 
         Ok(filtered_pkgs)
     }
 
+    /// Checks the option values and, with `probe_endpoints`, that every network
+    /// endpoint the run would use can be reached, reporting each finding
+    async fn validate_configuration(&self, request: &SbomRequest) -> ValidationReport {
+        let msgs = Messages::for_locale(self.locale);
+        let mut report = ValidationReport {
+            issues: ConfigurationValidator::validate(
+                request.cvss_threshold,
+                request.ignore_cves.iter().map(|entry| entry.id.as_str()),
+            ),
+            probed_endpoints: Vec::new(),
+        };
+
+        if request.probe_endpoints {
+            self.progress_reporter
+                .report(msgs.progress_probing_endpoints);
+            let mut probes = self.license_repository.probe_endpoints().await;
+            if let Some(repository) = &self.vulnerability_repository {
+                probes.extend(repository.probe_endpoints().await);
+            }
+            if let Some(repository) = &self.maintenance_repository {
+                probes.extend(repository.probe_endpoints().await);
+            }
+            for probe in probes {
+                if report.probed_endpoints.contains(&probe.endpoint) {
+                    continue;
+                }
+                report.probed_endpoints.push(probe.endpoint.clone());
+                match probe.error {
+                    Some(error) => report.issues.push(ValidationIssue::EndpointUnreachable {
+                        endpoint: probe.endpoint,
+                        error,
+                    }),
+                    None => self.progress_reporter.report(&Messages::format(
+                        msgs.progress_endpoint_reachable,
+                        &[&probe.endpoint],
+                    )),
+                }
+            }
+        }
+
+        for issue in &report.issues {
+            match issue {
+                ValidationIssue::CvssThresholdOutOfRange { value } => {
+                    self.progress_reporter.report_error(&Messages::format(
+                        msgs.error_cvss_threshold_out_of_range,
+                        &[&value.to_string()],
+                    ))
+                }
+                ValidationIssue::UnrecognizedIgnoreId { id } => self
+                    .progress_reporter
+                    .report(&Messages::format(msgs.warn_unrecognized_ignore_id, &[id])),
+                ValidationIssue::EndpointUnreachable { endpoint, error } => {
+                    self.progress_reporter.report_error(&Messages::format(
+                        msgs.error_endpoint_unreachable,
+                        &[endpoint, error],
+                    ))
+                }
+            }
+        }
+        report
+    }
+
     /// Builds a response for dry-run mode (validation only)
-    fn build_dry_run_response(&self) -> Result<SbomResponse> {
-        self.progress_reporter
-            .report_completion("Success: Configuration validated. No issues found.");
+    fn build_dry_run_response(
+        &self,
+        warnings: Vec<SbomWarning>,
+        stats: Option<GenerationStats>,
+        validation_report: ValidationReport,
+    ) -> Result<SbomResponse> {
+        let msgs = Messages::for_locale(self.locale);
+        let errors = validation_report
+            .issues
+            .iter()
+            .filter(|issue| issue.is_error())
+            .count();
+        let issue_warnings = validation_report.issues.len() - errors;
+        match validation_report.outcome() {
+            ValidationOutcome::Valid => self
+                .progress_reporter
+                .report_completion("Success: Configuration validated. No issues found."),
+            ValidationOutcome::ValidWithWarnings => {
+                self.progress_reporter.report_completion(&Messages::format(
+                    msgs.progress_dry_run_valid_with_warnings,
+                    &[&issue_warnings.to_string()],
+                ))
+            }
+            ValidationOutcome::Invalid => self.progress_reporter.report_error(&Messages::format(
+                msgs.progress_dry_run_invalid,
+                &[&errors.to_string(), &issue_warnings.to_string()],
+            )),
+        }
+        if let Some(stats) = &stats {
+            self.report_stats(stats);
+        }
         let metadata = SbomGenerator::generate_default_metadata();
-        Ok(SbomResponse::builder()
+        let mut response = SbomResponse::builder()
             .metadata(metadata)
+            .warnings(warnings)
             .build()
-            .expect("dry-run response build should not fail"))
+            .expect("dry-run response build should not fail");
+        response.stats = stats;
+        response.validation_report = Some(validation_report);
+        Ok(response)
+    }
+
+    /// Reports every package that is locked at more than one version
+    fn report_version_conflicts(&self, packages: &[Package]) {
+        let msgs = Messages::for_locale(self.locale);
+        for conflict in DependencyAnalyzer::find_version_conflicts(packages) {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_version_conflict,
+                &[&conflict.name, &conflict.versions.join(", ")],
+            ));
+        }
     }
 
     /// Analyzes dependencies if requested in the SBOM request
@@ -343,13 +1294,21 @@ where
     /// # Arguments
     /// * `request` - The SBOM request
     /// * `dependency_map` - Map of package dependencies
+    /// * `edge_extras` - Extras requested on dependency edges
+    /// * `lockfile_root` - The package the lockfile marks as the project, if any
+    /// * `warnings` - Collection a skipped analysis is recorded into
     ///
     /// # Returns
-    /// Optional DependencyGraph if analysis was requested
+    /// Optional DependencyGraph if analysis was requested. Without a pyproject.toml the
+    /// lockfile's project package is the root; when there is none either, the analysis
+    /// is skipped with a warning, or fails with `strict`.
     fn analyze_dependencies_if_requested(
         &self,
         request: &SbomRequest,
-        dependency_map: &std::collections::HashMap<String, Vec<String>>,
+        dependency_map: &HashMap<String, Vec<String>>,
+        edge_extras: &EdgeExtras,
+        lockfile_root: Option<&str>,
+        warnings: &mut Vec<SbomWarning>,
     ) -> Result<Option<crate::sbom_generation::domain::DependencyGraph>> {
         if !request.include_dependency_info {
             return Ok(None);
@@ -358,16 +1317,41 @@ where
         let msgs = Messages::for_locale(self.locale);
         self.progress_reporter.report(msgs.progress_parsing_deps);
 
-        let project_name = self
-            .project_config_reader
-            .read_project_name(&request.project_path)?;
-        let project_package_name = PackageName::new(project_name)?;
-
-        let graph = DependencyAnalyzer::analyze(&project_package_name, dependency_map)?;
-
-        self.progress_reporter.report(&Messages::format(
-            msgs.progress_direct_deps,
-            &[&graph.direct_dependency_count().to_string()],
+        let roots = match self.resolve_dependency_roots(request) {
+            Ok(roots) => roots,
+            Err(UvSbomError::ProjectConfigNotFound { path }) => match lockfile_root {
+                Some(root) => {
+                    self.progress_reporter
+                        .report(&Messages::format(msgs.progress_lockfile_root, &[root]));
+                    vec![PackageName::new(root.to_string())?]
+                }
+                None if request.strict => return Err(UvSbomError::ProjectConfigNotFound { path }),
+                None => {
+                    let pyproject = path.display().to_string();
+                    self.warn(
+                        warnings,
+                        &Messages::format(msgs.warn_dependency_analysis_skipped, &[&pyproject]),
+                        SbomWarning::DependencyAnalysisSkipped { pyproject },
+                    );
+                    return Ok(None);
+                }
+            },
+            Err(e) => return Err(e),
+        };
+        if roots.len() > 1 {
+            let names: Vec<&str> = roots.iter().map(|r| r.as_str()).collect();
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_workspace_roots,
+                &[&names.join(", ")],
+            ));
+        }
+
+        let graph = DependencyAnalyzer::analyze(&roots, dependency_map)?
+            .with_edge_extras(Self::edge_extras_by_package(edge_extras)?);
+
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_direct_deps,
+            &[&graph.direct_dependency_count().to_string()],
         ));
         self.progress_reporter.report(&Messages::format(
             msgs.progress_transitive_deps,
@@ -377,44 +1361,452 @@ where
         Ok(Some(graph))
     }
 
+    /// Builds the dependency graph rooted at the packages given with `--roots`
+    ///
+    /// The roots are the direct dependencies; the packages of `packages` that none
+    /// of them reaches are recorded on the graph as unreferenced.
+    fn analyze_explicit_roots(
+        &self,
+        roots: &[PackageName],
+        packages: &[Package],
+        dependency_map: &HashMap<String, Vec<String>>,
+        edge_extras: &EdgeExtras,
+    ) -> Result<DependencyGraph> {
+        let msgs = Messages::for_locale(self.locale);
+        self.progress_reporter.report(msgs.progress_parsing_deps);
+        let names: Vec<&str> = roots.iter().map(|r| r.as_str()).collect();
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_explicit_roots,
+            &[&names.join(", ")],
+        ));
+
+        let graph = DependencyAnalyzer::analyze_with_roots(roots, dependency_map)?
+            .with_edge_extras(Self::edge_extras_by_package(edge_extras)?);
+        let unreferenced = DependencyAnalyzer::find_unreferenced(&graph, packages)?;
+
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_transitive_deps,
+            &[&graph.transitive_dependency_count().to_string()],
+        ));
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_unreferenced_packages,
+            &[&unreferenced.len().to_string()],
+        ));
+        Ok(graph.with_unreferenced_packages(unreferenced))
+    }
+
+    /// Expands the `--roots` patterns against the lockfile's packages
+    ///
+    /// Returns `None` when no roots were given, so the project is analyzed. Each
+    /// pattern must match at least one package; `deps-of:` patterns are rejected.
+    fn resolve_explicit_roots(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+    ) -> Result<Option<Vec<PackageName>>> {
+        if request.roots.is_empty() {
+            return Ok(None);
+        }
+        if let Some(pattern) = request.roots.iter().find(|p| p.starts_with("deps-of:")) {
+            return Err(UvSbomError::validation(format!(
+                "--roots does not accept deps-of: patterns: {}",
+                pattern
+            )));
+        }
+
+        let filter = PackageFilter::new(request.roots.clone())?;
+        let mut roots: Vec<PackageName> = Vec::new();
+        for package in filter.select_packages(packages) {
+            let name = PackageName::new(package.name().to_string())?;
+            if !roots.contains(&name) {
+                roots.push(name);
+            }
+        }
+        let unmatched = filter.get_unmatched_patterns();
+        if !unmatched.is_empty() {
+            return Err(UvSbomError::validation(format!(
+                "--roots matched no package in the lockfile: {}",
+                unmatched.join(", ")
+            )));
+        }
+        Ok(Some(roots))
+    }
+
+    /// Converts the lockfile's edge extras to package names
+    fn edge_extras_by_package(
+        edge_extras: &EdgeExtras,
+    ) -> Result<HashMap<PackageName, HashMap<PackageName, Vec<String>>>> {
+        edge_extras
+            .iter()
+            .map(|(parent, children)| {
+                let children = children
+                    .iter()
+                    .map(|(child, extras)| Ok((PackageName::new(child.clone())?, extras.clone())))
+                    .collect::<Result<HashMap<_, _>>>()?;
+                Ok((PackageName::new(parent.clone())?, children))
+            })
+            .collect()
+    }
+
+    /// Resolves the roots whose direct dependencies make up the project's
+    /// direct dependencies
+    ///
+    /// For a uv workspace root these are the project itself (when its
+    /// `pyproject.toml` declares one) and every workspace member. A virtual
+    /// workspace root without a `[project]` table contributes only its members.
+    fn resolve_dependency_roots(&self, request: &SbomRequest) -> Result<Vec<PackageName>> {
+        let members = self
+            .project_config_reader
+            .read_workspace_member_names(&request.project_path)?;
+
+        let mut roots = Vec::new();
+        match self.read_project_name(request) {
+            Ok(name) => roots.push(PackageName::new(name)?),
+            // A virtual workspace root has no [project] table; its members are the roots
+            Err(UvSbomError::ProjectNameMissing { .. }) if !members.is_empty() => {}
+            Err(e) => return Err(e),
+        }
+        for member in members {
+            let member = PackageName::new(member)?;
+            if !roots.contains(&member) {
+                roots.push(member);
+            }
+        }
+        Ok(roots)
+    }
+
+    /// Returns the project name given with the request, or else the one in pyproject.toml
+    fn read_project_name(&self, request: &SbomRequest) -> Result<String> {
+        match &request.project_name {
+            Some(name) => Ok(name.clone()),
+            None => self
+                .project_config_reader
+                .read_project_name(&request.project_path),
+        }
+    }
+
     /// Fetches license information for packages
     ///
     /// # Arguments
+    /// * `request` - The SBOM request
     /// * `packages` - Packages to enrich with license info
+    /// * `targets` - Normalized names of the packages to look up; `None` looks up every package
+    /// * `budget` - Lookups left; packages beyond it are not looked up
+    /// * `interruption` - Deadline and cancellation the fetch is aborted by
+    /// * `warnings` - Collection that failed fetches are recorded into
     ///
     /// # Returns
-    /// Vector of EnrichedPackage with license information
-    async fn fetch_license_info(&self, packages: Vec<Package>) -> Result<Vec<EnrichedPackage>> {
+    /// Vector of EnrichedPackage with license information, in the order of `packages`
+    async fn fetch_license_info(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+        targets: Option<&HashSet<String>>,
+        budget: &mut LookupBudget,
+        interruption: &Interruption,
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Result<Vec<EnrichedPackage>> {
         let msgs = Messages::for_locale(self.locale);
         self.progress_reporter
             .report(msgs.progress_fetching_license);
 
-        let fetch_use_case = FetchLicensesUseCase::new(self.license_repository.clone());
-        let (enriched, errors) = fetch_use_case.fetch_with_progress(packages).await?;
+        // Packages from local sources are not on PyPI; their licenses are read from
+        // their own pyproject.toml instead. Packages outside `targets` are listed
+        // without a license, and so are those beyond the lookup budget.
+        let is_target =
+            |p: &Package| targets.is_none_or(|t| t.contains(&PackageName::normalize(p.name())));
+        let wanted = packages
+            .iter()
+            .filter(|p| !p.has_local_source() && is_target(p))
+            .count();
+        let mut granted = budget.take(wanted);
+        let lookups: Vec<LicenseLookup> = packages
+            .iter()
+            .map(|p| {
+                if p.has_local_source() {
+                    LicenseLookup::Local
+                } else if !is_target(p) {
+                    LicenseLookup::NotRequested
+                } else if granted > 0 {
+                    granted -= 1;
+                    LicenseLookup::Index
+                } else {
+                    LicenseLookup::OverBudget
+                }
+            })
+            .collect();
+        let of_lookup = |lookup: LicenseLookup| {
+            packages
+                .iter()
+                .zip(&lookups)
+                .filter(move |(_, l)| **l == lookup)
+                .map(|(p, _)| p)
+        };
+        let mut local = self
+            .enrich_from_local_metadata(request, of_lookup(LicenseLookup::Local))
+            .into_iter();
+        let budget_error = Messages::format(
+            msgs.error_network_budget_exhausted,
+            &[&budget.limit().unwrap_or_default().to_string()],
+        );
+        let mut errors: Vec<LicenseFetchFailure> = of_lookup(LicenseLookup::OverBudget)
+            .map(|p| LicenseFetchFailure {
+                package: p.name().to_string(),
+                kind: LicenseFetchErrorKind::Skipped,
+                error: budget_error.clone(),
+            })
+            .collect();
+        let packages_to_fetch: Vec<&Package> = of_lookup(LicenseLookup::Index).collect();
 
-        eprintln!(); // Add newline after progress bar
+        let total = packages_to_fetch.len();
+        let task = Arc::new(CountingTask::new(
+            self.progress_reporter
+                .start_task(ProgressPhase::LicenseEnrichment, total),
+            total,
+        ));
+        let fetch_use_case =
+            FetchLicensesUseCase::new(self.license_repository.clone()).with_progress(task.clone());
+        let fetched = interruption
+            .run(
+                GenerationPhase::LicenseEnrichment,
+                &task,
+                fetch_use_case.fetch_with_progress(packages_to_fetch),
+            )
+            .await;
+        task.finish();
+        let (fetched, fetch_errors) = fetched?;
+        errors.extend(fetch_errors);
+        let mut fetched = fetched.into_iter();
+        let enriched: Vec<EnrichedPackage> = packages
+            .iter()
+            .zip(lookups)
+            .filter_map(|(package, lookup)| match lookup {
+                LicenseLookup::Local => local.next(),
+                LicenseLookup::Index => fetched.next(),
+                LicenseLookup::NotRequested => {
+                    Some(EnrichedPackage::new(package.clone(), None, None))
+                }
+                LicenseLookup::OverBudget => Some(
+                    EnrichedPackage::new(package.clone(), None, None).with_enrichment_status(
+                        EnrichmentStatus::FetchFailed(budget_error.clone()),
+                    ),
+                ),
+            })
+            .collect();
 
-        for (package_name, error_msg) in &errors {
-            self.progress_reporter.report_error(&Messages::format(
-                msgs.warn_license_fetch_failed,
-                &[package_name, error_msg],
-            ));
+        self.report_license_details(&enriched, &errors);
+
+        for failure in &errors {
+            // Skipped lookups are summed up at the end of the run, not reported one by one
+            if failure.kind == LicenseFetchErrorKind::Skipped {
+                warnings.push(SbomWarning::LicenseFetchFailed {
+                    package: failure.package.clone(),
+                    category: FetchFailureCategory::Skipped,
+                    error: failure.error.clone(),
+                });
+                continue;
+            }
+            let message = match failure.kind {
+                LicenseFetchErrorKind::NotFound => {
+                    Messages::format(msgs.warn_license_not_found, &[&failure.package])
+                }
+                _ => Messages::format(
+                    msgs.warn_license_fetch_failed,
+                    &[&failure.package, &failure.error],
+                ),
+            };
+            self.warn(
+                warnings,
+                &message,
+                SbomWarning::LicenseFetchFailed {
+                    package: failure.package.clone(),
+                    category: FetchFailureCategory::from_kind(failure.kind, &failure.error),
+                    error: failure.error.clone(),
+                },
+            );
         }
 
         let (successful, total, failed) =
             FetchLicensesUseCase::<LREPO>::summarize(&enriched, &errors);
-        self.progress_reporter.report_completion(&Messages::format(
+        let mut completion = Messages::format(
             msgs.progress_license_complete,
             &[
                 &successful.to_string(),
                 &total.to_string(),
                 &failed.to_string(),
             ],
-        ));
+        );
+        if failed > 0 {
+            completion.push_str(&Messages::format(
+                msgs.progress_license_failure_breakdown,
+                &[&self.license_failure_breakdown(&errors)],
+            ));
+        }
+        self.progress_reporter.report_completion(&completion);
 
         Ok(enriched)
     }
 
+    /// Returns the normalized names of the packages whose license is looked up,
+    /// or `None` for every package
+    ///
+    /// With `licenses_for: direct-only` these are the direct dependencies of the
+    /// project. When the project root cannot be determined every package is
+    /// looked up, with a warning.
+    fn select_license_targets(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+        dependency_graph: Option<&DependencyGraph>,
+        dependency_map: &HashMap<String, Vec<String>>,
+        lockfile_root: Option<&str>,
+    ) -> Option<HashSet<String>> {
+        if request.licenses_for != LicensesFor::DirectOnly {
+            return None;
+        }
+        let msgs = Messages::for_locale(self.locale);
+        let direct = match dependency_graph {
+            Some(graph) => Some(graph.direct_dependencies().to_vec()),
+            None => self
+                .resolve_dependency_roots(request)
+                .ok()
+                .or_else(|| {
+                    let root = PackageName::new(lockfile_root?.to_string()).ok()?;
+                    Some(vec![root])
+                })
+                .and_then(|roots| DependencyAnalyzer::analyze(&roots, dependency_map).ok())
+                .map(|graph| graph.direct_dependencies().to_vec()),
+        };
+        let Some(direct) = direct else {
+            self.progress_reporter
+                .report_error(msgs.warn_licenses_direct_only_unavailable);
+            return None;
+        };
+
+        let targets: HashSet<String> = direct
+            .iter()
+            .map(|name| PackageName::normalize(name.as_str()))
+            .collect();
+        let transitive = packages
+            .iter()
+            .filter(|p| !p.has_local_source() && !p.is_project_root())
+            .filter(|p| !targets.contains(&PackageName::normalize(p.name())))
+            .count();
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_licenses_direct_only,
+            &[&transitive.to_string()],
+        ));
+        Some(targets)
+    }
+
+    /// Describes how many license fetches failed for each reason, e.g.
+    /// `3 not on PyPI, 1 timeout`
+    fn license_failure_breakdown(&self, errors: &[LicenseFetchFailure]) -> String {
+        let msgs = Messages::for_locale(self.locale);
+        FetchLicensesUseCase::<LREPO>::count_by_kind(errors)
+            .into_iter()
+            .map(|(kind, count)| {
+                let template = match kind {
+                    LicenseFetchErrorKind::NotFound => msgs.license_failures_not_found,
+                    LicenseFetchErrorKind::RateLimited => msgs.license_failures_rate_limited,
+                    LicenseFetchErrorKind::Timeout => msgs.license_failures_timeout,
+                    LicenseFetchErrorKind::Skipped => msgs.license_failures_skipped,
+                    LicenseFetchErrorKind::Other => msgs.license_failures_other,
+                };
+                Messages::format(template, &[&count.to_string()])
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Reports the license fetch result of every package as a detail line
+    fn report_license_details(&self, enriched: &[EnrichedPackage], errors: &[LicenseFetchFailure]) {
+        let msgs = Messages::for_locale(self.locale);
+        let errors: HashMap<&str, &str> = errors
+            .iter()
+            .map(|failure| (failure.package.as_str(), failure.error.as_str()))
+            .collect();
+        for ep in enriched {
+            let name = ep.package.name();
+            let version = ep.package.version();
+            let detail = match (errors.get(name), &ep.license) {
+                (Some(error), _) => {
+                    Messages::format(msgs.detail_license_failed, &[name, version, error])
+                }
+                (None, Some(license)) => {
+                    Messages::format(msgs.detail_license_resolved, &[name, version, license])
+                }
+                (None, None) => Messages::format(msgs.detail_license_missing, &[name, version]),
+            };
+            self.progress_reporter.report_detail(&detail);
+        }
+    }
+
+    /// Reports once that packages from local path or virtual sources are not
+    /// looked up on PyPI or OSV
+    fn report_local_sources(&self, packages: &[Package]) {
+        let count = packages.iter().filter(|p| p.has_local_source()).count();
+        if count > 0 {
+            let msgs = Messages::for_locale(self.locale);
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_local_sources_skipped,
+                &[&count.to_string()],
+            ));
+        }
+    }
+
+    /// Enriches packages with licenses from local project metadata
+    ///
+    /// Only packages with a local source in uv.lock can be resolved; all others
+    /// keep license `None`.
+    fn enrich_from_local_metadata<'a>(
+        &self,
+        request: &SbomRequest,
+        packages: impl IntoIterator<Item = &'a Package>,
+    ) -> Vec<EnrichedPackage> {
+        let mut packages = packages.into_iter().peekable();
+        if packages.peek().is_none() {
+            return Vec::new();
+        }
+        // Local metadata is best effort; without it the SBOM simply has no licenses
+        let local_licenses = self
+            .project_config_reader
+            .read_local_package_licenses(&request.project_path)
+            .unwrap_or_default();
+
+        packages
+            .map(
+                |package| match local_licenses.get(&PackageName::normalize(package.name())) {
+                    Some(license) => {
+                        EnrichedPackage::new(package.clone(), Some(license.clone()), None)
+                            .with_license_source(LicenseSource::LocalMetadata)
+                    }
+                    None => EnrichedPackage::new(package.clone(), None, None),
+                },
+            )
+            .collect()
+    }
+
+    /// Enriches packages with licenses from local project metadata (offline mode)
+    ///
+    /// Reports a single progress line instead of per-package warnings.
+    fn read_local_license_info(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+    ) -> Vec<EnrichedPackage> {
+        let msgs = Messages::for_locale(self.locale);
+        let enriched = self.enrich_from_local_metadata(request, packages);
+
+        let found = enriched.iter().filter(|p| p.license.is_some()).count();
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_offline_mode,
+            &[&found.to_string(), &enriched.len().to_string()],
+        ));
+
+        enriched
+    }
+
     /// Checks vulnerabilities if CVE check is requested
     ///
     /// This method delegates to CheckVulnerabilitiesUseCase for the actual
@@ -424,6 +1816,7 @@ where
     /// # Arguments
     /// * `request` - The SBOM request
     /// * `packages` - Packages to check for vulnerabilities
+    /// * `interruption` - Deadline and cancellation the check is aborted by
     ///
     /// # Returns
     /// Optional vulnerability report
@@ -431,6 +1824,8 @@ where
         &self,
         request: &SbomRequest,
         packages: &[Package],
+        interruption: &Interruption,
+        warnings: &mut Vec<SbomWarning>,
     ) -> Result<Option<Vec<crate::sbom_generation::domain::PackageVulnerabilities>>> {
         if !request.check_cve {
             return Ok(None);
@@ -446,27 +1841,245 @@ where
         self.progress_reporter.report(msgs.progress_fetching_vulns);
 
         // Delegate to CheckVulnerabilitiesUseCase for vulnerability fetching
-        let vuln_use_case = CheckVulnerabilitiesUseCase::new(repo.clone());
-        let vulnerabilities = vuln_use_case.check_with_progress(packages.to_vec()).await?;
+        // Total is unknown until the batch query returns
+        let task = Arc::new(CountingTask::new(
+            self.progress_reporter
+                .start_task(ProgressPhase::VulnerabilityCheck, 0),
+            0,
+        ));
+        let vuln_use_case =
+            CheckVulnerabilitiesUseCase::new(repo.clone()).with_progress(task.clone());
+        let checked = interruption
+            .run(
+                GenerationPhase::VulnerabilityCheck,
+                &task,
+                vuln_use_case.check_with_progress(packages),
+            )
+            .await;
+        task.finish();
+        let outcome = checked?;
+        let cached_packages = outcome.cached_packages;
+        let vulnerabilities = outcome.results;
 
         // Report completion based on results
         let (total_vulns, affected_packages) =
             CheckVulnerabilitiesUseCase::<VREPO>::summarize(&vulnerabilities);
-        eprintln!(); // Add newline after progress bar
+        let unchecked_count = outcome.failed_packages.len();
+        self.record_unchecked_packages(warnings, outcome.failed_packages);
+        self.record_incomplete_advisories(warnings, outcome.incomplete_advisories);
         if total_vulns > 0 {
             self.progress_reporter.report_completion(&Messages::format(
                 msgs.progress_vuln_found,
                 &[&total_vulns.to_string(), &affected_packages.to_string()],
             ));
-        } else {
+        } else if unchecked_count == 0 {
             self.progress_reporter
                 .report_completion(msgs.progress_vuln_none);
         }
+        if cached_packages > 0 {
+            self.progress_reporter.report_completion(&Messages::format(
+                msgs.progress_vuln_cached,
+                &[&cached_packages.to_string(), &packages.len().to_string()],
+            ));
+        }
+        // Skipped packages may have vulnerabilities, so "none found" is never claimed for them
+        if unchecked_count > 0 {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.progress_vuln_incomplete,
+                &[&unchecked_count.to_string()],
+            ));
+        }
 
         // Return Some even if empty (indicates check was performed)
         Ok(Some(vulnerabilities))
     }
 
+    /// Keeps the packages of `query` the lookup budget allows
+    ///
+    /// The packages left out are recorded as unchecked, so no report claims they
+    /// have no vulnerabilities.
+    fn limit_vulnerability_query(
+        &self,
+        mut query: Vec<Package>,
+        budget: &mut LookupBudget,
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Vec<Package> {
+        let granted = budget.take(query.len());
+        if granted < query.len() {
+            let msgs = Messages::for_locale(self.locale);
+            let error = Messages::format(
+                msgs.error_network_budget_exhausted,
+                &[&budget.limit().unwrap_or_default().to_string()],
+            );
+            for package in query.drain(granted..) {
+                warnings.push(SbomWarning::VulnerabilityCheckFailed {
+                    package: package.name().to_string(),
+                    version: package.version().to_string(),
+                    error: error.clone(),
+                });
+            }
+        }
+        query
+    }
+
+    /// Reports how many lookups were skipped once the lookup budget ran out
+    fn report_lookup_budget(&self, budget: &LookupBudget) {
+        if let (Some(limit), skipped @ 1..) = (budget.limit(), budget.skipped()) {
+            let msgs = Messages::for_locale(self.locale);
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_network_budget_exhausted,
+                &[&limit.to_string(), &skipped.to_string()],
+            ));
+        }
+    }
+
+    /// Records a warning for each package the vulnerability check could not query
+    fn record_unchecked_packages(
+        &self,
+        warnings: &mut Vec<SbomWarning>,
+        unchecked: Vec<UncheckedPackage>,
+    ) {
+        let msgs = Messages::for_locale(self.locale);
+        for UncheckedPackage { package, error } in unchecked {
+            let (name, version) = (package.name(), package.version());
+            self.warn(
+                warnings,
+                &Messages::format(msgs.warn_vuln_check_failed, &[name, version, &error]),
+                SbomWarning::VulnerabilityCheckFailed {
+                    package: name.to_string(),
+                    version: version.to_string(),
+                    error,
+                },
+            );
+        }
+    }
+
+    /// Records a warning for each advisory reported without its details
+    fn record_incomplete_advisories(
+        &self,
+        warnings: &mut Vec<SbomWarning>,
+        incomplete: Vec<IncompleteAdvisory>,
+    ) {
+        let msgs = Messages::for_locale(self.locale);
+        for IncompleteAdvisory { id, error } in incomplete {
+            self.warn(
+                warnings,
+                &Messages::format(msgs.warn_advisory_details_failed, &[&id, &error]),
+                SbomWarning::AdvisoryDetailsFetchFailed { id, error },
+            );
+        }
+    }
+
+    /// Selects the packages to query for vulnerabilities, and the root project
+    /// when `check_self` is enabled
+    ///
+    /// With check_self the root project is queried on its own, so its findings are
+    /// attributed to the project itself rather than listed as a dependency.
+    /// Packages from local sources have no OSV records and are not queried.
+    /// Nothing is copied when the CVE check is disabled.
+    fn build_vulnerability_query(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+    ) -> (Option<Package>, Vec<Package>) {
+        if !request.check_cve || self.vulnerability_repository.is_none() {
+            return (None, Vec::new());
+        }
+        let self_package = self.resolve_self_package_if_requested(request, packages);
+        let query = packages
+            .iter()
+            .filter(|p| !p.has_local_source())
+            .filter(|p| {
+                self_package
+                    .as_ref()
+                    .is_none_or(|root| p.name() != root.name())
+            })
+            .cloned()
+            .collect();
+        (self_package, query)
+    }
+
+    /// Resolves the root project as a package when `check_self` is enabled
+    ///
+    /// The version comes from `[project].version` in pyproject.toml, falling back to
+    /// the version recorded in the lockfile. Returns `None` when the check is disabled
+    /// or the name or version cannot be determined.
+    fn resolve_self_package_if_requested(
+        &self,
+        request: &SbomRequest,
+        packages: &[Package],
+    ) -> Option<Package> {
+        if !request.check_cve || !request.check_self || self.vulnerability_repository.is_none() {
+            return None;
+        }
+
+        let name = self.read_project_name(request).ok()?;
+        let version = self
+            .project_config_reader
+            .read_project_version(&request.project_path)
+            .ok()
+            .flatten()
+            .or_else(|| {
+                packages
+                    .iter()
+                    .find(|p| p.name() == name)
+                    .map(|p| p.version().to_string())
+            })?;
+
+        Package::new(name, version).ok()
+    }
+
+    /// Checks the project's own published version for vulnerabilities
+    ///
+    /// A project that was never published to PyPI simply has no OSV records,
+    /// so it yields an empty result rather than an error. A failed query is
+    /// recorded as a warning instead.
+    ///
+    /// # Returns
+    /// Threshold-evaluated result for the root project, or `None` when not
+    /// requested or the query failed
+    async fn check_project_self_if_requested(
+        &self,
+        request: &SbomRequest,
+        self_package: Option<Package>,
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Result<Option<VulnerabilityCheckResult>> {
+        let (Some(package), Some(repo)) = (self_package, &self.vulnerability_repository) else {
+            return Ok(None);
+        };
+
+        let name = package.name().to_string();
+        let version = package.version().to_string();
+        let outcome = repo.fetch_vulnerabilities(&[package]).await?;
+        self.record_incomplete_advisories(warnings, outcome.incomplete_advisories);
+        if !outcome.failed_packages.is_empty() {
+            self.record_unchecked_packages(warnings, outcome.failed_packages);
+            return Ok(None);
+        }
+        let result = VulnerabilityChecker::check(
+            outcome.results,
+            Self::build_threshold_config(request),
+            &request.ignore_cves,
+        );
+        self.report_ignored_cves(&result.ignored);
+
+        let msgs = Messages::for_locale(self.locale);
+        let total = result.actionable_count() + result.informational_count();
+        if total > 0 {
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_self_check_found,
+                &[&total.to_string(), &name, &version],
+            ));
+        } else {
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_self_check_none,
+                &[&name, &version],
+            ));
+        }
+
+        Ok(Some(result))
+    }
+
     /// Builds ThresholdConfig from SbomRequest options
     ///
     /// # Arguments
@@ -478,11 +2091,29 @@ where
         match (&request.severity_threshold, &request.cvss_threshold) {
             (Some(severity), None) => ThresholdConfig::Severity(*severity),
             (None, Some(cvss)) => ThresholdConfig::Cvss(*cvss),
-            // Both None or unreachable (clap group prevents both being set)
+            // Both None, or both set (rejected when the CLI and config values are merged)
             _ => ThresholdConfig::None,
         }
     }
 
+    /// Runs the FixSuggester on the actionable vulnerabilities when `suggest_fixes` is true
+    ///
+    /// Returns `None` when `suggest_fixes` is false, or when no vulnerability check
+    /// or dependency graph is available to tell direct from transitive packages.
+    fn suggest_fixes_if_requested(
+        request: &SbomRequest,
+        vulnerability_check_result: Option<&VulnerabilityCheckResult>,
+        dependency_graph: Option<&DependencyGraph>,
+    ) -> Option<Vec<FixSuggestion>> {
+        if !request.suggest_fixes {
+            return None;
+        }
+        let (Some(result), Some(graph)) = (vulnerability_check_result, dependency_graph) else {
+            return None;
+        };
+        Some(FixSuggester::suggest(&result.above_threshold, graph))
+    }
+
     /// Runs the UpgradeAdvisor when `suggest_fix` is true and the required context is available
     ///
     /// Returns `None` when `suggest_fix` is false (no overhead).
@@ -493,6 +2124,7 @@ where
         dependency_graph: Option<&crate::sbom_generation::domain::DependencyGraph>,
         vulnerability_report: Option<&[crate::sbom_generation::domain::PackageVulnerabilities]>,
         enriched_packages: &[EnrichedPackage],
+        warnings: &mut Vec<SbomWarning>,
     ) -> Option<Vec<UpgradeRecommendation>> {
         if !request.suggest_fix {
             return None;
@@ -515,17 +2147,17 @@ where
             .map(|i| i.package_name())
             .collect::<std::collections::HashSet<_>>()
             .len();
-        let unit = if unique_dep_count == 1 {
-            msgs.label_dependency_singular
-        } else {
-            msgs.label_dependency_plural
-        };
+        let unit = msgs.unit(
+            unique_dep_count,
+            msgs.label_dependency_singular,
+            msgs.label_dependency_plural,
+        );
         self.progress_reporter.report(&Messages::format(
             msgs.progress_analyzing_upgrade_paths,
             &[&unique_dep_count.to_string(), unit],
         ));
 
-        let simulator = UvLockAdapter::new();
+        let simulator = UvLockAdapter::new().with_data_dir(request.data_dir.clone());
         let recommendations =
             UpgradeAdvisor::advise(&simulator, &entries, &request.project_path).await;
 
@@ -568,6 +2200,10 @@ where
                         msgs.progress_upgrade_simulation_failed,
                         &[direct_dep_name, error],
                     ));
+                    warnings.push(SbomWarning::UpgradeSimulationFailed {
+                        package: direct_dep_name.clone(),
+                        error: error.clone(),
+                    });
                 }
             }
         }
@@ -621,9 +2257,246 @@ where
         Some(result)
     }
 
+    /// Evaluates dependency licenses against the project license if requested
+    ///
+    /// The project license comes from the request when set, otherwise from pyproject.toml.
+    fn check_license_compatibility_if_requested(
+        &self,
+        request: &SbomRequest,
+        enriched_packages: &[EnrichedPackage],
+    ) -> Result<Option<LicenseCompatibilityResult>> {
+        if !request.check_license_compatibility {
+            return Ok(None);
+        }
+
+        let project_license = match &request.project_license {
+            Some(license) => Some(license.clone()),
+            None => self
+                .project_config_reader
+                .read_project_license(&request.project_path)?,
+        };
+
+        let packages: Vec<(String, String, Option<String>)> = enriched_packages
+            .iter()
+            .map(|ep| {
+                (
+                    ep.package.name().to_string(),
+                    ep.package.version().to_string(),
+                    ep.license.clone(),
+                )
+            })
+            .collect();
+
+        let result = LicenseCompatibilityChecker::check(
+            project_license.as_deref(),
+            &packages,
+            &request.license_compatibility_policy,
+        );
+
+        let msgs = Messages::for_locale(self.locale);
+        if result.project_family.is_none() {
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_license_compatibility_unknown_project,
+                &[project_license.as_deref().unwrap_or("-")],
+            ));
+        }
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_license_compatibility_summary,
+            &[
+                &result.count(Compatibility::Compatible).to_string(),
+                &result.count(Compatibility::Conditional).to_string(),
+                &result.count(Compatibility::Incompatible).to_string(),
+                &result.count(Compatibility::NeedsReview).to_string(),
+            ],
+        ));
+
+        Ok(Some(result))
+    }
+
+    /// Finds the copyleft packages reachable through the dependency graph if requested
+    ///
+    /// Each finding is reported as a warning with its shortest introduction path.
+    /// Returns `None` when the check was not requested or no dependency graph was built.
+    fn check_license_reachability_if_requested(
+        &self,
+        request: &SbomRequest,
+        enriched_packages: &[EnrichedPackage],
+        dependency_graph: Option<&DependencyGraph>,
+    ) -> Option<LicenseRiskReport> {
+        if !request.check_license_reachability {
+            return None;
+        }
+        let graph = dependency_graph?;
+
+        let report = LicenseReachabilityAnalyzer::analyze(
+            enriched_packages,
+            graph,
+            &request.copyleft_licenses,
+            request.strict_license_reachability,
+        );
+
+        let msgs = Messages::for_locale(self.locale);
+        for risk in &report.copyleft {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_copyleft_reachable,
+                &[
+                    risk.package_name(),
+                    risk.package_version(),
+                    risk.license().unwrap_or("-"),
+                    &risk.introduction_path().join(" → "),
+                ],
+            ));
+        }
+        for risk in &report.unknown {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_unknown_license_reachable,
+                &[
+                    risk.package_name(),
+                    risk.package_version(),
+                    &risk.introduction_path().join(" → "),
+                ],
+            ));
+        }
+
+        Some(report)
+    }
+
+    /// Compares each package's `requires-python` with the project's if requested
+    ///
+    /// Each package that does not support every Python version the project allows
+    /// is reported as a warning. Returns `None` when the check was not requested,
+    /// or the project declares no `requires-python` or one that is not understood.
+    fn check_python_compat_if_requested(
+        &self,
+        request: &SbomRequest,
+        enriched_packages: &[EnrichedPackage],
+    ) -> Result<Option<PythonCompatibilityReport>> {
+        if !request.check_python_compat {
+            return Ok(None);
+        }
+
+        let msgs = Messages::for_locale(self.locale);
+        let Some(project_requires_python) = self
+            .project_config_reader
+            .read_requires_python(&request.project_path)?
+        else {
+            self.progress_reporter
+                .report(msgs.progress_python_compat_no_project_range);
+            return Ok(None);
+        };
+        let Some(report) =
+            PythonCompatibilityChecker::check(enriched_packages, &project_requires_python)
+        else {
+            self.progress_reporter.report(&Messages::format(
+                msgs.progress_python_compat_unparsed_project_range,
+                &[&project_requires_python],
+            ));
+            return Ok(None);
+        };
+
+        for incompatibility in &report.incompatible {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_python_incompatible,
+                &[
+                    &incompatibility.package_name,
+                    &incompatibility.package_version,
+                    &incompatibility.requires_python,
+                    &report.project_requires_python,
+                ],
+            ));
+        }
+
+        Ok(Some(report))
+    }
+
+    /// Flags packages whose names are near misses of popular package names
+    /// if requested, warning about each one
+    fn check_typosquats_if_requested(
+        &self,
+        request: &SbomRequest,
+        enriched_packages: &[EnrichedPackage],
+    ) -> Option<Vec<TyposquatFinding>> {
+        if !request.check_typosquats {
+            return None;
+        }
+
+        let msgs = Messages::for_locale(self.locale);
+        let findings = TyposquatChecker::check(
+            enriched_packages,
+            request.typosquat_max_distance,
+            &request.ignore_typosquats,
+        );
+        for finding in &findings {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_possible_typosquat,
+                &[
+                    &finding.package_name,
+                    &finding.package_version,
+                    &finding.similar_to,
+                ],
+            ));
+        }
+
+        Some(findings)
+    }
+
+    /// Generates metadata whose serial number is derived from the SBOM inputs
+    ///
+    /// The serial number covers the lockfile content and the packages that end
+    /// up in the SBOM, so runs with different filters or different workspace
+    /// members of one lockfile still get distinct serial numbers.
+    fn generate_reproducible_metadata(
+        &self,
+        request: &SbomRequest,
+        packages: &[EnrichedPackage],
+    ) -> Result<SbomMetadata> {
+        let mut content = self.lockfile_reader.read_lockfile(&request.project_path)?;
+        let mut package_ids: Vec<String> = packages
+            .iter()
+            .map(|p| format!("{}=={}", p.package.name(), p.package.version()))
+            .collect();
+        package_ids.sort();
+        for id in package_ids {
+            content.push('\n');
+            content.push_str(&id);
+        }
+        Ok(SbomGenerator::generate_default_reproducible_metadata(
+            content.as_bytes(),
+            request.source_date_epoch,
+        ))
+    }
+
+    /// Collects the options the SBOM is generated with for its metadata
+    fn invocation_parameters(request: &SbomRequest) -> InvocationParameters {
+        InvocationParameters {
+            project_path: request.project_path.display().to_string(),
+            config_path: request
+                .config_path
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            exclude_patterns: request.exclude_patterns.clone(),
+            check_cve: request.check_cve,
+            severity_threshold: request.severity_threshold,
+            cvss_threshold: request.cvss_threshold,
+            ignored_cve_count: request.ignore_cves.len(),
+            check_license: request.check_license,
+            offline: request.offline,
+            advisory_db: request
+                .advisory_db
+                .as_ref()
+                .map(|path| path.display().to_string()),
+            dry_run: request.dry_run,
+            license_resolution: request.license_resolution,
+            direct_licenses_only: request.licenses_for == LicensesFor::DirectOnly,
+            max_network_requests: request.max_network_requests,
+            ..Default::default()
+        }
+        .with_index_url(request.index_url.as_deref())
+    }
+
     /// Builds the final SBOM response
     fn build_response(
-        &self,
+        request: &SbomRequest,
         enriched_packages: Vec<EnrichedPackage>,
         dependency_graph: Option<DependencyGraph>,
         vulnerability_check_result: Option<VulnerabilityCheckResult>,
@@ -631,7 +2504,8 @@ where
         upgrade_recommendations: Option<Vec<UpgradeRecommendation>>,
         abandoned_packages_report: Option<AbandonedPackagesReport>,
     ) -> SbomResponse {
-        let metadata = SbomGenerator::generate_default_metadata();
+        let metadata = SbomGenerator::generate_default_metadata()
+            .with_invocation(Some(Self::invocation_parameters(request)));
 
         // Use threshold check result if available, otherwise check if any vulnerabilities exist
         let has_vulnerabilities_above_threshold = vulnerability_check_result
@@ -672,3 +2546,8 @@ where
 
 #[cfg(test)]
 mod tests;
+
+/// Returns true when `packages` holds anything besides the project itself
+fn has_third_party_packages(packages: &[Package]) -> bool {
+    packages.iter().any(|p| !p.is_project_root())
+}
@@ -0,0 +1,46 @@
+use crate::ports::inbound::{SbomGenerationEvent, SbomGenerationObserver, SbomPhase};
+use crate::shared::error::UvSbomError;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Tracks the running phase of one run and reports its events to the observer
+pub(super) struct PhaseTracker<'a> {
+    observer: &'a dyn SbomGenerationObserver,
+    current: Mutex<Option<(SbomPhase, Instant)>>,
+}
+
+impl<'a> PhaseTracker<'a> {
+    pub(super) fn new(observer: &'a dyn SbomGenerationObserver) -> Self {
+        Self {
+            observer,
+            current: Mutex::new(None),
+        }
+    }
+
+    /// Opens `phase`, which is expected to be closed by [`Self::finish`]
+    pub(super) fn start(&self, phase: SbomPhase) {
+        *self.current.lock().unwrap() = Some((phase, Instant::now()));
+        self.observer
+            .on_event(&SbomGenerationEvent::PhaseStarted { phase });
+    }
+
+    /// Closes the running phase with the event `finished` builds from its duration
+    pub(super) fn finish(&self, finished: impl FnOnce(Duration) -> SbomGenerationEvent) {
+        let Some((_, started)) = self.current.lock().unwrap().take() else {
+            return;
+        };
+        self.observer.on_event(&finished(started.elapsed()));
+    }
+
+    /// Closes the running phase with a failure event; nothing happens between phases
+    pub(super) fn fail(&self, error: &UvSbomError) {
+        let Some((phase, started)) = self.current.lock().unwrap().take() else {
+            return;
+        };
+        self.observer.on_event(&SbomGenerationEvent::PhaseFailed {
+            phase,
+            error: error.to_string(),
+            duration: started.elapsed(),
+        });
+    }
+}
@@ -1,13 +1,19 @@
 /// Use cases module containing application business logic orchestration
+mod aggregate_sbom;
 mod check_abandoned_packages;
 mod check_vulnerabilities;
+mod diff_sbom;
 mod fetch_licenses;
 mod generate_sbom;
+mod scan_sbom;
 
 #[cfg(test)]
 pub(crate) mod test_doubles;
 
+pub use aggregate_sbom::AggregateSbomUseCase;
 pub use check_abandoned_packages::CheckAbandonedPackagesUseCase;
 pub use check_vulnerabilities::CheckVulnerabilitiesUseCase;
-pub use fetch_licenses::FetchLicensesUseCase;
+pub use diff_sbom::{DiffSbomUseCase, DiffSource};
+pub use fetch_licenses::{FetchLicensesUseCase, LicenseFetchFailure};
 pub use generate_sbom::GenerateSbomUseCase;
+pub use scan_sbom::ScanSbomUseCase;
@@ -0,0 +1,360 @@
+use crate::application::read_models::SbomWarning;
+use crate::application::use_cases::CheckVulnerabilitiesUseCase;
+use crate::config::IgnoreCve;
+use crate::i18n::{Locale, Messages};
+use crate::ports::outbound::{
+    EnrichedPackage, IncompleteAdvisory, ProgressPhase, ProgressReporter, SbomReader,
+    UncheckedPackage, VulnerabilityRepository,
+};
+use crate::sbom_generation::domain::services::{
+    ThresholdConfig, VulnerabilityCheckResult, VulnerabilityChecker,
+};
+use crate::sbom_generation::domain::{Package, PackageEntry};
+use crate::shared::Result;
+use std::path::Path;
+
+/// Package URL prefix of PyPI packages, the only ones OSV is queried for
+const PYPI_PURL_PREFIX: &str = "pkg:pypi/";
+
+/// Result of re-scanning a saved SBOM for vulnerabilities
+#[derive(Debug)]
+pub struct SbomScan {
+    /// The components that were checked, with the license the SBOM records
+    pub packages: Vec<EnrichedPackage>,
+    /// Threshold evaluation of the vulnerabilities found
+    pub vulnerability_check_result: VulnerabilityCheckResult,
+    /// Skipped components and packages the vulnerability check could not query
+    pub warnings: Vec<SbomWarning>,
+}
+
+impl SbomScan {
+    /// Returns true when a vulnerability not on the ignore list meets the threshold
+    pub fn threshold_exceeded(&self) -> bool {
+        self.vulnerability_check_result.threshold_exceeded
+    }
+
+    /// Returns true when some packages could not be checked and may be vulnerable
+    pub fn is_incomplete(&self) -> bool {
+        self.warnings
+            .iter()
+            .any(|w| matches!(w, SbomWarning::VulnerabilityCheckFailed { .. }))
+    }
+}
+
+/// Use case for checking a previously generated SBOM for newly published vulnerabilities.
+///
+/// The components are read back through an `SbomReader` and queried with
+/// `CheckVulnerabilitiesUseCase`, so no lockfile is read and PyPI is not contacted.
+/// Components whose purl is not a PyPI package URL are skipped with a warning;
+/// components without a purl are assumed to be PyPI packages.
+///
+/// # Type Parameters
+/// * `SR` - `SbomReader` implementation
+/// * `VREPO` - `VulnerabilityRepository` implementation
+/// * `PR` - `ProgressReporter` implementation
+pub struct ScanSbomUseCase<SR, VREPO, PR> {
+    sbom_reader: SR,
+    vulnerability_repository: VREPO,
+    progress_reporter: PR,
+    locale: Locale,
+}
+
+impl<SR, VREPO, PR> ScanSbomUseCase<SR, VREPO, PR>
+where
+    SR: SbomReader,
+    VREPO: VulnerabilityRepository + Clone,
+    PR: ProgressReporter,
+{
+    pub fn new(
+        sbom_reader: SR,
+        vulnerability_repository: VREPO,
+        progress_reporter: PR,
+        locale: Locale,
+    ) -> Self {
+        Self {
+            sbom_reader,
+            vulnerability_repository,
+            progress_reporter,
+            locale,
+        }
+    }
+
+    /// Reads the SBOM at `sbom_path` and checks its PyPI components for vulnerabilities.
+    ///
+    /// # Arguments
+    /// * `sbom_path` - CycloneDX JSON file to scan
+    /// * `threshold` - Threshold the findings are evaluated against
+    /// * `ignore_cves` - CVEs dropped before the threshold evaluation
+    pub async fn execute(
+        &self,
+        sbom_path: &Path,
+        threshold: ThresholdConfig,
+        ignore_cves: &[IgnoreCve],
+    ) -> Result<SbomScan> {
+        let msgs = Messages::for_locale(self.locale);
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_reading_sbom,
+            &[&sbom_path.display().to_string()],
+        ));
+
+        let mut warnings = Vec::new();
+        let mut packages = Vec::new();
+        for entry in self.sbom_reader.read_components(sbom_path)? {
+            if let Some(package) = self.scannable_package(entry, &mut warnings)? {
+                packages.push(package);
+            }
+        }
+        self.progress_reporter.report(&Messages::format(
+            msgs.progress_detected_packages,
+            &[&packages.len().to_string()],
+        ));
+
+        self.progress_reporter.report(msgs.progress_fetching_vulns);
+        let query: Vec<Package> = packages.iter().map(|ep| ep.package.clone()).collect();
+        let task = self
+            .progress_reporter
+            .start_task(ProgressPhase::VulnerabilityCheck, 0);
+        let checked = CheckVulnerabilitiesUseCase::new(self.vulnerability_repository.clone())
+            .with_progress(task.clone())
+            .check_with_progress(&query)
+            .await;
+        task.finish();
+        let outcome = checked?;
+
+        let (total_vulns, affected_packages) =
+            CheckVulnerabilitiesUseCase::<VREPO>::summarize(&outcome.results);
+        let unchecked_count = outcome.failed_packages.len();
+        self.record_unchecked_packages(&mut warnings, outcome.failed_packages);
+        self.record_incomplete_advisories(&mut warnings, outcome.incomplete_advisories);
+        if total_vulns > 0 {
+            self.progress_reporter.report_completion(&Messages::format(
+                msgs.progress_vuln_found,
+                &[&total_vulns.to_string(), &affected_packages.to_string()],
+            ));
+        } else if unchecked_count == 0 {
+            self.progress_reporter
+                .report_completion(msgs.progress_vuln_none);
+        } else {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.progress_vuln_incomplete,
+                &[&unchecked_count.to_string()],
+            ));
+        }
+
+        let mut vulnerability_check_result =
+            VulnerabilityChecker::check(outcome.results, threshold, ignore_cves);
+        for entry in std::mem::take(&mut vulnerability_check_result.ignored) {
+            let id = entry.vulnerability.id();
+            let message = match &entry.reason {
+                Some(reason) => Messages::format(
+                    msgs.progress_cve_ignored,
+                    &[id, &entry.package_name, reason],
+                ),
+                None => Messages::format(
+                    msgs.progress_cve_ignored_no_reason,
+                    &[id, &entry.package_name],
+                ),
+            };
+            self.progress_reporter.report(&message);
+        }
+
+        Ok(SbomScan {
+            packages,
+            vulnerability_check_result,
+            warnings,
+        })
+    }
+
+    /// Converts a component into a package to query, or records why it is skipped
+    fn scannable_package(
+        &self,
+        entry: PackageEntry,
+        warnings: &mut Vec<SbomWarning>,
+    ) -> Result<Option<EnrichedPackage>> {
+        if let Some(purl) = entry
+            .purl
+            .filter(|purl| !purl.starts_with(PYPI_PURL_PREFIX))
+        {
+            let msgs = Messages::for_locale(self.locale);
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_non_pypi_component,
+                &[&entry.name, &entry.version, &purl],
+            ));
+            warnings.push(SbomWarning::NonPypiComponent {
+                package: entry.name,
+                version: entry.version,
+                purl,
+            });
+            return Ok(None);
+        }
+        let package = Package::new(entry.name, entry.version)?;
+        Ok(Some(EnrichedPackage::new(package, entry.license, None)))
+    }
+
+    /// Records a warning for each package the vulnerability check could not query
+    fn record_unchecked_packages(
+        &self,
+        warnings: &mut Vec<SbomWarning>,
+        unchecked: Vec<UncheckedPackage>,
+    ) {
+        let msgs = Messages::for_locale(self.locale);
+        for UncheckedPackage { package, error } in unchecked {
+            let (name, version) = (package.name(), package.version());
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_vuln_check_failed,
+                &[name, version, &error],
+            ));
+            warnings.push(SbomWarning::VulnerabilityCheckFailed {
+                package: name.to_string(),
+                version: version.to_string(),
+                error,
+            });
+        }
+    }
+
+    /// Records a warning for each advisory reported without its details
+    fn record_incomplete_advisories(
+        &self,
+        warnings: &mut Vec<SbomWarning>,
+        incomplete: Vec<IncompleteAdvisory>,
+    ) {
+        let msgs = Messages::for_locale(self.locale);
+        for IncompleteAdvisory { id, error } in incomplete {
+            self.progress_reporter.report_error(&Messages::format(
+                msgs.warn_advisory_details_failed,
+                &[&id, &error],
+            ));
+            warnings.push(SbomWarning::AdvisoryDetailsFetchFailed { id, error });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::console::SilentProgressReporter;
+    use crate::application::use_cases::test_doubles::MockVulnerabilityRepository;
+    use crate::sbom_generation::domain::{PackageVulnerabilities, Severity, Vulnerability};
+
+    struct MockSbomReader {
+        components: Vec<PackageEntry>,
+    }
+
+    impl SbomReader for MockSbomReader {
+        fn read_packages(&self, _path: &Path) -> Result<Vec<PackageEntry>> {
+            Ok(self.components.clone())
+        }
+
+        fn read_components(&self, _path: &Path) -> Result<Vec<PackageEntry>> {
+            Ok(self.components.clone())
+        }
+    }
+
+    fn component(name: &str, version: &str, purl: Option<&str>) -> PackageEntry {
+        PackageEntry::new(name, version).with_purl(purl.map(String::from))
+    }
+
+    fn vulnerabilities(package: &str, version: &str, severity: Severity) -> PackageVulnerabilities {
+        let vulnerability = Vulnerability::new(
+            format!("CVE-2024-{}", package.len()),
+            None,
+            severity,
+            None,
+            None,
+        )
+        .unwrap();
+        PackageVulnerabilities::new(
+            package.to_string(),
+            version.to_string(),
+            vec![vulnerability],
+        )
+    }
+
+    fn use_case(
+        components: Vec<PackageEntry>,
+        repository: MockVulnerabilityRepository,
+    ) -> ScanSbomUseCase<MockSbomReader, MockVulnerabilityRepository, SilentProgressReporter> {
+        ScanSbomUseCase::new(
+            MockSbomReader { components },
+            repository,
+            SilentProgressReporter,
+            Locale::En,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_scan_evaluates_threshold() {
+        let repository = MockVulnerabilityRepository {
+            vulnerabilities: vec![
+                vulnerabilities("requests", "2.31.0", Severity::High),
+                vulnerabilities("urllib3", "2.0.0", Severity::Low),
+            ],
+            ..Default::default()
+        };
+        let components = vec![
+            component("requests", "2.31.0", Some("pkg:pypi/requests@2.31.0")),
+            component("urllib3", "2.0.0", None),
+        ];
+
+        let scan = use_case(components, repository)
+            .execute(
+                Path::new("sbom.json"),
+                ThresholdConfig::Severity(Severity::High),
+                &[],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(scan.packages.len(), 2);
+        assert!(scan.threshold_exceeded());
+        assert!(!scan.is_incomplete());
+        let result = &scan.vulnerability_check_result;
+        assert_eq!(result.above_threshold[0].package_name(), "requests");
+        assert_eq!(result.below_threshold[0].package_name(), "urllib3");
+    }
+
+    #[tokio::test]
+    async fn test_scan_skips_non_pypi_components() {
+        let repository = MockVulnerabilityRepository {
+            vulnerabilities: vec![vulnerabilities("left-pad", "1.3.0", Severity::Critical)],
+            ..Default::default()
+        };
+        let components = vec![
+            component("requests", "2.31.0", Some("pkg:pypi/requests@2.31.0")),
+            component("left-pad", "1.3.0", Some("pkg:npm/left-pad@1.3.0")),
+        ];
+
+        let scan = use_case(components, repository)
+            .execute(Path::new("sbom.json"), ThresholdConfig::None, &[])
+            .await
+            .unwrap();
+
+        assert_eq!(scan.packages.len(), 1);
+        assert!(!scan.threshold_exceeded());
+        assert_eq!(
+            scan.warnings,
+            vec![SbomWarning::NonPypiComponent {
+                package: "left-pad".to_string(),
+                version: "1.3.0".to_string(),
+                purl: "pkg:npm/left-pad@1.3.0".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scan_reports_unchecked_packages() {
+        let repository = MockVulnerabilityRepository {
+            failing_packages: vec!["requests".to_string()],
+            ..Default::default()
+        };
+        let components = vec![component("requests", "2.31.0", None)];
+
+        let scan = use_case(components, repository)
+            .execute(Path::new("sbom.json"), ThresholdConfig::None, &[])
+            .await
+            .unwrap();
+
+        assert!(scan.is_incomplete());
+        assert!(!scan.threshold_exceeded());
+    }
+}
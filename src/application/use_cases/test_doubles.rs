@@ -1,7 +1,9 @@
 use crate::ports::outbound::{
-    MaintenanceInfo, MaintenanceRepository, ProgressCallback, VulnerabilityRepository,
+    EndpointProbe, IncompleteAdvisory, MaintenanceInfo, MaintenanceRepository, ProgressCallback,
+    UncheckedPackage, VulnerabilityFetchOutcome, VulnerabilityRepository,
 };
 use crate::sbom_generation::domain::{Package, PackageVulnerabilities};
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 use async_trait::async_trait;
 use std::collections::VecDeque;
@@ -49,7 +51,7 @@ impl MaintenanceRepository for MockMaintenanceRepository {
         let next = self.queue.lock().unwrap().pop_front();
         match next {
             Some(Ok(info)) => Ok(info),
-            Some(Err(msg)) => Err(anyhow::anyhow!("{}", msg)),
+            Some(Err(msg)) => Err(UvSbomError::other(msg)),
             None => Ok(MaintenanceInfo {
                 last_release_date: None,
             }),
@@ -59,11 +61,21 @@ impl MaintenanceRepository for MockMaintenanceRepository {
 
 /// Configurable in-memory mock implementing `VulnerabilityRepository`.
 ///
-/// Use `MockVulnerabilityRepository::new()` for the empty variant returning `Ok(vec![])`.
+/// Use `MockVulnerabilityRepository::new()` for the empty variant returning an empty outcome.
 /// Use `MockVulnerabilityRepository { vulnerabilities: ... }` for tests that need pre-loaded data.
+/// Packages named in `failing_packages` are reported as unchecked,
+/// the advisory IDs in `incomplete_advisories` as fetched without details,
+/// `cached_packages` is reported as the number of cache hits, and
+/// `probes` are returned as the connectivity probe results. Every queried
+/// package name is appended to `queried`, shared between clones.
 #[derive(Clone, Default)]
 pub(crate) struct MockVulnerabilityRepository {
     pub vulnerabilities: Vec<PackageVulnerabilities>,
+    pub failing_packages: Vec<String>,
+    pub incomplete_advisories: Vec<String>,
+    pub cached_packages: usize,
+    pub probes: Vec<EndpointProbe>,
+    pub queried: Arc<Mutex<Vec<String>>>,
 }
 
 impl MockVulnerabilityRepository {
@@ -76,16 +88,56 @@ impl MockVulnerabilityRepository {
 impl VulnerabilityRepository for MockVulnerabilityRepository {
     async fn fetch_vulnerabilities(
         &self,
-        _packages: Vec<Package>,
-    ) -> Result<Vec<PackageVulnerabilities>> {
-        Ok(self.vulnerabilities.clone())
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
+        self.queried
+            .lock()
+            .unwrap()
+            .extend(packages.iter().map(|p| p.name().to_string()));
+        let (failed, checked): (Vec<&Package>, Vec<&Package>) = packages
+            .iter()
+            .partition(|p| self.failing_packages.iter().any(|name| name == p.name()));
+        // Like the real repository, only report packages that were actually queried
+        let results = self
+            .vulnerabilities
+            .iter()
+            .filter(|pv| {
+                checked
+                    .iter()
+                    .any(|p| p.name() == pv.package_name() && p.version() == pv.current_version())
+            })
+            .cloned()
+            .collect();
+        Ok(VulnerabilityFetchOutcome {
+            results,
+            failed_packages: failed
+                .into_iter()
+                .map(|package| UncheckedPackage {
+                    package: package.clone(),
+                    error: "OSV API returned status code 503".to_string(),
+                })
+                .collect(),
+            incomplete_advisories: self
+                .incomplete_advisories
+                .iter()
+                .map(|id| IncompleteAdvisory {
+                    id: id.clone(),
+                    error: "OSV API returned status code 503".to_string(),
+                })
+                .collect(),
+            cached_packages: self.cached_packages,
+        })
+    }
+
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        self.probes.clone()
     }
 
     async fn fetch_vulnerabilities_with_progress(
         &self,
-        _packages: Vec<Package>,
+        packages: &[Package],
         _progress_callback: ProgressCallback<'static>,
-    ) -> Result<Vec<PackageVulnerabilities>> {
-        Ok(self.vulnerabilities.clone())
+    ) -> Result<VulnerabilityFetchOutcome> {
+        self.fetch_vulnerabilities(packages).await
     }
 }
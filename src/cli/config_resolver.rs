@@ -1,32 +1,93 @@
-use crate::application::dto::OutputFormat;
+use crate::adapters::outbound::formatters::SpecVersion;
+use crate::adapters::outbound::network::{NetworkConfig, OsvClient, RetryConfig};
+use crate::application::dto::{ExcludeScope, OutputFormat};
 use crate::sbom_generation::domain::license_policy::{LicensePolicy, UnknownLicenseHandling};
+use crate::sbom_generation::domain::services::TyposquatChecker;
 use crate::sbom_generation::domain::vulnerability::Severity;
-use crate::shared::Result;
+use crate::sbom_generation::domain::{CopyleftLicenses, LicenseOverride};
+use crate::sbom_generation::policies::{
+    Compatibility, LicenseCompatibilityPolicy, LicenseFamily, LicensePriority,
+};
 use std::collections::HashSet;
-use uv_sbom::config::{self, ConfigFile, IgnoreCve};
+use std::path::PathBuf;
+use uv_sbom::config::{self, ConfigFile, IgnoreCve, LicenseCompatibilityConfig};
+use uv_sbom::shared::error::UvSbomError;
+use uv_sbom::shared::Result;
 
 use super::Args;
 
 /// Merged configuration after combining CLI arguments and config file values.
 pub struct MergedConfig {
+    /// The first of `formats`, used when a single output is written
     pub format: OutputFormat,
+    /// Every selected format in order, without duplicates
+    pub formats: Vec<OutputFormat>,
     pub exclude_patterns: Vec<String>,
+    /// Whether excluded packages are still checked for vulnerabilities
+    pub exclude_scope: ExcludeScope,
     pub check_cve: bool,
     pub severity_threshold: Option<Severity>,
     pub cvss_threshold: Option<f32>,
     pub ignore_cves: Vec<IgnoreCve>,
+    /// Emit ignored CVEs as VEX entries instead of dropping them
+    pub vex: bool,
+    /// Skip all network access
+    pub offline: bool,
+    /// Local PyPA advisory database, with `~` expanded
+    pub advisory_db: Option<PathBuf>,
     pub check_license: bool,
     pub license_policy: Option<LicensePolicy>,
+    pub check_license_compatibility: bool,
+    pub fail_on_license_incompatibility: bool,
+    pub license_compatibility_policy: LicenseCompatibilityPolicy,
+    /// Project license from the config file; `None` means read it from pyproject.toml
+    pub project_license: Option<String>,
+    /// Per-package licenses from the config file (there is no CLI equivalent)
+    pub license_overrides: Vec<LicenseOverride>,
+    /// How the license is picked from PyPI metadata (there is no CLI equivalent)
+    pub license_priority: LicensePriority,
+    pub check_license_reachability: bool,
+    pub strict_license_reachability: bool,
+    /// Copyleft set from the config file (there is no CLI equivalent)
+    pub copyleft_licenses: CopyleftLicenses,
+    pub check_python_compat: bool,
+    pub check_typosquats: bool,
+    pub fail_on_typosquat: bool,
+    pub typosquat_max_distance: usize,
+    /// Package names the typosquat check never reports (there is no CLI equivalent)
+    pub ignore_typosquats: Vec<String>,
+    pub check_self: bool,
+    pub self_check_fails_build: bool,
     pub suggest_fix: bool,
+    pub suggest_fixes: bool,
     pub check_abandoned: bool,
     pub abandoned_threshold_days: u64,
+    /// Retry policy for transient PyPI / OSV failures
+    pub retry: RetryConfig,
+    /// Proxy, CA bundle and timeout for PyPI / OSV requests
+    pub network: NetworkConfig,
+    /// OSV advisory detail requests in flight at once
+    pub osv_detail_workers: usize,
+    /// Package index for license lookups; `None` means pypi.org
+    pub index_url: Option<String>,
+    /// Package lookups per run; `None` means no limit
+    pub max_network_requests: Option<usize>,
+    /// CycloneDX spec version of the JSON output
+    pub spec_version: SpecVersion,
 }
 
 /// Load a config file from an explicit path or via auto-discovery.
 pub fn load_config(args: &Args, project_path: &std::path::Path) -> Result<Option<ConfigFile>> {
     if let Some(ref config_path) = args.config {
-        let path = std::path::Path::new(config_path);
-        let cfg = config::load_config_from_path(path)?;
+        let path = super::path_resolver::expand_tilde(config_path);
+        if let Some(problem) = super::path_resolver::classify_file(&path) {
+            return Err(UvSbomError::InvalidPath {
+                option: "--config",
+                path: PathBuf::from(config_path),
+                reason: problem.file_reason(),
+            });
+        }
+        let cfg = config::load_config_from_path(&path)?;
         eprintln!("📄 Loaded config from: {}", path.display());
         Ok(Some(cfg))
     } else {
@@ -38,6 +99,53 @@ pub fn load_config(args: &Args, project_path: &std::path::Path) -> Result<Option
     }
 }
 
+/// Path of the config file `load_config` reads: `--config`, or the file
+/// auto-discovered in the project directory. `None` when there is neither.
+pub fn config_file_path(args: &Args, project_path: &std::path::Path) -> Option<PathBuf> {
+    match &args.config {
+        Some(config_path) => Some(super::path_resolver::expand_tilde(config_path)),
+        None => Some(project_path.join(config::CONFIG_FILENAME)).filter(|path| path.exists()),
+    }
+}
+
+/// Rejects a run that ends up with both a severity and a CVSS threshold.
+///
+/// Each threshold is taken from the command line when given there and from the
+/// config file otherwise, and the two sources are checked together: the config
+/// file may set one kind while the command line sets the other. The error names
+/// where each threshold came from.
+pub fn check_threshold_sources(args: &Args, config: &Option<ConfigFile>) -> Result<()> {
+    let config = config.as_ref();
+    let severity_source = if args.severity_threshold.is_some() {
+        Some("--severity-threshold on the command line")
+    } else {
+        config
+            .and_then(|c| c.severity_threshold.as_ref())
+            .map(|_| "severity_threshold in the config file")
+    };
+    let cvss_source = if args.cvss_threshold.is_some() {
+        Some("--cvss-threshold on the command line")
+    } else {
+        config
+            .and_then(|c| c.cvss_threshold)
+            .map(|_| "cvss_threshold in the config file")
+    };
+
+    match (severity_source, cvss_source) {
+        (Some(severity), Some(cvss)) => Err(UvSbomError::ConfigInvalid {
+            message: format!(
+                "A severity threshold and a CVSS threshold cannot be used together: \
+                 the severity threshold comes from {} and the CVSS threshold from {}.\n\n\
+                 💡 Hint: Keep only one of them. A command-line threshold only replaces the \
+                 config file threshold of the same kind.",
+                severity, cvss
+            ),
+            source: None,
+        }),
+        _ => Ok(()),
+    }
+}
+
 /// Merge two string lists and deduplicate.
 pub fn merge_string_lists(cli: &[String], config: &Option<Vec<String>>) -> Vec<String> {
     let mut seen = HashSet::new();
@@ -62,6 +170,17 @@ pub fn merge_string_lists(cli: &[String], config: &Option<Vec<String>>) -> Vec<S
     result
 }
 
+/// Deduplicates formats, keeping the first occurrence of each.
+fn dedup_formats(formats: impl IntoIterator<Item = OutputFormat>) -> Vec<OutputFormat> {
+    let mut result = Vec::new();
+    for format in formats {
+        if !result.contains(&format) {
+            result.push(format);
+        }
+    }
+    result
+}
+
 /// Merge two ignore_cves lists and deduplicate by ID (CLI entries take precedence).
 pub fn merge_ignore_cves(cli: &[IgnoreCve], config: &Option<Vec<IgnoreCve>>) -> Vec<IgnoreCve> {
     let mut seen = HashSet::new();
@@ -112,12 +231,20 @@ pub fn merge_config(args: &Args, config: &Option<ConfigFile>) -> MergedConfig {
                 ))
             } else {
                 None
-            };
+            }
+            .map(|policy| apply_fail_on_unknown_license(policy, args));
 
+            let formats = dedup_formats(args.format.iter().copied());
             return MergedConfig {
-                format: args.format,
+                format: formats[0],
+                formats,
                 exclude_patterns: args.exclude.clone(),
-                check_cve: !args.no_check_cve,
+                exclude_scope: args.exclude_scope.unwrap_or_default(),
+                // Offline turns the default CVE check off unless a local advisory database
+                // is given; an explicit --check-cve is kept so the request fails validation
+                // instead of silently dropping it
+                check_cve: !args.no_check_cve
+                    && (!args.offline || args.check_cve || args.advisory_db.is_some()),
                 severity_threshold: args.severity_threshold,
                 cvss_threshold: args.cvss_threshold,
                 ignore_cves: args
@@ -126,13 +253,45 @@ pub fn merge_config(args: &Args, config: &Option<ConfigFile>) -> MergedConfig {
                     .map(|id| IgnoreCve {
                         id: id.clone(),
                         reason: None,
+                        state: None,
                     })
                     .collect(),
+                vex: args.vex,
+                offline: args.offline,
+                advisory_db: args
+                    .advisory_db
+                    .as_deref()
+                    .map(super::path_resolver::expand_tilde),
                 check_license: args.check_license,
                 license_policy,
+                check_license_compatibility: args.check_license_compatibility,
+                fail_on_license_incompatibility: args.fail_on_license_incompatibility,
+                license_compatibility_policy: LicenseCompatibilityPolicy::default(),
+                project_license: None,
+                license_overrides: Vec::new(),
+                license_priority: LicensePriority::default(),
+                check_license_reachability: args.check_license_reachability,
+                strict_license_reachability: args.strict_license_reachability,
+                copyleft_licenses: CopyleftLicenses::default(),
+                check_python_compat: args.check_python_compat,
+                check_typosquats: args.check_typosquats,
+                fail_on_typosquat: args.fail_on_typosquat,
+                typosquat_max_distance: args
+                    .typosquat_max_distance
+                    .unwrap_or(TyposquatChecker::DEFAULT_MAX_DISTANCE),
+                ignore_typosquats: Vec::new(),
+                check_self: args.check_self,
+                self_check_fails_build: !args.self_check_no_fail,
                 suggest_fix: args.suggest_fix,
+                suggest_fixes: args.suggest_fixes,
                 check_abandoned: args.check_abandoned,
                 abandoned_threshold_days: args.abandoned_threshold_days.unwrap_or(730),
+                retry: RetryConfig::default(),
+                network: merge_network_config(args, None),
+                osv_detail_workers: OsvClient::DEFAULT_DETAIL_WORKERS,
+                index_url: args.index_url.clone(),
+                max_network_requests: args.max_network_requests,
+                spec_version: args.spec_version.unwrap_or_default(),
             };
         }
     };
@@ -140,6 +299,12 @@ pub fn merge_config(args: &Args, config: &Option<ConfigFile>) -> MergedConfig {
     // Merge exclude_patterns: combine both sources, deduplicate
     let exclude_patterns = merge_string_lists(&args.exclude, &config.exclude_packages);
 
+    // exclude_scope: CLI > config > default (all); the config value was validated on load
+    let exclude_scope = args
+        .exclude_scope
+        .or_else(|| config.exclude_scope.as_deref()?.parse().ok())
+        .unwrap_or_default();
+
     // Merge ignore_cves: combine both sources, deduplicate by ID
     let cli_ignore_cves: Vec<IgnoreCve> = args
         .ignore_cve
@@ -147,6 +312,7 @@ pub fn merge_config(args: &Args, config: &Option<ConfigFile>) -> MergedConfig {
         .map(|id| IgnoreCve {
             id: id.clone(),
             reason: None,
+            state: None,
         })
         .collect();
     let ignore_cves = merge_ignore_cves(&cli_ignore_cves, &config.ignore_cves);
@@ -156,23 +322,38 @@ pub fn merge_config(args: &Args, config: &Option<ConfigFile>) -> MergedConfig {
     // provided it by comparing against the default. However, since clap's default_value means
     // args.format is always set, we use config only when format is json (default) and config
     // provides a different value.
-    let format = if let Some(ref config_format) = config.format {
+    // The config `formats` list takes precedence over `format`; its values were validated on load.
+    let formats = if args.format != [OutputFormat::Json] {
+        dedup_formats(args.format.iter().copied())
+    } else if let Some(ref config_formats) = config.formats {
+        dedup_formats(config_formats.iter().filter_map(|f| f.parse().ok()))
+    } else if let Some(ref config_format) = config.format {
         // If user didn't explicitly pass --format, use config value
         // clap default is "json", so if args.format == Json, config might override
         // But we can't distinguish "user passed --format json" from "default json"
         // Convention: CLI always wins since clap provides the value
-        if args.format != OutputFormat::Json {
-            args.format
-        } else {
-            config_format.parse::<OutputFormat>().unwrap_or(args.format)
-        }
+        vec![config_format
+            .parse::<OutputFormat>()
+            .unwrap_or(OutputFormat::Json)]
     } else {
-        args.format
+        vec![OutputFormat::Json]
     };
+    let format = formats[0];
+
+    // advisory_db: CLI > config > None
+    let advisory_db = args
+        .advisory_db
+        .as_deref()
+        .or(config.advisory_db.as_deref())
+        .map(super::path_resolver::expand_tilde);
 
-    // check_cve: CLI opt-out takes highest priority; otherwise use config value (default true)
+    // check_cve: CLI opt-out takes highest priority; otherwise use config value (default true).
+    // Offline turns the default off unless a local advisory database is given, but keeps
+    // an explicit request so it fails validation.
     let check_cve = if args.no_check_cve {
         false
+    } else if args.offline {
+        args.check_cve || config.check_cve == Some(true) || advisory_db.is_some()
     } else {
         config.check_cve.unwrap_or(true)
     };
@@ -232,11 +413,93 @@ pub fn merge_config(args: &Args, config: &Option<ConfigFile>) -> MergedConfig {
         }
     } else {
         None
-    };
+    }
+    .map(|policy| apply_fail_on_unknown_license(policy, args));
+
+    // check_license_compatibility / fail_on_license_incompatibility: CLI flag || config value
+    let check_license_compatibility =
+        args.check_license_compatibility || config.check_license_compatibility.unwrap_or(false);
+    let fail_on_license_incompatibility = args.fail_on_license_incompatibility
+        || config.fail_on_license_incompatibility.unwrap_or(false);
+
+    // check_license_reachability / strict_license_reachability: CLI flag || config value
+    let check_license_reachability =
+        args.check_license_reachability || config.check_license_reachability.unwrap_or(false);
+    let strict_license_reachability =
+        args.strict_license_reachability || config.strict_license_reachability.unwrap_or(false);
+
+    // copyleft_licenses: the config list replaces the default set
+    let copyleft_licenses = config
+        .copyleft_licenses
+        .as_deref()
+        .map(CopyleftLicenses::new)
+        .unwrap_or_default();
+
+    // check_python_compat: CLI flag || config value
+    let check_python_compat =
+        args.check_python_compat || config.check_python_compat.unwrap_or(false);
+
+    // check_typosquats / fail_on_typosquat: CLI flag || config value
+    let check_typosquats = args.check_typosquats || config.check_typosquats.unwrap_or(false);
+    let fail_on_typosquat = args.fail_on_typosquat || config.fail_on_typosquat.unwrap_or(false);
+
+    // typosquat_max_distance: CLI > config > default
+    let typosquat_max_distance = args
+        .typosquat_max_distance
+        .or(config.typosquat_max_distance)
+        .unwrap_or(TyposquatChecker::DEFAULT_MAX_DISTANCE);
+
+    // ignore_typosquats: config only
+    let ignore_typosquats = config.ignore_typosquats.clone().unwrap_or_default();
+
+    // license_compatibility: config overrides on top of the embedded default matrix
+    let license_compatibility_policy =
+        build_license_compatibility_policy(config.license_compatibility.as_ref());
+    let project_license = config
+        .license_compatibility
+        .as_ref()
+        .and_then(|lc| lc.project_license.clone());
+
+    // license_overrides: config only
+    let license_overrides = config
+        .license_overrides
+        .iter()
+        .flatten()
+        .map(|(package, entry)| {
+            LicenseOverride::new(package.clone(), entry.license.clone(), entry.reason.clone())
+        })
+        .collect();
+
+    // license_resolution / license_max_length: config only; validated on load
+    let license_priority = LicensePriority::from_policy(
+        config
+            .license_resolution
+            .as_deref()
+            .and_then(|resolution| resolution.parse().ok())
+            .unwrap_or_default(),
+    )
+    .with_max_license_length(
+        config
+            .license_max_length
+            .unwrap_or(LicensePriority::DEFAULT_MAX_LICENSE_LENGTH),
+    );
+
+    // vex: CLI flag || config value
+    let vex = args.vex || config.vex.unwrap_or(false);
+
+    // check_self: CLI flag || config value
+    let check_self = args.check_self || config.check_self.unwrap_or(false);
+
+    // self_check_fails_build: --self-check-no-fail always wins; otherwise config, default true
+    let self_check_fails_build =
+        !args.self_check_no_fail && config.self_check_fails_build.unwrap_or(true);
 
     // suggest_fix: CLI flag takes priority over config value
     let suggest_fix = args.suggest_fix || config.suggest_fix.unwrap_or(false);
 
+    // suggest_fixes: CLI flag || config value
+    let suggest_fixes = args.suggest_fixes || config.suggest_fixes.unwrap_or(false);
+
     // check_abandoned: CLI flag || config value (mirrors check_license / suggest_fix)
     let check_abandoned = args.check_abandoned || config.check_abandoned.unwrap_or(false);
 
@@ -248,28 +511,181 @@ pub fn merge_config(args: &Args, config: &Option<ConfigFile>) -> MergedConfig {
         .or(config.abandoned_threshold_days)
         .unwrap_or(730);
 
+    // network: config only, defaults for missing values
+    let retry = config
+        .network
+        .as_ref()
+        .map_or_else(RetryConfig::default, |network| {
+            RetryConfig::from_settings(network.max_retries, network.retry_base_delay_ms)
+        });
+    let network = merge_network_config(args, config.network.as_ref());
+    let osv_detail_workers = config
+        .network
+        .as_ref()
+        .and_then(|network| network.osv_detail_workers)
+        .unwrap_or(OsvClient::DEFAULT_DETAIL_WORKERS);
+    // index_url: CLI (or UV_SBOM_INDEX_URL) > config
+    let index_url = args.index_url.clone().or_else(|| {
+        config
+            .network
+            .as_ref()
+            .and_then(|network| network.index_url.clone())
+    });
+    // max_network_requests: CLI > config
+    let max_network_requests = args.max_network_requests.or_else(|| {
+        config
+            .network
+            .as_ref()
+            .and_then(|network| network.max_requests)
+    });
+
+    // spec_version: CLI > config > default (1.6); the config value was validated on load
+    let spec_version = args
+        .spec_version
+        .or_else(|| {
+            config
+                .cyclonedx
+                .as_ref()
+                .and_then(|c| c.spec_version.as_deref())
+                .and_then(SpecVersion::parse)
+        })
+        .unwrap_or_default();
+
     MergedConfig {
         format,
+        formats,
         exclude_patterns,
+        exclude_scope,
         check_cve,
         severity_threshold,
         cvss_threshold,
         ignore_cves,
+        vex,
+        offline: args.offline,
+        advisory_db,
         check_license,
         license_policy,
+        check_license_compatibility,
+        fail_on_license_incompatibility,
+        license_compatibility_policy,
+        project_license,
+        license_overrides,
+        license_priority,
+        check_license_reachability,
+        strict_license_reachability,
+        copyleft_licenses,
+        check_python_compat,
+        check_typosquats,
+        fail_on_typosquat,
+        typosquat_max_distance,
+        ignore_typosquats,
+        check_self,
+        self_check_fails_build,
         suggest_fix,
+        suggest_fixes,
         check_abandoned,
         abandoned_threshold_days,
+        retry,
+        osv_detail_workers,
+        network,
+        index_url,
+        max_network_requests,
+        spec_version,
+    }
+}
+
+/// Transport settings: `--ca-bundle` (or `UV_SBOM_CA_BUNDLE`) overrides
+/// `network.ca_bundle_path` and `--user-agent-suffix` (or `UV_SBOM_CONTACT`)
+/// overrides `network.user_agent_suffix`; proxy and timeout come from the
+/// config file only.
+fn merge_network_config(args: &Args, config: Option<&config::NetworkConfig>) -> NetworkConfig {
+    let ca_bundle = args
+        .ca_bundle
+        .as_deref()
+        .or_else(|| config.and_then(|c| c.ca_bundle_path.as_deref()));
+    NetworkConfig {
+        proxy_url: config.and_then(|c| c.proxy_url.clone()),
+        ca_bundle_path: ca_bundle.map(super::path_resolver::expand_tilde),
+        timeout_secs: config.and_then(|c| c.timeout_secs),
+        user_agent_suffix: args
+            .user_agent_suffix
+            .clone()
+            .or_else(|| config.and_then(|c| c.user_agent_suffix.clone())),
+    }
+}
+
+/// `--fail-on-unknown-license` makes unknown licenses violations, whichever
+/// source the rest of the policy came from.
+fn apply_fail_on_unknown_license(mut policy: LicensePolicy, args: &Args) -> LicensePolicy {
+    if args.fail_on_unknown_license {
+        policy.unknown = UnknownLicenseHandling::Deny;
+    }
+    policy
+}
+
+/// Applies config file overrides to the default license compatibility matrix.
+///
+/// Identifiers were validated when the config was loaded; unknown ones are skipped.
+fn build_license_compatibility_policy(
+    config: Option<&LicenseCompatibilityConfig>,
+) -> LicenseCompatibilityPolicy {
+    let mut policy = LicenseCompatibilityPolicy::default();
+    let Some(config) = config else {
+        return policy;
+    };
+
+    for (family, licenses) in config.families.iter().flatten() {
+        if let Some(family) = LicenseFamily::parse(family) {
+            for license in licenses {
+                policy = policy.with_family(license, family);
+            }
+        }
+    }
+    for (project, row) in config.matrix.iter().flatten() {
+        for (dependency, verdict) in row {
+            if let (Some(project), Some(dependency), Some(verdict)) = (
+                LicenseFamily::parse(project),
+                LicenseFamily::parse(dependency),
+                Compatibility::parse(verdict),
+            ) {
+                policy = policy.with_rule(project, dependency, verdict);
+            }
+        }
     }
+    for (license, verdict) in config.licenses.iter().flatten() {
+        if let Some(verdict) = Compatibility::parse(verdict) {
+            policy = policy.with_license_verdict(license, verdict);
+        }
+    }
+    policy
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use clap::Parser;
+    use uv_sbom::config::LicensePolicyConfig;
 
     // --- merge_config tests ---
 
+    #[test]
+    fn test_load_config_missing_explicit_file() {
+        let args = Args::parse_from(["uv-sbom", "--config", "does-not-exist-xyz.yml"]);
+        let err = load_config(&args, std::path::Path::new(".")).unwrap_err();
+        match err {
+            UvSbomError::InvalidPath {
+                option,
+                path,
+                reason,
+            } => {
+                assert_eq!(option, "--config");
+                assert_eq!(path, PathBuf::from("does-not-exist-xyz.yml"));
+                assert_eq!(reason, "File does not exist");
+            }
+            other => panic!("expected InvalidPath, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_merge_config_no_config_file() {
         let args = Args::parse_from(["uv-sbom"]);
@@ -296,6 +712,7 @@ mod tests {
             ignore_cves: Some(vec![IgnoreCve {
                 id: "CVE-2024-1".to_string(),
                 reason: Some("not applicable".to_string()),
+                state: None,
             }]),
             ..Default::default()
         });
@@ -311,6 +728,193 @@ mod tests {
         assert_eq!(result.abandoned_threshold_days, 730);
     }
 
+    #[test]
+    fn test_merge_config_network_retry_settings() {
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(merge_config(&args, &None).retry, RetryConfig::default());
+
+        let config = Some(ConfigFile {
+            network: Some(config::NetworkConfig {
+                max_retries: Some(0),
+                retry_base_delay_ms: Some(10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert_eq!(result.retry.max_attempts, 1);
+        assert_eq!(
+            result.retry.base_delay,
+            std::time::Duration::from_millis(10)
+        );
+        assert_eq!(result.osv_detail_workers, OsvClient::DEFAULT_DETAIL_WORKERS);
+
+        let config = Some(ConfigFile {
+            network: Some(config::NetworkConfig {
+                osv_detail_workers: Some(8),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        assert_eq!(merge_config(&args, &config).osv_detail_workers, 8);
+    }
+
+    #[test]
+    fn test_merge_config_index_url() {
+        let config = Some(ConfigFile {
+            network: Some(config::NetworkConfig {
+                index_url: Some("https://config.example.com/".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(
+            merge_config(&args, &config).index_url.as_deref(),
+            Some("https://config.example.com/")
+        );
+
+        // --index-url wins over the config file
+        let args = Args::parse_from(["uv-sbom", "--index-url", "https://cli.example.com"]);
+        assert_eq!(
+            merge_config(&args, &config).index_url.as_deref(),
+            Some("https://cli.example.com")
+        );
+        assert_eq!(
+            merge_config(&args, &None).index_url.as_deref(),
+            Some("https://cli.example.com")
+        );
+    }
+
+    #[test]
+    fn test_merge_config_network_transport_settings() {
+        let config = Some(ConfigFile {
+            network: Some(config::NetworkConfig {
+                proxy_url: Some("http://proxy.internal:3128".to_string()),
+                ca_bundle_path: Some("/etc/ssl/config-ca.pem".to_string()),
+                timeout_secs: Some(60),
+                user_agent_suffix: Some("(+mailto:config@example.com)".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let args = Args::parse_from(["uv-sbom"]);
+        let result = merge_config(&args, &config);
+        assert_eq!(
+            result.network,
+            NetworkConfig {
+                proxy_url: Some("http://proxy.internal:3128".to_string()),
+                ca_bundle_path: Some("/etc/ssl/config-ca.pem".into()),
+                timeout_secs: Some(60),
+                user_agent_suffix: Some("(+mailto:config@example.com)".to_string()),
+            }
+        );
+
+        // --ca-bundle wins over the config file
+        let args = Args::parse_from(["uv-sbom", "--ca-bundle", "/etc/ssl/cli-ca.pem"]);
+        let result = merge_config(&args, &config);
+        assert_eq!(
+            result.network.ca_bundle_path,
+            Some("/etc/ssl/cli-ca.pem".into())
+        );
+        assert_eq!(merge_config(&args, &None).network.timeout_secs, None);
+
+        // --user-agent-suffix wins over the config file
+        let args = Args::parse_from([
+            "uv-sbom",
+            "--user-agent-suffix",
+            "(+mailto:cli@example.com)",
+        ]);
+        assert_eq!(
+            merge_config(&args, &config)
+                .network
+                .user_agent_suffix
+                .as_deref(),
+            Some("(+mailto:cli@example.com)")
+        );
+    }
+
+    #[test]
+    fn test_merge_config_spec_version() {
+        let config = Some(ConfigFile {
+            cyclonedx: Some(config::CycloneDxConfig {
+                spec_version: Some("1.5".to_string()),
+            }),
+            ..Default::default()
+        });
+
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(merge_config(&args, &None).spec_version, SpecVersion::V1_6);
+        assert_eq!(merge_config(&args, &config).spec_version, SpecVersion::V1_5);
+
+        let args = Args::parse_from(["uv-sbom", "--spec-version", "1.4"]);
+        assert_eq!(merge_config(&args, &config).spec_version, SpecVersion::V1_4);
+    }
+
+    #[test]
+    fn test_merge_config_license_priority() {
+        use crate::sbom_generation::policies::LicenseResolution;
+
+        let config = Some(ConfigFile {
+            license_resolution: Some("spdx_only".to_string()),
+            license_max_length: Some(64),
+            ..Default::default()
+        });
+
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(
+            merge_config(&args, &None).license_priority,
+            LicensePriority::default()
+        );
+        assert_eq!(
+            merge_config(&args, &config).license_priority,
+            LicensePriority::from_policy(LicenseResolution::SpdxOnly).with_max_license_length(64)
+        );
+    }
+
+    #[test]
+    fn test_merge_config_max_network_requests() {
+        let config = Some(ConfigFile {
+            network: Some(config::NetworkConfig {
+                max_requests: Some(500),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(merge_config(&args, &None).max_network_requests, None);
+        assert_eq!(merge_config(&args, &config).max_network_requests, Some(500));
+
+        // --max-network-requests wins over the config file
+        let args = Args::parse_from(["uv-sbom", "--max-network-requests", "20"]);
+        assert_eq!(merge_config(&args, &config).max_network_requests, Some(20));
+        assert_eq!(merge_config(&args, &None).max_network_requests, Some(20));
+    }
+
+    #[test]
+    fn test_merge_config_exclude_scope() {
+        let config = Some(ConfigFile {
+            exclude_scope: Some("output".to_string()),
+            ..Default::default()
+        });
+
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(merge_config(&args, &None).exclude_scope, ExcludeScope::All);
+        assert_eq!(
+            merge_config(&args, &config).exclude_scope,
+            ExcludeScope::Output
+        );
+
+        let args = Args::parse_from(["uv-sbom", "--exclude-scope", "all"]);
+        assert_eq!(
+            merge_config(&args, &config).exclude_scope,
+            ExcludeScope::All
+        );
+    }
+
     #[test]
     fn test_merge_config_cli_overrides_format() {
         let args = Args::parse_from(["uv-sbom", "--format", "markdown"]);
@@ -322,6 +926,39 @@ mod tests {
         assert_eq!(result.format, OutputFormat::Markdown);
     }
 
+    #[test]
+    fn test_merge_config_formats_from_config() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            format: Some("html".to_string()),
+            formats: Some(vec![
+                "markdown".to_string(),
+                "json".to_string(),
+                "md".to_string(),
+            ]),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert_eq!(
+            result.formats,
+            vec![OutputFormat::Markdown, OutputFormat::Json]
+        );
+        assert_eq!(result.format, OutputFormat::Markdown);
+    }
+
+    #[test]
+    fn test_merge_config_cli_formats_override_config() {
+        let args = Args::parse_from(["uv-sbom", "-f", "html", "-f", "json", "-f", "html"]);
+        let config = Some(ConfigFile {
+            formats: Some(vec!["markdown".to_string()]),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert_eq!(result.formats, vec![OutputFormat::Html, OutputFormat::Json]);
+        assert_eq!(result.format, OutputFormat::Html);
+        assert_eq!(merge_config(&args, &None).formats, result.formats);
+    }
+
     #[test]
     fn test_merge_config_no_check_cve_cli_flag() {
         let args = Args::parse_from(["uv-sbom", "--no-check-cve"]);
@@ -375,10 +1012,12 @@ mod tests {
                 IgnoreCve {
                     id: "CVE-2024-1".to_string(),
                     reason: Some("config reason".to_string()),
+                    state: None,
                 },
                 IgnoreCve {
                     id: "CVE-2024-2".to_string(),
                     reason: None,
+                    state: None,
                 },
             ]),
             ..Default::default()
@@ -435,6 +1074,46 @@ mod tests {
         assert_eq!(result.cvss_threshold, Some(6.0));
     }
 
+    #[test]
+    fn test_check_threshold_sources_accepts_single_threshold() {
+        let args = Args::parse_from(["uv-sbom", "--severity-threshold", "high"]);
+        let config = Some(ConfigFile {
+            severity_threshold: Some("low".to_string()),
+            ..Default::default()
+        });
+        assert!(check_threshold_sources(&args, &config).is_ok());
+        assert!(check_threshold_sources(&Args::parse_from(["uv-sbom"]), &None).is_ok());
+    }
+
+    #[test]
+    fn test_check_threshold_sources_rejects_both_in_config() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            severity_threshold: Some("high".to_string()),
+            cvss_threshold: Some(7.0),
+            ..Default::default()
+        });
+        let err = check_threshold_sources(&args, &config)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("severity_threshold in the config file"));
+        assert!(err.contains("cvss_threshold in the config file"));
+    }
+
+    #[test]
+    fn test_check_threshold_sources_rejects_cli_and_config_mix() {
+        let args = Args::parse_from(["uv-sbom", "--cvss-threshold", "7.0"]);
+        let config = Some(ConfigFile {
+            severity_threshold: Some("high".to_string()),
+            ..Default::default()
+        });
+        let err = check_threshold_sources(&args, &config)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("severity_threshold in the config file"));
+        assert!(err.contains("--cvss-threshold on the command line"));
+    }
+
     // --- suggest_fix merge tests ---
 
     #[test]
@@ -484,6 +1163,152 @@ mod tests {
         assert!(!result.suggest_fix);
     }
 
+    // --- suggest_fixes merge tests ---
+
+    #[test]
+    fn test_merge_config_suggest_fixes_from_config_or_cli() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            suggest_fixes: Some(true),
+            ..Default::default()
+        });
+        assert!(merge_config(&args, &config).suggest_fixes);
+
+        let args = Args::parse_from(["uv-sbom", "--suggest-fixes"]);
+        let config = Some(ConfigFile {
+            suggest_fixes: Some(false),
+            ..Default::default()
+        });
+        assert!(merge_config(&args, &config).suggest_fixes);
+
+        let args = Args::parse_from(["uv-sbom"]);
+        assert!(!merge_config(&args, &None).suggest_fixes);
+    }
+
+    // --- fail_on_unknown_license tests ---
+
+    #[test]
+    fn test_merge_config_fail_on_unknown_license_without_config() {
+        let args = Args::parse_from([
+            "uv-sbom",
+            "--check-license",
+            "--license-deny",
+            "GPL-*",
+            "--fail-on-unknown-license",
+        ]);
+        let policy = merge_config(&args, &None).license_policy.unwrap();
+        assert_eq!(policy.unknown, UnknownLicenseHandling::Deny);
+        assert_eq!(policy.deny.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_config_fail_on_unknown_license_overrides_config_policy() {
+        let args = Args::parse_from(["uv-sbom", "--check-license", "--fail-on-unknown-license"]);
+        let config = Some(ConfigFile {
+            license_policy: Some(LicensePolicyConfig {
+                allow: None,
+                deny: Some(vec!["GPL-*".to_string()]),
+                unknown: Some("allow".to_string()),
+            }),
+            ..Default::default()
+        });
+        let policy = merge_config(&args, &config).license_policy.unwrap();
+        assert_eq!(policy.unknown, UnknownLicenseHandling::Deny);
+        assert_eq!(policy.deny.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_config_unknown_license_defaults_to_warn() {
+        let args = Args::parse_from(["uv-sbom", "--check-license"]);
+        let policy = merge_config(&args, &None).license_policy.unwrap();
+        assert_eq!(policy.unknown, UnknownLicenseHandling::Warn);
+    }
+
+    // --- offline merge tests ---
+
+    #[test]
+    fn test_merge_config_offline_disables_default_cve_check() {
+        let args = Args::parse_from(["uv-sbom", "--offline"]);
+        let result = merge_config(&args, &None);
+        assert!(result.offline);
+        assert!(!result.check_cve);
+
+        let result = merge_config(&args, &Some(ConfigFile::default()));
+        assert!(!result.check_cve);
+    }
+
+    #[test]
+    fn test_merge_config_offline_keeps_explicit_cve_check() {
+        let args = Args::parse_from(["uv-sbom", "--offline"]);
+        let config = Some(ConfigFile {
+            check_cve: Some(true),
+            ..Default::default()
+        });
+        assert!(merge_config(&args, &config).check_cve);
+
+        let args = Args::parse_from(["uv-sbom", "--offline", "--check-cve"]);
+        assert!(merge_config(&args, &None).check_cve);
+    }
+
+    #[test]
+    fn test_merge_config_offline_with_advisory_db_checks_cve() {
+        let args = Args::parse_from(["uv-sbom", "--offline", "--advisory-db", "/srv/advisory-db"]);
+        let result = merge_config(&args, &None);
+        assert!(result.check_cve);
+        assert_eq!(result.advisory_db, Some(PathBuf::from("/srv/advisory-db")));
+
+        // CLI takes precedence over config
+        let config = Some(ConfigFile {
+            advisory_db: Some("/opt/advisory-db".to_string()),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert!(result.check_cve);
+        assert_eq!(result.advisory_db, Some(PathBuf::from("/srv/advisory-db")));
+
+        let args = Args::parse_from(["uv-sbom", "--offline"]);
+        let result = merge_config(&args, &config);
+        assert!(result.check_cve);
+        assert_eq!(result.advisory_db, Some(PathBuf::from("/opt/advisory-db")));
+    }
+
+    #[test]
+    fn test_offline_conflicts_with_verify_links() {
+        let result = Args::try_parse_from(["uv-sbom", "--offline", "--verify-links"]);
+        assert!(result.is_err());
+    }
+
+    // --- vex merge tests ---
+
+    #[test]
+    fn test_merge_config_vex_default_false() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let result = merge_config(&args, &None);
+        assert!(!result.vex);
+    }
+
+    #[test]
+    fn test_merge_config_vex_from_config() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            vex: Some(true),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert!(result.vex);
+    }
+
+    #[test]
+    fn test_merge_config_vex_cli_wins_over_config_false() {
+        let args = Args::parse_from(["uv-sbom", "--vex"]);
+        let config = Some(ConfigFile {
+            vex: Some(false),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert!(result.vex);
+    }
+
     // --- Merge logic tests ---
 
     #[test]
@@ -525,6 +1350,7 @@ mod tests {
         let cli = vec![IgnoreCve {
             id: "CVE-2024-1".to_string(),
             reason: None,
+            state: None,
         }];
         let result = merge_ignore_cves(&cli, &None);
         assert_eq!(result.len(), 1);
@@ -536,6 +1362,7 @@ mod tests {
         let config = Some(vec![IgnoreCve {
             id: "CVE-2024-2".to_string(),
             reason: Some("reason".to_string()),
+            state: None,
         }]);
         let result = merge_ignore_cves(&[], &config);
         assert_eq!(result.len(), 1);
@@ -548,15 +1375,18 @@ mod tests {
         let cli = vec![IgnoreCve {
             id: "CVE-2024-1".to_string(),
             reason: Some("cli reason".to_string()),
+            state: None,
         }];
         let config = Some(vec![
             IgnoreCve {
                 id: "CVE-2024-1".to_string(),
                 reason: Some("config reason".to_string()),
+                state: None,
             },
             IgnoreCve {
                 id: "CVE-2024-2".to_string(),
                 reason: None,
+                state: None,
             },
         ]);
         let result = merge_ignore_cves(&cli, &config);
@@ -566,6 +1396,47 @@ mod tests {
         assert_eq!(result[1].id, "CVE-2024-2");
     }
 
+    // --- check_self / self_check_fails_build merge tests ---
+
+    #[test]
+    fn test_merge_config_check_self_defaults() {
+        // No CLI flag, no config → check_self=false, self_check_fails_build=true
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert!(!result.check_self);
+        assert!(result.self_check_fails_build);
+    }
+
+    #[test]
+    fn test_merge_config_check_self_from_config() {
+        // config: check_self true, self_check_fails_build false → both honoured
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            check_self: Some(true),
+            self_check_fails_build: Some(false),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert!(result.check_self);
+        assert!(!result.self_check_fails_build);
+    }
+
+    #[test]
+    fn test_merge_config_self_check_no_fail_wins_over_config() {
+        // CLI: --check-self --self-check-no-fail + config: fails_build true → CLI wins
+        let args = Args::parse_from(["uv-sbom", "--check-self", "--self-check-no-fail"]);
+        let config = Some(ConfigFile {
+            self_check_fails_build: Some(true),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        assert!(result.check_self);
+        assert!(!result.self_check_fails_build);
+    }
+
     // --- check_abandoned / abandoned_threshold_days merge tests ---
 
     #[test]
@@ -669,4 +1540,169 @@ mod tests {
         assert!(result.check_abandoned);
         assert_eq!(result.abandoned_threshold_days, 180);
     }
+
+    #[test]
+    fn test_merge_config_license_compatibility_cli_flags() {
+        let args = Args::parse_from([
+            "uv-sbom",
+            "--check-license-compatibility",
+            "--fail-on-license-incompatibility",
+        ]);
+        let result = merge_config(&args, &None);
+        assert!(result.check_license_compatibility);
+        assert!(result.fail_on_license_incompatibility);
+        assert!(result.project_license.is_none());
+    }
+
+    #[test]
+    fn test_merge_config_license_overrides_from_config() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            license_overrides: Some(
+                [(
+                    "internal-utils".to_string(),
+                    config::LicenseOverrideConfig {
+                        license: "MIT".to_string(),
+                        reason: Some("vendored fork".to_string()),
+                    },
+                )]
+                .into(),
+            ),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+
+        assert_eq!(
+            result.license_overrides,
+            vec![LicenseOverride::new(
+                "internal-utils".to_string(),
+                "MIT".to_string(),
+                Some("vendored fork".to_string()),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_merge_config_license_compatibility_from_config() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            check_license_compatibility: Some(true),
+            license_compatibility: Some(LicenseCompatibilityConfig {
+                project_license: Some("MIT".to_string()),
+                families: Some(
+                    [(
+                        "permissive".to_string(),
+                        vec!["LicenseRef-Internal".to_string()],
+                    )]
+                    .into(),
+                ),
+                matrix: Some(
+                    [(
+                        "permissive".to_string(),
+                        [("weak-copyleft".to_string(), "compatible".to_string())].into(),
+                    )]
+                    .into(),
+                ),
+                licenses: Some([("GPL-3.0-only".to_string(), "needs-review".to_string())].into()),
+            }),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+        let policy = &result.license_compatibility_policy;
+
+        assert!(result.check_license_compatibility);
+        assert!(!result.fail_on_license_incompatibility);
+        assert_eq!(result.project_license.as_deref(), Some("MIT"));
+        assert_eq!(
+            policy.family_of("LicenseRef-Internal"),
+            Some(LicenseFamily::Permissive)
+        );
+        assert_eq!(
+            policy.evaluate(LicenseFamily::Permissive, "MPL-2.0"),
+            Compatibility::Compatible
+        );
+        assert_eq!(
+            policy.evaluate(LicenseFamily::Permissive, "GPL-3.0-only"),
+            Compatibility::NeedsReview
+        );
+    }
+
+    #[test]
+    fn test_merge_config_license_reachability_from_config() {
+        let args = Args::parse_from(["uv-sbom", "--check-license-reachability"]);
+        let config = Some(ConfigFile {
+            strict_license_reachability: Some(true),
+            copyleft_licenses: Some(vec!["MPL-2.0".to_string()]),
+            ..Default::default()
+        });
+        let result = merge_config(&args, &config);
+
+        assert!(result.check_license_reachability);
+        assert!(result.strict_license_reachability);
+        assert!(result.copyleft_licenses.matches("MPL-2.0"));
+        assert!(!result.copyleft_licenses.matches("GPL-3.0-only"));
+
+        let result = merge_config(&args, &None);
+        assert!(!result.strict_license_reachability);
+        assert!(result.copyleft_licenses.matches("GPL-3.0-only"));
+    }
+
+    #[test]
+    fn test_merge_config_check_python_compat() {
+        let args = Args::parse_from(["uv-sbom"]);
+        let config = Some(ConfigFile {
+            check_python_compat: Some(true),
+            ..Default::default()
+        });
+
+        assert!(merge_config(&args, &config).check_python_compat);
+        assert!(!merge_config(&args, &None).check_python_compat);
+        let args = Args::parse_from(["uv-sbom", "--check-python-compat"]);
+        assert!(merge_config(&args, &None).check_python_compat);
+    }
+
+    #[test]
+    fn test_strict_license_reachability_requires_check_flag() {
+        let result = Args::try_parse_from(["uv-sbom", "--strict-license-reachability"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_merge_config_typosquats() {
+        let args = Args::parse_from(["uv-sbom", "--check-typosquats"]);
+        let config = Some(ConfigFile {
+            fail_on_typosquat: Some(true),
+            typosquat_max_distance: Some(2),
+            ignore_typosquats: Some(vec!["requets".to_string()]),
+            ..Default::default()
+        });
+
+        let result = merge_config(&args, &config);
+        assert!(result.check_typosquats);
+        assert!(result.fail_on_typosquat);
+        assert_eq!(result.typosquat_max_distance, 2);
+        assert_eq!(result.ignore_typosquats, ["requets"]);
+
+        let args = Args::parse_from([
+            "uv-sbom",
+            "--check-typosquats",
+            "--typosquat-max-distance",
+            "3",
+        ]);
+        assert_eq!(merge_config(&args, &config).typosquat_max_distance, 3);
+        let args = Args::parse_from(["uv-sbom", "--check-typosquats"]);
+        assert_eq!(merge_config(&args, &None).typosquat_max_distance, 1);
+    }
+
+    #[test]
+    fn test_fail_on_typosquat_requires_check_flag() {
+        let result = Args::try_parse_from(["uv-sbom", "--fail-on-typosquat"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_fail_on_license_incompatibility_requires_check_flag() {
+        let result = Args::try_parse_from(["uv-sbom", "--fail-on-license-incompatibility"]);
+        assert!(result.is_err());
+    }
 }
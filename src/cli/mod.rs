@@ -1,21 +1,33 @@
 pub mod config_resolver;
+pub mod path_resolver;
 pub mod runner;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use std::time::Duration;
 
-use crate::application::dto::OutputFormat;
+use crate::adapters::outbound::formatters::{GraphFormat, MarkdownStyle, SpecVersion};
+use crate::adapters::outbound::network::DEFAULT_CVE_CACHE_TTL;
+use crate::application::dto::{ExcludeScope, LicensesFor, OutputFormat};
+use crate::application::read_models::InformationalDisplay;
 use crate::i18n::Locale;
-use crate::sbom_generation::domain::vulnerability::Severity;
+use crate::ports::outbound::{LicenseFetchFields, Verbosity};
+use crate::sbom_generation::domain::environment_marker::MARKER_VARIABLES;
+use crate::sbom_generation::domain::vulnerability::{CvssPreference, Severity};
+use crate::sbom_generation::domain::TargetPlatform;
+use crate::shared::security::DEFAULT_MAX_LOCKFILE_SIZE;
+use crate::shared::terminal::ProgressDisplay;
 
 /// Generate SBOMs for Python projects managed by uv
 #[derive(Parser, Debug)]
 #[command(name = "uv-sbom")]
 #[command(version)]
 #[command(about = "Generate SBOMs for Python projects managed by uv", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct Args {
-    /// Output format: json or markdown
-    #[arg(short, long, default_value = "json")]
-    pub format: OutputFormat,
+    /// Output format: json, markdown or html. With --output-dir, repeat it to write
+    /// several formats from one run: -f json -f markdown
+    #[arg(short, long, default_value = "json", action = clap::ArgAction::Append)]
+    pub format: Vec<OutputFormat>,
 
     /// Path to the project directory (defaults to current directory)
     #[arg(short, long)]
@@ -25,14 +37,123 @@ pub struct Args {
     #[arg(short, long)]
     pub output: Option<String>,
 
-    /// Exclude packages matching patterns (supports wildcards: *)
-    /// Can be specified multiple times: -e "pkg-a" -e "debug-*"
+    /// Split Markdown output into linked files (index.md, components.md, dependencies.md,
+    /// vulnerabilities.md) written into the --output directory
+    #[arg(long, requires = "output")]
+    pub markdown_split: bool,
+
+    /// Write every --format into this directory (sbom.json, sbom.md, sbom.html), plus
+    /// cve-report.json when the CVE check runs, from a single pipeline run
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["output", "markdown_split", "workspace"])]
+    pub output_dir: Option<String>,
+
+    /// With --output-dir, also print this format to stdout; it must be one of the
+    /// formats written (default: nothing is printed)
+    #[arg(long, value_name = "FORMAT", requires = "output_dir")]
+    pub stdout_format: Option<OutputFormat>,
+
+    /// Create missing parent directories of --output, --output-dir, --cve-report and
+    /// --graph-output instead of failing
+    #[arg(long)]
+    pub create_dirs: bool,
+
+    /// Directory relative output paths are resolved against: cwd (default) or project
+    /// (the --path directory)
+    #[arg(long, value_name = "BASE", default_value = "cwd", value_parser = parse_output_base)]
+    pub output_relative_to: OutputBase,
+
+    /// Markdown rendering of severities and status markers: emoji (default) or plain
+    /// (uppercase text and [OK]/[WARNING] labels, for tools that strip emoji)
+    #[arg(long, default_value = "emoji", value_parser = parse_markdown_style)]
+    pub markdown_style: MarkdownStyle,
+
+    /// Show where each license came from (pypi-license-field, pypi-classifier,
+    /// local-metadata or config-override) in the Markdown component table
+    #[arg(long)]
+    pub show_license_source: bool,
+
+    /// Add a Supplier column, taken from the package author or maintainer, to the
+    /// Markdown component table
+    #[arg(long)]
+    pub include_supplier: bool,
+
+    /// Render transitive dependencies in the Markdown report as an indented tree,
+    /// N levels below each direct dependency (0 = unlimited)
+    #[arg(long, value_name = "N")]
+    pub tree_depth: Option<usize>,
+
+    /// Shorten package descriptions in the Markdown tables to N characters,
+    /// ending them with an ellipsis (0 = unlimited)
+    #[arg(long, value_name = "N", default_value_t = 200)]
+    pub description_max_length: usize,
+
+    /// CycloneDX spec version of the JSON output: 1.4, 1.5 or 1.6 (default: 1.6).
+    /// Overrides cyclonedx.spec_version in the config file
+    #[arg(long, value_name = "VERSION", value_parser = parse_spec_version)]
+    pub spec_version: Option<SpecVersion>,
+
+    /// Exclude packages matching patterns (supports wildcards: *), or with
+    /// deps-of:<package> the packages only that package pulls in.
+    /// Can be specified multiple times: -e "pkg-a" -e "debug-*" -e "deps-of:sphinx"
     #[arg(short, long = "exclude", value_name = "PATTERN")]
     pub exclude: Vec<String>,
 
-    /// Validate configuration without performing network operations or generating output
+    /// What exclusions apply to: all (excluded packages are also skipped by the CVE
+    /// check) or output (they are still checked, only left out of the SBOM).
+    /// Overrides exclude_scope in the config file
+    #[arg(long, value_name = "SCOPE")]
+    pub exclude_scope: Option<ExcludeScope>,
+
+    /// Keep only packages needed by these dependency groups; `main` is the runtime
+    /// dependencies. Can be specified multiple times: --only-group main
+    #[arg(long = "only-group", value_name = "GROUP", conflicts_with_all = ["exclude_groups", "workspace"])]
+    pub only_groups: Vec<String>,
+
+    /// Drop packages needed only by these dependency groups or extras, e.g. dev.
+    /// Packages another selected group also needs are kept
+    #[arg(
+        long = "exclude-group",
+        value_name = "GROUP",
+        conflicts_with = "workspace"
+    )]
+    pub exclude_groups: Vec<String>,
+
+    /// Target platform: linux, macos or windows. Packages whose environment markers
+    /// can never be true there, such as Windows-only dependencies on linux, are left out
+    #[arg(long, value_name = "PLATFORM", conflicts_with = "workspace")]
+    pub platform: Option<TargetPlatform>,
+
+    /// Sets a PEP 508 marker variable of the target environment, e.g. python_version=3.12.
+    /// Overrides the value --platform implies. Can be specified multiple times
+    #[arg(
+        long = "marker-env",
+        value_name = "KEY=VALUE",
+        value_parser = parse_marker_env,
+        conflicts_with = "workspace"
+    )]
+    pub marker_env: Vec<(String, String)>,
+
+    /// Validate configuration without generating output. --dry-run=full also probes
+    /// the package index and vulnerability endpoints
+    #[arg(
+        long,
+        value_name = "MODE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "basic",
+        value_parser = parse_dry_run_mode
+    )]
+    pub dry_run: Option<DryRunMode>,
+
+    /// Print package counts, license and vulnerability totals and per-phase timings.
+    /// Markdown output also gets a "Generation Summary" section
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Do not print the summary table (package, license and vulnerability counts,
+    /// output path and elapsed time) on stderr after the SBOM is written
     #[arg(long)]
-    pub dry_run: bool,
+    pub no_summary: bool,
 
     /// [DEPRECATED] CVE checking is now enabled by default. This flag has no effect.
     /// Use --no-check-cve to opt out. This flag will be removed in a future release.
@@ -52,14 +173,196 @@ pub struct Args {
     #[arg(long, value_parser = parse_cvss_threshold, group = "threshold", conflicts_with = "no_check_cve")]
     pub cvss_threshold: Option<f32>,
 
+    /// Also check the project's own published name and version for vulnerabilities
+    #[arg(long, conflicts_with = "no_check_cve")]
+    pub check_self: bool,
+
+    /// Report project self-check findings without failing the build (requires --check-self)
+    #[arg(long, requires = "check_self")]
+    pub self_check_no_fail: bool,
+
+    /// CVSS version to score with when an advisory carries both: v4 (default) or v3
+    #[arg(long, default_value = "v4", value_parser = parse_cvss_preference, conflicts_with = "no_check_cve")]
+    pub cvss_preference: CvssPreference,
+
+    /// Also check packages against a local clone of the PyPA advisory database
+    /// (https://github.com/pypa/advisory-database); with --offline it is the only source
+    #[arg(long, value_name = "DIR", conflicts_with = "no_check_cve")]
+    pub advisory_db: Option<String>,
+
+    /// Reuse OSV results cached under --data-dir (or the system temporary directory)
+    /// for this many hours before querying a package again; 0 disables the cache
+    /// (default: 24)
+    #[arg(long, value_name = "HOURS", conflicts_with = "no_check_cve")]
+    pub cve_cache_ttl: Option<u64>,
+
+    /// Ignore cached OSV results and query every package again, refreshing the cache
+    #[arg(long, conflicts_with = "no_check_cve")]
+    pub refresh_cve_cache: bool,
+
     /// Suggest upgrade paths for vulnerable transitive dependencies
     #[arg(long, conflicts_with = "no_check_cve")]
     pub suggest_fix: bool,
 
+    /// List the upgrade fixing each vulnerable package, with a ready-to-run `uv add`
+    /// command for direct dependencies (Markdown output)
+    #[arg(long, conflicts_with = "no_check_cve")]
+    pub suggest_fixes: bool,
+
+    /// Also write the vulnerability check result as JSON to this file (the SBOM itself
+    /// still goes to --output or stdout), for CI gates that parse the findings
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["no_check_cve", "workspace"])]
+    pub cve_report: Option<String>,
+
+    /// Also export the dependency graph in this syntax (dot or mermaid) to
+    /// --graph-output; direct dependencies and vulnerable packages are highlighted
+    #[arg(
+        long,
+        value_name = "FORMAT",
+        value_parser = parse_graph_format,
+        requires = "graph_output",
+        conflicts_with = "workspace"
+    )]
+    pub graph_format: Option<GraphFormat>,
+
+    /// File the dependency graph is written to
+    #[arg(long, value_name = "PATH", requires = "graph_format")]
+    pub graph_output: Option<String>,
+
+    /// List at most N below-threshold vulnerabilities in the Info section of the
+    /// Markdown and HTML reports, most severe first (the summary still counts all)
+    #[arg(long, value_name = "N", conflicts_with = "no_check_cve")]
+    pub max_informational: Option<usize>,
+
+    /// Leave the Info section out of the Markdown and HTML reports (the summary
+    /// still counts below-threshold vulnerabilities)
+    #[arg(long, conflicts_with_all = ["no_check_cve", "max_informational"])]
+    pub hide_informational: bool,
+
+    /// Maximum number of outbound HTTP requests for the whole run. Once reached, no new
+    /// requests are sent: remaining lookups are skipped and the CVE check is truncated
+    #[arg(long, value_name = "N")]
+    pub max_requests: Option<u64>,
+
+    /// Maximum number of package lookups (one per package for licenses and for the
+    /// CVE check) for the whole run. Once spent, the remaining packages are listed
+    /// without license or vulnerability data and the run still completes.
+    /// Overrides network.max_requests in the config file
+    #[arg(long, value_name = "N")]
+    pub max_network_requests: Option<usize>,
+
+    /// Which packages have their license looked up: all (default) or direct-only,
+    /// which lists transitive packages without a license (cheaper for huge trees)
+    #[arg(long, value_name = "SCOPE")]
+    pub licenses_for: Option<LicensesFor>,
+
+    /// Refuse to read a lockfile larger than this many megabytes (default: 50)
+    #[arg(long, value_name = "MB", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_lockfile_size: Option<u64>,
+
+    /// Abort generation with an error when license lookups and the CVE check have not
+    /// finished within this many seconds of starting
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+
+    /// Base directory for cache, state and temporary files. Falls back to the system
+    /// temporary directory with a warning when it is not writable
+    #[arg(long, value_name = "DIR", env = "UV_SBOM_DATA_DIR")]
+    pub data_dir: Option<String>,
+
+    /// PEM file with root certificates to trust in addition to the built-in ones,
+    /// e.g. a corporate TLS-inspecting proxy's CA (overrides network.ca_bundle_path)
+    #[arg(long, value_name = "PATH", env = "UV_SBOM_CA_BUNDLE")]
+    pub ca_bundle: Option<String>,
+
+    /// Mirror or private index to look up licenses on instead of pypi.org; it must serve
+    /// the PyPI JSON API (overrides network.index_url). Basic-auth credentials are read
+    /// from UV_SBOM_INDEX_USERNAME and UV_SBOM_INDEX_PASSWORD
+    #[arg(long, value_name = "URL", env = "UV_SBOM_INDEX_URL")]
+    pub index_url: Option<String>,
+
+    /// Contact details appended to the User-Agent of PyPI and OSV requests, e.g.
+    /// "(+mailto:secops@example.com)" (overrides network.user_agent_suffix)
+    #[arg(long, value_name = "TEXT", env = "UV_SBOM_CONTACT")]
+    pub user_agent_suffix: Option<String>,
+
+    /// Look packages up on pypi.org when the --index-url index does not have them (404)
+    #[arg(long)]
+    pub fallback_to_pypi: bool,
+
     /// Verify PyPI links exist before generating hyperlinks (requires network access, Markdown format only)
     #[arg(long)]
     pub verify_links: bool,
 
+    /// Check the generated CycloneDX output for structural errors (unique bom-refs,
+    /// resolvable references, purl and serial number syntax) and fail if any are found
+    #[arg(long)]
+    pub validate: bool,
+
+    /// Produce identical output for identical inputs: the serial number is derived
+    /// from the lockfile, all lists are sorted and the timestamp is taken from
+    /// SOURCE_DATE_EPOCH when it is set
+    #[arg(long)]
+    pub reproducible: bool,
+
+    /// Write the SHA-256 digest of the output to <OUTPUT>.sha256 (sha256sum format),
+    /// print it on completion, and record the lockfile digest in CycloneDX output
+    #[arg(long, conflicts_with = "workspace")]
+    pub emit_digest: bool,
+
+    /// Lockfile to read instead of auto-detecting uv.lock / pylock.toml in the project
+    /// directory. The parser is chosen by file name: uv.lock, pylock[.<name>].toml, or a
+    /// pinned requirements file ending in .txt. Relative paths are resolved against the current directory. `-` reads a uv.lock
+    /// from stdin
+    #[arg(long, value_name = "PATH", conflicts_with = "workspace")]
+    pub lockfile: Option<String>,
+
+    /// Project name whose dependencies are the direct dependencies, instead of the
+    /// name in pyproject.toml. Needed for Markdown and HTML output when the lockfile
+    /// is read from stdin (--lockfile -) without --pyproject
+    #[arg(long, value_name = "NAME", conflicts_with = "workspace")]
+    pub project_name: Option<String>,
+
+    /// Analyze the dependency graph rooted at these packages instead of the
+    /// project (comma-separated, '*' wildcards allowed, e.g. boto3,flask-*).
+    /// Every pattern must match a package in the lockfile. Only affects output
+    /// that includes dependency information (Markdown, HTML, --graph-format),
+    /// where the roots are listed as direct dependencies, their subtrees as
+    /// transitive dependencies, and packages no root reaches under
+    /// "Unreferenced Packages". The component inventory is unchanged
+    #[arg(
+        long,
+        value_name = "PACKAGES",
+        value_delimiter = ',',
+        conflicts_with_all = ["project_name", "workspace"]
+    )]
+    pub roots: Vec<String>,
+
+    /// pyproject.toml to read the project name, version, license and annotations from
+    /// instead of the one in the project directory. Relative paths are resolved
+    /// against the current directory
+    #[arg(long, value_name = "PATH", conflicts_with = "workspace")]
+    pub pyproject: Option<String>,
+
+    /// Fail with exit code 5 instead of warning when the lockfile is out of date
+    /// with the dependencies declared in pyproject.toml
+    #[arg(long, conflicts_with = "no_lock_check")]
+    pub strict_lock: bool,
+
+    /// Skip comparing the lockfile with the dependencies declared in pyproject.toml
+    #[arg(long)]
+    pub no_lock_check: bool,
+
+    /// Fail instead of warning when the lockfile format is newer than this
+    /// version of uv-sbom supports
+    #[arg(long)]
+    pub strict_lock_version: bool,
+
+    /// Fail instead of skipping dependency analysis when there is no pyproject.toml
+    /// and the lockfile does not show which package is the project
+    #[arg(long)]
+    pub strict: bool,
+
     /// Explicit config file path (overrides auto-discovery)
     #[arg(short = 'c', long = "config", value_name = "PATH")]
     pub config: Option<String>,
@@ -68,6 +371,34 @@ pub struct Args {
     #[arg(short = 'i', long = "ignore-cve", value_name = "CVE_ID")]
     pub ignore_cve: Vec<String>,
 
+    /// Keep ignored CVEs in CycloneDX output as VEX entries with an analysis block
+    #[arg(long)]
+    pub vex: bool,
+
+    /// JSON file of previously accepted vulnerabilities; only findings missing from
+    /// it count toward the threshold, so known issues no longer fail the run
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["no_check_cve", "workspace"])]
+    pub cve_baseline: Option<String>,
+
+    /// Write the current findings to the --cve-baseline file, accepting them; the
+    /// run then does not fail on vulnerabilities
+    #[arg(long, requires = "cve_baseline")]
+    pub update_cve_baseline: bool,
+
+    /// Skip all network access (PyPI, OSV). Licenses are taken from local project metadata only
+    #[arg(long, conflicts_with_all = ["verify_links", "check_abandoned"])]
+    pub offline: bool,
+
+    /// Where to read package licenses from: local (installed .venv metadata), pypi, or
+    /// auto (default: local metadata first, PyPI for packages not installed)
+    #[arg(long, default_value = "auto", value_parser = parse_license_source)]
+    pub license_source: LicenseSource,
+
+    /// Resolve licenses only and leave package descriptions out of the SBOM, reading
+    /// less of each PyPI response (JSON format only)
+    #[arg(long)]
+    pub minimal_metadata: bool,
+
     /// Check license compliance against a policy (Markdown format only)
     #[arg(long)]
     pub check_license: bool,
@@ -80,6 +411,15 @@ pub struct Args {
     #[arg(long, value_name = "DAYS", requires = "check_abandoned")]
     pub abandoned_threshold_days: Option<u64>,
 
+    /// Exit with code 1 when a package version in the SBOM has been yanked from PyPI
+    #[arg(long, conflicts_with = "offline")]
+    pub fail_on_yanked: bool,
+
+    /// Exit with code 3 when the metadata of a package could not be fetched, instead
+    /// of recording its license as unavailable and continuing
+    #[arg(long, conflicts_with = "offline")]
+    pub fail_on_enrichment_error: bool,
+
     /// Allowed license patterns (comma-separated, requires --check-license)
     /// Supports wildcards: "MIT,Apache-2.0,BSD-*"
     #[arg(long, value_delimiter = ',', requires = "check_license")]
@@ -90,6 +430,42 @@ pub struct Args {
     #[arg(long, value_delimiter = ',', requires = "check_license")]
     pub license_deny: Vec<String>,
 
+    /// Treat packages with unknown licenses as violations (requires --check-license)
+    #[arg(long, requires = "check_license")]
+    pub fail_on_unknown_license: bool,
+
+    /// Check dependency licenses against the project's own license (Markdown format only)
+    #[arg(long)]
+    pub check_license_compatibility: bool,
+
+    /// Exit with code 1 when a dependency license is incompatible with the project license
+    #[arg(long, requires = "check_license_compatibility")]
+    pub fail_on_license_incompatibility: bool,
+
+    /// Report copyleft packages reachable from the project and how each is introduced (Markdown format only)
+    #[arg(long)]
+    pub check_license_reachability: bool,
+
+    /// Also report reachable packages whose license is unknown (requires --check-license-reachability)
+    #[arg(long, requires = "check_license_reachability")]
+    pub strict_license_reachability: bool,
+
+    /// Warn about packages whose requires-python excludes Python versions the project supports, and list them in the Markdown report
+    #[arg(long)]
+    pub check_python_compat: bool,
+
+    /// Warn about packages whose names are near misses of popular PyPI packages, and list them in the Markdown report
+    #[arg(long)]
+    pub check_typosquats: bool,
+
+    /// Largest edit distance from a popular package name that is reported (default: 1)
+    #[arg(long, value_name = "N", requires = "check_typosquats")]
+    pub typosquat_max_distance: Option<usize>,
+
+    /// Exit with code 1 when the typosquat check reports a package
+    #[arg(long, requires = "check_typosquats")]
+    pub fail_on_typosquat: bool,
+
     /// Generate a uv-sbom.config.yml template file
     #[arg(long)]
     pub init: bool,
@@ -99,8 +475,303 @@ pub struct Args {
     pub workspace: bool,
 
     /// Output language for human-readable formats: en (default) or ja
-    #[arg(long, default_value = "en", value_parser = parse_lang)]
+    #[arg(long, global = true, default_value = "en", value_parser = parse_lang)]
     pub lang: Locale,
+
+    /// Only print errors and warnings on stderr (no progress messages or bars)
+    #[arg(short, long, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Also print per-package details, such as each package's license fetch result
+    #[arg(short, long)]
+    pub verbose: bool,
+
+    /// Progress output on stderr: text (default) or json (newline-delimited events
+    /// for tools that wrap uv-sbom)
+    #[arg(long, default_value = "text", value_parser = parse_progress_format)]
+    pub progress_format: ProgressFormat,
+
+    /// When to draw progress bars: auto (default; only on an interactive terminal
+    /// without NO_COLOR or UV_SBOM_NO_PROGRESS), always, or never (plain lines)
+    #[arg(long, value_name = "WHEN", default_value = "auto", value_parser = parse_progress_display)]
+    pub progress: ProgressDisplay,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Subcommands; without one, uv-sbom generates an SBOM
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Compare two SBOMs and report added, removed and updated packages
+    Diff(DiffArgs),
+    /// Merge the SBOMs of several projects into one aggregated SBOM
+    Merge(MergeArgs),
+    /// Check an SBOM against the digest file written by --emit-digest
+    Verify(VerifyArgs),
+    /// Check the components of a saved CycloneDX SBOM for vulnerabilities
+    Scan(ScanArgs),
+}
+
+/// Arguments of `uv-sbom scan`
+#[derive(clap::Args, Debug)]
+pub struct ScanArgs {
+    /// CycloneDX JSON file to scan
+    #[arg(long, value_name = "FILE")]
+    pub sbom: String,
+
+    /// Output format: markdown or json
+    #[arg(short, long, default_value = "markdown")]
+    pub format: OutputFormat,
+
+    /// Output file path (if not specified, outputs to stdout)
+    #[arg(short, long)]
+    pub output: Option<String>,
+
+    /// Severity threshold for vulnerability check (low/medium/high/critical)
+    #[arg(long, value_parser = parse_severity_threshold, group = "threshold")]
+    pub severity_threshold: Option<Severity>,
+
+    /// CVSS threshold for vulnerability check (0.0-10.0)
+    #[arg(long, value_parser = parse_cvss_threshold, group = "threshold")]
+    pub cvss_threshold: Option<f32>,
+
+    /// CVE IDs to ignore (can be specified multiple times)
+    #[arg(short = 'i', long = "ignore-cve", value_name = "CVE_ID")]
+    pub ignore_cve: Vec<String>,
+}
+
+/// Arguments of `uv-sbom verify`
+#[derive(clap::Args, Debug)]
+pub struct VerifyArgs {
+    /// SBOM file to check
+    #[arg(value_name = "SBOM")]
+    pub sbom: String,
+
+    /// Digest file in sha256sum format (default: <SBOM>.sha256)
+    #[arg(long, value_name = "FILE")]
+    pub digest: Option<String>,
+}
+
+/// Arguments of `uv-sbom diff`
+#[derive(clap::Args, Debug)]
+pub struct DiffArgs {
+    /// Base of the comparison: a project directory or a CycloneDX JSON file
+    #[arg(long)]
+    pub base: String,
+
+    /// Head of the comparison: a project directory or a CycloneDX JSON file
+    #[arg(long)]
+    pub head: String,
+
+    /// Output format: markdown or json
+    #[arg(short, long, default_value = "markdown")]
+    pub format: OutputFormat,
+
+    /// Output file path (if not specified, outputs to stdout)
+    #[arg(short, long)]
+    pub output: Option<String>,
+}
+
+/// Arguments of `uv-sbom merge`
+#[derive(clap::Args, Debug)]
+pub struct MergeArgs {
+    /// Project directories to merge, each containing a uv.lock
+    #[arg(required = true, num_args = 2.., value_name = "PROJECT")]
+    pub projects: Vec<String>,
+
+    /// Output format: json, markdown or html
+    #[arg(short, long, default_value = "json")]
+    pub format: OutputFormat,
+
+    /// Output file path (if not specified, outputs to stdout)
+    #[arg(short, long)]
+    pub output: Option<String>,
+
+    /// Exclude packages matching patterns (supports wildcards: *) in every project
+    #[arg(short, long = "exclude", value_name = "PATTERN")]
+    pub exclude: Vec<String>,
+
+    /// Disable the vulnerability check against OSV
+    #[arg(long)]
+    pub no_check_cve: bool,
+
+    /// Skip all network access (PyPI, OSV); packages are listed without licenses
+    #[arg(long)]
+    pub offline: bool,
+}
+
+impl Args {
+    /// Progress output level selected by `--quiet` / `--verbose`
+    ///
+    /// `--progress-format json` counts as quiet, so the banner and other text
+    /// lines do not interleave with the JSON events.
+    pub fn verbosity(&self) -> Verbosity {
+        if self.quiet || self.progress_format == ProgressFormat::Json {
+            Verbosity::Quiet
+        } else if self.verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        }
+    }
+
+    /// Lockfile size limit in bytes, from `--max-lockfile-size` or the 50 MB default
+    pub fn max_lockfile_bytes(&self) -> u64 {
+        self.max_lockfile_size
+            .map_or(DEFAULT_MAX_LOCKFILE_SIZE, |mb| {
+                mb.saturating_mul(1024 * 1024)
+            })
+    }
+
+    /// How long cached OSV results are reused, from `--cve-cache-ttl` or the 24 hour default
+    pub fn cve_cache_ttl(&self) -> Duration {
+        self.cve_cache_ttl.map_or(DEFAULT_CVE_CACHE_TTL, |hours| {
+            Duration::from_secs(hours.saturating_mul(60 * 60))
+        })
+    }
+
+    /// How much of the Info section reports list, from `--max-informational`
+    /// and `--hide-informational`
+    pub fn informational_display(&self) -> InformationalDisplay {
+        match (self.hide_informational, self.max_informational) {
+            (true, _) => InformationalDisplay::Hidden,
+            (false, Some(limit)) => InformationalDisplay::Limit(limit),
+            (false, None) => InformationalDisplay::All,
+        }
+    }
+
+    /// Package metadata fields selected by `--minimal-metadata`
+    pub fn license_fetch_fields(&self) -> LicenseFetchFields {
+        if self.minimal_metadata {
+            LicenseFetchFields::LicenseOnly
+        } else {
+            LicenseFetchFields::Full
+        }
+    }
+}
+
+/// Source of package license information
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LicenseSource {
+    /// `*.dist-info/METADATA` of packages installed in the project's `.venv`
+    Local,
+    /// The PyPI JSON API
+    Pypi,
+    /// Local metadata first, PyPI for packages that are not installed
+    #[default]
+    Auto,
+}
+
+/// Format of the progress output on stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressFormat {
+    /// Messages and progress bars for people
+    #[default]
+    Text,
+    /// Newline-delimited JSON events
+    Json,
+}
+
+/// Base directory for relative output paths (`--output-relative-to`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputBase {
+    /// The current working directory
+    #[default]
+    Cwd,
+    /// The project directory given with `--path`
+    Project,
+}
+
+/// Depth of `--dry-run` validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DryRunMode {
+    /// Parse the lockfile and check option values; no network access
+    Basic,
+    /// Also send one request to each network endpoint the run would use
+    Full,
+}
+
+fn parse_dry_run_mode(s: &str) -> Result<DryRunMode, String> {
+    match s.to_lowercase().as_str() {
+        "basic" => Ok(DryRunMode::Basic),
+        "full" => Ok(DryRunMode::Full),
+        _ => Err(format!(
+            "Invalid dry-run mode: {}. Valid values: basic, full",
+            s
+        )),
+    }
+}
+
+fn parse_markdown_style(s: &str) -> Result<MarkdownStyle, String> {
+    match s.to_lowercase().as_str() {
+        "emoji" => Ok(MarkdownStyle::default()),
+        "plain" => Ok(MarkdownStyle::plain()),
+        _ => Err(format!(
+            "Invalid markdown style: {}. Valid values: emoji, plain",
+            s
+        )),
+    }
+}
+
+fn parse_spec_version(s: &str) -> Result<SpecVersion, String> {
+    SpecVersion::parse(s).ok_or_else(|| {
+        format!(
+            "Invalid CycloneDX spec version: {}. Valid values: 1.4, 1.5, 1.6",
+            s
+        )
+    })
+}
+
+fn parse_graph_format(s: &str) -> Result<GraphFormat, String> {
+    GraphFormat::parse(s)
+        .ok_or_else(|| format!("Invalid graph format: {}. Valid values: dot, mermaid", s))
+}
+
+fn parse_license_source(s: &str) -> Result<LicenseSource, String> {
+    match s.to_lowercase().as_str() {
+        "local" => Ok(LicenseSource::Local),
+        "pypi" => Ok(LicenseSource::Pypi),
+        "auto" => Ok(LicenseSource::Auto),
+        _ => Err(format!(
+            "Invalid license source: {}. Valid values: local, pypi, auto",
+            s
+        )),
+    }
+}
+
+fn parse_progress_format(s: &str) -> Result<ProgressFormat, String> {
+    match s.to_lowercase().as_str() {
+        "text" => Ok(ProgressFormat::Text),
+        "json" => Ok(ProgressFormat::Json),
+        _ => Err(format!(
+            "Invalid progress format: {}. Valid values: text, json",
+            s
+        )),
+    }
+}
+
+fn parse_progress_display(s: &str) -> Result<ProgressDisplay, String> {
+    match s.to_lowercase().as_str() {
+        "auto" => Ok(ProgressDisplay::Auto),
+        "always" => Ok(ProgressDisplay::Always),
+        "never" => Ok(ProgressDisplay::Never),
+        _ => Err(format!(
+            "Invalid progress mode: {}. Valid values: auto, always, never",
+            s
+        )),
+    }
+}
+
+fn parse_output_base(s: &str) -> Result<OutputBase, String> {
+    match s.to_lowercase().as_str() {
+        "cwd" => Ok(OutputBase::Cwd),
+        "project" => Ok(OutputBase::Project),
+        _ => Err(format!(
+            "Invalid output base: {}. Valid values: project, cwd",
+            s
+        )),
+    }
 }
 
 fn parse_lang(s: &str) -> Result<Locale, String> {
@@ -121,6 +792,39 @@ fn parse_severity_threshold(s: &str) -> Result<Severity, String> {
     }
 }
 
+fn parse_marker_env(s: &str) -> Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid marker variable: {}. Expected KEY=VALUE", s))?;
+    let key = key.trim();
+    if !MARKER_VARIABLES.contains(&key) {
+        return Err(format!(
+            "Unknown marker variable: {}. Valid variables: {}",
+            key,
+            MARKER_VARIABLES.join(", ")
+        ));
+    }
+    Ok((key.to_string(), value.trim().to_string()))
+}
+
+fn parse_cvss_preference(s: &str) -> Result<CvssPreference, String> {
+    match s.to_lowercase().as_str() {
+        "v4" => Ok(CvssPreference::V4),
+        "v3" => Ok(CvssPreference::V3),
+        _ => Err(format!(
+            "Invalid CVSS preference: {}. Valid values: v4, v3",
+            s
+        )),
+    }
+}
+
+fn parse_timeout(s: &str) -> Result<Duration, String> {
+    match s.parse::<u64>() {
+        Ok(seconds) if seconds > 0 => Ok(Duration::from_secs(seconds)),
+        _ => Err("Timeout must be a positive number of seconds".to_string()),
+    }
+}
+
 fn parse_cvss_threshold(s: &str) -> Result<f32, String> {
     let threshold: f32 = s
         .parse()
@@ -195,6 +899,348 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_cvss_preference() {
+        assert_eq!(parse_cvss_preference("v4").unwrap(), CvssPreference::V4);
+        assert_eq!(parse_cvss_preference("V3").unwrap(), CvssPreference::V3);
+        let result = parse_cvss_preference("v2");
+        assert!(result.unwrap_err().contains("Invalid CVSS preference"));
+    }
+
+    #[test]
+    fn test_parse_markdown_style() {
+        assert_eq!(
+            parse_markdown_style("emoji").unwrap(),
+            MarkdownStyle::default()
+        );
+        assert_eq!(
+            parse_markdown_style("PLAIN").unwrap(),
+            MarkdownStyle::plain()
+        );
+        let result = parse_markdown_style("ascii");
+        assert!(result.unwrap_err().contains("Invalid markdown style"));
+    }
+
+    #[test]
+    fn test_parse_spec_version() {
+        assert_eq!(parse_spec_version("1.5").unwrap(), SpecVersion::V1_5);
+        assert_eq!(parse_spec_version("1.6").unwrap(), SpecVersion::V1_6);
+        let result = parse_spec_version("2.0");
+        assert!(result
+            .unwrap_err()
+            .contains("Invalid CycloneDX spec version"));
+    }
+
+    #[test]
+    fn test_diff_subcommand() {
+        let args = Args::parse_from([
+            "uv-sbom", "diff", "--base", "v1.json", "--head", ".", "--lang", "ja",
+        ]);
+        let Some(Command::Diff(diff)) = &args.command else {
+            panic!("expected the diff subcommand");
+        };
+        assert_eq!(diff.base, "v1.json");
+        assert_eq!(diff.head, ".");
+        assert_eq!(diff.format, OutputFormat::Markdown);
+        assert_eq!(args.lang, Locale::Ja);
+
+        assert!(Args::parse_from(["uv-sbom"]).command.is_none());
+        assert!(Args::try_parse_from(["uv-sbom", "diff", "--base", "."]).is_err());
+        // Generation options do not apply to diff
+        assert!(Args::try_parse_from([
+            "uv-sbom",
+            "--check-license",
+            "diff",
+            "--base",
+            ".",
+            "--head",
+            "."
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_merge_subcommand() {
+        let args = Args::parse_from([
+            "uv-sbom",
+            "merge",
+            "api",
+            "worker",
+            "-e",
+            "debug-*",
+            "--offline",
+        ]);
+        let Some(Command::Merge(merge)) = &args.command else {
+            panic!("expected the merge subcommand");
+        };
+        assert_eq!(merge.projects, ["api", "worker"]);
+        assert_eq!(merge.exclude, ["debug-*"]);
+        assert_eq!(merge.format, OutputFormat::Json);
+        assert!(merge.offline);
+        assert!(!merge.no_check_cve);
+
+        // At least two projects are required
+        assert!(Args::try_parse_from(["uv-sbom", "merge", "api"]).is_err());
+    }
+
+    #[test]
+    fn test_verify_subcommand() {
+        let args = Args::parse_from(["uv-sbom", "verify", "sbom.json"]);
+        let Some(Command::Verify(verify)) = &args.command else {
+            panic!("expected the verify subcommand");
+        };
+        assert_eq!(verify.sbom, "sbom.json");
+        assert_eq!(verify.digest, None);
+
+        let args = Args::parse_from(["uv-sbom", "verify", "sbom.json", "--digest", "sums.txt"]);
+        let Some(Command::Verify(verify)) = &args.command else {
+            panic!("expected the verify subcommand");
+        };
+        assert_eq!(verify.digest.as_deref(), Some("sums.txt"));
+
+        assert!(Args::try_parse_from(["uv-sbom", "--emit-digest", "--workspace"]).is_err());
+    }
+
+    #[test]
+    fn test_scan_subcommand() {
+        let args = Args::parse_from([
+            "uv-sbom",
+            "scan",
+            "--sbom",
+            "sbom.json",
+            "--severity-threshold",
+            "high",
+            "-i",
+            "CVE-2024-1234",
+        ]);
+        let Some(Command::Scan(scan)) = &args.command else {
+            panic!("expected the scan subcommand");
+        };
+        assert_eq!(scan.sbom, "sbom.json");
+        assert_eq!(scan.format, OutputFormat::Markdown);
+        assert_eq!(scan.severity_threshold, Some(Severity::High));
+        assert_eq!(scan.ignore_cve, ["CVE-2024-1234"]);
+
+        assert!(Args::try_parse_from(["uv-sbom", "scan"]).is_err());
+        assert!(Args::try_parse_from([
+            "uv-sbom",
+            "scan",
+            "--sbom",
+            "sbom.json",
+            "--severity-threshold",
+            "high",
+            "--cvss-threshold",
+            "7.0"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_output_dir_flags() {
+        assert_eq!(Args::parse_from(["uv-sbom"]).format, [OutputFormat::Json]);
+        let args = Args::parse_from([
+            "uv-sbom",
+            "-f",
+            "json",
+            "--format",
+            "markdown",
+            "--output-dir",
+            "out",
+            "--stdout-format",
+            "markdown",
+        ]);
+        assert_eq!(args.format, [OutputFormat::Json, OutputFormat::Markdown]);
+        assert_eq!(args.output_dir.as_deref(), Some("out"));
+        assert_eq!(args.stdout_format, Some(OutputFormat::Markdown));
+
+        // --stdout-format only applies to --output-dir, which replaces --output
+        assert!(Args::try_parse_from(["uv-sbom", "--stdout-format", "json"]).is_err());
+        assert!(
+            Args::try_parse_from(["uv-sbom", "--output-dir", "out", "-o", "sbom.json"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_output_path_flags() {
+        let args = Args::parse_from(["uv-sbom"]);
+        assert!(!args.create_dirs);
+        assert_eq!(args.output_relative_to, OutputBase::Cwd);
+
+        let args = Args::parse_from([
+            "uv-sbom",
+            "-o",
+            "reports/sbom.json",
+            "--create-dirs",
+            "--output-relative-to",
+            "project",
+        ]);
+        assert!(args.create_dirs);
+        assert_eq!(args.output_relative_to, OutputBase::Project);
+
+        assert!(Args::try_parse_from(["uv-sbom", "--output-relative-to", "home"]).is_err());
+    }
+
+    #[test]
+    fn test_verbosity_flags() {
+        let verbosity = |args: &[&str]| Args::parse_from(args).verbosity();
+        assert_eq!(verbosity(&["uv-sbom"]), Verbosity::Normal);
+        assert_eq!(verbosity(&["uv-sbom", "-q"]), Verbosity::Quiet);
+        assert_eq!(verbosity(&["uv-sbom", "--verbose"]), Verbosity::Verbose);
+        assert!(Args::try_parse_from(["uv-sbom", "-q", "-v"]).is_err());
+        assert_eq!(
+            verbosity(&["uv-sbom", "--progress-format", "json"]),
+            Verbosity::Quiet
+        );
+    }
+
+    #[test]
+    fn test_parse_progress_format() {
+        assert_eq!(parse_progress_format("text").unwrap(), ProgressFormat::Text);
+        assert_eq!(parse_progress_format("JSON").unwrap(), ProgressFormat::Json);
+        assert!(parse_progress_format("yaml").is_err());
+        assert_eq!(
+            Args::parse_from(["uv-sbom"]).progress_format,
+            ProgressFormat::Text
+        );
+    }
+
+    #[test]
+    fn test_parse_progress_display() {
+        assert_eq!(
+            parse_progress_display("always").unwrap(),
+            ProgressDisplay::Always
+        );
+        assert_eq!(
+            parse_progress_display("Never").unwrap(),
+            ProgressDisplay::Never
+        );
+        assert!(parse_progress_display("sometimes").is_err());
+        assert_eq!(
+            Args::parse_from(["uv-sbom"]).progress,
+            ProgressDisplay::Auto
+        );
+    }
+
+    #[test]
+    fn test_graph_export_options() {
+        let args = Args::parse_from([
+            "uv-sbom",
+            "--graph-format",
+            "mermaid",
+            "--graph-output",
+            "deps.mmd",
+        ]);
+        assert_eq!(args.graph_format, Some(GraphFormat::Mermaid));
+        assert_eq!(args.graph_output.as_deref(), Some("deps.mmd"));
+
+        assert!(Args::try_parse_from(["uv-sbom", "--graph-format", "dot"]).is_err());
+        assert!(Args::try_parse_from(["uv-sbom", "--graph-output", "deps.dot"]).is_err());
+        assert!(parse_graph_format("svg")
+            .unwrap_err()
+            .contains("Invalid graph format"));
+    }
+
+    #[test]
+    fn test_cve_baseline_options() {
+        let args = Args::parse_from([
+            "uv-sbom",
+            "--cve-baseline",
+            "cve-baseline.json",
+            "--update-cve-baseline",
+        ]);
+        assert_eq!(args.cve_baseline.as_deref(), Some("cve-baseline.json"));
+        assert!(args.update_cve_baseline);
+
+        assert!(Args::try_parse_from(["uv-sbom", "--update-cve-baseline"]).is_err());
+        assert!(Args::try_parse_from([
+            "uv-sbom",
+            "--cve-baseline",
+            "cve-baseline.json",
+            "--no-check-cve"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_license_source() {
+        assert_eq!(parse_license_source("local").unwrap(), LicenseSource::Local);
+        assert_eq!(parse_license_source("PyPI").unwrap(), LicenseSource::Pypi);
+        assert_eq!(parse_license_source("auto").unwrap(), LicenseSource::Auto);
+        let result = parse_license_source("conda");
+        assert!(result.unwrap_err().contains("Invalid license source"));
+    }
+
+    #[test]
+    fn test_max_lockfile_bytes() {
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(args.max_lockfile_bytes(), DEFAULT_MAX_LOCKFILE_SIZE);
+
+        let args = Args::parse_from(["uv-sbom", "--max-lockfile-size", "200"]);
+        assert_eq!(args.max_lockfile_bytes(), 200 * 1024 * 1024);
+
+        assert!(Args::try_parse_from(["uv-sbom", "--max-lockfile-size", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_cve_cache_ttl() {
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(args.cve_cache_ttl(), Duration::from_secs(24 * 60 * 60));
+        assert!(!args.refresh_cve_cache);
+
+        let args = Args::parse_from(["uv-sbom", "--cve-cache-ttl", "0", "--refresh-cve-cache"]);
+        assert_eq!(args.cve_cache_ttl(), Duration::ZERO);
+        assert!(args.refresh_cve_cache);
+
+        assert!(
+            Args::try_parse_from(["uv-sbom", "--no-check-cve", "--refresh-cve-cache"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_informational_display() {
+        let args = Args::parse_from(["uv-sbom"]);
+        assert_eq!(args.informational_display(), InformationalDisplay::All);
+
+        let args = Args::parse_from(["uv-sbom", "--max-informational", "0"]);
+        assert_eq!(args.informational_display(), InformationalDisplay::Limit(0));
+
+        let args = Args::parse_from(["uv-sbom", "--hide-informational"]);
+        assert_eq!(args.informational_display(), InformationalDisplay::Hidden);
+
+        assert!(Args::try_parse_from([
+            "uv-sbom",
+            "--hide-informational",
+            "--max-informational",
+            "5"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("120").unwrap(), Duration::from_secs(120));
+        for invalid in ["0", "-5", "1.5", "2m", ""] {
+            assert_eq!(
+                parse_timeout(invalid).unwrap_err(),
+                "Timeout must be a positive number of seconds"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_marker_env() {
+        assert_eq!(
+            parse_marker_env("python_version=3.12").unwrap(),
+            ("python_version".to_string(), "3.12".to_string())
+        );
+        assert!(parse_marker_env("python_version")
+            .unwrap_err()
+            .contains("Expected KEY=VALUE"));
+        assert!(parse_marker_env("extra=dev")
+            .unwrap_err()
+            .contains("Unknown marker variable: extra"));
+    }
+
     #[test]
     fn test_parse_cvss_threshold_valid() {
         assert_eq!(parse_cvss_threshold("0.0").unwrap(), 0.0);
@@ -220,4 +1266,21 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("must be a number"));
     }
+
+    #[test]
+    fn test_dry_run_mode() {
+        assert_eq!(Args::parse_from(["uv-sbom"]).dry_run, None);
+        assert_eq!(
+            Args::parse_from(["uv-sbom", "--dry-run"]).dry_run,
+            Some(DryRunMode::Basic)
+        );
+        assert_eq!(
+            Args::parse_from(["uv-sbom", "--dry-run=full"]).dry_run,
+            Some(DryRunMode::Full)
+        );
+        // The mode must be attached with '=', so a following argument is not taken as one
+        let args = Args::parse_from(["uv-sbom", "--dry-run", "--no-check-cve"]);
+        assert_eq!(args.dry_run, Some(DryRunMode::Basic));
+        assert!(Args::try_parse_from(["uv-sbom", "--dry-run=deep"]).is_err());
+    }
 }
@@ -0,0 +1,649 @@
+//! Path handling for user-supplied CLI paths (`--path`, `--output`, `--config`,
+//! `--lockfile`, `--pyproject`).
+//!
+//! Expands a leading `~` to the home directory and classifies why a project
+//! directory cannot be used, so error messages can distinguish missing paths,
+//! non-directories, permission problems, and dangling symbolic links.
+
+use super::OutputBase;
+use crate::shared::error::UvSbomError;
+use crate::shared::Result;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Reason a user-supplied directory path cannot be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathProblem {
+    /// Nothing exists at the path
+    NotFound,
+    /// The path exists but is not a directory
+    NotADirectory,
+    /// The path (or one of its parents) cannot be accessed
+    PermissionDenied,
+    /// The path is a symbolic link whose target does not exist
+    BrokenSymlink { target: PathBuf },
+    /// A file was expected but the path is a directory
+    IsADirectory,
+}
+
+impl PathProblem {
+    /// Returns the human-readable reason used in error messages.
+    pub fn reason(&self) -> String {
+        match self {
+            PathProblem::NotFound => "Directory does not exist".to_string(),
+            PathProblem::NotADirectory => "Not a directory".to_string(),
+            PathProblem::PermissionDenied => "Permission denied".to_string(),
+            PathProblem::BrokenSymlink { target } => {
+                format!("Broken symbolic link (target: {})", target.display())
+            }
+            PathProblem::IsADirectory => "Is a directory".to_string(),
+        }
+    }
+
+    /// Returns the reason used when the path was expected to be a file.
+    pub fn file_reason(&self) -> String {
+        match self {
+            PathProblem::NotFound => "File does not exist".to_string(),
+            _ => self.reason(),
+        }
+    }
+}
+
+/// Returns the current user's home directory from the environment.
+///
+/// Uses `HOME` on Unix-like systems and `USERPROFILE` on Windows.
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|h| !h.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Expands a leading `~` in a user-supplied path to the home directory.
+///
+/// Only `~` on its own or followed by a path separator is expanded; `~user`
+/// forms and tildes elsewhere in the path are left untouched.
+pub fn expand_tilde(raw: &str) -> PathBuf {
+    expand_tilde_with_home(raw, home_dir().as_deref())
+}
+
+/// Expands a leading `~` using an explicit home directory.
+///
+/// The remainder is split on both `/` and `\` and joined component by
+/// component, so `~\reports` under a home of `C:\Users\alice` does not end up
+/// with mixed separators. Returns the input unchanged when `home` is `None`.
+pub fn expand_tilde_with_home(raw: &str, home: Option<&Path>) -> PathBuf {
+    let Some(home) = home else {
+        return PathBuf::from(raw);
+    };
+    if raw == "~" {
+        return home.to_path_buf();
+    }
+    match raw.strip_prefix("~/").or_else(|| raw.strip_prefix("~\\")) {
+        Some(rest) => rest
+            .split(['/', '\\'])
+            .filter(|component| !component.is_empty())
+            .fold(home.to_path_buf(), |path, component| path.join(component)),
+        None => PathBuf::from(raw),
+    }
+}
+
+/// Removes the `\\?\` verbatim prefix that `canonicalize` adds on Windows.
+///
+/// `\\?\C:\dir` becomes `C:\dir` and `\\?\UNC\server\share\dir` becomes
+/// `\\server\share\dir`, which is what users type and what other tools accept.
+/// Paths too long for the plain form keep their prefix; everything else is
+/// returned unchanged.
+pub fn simplify_verbatim(path: PathBuf) -> PathBuf {
+    match path.to_str().and_then(strip_verbatim_prefix) {
+        Some(simplified) => PathBuf::from(simplified),
+        None => path,
+    }
+}
+
+/// Longest path the Win32 APIs accept without the verbatim prefix (`MAX_PATH` - 1)
+const MAX_PLAIN_PATH_LEN: usize = 259;
+
+fn strip_verbatim_prefix(raw: &str) -> Option<String> {
+    let simplified = if let Some(rest) = raw.strip_prefix(r"\\?\UNC\") {
+        format!(r"\\{}", rest)
+    } else {
+        let rest = raw.strip_prefix(r"\\?\")?;
+        let bytes = rest.as_bytes();
+        let is_drive = bytes.len() >= 2
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1] == b':'
+            && (bytes.len() == 2 || bytes[2] == b'\\');
+        if !is_drive {
+            return None;
+        }
+        rest.to_string()
+    };
+    (simplified.len() <= MAX_PLAIN_PATH_LEN).then_some(simplified)
+}
+
+/// Classifies why `path` cannot be used as a directory.
+///
+/// Returns `None` when the path resolves (following symlinks) to a directory.
+pub fn classify_directory(path: &Path) -> Option<PathProblem> {
+    let link_metadata = match fs::symlink_metadata(path) {
+        Ok(m) => m,
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+            return Some(PathProblem::PermissionDenied)
+        }
+        Err(_) => return Some(PathProblem::NotFound),
+    };
+
+    let metadata = if link_metadata.is_symlink() {
+        match fs::metadata(path) {
+            Ok(m) => m,
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+                return Some(PathProblem::PermissionDenied)
+            }
+            Err(_) => {
+                let target = fs::read_link(path).unwrap_or_default();
+                return Some(PathProblem::BrokenSymlink { target });
+            }
+        }
+    } else {
+        link_metadata
+    };
+
+    if !metadata.is_dir() {
+        return Some(PathProblem::NotADirectory);
+    }
+
+    if let Err(e) = fs::read_dir(path) {
+        if e.kind() == ErrorKind::PermissionDenied {
+            return Some(PathProblem::PermissionDenied);
+        }
+    }
+
+    None
+}
+
+/// Classifies why `path` cannot be used as an existing file.
+///
+/// Returns `None` when the path resolves (following symlinks) to a readable
+/// file. A missing file is reported as [`PathProblem::NotFound`].
+pub fn classify_file(path: &Path) -> Option<PathProblem> {
+    let link_metadata = match fs::symlink_metadata(path) {
+        Ok(m) => m,
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+            return Some(PathProblem::PermissionDenied)
+        }
+        Err(_) => return Some(PathProblem::NotFound),
+    };
+
+    let metadata = match fs::metadata(path) {
+        Ok(m) => m,
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => {
+            return Some(PathProblem::PermissionDenied)
+        }
+        Err(_) if link_metadata.is_symlink() => {
+            let target = fs::read_link(path).unwrap_or_default();
+            return Some(PathProblem::BrokenSymlink { target });
+        }
+        Err(_) => return Some(PathProblem::NotFound),
+    };
+
+    if metadata.is_dir() {
+        return Some(PathProblem::IsADirectory);
+    }
+
+    match fs::File::open(path) {
+        Err(e) if e.kind() == ErrorKind::PermissionDenied => Some(PathProblem::PermissionDenied),
+        _ => None,
+    }
+}
+
+/// Resolves a user-supplied project directory to its canonical form.
+///
+/// Expands `~`, resolves symbolic links, and validates the result is a
+/// readable directory. On Windows the `\\?\` prefix added by canonicalization
+/// is removed again. Errors report the path exactly as the user typed it.
+pub fn resolve_project_dir(raw: &str) -> Result<PathBuf> {
+    let expanded = expand_tilde(raw);
+
+    if let Some(problem) = classify_directory(&expanded) {
+        return Err(UvSbomError::InvalidProjectPath {
+            path: PathBuf::from(raw),
+            reason: problem.reason(),
+        });
+    }
+
+    let canonical = expanded
+        .canonicalize()
+        .map_err(|e| UvSbomError::InvalidProjectPath {
+            path: PathBuf::from(raw),
+            reason: format!("Failed to canonicalize path: {}", e),
+        })?;
+    let canonical = simplify_verbatim(canonical);
+
+    crate::shared::security::validate_directory_path(&canonical)?;
+    Ok(canonical)
+}
+
+/// Resolves a user-supplied file override such as `--lockfile` or `--pyproject`.
+///
+/// Expands `~` and anchors relative paths at the current working directory,
+/// not the project directory, so `--path services/api --lockfile services/api/uv.lock`
+/// works from the repository root. The file itself is not checked here; the
+/// reader reports the resolved path when it is missing.
+pub fn resolve_file_override(raw: &str) -> Result<PathBuf> {
+    let expanded = expand_tilde(raw);
+    if expanded.is_absolute() {
+        return Ok(expanded);
+    }
+    Ok(std::env::current_dir()?.join(expanded))
+}
+
+/// Resolves a user-supplied output path such as `--output` or `--output-dir`.
+///
+/// Expands `~`. Relative paths stay relative to the current working directory
+/// with [`OutputBase::Cwd`] and are joined to `project_dir` with
+/// [`OutputBase::Project`], so `--path services/api -o sbom.json` can write
+/// next to the project's lockfile.
+pub fn resolve_output_path(raw: &str, base: OutputBase, project_dir: &Path) -> PathBuf {
+    let expanded = expand_tilde(raw);
+    match base {
+        OutputBase::Project if expanded.is_relative() => project_dir.join(expanded),
+        _ => expanded,
+    }
+}
+
+/// Checks that an output file can be created at `resolved`, the resolved form
+/// of `raw` as passed to `option`.
+///
+/// Rejects an existing directory, a dangling symbolic link, and a parent that
+/// is not a usable directory. A parent that does not exist yet is accepted, so
+/// the writer can create it with `--create-dirs` or report it missing.
+pub fn validate_output_path(option: &'static str, raw: &str, resolved: &Path) -> Result<()> {
+    match classify_file(resolved) {
+        Some(PathProblem::NotFound) | None => {}
+        Some(problem @ (PathProblem::IsADirectory | PathProblem::BrokenSymlink { .. })) => {
+            return Err(invalid_path(option, raw, problem.file_reason()))
+        }
+        // An unreadable file may still be replaceable; the writer reports it if not
+        Some(_) => {}
+    }
+    validate_output_parent(option, raw, resolved)
+}
+
+/// Checks that an output directory, such as the `--output` of `--markdown-split`,
+/// can be used or created at `resolved`.
+///
+/// An existing directory is accepted; a file, a dangling symbolic link or an
+/// unreadable directory is rejected. A missing directory is checked like an
+/// output file, through its parent.
+pub fn validate_output_dir(option: &'static str, raw: &str, resolved: &Path) -> Result<()> {
+    match classify_directory(resolved) {
+        None => Ok(()),
+        Some(PathProblem::NotFound) => validate_output_parent(option, raw, resolved),
+        Some(problem) => Err(invalid_path(option, raw, problem.reason())),
+    }
+}
+
+/// Rejects a parent of `resolved` that exists but is not a usable directory
+fn validate_output_parent(option: &'static str, raw: &str, resolved: &Path) -> Result<()> {
+    let parent = resolved.parent().filter(|p| *p != Path::new(""));
+    match parent.and_then(|parent| Some((parent, classify_directory(parent)?))) {
+        None | Some((_, PathProblem::NotFound)) => Ok(()),
+        Some((parent, problem)) => Err(invalid_path(
+            option,
+            raw,
+            format!(
+                "Parent directory {}: {}",
+                parent.display(),
+                problem.reason()
+            ),
+        )),
+    }
+}
+
+fn invalid_path(option: &'static str, raw: &str, reason: String) -> UvSbomError {
+    UvSbomError::InvalidPath {
+        option,
+        path: PathBuf::from(raw),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_expand_tilde_alone() {
+        let home = PathBuf::from("/home/alice");
+        assert_eq!(expand_tilde_with_home("~", Some(&home)), home);
+    }
+
+    #[test]
+    fn test_expand_tilde_with_subpath() {
+        let home = PathBuf::from("/home/alice");
+        assert_eq!(
+            expand_tilde_with_home("~/projects/app", Some(&home)),
+            home.join("projects/app")
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde_leaves_other_paths_untouched() {
+        let home = PathBuf::from("/home/alice");
+        assert_eq!(
+            expand_tilde_with_home("./app", Some(&home)),
+            PathBuf::from("./app")
+        );
+        assert_eq!(
+            expand_tilde_with_home("~bob/app", Some(&home)),
+            PathBuf::from("~bob/app")
+        );
+        assert_eq!(
+            expand_tilde_with_home("/tmp/~/app", Some(&home)),
+            PathBuf::from("/tmp/~/app")
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde_without_home() {
+        assert_eq!(
+            expand_tilde_with_home("~/app", None),
+            PathBuf::from("~/app")
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde_windows_home_with_backslashes() {
+        let home = PathBuf::from(r"C:\Users\alice");
+        assert_eq!(
+            expand_tilde_with_home(r"~\reports\sbom.json", Some(&home)),
+            home.join("reports").join("sbom.json")
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde_mixed_separators() {
+        let home = PathBuf::from(r"C:\Users\alice");
+        assert_eq!(
+            expand_tilde_with_home(r"~/projects\app/", Some(&home)),
+            home.join("projects").join("app")
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde_unc_home() {
+        let home = PathBuf::from(r"\\fileserver\home\alice");
+        assert_eq!(
+            expand_tilde_with_home("~/app", Some(&home)),
+            home.join("app")
+        );
+    }
+
+    #[test]
+    fn test_expand_tilde_leaves_drive_and_unc_paths_untouched() {
+        let home = PathBuf::from(r"C:\Users\alice");
+        for raw in [
+            r"C:\~\app",
+            r"D:app",
+            r"\\server\share\~\app",
+            r"\\?\C:\app",
+        ] {
+            assert_eq!(expand_tilde_with_home(raw, Some(&home)), PathBuf::from(raw));
+        }
+    }
+
+    #[test]
+    fn test_simplify_verbatim_drive_path() {
+        assert_eq!(
+            simplify_verbatim(PathBuf::from(r"\\?\C:\projects\app")),
+            PathBuf::from(r"C:\projects\app")
+        );
+        assert_eq!(
+            simplify_verbatim(PathBuf::from(r"\\?\D:")),
+            PathBuf::from(r"D:")
+        );
+    }
+
+    #[test]
+    fn test_simplify_verbatim_unc_path() {
+        assert_eq!(
+            simplify_verbatim(PathBuf::from(r"\\?\UNC\server\share\app")),
+            PathBuf::from(r"\\server\share\app")
+        );
+    }
+
+    #[test]
+    fn test_simplify_verbatim_leaves_other_paths_untouched() {
+        for raw in [
+            r"C:\projects\app",
+            r"\\server\share\app",
+            r"\\?\Volume{0000}\app",
+            r"\\?\GLOBALROOT\Device",
+            "/home/alice/app",
+        ] {
+            assert_eq!(simplify_verbatim(PathBuf::from(raw)), PathBuf::from(raw));
+        }
+    }
+
+    #[test]
+    fn test_simplify_verbatim_keeps_prefix_for_long_paths() {
+        let long = format!(r"\\?\C:\{}", "a".repeat(300));
+        assert_eq!(
+            simplify_verbatim(PathBuf::from(&long)),
+            PathBuf::from(&long)
+        );
+    }
+
+    #[test]
+    fn test_classify_directory_ok() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(classify_directory(temp_dir.path()), None);
+    }
+
+    #[test]
+    fn test_classify_directory_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+        let missing = temp_dir.path().join("missing");
+        assert_eq!(classify_directory(&missing), Some(PathProblem::NotFound));
+    }
+
+    #[test]
+    fn test_classify_directory_not_a_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("file.txt");
+        fs::write(&file, "x").unwrap();
+        assert_eq!(classify_directory(&file), Some(PathProblem::NotADirectory));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_classify_directory_symlink_to_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("real");
+        fs::create_dir(&target).unwrap();
+        let link = temp_dir.path().join("link");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+        assert_eq!(classify_directory(&link), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_classify_directory_broken_symlink() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("moved-away");
+        let link = temp_dir.path().join("link");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+        assert_eq!(
+            classify_directory(&link),
+            Some(PathProblem::BrokenSymlink { target })
+        );
+    }
+
+    #[test]
+    fn test_path_problem_reason_mentions_symlink_target() {
+        let problem = PathProblem::BrokenSymlink {
+            target: PathBuf::from("/old/location"),
+        };
+        assert!(problem.reason().contains("/old/location"));
+        assert!(problem.reason().contains("Broken symbolic link"));
+    }
+
+    #[test]
+    fn test_resolve_project_dir_preserves_user_form_in_error() {
+        let err = resolve_project_dir("does-not-exist-xyz").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("does-not-exist-xyz"));
+        assert!(message.contains("Directory does not exist"));
+    }
+
+    #[test]
+    fn test_resolve_project_dir_returns_canonical_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let resolved = resolve_project_dir(temp_dir.path().to_str().unwrap()).unwrap();
+        assert_eq!(resolved, temp_dir.path().canonicalize().unwrap());
+    }
+
+    #[test]
+    fn test_classify_file_ok() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("config.yml");
+        fs::write(&file, "x").unwrap();
+        assert_eq!(classify_file(&file), None);
+    }
+
+    #[test]
+    fn test_classify_file_not_found_and_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(
+            classify_file(&temp_dir.path().join("missing.yml")),
+            Some(PathProblem::NotFound)
+        );
+        assert_eq!(
+            classify_file(temp_dir.path()),
+            Some(PathProblem::IsADirectory)
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_classify_file_broken_symlink() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("moved.yml");
+        let link = temp_dir.path().join("config.yml");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+        assert_eq!(
+            classify_file(&link),
+            Some(PathProblem::BrokenSymlink { target })
+        );
+    }
+
+    #[test]
+    fn test_validate_output_path_accepts_new_file_and_missing_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let new_file = temp_dir.path().join("sbom.json");
+        assert!(validate_output_path("--output", "sbom.json", &new_file).is_ok());
+        let nested = temp_dir.path().join("reports").join("sbom.json");
+        assert!(validate_output_path("--output", "reports/sbom.json", &nested).is_ok());
+    }
+
+    #[test]
+    fn test_validate_output_path_rejects_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let err = validate_output_path("--output", "out", temp_dir.path()).unwrap_err();
+        assert!(matches!(
+            err,
+            UvSbomError::InvalidPath { option: "--output", ref reason, .. } if reason == "Is a directory"
+        ));
+    }
+
+    #[test]
+    fn test_validate_output_dir_accepts_existing_and_missing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(validate_output_dir("--output", "out", temp_dir.path()).is_ok());
+        let missing = temp_dir.path().join("docs");
+        assert!(validate_output_dir("--output", "docs", &missing).is_ok());
+    }
+
+    #[test]
+    fn test_validate_output_dir_rejects_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = temp_dir.path().join("sbom.md");
+        fs::write(&file, "x").unwrap();
+        let err = validate_output_dir("--output", "sbom.md", &file).unwrap_err();
+        assert!(matches!(
+            err,
+            UvSbomError::InvalidPath { option: "--output", ref reason, .. } if reason == "Not a directory"
+        ));
+    }
+
+    #[test]
+    fn test_validate_output_path_rejects_file_as_parent() {
+        let temp_dir = TempDir::new().unwrap();
+        let blocker = temp_dir.path().join("reports");
+        fs::write(&blocker, "x").unwrap();
+        let err = validate_output_path("--output", "reports/sbom.json", &blocker.join("sbom.json"))
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Invalid --output path: reports/sbom.json"));
+        assert!(message.contains("Not a directory"));
+    }
+
+    #[test]
+    fn test_resolve_file_override_anchors_relative_paths_at_cwd() {
+        let resolved = resolve_file_override("services/api/uv.lock").unwrap();
+        assert_eq!(
+            resolved,
+            std::env::current_dir()
+                .unwrap()
+                .join("services")
+                .join("api")
+                .join("uv.lock")
+        );
+    }
+
+    #[test]
+    fn test_resolve_file_override_keeps_absolute_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("pyproject.toml");
+        let resolved = resolve_file_override(path.to_str().unwrap()).unwrap();
+        assert_eq!(resolved, path);
+    }
+
+    #[test]
+    fn test_resolve_output_path_relative_to_cwd() {
+        let resolved = resolve_output_path(
+            "reports/sbom.json",
+            OutputBase::Cwd,
+            Path::new("/work/services/api"),
+        );
+        assert_eq!(resolved, PathBuf::from("reports/sbom.json"));
+    }
+
+    #[test]
+    fn test_resolve_output_path_relative_to_project() {
+        let resolved = resolve_output_path(
+            "reports/sbom.json",
+            OutputBase::Project,
+            Path::new("/work/services/api"),
+        );
+        assert_eq!(
+            resolved,
+            Path::new("/work/services/api").join("reports/sbom.json")
+        );
+    }
+
+    #[test]
+    fn test_resolve_output_path_keeps_absolute_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("sbom.json");
+        let resolved = resolve_output_path(
+            path.to_str().unwrap(),
+            OutputBase::Project,
+            Path::new("/work/services/api"),
+        );
+        assert_eq!(resolved, path);
+    }
+}
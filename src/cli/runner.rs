@@ -1,9 +1,29 @@
 use owo_colors::OwoColorize;
+use std::io::Write;
 
+use crate::adapters::outbound::console::AnyProgressReporter;
+use crate::adapters::outbound::network::{NetworkConfig, RequestCounter};
+use crate::i18n::Messages;
+use crate::ports::outbound::{ProgressReporter, Verbosity};
+use crate::shared::error::UvSbomError;
+use crate::shared::terminal::{no_color, stderr_colors_enabled, stderr_text};
 use crate::shared::Result;
 
+use super::path_resolver::{classify_directory, PathProblem};
+
 pub fn display_banner() {
     let version = env!("CARGO_PKG_VERSION");
+    // Legacy consoles print colour codes literally, and logs should not get them
+    if no_color() {
+        eprintln!("uv-sbom v{}", version);
+        eprintln!();
+        return;
+    }
+    if !stderr_colors_enabled() {
+        eprintln!("🚀 uv-sbom v{}", version);
+        eprintln!();
+        return;
+    }
     eprintln!(
         "{} {} {}",
         "🚀".bright_yellow(),
@@ -19,9 +39,14 @@ pub fn display_banner() {
 /// When `suggest_fix` is `true`, verifies that:
 /// - the `uv` CLI is available in PATH
 /// - `pyproject.toml` exists in the given project directory
+/// - `scratch_dir`, where the lock simulation copies the project, is writable
 ///
 /// Prints a warning and returns `false` on the first failing condition.
-pub fn resolve_suggest_fix(suggest_fix: bool, project_path: &std::path::Path) -> bool {
+pub fn resolve_suggest_fix(
+    suggest_fix: bool,
+    project_path: &std::path::Path,
+    scratch_dir: &std::path::Path,
+) -> bool {
     if !suggest_fix {
         return false;
     }
@@ -41,19 +66,143 @@ pub fn resolve_suggest_fix(suggest_fix: bool, project_path: &std::path::Path) ->
         eprintln!("⚠ --suggest-fix requires pyproject.toml in the project directory.");
         return false;
     }
+    if ensure_writable(scratch_dir).is_err() {
+        eprintln!(
+            "⚠ --suggest-fix requires a writable scratch directory, but {} is not writable. \
+             Point --data-dir at a writable location to enable it.",
+            scratch_dir.display()
+        );
+        return false;
+    }
     true
 }
 
-/// Validates that the project path is a valid directory.
+/// Resolves the directory used as the base for cache, state and temporary files.
+///
+/// Returns `None` when no data directory was given, in which case the system
+/// temporary directory is used. A data directory that cannot be created or written
+/// to is reported as a warning and also yields `None`: it never fails the run.
+pub fn resolve_data_dir(
+    data_dir: Option<&std::path::Path>,
+    msgs: &Messages,
+) -> Option<std::path::PathBuf> {
+    let dir = data_dir?;
+    match ensure_writable(dir) {
+        Ok(()) => Some(dir.to_path_buf()),
+        Err(e) => {
+            let reason = match e {
+                UvSbomError::InvalidPath { reason, .. } => reason,
+                other => other.to_string(),
+            };
+            eprintln!(
+                "{}",
+                Messages::format(
+                    msgs.warn_data_dir_not_writable,
+                    &[&dir.display().to_string(), &reason]
+                )
+            );
+            None
+        }
+    }
+}
+
+/// Creates `dir` if needed and checks that a file can be written into it.
+///
+/// Failures are reported as [`UvSbomError::InvalidPath`] for `--data-dir`,
+/// naming a broken symbolic link or non-directory the same way `--path` does.
+fn ensure_writable(dir: &std::path::Path) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .and_then(|()| {
+            tempfile::Builder::new()
+                .prefix(".uv-sbom-probe")
+                .tempfile_in(dir)
+                .map(drop)
+        })
+        .map_err(|e| UvSbomError::InvalidPath {
+            option: "--data-dir",
+            path: dir.to_path_buf(),
+            reason: match classify_directory(dir) {
+                Some(
+                    problem @ (PathProblem::NotADirectory | PathProblem::BrokenSymlink { .. }),
+                ) => problem.reason(),
+                _ => e.to_string(),
+            },
+        })
+}
+
+/// Validates that the project path is a valid directory and returns its canonical form.
 ///
-/// This delegates to `validate_directory_path` in `shared::security`,
-/// which provides comprehensive security validation including:
-/// - Existence check
-/// - Symlink rejection
-/// - Directory type verification
-/// - Path canonicalization for traversal prevention
-pub fn validate_project_path(path: &std::path::Path) -> Result<()> {
-    crate::shared::security::validate_directory_path(path)
+/// This delegates to `resolve_project_dir` in `cli::path_resolver`, which:
+/// - Expands a leading `~` to the home directory
+/// - Resolves symbolic links, reporting dangling links with their target
+/// - Distinguishes missing paths, non-directories, and permission errors
+/// - Runs `validate_directory_path` in `shared::security` on the resolved path
+pub fn validate_project_path(path: &std::path::Path) -> Result<std::path::PathBuf> {
+    super::path_resolver::resolve_project_dir(&path.to_string_lossy())
+}
+
+/// Prints the User-Agent the PyPI and OSV requests will carry, so `--verbose`
+/// runs can confirm the configured contact details. Nothing is printed in
+/// offline mode, which sends no requests.
+pub fn report_user_agent(
+    network: &NetworkConfig,
+    offline: bool,
+    verbosity: Verbosity,
+    msgs: &Messages,
+) {
+    if verbosity == Verbosity::Verbose && !offline {
+        eprintln!(
+            "{}",
+            Messages::format(msgs.progress_user_agent, &[&network.user_agent()])
+        );
+    }
+}
+
+/// Prints the number of HTTP requests sent during the run, broken down by host,
+/// and a warning when the `--max-requests` ceiling cut the run short. In quiet
+/// mode only the warning is printed, and with `--progress-format json` it is
+/// emitted as an `error` event instead.
+pub fn report_request_usage(
+    counter: &RequestCounter,
+    verbosity: Verbosity,
+    reporter: &AnyProgressReporter,
+    msgs: &Messages,
+) {
+    // Printed after the SBOM has been written; if stderr shares a pipe that the
+    // consumer has already closed, the summary is dropped instead of panicking
+    let mut stderr = std::io::stderr();
+    // Offline runs and runs served entirely from the cache send nothing
+    if verbosity != Verbosity::Quiet && counter.total() > 0 {
+        let by_host = counter
+            .by_host()
+            .iter()
+            .map(|(host, count)| format!("{}: {}", host, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _ = writeln!(
+            stderr,
+            "{}",
+            stderr_text(&Messages::format(
+                msgs.progress_http_requests,
+                &[&counter.total().to_string(), &by_host]
+            ))
+        );
+    }
+
+    if let Some(max) = counter.max_requests() {
+        if counter.refused() > 0 {
+            let warning = Messages::format(
+                msgs.warn_request_limit_reached,
+                &[&max.to_string(), &counter.refused().to_string()],
+            );
+            match reporter {
+                AnyProgressReporter::Json(_) => reporter.report_error(&warning),
+                AnyProgressReporter::Text(_) => {
+                    let _ = writeln!(stderr, "{}", warning);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -106,7 +255,11 @@ mod tests {
     fn test_resolve_suggest_fix_disabled() {
         let temp_dir = TempDir::new().unwrap();
         // When suggest_fix is false, always returns false without checking anything
-        assert!(!resolve_suggest_fix(false, temp_dir.path()));
+        assert!(!resolve_suggest_fix(
+            false,
+            temp_dir.path(),
+            temp_dir.path()
+        ));
     }
 
     #[test]
@@ -119,7 +272,7 @@ mod tests {
             .map(|o| o.status.success())
             .unwrap_or(false);
         if uv_available {
-            assert!(!resolve_suggest_fix(true, temp_dir.path()));
+            assert!(!resolve_suggest_fix(true, temp_dir.path(), temp_dir.path()));
         }
     }
 
@@ -137,6 +290,73 @@ mod tests {
             .map(|o| o.status.success())
             .unwrap_or(false);
         // Result should match uv availability
-        assert_eq!(resolve_suggest_fix(true, temp_dir.path()), uv_available);
+        assert_eq!(
+            resolve_suggest_fix(true, temp_dir.path(), temp_dir.path()),
+            uv_available
+        );
+    }
+
+    #[test]
+    fn test_resolve_data_dir_none_when_not_given() {
+        let msgs = Messages::for_locale(crate::i18n::Locale::En);
+        assert_eq!(resolve_data_dir(None, msgs), None);
+    }
+
+    #[test]
+    fn test_resolve_data_dir_creates_missing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("state").join("uv-sbom");
+        let msgs = Messages::for_locale(crate::i18n::Locale::En);
+
+        assert_eq!(
+            resolve_data_dir(Some(&data_dir), msgs),
+            Some(data_dir.clone())
+        );
+        assert!(data_dir.is_dir());
+        // The writability probe must not be left behind
+        assert_eq!(fs::read_dir(&data_dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_resolve_data_dir_falls_back_when_not_writable() {
+        let temp_dir = TempDir::new().unwrap();
+        let blocker = temp_dir.path().join("not-a-dir");
+        fs::write(&blocker, "").unwrap();
+        let msgs = Messages::for_locale(crate::i18n::Locale::En);
+
+        assert_eq!(resolve_data_dir(Some(&blocker.join("data")), msgs), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_ensure_writable_reports_broken_symlink() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("moved-cache");
+        let link = temp_dir.path().join("cache");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let err = ensure_writable(&link).unwrap_err();
+        match err {
+            UvSbomError::InvalidPath { option, reason, .. } => {
+                assert_eq!(option, "--data-dir");
+                assert!(reason.contains("Broken symbolic link"));
+                assert!(reason.contains("moved-cache"));
+            }
+            other => panic!("expected InvalidPath, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolve_suggest_fix_unwritable_scratch_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"test\"\n",
+        )
+        .unwrap();
+        let blocker = temp_dir.path().join("not-a-dir");
+        fs::write(&blocker, "").unwrap();
+
+        assert!(!resolve_suggest_fix(true, temp_dir.path(), &blocker));
     }
 }
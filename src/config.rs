@@ -3,34 +3,50 @@
 //! Provides YAML-based configuration through `uv-sbom.config.yml` files,
 //! including data structures, file loading, and validation.
 
-use anyhow::{bail, Context};
-use serde::Deserialize;
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
+use crate::adapters::outbound::formatters::SpecVersion;
+use crate::application::dto::{ExcludeScope, OutputFormat};
+use crate::sbom_generation::policies::{Compatibility, LicenseFamily, LicenseResolution};
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 
 pub const CONFIG_FILENAME: &str = "uv-sbom.config.yml";
 
+/// Upper bound for `network.osv_detail_workers`, to stay polite to the public OSV API
+const MAX_OSV_DETAIL_WORKERS: usize = 16;
+
 /// Template content for `uv-sbom.config.yml`.
 const CONFIG_TEMPLATE: &str = r#"# uv-sbom configuration file
 # Documentation: https://github.com/Taketo-Yoda/uv-sbom#configuration
 
-# Output format: json | markdown
+# Output format: json | markdown | html
 # format: json
 
+# Formats written by one run with --output-dir (sbom.json, sbom.md, sbom.html); overrides format
+# formats: [json, markdown]
+
 # Package exclusion patterns (supports wildcards)
 # exclude_packages:
 #   - "debug-*"
 #   - "test-*"
 
+# What exclusions apply to: all (also skip the CVE check) | output (still check excluded packages)
+# exclude_scope: all
+
 # Disable CVE vulnerability checking (enabled by default; set to false to opt out)
 # check_cve: true
 
+# Also check packages against a local clone of the PyPA advisory database;
+# with --offline it is the only vulnerability source
+# advisory_db: ~/src/advisory-database
+
 # Severity threshold: low | medium | high | critical
 # severity_threshold: high
 
-# CVSS score threshold (0.0 - 10.0)
+# CVSS score threshold (0.0 - 10.0); set either this or severity_threshold
 # cvss_threshold: 7.0
 
 # CVEs to ignore during vulnerability checks
@@ -38,6 +54,10 @@ const CONFIG_TEMPLATE: &str = r#"# uv-sbom configuration file
 #   - id: CVE-2024-1234
 #     reason: "False positive: code path not reachable"
 #   - id: CVE-2024-5678
+#     state: false_positive   # VEX analysis state: not_affected (default) | false_positive
+
+# Keep ignored CVEs in the output as VEX entries instead of dropping them (JSON format only)
+# vex: false
 
 # Enable license compliance checking
 # check_license: false
@@ -53,9 +73,80 @@ const CONFIG_TEMPLATE: &str = r#"# uv-sbom configuration file
 #     - "GPL-*"
 #   unknown: warn
 
+# Licenses set manually for packages whose PyPI metadata is wrong or missing.
+# Replaces the fetched license; overridden components are marked in the output.
+# license_overrides:
+#   internal-utils:
+#     license: "MIT"
+#     reason: "vendored fork"
+
+# Which PyPI metadata wins when the license field and the classifiers disagree:
+# license_field_first (default) | classifier_first | shortest | spdx_only
+# license_resolution: license_field_first
+
+# Longest license field that is used as is; longer values (usually the full
+# license text) lose to the classifier, or are cut to their first line
+# license_max_length: 200
+
+# Check dependency licenses against the project's own license (Markdown format only)
+# check_license_compatibility: false
+
+# Exit with code 1 when a dependency license is incompatible with the project license
+# fail_on_license_incompatibility: false
+
+# License compatibility matrix overrides (defaults assume binary distribution)
+# license_compatibility:
+#   project_license: "Apache-2.0"   # defaults to [project].license in pyproject.toml
+#   families:                       # permissive | weak-copyleft | strong-copyleft
+#     permissive:
+#       - "LicenseRef-Internal"
+#   matrix:                         # project family -> dependency family -> verdict
+#     permissive:
+#       weak-copyleft: compatible   # compatible | conditional | needs-review | incompatible
+#   licenses:                       # fixed verdicts for specific dependency licenses
+#     "GPL-2.0-or-later WITH Classpath-exception-2.0": conditional
+
+# Report copyleft packages reachable from the project, with how each is introduced (Markdown format only)
+# check_license_reachability: false
+
+# Also report reachable packages whose license is unknown (requires check_license_reachability: true)
+# strict_license_reachability: false
+
+# Licenses reported by the reachability check; -only, -or-later and + variants are included
+# copyleft_licenses:
+#   - "GPL-2.0"
+#   - "GPL-3.0"
+#   - "AGPL-3.0"
+
+# Warn about packages whose requires-python excludes Python versions the project supports
+# check_python_compat: false
+
+# Warn about packages whose names are near misses of popular PyPI packages (e.g. "requets")
+# check_typosquats: false
+
+# Exit with code 1 when the typosquat check reports a package
+# fail_on_typosquat: false
+
+# Largest edit distance from a popular name that is reported (default: 1)
+# typosquat_max_distance: 1
+
+# Package names the typosquat check never reports
+# ignore_typosquats:
+#   - "internal-requets-fork"
+
+# Also check the project's own published name and version for vulnerabilities (requires check_cve: true)
+# check_self: false
+
+# Whether self-check findings above the threshold fail the build (default: true)
+# self_check_fails_build: true
+
 # Suggest upgrade paths to fix vulnerable transitive dependencies (requires check_cve: true)
 # suggest_fix: false
 
+# List the upgrade fixing each vulnerable package in the Markdown report, from the
+# fixed versions in the vulnerability database (requires check_cve: true)
+# suggest_fixes: false
+
 # Enable workspace mode: generate per-member SBOMs for uv workspaces (equivalent to --workspace flag)
 # workspace: false
 
@@ -64,6 +155,25 @@ const CONFIG_TEMPLATE: &str = r#"# uv-sbom configuration file
 
 # Inactivity threshold in days for abandoned-package detection (default: 730)
 # abandoned_threshold_days: 730
+
+# Retries for transient PyPI / OSV failures (429, 5xx, connection errors)
+# network:
+#   max_retries: 3             # retries after the first attempt; 0 disables retrying
+#   retry_base_delay_ms: 200   # first backoff delay, doubled for each retry (with jitter)
+#   proxy_url: http://proxy.example.com:8080   # default: HTTP_PROXY / HTTPS_PROXY
+#   ca_bundle_path: /etc/ssl/corp-ca.pem       # extra trusted root certificates (PEM)
+#   timeout_secs: 30           # request timeout (default: 10 for PyPI, 30 for OSV)
+#   index_url: https://pypi.internal.example.com   # mirror or private index for license lookups
+#                              # credentials: UV_SBOM_INDEX_USERNAME / UV_SBOM_INDEX_PASSWORD
+#   osv_detail_workers: 4      # OSV advisory detail requests in flight at once (1-16)
+#   user_agent_suffix: "(+mailto:secops@example.com)"   # contact details appended to the User-Agent
+#                              # overridden by --user-agent-suffix / UV_SBOM_CONTACT
+#   max_requests: 500          # package lookups per run (licenses + CVE check); the rest are skipped
+#                              # overridden by --max-network-requests
+
+# CycloneDX JSON output settings
+# cyclonedx:
+#   spec_version: "1.6"        # 1.4 | 1.5 | 1.6
 "#;
 
 /// Generate a config template file in the specified directory.
@@ -75,18 +185,16 @@ pub fn generate_config_template(dir: &Path) -> Result<std::path::PathBuf> {
 
     if file_path.exists() {
         let abs_path = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
-        bail!(
+        return Err(UvSbomError::other(format!(
             "{} already exists in {}. Use a different directory or remove the existing file.",
             CONFIG_FILENAME,
             abs_path.display()
-        );
+        )));
     }
 
-    std::fs::write(&file_path, CONFIG_TEMPLATE).with_context(|| {
-        format!(
-            "Failed to write config template to: {}",
-            file_path.display()
-        )
+    std::fs::write(&file_path, CONFIG_TEMPLATE).map_err(|e| UvSbomError::FileWriteError {
+        path: file_path.clone(),
+        details: e.to_string(),
     })?;
 
     let abs_path = file_path
@@ -99,16 +207,47 @@ pub fn generate_config_template(dir: &Path) -> Result<std::path::PathBuf> {
 #[derive(Debug, Deserialize, Default)]
 pub struct ConfigFile {
     pub format: Option<String>,
+    /// Formats written into `--output-dir`; takes precedence over `format`
+    pub formats: Option<Vec<String>>,
     pub exclude_packages: Option<Vec<String>>,
+    /// `all` or `output`; see [`ExcludeScope`]
+    pub exclude_scope: Option<String>,
     pub check_cve: Option<bool>,
+    /// Local PyPA advisory database directory
+    pub advisory_db: Option<String>,
     pub severity_threshold: Option<String>,
     pub cvss_threshold: Option<f64>,
     pub ignore_cves: Option<Vec<IgnoreCve>>,
+    pub vex: Option<bool>,
     pub check_license: Option<bool>,
     pub license_policy: Option<LicensePolicyConfig>,
+    /// Package name -> license to record instead of the fetched one
+    pub license_overrides: Option<BTreeMap<String, LicenseOverrideConfig>>,
+    /// See [`LicenseResolution`]
+    pub license_resolution: Option<String>,
+    /// Longest license field value used as is
+    pub license_max_length: Option<usize>,
+    pub check_license_compatibility: Option<bool>,
+    pub fail_on_license_incompatibility: Option<bool>,
+    pub license_compatibility: Option<LicenseCompatibilityConfig>,
+    pub check_license_reachability: Option<bool>,
+    pub strict_license_reachability: Option<bool>,
+    /// Replaces the default copyleft set of the reachability check
+    pub copyleft_licenses: Option<Vec<String>>,
+    pub check_python_compat: Option<bool>,
+    pub check_typosquats: Option<bool>,
+    pub fail_on_typosquat: Option<bool>,
+    pub typosquat_max_distance: Option<usize>,
+    /// Package names the typosquat check never reports
+    pub ignore_typosquats: Option<Vec<String>>,
+    pub check_self: Option<bool>,
+    pub self_check_fails_build: Option<bool>,
     pub suggest_fix: Option<bool>,
+    pub suggest_fixes: Option<bool>,
     pub check_abandoned: Option<bool>,
     pub abandoned_threshold_days: Option<u64>,
+    pub network: Option<NetworkConfig>,
+    pub cyclonedx: Option<CycloneDxConfig>,
     /// Captures unknown fields for warnings.
     #[serde(flatten)]
     pub unknown_fields: HashMap<String, serde_yaml_ng::Value>,
@@ -122,11 +261,88 @@ pub struct LicensePolicyConfig {
     pub unknown: Option<String>,
 }
 
+/// A manually set license for one package from config file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LicenseOverrideConfig {
+    pub license: String,
+    pub reason: Option<String>,
+}
+
+/// Network settings from config file.
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+pub struct NetworkConfig {
+    /// Retries after the first attempt for transient failures
+    pub max_retries: Option<u32>,
+    /// Delay before the first retry in milliseconds; doubled for each further retry
+    pub retry_base_delay_ms: Option<u64>,
+    /// Proxy for PyPI and OSV requests
+    pub proxy_url: Option<String>,
+    /// PEM file with root certificates to trust in addition to the built-in ones
+    pub ca_bundle_path: Option<String>,
+    /// Request timeout in seconds
+    pub timeout_secs: Option<u64>,
+    /// Mirror or private index serving the PyPI JSON API, used for license lookups
+    pub index_url: Option<String>,
+    /// OSV advisory detail requests in flight at once
+    pub osv_detail_workers: Option<usize>,
+    /// Contact details appended to the User-Agent of PyPI and OSV requests
+    pub user_agent_suffix: Option<String>,
+    /// Package lookups per run across license enrichment and the CVE check
+    pub max_requests: Option<usize>,
+}
+
+/// CycloneDX output settings from config file.
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+pub struct CycloneDxConfig {
+    /// Spec version of the JSON output; accepts `1.5` as well as `"1.5"`
+    #[serde(default, deserialize_with = "deserialize_version_string")]
+    pub spec_version: Option<String>,
+}
+
+/// Reads a version that YAML may have parsed as a number (`1.5`) as a string.
+fn deserialize_version_string<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Version {
+        String(String),
+        Number(f64),
+    }
+
+    Ok(
+        Option::<Version>::deserialize(deserializer)?.map(|version| match version {
+            Version::String(s) => s,
+            Version::Number(n) => n.to_string(),
+        }),
+    )
+}
+
+/// License compatibility matrix overrides from config file.
+///
+/// Keys and values are validated against the family and verdict identifiers
+/// of [`LicenseFamily`] and [`Compatibility`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+pub struct LicenseCompatibilityConfig {
+    pub project_license: Option<String>,
+    /// Family name -> licenses assigned to that family
+    pub families: Option<HashMap<String, Vec<String>>>,
+    /// Project family -> dependency family -> verdict
+    pub matrix: Option<HashMap<String, HashMap<String, String>>>,
+    /// Dependency license -> verdict
+    pub licenses: Option<HashMap<String, String>>,
+}
+
 /// A CVE entry to ignore during vulnerability checks.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct IgnoreCve {
     pub id: String,
     pub reason: Option<String>,
+    /// VEX analysis state reported for this CVE when `vex` output is enabled
+    pub state: Option<IgnoreState>,
 }
 
 impl IgnoreCve {
@@ -134,23 +350,42 @@ impl IgnoreCve {
     pub fn reason(&self) -> Option<&str> {
         self.reason.as_deref()
     }
+
+    /// Returns the VEX analysis state, defaulting to `not_affected`
+    pub fn state(&self) -> IgnoreState {
+        self.state.unwrap_or_default()
+    }
+}
+
+/// Why an ignored CVE does not apply, as a CycloneDX VEX analysis state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IgnoreState {
+    /// The component is not affected by the vulnerability
+    #[default]
+    NotAffected,
+    /// The vulnerability was reported for the component in error
+    FalsePositive,
 }
 
 /// Load config from an explicit path. Returns an error if the file is not found.
 pub fn load_config_from_path(path: &Path) -> Result<ConfigFile> {
-    let content = std::fs::read_to_string(path).with_context(|| {
-        format!(
+    let content = std::fs::read_to_string(path).map_err(|e| UvSbomError::ConfigInvalid {
+        message: format!(
             "Failed to read config file: {}\n\n💡 Hint: Check that the file exists and is readable.",
             path.display()
-        )
+        ),
+        source: Some(Box::new(e)),
     })?;
 
-    let config: ConfigFile = serde_yaml_ng::from_str(&content).with_context(|| {
-        format!(
-            "Failed to parse config file: {}\n\n💡 Hint: Ensure the file contains valid YAML syntax.",
-            path.display()
-        )
-    })?;
+    let config: ConfigFile =
+        serde_yaml_ng::from_str(&content).map_err(|e| UvSbomError::ConfigInvalid {
+            message: format!(
+                "Failed to parse config file: {}\n\n💡 Hint: Ensure the file contains valid YAML syntax.",
+                path.display()
+            ),
+            source: Some(Box::new(e)),
+        })?;
 
     validate_config(&config)?;
     warn_unknown_fields(&config);
@@ -170,32 +405,179 @@ pub fn discover_config(dir: &Path) -> Result<Option<ConfigFile>> {
     Ok(Some(config))
 }
 
+/// Creates the error for a config value that failed validation.
+fn invalid_config(message: impl Into<String>) -> UvSbomError {
+    UvSbomError::ConfigInvalid {
+        message: message.into(),
+        source: None,
+    }
+}
+
 /// Validate the loaded configuration.
 fn validate_config(config: &ConfigFile) -> Result<()> {
     if let Some(ref ignore_cves) = config.ignore_cves {
         for (i, entry) in ignore_cves.iter().enumerate() {
             if entry.id.trim().is_empty() {
-                bail!(
-                    "Invalid config: ignore_cves[{}].id must not be empty.\n\n\
+                return Err(invalid_config(format!("Invalid config: ignore_cves[{}].id must not be empty.\n\n\
                      💡 Hint: Each ignore_cves entry must have a non-empty 'id' field (e.g., \"CVE-2024-1234\").",
-                    i
-                );
+                    i)));
             }
         }
     }
 
+    if let Some(ref formats) = config.formats {
+        if formats.is_empty() {
+            return Err(invalid_config(
+                "Invalid config: formats must list at least one format (json, markdown or html)",
+            ));
+        }
+        for format in formats {
+            if format.parse::<OutputFormat>().is_err() {
+                return Err(invalid_config(format!(
+                    "Invalid config: formats must only contain json, markdown or html. Got: \"{}\"",
+                    format
+                )));
+            }
+        }
+    }
+
+    if let Some(ref severity) = config.severity_threshold {
+        let valid = ["low", "medium", "high", "critical"];
+        if !valid.contains(&severity.to_lowercase().as_str()) {
+            return Err(invalid_config(format!(
+                "Invalid config: severity_threshold must be one of: low, medium, high, critical. Got: \"{}\"",
+                severity
+            )));
+        }
+    }
+
+    if let Some(cvss) = config.cvss_threshold {
+        if !(0.0..=10.0).contains(&cvss) {
+            return Err(invalid_config(format!(
+                "Invalid config: cvss_threshold must be between 0.0 and 10.0. Got: {}",
+                cvss
+            )));
+        }
+    }
+
+    if let Some(ref scope) = config.exclude_scope {
+        if scope.parse::<ExcludeScope>().is_err() {
+            return Err(invalid_config(format!(
+                "Invalid config: exclude_scope must be one of: output, all. Got: \"{}\"",
+                scope
+            )));
+        }
+    }
+
+    if let Some(ref resolution) = config.license_resolution {
+        if resolution.parse::<LicenseResolution>().is_err() {
+            return Err(invalid_config(format!(
+                "Invalid config: license_resolution must be one of: classifier_first, license_field_first, shortest, spdx_only. Got: \"{}\"",
+                resolution
+            )));
+        }
+    }
+
+    if config.license_max_length == Some(0) {
+        return Err(invalid_config(
+            "Invalid config: license_max_length must be at least 1",
+        ));
+    }
+
+    if let Some(workers) = config.network.as_ref().and_then(|n| n.osv_detail_workers) {
+        if !(1..=MAX_OSV_DETAIL_WORKERS).contains(&workers) {
+            return Err(invalid_config(format!(
+                "Invalid config: network.osv_detail_workers must be between 1 and {}. Got: {}",
+                MAX_OSV_DETAIL_WORKERS, workers
+            )));
+        }
+    }
+
     if let Some(ref lp) = config.license_policy {
         if let Some(ref unknown) = lp.unknown {
             let valid = ["warn", "deny", "allow"];
             if !valid.contains(&unknown.to_lowercase().as_str()) {
-                bail!(
-                    "Invalid config: license_policy.unknown must be one of: warn, deny, allow. Got: \"{}\"",
-                    unknown
-                );
+                return Err(invalid_config(format!("Invalid config: license_policy.unknown must be one of: warn, deny, allow. Got: \"{}\"",
+                    unknown)));
+            }
+        }
+    }
+
+    if let Some(ref overrides) = config.license_overrides {
+        for (package, entry) in overrides {
+            if entry.license.trim().is_empty() {
+                return Err(invalid_config(format!(
+                    "Invalid config: license_overrides.{}.license must not be empty.\n\n\
+                     💡 Hint: Each license_overrides entry must name a license (e.g., \"MIT\").",
+                    package
+                )));
             }
         }
     }
 
+    if let Some(ref lc) = config.license_compatibility {
+        validate_license_compatibility(lc)?;
+    }
+
+    if let Some(version) = config
+        .cyclonedx
+        .as_ref()
+        .and_then(|c| c.spec_version.as_deref())
+    {
+        if SpecVersion::parse(version).is_none() {
+            return Err(invalid_config(format!(
+                "Invalid config: cyclonedx.spec_version must be one of: 1.4, 1.5, 1.6. Got: \"{}\"",
+                version
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_license_compatibility(config: &LicenseCompatibilityConfig) -> Result<()> {
+    const FAMILIES: &str = "permissive, weak-copyleft, strong-copyleft";
+    const VERDICTS: &str = "compatible, conditional, needs-review, incompatible";
+
+    let check_family = |field: &str, value: &str| -> Result<()> {
+        if LicenseFamily::parse(value).is_none() {
+            return Err(invalid_config(format!(
+                "Invalid config: {} must be one of: {}. Got: \"{}\"",
+                field, FAMILIES, value
+            )));
+        }
+        Ok(())
+    };
+    let check_verdict = |field: &str, value: &str| -> Result<()> {
+        if Compatibility::parse(value).is_none() {
+            return Err(invalid_config(format!(
+                "Invalid config: {} must be one of: {}. Got: \"{}\"",
+                field, VERDICTS, value
+            )));
+        }
+        Ok(())
+    };
+
+    for family in config.families.iter().flat_map(|f| f.keys()) {
+        check_family("license_compatibility.families keys", family)?;
+    }
+    for (project, row) in config.matrix.iter().flatten() {
+        check_family("license_compatibility.matrix keys", project)?;
+        for (dependency, verdict) in row {
+            check_family("license_compatibility.matrix keys", dependency)?;
+            check_verdict(
+                &format!("license_compatibility.matrix.{}.{}", project, dependency),
+                verdict,
+            )?;
+        }
+    }
+    for (license, verdict) in config.licenses.iter().flatten() {
+        check_verdict(
+            &format!("license_compatibility.licenses.\"{}\"", license),
+            verdict,
+        )?;
+    }
+
     Ok(())
 }
 
@@ -257,6 +639,30 @@ ignore_cves:
         assert!(cves[1].reason.is_none());
     }
 
+    #[test]
+    fn test_ignore_cve_state() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        fs::write(
+            &config_path,
+            r#"
+vex: true
+ignore_cves:
+  - id: CVE-2024-1234
+    state: false_positive
+  - id: CVE-2024-5678
+"#,
+        )
+        .unwrap();
+
+        let config = load_config_from_path(&config_path).unwrap();
+        assert_eq!(config.vex, Some(true));
+        let cves = config.ignore_cves.unwrap();
+        assert_eq!(cves[0].state(), IgnoreState::FalsePositive);
+        assert_eq!(cves[1].state, None);
+        assert_eq!(cves[1].state(), IgnoreState::NotAffected);
+    }
+
     #[test]
     fn test_discover_config_found() {
         let dir = TempDir::new().unwrap();
@@ -344,6 +750,267 @@ ignore_cves:
         assert!(err.contains("must not be empty"));
     }
 
+    #[test]
+    fn test_license_overrides_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        fs::write(
+            &config_path,
+            r#"
+license_overrides:
+  internal-utils:
+    license: "MIT"
+    reason: "vendored fork"
+  legacy-client:
+    license: "LicenseRef-Proprietary"
+"#,
+        )
+        .unwrap();
+
+        let config = load_config_from_path(&config_path).unwrap();
+        let overrides = config.license_overrides.unwrap();
+        assert_eq!(
+            overrides["internal-utils"],
+            LicenseOverrideConfig {
+                license: "MIT".to_string(),
+                reason: Some("vendored fork".to_string()),
+            }
+        );
+        assert_eq!(overrides["legacy-client"].reason, None);
+
+        fs::write(
+            &config_path,
+            "license_overrides:\n  internal-utils:\n    license: \" \"\n",
+        )
+        .unwrap();
+        let err = load_config_from_path(&config_path).unwrap_err().to_string();
+        assert!(err.contains("license_overrides.internal-utils.license must not be empty"));
+    }
+
+    #[test]
+    fn test_license_compatibility_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        fs::write(
+            &config_path,
+            r#"
+check_license_compatibility: true
+fail_on_license_incompatibility: true
+license_compatibility:
+  project_license: Apache-2.0
+  families:
+    permissive:
+      - LicenseRef-Internal
+  matrix:
+    permissive:
+      weak-copyleft: compatible
+  licenses:
+    GPL-2.0-or-later: conditional
+"#,
+        )
+        .unwrap();
+
+        let config = load_config_from_path(&config_path).unwrap();
+        assert_eq!(config.check_license_compatibility, Some(true));
+        assert_eq!(config.fail_on_license_incompatibility, Some(true));
+        let lc = config.license_compatibility.unwrap();
+        assert_eq!(lc.project_license.as_deref(), Some("Apache-2.0"));
+        assert_eq!(
+            lc.families.unwrap()["permissive"],
+            vec!["LicenseRef-Internal".to_string()]
+        );
+        assert_eq!(
+            lc.matrix.unwrap()["permissive"]["weak-copyleft"],
+            "compatible"
+        );
+        assert_eq!(lc.licenses.unwrap()["GPL-2.0-or-later"], "conditional");
+    }
+
+    #[test]
+    fn test_license_compatibility_config_validation_errors() {
+        let cases = [
+            (
+                "license_compatibility:\n  families:\n    copyleft: [GPL-3.0-only]\n",
+                "license_compatibility.families keys must be one of",
+            ),
+            (
+                "license_compatibility:\n  matrix:\n    permissive:\n      strong-copyleft: ok\n",
+                "license_compatibility.matrix.permissive.strong-copyleft must be one of",
+            ),
+            (
+                "license_compatibility:\n  licenses:\n    MIT: fine\n",
+                "license_compatibility.licenses.\"MIT\" must be one of",
+            ),
+        ];
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        for (yaml, expected) in cases {
+            fs::write(&config_path, yaml).unwrap();
+            let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+            assert!(err.contains(expected), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_network_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        fs::write(
+            &config_path,
+            r#"
+network:
+  max_retries: 5
+  retry_base_delay_ms: 50
+  proxy_url: http://proxy.internal:3128
+  ca_bundle_path: ~/corp-ca.pem
+  timeout_secs: 60
+  index_url: https://pypi.internal.example.com/
+  osv_detail_workers: 8
+  user_agent_suffix: "(+mailto:secops@example.com)"
+  max_requests: 500
+"#,
+        )
+        .unwrap();
+
+        let config = load_config_from_path(&config_path).unwrap();
+        assert_eq!(
+            config.network,
+            Some(NetworkConfig {
+                max_retries: Some(5),
+                retry_base_delay_ms: Some(50),
+                proxy_url: Some("http://proxy.internal:3128".to_string()),
+                ca_bundle_path: Some("~/corp-ca.pem".to_string()),
+                timeout_secs: Some(60),
+                index_url: Some("https://pypi.internal.example.com/".to_string()),
+                osv_detail_workers: Some(8),
+                user_agent_suffix: Some("(+mailto:secops@example.com)".to_string()),
+                max_requests: Some(500),
+            })
+        );
+        assert!(config.unknown_fields.is_empty());
+
+        fs::write(&config_path, "network:\n  osv_detail_workers: 0\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(
+            err.contains("network.osv_detail_workers must be between 1 and 16"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_cyclonedx_spec_version_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        for (yaml, expected) in [
+            ("cyclonedx:\n  spec_version: 1.5\n", "1.5"),
+            ("cyclonedx:\n  spec_version: \"1.4\"\n", "1.4"),
+        ] {
+            fs::write(&config_path, yaml).unwrap();
+            let config = load_config_from_path(&config_path).unwrap();
+            assert_eq!(
+                config.cyclonedx.unwrap().spec_version.as_deref(),
+                Some(expected)
+            );
+        }
+
+        fs::write(&config_path, "cyclonedx:\n  spec_version: 1.3\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(
+            err.contains("cyclonedx.spec_version must be one of: 1.4, 1.5, 1.6"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_exclude_scope_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        fs::write(&config_path, "exclude_scope: output\n").unwrap();
+        let config = load_config_from_path(&config_path).unwrap();
+        assert_eq!(config.exclude_scope.as_deref(), Some("output"));
+
+        fs::write(&config_path, "exclude_scope: components\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(
+            err.contains("exclude_scope must be one of: output, all"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_license_resolution_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        fs::write(
+            &config_path,
+            "license_resolution: classifier_first\nlicense_max_length: 80\n",
+        )
+        .unwrap();
+        let config = load_config_from_path(&config_path).unwrap();
+        assert_eq!(
+            config.license_resolution.as_deref(),
+            Some("classifier_first")
+        );
+        assert_eq!(config.license_max_length, Some(80));
+
+        fs::write(&config_path, "license_resolution: classifiers\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(err.contains("license_resolution must be one of"), "{}", err);
+
+        fs::write(&config_path, "license_max_length: 0\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(
+            err.contains("license_max_length must be at least 1"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_formats_config() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+        fs::write(&config_path, "formats: [json, md]\n").unwrap();
+        let config = load_config_from_path(&config_path).unwrap();
+        assert_eq!(
+            config.formats,
+            Some(vec!["json".to_string(), "md".to_string()])
+        );
+
+        fs::write(&config_path, "formats: [json, spdx]\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(err.contains("Got: \"spdx\""), "{}", err);
+
+        fs::write(&config_path, "formats: []\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(err.contains("at least one format"), "{}", err);
+    }
+
+    #[test]
+    fn test_threshold_config_validation_errors() {
+        let dir = TempDir::new().unwrap();
+        let config_path = dir.path().join("config.yml");
+
+        fs::write(&config_path, "severity_threshold: severe\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(
+            err.contains("severity_threshold must be one of: low, medium, high, critical"),
+            "{}",
+            err
+        );
+
+        fs::write(&config_path, "cvss_threshold: 12.5\n").unwrap();
+        let err = format!("{}", load_config_from_path(&config_path).unwrap_err());
+        assert!(
+            err.contains("cvss_threshold must be between 0.0 and 10.0"),
+            "{}",
+            err
+        );
+    }
+
     #[test]
     fn test_unknown_fields_warning() {
         let dir = TempDir::new().unwrap();
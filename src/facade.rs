@@ -0,0 +1,404 @@
+//! High-level entry point that wires the default adapter stack
+//!
+//! [`Sbom`] reads the lockfile from disk, enriches licenses from PyPI,
+//! optionally checks OSV for vulnerabilities and formats the result, without
+//! the caller naming any adapter or type parameter. Callers that need other
+//! adapters can still assemble [`GenerateSbomUseCase`] themselves.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use uv_sbom::prelude::*;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! let output = Sbom::builder()
+//!     .project_path(".")
+//!     .check_cve(true)
+//!     .format(OutputFormat::Markdown)
+//!     .quiet()
+//!     .build()?
+//!     .run()
+//!     .await?;
+//!
+//! println!("{}", output.formatted);
+//! if output.response.has_vulnerabilities_above_threshold {
+//!     eprintln!("vulnerabilities found");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::adapters::outbound::console::{SilentProgressReporter, StderrProgressReporter};
+use crate::adapters::outbound::filesystem::{AnyLockfileReader, FileSystemReader};
+use crate::adapters::outbound::network::{
+    CachingPyPiLicenseRepository, OsvClient, PyPiLicenseRepository, PyPiMaintenanceRepository,
+};
+use crate::application::dto::{OutputFormat, SbomRequest, SbomResponse};
+use crate::application::factories::FormatterFactory;
+use crate::application::read_models::SbomReadModelBuilder;
+use crate::application::use_cases::GenerateSbomUseCase;
+use crate::i18n::Locale;
+use crate::ports::outbound::{
+    LicenseFetchFields, LicenseRepository, ProgressReporter, ProjectConfigReader,
+};
+use crate::shared::Result;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// A configured SBOM run over the default adapters
+///
+/// Created through [`Sbom::builder`]; the request is validated when the
+/// builder's `build` is called, before any file or network access.
+#[derive(Debug)]
+pub struct Sbom {
+    request: SbomRequest,
+    format: OutputFormat,
+    quiet: bool,
+    cache_licenses: bool,
+}
+
+/// The formatted SBOM together with the response it was rendered from
+#[derive(Debug)]
+pub struct SbomOutput {
+    /// The SBOM in the requested format
+    pub formatted: String,
+    /// Packages, check results and warnings of the run
+    pub response: SbomResponse,
+}
+
+impl Sbom {
+    /// Creates a builder with CycloneDX JSON output and no CVE check
+    pub fn builder() -> SbomBuilder {
+        SbomBuilder::new()
+    }
+
+    /// Generates the SBOM and formats it
+    ///
+    /// Progress goes to stderr unless the builder was made `quiet`.
+    pub async fn run(self) -> Result<SbomOutput> {
+        let pypi = PyPiLicenseRepository::new()?.with_fields(self.request.license_fetch_fields);
+        let locale = self.request.locale;
+        match (self.cache_licenses, self.quiet) {
+            (true, true) => {
+                self.execute(
+                    CachingPyPiLicenseRepository::new(pypi),
+                    SilentProgressReporter,
+                )
+                .await
+            }
+            (true, false) => {
+                self.execute(
+                    CachingPyPiLicenseRepository::new(pypi),
+                    StderrProgressReporter::new(locale),
+                )
+                .await
+            }
+            (false, true) => self.execute(pypi, SilentProgressReporter).await,
+            (false, false) => {
+                self.execute(pypi, StderrProgressReporter::new(locale))
+                    .await
+            }
+        }
+    }
+
+    async fn execute<LREPO, PR>(self, license_repository: LREPO, progress: PR) -> Result<SbomOutput>
+    where
+        LREPO: LicenseRepository + Clone,
+        PR: ProgressReporter,
+    {
+        let project_path = self.request.project_path.clone();
+        let locale = self.request.locale;
+        let vulnerability_repository = if self.request.check_cve {
+            Some(OsvClient::new()?)
+        } else {
+            None
+        };
+
+        let use_case = GenerateSbomUseCase::new(
+            AnyLockfileReader::detect(&project_path).0,
+            FileSystemReader::new(),
+            license_repository,
+            progress,
+            vulnerability_repository,
+            None::<PyPiMaintenanceRepository>,
+            locale,
+        );
+        let response = use_case.execute(self.request).await?;
+
+        let project_reader = FileSystemReader::new();
+        let project_component_info =
+            project_reader
+                .read_project_name(&project_path)
+                .ok()
+                .map(|name| {
+                    let version = project_reader
+                        .read_project_version(&project_path)
+                        .ok()
+                        .flatten()
+                        .or_else(|| {
+                            response
+                                .enriched_packages
+                                .iter()
+                                .find(|ep| ep.package.name() == name)
+                                .map(|ep| ep.package.version().to_string())
+                        });
+                    (name, version)
+                });
+        let read_model = SbomReadModelBuilder::build_with_project(
+            response.enriched_packages.clone(),
+            &response.metadata,
+            response.dependency_graph.as_ref(),
+            response.vulnerability_check_result.as_ref(),
+            response.license_compliance_result.as_ref(),
+            project_component_info
+                .as_ref()
+                .map(|(name, version)| (name.as_str(), version.as_deref())),
+            response.upgrade_recommendations.as_deref(),
+        )
+        .with_annotations(&response.package_annotations)
+        .with_license_overrides(&response.license_overrides)
+        .with_warnings(response.warnings.clone());
+
+        let formatter = FormatterFactory::create(
+            self.format,
+            None,
+            locale,
+            Default::default(),
+            Default::default(),
+        );
+        let formatted = formatter.format(&read_model)?;
+
+        Ok(SbomOutput {
+            formatted,
+            response,
+        })
+    }
+}
+
+/// Builder for [`Sbom`]
+///
+/// Covers the common settings; advanced callers can build a [`SbomRequest`]
+/// and run [`GenerateSbomUseCase`] directly.
+#[derive(Debug)]
+pub struct SbomBuilder {
+    project_path: Option<PathBuf>,
+    check_cve: bool,
+    format: OutputFormat,
+    exclude_patterns: Vec<String>,
+    timeout: Option<Duration>,
+    offline: bool,
+    minimal_metadata: bool,
+    quiet: bool,
+    cache_licenses: bool,
+    locale: Locale,
+}
+
+impl SbomBuilder {
+    /// Creates a new SbomBuilder with default values.
+    ///
+    /// Default values:
+    /// - project_path: None (required)
+    /// - check_cve: false
+    /// - format: CycloneDX JSON
+    /// - quiet: false (progress on stderr)
+    /// - cache_licenses: true
+    pub fn new() -> Self {
+        Self {
+            project_path: None,
+            check_cve: false,
+            format: OutputFormat::Json,
+            exclude_patterns: Vec::new(),
+            timeout: None,
+            offline: false,
+            minimal_metadata: false,
+            quiet: false,
+            cache_licenses: true,
+            locale: Locale::default(),
+        }
+    }
+
+    /// Sets the project directory containing the lockfile (required).
+    pub fn project_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.project_path = Some(path.into());
+        self
+    }
+
+    /// Sets whether to check OSV for vulnerabilities.
+    pub fn check_cve(mut self, check: bool) -> Self {
+        self.check_cve = check;
+        self
+    }
+
+    /// Sets the output format.
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the exclusion patterns for filtering packages.
+    pub fn exclude_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.exclude_patterns = patterns;
+        self
+    }
+
+    /// Sets an overall deadline for license enrichment and the CVE check.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Skips every network lookup; licenses come from local package sources only.
+    ///
+    /// Cannot be combined with `check_cve(true)`.
+    pub fn offline(mut self) -> Self {
+        self.offline = true;
+        self
+    }
+
+    /// Resolves licenses only and leaves package descriptions empty.
+    ///
+    /// Only valid for CycloneDX JSON output.
+    pub fn minimal_metadata(mut self) -> Self {
+        self.minimal_metadata = true;
+        self
+    }
+
+    /// Writes nothing to stderr; warnings remain available on the response.
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    /// Sets whether repeated license lookups for a package are served from memory.
+    pub fn cache_licenses(mut self, cache: bool) -> Self {
+        self.cache_licenses = cache;
+        self
+    }
+
+    /// Sets the locale for messages and localized output.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Validates the settings and builds the [`Sbom`].
+    ///
+    /// # Errors
+    /// Returns an error if the project path is missing, if `offline` is combined with
+    /// the CVE check, or if `minimal_metadata` is combined with Markdown or HTML output.
+    pub fn build(self) -> Result<Sbom> {
+        let describes_packages = matches!(self.format, OutputFormat::Markdown | OutputFormat::Html);
+        let mut request = SbomRequest::builder()
+            .include_dependency_info(describes_packages)
+            .show_descriptions(describes_packages)
+            .exclude_patterns(self.exclude_patterns)
+            .check_cve(self.check_cve)
+            .timeout_opt(self.timeout)
+            .offline(self.offline)
+            .license_fetch_fields(if self.minimal_metadata {
+                LicenseFetchFields::LicenseOnly
+            } else {
+                LicenseFetchFields::Full
+            })
+            .locale(self.locale);
+        if let Some(project_path) = self.project_path {
+            request = request.project_path(project_path);
+        }
+
+        Ok(Sbom {
+            request: request.build()?,
+            format: self.format,
+            quiet: self.quiet,
+            cache_licenses: self.cache_licenses,
+        })
+    }
+}
+
+impl Default for SbomBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_requires_project_path() {
+        let result = Sbom::builder().build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_build_includes_dependency_info_for_markdown() {
+        let sbom = Sbom::builder()
+            .project_path("/tmp/project")
+            .format(OutputFormat::Markdown)
+            .build()
+            .unwrap();
+
+        assert!(sbom.request.include_dependency_info);
+        assert!(!sbom.request.check_cve);
+    }
+
+    #[test]
+    fn test_build_defaults_to_cyclonedx_without_cve_check() {
+        let sbom = Sbom::builder()
+            .project_path("/tmp/project")
+            .build()
+            .unwrap();
+
+        assert_eq!(sbom.format, OutputFormat::Json);
+        assert!(!sbom.request.include_dependency_info);
+        assert!(!sbom.request.check_cve);
+        assert!(sbom.cache_licenses);
+        assert!(!sbom.quiet);
+    }
+
+    #[test]
+    fn test_build_rejects_cve_check_offline() {
+        let result = Sbom::builder()
+            .project_path("/tmp/project")
+            .check_cve(true)
+            .offline()
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_build_rejects_minimal_metadata_for_markdown() {
+        let result = Sbom::builder()
+            .project_path("/tmp/project")
+            .format(OutputFormat::Markdown)
+            .minimal_metadata()
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_formats_offline_fixture_project() {
+        let output = Sbom::builder()
+            .project_path(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/sample-project"
+            ))
+            .format(OutputFormat::Markdown)
+            .offline()
+            .quiet()
+            .build()
+            .unwrap()
+            .run()
+            .await
+            .unwrap();
+
+        assert!(!output.response.enriched_packages.is_empty());
+        for enriched in &output.response.enriched_packages {
+            assert!(output.formatted.contains(enriched.package.name()));
+        }
+    }
+}
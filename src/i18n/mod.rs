@@ -29,17 +29,40 @@ impl Locale {
     }
 }
 
+/// How a locale picks between the singular and plural unit words of a count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralRule {
+    /// Singular for exactly one, plural otherwise (English)
+    OneOther,
+    /// Nouns do not inflect for number, so the singular word is always used (Japanese)
+    Invariant,
+}
+
 /// All translatable strings used in formatted output.
 pub struct Messages {
+    /// Plural rule used by [`Messages::unit`]
+    pub plural_rule: PluralRule,
+
     // Section headers
     pub section_sbom_title: &'static str,
     pub section_component_inventory: &'static str,
     pub section_direct_deps: &'static str,
     pub section_transitive_deps: &'static str,
+    /// Packages no `--roots` package reaches
+    pub section_unreferenced_packages: &'static str,
     pub section_vuln_report: &'static str,
     pub section_license_compliance: &'static str,
     pub section_resolution_guide: &'static str,
     pub section_dependency_chains: &'static str,
+    pub label_target_platform: &'static str,
+    pub label_project: &'static str,
+
+    // Navigation for split Markdown output (--markdown-split)
+    pub label_split_contents: &'static str,
+    pub label_split_back_to_index: &'static str,
+    pub label_split_components: &'static str,
+    pub label_split_dependencies: &'static str,
+    pub label_split_vulnerabilities: &'static str,
 
     // Table column headers
     pub col_package: &'static str,
@@ -50,30 +73,110 @@ pub struct Messages {
     pub col_fixed_version: &'static str,
     pub col_severity: &'static str,
     pub col_vuln_id: &'static str,
+    pub col_aliases: &'static str,
+    pub col_via: &'static str,
+    pub col_declared_constraint: &'static str,
+    pub col_supplier: &'static str,
+    /// Via cell entry for a vulnerable package that is itself a direct dependency
+    pub label_via_direct: &'static str,
+    /// Note after a vulnerable package that `exclude_scope: output` left out of the component list
+    pub label_excluded_from_inventory: &'static str,
+    pub label_cve_baseline: &'static str,
     pub col_cvss: &'static str,
 
     // Progress messages (formatter/main layer)
     pub progress_generating_json: &'static str,
     pub progress_generating_markdown: &'static str,
+    pub progress_generating_html: &'static str,
     pub progress_verifying_links: &'static str,
+    pub progress_cyclonedx_valid: &'static str,
     pub progress_fetching_license: &'static str,
     pub progress_fetching_vulns: &'static str,
 
     // Progress messages (use case layer)
     pub progress_loading_lockfile: &'static str,
     pub progress_detected_packages: &'static str,
+    pub progress_groups_pruned: &'static str,
+    /// Shown when no package besides the project itself is left after filtering
+    pub progress_no_third_party_packages: &'static str,
+    pub progress_platform_pruned: &'static str,
+    pub warn_unknown_dependency_group: &'static str,
+    pub warn_version_conflict: &'static str,
     pub progress_parsing_deps: &'static str,
+    pub progress_workspace_roots: &'static str,
+    pub progress_lockfile_root: &'static str,
+    pub warn_dependency_analysis_skipped: &'static str,
     pub progress_direct_deps: &'static str,
     pub progress_transitive_deps: &'static str,
+    pub progress_explicit_roots: &'static str,
+    pub progress_unreferenced_packages: &'static str,
     pub warn_license_fetch_failed: &'static str,
+    pub warn_license_not_found: &'static str,
+    pub warn_vuln_check_failed: &'static str,
+    pub warn_advisory_details_failed: &'static str,
     pub progress_license_complete: &'static str,
+    pub progress_license_failure_breakdown: &'static str,
+    pub license_failures_not_found: &'static str,
+    pub license_failures_rate_limited: &'static str,
+    pub license_failures_timeout: &'static str,
+    pub license_failures_skipped: &'static str,
+    pub license_failures_other: &'static str,
+    pub detail_license_resolved: &'static str,
+    pub detail_license_missing: &'static str,
+    pub detail_license_failed: &'static str,
+    pub progress_offline_mode: &'static str,
+    pub progress_local_sources_skipped: &'static str,
+    pub progress_license_overrides_applied: &'static str,
+    pub progress_cve_baseline_updated: &'static str,
+    pub progress_license_bytes_downloaded: &'static str,
+    pub progress_output_digest: &'static str,
+    pub verify_digest_matches: &'static str,
+    pub progress_reading_sbom: &'static str,
+    pub warn_non_pypi_component: &'static str,
+    pub progress_http_requests: &'static str,
+    pub progress_user_agent: &'static str,
+    pub progress_phase_started: &'static str,
+    pub progress_phase_finished: &'static str,
+    pub progress_phase_failed: &'static str,
+    pub warn_request_limit_reached: &'static str,
+    pub warn_network_budget_exhausted: &'static str,
+    pub error_network_budget_exhausted: &'static str,
+    pub progress_licenses_direct_only: &'static str,
+    pub warn_licenses_direct_only_unavailable: &'static str,
+    pub warn_lockfile_version_newer: &'static str,
+    pub warn_lockfile_line_skipped: &'static str,
+    pub warn_dependency_graph_unavailable: &'static str,
+    pub warn_data_dir_not_writable: &'static str,
     pub progress_vuln_found: &'static str,
     pub progress_vuln_none: &'static str,
+    pub progress_vuln_incomplete: &'static str,
+    pub progress_vuln_cached: &'static str,
+    pub progress_self_check_found: &'static str,
+    pub progress_cve_ignored: &'static str,
+    pub progress_cve_ignored_no_reason: &'static str,
+    pub progress_self_check_none: &'static str,
 
     // License compliance progress messages (use case layer)
     pub progress_license_violations_found: &'static str,
     pub progress_license_no_violations: &'static str,
     pub progress_license_unknown_packages: &'static str,
+    pub progress_license_compatibility_summary: &'static str,
+    pub progress_license_compatibility_unknown_project: &'static str,
+    pub warn_copyleft_reachable: &'static str,
+    pub warn_unknown_license_reachable: &'static str,
+    pub warn_python_incompatible: &'static str,
+    pub warn_possible_typosquat: &'static str,
+    pub progress_python_compat_no_project_range: &'static str,
+    pub progress_python_compat_unparsed_project_range: &'static str,
+
+    // Dry-run validation messages (use case layer)
+    pub progress_probing_endpoints: &'static str,
+    pub progress_endpoint_reachable: &'static str,
+    pub error_endpoint_unreachable: &'static str,
+    pub error_cvss_threshold_out_of_range: &'static str,
+    pub warn_unrecognized_ignore_id: &'static str,
+    pub progress_dry_run_valid_with_warnings: &'static str,
+    pub progress_dry_run_invalid: &'static str,
 
     // Upgrade advisor progress messages (use case layer)
     pub progress_analyzing_upgrade_paths: &'static str,
@@ -88,28 +191,55 @@ pub struct Messages {
     // Warning messages
     pub warn_check_cve_no_effect: &'static str,
     pub warn_check_license_no_effect: &'static str,
+    pub warn_check_license_compatibility_no_effect: &'static str,
+    pub warn_check_license_reachability_no_effect: &'static str,
     pub warn_verify_links_no_effect: &'static str,
+    pub warn_validate_no_effect: &'static str,
+    pub warn_both_lockfiles_found: &'static str,
     pub warn_abandoned_fetch_failed: &'static str,
+    pub warn_orphaned_annotation: &'static str,
+    pub warn_unmatched_license_override: &'static str,
+    pub warn_unlocked_dependency: &'static str,
+    pub warn_unknown_exclude_parent: &'static str,
+    pub warn_stale_locked_dependency: &'static str,
     pub progress_fetching_abandoned: &'static str,
     pub progress_abandoned_found: &'static str,
     pub progress_abandoned_none: &'static str,
 
     // Section description paragraphs
     pub desc_sbom_report: &'static str,
+    pub desc_license_override: &'static str,
+    pub label_yanked: &'static str,
+    pub desc_yanked: &'static str,
+    pub error_yanked_releases: &'static str,
+    pub label_license_fetch_error: &'static str,
+    pub error_enrichment_failures: &'static str,
     pub desc_direct_deps: &'static str,
     pub desc_transitive_deps: &'static str,
+    pub desc_unreferenced_packages: &'static str,
     pub desc_transitive_vuln_table: &'static str,
 
     // Empty-state labels
     pub label_no_direct_deps: &'static str,
+    /// Replaces the component table when the lockfile holds only the project itself
+    pub label_no_third_party_deps: &'static str,
     pub label_no_transitive_deps: &'static str,
+    pub label_circular_dependency: &'static str,
     pub label_no_license_violations: &'static str,
     pub label_osv_attribution: &'static str,
 
+    // Project self-check subsection of the vulnerability report
+    pub section_project_self_check: &'static str,
+    pub desc_project_self_check: &'static str,
+    pub label_project_self_check_none: &'static str,
+
     // Vulnerability count templates (4 placeholders: count, unit, count, unit)
     pub warn_no_vuln_above_threshold: &'static str,
+    pub warn_vuln_check_incomplete: &'static str,
     pub warn_vuln_found: &'static str,
     pub info_vuln_found: &'static str,
+    /// Footer of a truncated informational table; `{}` is the number not listed
+    pub label_informational_omitted: &'static str,
 
     // Singular/plural unit labels for vulnerability count templates
     pub label_vulnerability_singular: &'static str,
@@ -129,6 +259,47 @@ pub struct Messages {
     // template: count + unit word (EN uses both {}, JA only first {})
     pub warn_unknown_license_packages: &'static str,
 
+    // License compatibility section strings
+    pub section_license_compatibility: &'static str,
+    // template: project license, family
+    pub label_project_license: &'static str,
+    // template: project license
+    pub label_project_license_unclassified: &'static str,
+    pub label_project_license_missing: &'static str,
+    // template: compatible, conditional, incompatible, needs-review counts
+    pub label_license_compatibility_counts: &'static str,
+    pub label_all_licenses_compatible: &'static str,
+    pub col_verdict: &'static str,
+    pub col_note: &'static str,
+    pub verdict_conditional: &'static str,
+    pub verdict_needs_review: &'static str,
+    pub verdict_incompatible: &'static str,
+    pub note_license_conditional: &'static str,
+    pub note_license_needs_review: &'static str,
+    pub note_license_incompatible: &'static str,
+
+    // License risk section strings (--check-license-reachability)
+    pub section_license_risk: &'static str,
+    pub desc_license_risk: &'static str,
+    pub label_no_license_risk: &'static str,
+    pub section_unknown_license_reachable: &'static str,
+    pub col_introduced_via: &'static str,
+    pub col_path_count: &'static str,
+
+    // Python compatibility section strings (--check-python-compat)
+    pub section_python_compatibility: &'static str,
+    pub desc_python_compatibility: &'static str,
+    pub label_no_python_incompatibility: &'static str,
+    pub col_requires_python: &'static str,
+    pub section_supply_chain_warnings: &'static str,
+    pub desc_supply_chain_warnings: &'static str,
+    pub col_similar_to: &'static str,
+
+    // Version conflicts subsection strings
+    pub section_version_conflicts: &'static str,
+    pub desc_version_conflicts: &'static str,
+    pub col_versions: &'static str,
+
     // Resolution guide column headers
     pub col_vulnerable_package: &'static str,
     pub col_current: &'static str,
@@ -140,11 +311,20 @@ pub struct Messages {
     pub action_cannot_resolve: &'static str,
     pub action_could_not_analyze: &'static str,
 
+    // Suggested fixes section (--suggest-fixes)
+    pub section_fix_suggestions: &'static str,
+    pub desc_fix_suggestions: &'static str,
+    pub section_no_fix_available: &'static str,
+    pub desc_no_fix_available: &'static str,
+    pub col_action: &'static str,
+    pub action_fix_transitive: &'static str,
+
     // Transitive dependency sub-header (1 placeholder: package name)
     pub deps_for_header: &'static str,
 
     // Vulnerability summary line (4 placeholders: count, unit, count, unit)
     pub summary_vuln_found: &'static str,
+    pub summary_vuln_baseline: &'static str,
 
     // Workspace output messages
     pub output_complete: &'static str,
@@ -165,11 +345,95 @@ pub struct Messages {
     pub label_vuln_high: &'static str,
     pub label_vuln_medium: &'static str,
     pub label_vuln_low: &'static str,
+    pub label_vuln_unchecked: &'static str,
     pub label_license_violations: &'static str,
+    pub label_license_incompatibilities: &'static str,
+    pub label_licenses_needing_review: &'static str,
     pub label_vuln_check_skipped: &'static str,
     pub overall_action_required: &'static str,
     pub overall_attention_recommended: &'static str,
     pub overall_no_issues: &'static str,
+
+    // Run warnings appendix
+    pub section_run_warnings: &'static str,
+    pub desc_run_warnings: &'static str,
+    pub run_warning_unmatched_exclude_pattern: &'static str,
+    pub run_warning_unknown_exclude_parent: &'static str,
+    pub run_warning_license_fetch_failed: &'static str,
+    pub run_warning_maintenance_fetch_failed: &'static str,
+    pub run_warning_upgrade_simulation_failed: &'static str,
+    pub run_warning_orphaned_annotation: &'static str,
+    pub run_warning_unmatched_license_override: &'static str,
+    pub run_warning_unlocked_dependency: &'static str,
+    pub run_warning_stale_locked_dependency: &'static str,
+    pub run_warning_vulnerability_check_failed: &'static str,
+    pub run_warning_advisory_details_fetch_failed: &'static str,
+    pub run_warning_conflicting_license: &'static str,
+    pub run_warning_non_pypi_component: &'static str,
+    pub run_warning_dependency_analysis_skipped: &'static str,
+    pub run_warning_newer_lockfile_version: &'static str,
+    pub run_warning_lockfile_line_skipped: &'static str,
+    pub run_warning_dependency_graph_unavailable: &'static str,
+
+    // Aggregated SBOM (uv-sbom merge)
+    pub merge_mode_projects: &'static str,
+    pub section_merged_projects: &'static str,
+    pub col_project: &'static str,
+    pub col_direct_dependencies: &'static str,
+
+    // SBOM diff report (uv-sbom diff)
+    pub section_diff_title: &'static str,
+    // template: added, removed, updated, license change counts
+    pub label_diff_summary: &'static str,
+    pub label_diff_no_changes: &'static str,
+    pub section_diff_added: &'static str,
+    pub section_diff_removed: &'static str,
+    pub section_diff_updated: &'static str,
+    pub section_diff_license_changed: &'static str,
+    pub col_old_version: &'static str,
+    pub col_new_version: &'static str,
+    pub col_old_license: &'static str,
+    pub col_new_license: &'static str,
+
+    // Generation summary (--stats)
+    pub section_generation_summary: &'static str,
+    pub label_stats_title: &'static str,
+    pub col_metric: &'static str,
+    pub col_value: &'static str,
+
+    // Generation parameters
+    pub section_generation_parameters: &'static str,
+    pub label_generation_parameters_summary: &'static str,
+    pub col_option: &'static str,
+    pub label_stats_total_packages: &'static str,
+    pub label_stats_dependencies: &'static str,
+    // template: direct, transitive counts
+    pub stats_dependencies_value: &'static str,
+    pub label_stats_licenses: &'static str,
+    // template: resolved, missing counts
+    pub stats_licenses_value: &'static str,
+    pub label_stats_vulnerabilities: &'static str,
+    // template: critical, high, medium, low counts
+    pub stats_vulnerabilities_value: &'static str,
+    pub label_stats_parse_time: &'static str,
+    pub label_stats_license_time: &'static str,
+    pub label_stats_cve_time: &'static str,
+    pub stats_skipped: &'static str,
+
+    // End-of-run summary table (the counts reuse the --stats labels)
+    pub label_summary_title: &'static str,
+    pub label_summary_output: &'static str,
+    pub summary_output_stdout: &'static str,
+    pub label_summary_elapsed: &'static str,
+    // template: elapsed seconds
+    pub summary_elapsed_value: &'static str,
+
+    // HTML report (--format html)
+    pub label_html_tool: &'static str,
+    pub label_html_timestamp: &'static str,
+    pub label_html_serial_number: &'static str,
+    pub label_html_sort_hint: &'static str,
+    pub label_html_osv_attribution: &'static str,
 }
 
 impl Messages {
@@ -201,18 +465,44 @@ impl Messages {
         }
         result
     }
+
+    /// Picks the unit word for `count` according to the locale's plural rule.
+    ///
+    /// # Example
+    /// ```
+    /// use uv_sbom::i18n::{Locale, Messages};
+    /// let msgs = Messages::for_locale(Locale::En);
+    /// assert_eq!(msgs.unit(2, "package", "packages"), "packages");
+    /// ```
+    pub fn unit(&self, count: usize, singular: &'static str, plural: &'static str) -> &'static str {
+        match self.plural_rule {
+            PluralRule::OneOther if count != 1 => plural,
+            _ => singular,
+        }
+    }
 }
 
 static EN_MESSAGES: Messages = Messages {
+    plural_rule: PluralRule::OneOther,
+
     // Section headers
     section_sbom_title: "# Software Bill of Materials (SBOM)",
     section_component_inventory: "## Component Inventory",
     section_direct_deps: "## Direct Dependencies",
     section_transitive_deps: "## Transitive Dependencies",
+    section_unreferenced_packages: "## Unreferenced Packages",
     section_vuln_report: "## Vulnerability Report",
     section_license_compliance: "## License Compliance Report",
     section_resolution_guide: "## Vulnerability Resolution Guide",
     section_dependency_chains: "### Dependency Chains",
+    label_target_platform: "**Target platform:** {}",
+    label_project: "**Project:** {}",
+
+    label_split_contents: "## Contents",
+    label_split_back_to_index: "← Back to index",
+    label_split_components: "Component Inventory",
+    label_split_dependencies: "Dependencies",
+    label_split_vulnerabilities: "Vulnerabilities and Compliance",
 
     // Table column headers
     col_package: "Package",
@@ -223,31 +513,115 @@ static EN_MESSAGES: Messages = Messages {
     col_fixed_version: "Fixed Version",
     col_severity: "Severity",
     col_vuln_id: "Vulnerability ID",
+    col_aliases: "Aliases",
+    col_via: "Via",
+    col_declared_constraint: "Declared Constraint",
+    col_supplier: "Supplier",
+    label_via_direct: "(direct)",
+    label_excluded_from_inventory: "(excluded from inventory)",
+    label_cve_baseline: "(baseline)",
     col_cvss: "CVSS",
 
     // Progress messages
     progress_generating_json: "📝 Generating CycloneDX JSON format output...",
     progress_generating_markdown: "📝 Generating Markdown format output...",
+    progress_generating_html: "📝 Generating HTML format output...",
     progress_verifying_links: "🔗 Verifying PyPI links...",
+    progress_cyclonedx_valid: "✅ CycloneDX output passed validation",
     progress_fetching_license: "🔍 Fetching license information...",
     progress_fetching_vulns: "🔍 Fetching vulnerability information...",
 
     // Progress messages (use case layer)
     progress_loading_lockfile: "📖 Loading uv.lock file from: {}",
     progress_detected_packages: "✅ Detected {} package(s)",
+    progress_groups_pruned: "🚫 Excluded {} package(s) only needed by deselected dependency groups",
+    progress_no_third_party_packages: "ℹ️  No third-party packages to include; skipping license and vulnerability lookups",
+    progress_platform_pruned: "🚫 Excluded {} package(s) not installed on the target platform ({})",
+    warn_unknown_dependency_group: "⚠️  Warning: Dependency group '{}' was not found in the lockfile.",
+    warn_version_conflict: "⚠️  Warning: {} is locked at multiple versions ({})",
     progress_parsing_deps: "📊 Parsing dependency information...",
+    progress_workspace_roots: "   - Workspace members: {}",
+    progress_lockfile_root: "   - Project (from uv.lock, no pyproject.toml found): {}",
+    warn_dependency_analysis_skipped: "⚠️  Warning: {} not found and uv.lock does not show which package is the project; skipping dependency analysis (pass --project-name to name it, or --strict to fail instead)",
     progress_direct_deps: "   - Direct dependencies: {}",
     progress_transitive_deps: "   - Transitive dependencies: {}",
+    progress_explicit_roots: "   - Roots: {}",
+    progress_unreferenced_packages: "   - Unreferenced packages: {}",
     warn_license_fetch_failed: "⚠️  Warning: Error: Failed to fetch license information for {}: {}",
+    warn_license_not_found: "⚠️  Warning: {} was not found on the package index; no license is recorded",
+    warn_vuln_check_failed: "⚠️  Warning: Failed to check {} {} for vulnerabilities: {}",
+    warn_advisory_details_failed: "⚠️  Warning: Failed to fetch details for {}; it is reported without them: {}",
     progress_license_complete:
         "✅ License information retrieval complete: {} succeeded out of {}, {} failed",
+    progress_license_failure_breakdown: " ({})",
+    license_failures_not_found: "{} not on PyPI",
+    license_failures_rate_limited: "{} rate limited",
+    license_failures_timeout: "{} timeout",
+    license_failures_skipped: "{} skipped",
+    license_failures_other: "{} other",
+    detail_license_resolved: "   - {} {}: {}",
+    detail_license_missing: "   - {} {}: no license found",
+    detail_license_failed: "   - {} {}: failed ({})",
+    progress_offline_mode:
+        "📴 Offline mode: skipping PyPI and OSV. Licenses were found locally for {} of {} package(s)",
+    progress_local_sources_skipped:
+        "📁 Skipping PyPI and OSV lookups for {} package(s) from a local path or virtual source",
+    progress_license_overrides_applied: "✏️  Applied {} license override(s) from the config file",
+    progress_license_bytes_downloaded: "   - PyPI metadata downloaded: {} KiB",
+    progress_cve_baseline_updated: "📌 CVE baseline updated: {} accepted vulnerabilities",
+    progress_output_digest: "🔏 SHA-256 ({}): {}",
+    verify_digest_matches: "✅ SHA-256 digest of {} matches: {}",
+    progress_reading_sbom: "📖 Reading SBOM from: {}",
+    warn_non_pypi_component: "⚠️  Warning: Skipping {} {}: {} is not a PyPI package",
+    progress_http_requests: "🌐 HTTP requests sent: {} ({})",
+    progress_user_agent: "🌐 User-Agent: {}",
+    progress_phase_started: "▶️  Phase {} started",
+    progress_phase_finished: "⏱️  Phase {} finished in {} ms{}",
+    progress_phase_failed: "❌ Phase {} failed after {} ms: {}",
+    warn_request_limit_reached: "⚠️  Request limit of {} reached: {} request(s) skipped, results may be incomplete",
+    warn_network_budget_exhausted: "⚠️  Network request budget of {} exhausted: {} lookup(s) skipped, results may be incomplete",
+    error_network_budget_exhausted: "not looked up: network request budget of {} exhausted",
+    progress_licenses_direct_only: "ℹ️  Looking up licenses for direct dependencies only; {} transitive package(s) are listed without a license",
+    warn_licenses_direct_only_unavailable: "⚠️  Warning: direct dependencies could not be determined; looking up licenses for all packages",
+    warn_lockfile_version_newer: "⚠️  Warning: lockfile version {} is newer than supported {}; results may be incomplete",
+    warn_lockfile_line_skipped: "⚠️  Warning: skipped line {} of the lockfile: {}",
+    warn_dependency_graph_unavailable: "⚠️  Warning: the lockfile records no dependency graph (requirements.txt); dependency information is not available",
+    warn_data_dir_not_writable: "⚠️  Data directory {} is not writable ({}); falling back to the system temporary directory",
     progress_vuln_found: "✅ Vulnerability check complete: {} vulnerabilities found in {} packages",
     progress_vuln_none: "✅ Vulnerability check complete: No known vulnerabilities found",
+    progress_vuln_incomplete: "⚠️ Vulnerability check incomplete: {} packages could not be checked",
+    progress_vuln_cached: "   - Results for {} of {} package(s) came from the CVE cache",
+    progress_self_check_found: "⚠️  Project self-check: {} vulnerabilities found in {} {}",
+    progress_cve_ignored: "⚠ Ignored {} for package {} (reason: {})",
+    progress_cve_ignored_no_reason: "⚠ Ignored {} for package {} (no reason provided)",
+    progress_self_check_none: "✅ Project self-check: No known vulnerabilities found for {} {}",
 
     // License compliance progress messages (use case layer)
     progress_license_violations_found: "⚠️  License compliance: {} violation(s) found",
     progress_license_no_violations: "✅ License compliance: No violations found",
     progress_license_unknown_packages: "⚠️  License compliance: {} package(s) with unknown license",
+    progress_license_compatibility_summary:
+        "📋 License compatibility: {} compatible, {} conditional, {} incompatible, {} need review",
+    progress_license_compatibility_unknown_project:
+        "⚠️  License compatibility: project license '{}' is not classified; evaluating as permissive",
+    warn_copyleft_reachable: "⚠️  Warning: {} {} ({}) is reachable via {}",
+    warn_unknown_license_reachable: "⚠️  Warning: {} {} has an unknown license and is reachable via {}",
+    warn_python_incompatible: "⚠️  Warning: {} {} requires Python {}, narrower than the project's {}",
+    warn_possible_typosquat: "⚠️  Warning: {} {} looks like a misspelling of the popular package {}; add it to ignore_typosquats if it is legitimate",
+    progress_python_compat_no_project_range:
+        "⚠️  Python compatibility: the project declares no requires-python; skipping the check",
+    progress_python_compat_unparsed_project_range:
+        "⚠️  Python compatibility: the project's requires-python '{}' is not understood; skipping the check",
+
+    // Dry-run validation messages (use case layer)
+    progress_probing_endpoints: "🌐 Probing network endpoints...",
+    progress_endpoint_reachable: "  ✓ {} is reachable",
+    error_endpoint_unreachable: "❌ Cannot reach {}: {}",
+    error_cvss_threshold_out_of_range: "❌ CVSS threshold {} is outside the range 0.0-10.0",
+    warn_unrecognized_ignore_id:
+        "⚠️  Warning: ignore_cves entry '{}' is not a CVE-, GHSA- or PYSEC- ID and will likely never match (IDs are case-sensitive)",
+    progress_dry_run_valid_with_warnings: "Configuration validated with {} warning(s).",
+    progress_dry_run_invalid: "Configuration is invalid: {} error(s), {} warning(s).",
 
     // Upgrade advisor progress messages (use case layer)
     progress_analyzing_upgrade_paths: "🔍 Analyzing upgrade paths for {} direct {}...",
@@ -262,28 +636,54 @@ static EN_MESSAGES: Messages = Messages {
     // Warning messages
     warn_check_cve_no_effect: "⚠️  Warning: --check-cve has no effect with JSON format.",
     warn_check_license_no_effect: "⚠️  Warning: --check-license has no effect with JSON format.",
+    warn_check_license_compatibility_no_effect:
+        "⚠️  Warning: --check-license-compatibility results are not shown with JSON format.",
+    warn_check_license_reachability_no_effect:
+        "⚠️  Warning: --check-license-reachability has no effect with JSON format.",
     warn_verify_links_no_effect: "⚠️  Warning: --verify-links has no effect with JSON format.",
+    warn_validate_no_effect: "⚠️  Warning: --validate has no effect with {} format.",
+    warn_both_lockfiles_found: "⚠️  Note: both uv.lock and pylock.toml found; using uv.lock. Pass --lockfile pylock.toml to use the other one.",
     warn_abandoned_fetch_failed: "⚠️  Warning: Failed to fetch maintenance info for {}: {}",
+    warn_orphaned_annotation: "⚠️  Warning: Annotated package '{}' in pyproject.toml was not found in uv.lock.",
+    warn_unmatched_license_override: "⚠️  Warning: License override for package '{}' did not match any package in the lockfile.",
+    warn_unlocked_dependency: "⚠️  Warning: Dependency '{}' is declared in pyproject.toml but not in the lockfile. Run `uv lock` to update it.",
+    warn_unknown_exclude_parent: "⚠️  Warning: Exclude pattern 'deps-of:{}' names a package that is not in the lockfile.",
+    warn_stale_locked_dependency: "⚠️  Warning: Dependency '{}' is in the lockfile but no longer declared in pyproject.toml. Run `uv lock` to update it.",
     progress_fetching_abandoned: "🔍 Fetching package maintenance information...",
     progress_abandoned_found: "✅ Abandoned check complete: {} package(s) abandoned ({} direct, {} transitive), threshold: {} days",
     progress_abandoned_none: "✅ Abandoned check complete: No packages exceed {} day threshold",
 
     // Section description paragraphs
     desc_sbom_report: "A comprehensive list of all software components and libraries included in this project.",
+    desc_license_override: "\\* License set manually via `license_overrides` in the config file: {}",
+    label_yanked: "⚠️ yanked",
+    desc_yanked: "⚠️ Release yanked from PyPI: {}",
+    error_yanked_releases: "❌ Yanked releases found (--fail-on-yanked): {}",
+    label_license_fetch_error: "Unavailable (fetch error)",
+    error_enrichment_failures: "❌ Package metadata could not be fetched (--fail-on-enrichment-error): {}",
     desc_direct_deps: "Primary packages explicitly defined in the project configuration(e.g., pyproject.toml).",
     desc_transitive_deps: "Secondary dependencies introduced by the primary packages.",
+    desc_unreferenced_packages: "Packages in the lockfile that none of the packages given with --roots depends on, directly or indirectly.",
     desc_transitive_vuln_table: "The following transitive dependencies have known vulnerabilities. The table shows which direct dependency introduces each vulnerable package.",
 
     // Empty-state labels
     label_no_direct_deps: "*No direct dependencies*",
+    label_no_third_party_deps: "*No third-party dependencies*",
     label_no_transitive_deps: "*No transitive dependencies*",
+    label_circular_dependency: "(circular)",
     label_no_license_violations: "**No license violations found.**",
     label_osv_attribution: "*Vulnerability data provided by [OSV](https://osv.dev) under CC-BY 4.0*",
 
+    section_project_self_check: "### Project Self-Check",
+    desc_project_self_check: "Known vulnerabilities affecting the published release of this project itself.",
+    label_project_self_check_none: "**No known vulnerabilities affect this project's published release.**",
+
     // Vulnerability count templates (4 placeholders: count, unit, count, unit)
     warn_no_vuln_above_threshold: "### ⚠️Warning No vulnerabilities found above threshold.",
+    warn_vuln_check_incomplete: "**⚠️ {} packages could not be checked for vulnerabilities, so this report is incomplete. See the Warnings section for details.**",
     warn_vuln_found: "### ⚠️Warning Found {} {} in {} {}.",
     info_vuln_found: "### ℹ️Info Found {} {} in {} {}.",
+    label_informational_omitted: "… and {} more",
 
     // Singular/plural unit labels for vulnerability count templates
     label_vulnerability_singular: "vulnerability",
@@ -302,6 +702,41 @@ static EN_MESSAGES: Messages = Messages {
     col_matched_pattern: "Matched Pattern",
     warn_unknown_license_packages: "**{} {} with unknown license.**",
 
+    section_license_compatibility: "## License Compatibility",
+    label_project_license: "Project license: **{}** ({})",
+    label_project_license_unclassified: "Project license: **{}** (not classified; evaluated as permissive)",
+    label_project_license_missing: "Project license: not declared (evaluated as permissive)",
+    label_license_compatibility_counts:
+        "**{} compatible, {} conditional, {} incompatible, {} need review.**",
+    label_all_licenses_compatible: "All dependency licenses are compatible with the project license.",
+    col_verdict: "Verdict",
+    col_note: "Note",
+    verdict_conditional: "⚠️ Conditional",
+    verdict_needs_review: "❓ Needs review",
+    verdict_incompatible: "❌ Incompatible",
+    note_license_conditional: "Compatible if its conditions are met (e.g. keep the library replaceable, publish changes to its files)",
+    note_license_needs_review: "License unknown or not classified; review manually",
+    note_license_incompatible: "Cannot be distributed under the project license",
+
+    section_license_risk: "## License Risk",
+    desc_license_risk: "The following copyleft packages are reachable from the project. Each one is listed with the shortest path through which a direct dependency introduces it.",
+    label_no_license_risk: "No copyleft package is reachable from the project.",
+    section_unknown_license_reachable: "### Reachable packages with unknown license",
+    col_introduced_via: "Introduced Via",
+    col_path_count: "Paths",
+
+    section_python_compatibility: "## Python Compatibility",
+    desc_python_compatibility: "The following packages do not support every Python version the project's `{}` allows. Installing on the excluded versions fails or resolves to a different version.",
+    label_no_python_incompatibility: "Every package supports all Python versions the project allows (`{}`).",
+    col_requires_python: "Requires Python",
+    section_supply_chain_warnings: "## Supply Chain Warnings",
+    desc_supply_chain_warnings: "The following package names differ only slightly from the names of popular packages and may be typosquats. Verify that each is the package you intended to depend on.",
+    col_similar_to: "Similar To",
+
+    section_version_conflicts: "### Version Conflicts",
+    desc_version_conflicts: "The following packages are locked at more than one version, usually because the resolution forks on environment markers. Each version is listed as its own component.",
+    col_versions: "Versions",
+
     // Resolution guide column headers
     col_vulnerable_package: "Vulnerable Package",
     col_current: "Current",
@@ -313,11 +748,20 @@ static EN_MESSAGES: Messages = Messages {
     action_cannot_resolve: "⚠️ Cannot resolve: {}",
     action_could_not_analyze: "❓ Could not analyze: {}",
 
+    // Suggested fixes section
+    section_fix_suggestions: "## Suggested Fixes",
+    desc_fix_suggestions: "Upgrading each package to the fixed version resolves every listed vulnerability. Fixed versions come from the vulnerability database; run `uv lock` afterwards to confirm the upgrade resolves.",
+    section_no_fix_available: "### No fix available yet",
+    desc_no_fix_available: "The following vulnerabilities are not fixed in any released version yet.",
+    col_action: "Action",
+    action_fix_transitive: "Transitive via {} — upgrade {} or add a constraint",
+
     // Transitive dependency sub-header
     deps_for_header: "### Dependencies for {}",
 
     // Vulnerability summary line
     summary_vuln_found: "**Found {} {} in {} {}.**",
+    summary_vuln_baseline: "{} of them are accepted in the CVE baseline and marked (baseline); only the others count toward the threshold.",
 
     // Workspace output messages
     output_complete: "✅ Output complete: {}",
@@ -338,23 +782,109 @@ static EN_MESSAGES: Messages = Messages {
     label_vuln_high: "Vulnerabilities (HIGH)",
     label_vuln_medium: "Vulnerabilities (MEDIUM)",
     label_vuln_low: "Vulnerabilities (LOW)",
+    label_vuln_unchecked: "Packages not checked for vulnerabilities",
     label_license_violations: "License violations",
+    label_license_incompatibilities: "License incompatibilities",
+    label_licenses_needing_review: "Licenses needing review",
     label_vuln_check_skipped: "_Vulnerability check skipped._",
     overall_action_required: "**Overall: Action required**",
     overall_attention_recommended: "**Overall: Attention recommended**",
     overall_no_issues: "**Overall: No issues found** ✅",
+
+    // Run warnings appendix
+    section_run_warnings: "## Warnings",
+    desc_run_warnings: "The following issues occurred while generating this SBOM. The affected data may be incomplete.",
+    run_warning_unmatched_exclude_pattern: "Exclude pattern '{}' did not match any dependencies",
+    run_warning_unknown_exclude_parent: "Exclude pattern 'deps-of:{}' names a package that is not in the lockfile",
+    run_warning_license_fetch_failed: "Failed to fetch license information for {} ({}): {}",
+    run_warning_maintenance_fetch_failed: "Failed to fetch maintenance information for {}: {}",
+    run_warning_upgrade_simulation_failed: "Upgrade simulation failed for {}: {}",
+    run_warning_orphaned_annotation: "Annotated package '{}' was not found in uv.lock",
+    run_warning_unmatched_license_override: "License override for package '{}' did not match any package in the lockfile",
+    run_warning_unlocked_dependency: "Dependency '{}' is declared in pyproject.toml but not in the lockfile",
+    run_warning_stale_locked_dependency: "Dependency '{}' is in the lockfile but no longer declared in pyproject.toml",
+    run_warning_vulnerability_check_failed: "Failed to check {} {} for vulnerabilities: {}",
+    run_warning_advisory_details_fetch_failed: "Details of {} could not be fetched, so it is reported with only its ID: {}",
+    run_warning_conflicting_license: "{} {} has different licenses in the merged projects; keeping '{}' and ignoring '{}'",
+    run_warning_non_pypi_component: "{} {} was not scanned: {} is not a PyPI package",
+    run_warning_dependency_analysis_skipped: "Dependency analysis was skipped: {} was not found and uv.lock does not show which package is the project",
+    run_warning_newer_lockfile_version: "Lockfile version {} is newer than supported {}; the lockfile was parsed on a best-effort basis",
+    run_warning_lockfile_line_skipped: "Line {} of the lockfile was skipped: {}",
+    run_warning_dependency_graph_unavailable: "The lockfile records no dependency graph, so direct and transitive dependencies are not told apart",
+
+    // Aggregated SBOM
+    merge_mode_projects: "Merge mode: {} projects",
+    section_merged_projects: "## Source Projects",
+    col_project: "Project",
+    col_direct_dependencies: "Direct Dependencies",
+
+    // SBOM diff report
+    section_diff_title: "# SBOM Diff",
+    label_diff_summary: "**{} added, {} removed, {} updated, {} license changes.**",
+    label_diff_no_changes: "No package changes between the two SBOMs.",
+    section_diff_added: "### Added",
+    section_diff_removed: "### Removed",
+    section_diff_updated: "### Updated",
+    section_diff_license_changed: "### License Changes",
+    col_old_version: "Old Version",
+    col_new_version: "New Version",
+    col_old_license: "Old License",
+    col_new_license: "New License",
+
+    // Generation summary
+    section_generation_summary: "## Generation Summary",
+    label_stats_title: "Generation summary:",
+    col_metric: "Metric",
+    col_value: "Value",
+
+    // Generation parameters
+    section_generation_parameters: "## Generation Parameters",
+    label_generation_parameters_summary: "Options this SBOM was generated with",
+    col_option: "Option",
+    label_stats_total_packages: "Total packages",
+    label_stats_dependencies: "Dependencies",
+    stats_dependencies_value: "{} direct, {} transitive",
+    label_stats_licenses: "Licenses",
+    stats_licenses_value: "{} resolved, {} missing",
+    label_stats_vulnerabilities: "Vulnerabilities",
+    stats_vulnerabilities_value: "{} critical, {} high, {} medium, {} low",
+    label_stats_parse_time: "Lockfile parsing",
+    label_stats_license_time: "License fetch",
+    label_stats_cve_time: "Vulnerability check",
+    stats_skipped: "skipped",
+    label_summary_title: "Summary",
+    label_summary_output: "Output",
+    summary_output_stdout: "stdout",
+    label_summary_elapsed: "Elapsed",
+    summary_elapsed_value: "{} s",
+    label_html_tool: "Tool",
+    label_html_timestamp: "Generated",
+    label_html_serial_number: "Serial number",
+    label_html_sort_hint: "Click a column header to sort the table.",
+    label_html_osv_attribution: "Vulnerability data provided by OSV (https://osv.dev) under CC-BY 4.0",
 };
 
 static JA_MESSAGES: Messages = Messages {
+    plural_rule: PluralRule::Invariant,
+
     // Section headers
     section_sbom_title: "# ソフトウェア部品表 (SBOM)",
     section_component_inventory: "## コンポーネント一覧",
     section_direct_deps: "## 直接依存パッケージ",
     section_transitive_deps: "## 間接依存パッケージ",
+    section_unreferenced_packages: "## 参照されていないパッケージ",
     section_vuln_report: "## 脆弱性レポート",
     section_license_compliance: "## ライセンスコンプライアンスレポート",
     section_resolution_guide: "## 脆弱性解決ガイド",
     section_dependency_chains: "### 依存チェーン",
+    label_target_platform: "**対象プラットフォーム:** {}",
+    label_project: "**プロジェクト:** {}",
+
+    label_split_contents: "## 目次",
+    label_split_back_to_index: "← 目次に戻る",
+    label_split_components: "コンポーネント一覧",
+    label_split_dependencies: "依存関係",
+    label_split_vulnerabilities: "脆弱性とコンプライアンス",
 
     // Table column headers
     col_package: "パッケージ",
@@ -365,30 +895,114 @@ static JA_MESSAGES: Messages = Messages {
     col_fixed_version: "修正済みバージョン",
     col_severity: "深刻度",
     col_vuln_id: "脆弱性ID",
+    col_aliases: "別名",
+    col_via: "経由",
+    col_declared_constraint: "宣言された制約",
+    col_supplier: "供給者",
+    label_via_direct: "（直接）",
+    label_excluded_from_inventory: "（インベントリ対象外）",
+    label_cve_baseline: "（ベースライン）",
     col_cvss: "CVSS",
 
     // Progress messages
     progress_generating_json: "📝 CycloneDX JSON形式で出力を生成中...",
     progress_generating_markdown: "📝 Markdown形式で出力を生成中...",
+    progress_generating_html: "📝 HTML形式で出力を生成中...",
     progress_verifying_links: "🔗 PyPIリンクを検証中...",
+    progress_cyclonedx_valid: "✅ CycloneDX出力の検証に成功しました",
     progress_fetching_license: "🔍 ライセンス情報を取得中...",
     progress_fetching_vulns: "🔍 脆弱性情報を取得中...",
 
     // Progress messages (use case layer)
     progress_loading_lockfile: "📖 uv.lockファイルを読み込み中: {}",
     progress_detected_packages: "✅ {}個のパッケージを検出",
+    progress_groups_pruned: "🚫 選択されていない依存グループでのみ必要な{}個のパッケージを除外",
+    progress_no_third_party_packages: "ℹ️  対象のサードパーティパッケージがないため、ライセンスと脆弱性の取得をスキップします",
+    progress_platform_pruned: "🚫 {}個のパッケージを対象プラットフォーム ({}) にインストールされないため除外",
+    warn_unknown_dependency_group: "⚠️  警告: 依存グループ '{}' がロックファイルに見つかりません。",
+    warn_version_conflict: "⚠️  警告: {} が複数のバージョンでロックされています ({})",
     progress_parsing_deps: "📊 依存関係情報を解析中...",
+    progress_workspace_roots: "   - ワークスペースメンバー: {}",
+    progress_lockfile_root: "   - プロジェクト (pyproject.toml がないため uv.lock から特定): {}",
+    warn_dependency_analysis_skipped: "⚠️  警告: {} が見つからず、uv.lock からもプロジェクトのパッケージを特定できないため、依存関係の解析をスキップします (--project-name で指定するか、--strict で失敗させてください)",
     progress_direct_deps: "   - 直接依存: {}",
     progress_transitive_deps: "   - 間接依存: {}",
+    progress_explicit_roots: "   - ルート: {}",
+    progress_unreferenced_packages: "   - 参照されていないパッケージ: {}",
     warn_license_fetch_failed: "⚠️  警告: {}のライセンス情報の取得に失敗: {}",
+    warn_license_not_found: "⚠️  警告: {}はパッケージインデックスに見つからないため、ライセンス情報は記録されません",
+    warn_vuln_check_failed: "⚠️  警告: {} {}の脆弱性チェックに失敗: {}",
+    warn_advisory_details_failed: "⚠️  警告: {}の詳細の取得に失敗したため、詳細なしで報告します: {}",
     progress_license_complete: "✅ ライセンス情報取得完了: {}件成功 / {}件中、{}件失敗",
+    progress_license_failure_breakdown: " ({})",
+    license_failures_not_found: "PyPI未登録 {}件",
+    license_failures_rate_limited: "レート制限 {}件",
+    license_failures_timeout: "タイムアウト {}件",
+    license_failures_skipped: "スキップ {}件",
+    license_failures_other: "その他のエラー {}件",
+    detail_license_resolved: "   - {} {}: {}",
+    detail_license_missing: "   - {} {}: ライセンス情報なし",
+    detail_license_failed: "   - {} {}: 取得失敗 ({})",
+    progress_offline_mode:
+        "📴 オフラインモード: PyPIとOSVへのアクセスをスキップ。ローカルでライセンスを取得: {}件 / {}件中",
+    progress_local_sources_skipped:
+        "📁 ローカルパスまたはvirtualソースの{}個のパッケージはPyPIとOSVの照会をスキップします",
+    progress_license_overrides_applied: "✏️  設定ファイルのライセンス上書きを{}件適用しました",
+    progress_license_bytes_downloaded: "   - PyPIメタデータ取得量: {} KiB",
+    progress_cve_baseline_updated: "📌 CVEベースラインを更新しました: 許容済みの脆弱性 {}件",
+    progress_output_digest: "🔏 SHA-256 ({}): {}",
+    verify_digest_matches: "✅ {} のSHA-256ダイジェストが一致しました: {}",
+    progress_reading_sbom: "📖 SBOMを読み込み中: {}",
+    warn_non_pypi_component: "⚠️  警告: {} {}をスキップします: {} はPyPIパッケージではありません",
+    progress_http_requests: "🌐 送信したHTTPリクエスト数: {} ({})",
+    progress_user_agent: "🌐 User-Agent: {}",
+    progress_phase_started: "▶️  フェーズ {} を開始",
+    progress_phase_finished: "⏱️  フェーズ {} が {} ms で完了{}",
+    progress_phase_failed: "❌ フェーズ {} が {} ms 後に失敗: {}",
+    warn_request_limit_reached: "⚠️  リクエスト上限 {} に達しました: {}件のリクエストをスキップしたため、結果が不完全な可能性があります",
+    warn_network_budget_exhausted: "⚠️  ネットワークリクエスト上限 {} を使い切りました: {}件の照会をスキップしたため、結果が不完全な可能性があります",
+    error_network_budget_exhausted: "未照会: ネットワークリクエスト上限 {} を使い切りました",
+    progress_licenses_direct_only: "ℹ️  ライセンスは直接依存のみ照会します。間接依存の{}個のパッケージはライセンスなしで記載されます",
+    warn_licenses_direct_only_unavailable: "⚠️  警告: 直接依存を特定できないため、すべてのパッケージのライセンスを照会します",
+    warn_lockfile_version_newer: "⚠️  警告: ロックファイルのバージョン {} はサポート対象の {} より新しいため、結果が不完全な可能性があります",
+    warn_lockfile_line_skipped: "⚠️  警告: ロックファイルの {} 行目をスキップしました: {}",
+    warn_dependency_graph_unavailable: "⚠️  警告: ロックファイルに依存関係グラフが記録されていないため (requirements.txt)、依存関係情報は利用できません",
+    warn_data_dir_not_writable: "⚠️  データディレクトリ {} に書き込めません ({})。システムの一時ディレクトリを使用します",
     progress_vuln_found: "✅ 脆弱性チェック完了: {}個のパッケージで{}件の脆弱性を検出",
     progress_vuln_none: "✅ 脆弱性チェック完了: 既知の脆弱性は検出されませんでした",
+    progress_vuln_incomplete: "⚠️ 脆弱性チェック未完了: {}個のパッケージをチェックできませんでした",
+    progress_vuln_cached: "   - CVEキャッシュから取得した結果: {}/{}個のパッケージ",
+    progress_self_check_found: "⚠️  プロジェクト自己チェック: {}件の脆弱性を{} {}で検出",
+    progress_cve_ignored: "⚠ {} を無視しました (パッケージ: {}, 理由: {})",
+    progress_cve_ignored_no_reason: "⚠ {} を無視しました (パッケージ: {}, 理由の記載なし)",
+    progress_self_check_none: "✅ プロジェクト自己チェック: {} {}に既知の脆弱性は検出されませんでした",
 
     // License compliance progress messages (use case layer)
     progress_license_violations_found: "⚠️  ライセンスコンプライアンス: {}件の違反が見つかりました",
     progress_license_no_violations: "✅ ライセンスコンプライアンス: 違反は見つかりませんでした",
     progress_license_unknown_packages: "⚠️  ライセンスコンプライアンス: ライセンス不明のパッケージが{}件あります",
+    progress_license_compatibility_summary:
+        "📋 ライセンス互換性: 互換 {}件、条件付き {}件、非互換 {}件、要確認 {}件",
+    progress_license_compatibility_unknown_project:
+        "⚠️  ライセンス互換性: プロジェクトのライセンス '{}' は分類されていないため、パーミッシブとして評価します",
+    warn_copyleft_reachable: "⚠️  警告: {} {}（{}）は {} の経路で到達可能です",
+    warn_unknown_license_reachable: "⚠️  警告: {} {}はライセンス不明で、{} の経路で到達可能です",
+    warn_python_incompatible: "⚠️  警告: {} {}の対応Pythonは {} で、プロジェクトの {} より狭い範囲です",
+    warn_possible_typosquat: "⚠️  警告: {} {} は人気パッケージ {} の綴り間違いの可能性があります。正規のパッケージであれば ignore_typosquats に追加してください",
+    progress_python_compat_no_project_range:
+        "⚠️  Python互換性: プロジェクトに requires-python が宣言されていないため、チェックをスキップします",
+    progress_python_compat_unparsed_project_range:
+        "⚠️  Python互換性: プロジェクトの requires-python '{}' を解釈できないため、チェックをスキップします",
+
+    // Dry-run validation messages (use case layer)
+    progress_probing_endpoints: "🌐 ネットワーク接続先を確認中...",
+    progress_endpoint_reachable: "  ✓ {} に接続できます",
+    error_endpoint_unreachable: "❌ {} に接続できません: {}",
+    error_cvss_threshold_out_of_range: "❌ CVSS閾値 {} が 0.0〜10.0 の範囲外です",
+    warn_unrecognized_ignore_id:
+        "⚠️  警告: ignore_cves のエントリ '{}' は CVE-、GHSA-、PYSEC- のいずれのIDでもないため、一致しない可能性があります（IDは大文字小文字を区別します）",
+    progress_dry_run_valid_with_warnings: "設定を検証しました。警告 {}件。",
+    progress_dry_run_invalid: "設定が不正です: エラー {}件、警告 {}件。",
 
     // Upgrade advisor progress messages (use case layer)
     // JA: only first {} (count) is used; second {} (unit word) is ignored
@@ -404,29 +1018,55 @@ static JA_MESSAGES: Messages = Messages {
     // Warning messages
     warn_check_cve_no_effect: "⚠️  警告: JSON形式では --check-cve は効果がありません。",
     warn_check_license_no_effect: "⚠️  警告: JSON形式では --check-license は効果がありません。",
+    warn_check_license_compatibility_no_effect:
+        "⚠️  警告: JSON形式では --check-license-compatibility の結果は表示されません。",
+    warn_check_license_reachability_no_effect:
+        "⚠️  警告: JSON形式では --check-license-reachability は効果がありません。",
     warn_verify_links_no_effect: "⚠️  警告: JSON形式では --verify-links は効果がありません。",
+    warn_validate_no_effect: "⚠️  警告: {}形式では --validate は効果がありません。",
+    warn_both_lockfiles_found: "⚠️  注意: uv.lockとpylock.tomlの両方が見つかりました。uv.lockを使用します。pylock.tomlを使用するには --lockfile pylock.toml を指定してください。",
     warn_abandoned_fetch_failed: "⚠️  警告: {}のメンテナンス情報の取得に失敗: {}",
+    warn_orphaned_annotation: "⚠️  警告: pyproject.tomlで注釈が付けられたパッケージ '{}' がuv.lockに見つかりません。",
+    warn_unmatched_license_override: "⚠️  警告: パッケージ '{}' のライセンス上書き設定に一致するパッケージがロックファイルにありません。",
+    warn_unlocked_dependency: "⚠️  警告: 依存関係 '{}' はpyproject.tomlで宣言されていますが、ロックファイルにありません。`uv lock` で更新してください。",
+    warn_unknown_exclude_parent: "⚠️  警告: 除外パターン 'deps-of:{}' のパッケージはロックファイルにありません。",
+    warn_stale_locked_dependency: "⚠️  警告: 依存関係 '{}' はロックファイルにありますが、pyproject.tomlで宣言されていません。`uv lock` で更新してください。",
     progress_fetching_abandoned: "🔍 パッケージのメンテナンス情報を取得中...",
     progress_abandoned_found: "✅ 廃止パッケージチェック完了: {}件廃止（直接: {}件、間接: {}件）、閾値: {}日",
     progress_abandoned_none: "✅ 廃止パッケージチェック完了: {}日以上更新のないパッケージはありません",
 
     // Section description paragraphs
     desc_sbom_report: "このプロジェクトに含まれるすべてのソフトウェアコンポーネントとライブラリの一覧です。",
+    desc_license_override: "\\* 設定ファイルの `license_overrides` で手動設定されたライセンス: {}",
+    label_yanked: "⚠️ 取り下げ済み (yanked)",
+    desc_yanked: "⚠️ PyPI で取り下げ (yank) られたリリース: {}",
+    error_yanked_releases: "❌ 取り下げ (yank) られたリリースが含まれています (--fail-on-yanked): {}",
+    label_license_fetch_error: "取得不可 (取得エラー)",
+    error_enrichment_failures: "❌ パッケージのメタデータを取得できませんでした (--fail-on-enrichment-error): {}",
     desc_direct_deps: "プロジェクト設定（例: pyproject.toml）に明示的に定義された主要パッケージです。",
     desc_transitive_deps: "主要パッケージによって導入される間接的な依存パッケージです。",
+    desc_unreferenced_packages: "--roots で指定したどのパッケージからも直接・間接に依存されていない、ロックファイル内のパッケージです。",
     desc_transitive_vuln_table: "以下の間接依存パッケージに既知の脆弱性があります。テーブルはどの直接依存パッケージが各脆弱性パッケージを導入しているかを示します。",
 
     // Empty-state labels
     label_no_direct_deps: "*直接依存パッケージなし*",
+    label_no_third_party_deps: "*サードパーティ依存パッケージなし*",
     label_no_transitive_deps: "*間接依存パッケージなし*",
+    label_circular_dependency: "(循環)",
     label_no_license_violations: "**ライセンス違反は見つかりませんでした。**",
     label_osv_attribution: "*脆弱性データは [OSV](https://osv.dev) より CC-BY 4.0 ライセンスの下で提供されています*",
 
+    section_project_self_check: "### プロジェクト自己チェック",
+    desc_project_self_check: "このプロジェクト自身の公開リリースに影響する既知の脆弱性です。",
+    label_project_self_check_none: "**このプロジェクトの公開リリースに影響する既知の脆弱性はありません。**",
+
     // Vulnerability count templates
     // JA uses 4 placeholders in order: vuln_count, vuln_unit, pkg_count, pkg_unit
     warn_no_vuln_above_threshold: "### ⚠️警告 閾値を超える脆弱性は見つかりませんでした。",
+    warn_vuln_check_incomplete: "**⚠️ {}個のパッケージの脆弱性をチェックできなかったため、このレポートは不完全です。詳細は警告セクションを参照してください。**",
     warn_vuln_found: "### ⚠️警告 {}{}が{}{}で見つかりました。",
     info_vuln_found: "### ℹ️情報 {}{}が{}{}で見つかりました。",
+    label_informational_omitted: "…ほか{}件",
 
     // Singular/plural unit labels (no distinction in Japanese)
     label_vulnerability_singular: "件の脆弱性",
@@ -446,6 +1086,41 @@ static JA_MESSAGES: Messages = Messages {
     // JA: only first {} (count) is used; second {} (unit word) is ignored
     warn_unknown_license_packages: "**{}個のライセンス不明パッケージがあります。**",
 
+    section_license_compatibility: "## ライセンス互換性",
+    label_project_license: "プロジェクトのライセンス: **{}**（{}）",
+    label_project_license_unclassified: "プロジェクトのライセンス: **{}**（未分類のためパーミッシブとして評価）",
+    label_project_license_missing: "プロジェクトのライセンス: 未宣言（パーミッシブとして評価）",
+    label_license_compatibility_counts:
+        "**互換 {}件、条件付き {}件、非互換 {}件、要確認 {}件**",
+    label_all_licenses_compatible: "すべての依存パッケージのライセンスはプロジェクトのライセンスと互換性があります。",
+    col_verdict: "判定",
+    col_note: "備考",
+    verdict_conditional: "⚠️ 条件付き",
+    verdict_needs_review: "❓ 要確認",
+    verdict_incompatible: "❌ 非互換",
+    note_license_conditional: "条件を満たせば互換（ライブラリを差し替え可能に保つ、変更したファイルを公開する等）",
+    note_license_needs_review: "ライセンスが不明または未分類のため、手動で確認してください",
+    note_license_incompatible: "プロジェクトのライセンスでは配布できません",
+
+    section_license_risk: "## ライセンスリスク",
+    desc_license_risk: "以下のコピーレフトパッケージはプロジェクトから到達可能です。直接依存パッケージから導入される最短の経路を示します。",
+    label_no_license_risk: "プロジェクトから到達可能なコピーレフトパッケージはありません。",
+    section_unknown_license_reachable: "### ライセンス不明の到達可能パッケージ",
+    col_introduced_via: "導入経路",
+    col_path_count: "経路数",
+
+    section_python_compatibility: "## Python互換性",
+    desc_python_compatibility: "以下のパッケージは、プロジェクトの `{}` が許可するPythonバージョンの一部に対応していません。対応外のバージョンではインストールに失敗するか、別のバージョンが解決されます。",
+    label_no_python_incompatibility: "すべてのパッケージがプロジェクトの許可するPythonバージョン（`{}`）に対応しています。",
+    col_requires_python: "対応Python",
+    section_supply_chain_warnings: "## サプライチェーンの警告",
+    desc_supply_chain_warnings: "以下のパッケージ名は人気パッケージの名前とわずかしか違わず、タイポスクワッティングの可能性があります。意図したパッケージであることを確認してください。",
+    col_similar_to: "類似パッケージ",
+
+    section_version_conflicts: "### バージョンの重複",
+    desc_version_conflicts: "以下のパッケージは複数のバージョンでロックされています。多くの場合、環境マーカーによって依存解決が分岐していることが原因です。各バージョンはそれぞれ別のコンポーネントとして記載されています。",
+    col_versions: "バージョン一覧",
+
     // Resolution guide column headers
     col_vulnerable_package: "脆弱性のあるパッケージ",
     col_current: "現在",
@@ -457,11 +1132,20 @@ static JA_MESSAGES: Messages = Messages {
     action_cannot_resolve: "⚠️ 解決不可: {}",
     action_could_not_analyze: "❓ 分析不可: {}",
 
+    // Suggested fixes section
+    section_fix_suggestions: "## 修正候補",
+    desc_fix_suggestions: "各パッケージを修正バージョンにアップグレードすると、記載されたすべての脆弱性が解決します。修正バージョンは脆弱性データベースに基づきます。アップグレード後に `uv lock` で解決できることを確認してください。",
+    section_no_fix_available: "### 修正版未提供",
+    desc_no_fix_available: "以下の脆弱性は、まだどのリリースでも修正されていません。",
+    col_action: "アクション",
+    action_fix_transitive: "{}経由の間接依存 — {}をアップグレードするか制約を追加してください",
+
     // Transitive dependency sub-header
     deps_for_header: "### {}の依存パッケージ",
 
     // Vulnerability summary line
     summary_vuln_found: "**{}{}が{}{}で見つかりました。**",
+    summary_vuln_baseline: "うち{}件はCVEベースラインで許容済みのため（ベースライン）と表示し、閾値の判定には含めません。",
 
     // Workspace output messages
     output_complete: "✅ 出力完了: {}",
@@ -482,11 +1166,86 @@ static JA_MESSAGES: Messages = Messages {
     label_vuln_high: "脆弱性 (HIGH)",
     label_vuln_medium: "脆弱性 (MEDIUM)",
     label_vuln_low: "脆弱性 (LOW)",
+    label_vuln_unchecked: "脆弱性未チェックのパッケージ",
     label_license_violations: "ライセンス違反",
+    label_license_incompatibilities: "ライセンス非互換",
+    label_licenses_needing_review: "要確認ライセンス",
     label_vuln_check_skipped: "_脆弱性チェックはスキップされました。_",
     overall_action_required: "**総合判定: 対応が必要です**",
     overall_attention_recommended: "**総合判定: 注意が必要です**",
     overall_no_issues: "**総合判定: 問題なし** ✅",
+
+    // Run warnings appendix
+    section_run_warnings: "## 警告",
+    desc_run_warnings: "このSBOMの生成中に以下の問題が発生しました。該当するデータは不完全な可能性があります。",
+    run_warning_unmatched_exclude_pattern: "除外パターン '{}' はどの依存パッケージにも一致しませんでした",
+    run_warning_unknown_exclude_parent: "除外パターン 'deps-of:{}' のパッケージはロックファイルにありません",
+    run_warning_license_fetch_failed: "{}のライセンス情報の取得に失敗しました ({}): {}",
+    run_warning_maintenance_fetch_failed: "{}のメンテナンス情報の取得に失敗しました: {}",
+    run_warning_upgrade_simulation_failed: "{}のアップグレードシミュレーションに失敗しました: {}",
+    run_warning_orphaned_annotation: "注釈が付けられたパッケージ '{}' はuv.lockに見つかりませんでした",
+    run_warning_unmatched_license_override: "パッケージ '{}' のライセンス上書き設定はロックファイルのどのパッケージにも一致しませんでした",
+    run_warning_unlocked_dependency: "依存関係 '{}' はpyproject.tomlで宣言されていますが、ロックファイルにありません",
+    run_warning_stale_locked_dependency: "依存関係 '{}' はロックファイルにありますが、pyproject.tomlで宣言されていません",
+    run_warning_vulnerability_check_failed: "{} {}の脆弱性チェックに失敗しました: {}",
+    run_warning_advisory_details_fetch_failed: "{}の詳細を取得できなかったため、IDのみで報告しています: {}",
+    run_warning_conflicting_license: "{} {} のライセンスがマージ元プロジェクト間で異なります。'{}' を採用し、'{}' は無視します",
+    run_warning_non_pypi_component: "{} {} はスキャンされていません: {} はPyPIパッケージではありません",
+    run_warning_dependency_analysis_skipped: "依存関係の解析をスキップしました: {} が見つからず、uv.lock からもプロジェクトのパッケージを特定できません",
+    run_warning_newer_lockfile_version: "ロックファイルのバージョン {} はサポート対象の {} より新しいため、可能な範囲で解析しました",
+    run_warning_lockfile_line_skipped: "ロックファイルの {} 行目をスキップしました: {}",
+    run_warning_dependency_graph_unavailable: "ロックファイルに依存関係グラフが記録されていないため、直接依存と間接依存を区別していません",
+
+    // Aggregated SBOM
+    merge_mode_projects: "マージモード: {} プロジェクト",
+    section_merged_projects: "## 集約元プロジェクト",
+    col_project: "プロジェクト",
+    col_direct_dependencies: "直接依存パッケージ",
+
+    // SBOM diff report
+    section_diff_title: "# SBOM 差分",
+    label_diff_summary: "**追加 {} 件、削除 {} 件、更新 {} 件、ライセンス変更 {} 件。**",
+    label_diff_no_changes: "2つのSBOMの間にパッケージの変更はありません。",
+    section_diff_added: "### 追加",
+    section_diff_removed: "### 削除",
+    section_diff_updated: "### 更新",
+    section_diff_license_changed: "### ライセンス変更",
+    col_old_version: "旧バージョン",
+    col_new_version: "新バージョン",
+    col_old_license: "旧ライセンス",
+    col_new_license: "新ライセンス",
+
+    // Generation summary
+    section_generation_summary: "## 生成サマリー",
+    label_stats_title: "生成サマリー:",
+    col_metric: "項目",
+    col_value: "値",
+
+    // Generation parameters
+    section_generation_parameters: "## 生成パラメータ",
+    label_generation_parameters_summary: "このSBOMの生成に使われたオプション",
+    col_option: "オプション",
+    label_stats_total_packages: "パッケージ総数",
+    label_stats_dependencies: "依存パッケージ",
+    stats_dependencies_value: "直接 {} 件、間接 {} 件",
+    label_stats_licenses: "ライセンス",
+    stats_licenses_value: "判明 {} 件、不明 {} 件",
+    label_stats_vulnerabilities: "脆弱性",
+    stats_vulnerabilities_value: "CRITICAL {} 件、HIGH {} 件、MEDIUM {} 件、LOW {} 件",
+    label_stats_parse_time: "ロックファイル解析",
+    label_stats_license_time: "ライセンス取得",
+    label_stats_cve_time: "脆弱性チェック",
+    stats_skipped: "スキップ",
+    label_summary_title: "実行結果",
+    label_summary_output: "出力先",
+    summary_output_stdout: "標準出力",
+    label_summary_elapsed: "所要時間",
+    summary_elapsed_value: "{} 秒",
+    label_html_tool: "ツール",
+    label_html_timestamp: "生成日時",
+    label_html_serial_number: "シリアル番号",
+    label_html_sort_hint: "列見出しをクリックすると表を並べ替えられます。",
+    label_html_osv_attribution: "脆弱性データは OSV (https://osv.dev) より CC-BY 4.0 ライセンスの下で提供されています",
 };
 
 #[cfg(test)]
@@ -910,6 +1669,18 @@ mod tests {
         assert_eq!(result, "  ❓ Simulation failed for pkg-b: uv lock failed");
     }
 
+    #[test]
+    fn test_unit_follows_locale_plural_rule() {
+        let en = Messages::for_locale(Locale::En);
+        assert_eq!(en.unit(0, "package", "packages"), "packages");
+        assert_eq!(en.unit(1, "package", "packages"), "package");
+        assert_eq!(en.unit(2, "package", "packages"), "packages");
+
+        let ja = Messages::for_locale(Locale::Ja);
+        assert_eq!(ja.plural_rule, PluralRule::Invariant);
+        assert_eq!(ja.unit(2, "singular", "plural"), "singular");
+    }
+
     #[test]
     fn test_warn_vuln_found_ja_format() {
         let msgs = Messages::for_locale(Locale::Ja);
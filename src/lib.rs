@@ -13,8 +13,33 @@
 //! - **Adapters** (`adapters`): Concrete implementations of ports
 //! - **Shared** (`shared`): Common utilities and error types
 //!
+//! Enable the `test-fixtures` feature to get `test_fixtures`, a builder for
+//! consistent `SbomReadModel` values when testing custom formatters.
+//!
 //! # Example
 //!
+//! [`facade::Sbom`] wires the default adapters (lockfile on disk, PyPI
+//! licenses, OSV vulnerabilities, progress on stderr):
+//!
+//! ```no_run
+//! use uv_sbom::prelude::*;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! let output = Sbom::builder()
+//!     .project_path(".")
+//!     .check_cve(true)
+//!     .format(OutputFormat::Json)
+//!     .build()?
+//!     .run()
+//!     .await?;
+//! println!("{}", output.formatted);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To swap in other adapters, assemble the use case directly:
+//!
 //! ```no_run
 //! use uv_sbom::prelude::*;
 //! use std::path::PathBuf;
@@ -64,32 +89,44 @@
 pub mod adapters;
 pub mod application;
 pub mod config;
+pub mod facade;
 pub mod i18n;
 pub mod ports;
 pub mod sbom_generation;
 pub mod shared;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod test_fixtures;
 
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::adapters::outbound::console::StderrProgressReporter;
+    pub use crate::adapters::inbound::{CollectingObserver, ProgressReporterObserver};
+    pub use crate::adapters::outbound::console::{SilentProgressReporter, StderrProgressReporter};
     pub use crate::adapters::outbound::filesystem::{
         FileSystemReader, FileSystemWriter, StdoutPresenter,
     };
-    pub use crate::adapters::outbound::formatters::{CycloneDxFormatter, MarkdownFormatter};
+    pub use crate::adapters::outbound::formatters::{
+        CycloneDxFormatter, HtmlFormatter, MarkdownFormatter, SpecVersion,
+    };
     pub use crate::adapters::outbound::network::PyPiLicenseRepository;
     pub use crate::application::dto::{
         OutputFormat, SbomRequest, SbomRequestBuilder, SbomResponse,
     };
     pub use crate::application::factories::{FormatterFactory, PresenterFactory, PresenterType};
     pub use crate::application::use_cases::GenerateSbomUseCase;
+    pub use crate::facade::{Sbom, SbomBuilder, SbomOutput};
+    pub use crate::ports::inbound::{SbomGenerationEvent, SbomGenerationObserver, SbomPhase};
     pub use crate::ports::outbound::{
-        LicenseRepository, LockfileParseResult, LockfileReader, OutputPresenter, ProgressReporter,
-        ProjectConfigReader, SbomFormatter,
+        DependencyGroups, EdgeExtras, EdgeMarkers, LicenseRepository, LockfileParseResult,
+        LockfileReader, LockfileVersion, OutputPresenter, ProgressReporter, ProjectConfigReader,
+        SbomFormatter, SkippedLockfileLine, Verbosity,
     };
     pub use crate::sbom_generation::domain::{
-        DependencyGraph, LicenseInfo, Package, PackageName, SbomMetadata,
+        DependencyGraph, LicenseInfo, Package, PackageAuthorship, PackageLinks, PackageName,
+        SbomMetadata,
     };
-    pub use crate::sbom_generation::policies::LicensePriority;
+    pub use crate::sbom_generation::policies::{LicensePriority, LicenseResolution};
     pub use crate::sbom_generation::services::{DependencyAnalyzer, SbomGenerator};
+    pub use crate::shared::error::{GenerationPhase, LicenseFetchErrorKind, UvSbomError};
     pub use crate::shared::Result;
+    pub use tokio_util::sync::CancellationToken;
 }
@@ -5,29 +5,71 @@ mod i18n;
 mod ports;
 mod sbom_generation;
 mod shared;
+#[cfg(test)]
+mod test_fixtures;
 
-use adapters::outbound::console::StderrProgressReporter;
-use adapters::outbound::filesystem::FileSystemReader;
+use adapters::outbound::console::{
+    AnyProgressReporter, ConsoleSummary, ConsoleSummaryPresenter, JsonProgressReporter,
+    StderrProgressReporter,
+};
+use adapters::outbound::filesystem::{
+    is_output_closed, AnyLockfileReader, CveBaselineFile, CycloneDxReader, FileSystemReader,
+    LocalMetadataLicenseRepository, PypaAdvisoryDbRepository, StdinLockfileReader, STDIN_LOCKFILE,
+};
+use adapters::outbound::formatters::{
+    CycloneDxValidator, GraphFormatter, MarkdownFormatter, VulnerabilityJsonFormatter,
+};
 use adapters::outbound::network::{
-    CachingPyPiLicenseRepository, OsvClient, PyPiLicenseRepository, PyPiMaintenanceRepository,
+    cve_cache_dir, CachedVulnerabilityRepository, CachingPyPiLicenseRepository, IndexCredentials,
+    OsvClient, PyPiLicenseRepository, PyPiMaintenanceRepository, RequestCounter,
 };
 use adapters::outbound::uv::UvWorkspaceReader;
+use adapters::outbound::{ChainedLicenseRepository, CompositeVulnerabilityRepository};
 use application::dto::{OutputFormat, SbomRequest};
 use application::factories::{FormatterFactory, PresenterFactory, PresenterType};
-use application::read_models::SbomReadModelBuilder;
-use application::use_cases::GenerateSbomUseCase;
+use application::read_models::{SbomDiffView, SbomReadModelBuilder, SbomWarning};
+use application::use_cases::{
+    AggregateSbomUseCase, DiffSbomUseCase, DiffSource, GenerateSbomUseCase, ScanSbomUseCase,
+};
 use clap::Parser;
-use cli::config_resolver::{load_config, merge_config};
-use cli::runner::{display_banner, resolve_suggest_fix, validate_project_path};
-use cli::Args;
-use i18n::Messages;
-use ports::outbound::{LockfileParseResult, LockfileReader, ProjectConfigReader, WorkspaceReader};
-use shared::error::ExitCode;
+use cli::config_resolver::{check_threshold_sources, config_file_path, load_config, merge_config};
+use cli::path_resolver::{
+    expand_tilde, resolve_file_override, resolve_output_path, simplify_verbatim,
+    validate_output_dir, validate_output_path,
+};
+use cli::runner::{
+    display_banner, report_request_usage, report_user_agent, resolve_data_dir, resolve_suggest_fix,
+    validate_project_path,
+};
+use cli::{
+    Args, Command, DiffArgs, DryRunMode, LicenseSource, MergeArgs, ProgressFormat, ScanArgs,
+    VerifyArgs,
+};
+use i18n::{Locale, Messages};
+use ports::outbound::{
+    LockfileParseResult, LockfileReader, ProgressReporter, ProjectConfigReader, SbomFormatter,
+    Verbosity, WorkspaceReader,
+};
+use sbom_generation::domain::services::{
+    ThresholdConfig, ValidationOutcome, ValidationReport, VulnerabilityCheckResult,
+};
+use sbom_generation::domain::vulnerability::CvssPreference;
+use sbom_generation::domain::{MarkerEnvironment, VulnerabilityBaseline};
+use sbom_generation::policies::LicensePriority;
+use sbom_generation::services::SbomGenerator;
+use shared::digest::{parse_sha256sum, sha256_hex, sha256sum_line};
+use shared::error::{ExitCode, UvSbomError};
+use shared::terminal::{stderr_text, ProgressDisplay};
 use shared::Result;
 use std::path::{Path, PathBuf};
 use std::process;
+use std::time::Instant;
+use tokio_util::sync::CancellationToken;
 use uv_sbom::config;
 
+/// File the vulnerability check result is written to in `--output-dir`
+const CVE_REPORT_FILE_NAME: &str = "cve-report.json";
+
 /// A LockfileReader adapter that reads the workspace-root uv.lock but returns
 /// only packages reachable from the specified workspace member.
 ///
@@ -40,9 +82,9 @@ struct MemberScopedLockfileReader {
 }
 
 impl MemberScopedLockfileReader {
-    fn new(workspace_root: PathBuf, member_name: String) -> Self {
+    fn new(workspace_root: PathBuf, member_name: String, max_lockfile_size: u64) -> Self {
         Self {
-            inner: FileSystemReader::new(),
+            inner: FileSystemReader::new().with_max_lockfile_size(max_lockfile_size),
             workspace_root,
             member_name,
         }
@@ -88,78 +130,140 @@ async fn main() {
         }
     };
 
+    // Handle the diff subcommand before normal flow
+    if let Some(Command::Diff(diff_args)) = &args.command {
+        match run_diff(diff_args, args.lang) {
+            Ok(()) => process::exit(ExitCode::Success.as_i32()),
+            Err(e) => exit_with_error(e),
+        }
+    }
+
+    // Handle the merge subcommand before normal flow
+    if let Some(Command::Merge(merge_args)) = &args.command {
+        match run_merge(merge_args, args.lang).await {
+            Ok(()) => process::exit(ExitCode::Success.as_i32()),
+            Err(e) => exit_with_error(e),
+        }
+    }
+
+    // Handle the verify subcommand before normal flow
+    if let Some(Command::Verify(verify_args)) = &args.command {
+        match run_verify(verify_args, args.lang) {
+            Ok(()) => process::exit(ExitCode::Success.as_i32()),
+            Err(e) => exit_with_error(e),
+        }
+    }
+
+    // Handle the scan subcommand before normal flow
+    if let Some(Command::Scan(scan_args)) = &args.command {
+        match run_scan(scan_args, args.lang).await {
+            Ok(exit_code) => process::exit(exit_code.as_i32()),
+            Err(e) => exit_with_error(e),
+        }
+    }
+
     // Handle --workspace mode before normal flow
     if args.workspace {
         let workspace_root = PathBuf::from(args.path.as_deref().unwrap_or("."));
         match run_workspace(args, workspace_root).await {
             Ok(()) => process::exit(ExitCode::Success.as_i32()),
-            Err(e) => {
-                eprintln!("\n❌ An error occurred:\n");
-                eprintln!("{}", e);
-                let mut source = e.source();
-                while let Some(err) = source {
-                    eprintln!("\nCaused by: {}", err);
-                    source = err.source();
-                }
-                eprintln!();
-                process::exit(ExitCode::ApplicationError.as_i32());
-            }
+            Err(e) => exit_with_error(e),
         }
     }
 
     // Handle --init before normal flow
     if args.init {
-        let dir = args.path.as_deref().unwrap_or(".");
-        let dir_path = std::path::Path::new(dir);
+        let dir_path = expand_tilde(args.path.as_deref().unwrap_or("."));
+        let dir_path = dir_path.as_path();
         match config::generate_config_template(dir_path) {
             Ok(abs_path) => {
                 eprintln!(
                     "Created {} in {}",
                     config::CONFIG_FILENAME,
-                    abs_path.parent().unwrap_or(dir_path).display()
+                    simplify_verbatim(abs_path.parent().unwrap_or(dir_path).to_path_buf())
+                        .display()
                 );
                 process::exit(ExitCode::Success.as_i32());
             }
             Err(e) => {
                 eprintln!("Error: {}", e);
-                process::exit(ExitCode::ApplicationError.as_i32());
+                process::exit(uv_sbom::shared::error::ExitCode::from(&e).as_i32());
             }
         }
     }
 
     // Run the main application logic
     match run(args).await {
-        Ok(has_vulnerabilities) => {
-            if has_vulnerabilities {
-                process::exit(ExitCode::VulnerabilitiesDetected.as_i32());
-            }
-            process::exit(ExitCode::Success.as_i32());
-        }
-        Err(e) => {
-            eprintln!("\n❌ An error occurred:\n");
-            eprintln!("{}", e);
-
-            // Display error chain
-            let mut source = e.source();
-            while let Some(err) = source {
-                eprintln!("\nCaused by: {}", err);
-                source = err.source();
-            }
+        Ok(exit_code) => process::exit(exit_code.as_i32()),
+        Err(e) => exit_with_error(e),
+    }
+}
 
-            eprintln!();
-            process::exit(ExitCode::ApplicationError.as_i32());
-        }
+/// Prints an error with its chain of causes and exits with the matching code.
+fn exit_with_error(error: anyhow::Error) -> ! {
+    eprintln!("\n{}\n", stderr_text("❌ An error occurred:"));
+    eprintln!("{}", error);
+
+    // Display error chain
+    let mut source = error.source();
+    while let Some(err) = source {
+        eprintln!("\nCaused by: {}", err);
+        source = err.source();
+    }
+
+    eprintln!();
+    process::exit(exit_code_for(&error));
+}
+
+/// Returns the exit code for an error that ended the run.
+///
+/// Library errors map through `ExitCode::from`; errors raised by the CLI itself,
+/// such as invalid option combinations, are application errors.
+fn exit_code_for(error: &anyhow::Error) -> i32 {
+    if let Some(e) = error.downcast_ref::<UvSbomError>() {
+        return ExitCode::from(e).as_i32();
     }
+    // Config file errors come from the library crate's `config` module
+    if let Some(e) = error.downcast_ref::<uv_sbom::shared::error::UvSbomError>() {
+        return uv_sbom::shared::error::ExitCode::from(e).as_i32();
+    }
+    ExitCode::ApplicationError.as_i32()
+}
+
+/// Returns a token that is cancelled on the first Ctrl-C.
+///
+/// Cancelling lets generation stop its network phases and report how far it got;
+/// a second Ctrl-C exits immediately with the conventional SIGINT status 130.
+fn cancel_on_ctrl_c() -> CancellationToken {
+    let token = CancellationToken::new();
+    let cancel = token.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            cancel.cancel();
+            if tokio::signal::ctrl_c().await.is_ok() {
+                process::exit(130);
+            }
+        }
+    });
+    token
 }
 
 /// Runs the main application logic.
 ///
-/// Returns `Ok(true)` if vulnerabilities were detected above threshold,
-/// `Ok(false)` if no vulnerabilities (or all below threshold),
-/// or `Err` for application errors.
-async fn run(args: Args) -> Result<bool> {
+/// Returns the exit code for the scan results: `VulnerabilitiesDetected` for
+/// vulnerabilities above the threshold (and other failing checks),
+/// `LicenseViolations` when only the license policy failed, otherwise `Success`.
+/// Application errors are returned as `Err`.
+async fn run(args: Args) -> anyhow::Result<ExitCode> {
+    let started = Instant::now();
+    let verbosity = args.verbosity();
+    let quiet = verbosity == Verbosity::Quiet;
+    let license_fetch_fields = args.license_fetch_fields();
+
     // Display startup banner
-    display_banner();
+    if !quiet {
+        display_banner();
+    }
 
     let locale = args.lang;
     let msgs = Messages::for_locale(locale);
@@ -169,8 +273,11 @@ async fn run(args: Args) -> Result<bool> {
         eprintln!("Warning: --check-cve is deprecated and will be removed in a future release. CVE checking is now enabled by default. Use --no-check-cve to opt out.");
     }
 
+    // The report-only options below have no effect when every requested format is JSON
+    let json_only = args.format.iter().all(|f| *f == OutputFormat::Json);
+
     // Warn if CVE check is active with JSON format
-    if !args.no_check_cve && args.format == OutputFormat::Json {
+    if !args.no_check_cve && !args.offline && json_only {
         eprintln!("{}", msgs.warn_check_cve_no_effect);
         eprintln!("   Vulnerability data is not included in JSON output.");
         eprintln!("   Use --format markdown to see vulnerability report.");
@@ -178,50 +285,208 @@ async fn run(args: Args) -> Result<bool> {
     }
 
     // Warn if check_license is used with JSON format
-    if args.check_license && args.format == OutputFormat::Json {
+    if args.check_license && json_only {
         eprintln!("{}", msgs.warn_check_license_no_effect);
         eprintln!("   License compliance data is not included in JSON output.");
         eprintln!("   Use --format markdown to see license compliance report.");
         eprintln!();
     }
 
+    // Warn if check_license_compatibility is used with JSON format
+    if args.check_license_compatibility && json_only {
+        eprintln!("{}", msgs.warn_check_license_compatibility_no_effect);
+        eprintln!("   Only --fail-on-license-incompatibility still affects the exit code.");
+        eprintln!("   Use --format markdown to see the license compatibility report.");
+        eprintln!();
+    }
+
+    // Warn if check_license_reachability is used with JSON format
+    if args.check_license_reachability && json_only {
+        eprintln!("{}", msgs.warn_check_license_reachability_no_effect);
+        eprintln!("   The reachability check needs the dependency graph of the Markdown report.");
+        eprintln!("   Use --format markdown to see the license risk report.");
+        eprintln!();
+    }
+
     // Warn if verify_links is used with JSON format
-    if args.verify_links && args.format == OutputFormat::Json {
+    if args.verify_links && json_only {
         eprintln!("{}", msgs.warn_verify_links_no_effect);
         eprintln!("   PyPI link verification only applies to Markdown output.");
         eprintln!("   Use --format markdown to use link verification.");
         eprintln!();
     }
 
-    // Validate project directory
+    // Warn if validate is used with a format other than CycloneDX JSON
+    if args.validate && !args.format.contains(&OutputFormat::Json) {
+        let format_name = match args.format[0] {
+            OutputFormat::Html => "HTML",
+            _ => "Markdown",
+        };
+        eprintln!(
+            "{}",
+            Messages::format(msgs.warn_validate_no_effect, &[format_name])
+        );
+        eprintln!("   Validation only applies to CycloneDX JSON output.");
+        eprintln!();
+    }
+
+    // Validate project directory (expands `~` and resolves symlinks). A lockfile
+    // piped through stdin may come without any project directory.
+    let lockfile_from_stdin = args.lockfile.as_deref() == Some(STDIN_LOCKFILE);
     let project_dir = args.path.as_deref().unwrap_or(".");
-    let project_path = PathBuf::from(project_dir);
+    let project_path = if lockfile_from_stdin {
+        expand_tilde(project_dir)
+    } else {
+        validate_project_path(Path::new(project_dir))?
+    };
 
-    validate_project_path(&project_path)?;
+    // Reject an unusable --output before any network lookups are made
+    if let Some(raw) = args.output.as_deref() {
+        let resolved = resolve_output_path(raw, args.output_relative_to, &project_path);
+        if args.markdown_split {
+            validate_output_dir("--output", raw, &resolved)?;
+        } else {
+            validate_output_path("--output", raw, &resolved)?;
+        }
+    }
 
     // Load config file (explicit path or auto-discovery)
     let config = load_config(&args, &project_path)?;
+    let config_path = config_file_path(&args, &project_path);
 
     // Merge CLI and config values
+    check_threshold_sources(&args, &config)?;
     let merged = merge_config(&args, &config);
 
+    if args.markdown_split && merged.format != OutputFormat::Markdown {
+        anyhow::bail!("--markdown-split requires --format markdown");
+    }
+    if merged.formats.len() > 1 && args.output_dir.is_none() {
+        anyhow::bail!("Writing several formats in one run requires --output-dir");
+    }
+    if let Some(stdout_format) = args.stdout_format {
+        if !merged.formats.contains(&stdout_format) {
+            anyhow::bail!(
+                "--stdout-format {} must be one of the formats written to --output-dir",
+                stdout_format
+            );
+        }
+    }
+    if args.cve_report.is_some() && !merged.check_cve {
+        anyhow::bail!("--cve-report requires the CVE check, which is disabled for this run");
+    }
+    if args.cve_baseline.is_some() && !merged.check_cve {
+        anyhow::bail!("--cve-baseline requires the CVE check, which is disabled for this run");
+    }
+    if let Some(format) = merged
+        .formats
+        .iter()
+        .find(|f| matches!(f, OutputFormat::Markdown | OutputFormat::Html))
+        .filter(|_| {
+            lockfile_from_stdin
+                && args.project_name.is_none()
+                && args.pyproject.is_none()
+                && args.roots.is_empty()
+        })
+    {
+        anyhow::bail!(
+            "--lockfile - needs --project-name (or --pyproject) for {} output: the project name \
+             identifies the direct dependencies",
+            format
+        );
+    }
+    let source_date_epoch = read_source_date_epoch(args.reproducible)?;
+
+    // Read the accepted vulnerabilities; with --update-cve-baseline the file may not exist yet
+    let cve_baseline_path = args
+        .cve_baseline
+        .as_deref()
+        .map(resolve_file_override)
+        .transpose()?;
+    let cve_baseline = match &cve_baseline_path {
+        Some(path) if !args.update_cve_baseline || path.exists() => CveBaselineFile::read(path)?,
+        _ => VulnerabilityBaseline::new(),
+    };
+
     // Create adapters (Dependency Injection)
-    let lockfile_reader = FileSystemReader::new();
-    let project_config_reader = FileSystemReader::new();
-    let pypi_repository = PyPiLicenseRepository::new()?;
-    let license_repository = CachingPyPiLicenseRepository::new(pypi_repository);
-    let progress_reporter = StderrProgressReporter::new(locale);
+    // All network adapters share one counter so --max-requests bounds the whole run
+    let request_counter = RequestCounter::new(args.max_requests);
+    let lockfile_reader = match args.lockfile.as_deref() {
+        Some(STDIN_LOCKFILE) => {
+            AnyLockfileReader::Stdin(StdinLockfileReader::from_stdin(args.max_lockfile_bytes())?)
+        }
+        Some(path) => AnyLockfileReader::for_path(resolve_file_override(path)?)?,
+        None => {
+            let (reader, both_present) = AnyLockfileReader::detect(&project_path);
+            if both_present {
+                eprintln!("{}", msgs.warn_both_lockfiles_found);
+            }
+            reader
+        }
+    }
+    .with_max_lockfile_size(args.max_lockfile_bytes());
+    let pyproject_path = args
+        .pyproject
+        .as_deref()
+        .map(resolve_file_override)
+        .transpose()?;
+    let project_config_reader = build_project_config_reader(pyproject_path.as_ref());
+    report_user_agent(&merged.network, merged.offline, verbosity, msgs);
+    let pypi_repository = configure_index(
+        PyPiLicenseRepository::new()?
+            .with_network_config(&merged.network)?
+            .with_request_counter(request_counter.clone())
+            .with_retry_config(merged.retry)
+            .with_fields(license_fetch_fields)
+            .with_license_priority(merged.license_priority),
+        merged.index_url.as_deref(),
+        args.fallback_to_pypi,
+    )?;
+    let license_repository = build_license_repository(
+        args.license_source,
+        &project_path,
+        merged.license_priority,
+        CachingPyPiLicenseRepository::new(pypi_repository.clone()),
+    );
+    // `done` is held back until the output is written, so it stays the last JSON event
+    let progress_reporter =
+        progress_reporter(args.progress_format, args.progress, locale, verbosity)
+            .with_deferred_done();
+
+    // Resolve where cache, state and temporary files go; never the project directory
+    let data_dir = resolve_data_dir(args.data_dir.as_deref().map(expand_tilde).as_deref(), msgs);
+    let scratch_dir = data_dir.clone().unwrap_or_else(std::env::temp_dir);
 
     // Create vulnerability repository if CVE check is requested
-    let vulnerability_repository = if merged.check_cve {
-        Some(OsvClient::new()?)
+    let osv_client = if merged.check_cve && !merged.offline {
+        Some(cache_osv_results(
+            OsvClient::new()?
+                .with_network_config(&merged.network)?
+                .with_request_counter(request_counter.clone())
+                .with_retry_config(merged.retry)
+                .with_detail_workers(merged.osv_detail_workers)
+                .with_cvss_preference(args.cvss_preference),
+            &args,
+            &scratch_dir,
+        ))
     } else {
         None
     };
+    let vulnerability_repository = build_vulnerability_repository(
+        merged.check_cve,
+        osv_client,
+        merged.advisory_db.as_deref(),
+        args.cvss_preference,
+    )?;
 
     // Create maintenance repository if abandoned check is requested
     let maintenance_repository = if merged.check_abandoned {
-        Some(PyPiMaintenanceRepository::new()?)
+        Some(
+            PyPiMaintenanceRepository::new()?
+                .with_network_config(&merged.network)?
+                .with_request_counter(request_counter.clone())
+                .with_retry_config(merged.retry),
+        )
     } else {
         None
     };
@@ -231,63 +496,150 @@ async fn run(args: Args) -> Result<bool> {
         lockfile_reader,
         project_config_reader,
         license_repository,
-        progress_reporter,
+        progress_reporter.clone(),
         vulnerability_repository,
         maintenance_repository,
         locale,
     );
 
     // Pre-flight check for --suggest-fix
-    let suggest_fix = resolve_suggest_fix(merged.suggest_fix, &project_path);
+    let suggest_fix = resolve_suggest_fix(merged.suggest_fix, &project_path, &scratch_dir);
 
     // Create request using builder pattern
-    let include_dependency_info = matches!(merged.format, OutputFormat::Markdown);
+    let show_descriptions = merged
+        .formats
+        .iter()
+        .any(|f| matches!(f, OutputFormat::Markdown | OutputFormat::Html));
+    let include_dependency_info = args.graph_format.is_some() || show_descriptions;
+    let informational_display = args.informational_display();
+    let project_name = args.project_name.clone();
+    let target_environment = MarkerEnvironment::new(args.platform, args.marker_env);
     let request = SbomRequest::builder()
         .project_path(project_path.clone())
         .include_dependency_info(include_dependency_info)
+        .show_descriptions(show_descriptions)
         .exclude_patterns(merged.exclude_patterns)
-        .dry_run(args.dry_run)
+        .exclude_scope(merged.exclude_scope)
+        .only_groups(args.only_groups)
+        .exclude_groups(args.exclude_groups)
+        .marker_environment(target_environment.clone())
+        .dry_run(args.dry_run.is_some())
+        .probe_endpoints(args.dry_run == Some(DryRunMode::Full))
+        .stats(args.stats)
+        .lockfile_digest(args.emit_digest)
+        .reproducible(args.reproducible)
+        .source_date_epoch_opt(source_date_epoch)
+        .timeout_opt(args.timeout)
         .check_cve(merged.check_cve)
         .severity_threshold_opt(merged.severity_threshold)
         .cvss_threshold_opt(merged.cvss_threshold)
         .ignore_cves(merged.ignore_cves)
+        .vex(merged.vex)
+        .cve_baseline(cve_baseline)
         .check_license(merged.check_license)
         .license_policy(merged.license_policy)
+        .check_license_compatibility(merged.check_license_compatibility)
+        .license_compatibility_policy(merged.license_compatibility_policy)
+        .project_license_opt(merged.project_license)
+        .project_name_opt(project_name.clone())
+        .roots(args.roots.clone())
+        .fail_on_license_incompatibility(merged.fail_on_license_incompatibility)
+        .license_overrides(merged.license_overrides)
+        .check_license_reachability(merged.check_license_reachability)
+        .strict_license_reachability(merged.strict_license_reachability)
+        .copyleft_licenses(merged.copyleft_licenses)
+        .check_python_compat(merged.check_python_compat)
+        .check_typosquats(merged.check_typosquats)
+        .typosquat_max_distance(merged.typosquat_max_distance)
+        .ignore_typosquats(merged.ignore_typosquats.clone())
+        .fail_on_typosquat(merged.fail_on_typosquat)
         .suggest_fix(suggest_fix)
+        .suggest_fixes(merged.suggest_fixes)
+        .data_dir_opt(data_dir)
+        .config_path_opt(config_path)
+        .index_url_opt(merged.index_url.clone())
+        .license_resolution(merged.license_priority.resolution())
+        .check_self(merged.check_self)
+        .self_check_fails_build(merged.self_check_fails_build)
         .check_abandoned(merged.check_abandoned)
         .abandoned_threshold_days(merged.abandoned_threshold_days)
+        .offline(merged.offline)
+        .advisory_db_opt(merged.advisory_db.clone())
+        .license_fetch_fields(license_fetch_fields)
+        .licenses_for(args.licenses_for.unwrap_or_default())
+        .max_network_requests_opt(merged.max_network_requests)
+        .check_lock(!args.no_lock_check)
+        .strict_lock(args.strict_lock)
+        .strict_lock_version(args.strict_lock_version)
+        .strict(args.strict)
         .locale(locale)
         .build()?;
 
     // Re-bind locale from the validated request to ensure consistency
     let locale = request.locale;
 
-    // Execute use case
-    let response = use_case.execute(request).await?;
+    // Execute use case; Ctrl-C stops it between lookups instead of killing the process
+    let response = use_case
+        .execute_with_cancel(request, cancel_on_ctrl_c())
+        .await?;
 
-    // Skip output generation for dry-run mode
-    if args.dry_run {
-        return Ok(false);
+    let bytes_downloaded = pypi_repository.bytes_downloaded();
+    if bytes_downloaded > 0 && !quiet {
+        eprintln!(
+            "{}",
+            Messages::format(
+                msgs.progress_license_bytes_downloaded,
+                &[&bytes_downloaded.div_ceil(1024).to_string()],
+            )
+        );
     }
 
+    // Skip output generation for dry-run mode; the exit code reflects the validation findings
+    if args.dry_run.is_some() {
+        let outcome = response
+            .validation_report
+            .as_ref()
+            .map_or(ValidationOutcome::Valid, ValidationReport::outcome);
+        progress_reporter.finish();
+        return Ok(match outcome {
+            ValidationOutcome::Valid => ExitCode::Success,
+            ValidationOutcome::ValidWithWarnings => ExitCode::ValidationWarnings,
+            ValidationOutcome::Invalid => ExitCode::ApplicationError,
+        });
+    }
+
+    // Counted before the read model takes ownership of the response
+    let console_summary =
+        (!quiet && !args.no_summary).then(|| ConsoleSummary::from_response(&response));
+
     // Display progress message
-    eprintln!(
-        "{}",
-        FormatterFactory::progress_message(merged.format, locale)
-    );
+    if !quiet {
+        for &format in &merged.formats {
+            eprintln!(
+                "{}",
+                stderr_text(FormatterFactory::progress_message(format, locale))
+            );
+        }
+    }
 
-    // Determine project component for CycloneDX metadata
-    let project_reader = FileSystemReader::new();
-    let project_component_info = project_reader
-        .read_project_name(&project_path)
-        .ok()
-        .and_then(|name| {
-            let version = response
-                .enriched_packages
-                .iter()
-                .find(|ep| ep.package.name() == name)
-                .map(|ep| ep.package.version().to_string());
-            version.map(|v| (name, v))
+    // Determine project component for CycloneDX metadata; a dynamic version falls
+    // back to the one the lockfile records, if any
+    let project_reader = build_project_config_reader(pyproject_path.as_ref());
+    let project_component_info = project_name
+        .or_else(|| project_reader.read_project_name(&project_path).ok())
+        .map(|name| {
+            let version = project_reader
+                .read_project_version(&project_path)
+                .ok()
+                .flatten()
+                .or_else(|| {
+                    response
+                        .enriched_packages
+                        .iter()
+                        .find(|ep| ep.package.name() == name)
+                        .map(|ep| ep.package.version().to_string())
+                });
+            (name, version)
         });
 
     // Build read model first so we can extract package names for verification
@@ -299,14 +651,59 @@ async fn run(args: Args) -> Result<bool> {
         response.license_compliance_result.as_ref(),
         project_component_info
             .as_ref()
-            .map(|(n, v)| (n.as_str(), v.as_str())),
+            .map(|(n, v)| (n.as_str(), v.as_deref())),
         response.upgrade_recommendations.as_deref(),
-    );
+    )
+    .with_project_self_check(
+        response
+            .project_self_check_result
+            .as_ref()
+            .map(SbomReadModelBuilder::build_project_self_check),
+    )
+    .with_license_compatibility(
+        response
+            .license_compatibility_result
+            .as_ref()
+            .map(SbomReadModelBuilder::build_license_compatibility),
+    )
+    .with_license_risk(
+        response
+            .license_risk_report
+            .as_ref()
+            .map(SbomReadModelBuilder::build_license_risk),
+    )
+    .with_python_compatibility(response.python_compatibility_report.as_ref())
+    .with_typosquat_findings(response.typosquat_findings.as_deref())
+    .with_fix_suggestions(
+        response
+            .fix_suggestions
+            .as_deref()
+            .map(SbomReadModelBuilder::build_fix_suggestions),
+    )
+    .with_annotations(&response.package_annotations)
+    .with_license_overrides(&response.license_overrides)
+    .with_warnings(response.warnings)
+    .with_stats(response.stats)
+    .with_lockfile_sha256(response.lockfile_sha256)
+    .with_target_environment(
+        (!target_environment.is_empty()).then(|| target_environment.to_string()),
+    )
+    .with_informational_display(informational_display);
+    let read_model = if args.reproducible {
+        read_model.with_stable_order()
+    } else {
+        read_model
+    };
 
     // Verify PyPI links if requested
-    let verified_packages = if args.verify_links && merged.format == OutputFormat::Markdown {
-        eprintln!("{}", msgs.progress_verifying_links);
-        let pypi_verifier = PyPiLicenseRepository::new()?;
+    let verified_packages = if args.verify_links && merged.formats.contains(&OutputFormat::Markdown)
+    {
+        if !quiet {
+            eprintln!("{}", stderr_text(msgs.progress_verifying_links));
+        }
+        let pypi_verifier = PyPiLicenseRepository::new()?
+            .with_network_config(&merged.network)?
+            .with_request_counter(request_counter.clone());
         let package_names: Vec<String> = read_model
             .components
             .iter()
@@ -317,31 +714,689 @@ async fn run(args: Args) -> Result<bool> {
         None
     };
 
-    // Create formatter using factory with optional verified packages
-    let formatter = FormatterFactory::create(merged.format, verified_packages, locale);
-    let formatted_output = formatter.format(&read_model)?;
-
-    // Create presenter using factory
-    let presenter_type = if let Some(output_path) = args.output {
-        PresenterType::File(PathBuf::from(output_path))
+    // Create presenter using factory; relative output paths follow --output-relative-to
+    let resolve_output =
+        |raw: &str| resolve_output_path(raw, args.output_relative_to, &project_path);
+    let output_path = args.output.as_deref().map(resolve_output);
+    let presenter_type = if let Some(output_path) = &output_path {
+        PresenterType::File(output_path.clone())
+    } else if let Some(output_dir) = &args.output_dir {
+        PresenterType::File(resolve_output(output_dir))
     } else {
         PresenterType::Stdout
     };
+    let presenter = PresenterFactory::create_with_verbosity(
+        presenter_type,
+        locale,
+        args.create_dirs,
+        verbosity,
+    );
+    let markdown_style = args
+        .markdown_style
+        .with_license_source(args.show_license_source)
+        .with_supplier(args.include_supplier)
+        .with_tree_depth(args.tree_depth)
+        .with_description_max_length(args.description_max_length);
+    let digest_reporter = args.emit_digest.then(|| progress_reporter.clone());
+
+    let presented = if args.output_dir.is_some() {
+        // One file per format from the same read model, so PyPI and OSV are queried once
+        let mut files = Vec::new();
+        let mut stdout_output = None;
+        for (format, formatter) in FormatterFactory::create_all(
+            &merged.formats,
+            verified_packages,
+            locale,
+            markdown_style,
+            merged.spec_version,
+        ) {
+            let formatted_output = formatter.format(&read_model)?;
+            if args.validate && format == OutputFormat::Json {
+                validate_cyclonedx(&formatted_output, quiet, msgs)?;
+            }
+            if args.stdout_format == Some(format) {
+                stdout_output = Some(formatted_output.clone());
+            }
+            files.push((format.file_name().to_string(), formatted_output));
+        }
+        if merged.check_cve {
+            let report = VulnerabilityJsonFormatter::new().format(&read_model)?;
+            files.push((CVE_REPORT_FILE_NAME.to_string(), report));
+        }
+        if let Some(reporter) = &digest_reporter {
+            files = with_digests(files, reporter, msgs);
+        }
+        // Every file is attempted before a write error is reported
+        let written = presenter.present_many(&files);
+        let printed = stdout_output.map_or(Ok(()), |output| {
+            PresenterFactory::create(PresenterType::Stdout, locale).present(&output)
+        });
+        written.and(printed)
+    } else if args.markdown_split {
+        // --output names a directory; one linked file is written per section group
+        let formatter = match verified_packages {
+            Some(packages) => MarkdownFormatter::with_verified_packages(packages, locale),
+            None => MarkdownFormatter::new(locale),
+        }
+        .with_style(markdown_style);
+        let mut files = formatter.format_split(&read_model);
+        if let Some(reporter) = &digest_reporter {
+            files = with_digests(files, reporter, msgs);
+        }
+        presenter.present_many(&files)
+    } else {
+        // Create formatter using factory with optional verified packages
+        let formatter = FormatterFactory::create(
+            merged.format,
+            verified_packages,
+            locale,
+            markdown_style,
+            merged.spec_version,
+        );
+        let formatted_output = formatter.format(&read_model)?;
+        if args.validate && merged.format == OutputFormat::Json {
+            validate_cyclonedx(&formatted_output, quiet, msgs)?;
+        }
+        presenter.present(&formatted_output).and_then(|()| {
+            digest_reporter.as_ref().map_or(Ok(()), |reporter| {
+                write_digest(
+                    &formatted_output,
+                    output_path.as_deref(),
+                    reporter,
+                    verbosity,
+                    locale,
+                )
+            })
+        })
+    };
+    match presented {
+        // The consumer stopped reading early (e.g. `| head -c 200`); the SBOM itself
+        // was generated, so this is not a failure
+        Err(e) if is_output_closed(&e) => {}
+        other => other?,
+    }
 
-    let presenter = PresenterFactory::create(presenter_type, locale);
-    presenter.present(&formatted_output)?;
+    // The CVE report goes to its own file so one run yields both the SBOM and the gate input
+    if let Some(report_path) = &args.cve_report {
+        let report = VulnerabilityJsonFormatter::new().format(&read_model)?;
+        PresenterFactory::create_with_verbosity(
+            PresenterType::File(resolve_output(report_path)),
+            locale,
+            args.create_dirs,
+            verbosity,
+        )
+        .present(&report)?;
+    }
 
-    // Determine if vulnerabilities, license violations, or abandoned packages were detected
+    if let (Some(graph_format), Some(graph_path)) = (args.graph_format, &args.graph_output) {
+        let graph = GraphFormatter::new(graph_format).format(&read_model)?;
+        PresenterFactory::create_with_verbosity(
+            PresenterType::File(resolve_output(graph_path)),
+            locale,
+            args.create_dirs,
+            verbosity,
+        )
+        .present(&graph)?;
+    }
+
+    if let Some(summary) = console_summary {
+        let output = output_path
+            .clone()
+            .or_else(|| args.output_dir.as_deref().map(resolve_output));
+        ConsoleSummaryPresenter::new(locale)
+            .present(&summary.with_output(output).with_elapsed(started.elapsed()));
+    }
+
+    if let Some(path) = cve_baseline_path.filter(|_| args.update_cve_baseline) {
+        let complete_result = response
+            .vulnerability_check_result
+            .as_ref()
+            .filter(|_| !response.vulnerability_check_incomplete);
+        update_cve_baseline(complete_result, &path, args.create_dirs, verbosity, locale)?;
+    }
+
+    report_request_usage(&request_counter, verbosity, &progress_reporter, msgs);
+
+    // Determine if vulnerabilities, license violations, abandoned packages or (with
+    // --fail-on-yanked) yanked releases were detected, or whether the vulnerability
+    // check could not cover every package
     let has_abandoned = response
         .abandoned_packages_report
         .as_ref()
         .map(|r| !r.is_empty())
         .unwrap_or(false);
-    let has_issues = response.has_vulnerabilities_above_threshold
-        || response.has_license_violations
-        || has_abandoned;
+    let yanked: Vec<String> = read_model
+        .components
+        .iter()
+        .filter(|component| args.fail_on_yanked && component.yanked.is_some())
+        .map(|component| format!("{} {}", component.name, component.version))
+        .collect();
+    if !yanked.is_empty() {
+        progress_reporter.report_error(&Messages::format(
+            msgs.error_yanked_releases,
+            &[&yanked.join(", ")],
+        ));
+    }
+    let fetch_failures: Vec<String> = read_model
+        .components
+        .iter()
+        .filter(|component| {
+            args.fail_on_enrichment_error && component.license_fetch_error.is_some()
+        })
+        .map(|component| format!("{} {}", component.name, component.version))
+        .collect();
+    if !fetch_failures.is_empty() {
+        progress_reporter.report_error(&Messages::format(
+            msgs.error_enrichment_failures,
+            &[&fetch_failures.join(", ")],
+        ));
+    }
+    // Findings written by --update-cve-baseline are accepted, so they do not fail the run
+    let has_vulnerabilities =
+        response.has_vulnerabilities_above_threshold && !args.update_cve_baseline;
+    let exit_code = if has_vulnerabilities
+        || response.has_license_incompatibilities
+        || response.has_typosquats
+        || has_abandoned
+        || !yanked.is_empty()
+    {
+        ExitCode::VulnerabilitiesDetected
+    } else if response.has_license_violations {
+        ExitCode::LicenseViolations
+    } else if response.vulnerability_check_incomplete || !fetch_failures.is_empty() {
+        // Unchecked packages may be vulnerable, and with --fail-on-enrichment-error
+        // missing metadata is a failure, so the run cannot report success
+        ExitCode::ApplicationError
+    } else {
+        ExitCode::Success
+    };
+
+    progress_reporter.finish();
+    Ok(exit_code)
+}
+
+/// Writes the findings of this run to the `--cve-baseline` file, replacing its entries.
+///
+/// `result` is `None` when the vulnerability check did not run or did not cover
+/// every package; the file is then left alone, as the accepted entries of the
+/// unchecked packages would be lost.
+fn update_cve_baseline(
+    result: Option<&VulnerabilityCheckResult>,
+    path: &Path,
+    create_dirs: bool,
+    verbosity: Verbosity,
+    locale: Locale,
+) -> anyhow::Result<()> {
+    let Some(result) = result else {
+        anyhow::bail!(
+            "--update-cve-baseline needs a complete vulnerability check; {} was not updated",
+            path.display()
+        );
+    };
+
+    let baseline = VulnerabilityBaseline::from_findings(
+        result.above_threshold.iter().chain(&result.below_threshold),
+    );
+    PresenterFactory::create_with_verbosity(
+        PresenterType::File(path.to_path_buf()),
+        locale,
+        create_dirs,
+        verbosity,
+    )
+    .present(&CveBaselineFile::render(&baseline)?)?;
+    if verbosity != Verbosity::Quiet {
+        let msgs = Messages::for_locale(locale);
+        eprintln!(
+            "{}",
+            Messages::format(
+                msgs.progress_cve_baseline_updated,
+                &[&baseline.len().to_string()]
+            )
+        );
+    }
+    Ok(())
+}
+
+/// Builds the license repository for the selected `--license-source`.
+///
+/// Installed distributions are looked up in `project_path/.venv`; in a
+/// workspace that is the workspace root, shared by all members. They are
+/// read with the same `priority` as the PyPI metadata.
+fn build_license_repository(
+    source: LicenseSource,
+    project_path: &Path,
+    priority: LicensePriority,
+    pypi: CachingPyPiLicenseRepository<PyPiLicenseRepository>,
+) -> ChainedLicenseRepository<
+    LocalMetadataLicenseRepository,
+    CachingPyPiLicenseRepository<PyPiLicenseRepository>,
+> {
+    let local =
+        || LocalMetadataLicenseRepository::new(project_path).with_license_priority(priority);
+    match source {
+        LicenseSource::Local => ChainedLicenseRepository::primary_only(local()),
+        LicenseSource::Pypi => ChainedLicenseRepository::fallback_only(pypi),
+        LicenseSource::Auto => ChainedLicenseRepository::new(local(), pypi),
+    }
+}
+
+/// OSV behind the on-disk result cache, and the local advisory database
+type VulnerabilitySources = CompositeVulnerabilityRepository<
+    CachedVulnerabilityRepository<OsvClient>,
+    PypaAdvisoryDbRepository,
+>;
+
+/// Wraps the OSV client in the result cache under `base_dir`, with the TTL
+/// and refresh behaviour from `--cve-cache-ttl` and `--refresh-cve-cache`
+fn cache_osv_results(
+    osv: OsvClient,
+    args: &Args,
+    base_dir: &Path,
+) -> CachedVulnerabilityRepository<OsvClient> {
+    CachedVulnerabilityRepository::new(osv, cve_cache_dir(base_dir), args.cve_cache_ttl())
+        .with_refresh(args.refresh_cve_cache)
+}
+
+/// Builds the vulnerability repository from OSV and the `--advisory-db` directory.
+///
+/// `osv` is `None` when offline; the advisory database is then the only source.
+/// Returns `None` when the CVE check is off or no source is available.
+fn build_vulnerability_repository(
+    check_cve: bool,
+    osv: Option<CachedVulnerabilityRepository<OsvClient>>,
+    advisory_db: Option<&Path>,
+    cvss_preference: CvssPreference,
+) -> anyhow::Result<Option<VulnerabilitySources>> {
+    if !check_cve {
+        return Ok(None);
+    }
+    let advisory_db = match advisory_db {
+        Some(dir) => {
+            Some(PypaAdvisoryDbRepository::open(dir)?.with_cvss_preference(cvss_preference))
+        }
+        None => None,
+    };
+    Ok(match (osv, advisory_db) {
+        (Some(osv), Some(db)) => Some(CompositeVulnerabilityRepository::new(osv, db)),
+        (Some(osv), None) => Some(CompositeVulnerabilityRepository::primary_only(osv)),
+        (None, Some(db)) => Some(CompositeVulnerabilityRepository::secondary_only(db)),
+        (None, None) => None,
+    })
+}
+
+/// Runs `uv-sbom diff`: compares two SBOMs and writes the report to stdout or `--output`.
+fn run_diff(diff_args: &DiffArgs, locale: Locale) -> anyhow::Result<()> {
+    if diff_args.format == OutputFormat::Html {
+        anyhow::bail!("uv-sbom diff supports --format json or markdown");
+    }
+    let msgs = Messages::for_locale(locale);
+    let base = resolve_diff_source(&diff_args.base)?;
+    let head = resolve_diff_source(&diff_args.head)?;
+
+    let use_case = DiffSbomUseCase::new(
+        detect_lockfile_reader(base.path(), msgs),
+        detect_lockfile_reader(head.path(), msgs),
+        CycloneDxReader::new(),
+    );
+    let diff = use_case.execute(&base, &head)?;
+
+    let formatter = FormatterFactory::create_diff(diff_args.format, locale);
+    let formatted_output = formatter.format_diff(&SbomDiffView::from(&diff))?;
+
+    let presenter_type = match &diff_args.output {
+        Some(output_path) => PresenterType::File(expand_tilde(output_path)),
+        None => PresenterType::Stdout,
+    };
+    match PresenterFactory::create(presenter_type, locale).present(&formatted_output) {
+        Err(e) if is_output_closed(&e) => Ok(()),
+        other => Ok(other?),
+    }
+}
+
+/// Runs `uv-sbom scan`: checks the components of a saved CycloneDX SBOM against OSV
+/// and prints only the vulnerability report.
+///
+/// Exit codes follow `--check-cve`: `VulnerabilitiesDetected` for vulnerabilities
+/// above the threshold, `ApplicationError` when some packages could not be checked.
+async fn run_scan(scan_args: &ScanArgs, locale: Locale) -> anyhow::Result<ExitCode> {
+    if scan_args.format == OutputFormat::Html {
+        anyhow::bail!("uv-sbom scan supports --format json or markdown");
+    }
+    let threshold = match (scan_args.severity_threshold, scan_args.cvss_threshold) {
+        (Some(severity), None) => ThresholdConfig::Severity(severity),
+        (None, Some(cvss)) => ThresholdConfig::Cvss(cvss),
+        // Both None or unreachable (clap group prevents both being set)
+        _ => ThresholdConfig::None,
+    };
+    let ignore_cves: Vec<config::IgnoreCve> = scan_args
+        .ignore_cve
+        .iter()
+        .map(|id| config::IgnoreCve {
+            id: id.clone(),
+            reason: None,
+            state: None,
+        })
+        .collect();
+
+    let use_case = ScanSbomUseCase::new(
+        CycloneDxReader::new(),
+        OsvClient::new()?,
+        StderrProgressReporter::new(locale),
+        locale,
+    );
+    let scan = use_case
+        .execute(&expand_tilde(&scan_args.sbom), threshold, &ignore_cves)
+        .await?;
+    let exit_code = if scan.threshold_exceeded() {
+        ExitCode::VulnerabilitiesDetected
+    } else if scan.is_incomplete() {
+        // Unchecked packages may be vulnerable, so the scan cannot report success
+        ExitCode::ApplicationError
+    } else {
+        ExitCode::Success
+    };
+
+    let read_model = SbomReadModelBuilder::build_with_project(
+        scan.packages,
+        &SbomGenerator::generate_default_metadata(),
+        None,
+        Some(&scan.vulnerability_check_result),
+        None,
+        None,
+        None,
+    )
+    .with_warnings(scan.warnings);
+    let formatted_output = match scan_args.format {
+        OutputFormat::Json => VulnerabilityJsonFormatter::new().format(&read_model)?,
+        _ => MarkdownFormatter::new(locale).format_vulnerability_report(&read_model),
+    };
+
+    let presenter_type = match &scan_args.output {
+        Some(output_path) => PresenterType::File(expand_tilde(output_path)),
+        None => PresenterType::Stdout,
+    };
+    match PresenterFactory::create(presenter_type, locale).present(&formatted_output) {
+        Err(e) if is_output_closed(&e) => {}
+        other => other?,
+    }
+    Ok(exit_code)
+}
+
+/// Runs `uv-sbom verify`: recomputes the SHA-256 digest of an SBOM file and
+/// compares it with the digest file written by `--emit-digest`.
+fn run_verify(verify_args: &VerifyArgs, locale: Locale) -> anyhow::Result<()> {
+    let msgs = Messages::for_locale(locale);
+    let sbom_path = expand_tilde(&verify_args.sbom);
+    let digest_path = match &verify_args.digest {
+        Some(path) => expand_tilde(path),
+        None => digest_path_for(&sbom_path),
+    };
+    let read = |path: &Path| {
+        std::fs::read(path).map_err(|e| UvSbomError::FileReadError {
+            path: path.to_path_buf(),
+            details: e.to_string(),
+        })
+    };
+
+    let actual = sha256_hex(&read(&sbom_path)?);
+    let digest_file = String::from_utf8_lossy(&read(&digest_path)?).into_owned();
+    let Some((expected, _)) = parse_sha256sum(&digest_file) else {
+        anyhow::bail!(
+            "{} is not a sha256sum digest file (expected '<sha256>  <file name>')",
+            digest_path.display()
+        );
+    };
+    if actual != expected {
+        anyhow::bail!(
+            "SHA-256 digest mismatch for {}: expected {}, got {}. The file was modified after it was generated",
+            sbom_path.display(),
+            expected,
+            actual
+        );
+    }
+    eprintln!(
+        "{}",
+        Messages::format(
+            msgs.verify_digest_matches,
+            &[&sbom_path.display().to_string(), &actual]
+        )
+    );
+    Ok(())
+}
+
+/// Path of the digest file written next to `output`: `<output>.sha256`
+fn digest_path_for(output: &Path) -> PathBuf {
+    let mut path = output.as_os_str().to_owned();
+    path.push(".sha256");
+    PathBuf::from(path)
+}
+
+/// Reports the SHA-256 digest of `content` and, for file output, writes it to
+/// `<output>.sha256`. Output to stdout is named `-`, as `sha256sum` does.
+fn write_digest(
+    content: &str,
+    output_path: Option<&Path>,
+    reporter: &impl ProgressReporter,
+    verbosity: Verbosity,
+    locale: Locale,
+) -> Result<()> {
+    let msgs = Messages::for_locale(locale);
+    let digest = sha256_hex(content.as_bytes());
+    let name = output_path
+        .and_then(Path::file_name)
+        .map_or("-".into(), |name| name.to_string_lossy());
+    if let Some(output_path) = output_path {
+        PresenterFactory::create_with_verbosity(
+            PresenterType::File(digest_path_for(output_path)),
+            locale,
+            false,
+            verbosity,
+        )
+        .present(&sha256sum_line(&digest, &name))?;
+    }
+    reporter.report_completion(&Messages::format(
+        msgs.progress_output_digest,
+        &[&name, &digest],
+    ));
+    Ok(())
+}
 
-    Ok(has_issues)
+/// Appends a `<name>.sha256` digest file for each output file and reports the digests
+fn with_digests(
+    files: Vec<(String, String)>,
+    reporter: &impl ProgressReporter,
+    msgs: &Messages,
+) -> Vec<(String, String)> {
+    let digests: Vec<(String, String)> = files
+        .iter()
+        .map(|(name, content)| {
+            let digest = sha256_hex(content.as_bytes());
+            reporter.report_completion(&Messages::format(
+                msgs.progress_output_digest,
+                &[name, &digest],
+            ));
+            (format!("{}.sha256", name), sha256sum_line(&digest, name))
+        })
+        .collect();
+    files.into_iter().chain(digests).collect()
+}
+
+/// Runs `uv-sbom merge`: generates an SBOM for each project and writes one
+/// aggregated SBOM to stdout or `--output`.
+///
+/// Every project is read from its own `uv.lock`. Licenses come from PyPI, or
+/// from each project's local metadata with `--offline`.
+async fn run_merge(merge_args: &MergeArgs, locale: Locale) -> anyhow::Result<()> {
+    let msgs = Messages::for_locale(locale);
+    let project_paths = merge_args
+        .projects
+        .iter()
+        .map(|path| validate_project_path(Path::new(path)))
+        .collect::<Result<Vec<PathBuf>>>()?;
+    eprintln!(
+        "{}\n",
+        Messages::format(
+            msgs.merge_mode_projects,
+            &[&project_paths.len().to_string()]
+        )
+    );
+
+    let check_cve = !merge_args.no_check_cve && !merge_args.offline;
+    let request_counter = RequestCounter::new(None);
+    let license_repository =
+        ChainedLicenseRepository::<LocalMetadataLicenseRepository, _>::fallback_only(
+            CachingPyPiLicenseRepository::new(
+                PyPiLicenseRepository::new()?.with_request_counter(request_counter.clone()),
+            ),
+        );
+    let vulnerability_repository = if check_cve {
+        Some(OsvClient::new()?.with_request_counter(request_counter.clone()))
+    } else {
+        None
+    };
+    let generate_sbom = GenerateSbomUseCase::new(
+        FileSystemReader::new(),
+        FileSystemReader::new(),
+        license_repository,
+        StderrProgressReporter::new(locale),
+        vulnerability_repository,
+        None::<PyPiMaintenanceRepository>,
+        locale,
+    );
+    let use_case = AggregateSbomUseCase::new(generate_sbom, FileSystemReader::new());
+
+    let requests = project_paths
+        .into_iter()
+        .map(|project_path| {
+            SbomRequest::builder()
+                .project_path(project_path)
+                .include_dependency_info(true)
+                .exclude_patterns(merge_args.exclude.clone())
+                .check_cve(check_cve)
+                .offline(merge_args.offline)
+                .locale(locale)
+                .build()
+        })
+        .collect::<Result<Vec<SbomRequest>>>()?;
+    let read_model = use_case.execute(requests).await?;
+    for warning in &read_model.warnings {
+        if matches!(warning, SbomWarning::ConflictingLicense { .. }) {
+            eprintln!("{}", warning.message(msgs));
+        }
+    }
+
+    let formatter = FormatterFactory::create(
+        merge_args.format,
+        None,
+        locale,
+        Default::default(),
+        Default::default(),
+    );
+    let formatted_output = formatter.format(&read_model)?;
+
+    let presenter_type = match &merge_args.output {
+        Some(output_path) => PresenterType::File(expand_tilde(output_path)),
+        None => PresenterType::Stdout,
+    };
+    match PresenterFactory::create(presenter_type, locale).present(&formatted_output) {
+        Err(e) if is_output_closed(&e) => Ok(()),
+        other => Ok(other?),
+    }
+}
+
+/// Reads `SOURCE_DATE_EPOCH` for `--reproducible`; other runs ignore it.
+///
+/// An unset or empty variable means the current time is used.
+fn read_source_date_epoch(reproducible: bool) -> anyhow::Result<Option<i64>> {
+    if !reproducible {
+        return Ok(None);
+    }
+    match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().map(Some).map_err(|_| {
+            anyhow::anyhow!(
+                "SOURCE_DATE_EPOCH must be a number of seconds since the Unix epoch, got '{}'",
+                value
+            )
+        }),
+        _ => Ok(None),
+    }
+}
+
+/// Runs `--validate` on generated CycloneDX output; invalid output is not written.
+fn validate_cyclonedx(output: &str, quiet: bool, msgs: &Messages) -> anyhow::Result<()> {
+    let violations = CycloneDxValidator::new().validate(output);
+    if violations.is_empty() {
+        if !quiet {
+            eprintln!("{}", stderr_text(msgs.progress_cyclonedx_valid));
+        }
+        return Ok(());
+    }
+    let list: Vec<String> = violations.iter().map(|v| format!("  - {}", v)).collect();
+    Err(UvSbomError::CycloneDxValidationFailed {
+        count: violations.len(),
+        violations: list.join("\n"),
+    }
+    .into())
+}
+
+/// Resolves a `--base` / `--head` value: `.json` files are read as CycloneDX,
+/// anything else must be a project directory.
+fn resolve_diff_source(path: &str) -> anyhow::Result<DiffSource> {
+    match DiffSource::from_path(expand_tilde(path)) {
+        DiffSource::Project(_) => Ok(DiffSource::Project(validate_project_path(Path::new(path))?)),
+        sbom_file => Ok(sbom_file),
+    }
+}
+
+/// Creates the project configuration reader, honoring a `--pyproject` override
+fn build_project_config_reader(pyproject_path: Option<&PathBuf>) -> FileSystemReader {
+    match pyproject_path {
+        Some(path) => FileSystemReader::new().with_pyproject_path(path.clone()),
+        None => FileSystemReader::new(),
+    }
+}
+
+/// Creates the reporter for the selected progress format
+fn progress_reporter(
+    format: ProgressFormat,
+    display: ProgressDisplay,
+    locale: Locale,
+    verbosity: Verbosity,
+) -> AnyProgressReporter {
+    match format {
+        ProgressFormat::Text => AnyProgressReporter::Text(
+            StderrProgressReporter::new(locale)
+                .with_verbosity(verbosity)
+                .with_progress_display(display),
+        ),
+        ProgressFormat::Json => AnyProgressReporter::Json(JsonProgressReporter::new()),
+    }
+}
+
+/// Points license lookups at `index_url` with the credentials from the
+/// environment; without an index URL, pypi.org is used and nothing changes.
+fn configure_index(
+    repository: PyPiLicenseRepository,
+    index_url: Option<&str>,
+    fallback_to_pypi: bool,
+) -> Result<PyPiLicenseRepository> {
+    let Some(index_url) = index_url else {
+        return Ok(repository);
+    };
+    Ok(repository
+        .with_index_url(index_url)?
+        .with_credentials(IndexCredentials::from_env())
+        .with_pypi_fallback(fallback_to_pypi))
+}
+
+/// Picks the lockfile reader for a project directory, noting when `uv.lock`
+/// shadows a `pylock.toml`.
+fn detect_lockfile_reader(project_path: &Path, msgs: &Messages) -> AnyLockfileReader {
+    let (reader, both_present) = AnyLockfileReader::detect(project_path);
+    if both_present {
+        eprintln!("{}", msgs.warn_both_lockfiles_found);
+    }
+    reader
 }
 
 /// Runs workspace mode: generates one SBOM per workspace member.
@@ -349,10 +1404,15 @@ async fn run(args: Args) -> Result<bool> {
 /// Reads `[manifest].members` from `workspace_root/uv.lock`, then for each
 /// member runs `GenerateSbomUseCase` scoped to that member and writes the
 /// output to `{member_path}/sbom.{ext}`. Prints a summary table when done.
-async fn run_workspace(args: Args, workspace_root: PathBuf) -> Result<()> {
-    display_banner();
+async fn run_workspace(args: Args, workspace_root: PathBuf) -> anyhow::Result<()> {
+    let verbosity = args.verbosity();
+    let license_fetch_fields = args.license_fetch_fields();
+    if verbosity != Verbosity::Quiet {
+        display_banner();
+    }
 
-    validate_project_path(&workspace_root)?;
+    let workspace_root = validate_project_path(&workspace_root)?;
+    let source_date_epoch = read_source_date_epoch(args.reproducible)?;
 
     let workspace_reader = UvWorkspaceReader::new();
     let members = workspace_reader.read_workspace_members(&workspace_root)?;
@@ -372,14 +1432,19 @@ async fn run_workspace(args: Args, workspace_root: PathBuf) -> Result<()> {
     );
 
     let config = load_config(&args, &workspace_root)?;
+    check_threshold_sources(&args, &config)?;
     let merged = merge_config(&args, &config);
-
-    let format_ext = match merged.format {
-        OutputFormat::Json => "json",
-        OutputFormat::Markdown => "md",
-    };
+    if merged.formats.len() > 1 {
+        anyhow::bail!("Workspace mode writes a single format per member; select one --format");
+    }
 
     let mut summary: Vec<(String, PathBuf)> = Vec::new();
+    let request_counter = RequestCounter::new(args.max_requests);
+    let cache_base_dir =
+        resolve_data_dir(args.data_dir.as_deref().map(expand_tilde).as_deref(), msgs)
+            .unwrap_or_else(std::env::temp_dir);
+    let cancel = cancel_on_ctrl_c();
+    report_user_agent(&merged.network, merged.offline, verbosity, msgs);
 
     for member in &members {
         eprintln!(
@@ -387,21 +1452,58 @@ async fn run_workspace(args: Args, workspace_root: PathBuf) -> Result<()> {
             Messages::format(msgs.workspace_processing_member, &[&member.name])
         );
 
-        let lockfile_reader =
-            MemberScopedLockfileReader::new(workspace_root.clone(), member.name.clone());
+        let lockfile_reader = MemberScopedLockfileReader::new(
+            workspace_root.clone(),
+            member.name.clone(),
+            args.max_lockfile_bytes(),
+        );
         let project_config_reader = FileSystemReader::new();
-        let pypi_repository = PyPiLicenseRepository::new()?;
-        let license_repository = CachingPyPiLicenseRepository::new(pypi_repository);
-        let progress_reporter = StderrProgressReporter::new(locale);
+        let pypi_repository = configure_index(
+            PyPiLicenseRepository::new()?
+                .with_network_config(&merged.network)?
+                .with_request_counter(request_counter.clone())
+                .with_retry_config(merged.retry)
+                .with_fields(license_fetch_fields)
+                .with_license_priority(merged.license_priority),
+            merged.index_url.as_deref(),
+            args.fallback_to_pypi,
+        )?;
+        let license_repository = build_license_repository(
+            args.license_source,
+            &workspace_root,
+            merged.license_priority,
+            CachingPyPiLicenseRepository::new(pypi_repository),
+        );
+        let progress_reporter =
+            progress_reporter(args.progress_format, args.progress, locale, verbosity);
 
-        let vulnerability_repository = if merged.check_cve {
-            Some(OsvClient::new()?)
+        let osv_client = if merged.check_cve && !merged.offline {
+            Some(cache_osv_results(
+                OsvClient::new()?
+                    .with_network_config(&merged.network)?
+                    .with_request_counter(request_counter.clone())
+                    .with_detail_workers(merged.osv_detail_workers)
+                    .with_cvss_preference(args.cvss_preference),
+                &args,
+                &cache_base_dir,
+            ))
         } else {
             None
         };
+        let vulnerability_repository = build_vulnerability_repository(
+            merged.check_cve,
+            osv_client,
+            merged.advisory_db.as_deref(),
+            args.cvss_preference,
+        )?;
 
         let maintenance_repository = if merged.check_abandoned {
-            Some(PyPiMaintenanceRepository::new()?)
+            Some(
+                PyPiMaintenanceRepository::new()?
+                    .with_network_config(&merged.network)?
+                    .with_request_counter(request_counter.clone())
+                    .with_retry_config(merged.retry),
+            )
         } else {
             None
         };
@@ -416,24 +1518,62 @@ async fn run_workspace(args: Args, workspace_root: PathBuf) -> Result<()> {
             locale,
         );
 
-        let include_dependency_info = matches!(merged.format, OutputFormat::Markdown);
+        let include_dependency_info =
+            matches!(merged.format, OutputFormat::Markdown | OutputFormat::Html);
         let request = SbomRequest::builder()
             .project_path(member.absolute_path.clone())
             .include_dependency_info(include_dependency_info)
+            .show_descriptions(include_dependency_info)
             .exclude_patterns(merged.exclude_patterns.clone())
+            .exclude_scope(merged.exclude_scope)
             .check_cve(merged.check_cve)
             .severity_threshold_opt(merged.severity_threshold)
             .cvss_threshold_opt(merged.cvss_threshold)
             .ignore_cves(merged.ignore_cves.clone())
+            .vex(merged.vex)
             .check_license(merged.check_license)
             .license_policy(merged.license_policy.clone())
+            .check_license_compatibility(merged.check_license_compatibility)
+            .license_compatibility_policy(merged.license_compatibility_policy.clone())
+            .project_license_opt(merged.project_license.clone())
+            .fail_on_license_incompatibility(merged.fail_on_license_incompatibility)
+            .license_overrides(merged.license_overrides.clone())
+            .check_license_reachability(merged.check_license_reachability)
+            .strict_license_reachability(merged.strict_license_reachability)
+            .copyleft_licenses(merged.copyleft_licenses.clone())
+            .check_python_compat(merged.check_python_compat)
+            .check_typosquats(merged.check_typosquats)
+            .typosquat_max_distance(merged.typosquat_max_distance)
+            .ignore_typosquats(merged.ignore_typosquats.clone())
+            .fail_on_typosquat(merged.fail_on_typosquat)
             .suggest_fix(false)
+            .suggest_fixes(merged.suggest_fixes)
+            .check_self(merged.check_self)
+            .self_check_fails_build(merged.self_check_fails_build)
             .check_abandoned(merged.check_abandoned)
             .abandoned_threshold_days(merged.abandoned_threshold_days)
+            .offline(merged.offline)
+            .advisory_db_opt(merged.advisory_db.clone())
+            .config_path_opt(config_file_path(&args, &workspace_root))
+            .index_url_opt(merged.index_url.clone())
+            .license_resolution(merged.license_priority.resolution())
+            .license_fetch_fields(license_fetch_fields)
+            .licenses_for(args.licenses_for.unwrap_or_default())
+            .max_network_requests_opt(merged.max_network_requests)
+            // Member-scoped lockfiles drop the packages of other members and groups,
+            // so they cannot be compared with the member's pyproject.toml
+            .check_lock(false)
+            .strict_lock_version(args.strict_lock_version)
+            .stats(args.stats)
+            .reproducible(args.reproducible)
+            .source_date_epoch_opt(source_date_epoch)
+            .timeout_opt(args.timeout)
             .locale(locale)
             .build()?;
 
-        let response = use_case.execute(request).await?;
+        let response = use_case
+            .execute_with_cancel(request, cancel.clone())
+            .await?;
 
         let read_model = SbomReadModelBuilder::build_with_project(
             response.enriched_packages,
@@ -443,13 +1583,67 @@ async fn run_workspace(args: Args, workspace_root: PathBuf) -> Result<()> {
             response.license_compliance_result.as_ref(),
             None,
             response.upgrade_recommendations.as_deref(),
-        );
+        )
+        .with_project_self_check(
+            response
+                .project_self_check_result
+                .as_ref()
+                .map(SbomReadModelBuilder::build_project_self_check),
+        )
+        .with_license_compatibility(
+            response
+                .license_compatibility_result
+                .as_ref()
+                .map(SbomReadModelBuilder::build_license_compatibility),
+        )
+        .with_license_risk(
+            response
+                .license_risk_report
+                .as_ref()
+                .map(SbomReadModelBuilder::build_license_risk),
+        )
+        .with_python_compatibility(response.python_compatibility_report.as_ref())
+        .with_typosquat_findings(response.typosquat_findings.as_deref())
+        .with_fix_suggestions(
+            response
+                .fix_suggestions
+                .as_deref()
+                .map(SbomReadModelBuilder::build_fix_suggestions),
+        )
+        .with_annotations(&response.package_annotations)
+        .with_license_overrides(&response.license_overrides)
+        .with_warnings(response.warnings)
+        .with_stats(response.stats)
+        .with_informational_display(args.informational_display());
+        let read_model = if args.reproducible {
+            read_model.with_stable_order()
+        } else {
+            read_model
+        };
 
-        let formatter = FormatterFactory::create(merged.format, None, locale);
+        let formatter = FormatterFactory::create(
+            merged.format,
+            None,
+            locale,
+            args.markdown_style
+                .with_license_source(args.show_license_source)
+                .with_supplier(args.include_supplier)
+                .with_tree_depth(args.tree_depth)
+                .with_description_max_length(args.description_max_length),
+            merged.spec_version,
+        );
         let formatted_output = formatter.format(&read_model)?;
+        if args.validate && merged.format == OutputFormat::Json {
+            validate_cyclonedx(&formatted_output, verbosity == Verbosity::Quiet, msgs)?;
+        }
 
-        let output_path = member.absolute_path.join(format!("sbom.{}", format_ext));
-        let presenter = PresenterFactory::create(PresenterType::File(output_path.clone()), locale);
+        let output_path = member.absolute_path.join(merged.format.file_name());
+        let presenter = PresenterFactory::create_with_verbosity(
+            PresenterType::File(output_path.clone()),
+            locale,
+            false,
+            verbosity,
+        );
         presenter.present(&formatted_output)?;
 
         summary.push((member.name.clone(), output_path));
@@ -467,6 +1661,12 @@ async fn run_workspace(args: Args, workspace_root: PathBuf) -> Result<()> {
         eprintln!("{:<20} {}", name, path.display());
     }
     eprintln!("{}", "─".repeat(60));
+    report_request_usage(
+        &request_counter,
+        verbosity,
+        &progress_reporter(args.progress_format, args.progress, locale, verbosity),
+        msgs,
+    );
 
     Ok(())
 }
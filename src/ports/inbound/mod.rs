@@ -1,7 +1,10 @@
-// Inbound ports (Driving ports) - Use case interfaces
-//
-// These ports define the interfaces that external adapters (e.g., CLI)
-// use to interact with the application core.
-//
-// Currently, the application uses direct use case invocation instead of
-// inbound ports, which is a valid hexagonal architecture pattern.
+/// Inbound ports (Driving ports) - Use case interfaces
+///
+/// These ports define the interfaces that external adapters (e.g., CLI)
+/// use to interact with the application core. The use cases themselves are
+/// invoked directly; the observer port lets callers follow their progress.
+pub mod sbom_generation_observer;
+
+pub use sbom_generation_observer::{
+    NoopSbomGenerationObserver, SbomGenerationEvent, SbomGenerationObserver, SbomPhase,
+};
@@ -0,0 +1,263 @@
+use std::time::Duration;
+
+/// A step of SBOM generation reported to a [`SbomGenerationObserver`]
+///
+/// Phases run in the order of the variants. Phases that were not requested
+/// (e.g. `CheckVulnerabilities` without `check_cve`) are left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SbomPhase {
+    /// Reading uv.lock and pyproject.toml, and pruning deselected groups and platforms
+    ReadLockfile,
+    /// Applying the exclude patterns
+    FilterPackages,
+    /// Dry run only: checking the configuration (and endpoints with `probe_endpoints`)
+    ValidateConfiguration,
+    /// Telling direct and transitive dependencies apart (`include_dependency_info`)
+    AnalyzeDependencies,
+    /// Fetching license information, or reading it locally when offline
+    EnrichLicenses,
+    /// Checking packages for vulnerabilities (`check_cve`)
+    CheckVulnerabilities,
+    /// Applying thresholds and the license, Python and typosquat checks
+    EvaluatePolicies,
+    /// Checking packages for missing releases (`check_abandoned`)
+    CheckAbandoned,
+    /// Assembling the response
+    BuildResponse,
+}
+
+impl SbomPhase {
+    /// Stable identifier for machine-readable output, e.g. `read_lockfile`
+    pub fn id(self) -> &'static str {
+        match self {
+            SbomPhase::ReadLockfile => "read_lockfile",
+            SbomPhase::FilterPackages => "filter_packages",
+            SbomPhase::ValidateConfiguration => "validate_configuration",
+            SbomPhase::AnalyzeDependencies => "analyze_dependencies",
+            SbomPhase::EnrichLicenses => "enrich_licenses",
+            SbomPhase::CheckVulnerabilities => "check_vulnerabilities",
+            SbomPhase::EvaluatePolicies => "evaluate_policies",
+            SbomPhase::CheckAbandoned => "check_abandoned",
+            SbomPhase::BuildResponse => "build_response",
+        }
+    }
+}
+
+/// An event of SBOM generation
+///
+/// Every phase opens with `PhaseStarted` and closes with either its own
+/// finish event, which carries the phase's counts, or `PhaseFailed`.
+/// Durations are measured from the phase's `PhaseStarted` event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SbomGenerationEvent {
+    /// A phase began
+    PhaseStarted { phase: SbomPhase },
+    /// The lockfile was read; `package_count` is after group and platform pruning
+    LockfileRead {
+        package_count: usize,
+        duration: Duration,
+    },
+    /// The exclude patterns were applied
+    PackagesFiltered {
+        package_count: usize,
+        excluded_count: usize,
+        duration: Duration,
+    },
+    /// The dry-run configuration check finished
+    ConfigurationValidated {
+        issue_count: usize,
+        duration: Duration,
+    },
+    /// The dependency graph was built; both counts are 0 when the analysis
+    /// was skipped because the project package is unknown
+    DependenciesAnalyzed {
+        direct_count: usize,
+        transitive_count: usize,
+        duration: Duration,
+    },
+    /// License information was resolved for the packages
+    LicensesEnriched {
+        package_count: usize,
+        resolved_count: usize,
+        duration: Duration,
+    },
+    /// The vulnerability check finished
+    VulnerabilitiesChecked {
+        package_count: usize,
+        vulnerable_package_count: usize,
+        duration: Duration,
+    },
+    /// The policy checks finished
+    PoliciesEvaluated { duration: Duration },
+    /// The abandoned package check finished
+    AbandonedChecked {
+        package_count: usize,
+        abandoned_count: usize,
+        duration: Duration,
+    },
+    /// The response was assembled; this is the last event of a successful run
+    ResponseBuilt {
+        package_count: usize,
+        warning_count: usize,
+        duration: Duration,
+    },
+    /// A phase ended with an error; this is the last event of a failed run
+    PhaseFailed {
+        phase: SbomPhase,
+        error: String,
+        duration: Duration,
+    },
+}
+
+impl SbomGenerationEvent {
+    /// Returns the phase the event belongs to
+    pub fn phase(&self) -> SbomPhase {
+        match self {
+            Self::PhaseStarted { phase } | Self::PhaseFailed { phase, .. } => *phase,
+            Self::LockfileRead { .. } => SbomPhase::ReadLockfile,
+            Self::PackagesFiltered { .. } => SbomPhase::FilterPackages,
+            Self::ConfigurationValidated { .. } => SbomPhase::ValidateConfiguration,
+            Self::DependenciesAnalyzed { .. } => SbomPhase::AnalyzeDependencies,
+            Self::LicensesEnriched { .. } => SbomPhase::EnrichLicenses,
+            Self::VulnerabilitiesChecked { .. } => SbomPhase::CheckVulnerabilities,
+            Self::PoliciesEvaluated { .. } => SbomPhase::EvaluatePolicies,
+            Self::AbandonedChecked { .. } => SbomPhase::CheckAbandoned,
+            Self::ResponseBuilt { .. } => SbomPhase::BuildResponse,
+        }
+    }
+
+    /// Returns how long the phase took; `None` for `PhaseStarted`
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            Self::PhaseStarted { .. } => None,
+            Self::LockfileRead { duration, .. }
+            | Self::PackagesFiltered { duration, .. }
+            | Self::ConfigurationValidated { duration, .. }
+            | Self::DependenciesAnalyzed { duration, .. }
+            | Self::LicensesEnriched { duration, .. }
+            | Self::VulnerabilitiesChecked { duration, .. }
+            | Self::PoliciesEvaluated { duration }
+            | Self::AbandonedChecked { duration, .. }
+            | Self::ResponseBuilt { duration, .. }
+            | Self::PhaseFailed { duration, .. } => Some(*duration),
+        }
+    }
+
+    /// Returns the counts a finish event carries, as (field name, value) pairs
+    pub fn counts(&self) -> Vec<(&'static str, usize)> {
+        match self {
+            Self::PhaseStarted { .. }
+            | Self::PoliciesEvaluated { .. }
+            | Self::PhaseFailed { .. } => vec![],
+            Self::LockfileRead { package_count, .. } => vec![("package_count", *package_count)],
+            Self::PackagesFiltered {
+                package_count,
+                excluded_count,
+                ..
+            } => vec![
+                ("package_count", *package_count),
+                ("excluded_count", *excluded_count),
+            ],
+            Self::ConfigurationValidated { issue_count, .. } => {
+                vec![("issue_count", *issue_count)]
+            }
+            Self::DependenciesAnalyzed {
+                direct_count,
+                transitive_count,
+                ..
+            } => vec![
+                ("direct_count", *direct_count),
+                ("transitive_count", *transitive_count),
+            ],
+            Self::LicensesEnriched {
+                package_count,
+                resolved_count,
+                ..
+            } => vec![
+                ("package_count", *package_count),
+                ("resolved_count", *resolved_count),
+            ],
+            Self::VulnerabilitiesChecked {
+                package_count,
+                vulnerable_package_count,
+                ..
+            } => vec![
+                ("package_count", *package_count),
+                ("vulnerable_package_count", *vulnerable_package_count),
+            ],
+            Self::AbandonedChecked {
+                package_count,
+                abandoned_count,
+                ..
+            } => vec![
+                ("package_count", *package_count),
+                ("abandoned_count", *abandoned_count),
+            ],
+            Self::ResponseBuilt {
+                package_count,
+                warning_count,
+                ..
+            } => vec![
+                ("package_count", *package_count),
+                ("warning_count", *warning_count),
+            ],
+        }
+    }
+}
+
+/// SbomGenerationObserver port for following SBOM generation phase by phase
+///
+/// Unlike the free-text [`crate::ports::outbound::ProgressReporter`] messages,
+/// observers receive typed events with counts and durations, e.g. to drive the
+/// UI of an application embedding uv-sbom.
+///
+/// # Ordering guarantees
+/// - Events are delivered synchronously, in order, from the task running
+///   `GenerateSbomUseCase::execute`; phases never overlap.
+/// - A run emits the phases in the order of [`SbomPhase`], leaving out the
+///   ones that were not requested. A dry run emits `ReadLockfile`,
+///   `FilterPackages`, `ValidateConfiguration` and `BuildResponse`.
+/// - Each `PhaseStarted` is followed by exactly one finish event of that
+///   phase, or by `PhaseFailed` when the phase returned an error, was
+///   cancelled or timed out.
+/// - A successful run ends with `ResponseBuilt`; a failed run ends with
+///   `PhaseFailed` and emits nothing after it.
+pub trait SbomGenerationObserver: Send + Sync {
+    /// Receives one event
+    fn on_event(&self, event: &SbomGenerationEvent);
+}
+
+/// SbomGenerationObserver that ignores every event
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopSbomGenerationObserver;
+
+impl SbomGenerationObserver for NoopSbomGenerationObserver {
+    fn on_event(&self, _event: &SbomGenerationEvent) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_phase_and_duration() {
+        let started = SbomGenerationEvent::PhaseStarted {
+            phase: SbomPhase::EnrichLicenses,
+        };
+        let finished = SbomGenerationEvent::LicensesEnriched {
+            package_count: 3,
+            resolved_count: 2,
+            duration: Duration::from_millis(40),
+        };
+
+        assert_eq!(started.phase(), SbomPhase::EnrichLicenses);
+        assert_eq!(started.duration(), None);
+        assert_eq!(finished.phase(), SbomPhase::EnrichLicenses);
+        assert_eq!(finished.duration(), Some(Duration::from_millis(40)));
+        assert!(started.counts().is_empty());
+        assert_eq!(
+            finished.counts(),
+            vec![("package_count", 3), ("resolved_count", 2)]
+        );
+    }
+}
@@ -0,0 +1,11 @@
+/// Outcome of a connectivity probe to one network endpoint
+///
+/// Any HTTP response counts as reachable; only transport failures such as DNS
+/// errors, refused connections, TLS errors and timeouts are reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointProbe {
+    /// URL that was probed
+    pub endpoint: String,
+    /// Why the endpoint could not be reached; `None` when it answered
+    pub error: Option<String>,
+}
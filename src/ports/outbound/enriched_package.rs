@@ -1,4 +1,6 @@
-use crate::sbom_generation::domain::Package;
+use crate::sbom_generation::domain::{
+    ExternalRefType, LicenseSource, Package, PackageAuthorship, PackageLinks,
+};
 
 /// EnrichedPackage represents a package with its license information
 ///
@@ -10,15 +12,53 @@ pub struct EnrichedPackage {
     pub license: Option<String>,
     pub description: Option<String>,
     pub sha256_hash: Option<String>,
+    /// Where `license` came from
+    pub license_source: LicenseSource,
+    /// Yank reason (empty if none was given) when the release was yanked from PyPI
+    pub yanked: Option<String>,
+    /// Author named in the package metadata
+    pub author: Option<String>,
+    /// Supplier named in the package metadata: the author, else the maintainer
+    pub supplier: Option<String>,
+    /// Homepage, repository and documentation links from the package metadata
+    pub external_refs: Vec<(ExternalRefType, String)>,
+    /// Outcome of the metadata lookup, telling a failed fetch apart from
+    /// metadata without a license
+    pub enrichment_status: EnrichmentStatus,
+}
+
+/// Outcome of the metadata lookup for an [`EnrichedPackage`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnrichmentStatus {
+    /// The metadata was read and a license was found
+    Enriched,
+    /// The metadata was read (or the package is not on the index) but has no license
+    NoMetadata,
+    /// The metadata could not be fetched; holds the error message
+    FetchFailed(String),
 }
 
 impl EnrichedPackage {
+    /// Creates an enriched package. The SHA-256 hash is taken from the package
+    /// (i.e. the lockfile) and can be overridden with [`Self::with_sha256_hash`].
+    /// The enrichment status follows from whether a license is given.
     pub fn new(package: Package, license: Option<String>, description: Option<String>) -> Self {
+        let sha256_hash = package.sha256_hash().map(String::from);
+        let enrichment_status = match license {
+            Some(_) => EnrichmentStatus::Enriched,
+            None => EnrichmentStatus::NoMetadata,
+        };
         Self {
             package,
             license,
             description,
-            sha256_hash: None,
+            sha256_hash,
+            license_source: LicenseSource::Unknown,
+            yanked: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
+            enrichment_status,
         }
     }
 
@@ -26,4 +66,40 @@ impl EnrichedPackage {
         self.sha256_hash = sha256_hash;
         self
     }
+
+    pub fn with_license_source(mut self, license_source: LicenseSource) -> Self {
+        self.license_source = license_source;
+        self
+    }
+
+    pub fn with_yanked(mut self, yanked: Option<String>) -> Self {
+        self.yanked = yanked;
+        self
+    }
+
+    /// Takes the author and supplier from the package metadata
+    pub fn with_authorship(mut self, authorship: &PackageAuthorship) -> Self {
+        self.author = authorship.author().map(String::from);
+        self.supplier = authorship.supplier().map(String::from);
+        self
+    }
+
+    /// Takes the homepage, repository and documentation links from the package metadata
+    pub fn with_links(mut self, links: &PackageLinks) -> Self {
+        self.external_refs = links.refs().to_vec();
+        self
+    }
+
+    pub fn with_enrichment_status(mut self, enrichment_status: EnrichmentStatus) -> Self {
+        self.enrichment_status = enrichment_status;
+        self
+    }
+
+    /// Returns the error message when the metadata lookup failed
+    pub fn fetch_error(&self) -> Option<&str> {
+        match &self.enrichment_status {
+            EnrichmentStatus::FetchFailed(error) => Some(error),
+            _ => None,
+        }
+    }
 }
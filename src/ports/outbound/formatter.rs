@@ -1,4 +1,4 @@
-use crate::application::read_models::SbomReadModel;
+use crate::application::read_models::{SbomDiffView, SbomReadModel};
 use crate::shared::Result;
 
 /// SbomFormatter port for formatting SBOM output
@@ -19,3 +19,12 @@ pub trait SbomFormatter {
     /// Returns an error if formatting or serialization fails
     fn format(&self, model: &SbomReadModel) -> Result<String>;
 }
+
+/// SbomDiffFormatter port for rendering the difference between two SBOMs
+pub trait SbomDiffFormatter {
+    /// Formats the added, removed and updated packages of an SBOM comparison
+    ///
+    /// # Errors
+    /// Returns an error if formatting or serialization fails
+    fn format_diff(&self, diff: &SbomDiffView) -> Result<String>;
+}
@@ -1,15 +1,42 @@
-use crate::sbom_generation::domain::LicenseInfo;
+use crate::ports::outbound::EndpointProbe;
+use crate::sbom_generation::domain::{LicenseInfo, PackageAuthorship, PackageLinks};
+use crate::sbom_generation::policies::LicensePriority;
 use crate::shared::Result;
 use async_trait::async_trait;
 
-/// Type alias for PyPI metadata: (license, license_expression, classifiers, description, sha256_hash)
-pub type PyPiMetadata = (
-    Option<String>,
-    Option<String>,
-    Vec<String>,
-    Option<String>,
-    Option<String>,
-);
+/// Package metadata returned by a license lookup
+///
+/// Fields a source does not provide are left at their defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PyPiMetadata {
+    /// `License` field of the package metadata
+    pub license: Option<String>,
+    /// `License-Expression` field of the package metadata (PEP 639)
+    pub license_expression: Option<String>,
+    /// Trove classifiers of the package
+    pub classifiers: Vec<String>,
+    /// Package description/summary
+    pub description: Option<String>,
+    /// SHA-256 hash of a release file
+    pub sha256_hash: Option<String>,
+    /// `Some` with the yank reason (empty if none was given) when the release has
+    /// been yanked from the index; `None` otherwise or when the source cannot tell
+    pub yanked: Option<String>,
+    /// Author and maintainer
+    pub authorship: PackageAuthorship,
+    /// Homepage, repository and documentation links
+    pub links: PackageLinks,
+}
+
+/// Package metadata fields a license lookup needs to resolve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LicenseFetchFields {
+    /// License fields and the package description
+    #[default]
+    Full,
+    /// License fields only; descriptions are not read and stay `None`
+    LicenseOnly,
+}
 
 /// LicenseRepository port for fetching license information
 ///
@@ -28,11 +55,8 @@ pub trait LicenseRepository: Send + Sync {
     /// * `version` - Version of the package
     ///
     /// # Returns
-    /// PyPiMetadata tuple containing:
-    /// - Optional license field from package metadata
-    /// - Optional license_expression field from package metadata
-    /// - List of classifiers from package metadata
-    /// - Optional package description/summary
+    /// The package's [`PyPiMetadata`]; fields the source does not provide are
+    /// left empty
     ///
     /// # Errors
     /// Returns an error if:
@@ -41,10 +65,18 @@ pub trait LicenseRepository: Send + Sync {
     /// - The response cannot be parsed
     async fn fetch_license_info(&self, package_name: &str, version: &str) -> Result<PyPiMetadata>;
 
+    /// Returns the policy that picks the license among the fetched fields
+    ///
+    /// # Default Implementation
+    /// Returns the default [`LicensePriority`].
+    fn license_priority(&self) -> LicensePriority {
+        LicensePriority::default()
+    }
+
     /// Enriches a package with license information from the repository
     ///
     /// This is a convenience method that fetches raw data and converts
-    /// it to a LicenseInfo domain object using license priority rules.
+    /// it to a LicenseInfo domain object using [`Self::license_priority`].
     ///
     /// # Arguments
     /// * `package_name` - Name of the package
@@ -53,16 +85,29 @@ pub trait LicenseRepository: Send + Sync {
     /// # Returns
     /// A LicenseInfo object with the selected license and description
     async fn enrich_with_license(&self, package_name: &str, version: &str) -> Result<LicenseInfo> {
-        let (license, license_expression, classifiers, description, sha256_hash) =
-            self.fetch_license_info(package_name, version).await?;
+        let metadata = self.fetch_license_info(package_name, version).await?;
+
+        Ok(self
+            .license_priority()
+            .license_info(
+                metadata.license,
+                metadata.license_expression,
+                &metadata.classifiers,
+                metadata.description,
+            )
+            .with_sha256_hash(metadata.sha256_hash)
+            .with_yanked(metadata.yanked)
+            .with_authorship(metadata.authorship)
+            .with_links(metadata.links))
+    }
 
-        use crate::sbom_generation::policies::LicensePriority;
-        Ok(LicensePriority::create_license_info(
-            license,
-            license_expression,
-            &classifiers,
-            description,
-        )
-        .with_sha256_hash(sha256_hash))
+    /// Checks that the endpoints this repository queries can be reached
+    ///
+    /// Used by `--dry-run=full`. Sends at most one small request per endpoint.
+    ///
+    /// # Default Implementation
+    /// Returns no probes, for repositories that need no network access.
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        Vec::new()
     }
 }
@@ -1,4 +1,5 @@
 use crate::sbom_generation::domain::Package;
+use crate::shared::digest::sha256_hex;
 use crate::shared::Result;
 use std::collections::HashMap;
 use std::path::Path;
@@ -6,8 +7,80 @@ use std::path::Path;
 /// Type alias for dependency map: package name -> list of dependency names
 pub type DependencyMap = HashMap<String, Vec<String>>;
 
-/// Type alias for lockfile parsing result: (packages, dependency map)
-pub type LockfileParseResult = (Vec<Package>, DependencyMap);
+/// Type alias for dependency group edges: group name -> the edges that group adds
+/// on top of the runtime dependencies in the `DependencyMap`
+pub type DependencyGroups = HashMap<String, DependencyMap>;
+
+/// Type alias for the extras requested on dependency edges: parent name -> dependency
+/// name -> extras, e.g. `requests[socks]` records `["socks"]` under the edge to `requests`.
+/// Edges without extras are absent.
+pub type EdgeExtras = HashMap<String, HashMap<String, Vec<String>>>;
+
+/// Type alias for the environment markers of conditional dependency edges: parent name ->
+/// dependency name -> PEP 508 marker, e.g. `sys_platform == 'win32'` for an edge only
+/// followed on Windows. Unconditional edges are absent.
+pub type EdgeMarkers = HashMap<String, HashMap<String, String>>;
+
+/// Format version of a lockfile, with the newest one its reader was written for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockfileVersion {
+    /// Version as written in the lockfile, e.g. `1` (uv.lock) or `1.0` (pylock.toml)
+    pub version: String,
+    /// Newest major version the reader understands
+    pub supported_major: u32,
+}
+
+impl LockfileVersion {
+    pub fn new(version: impl Into<String>, supported_major: u32) -> Self {
+        Self {
+            version: version.into(),
+            supported_major,
+        }
+    }
+
+    /// Returns the major version, the part before the first `.`
+    pub fn major(&self) -> Option<u32> {
+        self.version.split('.').next()?.trim().parse().ok()
+    }
+
+    /// Returns true when the lockfile was written in a format newer than the
+    /// reader knows, so structure it added may have been ignored
+    pub fn is_newer_than_supported(&self) -> bool {
+        self.major()
+            .is_some_and(|major| major > self.supported_major)
+    }
+}
+
+/// A lockfile line the reader ignored, such as an editable requirement in a
+/// requirements.txt file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedLockfileLine {
+    /// 1-based line number
+    pub line: usize,
+    /// The line as written, without comments
+    pub content: String,
+}
+
+/// Everything read from a lockfile
+#[derive(Debug, Clone, Default)]
+pub struct LockfileParseResult {
+    pub packages: Vec<Package>,
+    /// Runtime dependencies of each package
+    pub dependency_map: DependencyMap,
+    /// Edges contributed by dependency groups (`dev` and other `[dependency-groups]`)
+    /// and by the project's own extras, keyed by group name
+    pub dependency_groups: DependencyGroups,
+    /// Extras requested on dependency edges of either kind
+    pub edge_extras: EdgeExtras,
+    /// Environment markers of conditional dependency edges of either kind
+    pub edge_markers: EdgeMarkers,
+    /// Format version from the lockfile header; `None` when it records none
+    pub version: Option<LockfileVersion>,
+    /// Python versions the whole resolution supports, from the lockfile header
+    pub requires_python: Option<String>,
+    /// Lines that were ignored; only formats with free-form lines report any
+    pub skipped_lines: Vec<SkippedLockfileLine>,
+}
 
 /// LockfileReader port for reading and parsing lockfile contents
 ///
@@ -28,15 +101,28 @@ pub trait LockfileReader {
     /// - The file cannot be read due to permissions or I/O errors
     fn read_lockfile(&self, project_path: &Path) -> Result<String>;
 
+    /// Computes the SHA-256 digest of the raw lockfile content
+    ///
+    /// Identifies the exact lockfile state an SBOM was generated from.
+    ///
+    /// # Returns
+    /// The lowercase hex digest of the lockfile bytes
+    ///
+    /// # Errors
+    /// Returns an error if the lockfile cannot be read
+    fn lockfile_sha256(&self, project_path: &Path) -> Result<String> {
+        Ok(sha256_hex(self.read_lockfile(project_path)?.as_bytes()))
+    }
+
     /// Reads and parses the uv.lock file from the specified project directory
     ///
     /// # Arguments
     /// * `project_path` - Path to the project directory containing uv.lock
     ///
     /// # Returns
-    /// A tuple of (packages, dependency_map) where:
-    /// - packages: Vector of Package domain objects
-    /// - dependency_map: Map of package name to its dependencies
+    /// The packages, their dependency edges and the lockfile's format version.
+    /// A lockfile newer than the reader supports is still parsed as far as
+    /// possible; the caller decides whether to accept it.
     ///
     /// # Errors
     /// Returns an error if:
@@ -45,6 +131,12 @@ pub trait LockfileReader {
     /// - The lockfile has invalid structure
     fn read_and_parse_lockfile(&self, project_path: &Path) -> Result<LockfileParseResult>;
 
+    /// Returns false for formats that record no dependency edges, such as a
+    /// requirements.txt; their dependency map is always empty
+    fn has_dependency_graph(&self) -> bool {
+        true
+    }
+
     /// Parse the lockfile and return only packages reachable from the given member.
     ///
     /// Performs a BFS traversal starting from the `[[package]]` entry whose
@@ -57,8 +149,9 @@ pub trait LockfileReader {
     /// * `member_name` - The workspace member name to scope the result to
     ///
     /// # Returns
-    /// A tuple of (packages, dependency_map) containing only packages reachable
-    /// from the specified member (excluding the member itself).
+    /// A result containing only packages reachable from the specified member
+    /// (excluding the member itself). Group edges are folded into
+    /// `dependency_map`, so `dependency_groups` is empty.
     ///
     /// # Errors
     /// Returns an error if:
@@ -71,3 +164,18 @@ pub trait LockfileReader {
         member_name: &str,
     ) -> Result<LockfileParseResult>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockfile_version_newer_than_supported() {
+        assert!(!LockfileVersion::new("1", 1).is_newer_than_supported());
+        assert!(!LockfileVersion::new("1.0", 1).is_newer_than_supported());
+        assert!(LockfileVersion::new("2", 1).is_newer_than_supported());
+        assert!(LockfileVersion::new("2.0", 1).is_newer_than_supported());
+        assert_eq!(LockfileVersion::new("x", 1).major(), None);
+        assert!(!LockfileVersion::new("x", 1).is_newer_than_supported());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::ports::outbound::EndpointProbe;
 use crate::shared::Result;
 use async_trait::async_trait;
 use chrono::NaiveDate;
@@ -74,6 +75,16 @@ pub trait MaintenanceRepository: Send + Sync {
     /// - API response is invalid
     /// - Timeout occurs
     async fn fetch_maintenance_info(&self, package_name: &str) -> Result<MaintenanceInfo>;
+
+    /// Checks that the endpoints this repository queries can be reached
+    ///
+    /// Used by `--dry-run=full`. Sends at most one small request per endpoint.
+    ///
+    /// # Default Implementation
+    /// Returns no probes, for repositories that need no network access.
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        Vec::new()
+    }
 }
 
 /// Dummy implementation of MaintenanceRepository for the unit type.
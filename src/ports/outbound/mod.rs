@@ -2,6 +2,7 @@
 ///
 /// These ports define the interfaces that the application core uses
 /// to interact with external systems (file system, network, console, etc.).
+pub mod endpoint_probe;
 pub mod enriched_package;
 pub mod formatter;
 pub mod license_repository;
@@ -10,26 +11,36 @@ pub mod maintenance_repository;
 pub mod output_presenter;
 pub mod progress_reporter;
 pub mod project_config_reader;
+pub mod sbom_reader;
 pub mod uv_lock_simulator;
 pub mod vulnerability_repository;
 pub mod workspace_reader;
 
-pub use enriched_package::EnrichedPackage;
-pub use formatter::SbomFormatter;
-pub use license_repository::{LicenseRepository, PyPiMetadata};
-pub use lockfile_reader::{LockfileParseResult, LockfileReader};
+pub use endpoint_probe::EndpointProbe;
+pub use enriched_package::{EnrichedPackage, EnrichmentStatus};
+pub use formatter::{SbomDiffFormatter, SbomFormatter};
+pub use license_repository::{LicenseFetchFields, LicenseRepository, PyPiMetadata};
+pub use lockfile_reader::{
+    DependencyGroups, EdgeExtras, EdgeMarkers, LockfileParseResult, LockfileReader,
+    LockfileVersion, SkippedLockfileLine,
+};
 // Note: Will be used in subsequent subtasks (abandoned package detection)
 #[allow(unused_imports)]
 pub use maintenance_repository::{MaintenanceInfo, MaintenanceRepository};
 pub use output_presenter::OutputPresenter;
-pub use progress_reporter::{ProgressCallback, ProgressReporter};
+pub use progress_reporter::{
+    NoopTaskHandle, ProgressCallback, ProgressPhase, ProgressReporter, TaskHandle, Verbosity,
+};
 pub use project_config_reader::ProjectConfigReader;
+pub use sbom_reader::SbomReader;
 // Note: This will be used in a subsequent subtask for uv lock simulation
 #[allow(unused_imports)]
 pub use uv_lock_simulator::{SimulationResult, UvLockSimulator};
 // Note: This will be used in subsequent subtasks (Subtask 3-8)
 #[allow(unused_imports)]
-pub use vulnerability_repository::VulnerabilityRepository;
+pub use vulnerability_repository::{
+    IncompleteAdvisory, UncheckedPackage, VulnerabilityFetchOutcome, VulnerabilityRepository,
+};
 // Note: Will be used in a subsequent subtask for workspace detection
 #[allow(unused_imports)]
 pub use workspace_reader::{WorkspaceMember, WorkspaceReader};
@@ -1,3 +1,4 @@
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 
 /// OutputPresenter port for presenting final output
@@ -19,4 +20,17 @@ pub trait OutputPresenter {
     /// - File permissions prevent writing
     /// - Disk space is insufficient
     fn present(&self, content: &str) -> Result<()>;
+
+    /// Presents several named output files at once
+    ///
+    /// # Arguments
+    /// * `files` - `(file name, content)` pairs; names are relative to the destination
+    ///
+    /// # Default Implementation
+    /// Returns an error, since destinations such as stdout cannot hold multiple files.
+    fn present_many(&self, _files: &[(String, String)]) -> Result<()> {
+        Err(UvSbomError::other(
+            "This output destination does not support multi-file output",
+        ))
+    }
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 /// Progress callback for operations that need to report progress
 ///
 /// This is a generic callback type that can be used by any operation
@@ -20,6 +22,73 @@
 /// The callback must be `Send` to support async operations.
 pub type ProgressCallback<'a> = Box<dyn Fn(usize, usize) + Send + 'a>;
 
+/// How much progress output a reporter emits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Verbosity {
+    /// Errors and warnings only: no step messages, no progress bars
+    Quiet,
+    /// Step messages and progress bars
+    #[default]
+    Normal,
+    /// Everything in `Normal`, plus per-package details
+    Verbose,
+}
+
+/// A long-running phase of SBOM generation that reports per-item progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressPhase {
+    /// Fetching license information, counted in packages
+    LicenseEnrichment,
+    /// Fetching vulnerability records from OSV; the total is known only
+    /// after the batch query returns
+    VulnerabilityCheck,
+    /// Fetching maintenance information for the abandoned package check
+    AbandonedCheck,
+}
+
+impl ProgressPhase {
+    /// Stable identifier for machine-readable output, e.g. `license_fetch`
+    pub fn id(self) -> &'static str {
+        match self {
+            ProgressPhase::LicenseEnrichment => "license_fetch",
+            ProgressPhase::VulnerabilityCheck => "vulnerability_fetch",
+            ProgressPhase::AbandonedCheck => "maintenance_fetch",
+        }
+    }
+
+    /// Label shown next to the progress bar
+    pub fn label(self) -> &'static str {
+        // i18n-ok: internal progress bar labels
+        match self {
+            ProgressPhase::LicenseEnrichment => "Fetching license information...",
+            ProgressPhase::VulnerabilityCheck => "Fetching vulnerability details...",
+            ProgressPhase::AbandonedCheck => "Fetching maintenance information...",
+        }
+    }
+}
+
+/// Progress display of one long-running task, returned by
+/// [`ProgressReporter::start_task`]
+///
+/// Handles are shared with progress callbacks, so updates may arrive from any thread.
+pub trait TaskHandle: Send + Sync {
+    /// Records that `current` of `total` items are done; `total` is 0 while
+    /// the amount of work is still unknown
+    fn update(&self, current: usize, total: usize);
+
+    /// Ends the task and removes its progress display
+    fn finish(&self);
+}
+
+/// TaskHandle that displays nothing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopTaskHandle;
+
+impl TaskHandle for NoopTaskHandle {
+    fn update(&self, _current: usize, _total: usize) {}
+    fn finish(&self) {}
+}
+
 /// ProgressReporter port for reporting progress during operations
 ///
 /// This port abstracts progress reporting (e.g., to stderr)
@@ -42,4 +111,37 @@ pub trait ProgressReporter {
     /// # Arguments
     /// * `message` - Completion message
     fn report_completion(&self, message: &str);
+
+    /// Reports a per-item detail (e.g. one package's fetch result), meant for
+    /// verbose output. Ignored unless the implementation overrides it.
+    ///
+    /// # Arguments
+    /// * `message` - The detail message
+    fn report_detail(&self, _message: &str) {}
+
+    /// Returns whether progress bars should be drawn
+    fn shows_progress(&self) -> bool {
+        true
+    }
+
+    /// Starts progress display for a phase over `total` items (0 if not yet known)
+    ///
+    /// The caller reports progress through the returned handle and calls
+    /// [`TaskHandle::finish`] when the phase is done. Displays nothing unless the
+    /// implementation overrides it.
+    ///
+    /// # Arguments
+    /// * `phase` - The phase being started
+    /// * `total` - Number of items to process
+    fn start_task(&self, _phase: ProgressPhase, _total: usize) -> Arc<dyn TaskHandle> {
+        Arc::new(NoopTaskHandle)
+    }
+
+    /// Reports that SBOM generation finished successfully. Ignored unless the
+    /// implementation overrides it.
+    ///
+    /// # Arguments
+    /// * `packages` - Number of packages in the SBOM
+    /// * `warnings` - Number of warnings raised during the run
+    fn report_done(&self, _packages: usize, _warnings: usize) {}
 }
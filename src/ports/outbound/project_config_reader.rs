@@ -1,4 +1,6 @@
+use crate::sbom_generation::domain::PackageAnnotation;
 use crate::shared::Result;
+use std::collections::HashMap;
 use std::path::Path;
 
 /// ProjectConfigReader port for reading project configuration
@@ -16,8 +18,101 @@ pub trait ProjectConfigReader {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The configuration file (pyproject.toml) does not exist
+    /// - The configuration file (pyproject.toml) does not exist, reported as
+    ///   [`crate::shared::error::UvSbomError::ProjectConfigNotFound`] so that
+    ///   callers can fall back to the lockfile
     /// - The file cannot be parsed
     /// - The project name field is missing
     fn read_project_name(&self, project_path: &Path) -> Result<String>;
+
+    /// Reads the static project version from the project configuration
+    ///
+    /// # Returns
+    /// `Some(version)` when `[project].version` is set, `None` when the version
+    /// is absent or declared dynamic
+    ///
+    /// # Default Implementation
+    /// Returns `Ok(None)`; callers fall back to the version recorded in the lockfile.
+    fn read_project_version(&self, _project_path: &Path) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Reads the project's own license from the project configuration
+    ///
+    /// # Returns
+    /// The `[project].license` expression (or `license.text`), falling back to the
+    /// first `License ::` trove classifier. `None` when no license is declared.
+    ///
+    /// # Default Implementation
+    /// Returns `Ok(None)`.
+    fn read_project_license(&self, _project_path: &Path) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Reads the Python versions the project supports from the project configuration
+    ///
+    /// # Returns
+    /// The `[project].requires-python` specifier set (e.g. `>=3.9`). `None`
+    /// when there is no pyproject.toml or the field is absent.
+    ///
+    /// # Default Implementation
+    /// Returns `Ok(None)`; the Python compatibility check is skipped.
+    fn read_requires_python(&self, _project_path: &Path) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    /// Reads the requirements the project declares in its project configuration
+    ///
+    /// # Returns
+    /// `Some` with the PEP 508 requirement strings of `[project].dependencies`,
+    /// every `[project.optional-dependencies]` extra, every `[dependency-groups]`
+    /// group and `[tool.uv].dev-dependencies`. `None` when there is no
+    /// pyproject.toml, it has no `[project]` table or its dependencies are dynamic.
+    ///
+    /// # Default Implementation
+    /// Returns `Ok(None)`; the lockfile consistency check is skipped and no
+    /// declared constraints are recorded.
+    fn read_declared_requirements(&self, _project_path: &Path) -> Result<Option<Vec<String>>> {
+        Ok(None)
+    }
+
+    /// Reads per-package annotations from the project configuration
+    ///
+    /// # Returns
+    /// One entry per package listed under `[tool.uv-sbom.annotations]`.
+    /// Empty when the table (or the file) is absent.
+    ///
+    /// # Errors
+    /// Returns an error if the table is malformed or a field value is not a string
+    ///
+    /// # Default Implementation
+    /// Returns `Ok(vec![])`.
+    fn read_package_annotations(&self, _project_path: &Path) -> Result<Vec<PackageAnnotation>> {
+        Ok(vec![])
+    }
+
+    /// Reads the names of the uv workspace members rooted at the project directory
+    ///
+    /// # Returns
+    /// The member package names from `[manifest].members` in `uv.lock`.
+    /// Empty when the project is not a workspace root.
+    ///
+    /// # Default Implementation
+    /// Returns `Ok(vec![])`; the project is analyzed with a single root.
+    fn read_workspace_member_names(&self, _project_path: &Path) -> Result<Vec<String>> {
+        Ok(vec![])
+    }
+
+    /// Reads licenses of packages that are available locally, without network access
+    ///
+    /// # Returns
+    /// A map from PEP 503 normalized package name to license, for packages in
+    /// `uv.lock` with a local source (editable, virtual or directory) whose
+    /// `pyproject.toml` declares a license.
+    ///
+    /// # Default Implementation
+    /// Returns an empty map.
+    fn read_local_package_licenses(&self, _project_path: &Path) -> Result<HashMap<String, String>> {
+        Ok(HashMap::new())
+    }
 }
@@ -0,0 +1,26 @@
+use crate::sbom_generation::domain::PackageEntry;
+use crate::shared::Result;
+use std::path::Path;
+
+/// SbomReader port for reading packages back from a previously generated SBOM
+///
+/// This port abstracts parsing an SBOM document (e.g. CycloneDX JSON) so that
+/// saved SBOMs can be compared with each other or with a project's lockfile,
+/// or re-scanned for vulnerabilities.
+pub trait SbomReader {
+    /// Reads the components listed in the SBOM file at `path`
+    ///
+    /// # Returns
+    /// One entry per component, with the license when the SBOM records one
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid SBOM
+    fn read_packages(&self, path: &Path) -> Result<Vec<PackageEntry>>;
+
+    /// Reads the dependency components of the SBOM file at `path`, leaving out
+    /// the project the SBOM describes
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid SBOM
+    fn read_components(&self, path: &Path) -> Result<Vec<PackageEntry>>;
+}
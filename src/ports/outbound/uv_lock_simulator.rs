@@ -1,4 +1,4 @@
-use anyhow::Result;
+use crate::shared::Result;
 use std::collections::HashMap;
 
 /// Represents the result of a `uv lock --upgrade-package` simulation
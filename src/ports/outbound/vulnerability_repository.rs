@@ -1,9 +1,46 @@
-use crate::ports::outbound::ProgressCallback;
+use crate::ports::outbound::{EndpointProbe, ProgressCallback};
 use crate::sbom_generation::domain::vulnerability::PackageVulnerabilities;
 use crate::sbom_generation::domain::Package;
 use crate::shared::Result;
 use async_trait::async_trait;
 
+/// A package whose vulnerability check failed or is incomplete
+///
+/// When only some result pages of a package could be fetched, the advisories
+/// found on them are still in `VulnerabilityFetchOutcome::results`.
+#[derive(Debug, Clone)]
+pub struct UncheckedPackage {
+    pub package: Package,
+    /// Why the check failed, e.g. the error of the batch request that covered it
+    pub error: String,
+}
+
+/// An advisory whose details could not be fetched
+///
+/// It is still reported for the affected package, with only its ID.
+#[derive(Debug, Clone)]
+pub struct IncompleteAdvisory {
+    pub id: String,
+    /// Why the details could not be fetched
+    pub error: String,
+}
+
+/// Result of a vulnerability fetch that may have checked only some packages
+///
+/// A failed batch request does not discard the batches that succeeded; the
+/// packages it covered are listed in `failed_packages` instead.
+#[derive(Debug, Clone, Default)]
+pub struct VulnerabilityFetchOutcome {
+    /// Checked packages that have known vulnerabilities
+    pub results: Vec<PackageVulnerabilities>,
+    /// Packages that could not be checked
+    pub failed_packages: Vec<UncheckedPackage>,
+    /// Advisories in `results` whose details could not be fetched
+    pub incomplete_advisories: Vec<IncompleteAdvisory>,
+    /// Checked packages whose results were served from a cache instead of queried
+    pub cached_packages: usize,
+}
+
 /// Port for fetching vulnerability information from external sources
 ///
 /// This trait defines the interface for querying vulnerability databases
@@ -17,7 +54,8 @@ use async_trait::async_trait;
 /// # Implementation Notes
 /// - Use batch API calls when possible for efficiency (OSV API provides batch endpoint)
 /// - Filter results to only include packages with vulnerabilities
-/// - Empty result indicates no vulnerabilities found (not an error condition)
+/// - Empty result indicates no vulnerabilities found (not an error condition),
+///   unless packages are listed in `failed_packages`
 /// - All methods are async for efficient parallel vulnerability fetching
 ///
 /// # Example
@@ -30,9 +68,9 @@ use async_trait::async_trait;
 /// # impl VulnerabilityRepository for MockRepo {
 /// #     async fn fetch_vulnerabilities(
 /// #         &self,
-/// #         packages: Vec<Package>,
-/// #     ) -> uv_sbom::shared::Result<Vec<uv_sbom::sbom_generation::domain::vulnerability::PackageVulnerabilities>> {
-/// #         Ok(vec![])
+/// #         packages: &[Package],
+/// #     ) -> uv_sbom::shared::Result<uv_sbom::ports::outbound::VulnerabilityFetchOutcome> {
+/// #         Ok(Default::default())
 /// #     }
 /// # }
 /// # async fn example() -> uv_sbom::shared::Result<()> {
@@ -42,8 +80,9 @@ use async_trait::async_trait;
 ///     Package::new("urllib3".to_string(), "1.26.0".to_string())?,
 /// ];
 ///
-/// let vulnerabilities = repo.fetch_vulnerabilities(packages).await?;
-/// // vulnerabilities contains only packages with known vulnerabilities
+/// let outcome = repo.fetch_vulnerabilities(&packages).await?;
+/// // outcome.results contains only packages with known vulnerabilities
+/// // outcome.failed_packages lists packages whose check failed
 /// # Ok(())
 /// # }
 /// ```
@@ -55,19 +94,18 @@ pub trait VulnerabilityRepository: Send + Sync {
     /// * `packages` - List of Package objects to check for vulnerabilities
     ///
     /// # Returns
-    /// Vector of PackageVulnerabilities for packages that have vulnerabilities.
-    /// Packages without vulnerabilities are not included in the result.
+    /// `VulnerabilityFetchOutcome` whose `results` hold the packages that have
+    /// vulnerabilities; packages without vulnerabilities are not included.
+    /// Packages whose request failed (network error, invalid response, timeout)
+    /// are listed in `failed_packages` rather than failing the whole fetch.
     ///
     /// # Errors
-    /// Returns error if:
-    /// - Network request fails
-    /// - API response is invalid
-    /// - Timeout occurs
+    /// Returns error only when the fetch cannot be attempted at all
     ///
     /// # Notes
     /// - Implementations should use batch API calls when possible for efficiency
     /// - Results should be filtered to only include packages with vulnerabilities
-    /// - Empty result indicates no vulnerabilities found (not an error)
+    /// - Empty results with no failed packages indicate no vulnerabilities found
     ///
     /// # Performance
     /// - Batch requests are preferred over individual requests
@@ -76,8 +114,8 @@ pub trait VulnerabilityRepository: Send + Sync {
     /// - Async implementation enables parallel fetching for better performance
     async fn fetch_vulnerabilities(
         &self,
-        packages: Vec<Package>,
-    ) -> Result<Vec<PackageVulnerabilities>>;
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome>;
 
     /// Fetches vulnerability information with progress reporting
     ///
@@ -93,12 +131,22 @@ pub trait VulnerabilityRepository: Send + Sync {
     /// Implementations should override this to provide progress updates.
     async fn fetch_vulnerabilities_with_progress(
         &self,
-        packages: Vec<Package>,
+        packages: &[Package],
         _progress_callback: ProgressCallback<'static>,
-    ) -> Result<Vec<PackageVulnerabilities>> {
+    ) -> Result<VulnerabilityFetchOutcome> {
         // Default implementation: no progress reporting
         self.fetch_vulnerabilities(packages).await
     }
+
+    /// Checks that the endpoints this repository queries can be reached
+    ///
+    /// Used by `--dry-run=full`. Sends at most one small request per endpoint.
+    ///
+    /// # Default Implementation
+    /// Returns no probes, for repositories that need no network access.
+    async fn probe_endpoints(&self) -> Vec<EndpointProbe> {
+        Vec::new()
+    }
 }
 
 /// Dummy implementation of VulnerabilityRepository for unit type
@@ -107,8 +155,8 @@ pub trait VulnerabilityRepository: Send + Sync {
 impl VulnerabilityRepository for () {
     async fn fetch_vulnerabilities(
         &self,
-        _packages: Vec<Package>,
-    ) -> Result<Vec<PackageVulnerabilities>> {
+        _packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
         // Dummy implementation - should never be called since Option<()> will always be None
         unreachable!("VulnerabilityRepository not configured")
     }
@@ -1,6 +1,12 @@
 use super::PackageName;
 use std::collections::{HashMap, HashSet, VecDeque};
 
+/// Upper bound on the paths returned by [`DependencyGraph::dependency_paths_to`].
+///
+/// The number of simple paths grows exponentially on dense graphs; callers only
+/// need enough of them to name the direct dependencies involved.
+const MAX_DEPENDENCY_PATHS: usize = 100;
+
 /// DependencyGraph aggregate representing the complete dependency structure
 #[derive(Debug, Clone)]
 pub struct DependencyGraph {
@@ -9,6 +15,10 @@ pub struct DependencyGraph {
     transitive_dependencies: HashMap<PackageName, Vec<PackageName>>,
     /// pkg → immediate children for ALL packages (used by find_paths_to for BFS traversal)
     package_edges: HashMap<PackageName, Vec<PackageName>>,
+    /// parent → child → extras requested on that edge; edges without extras are absent
+    edge_extras: HashMap<PackageName, HashMap<PackageName, Vec<String>>>,
+    /// Packages of the lockfile that no explicitly given root reaches
+    unreferenced_packages: Vec<PackageName>,
 }
 
 impl DependencyGraph {
@@ -27,9 +37,60 @@ impl DependencyGraph {
             direct_dependencies,
             transitive_dependencies,
             package_edges,
+            edge_extras: HashMap::new(),
+            unreferenced_packages: Vec::new(),
         }
     }
 
+    /// Records the packages that none of the explicitly given roots reaches.
+    ///
+    /// Only set for graphs rooted at `--roots`; a project's graph leaves it empty.
+    pub fn with_unreferenced_packages(mut self, packages: Vec<PackageName>) -> Self {
+        self.unreferenced_packages = packages;
+        self
+    }
+
+    /// Returns the packages no root reaches, in lockfile order
+    pub fn unreferenced_packages(&self) -> &[PackageName] {
+        &self.unreferenced_packages
+    }
+
+    /// Records the extras requested on dependency edges, keyed by parent and then child.
+    ///
+    /// Unlike `package_edges`, the project roots may appear as parents, since
+    /// the project itself is what usually requests a dependency with extras.
+    pub fn with_edge_extras(
+        mut self,
+        edge_extras: HashMap<PackageName, HashMap<PackageName, Vec<String>>>,
+    ) -> Self {
+        self.edge_extras = edge_extras;
+        self
+    }
+
+    /// Returns the extras with which `parent` requests `child`, e.g. `["socks"]`
+    /// for `requests[socks]`; empty when the edge has none or does not exist.
+    pub fn edge_extras(&self, parent: &PackageName, child: &PackageName) -> &[String] {
+        self.edge_extras
+            .get(parent)
+            .and_then(|children| children.get(child))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Returns the extras requested for `package` by any of its parents, sorted
+    /// and without duplicates
+    pub fn requested_extras(&self, package: &PackageName) -> Vec<String> {
+        let mut extras: Vec<String> = self
+            .edge_extras
+            .keys()
+            .flat_map(|parent| self.edge_extras(parent, package))
+            .cloned()
+            .collect();
+        extras.sort();
+        extras.dedup();
+        extras
+    }
+
     pub fn direct_dependencies(&self) -> &[PackageName] {
         &self.direct_dependencies
     }
@@ -42,8 +103,23 @@ impl DependencyGraph {
         self.direct_dependencies.len()
     }
 
+    /// Number of distinct transitive packages; a package pulled in by several
+    /// direct dependencies is counted once
     pub fn transitive_dependency_count(&self) -> usize {
-        self.transitive_dependencies.values().map(|v| v.len()).sum()
+        self.transitive_dependencies
+            .values()
+            .flatten()
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Returns the immediate dependencies of `package`, in lockfile order;
+    /// empty for a package without dependencies or one that is not in the graph
+    pub fn children_of(&self, package: &PackageName) -> &[PackageName] {
+        self.package_edges
+            .get(package)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
     }
 
     /// Returns all paths from any direct dependency to `target`.
@@ -51,12 +127,70 @@ impl DependencyGraph {
     /// Returns an empty Vec if `target` is itself a direct dependency (one-hop not shown).
     /// Uses BFS with per-path visited tracking to handle cyclic graphs safely.
     pub fn find_paths_to(&self, target: &PackageName) -> Vec<Vec<PackageName>> {
+        self.collect_paths_to(target, usize::MAX)
+    }
+
+    /// Returns the paths through which the project depends on `target`.
+    ///
+    /// Each path is ordered `[direct_dep, ..., target]`; a direct dependency is
+    /// returned as the one-element path `[target]`, followed by any longer paths
+    /// that also reach it. Shorter paths come first, and at most
+    /// [`MAX_DEPENDENCY_PATHS`] are returned. Returns an empty Vec when `target`
+    /// is not in the graph.
+    pub fn dependency_paths_to(&self, target: &PackageName) -> Vec<Vec<PackageName>> {
+        let mut paths = Vec::new();
+        if self.direct_dependencies.contains(target) {
+            paths.push(vec![target.clone()]);
+        }
+        paths.extend(self.collect_paths_to(target, MAX_DEPENDENCY_PATHS - paths.len()));
+        paths
+    }
+
+    /// Returns the packages that depend on `package` directly, sorted by name.
+    ///
+    /// The project root is not part of the edge map, so direct dependencies
+    /// only list the other packages that also require them.
+    pub fn reverse_dependencies(&self, package: &PackageName) -> Vec<PackageName> {
+        let mut parents: Vec<PackageName> = self
+            .package_edges
+            .iter()
+            .filter(|(_, children)| children.contains(package))
+            .map(|(parent, _)| parent.clone())
+            .collect();
+        parents.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        parents
+    }
+
+    /// Packages from which `target` can be reached, including `target` itself
+    fn ancestors_of(&self, target: &PackageName) -> HashSet<PackageName> {
+        let mut ancestors = HashSet::from([target.clone()]);
+        let mut queue = VecDeque::from([target.clone()]);
+        while let Some(current) = queue.pop_front() {
+            for parent in self.reverse_dependencies(&current) {
+                if ancestors.insert(parent.clone()) {
+                    queue.push_back(parent);
+                }
+            }
+        }
+        ancestors
+    }
+
+    /// Breadth-first enumeration of the multi-hop paths from direct dependencies
+    /// to `target`, stopping after `limit` paths.
+    ///
+    /// Only packages that can reach `target` are expanded, so dead branches of
+    /// a large graph are never walked.
+    fn collect_paths_to(&self, target: &PackageName, limit: usize) -> Vec<Vec<PackageName>> {
         let mut results: Vec<Vec<PackageName>> = Vec::new();
+        if limit == 0 {
+            return results;
+        }
+        let ancestors = self.ancestors_of(target);
         let mut queue: VecDeque<(PackageName, Vec<PackageName>, HashSet<PackageName>)> =
             VecDeque::new();
 
         for direct in &self.direct_dependencies {
-            if direct == target {
+            if direct == target || !ancestors.contains(direct) {
                 continue;
             }
             let path = vec![direct.clone()];
@@ -71,7 +205,7 @@ impl DependencyGraph {
             };
 
             for child in children {
-                if visited.contains(child) {
+                if visited.contains(child) || !ancestors.contains(child) {
                     continue;
                 }
                 let mut new_path = path.clone();
@@ -79,6 +213,9 @@ impl DependencyGraph {
 
                 if child == target {
                     results.push(new_path);
+                    if results.len() >= limit {
+                        return results;
+                    }
                     continue;
                 }
 
@@ -106,7 +243,7 @@ mod tests {
             .into_iter()
             .map(|(parent, children)| (pkg(parent), children.into_iter().map(pkg).collect()))
             .collect();
-        // These tests only exercise find_paths_to (BFS), so transitive_dependencies is unused.
+        // These tests only exercise the edge map, so transitive_dependencies is unused.
         DependencyGraph::new(direct_deps, HashMap::new(), package_edges)
     }
 
@@ -125,6 +262,36 @@ mod tests {
         assert_eq!(graph.transitive_dependency_count(), 1);
     }
 
+    #[test]
+    fn test_shared_transitive_dependency_is_counted_once() {
+        let transitive = HashMap::from([
+            (pkg("requests"), vec![pkg("urllib3"), pkg("idna")]),
+            (pkg("httpx"), vec![pkg("idna")]),
+        ]);
+        let graph = DependencyGraph::new(
+            vec![pkg("requests"), pkg("httpx")],
+            transitive,
+            HashMap::new(),
+        );
+
+        assert_eq!(graph.transitive_dependency_count(), 2);
+    }
+
+    #[test]
+    fn test_children_of() {
+        let graph = make_graph(
+            vec!["requests"],
+            vec![("requests", vec!["urllib3", "idna"]), ("urllib3", vec![])],
+        );
+
+        assert_eq!(
+            graph.children_of(&pkg("requests")),
+            [pkg("urllib3"), pkg("idna")]
+        );
+        assert!(graph.children_of(&pkg("urllib3")).is_empty());
+        assert!(graph.children_of(&pkg("missing")).is_empty());
+    }
+
     #[test]
     fn test_dependency_graph_empty() {
         let graph = DependencyGraph::new(vec![], HashMap::new(), HashMap::new());
@@ -133,6 +300,51 @@ mod tests {
         assert_eq!(graph.transitive_dependency_count(), 0);
     }
 
+    #[test]
+    fn test_edge_extras() {
+        let extras = HashMap::from([(
+            pkg("my-project"),
+            HashMap::from([(pkg("requests"), vec!["socks".to_string()])]),
+        )]);
+        let graph = make_graph(vec!["requests"], vec![("requests", vec!["pysocks"])])
+            .with_edge_extras(extras);
+
+        assert_eq!(
+            graph.edge_extras(&pkg("my-project"), &pkg("requests")),
+            ["socks"]
+        );
+        assert!(graph
+            .edge_extras(&pkg("requests"), &pkg("pysocks"))
+            .is_empty());
+        assert!(graph
+            .edge_extras(&pkg("other"), &pkg("requests"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_requested_extras_merges_parents() {
+        let extras = HashMap::from([
+            (
+                pkg("my-project"),
+                HashMap::from([(pkg("requests"), vec!["socks".to_string()])]),
+            ),
+            (
+                pkg("httpx"),
+                HashMap::from([(
+                    pkg("requests"),
+                    vec!["use-chardet".to_string(), "socks".to_string()],
+                )]),
+            ),
+        ]);
+        let graph = make_graph(vec!["requests"], vec![]).with_edge_extras(extras);
+
+        assert_eq!(
+            graph.requested_extras(&pkg("requests")),
+            vec!["socks", "use-chardet"]
+        );
+        assert!(graph.requested_extras(&pkg("httpx")).is_empty());
+    }
+
     #[test]
     fn test_find_paths_to_simple_transitive() {
         let graph = make_graph(vec!["requests"], vec![("requests", vec!["urllib3"])]);
@@ -220,6 +432,65 @@ mod tests {
         assert!(paths.contains(&vec![pkg("a"), pkg("c"), pkg("target")]));
     }
 
+    #[test]
+    fn test_dependency_paths_to_includes_direct_path() {
+        // "b" is a direct dependency and also reachable via "a"
+        let graph = make_graph(vec!["a", "b"], vec![("a", vec!["b"])]);
+        let paths = graph.dependency_paths_to(&pkg("b"));
+        assert_eq!(paths, vec![vec![pkg("b")], vec![pkg("a"), pkg("b")]]);
+        assert!(graph.dependency_paths_to(&pkg("zzz")).is_empty());
+    }
+
+    #[test]
+    fn test_dependency_paths_to_is_capped_on_dense_graphs() {
+        // Ten layers of two packages, each depending on both packages of the next
+        // layer: 2^10 paths from the two direct dependencies to "target"
+        let layer = |i: usize| vec![format!("l{}a", i), format!("l{}b", i)];
+        let mut edges: Vec<(String, Vec<String>)> = Vec::new();
+        for i in 0..10 {
+            let next = if i == 9 {
+                vec!["target".to_string()]
+            } else {
+                layer(i + 1)
+            };
+            for name in layer(i) {
+                edges.push((name, next.clone()));
+            }
+        }
+        let graph = make_graph(
+            layer(0).iter().map(String::as_str).collect(),
+            edges
+                .iter()
+                .map(|(parent, children)| {
+                    (
+                        parent.as_str(),
+                        children.iter().map(String::as_str).collect(),
+                    )
+                })
+                .collect(),
+        );
+
+        let paths = graph.dependency_paths_to(&pkg("target"));
+        assert_eq!(paths.len(), MAX_DEPENDENCY_PATHS);
+        assert!(paths.iter().all(|p| p.len() == 11));
+    }
+
+    #[test]
+    fn test_reverse_dependencies() {
+        let graph = make_graph(
+            vec!["requests", "httpx"],
+            vec![
+                ("requests", vec!["urllib3", "idna"]),
+                ("httpx", vec!["idna"]),
+            ],
+        );
+        assert_eq!(
+            graph.reverse_dependencies(&pkg("idna")),
+            vec![pkg("httpx"), pkg("requests")]
+        );
+        assert!(graph.reverse_dependencies(&pkg("requests")).is_empty());
+    }
+
     #[test]
     fn test_find_paths_to_target_appears_midpath() {
         // a -> target -> x  (BFS stops at target, does not continue to x)
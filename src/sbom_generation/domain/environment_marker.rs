@@ -0,0 +1,511 @@
+//! PEP 508 environment markers
+//!
+//! A minimal evaluator for markers such as `sys_platform == 'win32'` or
+//! `python_version < '3.11' and os_name != 'nt'`. Evaluation is three-valued:
+//! a comparison involving a variable the environment does not define (e.g.
+//! `platform_machine` or `extra`) is undecided rather than false. A marker is
+//! therefore only ruled out when it can never be true in the environment.
+
+use super::pep440::compare_versions;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// Marker variables defined by PEP 508, other than `extra`
+pub const MARKER_VARIABLES: &[&str] = &[
+    "os_name",
+    "sys_platform",
+    "platform_machine",
+    "platform_python_implementation",
+    "platform_release",
+    "platform_system",
+    "platform_version",
+    "python_version",
+    "python_full_version",
+    "implementation_name",
+    "implementation_version",
+];
+
+/// Variables whose values are compared as PEP 440 versions
+const VERSION_VARIABLES: &[&str] = &[
+    "python_version",
+    "python_full_version",
+    "implementation_version",
+];
+
+/// Operating system an SBOM is generated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetPlatform {
+    Linux,
+    Macos,
+    Windows,
+}
+
+impl TargetPlatform {
+    /// Values of `sys_platform`, `platform_system` and `os_name` on this platform
+    fn marker_values(self) -> [(&'static str, &'static str); 3] {
+        let (sys_platform, platform_system, os_name) = match self {
+            TargetPlatform::Linux => ("linux", "Linux", "posix"),
+            TargetPlatform::Macos => ("darwin", "Darwin", "posix"),
+            TargetPlatform::Windows => ("win32", "Windows", "nt"),
+        };
+        [
+            ("sys_platform", sys_platform),
+            ("platform_system", platform_system),
+            ("os_name", os_name),
+        ]
+    }
+}
+
+impl std::str::FromStr for TargetPlatform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "linux" => Ok(TargetPlatform::Linux),
+            "macos" | "darwin" => Ok(TargetPlatform::Macos),
+            "windows" | "win32" => Ok(TargetPlatform::Windows),
+            _ => Err(format!(
+                "Invalid platform: {}. Please specify 'linux', 'macos' or 'windows'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for TargetPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetPlatform::Linux => write!(f, "linux"),
+            TargetPlatform::Macos => write!(f, "macos"),
+            TargetPlatform::Windows => write!(f, "windows"),
+        }
+    }
+}
+
+/// Values of marker variables in the environment an SBOM is generated for
+///
+/// Variables that are not set are unknown; comparisons against them are undecided.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkerEnvironment {
+    platform: Option<TargetPlatform>,
+    overrides: BTreeMap<String, String>,
+}
+
+impl MarkerEnvironment {
+    /// Creates an environment for `platform` with `overrides` set on top of
+    /// (or replacing) the platform's values
+    pub fn new(platform: Option<TargetPlatform>, overrides: Vec<(String, String)>) -> Self {
+        Self {
+            platform,
+            overrides: overrides.into_iter().collect(),
+        }
+    }
+
+    /// Returns true when no variable is set, so that every marker is undecided
+    pub fn is_empty(&self) -> bool {
+        self.platform.is_none() && self.overrides.is_empty()
+    }
+
+    /// Returns the value of `variable`, if the environment defines it
+    pub fn get(&self, variable: &str) -> Option<&str> {
+        if let Some(value) = self.overrides.get(variable) {
+            return Some(value);
+        }
+        self.platform?
+            .marker_values()
+            .into_iter()
+            .find(|(name, _)| *name == variable)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns false only when `marker` can never be true in this environment
+    ///
+    /// Markers that cannot be parsed are kept, so a syntax this evaluator does
+    /// not understand never drops a package.
+    pub fn may_satisfy(&self, marker: &str) -> bool {
+        MarkerExpression::parse(marker).is_none_or(|expr| expr.evaluate(self) != Some(false))
+    }
+}
+
+impl std::fmt::Display for MarkerEnvironment {
+    /// `linux`, `linux, python_version=3.12` or `python_version=3.12`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = self
+            .platform
+            .map(|p| p.to_string())
+            .into_iter()
+            .chain(self.overrides.iter().map(|(k, v)| format!("{}={}", k, v)))
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// A parsed PEP 508 marker expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkerExpression {
+    And(Vec<MarkerExpression>),
+    Or(Vec<MarkerExpression>),
+    Compare {
+        left: MarkerValue,
+        op: MarkerOperator,
+        right: MarkerValue,
+    },
+}
+
+/// An operand of a marker comparison
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkerValue {
+    Variable(String),
+    Literal(String),
+}
+
+/// A marker comparison operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Compatible,
+    ArbitraryEqual,
+    In,
+    NotIn,
+}
+
+impl MarkerExpression {
+    /// Parses a marker; returns `None` for input that is not valid PEP 508 marker syntax
+    pub fn parse(marker: &str) -> Option<Self> {
+        let tokens = tokenize(marker)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        (parser.pos == parser.tokens.len()).then_some(expr)
+    }
+
+    /// Evaluates the marker: `Some(true)` or `Some(false)` when the environment
+    /// decides it, `None` when it depends on a variable that is not set
+    pub fn evaluate(&self, env: &MarkerEnvironment) -> Option<bool> {
+        match self {
+            MarkerExpression::And(exprs) => {
+                let results: Vec<Option<bool>> = exprs.iter().map(|e| e.evaluate(env)).collect();
+                if results.contains(&Some(false)) {
+                    Some(false)
+                } else if results.iter().all(|r| *r == Some(true)) {
+                    Some(true)
+                } else {
+                    None
+                }
+            }
+            MarkerExpression::Or(exprs) => {
+                let results: Vec<Option<bool>> = exprs.iter().map(|e| e.evaluate(env)).collect();
+                if results.contains(&Some(true)) {
+                    Some(true)
+                } else if results.iter().all(|r| *r == Some(false)) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            MarkerExpression::Compare { left, op, right } => {
+                let is_version = [left, right].into_iter().any(|value| {
+                    matches!(value, MarkerValue::Variable(v) if VERSION_VARIABLES.contains(&v.as_str()))
+                });
+                let resolve = |value: &MarkerValue| match value {
+                    MarkerValue::Variable(name) => env.get(name).map(str::to_string),
+                    MarkerValue::Literal(literal) => Some(literal.clone()),
+                };
+                let (left, right) = (resolve(left)?, resolve(right)?);
+                Some(compare(&left, *op, &right, is_version))
+            }
+        }
+    }
+}
+
+/// Compares two resolved operands; `in` tests whether `left` is a substring of `right`
+fn compare(left: &str, op: MarkerOperator, right: &str, is_version: bool) -> bool {
+    let ordering = || {
+        if is_version {
+            compare_versions(left, right)
+        } else {
+            left.cmp(right)
+        }
+    };
+    match op {
+        MarkerOperator::Equal if is_version => version_matches(left, right),
+        MarkerOperator::NotEqual if is_version => !version_matches(left, right),
+        MarkerOperator::Equal | MarkerOperator::ArbitraryEqual => left == right,
+        MarkerOperator::NotEqual => left != right,
+        MarkerOperator::Less => ordering() == Ordering::Less,
+        MarkerOperator::LessEqual => ordering() != Ordering::Greater,
+        MarkerOperator::Greater => ordering() == Ordering::Greater,
+        MarkerOperator::GreaterEqual => ordering() != Ordering::Less,
+        MarkerOperator::Compatible => {
+            // ~= 3.8 means >= 3.8 and == 3.*; ~= 3.8.1 means >= 3.8.1 and == 3.8.*
+            let prefix = right.rsplit_once('.').map_or(right, |(prefix, _)| prefix);
+            ordering() != Ordering::Less && version_matches(left, &format!("{}.*", prefix))
+        }
+        MarkerOperator::In => right.contains(left),
+        MarkerOperator::NotIn => !right.contains(left),
+    }
+}
+
+/// Version equality, with `3.*` matching every `3.x` release
+fn version_matches(version: &str, spec: &str) -> bool {
+    match spec.strip_suffix(".*") {
+        Some(prefix) => {
+            let segments: Vec<&str> = version.split('.').collect();
+            let prefix: Vec<&str> = prefix.split('.').collect();
+            segments.len() >= prefix.len()
+                && compare_versions(&segments[..prefix.len()].join("."), &prefix.join("."))
+                    == Ordering::Equal
+        }
+        None => compare_versions(version, spec) == Ordering::Equal,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Op(MarkerOperator),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+fn tokenize(marker: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = marker.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' => {
+                chars.next();
+                tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            }
+            '\'' | '"' => {
+                chars.next();
+                let literal: String = chars.by_ref().take_while(|ch| *ch != c).collect();
+                tokens.push(Token::Str(literal));
+            }
+            '<' | '>' | '=' | '!' | '~' => {
+                let mut op = String::new();
+                while let Some(&ch) = chars.peek().filter(|ch| "<>=!~".contains(**ch)) {
+                    op.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Op(match op.as_str() {
+                    "==" => MarkerOperator::Equal,
+                    "!=" => MarkerOperator::NotEqual,
+                    "<" => MarkerOperator::Less,
+                    "<=" => MarkerOperator::LessEqual,
+                    ">" => MarkerOperator::Greater,
+                    ">=" => MarkerOperator::GreaterEqual,
+                    "~=" => MarkerOperator::Compatible,
+                    "===" => MarkerOperator::ArbitraryEqual,
+                    _ => return None,
+                }));
+            }
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '.' => {
+                let mut word = String::new();
+                while let Some(&ch) = chars
+                    .peek()
+                    .filter(|ch| ch.is_ascii_alphanumeric() || **ch == '_' || **ch == '.')
+                {
+                    word.push(ch);
+                    chars.next();
+                }
+                tokens.push(match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    "in" => Token::Op(MarkerOperator::In),
+                    _ => Token::Ident(word),
+                });
+            }
+            _ => return None,
+        }
+    }
+    Some(tokens)
+}
+
+/// Recursive-descent parser; `and` binds tighter than `or`
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let matched = self.tokens.get(self.pos) == Some(token);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn parse_or(&mut self) -> Option<MarkerExpression> {
+        let mut exprs = vec![self.parse_and()?];
+        while self.eat(&Token::Or) {
+            exprs.push(self.parse_and()?);
+        }
+        Some(if exprs.len() == 1 {
+            exprs.remove(0)
+        } else {
+            MarkerExpression::Or(exprs)
+        })
+    }
+
+    fn parse_and(&mut self) -> Option<MarkerExpression> {
+        let mut exprs = vec![self.parse_atom()?];
+        while self.eat(&Token::And) {
+            exprs.push(self.parse_atom()?);
+        }
+        Some(if exprs.len() == 1 {
+            exprs.remove(0)
+        } else {
+            MarkerExpression::And(exprs)
+        })
+    }
+
+    fn parse_atom(&mut self) -> Option<MarkerExpression> {
+        if self.eat(&Token::Open) {
+            let expr = self.parse_or()?;
+            return self.eat(&Token::Close).then_some(expr);
+        }
+        let left = self.parse_value()?;
+        let op = match self.next()? {
+            Token::Op(op) => op,
+            Token::Not if self.eat(&Token::Op(MarkerOperator::In)) => MarkerOperator::NotIn,
+            _ => return None,
+        };
+        let right = self.parse_value()?;
+        Some(MarkerExpression::Compare { left, op, right })
+    }
+
+    fn parse_value(&mut self) -> Option<MarkerValue> {
+        match self.next()? {
+            Token::Ident(name) => Some(MarkerValue::Variable(name)),
+            Token::Str(literal) => Some(MarkerValue::Literal(literal)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linux() -> MarkerEnvironment {
+        MarkerEnvironment::new(Some(TargetPlatform::Linux), Vec::new())
+    }
+
+    fn eval(marker: &str, env: &MarkerEnvironment) -> Option<bool> {
+        MarkerExpression::parse(marker).unwrap().evaluate(env)
+    }
+
+    #[test]
+    fn test_platform_variables() {
+        let env = linux();
+        assert_eq!(eval("sys_platform == 'linux'", &env), Some(true));
+        assert_eq!(eval("sys_platform == 'win32'", &env), Some(false));
+        assert_eq!(eval("platform_system != \"Windows\"", &env), Some(true));
+        assert_eq!(eval("os_name == 'nt'", &env), Some(false));
+        assert_eq!(eval("'linux' == sys_platform", &env), Some(true));
+    }
+
+    #[test]
+    fn test_unknown_variables_are_undecided() {
+        let env = linux();
+        assert_eq!(eval("platform_machine == 'x86_64'", &env), None);
+        assert_eq!(eval("python_version < '3.11'", &env), None);
+        assert_eq!(
+            eval("sys_platform == 'win32' and python_version < '3.11'", &env),
+            Some(false)
+        );
+        assert_eq!(
+            eval("sys_platform == 'linux' or python_version < '3.11'", &env),
+            Some(true)
+        );
+        assert_eq!(
+            eval("sys_platform == 'linux' and python_version < '3.11'", &env),
+            None
+        );
+    }
+
+    #[test]
+    fn test_python_version_comparisons() {
+        let env = MarkerEnvironment::new(
+            None,
+            vec![("python_version".to_string(), "3.12".to_string())],
+        );
+        assert_eq!(eval("python_version < '3.11'", &env), Some(false));
+        assert_eq!(eval("python_version >= '3.9'", &env), Some(true));
+        assert_eq!(eval("python_version == '3.12.0'", &env), Some(true));
+        assert_eq!(eval("python_version == '3.*'", &env), Some(true));
+        assert_eq!(eval("python_version ~= '3.10'", &env), Some(true));
+        assert_eq!(eval("python_version ~= '3.10.2'", &env), Some(false));
+        assert_eq!(eval("sys_platform == 'win32'", &env), None);
+    }
+
+    #[test]
+    fn test_precedence_parentheses_and_in() {
+        let env = linux();
+        // `and` binds tighter: false or (true and true)
+        assert_eq!(
+            eval(
+                "os_name == 'nt' or sys_platform == 'linux' and platform_system == 'Linux'",
+                &env
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            eval(
+                "(os_name == 'nt' or sys_platform == 'linux') and platform_system == 'Darwin'",
+                &env
+            ),
+            Some(false)
+        );
+        assert_eq!(eval("'linux' in sys_platform", &env), Some(true));
+        assert_eq!(eval("sys_platform not in 'win32 cygwin'", &env), Some(true));
+    }
+
+    #[test]
+    fn test_overrides_replace_platform_values() {
+        let env = MarkerEnvironment::new(
+            Some(TargetPlatform::Windows),
+            vec![("sys_platform".to_string(), "cygwin".to_string())],
+        );
+        assert_eq!(env.get("sys_platform"), Some("cygwin"));
+        assert_eq!(env.get("os_name"), Some("nt"));
+        assert_eq!(env.to_string(), "windows, sys_platform=cygwin");
+    }
+
+    #[test]
+    fn test_may_satisfy_keeps_unparseable_markers() {
+        let env = linux();
+        assert!(!env.may_satisfy("sys_platform == 'win32'"));
+        assert!(env.may_satisfy("platform_machine == 'arm64'"));
+        assert!(env.may_satisfy("sys_platform === "));
+        assert!(MarkerExpression::parse("sys_platform == 'linux' and").is_none());
+    }
+
+    #[test]
+    fn test_target_platform_from_str() {
+        assert_eq!("Linux".parse(), Ok(TargetPlatform::Linux));
+        assert_eq!("darwin".parse(), Ok(TargetPlatform::Macos));
+        assert_eq!("windows".parse(), Ok(TargetPlatform::Windows));
+        assert!("solaris".parse::<TargetPlatform>().is_err());
+    }
+}
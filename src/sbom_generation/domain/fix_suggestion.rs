@@ -0,0 +1,107 @@
+/// Suggested upgrade for one vulnerable package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixSuggestion {
+    /// Name of the vulnerable package
+    package_name: String,
+    /// Currently locked version
+    current_version: String,
+    /// Lowest version that fixes every advisory with a known fix
+    fixed_version: Option<String>,
+    /// Advisories resolved by upgrading to `fixed_version`
+    fixed_ids: Vec<String>,
+    /// Advisories without a published fix
+    unfixed_ids: Vec<String>,
+    /// Whether the project depends on the package directly
+    is_direct: bool,
+    /// Direct dependencies that pull in a transitive package, sorted by name
+    introduced_by: Vec<String>,
+}
+
+impl FixSuggestion {
+    pub fn new(
+        package_name: String,
+        current_version: String,
+        fixed_version: Option<String>,
+        fixed_ids: Vec<String>,
+        unfixed_ids: Vec<String>,
+        is_direct: bool,
+        introduced_by: Vec<String>,
+    ) -> Self {
+        Self {
+            package_name,
+            current_version,
+            fixed_version,
+            fixed_ids,
+            unfixed_ids,
+            is_direct,
+            introduced_by,
+        }
+    }
+
+    pub fn package_name(&self) -> &str {
+        &self.package_name
+    }
+
+    pub fn current_version(&self) -> &str {
+        &self.current_version
+    }
+
+    pub fn fixed_version(&self) -> Option<&str> {
+        self.fixed_version.as_deref()
+    }
+
+    pub fn fixed_ids(&self) -> &[String] {
+        &self.fixed_ids
+    }
+
+    pub fn unfixed_ids(&self) -> &[String] {
+        &self.unfixed_ids
+    }
+
+    pub fn introduced_by(&self) -> &[String] {
+        &self.introduced_by
+    }
+
+    /// The `uv add` command raising a direct dependency to the fixed version,
+    /// e.g. `uv add 'urllib3>=1.26.19'`
+    ///
+    /// `None` for transitive dependencies, which are fixed through the direct
+    /// dependency that pulls them in or a constraint, and when no fix is known.
+    pub fn upgrade_command(&self) -> Option<String> {
+        let fixed_version = self.fixed_version.as_ref().filter(|_| self.is_direct)?;
+        Some(format!("uv add '{}>={}'", self.package_name, fixed_version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(fixed_version: Option<&str>, is_direct: bool) -> FixSuggestion {
+        FixSuggestion::new(
+            "urllib3".to_string(),
+            "1.26.15".to_string(),
+            fixed_version.map(str::to_string),
+            vec!["CVE-2024-37891".to_string()],
+            vec![],
+            is_direct,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_upgrade_command_for_direct_dependency() {
+        assert_eq!(
+            suggestion(Some("1.26.19"), true)
+                .upgrade_command()
+                .as_deref(),
+            Some("uv add 'urllib3>=1.26.19'")
+        );
+    }
+
+    #[test]
+    fn test_no_upgrade_command_for_transitive_or_unfixed() {
+        assert_eq!(suggestion(Some("1.26.19"), false).upgrade_command(), None);
+        assert_eq!(suggestion(None, true).upgrade_command(), None);
+    }
+}
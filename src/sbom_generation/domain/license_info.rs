@@ -1,9 +1,47 @@
+use super::{PackageAuthorship, PackageLinks};
+
+/// Where a package's license string came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LicenseSource {
+    /// The `license` or `license_expression` field of the PyPI metadata
+    PyPiLicenseField,
+    /// An "License :: OSI Approved :: ..." classifier of the PyPI metadata
+    PyPiClassifier,
+    /// The installed distribution's METADATA or a local package's `pyproject.toml`
+    LocalMetadata,
+    /// A `license_overrides` entry in the config file
+    ConfigOverride,
+    /// The package is not on the index, so it has no license to look up
+    NotFound,
+    /// No license was found
+    #[default]
+    Unknown,
+}
+
+impl LicenseSource {
+    /// Returns the kebab-case name used in output, e.g. `pypi-classifier`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PyPiLicenseField => "pypi-license-field",
+            Self::PyPiClassifier => "pypi-classifier",
+            Self::LocalMetadata => "local-metadata",
+            Self::ConfigOverride => "config-override",
+            Self::NotFound => "not-found",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
 /// LicenseInfo value object representing license, description, and hash information
 #[derive(Debug, Clone, PartialEq)]
 pub struct LicenseInfo {
     license_text: Option<String>,
     description: Option<String>,
     sha256_hash: Option<String>,
+    yanked: Option<String>,
+    authorship: PackageAuthorship,
+    links: PackageLinks,
+    source: LicenseSource,
 }
 
 impl LicenseInfo {
@@ -12,14 +50,42 @@ impl LicenseInfo {
             license_text,
             description,
             sha256_hash: None,
+            yanked: None,
+            authorship: PackageAuthorship::default(),
+            links: PackageLinks::default(),
+            source: LicenseSource::Unknown,
         }
     }
 
+    /// Records where the license string came from
+    pub fn with_source(mut self, source: LicenseSource) -> Self {
+        self.source = source;
+        self
+    }
+
     pub fn with_sha256_hash(mut self, sha256_hash: Option<String>) -> Self {
         self.sha256_hash = sha256_hash;
         self
     }
 
+    /// Marks the release as yanked, with the yank reason (empty if none was given)
+    pub fn with_yanked(mut self, yanked: Option<String>) -> Self {
+        self.yanked = yanked;
+        self
+    }
+
+    /// Records who wrote and who maintains the package
+    pub fn with_authorship(mut self, authorship: PackageAuthorship) -> Self {
+        self.authorship = authorship;
+        self
+    }
+
+    /// Records the package's homepage, repository and documentation links
+    pub fn with_links(mut self, links: PackageLinks) -> Self {
+        self.links = links;
+        self
+    }
+
     pub fn license_text(&self) -> Option<&str> {
         self.license_text.as_deref()
     }
@@ -31,6 +97,23 @@ impl LicenseInfo {
     pub fn sha256_hash(&self) -> Option<&str> {
         self.sha256_hash.as_deref()
     }
+
+    /// Returns the yank reason if the release was yanked
+    pub fn yanked(&self) -> Option<&str> {
+        self.yanked.as_deref()
+    }
+
+    pub fn authorship(&self) -> &PackageAuthorship {
+        &self.authorship
+    }
+
+    pub fn links(&self) -> &PackageLinks {
+        &self.links
+    }
+
+    pub fn source(&self) -> LicenseSource {
+        self.source
+    }
 }
 
 #[cfg(test)]
@@ -66,6 +149,25 @@ mod tests {
         assert_eq!(info.sha256_hash(), Some("abc123"));
     }
 
+    #[test]
+    fn test_license_info_with_yanked() {
+        let info = LicenseInfo::new(Some("MIT".to_string()), None);
+        assert_eq!(info.yanked(), None);
+
+        let info = info.with_yanked(Some("Broken wheel".to_string()));
+        assert_eq!(info.yanked(), Some("Broken wheel"));
+    }
+
+    #[test]
+    fn test_license_info_source_defaults_to_unknown() {
+        let info = LicenseInfo::new(None, None);
+        assert_eq!(info.source(), LicenseSource::Unknown);
+
+        let info = info.with_source(LicenseSource::LocalMetadata);
+        assert_eq!(info.source(), LicenseSource::LocalMetadata);
+        assert_eq!(info.source().as_str(), "local-metadata");
+    }
+
     #[test]
     fn test_license_info_without_sha256_hash() {
         let info = LicenseInfo::new(Some("MIT".to_string()), None);
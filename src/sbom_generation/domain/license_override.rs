@@ -0,0 +1,60 @@
+use super::PackageName;
+
+/// A license set manually for a package, replacing the one found by enrichment
+///
+/// Declared in the config file under `license_overrides`, for packages whose
+/// registry metadata is wrong or missing (internal packages, vendored forks).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseOverride {
+    package: String,
+    license: String,
+    reason: Option<String>,
+}
+
+impl LicenseOverride {
+    pub fn new(package: String, license: String, reason: Option<String>) -> Self {
+        Self {
+            package,
+            license,
+            reason,
+        }
+    }
+
+    /// The package name as written in the config file
+    pub fn package(&self) -> &str {
+        &self.package
+    }
+
+    /// The license expression or name to record for the package
+    pub fn license(&self) -> &str {
+        &self.license
+    }
+
+    /// Why the license was overridden, if given
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Returns true when this override targets `name` after PEP 503 normalization.
+    pub fn applies_to(&self, name: &str) -> bool {
+        PackageName::normalize(&self.package) == PackageName::normalize(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_applies_to_uses_pep503_normalization() {
+        let license_override = LicenseOverride::new(
+            "Internal_Utils".to_string(),
+            "MIT".to_string(),
+            Some("vendored fork".to_string()),
+        );
+        assert!(license_override.applies_to("internal-utils"));
+        assert!(license_override.applies_to("internal.utils"));
+        assert!(!license_override.applies_to("internal"));
+        assert_eq!(license_override.reason(), Some("vendored fork"));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::sbom_generation::policies::spdx_license_map;
+
+/// Copyleft licenses reported by the default license reachability check
+pub const DEFAULT_COPYLEFT_LICENSES: [&str; 3] = ["GPL-2.0", "GPL-3.0", "AGPL-3.0"];
+
+/// The set of copyleft licenses whose reachability is reported
+///
+/// An entry matches the license itself and its `-only`, `-or-later` and `+`
+/// variants, so `GPL-3.0` covers `GPL-3.0-only` and `GPL-3.0-or-later`.
+/// License names such as "GNU General Public License v3 (GPLv3)" are mapped
+/// to their SPDX id first. Matching is case-insensitive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyleftLicenses {
+    ids: Vec<String>,
+}
+
+impl CopyleftLicenses {
+    pub fn new(ids: &[String]) -> Self {
+        Self {
+            ids: ids
+                .iter()
+                .map(|id| id.trim().to_lowercase())
+                .filter(|id| !id.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Returns true when `license` (a name or an SPDX expression) is copyleft.
+    ///
+    /// A choice (`OR`) is copyleft only when every alternative is, and a
+    /// combination (`AND`) when any part is. Expressions with parentheses are
+    /// evaluated conservatively: any copyleft license in them matches.
+    pub fn matches(&self, license: &str) -> bool {
+        let expression = license.trim();
+        if self.matches_single(expression) {
+            return true;
+        }
+        if expression.contains('(') || expression.contains(')') {
+            return expression
+                .replace(['(', ')'], " ")
+                .split_whitespace()
+                .any(|token| self.matches_single(token));
+        }
+        expression.split(" OR ").all(|alternative| {
+            alternative
+                .split(" AND ")
+                .any(|part| self.matches_single(part))
+        })
+    }
+
+    fn matches_single(&self, license: &str) -> bool {
+        let base = license.split(" WITH ").next().unwrap_or(license).trim();
+        let id = spdx_license_map::get_spdx_id(base)
+            .unwrap_or_else(|| base.to_string())
+            .to_lowercase();
+        self.ids.iter().any(|copyleft| {
+            id.strip_prefix(copyleft.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest == "+" || rest.starts_with('-'))
+        })
+    }
+}
+
+impl Default for CopyleftLicenses {
+    fn default() -> Self {
+        Self::new(&DEFAULT_COPYLEFT_LICENSES.map(String::from))
+    }
+}
+
+/// A package reachable from the project whose license needs legal review
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseRisk {
+    package_name: String,
+    package_version: String,
+    /// `None` for packages without a known license
+    license: Option<String>,
+    /// Shortest path from a direct dependency, ordered `[direct_dep, ..., package]`
+    introduction_path: Vec<String>,
+    /// Number of paths through which the project depends on the package
+    path_count: usize,
+}
+
+impl LicenseRisk {
+    pub fn new(
+        package_name: String,
+        package_version: String,
+        license: Option<String>,
+        introduction_path: Vec<String>,
+        path_count: usize,
+    ) -> Self {
+        Self {
+            package_name,
+            package_version,
+            license,
+            introduction_path,
+            path_count,
+        }
+    }
+
+    pub fn package_name(&self) -> &str {
+        &self.package_name
+    }
+
+    pub fn package_version(&self) -> &str {
+        &self.package_version
+    }
+
+    pub fn license(&self) -> Option<&str> {
+        self.license.as_deref()
+    }
+
+    pub fn introduction_path(&self) -> &[String] {
+        &self.introduction_path
+    }
+
+    pub fn path_count(&self) -> usize {
+        self.path_count
+    }
+}
+
+/// Result of the license reachability check
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LicenseRiskReport {
+    /// Reachable packages under a copyleft license, sorted by name
+    pub copyleft: Vec<LicenseRisk>,
+    /// Reachable packages without a known license, sorted by name.
+    /// Only filled in strict mode.
+    pub unknown: Vec<LicenseRisk>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_set_matches_gpl_variants() {
+        let copyleft = CopyleftLicenses::default();
+
+        for license in [
+            "GPL-3.0",
+            "GPL-3.0-only",
+            "gpl-2.0-or-later",
+            "GPL-2.0+",
+            "AGPL-3.0-only",
+            "GNU General Public License v3 (GPLv3)",
+            "GPL-3.0-or-later WITH Classpath-exception-2.0",
+        ] {
+            assert!(copyleft.matches(license), "{license}");
+        }
+        for license in ["MIT", "LGPL-3.0-only", "GPL-3.0X", "Apache-2.0"] {
+            assert!(!copyleft.matches(license), "{license}");
+        }
+    }
+
+    #[test]
+    fn test_expressions() {
+        let copyleft = CopyleftLicenses::default();
+
+        // A permissive alternative can be chosen instead
+        assert!(!copyleft.matches("MIT OR GPL-3.0-only"));
+        assert!(copyleft.matches("GPL-2.0-only OR GPL-3.0-only"));
+        assert!(copyleft.matches("MIT AND GPL-3.0-only"));
+        assert!(copyleft.matches("(MIT OR GPL-3.0-only) AND Apache-2.0"));
+    }
+
+    #[test]
+    fn test_configured_set_replaces_default() {
+        let copyleft = CopyleftLicenses::new(&["MPL-2.0".to_string()]);
+
+        assert!(copyleft.matches("MPL-2.0"));
+        assert!(!copyleft.matches("GPL-3.0-only"));
+    }
+}
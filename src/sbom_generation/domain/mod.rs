@@ -1,26 +1,50 @@
 pub mod dependency_graph;
+pub mod environment_marker;
+pub mod fix_suggestion;
+pub mod invocation_parameters;
 pub mod license_info;
+pub mod license_override;
 pub mod license_policy;
+pub mod license_risk;
 pub mod package;
+pub mod package_annotation;
+pub mod package_authorship;
+pub mod package_links;
+pub mod pep440;
+pub mod python_compatibility;
 pub mod resolution_guide;
+pub mod sbom_diff;
 pub mod sbom_metadata;
 pub mod services;
+pub mod typosquat;
 pub mod upgrade_recommendation;
 pub mod vulnerability;
+pub mod vulnerability_baseline;
 
 pub use dependency_graph::DependencyGraph;
-pub use license_info::LicenseInfo;
+pub use environment_marker::{MarkerEnvironment, TargetPlatform};
+pub use fix_suggestion::FixSuggestion;
+pub use invocation_parameters::InvocationParameters;
+pub use license_info::{LicenseInfo, LicenseSource};
+pub use license_override::LicenseOverride;
 // Note: These types are used within the application layer via full paths
 #[allow(unused_imports)]
 pub use license_policy::{
     LicenseComplianceResult, LicensePolicy, LicenseViolation, LicenseWarning,
     UnknownLicenseHandling, ViolationReason,
 };
-pub use package::{Package, PackageName};
+pub use license_risk::{CopyleftLicenses, LicenseRisk, LicenseRiskReport};
+pub use package::{Package, PackageName, PackageSource};
+pub use package_annotation::PackageAnnotation;
+pub use package_authorship::PackageAuthorship;
+pub use package_links::{ExternalRefType, PackageLinks};
+pub use python_compatibility::PythonCompatibilityReport;
 // Note: These will be used in subsequent subtasks (Issue #221 sub-tasks 2-4)
 #[allow(unused_imports)]
 pub use resolution_guide::{IntroducedBy, ResolutionEntry};
+pub use sbom_diff::{LicenseChange, PackageEntry, SbomDiff, VersionChange};
 pub use sbom_metadata::SbomMetadata;
+pub use typosquat::TyposquatFinding;
 // Note: ResolutionAnalyzer will be used in subsequent subtasks (Issue #221 sub-tasks 3-4)
 #[allow(unused_imports)]
 pub use services::ResolutionAnalyzer;
@@ -32,4 +56,7 @@ pub use services::{ThresholdConfig, VulnerabilityCheckResult, VulnerabilityCheck
 pub use upgrade_recommendation::UpgradeRecommendation;
 // Note: These will be used in subsequent subtasks (Subtask 2-8)
 #[allow(unused_imports)]
-pub use vulnerability::{CvssScore, PackageVulnerabilities, Severity, Vulnerability};
+pub use vulnerability::{
+    CvssPreference, CvssScore, CvssVersion, PackageVulnerabilities, Severity, Vulnerability,
+};
+pub use vulnerability_baseline::VulnerabilityBaseline;
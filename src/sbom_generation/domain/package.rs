@@ -1,3 +1,4 @@
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 
 /// Maximum length for package names (security limit)
@@ -14,16 +15,16 @@ impl PackageName {
     pub fn new(name: String) -> Result<Self> {
         // Basic validation
         if name.is_empty() {
-            anyhow::bail!("Package name cannot be empty");
+            return Err(UvSbomError::validation("Package name cannot be empty"));
         }
 
         // Security: Length limit to prevent DoS
         if name.len() > MAX_PACKAGE_NAME_LENGTH {
-            anyhow::bail!(
+            return Err(UvSbomError::validation(format!(
                 "Package name is too long ({} bytes). Maximum allowed: {} bytes",
                 name.len(),
                 MAX_PACKAGE_NAME_LENGTH
-            );
+            )));
         }
 
         // Security: Validate characters (allow alphanumeric, hyphens, underscores, dots, and common package chars)
@@ -32,9 +33,7 @@ impl PackageName {
             c.is_alphanumeric() || c == '-' || c == '_' || c == '.' || c == '[' || c == ']'
             // For extras like package[extra]
         }) {
-            anyhow::bail!(
-                "Package name contains invalid characters. Only alphanumeric, hyphens, underscores, dots, and brackets are allowed."
-            );
+            return Err(UvSbomError::validation("Package name contains invalid characters. Only alphanumeric, hyphens, underscores, dots, and brackets are allowed."));
         }
 
         Ok(Self(name))
@@ -43,6 +42,27 @@ impl PackageName {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Normalizes a package name as specified by PEP 503.
+    ///
+    /// Runs of `-`, `_` and `.` collapse to a single `-` and the result is
+    /// lowercased, so `Foo.Bar`, `foo_bar` and `FOO--bar` compare equal.
+    pub fn normalize(name: &str) -> String {
+        let mut normalized = String::with_capacity(name.len());
+        let mut in_separator = false;
+        for c in name.chars() {
+            if matches!(c, '-' | '_' | '.') {
+                if !in_separator {
+                    normalized.push('-');
+                }
+                in_separator = true;
+            } else {
+                normalized.extend(c.to_lowercase());
+                in_separator = false;
+            }
+        }
+        normalized
+    }
 }
 
 impl std::fmt::Display for PackageName {
@@ -59,16 +79,16 @@ impl Version {
     pub fn new(version: String) -> Result<Self> {
         // Basic validation
         if version.is_empty() {
-            anyhow::bail!("Package version cannot be empty");
+            return Err(UvSbomError::validation("Package version cannot be empty"));
         }
 
         // Security: Length limit to prevent DoS
         if version.len() > MAX_VERSION_LENGTH {
-            anyhow::bail!(
+            return Err(UvSbomError::validation(format!(
                 "Package version is too long ({} bytes). Maximum allowed: {} bytes",
                 version.len(),
                 MAX_VERSION_LENGTH
-            );
+            )));
         }
 
         // Security: Validate characters (allow alphanumeric, dots, hyphens, plus, and common version chars)
@@ -76,9 +96,7 @@ impl Version {
         if !version.chars().all(|c| {
             c.is_alphanumeric() || c == '.' || c == '-' || c == '+' || c == '*' // For wildcards
         }) {
-            anyhow::bail!(
-                "Package version contains invalid characters. Only alphanumeric, dots, hyphens, plus, and asterisks are allowed."
-            );
+            return Err(UvSbomError::validation("Package version contains invalid characters. Only alphanumeric, dots, hyphens, plus, and asterisks are allowed."));
         }
 
         Ok(Self(version))
@@ -95,11 +113,41 @@ impl std::fmt::Display for Version {
     }
 }
 
+/// Where a locked package comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSource {
+    /// A package index, e.g. `https://pypi.org/simple`
+    Registry { url: String },
+    /// A Git repository, pinned to the commit `revision` when it is known
+    Git {
+        url: String,
+        revision: Option<String>,
+    },
+    /// A local directory or archive, including editable installs
+    Path { path: String },
+    /// A local project that is not built, such as the project root itself
+    Virtual { path: String },
+}
+
+impl PackageSource {
+    /// Returns true for sources on the local file system, which are not
+    /// published to PyPI and have no OSV records
+    pub fn is_local(&self) -> bool {
+        matches!(self, Self::Path { .. } | Self::Virtual { .. })
+    }
+}
+
 /// Package value object representing a software package
 #[derive(Debug, Clone, PartialEq)]
 pub struct Package {
     name: PackageName,
     version: Version,
+    sha256_hash: Option<String>,
+    source: Option<PackageSource>,
+    declared_constraint: Option<String>,
+    marker: Option<String>,
+    requires_python: Option<String>,
+    project_root: bool,
 }
 
 impl Package {
@@ -107,9 +155,54 @@ impl Package {
         Ok(Self {
             name: PackageName::new(name)?,
             version: Version::new(version)?,
+            sha256_hash: None,
+            source: None,
+            declared_constraint: None,
+            marker: None,
+            requires_python: None,
+            project_root: false,
         })
     }
 
+    /// Sets the SHA-256 digest of the locked artifact (hex, without the `sha256:` prefix).
+    pub fn with_sha256_hash(mut self, sha256_hash: Option<String>) -> Self {
+        self.sha256_hash = sha256_hash;
+        self
+    }
+
+    /// Sets where the package comes from, as recorded in the lockfile.
+    pub fn with_source(mut self, source: Option<PackageSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Sets the requirement pyproject.toml declares for the package, as written.
+    pub fn with_declared_constraint(mut self, declared_constraint: Option<String>) -> Self {
+        self.declared_constraint = declared_constraint;
+        self
+    }
+
+    /// Sets the PEP 508 environment marker the package is limited to, e.g. the
+    /// platforms its wheels are built for. `None` means every environment.
+    pub fn with_marker(mut self, marker: Option<String>) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Sets the Python versions the package supports, as the lockfile records
+    /// its `requires-python` (e.g. `>=3.8,<3.12`). `None` means it is not known.
+    pub fn with_requires_python(mut self, requires_python: Option<String>) -> Self {
+        self.requires_python = requires_python;
+        self
+    }
+
+    /// Marks the package as the project the lockfile was created for, which uv
+    /// locks as an editable or virtual package at `.`
+    pub fn with_project_root(mut self, project_root: bool) -> Self {
+        self.project_root = project_root;
+        self
+    }
+
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
@@ -117,6 +210,36 @@ impl Package {
     pub fn version(&self) -> &str {
         self.version.as_str()
     }
+
+    pub fn sha256_hash(&self) -> Option<&str> {
+        self.sha256_hash.as_deref()
+    }
+
+    pub fn source(&self) -> Option<&PackageSource> {
+        self.source.as_ref()
+    }
+
+    pub fn declared_constraint(&self) -> Option<&str> {
+        self.declared_constraint.as_deref()
+    }
+
+    pub fn marker(&self) -> Option<&str> {
+        self.marker.as_deref()
+    }
+
+    pub fn requires_python(&self) -> Option<&str> {
+        self.requires_python.as_deref()
+    }
+
+    /// Returns true when the lockfile records a local path or virtual source
+    pub fn has_local_source(&self) -> bool {
+        self.source.as_ref().is_some_and(PackageSource::is_local)
+    }
+
+    /// Returns true for the project the lockfile was created for
+    pub fn is_project_root(&self) -> bool {
+        self.project_root
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +252,22 @@ mod tests {
         assert_eq!(name.as_str(), "requests");
     }
 
+    #[test]
+    fn test_package_name_normalize_pep503() {
+        assert_eq!(PackageName::normalize("Foo.Bar"), "foo-bar");
+        assert_eq!(PackageName::normalize("foo_bar"), "foo-bar");
+        assert_eq!(PackageName::normalize("FOO--bar"), "foo-bar");
+        assert_eq!(PackageName::normalize("a._-b"), "a-b");
+        assert_eq!(PackageName::normalize("requests"), "requests");
+        assert_eq!(PackageName::normalize("Django"), "django");
+        assert_eq!(PackageName::normalize("zope.interface"), "zope-interface");
+        assert_eq!(PackageName::normalize("ruamel.yaml"), "ruamel-yaml");
+        assert_eq!(
+            PackageName::normalize("typing_extensions"),
+            "typing-extensions"
+        );
+    }
+
     #[test]
     fn test_package_name_new_empty() {
         let result = PackageName::new("".to_string());
@@ -154,6 +293,15 @@ mod tests {
         assert_eq!(package.version(), "2.31.0");
     }
 
+    #[test]
+    fn test_package_with_sha256_hash() {
+        let package = Package::new("requests".to_string(), "2.31.0".to_string()).unwrap();
+        assert_eq!(package.sha256_hash(), None);
+
+        let package = package.with_sha256_hash(Some("abc123".to_string()));
+        assert_eq!(package.sha256_hash(), Some("abc123"));
+    }
+
     #[test]
     fn test_package_new_empty_name() {
         let result = Package::new("".to_string(), "1.0.0".to_string());
@@ -173,6 +321,34 @@ mod tests {
         assert_eq!(pkg1, pkg2);
     }
 
+    #[test]
+    fn test_package_source_is_local() {
+        let path = PackageSource::Path {
+            path: "../lib".to_string(),
+        };
+        let virtual_root = PackageSource::Virtual {
+            path: ".".to_string(),
+        };
+        let git = PackageSource::Git {
+            url: "https://github.com/psf/requests".to_string(),
+            revision: None,
+        };
+        let registry = PackageSource::Registry {
+            url: "https://pypi.org/simple".to_string(),
+        };
+
+        assert!(path.is_local());
+        assert!(virtual_root.is_local());
+        assert!(!git.is_local());
+        assert!(!registry.is_local());
+
+        let package = Package::new("lib".to_string(), "0.1.0".to_string())
+            .unwrap()
+            .with_source(Some(path.clone()));
+        assert_eq!(package.source(), Some(&path));
+        assert!(package.has_local_source());
+    }
+
     #[test]
     fn test_package_name_display() {
         let name = PackageName::new("requests".to_string()).unwrap();
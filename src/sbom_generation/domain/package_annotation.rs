@@ -0,0 +1,62 @@
+use super::PackageName;
+use std::collections::BTreeMap;
+
+/// Free-form key/value annotations attached to a package by the project
+///
+/// Declared in `pyproject.toml` under `[tool.uv-sbom.annotations."<package>"]`,
+/// typically to record ownership or the justification for a dependency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageAnnotation {
+    package: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl PackageAnnotation {
+    pub fn new(package: String, fields: BTreeMap<String, String>) -> Self {
+        Self { package, fields }
+    }
+
+    /// The package name as written in `pyproject.toml`
+    pub fn package(&self) -> &str {
+        &self.package
+    }
+
+    /// Annotation fields, sorted by key
+    pub fn fields(&self) -> &BTreeMap<String, String> {
+        &self.fields
+    }
+
+    /// Returns true when this annotation targets `name` after PEP 503 normalization.
+    pub fn applies_to(&self, name: &str) -> bool {
+        PackageName::normalize(&self.package) == PackageName::normalize(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotation(package: &str) -> PackageAnnotation {
+        let fields = BTreeMap::from([("owner".to_string(), "team-data".to_string())]);
+        PackageAnnotation::new(package.to_string(), fields)
+    }
+
+    #[test]
+    fn test_applies_to_uses_pep503_normalization() {
+        let annotation = annotation("Typing_Extensions");
+        assert!(annotation.applies_to("typing-extensions"));
+        assert!(annotation.applies_to("typing.extensions"));
+        assert!(!annotation.applies_to("typing"));
+    }
+
+    #[test]
+    fn test_fields_are_sorted_by_key() {
+        let fields = BTreeMap::from([
+            ("owner".to_string(), "team-data".to_string()),
+            ("justification".to_string(), "needed for ETL".to_string()),
+        ]);
+        let annotation = PackageAnnotation::new("pandas".to_string(), fields);
+        let keys: Vec<&str> = annotation.fields().keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["justification", "owner"]);
+    }
+}
@@ -0,0 +1,86 @@
+/// Who wrote and who maintains a package, from its index metadata
+///
+/// Built from the `author`, `author_email` and `maintainer` fields of the
+/// PyPI JSON API (or the matching core metadata headers). Blank values and
+/// the `UNKNOWN` placeholder written by old setuptools releases are dropped,
+/// so a package that names nobody has no author and no supplier.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageAuthorship {
+    author: Option<String>,
+    author_email: Option<String>,
+    maintainer: Option<String>,
+}
+
+impl PackageAuthorship {
+    pub fn new(
+        author: Option<String>,
+        author_email: Option<String>,
+        maintainer: Option<String>,
+    ) -> Self {
+        Self {
+            author: meaningful(author),
+            author_email: meaningful(author_email),
+            maintainer: meaningful(maintainer),
+        }
+    }
+
+    /// The author to report for the package
+    ///
+    /// Falls back to `author_email` when no author name is given, since
+    /// projects using `pyproject.toml` often only fill in
+    /// `authors = [{ name = "...", email = "..." }]`, which PyPI reports as
+    /// `"Name <email>"` in `author_email`.
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref().or(self.author_email.as_deref())
+    }
+
+    /// The supplier to report for the package: the author, else the maintainer
+    pub fn supplier(&self) -> Option<&str> {
+        self.author().or(self.maintainer.as_deref())
+    }
+
+    #[allow(dead_code)] // Library API
+    pub fn maintainer(&self) -> Option<&str> {
+        self.maintainer.as_deref()
+    }
+}
+
+fn meaningful(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("UNKNOWN"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn some(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn test_supplier_prefers_author_then_maintainer() {
+        let authorship = PackageAuthorship::new(some("Kenneth Reitz"), None, some("PSF"));
+        assert_eq!(authorship.author(), Some("Kenneth Reitz"));
+        assert_eq!(authorship.supplier(), Some("Kenneth Reitz"));
+
+        let authorship = PackageAuthorship::new(None, None, some("PSF"));
+        assert_eq!(authorship.author(), None);
+        assert_eq!(authorship.supplier(), Some("PSF"));
+    }
+
+    #[test]
+    fn test_author_falls_back_to_author_email() {
+        let authorship = PackageAuthorship::new(None, some("Jane Doe <jane@example.com>"), None);
+        assert_eq!(authorship.author(), Some("Jane Doe <jane@example.com>"));
+        assert_eq!(authorship.supplier(), Some("Jane Doe <jane@example.com>"));
+    }
+
+    #[test]
+    fn test_blank_and_unknown_values_are_dropped() {
+        let authorship = PackageAuthorship::new(some("  "), some("UNKNOWN"), some(""));
+        assert_eq!(authorship, PackageAuthorship::default());
+        assert_eq!(authorship.supplier(), None);
+    }
+}
@@ -0,0 +1,193 @@
+/// Longest URL kept from package metadata
+const MAX_URL_LENGTH: usize = 2048;
+
+/// Kind of an external resource of a package, named after the CycloneDX
+/// external reference type it becomes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExternalRefType {
+    /// The project's homepage
+    Website,
+    /// The source code repository
+    Vcs,
+    /// The project's documentation
+    Documentation,
+}
+
+impl ExternalRefType {
+    /// Returns the CycloneDX external reference type, e.g. `vcs`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Website => "website",
+            Self::Vcs => "vcs",
+            Self::Documentation => "documentation",
+        }
+    }
+
+    /// Project URL labels of each kind, most specific first. Labels are
+    /// compared lowercased with everything but letters removed, so
+    /// `Source Code`, `source-code` and `SourceCode` are the same label.
+    fn labels(&self) -> &'static [&'static str] {
+        match self {
+            Self::Website => &["homepage", "home", "website"],
+            Self::Vcs => &[
+                "repository",
+                "source",
+                "sourcecode",
+                "code",
+                "github",
+                "gitlab",
+            ],
+            Self::Documentation => &["documentation", "docs"],
+        }
+    }
+}
+
+/// Links to a package's homepage, repository and documentation, from its
+/// index metadata
+///
+/// Built from the `project_urls` map of the PyPI JSON API (or the matching
+/// `Project-URL` core metadata headers), with `home_page` as a fallback for
+/// the homepage. At most one link of each kind is kept. Only `http` and
+/// `https` URLs of at most 2048 characters are accepted, so `javascript:`
+/// links and other garbage from malformed metadata never reach the SBOM.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackageLinks {
+    refs: Vec<(ExternalRefType, String)>,
+}
+
+impl PackageLinks {
+    /// Picks the links from labelled project URLs
+    pub fn new<'a>(
+        home_page: Option<&str>,
+        project_urls: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let project_urls: Vec<(String, &str)> = project_urls
+            .into_iter()
+            .map(|(label, url)| (normalize_label(label), url.trim()))
+            .filter(|(_, url)| is_acceptable_url(url))
+            .collect();
+
+        let mut refs = Vec::new();
+        for ref_type in [
+            ExternalRefType::Website,
+            ExternalRefType::Vcs,
+            ExternalRefType::Documentation,
+        ] {
+            let labelled = ref_type.labels().iter().find_map(|label| {
+                project_urls
+                    .iter()
+                    .find(|(candidate, _)| candidate == label)
+                    .map(|(_, url)| *url)
+            });
+            let url = match ref_type {
+                ExternalRefType::Website => labelled.or_else(|| {
+                    home_page
+                        .map(str::trim)
+                        .filter(|url| is_acceptable_url(url))
+                }),
+                _ => labelled,
+            };
+            if let Some(url) = url {
+                refs.push((ref_type, url.to_string()));
+            }
+        }
+        Self { refs }
+    }
+
+    /// Returns the links in the order website, vcs, documentation
+    pub fn refs(&self) -> &[(ExternalRefType, String)] {
+        &self.refs
+    }
+
+    #[allow(dead_code)] // Library API
+    pub fn is_empty(&self) -> bool {
+        self.refs.is_empty()
+    }
+}
+
+fn normalize_label(label: &str) -> String {
+    label
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Accepts absolute `http` and `https` URLs with a host, no whitespace or
+/// control characters, and at most [`MAX_URL_LENGTH`] characters
+fn is_acceptable_url(url: &str) -> bool {
+    if url.len() > MAX_URL_LENGTH || url.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return false;
+    }
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    (scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https"))
+        && !host.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_picks_one_link_of_each_kind() {
+        let links = PackageLinks::new(
+            Some("https://old.example.com"),
+            [
+                ("Documentation", "https://requests.readthedocs.io"),
+                ("Source", "https://github.com/psf/requests"),
+                ("Homepage", "https://requests.readthedocs.io/en/latest/"),
+                ("Changelog", "https://example.com/changes"),
+            ],
+        );
+
+        assert_eq!(
+            links.refs(),
+            [
+                (
+                    ExternalRefType::Website,
+                    "https://requests.readthedocs.io/en/latest/".to_string()
+                ),
+                (
+                    ExternalRefType::Vcs,
+                    "https://github.com/psf/requests".to_string()
+                ),
+                (
+                    ExternalRefType::Documentation,
+                    "https://requests.readthedocs.io".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_home_page_is_the_website_fallback() {
+        let links = PackageLinks::new(Some("http://six.example.com"), [("Source Code", "x")]);
+
+        assert_eq!(
+            links.refs(),
+            [(
+                ExternalRefType::Website,
+                "http://six.example.com".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_http_and_oversized_urls() {
+        let long = format!("https://example.com/{}", "a".repeat(MAX_URL_LENGTH));
+        let links = PackageLinks::new(
+            Some("javascript:alert(1)"),
+            [
+                ("Homepage", "javascript:alert(1)"),
+                ("Repository", long.as_str()),
+                ("Documentation", "https://exa mple.com"),
+                ("Docs", "https://"),
+            ],
+        );
+
+        assert!(links.is_empty());
+    }
+}
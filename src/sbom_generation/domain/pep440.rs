@@ -0,0 +1,180 @@
+//! PEP 440 version ordering
+//!
+//! Only ordering is implemented: versions are compared by epoch, release,
+//! pre-release, post-release and development release. Local version labels
+//! (`+ubuntu1`) are ignored.
+
+use std::cmp::Ordering;
+
+/// Compares two version strings in PEP 440 order
+///
+/// `1.0.dev1 < 1.0a1 < 1.0b1 < 1.0rc1 < 1.0 == 1.0.0 < 1.0.post1 < 1.0.1`.
+/// Strings that are not valid PEP 440 versions sort by plain string
+/// comparison, after every valid version.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    match (SortKey::parse(a), SortKey::parse(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.cmp(b),
+    }
+}
+
+/// Fields of a version in the order PEP 440 compares them
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct SortKey {
+    epoch: u64,
+    /// Release segments without trailing zeros, so `1.0` equals `1.0.0`
+    release: Vec<u64>,
+    /// `(0, 0)` for a development release of the final version, `(1..=3, n)`
+    /// for alpha, beta and release candidates, `(4, 0)` otherwise
+    pre: (u8, u64),
+    post: Option<u64>,
+    /// `(0, n)` for `.devN`, `(1, 0)` otherwise
+    dev: (u8, u64),
+}
+
+enum Label {
+    Pre(u8),
+    Post,
+    Dev,
+}
+
+/// Suffix spellings; longer spellings come first so that they win over their prefixes
+const LABELS: &[(&str, Label)] = &[
+    ("alpha", Label::Pre(1)),
+    ("beta", Label::Pre(2)),
+    ("preview", Label::Pre(3)),
+    ("pre", Label::Pre(3)),
+    ("rc", Label::Pre(3)),
+    ("a", Label::Pre(1)),
+    ("b", Label::Pre(2)),
+    ("c", Label::Pre(3)),
+    ("post", Label::Post),
+    ("rev", Label::Post),
+    ("r", Label::Post),
+    ("dev", Label::Dev),
+];
+
+impl SortKey {
+    fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().to_ascii_lowercase();
+        let version = version.strip_prefix('v').unwrap_or(&version);
+        let version = version.split('+').next()?;
+        let (epoch, rest) = match version.split_once('!') {
+            Some((epoch, rest)) => (epoch.parse().ok()?, rest),
+            None => (0, version),
+        };
+
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let mut release = rest[..end]
+            .trim_end_matches('.')
+            .split('.')
+            .map(|segment| segment.parse().ok())
+            .collect::<Option<Vec<u64>>>()?;
+        while release.last() == Some(&0) {
+            release.pop();
+        }
+
+        let (mut pre, mut post, mut dev) = (None, None, None);
+        let mut suffix = &rest[end..];
+        while !suffix.is_empty() {
+            // `1.0-1` is an implicit post-release
+            let implicit_post = suffix
+                .strip_prefix('-')
+                .is_some_and(|s| s.starts_with(|c: char| c.is_ascii_digit()));
+            suffix = suffix.trim_start_matches(['.', '-', '_']);
+            let (label, after) = if implicit_post {
+                (&Label::Post, suffix)
+            } else {
+                LABELS.iter().find_map(|(spelling, label)| {
+                    suffix.strip_prefix(spelling).map(|after| (label, after))
+                })?
+            };
+            let (number, after) = leading_number(after.trim_start_matches(['.', '-', '_']));
+            match label {
+                Label::Pre(rank) => pre = Some((*rank, number)),
+                Label::Post => post = Some(number),
+                Label::Dev => dev = Some(number),
+            }
+            suffix = after;
+        }
+
+        let pre = match (pre, post, dev) {
+            (Some(pre), _, _) => pre,
+            (None, None, Some(_)) => (0, 0),
+            _ => (4, 0),
+        };
+        Some(Self {
+            epoch,
+            release,
+            pre,
+            post,
+            dev: dev.map_or((1, 0), |n| (0, n)),
+        })
+    }
+}
+
+/// Splits off a leading decimal number, which defaults to 0 when absent (`1.0rc` is `1.0rc0`)
+fn leading_number(s: &str) -> (u64, &str) {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    (s[..end].parse().unwrap_or(0), &s[end..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versions_sort_in_pep440_order() {
+        let ordered = [
+            "1.0.dev1",
+            "1.0a1",
+            "1.0a2.dev1",
+            "1.0a2",
+            "1.0b1",
+            "1.0rc1",
+            "1.0",
+            "1.0.post1.dev1",
+            "1.0.post1",
+            "1.0.1",
+            "1.9",
+            "1.10",
+            "1!0.1",
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(
+                compare_versions(pair[0], pair[1]),
+                Ordering::Less,
+                "{} < {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_equivalent_spellings_compare_equal() {
+        assert_eq!(compare_versions("1.0", "1.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("v2.1", "2.1"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0RC1", "1.0rc1"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0-alpha.2", "1.0a2"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0-1", "1.0.post1"), Ordering::Equal);
+        assert_eq!(compare_versions("1.0+ubuntu1", "1.0"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_numeric_segments_compare_as_numbers() {
+        assert_eq!(compare_versions("1.26.19", "1.26.9"), Ordering::Greater);
+        assert_eq!(compare_versions("2024.10", "2024.9"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_invalid_versions_sort_last() {
+        assert_eq!(compare_versions("1.0", "latest"), Ordering::Less);
+        assert_eq!(compare_versions("nightly", "1.0"), Ordering::Greater);
+        assert_eq!(compare_versions("abc", "abd"), Ordering::Less);
+    }
+}
@@ -0,0 +1,284 @@
+//! `requires-python` specifier sets and the packages that do not support
+//! every Python version the project allows
+
+use super::pep440::compare_versions;
+use std::cmp::Ordering;
+
+/// One end of a version range
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bound {
+    version: String,
+    inclusive: bool,
+}
+
+impl Bound {
+    fn new(version: &str, inclusive: bool) -> Self {
+        Self {
+            version: version.to_string(),
+            inclusive,
+        }
+    }
+}
+
+/// The Python versions a `requires-python` specifier set allows
+///
+/// The comma-separated clauses are intersected into one range with a list of
+/// excluded versions. `>=`, `>`, `<=`, `<`, `==` and `!=` (both with `.*`
+/// wildcards) and `~=` are understood; `===` is not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PythonRequirement {
+    /// `None` means no lower limit
+    lower: Option<Bound>,
+    /// `None` means no upper limit
+    upper: Option<Bound>,
+    /// Ranges excluded with `!=`, as `(lower, upper)`; a single version has equal ends
+    excluded: Vec<(Bound, Bound)>,
+}
+
+impl PythonRequirement {
+    /// Parses a specifier set such as `>=3.9,<3.13,!=3.9.1`
+    ///
+    /// Returns `None` for operators or versions that are not understood. An
+    /// empty string allows every version.
+    pub fn parse(specifiers: &str) -> Option<Self> {
+        let mut requirement = Self::any();
+        for clause in specifiers
+            .split(',')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+        {
+            requirement = requirement.intersect(&Self::parse_clause(clause)?);
+        }
+        Some(requirement)
+    }
+
+    /// Returns true when every version `other` allows is also allowed here
+    pub fn covers(&self, other: &Self) -> bool {
+        let lower_ok = match (&self.lower, &other.lower) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(own), Some(theirs)) => match compare_versions(&theirs.version, &own.version) {
+                Ordering::Greater => true,
+                Ordering::Equal => own.inclusive || !theirs.inclusive,
+                Ordering::Less => false,
+            },
+        };
+        let upper_ok = match (&self.upper, &other.upper) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(own), Some(theirs)) => match compare_versions(&theirs.version, &own.version) {
+                Ordering::Less => true,
+                Ordering::Equal => own.inclusive || !theirs.inclusive,
+                Ordering::Greater => false,
+            },
+        };
+        lower_ok
+            && upper_ok
+            && self.excluded.iter().all(|(lower, upper)| {
+                let excluded = Self {
+                    lower: Some(lower.clone()),
+                    upper: Some(upper.clone()),
+                    excluded: Vec::new(),
+                };
+                other.excluded.contains(&(lower.clone(), upper.clone()))
+                    || other.intersect(&excluded).is_empty()
+            })
+    }
+
+    fn any() -> Self {
+        Self {
+            lower: None,
+            upper: None,
+            excluded: Vec::new(),
+        }
+    }
+
+    fn parse_clause(clause: &str) -> Option<Self> {
+        let split = clause.find(|c: char| c.is_ascii_digit())?;
+        let (operator, version) = (clause[..split].trim(), clause[split..].trim());
+        let mut requirement = Self::any();
+        match (operator, version.strip_suffix(".*")) {
+            (">=", None) => requirement.lower = Some(Bound::new(version, true)),
+            (">", None) => requirement.lower = Some(Bound::new(version, false)),
+            ("<=", None) => requirement.upper = Some(Bound::new(version, true)),
+            ("<", None) => requirement.upper = Some(Bound::new(version, false)),
+            ("==", None) => {
+                requirement.lower = Some(Bound::new(version, true));
+                requirement.upper = Some(Bound::new(version, true));
+            }
+            ("==", Some(prefix)) => {
+                requirement.lower = Some(Bound::new(prefix, true));
+                requirement.upper = Some(Bound::new(&next_release(prefix)?, false));
+            }
+            ("!=", None) => requirement
+                .excluded
+                .push((Bound::new(version, true), Bound::new(version, true))),
+            ("!=", Some(prefix)) => requirement.excluded.push((
+                Bound::new(prefix, true),
+                Bound::new(&next_release(prefix)?, false),
+            )),
+            ("~=", None) => {
+                // ~=3.8 means >=3.8,==3.*; ~=3.8.1 means >=3.8.1,==3.8.*
+                let (prefix, _) = version.rsplit_once('.')?;
+                requirement.lower = Some(Bound::new(version, true));
+                requirement.upper = Some(Bound::new(&next_release(prefix)?, false));
+            }
+            _ => return None,
+        }
+        Some(requirement)
+    }
+
+    /// The versions allowed by both requirements
+    fn intersect(&self, other: &Self) -> Self {
+        let lower = match (&self.lower, &other.lower) {
+            (Some(a), Some(b)) => Some(stricter(a, b, Ordering::Greater).clone()),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        let upper = match (&self.upper, &other.upper) {
+            (Some(a), Some(b)) => Some(stricter(a, b, Ordering::Less).clone()),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        let mut excluded = self.excluded.clone();
+        excluded.extend(
+            other
+                .excluded
+                .iter()
+                .filter(|e| !self.excluded.contains(e))
+                .cloned(),
+        );
+        Self {
+            lower,
+            upper,
+            excluded,
+        }
+    }
+
+    /// Returns true when the range allows no version at all
+    fn is_empty(&self) -> bool {
+        let (Some(lower), Some(upper)) = (&self.lower, &self.upper) else {
+            return false;
+        };
+        match compare_versions(&lower.version, &upper.version) {
+            Ordering::Greater => true,
+            // A single version, unless an exclusion covers it
+            Ordering::Equal => {
+                !(lower.inclusive && upper.inclusive)
+                    || self.excluded.iter().any(|(l, u)| {
+                        compare_versions(&l.version, &lower.version) != Ordering::Greater
+                            && match compare_versions(&lower.version, &u.version) {
+                                Ordering::Less => true,
+                                Ordering::Equal => u.inclusive,
+                                Ordering::Greater => false,
+                            }
+                    })
+            }
+            Ordering::Less => false,
+        }
+    }
+}
+
+/// Of two bounds, the one further in the `direction` order, or the exclusive
+/// one when both name the same version
+fn stricter<'a>(a: &'a Bound, b: &'a Bound, direction: Ordering) -> &'a Bound {
+    match compare_versions(&a.version, &b.version) {
+        Ordering::Equal if a.inclusive => b,
+        Ordering::Equal => a,
+        ordering if ordering == direction => a,
+        _ => b,
+    }
+}
+
+/// The first release after every version starting with `prefix`: `3.9` -> `3.10`
+fn next_release(prefix: &str) -> Option<String> {
+    let (head, last) = match prefix.rsplit_once('.') {
+        Some((head, last)) => (Some(head), last),
+        None => (None, prefix),
+    };
+    let next = last.parse::<u64>().ok()? + 1;
+    Some(match head {
+        Some(head) => format!("{}.{}", head, next),
+        None => next.to_string(),
+    })
+}
+
+/// A package whose `requires-python` excludes some Python versions the project allows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PythonIncompatibility {
+    pub package_name: String,
+    pub package_version: String,
+    /// The package's `requires-python`, as written in the lockfile
+    pub requires_python: String,
+}
+
+/// Result of the Python compatibility check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PythonCompatibilityReport {
+    /// The project's `requires-python`, as written in pyproject.toml
+    pub project_requires_python: String,
+    /// Packages that do not support every Python version the project allows,
+    /// sorted by name
+    pub incompatible: Vec<PythonIncompatibility>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req(specifiers: &str) -> PythonRequirement {
+        PythonRequirement::parse(specifiers).unwrap()
+    }
+
+    #[test]
+    fn test_lower_bounds() {
+        assert!(req(">=3.8").covers(&req(">=3.9")));
+        assert!(req(">=3.9").covers(&req(">=3.9.0")));
+        assert!(!req(">=3.10").covers(&req(">=3.9")));
+        assert!(req(">=3.8").covers(&req(">3.8")));
+        assert!(!req(">3.8").covers(&req(">=3.8")));
+        assert!(req("").covers(&req(">=3.9")));
+    }
+
+    #[test]
+    fn test_upper_bound_against_open_project_range() {
+        assert!(!req(">=3.8,<3.12").covers(&req(">=3.9")));
+        assert!(req(">=3.8,<3.12").covers(&req(">=3.9,<3.12")));
+        assert!(!req("<3.12").covers(&req(">=3.9,<=3.12")));
+        assert!(req("<=3.12").covers(&req(">=3.9,<3.12")));
+    }
+
+    #[test]
+    fn test_compatible_release() {
+        // ~=3.8 is >=3.8,<4; ~=3.8.1 is >=3.8.1,<3.9
+        assert!(req("~=3.8").covers(&req(">=3.9,<4")));
+        assert!(!req("~=3.8").covers(&req(">=3.9")));
+        assert!(req("~=3.8.1").covers(&req("==3.8.*,>=3.8.2")));
+        assert!(!req("~=3.8.1").covers(&req(">=3.8,<3.9")));
+        assert!(PythonRequirement::parse("~=3").is_none());
+    }
+
+    #[test]
+    fn test_wildcard_equality() {
+        assert!(req("==3.*").covers(&req(">=3.9,<4")));
+        assert!(!req("==3.9.*").covers(&req(">=3.9,<3.11")));
+        assert!(req(">=3.9,<3.10").covers(&req("==3.9.*")));
+        assert!(req("==3.11").covers(&req("==3.11.0")));
+    }
+
+    #[test]
+    fn test_exclusions() {
+        // A version the project allows is excluded
+        assert!(!req(">=3.8,!=3.9.1").covers(&req(">=3.9")));
+        assert!(!req("!=3.10.*").covers(&req(">=3.9,<3.12")));
+        // Excluded versions outside the project range do not matter
+        assert!(req(">=3.7,!=3.8.*").covers(&req(">=3.9")));
+        assert!(req("!=3.9.1").covers(&req(">=3.9,<4,!=3.9.1")));
+        assert!(req("!=3.12").covers(&req(">=3.9,<3.12")));
+    }
+
+    #[test]
+    fn test_unsupported_specifiers() {
+        assert!(PythonRequirement::parse("===3.9").is_none());
+        assert!(PythonRequirement::parse(">=three").is_none());
+        assert_eq!(req(" >= 3.9 , < 4 "), req(">=3.9,<4"));
+    }
+}
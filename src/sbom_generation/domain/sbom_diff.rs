@@ -0,0 +1,290 @@
+use super::PackageName;
+use std::collections::BTreeMap;
+
+/// A package as listed on one side of an SBOM comparison
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageEntry {
+    pub name: String,
+    pub version: String,
+    /// License expression or name; `None` when the source carries no license data
+    pub license: Option<String>,
+    /// Package URL; `None` when the source does not record one, as lockfiles do not
+    pub purl: Option<String>,
+}
+
+impl PackageEntry {
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            license: None,
+            purl: None,
+        }
+    }
+
+    pub fn with_license(mut self, license: Option<String>) -> Self {
+        self.license = license;
+        self
+    }
+
+    pub fn with_purl(mut self, purl: Option<String>) -> Self {
+        self.purl = purl;
+        self
+    }
+}
+
+/// A package whose version differs between the base and head SBOMs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionChange {
+    pub name: String,
+    pub old_version: String,
+    pub new_version: String,
+    pub old_license: Option<String>,
+    pub new_license: Option<String>,
+}
+
+/// A package with the same version on both sides whose license changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LicenseChange {
+    pub name: String,
+    pub version: String,
+    pub old_license: Option<String>,
+    pub new_license: Option<String>,
+}
+
+/// Difference between two SBOMs: added, removed and updated packages
+///
+/// Packages are matched by PEP 503 normalized name. A package whose version is
+/// unchanged but whose license differs is listed in `license_changed` rather than
+/// `updated`. Licenses are only compared when both sides carry license data, so a
+/// lockfile (which has none) compared with a generated SBOM reports no license
+/// changes. All lists are sorted by package name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SbomDiff {
+    added: Vec<PackageEntry>,
+    removed: Vec<PackageEntry>,
+    updated: Vec<VersionChange>,
+    license_changed: Vec<LicenseChange>,
+}
+
+impl SbomDiff {
+    /// Compares the packages of a base SBOM with those of a head SBOM
+    pub fn compare(base: &[PackageEntry], head: &[PackageEntry]) -> Self {
+        let base_by_name = group_by_name(base);
+        let head_by_name = group_by_name(head);
+        let mut diff = Self::default();
+
+        for (key, base_entries) in &base_by_name {
+            let Some(head_entries) = head_by_name.get(key) else {
+                diff.removed
+                    .extend(base_entries.iter().map(|e| (*e).clone()));
+                continue;
+            };
+            diff.compare_versions(base_entries, head_entries);
+        }
+        for (key, head_entries) in &head_by_name {
+            if !base_by_name.contains_key(key) {
+                diff.added.extend(head_entries.iter().map(|e| (*e).clone()));
+            }
+        }
+
+        diff
+    }
+
+    /// Compares the versions of one package present on both sides
+    ///
+    /// Lockfiles may list a package under several versions (e.g. for different
+    /// markers). Versions present on both sides are compared for license changes.
+    /// A single remaining version on each side is an update; any other leftovers
+    /// are reported as added or removed.
+    fn compare_versions(&mut self, base: &[&PackageEntry], head: &[&PackageEntry]) {
+        let mut base_only = Vec::new();
+        for old in base {
+            match head.iter().find(|new| new.version == old.version) {
+                Some(new) => {
+                    if license_changed(old, new) {
+                        self.license_changed.push(LicenseChange {
+                            name: new.name.clone(),
+                            version: new.version.clone(),
+                            old_license: old.license.clone(),
+                            new_license: new.license.clone(),
+                        });
+                    }
+                }
+                None => base_only.push(*old),
+            }
+        }
+        let head_only: Vec<&PackageEntry> = head
+            .iter()
+            .filter(|new| !base.iter().any(|old| old.version == new.version))
+            .copied()
+            .collect();
+
+        match (base_only.as_slice(), head_only.as_slice()) {
+            ([old], [new]) => self.updated.push(VersionChange {
+                name: new.name.clone(),
+                old_version: old.version.clone(),
+                new_version: new.version.clone(),
+                old_license: old.license.clone(),
+                new_license: new.license.clone(),
+            }),
+            _ => {
+                self.removed.extend(base_only.into_iter().cloned());
+                self.added.extend(head_only.into_iter().cloned());
+            }
+        }
+    }
+
+    pub fn added(&self) -> &[PackageEntry] {
+        &self.added
+    }
+
+    pub fn removed(&self) -> &[PackageEntry] {
+        &self.removed
+    }
+
+    pub fn updated(&self) -> &[VersionChange] {
+        &self.updated
+    }
+
+    pub fn license_changed(&self) -> &[LicenseChange] {
+        &self.license_changed
+    }
+
+    /// Returns true when both SBOMs list the same packages, versions and licenses
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.updated.is_empty()
+            && self.license_changed.is_empty()
+    }
+}
+
+/// Groups entries by normalized name; the map keeps the lists sorted by name
+fn group_by_name(entries: &[PackageEntry]) -> BTreeMap<String, Vec<&PackageEntry>> {
+    let mut grouped: BTreeMap<String, Vec<&PackageEntry>> = BTreeMap::new();
+    for entry in entries {
+        grouped
+            .entry(PackageName::normalize(&entry.name))
+            .or_default()
+            .push(entry);
+    }
+    grouped
+}
+
+fn license_changed(old: &PackageEntry, new: &PackageEntry) -> bool {
+    match (&old.license, &new.license) {
+        (Some(old), Some(new)) => old != new,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, version: &str) -> PackageEntry {
+        PackageEntry::new(name, version)
+    }
+
+    fn licensed(name: &str, version: &str, license: &str) -> PackageEntry {
+        entry(name, version).with_license(Some(license.to_string()))
+    }
+
+    #[test]
+    fn test_compare_added_removed_updated() {
+        let base = vec![
+            entry("requests", "2.31.0"),
+            entry("urllib3", "1.26.5"),
+            entry("chardet", "5.2.0"),
+        ];
+        let head = vec![
+            entry("requests", "2.32.3"),
+            entry("urllib3", "1.26.5"),
+            entry("idna", "3.7"),
+        ];
+
+        let diff = SbomDiff::compare(&base, &head);
+
+        assert_eq!(diff.added(), &[entry("idna", "3.7")]);
+        assert_eq!(diff.removed(), &[entry("chardet", "5.2.0")]);
+        assert_eq!(diff.updated().len(), 1);
+        assert_eq!(diff.updated()[0].name, "requests");
+        assert_eq!(diff.updated()[0].old_version, "2.31.0");
+        assert_eq!(diff.updated()[0].new_version, "2.32.3");
+        assert!(diff.license_changed().is_empty());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_compare_identical_is_empty() {
+        let packages = vec![licensed("requests", "2.31.0", "Apache-2.0")];
+        assert!(SbomDiff::compare(&packages, &packages).is_empty());
+    }
+
+    #[test]
+    fn test_compare_matches_normalized_names() {
+        let base = vec![entry("Charset_Normalizer", "3.3.2")];
+        let head = vec![entry("charset-normalizer", "3.3.2")];
+        assert!(SbomDiff::compare(&base, &head).is_empty());
+    }
+
+    #[test]
+    fn test_compare_license_only_change_is_reported_separately() {
+        let base = vec![
+            licensed("pyyaml", "6.0.1", "MIT"),
+            licensed("requests", "2.31.0", "Apache-2.0"),
+        ];
+        let head = vec![
+            licensed("pyyaml", "6.0.1", "MIT OR Apache-2.0"),
+            licensed("requests", "2.32.3", "Apache-2.0"),
+        ];
+
+        let diff = SbomDiff::compare(&base, &head);
+
+        assert_eq!(
+            diff.license_changed(),
+            &[LicenseChange {
+                name: "pyyaml".to_string(),
+                version: "6.0.1".to_string(),
+                old_license: Some("MIT".to_string()),
+                new_license: Some("MIT OR Apache-2.0".to_string()),
+            }]
+        );
+        assert_eq!(diff.updated().len(), 1);
+        assert_eq!(diff.updated()[0].name, "requests");
+    }
+
+    #[test]
+    fn test_compare_missing_license_is_not_a_change() {
+        let base = vec![entry("requests", "2.31.0")];
+        let head = vec![licensed("requests", "2.31.0", "Apache-2.0")];
+        assert!(SbomDiff::compare(&base, &head).is_empty());
+    }
+
+    #[test]
+    fn test_compare_multiple_versions_of_one_package() {
+        let base = vec![entry("numpy", "1.24.4"), entry("numpy", "1.26.4")];
+        let head = vec![entry("numpy", "1.26.4"), entry("numpy", "2.0.0")];
+
+        let diff = SbomDiff::compare(&base, &head);
+
+        assert_eq!(diff.updated().len(), 1);
+        assert_eq!(diff.updated()[0].old_version, "1.24.4");
+        assert_eq!(diff.updated()[0].new_version, "2.0.0");
+
+        let head = vec![
+            entry("numpy", "1.26.4"),
+            entry("numpy", "2.0.0"),
+            entry("numpy", "2.1.0"),
+        ];
+        let diff = SbomDiff::compare(&base, &head);
+        assert!(diff.updated().is_empty());
+        assert_eq!(diff.removed(), &[entry("numpy", "1.24.4")]);
+        assert_eq!(
+            diff.added(),
+            &[entry("numpy", "2.0.0"), entry("numpy", "2.1.0")]
+        );
+    }
+}
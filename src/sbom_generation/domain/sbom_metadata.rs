@@ -1,3 +1,5 @@
+use super::InvocationParameters;
+
 /// SbomMetadata value object representing SBOM metadata
 #[derive(Debug, Clone)]
 pub struct SbomMetadata {
@@ -5,6 +7,9 @@ pub struct SbomMetadata {
     tool_name: String,
     tool_version: String,
     serial_number: String,
+    invocation: Option<InvocationParameters>,
+    lockfile_version: Option<String>,
+    lockfile_requires_python: Option<String>,
 }
 
 impl SbomMetadata {
@@ -19,9 +24,30 @@ impl SbomMetadata {
             tool_name,
             tool_version,
             serial_number,
+            invocation: None,
+            lockfile_version: None,
+            lockfile_requires_python: None,
         }
     }
 
+    /// Records the options the SBOM was generated with
+    pub fn with_invocation(mut self, invocation: Option<InvocationParameters>) -> Self {
+        self.invocation = invocation;
+        self
+    }
+
+    /// Records the format version and `requires-python` of the lockfile the
+    /// SBOM was generated from
+    pub fn with_lockfile_format(
+        mut self,
+        version: Option<String>,
+        requires_python: Option<String>,
+    ) -> Self {
+        self.lockfile_version = version;
+        self.lockfile_requires_python = requires_python;
+        self
+    }
+
     pub fn timestamp(&self) -> &str {
         &self.timestamp
     }
@@ -37,6 +63,18 @@ impl SbomMetadata {
     pub fn serial_number(&self) -> &str {
         &self.serial_number
     }
+
+    pub fn invocation(&self) -> Option<&InvocationParameters> {
+        self.invocation.as_ref()
+    }
+
+    pub fn lockfile_version(&self) -> Option<&str> {
+        self.lockfile_version.as_deref()
+    }
+
+    pub fn lockfile_requires_python(&self) -> Option<&str> {
+        self.lockfile_requires_python.as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -56,5 +94,20 @@ mod tests {
         assert_eq!(metadata.tool_name(), "uv-sbom");
         assert_eq!(metadata.tool_version(), "0.1.0");
         assert_eq!(metadata.serial_number(), "urn:uuid:12345");
+        assert_eq!(metadata.lockfile_version(), None);
+    }
+
+    #[test]
+    fn test_with_lockfile_format() {
+        let metadata = SbomMetadata::new(
+            "2024-01-01T00:00:00Z".to_string(),
+            "uv-sbom".to_string(),
+            "0.1.0".to_string(),
+            "urn:uuid:12345".to_string(),
+        )
+        .with_lockfile_format(Some("1".to_string()), Some(">=3.12".to_string()));
+
+        assert_eq!(metadata.lockfile_version(), Some("1"));
+        assert_eq!(metadata.lockfile_requires_python(), Some(">=3.12"));
     }
 }
@@ -0,0 +1,170 @@
+use super::super::vulnerability::Vulnerability;
+
+/// Domain service for collapsing advisories that describe the same flaw
+///
+/// Advisory databases publish one flaw under several IDs (a CVE, a GHSA, a
+/// PYSEC entry, ...) and cross-reference them as aliases, so a single lookup
+/// can return the same issue two or three times.
+pub struct AliasDeduplicator;
+
+impl AliasDeduplicator {
+    /// Merges the vulnerabilities of one package that share an ID or alias
+    ///
+    /// Each group is reduced to a single entry under its canonical ID: a CVE ID
+    /// if the group has one, otherwise a GHSA ID, otherwise the first ID seen.
+    /// Groups keep the order in which they were first seen.
+    ///
+    /// # Arguments
+    /// * `vulnerabilities` - Vulnerabilities reported for a single package
+    ///
+    /// # Returns
+    /// One vulnerability per alias group, listing the group's other IDs as aliases
+    pub fn apply(vulnerabilities: Vec<Vulnerability>) -> Vec<Vulnerability> {
+        let mut groups: Vec<Vec<Vulnerability>> = Vec::new();
+
+        for vuln in vulnerabilities {
+            // A new entry can link groups that did not share an ID before
+            let mut group = Vec::new();
+            let mut position = None;
+            let mut index = 0;
+            while index < groups.len() {
+                if groups[index].iter().any(|member| shares_id(member, &vuln)) {
+                    position.get_or_insert(index);
+                    group.append(&mut groups.remove(index));
+                } else {
+                    index += 1;
+                }
+            }
+            group.push(vuln);
+            groups.insert(position.unwrap_or(groups.len()), group);
+        }
+
+        groups.into_iter().map(merge_group).collect()
+    }
+}
+
+fn shares_id(a: &Vulnerability, b: &Vulnerability) -> bool {
+    a.all_ids().any(|id| b.all_ids().any(|other| other == id))
+}
+
+/// Folds a group into the entry published under its canonical ID, if any
+fn merge_group(mut group: Vec<Vulnerability>) -> Vulnerability {
+    let canonical = canonical_id(&group);
+    let primary = group
+        .iter()
+        .position(|vuln| vuln.id() == canonical)
+        .unwrap_or(0);
+    let primary = group.remove(primary);
+    primary.merged(&canonical, group)
+}
+
+/// Picks a CVE ID, then a GHSA ID, then the first member's ID
+fn canonical_id(group: &[Vulnerability]) -> String {
+    let mut ids: Vec<&str> = group.iter().flat_map(Vulnerability::all_ids).collect();
+    ids.sort_unstable();
+    ids.iter()
+        .find(|id| id.starts_with("CVE-"))
+        .or_else(|| ids.iter().find(|id| id.starts_with("GHSA-")))
+        .copied()
+        .unwrap_or_else(|| group[0].id())
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::vulnerability::{CvssScore, Severity};
+
+    fn make_vuln(id: &str, aliases: &[&str]) -> Vulnerability {
+        Vulnerability::new(id.to_string(), None, Severity::None, None, None)
+            .unwrap()
+            .with_aliases(aliases.iter().map(|a| a.to_string()).collect())
+    }
+
+    #[test]
+    fn test_unrelated_vulnerabilities_are_kept() {
+        let result = AliasDeduplicator::apply(vec![
+            make_vuln("CVE-2024-0001", &[]),
+            make_vuln("CVE-2024-0002", &[]),
+        ]);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].id(), "CVE-2024-0001");
+        assert!(result[0].aliases().is_empty());
+        assert_eq!(result[1].id(), "CVE-2024-0002");
+    }
+
+    #[test]
+    fn test_cve_is_preferred_as_canonical_id() {
+        let result = AliasDeduplicator::apply(vec![
+            make_vuln("GHSA-aaaa-bbbb-cccc", &["CVE-2024-0001", "PYSEC-2024-1"]),
+            make_vuln("PYSEC-2024-1", &["CVE-2024-0001"]),
+        ]);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id(), "CVE-2024-0001");
+        assert_eq!(
+            result[0].aliases(),
+            &[
+                "GHSA-aaaa-bbbb-cccc".to_string(),
+                "PYSEC-2024-1".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ghsa_is_preferred_without_cve() {
+        let result = AliasDeduplicator::apply(vec![
+            make_vuln("PYSEC-2024-1", &["GHSA-aaaa-bbbb-cccc"]),
+            make_vuln("GHSA-aaaa-bbbb-cccc", &["PYSEC-2024-1"]),
+        ]);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id(), "GHSA-aaaa-bbbb-cccc");
+        assert_eq!(result[0].aliases(), &["PYSEC-2024-1".to_string()]);
+    }
+
+    #[test]
+    fn test_entry_linking_two_groups_merges_them() {
+        let result = AliasDeduplicator::apply(vec![
+            make_vuln("PYSEC-2024-1", &[]),
+            make_vuln("CVE-2024-0009", &[]),
+            make_vuln("GHSA-aaaa-bbbb-cccc", &[]),
+            make_vuln("OSV-2024-1", &["PYSEC-2024-1", "GHSA-aaaa-bbbb-cccc"]),
+        ]);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].id(), "GHSA-aaaa-bbbb-cccc");
+        assert_eq!(result[1].id(), "CVE-2024-0009");
+    }
+
+    #[test]
+    fn test_canonical_entry_details_are_kept_and_gaps_filled() {
+        let score = CvssScore::new(8.1).unwrap();
+        let ghsa = Vulnerability::new(
+            "GHSA-aaaa-bbbb-cccc".to_string(),
+            Some(score),
+            Severity::High,
+            Some("2.0.0".to_string()),
+            None,
+        )
+        .unwrap()
+        .with_aliases(vec!["CVE-2024-0001".to_string()])
+        .with_source_url(Some(
+            "https://github.com/advisories/GHSA-aaaa-bbbb-cccc".to_string(),
+        ));
+        let cve = make_vuln("CVE-2024-0001", &["GHSA-aaaa-bbbb-cccc"]);
+
+        let result = AliasDeduplicator::apply(vec![ghsa, cve]);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id(), "CVE-2024-0001");
+        assert_eq!(result[0].cvss_score(), Some(score));
+        assert_eq!(result[0].severity(), Severity::High);
+        assert_eq!(result[0].fixed_version(), Some("2.0.0"));
+        assert_eq!(
+            result[0].source_url(),
+            Some("https://github.com/advisories/GHSA-aaaa-bbbb-cccc")
+        );
+    }
+}
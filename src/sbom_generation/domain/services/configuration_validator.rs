@@ -0,0 +1,148 @@
+/// Advisory ID prefixes the vulnerability sources report; ignore-list entries are
+/// matched against them exactly
+const KNOWN_ADVISORY_PREFIXES: [&str; 3] = ["CVE-", "GHSA-", "PYSEC-"];
+
+/// A problem found while validating the configuration in dry-run mode
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    /// The CVSS threshold is outside 0.0-10.0, so it can never be compared meaningfully
+    CvssThresholdOutOfRange { value: f32 },
+    /// An ignore-list entry does not look like a CVE, GHSA or PYSEC ID and will
+    /// likely never match an advisory
+    UnrecognizedIgnoreId { id: String },
+    /// A network endpoint the run depends on could not be reached
+    EndpointUnreachable { endpoint: String, error: String },
+}
+
+impl ValidationIssue {
+    /// Returns true for issues that make the configuration unusable
+    pub fn is_error(&self) -> bool {
+        match self {
+            ValidationIssue::CvssThresholdOutOfRange { .. }
+            | ValidationIssue::EndpointUnreachable { .. } => true,
+            ValidationIssue::UnrecognizedIgnoreId { .. } => false,
+        }
+    }
+}
+
+/// Overall verdict of a dry-run validation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationOutcome {
+    Valid,
+    /// Usable, but some settings probably do not do what was intended
+    ValidWithWarnings,
+    Invalid,
+}
+
+/// Findings of a dry-run validation, in the order they were found
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+    /// Endpoints probed for connectivity, reachable or not
+    pub probed_endpoints: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn outcome(&self) -> ValidationOutcome {
+        if self.issues.iter().any(ValidationIssue::is_error) {
+            ValidationOutcome::Invalid
+        } else if self.issues.is_empty() {
+            ValidationOutcome::Valid
+        } else {
+            ValidationOutcome::ValidWithWarnings
+        }
+    }
+}
+
+/// Stateless domain service checking option values that parse but cannot work
+pub struct ConfigurationValidator;
+
+impl ConfigurationValidator {
+    /// Checks the CVSS threshold range and the format of the ignore-list IDs
+    ///
+    /// # Arguments
+    /// * `cvss_threshold` - The CVSS threshold, if any
+    /// * `ignore_ids` - IDs on the ignore list
+    pub fn validate<'a>(
+        cvss_threshold: Option<f32>,
+        ignore_ids: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if let Some(value) = cvss_threshold {
+            if !(0.0..=10.0).contains(&value) {
+                issues.push(ValidationIssue::CvssThresholdOutOfRange { value });
+            }
+        }
+        issues.extend(
+            ignore_ids
+                .into_iter()
+                .filter(|id| {
+                    !KNOWN_ADVISORY_PREFIXES
+                        .iter()
+                        .any(|prefix| id.starts_with(prefix))
+                })
+                .map(|id| ValidationIssue::UnrecognizedIgnoreId { id: id.to_string() }),
+        );
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_configuration() {
+        let issues = ConfigurationValidator::validate(
+            Some(7.0),
+            ["CVE-2024-1234", "GHSA-j8r2-6x86-q33q", "PYSEC-2023-74"],
+        );
+
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_cvss_threshold_out_of_range() {
+        assert_eq!(
+            ConfigurationValidator::validate(Some(10.5), []),
+            [ValidationIssue::CvssThresholdOutOfRange { value: 10.5 }]
+        );
+        assert_eq!(ConfigurationValidator::validate(Some(-1.0), []).len(), 1);
+        assert!(ConfigurationValidator::validate(Some(0.0), []).is_empty());
+        assert!(ConfigurationValidator::validate(Some(10.0), []).is_empty());
+    }
+
+    #[test]
+    fn test_unrecognized_ignore_ids() {
+        // Ignore entries are matched case-sensitively, so a lowercase ID never matches
+        let issues =
+            ConfigurationValidator::validate(None, ["cve-2024-1234", "CVE-2024-1", "OSV-42"]);
+
+        let ids: Vec<&str> = issues
+            .iter()
+            .map(|issue| match issue {
+                ValidationIssue::UnrecognizedIgnoreId { id } => id.as_str(),
+                _ => panic!("unexpected issue {issue:?}"),
+            })
+            .collect();
+        assert_eq!(ids, ["cve-2024-1234", "OSV-42"]);
+    }
+
+    #[test]
+    fn test_report_outcome() {
+        let warning = ValidationIssue::UnrecognizedIgnoreId {
+            id: "OSV-42".to_string(),
+        };
+        let error = ValidationIssue::EndpointUnreachable {
+            endpoint: "https://pypi.org".to_string(),
+            error: "connection refused".to_string(),
+        };
+
+        let mut report = ValidationReport::default();
+        assert_eq!(report.outcome(), ValidationOutcome::Valid);
+        report.issues.push(warning);
+        assert_eq!(report.outcome(), ValidationOutcome::ValidWithWarnings);
+        report.issues.push(error);
+        assert_eq!(report.outcome(), ValidationOutcome::Invalid);
+    }
+}
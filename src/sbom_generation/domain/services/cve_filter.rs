@@ -1,4 +1,5 @@
 use super::super::vulnerability::PackageVulnerabilities;
+use super::vulnerability_checker::IgnoredVulnerability;
 use crate::config::IgnoreCve;
 
 /// Domain service for filtering ignored CVEs from vulnerability results
@@ -7,51 +8,46 @@ pub struct CveFilter;
 impl CveFilter {
     /// Filters out ignored CVEs from vulnerability results
     ///
-    /// Removes vulnerabilities whose IDs match the ignore list (exact, case-sensitive).
-    /// Logs each ignored CVE to stderr for transparency.
+    /// Removes vulnerabilities whose ID or any alias matches the ignore list (exact,
+    /// case-sensitive), so ignoring one ID of an advisory ignores the whole alias group.
+    /// The removed entries are returned so that the caller can report them.
     ///
     /// # Arguments
     /// * `vulnerabilities` - List of package vulnerabilities to filter
     /// * `ignore_cves` - List of CVE entries to ignore
     ///
     /// # Returns
-    /// Filtered list with ignored CVEs removed (packages with no remaining vulns are dropped)
+    /// Filtered list with ignored CVEs removed (packages with no remaining vulns are dropped),
+    /// and the removed vulnerabilities with the state and reason from their ignore entry
     pub fn apply(
         vulnerabilities: Vec<PackageVulnerabilities>,
         ignore_cves: &[IgnoreCve],
-    ) -> Vec<PackageVulnerabilities> {
+    ) -> (Vec<PackageVulnerabilities>, Vec<IgnoredVulnerability>) {
         if ignore_cves.is_empty() {
-            return vulnerabilities;
+            return (vulnerabilities, Vec::new());
         }
 
-        let ignore_ids: std::collections::HashSet<&str> =
-            ignore_cves.iter().map(|c| c.id.as_str()).collect();
+        let mut ignore_by_id: std::collections::HashMap<&str, &IgnoreCve> =
+            std::collections::HashMap::new();
+        for entry in ignore_cves {
+            ignore_by_id.entry(entry.id.as_str()).or_insert(entry);
+        }
 
         let mut result = Vec::new();
+        let mut ignored = Vec::new();
 
         for pkg_vulns in vulnerabilities {
             let mut kept = Vec::new();
 
             for vuln in pkg_vulns.vulnerabilities() {
-                if ignore_ids.contains(vuln.id()) {
-                    let reason = ignore_cves
-                        .iter()
-                        .find(|c| c.id == vuln.id())
-                        .and_then(|c| c.reason());
-
-                    match reason {
-                        Some(r) => eprintln!(
-                            "⚠ Ignored {} for package {} (reason: {})",
-                            vuln.id(),
-                            pkg_vulns.package_name(),
-                            r
-                        ),
-                        None => eprintln!(
-                            "⚠ Ignored {} for package {} (no reason provided)",
-                            vuln.id(),
-                            pkg_vulns.package_name()
-                        ),
-                    }
+                if let Some(entry) = vuln.all_ids().find_map(|id| ignore_by_id.get(id)) {
+                    ignored.push(IgnoredVulnerability {
+                        package_name: pkg_vulns.package_name().to_string(),
+                        package_version: pkg_vulns.current_version().to_string(),
+                        vulnerability: vuln.clone(),
+                        state: entry.state(),
+                        reason: entry.reason().map(str::to_string),
+                    });
                 } else {
                     kept.push(vuln.clone());
                 }
@@ -66,7 +62,7 @@ impl CveFilter {
             }
         }
 
-        result
+        (result, ignored)
     }
 }
 
@@ -90,6 +86,7 @@ mod tests {
         IgnoreCve {
             id: id.to_string(),
             reason: None,
+            state: None,
         }
     }
 
@@ -97,13 +94,14 @@ mod tests {
         IgnoreCve {
             id: id.to_string(),
             reason: Some(reason.to_string()),
+            state: None,
         }
     }
 
     #[test]
     fn test_empty_ignore_list_returns_input_unchanged() {
         let pkg = make_pkg("pkg-a", vec![make_vuln("CVE-2024-001")]);
-        let result = CveFilter::apply(vec![pkg], &[]);
+        let (result, _) = CveFilter::apply(vec![pkg], &[]);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].vulnerabilities().len(), 1);
@@ -116,7 +114,7 @@ mod tests {
             "pkg-a",
             vec![make_vuln("CVE-2024-001"), make_vuln("CVE-2024-002")],
         );
-        let result = CveFilter::apply(vec![pkg], &[ignore("CVE-2024-001")]);
+        let (result, _) = CveFilter::apply(vec![pkg], &[ignore("CVE-2024-001")]);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].vulnerabilities().len(), 1);
@@ -126,7 +124,7 @@ mod tests {
     #[test]
     fn test_non_matching_cve_is_kept() {
         let pkg = make_pkg("pkg-a", vec![make_vuln("CVE-2024-001")]);
-        let result = CveFilter::apply(vec![pkg], &[ignore("CVE-9999-999")]);
+        let (result, _) = CveFilter::apply(vec![pkg], &[ignore("CVE-9999-999")]);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].vulnerabilities()[0].id(), "CVE-2024-001");
@@ -136,7 +134,7 @@ mod tests {
     fn test_matching_is_case_sensitive() {
         let pkg = make_pkg("pkg-a", vec![make_vuln("CVE-2024-001")]);
         // Lowercase should NOT match
-        let result = CveFilter::apply(vec![pkg], &[ignore("cve-2024-001")]);
+        let (result, _) = CveFilter::apply(vec![pkg], &[ignore("cve-2024-001")]);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].vulnerabilities()[0].id(), "CVE-2024-001");
@@ -148,7 +146,8 @@ mod tests {
             "pkg-a",
             vec![make_vuln("CVE-2024-001"), make_vuln("CVE-2024-002")],
         );
-        let result = CveFilter::apply(vec![pkg], &[ignore("CVE-2024-001"), ignore("CVE-2024-002")]);
+        let (result, _) =
+            CveFilter::apply(vec![pkg], &[ignore("CVE-2024-001"), ignore("CVE-2024-002")]);
 
         assert!(result.is_empty());
     }
@@ -160,7 +159,7 @@ mod tests {
             "pkg-b",
             vec![make_vuln("CVE-2024-001"), make_vuln("CVE-2024-002")],
         );
-        let result = CveFilter::apply(vec![pkg1, pkg2], &[ignore("CVE-2024-001")]);
+        let (result, _) = CveFilter::apply(vec![pkg1, pkg2], &[ignore("CVE-2024-001")]);
 
         // pkg-a had only CVE-2024-001 → dropped entirely
         // pkg-b retains CVE-2024-002
@@ -172,7 +171,7 @@ mod tests {
     #[test]
     fn test_ignore_with_reason_does_not_panic() {
         let pkg = make_pkg("pkg-a", vec![make_vuln("CVE-2024-001")]);
-        let result = CveFilter::apply(
+        let (result, _) = CveFilter::apply(
             vec![pkg],
             &[ignore_with_reason("CVE-2024-001", "False positive")],
         );
@@ -183,8 +182,23 @@ mod tests {
     #[test]
     fn test_ignore_without_reason_does_not_panic() {
         let pkg = make_pkg("pkg-a", vec![make_vuln("CVE-2024-001")]);
-        let result = CveFilter::apply(vec![pkg], &[ignore("CVE-2024-001")]);
+        let (result, _) = CveFilter::apply(vec![pkg], &[ignore("CVE-2024-001")]);
 
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_ignoring_an_alias_ignores_the_whole_group() {
+        let vuln = make_vuln("CVE-2024-001").with_aliases(vec![
+            "GHSA-aaaa-bbbb-cccc".to_string(),
+            "PYSEC-2024-1".to_string(),
+        ]);
+        let pkg = make_pkg("pkg-a", vec![vuln, make_vuln("CVE-2024-002")]);
+        let (result, ignored) = CveFilter::apply(vec![pkg], &[ignore("GHSA-aaaa-bbbb-cccc")]);
+
+        assert_eq!(result[0].vulnerabilities().len(), 1);
+        assert_eq!(result[0].vulnerabilities()[0].id(), "CVE-2024-002");
+        assert_eq!(ignored.len(), 1);
+        assert_eq!(ignored[0].vulnerability.id(), "CVE-2024-001");
+    }
 }
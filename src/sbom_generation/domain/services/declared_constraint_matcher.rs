@@ -0,0 +1,97 @@
+use super::super::package::PackageName;
+use super::lockfile_consistency_checker::requirement_name;
+use std::collections::HashMap;
+
+/// Domain service for matching packages to the requirements pyproject.toml declares
+///
+/// The SBOM lists resolved versions; the declared requirement (e.g.
+/// `requests>=2.28,<3`) shows how much room the project leaves for updates.
+/// Requirements are kept as written, extras and environment markers included.
+pub struct DeclaredConstraintMatcher;
+
+impl DeclaredConstraintMatcher {
+    /// Indexes the declared requirements by PEP 503 normalized package name
+    ///
+    /// # Arguments
+    /// * `project_name` - Name of the project, if known; requirements on the project
+    ///   itself (e.g. `my-app[extra]` inside an extra) are ignored
+    /// * `requirements` - PEP 508 requirement strings from every dependency list
+    ///   of pyproject.toml (runtime, extras and groups)
+    ///
+    /// # Returns
+    /// A map from normalized name to requirement. A package declared in several
+    /// lists with different requirements gets them joined with ` | `, in
+    /// declaration order.
+    pub fn index(project_name: Option<&str>, requirements: &[String]) -> HashMap<String, String> {
+        let project = project_name.map(PackageName::normalize);
+        let mut declared: HashMap<String, Vec<&str>> = HashMap::new();
+        for requirement in requirements {
+            let requirement = requirement.trim();
+            let Some(name) = requirement_name(requirement) else {
+                continue;
+            };
+            let normalized = PackageName::normalize(name);
+            if project.as_ref() == Some(&normalized) {
+                continue;
+            }
+            let entry = declared.entry(normalized).or_default();
+            if !entry.contains(&requirement) {
+                entry.push(requirement);
+            }
+        }
+
+        declared
+            .into_iter()
+            .map(|(name, requirements)| (name, requirements.join(" | ")))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requirements(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_requirements_are_kept_verbatim() {
+        let index = DeclaredConstraintMatcher::index(
+            Some("my-app"),
+            &requirements(&[
+                "requests[socks]>=2.28,<3; python_version >= \"3.9\"",
+                "  Typing_Extensions  ",
+                "httpx",
+            ]),
+        );
+
+        assert_eq!(
+            index["requests"],
+            "requests[socks]>=2.28,<3; python_version >= \"3.9\""
+        );
+        assert_eq!(index["typing-extensions"], "Typing_Extensions");
+        assert_eq!(index["httpx"], "httpx");
+    }
+
+    #[test]
+    fn test_repeated_declarations_are_joined() {
+        let index = DeclaredConstraintMatcher::index(
+            None,
+            &requirements(&["pytest>=8", "pytest>=7; extra == 'test'", "pytest>=8"]),
+        );
+
+        assert_eq!(index["pytest"], "pytest>=8 | pytest>=7; extra == 'test'");
+    }
+
+    #[test]
+    fn test_project_self_reference_is_ignored() {
+        let index = DeclaredConstraintMatcher::index(
+            Some("My_App"),
+            &requirements(&["my-app[cli]", "click>=8"]),
+        );
+
+        assert_eq!(index.len(), 1);
+        assert_eq!(index["click"], "click>=8");
+    }
+}
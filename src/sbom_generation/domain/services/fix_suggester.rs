@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+
+use crate::sbom_generation::domain::dependency_graph::DependencyGraph;
+use crate::sbom_generation::domain::fix_suggestion::FixSuggestion;
+use crate::sbom_generation::domain::pep440;
+use crate::sbom_generation::domain::vulnerability::{PackageVulnerabilities, Vulnerability};
+
+/// Stateless domain service turning vulnerability findings into upgrade
+/// suggestions, using only the fixed versions reported by OSV and the
+/// dependency graph (no resolver run is needed).
+pub struct FixSuggester;
+
+impl FixSuggester {
+    /// Builds one suggestion per vulnerable package, sorted by package name
+    ///
+    /// # Algorithm
+    /// 1. Group the advisories by package (a package can be listed more than once)
+    /// 2. Take the highest fixed version in PEP 440 order: the lowest version
+    ///    that fixes every advisory with a known fix
+    /// 3. Keep the advisories without a fixed version apart, as "no fix available yet"
+    /// 4. For a transitive package, list the direct dependencies that pull it in
+    pub fn suggest(
+        vulnerabilities: &[PackageVulnerabilities],
+        dependency_graph: &DependencyGraph,
+    ) -> Vec<FixSuggestion> {
+        let mut by_package: BTreeMap<(&str, &str), Vec<&Vulnerability>> = BTreeMap::new();
+        for package in vulnerabilities {
+            by_package
+                .entry((package.package_name(), package.current_version()))
+                .or_default()
+                .extend(package.vulnerabilities());
+        }
+
+        by_package
+            .into_iter()
+            .map(|((name, version), advisories)| {
+                let fixed_version = advisories
+                    .iter()
+                    .filter_map(|v| v.fixed_version())
+                    .max_by(|a, b| pep440::compare_versions(a, b))
+                    .map(str::to_string);
+                let fixed_ids =
+                    sorted_ids(advisories.iter().filter(|v| v.fixed_version().is_some()));
+                let unfixed_ids =
+                    sorted_ids(advisories.iter().filter(|v| v.fixed_version().is_none()));
+
+                let is_direct = dependency_graph
+                    .direct_dependencies()
+                    .iter()
+                    .any(|dep| dep.as_str() == name);
+                let mut introduced_by: Vec<String> = if is_direct {
+                    Vec::new()
+                } else {
+                    dependency_graph
+                        .transitive_dependencies()
+                        .iter()
+                        .filter(|(_, reachable)| reachable.iter().any(|p| p.as_str() == name))
+                        .map(|(direct, _)| direct.as_str().to_string())
+                        .collect()
+                };
+                introduced_by.sort();
+
+                FixSuggestion::new(
+                    name.to_string(),
+                    version.to_string(),
+                    fixed_version,
+                    fixed_ids,
+                    unfixed_ids,
+                    is_direct,
+                    introduced_by,
+                )
+            })
+            .collect()
+    }
+}
+
+/// IDs of `advisories`, sorted and without duplicates
+fn sorted_ids<'a>(advisories: impl Iterator<Item = &'a &'a Vulnerability>) -> Vec<String> {
+    let mut ids: Vec<String> = advisories.map(|v| v.id().to_string()).collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::package::PackageName;
+    use crate::sbom_generation::domain::vulnerability::Severity;
+    use std::collections::HashMap;
+
+    fn name(n: &str) -> PackageName {
+        PackageName::new(n.to_string()).unwrap()
+    }
+
+    fn vuln(id: &str, fixed: Option<&str>) -> Vulnerability {
+        Vulnerability::new(
+            id.to_string(),
+            None,
+            Severity::High,
+            fixed.map(str::to_string),
+            None,
+        )
+        .unwrap()
+    }
+
+    /// requests and httpx are direct; urllib3 comes in through requests,
+    /// idna through both
+    fn graph() -> DependencyGraph {
+        DependencyGraph::new(
+            vec![name("requests"), name("httpx")],
+            HashMap::from([
+                (name("requests"), vec![name("urllib3"), name("idna")]),
+                (name("httpx"), vec![name("idna")]),
+            ]),
+            HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn test_direct_dependency_gets_highest_fixed_version() {
+        let vulnerabilities = vec![PackageVulnerabilities::new(
+            "requests".to_string(),
+            "2.31.0".to_string(),
+            vec![
+                vuln("CVE-2024-0002", Some("2.32.0")),
+                vuln("CVE-2024-0001", Some("2.31.1")),
+                vuln("CVE-2024-0003", Some("2.32.0rc1")),
+            ],
+        )];
+
+        let suggestions = FixSuggester::suggest(&vulnerabilities, &graph());
+
+        assert_eq!(suggestions.len(), 1);
+        let suggestion = &suggestions[0];
+        assert_eq!(suggestion.fixed_version(), Some("2.32.0"));
+        assert_eq!(
+            suggestion.fixed_ids(),
+            ["CVE-2024-0001", "CVE-2024-0002", "CVE-2024-0003"]
+        );
+        assert!(suggestion.unfixed_ids().is_empty());
+        assert_eq!(
+            suggestion.upgrade_command().as_deref(),
+            Some("uv add 'requests>=2.32.0'")
+        );
+    }
+
+    #[test]
+    fn test_transitive_dependency_lists_direct_parents() {
+        let vulnerabilities = vec![
+            PackageVulnerabilities::new(
+                "urllib3".to_string(),
+                "1.26.15".to_string(),
+                vec![vuln("CVE-2024-0010", Some("1.26.19"))],
+            ),
+            PackageVulnerabilities::new(
+                "idna".to_string(),
+                "3.6".to_string(),
+                vec![vuln("CVE-2024-0020", Some("3.7"))],
+            ),
+        ];
+
+        let suggestions = FixSuggester::suggest(&vulnerabilities, &graph());
+
+        // Sorted by package name
+        assert_eq!(suggestions[0].package_name(), "idna");
+        assert_eq!(suggestions[0].introduced_by(), ["httpx", "requests"]);
+        assert_eq!(suggestions[1].package_name(), "urllib3");
+        assert_eq!(suggestions[1].introduced_by(), ["requests"]);
+        assert_eq!(suggestions[1].fixed_version(), Some("1.26.19"));
+        assert_eq!(suggestions[1].upgrade_command(), None);
+    }
+
+    #[test]
+    fn test_advisories_without_fix_are_kept_apart() {
+        let vulnerabilities = vec![PackageVulnerabilities::new(
+            "httpx".to_string(),
+            "0.27.0".to_string(),
+            vec![
+                vuln("GHSA-aaaa-bbbb-cccc", None),
+                vuln("CVE-2024-0030", Some("0.28.0")),
+            ],
+        )];
+
+        let suggestions = FixSuggester::suggest(&vulnerabilities, &graph());
+
+        assert_eq!(suggestions[0].fixed_version(), Some("0.28.0"));
+        assert_eq!(suggestions[0].fixed_ids(), ["CVE-2024-0030"]);
+        assert_eq!(suggestions[0].unfixed_ids(), ["GHSA-aaaa-bbbb-cccc"]);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::ports::outbound::enriched_package::EnrichedPackage;
+use crate::sbom_generation::domain::dependency_graph::DependencyGraph;
+use crate::sbom_generation::domain::license_risk::{
+    CopyleftLicenses, LicenseRisk, LicenseRiskReport,
+};
+use crate::sbom_generation::domain::package::PackageName;
+
+/// Stateless domain service finding the copyleft packages the project can reach
+/// through its dependency graph, and how each one is introduced.
+pub struct LicenseReachabilityAnalyzer;
+
+impl LicenseReachabilityAnalyzer {
+    /// Lists every reachable package under a copyleft license, with the shortest
+    /// path from a direct dependency and the number of paths that reach it.
+    ///
+    /// A package the project depends on both directly and transitively is
+    /// reported once, with the one-element path of the direct dependency.
+    /// Packages the graph does not reach, such as the project itself, are not
+    /// reported.
+    ///
+    /// # Arguments
+    /// * `packages` - Packages with their resolved licenses
+    /// * `dependency_graph` - The project's dependency graph
+    /// * `copyleft` - Licenses to report
+    /// * `include_unknown` - Also report reachable packages without a license
+    pub fn analyze(
+        packages: &[EnrichedPackage],
+        dependency_graph: &DependencyGraph,
+        copyleft: &CopyleftLicenses,
+        include_unknown: bool,
+    ) -> LicenseRiskReport {
+        let mut report = LicenseRiskReport::default();
+
+        for package in packages {
+            let license = package.license.as_deref().filter(|l| !l.trim().is_empty());
+            let list = match license {
+                Some(license) if copyleft.matches(license) => &mut report.copyleft,
+                None if include_unknown => &mut report.unknown,
+                _ => continue,
+            };
+            let Ok(name) = PackageName::new(package.package.name().to_string()) else {
+                continue;
+            };
+            let paths = dependency_graph.dependency_paths_to(&name);
+            let Some(shortest) = paths.first() else {
+                continue;
+            };
+
+            list.push(LicenseRisk::new(
+                name.as_str().to_string(),
+                package.package.version().to_string(),
+                license.map(str::to_string),
+                shortest.iter().map(|p| p.as_str().to_string()).collect(),
+                paths.len(),
+            ));
+        }
+
+        report
+            .copyleft
+            .sort_by(|a, b| a.package_name().cmp(b.package_name()));
+        report
+            .unknown
+            .sort_by(|a, b| a.package_name().cmp(b.package_name()));
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::Package;
+    use std::collections::HashMap;
+
+    fn name(value: &str) -> PackageName {
+        PackageName::new(value.to_string()).unwrap()
+    }
+
+    fn package(value: &str, license: Option<&str>) -> EnrichedPackage {
+        EnrichedPackage::new(
+            Package::new(value.to_string(), "1.0.0".to_string()).unwrap(),
+            license.map(str::to_string),
+            None,
+        )
+    }
+
+    /// web -> gpl-lib; web -> helper -> gpl-lib; cli -> helper -> core -> agpl-core;
+    /// helper -> mystery (no license)
+    fn graph() -> DependencyGraph {
+        let edges = HashMap::from([
+            (name("web"), vec![name("gpl-lib"), name("helper")]),
+            (name("cli"), vec![name("helper")]),
+            (
+                name("helper"),
+                vec![name("gpl-lib"), name("core"), name("mystery")],
+            ),
+            (name("core"), vec![name("agpl-core")]),
+        ]);
+        let transitive = HashMap::from([
+            (
+                name("web"),
+                vec![
+                    name("helper"),
+                    name("core"),
+                    name("agpl-core"),
+                    name("mystery"),
+                ],
+            ),
+            (
+                name("cli"),
+                vec![
+                    name("helper"),
+                    name("gpl-lib"),
+                    name("core"),
+                    name("agpl-core"),
+                    name("mystery"),
+                ],
+            ),
+        ]);
+        DependencyGraph::new(
+            vec![name("web"), name("cli"), name("gpl-lib")],
+            transitive,
+            edges,
+        )
+    }
+
+    fn packages() -> Vec<EnrichedPackage> {
+        vec![
+            package("web", Some("MIT")),
+            package("cli", Some("Apache-2.0")),
+            package("helper", Some("MIT OR GPL-3.0-only")),
+            package("gpl-lib", Some("GPL-3.0-only")),
+            package("core", Some("BSD-3-Clause")),
+            package("agpl-core", Some("AGPL-3.0-or-later")),
+            package("mystery", None),
+            package("orphan", Some("GPL-2.0-only")),
+        ]
+    }
+
+    #[test]
+    fn test_copyleft_package_that_is_direct_and_transitive() {
+        let report = LicenseReachabilityAnalyzer::analyze(
+            &packages(),
+            &graph(),
+            &CopyleftLicenses::default(),
+            false,
+        );
+
+        let gpl = &report.copyleft[1];
+        assert_eq!(gpl.package_name(), "gpl-lib");
+        assert_eq!(gpl.introduction_path(), ["gpl-lib"]);
+        // Directly, and via web, web -> helper and cli -> helper
+        assert_eq!(gpl.path_count(), 4);
+    }
+
+    #[test]
+    fn test_shortest_introduction_path_of_transitive_package() {
+        let report = LicenseReachabilityAnalyzer::analyze(
+            &packages(),
+            &graph(),
+            &CopyleftLicenses::default(),
+            false,
+        );
+
+        let names: Vec<&str> = report.copyleft.iter().map(|r| r.package_name()).collect();
+        // helper can be used under MIT; orphan is not reachable
+        assert_eq!(names, ["agpl-core", "gpl-lib"]);
+        let agpl = &report.copyleft[0];
+        assert_eq!(
+            agpl.introduction_path(),
+            ["web", "helper", "core", "agpl-core"]
+        );
+        assert_eq!(agpl.license(), Some("AGPL-3.0-or-later"));
+        assert!(report.unknown.is_empty());
+    }
+
+    #[test]
+    fn test_strict_mode_reports_unknown_licenses() {
+        let report = LicenseReachabilityAnalyzer::analyze(
+            &packages(),
+            &graph(),
+            &CopyleftLicenses::default(),
+            true,
+        );
+
+        assert_eq!(report.unknown.len(), 1);
+        assert_eq!(report.unknown[0].package_name(), "mystery");
+        assert_eq!(report.unknown[0].license(), None);
+        assert_eq!(
+            report.unknown[0].introduction_path(),
+            ["web", "helper", "mystery"]
+        );
+    }
+}
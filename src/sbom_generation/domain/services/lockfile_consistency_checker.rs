@@ -0,0 +1,165 @@
+use super::super::package::PackageName;
+use std::collections::BTreeSet;
+
+/// Result of comparing the declared requirements with the lockfile
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LockfileConsistency {
+    /// Requirements declared in pyproject.toml that the lockfile has no package for,
+    /// as written in pyproject.toml
+    pub unlocked: Vec<String>,
+    /// Direct dependencies the lockfile records for the project that pyproject.toml
+    /// no longer declares
+    pub stale: Vec<String>,
+}
+
+impl LockfileConsistency {
+    /// Returns true when the lockfile matches the declared requirements
+    pub fn is_consistent(&self) -> bool {
+        self.unlocked.is_empty() && self.stale.is_empty()
+    }
+}
+
+/// Domain service for detecting a lockfile that is out of date with pyproject.toml
+///
+/// Editing the dependencies in pyproject.toml without re-running `uv lock`
+/// leaves a lockfile that no longer describes the project, and an SBOM
+/// generated from it silently lists the wrong packages. Requirements are
+/// compared by name only (PEP 503 normalized); version specifiers are left
+/// to the resolver.
+pub struct LockfileConsistencyChecker;
+
+impl LockfileConsistencyChecker {
+    /// Compares the requirements declared in pyproject.toml with the lockfile
+    ///
+    /// # Arguments
+    /// * `project_name` - Name of the project; requirements on the project itself
+    ///   (e.g. `my-app[extra]` inside an extra) are ignored
+    /// * `requirements` - PEP 508 requirement strings from every dependency list
+    ///   of pyproject.toml (runtime, extras and groups)
+    /// * `locked_packages` - Names of all packages in the lockfile
+    /// * `locked_direct` - Names the lockfile records as direct dependencies of the project
+    pub fn check(
+        project_name: &str,
+        requirements: &[String],
+        locked_packages: &[&str],
+        locked_direct: &[&str],
+    ) -> LockfileConsistency {
+        let project = PackageName::normalize(project_name);
+        let locked: BTreeSet<String> = locked_packages
+            .iter()
+            .map(|name| PackageName::normalize(name))
+            .collect();
+
+        let mut declared = BTreeSet::new();
+        let mut unlocked = BTreeSet::new();
+        for name in requirements.iter().filter_map(|r| requirement_name(r)) {
+            let normalized = PackageName::normalize(name);
+            if normalized == project {
+                continue;
+            }
+            if !locked.contains(&normalized) {
+                unlocked.insert(name.to_string());
+            }
+            declared.insert(normalized);
+        }
+
+        let stale: BTreeSet<String> = locked_direct
+            .iter()
+            .filter(|name| {
+                let normalized = PackageName::normalize(name);
+                normalized != project && !declared.contains(&normalized)
+            })
+            .map(|name| name.to_string())
+            .collect();
+
+        LockfileConsistency {
+            unlocked: unlocked.into_iter().collect(),
+            stale: stale.into_iter().collect(),
+        }
+    }
+}
+
+/// Extracts the distribution name from a PEP 508 requirement string,
+/// e.g. `requests` from `requests[socks]>=2.31; python_version >= "3.9"`
+pub(super) fn requirement_name(requirement: &str) -> Option<&str> {
+    let requirement = requirement.trim_start();
+    let end = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    let name = &requirement[..end];
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requirements(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_matching_lockfile_is_consistent() {
+        let result = LockfileConsistencyChecker::check(
+            "my-app",
+            &requirements(&["requests>=2.31", "pytest; extra == 'test'"]),
+            &["my-app", "requests", "urllib3", "pytest"],
+            &["requests", "pytest"],
+        );
+
+        assert!(result.is_consistent());
+    }
+
+    #[test]
+    fn test_added_but_not_locked_dependency() {
+        let result = LockfileConsistencyChecker::check(
+            "my-app",
+            &requirements(&["requests>=2.31", "httpx[http2] ~= 0.27"]),
+            &["my-app", "requests", "urllib3"],
+            &["requests"],
+        );
+
+        assert_eq!(result.unlocked, vec!["httpx".to_string()]);
+        assert!(result.stale.is_empty());
+    }
+
+    #[test]
+    fn test_removed_but_still_locked_dependency() {
+        let result = LockfileConsistencyChecker::check(
+            "my-app",
+            &requirements(&["requests>=2.31"]),
+            &["my-app", "requests", "urllib3", "flask"],
+            &["requests", "flask"],
+        );
+
+        assert!(result.unlocked.is_empty());
+        assert_eq!(result.stale, vec!["flask".to_string()]);
+    }
+
+    #[test]
+    fn test_names_are_compared_after_normalization() {
+        let result = LockfileConsistencyChecker::check(
+            "My_App",
+            &requirements(&["Typing_Extensions", "ruamel.yaml", "my-app[cli]"]),
+            &["my-app", "typing-extensions", "ruamel-yaml"],
+            &["typing-extensions", "ruamel-yaml"],
+        );
+
+        assert!(result.is_consistent());
+    }
+
+    #[test]
+    fn test_requirement_name() {
+        assert_eq!(requirement_name("requests"), Some("requests"));
+        assert_eq!(requirement_name("  requests[socks]>=2"), Some("requests"));
+        assert_eq!(
+            requirement_name("zope.interface; python_version<'3.12'"),
+            Some("zope.interface")
+        );
+        assert_eq!(
+            requirement_name("pkg @ https://example.com/pkg.whl"),
+            Some("pkg")
+        );
+        assert_eq!(requirement_name(""), None);
+    }
+}
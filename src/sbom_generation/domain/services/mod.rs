@@ -1,10 +1,30 @@
+pub mod alias_deduplicator;
+pub mod configuration_validator;
 pub mod cve_filter;
+pub mod declared_constraint_matcher;
+pub mod fix_suggester;
 pub mod license_compliance_checker;
+pub mod license_reachability_analyzer;
+pub mod lockfile_consistency_checker;
+pub mod python_compatibility_checker;
 pub mod resolution_analyzer;
+pub mod typosquat_checker;
 pub mod upgrade_advisor;
 pub mod vulnerability_checker;
 
+pub use alias_deduplicator::AliasDeduplicator;
+pub use configuration_validator::{
+    ConfigurationValidator, ValidationIssue, ValidationOutcome, ValidationReport,
+};
+pub use declared_constraint_matcher::DeclaredConstraintMatcher;
+pub use fix_suggester::FixSuggester;
 pub use license_compliance_checker::LicenseComplianceChecker;
+pub use license_reachability_analyzer::LicenseReachabilityAnalyzer;
+pub use lockfile_consistency_checker::LockfileConsistencyChecker;
+pub use python_compatibility_checker::PythonCompatibilityChecker;
 pub use resolution_analyzer::ResolutionAnalyzer;
+pub use typosquat_checker::TyposquatChecker;
 pub use upgrade_advisor::UpgradeAdvisor;
-pub use vulnerability_checker::{ThresholdConfig, VulnerabilityCheckResult, VulnerabilityChecker};
+pub use vulnerability_checker::{
+    IgnoredVulnerability, ThresholdConfig, VulnerabilityCheckResult, VulnerabilityChecker,
+};
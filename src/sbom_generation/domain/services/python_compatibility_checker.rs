@@ -0,0 +1,90 @@
+use crate::ports::outbound::enriched_package::EnrichedPackage;
+use crate::sbom_generation::domain::python_compatibility::{
+    PythonCompatibilityReport, PythonIncompatibility, PythonRequirement,
+};
+
+/// Stateless domain service comparing each package's `requires-python` with
+/// the Python versions the project supports.
+pub struct PythonCompatibilityChecker;
+
+impl PythonCompatibilityChecker {
+    /// Lists the packages whose `requires-python` does not allow every Python
+    /// version the project's `requires-python` allows.
+    ///
+    /// Packages without a `requires-python`, and packages whose specifiers are
+    /// not understood, are assumed to be compatible.
+    ///
+    /// # Arguments
+    /// * `packages` - Packages with the `requires-python` read from the lockfile
+    /// * `project_requires_python` - The project's `requires-python`
+    ///
+    /// # Returns
+    /// `None` when the project's `requires-python` is not understood
+    pub fn check(
+        packages: &[EnrichedPackage],
+        project_requires_python: &str,
+    ) -> Option<PythonCompatibilityReport> {
+        let project = PythonRequirement::parse(project_requires_python)?;
+
+        let mut incompatible: Vec<PythonIncompatibility> = packages
+            .iter()
+            .filter_map(|enriched| {
+                let requires_python = enriched.package.requires_python()?;
+                let requirement = PythonRequirement::parse(requires_python)?;
+                (!requirement.covers(&project)).then(|| PythonIncompatibility {
+                    package_name: enriched.package.name().to_string(),
+                    package_version: enriched.package.version().to_string(),
+                    requires_python: requires_python.to_string(),
+                })
+            })
+            .collect();
+        incompatible.sort_by(|a, b| a.package_name.cmp(&b.package_name));
+
+        Some(PythonCompatibilityReport {
+            project_requires_python: project_requires_python.to_string(),
+            incompatible,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::Package;
+
+    fn package(name: &str, requires_python: Option<&str>) -> EnrichedPackage {
+        let package = Package::new(name.to_string(), "1.0.0".to_string())
+            .unwrap()
+            .with_requires_python(requires_python.map(str::to_string));
+        EnrichedPackage::new(package, None, None)
+    }
+
+    #[test]
+    fn test_lists_packages_not_covering_project_range() {
+        let packages = vec![
+            package("numpy", Some(">=3.9,<3.12")),
+            package("attrs", Some(">=3.7")),
+            package("six", None),
+            package("legacy", Some(">=3.10")),
+            package("odd", Some("===3.9")),
+        ];
+
+        let report = PythonCompatibilityChecker::check(&packages, ">=3.9").unwrap();
+
+        assert_eq!(report.project_requires_python, ">=3.9");
+        let names: Vec<&str> = report
+            .incompatible
+            .iter()
+            .map(|i| i.package_name.as_str())
+            .collect();
+        assert_eq!(names, ["legacy", "numpy"]);
+        assert_eq!(report.incompatible[1].requires_python, ">=3.9,<3.12");
+    }
+
+    #[test]
+    fn test_unparseable_project_range_skips_check() {
+        let packages = vec![package("numpy", Some(">=3.9,<3.12"))];
+
+        assert!(PythonCompatibilityChecker::check(&packages, "===3.9").is_none());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::ports::outbound::enriched_package::EnrichedPackage;
+use crate::sbom_generation::domain::typosquat::TyposquatFinding;
+use crate::sbom_generation::domain::PackageName;
+use crate::sbom_generation::policies::popular_packages::POPULAR_PACKAGES;
+use std::collections::HashSet;
+
+/// Popular names shorter than this are skipped: almost any short name is one
+/// edit away from another, so they would only produce noise.
+const MIN_POPULAR_NAME_LENGTH: usize = 4;
+
+/// Stateless domain service flagging locked packages whose names are near
+/// misses of popular PyPI package names, such as `requets` for `requests`.
+pub struct TyposquatChecker;
+
+impl TyposquatChecker {
+    /// Default Damerau-Levenshtein distance at which a name is reported
+    pub const DEFAULT_MAX_DISTANCE: usize = 1;
+
+    /// Lists the packages whose normalized name is within `max_distance` edits
+    /// of a popular package name.
+    ///
+    /// Packages that are themselves popular are never reported, and neither
+    /// are the packages named in `ignored` (compared after PEP 503
+    /// normalization). Each package is reported once, against the closest
+    /// popular name.
+    ///
+    /// # Arguments
+    /// * `packages` - Packages from the lockfile
+    /// * `max_distance` - Largest edit distance still reported; 0 reports nothing
+    /// * `ignored` - Package names known to be legitimate
+    ///
+    /// # Returns
+    /// Findings sorted by package name
+    pub fn check(
+        packages: &[EnrichedPackage],
+        max_distance: usize,
+        ignored: &[String],
+    ) -> Vec<TyposquatFinding> {
+        if max_distance == 0 {
+            return Vec::new();
+        }
+        let ignored: HashSet<String> = ignored.iter().map(|n| PackageName::normalize(n)).collect();
+        let popular: HashSet<&str> = POPULAR_PACKAGES.iter().copied().collect();
+
+        let mut findings: Vec<TyposquatFinding> = packages
+            .iter()
+            .filter_map(|enriched| {
+                let name = PackageName::normalize(enriched.package.name());
+                if popular.contains(name.as_str()) || ignored.contains(&name) {
+                    return None;
+                }
+                let (similar_to, distance) = Self::closest_popular_name(&name, max_distance)?;
+                Some(TyposquatFinding {
+                    package_name: enriched.package.name().to_string(),
+                    package_version: enriched.package.version().to_string(),
+                    similar_to: similar_to.to_string(),
+                    distance,
+                })
+            })
+            .collect();
+        findings.sort_by(|a, b| {
+            (&a.package_name, &a.package_version).cmp(&(&b.package_name, &b.package_version))
+        });
+        findings
+    }
+
+    /// Returns the popular name closest to `name`, if one is within `max_distance`
+    fn closest_popular_name(name: &str, max_distance: usize) -> Option<(&'static str, usize)> {
+        let name: Vec<char> = name.chars().collect();
+        POPULAR_PACKAGES
+            .iter()
+            .filter(|popular| popular.len() >= MIN_POPULAR_NAME_LENGTH)
+            .filter(|popular| popular.len().abs_diff(name.len()) <= max_distance)
+            .map(|popular| {
+                let candidate: Vec<char> = popular.chars().collect();
+                (*popular, damerau_levenshtein(&name, &candidate))
+            })
+            .filter(|(_, distance)| *distance <= max_distance)
+            .min_by_key(|(_, distance)| *distance)
+    }
+}
+
+/// Edit distance counting insertions, deletions, substitutions and swaps of
+/// two adjacent characters (the optimal string alignment variant).
+fn damerau_levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::Package;
+
+    fn package(name: &str) -> EnrichedPackage {
+        let package = Package::new(name.to_string(), "1.0.0".to_string()).unwrap();
+        EnrichedPackage::new(package, None, None)
+    }
+
+    fn distance(a: &str, b: &str) -> usize {
+        let a: Vec<char> = a.chars().collect();
+        let b: Vec<char> = b.chars().collect();
+        damerau_levenshtein(&a, &b)
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() {
+        assert_eq!(distance("requests", "requests"), 0);
+        assert_eq!(distance("requets", "requests"), 1);
+        assert_eq!(distance("reqeusts", "requests"), 1);
+        assert_eq!(distance("urlib3", "urllib3"), 1);
+        assert_eq!(distance("nmupy", "numpy"), 1);
+        assert_eq!(distance("flask", "bottle"), 6);
+        assert_eq!(distance("", "six"), 3);
+    }
+
+    #[test]
+    fn test_reports_near_miss_names() {
+        let packages = vec![package("urlib3"), package("requets"), package("my-app")];
+
+        let findings = TyposquatChecker::check(&packages, 1, &[]);
+
+        let found: Vec<(&str, &str)> = findings
+            .iter()
+            .map(|f| (f.package_name.as_str(), f.similar_to.as_str()))
+            .collect();
+        assert_eq!(found, [("requets", "requests"), ("urlib3", "urllib3")]);
+        assert!(findings.iter().all(|f| f.distance == 1));
+    }
+
+    #[test]
+    fn test_popular_names_are_not_reported() {
+        // tomli is one edit from toml, but both are legitimate popular packages
+        let packages = vec![
+            package("requests"),
+            package("tomli"),
+            package("toml"),
+            package("Typing_Extensions"),
+        ];
+
+        assert!(TyposquatChecker::check(&packages, 1, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_ignored_names_are_not_reported() {
+        let packages = vec![package("Requets"), package("urlib3")];
+
+        let findings = TyposquatChecker::check(&packages, 1, &["requets".to_string()]);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].package_name, "urlib3");
+    }
+
+    #[test]
+    fn test_max_distance() {
+        let packages = vec![package("reqests2")];
+
+        assert!(TyposquatChecker::check(&packages, 1, &[]).is_empty());
+        let findings = TyposquatChecker::check(&packages, 2, &[]);
+        assert_eq!(findings[0].similar_to, "requests");
+        assert_eq!(findings[0].distance, 2);
+        assert!(TyposquatChecker::check(&[package("requets")], 0, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_short_popular_names_are_skipped() {
+        // One edit from "six" and "pip", which are too short to compare
+        let packages = vec![package("sex"), package("pipe")];
+
+        assert!(TyposquatChecker::check(&packages, 1, &[]).is_empty());
+    }
+}
@@ -221,7 +221,8 @@ mod tests {
     use super::*;
     use crate::sbom_generation::domain::resolution_guide::{IntroducedBy, ResolutionEntry};
     use crate::sbom_generation::domain::vulnerability::Severity;
-    use anyhow::Result;
+    use crate::shared::error::UvSbomError;
+    use crate::shared::Result;
     use async_trait::async_trait;
     use std::collections::HashMap;
     use std::path::Path;
@@ -270,15 +271,15 @@ mod tests {
             _project_path: &Path,
         ) -> Result<SimulationResult> {
             if let Some(error) = self.errors.get(package_name) {
-                return Err(anyhow::anyhow!("{}", error));
+                return Err(UvSbomError::other(error.clone()));
             }
             if let Some(result) = self.results.get(package_name) {
                 return Ok(result.clone());
             }
-            Err(anyhow::anyhow!(
+            Err(UvSbomError::other(format!(
                 "package not configured in mock: {}",
                 package_name
-            ))
+            )))
         }
     }
 
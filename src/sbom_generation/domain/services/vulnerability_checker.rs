@@ -1,6 +1,7 @@
 use super::super::vulnerability::{PackageVulnerabilities, Severity, Vulnerability};
+use super::super::vulnerability_baseline::VulnerabilityBaseline;
 use super::cve_filter::CveFilter;
-use crate::config::IgnoreCve;
+use crate::config::{IgnoreCve, IgnoreState};
 
 /// Configuration for threshold evaluation
 #[derive(Debug, Clone, PartialEq)]
@@ -37,8 +38,31 @@ pub struct VulnerabilityCheckResult {
     pub above_threshold: Vec<PackageVulnerabilities>,
     /// Packages with vulnerabilities below the threshold
     pub below_threshold: Vec<PackageVulnerabilities>,
-    /// Whether any vulnerability exceeded the threshold
+    /// Whether any vulnerability outside the baseline exceeded the threshold
     pub threshold_exceeded: bool,
+    /// Vulnerabilities suppressed through the ignore list
+    pub ignored: Vec<IgnoredVulnerability>,
+    /// Findings accepted by the baseline; they stay in `above_threshold` and
+    /// `below_threshold` but do not count toward `threshold_exceeded`
+    pub baseline: VulnerabilityBaseline,
+}
+
+/// A vulnerability suppressed through the ignore list
+///
+/// Kept so that it can still be reported as a VEX statement instead of
+/// silently disappearing from the output.
+#[derive(Debug, Clone)]
+pub struct IgnoredVulnerability {
+    /// Name of the affected package
+    pub package_name: String,
+    /// Installed version of the affected package
+    pub package_version: String,
+    /// The suppressed vulnerability
+    pub vulnerability: Vulnerability,
+    /// Analysis state from the ignore entry
+    pub state: IgnoreState,
+    /// Reason from the ignore entry, if provided
+    pub reason: Option<String>,
 }
 
 impl VulnerabilityCheckResult {
@@ -57,6 +81,11 @@ impl VulnerabilityCheckResult {
             .map(|pv| pv.vulnerabilities().len())
             .sum()
     }
+
+    /// Returns true if `vulnerability` of `package_name` was accepted by the baseline
+    pub fn is_baseline(&self, package_name: &str, vulnerability: &Vulnerability) -> bool {
+        self.baseline.contains(package_name, vulnerability)
+    }
 }
 
 /// Domain service for evaluating vulnerabilities against thresholds
@@ -76,9 +105,28 @@ impl VulnerabilityChecker {
         vulnerabilities: Vec<PackageVulnerabilities>,
         threshold: ThresholdConfig,
         ignore_cves: &[IgnoreCve],
+    ) -> VulnerabilityCheckResult {
+        Self::check_against_baseline(
+            vulnerabilities,
+            threshold,
+            ignore_cves,
+            &VulnerabilityBaseline::new(),
+        )
+    }
+
+    /// Checks vulnerabilities like [`Self::check`], counting only the findings
+    /// missing from `baseline` toward `threshold_exceeded`
+    ///
+    /// Ignored CVEs are removed first, so a vulnerability both ignored and in
+    /// the baseline is reported as ignored.
+    pub fn check_against_baseline(
+        vulnerabilities: Vec<PackageVulnerabilities>,
+        threshold: ThresholdConfig,
+        ignore_cves: &[IgnoreCve],
+        baseline: &VulnerabilityBaseline,
     ) -> VulnerabilityCheckResult {
         // Step 1: Filter out ignored CVEs
-        let filtered = CveFilter::apply(vulnerabilities, ignore_cves);
+        let (filtered, ignored) = CveFilter::apply(vulnerabilities, ignore_cves);
 
         // Step 2: Apply threshold evaluation
         let mut above_threshold = Vec::new();
@@ -113,12 +161,29 @@ impl VulnerabilityChecker {
             }
         }
 
-        let threshold_exceeded = !above_threshold.is_empty();
+        // Step 3: Tell accepted findings from new ones
+        let mut accepted = VulnerabilityBaseline::new();
+        let findings = above_threshold.iter().chain(below_threshold.iter());
+        for pkg_vulns in findings.filter(|_| !baseline.is_empty()) {
+            for vuln in pkg_vulns.vulnerabilities() {
+                if baseline.contains(pkg_vulns.package_name(), vuln) {
+                    accepted.insert(pkg_vulns.package_name(), vuln.id());
+                }
+            }
+        }
+        let threshold_exceeded = above_threshold.iter().any(|pkg_vulns| {
+            pkg_vulns
+                .vulnerabilities()
+                .iter()
+                .any(|vuln| !accepted.contains(pkg_vulns.package_name(), vuln))
+        });
 
         VulnerabilityCheckResult {
             above_threshold,
             below_threshold,
             threshold_exceeded,
+            ignored,
+            baseline: accepted,
         }
     }
 }
@@ -296,6 +361,8 @@ mod tests {
             above_threshold: vec![pkg],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.actionable_count(), 2);
@@ -313,6 +380,8 @@ mod tests {
             above_threshold: vec![pkg1, pkg2],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.actionable_count(), 3);
@@ -324,6 +393,8 @@ mod tests {
             above_threshold: vec![],
             below_threshold: vec![],
             threshold_exceeded: false,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.actionable_count(), 0);
@@ -339,6 +410,8 @@ mod tests {
             above_threshold: vec![],
             below_threshold: vec![pkg],
             threshold_exceeded: false,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.informational_count(), 2);
@@ -356,6 +429,8 @@ mod tests {
             above_threshold: vec![],
             below_threshold: vec![pkg1, pkg2],
             threshold_exceeded: false,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.informational_count(), 3);
@@ -372,6 +447,8 @@ mod tests {
             above_threshold: vec![pkg1, pkg2],
             below_threshold: vec![],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.above_threshold.len(), 2);
@@ -383,6 +460,8 @@ mod tests {
             above_threshold: vec![],
             below_threshold: vec![],
             threshold_exceeded: false,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.above_threshold.len(), 0);
@@ -401,6 +480,8 @@ mod tests {
             above_threshold: vec![],
             below_threshold: vec![pkg1, pkg2, pkg3],
             threshold_exceeded: false,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert_eq!(result.below_threshold.len(), 3);
@@ -422,6 +503,8 @@ mod tests {
             above_threshold: vec![above_pkg],
             below_threshold: vec![below_pkg1, below_pkg2],
             threshold_exceeded: true,
+            ignored: vec![],
+            baseline: Default::default(),
         };
 
         assert!(!result.above_threshold.is_empty());
@@ -443,6 +526,7 @@ mod tests {
         let ignore = vec![IgnoreCve {
             id: "CVE-2024-001".to_string(),
             reason: Some("False positive".to_string()),
+            state: None,
         }];
 
         let result = VulnerabilityChecker::check(vec![pkg], ThresholdConfig::None, &ignore);
@@ -468,10 +552,12 @@ mod tests {
             IgnoreCve {
                 id: "CVE-2024-001".to_string(),
                 reason: None,
+                state: None,
             },
             IgnoreCve {
                 id: "CVE-2024-002".to_string(),
                 reason: Some("Accepted risk".to_string()),
+                state: None,
             },
         ];
 
@@ -495,6 +581,7 @@ mod tests {
         let ignore = vec![IgnoreCve {
             id: "CVE-2024-001".to_string(),
             reason: Some("Code path not reachable".to_string()),
+            state: None,
         }];
 
         let result = VulnerabilityChecker::check(vec![pkg], ThresholdConfig::None, &ignore);
@@ -505,6 +592,39 @@ mod tests {
         assert!(result.below_threshold.is_empty());
     }
 
+    #[test]
+    fn test_ignored_cves_are_recorded_with_state_and_reason() {
+        let vuln1 = create_vulnerability("CVE-2024-001", Some(9.0), Severity::Critical);
+        let vuln2 = create_vulnerability("CVE-2024-002", Some(7.5), Severity::High);
+        let pkg = create_package_vulnerabilities("test-pkg", vec![vuln1, vuln2]);
+
+        let ignore = vec![
+            IgnoreCve {
+                id: "CVE-2024-001".to_string(),
+                reason: Some("Scanner misidentifies the package".to_string()),
+                state: Some(IgnoreState::FalsePositive),
+            },
+            IgnoreCve {
+                id: "CVE-2024-002".to_string(),
+                reason: None,
+                state: None,
+            },
+        ];
+
+        let result = VulnerabilityChecker::check(vec![pkg], ThresholdConfig::None, &ignore);
+
+        assert_eq!(result.ignored.len(), 2);
+        assert_eq!(result.ignored[0].package_name, "test-pkg");
+        assert_eq!(result.ignored[0].vulnerability.id(), "CVE-2024-001");
+        assert_eq!(result.ignored[0].state, IgnoreState::FalsePositive);
+        assert_eq!(
+            result.ignored[0].reason.as_deref(),
+            Some("Scanner misidentifies the package")
+        );
+        assert_eq!(result.ignored[1].state, IgnoreState::NotAffected);
+        assert!(result.ignored[1].reason.is_none());
+    }
+
     #[test]
     fn test_ignore_cve_no_match() {
         let vuln = create_vulnerability("CVE-2024-001", Some(9.0), Severity::Critical);
@@ -513,6 +633,7 @@ mod tests {
         let ignore = vec![IgnoreCve {
             id: "CVE-2024-999".to_string(),
             reason: None,
+            state: None,
         }];
 
         let result = VulnerabilityChecker::check(vec![pkg], ThresholdConfig::None, &ignore);
@@ -545,10 +666,12 @@ mod tests {
             IgnoreCve {
                 id: "CVE-2024-001".to_string(),
                 reason: None,
+                state: None,
             },
             IgnoreCve {
                 id: "CVE-2024-002".to_string(),
                 reason: None,
+                state: None,
             },
         ];
 
@@ -569,6 +692,7 @@ mod tests {
         let ignore = vec![IgnoreCve {
             id: "cve-2024-001".to_string(),
             reason: None,
+            state: None,
         }];
 
         let result = VulnerabilityChecker::check(vec![pkg], ThresholdConfig::None, &ignore);
@@ -588,6 +712,7 @@ mod tests {
         let ignore = vec![IgnoreCve {
             id: "CVE-2024-001".to_string(),
             reason: Some("False positive".to_string()),
+            state: None,
         }];
 
         let result = VulnerabilityChecker::check(
@@ -613,6 +738,7 @@ mod tests {
         let ignore = vec![IgnoreCve {
             id: "CVE-2024-001".to_string(),
             reason: None,
+            state: None,
         }];
 
         let result = VulnerabilityChecker::check(vec![pkg1, pkg2], ThresholdConfig::None, &ignore);
@@ -628,4 +754,65 @@ mod tests {
             "CVE-2024-002"
         );
     }
+
+    // Tests for baseline classification
+
+    #[test]
+    fn test_baseline_only_findings_do_not_exceed_threshold() {
+        let vuln = create_vulnerability("CVE-2024-001", Some(5.0), Severity::Medium);
+        let pkg = create_package_vulnerabilities("test-pkg", vec![vuln]);
+        let mut baseline = VulnerabilityBaseline::new();
+        baseline.insert("test-pkg", "CVE-2024-001");
+
+        let result = VulnerabilityChecker::check_against_baseline(
+            vec![pkg],
+            ThresholdConfig::None,
+            &[],
+            &baseline,
+        );
+
+        assert!(!result.threshold_exceeded);
+        assert_eq!(result.actionable_count(), 1);
+        assert!(result.is_baseline("test-pkg", &result.above_threshold[0].vulnerabilities()[0]));
+    }
+
+    #[test]
+    fn test_new_finding_next_to_baseline_exceeds_threshold() {
+        let known = create_vulnerability("CVE-2024-001", Some(5.0), Severity::Medium);
+        let new = create_vulnerability("CVE-2024-002", Some(5.0), Severity::Medium);
+        let pkg = create_package_vulnerabilities("test-pkg", vec![known, new]);
+        let mut baseline = VulnerabilityBaseline::new();
+        baseline.insert("test-pkg", "CVE-2024-001");
+
+        let result = VulnerabilityChecker::check_against_baseline(
+            vec![pkg],
+            ThresholdConfig::None,
+            &[],
+            &baseline,
+        );
+
+        assert!(result.threshold_exceeded);
+        let vulns = result.above_threshold[0].vulnerabilities();
+        assert!(result.is_baseline("test-pkg", &vulns[0]));
+        assert!(!result.is_baseline("test-pkg", &vulns[1]));
+        assert_eq!(result.baseline.len(), 1);
+    }
+
+    #[test]
+    fn test_baseline_entry_for_other_package_does_not_match() {
+        let vuln = create_vulnerability("CVE-2024-001", Some(9.0), Severity::Critical);
+        let pkg = create_package_vulnerabilities("pkg-2", vec![vuln]);
+        let mut baseline = VulnerabilityBaseline::new();
+        baseline.insert("pkg-1", "CVE-2024-001");
+
+        let result = VulnerabilityChecker::check_against_baseline(
+            vec![pkg],
+            ThresholdConfig::None,
+            &[],
+            &baseline,
+        );
+
+        assert!(result.threshold_exceeded);
+        assert!(result.baseline.is_empty());
+    }
 }
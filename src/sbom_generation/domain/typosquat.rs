@@ -0,0 +1,13 @@
+//! Locked packages whose names look like misspellings of popular packages
+
+/// A package whose name is a near miss of a popular package name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TyposquatFinding {
+    /// Package name as the lockfile spells it
+    pub package_name: String,
+    pub package_version: String,
+    /// The popular package the name resembles, PEP 503 normalized
+    pub similar_to: String,
+    /// Damerau-Levenshtein distance between the normalized names
+    pub distance: usize,
+}
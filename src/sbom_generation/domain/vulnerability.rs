@@ -1,3 +1,4 @@
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 
 /// CVSS Score value object with validation
@@ -19,11 +20,14 @@ impl CvssScore {
     /// - Score is outside valid range (0.0-10.0)
     pub fn new(score: f32) -> Result<Self> {
         if score.is_nan() {
-            anyhow::bail!("CVSS score cannot be NaN");
+            return Err(UvSbomError::validation("CVSS score cannot be NaN"));
         }
 
         if !(0.0..=10.0).contains(&score) {
-            anyhow::bail!("CVSS score must be between 0.0 and 10.0, got: {}", score);
+            return Err(UvSbomError::validation(format!(
+                "CVSS score must be between 0.0 and 10.0, got: {}",
+                score
+            )));
         }
 
         Ok(Self(score))
@@ -35,6 +39,28 @@ impl CvssScore {
     }
 }
 
+/// CVSS specification version a score was calculated with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CvssVersion {
+    V2_0,
+    V3_0,
+    V3_1,
+    V4_0,
+}
+
+/// Which CVSS version to score with when an advisory carries more than one
+///
+/// The other version is still used when the preferred one is absent. CVSS v2
+/// is only used when neither v4.0 nor v3.x is available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CvssPreference {
+    /// Prefer CVSS v4.0, which is more accurate for newer advisories
+    #[default]
+    V4,
+    /// Prefer CVSS v3.x, for consistency with scores from older tooling
+    V3,
+}
+
 /// Represents a single vulnerability (CVE) affecting a package
 #[derive(Debug, Clone, PartialEq)]
 pub struct Vulnerability {
@@ -44,6 +70,9 @@ pub struct Vulnerability {
     /// CVSS score (validated, 0.0 to 10.0)
     cvss_score: Option<CvssScore>,
 
+    /// CVSS version the score was calculated with
+    cvss_version: Option<CvssVersion>,
+
     /// Severity level (CRITICAL, HIGH, MEDIUM, LOW)
     severity: Severity,
 
@@ -52,6 +81,12 @@ pub struct Vulnerability {
 
     /// Brief summary of the vulnerability
     summary: Option<String>,
+
+    /// Other IDs the same advisory is published under (e.g., GHSA or PYSEC IDs)
+    aliases: Vec<String>,
+
+    /// URL of the primary advisory describing the vulnerability
+    source_url: Option<String>,
 }
 
 impl Vulnerability {
@@ -80,15 +115,18 @@ impl Vulnerability {
     ) -> Result<Self> {
         // Validate ID
         if id.is_empty() {
-            anyhow::bail!("Vulnerability ID cannot be empty");
+            return Err(UvSbomError::validation("Vulnerability ID cannot be empty"));
         }
 
         Ok(Self {
             id,
             cvss_score,
+            cvss_version: None,
             severity,
             fixed_version,
             summary,
+            aliases: Vec::new(),
+            source_url: None,
         })
     }
 
@@ -102,6 +140,17 @@ impl Vulnerability {
         self.cvss_score
     }
 
+    /// Records the CVSS version the score was calculated with
+    pub fn with_cvss_version(mut self, version: Option<CvssVersion>) -> Self {
+        self.cvss_version = version;
+        self
+    }
+
+    /// Returns the CVSS version the score was calculated with, if known
+    pub fn cvss_version(&self) -> Option<CvssVersion> {
+        self.cvss_version
+    }
+
     /// Returns the severity level
     pub fn severity(&self) -> Severity {
         self.severity
@@ -111,6 +160,73 @@ impl Vulnerability {
     pub fn fixed_version(&self) -> Option<&str> {
         self.fixed_version.as_deref()
     }
+
+    /// Records the other IDs the same advisory is published under
+    pub fn with_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Returns the other IDs the same advisory is published under
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// Records the URL of the primary advisory
+    pub fn with_source_url(mut self, url: Option<String>) -> Self {
+        self.source_url = url;
+        self
+    }
+
+    /// Returns the brief summary, if any
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    /// Returns the URL of the primary advisory, if known
+    pub fn source_url(&self) -> Option<&str> {
+        self.source_url.as_deref()
+    }
+
+    /// Returns the ID followed by every alias
+    pub fn all_ids(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.id.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+
+    /// Folds other advisories for the same flaw into this one under `canonical_id`
+    ///
+    /// Details missing here are taken from `others` in order, and every ID of
+    /// the group other than `canonical_id` becomes an alias.
+    pub fn merged(mut self, canonical_id: &str, others: Vec<Vulnerability>) -> Self {
+        let mut ids: Vec<String> = self.all_ids().map(str::to_string).collect();
+
+        for other in others {
+            ids.extend(other.all_ids().map(str::to_string));
+            if self.cvss_score.is_none() && other.cvss_score.is_some() {
+                self.cvss_score = other.cvss_score;
+                self.cvss_version = other.cvss_version;
+                self.severity = other.severity;
+            } else if self.severity == Severity::None {
+                self.severity = other.severity;
+            }
+            if self.fixed_version.is_none() {
+                self.fixed_version = other.fixed_version;
+            }
+            if self.summary.is_none() {
+                self.summary = other.summary;
+            }
+            if self.source_url.is_none() {
+                self.source_url = other.source_url;
+            }
+        }
+
+        ids.retain(|id| id != canonical_id);
+        ids.sort();
+        ids.dedup();
+        self.id = canonical_id.to_string();
+        self.aliases = ids;
+        self
+    }
 }
 
 /// Severity levels based on CVSS scores
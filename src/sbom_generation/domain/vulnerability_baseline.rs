@@ -0,0 +1,107 @@
+//! Previously accepted vulnerabilities that do not fail a run
+
+use super::package::PackageName;
+use super::vulnerability::{PackageVulnerabilities, Vulnerability};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Vulnerability IDs accepted per package, e.g. from a `--cve-baseline` file
+///
+/// Package names are PEP 503 normalized and IDs are kept sorted, so the same
+/// findings always produce the same baseline. Entries are not tied to a
+/// package version: an upgrade that still carries an accepted advisory keeps
+/// it accepted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VulnerabilityBaseline {
+    packages: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl VulnerabilityBaseline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a baseline accepting every finding in `findings`
+    ///
+    /// Each vulnerability is recorded under its primary ID.
+    pub fn from_findings<'a>(
+        findings: impl IntoIterator<Item = &'a PackageVulnerabilities>,
+    ) -> Self {
+        let mut baseline = Self::new();
+        for package in findings {
+            for vulnerability in package.vulnerabilities() {
+                baseline.insert(package.package_name(), vulnerability.id());
+            }
+        }
+        baseline
+    }
+
+    /// Accepts `id` for `package_name`
+    pub fn insert(&mut self, package_name: &str, id: &str) {
+        self.packages
+            .entry(PackageName::normalize(package_name))
+            .or_default()
+            .insert(id.to_string());
+    }
+
+    /// Returns true if `vulnerability` of `package_name` is accepted
+    ///
+    /// An entry matches the vulnerability's ID or any of its aliases, so the
+    /// baseline keeps working when the advisory is reported under another ID.
+    pub fn contains(&self, package_name: &str, vulnerability: &Vulnerability) -> bool {
+        self.packages
+            .get(&PackageName::normalize(package_name))
+            .is_some_and(|ids| vulnerability.all_ids().any(|id| ids.contains(id)))
+    }
+
+    /// Returns the accepted IDs per normalized package name, both sorted
+    pub fn packages(&self) -> &BTreeMap<String, BTreeSet<String>> {
+        &self.packages
+    }
+
+    /// Returns the number of accepted (package, ID) entries
+    pub fn len(&self) -> usize {
+        self.packages.values().map(BTreeSet::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::vulnerability::Severity;
+
+    fn vuln(id: &str, aliases: &[&str]) -> Vulnerability {
+        Vulnerability::new(id.to_string(), None, Severity::Medium, None, None)
+            .unwrap()
+            .with_aliases(aliases.iter().map(|a| a.to_string()).collect())
+    }
+
+    #[test]
+    fn test_contains_matches_id_or_alias_of_normalized_package() {
+        let mut baseline = VulnerabilityBaseline::new();
+        baseline.insert("Jinja2", "CVE-2024-1");
+
+        assert!(baseline.contains("jinja2", &vuln("CVE-2024-1", &[])));
+        assert!(baseline.contains("jinja2", &vuln("GHSA-xxxx", &["CVE-2024-1"])));
+        assert!(!baseline.contains("jinja2", &vuln("CVE-2024-2", &[])));
+        assert!(!baseline.contains("requests", &vuln("CVE-2024-1", &[])));
+    }
+
+    #[test]
+    fn test_from_findings_records_primary_ids() {
+        let findings = vec![PackageVulnerabilities::new(
+            "Requests".to_string(),
+            "2.31.0".to_string(),
+            vec![vuln("GHSA-b", &["CVE-2024-9"]), vuln("GHSA-a", &[])],
+        )];
+
+        let baseline = VulnerabilityBaseline::from_findings(&findings);
+
+        assert_eq!(baseline.len(), 2);
+        let ids: Vec<&String> = baseline.packages()["requests"].iter().collect();
+        assert_eq!(ids, ["GHSA-a", "GHSA-b"]);
+    }
+}
@@ -0,0 +1,610 @@
+//! License compatibility policy
+//!
+//! Decides whether a dependency license can be distributed as part of a
+//! project released under the project's own license. Licenses are grouped into
+//! families (permissive, weak copyleft, strong copyleft) and a matrix maps each
+//! (project family, dependency family) pair to a verdict.
+//!
+//! The default tables are conservative and assume binary distribution of the
+//! project. Every table can be overridden from the config file, since the
+//! legal answer depends on how the project is actually distributed.
+
+use super::spdx_license_map;
+use std::collections::HashMap;
+
+/// Coarse license family used by the compatibility matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LicenseFamily {
+    /// MIT, BSD, Apache-2.0, ... — few conditions beyond attribution
+    Permissive,
+    /// LGPL, MPL, EPL, ... — copyleft limited to the licensed files or library
+    WeakCopyleft,
+    /// GPL, AGPL, ... — copyleft extends to the combined work
+    StrongCopyleft,
+}
+
+impl LicenseFamily {
+    /// All families, in matrix order
+    pub const ALL: [LicenseFamily; 3] = [
+        LicenseFamily::Permissive,
+        LicenseFamily::WeakCopyleft,
+        LicenseFamily::StrongCopyleft,
+    ];
+
+    /// Returns the identifier used in config files and reports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Permissive => "permissive",
+            Self::WeakCopyleft => "weak-copyleft",
+            Self::StrongCopyleft => "strong-copyleft",
+        }
+    }
+
+    /// Parses a family identifier as written in config files (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "permissive" => Some(Self::Permissive),
+            "weak-copyleft" => Some(Self::WeakCopyleft),
+            "strong-copyleft" => Some(Self::StrongCopyleft),
+            _ => None,
+        }
+    }
+}
+
+/// Verdict for one dependency license against the project license
+///
+/// Variants are ordered from least to most severe, so combining the licenses of
+/// an `AND` expression takes the maximum and an `OR` expression the minimum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Compatibility {
+    /// Can be distributed under the project license
+    Compatible,
+    /// Compatible provided its conditions are met (e.g. dynamic linking, file-level source)
+    Conditional,
+    /// License is unknown or not classified; a human has to decide
+    NeedsReview,
+    /// Cannot be distributed under the project license
+    Incompatible,
+}
+
+impl Compatibility {
+    /// Parses a verdict identifier as written in config files (case-insensitive).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "compatible" => Some(Self::Compatible),
+            "conditional" => Some(Self::Conditional),
+            "needs-review" => Some(Self::NeedsReview),
+            "incompatible" => Some(Self::Incompatible),
+            _ => None,
+        }
+    }
+}
+
+/// Default family of common SPDX license identifiers (lowercase)
+const DEFAULT_FAMILIES: &[(&str, LicenseFamily)] = &[
+    ("0bsd", LicenseFamily::Permissive),
+    ("apache-2.0", LicenseFamily::Permissive),
+    ("artistic-2.0", LicenseFamily::Permissive),
+    ("bsd-2-clause", LicenseFamily::Permissive),
+    ("bsd-3-clause", LicenseFamily::Permissive),
+    ("bsl-1.0", LicenseFamily::Permissive),
+    ("cc0-1.0", LicenseFamily::Permissive),
+    ("hpnd", LicenseFamily::Permissive),
+    ("isc", LicenseFamily::Permissive),
+    ("mit", LicenseFamily::Permissive),
+    ("mit-0", LicenseFamily::Permissive),
+    ("ncsa", LicenseFamily::Permissive),
+    ("psf-2.0", LicenseFamily::Permissive),
+    ("python-2.0", LicenseFamily::Permissive),
+    ("unlicense", LicenseFamily::Permissive),
+    ("upl-1.0", LicenseFamily::Permissive),
+    ("wtfpl", LicenseFamily::Permissive),
+    ("zlib", LicenseFamily::Permissive),
+    ("cddl-1.0", LicenseFamily::WeakCopyleft),
+    ("cddl-1.1", LicenseFamily::WeakCopyleft),
+    ("cpl-1.0", LicenseFamily::WeakCopyleft),
+    ("epl-1.0", LicenseFamily::WeakCopyleft),
+    ("epl-2.0", LicenseFamily::WeakCopyleft),
+    ("lgpl-2.0-only", LicenseFamily::WeakCopyleft),
+    ("lgpl-2.0-or-later", LicenseFamily::WeakCopyleft),
+    ("lgpl-2.1-only", LicenseFamily::WeakCopyleft),
+    ("lgpl-2.1-or-later", LicenseFamily::WeakCopyleft),
+    ("lgpl-3.0-only", LicenseFamily::WeakCopyleft),
+    ("lgpl-3.0-or-later", LicenseFamily::WeakCopyleft),
+    ("mpl-1.1", LicenseFamily::WeakCopyleft),
+    ("mpl-2.0", LicenseFamily::WeakCopyleft),
+    ("agpl-3.0-only", LicenseFamily::StrongCopyleft),
+    ("agpl-3.0-or-later", LicenseFamily::StrongCopyleft),
+    ("cc-by-sa-4.0", LicenseFamily::StrongCopyleft),
+    ("eupl-1.1", LicenseFamily::StrongCopyleft),
+    ("eupl-1.2", LicenseFamily::StrongCopyleft),
+    ("gpl-2.0-only", LicenseFamily::StrongCopyleft),
+    ("gpl-2.0-or-later", LicenseFamily::StrongCopyleft),
+    ("gpl-3.0-only", LicenseFamily::StrongCopyleft),
+    ("gpl-3.0-or-later", LicenseFamily::StrongCopyleft),
+    ("osl-3.0", LicenseFamily::StrongCopyleft),
+    ("sspl-1.0", LicenseFamily::StrongCopyleft),
+];
+
+/// Default verdicts as (project family, dependency family, verdict)
+///
+/// Strong copyleft inside a strong copyleft project is only conditional because
+/// versions matter (GPL-2.0-only code cannot be combined with GPL-3.0 code).
+const DEFAULT_MATRIX: &[(LicenseFamily, LicenseFamily, Compatibility)] = &[
+    (
+        LicenseFamily::Permissive,
+        LicenseFamily::Permissive,
+        Compatibility::Compatible,
+    ),
+    (
+        LicenseFamily::Permissive,
+        LicenseFamily::WeakCopyleft,
+        Compatibility::Conditional,
+    ),
+    (
+        LicenseFamily::Permissive,
+        LicenseFamily::StrongCopyleft,
+        Compatibility::Incompatible,
+    ),
+    (
+        LicenseFamily::WeakCopyleft,
+        LicenseFamily::Permissive,
+        Compatibility::Compatible,
+    ),
+    (
+        LicenseFamily::WeakCopyleft,
+        LicenseFamily::WeakCopyleft,
+        Compatibility::Conditional,
+    ),
+    (
+        LicenseFamily::WeakCopyleft,
+        LicenseFamily::StrongCopyleft,
+        Compatibility::Incompatible,
+    ),
+    (
+        LicenseFamily::StrongCopyleft,
+        LicenseFamily::Permissive,
+        Compatibility::Compatible,
+    ),
+    (
+        LicenseFamily::StrongCopyleft,
+        LicenseFamily::WeakCopyleft,
+        Compatibility::Conditional,
+    ),
+    (
+        LicenseFamily::StrongCopyleft,
+        LicenseFamily::StrongCopyleft,
+        Compatibility::Conditional,
+    ),
+];
+
+/// Compatibility matrix with license family assignments and per-license overrides
+#[derive(Debug, Clone)]
+pub struct LicenseCompatibilityPolicy {
+    families: HashMap<String, LicenseFamily>,
+    matrix: HashMap<(LicenseFamily, LicenseFamily), Compatibility>,
+    license_verdicts: HashMap<String, Compatibility>,
+}
+
+impl Default for LicenseCompatibilityPolicy {
+    fn default() -> Self {
+        Self {
+            families: DEFAULT_FAMILIES
+                .iter()
+                .map(|(id, family)| (id.to_string(), *family))
+                .collect(),
+            matrix: DEFAULT_MATRIX
+                .iter()
+                .map(|(project, dependency, verdict)| ((*project, *dependency), *verdict))
+                .collect(),
+            license_verdicts: HashMap::new(),
+        }
+    }
+}
+
+impl LicenseCompatibilityPolicy {
+    /// Assigns `license` to `family`, replacing any default assignment.
+    pub fn with_family(mut self, license: &str, family: LicenseFamily) -> Self {
+        self.families.insert(normalize_license_id(license), family);
+        self
+    }
+
+    /// Sets the verdict for dependencies of `dependency` family in a `project` family project.
+    pub fn with_rule(
+        mut self,
+        project: LicenseFamily,
+        dependency: LicenseFamily,
+        verdict: Compatibility,
+    ) -> Self {
+        self.matrix.insert((project, dependency), verdict);
+        self
+    }
+
+    /// Fixes the verdict for a specific dependency license, bypassing the matrix.
+    pub fn with_license_verdict(mut self, license: &str, verdict: Compatibility) -> Self {
+        self.license_verdicts
+            .insert(normalize_license_id(license), verdict);
+        self
+    }
+
+    /// Returns the family of a single license, if it is classified.
+    pub fn family_of(&self, license: &str) -> Option<LicenseFamily> {
+        self.families.get(&normalize_license_id(license)).copied()
+    }
+
+    /// Classifies a project license, which may be an SPDX expression.
+    ///
+    /// For a choice of licenses the most permissive classified one is used, since
+    /// that is the most restrictive row of the matrix for incoming dependencies.
+    pub fn project_family(&self, license: &str) -> Option<LicenseFamily> {
+        if let Some(family) = self.family_of(license) {
+            return Some(family);
+        }
+        let order = |f: &LicenseFamily| LicenseFamily::ALL.iter().position(|a| a == f);
+        expression_leaves(license)
+            .iter()
+            .filter_map(|leaf| self.family_of(leaf))
+            .min_by_key(order)
+    }
+
+    /// Evaluates a dependency license (a name or an SPDX expression) against a project family.
+    ///
+    /// `OR` takes the best alternative and `AND` the worst part. Expressions with
+    /// parentheses are evaluated conservatively as if every operator were `AND`.
+    pub fn evaluate(&self, project: LicenseFamily, license: &str) -> Compatibility {
+        let whole = self.evaluate_single(project, license);
+        if whole != Compatibility::NeedsReview {
+            return whole;
+        }
+
+        let expression = license.trim();
+        if expression.contains('(') || expression.contains(')') {
+            return expression_leaves(expression)
+                .iter()
+                .map(|leaf| self.evaluate_single(project, leaf))
+                .max()
+                .unwrap_or(Compatibility::NeedsReview);
+        }
+        if !expression.contains(" OR ") && !expression.contains(" AND ") {
+            return whole;
+        }
+
+        expression
+            .split(" OR ")
+            .map(|alternative| {
+                alternative
+                    .split(" AND ")
+                    .map(|part| self.evaluate_single(project, part))
+                    .max()
+                    .unwrap_or(Compatibility::NeedsReview)
+            })
+            .min()
+            .unwrap_or(Compatibility::NeedsReview)
+    }
+
+    fn evaluate_single(&self, project: LicenseFamily, license: &str) -> Compatibility {
+        let id = normalize_license_id(license);
+        if let Some(verdict) = self.license_verdicts.get(&id) {
+            return *verdict;
+        }
+        self.families
+            .get(&id)
+            .and_then(|family| self.matrix.get(&(project, *family)))
+            .copied()
+            .unwrap_or(Compatibility::NeedsReview)
+    }
+}
+
+/// Normalizes a license name or SPDX id for lookup.
+///
+/// Drops a `WITH <exception>` suffix (conservatively evaluating the base
+/// license), maps common names such as "MIT License" to their SPDX id and
+/// lowercases the result.
+fn normalize_license_id(license: &str) -> String {
+    let base = license
+        .split(" WITH ")
+        .next()
+        .unwrap_or(license)
+        .trim()
+        .trim_matches(|c| c == '(' || c == ')')
+        .trim();
+    spdx_license_map::get_spdx_id(base)
+        .unwrap_or_else(|| base.to_string())
+        .to_lowercase()
+}
+
+/// Splits an SPDX expression into its license identifiers.
+fn expression_leaves(expression: &str) -> Vec<String> {
+    expression
+        .replace(['(', ')'], " ")
+        .split_whitespace()
+        .filter(|token| !matches!(*token, "AND" | "OR" | "and" | "or"))
+        .fold(Vec::<String>::new(), |mut leaves, token| {
+            // Keep `WITH <exception>` attached to its license
+            match leaves.last_mut() {
+                Some(last) if last.ends_with(" WITH") => {
+                    last.push(' ');
+                    last.push_str(token);
+                }
+                Some(last) if token == "WITH" => last.push_str(" WITH"),
+                _ => leaves.push(token.to_string()),
+            }
+            leaves
+        })
+}
+
+/// Compatibility verdict for one package
+#[derive(Debug, Clone, PartialEq)]
+pub struct LicenseCompatibilityEntry {
+    pub package_name: String,
+    pub package_version: String,
+    /// The dependency license as reported, or `None` when unknown
+    pub license: Option<String>,
+    pub verdict: Compatibility,
+}
+
+/// Result of evaluating every package against the project license
+#[derive(Debug, Clone, PartialEq)]
+pub struct LicenseCompatibilityResult {
+    /// The project license, or `None` when it could not be read
+    pub project_license: Option<String>,
+    /// Family the project license was evaluated as; `None` when unknown or unclassified
+    pub project_family: Option<LicenseFamily>,
+    /// One entry per package, in input order
+    pub entries: Vec<LicenseCompatibilityEntry>,
+}
+
+impl LicenseCompatibilityResult {
+    /// Number of packages with the given verdict
+    pub fn count(&self, verdict: Compatibility) -> usize {
+        self.entries.iter().filter(|e| e.verdict == verdict).count()
+    }
+
+    pub fn has_incompatibilities(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|e| e.verdict == Compatibility::Incompatible)
+    }
+}
+
+/// Stateless evaluation of dependency licenses against the project license.
+pub struct LicenseCompatibilityChecker;
+
+impl LicenseCompatibilityChecker {
+    /// Evaluates every package against the project license.
+    ///
+    /// # Arguments
+    /// * `project_license` - The project's own license (name or SPDX expression), if known.
+    /// * `packages` - Tuples of (name, version, license) where license may be None.
+    /// * `policy` - The compatibility matrix to apply.
+    ///
+    /// An unknown or unclassified project license is evaluated as permissive, the
+    /// most restrictive row of the matrix. Packages with an unknown license are
+    /// reported as [`Compatibility::NeedsReview`].
+    pub fn check(
+        project_license: Option<&str>,
+        packages: &[(String, String, Option<String>)],
+        policy: &LicenseCompatibilityPolicy,
+    ) -> LicenseCompatibilityResult {
+        let project_family = project_license.and_then(|l| policy.project_family(l));
+        let row = project_family.unwrap_or(LicenseFamily::Permissive);
+
+        let entries = packages
+            .iter()
+            .map(|(name, version, license)| LicenseCompatibilityEntry {
+                package_name: name.clone(),
+                package_version: version.clone(),
+                license: license.clone(),
+                verdict: license
+                    .as_deref()
+                    .map(|l| policy.evaluate(row, l))
+                    .unwrap_or(Compatibility::NeedsReview),
+            })
+            .collect();
+
+        LicenseCompatibilityResult {
+            project_license: project_license.map(str::to_string),
+            project_family,
+            entries,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Compatibility::*;
+    use LicenseFamily::*;
+
+    #[test]
+    fn test_default_matrix_apache_project() {
+        let policy = LicenseCompatibilityPolicy::default();
+        let cases = [
+            ("MIT", Compatible),
+            ("MIT License", Compatible),
+            ("BSD-3-Clause", Compatible),
+            ("Apache Software License", Compatible),
+            ("LGPL-3.0-only", Conditional),
+            ("MPL-2.0", Conditional),
+            ("GPL-3.0-only", Incompatible),
+            ("GPL-2.0-or-later", Incompatible),
+            ("AGPL-3.0-only", Incompatible),
+            ("Proprietary", NeedsReview),
+        ];
+        for (license, expected) in cases {
+            assert_eq!(
+                policy.evaluate(Permissive, license),
+                expected,
+                "{}",
+                license
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_matrix_rows() {
+        let policy = LicenseCompatibilityPolicy::default();
+        let cases = [
+            (WeakCopyleft, "MIT", Compatible),
+            (WeakCopyleft, "MPL-2.0", Conditional),
+            (WeakCopyleft, "GPL-3.0-only", Incompatible),
+            (StrongCopyleft, "MIT", Compatible),
+            (StrongCopyleft, "LGPL-2.1-only", Conditional),
+            (StrongCopyleft, "GPL-3.0-or-later", Conditional),
+        ];
+        for (project, license, expected) in cases {
+            assert_eq!(
+                policy.evaluate(project, license),
+                expected,
+                "{:?} / {}",
+                project,
+                license
+            );
+        }
+    }
+
+    #[test]
+    fn test_expressions() {
+        let policy = LicenseCompatibilityPolicy::default();
+        let cases = [
+            ("MIT OR GPL-3.0-only", Compatible),
+            ("GPL-3.0-only OR LGPL-3.0-only", Conditional),
+            ("MIT AND GPL-3.0-only", Incompatible),
+            ("MIT AND MPL-2.0", Conditional),
+            ("MIT OR Unknown-1.0", Compatible),
+            ("MIT AND Unknown-1.0", NeedsReview),
+            ("(MIT OR Apache-2.0) AND BSD-3-Clause", Compatible),
+            ("(MIT OR GPL-3.0-only) AND BSD-3-Clause", Incompatible),
+            (
+                "GPL-2.0-or-later WITH Classpath-exception-2.0",
+                Incompatible,
+            ),
+        ];
+        for (license, expected) in cases {
+            assert_eq!(
+                policy.evaluate(Permissive, license),
+                expected,
+                "{}",
+                license
+            );
+        }
+    }
+
+    #[test]
+    fn test_overrides() {
+        let policy = LicenseCompatibilityPolicy::default()
+            .with_family("Custom-1.0", Permissive)
+            .with_family("EPL-2.0", StrongCopyleft)
+            .with_rule(Permissive, WeakCopyleft, Compatible)
+            .with_license_verdict("GPL-2.0-or-later", Conditional);
+        let cases = [
+            ("Custom-1.0", Compatible),
+            ("EPL-2.0", Incompatible),
+            ("LGPL-3.0-only", Compatible),
+            ("GPL-2.0-or-later", Conditional),
+            ("GPL-3.0-only", Incompatible),
+        ];
+        for (license, expected) in cases {
+            assert_eq!(
+                policy.evaluate(Permissive, license),
+                expected,
+                "{}",
+                license
+            );
+        }
+    }
+
+    #[test]
+    fn test_project_family() {
+        let policy = LicenseCompatibilityPolicy::default();
+        let cases = [
+            ("Apache-2.0", Some(Permissive)),
+            ("Apache Software License", Some(Permissive)),
+            ("LGPL-3.0-or-later", Some(WeakCopyleft)),
+            ("GPL-3.0-only", Some(StrongCopyleft)),
+            ("GPL-3.0-only OR MIT", Some(Permissive)),
+            ("LicenseRef-Proprietary", None),
+        ];
+        for (license, expected) in cases {
+            assert_eq!(policy.project_family(license), expected, "{}", license);
+        }
+    }
+
+    #[test]
+    fn test_family_and_verdict_parsing() {
+        for family in LicenseFamily::ALL {
+            assert_eq!(LicenseFamily::parse(family.as_str()), Some(family));
+        }
+        let verdicts = [
+            ("compatible", Compatible),
+            ("conditional", Conditional),
+            ("needs-review", NeedsReview),
+            ("Incompatible", Incompatible),
+        ];
+        for (value, verdict) in verdicts {
+            assert_eq!(Compatibility::parse(value), Some(verdict));
+        }
+        assert_eq!(LicenseFamily::parse("Weak-Copyleft"), Some(WeakCopyleft));
+        assert_eq!(LicenseFamily::parse("copyleft"), None);
+        assert_eq!(Compatibility::parse("ok"), None);
+    }
+
+    fn pkg(name: &str, license: Option<&str>) -> (String, String, Option<String>) {
+        (
+            name.to_string(),
+            "1.0".to_string(),
+            license.map(str::to_string),
+        )
+    }
+
+    #[test]
+    fn test_check_counts_each_verdict() {
+        let packages = vec![
+            pkg("requests", Some("Apache-2.0")),
+            pkg("chardet", Some("LGPL-2.1-only")),
+            pkg("gpl-lib", Some("GPL-3.0-only")),
+            pkg("mystery", None),
+        ];
+        let result = LicenseCompatibilityChecker::check(
+            Some("Apache-2.0"),
+            &packages,
+            &LicenseCompatibilityPolicy::default(),
+        );
+
+        assert_eq!(result.project_family, Some(Permissive));
+        assert_eq!(result.count(Compatible), 1);
+        assert_eq!(result.count(Conditional), 1);
+        assert_eq!(result.count(Incompatible), 1);
+        assert_eq!(result.count(NeedsReview), 1);
+        assert!(result.has_incompatibilities());
+        assert_eq!(result.entries[2].package_name, "gpl-lib");
+        assert_eq!(result.entries[3].verdict, NeedsReview);
+    }
+
+    #[test]
+    fn test_check_unknown_project_license_is_evaluated_as_permissive() {
+        let packages = vec![pkg("gpl-lib", Some("GPL-3.0-only"))];
+        let result = LicenseCompatibilityChecker::check(
+            None,
+            &packages,
+            &LicenseCompatibilityPolicy::default(),
+        );
+
+        assert_eq!(result.project_license, None);
+        assert_eq!(result.project_family, None);
+        assert_eq!(result.entries[0].verdict, Incompatible);
+    }
+
+    #[test]
+    fn test_check_gpl_project_accepts_gpl_dependencies_conditionally() {
+        let packages = vec![pkg("gpl-lib", Some("GPL-3.0-only"))];
+        let result = LicenseCompatibilityChecker::check(
+            Some("GPL-3.0-or-later"),
+            &packages,
+            &LicenseCompatibilityPolicy::default(),
+        );
+
+        assert!(!result.has_incompatibilities());
+        assert_eq!(result.entries[0].verdict, Conditional);
+    }
+}
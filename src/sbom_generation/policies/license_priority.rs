@@ -1,18 +1,106 @@
-use crate::sbom_generation::domain::LicenseInfo;
+use super::spdx_license_map;
+use crate::sbom_generation::domain::{LicenseInfo, LicenseSource};
+
+/// Which license metadata wins when the fields disagree (`license_resolution`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LicenseResolution {
+    /// OSI Approved classifier, then the license field, then license_expression
+    ClassifierFirst,
+    /// License field, then license_expression, then the classifier
+    #[default]
+    LicenseFieldFirst,
+    /// The shortest of the available values
+    Shortest,
+    /// Only SPDX identifiers: license_expression as is, the license field or
+    /// classifier when it maps to an SPDX ID; anything else is no license
+    SpdxOnly,
+}
+
+impl LicenseResolution {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LicenseResolution::ClassifierFirst => "classifier_first",
+            LicenseResolution::LicenseFieldFirst => "license_field_first",
+            LicenseResolution::Shortest => "shortest",
+            LicenseResolution::SpdxOnly => "spdx_only",
+        }
+    }
+}
+
+impl std::str::FromStr for LicenseResolution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "classifier_first" => Ok(LicenseResolution::ClassifierFirst),
+            "license_field_first" => Ok(LicenseResolution::LicenseFieldFirst),
+            "shortest" => Ok(LicenseResolution::Shortest),
+            "spdx_only" => Ok(LicenseResolution::SpdxOnly),
+            _ => Err(format!(
+                "Invalid license resolution: {}. Valid values: classifier_first, license_field_first, shortest, spdx_only",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for LicenseResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// LicensePriority policy for determining license information precedence
 ///
 /// This policy encodes the business rules for selecting license information
 /// when multiple sources are available (license field, license_expression, classifiers).
 ///
-/// Priority order:
+/// The default priority order ([`LicenseResolution::LicenseFieldFirst`]):
 /// 1. license field (if non-empty and not "UNKNOWN")
 /// 2. license_expression field (if non-empty)
 /// 3. OSI Approved license from classifiers
-pub struct LicensePriority;
+///
+/// Some packages put the full license text into the license field. A license
+/// field longer than the maximum length loses to any other value; when there
+/// is none, it is cut down to its first line, and to the maximum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LicensePriority {
+    resolution: LicenseResolution,
+    max_license_length: usize,
+}
+
+impl Default for LicensePriority {
+    fn default() -> Self {
+        Self::from_policy(LicenseResolution::default())
+    }
+}
+
+/// A license value and the metadata field it was read from
+type Candidate = (String, LicenseSource);
 
 impl LicensePriority {
-    /// Selects the most appropriate license text based on priority rules
+    /// Longest license field value that is used as is
+    pub const DEFAULT_MAX_LICENSE_LENGTH: usize = 200;
+
+    /// Creates the policy for `resolution`
+    pub fn from_policy(resolution: LicenseResolution) -> Self {
+        Self {
+            resolution,
+            max_license_length: Self::DEFAULT_MAX_LICENSE_LENGTH,
+        }
+    }
+
+    /// Sets the longest license field value that is used as is
+    pub fn with_max_license_length(mut self, max_license_length: usize) -> Self {
+        self.max_license_length = max_license_length;
+        self
+    }
+
+    pub fn resolution(&self) -> LicenseResolution {
+        self.resolution
+    }
+
+    /// Selects the most appropriate license text with the default policy
     ///
     /// # Arguments
     /// * `license` - License field from package metadata
@@ -21,21 +109,98 @@ impl LicensePriority {
     ///
     /// # Returns
     /// The selected license text, or None if no valid license found
+    // Library API; the binary goes through `license_info`
+    #[allow(dead_code)]
     pub fn select_license(
         license: Option<String>,
         license_expression: Option<String>,
         classifiers: &[String],
     ) -> Option<String> {
-        // Priority 1: license field (non-empty and not "UNKNOWN")
-        license
+        Self::default()
+            .select_license_with_source(license, license_expression, classifiers)
+            .map(|(license, _)| license)
+    }
+
+    /// Selects the license text according to this policy, along with the
+    /// metadata field it was taken from
+    fn select_license_with_source(
+        &self,
+        license: Option<String>,
+        license_expression: Option<String>,
+        classifiers: &[String],
+    ) -> Option<Candidate> {
+        let field = license
             .filter(|l| !l.is_empty() && l != "UNKNOWN")
-            // Priority 2: license_expression field
-            .or_else(|| license_expression.filter(|l| !l.is_empty()))
-            // Priority 3: Extract from classifiers
-            .or_else(|| Self::extract_license_from_classifiers(classifiers))
+            .map(|l| (l, LicenseSource::PyPiLicenseField));
+        let expression = license_expression
+            .filter(|l| !l.is_empty())
+            .map(|l| (l, LicenseSource::PyPiLicenseField));
+        let classifier = Self::extract_license_from_classifiers(classifiers)
+            .map(|l| (l, LicenseSource::PyPiClassifier));
+
+        // A license field holding the full license text only counts when
+        // nothing else is available
+        let (field, long_field) = match field {
+            Some((text, source)) if text.chars().count() > self.max_license_length => {
+                (None, Some((self.truncate(&text), source)))
+            }
+            field => (field, None),
+        };
+
+        let candidates = match self.resolution {
+            LicenseResolution::LicenseFieldFirst => vec![field, expression, classifier],
+            LicenseResolution::ClassifierFirst => vec![classifier, field, expression],
+            LicenseResolution::Shortest => {
+                let shortest = [field, expression, classifier]
+                    .into_iter()
+                    .flatten()
+                    .min_by_key(|(text, _)| text.chars().count());
+                vec![shortest]
+            }
+            LicenseResolution::SpdxOnly => {
+                let to_spdx = |candidate: Option<Candidate>| {
+                    candidate.and_then(|(text, source)| {
+                        spdx_license_map::get_spdx_id(&text).map(|id| (id, source))
+                    })
+                };
+                // A long field is never an SPDX identifier
+                return expression
+                    .or_else(|| to_spdx(field))
+                    .or_else(|| to_spdx(classifier));
+            }
+        };
+        candidates.into_iter().flatten().next().or(long_field)
+    }
+
+    /// Cuts a license text down to its first line, and to the maximum length
+    fn truncate(&self, text: &str) -> String {
+        let first_line = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or_default();
+        if first_line.chars().count() <= self.max_license_length {
+            return first_line.to_string();
+        }
+        let cut: String = first_line.chars().take(self.max_license_length).collect();
+        format!("{}...", cut.trim_end())
+    }
+
+    /// Creates a LicenseInfo from PyPI package metadata with the default policy
+    ///
+    /// See [`Self::license_info`].
+    // Library API; the binary goes through `license_info`
+    #[allow(dead_code)]
+    pub fn create_license_info(
+        license: Option<String>,
+        license_expression: Option<String>,
+        classifiers: &[String],
+        summary: Option<String>,
+    ) -> LicenseInfo {
+        Self::default().license_info(license, license_expression, classifiers, summary)
     }
 
-    /// Creates a LicenseInfo from PyPI package metadata
+    /// Creates a LicenseInfo from PyPI package metadata according to this policy
     ///
     /// # Arguments
     /// * `license` - License field from PyPI API
@@ -44,15 +209,19 @@ impl LicensePriority {
     /// * `summary` - Package summary/description from PyPI API
     ///
     /// # Returns
-    /// LicenseInfo with selected license and description
-    pub fn create_license_info(
+    /// LicenseInfo with selected license and description, and the PyPI field
+    /// the license came from as its source
+    pub fn license_info(
+        &self,
         license: Option<String>,
         license_expression: Option<String>,
         classifiers: &[String],
         summary: Option<String>,
     ) -> LicenseInfo {
-        let selected_license = Self::select_license(license, license_expression, classifiers);
-        LicenseInfo::new(selected_license, summary)
+        match self.select_license_with_source(license, license_expression, classifiers) {
+            Some((license, source)) => LicenseInfo::new(Some(license), summary).with_source(source),
+            None => LicenseInfo::new(None, summary),
+        }
     }
 
     /// Extracts license information from classifier strings
@@ -217,4 +386,211 @@ mod tests {
         let result = LicensePriority::select_license(license, license_expression, &classifiers);
         assert_eq!(result, Some("MIT OR Apache-2.0".to_string()));
     }
+
+    #[test]
+    fn test_create_license_info_records_source() {
+        let classifiers = vec!["License :: OSI Approved :: MIT License".to_string()];
+
+        let field =
+            LicensePriority::create_license_info(Some("MIT".to_string()), None, &classifiers, None);
+        let expression =
+            LicensePriority::create_license_info(None, Some("MIT".to_string()), &[], None);
+        let classifier = LicensePriority::create_license_info(None, None, &classifiers, None);
+        let none = LicensePriority::create_license_info(None, None, &[], None);
+
+        assert_eq!(field.source(), LicenseSource::PyPiLicenseField);
+        assert_eq!(expression.source(), LicenseSource::PyPiLicenseField);
+        assert_eq!(classifier.source(), LicenseSource::PyPiClassifier);
+        assert_eq!(none.source(), LicenseSource::Unknown);
+    }
+
+    /// PyPI metadata shapes where the license field and the classifier disagree
+    mod fixtures {
+        /// The full license text in the license field, the SPDX name in the classifier
+        pub const FULL_TEXT_LICENSE: &str = "Copyright (c) 2010 Pallets\n\nRedistribution and use in source and binary forms, with or without modification, are permitted provided that the following conditions are met:\n\n1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following disclaimer.";
+        pub const FULL_TEXT_CLASSIFIER: &str = "License :: OSI Approved :: BSD License";
+
+        /// A vague classifier, the precise identifier in the license field
+        pub const PRECISE_FIELD: &str = "BSD-3-Clause";
+        pub const VAGUE_CLASSIFIER: &str = "License :: OSI Approved :: BSD License";
+
+        /// A free-text license field that names no SPDX license
+        pub const FREE_TEXT_FIELD: &str = "Dual License";
+        pub const MIT_CLASSIFIER: &str = "License :: OSI Approved :: MIT License";
+    }
+
+    fn select(
+        priority: LicensePriority,
+        license: Option<&str>,
+        license_expression: Option<&str>,
+        classifiers: &[&str],
+    ) -> Option<Candidate> {
+        let classifiers: Vec<String> = classifiers.iter().map(|c| c.to_string()).collect();
+        priority.select_license_with_source(
+            license.map(str::to_string),
+            license_expression.map(str::to_string),
+            &classifiers,
+        )
+    }
+
+    fn policy(resolution: LicenseResolution) -> LicensePriority {
+        LicensePriority::from_policy(resolution)
+    }
+
+    #[test]
+    fn test_license_resolution_parses_config_values() {
+        for resolution in [
+            LicenseResolution::ClassifierFirst,
+            LicenseResolution::LicenseFieldFirst,
+            LicenseResolution::Shortest,
+            LicenseResolution::SpdxOnly,
+        ] {
+            assert_eq!(resolution.as_str().parse(), Ok(resolution));
+        }
+        assert!("classifier".parse::<LicenseResolution>().is_err());
+        assert_eq!(
+            LicensePriority::default().resolution(),
+            LicenseResolution::LicenseFieldFirst
+        );
+    }
+
+    #[test]
+    fn test_full_text_license_field_loses_to_classifier_under_every_policy() {
+        use fixtures::*;
+
+        for resolution in [
+            LicenseResolution::ClassifierFirst,
+            LicenseResolution::LicenseFieldFirst,
+            LicenseResolution::Shortest,
+        ] {
+            assert_eq!(
+                select(
+                    policy(resolution),
+                    Some(FULL_TEXT_LICENSE),
+                    None,
+                    &[FULL_TEXT_CLASSIFIER]
+                ),
+                Some(("BSD License".to_string(), LicenseSource::PyPiClassifier)),
+                "{resolution}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_full_text_license_field_is_truncated_without_alternative() {
+        use fixtures::*;
+
+        let result = select(
+            LicensePriority::default(),
+            Some(FULL_TEXT_LICENSE),
+            None,
+            &[],
+        );
+        assert_eq!(
+            result,
+            Some((
+                "Copyright (c) 2010 Pallets".to_string(),
+                LicenseSource::PyPiLicenseField
+            ))
+        );
+
+        let short = LicensePriority::default().with_max_license_length(10);
+        let (text, _) = select(short, Some("Licensed under the terms of MIT"), None, &[]).unwrap();
+        assert_eq!(text, "Licensed u...");
+    }
+
+    #[test]
+    fn test_precise_field_with_vague_classifier() {
+        use fixtures::*;
+
+        let field_first = select(
+            policy(LicenseResolution::LicenseFieldFirst),
+            Some(PRECISE_FIELD),
+            None,
+            &[VAGUE_CLASSIFIER],
+        );
+        let classifier_first = select(
+            policy(LicenseResolution::ClassifierFirst),
+            Some(PRECISE_FIELD),
+            None,
+            &[VAGUE_CLASSIFIER],
+        );
+        let shortest = select(
+            policy(LicenseResolution::Shortest),
+            Some(PRECISE_FIELD),
+            None,
+            &[VAGUE_CLASSIFIER],
+        );
+        let spdx_only = select(
+            policy(LicenseResolution::SpdxOnly),
+            Some(PRECISE_FIELD),
+            None,
+            &[VAGUE_CLASSIFIER],
+        );
+
+        assert_eq!(field_first.unwrap().0, "BSD-3-Clause");
+        assert_eq!(classifier_first.unwrap().0, "BSD License");
+        assert_eq!(shortest.unwrap().0, "BSD License");
+        assert_eq!(spdx_only.unwrap().0, "BSD-3-Clause");
+    }
+
+    #[test]
+    fn test_free_text_field_with_precise_classifier() {
+        use fixtures::*;
+
+        let field_first = select(
+            policy(LicenseResolution::LicenseFieldFirst),
+            Some(FREE_TEXT_FIELD),
+            None,
+            &[MIT_CLASSIFIER],
+        );
+        let spdx_only = select(
+            policy(LicenseResolution::SpdxOnly),
+            Some(FREE_TEXT_FIELD),
+            None,
+            &[MIT_CLASSIFIER],
+        );
+
+        assert_eq!(field_first.unwrap().0, "Dual License");
+        assert_eq!(
+            spdx_only,
+            Some(("MIT".to_string(), LicenseSource::PyPiClassifier))
+        );
+    }
+
+    #[test]
+    fn test_spdx_only_prefers_expression_and_rejects_unmapped_values() {
+        use fixtures::*;
+
+        let expression = select(
+            policy(LicenseResolution::SpdxOnly),
+            Some("MIT"),
+            Some("MIT OR Apache-2.0"),
+            &[],
+        );
+        assert_eq!(expression.unwrap().0, "MIT OR Apache-2.0");
+
+        let unmapped = select(
+            policy(LicenseResolution::SpdxOnly),
+            Some(FREE_TEXT_FIELD),
+            None,
+            &[VAGUE_CLASSIFIER],
+        );
+        assert_eq!(unmapped, None);
+    }
+
+    #[test]
+    fn test_license_info_applies_policy() {
+        use fixtures::*;
+
+        let info = policy(LicenseResolution::ClassifierFirst).license_info(
+            Some(PRECISE_FIELD.to_string()),
+            None,
+            &[VAGUE_CLASSIFIER.to_string()],
+            None,
+        );
+
+        assert_eq!(info.license_text(), Some("BSD License"));
+        assert_eq!(info.source(), LicenseSource::PyPiClassifier);
+    }
 }
@@ -1,4 +1,10 @@
+pub mod license_compatibility;
 mod license_priority;
+pub mod popular_packages;
 pub mod spdx_license_map;
 
-pub use license_priority::LicensePriority;
+pub use license_compatibility::{
+    Compatibility, LicenseCompatibilityChecker, LicenseCompatibilityPolicy,
+    LicenseCompatibilityResult, LicenseFamily,
+};
+pub use license_priority::{LicensePriority, LicenseResolution};
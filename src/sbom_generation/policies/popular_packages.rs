@@ -0,0 +1,361 @@
+//! Popular PyPI package names checked for look-alike typosquats
+//!
+//! A snapshot of the most downloaded projects on PyPI, written in their
+//! PEP 503 normalized form. The list is compiled into the binary so the
+//! typosquat check works offline.
+
+/// Normalized names of widely used PyPI packages.
+pub const POPULAR_PACKAGES: &[&str] = &[
+    // Packaging and build tooling
+    "pip",
+    "setuptools",
+    "wheel",
+    "packaging",
+    "virtualenv",
+    "pipenv",
+    "poetry",
+    "poetry-core",
+    "hatchling",
+    "flit-core",
+    "build",
+    "twine",
+    "tox",
+    "nox",
+    "pyproject-hooks",
+    "setuptools-scm",
+    "distlib",
+    "filelock",
+    "platformdirs",
+    "importlib-metadata",
+    "importlib-resources",
+    "zipp",
+    "pkginfo",
+    "cython",
+    "scikit-build",
+    "maturin",
+    "pybind11",
+    // HTTP, networking and cloud SDKs
+    "requests",
+    "urllib3",
+    "certifi",
+    "charset-normalizer",
+    "chardet",
+    "idna",
+    "httpx",
+    "httpcore",
+    "h11",
+    "aiohttp",
+    "aiosignal",
+    "frozenlist",
+    "multidict",
+    "yarl",
+    "requests-oauthlib",
+    "requests-toolbelt",
+    "oauthlib",
+    "websockets",
+    "websocket-client",
+    "paramiko",
+    "pysocks",
+    "boto3",
+    "botocore",
+    "s3transfer",
+    "s3fs",
+    "aiobotocore",
+    "awscli",
+    "google-auth",
+    "google-api-core",
+    "google-api-python-client",
+    "google-cloud-core",
+    "google-cloud-storage",
+    "google-cloud-bigquery",
+    "google-resumable-media",
+    "googleapis-common-protos",
+    "grpcio",
+    "grpcio-tools",
+    "grpcio-status",
+    "protobuf",
+    "azure-core",
+    "azure-identity",
+    "azure-storage-blob",
+    "msal",
+    "msrest",
+    "kubernetes",
+    "docker",
+    "fsspec",
+    "gcsfs",
+    // Cryptography and security
+    "cryptography",
+    "pyopenssl",
+    "cffi",
+    "pycparser",
+    "pyasn1",
+    "pyasn1-modules",
+    "rsa",
+    "pyjwt",
+    "bcrypt",
+    "pynacl",
+    "pycryptodome",
+    "pycryptodomex",
+    "keyring",
+    "jeepney",
+    "secretstorage",
+    "passlib",
+    // Data science and machine learning
+    "numpy",
+    "pandas",
+    "scipy",
+    "matplotlib",
+    "seaborn",
+    "plotly",
+    "scikit-learn",
+    "scikit-image",
+    "statsmodels",
+    "sympy",
+    "networkx",
+    "numba",
+    "llvmlite",
+    "pyarrow",
+    "polars",
+    "dask",
+    "xarray",
+    "h5py",
+    "tables",
+    "openpyxl",
+    "xlrd",
+    "xlsxwriter",
+    "tensorflow",
+    "tensorboard",
+    "keras",
+    "torch",
+    "torchvision",
+    "torchaudio",
+    "transformers",
+    "tokenizers",
+    "huggingface-hub",
+    "datasets",
+    "safetensors",
+    "accelerate",
+    "sentencepiece",
+    "xgboost",
+    "lightgbm",
+    "catboost",
+    "joblib",
+    "threadpoolctl",
+    "opencv-python",
+    "pillow",
+    "imageio",
+    "nltk",
+    "spacy",
+    "gensim",
+    "openai",
+    "anthropic",
+    "langchain",
+    "tiktoken",
+    "mlflow",
+    "wandb",
+    "onnx",
+    "onnxruntime",
+    "jax",
+    "jaxlib",
+    "kiwisolver",
+    "cycler",
+    "fonttools",
+    "contourpy",
+    "pyparsing",
+    "tqdm",
+    // Web frameworks and servers
+    "django",
+    "djangorestframework",
+    "flask",
+    "werkzeug",
+    "jinja2",
+    "markupsafe",
+    "itsdangerous",
+    "fastapi",
+    "starlette",
+    "uvicorn",
+    "gunicorn",
+    "uvloop",
+    "httptools",
+    "tornado",
+    "bottle",
+    "pyramid",
+    "sanic",
+    "gevent",
+    "greenlet",
+    "eventlet",
+    "celery",
+    "kombu",
+    "billiard",
+    "vine",
+    "amqp",
+    "redis",
+    "pymongo",
+    "elasticsearch",
+    "sqlalchemy",
+    "alembic",
+    "psycopg2",
+    "psycopg2-binary",
+    "psycopg",
+    "asyncpg",
+    "pymysql",
+    "mysqlclient",
+    "sqlparse",
+    "peewee",
+    "graphql-core",
+    "flask-sqlalchemy",
+    "flask-cors",
+    "whitenoise",
+    // Serialization, parsing and validation
+    "pyyaml",
+    "ruamel-yaml",
+    "toml",
+    "tomli",
+    "tomlkit",
+    "simplejson",
+    "ujson",
+    "orjson",
+    "msgpack",
+    "jsonschema",
+    "jsonschema-specifications",
+    "referencing",
+    "rpds-py",
+    "jsonpointer",
+    "jsonpatch",
+    "jmespath",
+    "pydantic",
+    "pydantic-core",
+    "pydantic-settings",
+    "marshmallow",
+    "attrs",
+    "cattrs",
+    "lxml",
+    "beautifulsoup4",
+    "soupsieve",
+    "html5lib",
+    "defusedxml",
+    "xmltodict",
+    "markdown",
+    "markdown-it-py",
+    "mdurl",
+    "mistune",
+    "docutils",
+    "pygments",
+    "babel",
+    "python-multipart",
+    "email-validator",
+    "dnspython",
+    "validators",
+    // Date, time and text utilities
+    "python-dateutil",
+    "pytz",
+    "tzdata",
+    "tzlocal",
+    "arrow",
+    "pendulum",
+    "six",
+    "typing-extensions",
+    "typing-inspect",
+    "mypy-extensions",
+    "wrapt",
+    "decorator",
+    "deprecated",
+    "cachetools",
+    "more-itertools",
+    "toolz",
+    "sortedcontainers",
+    "regex",
+    "unidecode",
+    "python-slugify",
+    "text-unidecode",
+    "wcwidth",
+    "colorama",
+    "termcolor",
+    "rich",
+    "click",
+    "typer",
+    "prompt-toolkit",
+    "tabulate",
+    "humanize",
+    "psutil",
+    "python-dotenv",
+    "pexpect",
+    "ptyprocess",
+    "dill",
+    "cloudpickle",
+    "tenacity",
+    "backoff",
+    "retry",
+    "pathspec",
+    "watchdog",
+    "sniffio",
+    "anyio",
+    "exceptiongroup",
+    "async-timeout",
+    "distro",
+    "appdirs",
+    "jsonpickle",
+    "structlog",
+    "loguru",
+    "sentry-sdk",
+    "opentelemetry-api",
+    "opentelemetry-sdk",
+    "prometheus-client",
+    "smmap",
+    "gitdb",
+    "gitpython",
+    "pycodestyle",
+    // Testing, linting and development tools
+    "pytest",
+    "pytest-cov",
+    "pytest-mock",
+    "pytest-xdist",
+    "pytest-asyncio",
+    "pytest-timeout",
+    "pluggy",
+    "iniconfig",
+    "coverage",
+    "mock",
+    "hypothesis",
+    "faker",
+    "freezegun",
+    "responses",
+    "moto",
+    "black",
+    "isort",
+    "flake8",
+    "pyflakes",
+    "pylint",
+    "astroid",
+    "mypy",
+    "ruff",
+    "bandit",
+    "pre-commit",
+    "identify",
+    "nodeenv",
+    "cfgv",
+    "sphinx",
+    "mkdocs",
+    "ipython",
+    "ipykernel",
+    "jupyter",
+    "jupyterlab",
+    "notebook",
+    "nbformat",
+    "nbconvert",
+    "traitlets",
+    "jedi",
+    "parso",
+    "debugpy",
+    "pyzmq",
+    "setproctitle",
+    "pywin32",
+    "pyinstaller",
+    "selenium",
+    "playwright",
+    "scrapy",
+    "twisted",
+    "zope-interface",
+    "pycairo",
+    "pygobject",
+];
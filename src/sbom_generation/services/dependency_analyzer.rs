@@ -1,6 +1,16 @@
-use crate::sbom_generation::domain::{DependencyGraph, PackageName};
+use crate::sbom_generation::domain::{DependencyGraph, Package, PackageName};
 use crate::shared::Result;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A package locked at more than one version, e.g. because the resolution
+/// forks on platform markers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionConflict {
+    /// Package name as the lockfile spells it
+    pub name: String,
+    /// Locked versions, in lockfile order
+    pub versions: Vec<String>,
+}
 
 /// DependencyAnalyzer service for analyzing transitive dependencies
 ///
@@ -11,22 +21,97 @@ pub struct DependencyAnalyzer;
 impl DependencyAnalyzer {
     /// Analyzes dependencies and builds a DependencyGraph
     ///
+    /// A project normally has a single root. A uv workspace has one root per
+    /// member; their direct dependencies are merged: a package is direct when
+    /// it is a direct dependency of any root, and it is listed once even if
+    /// several roots depend on it. Roots that depend on each other are not
+    /// reported as dependencies, since they are part of the project itself.
+    ///
     /// # Arguments
-    /// * `project_name` - The name of the root project
+    /// * `roots` - The root project, or the workspace members (root project first)
     /// * `dependency_map` - Map of package name to its dependencies
     ///
     /// # Returns
-    /// A DependencyGraph containing direct dependencies and transitive dependencies
+    /// A DependencyGraph containing the merged direct dependencies and their
+    /// transitive dependencies
     pub fn analyze(
-        project_name: &PackageName,
+        roots: &[PackageName],
         dependency_map: &HashMap<String, Vec<String>>,
     ) -> Result<DependencyGraph> {
-        // Extract direct dependencies for the project
-        let direct_deps = dependency_map
-            .get(project_name.as_str())
-            .cloned()
-            .unwrap_or_default();
+        let root_names: HashSet<&str> = roots.iter().map(|r| r.as_str()).collect();
+
+        // Merge direct dependencies of every root, keeping first-seen order
+        let mut direct_deps: Vec<String> = Vec::new();
+        for root in roots {
+            for dep in dependency_map.get(root.as_str()).into_iter().flatten() {
+                if !root_names.contains(dep.as_str()) && !direct_deps.contains(dep) {
+                    direct_deps.push(dep.clone());
+                }
+            }
+        }
+
+        let package_edges = Self::build_package_edges(&root_names, dependency_map)?;
+        Self::build_graph(&direct_deps, dependency_map, package_edges)
+    }
+
+    /// Analyzes the dependencies of an arbitrary set of packages, e.g. to see
+    /// what `boto3` pulls in
+    ///
+    /// Unlike [`Self::analyze`], the roots are not the project but packages of
+    /// the lockfile: they become the direct dependencies of the graph, each with
+    /// its own subtree as transitive dependencies. A package reachable from
+    /// several roots is listed under each of them. A root that another root
+    /// depends on stays a direct dependency and is not repeated as transitive.
+    ///
+    /// # Arguments
+    /// * `roots` - Packages to root the graph at, in the order they are reported
+    /// * `dependency_map` - Map of package name to its dependencies
+    pub fn analyze_with_roots(
+        roots: &[PackageName],
+        dependency_map: &HashMap<String, Vec<String>>,
+    ) -> Result<DependencyGraph> {
+        let mut direct_deps: Vec<String> = Vec::new();
+        for root in roots {
+            if !direct_deps.iter().any(|d| d == root.as_str()) {
+                direct_deps.push(root.as_str().to_string());
+            }
+        }
+        let package_edges = Self::build_package_edges(&HashSet::new(), dependency_map)?;
+        Self::build_graph(&direct_deps, dependency_map, package_edges)
+    }
+
+    /// Lists the packages of `packages` that no direct dependency of `graph`
+    /// reaches, in the order given and without duplicates
+    ///
+    /// The lockfile's project package is never listed.
+    pub fn find_unreferenced<'a>(
+        graph: &DependencyGraph,
+        packages: impl IntoIterator<Item = &'a Package>,
+    ) -> Result<Vec<PackageName>> {
+        let reached: HashSet<&PackageName> = graph
+            .direct_dependencies()
+            .iter()
+            .chain(graph.transitive_dependencies().values().flatten())
+            .collect();
+        let mut unreferenced: Vec<PackageName> = Vec::new();
+        for package in packages {
+            if package.is_project_root() {
+                continue;
+            }
+            let name = PackageName::new(package.name().to_string())?;
+            if !reached.contains(&name) && !unreferenced.contains(&name) {
+                unreferenced.push(name);
+            }
+        }
+        Ok(unreferenced)
+    }
 
+    /// Builds the graph of `direct_deps` and the packages each of them reaches
+    fn build_graph(
+        direct_deps: &[String],
+        dependency_map: &HashMap<String, Vec<String>>,
+        package_edges: HashMap<PackageName, Vec<PackageName>>,
+    ) -> Result<DependencyGraph> {
         let direct_deps_names: Vec<PackageName> = direct_deps
             .iter()
             .map(|name| PackageName::new(name.clone()))
@@ -36,7 +121,7 @@ impl DependencyAnalyzer {
         let direct_deps_set: HashSet<String> = direct_deps.iter().cloned().collect();
         let mut transitive_dependencies: HashMap<PackageName, Vec<PackageName>> = HashMap::new();
 
-        for direct_dep in &direct_deps {
+        for direct_dep in direct_deps {
             let mut trans_deps = Vec::new();
             let mut visited = HashSet::new();
 
@@ -59,8 +144,6 @@ impl DependencyAnalyzer {
             }
         }
 
-        let package_edges = Self::build_package_edges(project_name, dependency_map)?;
-
         Ok(DependencyGraph::new(
             direct_deps_names,
             transitive_dependencies,
@@ -68,16 +151,42 @@ impl DependencyAnalyzer {
         ))
     }
 
+    /// Lists the packages present at more than one version, sorted by name
+    ///
+    /// Names are compared after normalization, so `Foo_Bar` and `foo-bar` are
+    /// the same package.
+    pub fn find_version_conflicts<'a>(
+        packages: impl IntoIterator<Item = &'a Package>,
+    ) -> Vec<VersionConflict> {
+        let mut by_name: BTreeMap<String, VersionConflict> = BTreeMap::new();
+        for package in packages {
+            let entry = by_name
+                .entry(PackageName::normalize(package.name()))
+                .or_insert_with(|| VersionConflict {
+                    name: package.name().to_string(),
+                    versions: Vec::new(),
+                });
+            let version = package.version().to_string();
+            if !entry.versions.contains(&version) {
+                entry.versions.push(version);
+            }
+        }
+        by_name
+            .into_values()
+            .filter(|conflict| conflict.versions.len() > 1)
+            .collect()
+    }
+
     /// Builds an edge map of `pkg → immediate children` for every package in
-    /// `dependency_map` except the project root. This map is consumed by
+    /// `dependency_map` except the project roots. This map is consumed by
     /// [`DependencyGraph::find_paths_to`] for multi-hop BFS path traversal.
     fn build_package_edges(
-        project_name: &PackageName,
+        roots: &HashSet<&str>,
         dependency_map: &HashMap<String, Vec<String>>,
     ) -> Result<HashMap<PackageName, Vec<PackageName>>> {
         let mut package_edges: HashMap<PackageName, Vec<PackageName>> = HashMap::new();
         for (parent, children) in dependency_map {
-            if parent == project_name.as_str() {
+            if roots.contains(parent.as_str()) {
                 continue;
             }
             let parent_name = PackageName::new(parent.clone())?;
@@ -160,7 +269,7 @@ mod tests {
         dependency_map.insert("requests".to_string(), vec!["urllib3".to_string()]);
 
         let project_name = PackageName::new("myproject".to_string()).unwrap();
-        let graph = DependencyAnalyzer::analyze(&project_name, &dependency_map).unwrap();
+        let graph = DependencyAnalyzer::analyze(&[project_name], &dependency_map).unwrap();
 
         assert_eq!(graph.direct_dependency_count(), 1);
         assert_eq!(graph.direct_dependencies()[0].as_str(), "requests");
@@ -174,6 +283,34 @@ mod tests {
         assert_eq!(trans_deps[&requests_name][0].as_str(), "urllib3");
     }
 
+    #[test]
+    fn test_find_version_conflicts() {
+        let packages = vec![
+            Package::new("numpy".to_string(), "2.0.0".to_string()).unwrap(),
+            Package::new("requests".to_string(), "2.31.0".to_string()).unwrap(),
+            Package::new("numpy".to_string(), "1.26.4".to_string()).unwrap(),
+            Package::new("Attrs".to_string(), "23.1.0".to_string()).unwrap(),
+            Package::new("attrs".to_string(), "24.2.0".to_string()).unwrap(),
+            Package::new("requests".to_string(), "2.31.0".to_string()).unwrap(),
+        ];
+
+        let conflicts = DependencyAnalyzer::find_version_conflicts(&packages);
+
+        assert_eq!(
+            conflicts,
+            [
+                VersionConflict {
+                    name: "Attrs".to_string(),
+                    versions: vec!["23.1.0".to_string(), "24.2.0".to_string()],
+                },
+                VersionConflict {
+                    name: "numpy".to_string(),
+                    versions: vec!["2.0.0".to_string(), "1.26.4".to_string()],
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_analyze_no_transitive_dependencies() {
         let mut dependency_map = HashMap::new();
@@ -181,7 +318,7 @@ mod tests {
         dependency_map.insert("simple-lib".to_string(), vec![]);
 
         let project_name = PackageName::new("myproject".to_string()).unwrap();
-        let graph = DependencyAnalyzer::analyze(&project_name, &dependency_map).unwrap();
+        let graph = DependencyAnalyzer::analyze(&[project_name], &dependency_map).unwrap();
 
         assert_eq!(graph.direct_dependency_count(), 1);
         assert_eq!(graph.transitive_dependency_count(), 0);
@@ -198,7 +335,7 @@ mod tests {
         dependency_map.insert("numpy".to_string(), vec![]);
 
         let project_name = PackageName::new("myproject".to_string()).unwrap();
-        let graph = DependencyAnalyzer::analyze(&project_name, &dependency_map).unwrap();
+        let graph = DependencyAnalyzer::analyze(&[project_name], &dependency_map).unwrap();
 
         assert_eq!(graph.direct_dependency_count(), 2);
         assert_eq!(graph.transitive_dependency_count(), 1);
@@ -260,7 +397,7 @@ mod tests {
         dependency_map.insert("myproject".to_string(), vec![]);
 
         let project_name = PackageName::new("myproject".to_string()).unwrap();
-        let graph = DependencyAnalyzer::analyze(&project_name, &dependency_map).unwrap();
+        let graph = DependencyAnalyzer::analyze(&[project_name], &dependency_map).unwrap();
 
         assert_eq!(graph.direct_dependency_count(), 0);
         assert_eq!(graph.transitive_dependency_count(), 0);
@@ -276,7 +413,7 @@ mod tests {
         dependency_map.insert("c".to_string(), vec![]);
 
         let project_name = PackageName::new("myproject".to_string()).unwrap();
-        let graph = DependencyAnalyzer::analyze(&project_name, &dependency_map).unwrap();
+        let graph = DependencyAnalyzer::analyze(&[project_name], &dependency_map).unwrap();
 
         let target = PackageName::new("c".to_string()).unwrap();
         let paths = graph.find_paths_to(&target);
@@ -299,7 +436,7 @@ mod tests {
         dependency_map.insert("requests".to_string(), vec!["urllib3".to_string()]);
 
         let project_name = PackageName::new("myproject".to_string()).unwrap();
-        let graph = DependencyAnalyzer::analyze(&project_name, &dependency_map).unwrap();
+        let graph = DependencyAnalyzer::analyze(&[project_name], &dependency_map).unwrap();
 
         let target = PackageName::new("urllib3".to_string()).unwrap();
         let paths = graph.find_paths_to(&target);
@@ -330,7 +467,7 @@ mod tests {
         dependency_map.insert("target".to_string(), vec![]);
 
         let project_name = PackageName::new("myproject".to_string()).unwrap();
-        let graph = DependencyAnalyzer::analyze(&project_name, &dependency_map).unwrap();
+        let graph = DependencyAnalyzer::analyze(&[project_name], &dependency_map).unwrap();
         let paths = graph.find_paths_to(&PackageName::new("target".to_string()).unwrap());
 
         assert_eq!(paths.len(), 2);
@@ -340,4 +477,154 @@ mod tests {
             assert_eq!(p[1].as_str(), "shared");
         }
     }
+
+    fn names(names: &[&str]) -> Vec<PackageName> {
+        names
+            .iter()
+            .map(|n| PackageName::new(n.to_string()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_analyze_workspace_merges_direct_dependencies_of_all_roots() {
+        // api -> requests, shared-lib; worker -> celery, requests, shared-lib
+        // shared-lib (member) -> pydantic; requests -> urllib3; celery -> kombu
+        let dependency_map = HashMap::from([
+            (
+                "api".to_string(),
+                vec!["requests".to_string(), "shared-lib".to_string()],
+            ),
+            (
+                "worker".to_string(),
+                vec![
+                    "celery".to_string(),
+                    "requests".to_string(),
+                    "shared-lib".to_string(),
+                ],
+            ),
+            ("shared-lib".to_string(), vec!["pydantic".to_string()]),
+            ("requests".to_string(), vec!["urllib3".to_string()]),
+            ("celery".to_string(), vec!["kombu".to_string()]),
+            ("urllib3".to_string(), vec![]),
+            ("kombu".to_string(), vec![]),
+            ("pydantic".to_string(), vec![]),
+        ]);
+
+        let graph =
+            DependencyAnalyzer::analyze(&names(&["api", "worker", "shared-lib"]), &dependency_map)
+                .unwrap();
+
+        // Shared direct dependencies appear once; members are not dependencies
+        assert_eq!(
+            graph.direct_dependencies(),
+            names(&["requests", "celery", "pydantic"]).as_slice()
+        );
+        assert_eq!(graph.transitive_dependency_count(), 2);
+        let trans_deps = graph.transitive_dependencies();
+        assert_eq!(trans_deps[&names(&["requests"])[0]], names(&["urllib3"]));
+        assert_eq!(trans_deps[&names(&["celery"])[0]], names(&["kombu"]));
+        assert_eq!(
+            graph.find_paths_to(&names(&["kombu"])[0]),
+            vec![names(&["celery", "kombu"])]
+        );
+    }
+
+    /// app -> boto3, flask; boto3 -> botocore, s3transfer; s3transfer -> botocore;
+    /// botocore -> urllib3; flask -> werkzeug; requests -> urllib3
+    fn boto_lockfile() -> HashMap<String, Vec<String>> {
+        HashMap::from([
+            (
+                "app".to_string(),
+                vec!["boto3".to_string(), "flask".to_string()],
+            ),
+            (
+                "boto3".to_string(),
+                vec!["botocore".to_string(), "s3transfer".to_string()],
+            ),
+            ("s3transfer".to_string(), vec!["botocore".to_string()]),
+            ("botocore".to_string(), vec!["urllib3".to_string()]),
+            ("flask".to_string(), vec!["werkzeug".to_string()]),
+            ("requests".to_string(), vec!["urllib3".to_string()]),
+        ])
+    }
+
+    #[test]
+    fn test_analyze_with_roots_makes_roots_direct_dependencies() {
+        let graph =
+            DependencyAnalyzer::analyze_with_roots(&names(&["boto3"]), &boto_lockfile()).unwrap();
+
+        assert_eq!(graph.direct_dependencies(), names(&["boto3"]).as_slice());
+        assert_eq!(
+            graph.transitive_dependencies()[&names(&["boto3"])[0]],
+            names(&["botocore", "urllib3", "s3transfer"])
+        );
+        assert_eq!(
+            graph.find_paths_to(&names(&["urllib3"])[0]),
+            vec![
+                names(&["boto3", "botocore", "urllib3"]),
+                names(&["boto3", "s3transfer", "botocore", "urllib3"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_with_roots_lists_overlapping_subtrees_under_each_root() {
+        let graph = DependencyAnalyzer::analyze_with_roots(
+            &names(&["requests", "boto3", "requests"]),
+            &boto_lockfile(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            graph.direct_dependencies(),
+            names(&["requests", "boto3"]).as_slice()
+        );
+        let trans_deps = graph.transitive_dependencies();
+        assert_eq!(trans_deps[&names(&["requests"])[0]], names(&["urllib3"]));
+        assert!(trans_deps[&names(&["boto3"])[0]].contains(&names(&["urllib3"])[0]));
+        // urllib3 is shared by both roots but counted once
+        assert_eq!(graph.transitive_dependency_count(), 3);
+    }
+
+    #[test]
+    fn test_analyze_with_roots_keeps_nested_root_direct() {
+        let graph = DependencyAnalyzer::analyze_with_roots(
+            &names(&["boto3", "botocore"]),
+            &boto_lockfile(),
+        )
+        .unwrap();
+
+        let trans_deps = graph.transitive_dependencies();
+        assert_eq!(
+            trans_deps[&names(&["boto3"])[0]],
+            names(&["urllib3", "s3transfer"])
+        );
+        assert_eq!(trans_deps[&names(&["botocore"])[0]], names(&["urllib3"]));
+    }
+
+    #[test]
+    fn test_find_unreferenced_lists_packages_no_root_reaches() {
+        let packages: Vec<Package> = [
+            "app",
+            "boto3",
+            "botocore",
+            "s3transfer",
+            "urllib3",
+            "flask",
+            "werkzeug",
+            "requests",
+        ]
+        .iter()
+        .map(|name| Package::new(name.to_string(), "1.0".to_string()).unwrap())
+        .collect();
+        let graph =
+            DependencyAnalyzer::analyze_with_roots(&names(&["boto3"]), &boto_lockfile()).unwrap();
+
+        let unreferenced = DependencyAnalyzer::find_unreferenced(&graph, &packages).unwrap();
+
+        assert_eq!(
+            unreferenced,
+            names(&["app", "flask", "werkzeug", "requests"])
+        );
+    }
 }
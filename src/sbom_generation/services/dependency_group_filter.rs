@@ -0,0 +1,272 @@
+use crate::sbom_generation::domain::{Package, PackageName};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Dependency map: package name -> names of its dependencies
+type DependencyMap = HashMap<String, Vec<String>>;
+
+/// DependencyGroupFilter service for restricting the SBOM to selected dependency groups
+///
+/// The runtime dependencies of the project roots form the implicit `main` group.
+/// Every dependency group (`dev` and the other `[dependency-groups]`) and every
+/// extra of the project forms a group of its own. Below the roots, runtime
+/// dependencies are always followed.
+///
+/// Pruning is reachability-based: a package is dropped only when the roots reach
+/// it exclusively through edges of deselected groups. A package needed by both
+/// `main` and `dev` therefore survives when `dev` is excluded. Packages the roots
+/// do not reach at all are left alone.
+#[derive(Debug, Clone, Default)]
+pub struct DependencyGroupFilter {
+    only: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl DependencyGroupFilter {
+    /// Name of the implicit group holding the runtime dependencies of the roots
+    pub const MAIN_GROUP: &'static str = "main";
+
+    /// Creates a filter keeping only the `only` groups when given, otherwise
+    /// every group except the `exclude` ones
+    pub fn new(only: Vec<String>, exclude: Vec<String>) -> Self {
+        Self { only, exclude }
+    }
+
+    /// Returns true when the filter deselects at least one group
+    pub fn is_active(&self) -> bool {
+        !self.only.is_empty() || !self.exclude.is_empty()
+    }
+
+    fn includes(&self, group: &str) -> bool {
+        if self.only.is_empty() {
+            !self.exclude.iter().any(|g| g == group)
+        } else {
+            self.only.iter().any(|g| g == group)
+        }
+    }
+
+    /// Returns the requested group names that match neither `main` nor a group
+    /// in the lockfile, in the order they were given
+    pub fn unknown_groups<'a>(&'a self, groups: &HashMap<String, DependencyMap>) -> Vec<&'a str> {
+        self.only
+            .iter()
+            .chain(&self.exclude)
+            .map(String::as_str)
+            .filter(|g| *g != Self::MAIN_GROUP && !groups.contains_key(*g))
+            .collect()
+    }
+
+    /// Merges the edges of the selected groups into `dependency_map` and prunes
+    /// the packages that are only reachable through deselected groups.
+    ///
+    /// # Arguments
+    /// * `roots` - The root project, or the workspace members
+    /// * `packages` - All packages from the lockfile
+    /// * `dependency_map` - Runtime dependencies of every package
+    /// * `groups` - Group name -> edges that group adds on top of `dependency_map`
+    ///
+    /// # Returns
+    /// The remaining packages and a dependency map restricted to them. When the
+    /// filter is inactive, every package is kept and every group is merged.
+    pub fn apply(
+        &self,
+        roots: &[PackageName],
+        mut packages: Vec<Package>,
+        dependency_map: DependencyMap,
+        groups: &HashMap<String, DependencyMap>,
+    ) -> (Vec<Package>, DependencyMap) {
+        let root_names: HashSet<&str> = roots.iter().map(|r| r.as_str()).collect();
+
+        // Group names are sorted so merged dependency lists have a stable order
+        let mut group_names: Vec<&String> = groups.keys().collect();
+        group_names.sort();
+
+        let merge = |selected: &dyn Fn(&str) -> bool| {
+            let mut merged = dependency_map.clone();
+            if !selected(Self::MAIN_GROUP) {
+                for root in &root_names {
+                    if let Some(deps) = merged.get_mut(*root) {
+                        deps.clear();
+                    }
+                }
+            }
+            for group in group_names.iter().filter(|g| selected(g)) {
+                for (package, deps) in &groups[*group] {
+                    let entry = merged.entry(package.clone()).or_default();
+                    for dep in deps {
+                        if !entry.contains(dep) {
+                            entry.push(dep.clone());
+                        }
+                    }
+                }
+            }
+            merged
+        };
+
+        let mut selected_map = merge(&|group| self.includes(group));
+        if !self.is_active() {
+            return (packages, selected_map);
+        }
+
+        let full_map = merge(&|_| true);
+        let reachable = Self::reachable(&root_names, &full_map);
+        let kept = Self::reachable(&root_names, &selected_map);
+        let pruned: HashSet<String> = reachable
+            .difference(&kept)
+            .map(|name| name.to_string())
+            .collect();
+
+        packages.retain(|p| !pruned.contains(p.name()));
+        selected_map.retain(|name, _| !pruned.contains(name));
+        (packages, selected_map)
+    }
+
+    /// Collects the roots and every package reachable from them
+    fn reachable<'a>(
+        roots: &HashSet<&'a str>,
+        dependency_map: &'a DependencyMap,
+    ) -> HashSet<&'a str> {
+        let mut visited: HashSet<&str> = roots.clone();
+        let mut queue: VecDeque<&str> = roots.iter().copied().collect();
+        while let Some(current) = queue.pop_front() {
+            for dep in dependency_map.get(current).into_iter().flatten() {
+                if visited.insert(dep.as_str()) {
+                    queue.push_back(dep.as_str());
+                }
+            }
+        }
+        visited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deps(entries: &[(&str, &[&str])]) -> DependencyMap {
+        entries
+            .iter()
+            .map(|(name, deps)| {
+                (
+                    name.to_string(),
+                    deps.iter().map(|d| d.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn package(name: &str) -> Package {
+        Package::new(name.to_string(), "1.0.0".to_string()).unwrap()
+    }
+
+    fn names(packages: &[Package]) -> Vec<&str> {
+        let mut names: Vec<&str> = packages.iter().map(|p| p.name()).collect();
+        names.sort();
+        names
+    }
+
+    /// myproject: main -> requests -> certifi; dev -> pytest -> pluggy, and
+    /// dev also depends on certifi (shared with main); lint -> ruff
+    fn lockfile() -> (Vec<Package>, DependencyMap, HashMap<String, DependencyMap>) {
+        let packages = [
+            "myproject",
+            "requests",
+            "certifi",
+            "pytest",
+            "pluggy",
+            "ruff",
+        ]
+        .into_iter()
+        .map(package)
+        .collect();
+        let runtime = deps(&[
+            ("myproject", &["requests"]),
+            ("requests", &["certifi"]),
+            ("certifi", &[]),
+            ("pytest", &["pluggy"]),
+            ("pluggy", &[]),
+            ("ruff", &[]),
+        ]);
+        let groups = HashMap::from([
+            (
+                "dev".to_string(),
+                deps(&[("myproject", &["pytest", "certifi"])]),
+            ),
+            ("lint".to_string(), deps(&[("myproject", &["ruff"])])),
+        ]);
+        (packages, runtime, groups)
+    }
+
+    fn roots() -> Vec<PackageName> {
+        vec![PackageName::new("myproject".to_string()).unwrap()]
+    }
+
+    #[test]
+    fn test_inactive_filter_keeps_everything_and_merges_groups() {
+        let (packages, runtime, groups) = lockfile();
+
+        let (kept, map) =
+            DependencyGroupFilter::default().apply(&roots(), packages, runtime, &groups);
+
+        assert_eq!(kept.len(), 6);
+        assert_eq!(map["myproject"], ["requests", "pytest", "certifi", "ruff"]);
+    }
+
+    #[test]
+    fn test_exclude_dev_prunes_dev_only_transitive_chain() {
+        let (packages, runtime, groups) = lockfile();
+        let filter = DependencyGroupFilter::new(vec![], vec!["dev".to_string()]);
+
+        let (kept, map) = filter.apply(&roots(), packages, runtime, &groups);
+
+        // pytest and its transitive pluggy go; certifi is shared with main and stays
+        assert_eq!(names(&kept), ["certifi", "myproject", "requests", "ruff"]);
+        assert_eq!(map["myproject"], ["requests", "ruff"]);
+        assert!(!map.contains_key("pytest"));
+        assert!(!map.contains_key("pluggy"));
+    }
+
+    #[test]
+    fn test_only_main_keeps_runtime_dependencies() {
+        let (packages, runtime, groups) = lockfile();
+        let filter = DependencyGroupFilter::new(vec!["main".to_string()], vec![]);
+
+        let (kept, map) = filter.apply(&roots(), packages, runtime, &groups);
+
+        assert_eq!(names(&kept), ["certifi", "myproject", "requests"]);
+        assert_eq!(map["myproject"], ["requests"]);
+    }
+
+    #[test]
+    fn test_only_dev_drops_runtime_dependencies_of_roots() {
+        let (packages, runtime, groups) = lockfile();
+        let filter = DependencyGroupFilter::new(vec!["dev".to_string()], vec![]);
+
+        let (kept, map) = filter.apply(&roots(), packages, runtime, &groups);
+
+        assert_eq!(names(&kept), ["certifi", "myproject", "pluggy", "pytest"]);
+        assert_eq!(map["myproject"], ["pytest", "certifi"]);
+    }
+
+    #[test]
+    fn test_unreachable_packages_are_kept() {
+        let (mut packages, mut runtime, groups) = lockfile();
+        packages.push(package("orphan"));
+        runtime.insert("orphan".to_string(), vec![]);
+        let filter = DependencyGroupFilter::new(vec!["main".to_string()], vec![]);
+
+        let (kept, _) = filter.apply(&roots(), packages, runtime, &groups);
+
+        assert!(kept.iter().any(|p| p.name() == "orphan"));
+    }
+
+    #[test]
+    fn test_unknown_groups() {
+        let (_, _, groups) = lockfile();
+        let filter = DependencyGroupFilter::new(
+            vec![],
+            vec!["dev".to_string(), "main".to_string(), "docs".to_string()],
+        );
+
+        assert_eq!(filter.unknown_groups(&groups), ["docs"]);
+    }
+}
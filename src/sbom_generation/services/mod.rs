@@ -1,7 +1,13 @@
 mod dependency_analyzer;
+mod dependency_group_filter;
 mod package_filter;
+mod platform_filter;
 mod sbom_generator;
 
 pub use dependency_analyzer::DependencyAnalyzer;
+#[allow(unused_imports)]
+pub use dependency_analyzer::VersionConflict;
+pub use dependency_group_filter::DependencyGroupFilter;
 pub use package_filter::PackageFilter;
+pub use platform_filter::PlatformFilter;
 pub use sbom_generator::SbomGenerator;
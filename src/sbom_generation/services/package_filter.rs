@@ -1,6 +1,8 @@
-use crate::sbom_generation::domain::Package;
+use crate::sbom_generation::domain::{Package, PackageName};
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Maximum number of exclude patterns to prevent DoS attacks
 ///
@@ -17,13 +19,21 @@ const MAX_EXCLUDE_PATTERNS: usize = 64;
 /// ecosystem and prevents both memory exhaustion and algorithmic complexity attacks.
 const MAX_PATTERN_LENGTH: usize = 255;
 
+/// Prefix of a pattern that excludes the dependencies of a package instead of
+/// matching names, e.g. `deps-of:sphinx`
+const DEPS_OF_PREFIX: &str = "deps-of:";
+
 /// PackageFilter - Filters packages based on exclusion patterns
 ///
 /// Supports wildcard patterns using '*' to match zero or more characters.
 /// Patterns are case-sensitive and validated against a character whitelist.
+///
+/// A `deps-of:<package>` pattern excludes the packages that are reachable only
+/// through `<package>` in the dependency map; the parent itself is kept.
 #[derive(Debug)]
 pub struct PackageFilter {
     patterns: Vec<ExcludePattern>,
+    parents: Vec<String>,
 }
 
 impl PackageFilter {
@@ -50,22 +60,29 @@ impl PackageFilter {
     pub fn new(patterns: Vec<String>) -> Result<Self> {
         // Check pattern count
         if patterns.len() > MAX_EXCLUDE_PATTERNS {
-            anyhow::bail!(
+            return Err(UvSbomError::validation(format!(
                 "Too many exclusion patterns: {} (maximum: {})",
                 patterns.len(),
                 MAX_EXCLUDE_PATTERNS
-            );
+            )));
         }
 
         // Validate and compile each pattern
         let mut compiled_patterns = Vec::new();
+        let mut parents = Vec::new();
         for pattern in patterns {
+            if let Some(parent) = pattern.strip_prefix(DEPS_OF_PREFIX) {
+                validate_pattern(&pattern)?;
+                parents.push(parent.to_string());
+                continue;
+            }
             let exclude_pattern = ExcludePattern::new(pattern)?;
             compiled_patterns.push(exclude_pattern);
         }
 
         Ok(Self {
             patterns: compiled_patterns,
+            parents,
         })
     }
 
@@ -83,6 +100,91 @@ impl PackageFilter {
             .collect()
     }
 
+    /// Returns the packages matching any name pattern, in the order given
+    ///
+    /// The inverse of [`Self::filter_packages`], used to select packages such as
+    /// the `--roots` of a dependency analysis. `deps-of:` patterns select nothing.
+    pub fn select_packages<'a>(&self, packages: &'a [Package]) -> Vec<&'a Package> {
+        packages
+            .iter()
+            .filter(|pkg| self.matches(pkg.name()))
+            .collect()
+    }
+
+    /// Filters packages by name, then prunes the dependencies of `deps-of:` parents
+    ///
+    /// A package is pruned when every path to it from a root (a package no other
+    /// package depends on) passes through a `deps-of:` parent. Packages another
+    /// root still reaches are kept, as are packages the parent does not reach.
+    /// Name patterns do not affect reachability.
+    ///
+    /// # Arguments
+    /// * `packages` - Vector of Package objects to filter
+    /// * `dependency_map` - Package name -> names of its dependencies
+    ///
+    /// # Returns
+    /// The packages left after both steps
+    pub fn filter_packages_with_dependencies(
+        &self,
+        packages: Vec<Package>,
+        dependency_map: &HashMap<String, Vec<String>>,
+    ) -> Vec<Package> {
+        let packages = self.filter_packages(packages);
+        if self.parents.is_empty() {
+            return packages;
+        }
+
+        let parents: HashSet<String> = self
+            .parents
+            .iter()
+            .map(|p| PackageName::normalize(p))
+            .collect();
+        let is_parent = |name: &str| parents.contains(&PackageName::normalize(name));
+
+        let dependents: HashSet<&str> = dependency_map
+            .values()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        let roots: Vec<&str> = dependency_map
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !dependents.contains(name))
+            .collect();
+
+        let pulled_in = reachable(
+            dependency_map
+                .keys()
+                .map(String::as_str)
+                .filter(|n| is_parent(n)),
+            dependency_map,
+            &|_| false,
+        );
+        let kept = reachable(roots.into_iter(), dependency_map, &is_parent);
+
+        packages
+            .into_iter()
+            .filter(|pkg| {
+                let name = pkg.name();
+                is_parent(name) || !pulled_in.contains(name) || kept.contains(name)
+            })
+            .collect()
+    }
+
+    /// Returns the `deps-of:` parents that name no package in `packages`
+    pub fn get_unknown_parents(&self, packages: &[Package]) -> Vec<String> {
+        self.parents
+            .iter()
+            .filter(|parent| {
+                let parent = PackageName::normalize(parent);
+                !packages
+                    .iter()
+                    .any(|p| PackageName::normalize(p.name()) == parent)
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Checks if a package name matches any exclusion pattern
     fn matches(&self, package_name: &str) -> bool {
         self.patterns.iter().any(|p| p.matches(package_name))
@@ -104,6 +206,33 @@ impl PackageFilter {
     }
 }
 
+/// Collects the packages reachable from `starts`, not descending below packages
+/// for which `stop` returns true (those are still collected themselves)
+fn reachable<'a>(
+    starts: impl Iterator<Item = &'a str>,
+    dependency_map: &'a HashMap<String, Vec<String>>,
+    stop: &dyn Fn(&str) -> bool,
+) -> HashSet<&'a str> {
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    for start in starts {
+        if visited.insert(start) {
+            queue.push_back(start);
+        }
+    }
+    while let Some(current) = queue.pop_front() {
+        if stop(current) {
+            continue;
+        }
+        for dep in dependency_map.get(current).into_iter().flatten() {
+            if visited.insert(dep.as_str()) {
+                queue.push_back(dep.as_str());
+            }
+        }
+    }
+    visited
+}
+
 /// Represents a single exclusion pattern with its compiled matcher
 #[derive(Debug)]
 struct ExcludePattern {
@@ -204,37 +333,53 @@ impl PatternMatcher {
 fn validate_pattern(pattern: &str) -> Result<()> {
     // Check if pattern is empty
     if pattern.is_empty() {
-        anyhow::bail!("Exclusion pattern cannot be empty");
+        return Err(UvSbomError::validation("Exclusion pattern cannot be empty"));
     }
 
+    // A deps-of pattern names exactly one parent package
+    let pattern = match pattern.strip_prefix(DEPS_OF_PREFIX) {
+        Some("") => {
+            return Err(UvSbomError::validation(format!(
+                "Exclusion pattern '{}' must name a package after '{}'",
+                pattern, DEPS_OF_PREFIX
+            )));
+        }
+        Some(parent) if parent.contains('*') => {
+            return Err(UvSbomError::validation(format!(
+                "Exclusion pattern '{}' cannot contain wildcards; '{}' takes a single package name",
+                pattern, DEPS_OF_PREFIX
+            )));
+        }
+        Some(parent) => parent,
+        None => pattern,
+    };
+
     // Check pattern length
     if pattern.len() > MAX_PATTERN_LENGTH {
-        anyhow::bail!(
+        return Err(UvSbomError::validation(format!(
             "Exclusion pattern is too long: '{}' ({} chars). Maximum: {} chars",
             pattern,
             pattern.len(),
             MAX_PATTERN_LENGTH
-        );
+        )));
     }
 
     // Validate characters
     for ch in pattern.chars() {
         if !is_valid_pattern_char(ch) {
-            anyhow::bail!(
-                "Exclusion pattern contains invalid character '{}' in pattern '{}'. \
+            return Err(UvSbomError::validation(format!("Exclusion pattern contains invalid character '{}' in pattern '{}'. \
                  Only alphanumeric, hyphens, underscores, dots, brackets, and asterisks (*) are allowed.",
                 ch,
-                pattern
-            );
+                pattern)));
         }
     }
 
     // Reject patterns with only wildcards
     if pattern.chars().all(|c| c == '*') {
-        anyhow::bail!(
+        return Err(UvSbomError::validation(format!(
             "Exclusion pattern cannot contain only wildcards: '{}'",
             pattern
-        );
+        )));
     }
 
     Ok(())
@@ -472,6 +617,25 @@ mod tests {
         assert_eq!(filtered[1].name(), "numpy");
     }
 
+    #[test]
+    fn test_select_packages_matches_wildcards_and_tracks_unmatched() {
+        let packages = vec![
+            Package::new("boto3".to_string(), "1.34.0".to_string()).unwrap(),
+            Package::new("requests".to_string(), "2.31.0".to_string()).unwrap(),
+            Package::new("botocore".to_string(), "1.34.0".to_string()).unwrap(),
+        ];
+        let filter = PackageFilter::new(vec!["boto*".to_string(), "flask".to_string()]).unwrap();
+
+        let selected: Vec<&str> = filter
+            .select_packages(&packages)
+            .iter()
+            .map(|p| p.name())
+            .collect();
+
+        assert_eq!(selected, ["boto3", "botocore"]);
+        assert_eq!(filter.get_unmatched_patterns(), ["flask"]);
+    }
+
     #[test]
     fn test_filter_packages_with_wildcard() {
         let packages = vec![
@@ -596,4 +760,103 @@ mod tests {
         assert_eq!(unmatched.len(), 1);
         assert_eq!(unmatched[0], "*-dev");
     }
+
+    /// myproject depends on sphinx and requests; sphinx pulls in docutils,
+    /// jinja2 (-> markupsafe) and requests, which is shared with myproject
+    fn sphinx_project() -> (Vec<Package>, HashMap<String, Vec<String>>) {
+        let deps: &[(&str, &[&str])] = &[
+            ("myproject", &["sphinx", "requests"]),
+            ("sphinx", &["docutils", "jinja2", "requests"]),
+            ("jinja2", &["markupsafe"]),
+            ("requests", &["idna"]),
+            ("docutils", &[]),
+            ("markupsafe", &[]),
+            ("idna", &[]),
+        ];
+        let packages = deps
+            .iter()
+            .map(|(name, _)| Package::new(name.to_string(), "1.0.0".to_string()).unwrap())
+            .collect();
+        let map = deps
+            .iter()
+            .map(|(name, d)| (name.to_string(), d.iter().map(|s| s.to_string()).collect()))
+            .collect();
+        (packages, map)
+    }
+
+    fn names(packages: &[Package]) -> Vec<&str> {
+        let mut names: Vec<&str> = packages.iter().map(|p| p.name()).collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_deps_of_prunes_packages_reachable_only_through_parent() {
+        let (packages, map) = sphinx_project();
+        let filter = PackageFilter::new(vec!["deps-of:Sphinx".to_string()]).unwrap();
+
+        let kept = filter.filter_packages_with_dependencies(packages, &map);
+
+        assert_eq!(names(&kept), ["idna", "myproject", "requests", "sphinx"]);
+        assert!(filter.get_unmatched_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_deps_of_applies_after_name_patterns() {
+        let (packages, map) = sphinx_project();
+        let filter =
+            PackageFilter::new(vec!["sphinx".to_string(), "deps-of:sphinx".to_string()]).unwrap();
+
+        let kept = filter.filter_packages_with_dependencies(packages, &map);
+
+        assert_eq!(names(&kept), ["idna", "myproject", "requests"]);
+    }
+
+    #[test]
+    fn test_deps_of_keeps_packages_outside_parent_subtree() {
+        let (mut packages, mut map) = sphinx_project();
+        packages.push(Package::new("orphan".to_string(), "1.0.0".to_string()).unwrap());
+        map.insert("orphan".to_string(), vec![]);
+        let filter = PackageFilter::new(vec!["deps-of:jinja2".to_string()]).unwrap();
+
+        let kept = filter.filter_packages_with_dependencies(packages, &map);
+
+        assert_eq!(
+            names(&kept),
+            [
+                "docutils",
+                "idna",
+                "jinja2",
+                "myproject",
+                "orphan",
+                "requests",
+                "sphinx"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deps_of_unknown_parent() {
+        let (packages, map) = sphinx_project();
+        let filter = PackageFilter::new(vec!["deps-of:mkdocs".to_string()]).unwrap();
+
+        assert_eq!(filter.get_unknown_parents(&packages), ["mkdocs"]);
+        let kept = filter.filter_packages_with_dependencies(packages, &map);
+        assert_eq!(kept.len(), 7);
+    }
+
+    #[test]
+    fn test_deps_of_validation() {
+        let missing = PackageFilter::new(vec!["deps-of:".to_string()]).unwrap_err();
+        assert!(missing.to_string().contains("must name a package"));
+
+        let wildcard = PackageFilter::new(vec!["deps-of:sphinx*".to_string()]).unwrap_err();
+        assert!(wildcard.to_string().contains("cannot contain wildcards"));
+
+        let invalid = PackageFilter::new(vec!["deps-of:a/b".to_string()]).unwrap_err();
+        assert!(invalid.to_string().contains("invalid character"));
+
+        // Outside the prefix a colon is still rejected
+        assert!(PackageFilter::new(vec!["docs:sphinx".to_string()]).is_err());
+    }
 }
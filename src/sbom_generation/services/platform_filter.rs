@@ -0,0 +1,298 @@
+use crate::sbom_generation::domain::{MarkerEnvironment, Package, PackageName};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Dependency map: package name -> names of its dependencies
+type DependencyMap = HashMap<String, Vec<String>>;
+
+/// Edge markers: parent name -> dependency name -> PEP 508 marker of that edge
+type EdgeMarkers = HashMap<String, HashMap<String, String>>;
+
+/// PlatformFilter service for restricting the SBOM to one target environment
+///
+/// uv.lock resolves every platform at once. A dependency edge whose marker can
+/// never be true in the environment is removed, and so is every package whose
+/// own marker (its resolution markers or platform-specific wheels) rules it out.
+/// Edges and packages without markers are always kept, as are markers the
+/// environment cannot decide, such as `platform_machine` comparisons.
+///
+/// Pruning is reachability-based, as in `DependencyGroupFilter`: a package is
+/// dropped when the roots only reach it through removed edges. Packages the
+/// roots do not reach at all are left alone unless their own marker rules
+/// them out.
+#[derive(Debug, Clone, Default)]
+pub struct PlatformFilter {
+    environment: MarkerEnvironment,
+}
+
+impl PlatformFilter {
+    pub fn new(environment: MarkerEnvironment) -> Self {
+        Self { environment }
+    }
+
+    /// Returns true when the environment sets at least one marker variable
+    pub fn is_active(&self) -> bool {
+        !self.environment.is_empty()
+    }
+
+    /// Removes the edges and packages that cannot exist in the target environment
+    ///
+    /// # Arguments
+    /// * `roots` - The root project, or the workspace members; never removed
+    /// * `packages` - All packages from the lockfile
+    /// * `dependency_map` - Dependencies of every package
+    /// * `edge_markers` - Markers of the conditional edges in `dependency_map`
+    ///
+    /// # Returns
+    /// The remaining packages and a dependency map restricted to them. When the
+    /// filter is inactive, both are returned unchanged.
+    pub fn apply(
+        &self,
+        roots: &[PackageName],
+        mut packages: Vec<Package>,
+        mut dependency_map: DependencyMap,
+        edge_markers: &EdgeMarkers,
+    ) -> (Vec<Package>, DependencyMap) {
+        if !self.is_active() {
+            return (packages, dependency_map);
+        }
+
+        let root_names: HashSet<&str> = roots.iter().map(|r| r.as_str()).collect();
+        // A forked lock has one entry per version, each with its own marker, so
+        // versions are ruled out one by one; a name is only gone from the graph
+        // once none of its versions is left
+        packages.retain(|p| {
+            root_names.contains(p.name())
+                || p.marker().is_none_or(|m| self.environment.may_satisfy(m))
+        });
+        let surviving: HashSet<&str> = packages.iter().map(|p| p.name()).collect();
+        let ruled_out: HashSet<String> = dependency_map
+            .values()
+            .flatten()
+            .chain(dependency_map.keys())
+            .filter(|name| !surviving.contains(name.as_str()))
+            .cloned()
+            .collect();
+
+        let reachable: HashSet<String> = Self::reachable(&root_names, &dependency_map);
+        for (parent, deps) in dependency_map.iter_mut() {
+            let markers = edge_markers.get(parent);
+            deps.retain(|dep| {
+                !ruled_out.contains(dep)
+                    && markers
+                        .and_then(|m| m.get(dep))
+                        .is_none_or(|marker| self.environment.may_satisfy(marker))
+            });
+        }
+        let kept = Self::reachable(&root_names, &dependency_map);
+
+        let pruned: HashSet<&String> = reachable.difference(&kept).chain(&ruled_out).collect();
+        packages.retain(|p| !pruned.contains(&p.name().to_string()));
+        dependency_map.retain(|name, _| !pruned.contains(name));
+        for deps in dependency_map.values_mut() {
+            deps.retain(|dep| !pruned.contains(dep));
+        }
+        (packages, dependency_map)
+    }
+
+    /// Collects the roots and every package reachable from them
+    fn reachable(roots: &HashSet<&str>, dependency_map: &DependencyMap) -> HashSet<String> {
+        let mut visited: HashSet<String> = roots.iter().map(|r| r.to_string()).collect();
+        let mut queue: VecDeque<&str> = roots.iter().copied().collect();
+        while let Some(current) = queue.pop_front() {
+            for dep in dependency_map.get(current).into_iter().flatten() {
+                if visited.insert(dep.clone()) {
+                    queue.push_back(dep.as_str());
+                }
+            }
+        }
+        visited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbom_generation::domain::TargetPlatform;
+
+    fn package(name: &str) -> Package {
+        Package::new(name.to_string(), "1.0.0".to_string()).unwrap()
+    }
+
+    fn names(packages: &[Package]) -> Vec<&str> {
+        let mut names: Vec<&str> = packages.iter().map(|p| p.name()).collect();
+        names.sort();
+        names
+    }
+
+    fn roots() -> Vec<PackageName> {
+        vec![PackageName::new("myservice".to_string()).unwrap()]
+    }
+
+    fn filter(platform: TargetPlatform) -> PlatformFilter {
+        PlatformFilter::new(MarkerEnvironment::new(Some(platform), Vec::new()))
+    }
+
+    /// myservice -> click -> colorama (win32 only); myservice -> pywin32 (wheels
+    /// for Windows only); myservice -> uvloop (not on Windows) -> certifi, and
+    /// requests -> certifi unconditionally
+    fn lockfile() -> (Vec<Package>, DependencyMap, EdgeMarkers) {
+        let packages = vec![
+            package("myservice"),
+            package("click"),
+            package("colorama"),
+            package("pywin32").with_marker(Some("sys_platform == 'win32'".to_string())),
+            package("uvloop"),
+            package("requests"),
+            package("certifi"),
+        ];
+        let map = DependencyMap::from([
+            (
+                "myservice".to_string(),
+                vec![
+                    "click".to_string(),
+                    "pywin32".to_string(),
+                    "uvloop".to_string(),
+                    "requests".to_string(),
+                ],
+            ),
+            ("click".to_string(), vec!["colorama".to_string()]),
+            ("uvloop".to_string(), vec!["certifi".to_string()]),
+            ("requests".to_string(), vec!["certifi".to_string()]),
+        ]);
+        let markers = EdgeMarkers::from([
+            (
+                "click".to_string(),
+                HashMap::from([(
+                    "colorama".to_string(),
+                    "platform_system == 'Windows'".to_string(),
+                )]),
+            ),
+            (
+                "myservice".to_string(),
+                HashMap::from([(
+                    "uvloop".to_string(),
+                    "sys_platform != 'win32' and platform_python_implementation != 'PyPy'"
+                        .to_string(),
+                )]),
+            ),
+        ]);
+        (packages, map, markers)
+    }
+
+    #[test]
+    fn test_linux_drops_windows_only_packages() {
+        let (packages, map, markers) = lockfile();
+
+        let (kept, map) = filter(TargetPlatform::Linux).apply(&roots(), packages, map, &markers);
+
+        assert_eq!(
+            names(&kept),
+            ["certifi", "click", "myservice", "requests", "uvloop"]
+        );
+        assert!(map["click"].is_empty());
+        assert_eq!(map["myservice"], ["click", "uvloop", "requests"]);
+    }
+
+    #[test]
+    fn test_windows_drops_edges_ruled_out_but_keeps_shared_packages() {
+        let (packages, map, markers) = lockfile();
+
+        let (kept, map) = filter(TargetPlatform::Windows).apply(&roots(), packages, map, &markers);
+
+        // uvloop goes; certifi is still needed by requests
+        assert_eq!(
+            names(&kept),
+            [
+                "certifi",
+                "click",
+                "colorama",
+                "myservice",
+                "pywin32",
+                "requests"
+            ]
+        );
+        assert!(!map.contains_key("uvloop"));
+    }
+
+    #[test]
+    fn test_inactive_filter_keeps_everything() {
+        let (packages, map, markers) = lockfile();
+
+        let (kept, result) =
+            PlatformFilter::default().apply(&roots(), packages, map.clone(), &markers);
+
+        assert_eq!(kept.len(), 7);
+        assert_eq!(result, map);
+    }
+
+    #[test]
+    fn test_unreachable_packages_are_kept_unless_ruled_out() {
+        let (mut packages, map, markers) = lockfile();
+        packages.push(package("orphan"));
+        packages.push(package("win-orphan").with_marker(Some("os_name == 'nt'".to_string())));
+
+        let (kept, _) = filter(TargetPlatform::Macos).apply(&roots(), packages, map, &markers);
+
+        assert!(kept.iter().any(|p| p.name() == "orphan"));
+        assert!(!kept.iter().any(|p| p.name() == "win-orphan"));
+    }
+
+    #[test]
+    fn test_forked_versions_are_filtered_one_by_one() {
+        let packages = vec![
+            package("myservice"),
+            Package::new("numpy".to_string(), "1.26.4".to_string())
+                .unwrap()
+                .with_marker(Some("python_full_version < '3.10'".to_string())),
+            Package::new("numpy".to_string(), "2.0.0".to_string())
+                .unwrap()
+                .with_marker(Some("python_full_version >= '3.10'".to_string())),
+            package("packaging"),
+        ];
+        let map = DependencyMap::from([
+            ("myservice".to_string(), vec!["numpy".to_string()]),
+            ("numpy".to_string(), vec!["packaging".to_string()]),
+        ]);
+        let environment = MarkerEnvironment::new(
+            None,
+            vec![("python_full_version".to_string(), "3.12.0".to_string())],
+        );
+
+        let (kept, map) =
+            PlatformFilter::new(environment).apply(&roots(), packages, map, &EdgeMarkers::new());
+
+        let versions: Vec<(&str, &str)> = kept.iter().map(|p| (p.name(), p.version())).collect();
+        assert_eq!(
+            versions,
+            [
+                ("myservice", "1.0.0"),
+                ("numpy", "2.0.0"),
+                ("packaging", "1.0.0")
+            ]
+        );
+        assert_eq!(map["myservice"], ["numpy"]);
+        assert_eq!(map["numpy"], ["packaging"]);
+    }
+
+    #[test]
+    fn test_name_is_pruned_when_every_version_is_ruled_out() {
+        let packages = vec![
+            package("myservice"),
+            Package::new("pywin32".to_string(), "305".to_string())
+                .unwrap()
+                .with_marker(Some(
+                    "sys_platform == 'win32' and python_version < '3.10'".to_string(),
+                )),
+            Package::new("pywin32".to_string(), "306".to_string())
+                .unwrap()
+                .with_marker(Some("sys_platform == 'win32'".to_string())),
+        ];
+        let map = DependencyMap::from([("myservice".to_string(), vec!["pywin32".to_string()])]);
+
+        let (kept, map) =
+            filter(TargetPlatform::Linux).apply(&roots(), packages, map, &EdgeMarkers::new());
+
+        assert_eq!(names(&kept), ["myservice"]);
+        assert!(map["myservice"].is_empty());
+    }
+}
@@ -1,7 +1,10 @@
 use crate::sbom_generation::domain::SbomMetadata;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+/// Namespace for serial numbers derived in reproducible mode
+const REPRODUCIBLE_SERIAL_NAMESPACE: Uuid = Uuid::NAMESPACE_URL;
+
 /// SbomGenerator service for generating SBOM metadata
 ///
 /// This service contains pure business logic for SBOM metadata generation.
@@ -29,12 +32,60 @@ impl SbomGenerator {
         )
     }
 
+    /// Generates SBOM metadata that is identical for identical inputs
+    ///
+    /// The serial number is a UUIDv5 derived from `content`, so the same input
+    /// always yields the same serial number. The timestamp is taken from
+    /// `source_date_epoch` (seconds since the Unix epoch) when given, and is the
+    /// current time otherwise.
+    ///
+    /// # Arguments
+    /// * `tool_name` - Name of the tool generating the SBOM
+    /// * `tool_version` - Version of the tool
+    /// * `content` - Bytes identifying the SBOM contents (lockfile and package list)
+    /// * `source_date_epoch` - Value of `SOURCE_DATE_EPOCH`, if set
+    pub fn generate_reproducible_metadata(
+        tool_name: &str,
+        tool_version: &str,
+        content: &[u8],
+        source_date_epoch: Option<i64>,
+    ) -> SbomMetadata {
+        let timestamp = source_date_epoch
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0))
+            .unwrap_or_else(Utc::now)
+            .to_rfc3339();
+        let serial_number = format!(
+            "urn:uuid:{}",
+            Uuid::new_v5(&REPRODUCIBLE_SERIAL_NAMESPACE, content)
+        );
+
+        SbomMetadata::new(
+            timestamp,
+            tool_name.to_string(),
+            tool_version.to_string(),
+            serial_number,
+        )
+    }
+
     /// Generates SBOM metadata with default tool information (uv-sbom)
     ///
     /// This uses the compile-time version from Cargo.toml
     pub fn generate_default_metadata() -> SbomMetadata {
         Self::generate_metadata("uv-sbom", env!("CARGO_PKG_VERSION"))
     }
+
+    /// Generates reproducible SBOM metadata with default tool information (uv-sbom)
+    pub fn generate_default_reproducible_metadata(
+        content: &[u8],
+        source_date_epoch: Option<i64>,
+    ) -> SbomMetadata {
+        Self::generate_reproducible_metadata(
+            "uv-sbom",
+            env!("CARGO_PKG_VERSION"),
+            content,
+            source_date_epoch,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -79,6 +130,26 @@ mod tests {
         assert_ne!(metadata1.serial_number(), metadata2.serial_number());
     }
 
+    #[test]
+    fn test_generate_reproducible_metadata_is_deterministic() {
+        let metadata1 =
+            SbomGenerator::generate_reproducible_metadata("test-tool", "1.0.0", b"lock", Some(0));
+        let metadata2 =
+            SbomGenerator::generate_reproducible_metadata("test-tool", "1.0.0", b"lock", Some(0));
+        let other =
+            SbomGenerator::generate_reproducible_metadata("test-tool", "1.0.0", b"other", Some(0));
+
+        assert_eq!(metadata1.serial_number(), metadata2.serial_number());
+        assert_ne!(metadata1.serial_number(), other.serial_number());
+        assert_eq!(metadata1.timestamp(), "1970-01-01T00:00:00+00:00");
+        assert_eq!(
+            Uuid::parse_str(metadata1.serial_number().strip_prefix("urn:uuid:").unwrap())
+                .unwrap()
+                .get_version_num(),
+            5
+        );
+    }
+
     #[test]
     fn test_generate_metadata_uuid_format() {
         let metadata = SbomGenerator::generate_metadata("test-tool", "1.0.0");
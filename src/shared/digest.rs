@@ -0,0 +1,72 @@
+//! SHA-256 digests of generated output, in the format of `sha256sum`
+//!
+//! A digest file holds one line per file: the lowercase hex digest, two spaces
+//! and the file name, e.g. `3a7b…  sbom.json`. `sha256sum -c` can check it.
+
+use sha2::{Digest, Sha256};
+
+/// Returns the lowercase hex SHA-256 digest of `content`
+pub fn sha256_hex(content: &[u8]) -> String {
+    Sha256::digest(content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Formats a `sha256sum` line for `file_name`, including the trailing newline
+pub fn sha256sum_line(digest: &str, file_name: &str) -> String {
+    format!("{}  {}\n", digest, file_name)
+}
+
+/// Parses the first line of a `sha256sum` digest file into `(digest, file name)`
+///
+/// Accepts the binary-mode marker (`<digest> *<file>`) as well. Returns `None`
+/// when the line does not start with a 64-character hex digest.
+pub fn parse_sha256sum(content: &str) -> Option<(String, String)> {
+    let line = content.lines().next()?.trim_end();
+    let (digest, file_name) = line.split_once(' ')?;
+    if digest.len() != 64 || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let file_name = file_name
+        .strip_prefix(' ')
+        .or_else(|| file_name.strip_prefix('*'))
+        .unwrap_or(file_name);
+    Some((digest.to_ascii_lowercase(), file_name.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_hex_known_value() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_sha256sum_line_round_trips() {
+        let digest = sha256_hex(b"{}");
+        let line = sha256sum_line(&digest, "sbom.json");
+
+        assert_eq!(line, format!("{}  sbom.json\n", digest));
+        assert_eq!(
+            parse_sha256sum(&line),
+            Some((digest, "sbom.json".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_sha256sum_binary_marker_and_invalid_input() {
+        let digest = "A".repeat(64);
+        assert_eq!(
+            parse_sha256sum(&format!("{} *sbom.json", digest)),
+            Some(("a".repeat(64), "sbom.json".to_string()))
+        );
+        assert_eq!(parse_sha256sum("not-a-digest  sbom.json"), None);
+        assert_eq!(parse_sha256sum(""), None);
+    }
+}
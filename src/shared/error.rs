@@ -1,5 +1,6 @@
 use std::fmt;
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Exit codes for the CLI application.
@@ -17,6 +18,12 @@ pub enum ExitCode {
     InvalidArguments = 2,
     /// Application error (API error, network error, file I/O error, etc.)
     ApplicationError = 3,
+    /// License policy violations were detected (and no vulnerabilities above the threshold)
+    LicenseViolations = 4,
+    /// The lockfile is out of date with pyproject.toml and `--strict-lock` was given
+    StaleLockfile = 5,
+    /// `--dry-run` found the configuration usable, but with warnings
+    ValidationWarnings = 6,
 }
 
 impl ExitCode {
@@ -26,6 +33,50 @@ impl ExitCode {
     }
 }
 
+impl From<&UvSbomError> for ExitCode {
+    /// Maps an error to the exit code the CLI reports for it
+    fn from(error: &UvSbomError) -> Self {
+        match error {
+            // The consumer of stdout stopped reading; the SBOM itself was generated
+            UvSbomError::OutputClosed => ExitCode::Success,
+            UvSbomError::StaleLockfile { .. } => ExitCode::StaleLockfile,
+            UvSbomError::LockfileNotFound { .. }
+            | UvSbomError::LockfileParseError { .. }
+            | UvSbomError::UnsupportedLockfileVersion { .. }
+            | UvSbomError::UnpinnedRequirement { .. }
+            | UvSbomError::LockfileTooLarge { .. }
+            | UvSbomError::SbomParseError { .. }
+            | UvSbomError::FileWriteError { .. }
+            | UvSbomError::OutputDirectoryMissing { .. }
+            | UvSbomError::InvalidProjectPath { .. }
+            | UvSbomError::InvalidPath { .. }
+            | UvSbomError::Validation { .. }
+            | UvSbomError::FileReadError { .. }
+            | UvSbomError::ProjectConfigNotFound { .. }
+            | UvSbomError::ProjectNameMissing { .. }
+            | UvSbomError::SecurityError { .. }
+            | UvSbomError::RequestLimitReached { .. }
+            | UvSbomError::CaBundleError { .. }
+            | UvSbomError::AdvisoryDbError { .. }
+            | UvSbomError::CveBaselineParseError { .. }
+            | UvSbomError::InvalidProxyUrl { .. }
+            | UvSbomError::InvalidUserAgentSuffix { .. }
+            | UvSbomError::CycloneDxValidationFailed { .. }
+            | UvSbomError::ConfigInvalid { .. }
+            | UvSbomError::AllPackagesExcluded { .. }
+            | UvSbomError::TimedOut { .. }
+            | UvSbomError::Cancelled { .. }
+            | UvSbomError::NetworkLicense { .. }
+            | UvSbomError::NetworkVulnerability(_)
+            | UvSbomError::Http(_)
+            | UvSbomError::HttpStatus { .. }
+            | UvSbomError::Io(_)
+            | UvSbomError::Json(_)
+            | UvSbomError::Other { .. } => ExitCode::ApplicationError,
+        }
+    }
+}
+
 impl fmt::Display for ExitCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -33,41 +84,310 @@ impl fmt::Display for ExitCode {
             ExitCode::VulnerabilitiesDetected => write!(f, "Vulnerabilities Detected (1)"),
             ExitCode::InvalidArguments => write!(f, "Invalid Arguments (2)"),
             ExitCode::ApplicationError => write!(f, "Application Error (3)"),
+            ExitCode::LicenseViolations => write!(f, "License Violations (4)"),
+            ExitCode::StaleLockfile => write!(f, "Stale Lockfile (5)"),
+            ExitCode::ValidationWarnings => write!(f, "Validation Warnings (6)"),
+        }
+    }
+}
+
+/// A phase of SBOM generation that a timeout or cancellation can interrupt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationPhase {
+    /// Fetching license information from the license source
+    LicenseEnrichment,
+    /// Fetching vulnerability records from OSV
+    VulnerabilityCheck,
+}
+
+impl GenerationPhase {
+    /// What the progress counts of this phase count
+    pub fn unit(self) -> &'static str {
+        match self {
+            GenerationPhase::LicenseEnrichment => "packages",
+            GenerationPhase::VulnerabilityCheck => "vulnerability records",
+        }
+    }
+}
+
+impl fmt::Display for GenerationPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerationPhase::LicenseEnrichment => write!(f, "license enrichment"),
+            GenerationPhase::VulnerabilityCheck => write!(f, "vulnerability check"),
+        }
+    }
+}
+
+/// Class of a failed license lookup
+///
+/// Tells packages that are simply not on the index (expected for internal
+/// packages) apart from lookups that failed and may succeed on another run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LicenseFetchErrorKind {
+    /// The package or version is not on the index (404)
+    NotFound,
+    /// The index answered 429 Too Many Requests
+    RateLimited,
+    /// The request timed out
+    Timeout,
+    /// The lookup was not made because the run's request budget was spent
+    Skipped,
+    /// Any other failure: connection and server errors, malformed responses
+    Other,
+}
+
+impl LicenseFetchErrorKind {
+    /// Classifies `error`; a [`UvSbomError::NetworkLicense`] keeps the class it was created with
+    pub fn of(error: &UvSbomError) -> Self {
+        match error {
+            UvSbomError::NetworkLicense { kind, .. } => *kind,
+            UvSbomError::HttpStatus { status, .. } if *status == reqwest::StatusCode::NOT_FOUND => {
+                Self::NotFound
+            }
+            UvSbomError::HttpStatus { status, .. }
+                if *status == reqwest::StatusCode::TOO_MANY_REQUESTS =>
+            {
+                Self::RateLimited
+            }
+            UvSbomError::Http(e) if e.is_timeout() => Self::Timeout,
+            UvSbomError::RequestLimitReached { .. } => Self::Skipped,
+            _ => Self::Other,
         }
     }
+
+    /// Whether the same request may succeed when retried after a delay
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::RateLimited | Self::Timeout)
+    }
 }
 
-/// Application-specific errors for SBOM generation.
+/// Errors returned by the uv-sbom library.
 ///
-/// Uses thiserror to derive Display and Error traits automatically,
+/// Every adapter and use case returns this type through [`crate::shared::Result`],
+/// so callers embedding the library can tell error categories apart by matching
+/// on the variant. Uses thiserror to derive Display and Error traits automatically,
 /// reducing boilerplate while maintaining user-friendly error messages.
 #[derive(Debug, Error)]
-pub enum SbomError {
+pub enum UvSbomError {
     #[error("uv.lock file not found: {path}\n\n💡 Hint: {suggestion}")]
     LockfileNotFound { path: PathBuf, suggestion: String },
 
     #[error("Failed to parse uv.lock file: {path}\nDetails: {details}\n\n💡 Hint: Please verify that the uv.lock file is in the correct format")]
     LockfileParseError { path: PathBuf, details: String },
 
+    /// The lockfile format is newer than supported and `--strict-lock-version` is set
+    #[error("Lockfile version {version} is newer than supported {supported}; results may be incomplete\n\n💡 Hint: Upgrade uv-sbom, or drop --strict-lock-version to continue with a best-effort parse")]
+    UnsupportedLockfileVersion { version: String, supported: u32 },
+
+    /// A requirements.txt line does not pin its package to one version
+    #[error("Unpinned requirement on line {line} of {path}: {requirement}\n\n💡 Hint: requirements.txt input needs an exact `==` version for every package. Export one with `uv export --format requirements-txt -o requirements.txt`, which pins them all")]
+    UnpinnedRequirement {
+        path: PathBuf,
+        line: usize,
+        requirement: String,
+    },
+
+    /// Raised before reading a lockfile larger than the configured limit
+    #[error("Lockfile is too large: {path} ({} MB, limit {} MB)\n\n💡 Hint: Lockfiles are parsed in memory. If the file is expected to be this large, raise the limit with --max-lockfile-size <MB>", .size.div_ceil(1024 * 1024), .limit / (1024 * 1024))]
+    LockfileTooLarge {
+        path: PathBuf,
+        size: u64,
+        limit: u64,
+    },
+
+    #[error("Failed to parse SBOM file: {path}\nDetails: {details}\n\n💡 Hint: Please pass a CycloneDX JSON file generated by uv-sbom, or a project directory")]
+    SbomParseError { path: PathBuf, details: String },
+
     #[error("Failed to write to file: {path}\nDetails: {details}\n\n💡 Hint: Please verify that the directory exists and you have write permissions")]
     FileWriteError { path: PathBuf, details: String },
 
+    /// Raised when the directory an output file is written into does not exist;
+    /// `path` is the absolute path of the output file
+    #[error("Failed to write to file: {path}\nDetails: Parent directory does not exist: {}\n\n💡 Hint: Create the directory first, or pass --create-dirs to create missing directories", .parent.display())]
+    OutputDirectoryMissing { path: PathBuf, parent: PathBuf },
+
     #[error("Invalid project path: {path}\nReason: {reason}\n\n💡 Hint: Please specify a valid project directory")]
     InvalidProjectPath { path: PathBuf, reason: String },
 
-    /// Validation error for builder patterns
+    /// A path given to a CLI option other than `--path` cannot be used, such as
+    /// `--output`, `--config` or `--data-dir`; `path` is as the user typed it
+    #[error("Invalid {option} path: {path}\nReason: {reason}\n\n💡 Hint: Please check the path passed to {option}")]
+    InvalidPath {
+        option: &'static str,
+        path: PathBuf,
+        reason: String,
+    },
+
+    /// Validation error for builder patterns and domain value objects
     #[error("Validation error: {message}")]
     Validation { message: String },
 
     #[error("Failed to read file: {path}\nDetails: {details}\n\n💡 Hint: Please verify that the file exists and you have read permissions")]
     FileReadError { path: PathBuf, details: String },
 
+    /// Raised by the project configuration reader when there is no pyproject.toml,
+    /// which callers may recover from, unlike a malformed one
+    #[error("pyproject.toml not found: {path}\n\n💡 Hint: Pass --project-name or --pyproject to name the project")]
+    ProjectConfigNotFound { path: PathBuf },
+
+    /// pyproject.toml has no `[project]` name, as in a virtual workspace root
+    #[error("Project name not found in {path}\n\n💡 Hint: Add a [project] table with a name, or pass --project-name")]
+    ProjectNameMissing { path: PathBuf },
+
     #[error("Security violation: {path}\nReason: {reason}\n\n💡 Hint: {hint}")]
     SecurityError {
         path: PathBuf,
         reason: String,
         hint: String,
     },
+    /// Raised by the shared HTTP client once `--max-requests` has been used up
+    #[error("Request limit of {limit} reached; request to {host} was skipped")]
+    RequestLimitReached { limit: u64, host: String },
+
+    /// Raised when the reader of stdout goes away mid-write (e.g. `uv-sbom | head`)
+    #[error("Output stream was closed before the SBOM was fully written")]
+    OutputClosed,
+
+    /// Raised while building the HTTP clients, before any request is sent
+    #[error("Failed to load CA bundle: {path}\nDetails: {details}\n\n💡 Hint: Please point ca_bundle_path, --ca-bundle or UV_SBOM_CA_BUNDLE at a PEM file with one or more certificates")]
+    CaBundleError { path: PathBuf, details: String },
+
+    /// Raised while opening a local advisory database, before any package is checked
+    #[error("Failed to read advisory database: {path}\nDetails: {details}\n\n💡 Hint: Please point --advisory-db or advisory_db at a local clone of https://github.com/pypa/advisory-database")]
+    AdvisoryDbError { path: PathBuf, details: String },
+
+    /// Raised while reading `--cve-baseline`, before any package is checked
+    #[error("Failed to parse CVE baseline: {path}\nDetails: {details}\n\n💡 Hint: Regenerate the file with --update-cve-baseline")]
+    CveBaselineParseError { path: PathBuf, details: String },
+
+    #[error("Invalid proxy URL: {url}\nDetails: {details}\n\n💡 Hint: Use a URL such as http://proxy.example.com:8080")]
+    InvalidProxyUrl { url: String, details: String },
+
+    /// Raised while building the HTTP clients, so a suffix cannot inject extra headers
+    #[error("Invalid User-Agent suffix: {suffix:?}\n\n💡 Hint: Control characters and line breaks are not allowed in network.user_agent_suffix, --user-agent-suffix or UV_SBOM_CONTACT")]
+    InvalidUserAgentSuffix { suffix: String },
+
+    /// Raised by `--validate` when the generated CycloneDX output breaks a structural rule
+    #[error("Generated CycloneDX output failed validation ({count} violations):\n{violations}\n\n💡 Hint: This is a bug in uv-sbom; please report it together with the violations above")]
+    CycloneDxValidationFailed { count: usize, violations: String },
+
+    /// The config file could not be read, parsed or failed validation
+    // Raised only by the library's `config` module, which the binary uses from the
+    // library crate instead of compiling its own copy
+    #[allow(dead_code)]
+    #[error("{message}")]
+    ConfigInvalid {
+        message: String,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
+    /// The lockfile does not match the requirements declared in pyproject.toml
+    #[error("The lockfile is out of date with pyproject.toml (declared but not locked: {}; locked but no longer declared: {})\n\n💡 Hint: Run `uv lock` to update the lockfile, or pass --no-lock-check to skip this check", join_or_none(.unlocked), join_or_none(.stale))]
+    StaleLockfile {
+        unlocked: Vec<String>,
+        stale: Vec<String>,
+    },
+
+    /// Every package in the lockfile matched an exclusion pattern
+    #[error("All {count} package(s) were excluded by the provided filters. The SBOM would be empty. Please adjust your exclusion patterns.")]
+    AllPackagesExcluded { count: usize },
+
+    /// The request's overall timeout passed while `phase` was still running
+    #[error("SBOM generation timed out after {timeout:?} during {phase} ({processed} of {total} {} done)\n\n💡 Hint: Raise --timeout, or skip network lookups with --offline or --no-check-cve", .phase.unit())]
+    TimedOut {
+        phase: GenerationPhase,
+        processed: usize,
+        total: usize,
+        timeout: Duration,
+    },
+
+    /// Generation was cancelled through its cancellation token, e.g. by Ctrl-C
+    #[error("SBOM generation was cancelled during {phase} ({processed} of {total} {} done)", .phase.unit())]
+    Cancelled {
+        phase: GenerationPhase,
+        processed: usize,
+        total: usize,
+    },
+
+    /// License lookup for a package failed at the license source
+    #[error("{error}")]
+    NetworkLicense {
+        package: String,
+        kind: LicenseFetchErrorKind,
+        error: Box<UvSbomError>,
+    },
+
+    /// The vulnerability database could not be queried
+    #[error(transparent)]
+    NetworkVulnerability(Box<UvSbomError>),
+
+    /// An HTTP request failed before a response was received
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+
+    /// A response with a non-success status code
+    ///
+    /// Kept apart from other failures so the retry policy can tell transient
+    /// statuses (429, 5xx) from permanent ones.
+    #[error("{message}")]
+    HttpStatus {
+        status: reqwest::StatusCode,
+        message: String,
+    },
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    /// Any other failure, described by its message
+    #[error("{message}")]
+    Other { message: String },
+}
+
+impl UvSbomError {
+    /// Creates a [`UvSbomError::Other`] from a message
+    pub fn other(message: impl Into<String>) -> Self {
+        Self::Other {
+            message: message.into(),
+        }
+    }
+
+    /// Creates a [`UvSbomError::Validation`] from a message
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::Validation {
+            message: message.into(),
+        }
+    }
+
+    /// Creates a [`UvSbomError::NetworkLicense`] for `package`, classifying `error`
+    pub fn license_fetch(package: impl Into<String>, error: UvSbomError) -> Self {
+        Self::NetworkLicense {
+            package: package.into(),
+            kind: LicenseFetchErrorKind::of(&error),
+            error: Box::new(error),
+        }
+    }
+
+    /// Creates a [`UvSbomError::HttpStatus`] for a response with `status`
+    pub fn http_status(status: reqwest::StatusCode, message: impl Into<String>) -> Self {
+        Self::HttpStatus {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Joins package names for an error message, or `none` when there are none
+fn join_or_none(names: &[String]) -> String {
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.join(", ")
+    }
 }
 
 #[cfg(test)]
@@ -82,6 +402,9 @@ mod tests {
         assert_eq!(ExitCode::VulnerabilitiesDetected.as_i32(), 1);
         assert_eq!(ExitCode::InvalidArguments.as_i32(), 2);
         assert_eq!(ExitCode::ApplicationError.as_i32(), 3);
+        assert_eq!(ExitCode::LicenseViolations.as_i32(), 4);
+        assert_eq!(ExitCode::StaleLockfile.as_i32(), 5);
+        assert_eq!(ExitCode::ValidationWarnings.as_i32(), 6);
     }
 
     #[test]
@@ -99,6 +422,43 @@ mod tests {
             format!("{}", ExitCode::ApplicationError),
             "Application Error (3)"
         );
+        assert_eq!(
+            format!("{}", ExitCode::LicenseViolations),
+            "License Violations (4)"
+        );
+        assert_eq!(format!("{}", ExitCode::StaleLockfile), "Stale Lockfile (5)");
+        assert_eq!(
+            format!("{}", ExitCode::ValidationWarnings),
+            "Validation Warnings (6)"
+        );
+    }
+
+    #[test]
+    fn test_exit_code_from_error() {
+        assert_eq!(
+            ExitCode::from(&UvSbomError::OutputClosed),
+            ExitCode::Success
+        );
+        for error in [
+            UvSbomError::AllPackagesExcluded { count: 3 },
+            UvSbomError::validation("Exclusion pattern cannot be empty"),
+            UvSbomError::NetworkVulnerability(Box::new(UvSbomError::other("timed out"))),
+            UvSbomError::Cancelled {
+                phase: GenerationPhase::LicenseEnrichment,
+                processed: 4,
+                total: 10,
+            },
+        ] {
+            assert_eq!(ExitCode::from(&error), ExitCode::ApplicationError);
+        }
+        let stale = UvSbomError::StaleLockfile {
+            unlocked: vec!["httpx".to_string()],
+            stale: vec![],
+        };
+        assert_eq!(ExitCode::from(&stale), ExitCode::StaleLockfile);
+        assert!(stale
+            .to_string()
+            .contains("declared but not locked: httpx; locked but no longer declared: none"));
     }
 
     #[test]
@@ -114,10 +474,10 @@ mod tests {
         assert_eq!(code, cloned);
     }
 
-    // SbomError tests
+    // UvSbomError tests
     #[test]
     fn test_lockfile_not_found_display() {
-        let error = SbomError::LockfileNotFound {
+        let error = UvSbomError::LockfileNotFound {
             path: PathBuf::from("/test/path/uv.lock"),
             suggestion: "Test suggestion".to_string(),
         };
@@ -130,7 +490,7 @@ mod tests {
 
     #[test]
     fn test_lockfile_parse_error_display() {
-        let error = SbomError::LockfileParseError {
+        let error = UvSbomError::LockfileParseError {
             path: PathBuf::from("/test/uv.lock"),
             details: "Invalid TOML syntax".to_string(),
         };
@@ -141,9 +501,34 @@ mod tests {
         assert!(display.contains("💡 Hint:"));
     }
 
+    #[test]
+    fn test_unsupported_lockfile_version_display() {
+        let error = UvSbomError::UnsupportedLockfileVersion {
+            version: "3".to_string(),
+            supported: 2,
+        };
+        let display = format!("{}", error);
+        assert!(display.contains("Lockfile version 3 is newer than supported 2"));
+        assert!(display.contains("--strict-lock-version"));
+        assert_eq!(ExitCode::from(&error), ExitCode::ApplicationError);
+    }
+
+    #[test]
+    fn test_unpinned_requirement_display() {
+        let error = UvSbomError::UnpinnedRequirement {
+            path: PathBuf::from("/test/requirements.txt"),
+            line: 4,
+            requirement: "requests>=2.0".to_string(),
+        };
+        let display = format!("{}", error);
+        assert!(display.contains("line 4 of /test/requirements.txt: requests>=2.0"));
+        assert!(display.contains("uv export --format requirements-txt"));
+        assert_eq!(ExitCode::from(&error), ExitCode::ApplicationError);
+    }
+
     #[test]
     fn test_file_write_error_display() {
-        let error = SbomError::FileWriteError {
+        let error = UvSbomError::FileWriteError {
             path: PathBuf::from("/test/output.json"),
             details: "Permission denied".to_string(),
         };
@@ -154,9 +539,22 @@ mod tests {
         assert!(display.contains("💡 Hint:"));
     }
 
+    #[test]
+    fn test_output_directory_missing_display() {
+        let error = UvSbomError::OutputDirectoryMissing {
+            path: PathBuf::from("/test/reports/sbom.json"),
+            parent: PathBuf::from("/test/reports"),
+        };
+        let display = format!("{}", error);
+        assert!(display.contains("/test/reports/sbom.json"));
+        assert!(display.contains("Parent directory does not exist: /test/reports"));
+        assert!(display.contains("--create-dirs"));
+        assert_eq!(ExitCode::from(&error), ExitCode::ApplicationError);
+    }
+
     #[test]
     fn test_invalid_project_path_display() {
-        let error = SbomError::InvalidProjectPath {
+        let error = UvSbomError::InvalidProjectPath {
             path: PathBuf::from("/invalid/path"),
             reason: "Directory does not exist".to_string(),
         };
@@ -167,9 +565,23 @@ mod tests {
         assert!(display.contains("💡 Hint:"));
     }
 
+    #[test]
+    fn test_invalid_path_display() {
+        let error = UvSbomError::InvalidPath {
+            option: "--config",
+            path: PathBuf::from("~/uv-sbom.config.yml"),
+            reason: "File does not exist".to_string(),
+        };
+        let display = format!("{}", error);
+        assert!(display.contains("Invalid --config path: ~/uv-sbom.config.yml"));
+        assert!(display.contains("File does not exist"));
+        assert!(display.contains("passed to --config"));
+        assert_eq!(ExitCode::from(&error), ExitCode::ApplicationError);
+    }
+
     #[test]
     fn test_file_read_error_display() {
-        let error = SbomError::FileReadError {
+        let error = UvSbomError::FileReadError {
             path: PathBuf::from("/test/file.txt"),
             details: "File not found".to_string(),
         };
@@ -180,9 +592,119 @@ mod tests {
         assert!(display.contains("💡 Hint:"));
     }
 
+    #[test]
+    fn test_project_name_missing_display() {
+        let error = UvSbomError::ProjectNameMissing {
+            path: PathBuf::from("/test/pyproject.toml"),
+        };
+        let display = format!("{}", error);
+        assert!(display.contains("Project name not found in /test/pyproject.toml"));
+        assert!(display.contains("--project-name"));
+        assert!(display.contains("💡 Hint:"));
+    }
+
+    #[test]
+    fn test_network_errors_display_the_underlying_error() {
+        let error = UvSbomError::license_fetch(
+            "requests",
+            UvSbomError::other("PyPI API returned status code 404"),
+        );
+        assert_eq!(error.to_string(), "PyPI API returned status code 404");
+
+        let error = UvSbomError::NetworkVulnerability(Box::new(UvSbomError::other(
+            "OSV API returned status code 503",
+        )));
+        assert_eq!(error.to_string(), "OSV API returned status code 503");
+    }
+
+    #[test]
+    fn test_license_fetch_error_kind_from_status() {
+        let kind_of = |status| {
+            LicenseFetchErrorKind::of(&UvSbomError::license_fetch(
+                "requests",
+                UvSbomError::http_status(status, format!("status code {}", status)),
+            ))
+        };
+        assert_eq!(
+            kind_of(reqwest::StatusCode::NOT_FOUND),
+            LicenseFetchErrorKind::NotFound
+        );
+        assert_eq!(
+            kind_of(reqwest::StatusCode::TOO_MANY_REQUESTS),
+            LicenseFetchErrorKind::RateLimited
+        );
+        assert_eq!(
+            kind_of(reqwest::StatusCode::BAD_GATEWAY),
+            LicenseFetchErrorKind::Other
+        );
+        assert_eq!(
+            LicenseFetchErrorKind::of(&UvSbomError::other("boom")),
+            LicenseFetchErrorKind::Other
+        );
+        assert_eq!(
+            LicenseFetchErrorKind::of(&UvSbomError::RequestLimitReached {
+                limit: 10,
+                host: "pypi.org".to_string(),
+            }),
+            LicenseFetchErrorKind::Skipped
+        );
+    }
+
+    #[test]
+    fn test_only_rate_limits_and_timeouts_are_retryable() {
+        assert!(LicenseFetchErrorKind::RateLimited.is_retryable());
+        assert!(LicenseFetchErrorKind::Timeout.is_retryable());
+        assert!(!LicenseFetchErrorKind::NotFound.is_retryable());
+        assert!(!LicenseFetchErrorKind::Skipped.is_retryable());
+        assert!(!LicenseFetchErrorKind::Other.is_retryable());
+    }
+
+    #[test]
+    fn test_all_packages_excluded_display() {
+        let display = UvSbomError::AllPackagesExcluded { count: 4 }.to_string();
+        assert!(display.contains("All 4 package(s) were excluded"));
+        assert!(display.contains("adjust your exclusion patterns"));
+    }
+
+    #[test]
+    fn test_invalid_user_agent_suffix_display() {
+        let display = UvSbomError::InvalidUserAgentSuffix {
+            suffix: "a\r\nX-Injected: 1".to_string(),
+        }
+        .to_string();
+        // The suffix is escaped, so the message itself has no stray line break
+        assert!(display.starts_with("Invalid User-Agent suffix: \"a\\r\\nX-Injected: 1\"\n"));
+        assert!(display.contains("UV_SBOM_CONTACT"));
+    }
+
+    #[test]
+    fn test_interrupted_generation_display() {
+        let display = UvSbomError::TimedOut {
+            phase: GenerationPhase::LicenseEnrichment,
+            processed: 12,
+            total: 40,
+            timeout: Duration::from_secs(120),
+        }
+        .to_string();
+        assert!(display.contains("timed out after 120s during license enrichment"));
+        assert!(display.contains("(12 of 40 packages done)"));
+        assert!(display.contains("💡 Hint: Raise --timeout"));
+
+        let display = UvSbomError::Cancelled {
+            phase: GenerationPhase::VulnerabilityCheck,
+            processed: 3,
+            total: 9,
+        }
+        .to_string();
+        assert_eq!(
+            display,
+            "SBOM generation was cancelled during vulnerability check (3 of 9 vulnerability records done)"
+        );
+    }
+
     #[test]
     fn test_security_error_display() {
-        let error = SbomError::SecurityError {
+        let error = UvSbomError::SecurityError {
             path: PathBuf::from("/test/symlink"),
             reason: "Symbolic links are not allowed".to_string(),
             hint: "Use a regular file instead".to_string(),
@@ -1,5 +1,7 @@
+pub mod digest;
 pub mod error;
 pub mod result;
 pub mod security;
+pub mod terminal;
 
 pub use result::Result;
@@ -1,3 +1,5 @@
-/// Type alias for Result with anyhow::Error as the error type.
+use super::error::UvSbomError;
+
+/// Type alias for Result with [`UvSbomError`] as the error type.
 /// This provides a consistent error handling pattern across the codebase.
-pub type Result<T> = std::result::Result<T, anyhow::Error>;
+pub type Result<T> = std::result::Result<T, UvSbomError>;
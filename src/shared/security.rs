@@ -1,4 +1,4 @@
-use crate::shared::error::SbomError;
+use crate::shared::error::UvSbomError;
 use crate::shared::Result;
 use std::fs::{self, File, Metadata};
 use std::io::Read;
@@ -8,6 +8,10 @@ use std::path::Path;
 /// This prevents DoS attacks via excessively large files
 pub const MAX_FILE_SIZE: u64 = 100 * 1024 * 1024;
 
+/// Default maximum lockfile size (50 MB)
+/// Lockfiles are parsed in memory; the limit can be raised with `--max-lockfile-size`
+pub const DEFAULT_MAX_LOCKFILE_SIZE: u64 = 50 * 1024 * 1024;
+
 /// Result of file validation containing metadata for reuse
 ///
 /// This struct allows callers to reuse the metadata obtained during validation,
@@ -33,44 +37,42 @@ pub struct FileValidationResult {
 /// `FileValidationResult` containing the file metadata on success
 ///
 /// # Errors
-/// Returns `SbomError::SecurityError` for symlinks or non-files
-/// Returns `SbomError::FileReadError` for metadata read failures or size violations
+/// Returns `UvSbomError::SecurityError` for symlinks or non-files
+/// Returns `UvSbomError::FileReadError` for metadata read failures or size violations
 pub fn validate_and_get_metadata(
     path: &Path,
     file_description: &str,
     max_size: u64,
 ) -> Result<FileValidationResult> {
     // Get metadata once and reuse it for all checks
-    let metadata = fs::symlink_metadata(path).map_err(|e| SbomError::FileReadError {
+    let metadata = fs::symlink_metadata(path).map_err(|e| UvSbomError::FileReadError {
         path: path.to_path_buf(),
         details: format!("Failed to read {} metadata: {}", file_description, e),
     })?;
 
     // Security check: Reject symbolic links
     if metadata.is_symlink() {
-        return Err(SbomError::SecurityError {
+        return Err(UvSbomError::SecurityError {
             path: path.to_path_buf(),
             reason: format!("{} is a symbolic link", file_description),
             hint:
                 "For security reasons, symbolic links are not allowed. Use a regular file instead."
                     .to_string(),
-        }
-        .into());
+        });
     }
 
     // Security check: Must be a regular file
     if !metadata.is_file() {
-        return Err(SbomError::SecurityError {
+        return Err(UvSbomError::SecurityError {
             path: path.to_path_buf(),
             reason: format!("{} is not a regular file", file_description),
             hint: "Please specify a regular file, not a directory or special file.".to_string(),
-        }
-        .into());
+        });
     }
 
     // Security check: File size limit
     if metadata.len() > max_size {
-        return Err(SbomError::FileReadError {
+        return Err(UvSbomError::FileReadError {
             path: path.to_path_buf(),
             details: format!(
                 "{} is too large ({} bytes). Maximum allowed size is {} bytes",
@@ -78,13 +80,28 @@ pub fn validate_and_get_metadata(
                 metadata.len(),
                 max_size
             ),
-        }
-        .into());
+        });
     }
 
     Ok(FileValidationResult { metadata })
 }
 
+/// Rejects a lockfile larger than `max_size` before it is read.
+///
+/// Unlike the generic size check in `validate_and_get_metadata`, this reports
+/// `LockfileTooLarge`, whose hint explains how to raise the limit. A file whose
+/// metadata cannot be read is left to `read_file_with_security` to report.
+pub fn check_lockfile_size(path: &Path, max_size: u64) -> Result<()> {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.len() > max_size => Err(UvSbomError::LockfileTooLarge {
+            path: path.to_path_buf(),
+            size: metadata.len(),
+            limit: max_size,
+        }),
+        _ => Ok(()),
+    }
+}
+
 /// Reads a file with comprehensive security checks.
 ///
 /// This function provides:
@@ -102,7 +119,7 @@ pub fn validate_and_get_metadata(
 /// The file contents as a String
 ///
 /// # Errors
-/// Returns appropriate `SbomError` variants for various failure modes
+/// Returns appropriate `UvSbomError` variants for various failure modes
 pub fn read_file_with_security(
     path: &Path,
     file_description: &str,
@@ -113,13 +130,13 @@ pub fn read_file_with_security(
     let initial_size = validation.metadata.len();
 
     // Open file
-    let mut file = File::open(path).map_err(|e| SbomError::FileReadError {
+    let mut file = File::open(path).map_err(|e| UvSbomError::FileReadError {
         path: path.to_path_buf(),
         details: format!("Failed to open {}: {}", file_description, e),
     })?;
 
     // TOCTOU mitigation: Re-check metadata on the opened file descriptor
-    let fd_metadata = file.metadata().map_err(|e| SbomError::FileReadError {
+    let fd_metadata = file.metadata().map_err(|e| UvSbomError::FileReadError {
         path: path.to_path_buf(),
         details: format!(
             "Failed to read {} metadata after opening: {}",
@@ -129,18 +146,17 @@ pub fn read_file_with_security(
 
     // Verify file hasn't changed (same size indicates likely same file)
     if fd_metadata.len() != initial_size {
-        return Err(SbomError::SecurityError {
+        return Err(UvSbomError::SecurityError {
             path: path.to_path_buf(),
             reason: "File changed between validation and reading".to_string(),
             hint: "This may indicate a TOCTOU attack. Please try again.".to_string(),
-        }
-        .into());
+        });
     }
 
     // Read file contents
     let mut contents = String::new();
     file.read_to_string(&mut contents)
-        .map_err(|e| SbomError::FileReadError {
+        .map_err(|e| UvSbomError::FileReadError {
             path: path.to_path_buf(),
             details: format!("Failed to read {}: {}", file_description, e),
         })?;
@@ -160,56 +176,52 @@ pub fn read_file_with_security(
 /// * `path` - The directory path to validate
 ///
 /// # Errors
-/// Returns `SbomError::InvalidProjectPath` for any validation failure
+/// Returns `UvSbomError::InvalidProjectPath` for any validation failure
 pub fn validate_directory_path(path: &Path) -> Result<()> {
     // Check existence
     if !path.exists() {
-        return Err(SbomError::InvalidProjectPath {
+        return Err(UvSbomError::InvalidProjectPath {
             path: path.to_path_buf(),
             reason: "Directory does not exist".to_string(),
-        }
-        .into());
+        });
     }
 
     // Get metadata to check for symlinks
-    let metadata = fs::symlink_metadata(path).map_err(|e| SbomError::InvalidProjectPath {
+    let metadata = fs::symlink_metadata(path).map_err(|e| UvSbomError::InvalidProjectPath {
         path: path.to_path_buf(),
         reason: format!("Failed to read path metadata: {}", e),
     })?;
 
     // Security check: Reject symbolic links
     if metadata.is_symlink() {
-        return Err(SbomError::InvalidProjectPath {
+        return Err(UvSbomError::InvalidProjectPath {
             path: path.to_path_buf(),
             reason: "Security: Project path is a symbolic link. For security reasons, symbolic links are not allowed.".to_string(),
-        }
-        .into());
+        });
     }
 
     // Check it's a directory
     if !path.is_dir() {
-        return Err(SbomError::InvalidProjectPath {
+        return Err(UvSbomError::InvalidProjectPath {
             path: path.to_path_buf(),
             reason: "Not a directory".to_string(),
-        }
-        .into());
+        });
     }
 
     // Canonicalize to prevent path traversal
     let canonical_path = path
         .canonicalize()
-        .map_err(|e| SbomError::InvalidProjectPath {
+        .map_err(|e| UvSbomError::InvalidProjectPath {
             path: path.to_path_buf(),
             reason: format!("Failed to canonicalize path: {}", e),
         })?;
 
     // Final check on canonicalized path
     if !canonical_path.is_dir() {
-        return Err(SbomError::InvalidProjectPath {
+        return Err(UvSbomError::InvalidProjectPath {
             path: path.to_path_buf(),
             reason: "Resolved path is not a directory".to_string(),
-        }
-        .into());
+        });
     }
 
     Ok(())
@@ -229,20 +241,18 @@ pub fn validate_directory_path(path: &Path) -> Result<()> {
 /// Returns an error if the path is a symbolic link or if metadata cannot be read
 pub fn validate_not_symlink(path: &Path, operation: &str) -> Result<()> {
     let metadata = fs::symlink_metadata(path).map_err(|e| {
-        anyhow::anyhow!(
+        UvSbomError::other(format!(
             "Failed to read metadata for {} operation on {}: {}",
             operation,
             path.display(),
             e
-        )
+        ))
     })?;
 
     if metadata.is_symlink() {
-        anyhow::bail!(
-            "Security: {} is a symbolic link. For security reasons, {} operations on symbolic links are not allowed.",
+        return Err(UvSbomError::other(format!("Security: {} is a symbolic link. For security reasons, {} operations on symbolic links are not allowed.",
             path.display(),
-            operation
-        );
+            operation)));
     }
 
     Ok(())
@@ -272,6 +282,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_check_lockfile_size() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("uv.lock");
+        fs::write(&file_path, "version = 1\n").unwrap();
+
+        assert!(check_lockfile_size(&file_path, DEFAULT_MAX_LOCKFILE_SIZE).is_ok());
+        let err = check_lockfile_size(&file_path, 4).unwrap_err();
+        assert!(matches!(
+            err,
+            UvSbomError::LockfileTooLarge {
+                size: 12,
+                limit: 4,
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("--max-lockfile-size"));
+    }
+
     #[test]
     fn test_max_file_size_constant() {
         assert_eq!(MAX_FILE_SIZE, 100 * 1024 * 1024); // 100 MB
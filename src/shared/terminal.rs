@@ -0,0 +1,473 @@
+//! Terminal capability detection and progress display.
+//!
+//! Legacy Windows consoles (cmd.exe without virtual terminal processing) print
+//! ANSI escape sequences literally, which garbles progress bars and colours.
+//! ANSI support is enabled on Windows 10+ consoles where possible; when it is
+//! unavailable, progress falls back to plain lines.
+//!
+//! The same plain lines are used when stderr is not a terminal (CI logs,
+//! redirected output) and when `NO_COLOR` or `UV_SBOM_NO_PROGRESS` is set, so
+//! that logs never contain escape sequences or carriage-return redraws.
+
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle, TermLike};
+use std::borrow::Cow;
+use std::io::{self, IsTerminal, Write};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+/// Minimum time between two plain progress lines
+const LINE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How progress is rendered on stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// Animated indicatif progress bar
+    Bar,
+    /// Plain lines, at most one per 10% of progress and one every 2 seconds
+    Lines,
+    /// Nothing is drawn (quiet mode)
+    Hidden,
+}
+
+/// When progress bars are drawn, from `--progress`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressDisplay {
+    /// Bars on an ANSI-capable terminal; plain lines otherwise, and when
+    /// `NO_COLOR` or `UV_SBOM_NO_PROGRESS` is set
+    #[default]
+    Auto,
+    /// Bars even when stderr is not a terminal
+    Always,
+    /// Plain lines only
+    Never,
+}
+
+/// Returns whether stderr can interpret ANSI escape sequences.
+///
+/// On Windows the first call enables virtual terminal processing on the console.
+/// The result is computed once per process.
+pub fn stderr_supports_ansi() -> bool {
+    static SUPPORTED: OnceLock<bool> = OnceLock::new();
+    *SUPPORTED.get_or_init(|| {
+        ansi_supported(std::env::var("TERM").ok().as_deref(), enable_ansi_console())
+    })
+}
+
+/// Enables ANSI escape processing on Windows 10+ consoles.
+///
+/// Returns `None` on other platforms, where nothing needs enabling.
+fn enable_ansi_console() -> Option<bool> {
+    anstyle_query::windows::enable_ansi_colors()
+}
+
+/// Decides ANSI support from `TERM` and the result of enabling the console.
+fn ansi_supported(term: Option<&str>, console_enabled: Option<bool>) -> bool {
+    if term == Some("dumb") {
+        return false;
+    }
+    console_enabled.unwrap_or(true)
+}
+
+/// Returns whether the `NO_COLOR` convention (<https://no-color.org>) is in effect.
+pub fn no_color() -> bool {
+    env_flag("NO_COLOR")
+}
+
+/// Returns whether colours may be written to stderr.
+pub fn stderr_colors_enabled() -> bool {
+    io::stderr().is_terminal() && stderr_supports_ansi() && !no_color()
+}
+
+/// Returns true if the environment variable is set to a non-empty value.
+fn env_flag(name: &str) -> bool {
+    std::env::var_os(name).is_some_and(|value| !value.is_empty())
+}
+
+/// Selects the progress mode for the current stderr and environment.
+pub fn progress_mode(display: ProgressDisplay) -> ProgressMode {
+    select_progress_mode(
+        display,
+        io::stderr().is_terminal(),
+        stderr_supports_ansi(),
+        no_color() || env_flag("UV_SBOM_NO_PROGRESS"),
+    )
+}
+
+fn select_progress_mode(
+    display: ProgressDisplay,
+    is_terminal: bool,
+    ansi: bool,
+    bars_disabled: bool,
+) -> ProgressMode {
+    match display {
+        ProgressDisplay::Always => ProgressMode::Bar,
+        ProgressDisplay::Never => ProgressMode::Lines,
+        ProgressDisplay::Auto if is_terminal && ansi && !bars_disabled => ProgressMode::Bar,
+        ProgressDisplay::Auto => ProgressMode::Lines,
+    }
+}
+
+/// Removes emoji, and the space that follows each, from `message`.
+///
+/// Used with `NO_COLOR`, whose users generally want plain text output.
+pub fn strip_emoji(message: &str) -> String {
+    let mut stripped = String::with_capacity(message.len());
+    let mut chars = message.chars().peekable();
+    while let Some(c) = chars.next() {
+        if is_emoji(c) {
+            while chars.next_if(|&next| is_emoji_modifier(next)).is_some() {}
+            chars.next_if_eq(&' ');
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+/// Returns `message` for stderr: without emoji when `NO_COLOR` is set.
+pub fn stderr_text(message: &str) -> Cow<'_, str> {
+    if no_color() {
+        Cow::Owned(strip_emoji(message))
+    } else {
+        Cow::Borrowed(message)
+    }
+}
+
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0x2300..=0x23FF | 0x2139 | 0x25B6
+    )
+}
+
+/// Variation selectors and joiners that belong to the preceding emoji
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32, 0xFE0E | 0xFE0F | 0x200D) || is_emoji(c)
+}
+
+/// Stderr as a draw target that indicatif never hides, for `--progress always`
+#[derive(Debug)]
+struct ForcedStderr;
+
+impl TermLike for ForcedStderr {
+    fn width(&self) -> u16 {
+        80
+    }
+
+    fn move_cursor_up(&self, n: usize) -> io::Result<()> {
+        self.write_escape(n, 'A')
+    }
+
+    fn move_cursor_down(&self, n: usize) -> io::Result<()> {
+        self.write_escape(n, 'B')
+    }
+
+    fn move_cursor_right(&self, n: usize) -> io::Result<()> {
+        self.write_escape(n, 'C')
+    }
+
+    fn move_cursor_left(&self, n: usize) -> io::Result<()> {
+        self.write_escape(n, 'D')
+    }
+
+    fn write_line(&self, s: &str) -> io::Result<()> {
+        writeln!(io::stderr(), "{}", s)
+    }
+
+    fn write_str(&self, s: &str) -> io::Result<()> {
+        io::stderr().write_all(s.as_bytes())
+    }
+
+    fn clear_line(&self) -> io::Result<()> {
+        io::stderr().write_all(b"\r\x1b[2K")
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        io::stderr().flush()
+    }
+}
+
+impl ForcedStderr {
+    fn write_escape(&self, n: usize, direction: char) -> io::Result<()> {
+        if n == 0 {
+            return Ok(());
+        }
+        write!(io::stderr(), "\x1b[{}{}", n, direction)
+    }
+}
+
+/// Progress indicator that renders as a bar or as plain lines depending on the terminal.
+pub struct TerminalProgress {
+    bar: Option<ProgressBar>,
+    mode: ProgressMode,
+    message: String,
+    len: u64,
+    reported_step: u64,
+    last_line: Option<Instant>,
+}
+
+impl TerminalProgress {
+    /// Creates a progress indicator for `len` items labelled with `message`.
+    pub fn new(display: ProgressDisplay, len: u64, message: impl Into<String>) -> Self {
+        let progress = Self::with_mode(progress_mode(display), len, message);
+        if display == ProgressDisplay::Always {
+            if let Some(bar) = &progress.bar {
+                bar.set_draw_target(ProgressDrawTarget::term_like(Box::new(ForcedStderr)));
+            }
+        }
+        progress
+    }
+
+    /// Creates a progress indicator that draws only when `visible` is true.
+    pub fn new_if(
+        visible: bool,
+        display: ProgressDisplay,
+        len: u64,
+        message: impl Into<String>,
+    ) -> Self {
+        if visible {
+            Self::new(display, len, message)
+        } else {
+            Self::with_mode(ProgressMode::Hidden, len, message)
+        }
+    }
+
+    fn with_mode(mode: ProgressMode, len: u64, message: impl Into<String>) -> Self {
+        let message = message.into();
+        let bar = match mode {
+            ProgressMode::Bar => {
+                let pb = ProgressBar::new(len);
+                pb.set_style(
+                    ProgressStyle::default_bar()
+                        .template("   {spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} - {msg}")
+                        .expect("Failed to set progress bar template")
+                        .progress_chars("=>-"),
+                );
+                pb.set_message(message.clone());
+                Some(pb)
+            }
+            ProgressMode::Lines | ProgressMode::Hidden => None,
+        };
+        Self {
+            bar,
+            mode,
+            message,
+            len,
+            reported_step: 0,
+            last_line: None,
+        }
+    }
+
+    pub fn set_length(&mut self, len: u64) {
+        if let Some(bar) = &self.bar {
+            bar.set_length(len);
+        }
+        self.len = len;
+    }
+
+    pub fn set_position(&mut self, pos: u64) {
+        if let Some(bar) = &self.bar {
+            bar.set_position(pos);
+        } else if self.mode == ProgressMode::Lines {
+            if let Some(line) = self.next_line(pos, Instant::now()) {
+                eprintln!("{}", line);
+            }
+        }
+    }
+
+    /// Advances the spinner while the total is still unknown.
+    pub fn tick(&self) {
+        if let Some(bar) = &self.bar {
+            bar.tick();
+        }
+    }
+
+    /// Removes the bar from the terminal once the work is done.
+    pub fn finish(self) {
+        if let Some(bar) = self.bar {
+            bar.finish_and_clear();
+        }
+    }
+
+    /// Returns the line to print in line mode when `pos` reaches a new 10% step.
+    ///
+    /// Lines are at least [`LINE_INTERVAL`] apart, except for the one that
+    /// reports completion.
+    fn next_line(&mut self, pos: u64, now: Instant) -> Option<String> {
+        if self.len == 0 || pos == 0 {
+            return None;
+        }
+        let step = pos.min(self.len) * 10 / self.len;
+        if step <= self.reported_step {
+            return None;
+        }
+        let throttled = self
+            .last_line
+            .is_some_and(|last| now.duration_since(last) < LINE_INTERVAL);
+        if throttled && pos < self.len {
+            return None;
+        }
+        self.reported_step = step;
+        self.last_line = Some(now);
+        Some(format!("   {} {}/{}", self.message, pos, self.len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi_supported_defaults_to_true_off_windows() {
+        assert!(ansi_supported(None, None));
+        assert!(ansi_supported(Some("xterm-256color"), None));
+    }
+
+    #[test]
+    fn test_ansi_supported_follows_console_result() {
+        assert!(ansi_supported(None, Some(true)));
+        assert!(!ansi_supported(None, Some(false)));
+    }
+
+    #[test]
+    fn test_ansi_supported_dumb_terminal() {
+        assert!(!ansi_supported(Some("dumb"), None));
+        assert!(!ansi_supported(Some("dumb"), Some(true)));
+    }
+
+    #[test]
+    fn test_select_progress_mode() {
+        let auto = ProgressDisplay::Auto;
+        assert_eq!(
+            select_progress_mode(auto, true, true, false),
+            ProgressMode::Bar
+        );
+        assert_eq!(
+            select_progress_mode(auto, true, false, false),
+            ProgressMode::Lines
+        );
+        // Not a terminal: plain lines, so logs get no escape sequences
+        assert_eq!(
+            select_progress_mode(auto, false, true, false),
+            ProgressMode::Lines
+        );
+        // NO_COLOR or UV_SBOM_NO_PROGRESS
+        assert_eq!(
+            select_progress_mode(auto, true, true, true),
+            ProgressMode::Lines
+        );
+    }
+
+    #[test]
+    fn test_select_progress_mode_overrides() {
+        for (is_terminal, ansi, bars_disabled) in [(false, false, true), (true, true, false)] {
+            assert_eq!(
+                select_progress_mode(ProgressDisplay::Always, is_terminal, ansi, bars_disabled),
+                ProgressMode::Bar
+            );
+            assert_eq!(
+                select_progress_mode(ProgressDisplay::Never, is_terminal, ansi, bars_disabled),
+                ProgressMode::Lines
+            );
+        }
+    }
+
+    /// Positions reported `secs` seconds apart
+    fn lines_at(progress: &mut TerminalProgress, positions: &[(u64, u64)]) -> Vec<String> {
+        let start = Instant::now();
+        positions
+            .iter()
+            .filter_map(|&(pos, secs)| progress.next_line(pos, start + Duration::from_secs(secs)))
+            .collect()
+    }
+
+    #[test]
+    fn test_line_mode_reports_each_ten_percent_once() {
+        let mut progress = TerminalProgress::with_mode(ProgressMode::Lines, 20, "Fetching...");
+        let positions: Vec<(u64, u64)> = (0..=20).map(|pos| (pos, pos * 2)).collect();
+        let lines = lines_at(&mut progress, &positions);
+
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "   Fetching... 2/20");
+        assert_eq!(lines[9], "   Fetching... 20/20");
+    }
+
+    #[test]
+    fn test_line_mode_small_totals_report_every_item() {
+        let mut progress = TerminalProgress::with_mode(ProgressMode::Lines, 3, "Fetching...");
+        let lines = lines_at(&mut progress, &[(0, 0), (1, 2), (2, 4), (3, 6)]);
+
+        assert_eq!(
+            lines,
+            vec![
+                "   Fetching... 1/3",
+                "   Fetching... 2/3",
+                "   Fetching... 3/3"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_mode_prints_at_most_every_two_seconds() {
+        let mut progress = TerminalProgress::with_mode(ProgressMode::Lines, 10, "Fetching...");
+        let lines = lines_at(
+            &mut progress,
+            &[(1, 0), (2, 1), (3, 1), (4, 2), (9, 3), (10, 3)],
+        );
+
+        // The final line is never held back
+        assert_eq!(
+            lines,
+            vec![
+                "   Fetching... 1/10",
+                "   Fetching... 4/10",
+                "   Fetching... 10/10"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strip_emoji() {
+        assert_eq!(
+            strip_emoji("🔍 Checking vulnerabilities..."),
+            "Checking vulnerabilities..."
+        );
+        assert_eq!(
+            strip_emoji("   ⚠️ Warning: 2 packages"),
+            "   Warning: 2 packages"
+        );
+        assert_eq!(strip_emoji("✅ Done → sbom.json"), "Done → sbom.json");
+        assert_eq!(
+            strip_emoji("ライセンス情報を取得中"),
+            "ライセンス情報を取得中"
+        );
+    }
+
+    #[test]
+    fn test_hidden_progress_draws_nothing() {
+        let progress = TerminalProgress::new_if(false, ProgressDisplay::Always, 20, "Fetching...");
+        assert!(progress.bar.is_none());
+        assert_eq!(progress.mode, ProgressMode::Hidden);
+    }
+
+    #[test]
+    fn test_line_mode_unknown_length_prints_nothing() {
+        let mut progress = TerminalProgress::with_mode(ProgressMode::Lines, 0, "Fetching...");
+        assert_eq!(progress.next_line(5, Instant::now()), None);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_enable_ansi_console_reports_result_on_windows() {
+        // Always Some on Windows: true on consoles with VT support, false otherwise
+        assert!(enable_ansi_console().is_some());
+        // Enabling is idempotent
+        assert_eq!(enable_ansi_console(), enable_ansi_console());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_stderr_supports_ansi_is_stable_on_windows() {
+        assert_eq!(stderr_supports_ansi(), stderr_supports_ansi());
+    }
+}
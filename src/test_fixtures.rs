@@ -0,0 +1,442 @@
+//! Fixture builder for [`SbomReadModel`]
+//!
+//! Formatter tests need read models, and writing them out as struct literals
+//! makes it easy to end up with a model the real builder could never produce
+//! (a dependency pointing at a missing bom-ref, summary counts that disagree
+//! with the vulnerability lists, ...). This module derives everything that the
+//! real builder would derive, so tests only state what they care about.
+//!
+//! Available to the crate's own tests, and to library consumers that enable
+//! the `test-fixtures` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! uv-sbom = { version = "2", features = ["test-fixtures"] }
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use uv_sbom::application::read_models::SeverityView;
+//! use uv_sbom::test_fixtures::SbomReadModelFixture;
+//!
+//! let mut fixture = SbomReadModelFixture::builder();
+//! fixture.add_component("requests", "2.31.0").license("Apache-2.0").direct();
+//! fixture.add_component("urllib3", "1.26.0").license("MIT");
+//! fixture
+//!     .add_vulnerability("CVE-2024-1234")
+//!     .severity(SeverityView::High)
+//!     .on("urllib3", "1.26.0");
+//! let model = fixture.with_dependencies(&[("requests", &["urllib3"])]).build();
+//!
+//! assert_eq!(model.components[1].bom_ref, "urllib3-1.26.0");
+//! assert_eq!(model.vulnerabilities.unwrap().summary.total_count, 1);
+//! ```
+
+use crate::application::read_models::{
+    ComponentView, DependencyView, LicenseView, SbomMetadataView, SbomReadModel, SeverityView,
+    VersionConflictView, VulnerabilityReportView, VulnerabilitySummary, VulnerabilityView,
+};
+use crate::sbom_generation::domain::PackageName;
+use crate::sbom_generation::policies::spdx_license_map;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Entry point for building [`SbomReadModel`] fixtures
+pub struct SbomReadModelFixture;
+
+impl SbomReadModelFixture {
+    /// Returns an empty fixture builder.
+    pub fn builder() -> SbomReadModelFixtureBuilder {
+        SbomReadModelFixtureBuilder::default()
+    }
+
+    /// Two components and no findings: `requests 2.31.0` (direct, Apache-2.0)
+    /// and `urllib3 1.26.0` (MIT). No dependency graph, and the vulnerability
+    /// check is treated as skipped.
+    pub fn small_clean() -> SbomReadModelFixtureBuilder {
+        let mut fixture = Self::builder();
+        fixture
+            .add_component("requests", "2.31.0")
+            .license("Apache-2.0")
+            .description("HTTP library")
+            .direct();
+        fixture.add_component("urllib3", "1.26.0").license("MIT");
+        fixture
+    }
+
+    /// [`small_clean`](Self::small_clean) plus an actionable CRITICAL
+    /// vulnerability on `requests` (CVE-2024-1234, fixed in 2.32.0) and an
+    /// informational LOW vulnerability on `urllib3` (CVE-2024-5678).
+    pub fn with_vulns() -> SbomReadModelFixtureBuilder {
+        let mut fixture = Self::small_clean();
+        fixture
+            .add_vulnerability("CVE-2024-1234")
+            .severity(SeverityView::Critical)
+            .cvss(9.8)
+            .fixed_in("2.32.0")
+            .on("requests", "2.31.0");
+        fixture
+            .add_vulnerability("CVE-2024-5678")
+            .severity(SeverityView::Low)
+            .cvss(2.0)
+            .informational()
+            .on("urllib3", "1.26.0");
+        fixture
+    }
+}
+
+/// Builder that derives bom-refs, purls, licenses and summary counts the same
+/// way [`SbomReadModelBuilder`](crate::application::read_models::SbomReadModelBuilder) does
+#[derive(Debug, Default)]
+pub struct SbomReadModelFixtureBuilder {
+    components: Vec<ComponentView>,
+    vulnerabilities: Vec<VulnerabilitySpec>,
+    vulnerability_check: bool,
+    dependencies: Option<Vec<(String, Vec<String>)>>,
+}
+
+#[derive(Debug)]
+struct VulnerabilitySpec {
+    id: String,
+    aliases: Vec<String>,
+    package: Option<(String, String)>,
+    severity: SeverityView,
+    cvss_score: Option<f32>,
+    fixed_version: Option<String>,
+    actionable: bool,
+}
+
+impl SbomReadModelFixtureBuilder {
+    /// Adds a component; bom-ref and purl are derived from the PEP 503 normalized
+    /// name and the version, as in generated read models.
+    pub fn add_component(&mut self, name: &str, version: &str) -> ComponentFixture<'_> {
+        let normalized = PackageName::normalize(name);
+        self.components.push(ComponentView {
+            bom_ref: format!("{}-{}", normalized, version),
+            name: name.to_string(),
+            version: version.to_string(),
+            purl: format!("pkg:pypi/{}@{}", normalized, version),
+            license: None,
+            description: None,
+            sha256_hash: None,
+            is_direct_dependency: false,
+            annotations: BTreeMap::new(),
+            license_override: None,
+            yanked: None,
+            source: None,
+            declared_constraint: None,
+            license_not_found: false,
+            license_fetch_error: None,
+            python_compat: None,
+            author: None,
+            supplier: None,
+            external_refs: Vec::new(),
+        });
+        ComponentFixture {
+            component: self.components.last_mut().unwrap(),
+        }
+    }
+
+    /// Adds a vulnerability. It must be attached to a component with
+    /// [`VulnerabilityFixture::on`]. Adding one also marks the vulnerability
+    /// check as performed.
+    pub fn add_vulnerability(&mut self, id: &str) -> VulnerabilityFixture<'_> {
+        self.vulnerability_check = true;
+        self.vulnerabilities.push(VulnerabilitySpec {
+            id: id.to_string(),
+            aliases: Vec::new(),
+            package: None,
+            severity: SeverityView::None,
+            cvss_score: None,
+            fixed_version: None,
+            actionable: true,
+        });
+        VulnerabilityFixture {
+            spec: self.vulnerabilities.last_mut().unwrap(),
+        }
+    }
+
+    /// Marks the vulnerability check as performed, producing an empty report
+    /// when no vulnerabilities were added.
+    pub fn with_vulnerability_check(&mut self) -> &mut Self {
+        self.vulnerability_check = true;
+        self
+    }
+
+    /// Adds a dependency graph. Each entry maps a component name to the names
+    /// of its dependencies; direct dependencies are the components marked with
+    /// [`ComponentFixture::direct`].
+    ///
+    /// # Panics
+    ///
+    /// `build` panics if a name does not match an added component.
+    pub fn with_dependencies(&mut self, edges: &[(&str, &[&str])]) -> &mut Self {
+        self.dependencies = Some(
+            edges
+                .iter()
+                .map(|(parent, children)| {
+                    (
+                        parent.to_string(),
+                        children.iter().map(|c| c.to_string()).collect(),
+                    )
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Builds the read model.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a vulnerability was not attached to a component, or if the
+    /// dependency graph names an unknown component.
+    pub fn build(&self) -> SbomReadModel {
+        SbomReadModel {
+            metadata: SbomMetadataView {
+                timestamp: "2024-01-01T00:00:00Z".to_string(),
+                tool_name: "uv-sbom".to_string(),
+                tool_version: "1.0.0".to_string(),
+                serial_number: "urn:uuid:3e671687-395b-41f5-a30f-a58921a69b79".to_string(),
+                component: None,
+                lockfile_sha256: None,
+                target_environment: None,
+                invocation: None,
+                lockfile_version: None,
+                lockfile_requires_python: None,
+            },
+            components: self.components.clone(),
+            dependencies: self
+                .dependencies
+                .as_ref()
+                .map(|edges| self.build_dependencies(edges)),
+            vulnerabilities: self
+                .vulnerability_check
+                .then(|| self.build_vulnerabilities()),
+            license_compliance: None,
+            license_compatibility: None,
+            license_risk: None,
+            python_compatibility: None,
+            typosquats: Vec::new(),
+            resolution_guide: None,
+            upgrade_recommendations: None,
+            fix_suggestions: None,
+            project_self_check: None,
+            warnings: Vec::new(),
+            stats: None,
+            projects: Vec::new(),
+            version_conflicts: self.build_version_conflicts(),
+        }
+    }
+
+    fn build_version_conflicts(&self) -> Vec<VersionConflictView> {
+        let mut versions: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for component in &self.components {
+            versions
+                .entry(component.name.as_str())
+                .or_default()
+                .push(component.version.clone());
+        }
+        versions
+            .into_iter()
+            .filter(|(_, versions)| versions.len() > 1)
+            .map(|(name, versions)| VersionConflictView {
+                name: name.to_string(),
+                versions,
+            })
+            .collect()
+    }
+
+    fn bom_ref_of(&self, name: &str) -> String {
+        self.components
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.bom_ref.clone())
+            .unwrap_or_else(|| panic!("fixture has no component named {:?}", name))
+    }
+
+    fn build_dependencies(&self, edges: &[(String, Vec<String>)]) -> DependencyView {
+        let direct = self
+            .components
+            .iter()
+            .filter(|c| c.is_direct_dependency)
+            .map(|c| c.bom_ref.clone())
+            .collect();
+        let transitive: HashMap<String, Vec<String>> = edges
+            .iter()
+            .map(|(parent, children)| {
+                (
+                    self.bom_ref_of(parent),
+                    children.iter().map(|c| self.bom_ref_of(c)).collect(),
+                )
+            })
+            .collect();
+        // The fixture edges double as the dependency tree
+        let children = transitive.clone();
+        DependencyView {
+            direct,
+            transitive,
+            children,
+            extras: HashMap::new(),
+            unreferenced: Vec::new(),
+        }
+    }
+
+    fn build_vulnerabilities(&self) -> VulnerabilityReportView {
+        let mut actionable = Vec::new();
+        let mut informational = Vec::new();
+        for spec in &self.vulnerabilities {
+            let (name, version) = spec.package.as_ref().unwrap_or_else(|| {
+                panic!(
+                    "vulnerability {:?} is not attached to a component; call .on(name, version)",
+                    spec.id
+                )
+            });
+            let affected_component = format!("{}-{}", PackageName::normalize(name), version);
+            let view = VulnerabilityView {
+                bom_ref: format!("{}-{}", spec.id, affected_component),
+                id: spec.id.clone(),
+                aliases: spec.aliases.clone(),
+                affected_component,
+                affected_component_name: name.clone(),
+                affected_version: version.clone(),
+                cvss_score: spec.cvss_score,
+                cvss_vector: None,
+                cvss_version: None,
+                severity: spec.severity,
+                fixed_version: spec.fixed_version.clone(),
+                description: None,
+                source_url: None,
+                introduced_by: vec![],
+                excluded_from_inventory: false,
+                in_baseline: false,
+            };
+            if spec.actionable {
+                actionable.push(view);
+            } else {
+                informational.push(view);
+            }
+        }
+
+        let affected_packages: HashSet<&str> = self
+            .vulnerabilities
+            .iter()
+            .filter_map(|spec| spec.package.as_ref().map(|(name, _)| name.as_str()))
+            .collect();
+
+        VulnerabilityReportView {
+            summary: VulnerabilitySummary {
+                total_count: actionable.len() + informational.len(),
+                affected_package_count: affected_packages.len(),
+            },
+            actionable,
+            informational,
+            ignored: Vec::new(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Handle for refining a component added with
+/// [`SbomReadModelFixtureBuilder::add_component`]
+pub struct ComponentFixture<'a> {
+    component: &'a mut ComponentView,
+}
+
+impl ComponentFixture<'_> {
+    /// Sets the license; the SPDX ID is resolved the same way as for real packages.
+    pub fn license(self, license: &str) -> Self {
+        self.component.license = Some(LicenseView {
+            spdx_id: spdx_license_map::get_spdx_id(license),
+            name: license.to_string(),
+            source: None,
+        });
+        self
+    }
+
+    /// Sets the package description.
+    pub fn description(self, description: &str) -> Self {
+        self.component.description = Some(description.to_string());
+        self
+    }
+
+    /// Marks the component as a direct dependency of the project.
+    pub fn direct(self) -> Self {
+        self.component.is_direct_dependency = true;
+        self
+    }
+}
+
+/// Handle for refining a vulnerability added with
+/// [`SbomReadModelFixtureBuilder::add_vulnerability`]
+pub struct VulnerabilityFixture<'a> {
+    spec: &'a mut VulnerabilitySpec,
+}
+
+impl VulnerabilityFixture<'_> {
+    /// Sets the severity (defaults to [`SeverityView::None`]).
+    pub fn severity(self, severity: SeverityView) -> Self {
+        self.spec.severity = severity;
+        self
+    }
+
+    /// Sets the CVSS base score.
+    pub fn cvss(self, score: f32) -> Self {
+        self.spec.cvss_score = Some(score);
+        self
+    }
+
+    /// Sets the first version that fixes the vulnerability.
+    pub fn fixed_in(self, version: &str) -> Self {
+        self.spec.fixed_version = Some(version.to_string());
+        self
+    }
+
+    /// Sets the other IDs the advisory is published under.
+    pub fn aliases(self, aliases: &[&str]) -> Self {
+        self.spec.aliases = aliases.iter().map(|alias| alias.to_string()).collect();
+        self
+    }
+
+    /// Reports the vulnerability as informational (below the threshold)
+    /// instead of actionable.
+    pub fn informational(self) -> Self {
+        self.spec.actionable = false;
+        self
+    }
+
+    /// Attaches the vulnerability to the given package version.
+    pub fn on(self, name: &str, version: &str) -> Self {
+        self.spec.package = Some((name.to_string(), version.to_string()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifiers_use_normalized_names() {
+        let mut fixture = SbomReadModelFixture::builder();
+        fixture.add_component("zope.interface", "6.2");
+        fixture
+            .add_vulnerability("CVE-2024-1")
+            .on("zope.interface", "6.2");
+
+        let model = fixture.build();
+
+        let component = &model.components[0];
+        assert_eq!(component.name, "zope.interface");
+        assert_eq!(component.bom_ref, "zope-interface-6.2");
+        assert_eq!(component.purl, "pkg:pypi/zope-interface@6.2");
+        let vulnerabilities = model.vulnerabilities.unwrap();
+        let affected: Vec<&str> = vulnerabilities
+            .actionable
+            .iter()
+            .chain(&vulnerabilities.informational)
+            .map(|v| v.affected_component.as_str())
+            .collect();
+        assert_eq!(affected, ["zope-interface-6.2"]);
+    }
+}
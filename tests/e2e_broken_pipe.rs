@@ -0,0 +1,43 @@
+/// End-to-end tests for a consumer that closes stdout before reading the whole SBOM
+use assert_cmd::assert::OutputAssertExt;
+use assert_cmd::cargo::cargo_bin;
+use predicates::prelude::*;
+use std::process::{Command, Stdio};
+
+/// Runs uv-sbom with the reading end of stdout closed before anything is written,
+/// the same situation as `uv-sbom | head -c 200` once `head` has exited
+fn run_with_closed_stdout(args: &[&str]) -> std::process::Output {
+    let mut child = Command::new(cargo_bin!("uv-sbom"))
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    drop(child.stdout.take());
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_closed_stdout_exits_successfully_for_json() {
+    run_with_closed_stdout(&["-p", "tests/fixtures/sample-project", "--no-check-cve"])
+        .assert()
+        .code(0)
+        .stderr(predicate::str::contains("panicked").not())
+        .stderr(predicate::str::contains("An error occurred").not())
+        .stderr(predicate::str::contains("Broken pipe").not());
+}
+
+#[test]
+fn test_closed_stdout_exits_successfully_for_markdown() {
+    run_with_closed_stdout(&[
+        "-p",
+        "tests/fixtures/sample-project",
+        "--no-check-cve",
+        "--format",
+        "markdown",
+    ])
+    .assert()
+    .code(0)
+    .stderr(predicate::str::contains("panicked").not())
+    .stderr(predicate::str::contains("An error occurred").not());
+}
@@ -284,6 +284,210 @@ format: json
     }
 }
 
+// ============================================================================
+// License Override via Config Tests
+// ============================================================================
+
+mod license_override_tests {
+    use super::*;
+
+    #[test]
+    fn test_license_override_via_config_file() {
+        let dir = TempDir::new().unwrap();
+        create_test_project(dir.path());
+
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            r#"
+license_overrides:
+  certifi:
+    license: "MPL-2.0"
+    reason: "vendored fork"
+  not-in-lockfile:
+    license: "MIT"
+"#,
+        );
+
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args(["-p", dir.path().to_str().unwrap(), "--offline"])
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let certifi = json["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|c| c["name"] == "certifi")
+            .unwrap();
+        assert_eq!(certifi["licenses"][0]["license"]["id"], "MPL-2.0");
+        assert_eq!(certifi["properties"][0]["name"], "uv-sbom:license-override");
+        assert_eq!(certifi["properties"][0]["value"], "true");
+        assert_eq!(certifi["properties"][1]["value"], "vendored fork");
+        assert_eq!(certifi["properties"][2]["name"], "uv-sbom:license-source");
+        assert_eq!(certifi["properties"][2]["value"], "config-override");
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("License override for package 'not-in-lockfile'"));
+    }
+}
+
+// ============================================================================
+// Threshold via Config Tests
+// ============================================================================
+
+mod threshold_config_tests {
+    use super::*;
+
+    /// A HIGH (CVSS 7.5) advisory for the certifi version in the test lockfile
+    const CERTIFI_ADVISORY: &str = r#"
+id: GHSA-xqr8-7jwr-rhp7
+summary: Test advisory for certifi
+affected:
+- package:
+    name: certifi
+    ecosystem: PyPI
+  ranges:
+  - type: ECOSYSTEM
+    events:
+    - introduced: "0"
+    - fixed: 2024.7.4
+severity:
+- type: CVSS_V3
+  score: CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N
+"#;
+
+    /// Writes a local advisory database so the CVE check runs offline.
+    fn write_advisory_db(dir: &std::path::Path) -> PathBuf {
+        let db = dir.join("advisory-db");
+        fs::create_dir_all(db.join("vulns/certifi")).unwrap();
+        fs::write(
+            db.join("vulns/certifi/GHSA-xqr8-7jwr-rhp7.yaml"),
+            CERTIFI_ADVISORY,
+        )
+        .unwrap();
+        db
+    }
+
+    fn run_offline(dir: &std::path::Path, extra_args: &[&str]) -> std::process::Output {
+        let db = write_advisory_db(dir);
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                dir.to_str().unwrap(),
+                "--offline",
+                "--advisory-db",
+                db.to_str().unwrap(),
+            ])
+            .args(extra_args)
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_severity_threshold_from_config_only() {
+        let dir = TempDir::new().unwrap();
+        create_test_project(dir.path());
+
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            "severity_threshold: critical\n",
+        );
+        let output = run_offline(dir.path(), &[]);
+        assert_eq!(
+            output.status.code(),
+            Some(0),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            "severity_threshold: high\n",
+        );
+        let output = run_offline(dir.path(), &[]);
+        assert_eq!(
+            output.status.code(),
+            Some(1),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn test_cvss_threshold_from_config_only() {
+        let dir = TempDir::new().unwrap();
+        create_test_project(dir.path());
+
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            "cvss_threshold: 8.0\n",
+        );
+        assert_eq!(run_offline(dir.path(), &[]).status.code(), Some(0));
+
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            "cvss_threshold: 7.0\n",
+        );
+        assert_eq!(run_offline(dir.path(), &[]).status.code(), Some(1));
+    }
+
+    #[test]
+    fn test_cli_threshold_overrides_config() {
+        let dir = TempDir::new().unwrap();
+        create_test_project(dir.path());
+
+        // The config threshold alone would pass the HIGH advisory
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            "severity_threshold: critical\n",
+        );
+        let output = run_offline(dir.path(), &["--severity-threshold", "high"]);
+        assert_eq!(
+            output.status.code(),
+            Some(1),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn test_conflicting_threshold_sources_error() {
+        let dir = TempDir::new().unwrap();
+        create_test_project(dir.path());
+
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            "severity_threshold: high\n",
+        );
+        let output = run_offline(dir.path(), &["--cvss-threshold", "7.0"]);
+
+        assert_eq!(output.status.code(), Some(3)); // ApplicationError
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("cannot be used together"), "{}", stderr);
+        assert!(stderr.contains("severity_threshold in the config file"));
+        assert!(stderr.contains("--cvss-threshold on the command line"));
+    }
+
+    #[test]
+    fn test_both_thresholds_in_config_error() {
+        let dir = TempDir::new().unwrap();
+        create_test_project(dir.path());
+
+        write_config(
+            &dir.path().join("uv-sbom.config.yml"),
+            "severity_threshold: high\ncvss_threshold: 7.0\n",
+        );
+        let output = run_offline(dir.path(), &[]);
+
+        assert_eq!(output.status.code(), Some(3));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("severity_threshold in the config file"));
+        assert!(stderr.contains("cvss_threshold in the config file"));
+    }
+}
+
 // ============================================================================
 // CVE Ignore via Config Tests
 // ============================================================================
@@ -548,7 +752,8 @@ ignore_cves:
 
         assert_eq!(output.status.code(), Some(3)); // ApplicationError
         let stderr = String::from_utf8_lossy(&output.stderr);
-        assert!(stderr.contains("Failed to read config file"));
+        assert!(stderr.contains("Invalid --config path"));
+        assert!(stderr.contains("File does not exist"));
     }
 
     #[test]
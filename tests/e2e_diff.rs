@@ -0,0 +1,123 @@
+/// End-to-end tests for the `diff` subcommand
+mod diff_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    const SAMPLE_PROJECT: &str = "tests/fixtures/sample-project";
+
+    /// Copies the sample project into `dir`, bumping requests to 2.32.3
+    fn upgraded_project(dir: &Path) {
+        fs::copy(
+            Path::new(SAMPLE_PROJECT).join("pyproject.toml"),
+            dir.join("pyproject.toml"),
+        )
+        .unwrap();
+        let lockfile = fs::read_to_string(Path::new(SAMPLE_PROJECT).join("uv.lock")).unwrap();
+        fs::write(
+            dir.join("uv.lock"),
+            lockfile.replace("version = \"2.31.0\"", "version = \"2.32.3\""),
+        )
+        .unwrap();
+    }
+
+    /// Two project directories are compared through their lockfiles
+    #[test]
+    fn test_diff_projects_markdown() {
+        let head = TempDir::new().unwrap();
+        upgraded_project(head.path());
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "diff",
+                "--base",
+                SAMPLE_PROJECT,
+                "--head",
+                head.path().to_str().unwrap(),
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("### Updated"))
+            .stdout(predicate::str::contains("| requests | 2.31.0 | 2.32.3 |"))
+            .stdout(predicate::str::contains("### Added").not());
+    }
+
+    /// A saved CycloneDX SBOM can be compared with a project directory
+    #[test]
+    fn test_diff_sbom_file_with_project_json() {
+        let dir = TempDir::new().unwrap();
+        let base_sbom = dir.path().join("base.json");
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                SAMPLE_PROJECT,
+                "--offline",
+                "-o",
+                base_sbom.to_str().unwrap(),
+            ])
+            .assert()
+            .code(0);
+
+        let head = TempDir::new().unwrap();
+        upgraded_project(head.path());
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "diff",
+                "--base",
+                base_sbom.to_str().unwrap(),
+                "--head",
+                head.path().to_str().unwrap(),
+                "--format",
+                "json",
+            ])
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        assert_eq!(
+            json["summary"],
+            serde_json::json!({"added": 0, "removed": 0, "updated": 1, "license_changed": 0})
+        );
+        assert_eq!(json["updated"][0]["name"], "requests");
+        assert_eq!(json["updated"][0]["new_version"], "2.32.3");
+    }
+
+    /// An unreadable SBOM file is an application error
+    #[test]
+    fn test_diff_invalid_sbom_file() {
+        let dir = TempDir::new().unwrap();
+        let base_sbom = dir.path().join("base.json");
+        fs::write(&base_sbom, "{}").unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "diff",
+                "--base",
+                base_sbom.to_str().unwrap(),
+                "--head",
+                SAMPLE_PROJECT,
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("Failed to parse SBOM file"));
+    }
+
+    /// Generation options cannot be combined with the subcommand
+    #[test]
+    fn test_diff_rejects_generation_options() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "--check-license",
+                "diff",
+                "--base",
+                SAMPLE_PROJECT,
+                "--head",
+                SAMPLE_PROJECT,
+            ])
+            .assert()
+            .code(2);
+    }
+}
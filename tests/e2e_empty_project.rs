@@ -0,0 +1,67 @@
+/// End-to-end tests for projects without third-party dependencies
+///
+/// `empty-project` locks only its own virtual root package and
+/// `empty-lock-project` has a lockfile without any packages.
+mod empty_project_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> String {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    /// Runs uv-sbom offline on `project`, returning (stdout, stderr)
+    fn run(project: &str, extra_args: &[&str]) -> (String, String) {
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args(["-p", &fixture(project), "--offline"])
+            .args(extra_args)
+            .output()
+            .unwrap();
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert_eq!(output.status.code(), Some(0), "stderr: {stderr}");
+        (String::from_utf8(output.stdout).unwrap(), stderr)
+    }
+
+    #[test]
+    fn test_root_only_lockfile_emits_empty_components() {
+        let (stdout, stderr) = run("empty-project", &[]);
+
+        let bom: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(bom["components"], serde_json::json!([]));
+        assert_eq!(bom["metadata"]["component"]["name"], "empty-project");
+        assert!(stderr.contains("No third-party packages"), "{stderr}");
+        assert!(!stderr.contains("Offline mode"), "{stderr}");
+    }
+
+    #[test]
+    fn test_root_only_lockfile_renders_no_dependencies_report() {
+        let (stdout, _) = run("empty-project", &["--format", "markdown"]);
+
+        assert!(stdout.contains("*No third-party dependencies*"), "{stdout}");
+        assert!(!stdout.contains("| [empty-project]"), "{stdout}");
+    }
+
+    #[test]
+    fn test_excluding_the_root_of_a_root_only_lockfile_is_not_an_error() {
+        let (stdout, _) = run("empty-project", &["--exclude", "empty-project"]);
+
+        let bom: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(bom["components"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_lockfile_without_packages_emits_empty_components() {
+        let (stdout, stderr) = run("empty-lock-project", &[]);
+
+        let bom: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_eq!(bom["components"], serde_json::json!([]));
+        assert_eq!(bom["metadata"]["component"]["name"], "empty-lock-project");
+        assert!(stderr.contains("No third-party packages"), "{stderr}");
+    }
+}
@@ -0,0 +1,125 @@
+/// Benchmark-style test generating an SBOM for a 2,000-package lockfile
+///
+/// Ignored by default; run it with `cargo test --test e2e_large_lockfile -- --ignored`.
+/// Time is paused, so the per-package rate limiting of the license lookups
+/// does not slow it down.
+use async_trait::async_trait;
+use std::fmt::Write as _;
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tempfile::TempDir;
+use uv_sbom::ports::outbound::{PyPiMetadata, VulnerabilityFetchOutcome, VulnerabilityRepository};
+use uv_sbom::prelude::*;
+
+const PACKAGE_COUNT: usize = 2_000;
+const DIRECT_COUNT: usize = 100;
+
+/// Writes a uv.lock whose project depends on the first `DIRECT_COUNT` packages;
+/// every package depends on the one `DIRECT_COUNT` places after it.
+fn write_project(dir: &TempDir) {
+    let direct: Vec<String> = (0..DIRECT_COUNT)
+        .map(|i| format!("\"pkg-{i:04}\""))
+        .collect();
+    fs::write(
+        dir.path().join("pyproject.toml"),
+        format!(
+            "[project]\nname = \"bench-project\"\nversion = \"0.1.0\"\ndependencies = [{}]\n",
+            direct.join(", ")
+        ),
+    )
+    .unwrap();
+
+    let mut lockfile = String::from(
+        "version = 1\nrequires-python = \">=3.12\"\n\n[[package]]\nname = \"bench-project\"\nversion = \"0.1.0\"\nsource = { virtual = \".\" }\ndependencies = [\n",
+    );
+    for i in 0..DIRECT_COUNT {
+        writeln!(lockfile, "    {{ name = \"pkg-{i:04}\" }},").unwrap();
+    }
+    lockfile.push_str("]\n");
+    for i in 0..PACKAGE_COUNT {
+        write!(
+            lockfile,
+            "\n[[package]]\nname = \"pkg-{i:04}\"\nversion = \"1.0.{i}\"\nsource = {{ registry = \"https://pypi.org/simple\" }}\n"
+        )
+        .unwrap();
+        if i + DIRECT_COUNT < PACKAGE_COUNT {
+            writeln!(
+                lockfile,
+                "dependencies = [\n    {{ name = \"pkg-{:04}\" }},\n]",
+                i + DIRECT_COUNT
+            )
+            .unwrap();
+        }
+    }
+    fs::write(dir.path().join("uv.lock"), lockfile).unwrap();
+}
+
+#[derive(Clone, Default)]
+struct CountingLicenseRepository {
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl LicenseRepository for CountingLicenseRepository {
+    async fn fetch_license_info(
+        &self,
+        _package_name: &str,
+        _version: &str,
+    ) -> Result<PyPiMetadata> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(PyPiMetadata {
+            license: Some("MIT".to_string()),
+            ..Default::default()
+        })
+    }
+}
+
+/// Records how many packages each vulnerability query receives
+#[derive(Clone, Default)]
+struct CountingVulnerabilityRepository {
+    queries: Arc<std::sync::Mutex<Vec<usize>>>,
+}
+
+#[async_trait]
+impl VulnerabilityRepository for CountingVulnerabilityRepository {
+    async fn fetch_vulnerabilities(
+        &self,
+        packages: &[Package],
+    ) -> Result<VulnerabilityFetchOutcome> {
+        self.queries.lock().unwrap().push(packages.len());
+        Ok(VulnerabilityFetchOutcome::default())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+#[ignore = "benchmark-style; run with --ignored"]
+async fn test_generate_sbom_for_2000_packages() {
+    let dir = TempDir::new().unwrap();
+    write_project(&dir);
+    let licenses = CountingLicenseRepository::default();
+    let vulnerabilities = CountingVulnerabilityRepository::default();
+
+    let use_case: GenerateSbomUseCase<_, _, _, _, _, ()> = GenerateSbomUseCase::new(
+        FileSystemReader::new(),
+        FileSystemReader::new(),
+        licenses.clone(),
+        SilentProgressReporter,
+        Some(vulnerabilities.clone()),
+        None,
+        uv_sbom::i18n::Locale::En,
+    );
+    let request = SbomRequest::builder()
+        .project_path(dir.path())
+        .include_dependency_info(true)
+        .check_cve(true)
+        .build()
+        .unwrap();
+    let response = use_case.execute(request).await.unwrap();
+
+    // The lockfile packages plus the project itself, whose license is not looked up
+    assert_eq!(response.enriched_packages.len(), PACKAGE_COUNT + 1);
+    assert_eq!(licenses.calls.load(Ordering::SeqCst), PACKAGE_COUNT);
+    // The vulnerability check borrows the package list in a single query
+    assert_eq!(*vulnerabilities.queries.lock().unwrap(), [PACKAGE_COUNT]);
+}
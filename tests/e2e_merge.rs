@@ -0,0 +1,121 @@
+/// End-to-end tests for the `merge` subcommand
+mod merge_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    const SAMPLE_PROJECT: &str = "tests/fixtures/sample-project";
+
+    /// Copies the sample project into `dir` as project `worker`, bumping requests to 2.32.3
+    fn worker_project(dir: &Path) {
+        for file in ["pyproject.toml", "uv.lock"] {
+            let content = fs::read_to_string(Path::new(SAMPLE_PROJECT).join(file)).unwrap();
+            fs::write(
+                dir.join(file),
+                content
+                    .replace("sample-project", "worker")
+                    .replace("version = \"2.31.0\"", "version = \"2.32.3\""),
+            )
+            .unwrap();
+        }
+    }
+
+    /// Each project becomes an application component that depends on its own packages
+    #[test]
+    fn test_merge_projects_json() {
+        let worker = TempDir::new().unwrap();
+        worker_project(worker.path());
+
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "merge",
+                SAMPLE_PROJECT,
+                worker.path().to_str().unwrap(),
+                "--offline",
+            ])
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let components = json["components"].as_array().unwrap();
+        let applications: Vec<&str> = components
+            .iter()
+            .filter(|c| c["type"] == "application")
+            .map(|c| c["bom-ref"].as_str().unwrap())
+            .collect();
+        assert_eq!(applications, ["sample-project-1.0.0", "worker-1.0.0"]);
+        // Shared packages are listed once; the two requests versions are kept apart
+        let libraries: Vec<&str> = components
+            .iter()
+            .filter(|c| c["type"] == "library")
+            .map(|c| c["bom-ref"].as_str().unwrap())
+            .collect();
+        assert_eq!(libraries.len(), 6);
+        assert_eq!(
+            libraries.iter().filter(|r| r.starts_with("idna-")).count(),
+            1
+        );
+        assert_eq!(json["dependencies"][0]["ref"], "sample-project-1.0.0");
+        assert_eq!(
+            json["dependencies"][0]["dependsOn"],
+            serde_json::json!(["requests-2.31.0"])
+        );
+        assert_eq!(json["dependencies"][1]["ref"], "worker-1.0.0");
+        assert_eq!(
+            json["dependencies"][1]["dependsOn"],
+            serde_json::json!(["requests-2.32.3"])
+        );
+        assert!(json["metadata"]["component"].is_null());
+    }
+
+    /// The Markdown report lists the source projects
+    #[test]
+    fn test_merge_projects_markdown() {
+        let worker = TempDir::new().unwrap();
+        worker_project(worker.path());
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "merge",
+                SAMPLE_PROJECT,
+                worker.path().to_str().unwrap(),
+                "--offline",
+                "--format",
+                "markdown",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("## Source Projects"))
+            .stdout(predicate::str::contains("| sample-project | 1.0.0 | 1 |"))
+            .stdout(predicate::str::contains("| worker | 1.0.0 | 1 |"));
+    }
+
+    /// Merging needs at least two projects
+    #[test]
+    fn test_merge_requires_two_projects() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["merge", SAMPLE_PROJECT])
+            .assert()
+            .code(2);
+    }
+
+    /// A project without a lockfile fails the whole merge
+    #[test]
+    fn test_merge_missing_lockfile() {
+        let empty = TempDir::new().unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "merge",
+                SAMPLE_PROJECT,
+                empty.path().to_str().unwrap(),
+                "--offline",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("uv.lock file not found"));
+    }
+}
@@ -0,0 +1,65 @@
+/// End-to-end tests for progress output when stderr is not a terminal
+///
+/// assert_cmd captures stderr through a pipe, so these runs see the same
+/// stderr as a CI job or a redirect to a log file.
+mod progress_output_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn fixtures_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+    }
+
+    /// Runs an offline CVE check on the vulnerable_project fixture
+    fn run(envs: &[(&str, &str)], extra_args: &[&str]) -> String {
+        let dir = TempDir::new().unwrap();
+        let db = dir.path().join("advisory-db");
+        fs::create_dir_all(&db).unwrap();
+
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                fixtures_path().join("vulnerable_project").to_str().unwrap(),
+                "--offline",
+                "--advisory-db",
+                db.to_str().unwrap(),
+            ])
+            .args(extra_args)
+            .env_remove("NO_COLOR")
+            .env_remove("UV_SBOM_NO_PROGRESS")
+            .envs(envs.iter().copied())
+            .output()
+            .unwrap();
+
+        assert_eq!(output.status.code(), Some(0));
+        String::from_utf8(output.stderr).unwrap()
+    }
+
+    #[test]
+    fn test_piped_stderr_has_no_escape_sequences() {
+        let stderr = run(&[], &[]);
+
+        assert!(stderr.contains("uv-sbom v"));
+        assert!(!stderr.contains("\x1b["), "stderr: {stderr:?}");
+    }
+
+    #[test]
+    fn test_no_color_strips_emoji() {
+        let stderr = run(&[("NO_COLOR", "1")], &["--progress", "never"]);
+
+        assert!(stderr.contains("uv-sbom v"));
+        assert!(!stderr.contains("\x1b["), "stderr: {stderr:?}");
+        assert!(!stderr.contains('🚀'), "stderr: {stderr:?}");
+        assert!(!stderr.contains('🔍'), "stderr: {stderr:?}");
+        assert!(!stderr.contains('📝'), "stderr: {stderr:?}");
+    }
+
+    #[test]
+    fn test_progress_always_draws_bars_when_piped() {
+        let stderr = run(&[], &["--progress", "always"]);
+
+        assert!(stderr.contains("uv-sbom v"));
+    }
+}
@@ -0,0 +1,95 @@
+/// End-to-end tests for running on a read-only filesystem
+#[cfg(unix)]
+mod read_only_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    /// Copies the sample project into `dir` and makes it read-only
+    fn read_only_project(dir: &Path) {
+        for file in ["pyproject.toml", "uv.lock"] {
+            fs::copy(
+                Path::new("tests/fixtures/sample-project").join(file),
+                dir.join(file),
+            )
+            .unwrap();
+        }
+        set_read_only(dir, true);
+    }
+
+    fn set_read_only(dir: &Path, read_only: bool) {
+        let mode = if read_only { 0o555 } else { 0o755 };
+        fs::set_permissions(dir, fs::Permissions::from_mode(mode)).unwrap();
+    }
+
+    fn entries(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Generation succeeds with a read-only HOME and project directory, and an
+    /// unusable data directory only produces a warning
+    #[test]
+    fn test_generation_succeeds_on_read_only_filesystem() {
+        let project = TempDir::new().unwrap();
+        let home = TempDir::new().unwrap();
+        let scratch = TempDir::new().unwrap();
+        read_only_project(project.path());
+        set_read_only(home.path(), true);
+
+        // A path below a regular file can never be created, even when running as root
+        let blocker = scratch.path().join("not-a-dir");
+        fs::write(&blocker, "").unwrap();
+
+        let assert = cargo_bin_cmd!("uv-sbom")
+            .args(["-p", project.path().to_str().unwrap(), "--no-check-cve"])
+            .env("HOME", home.path())
+            .env("UV_SBOM_DATA_DIR", blocker.join("data"))
+            .assert();
+
+        set_read_only(project.path(), false);
+        set_read_only(home.path(), false);
+
+        assert
+            .code(0)
+            .stdout(predicate::str::contains("bomFormat"))
+            .stderr(predicate::str::contains("is not writable"));
+
+        assert_eq!(entries(project.path()), vec!["pyproject.toml", "uv.lock"]);
+        assert!(entries(home.path()).is_empty());
+    }
+
+    /// A writable data directory is accepted without warnings
+    #[test]
+    fn test_writable_data_dir_is_used_without_warning() {
+        let project = TempDir::new().unwrap();
+        let data = TempDir::new().unwrap();
+        read_only_project(project.path());
+
+        let assert = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                project.path().to_str().unwrap(),
+                "--no-check-cve",
+                "--data-dir",
+                data.path().join("uv-sbom").to_str().unwrap(),
+            ])
+            .assert();
+
+        set_read_only(project.path(), false);
+
+        assert
+            .code(0)
+            .stderr(predicate::str::contains("is not writable").not());
+
+        assert!(data.path().join("uv-sbom").is_dir());
+        assert_eq!(entries(project.path()), vec!["pyproject.toml", "uv.lock"]);
+    }
+}
@@ -0,0 +1,85 @@
+/// End-to-end tests for the `scan` subcommand
+///
+/// These tests stay offline: OSV is only queried for PyPI components, and
+/// every SBOM used here has none.
+mod scan_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const NPM_ONLY_SBOM: &str = r#"{
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.6",
+        "components": [
+            {"name": "left-pad", "version": "1.3.0", "purl": "pkg:npm/left-pad@1.3.0"}
+        ]
+    }"#;
+
+    /// Non-PyPI components are skipped with a warning instead of failing the scan
+    #[test]
+    fn test_scan_skips_non_pypi_components() {
+        let dir = TempDir::new().unwrap();
+        let sbom = dir.path().join("sbom.json");
+        fs::write(&sbom, NPM_ONLY_SBOM).unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args(["scan", "--sbom", sbom.to_str().unwrap()])
+            .assert()
+            .code(0)
+            .stderr(predicate::str::contains(
+                "Skipping left-pad 1.3.0: pkg:npm/left-pad@1.3.0 is not a PyPI package",
+            ))
+            .stdout(predicate::str::contains("## Vulnerability Report"))
+            .stdout(predicate::str::contains("## Components").not());
+    }
+
+    /// The JSON report is the one `--cve-report` writes
+    #[test]
+    fn test_scan_json_report() {
+        let dir = TempDir::new().unwrap();
+        let sbom = dir.path().join("sbom.json");
+        let report = dir.path().join("cve-report.json");
+        fs::write(&sbom, NPM_ONLY_SBOM).unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "scan",
+                "--sbom",
+                sbom.to_str().unwrap(),
+                "-f",
+                "json",
+                "-o",
+                report.to_str().unwrap(),
+            ])
+            .assert()
+            .code(0);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&report).unwrap()).unwrap();
+        assert_eq!(json["schema_version"], "1");
+        assert_eq!(json["summary"]["total"], 0);
+    }
+
+    #[test]
+    fn test_scan_invalid_sbom_file() {
+        let dir = TempDir::new().unwrap();
+        let sbom = dir.path().join("sbom.json");
+        fs::write(&sbom, "{}").unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args(["scan", "--sbom", sbom.to_str().unwrap()])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("Failed to parse SBOM file"));
+    }
+
+    #[test]
+    fn test_scan_rejects_html() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["scan", "--sbom", "sbom.json", "-f", "html"])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("--format json or markdown"));
+    }
+}
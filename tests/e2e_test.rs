@@ -15,6 +15,93 @@ mod exit_code_tests {
             .code(0);
     }
 
+    /// Exit code 0: --offline generates the SBOM without network access
+    #[test]
+    fn test_exit_code_offline() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", "tests/fixtures/sample-project", "--offline"])
+            .assert()
+            .code(0)
+            .stdout(predicates::str::contains("\"name\": \"requests\""))
+            .stderr(predicates::str::contains("Offline mode"));
+    }
+
+    /// Exit code 3: --offline cannot be combined with an explicit CVE check
+    #[test]
+    fn test_exit_code_offline_with_check_cve() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--offline",
+                "--check-cve",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicates::str::contains(
+                "offline mode cannot be combined with CVE checking",
+            ));
+    }
+
+    /// Exit code 0: --validate accepts the generated CycloneDX output
+    #[test]
+    fn test_exit_code_validate() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--offline",
+                "--validate",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicates::str::contains("\"bomFormat\": \"CycloneDX\""))
+            .stderr(predicates::str::contains(
+                "CycloneDX output passed validation",
+            ));
+    }
+
+    /// --validate has nothing to check in Markdown output
+    #[test]
+    fn test_validate_with_markdown_warns() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "-f",
+                "markdown",
+                "--offline",
+                "--validate",
+            ])
+            .assert()
+            .code(0)
+            .stderr(predicates::str::contains(
+                "--validate has no effect with Markdown format",
+            ));
+    }
+
+    /// --format html writes a self-contained report with the dependency sections
+    #[test]
+    fn test_html_format_offline() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "-f",
+                "html",
+                "--offline",
+                "--validate",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicates::str::starts_with("<!DOCTYPE html>"))
+            .stdout(predicates::str::contains("<h2>Direct Dependencies</h2>"))
+            .stdout(predicates::str::contains("<td>requests</td>"))
+            .stderr(predicates::str::contains(
+                "--validate has no effect with HTML format",
+            ));
+    }
+
     /// Exit code 0: --help should return success
     #[test]
     fn test_exit_code_help() {
@@ -62,6 +149,50 @@ mod exit_code_tests {
             .assert()
             .code(3);
     }
+
+    /// Copies the sample project and declares a dependency its lockfile does not have
+    fn stale_lockfile_project() -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::copy(
+            "tests/fixtures/sample-project/uv.lock",
+            dir.path().join("uv.lock"),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("pyproject.toml"),
+            "[project]\nname = \"sample-project\"\nversion = \"1.0.0\"\n\
+             dependencies = [\"requests>=2.31.0\", \"httpx>=0.27\"]\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    /// Exit code 0: a stale lockfile is only a warning by default
+    #[test]
+    fn test_exit_code_stale_lockfile_warns() {
+        let dir = stale_lockfile_project();
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", dir.path().to_str().unwrap(), "--offline"])
+            .assert()
+            .code(0)
+            .stderr(predicates::str::contains("'httpx'"));
+    }
+
+    /// Exit code 5: --strict-lock fails on a stale lockfile
+    #[test]
+    fn test_exit_code_stale_lockfile_strict() {
+        let dir = stale_lockfile_project();
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                dir.path().to_str().unwrap(),
+                "--offline",
+                "--strict-lock",
+            ])
+            .assert()
+            .code(5)
+            .stderr(predicates::str::contains("uv lock"));
+    }
 }
 
 #[tokio::test]
@@ -402,6 +533,10 @@ async fn test_e2e_exclude_all_packages_error() {
     let error = result.unwrap_err();
     assert!(error.to_string().contains("All"));
     assert!(error.to_string().contains("excluded"));
+    assert!(matches!(
+        error,
+        UvSbomError::AllPackagesExcluded { count: 6 }
+    ));
 }
 
 /// Test for issue #206: Excluding root project preserves dependency classification
@@ -522,8 +657,247 @@ async fn test_e2e_exclude_root_project_markdown_output() {
     assert!(markdown.contains("urllib3"));
     assert!(markdown.contains("certifi"));
 
-    // Root project should NOT be in the output
-    assert!(!markdown.contains("sample-project"));
+    // Root project should NOT be in the output; the generation parameters at
+    // the bottom record the project path, which contains its name
+    let report = markdown.split("## Generation Parameters").next().unwrap();
+    assert!(!report.contains("sample-project"));
+}
+
+// Lockfile format detection and `--lockfile` tests
+mod lockfile_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+
+    /// A project with only pylock.toml is read without any flag
+    #[test]
+    fn test_pylock_is_auto_detected() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", "tests/fixtures/pylock-project", "--offline"])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("\"name\": \"requests\""))
+            .stdout(predicate::str::contains("\"name\": \"idna\""))
+            .stdout(predicate::str::contains(
+                "942c5a758f98d790eaed1a29cb6eefc7ffb0d1cf7af05c3d2791656dbd6ad1e1",
+            ));
+    }
+
+    /// --lockfile picks the parser from the file name
+    #[test]
+    fn test_explicit_pylock_path() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--lockfile",
+                "tests/fixtures/pylock-project/pylock.toml",
+                "--offline",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("\"name\": \"certifi\""))
+            .stdout(predicate::str::contains("urllib3").not());
+    }
+
+    /// Exit code 3: --lockfile with a name that matches no known format
+    #[test]
+    fn test_unrecognized_lockfile_name() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--lockfile",
+                "tests/fixtures/sample-project/pyproject.toml",
+                "--offline",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("Unrecognized lockfile name"));
+    }
+
+    /// A pinned requirements.txt is read as a flat package list; option lines
+    /// are skipped with a warning and the graph sections are left out
+    #[test]
+    fn test_requirements_txt_lockfile() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let requirements = temp_dir.path().join("requirements.txt");
+        std::fs::write(
+            &requirements,
+            "-e .\ncertifi==2024.2.2 \\\n    --hash=sha256:dc383c07b76109f368f6106eee2b593b04a011ea4d55f652c6ca24a754d1cdd1\nidna==3.6\n",
+        )
+        .unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--lockfile",
+                requirements.to_str().unwrap(),
+                "--format",
+                "markdown",
+                "--offline",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("[certifi]"))
+            .stdout(predicate::str::contains("[idna]"))
+            .stderr(predicate::str::contains("-e ."))
+            .stderr(predicate::str::contains("dependency graph"));
+    }
+
+    /// Exit code 3: every requirements.txt line must pin a version
+    #[test]
+    fn test_requirements_txt_unpinned_requirement() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let requirements = temp_dir.path().join("requirements.txt");
+        std::fs::write(&requirements, "certifi==2024.2.2\nidna>=3\n").unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--lockfile",
+                requirements.to_str().unwrap(),
+                "--offline",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("Unpinned requirement on line 2"))
+            .stderr(predicate::str::contains(
+                "uv export --format requirements-txt",
+            ));
+    }
+
+    /// --lockfile and --pyproject read files outside the project directory
+    #[test]
+    fn test_lockfile_and_pyproject_overrides() {
+        let project_dir = tempfile::TempDir::new().unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                project_dir.path().to_str().unwrap(),
+                "--lockfile",
+                "tests/fixtures/sample-project/uv.lock",
+                "--pyproject",
+                "tests/fixtures/sample-project/pyproject.toml",
+                "--offline",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("\"name\": \"urllib3\""))
+            .stdout(predicate::str::contains(
+                "\"purl\": \"pkg:pypi/sample-project@",
+            ));
+    }
+
+    /// --lockfile - reads uv.lock from stdin; the project directory need not exist
+    #[test]
+    fn test_lockfile_from_stdin() {
+        let lockfile = std::fs::read_to_string("tests/fixtures/sample-project/uv.lock").unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/no-such-project",
+                "--lockfile",
+                "-",
+                "--project-name",
+                "sample-project",
+                "--format",
+                "markdown",
+                "--offline",
+            ])
+            .write_stdin(lockfile)
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("| Direct dependencies | 1 |"))
+            .stdout(predicate::str::contains("[urllib3]"));
+    }
+
+    /// --roots analyzes the graph below the given packages, without a project name
+    #[test]
+    fn test_roots_list_unreferenced_packages() {
+        let lockfile = std::fs::read_to_string("tests/fixtures/sample-project/uv.lock").unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/no-such-project",
+                "--lockfile",
+                "-",
+                "--roots",
+                "idna,url*",
+                "--format",
+                "markdown",
+                "--offline",
+            ])
+            .write_stdin(lockfile)
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("| Direct dependencies | 2 |"))
+            .stdout(predicate::str::contains("## Unreferenced Packages"))
+            .stdout(predicate::str::contains("[charset-normalizer]"));
+    }
+
+    /// Exit code 3: every --roots pattern must match a package
+    #[test]
+    fn test_roots_matching_no_package_fail() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--roots",
+                "boto3",
+                "--format",
+                "markdown",
+                "--offline",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains(
+                "--roots matched no package in the lockfile: boto3",
+            ));
+    }
+
+    /// Exit code 3: dependency analysis of a stdin lockfile needs the project name
+    #[test]
+    fn test_lockfile_from_stdin_requires_project_name_for_markdown() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["--lockfile", "-", "--format", "markdown", "--offline"])
+            .write_stdin("")
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains(
+                "--lockfile - needs --project-name (or --pyproject) for markdown output",
+            ));
+    }
+
+    /// Exit code 3: a missing --lockfile is reported with the path resolved
+    /// against the current directory, not the project directory
+    #[test]
+    fn test_missing_lockfile_override_reports_resolved_path() {
+        let expected = std::env::current_dir()
+            .unwrap()
+            .join("services")
+            .join("api")
+            .join("uv.lock");
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--lockfile",
+                "services/api/uv.lock",
+                "--offline",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains(format!(
+                "{} does not exist",
+                expected.display()
+            )));
+    }
 }
 
 // CLI `--lang` option tests
@@ -616,10 +990,606 @@ mod lang_option_tests {
     }
 }
 
+// End-of-run summary table tests
+mod summary_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+
+    /// The summary table follows the output on stderr, without colour codes
+    /// when stderr is not a terminal
+    #[test]
+    fn test_summary_table_after_output() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "-f",
+                "markdown",
+                "--offline",
+            ])
+            .assert()
+            .code(0)
+            .stderr(predicate::str::contains("Summary\n"))
+            .stderr(predicate::str::contains(
+                "Dependencies     1 direct, 4 transitive\n",
+            ))
+            .stderr(predicate::str::contains("Output           stdout\n"))
+            .stderr(predicate::str::contains("\u{1b}[").not());
+    }
+
+    /// --no-summary leaves the table out
+    #[test]
+    fn test_no_summary() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--offline",
+                "--no-summary",
+            ])
+            .assert()
+            .code(0)
+            .stderr(predicate::str::contains("Elapsed").not());
+    }
+
+    /// An offline run sends no requests and leaves out the HTTP request count
+    #[test]
+    fn test_offline_omits_http_request_count() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", "tests/fixtures/sample-project", "--offline"])
+            .assert()
+            .code(0)
+            .stderr(predicate::str::contains("HTTP requests sent").not());
+    }
+}
+
+// CLI `-q/--quiet` tests
+mod quiet_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use tempfile::TempDir;
+
+    /// Written files are announced normally and not at all in quiet mode
+    #[test]
+    fn test_quiet_suppresses_output_complete() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("sbom.json");
+        let run = |quiet: bool| {
+            let mut cmd = cargo_bin_cmd!("uv-sbom");
+            cmd.args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--offline",
+                "-o",
+                output.to_str().unwrap(),
+            ]);
+            if quiet {
+                cmd.arg("-q");
+            }
+            cmd.assert().code(0)
+        };
+
+        run(false).stderr(predicate::str::contains("Output complete"));
+        run(true).stderr(predicate::str::contains("Output complete").not());
+        assert!(output.is_file());
+    }
+}
+
+// CLI `--stats` tests
+mod stats_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+
+    /// Markdown output gets a Generation Summary section; offline skips the license fetch
+    #[test]
+    fn test_stats_markdown_section_offline() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "-f",
+                "markdown",
+                "--offline",
+                "--stats",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("## Generation Summary"))
+            .stdout(predicate::str::contains("| License fetch | skipped |"))
+            .stdout(predicate::str::contains(
+                "| Vulnerability check | skipped |",
+            ))
+            .stderr(predicate::str::contains("Generation summary:"));
+    }
+
+    /// Dry-run reports only the parsing phase on stderr
+    #[test]
+    fn test_stats_dry_run_reports_skipped_phases() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--dry-run",
+                "--stats",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::is_empty())
+            .stderr(predicate::str::contains("Generation summary:"))
+            .stderr(predicate::str::contains("  Licenses: skipped"))
+            .stderr(predicate::str::contains("  License fetch: skipped"));
+    }
+
+    /// An ignore entry that can never match makes dry-run exit with the warnings code
+    #[test]
+    fn test_dry_run_warnings_exit_code() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--dry-run",
+                "--ignore-cve",
+                "cve-2024-1234",
+            ])
+            .assert()
+            .code(6)
+            .stdout(predicate::str::is_empty())
+            .stderr(predicate::str::contains("'cve-2024-1234'"));
+    }
+}
+
+// CLI `--progress-format` tests
+mod progress_format_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+
+    /// Every stderr line is a JSON event and the stream ends with `done`
+    #[test]
+    fn test_json_progress_stream_offline() {
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "-f",
+                "markdown",
+                "--offline",
+                "--progress-format",
+                "json",
+            ])
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        let events: Vec<serde_json::Value> = stderr
+            .lines()
+            .map(|line| serde_json::from_str(line).expect(line))
+            .collect();
+        assert!(events.iter().all(|event| event["event"].is_string()));
+        assert_eq!(events.last().unwrap()["event"], "done");
+        assert_eq!(events.last().unwrap()["packages"], 6);
+    }
+
+    /// Writing to a file with a digest adds no text lines, and `done` stays last
+    #[test]
+    fn test_json_progress_stream_with_output_file_and_digest() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("sbom.json");
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--offline",
+                "--emit-digest",
+                "--progress-format",
+                "json",
+                "-o",
+                output_path.to_str().unwrap(),
+            ])
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        let events: Vec<serde_json::Value> = stderr
+            .lines()
+            .map(|line| serde_json::from_str(line).expect(line))
+            .collect();
+        assert!(events.iter().any(|event| event["message"]
+            .as_str()
+            .is_some_and(|m| m.contains("sbom.json"))));
+        assert_eq!(events.last().unwrap()["event"], "done");
+        assert!(output_path.is_file());
+    }
+}
+
+// CLI `--tree-depth` tests
+mod tree_depth_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+
+    fn markdown_with_tree_depth(depth: &str) -> assert_cmd::assert::Assert {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/deep-tree-project",
+                "-f",
+                "markdown",
+                "--offline",
+                "--tree-depth",
+                depth,
+            ])
+            .assert()
+            .code(0)
+    }
+
+    /// Every level is rendered with 0, and the sphinx cycle is cut short
+    #[test]
+    fn test_tree_depth_unlimited() {
+        markdown_with_tree_depth("0")
+            .stdout(predicate::str::contains(
+                "  - [kombu](https://pypi.org/project/kombu/) 5.3.4\n    \
+                 - [amqp](https://pypi.org/project/amqp/) 5.2.0\n      \
+                 - [vine](https://pypi.org/project/vine/) 5.1.0\n",
+            ))
+            .stdout(predicate::str::contains(
+                "    - [sphinx](https://pypi.org/project/sphinx/) 7.2.6 (circular)\n",
+            ))
+            .stdout(predicate::str::contains("### Dependencies for").not());
+    }
+
+    /// Levels below the requested depth are left out
+    #[test]
+    fn test_tree_depth_limited() {
+        markdown_with_tree_depth("2")
+            .stdout(predicate::str::contains(
+                "    - [amqp](https://pypi.org/project/amqp/) 5.2.0\n    \
+                 - [vine](https://pypi.org/project/vine/) 5.1.0\n",
+            ))
+            .stdout(predicate::str::contains("      - ").not());
+    }
+}
+
+// CLI `--reproducible` tests
+mod reproducible_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+
+    fn generate(format: &str) -> Vec<u8> {
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "-f",
+                format,
+                "--offline",
+                "--reproducible",
+            ])
+            .env("SOURCE_DATE_EPOCH", "1700000000")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        output.stdout
+    }
+
+    /// Two runs on the same lockfile produce byte-identical output
+    #[test]
+    fn test_reproducible_output_is_byte_identical() {
+        let first = generate("json");
+        assert_eq!(first, generate("json"));
+        assert_eq!(generate("markdown"), generate("markdown"));
+
+        let json: serde_json::Value = serde_json::from_slice(&first).unwrap();
+        assert_eq!(json["metadata"]["timestamp"], "2023-11-14T22:13:20+00:00");
+    }
+
+    /// A malformed SOURCE_DATE_EPOCH is rejected rather than silently ignored
+    #[test]
+    fn test_reproducible_rejects_invalid_source_date_epoch() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/sample-project",
+                "--offline",
+                "--reproducible",
+            ])
+            .env("SOURCE_DATE_EPOCH", "yesterday")
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains(
+                "SOURCE_DATE_EPOCH must be a number",
+            ));
+    }
+}
+
+// CLI `--output-dir` tests
+mod output_dir_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const PROJECT: &str = "tests/fixtures/sample-project";
+
+    fn markdown_on_stdout() -> Vec<u8> {
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                PROJECT,
+                "-f",
+                "markdown",
+                "--offline",
+                "--reproducible",
+            ])
+            .env("SOURCE_DATE_EPOCH", "1700000000")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        output.stdout
+    }
+
+    /// One run writes every format, and the same Markdown as a single-format run
+    #[test]
+    fn test_output_dir_writes_each_format() {
+        let temp_dir = TempDir::new().unwrap();
+        let out = temp_dir.path().join("artifacts");
+
+        let output = cargo_bin_cmd!("uv-sbom")
+            .args(["-p", PROJECT, "-f", "json", "-f", "markdown", "--offline"])
+            .args([
+                "--reproducible",
+                "--stdout-format",
+                "markdown",
+                "--output-dir",
+            ])
+            .arg(&out)
+            .env("SOURCE_DATE_EPOCH", "1700000000")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let markdown = markdown_on_stdout();
+        assert_eq!(fs::read(out.join("sbom.md")).unwrap(), markdown);
+        assert_eq!(output.stdout, markdown);
+        let json: serde_json::Value =
+            serde_json::from_slice(&fs::read(out.join("sbom.json")).unwrap()).unwrap();
+        assert_eq!(json["bomFormat"], "CycloneDX");
+        // --offline skips the CVE check, so there is no CVE report
+        assert!(!out.join("cve-report.json").exists());
+    }
+
+    /// Without --stdout-format nothing is printed
+    #[test]
+    fn test_output_dir_keeps_stdout_empty() {
+        let temp_dir = TempDir::new().unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", PROJECT, "-f", "html", "--offline", "--output-dir"])
+            .arg(temp_dir.path().join("out"))
+            .assert()
+            .code(0)
+            .stdout(predicate::str::is_empty());
+
+        assert!(temp_dir.path().join("out/sbom.html").is_file());
+    }
+
+    #[test]
+    fn test_several_formats_require_output_dir() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", PROJECT, "-f", "json", "-f", "markdown", "--offline"])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("requires --output-dir"));
+    }
+
+    #[test]
+    fn test_stdout_format_must_be_written() {
+        let temp_dir = TempDir::new().unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", PROJECT, "-f", "json", "--offline"])
+            .args(["--stdout-format", "markdown", "--output-dir"])
+            .arg(temp_dir.path().join("out"))
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains(
+                "--stdout-format markdown must be one of the formats",
+            ));
+    }
+}
+
+// CLI `--path` resolution tests (tilde expansion, symlinks, error classification)
+#[cfg(unix)]
+mod path_resolution_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn copy_sample_project(dest: &std::path::Path) {
+        fs::create_dir_all(dest).unwrap();
+        for file in ["pyproject.toml", "uv.lock"] {
+            fs::copy(
+                std::path::Path::new("tests/fixtures/sample-project").join(file),
+                dest.join(file),
+            )
+            .unwrap();
+        }
+    }
+
+    /// A symlinked project directory is resolved and accepted
+    #[test]
+    fn test_symlinked_project_directory_is_resolved() {
+        let temp_dir = TempDir::new().unwrap();
+        let real = temp_dir.path().join("real-project");
+        copy_sample_project(&real);
+        let link = temp_dir.path().join("linked-project");
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", link.to_str().unwrap(), "--dry-run", "--no-check-cve"])
+            .assert()
+            .code(0);
+    }
+
+    /// A dangling symlink reports the missing target instead of a bare "does not exist"
+    #[test]
+    fn test_broken_symlink_reports_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let target = temp_dir.path().join("moved-project");
+        let link = temp_dir.path().join("linked-project");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", link.to_str().unwrap(), "--dry-run"])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("Broken symbolic link"))
+            .stderr(predicate::str::contains("moved-project"));
+    }
+
+    /// A leading `~` is expanded using HOME
+    #[test]
+    fn test_tilde_path_is_expanded() {
+        let home = TempDir::new().unwrap();
+        copy_sample_project(&home.path().join("app"));
+
+        cargo_bin_cmd!("uv-sbom")
+            .env("HOME", home.path())
+            .args(["--path=~/app", "--dry-run", "--no-check-cve"])
+            .assert()
+            .code(0);
+    }
+
+    /// A missing --config file names the option and the path as typed
+    #[test]
+    fn test_missing_config_file_reports_typed_path_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        copy_sample_project(&project);
+
+        cargo_bin_cmd!("uv-sbom")
+            .env("HOME", temp_dir.path())
+            .args([
+                "-p",
+                project.to_str().unwrap(),
+                "--config=~/missing.config.yml",
+                "--dry-run",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains(
+                "Invalid --config path: ~/missing.config.yml",
+            ))
+            .stderr(predicate::str::contains("File does not exist"));
+    }
+
+    /// An --output pointing at a directory is rejected before generation starts
+    #[test]
+    fn test_output_directory_reports_typed_path_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        copy_sample_project(&project);
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                project.to_str().unwrap(),
+                "-o",
+                temp_dir.path().to_str().unwrap(),
+                "--no-check-cve",
+                "--offline",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("Invalid --output path"))
+            .stderr(predicate::str::contains("Is a directory"));
+    }
+
+    /// --markdown-split writes into an existing --output directory
+    #[test]
+    fn test_markdown_split_accepts_existing_output_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        copy_sample_project(&project);
+        let docs = temp_dir.path().join("docs");
+        fs::create_dir(&docs).unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                project.to_str().unwrap(),
+                "-f",
+                "markdown",
+                "--markdown-split",
+                "-o",
+                docs.to_str().unwrap(),
+                "--no-check-cve",
+                "--offline",
+            ])
+            .assert()
+            .code(0);
+        assert!(docs.join("index.md").is_file());
+    }
+
+    /// --markdown-split still rejects an --output that is a file
+    #[test]
+    fn test_markdown_split_rejects_file_as_output_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let project = temp_dir.path().join("project");
+        copy_sample_project(&project);
+        let file = temp_dir.path().join("sbom.md");
+        fs::write(&file, "").unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                project.to_str().unwrap(),
+                "-f",
+                "markdown",
+                "--markdown-split",
+                "-o",
+                file.to_str().unwrap(),
+                "--no-check-cve",
+                "--offline",
+            ])
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("Invalid --output path"))
+            .stderr(predicate::str::contains("Not a directory"));
+    }
+}
+
+#[tokio::test]
+async fn test_e2e_shared_transitive_dependency_counted_once() {
+    let use_case: GenerateSbomUseCase<_, _, _, _, (), ()> = GenerateSbomUseCase::new(
+        FileSystemReader::new(),
+        FileSystemReader::new(),
+        create_test_license_repository(),
+        StderrProgressReporter::new(uv_sbom::i18n::Locale::En),
+        None,
+        None,
+        uv_sbom::i18n::Locale::En,
+    );
+
+    let request = SbomRequest::builder()
+        .project_path(PathBuf::from("tests/fixtures/deep-tree-project"))
+        .include_dependency_info(true)
+        .offline(true)
+        .build()
+        .unwrap();
+    let response = use_case.execute(request).await.unwrap();
+
+    // importlib-metadata and zipp are pulled in by both celery and sphinx
+    let graph = response.dependency_graph.unwrap();
+    assert_eq!(graph.direct_dependency_count(), 2);
+    assert_eq!(graph.transitive_dependency_count(), 7);
+}
+
 // Helper function to create a test license repository
 // In real tests, we would use a mock to avoid network calls
 fn create_test_license_repository() -> impl LicenseRepository + Clone {
     use std::collections::HashMap;
+    use uv_sbom::ports::outbound::PyPiMetadata;
 
     // Type alias for license data: (license, license_expression, classifiers, description)
     type LicenseData = (Option<String>, Option<String>, Vec<String>, Option<String>);
@@ -687,22 +1657,180 @@ fn create_test_license_repository() -> impl LicenseRepository + Clone {
             &self,
             package_name: &str,
             version: &str,
-        ) -> Result<(
-            Option<String>,
-            Option<String>,
-            Vec<String>,
-            Option<String>,
-            Option<String>,
-        )> {
+        ) -> Result<PyPiMetadata> {
             let key = format!("{}@{}", package_name, version);
             let base = self
                 .licenses
                 .get(&key)
                 .cloned()
                 .unwrap_or((None, None, vec![], None));
-            Ok((base.0, base.1, base.2, base.3, None))
+            Ok(PyPiMetadata {
+                license: base.0,
+                license_expression: base.1,
+                classifiers: base.2,
+                description: base.3,
+                ..Default::default()
+            })
         }
     }
 
     TestLicenseRepository::new()
 }
+
+mod digest_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const PROJECT: &str = "tests/fixtures/sample-project";
+
+    /// Writes an SBOM with `--emit-digest` and returns the directory holding it
+    fn generate_with_digest() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        cargo_bin_cmd!("uv-sbom")
+            .args(["-p", PROJECT, "--offline", "--emit-digest", "-o"])
+            .arg(temp_dir.path().join("sbom.json"))
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("SHA-256 (sbom.json): "));
+        temp_dir
+    }
+
+    #[test]
+    fn test_emit_digest_writes_sha256sum_file_and_lockfile_digest() {
+        let temp_dir = generate_with_digest();
+
+        let digest = fs::read_to_string(temp_dir.path().join("sbom.json.sha256")).unwrap();
+        let (hash, name) = digest.trim_end().split_once("  ").unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(name, "sbom.json");
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&fs::read(temp_dir.path().join("sbom.json")).unwrap()).unwrap();
+        let properties = json["properties"].as_array().unwrap();
+        assert_eq!(properties[0]["name"], "uv-sbom:lockfile-sha256");
+        assert_eq!(properties[0]["value"].as_str().unwrap().len(), 64);
+    }
+
+    #[test]
+    fn test_verify_accepts_unmodified_sbom() {
+        let temp_dir = generate_with_digest();
+
+        cargo_bin_cmd!("uv-sbom")
+            .arg("verify")
+            .arg(temp_dir.path().join("sbom.json"))
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("matches"));
+    }
+
+    #[test]
+    fn test_verify_rejects_modified_sbom() {
+        let temp_dir = generate_with_digest();
+        let sbom = temp_dir.path().join("sbom.json");
+        let mut content = fs::read_to_string(&sbom).unwrap();
+        content.push('\n');
+        fs::write(&sbom, content).unwrap();
+
+        cargo_bin_cmd!("uv-sbom")
+            .arg("verify")
+            .arg(&sbom)
+            .assert()
+            .code(3)
+            .stderr(predicate::str::contains("SHA-256 digest mismatch"));
+    }
+}
+
+// CLI `--platform` / `--marker-env` tests
+mod platform_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use predicates::prelude::*;
+
+    fn generate(extra_args: &[&str]) -> assert_cmd::assert::Assert {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/platform-project",
+                "-f",
+                "json",
+                "--offline",
+            ])
+            .args(extra_args)
+            .assert()
+            .code(0)
+    }
+
+    fn component_names(assert: &assert_cmd::assert::Assert) -> Vec<String> {
+        let sbom: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+        let mut names: Vec<String> = sbom["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_every_platform_by_default() {
+        let assert = generate(&[]);
+        assert_eq!(
+            component_names(&assert),
+            ["click", "colorama", "pywin32", "tomli", "uvloop"]
+        );
+    }
+
+    #[test]
+    fn test_linux_drops_windows_only_packages() {
+        let assert = generate(&["--platform", "linux"]).stderr(predicate::str::contains(
+            "Excluded 2 package(s) not installed on the target platform (linux)",
+        ));
+        // tomli depends on the Python version, which --platform leaves open
+        assert_eq!(component_names(&assert), ["click", "tomli", "uvloop"]);
+    }
+
+    #[test]
+    fn test_windows_with_python_version() {
+        let assert = generate(&[
+            "--platform",
+            "windows",
+            "--marker-env",
+            "python_full_version=3.12.1",
+        ]);
+        assert_eq!(component_names(&assert), ["click", "colorama", "pywin32"]);
+    }
+
+    #[test]
+    fn test_markdown_header_names_target_platform() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/platform-project",
+                "-f",
+                "markdown",
+                "--offline",
+                "--platform",
+                "macos",
+            ])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::contains("**Target platform:** macos"))
+            .stdout(predicate::str::contains("pywin32").not());
+    }
+
+    #[test]
+    fn test_invalid_platform_is_rejected() {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                "tests/fixtures/platform-project",
+                "--platform",
+                "solaris",
+            ])
+            .assert()
+            .code(2)
+            .stderr(predicate::str::contains("Invalid platform: solaris"));
+    }
+}
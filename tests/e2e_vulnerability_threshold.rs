@@ -89,6 +89,15 @@ mod cli_argument_tests {
             .code(2);
     }
 
+    /// CVE report conflicts with --no-check-cve
+    #[test]
+    fn test_cve_report_requires_check_cve() {
+        cargo_bin_cmd!("uv-sbom")
+            .args(["--no-check-cve", "--cve-report", "cve.json"])
+            .assert()
+            .code(2);
+    }
+
     /// Valid severity threshold values (case insensitive) - should not fail on argument parsing
     /// Note: These tests only verify argument parsing, not actual vulnerability checking
     #[test]
@@ -612,3 +621,157 @@ mod exit_code_tests {
             .code(0); // Success - no Critical vulnerabilities expected
     }
 }
+
+// ============================================================================
+// CVE Baseline Tests
+// ============================================================================
+// These tests run offline against a local advisory database, so they need no
+// network access.
+
+mod cve_baseline_tests {
+    use assert_cmd::cargo::cargo_bin_cmd;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
+
+    fn fixtures_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+    }
+
+    /// Writes an advisory for the certifi version in the vulnerable_project fixture
+    fn write_advisory(db: &Path, id: &str) {
+        let dir = db.join("vulns/certifi");
+        fs::create_dir_all(&dir).unwrap();
+        let advisory = format!(
+            r#"
+id: {id}
+summary: Test advisory for certifi
+affected:
+- package:
+    name: certifi
+    ecosystem: PyPI
+  ranges:
+  - type: ECOSYSTEM
+    events:
+    - introduced: "0"
+    - fixed: 2024.7.4
+severity:
+- type: CVSS_V3
+  score: CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:N/A:N
+"#
+        );
+        fs::write(dir.join(format!("{id}.yaml")), advisory).unwrap();
+    }
+
+    fn run(db: &Path, baseline: &Path, extra_args: &[&str]) -> std::process::Output {
+        cargo_bin_cmd!("uv-sbom")
+            .args([
+                "-p",
+                fixtures_path().join("vulnerable_project").to_str().unwrap(),
+                "--offline",
+                "--advisory-db",
+                db.to_str().unwrap(),
+                "--format",
+                "markdown",
+                "--cve-baseline",
+                baseline.to_str().unwrap(),
+            ])
+            .args(extra_args)
+            .output()
+            .unwrap()
+    }
+
+    fn write_baseline(path: &Path, ids: &[&str]) {
+        let ids: Vec<String> = ids.iter().map(|id| format!("\"{id}\"")).collect();
+        fs::write(
+            path,
+            format!(
+                r#"{{"version": 1, "packages": {{"certifi": [{}]}}}}"#,
+                ids.join(", ")
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_baseline_only_findings_pass() {
+        let dir = TempDir::new().unwrap();
+        let db = dir.path().join("advisory-db");
+        write_advisory(&db, "GHSA-xqr8-7jwr-rhp7");
+        let baseline = dir.path().join("cve-baseline.json");
+        write_baseline(&baseline, &["GHSA-xqr8-7jwr-rhp7"]);
+
+        let output = run(&db, &baseline, &[]);
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(0), "stderr: {}", stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("GHSA-xqr8-7jwr-rhp7) (baseline)"));
+    }
+
+    #[test]
+    fn test_new_finding_fails_against_baseline() {
+        let dir = TempDir::new().unwrap();
+        let db = dir.path().join("advisory-db");
+        write_advisory(&db, "GHSA-xqr8-7jwr-rhp7");
+        write_advisory(&db, "GHSA-2222-3333-4444");
+        let baseline = dir.path().join("cve-baseline.json");
+        write_baseline(&baseline, &["GHSA-xqr8-7jwr-rhp7"]);
+
+        let output = run(&db, &baseline, &[]);
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(1), "stderr: {}", stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("GHSA-xqr8-7jwr-rhp7) (baseline)"));
+        assert!(stdout.contains("GHSA-2222-3333-4444"));
+        assert!(!stdout.contains("GHSA-2222-3333-4444) (baseline)"));
+    }
+
+    #[test]
+    fn test_update_baseline_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let db = dir.path().join("advisory-db");
+        write_advisory(&db, "GHSA-xqr8-7jwr-rhp7");
+        let baseline = dir.path().join("cve-baseline.json");
+
+        // Without the file, only --update-cve-baseline may run; it accepts the findings
+        assert_eq!(run(&db, &baseline, &[]).status.code(), Some(3));
+        let output = run(&db, &baseline, &["--update-cve-baseline"]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(output.status.code(), Some(0), "stderr: {}", stderr);
+        assert!(stderr.contains("CVE baseline updated: 1 accepted vulnerabilities"));
+        assert_eq!(
+            fs::read_to_string(&baseline).unwrap(),
+            r#"{
+  "version": 1,
+  "packages": {
+    "certifi": [
+      "GHSA-xqr8-7jwr-rhp7"
+    ]
+  }
+}
+"#
+        );
+
+        // The written baseline lets the same findings pass, but not a new one
+        assert_eq!(run(&db, &baseline, &[]).status.code(), Some(0));
+        write_advisory(&db, "GHSA-2222-3333-4444");
+        assert_eq!(run(&db, &baseline, &[]).status.code(), Some(1));
+    }
+
+    #[test]
+    fn test_invalid_baseline_version_is_rejected() {
+        let dir = TempDir::new().unwrap();
+        let db = dir.path().join("advisory-db");
+        write_advisory(&db, "GHSA-xqr8-7jwr-rhp7");
+        let baseline = dir.path().join("cve-baseline.json");
+        fs::write(&baseline, r#"{"version": 99, "packages": {}}"#).unwrap();
+
+        let output = run(&db, &baseline, &[]);
+
+        assert_eq!(output.status.code(), Some(3));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("unsupported version 99"));
+    }
+}
@@ -592,3 +592,197 @@ source = { registry = "https://pypi.org/simple" }
     // but preserves dependency_map for correct direct/transitive classification
     assert_eq!(graph.transitive_dependency_count(), 1);
 }
+
+#[tokio::test]
+async fn test_generate_sbom_with_package_annotations() {
+    use uv_sbom::application::read_models::{SbomReadModelBuilder, SbomWarning};
+
+    let lockfile_content = r#"
+version = 1
+requires-python = ">=3.8"
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+    let lockfile_reader = MockLockfileReader::new(lockfile_content.to_string());
+    let license_repository =
+        MockLicenseRepository::new().with_license("requests", "2.31.0", "Apache 2.0", "HTTP");
+
+    let use_case: GenerateSbomUseCase<_, _, _, _, (), ()> = GenerateSbomUseCase::new(
+        lockfile_reader,
+        FileSystemReader::new(),
+        license_repository,
+        MockProgressReporter::new(),
+        None,
+        None,
+        uv_sbom::i18n::Locale::En,
+    );
+
+    let request = SbomRequest::builder()
+        .project_path("tests/fixtures/annotated-project")
+        .build()
+        .unwrap();
+    let response = use_case.execute(request).await.unwrap();
+
+    // The orphaned annotation is reported as a warning
+    assert_eq!(
+        response.warnings,
+        vec![SbomWarning::OrphanedAnnotation {
+            package: "left_pad".to_string()
+        }]
+    );
+
+    let read_model = SbomReadModelBuilder::build_with_project(
+        response.enriched_packages,
+        &response.metadata,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .with_annotations(&response.package_annotations);
+    let json = CycloneDxFormatter::new().format(&read_model).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    let properties = parsed["components"][0]["properties"].as_array().unwrap();
+    assert_eq!(properties.len(), 4);
+    assert_eq!(properties[0]["name"], "uv-sbom:annotation:justification");
+    assert_eq!(properties[0]["value"], "HTTP client for the public API");
+    assert_eq!(properties[1]["name"], "uv-sbom:annotation:owner");
+    assert_eq!(properties[1]["value"], "team-web");
+    assert_eq!(properties[2]["name"], "uv-sbom:license-source");
+    assert_eq!(properties[2]["value"], "pypi-license-field");
+    assert_eq!(properties[3]["name"], "uv-sbom:declared-constraint");
+    assert_eq!(properties[3]["value"], "requests>=2.31.0");
+}
+
+/// Copies only a uv.lock into a temporary directory, without a pyproject.toml
+fn lock_only_project(lockfile_content: &str) -> tempfile::TempDir {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(dir.path().join("uv.lock"), lockfile_content).unwrap();
+    dir
+}
+
+fn lock_only_use_case(
+    progress_reporter: MockProgressReporter,
+) -> GenerateSbomUseCase<
+    FileSystemReader,
+    FileSystemReader,
+    MockLicenseRepository,
+    MockProgressReporter,
+    (),
+    (),
+> {
+    let license_repository = MockLicenseRepository::new()
+        .with_license("requests", "2.31.0", "Apache 2.0", "HTTP library")
+        .with_license("urllib3", "1.26.0", "MIT", "HTTP library");
+    GenerateSbomUseCase::new(
+        FileSystemReader::new(),
+        FileSystemReader::new(),
+        license_repository,
+        progress_reporter,
+        None,
+        None,
+        uv_sbom::i18n::Locale::En,
+    )
+}
+
+const LOCK_WITHOUT_ROOT: &str = r#"
+version = 1
+requires-python = ">=3.8"
+
+[[package]]
+name = "requests"
+version = "2.31.0"
+source = { registry = "https://pypi.org/simple" }
+dependencies = [
+    { name = "urllib3" },
+]
+
+[[package]]
+name = "urllib3"
+version = "1.26.0"
+source = { registry = "https://pypi.org/simple" }
+"#;
+
+#[tokio::test]
+async fn test_generate_sbom_lock_only_uses_virtual_root() {
+    let dir = lock_only_project(&format!(
+        r#"{}
+[[package]]
+name = "exported-app"
+version = "1.0.0"
+source = {{ virtual = "." }}
+dependencies = [
+    {{ name = "requests" }},
+]
+"#,
+        LOCK_WITHOUT_ROOT
+    ));
+    let progress_reporter = MockProgressReporter::new();
+    let use_case = lock_only_use_case(progress_reporter.clone());
+
+    let request = SbomRequest::builder()
+        .project_path(dir.path())
+        .include_dependency_info(true)
+        .build()
+        .unwrap();
+    let response = use_case.execute(request).await.unwrap();
+
+    let graph = response.dependency_graph.unwrap();
+    assert_eq!(graph.direct_dependency_count(), 1);
+    assert_eq!(graph.transitive_dependency_count(), 1);
+    assert!(response.warnings.is_empty());
+    assert!(progress_reporter
+        .get_messages()
+        .iter()
+        .any(|m| m.contains("exported-app")));
+}
+
+#[tokio::test]
+async fn test_generate_sbom_lock_only_without_root_skips_dependency_analysis() {
+    use uv_sbom::application::read_models::SbomWarning;
+
+    let dir = lock_only_project(LOCK_WITHOUT_ROOT);
+    let use_case = lock_only_use_case(MockProgressReporter::new());
+
+    let request = SbomRequest::builder()
+        .project_path(dir.path())
+        .include_dependency_info(true)
+        .build()
+        .unwrap();
+    let response = use_case.execute(request).await.unwrap();
+
+    // The component inventory is still produced
+    assert_eq!(response.enriched_packages.len(), 2);
+    assert!(response.dependency_graph.is_none());
+    assert_eq!(
+        response.warnings,
+        vec![SbomWarning::DependencyAnalysisSkipped {
+            pyproject: dir.path().join("pyproject.toml").display().to_string()
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_generate_sbom_lock_only_without_root_fails_in_strict_mode() {
+    let dir = lock_only_project(LOCK_WITHOUT_ROOT);
+    let use_case = lock_only_use_case(MockProgressReporter::new());
+
+    let request = SbomRequest::builder()
+        .project_path(dir.path())
+        .include_dependency_info(true)
+        .strict(true)
+        .build()
+        .unwrap();
+    let result = use_case.execute(request).await;
+
+    assert!(matches!(
+        result,
+        Err(UvSbomError::ProjectConfigNotFound { path }) if path == dir.path().join("pyproject.toml")
+    ));
+}
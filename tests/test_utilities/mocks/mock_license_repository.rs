@@ -27,13 +27,11 @@ impl MockLicenseRepository {
     ) -> Self {
         self.licenses.insert(
             format!("{}@{}", package, version),
-            (
-                Some(license.to_string()),
-                None,
-                vec![],
-                Some(description.to_string()),
-                None,
-            ),
+            PyPiMetadata {
+                license: Some(license.to_string()),
+                description: Some(description.to_string()),
+                ..Default::default()
+            },
         );
         self
     }
@@ -56,14 +54,10 @@ impl Default for MockLicenseRepository {
 impl LicenseRepository for MockLicenseRepository {
     async fn fetch_license_info(&self, package_name: &str, version: &str) -> Result<PyPiMetadata> {
         if self.should_fail {
-            anyhow::bail!("Mock license repository failure");
+            return Err(UvSbomError::other("Mock license repository failure"));
         }
 
         let key = format!("{}@{}", package_name, version);
-        Ok(self
-            .licenses
-            .get(&key)
-            .cloned()
-            .unwrap_or((None, None, vec![], None, None)))
+        Ok(self.licenses.get(&key).cloned().unwrap_or_default())
     }
 }
@@ -27,14 +27,14 @@ impl MockLockfileReader {
 impl LockfileReader for MockLockfileReader {
     fn read_lockfile(&self, _project_path: &Path) -> Result<String> {
         if self.should_fail {
-            anyhow::bail!("Mock lockfile read failure");
+            return Err(UvSbomError::other("Mock lockfile read failure"));
         }
         Ok(self.content.clone())
     }
 
-    fn read_and_parse_lockfile(&self, _project_path: &Path) -> Result<LockfileParseResult> {
+    fn read_and_parse_lockfile(&self, project_path: &Path) -> Result<LockfileParseResult> {
         if self.should_fail {
-            anyhow::bail!("Mock lockfile read failure");
+            return Err(UvSbomError::other("Mock lockfile read failure"));
         }
 
         // Parse the mock content
@@ -66,7 +66,11 @@ impl LockfileReader for MockLockfileReader {
             dev: Vec<UvDependency>,
         }
 
-        let lockfile: UvLock = toml::from_str(&self.content)?;
+        let lockfile: UvLock =
+            toml::from_str(&self.content).map_err(|e| UvSbomError::LockfileParseError {
+                path: project_path.join("uv.lock"),
+                details: e.to_string(),
+            })?;
 
         let mut packages = Vec::new();
         let mut dependency_map = HashMap::new();
@@ -86,7 +90,11 @@ impl LockfileReader for MockLockfileReader {
             dependency_map.insert(pkg.name, deps);
         }
 
-        Ok((packages, dependency_map))
+        Ok(LockfileParseResult {
+            packages,
+            dependency_map,
+            ..Default::default()
+        })
     }
 
     fn read_and_parse_lockfile_for_member(
@@ -26,7 +26,7 @@ impl MockProjectConfigReader {
 impl ProjectConfigReader for MockProjectConfigReader {
     fn read_project_name(&self, _project_path: &Path) -> Result<String> {
         if self.should_fail {
-            anyhow::bail!("Mock project config read failure");
+            return Err(UvSbomError::other("Mock project config read failure"));
         }
         Ok(self.project_name.clone())
     }